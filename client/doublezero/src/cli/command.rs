@@ -4,7 +4,9 @@ use doublezero_daemon_cli::DaemonCommand;
 use doublezero_geolocation_cli::GeolocationArgs;
 use doublezero_serviceability_cli::cli::ServiceabilityCommand;
 
-use crate::cli::{multicast::MulticastCliCommand, sentinel::SentinelCliCommand};
+use crate::cli::{
+    multicast::MulticastCliCommand, schema::SchemaCliCommand, sentinel::SentinelCliCommand,
+};
 
 /// Top-level command tree for the unified `doublezero` binary.
 ///
@@ -14,9 +16,9 @@ use crate::cli::{multicast::MulticastCliCommand, sentinel::SentinelCliCommand};
 /// `disable`, `status`, `disconnect`, `latency`, `routes`) from
 /// `doublezero_daemon_cli`. The binary retains the
 /// `doublezero-geolocation-cli` module crate's geolocation subtree (via
-/// `GeolocationArgs`), the binary-only `Completion` generator, and `Multicast`
-/// (whose `Subscribe`/`Unsubscribe`/`Publish`/`Unpublish` arms route to
-/// `doublezero-daemon-cli` but stay nested to preserve the
+/// `GeolocationArgs`), the binary-only `Completion` and `Schema` generators,
+/// and `Multicast` (whose `Subscribe`/`Unsubscribe`/`Publish`/`Unpublish`
+/// arms route to `doublezero-daemon-cli` but stay nested to preserve the
 /// `doublezero multicast <verb>` invocation).
 #[derive(Subcommand, Debug)]
 pub enum Command {
@@ -38,6 +40,9 @@ pub enum Command {
     /// Generate shell completions
     Completion(CompletionCliCommand),
 
+    /// Describe the command tree as JSON
+    Schema(SchemaCliCommand),
+
     /// Flattened serviceability variants (Device, Link, Location, User, ...).
     /// Hoisted to top-level via `#[command(flatten)]`.
     #[command(flatten)]
@@ -48,4 +53,8 @@ pub enum Command {
 pub struct CompletionCliCommand {
     #[arg(value_enum)]
     pub shell: Shell,
+    /// Emit a script that completes device, location, link and multicast
+    /// group codes from the ledger instead of the static command tree
+    #[arg(long, default_value_t = false)]
+    pub dynamic: bool,
 }
@@ -0,0 +1,436 @@
+//! Dynamic shell completion of onchain codes.
+//!
+//! `doublezero completion <shell>` emits clap's static script, which knows the
+//! command tree but not the devices, locations, links and multicast groups on
+//! the ledger. With `--dynamic` it instead emits a registration script that
+//! calls back into the binary (`COMPLETE=<shell> doublezero ...`) on every
+//! `<TAB>`, and the arguments listed in [`CODE_ARGS`] then complete from the
+//! ledger. Fetched codes are cached per program ID for [`CACHE_TTL`] so
+//! repeated presses stay fast; any failure simply yields no candidates.
+
+use clap::{builder::StyledStr, Command};
+use clap_complete::{
+    engine::{ArgValueCompleter, CompletionCandidate},
+    env::Shells,
+    Shell,
+};
+use doublezero_sdk::{
+    commands::{
+        device::list::ListDeviceCommand, link::list::ListLinkCommand,
+        location::list::ListLocationCommand, multicastgroup::list::ListMulticastGroupCommand,
+    },
+    ClientPolicy, DZClient, DoubleZeroClient,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsStr,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// How long fetched codes are reused before the ledger is queried again.
+pub const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// RPC timeout for completion lookups; a slow ledger must not hang the shell.
+const RPC_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// The account types whose codes can be completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeKind {
+    Device,
+    Location,
+    Link,
+    MulticastGroup,
+}
+
+impl CodeKind {
+    fn cache_name(self) -> &'static str {
+        match self {
+            CodeKind::Device => "device",
+            CodeKind::Location => "location",
+            CodeKind::Link => "link",
+            CodeKind::MulticastGroup => "multicastgroup",
+        }
+    }
+}
+
+/// Arguments completed from the ledger: subcommand path, arg id, code kind.
+///
+/// Only arguments that accept an existing code are listed; `--code` on
+/// `create`/`update` names a new account and stays uncompleted.
+pub const CODE_ARGS: &[(&str, &str, CodeKind)] = &[
+    ("connect", "device", CodeKind::Device),
+    (
+        "connect multicast",
+        "multicast_groups",
+        CodeKind::MulticastGroup,
+    ),
+    ("connect multicast", "pub_groups", CodeKind::MulticastGroup),
+    ("connect multicast", "sub_groups", CodeKind::MulticastGroup),
+    ("disconnect", "device", CodeKind::Device),
+    ("latency history", "device", CodeKind::Device),
+    ("multicast subscribe", "groups", CodeKind::MulticastGroup),
+    ("multicast unsubscribe", "groups", CodeKind::MulticastGroup),
+    ("multicast publish", "groups", CodeKind::MulticastGroup),
+    ("multicast unpublish", "groups", CodeKind::MulticastGroup),
+    ("multicast group get", "code", CodeKind::MulticastGroup),
+    ("multicast group update", "pubkey", CodeKind::MulticastGroup),
+    ("multicast group delete", "pubkey", CodeKind::MulticastGroup),
+    (
+        "multicast group allowlist publisher list",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "multicast group allowlist publisher add",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "multicast group allowlist publisher remove",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "multicast group allowlist subscriber list",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "multicast group allowlist subscriber add",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "multicast group allowlist subscriber remove",
+        "code",
+        CodeKind::MulticastGroup,
+    ),
+    ("location get", "code", CodeKind::Location),
+    ("location update", "pubkey", CodeKind::Location),
+    ("location delete", "pubkey", CodeKind::Location),
+    ("device get", "code", CodeKind::Device),
+    ("device update", "pubkey", CodeKind::Device),
+    ("device delete", "pubkey", CodeKind::Device),
+    ("device set-health", "pubkey", CodeKind::Device),
+    ("device list", "code", CodeKind::Device),
+    ("device list", "location", CodeKind::Location),
+    ("device create", "location", CodeKind::Location),
+    ("device interface create", "device", CodeKind::Device),
+    ("device interface apply", "device", CodeKind::Device),
+    ("device interface list", "device", CodeKind::Device),
+    ("device interface get", "device", CodeKind::Device),
+    ("device interface status", "device", CodeKind::Device),
+    ("device interface delete", "device", CodeKind::Device),
+    (
+        "device interface update",
+        "pubkey_or_code",
+        CodeKind::Device,
+    ),
+    ("device diff", "code", CodeKind::Device),
+    (
+        "device rotate-metrics-publisher",
+        "pubkey",
+        CodeKind::Device,
+    ),
+    ("device challenge", "pubkey", CodeKind::Device),
+    ("device prove-key", "pubkey", CodeKind::Device),
+    ("device ack-config", "pubkey", CodeKind::Device),
+    ("link get", "code", CodeKind::Link),
+    ("link latency", "code", CodeKind::Link),
+    ("link accept", "code", CodeKind::Link),
+    ("link update", "pubkey", CodeKind::Link),
+    ("link set-health", "pubkey", CodeKind::Link),
+    ("link list", "code", CodeKind::Link),
+    ("link list", "side_a", CodeKind::Device),
+    ("link list", "side_z", CodeKind::Device),
+    ("link create wan", "side_a", CodeKind::Device),
+    ("link create wan", "side_z", CodeKind::Device),
+    ("link create dzx", "side_a", CodeKind::Device),
+    ("link create dzx", "side_z", CodeKind::Device),
+    ("exchange set-device", "device1", CodeKind::Device),
+    ("exchange set-device", "device2", CodeKind::Device),
+    ("user create", "device", CodeKind::Device),
+    ("user create-subscribe", "device", CodeKind::Device),
+    (
+        "user create-subscribe",
+        "publisher",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "user create-subscribe",
+        "subscriber",
+        CodeKind::MulticastGroup,
+    ),
+    ("user subscribe", "groups", CodeKind::MulticastGroup),
+    ("user list", "device", CodeKind::Device),
+    ("user list", "location", CodeKind::Location),
+    ("user list", "multicast_group", CodeKind::MulticastGroup),
+    ("feed create", "groups", CodeKind::MulticastGroup),
+    ("feed update", "groups", CodeKind::MulticastGroup),
+    (
+        "access-pass list",
+        "multicast_group_publisher",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "access-pass list",
+        "not_multicast_group_publisher",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "access-pass list",
+        "multicast_group_subscriber",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "access-pass list",
+        "not_multicast_group_subscriber",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "sentinel find-validator-multicast-publishers",
+        "multicast_group",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "sentinel create-validator-multicast-publishers",
+        "multicast_group",
+        CodeKind::MulticastGroup,
+    ),
+    (
+        "sentinel plan-tunnel-endpoint-rebalance",
+        "device",
+        CodeKind::Device,
+    ),
+    ("geolocation probe add-parent", "device", CodeKind::Device),
+    (
+        "geolocation probe remove-parent",
+        "device",
+        CodeKind::Device,
+    ),
+];
+
+/// Environment variable the registration script sets when calling back into
+/// the binary for candidates.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Write the script that registers `doublezero` for dynamic completion in
+/// `shell`.
+pub fn write_registration<W: Write>(shell: Shell, out: &mut W) -> eyre::Result<()> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(&shell.to_string())
+        .ok_or_else(|| eyre::eyre!("dynamic completion is not supported for {shell}"))?;
+    completer.write_registration(COMPLETE_VAR, "doublezero", "doublezero", "doublezero", out)?;
+    Ok(())
+}
+
+/// Attach a ledger-backed completer to every argument in [`CODE_ARGS`].
+pub fn with_code_completers(mut command: Command) -> Command {
+    for (path, arg_id, kind) in CODE_ARGS {
+        let path: Vec<&str> = path.split(' ').collect();
+        command = attach(command, &path, arg_id, *kind);
+    }
+    command
+}
+
+fn attach(command: Command, path: &[&str], arg_id: &str, kind: CodeKind) -> Command {
+    match path.split_first() {
+        Some((name, rest)) => command.mut_subcommand(name, |sub| attach(sub, rest, arg_id, kind)),
+        None => command.mut_arg(arg_id, |arg| {
+            arg.add(ArgValueCompleter::new(move |current: &OsStr| {
+                complete_codes(kind, current)
+            }))
+        }),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedCode {
+    code: String,
+    pubkey: String,
+}
+
+fn complete_codes(kind: CodeKind, current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return vec![];
+    };
+    let codes = load_codes(kind).unwrap_or_default();
+    candidates(&codes, current)
+}
+
+fn candidates(codes: &[CachedCode], prefix: &str) -> Vec<CompletionCandidate> {
+    codes
+        .iter()
+        .filter(|c| c.code.starts_with(prefix))
+        .map(|c| CompletionCandidate::new(&c.code).help(Some(StyledStr::from(&c.pubkey))))
+        .collect()
+}
+
+fn load_codes(kind: CodeKind) -> eyre::Result<Vec<CachedCode>> {
+    let client = DZClient::new(None, None, None, None)?.with_policy(ClientPolicy {
+        rpc_timeout: RPC_TIMEOUT,
+        max_retries: 0,
+        ..ClientPolicy::default()
+    });
+    let path = dirs_next::cache_dir()
+        .map(|dir| cache_path(&dir, &client.get_program_id().to_string(), kind));
+
+    if let Some(codes) = path.as_deref().and_then(|p| read_cache(p, CACHE_TTL)) {
+        return Ok(codes);
+    }
+    let codes = fetch_codes(&client, kind)?;
+    if let Some(path) = path {
+        // A cache that cannot be written only costs the next lookup a fetch.
+        let _ = write_cache(&path, &codes);
+    }
+    Ok(codes)
+}
+
+fn fetch_codes(client: &dyn DoubleZeroClient, kind: CodeKind) -> eyre::Result<Vec<CachedCode>> {
+    fn entries<T>(
+        accounts: std::collections::HashMap<solana_sdk::pubkey::Pubkey, T>,
+        code: impl Fn(&T) -> &str,
+    ) -> Vec<CachedCode> {
+        accounts
+            .iter()
+            .map(|(pubkey, account)| CachedCode {
+                code: code(account).to_string(),
+                pubkey: pubkey.to_string(),
+            })
+            .collect()
+    }
+
+    let mut codes = match kind {
+        CodeKind::Device => entries(ListDeviceCommand.execute(client)?, |d| &d.code),
+        CodeKind::Location => entries(ListLocationCommand.execute(client)?, |l| &l.code),
+        CodeKind::Link => entries(ListLinkCommand.execute(client)?, |l| &l.code),
+        CodeKind::MulticastGroup => {
+            entries(ListMulticastGroupCommand.execute(client)?, |m| &m.code)
+        }
+    };
+    codes.sort_by(|a, b| a.code.cmp(&b.code));
+    Ok(codes)
+}
+
+fn cache_path(cache_dir: &Path, program_id: &str, kind: CodeKind) -> PathBuf {
+    cache_dir
+        .join("doublezero")
+        .join("completion")
+        .join(program_id)
+        .join(format!("{}.json", kind.cache_name()))
+}
+
+/// The cached codes at `path`, unless missing, unreadable or older than `ttl`.
+fn read_cache(path: &Path, ttl: Duration) -> Option<Vec<CachedCode>> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+    if age > ttl {
+        return None;
+    }
+    serde_json::from_slice(&std::fs::read(path).ok()?).ok()
+}
+
+fn write_cache(path: &Path, codes: &[CachedCode]) -> eyre::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_vec(codes)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::App;
+    use clap::CommandFactory;
+    use tempfile::TempDir;
+
+    fn find<'a>(command: &'a Command, path: &str) -> Option<&'a Command> {
+        path.split(' ')
+            .try_fold(command, |parent, name| parent.find_subcommand(name))
+    }
+
+    fn code(code: &str, pubkey: &str) -> CachedCode {
+        CachedCode {
+            code: code.to_string(),
+            pubkey: pubkey.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_code_args_match_commands() {
+        let command = App::command();
+        for (path, arg_id, _) in CODE_ARGS {
+            let sub = find(&command, path).unwrap_or_else(|| panic!("no `{path}` command"));
+            assert!(
+                sub.get_arguments().any(|arg| arg.get_id() == *arg_id),
+                "no `{arg_id}` argument on `{path}`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_connect_device_has_completer() {
+        let command = with_code_completers(App::command());
+        let connect = find(&command, "connect").unwrap();
+        let device = connect
+            .get_arguments()
+            .find(|arg| arg.get_id() == "device")
+            .unwrap();
+        assert!(device.get::<ArgValueCompleter>().is_some());
+
+        let untouched = find(&command, "device create").unwrap();
+        let code = untouched
+            .get_arguments()
+            .find(|arg| arg.get_id() == "code")
+            .unwrap();
+        assert!(code.get::<ArgValueCompleter>().is_none());
+    }
+
+    #[test]
+    fn test_write_registration() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let mut out = Vec::new();
+            write_registration(shell, &mut out).unwrap();
+            let script = String::from_utf8(out).unwrap();
+            assert!(script.contains(COMPLETE_VAR), "{shell}: {script}");
+            assert!(script.contains("doublezero"), "{shell}: {script}");
+        }
+    }
+
+    #[test]
+    fn test_candidates_filter_by_prefix() {
+        let codes = vec![
+            code("ams-dz1", "A"),
+            code("ams-dz2", "B"),
+            code("fra-dz1", "C"),
+        ];
+        let values: Vec<String> = candidates(&codes, "ams")
+            .iter()
+            .map(|c| c.get_value().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(values, vec!["ams-dz1", "ams-dz2"]);
+        assert_eq!(candidates(&codes, "").len(), 3);
+        assert!(candidates(&codes, "nyc").is_empty());
+    }
+
+    #[test]
+    fn test_cache_round_trip_and_expiry() {
+        let tmp = TempDir::new().unwrap();
+        let path = cache_path(tmp.path(), "Prog1111", CodeKind::Device);
+        assert!(path.ends_with("doublezero/completion/Prog1111/device.json"));
+        assert!(read_cache(&path, CACHE_TTL).is_none());
+
+        let codes = vec![code("ams-dz1", "A"), code("fra-dz1", "C")];
+        write_cache(&path, &codes).unwrap();
+        assert_eq!(read_cache(&path, CACHE_TTL), Some(codes));
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(read_cache(&path, Duration::from_millis(1)).is_none());
+    }
+}
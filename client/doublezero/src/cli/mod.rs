@@ -1,3 +1,5 @@
 pub mod command;
+pub mod completion;
 pub mod multicast;
+pub mod schema;
 pub mod sentinel;
@@ -0,0 +1,95 @@
+use clap::{Args, Subcommand};
+use doublezero_cli_core::schema::cli_schema;
+use doublezero_serviceability_cli::schema::output_schemas;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SchemaCliCommand {
+    #[command(subcommand)]
+    pub command: SchemaCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SchemaCommands {
+    /// Print every command, its flags and its output schema as JSON
+    Dump(SchemaDumpCliCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct SchemaDumpCliCommand {
+    /// Print single-line JSON instead of pretty-printed JSON
+    #[arg(long)]
+    pub json_compact: bool,
+}
+
+impl SchemaDumpCliCommand {
+    /// Describe `command`, the binary's full clap tree, with the output
+    /// schemas the module crates register.
+    pub fn execute<W: Write>(&self, command: &clap::Command, out: &mut W) -> eyre::Result<()> {
+        let schema = cli_schema(command, &output_schemas());
+        if self.json_compact {
+            serde_json::to_writer(&mut *out, &schema)?;
+        } else {
+            serde_json::to_writer_pretty(&mut *out, &schema)?;
+        }
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::App;
+    use clap::CommandFactory;
+
+    fn find<'a>(commands: &'a [serde_json::Value], path: &str) -> Option<&'a serde_json::Value> {
+        commands.iter().find_map(|command| {
+            if command["path"] == path {
+                Some(command)
+            } else {
+                find(command["commands"].as_array()?, path)
+            }
+        })
+    }
+
+    #[test]
+    fn test_schema_dump() {
+        let mut out = Vec::new();
+        SchemaDumpCliCommand { json_compact: true }
+            .execute(&App::command().name("doublezero"), &mut out)
+            .unwrap();
+        let schema: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let commands = schema["commands"].as_array().unwrap();
+
+        assert_eq!(schema["name"], "doublezero");
+        assert!(schema["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|arg| arg["long"] == "env" && arg["kind"] == "value"));
+
+        let list = find(commands, "device list").unwrap();
+        assert_eq!(list["output"]["shape"], "collection");
+        assert_eq!(list["output"]["record"], "DeviceDisplay");
+        assert!(list["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|arg| arg["long"] == "json" && arg["kind"] == "flag"));
+        assert!(find(commands, "schema dump").is_some());
+        assert!(find(commands, "connect").is_some());
+        assert!(find(commands, "help").is_none());
+    }
+
+    #[test]
+    fn test_output_schemas_match_commands() {
+        let command = App::command();
+        for path in output_schemas().keys() {
+            let found = path
+                .split(' ')
+                .try_fold(&command, |parent, name| parent.find_subcommand(name));
+            assert!(found.is_some(), "no `{path}` command for its output schema");
+        }
+    }
+}
@@ -9,7 +9,9 @@ use doublezero_sentinel::{
     multicast_find::{apply_filters, FindFilters},
     nearest_device::{device_proximity_score, find_nearest_device_for_multicast},
     output::{print_table, OutputOptions},
-    tunnel_endpoint::select_tunnel_endpoint,
+    tunnel_endpoint::{
+        plan_tunnel_endpoint_rebalance, select_least_loaded_tunnel_endpoint, tunnel_endpoint_load,
+    },
     validator_metadata_reader::{
         HttpValidatorMetadataReader, ValidatorMetadataReader, DEFAULT_VALIDATOR_METADATA_URL,
     },
@@ -40,6 +42,8 @@ pub enum SentinelCommands {
     FindValidatorMulticastPublishers(FindValidatorMulticastPublishersCommand),
     /// Create multicast publisher users for IBRL validators that don't have one yet.
     CreateValidatorMulticastPublishers(CreateValidatorMulticastPublishersCommand),
+    /// Plan moves that spread users evenly across each device's user tunnel endpoints.
+    PlanTunnelEndpointRebalance(PlanTunnelEndpointRebalanceCommand),
 }
 
 // ---------------------------------------------------------------------------
@@ -459,10 +463,6 @@ pub struct CreateValidatorMulticastPublishersCommand {
     /// Use geographic distance (Haversine) instead of onchain link latency for nearest-device.
     #[arg(long)]
     nearest_via_geo: bool,
-
-    /// Simulate transactions without sending.
-    #[arg(long)]
-    dry_run: bool,
 }
 
 impl CreateValidatorMulticastPublishersCommand {
@@ -535,7 +535,7 @@ impl CreateValidatorMulticastPublishersCommand {
         )?;
 
         // Scope to solana tenant (or default/unset tenant).
-        let all_users: Vec<_> = all_users_unfiltered
+        let mut all_users: Vec<_> = all_users_unfiltered
             .into_iter()
             .filter(|u| u.tenant_pk == solana_tenant_pk || u.tenant_pk == default_tenant_pk)
             .collect();
@@ -673,7 +673,18 @@ impl CreateValidatorMulticastPublishersCommand {
                 let tunnel_endpoint = target_device
                     .map(|d| {
                         let exclude = tunnel_exclude_ips(&all_users, c.client_ip, &device_infos);
-                        select_tunnel_endpoint(d.public_ip, &d.user_tunnel_endpoints, &exclude)
+                        let load = tunnel_endpoint_load(
+                            &all_users,
+                            &d.pk,
+                            d.public_ip,
+                            &d.user_tunnel_endpoints,
+                        );
+                        select_least_loaded_tunnel_endpoint(
+                            d.public_ip,
+                            &d.user_tunnel_endpoints,
+                            &exclude,
+                            &load,
+                        )
                     })
                     .unwrap_or(Ipv4Addr::UNSPECIFIED);
                 PlanRow {
@@ -695,7 +706,8 @@ impl CreateValidatorMulticastPublishersCommand {
         print_table(plan_rows, &OutputOptions { json: false }, &[5]);
         eprintln!();
 
-        if self.dry_run {
+        // The global `--dry-run` flag stops here, after the plan is printed.
+        if dzclient.is_dry_run() {
             eprintln!("Dry run — no transactions sent.");
             return Ok(());
         }
@@ -747,6 +759,7 @@ impl CreateValidatorMulticastPublishersCommand {
             );
 
             let dz_user = DzUser {
+                pk: Pubkey::default(),
                 owner: candidate.owner,
                 client_ip: candidate.client_ip,
                 device_pk: target_device_pk,
@@ -761,7 +774,18 @@ impl CreateValidatorMulticastPublishersCommand {
                 .map(|d| {
                     let exclude =
                         tunnel_exclude_ips(&all_users, candidate.client_ip, &device_infos);
-                    select_tunnel_endpoint(d.public_ip, &d.user_tunnel_endpoints, &exclude)
+                    let load = tunnel_endpoint_load(
+                        &all_users,
+                        &target_device_pk,
+                        d.public_ip,
+                        &d.user_tunnel_endpoints,
+                    );
+                    select_least_loaded_tunnel_endpoint(
+                        d.public_ip,
+                        &d.user_tunnel_endpoints,
+                        &exclude,
+                        &load,
+                    )
                 })
                 .unwrap_or(Ipv4Addr::UNSPECIFIED);
 
@@ -822,6 +846,11 @@ impl CreateValidatorMulticastPublishersCommand {
                         d.users_count += 1;
                         d.multicast_publishers_count += 1;
                     }
+                    // Track the new user so later picks see its tunnel endpoint load.
+                    all_users.push(DzUser {
+                        tunnel_endpoint,
+                        ..dz_user
+                    });
                     created += 1;
                 }
                 Err(e) => {
@@ -844,6 +873,124 @@ impl CreateValidatorMulticastPublishersCommand {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Rebalance command
+// ---------------------------------------------------------------------------
+
+#[derive(Serialize, Tabled)]
+struct TunnelEndpointMoveRow {
+    #[tabled(rename = "DEVICE")]
+    device: String,
+    #[tabled(rename = "USER")]
+    user: String,
+    #[tabled(rename = "CLIENT IP")]
+    client_ip: String,
+    #[tabled(rename = "FROM")]
+    from: String,
+    #[tabled(rename = "TO")]
+    to: String,
+}
+
+/// Plan moves that spread users evenly across each device's user tunnel endpoints.
+///
+/// Read-only: prints the `doublezero user update --tunnel-endpoint` commands that
+/// would apply the plan.
+#[derive(Debug, Args)]
+pub struct PlanTunnelEndpointRebalanceCommand {
+    /// Only plan for this device (pubkey or code).
+    #[arg(long, value_name = "KEY_OR_CODE")]
+    device: Option<String>,
+
+    #[command(flatten)]
+    output: OutputOptions,
+}
+
+impl PlanTunnelEndpointRebalanceCommand {
+    pub async fn execute(self, dzclient: &DZClient) -> eyre::Result<()> {
+        let program_id = *dzclient.get_program_id();
+        let rpc_client = dzclient.rpc_client();
+
+        let device_infos: HashMap<Pubkey, DzDeviceInfo> =
+            dz_ledger_reader::fetch_device_infos(rpc_client, &program_id)
+                .map_err(|e| eyre::eyre!(e))?;
+        let dz_ledger = RpcDzLedgerReader::new(
+            NonblockingRpcClient::new_with_commitment(
+                dzclient.get_rpc().clone(),
+                CommitmentConfig::confirmed(),
+            ),
+            program_id,
+        );
+        let all_users = dz_ledger
+            .fetch_all_dz_users()
+            .await
+            .map_err(|e| eyre::eyre!(e))?;
+
+        let mut devices: Vec<&DzDeviceInfo> = device_infos
+            .values()
+            .filter(|d| match &self.device {
+                Some(key_or_code) => d.pk.to_string() == *key_or_code || d.code == *key_or_code,
+                None => true,
+            })
+            .collect();
+        if let Some(key_or_code) = &self.device {
+            if devices.is_empty() {
+                eyre::bail!("Device not found: {key_or_code}");
+            }
+        }
+        devices.sort_by(|a, b| a.code.cmp(&b.code));
+
+        let mut rows = vec![];
+        for device in devices {
+            for m in plan_tunnel_endpoint_rebalance(
+                &device.pk,
+                &all_users,
+                device.public_ip,
+                &device.user_tunnel_endpoints,
+            ) {
+                rows.push(TunnelEndpointMoveRow {
+                    device: device.code.clone(),
+                    user: m.user_pk.to_string(),
+                    client_ip: m.client_ip.to_string(),
+                    from: m.from.to_string(),
+                    to: m.to.to_string(),
+                });
+            }
+        }
+
+        if rows.is_empty() {
+            if self.output.json {
+                println!("[]");
+            } else {
+                eprintln!("Tunnel endpoints are balanced; nothing to move.");
+            }
+            return Ok(());
+        }
+
+        if !self.output.json {
+            eprintln!("\nPlanned {} move(s)\n", rows.len());
+        }
+        let commands: Vec<String> = rows
+            .iter()
+            .map(|r| {
+                format!(
+                    "doublezero user update --pubkey {} --tunnel-endpoint {}",
+                    r.user, r.to
+                )
+            })
+            .collect();
+        let json = self.output.json;
+        print_table(rows, &self.output, &[]);
+        if !json {
+            eprintln!("\nApply with:\n");
+            for cmd in commands {
+                println!("{cmd}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
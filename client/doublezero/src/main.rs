@@ -1,10 +1,13 @@
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
-use std::path::PathBuf;
+use std::{io::Write, path::PathBuf};
 mod cli;
-use crate::cli::{command::Command, multicast::MulticastCommands, sentinel::SentinelCommands};
+use crate::cli::{
+    command::Command, multicast::MulticastCommands, schema::SchemaCommands,
+    sentinel::SentinelCommands,
+};
 use doublezero_cli_core::LogLevel;
-use doublezero_config::Environment;
+use doublezero_config::{Environment, EnvironmentManifest, ManifestSource, ENV_CUSTOM_PREFIX};
 use doublezero_daemon_cli::{DaemonClientImpl, DaemonCommand};
 use doublezero_geolocation_cli::GeoCliCommandImpl;
 use doublezero_sdk::{
@@ -30,7 +33,7 @@ struct LedgerAdapter<'a, C: CliCommand> {
 
 impl<C: CliCommand + Sync> doublezero_daemon_cli::LedgerClient for LedgerAdapter<'_, C> {
     fn get_environment(&self) -> Environment {
-        self.env
+        self.env.clone()
     }
 
     fn get_payer(&self) -> solana_sdk::pubkey::Pubkey {
@@ -114,6 +117,10 @@ impl<C: CliCommand + Sync> doublezero_daemon_cli::LedgerClient for LedgerAdapter
             .get_tenant(doublezero_sdk::commands::tenant::get::GetTenantCommand { pubkey_or_code })
     }
 
+    fn network_health(&self) -> eyre::Result<doublezero_sdk::health::NetworkHealth> {
+        self.client.network_health()
+    }
+
     fn list_multicastgroup(
         &self,
     ) -> eyre::Result<
@@ -157,7 +164,11 @@ impl<C: CliCommand + Sync> doublezero_daemon_cli::LedgerClient for LedgerAdapter
 struct App {
     #[command(subcommand)]
     command: Option<Command>,
-    /// DZ env (testnet, devnet, or mainnet-beta).
+    /// DZ env (testnet, devnet, mainnet-beta, or custom:<name>).
+    ///
+    /// `custom:<name>` selects a user-defined environment from the manifest
+    /// named by `DZ_ENV_MANIFEST` (a file path or URL), defaulting to
+    /// `~/.config/doublezero/environments.json`.
     ///
     /// `--env` resolves a whole network at once (ledger URL, WS URL, Solana L1
     /// URL, serviceability and geolocation program IDs). The per-field flags
@@ -196,6 +207,31 @@ struct App {
     /// Suppress version warning output
     #[arg(long, global = true)]
     no_version_warning: bool,
+    /// Build transactions without sending them. The unsigned transactions
+    /// (base64) and their decoded instructions are written as JSON to stdout,
+    /// or to PATH with `--dry-run=PATH`, for review or offline signing.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "-",
+        global = true
+    )]
+    dry_run: Option<PathBuf>,
+    /// Per-request RPC timeout in seconds. Overrides `client_policy` in
+    /// `config.yml` and `DOUBLEZERO_RPC_TIMEOUT_SECS`.
+    #[arg(long, value_name = "SECS", global = true)]
+    rpc_timeout: Option<u64>,
+    /// Seconds a sent transaction may go unseen before confirmation gives up.
+    /// Overrides `client_policy` in `config.yml` and
+    /// `DOUBLEZERO_CONFIRM_TIMEOUT_SECS`.
+    #[arg(long, value_name = "SECS", global = true)]
+    confirm_timeout: Option<u64>,
+    /// Retries on transient RPC errors. Overrides `client_policy` in
+    /// `config.yml` and `DOUBLEZERO_RPC_MAX_RETRIES`.
+    #[arg(long, value_name = "N", global = true)]
+    max_retries: Option<usize>,
     /// Diagnostic logging level. One of: `off`, `error`, `warn` (default), `info`, `debug`, `trace`.
     #[arg(
         long = "log-level",
@@ -231,6 +267,28 @@ fn resolve_environment(
     }
 }
 
+/// Fetch `--env custom:<name>` when `DZ_ENV_MANIFEST` is a URL. Returns `None`
+/// for every other `--env` value; file manifests are read by
+/// [`resolve_environment`].
+async fn fetch_custom_environment(env_flag: Option<&str>) -> eyre::Result<Option<Environment>> {
+    let Some(name) = env_flag.and_then(|s| s.strip_prefix(ENV_CUSTOM_PREFIX)) else {
+        return Ok(None);
+    };
+    let Some(ManifestSource::Url(url)) = ManifestSource::from_env() else {
+        return Ok(None);
+    };
+    let body = solana_client::client_error::reqwest::get(&url)
+        .await
+        .and_then(|resp| resp.error_for_status())
+        .map_err(|e| eyre::eyre!("Unable to fetch environment manifest {url}: {e}"))?
+        .text()
+        .await?;
+    let manifest = EnvironmentManifest::from_json(&body)?;
+    Ok(Some(Environment::Custom(Box::new(
+        manifest.get(name)?.clone(),
+    ))))
+}
+
 /// Resolve a `--program-id` / `--geo-program-id` flag value into a `Pubkey`.
 ///
 /// The raw value is first run through `convert` so environment monikers (in
@@ -252,12 +310,48 @@ fn resolve_program_id(
     })
 }
 
+/// Render a top-level error like `render_eyre`, but name a failed program's
+/// custom error (`InterfaceHasEdgeAssignment: ...` rather than only its
+/// message) and follow the chain with a remediation hint where there is one.
+fn render_error(err: &eyre::Report) {
+    let Some(decoded) = doublezero_sdk::errors::decode_report(err) else {
+        doublezero_cli_core::error::render_eyre(err);
+        return;
+    };
+    let described = decoded.to_string();
+    for (i, cause) in err.chain().enumerate() {
+        let cause = cause.to_string();
+        let cause = if cause == decoded.message || cause == described {
+            &described
+        } else {
+            &cause
+        };
+        if i == 0 {
+            eprintln!("Error: {cause}");
+        } else {
+            eprintln!("  caused by: {cause}");
+        }
+    }
+    if let Some(hint) = decoded.hint {
+        eprintln!("  hint: {hint}");
+    }
+}
+
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
     unsafe {
         libc::signal(libc::SIGPIPE, libc::SIG_DFL);
     }
 
+    // Answers `COMPLETE=<shell> doublezero ...` callbacks from a dynamic
+    // completion script and exits; a no-op on regular runs.
+    clap_complete::CompleteEnv::with_factory(|| {
+        cli::completion::with_code_completers(App::command().name("doublezero"))
+    })
+    .var(cli::completion::COMPLETE_VAR)
+    .bin("doublezero")
+    .complete();
+
     let app = App::parse();
 
     doublezero_cli_core::init_logging(app.log_level);
@@ -286,11 +380,15 @@ async fn main() -> eyre::Result<()> {
     let persisted_exists = persisted_path.is_file();
 
     let env_explicit = app.env.is_some();
-    let env = resolve_environment(app.env.as_deref(), persisted_exists.then_some(&persisted))
-        .unwrap_or_else(|e| {
-            doublezero_cli_core::error::render_eyre(&e);
-            std::process::exit(1);
-        });
+    let env = match fetch_custom_environment(app.env.as_deref()).await {
+        Ok(Some(env)) => Ok(env),
+        Ok(None) => resolve_environment(app.env.as_deref(), persisted_exists.then_some(&persisted)),
+        Err(e) => Err(e),
+    }
+    .unwrap_or_else(|e| {
+        doublezero_cli_core::error::render_eyre(&e);
+        std::process::exit(1);
+    });
 
     let local_version = option_env!("BUILD_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
     let mut ctx_builder = doublezero_cli_core::CliContextBuilder::new()
@@ -351,11 +449,27 @@ async fn main() -> eyre::Result<()> {
     if let Some(s) = app.sock_file.clone() {
         ctx_builder = ctx_builder.with_daemon_socket_path(s);
     }
+    if let Some(home) = dirs_next::home_dir() {
+        ctx_builder = ctx_builder.with_config_dir(home.join(".config").join("doublezero"));
+    }
     let ctx = ctx_builder.build().unwrap_or_else(|e| {
         doublezero_cli_core::error::render_eyre(&e);
         std::process::exit(1);
     });
 
+    // Timeout/retry policy: flag > env var > persisted `config.yml` > default.
+    let policy = doublezero_sdk::ClientPolicy::resolve(
+        persisted_exists
+            .then_some(persisted.client_policy.as_ref())
+            .flatten(),
+    )?
+    .with_config(&doublezero_sdk::ClientPolicyConfig {
+        rpc_timeout_secs: app.rpc_timeout,
+        confirm_timeout_secs: app.confirm_timeout,
+        max_retries: app.max_retries,
+        backoff_ms: None,
+    });
+
     // Build the SDK client directly from the resolved `CliContext`. The context
     // already carries the fully resolved URL/WS/program-ID, so `from_context`
     // consumes them verbatim (no config-file re-read, no moniker conversion).
@@ -364,7 +478,16 @@ async fn main() -> eyre::Result<()> {
     // env var > stdin > context keypair path > default) is preserved. Passing
     // the layered ctx value as the CLI source would mask the env var, which the
     // e2e contributor-auth suite relies on for negative-authz checks.
-    let dzclient = DZClient::from_context(&ctx, app.keypair.clone())?;
+    let mut dzclient = DZClient::from_context(&ctx, app.keypair.clone())?.with_policy(policy);
+    // Persisted fallback endpoints back up the persisted primary only: an
+    // explicit `--env`/`--url`/`--ws` selects exactly one endpoint.
+    if persisted_exists && !env_explicit && app.url.is_none() && app.ws.is_none() {
+        dzclient = dzclient
+            .with_fallback_endpoints(&persisted.fallback_endpoints, persisted.rpc_read_mode)?;
+    }
+    if app.dry_run.is_some() {
+        dzclient = dzclient.with_dry_run();
+    }
     let has_keypair_source = app.keypair.is_some()
         || std::env::var(doublezero_sdk::keypair::ENV_KEYPAIR).is_ok()
         || !std::io::IsTerminal::is_terminal(&std::io::stdin());
@@ -394,6 +517,7 @@ async fn main() -> eyre::Result<()> {
         Command::Daemon(
             DaemonCommand::Enable(_) | DaemonCommand::Disable(_) | DaemonCommand::Status(_)
         ) | Command::Completion(_)
+            | Command::Schema(_)
             | Command::Serviceability(
                 ServiceabilityCommand::Address(_)
                     | ServiceabilityCommand::Balance(_)
@@ -431,6 +555,7 @@ async fn main() -> eyre::Result<()> {
             SentinelCommands::CreateValidatorMulticastPublishers(args) => {
                 args.execute(&dzclient).await
             }
+            SentinelCommands::PlanTunnelEndpointRebalance(args) => args.execute(&dzclient).await,
         },
 
         // Geolocation module crate (doublezero-geolocation-cli per RFC-20)
@@ -478,20 +603,29 @@ async fn main() -> eyre::Result<()> {
 
         // Clap shell-completion generator (binary-local)
         Command::Completion(args) => {
-            let mut cmd = App::command();
-            generate(args.shell, &mut cmd, "doublezero", &mut std::io::stdout());
-            Ok(())
+            if args.dynamic {
+                cli::completion::write_registration(args.shell, &mut std::io::stdout())
+            } else {
+                let mut cmd = App::command();
+                generate(args.shell, &mut cmd, "doublezero", &mut std::io::stdout());
+                Ok(())
+            }
         }
 
+        // Command-tree schema dump (binary-local): needs the full clap tree.
+        Command::Schema(args) => match args.command {
+            SchemaCommands::Dump(cmd) => {
+                let app = App::command().name("doublezero").version(local_version);
+                cmd.execute(&app, &mut handle)
+            }
+        },
+
         // Binary-level override: subscribe uses the real blocking websocket
         // loop (DZClient::subscribe) for live event streaming. The module
         // crate's SubscribeCliCommand.execute() falls back to a get_all()
         // snapshot for testability (mockall cannot mock FnMut callbacks).
         Command::Serviceability(ServiceabilityCommand::Subscribe(_)) => {
-            use std::{
-                io::Write,
-                sync::{atomic::AtomicBool, Arc},
-            };
+            use std::sync::{atomic::AtomicBool, Arc};
             writeln!(handle, "Waiting for events...")?;
             let stop = Arc::new(AtomicBool::new(false));
             dzclient.subscribe(
@@ -507,10 +641,21 @@ async fn main() -> eyre::Result<()> {
         Command::Serviceability(cmd) => cmd.execute(&ctx, &client, &mut handle).await,
     };
 
+    // Emit whatever was captured, even if a later step failed because the
+    // earlier transactions were never sent.
+    if let Some(path) = &app.dry_run {
+        let plan = serde_json::to_string_pretty(&dzclient.take_dry_run_transactions())?;
+        if path.as_os_str() == "-" {
+            writeln!(handle, "{plan}")?;
+        } else {
+            std::fs::write(path, format!("{plan}\n"))?;
+        }
+    }
+
     match res {
         Ok(_) => {}
         Err(e) => {
-            doublezero_cli_core::error::render_eyre(&e);
+            render_error(&e);
             std::process::exit(1);
         }
     };
@@ -601,12 +746,36 @@ mod tests {
         App::try_parse_from(["doublezero", "--env", "devnet"]).expect("--env alone should parse");
     }
 
+    #[test]
+    fn env_show_takes_global_env() {
+        let app = parse_ok(&["doublezero", "env", "show", "--env", "devnet"]);
+        assert_eq!(app.env.as_deref(), Some("devnet"));
+        assert!(App::try_parse_from(["doublezero", "env", "show", "devnet"]).is_err());
+    }
+
     #[test]
     fn url_alone_parses() {
         App::try_parse_from(["doublezero", "--url", "https://x.invalid/"])
             .expect("--url alone should parse");
     }
 
+    #[test]
+    fn dry_run_flag_parses() {
+        let app = parse_ok(&["doublezero", "device", "list"]);
+        assert_eq!(app.dry_run, None);
+
+        // Bare flag writes to stdout; `=PATH` writes to a file. The value must
+        // be attached with `=` so a following positional isn't swallowed.
+        let app = parse_ok(&["doublezero", "--dry-run", "device", "list"]);
+        assert_eq!(app.dry_run.as_deref(), Some(std::path::Path::new("-")));
+
+        let app = parse_ok(&["doublezero", "device", "list", "--dry-run=plan.json"]);
+        assert_eq!(
+            app.dry_run.as_deref(),
+            Some(std::path::Path::new("plan.json"))
+        );
+    }
+
     #[test]
     fn sentinel_subcommands_parse() {
         App::try_parse_from([
@@ -10,12 +10,17 @@ import (
 	"net/http"
 	"os"
 	"os/signal"
+	"path/filepath"
 	"syscall"
 	"time"
 
 	"github.com/gagliardetto/solana-go"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/latency"
 	"github.com/malbeclabs/doublezero/client/doublezerod/internal/liveness"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/manager"
 	"github.com/malbeclabs/doublezero/client/doublezerod/internal/runtime"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/usage"
 	"github.com/malbeclabs/doublezero/config"
 	"github.com/prometheus/client_golang/prometheus"
 	"github.com/prometheus/client_golang/prometheus/promauto"
@@ -43,6 +48,15 @@ var (
 	reconcilerFetchTimeout      = flag.Int("reconciler-fetch-timeout", 60, "timeout in seconds for onchain data fetches during reconciliation")
 	onchainRPCTimeout           = flag.Duration("onchain-rpc-timeout", defaultOnchainRPCTimeout, "Timeout for GetProgramData RPC calls inside the onchain caching fetcher.")
 	stateDir                    = flag.String("state-dir", "/var/lib/doublezerod", "directory for persistent state files")
+	pathSampling                = flag.Bool("path-sampling", false, "periodically measure RTT to the device terminating each connected tunnel")
+	pathSampleInterval          = flag.Duration("path-sample-interval", 60*time.Second, "interval between connected path RTT samples")
+	pathSampleJournal           = flag.String("path-sample-journal", "", "file connected path samples are appended to as JSON lines; empty keeps them in memory only")
+	tunnelHealth                = flag.Bool("tunnel-health", false, "monitor each connected tunnel (endpoint ping and BGP session) and re-provision it when it stays down")
+	tunnelHealthInterval        = flag.Duration("tunnel-health-interval", 30*time.Second, "interval between tunnel health checks")
+	tunnelHealthThreshold       = flag.Int("tunnel-health-failure-threshold", 3, "consecutive failed tunnel health checks before a service is re-provisioned")
+	usageAccounting             = flag.Bool("usage-accounting", false, "track bytes in and out of each connected session and persist daily rollups to the state directory")
+	usageInterval               = flag.Duration("usage-interval", 60*time.Second, "interval between reads of the tunnel interface counters")
+	apiAccessPolicy             = flag.String("api-access-policy", "", "JSON file listing the users and groups allowed to provision, remove, enable and disable; empty allows every local user")
 
 	// Route liveness configuration flags.
 	routeLivenessTxMin       = flag.Duration("route-liveness-tx-min", defaultRouteLivenessTxMin, "route liveness tx min")
@@ -190,10 +204,44 @@ func main() {
 		}
 	}
 
+	var psc *latency.PathSamplerConfig
+	if *pathSampling {
+		psc = &latency.PathSamplerConfig{
+			Interval:    *pathSampleInterval,
+			JournalPath: *pathSampleJournal,
+		}
+	}
+
+	var thc *manager.TunnelHealthConfig
+	if *tunnelHealth {
+		thc = &manager.TunnelHealthConfig{
+			Interval:         *tunnelHealthInterval,
+			FailureThreshold: *tunnelHealthThreshold,
+		}
+	}
+
+	var uc *usage.Config
+	if *usageAccounting {
+		uc = &usage.Config{
+			Interval: *usageInterval,
+			Path:     filepath.Join(*stateDir, usage.FileName),
+		}
+	}
+
+	var policy *api.AccessPolicy
+	if *apiAccessPolicy != "" {
+		var err error
+		policy, err = api.LoadAccessPolicy(*apiAccessPolicy)
+		if err != nil {
+			slog.Error("failed to load api access policy", "error", err)
+			os.Exit(1)
+		}
+	}
+
 	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
 	defer stop()
 
-	if err := runtime.Run(ctx, *sockFile, *routeConfigPath, *enableLatencyProbing, *enableLatencyMetrics, *latencyProbeTunnelEndpoints, *latencySingleSocket, networkConfig, *probeInterval, *cacheUpdateInterval, lmc, *clientIP, *reconcilerPollInterval, *reconcilerFetchTimeout, *stateDir, *onchainRPCTimeout); err != nil {
+	if err := runtime.Run(ctx, *sockFile, *routeConfigPath, *enableLatencyProbing, *enableLatencyMetrics, *latencyProbeTunnelEndpoints, *latencySingleSocket, networkConfig, *probeInterval, *cacheUpdateInterval, lmc, *clientIP, *reconcilerPollInterval, *reconcilerFetchTimeout, *stateDir, *onchainRPCTimeout, psc, thc, uc, policy); err != nil {
 		slog.Error("runtime error", "error", err)
 		os.Exit(1)
 	}
@@ -0,0 +1,197 @@
+package api
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net"
+	"net/http"
+	"os"
+	"os/user"
+	"slices"
+	"strconv"
+
+	"golang.org/x/sys/unix"
+)
+
+// privilegedOperations maps the API routes that change tunnels, routes or
+// daemon state to the operation names used in an AccessPolicy. Every other
+// route is read-only and open to any local user.
+var privilegedOperations = map[string]string{
+	"POST /provision": "provision",
+	"POST /remove":    "remove",
+	"POST /enable":    "enable",
+	"POST /disable":   "disable",
+}
+
+// Peer is the process on the other end of an API connection, as reported by
+// SO_PEERCRED.
+type Peer struct {
+	PID int32
+	UID uint32
+	GID uint32
+}
+
+type peerKey struct{}
+
+// PeerFromContext returns the peer credentials of the connection a request
+// arrived on, if the connection is a unix socket.
+func PeerFromContext(ctx context.Context) (Peer, bool) {
+	peer, ok := ctx.Value(peerKey{}).(Peer)
+	return peer, ok
+}
+
+// peerConnContext stores the credentials of unix socket peers in the
+// context of every request served on the connection.
+func peerConnContext(ctx context.Context, c net.Conn) context.Context {
+	uc, ok := c.(*net.UnixConn)
+	if !ok {
+		return ctx
+	}
+	raw, err := uc.SyscallConn()
+	if err != nil {
+		return ctx
+	}
+	var cred *unix.Ucred
+	var credErr error
+	err = raw.Control(func(fd uintptr) {
+		cred, credErr = unix.GetsockoptUcred(int(fd), unix.SOL_SOCKET, unix.SO_PEERCRED)
+	})
+	if err == nil {
+		err = credErr
+	}
+	if err != nil {
+		slog.Warn("api: error reading peer credentials", "error", err)
+		return ctx
+	}
+	return context.WithValue(ctx, peerKey{}, Peer{PID: cred.Pid, UID: cred.Uid, GID: cred.Gid})
+}
+
+// AccessPolicy is an allowlist of the local users and groups that may call
+// each privileged operation, so the CLI can run unprivileged while the daemon
+// holds CAP_NET_ADMIN. Root and the user the daemon runs as are always
+// allowed.
+//
+// Example:
+//
+//	{"rules": [{"operations": ["provision", "remove"], "groups": ["doublezero"]},
+//	           {"operations": ["*"], "users": ["ops"]}]}
+type AccessPolicy struct {
+	Rules []AccessRule `json:"rules"`
+}
+
+// AccessRule allows the listed users and members of the listed groups to call
+// the listed operations. Users and groups are names or numeric ids; "*"
+// matches every operation.
+type AccessRule struct {
+	Operations []string `json:"operations"`
+	Users      []string `json:"users,omitempty"`
+	Groups     []string `json:"groups,omitempty"`
+}
+
+// LoadAccessPolicy reads an AccessPolicy from a JSON file.
+func LoadAccessPolicy(path string) (*AccessPolicy, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("error reading access policy: %v", err)
+	}
+	var policy AccessPolicy
+	if err := json.Unmarshal(data, &policy); err != nil {
+		return nil, fmt.Errorf("error parsing access policy %s: %v", path, err)
+	}
+	for i, rule := range policy.Rules {
+		if len(rule.Operations) == 0 {
+			return nil, fmt.Errorf("access policy rule %d lists no operations", i)
+		}
+		for _, op := range rule.Operations {
+			if op != "*" && !isOperation(op) {
+				return nil, fmt.Errorf("access policy rule %d: unknown operation %q", i, op)
+			}
+		}
+	}
+	return &policy, nil
+}
+
+func isOperation(name string) bool {
+	for _, op := range privilegedOperations {
+		if op == name {
+			return true
+		}
+	}
+	return false
+}
+
+// Allows reports whether the peer may call the operation.
+func (p *AccessPolicy) Allows(op string, peer Peer) bool {
+	if peer.UID == 0 || peer.UID == uint32(os.Getuid()) {
+		return true
+	}
+	uid := strconv.FormatUint(uint64(peer.UID), 10)
+	var username string
+	gids := []string{strconv.FormatUint(uint64(peer.GID), 10)}
+	if u, err := user.LookupId(uid); err == nil {
+		username = u.Username
+		if groups, err := u.GroupIds(); err == nil {
+			gids = append(gids, groups...)
+		}
+	}
+
+	for _, rule := range p.Rules {
+		if !slices.Contains(rule.Operations, op) && !slices.Contains(rule.Operations, "*") {
+			continue
+		}
+		for _, u := range rule.Users {
+			if u == uid || (username != "" && u == username) {
+				return true
+			}
+		}
+		for _, g := range rule.Groups {
+			if slices.Contains(gids, g) {
+				return true
+			}
+			if grp, err := user.LookupGroup(g); err == nil && slices.Contains(gids, grp.Gid) {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+type statusRecorder struct {
+	http.ResponseWriter
+	status int
+}
+
+func (r *statusRecorder) WriteHeader(status int) {
+	r.status = status
+	r.ResponseWriter.WriteHeader(status)
+}
+
+// authorize audit-logs every privileged call with the caller's credentials
+// and, when a policy is set, rejects callers it does not allow.
+func authorize(next http.Handler, policy *AccessPolicy) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		op, ok := privilegedOperations[r.Method+" "+r.URL.Path]
+		if !ok {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		peer, known := PeerFromContext(r.Context())
+		attrs := []any{"operation", op}
+		if known {
+			attrs = append(attrs, "uid", peer.UID, "gid", peer.GID, "pid", peer.PID)
+		}
+
+		if policy != nil && (!known || !policy.Allows(op, peer)) {
+			slog.Warn("audit: privileged call denied", attrs...)
+			http.Error(w, fmt.Sprintf("operation %s not allowed for this user", op), http.StatusForbidden)
+			return
+		}
+
+		rec := &statusRecorder{ResponseWriter: w, status: http.StatusOK}
+		next.ServeHTTP(rec, r)
+		slog.Info("audit: privileged call", append(attrs, "status", rec.status)...)
+	})
+}
@@ -0,0 +1,124 @@
+package api
+
+import (
+	"context"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strconv"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+// unprivilegedPeer returns a peer that is neither root nor the test process.
+func unprivilegedPeer() Peer {
+	uid := uint32(os.Getuid()) + 4242
+	return Peer{PID: 1, UID: uid, GID: uid}
+}
+
+func TestAuthorize(t *testing.T) {
+	t.Parallel()
+
+	peer := unprivilegedPeer()
+	calls := 0
+	next := http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		w.WriteHeader(http.StatusOK)
+	})
+
+	serve := func(policy *AccessPolicy, method, path string, peer *Peer) int {
+		req := httptest.NewRequest(method, path, nil)
+		if peer != nil {
+			req = req.WithContext(context.WithValue(req.Context(), peerKey{}, *peer))
+		}
+		rr := httptest.NewRecorder()
+		authorize(next, policy).ServeHTTP(rr, req)
+		return rr.Code
+	}
+
+	// Without a policy every caller is allowed.
+	require.Equal(t, http.StatusOK, serve(nil, http.MethodPost, "/provision", &peer))
+
+	policy := &AccessPolicy{Rules: []AccessRule{
+		{Operations: []string{"remove"}, Users: []string{itoa(peer.UID)}},
+		{Operations: []string{"*"}, Groups: []string{itoa(peer.GID + 1)}},
+	}}
+	require.Equal(t, http.StatusOK, serve(policy, http.MethodPost, "/remove", &peer))
+	require.Equal(t, http.StatusForbidden, serve(policy, http.MethodPost, "/provision", &peer))
+
+	// Group rules match the peer's gid.
+	member := Peer{PID: 1, UID: peer.UID + 1, GID: peer.GID + 1}
+	require.Equal(t, http.StatusOK, serve(policy, http.MethodPost, "/provision", &member))
+
+	// Callers whose credentials are unknown are denied privileged calls.
+	require.Equal(t, http.StatusForbidden, serve(policy, http.MethodPost, "/enable", nil))
+
+	// Root is always allowed; read-only routes are open to everyone.
+	require.Equal(t, http.StatusOK, serve(policy, http.MethodPost, "/disable", &Peer{}))
+	require.Equal(t, http.StatusOK, serve(policy, http.MethodGet, "/v2/status", nil))
+
+	require.Equal(t, 5, calls)
+}
+
+func TestLoadAccessPolicy(t *testing.T) {
+	t.Parallel()
+
+	write := func(content string) string {
+		path := filepath.Join(t.TempDir(), "policy.json")
+		require.NoError(t, os.WriteFile(path, []byte(content), 0o600))
+		return path
+	}
+
+	policy, err := LoadAccessPolicy(write(`{"rules": [{"operations": ["provision", "remove"], "groups": ["doublezero"]}]}`))
+	require.NoError(t, err)
+	require.Equal(t, []AccessRule{{Operations: []string{"provision", "remove"}, Groups: []string{"doublezero"}}}, policy.Rules)
+
+	_, err = LoadAccessPolicy(write(`{"rules": [{"operations": ["reboot"], "users": ["ops"]}]}`))
+	require.ErrorContains(t, err, `unknown operation "reboot"`)
+
+	_, err = LoadAccessPolicy(write(`{"rules": [{"users": ["ops"]}]}`))
+	require.ErrorContains(t, err, "lists no operations")
+
+	_, err = LoadAccessPolicy(filepath.Join(t.TempDir(), "missing.json"))
+	require.Error(t, err)
+}
+
+func TestApiServer_PeerCredentials(t *testing.T) {
+	t.Parallel()
+
+	sockFile := filepath.Join(t.TempDir(), "api.sock")
+	lis, err := net.Listen("unix", sockFile)
+	require.NoError(t, err)
+
+	peers := make(chan Peer, 1)
+	mux := http.NewServeMux()
+	mux.HandleFunc("POST /provision", func(w http.ResponseWriter, r *http.Request) {
+		peer, _ := PeerFromContext(r.Context())
+		peers <- peer
+	})
+	srv := NewApiServer(WithHandler(mux), WithAccessPolicy(&AccessPolicy{}))
+	go srv.Serve(lis) //nolint
+	t.Cleanup(func() { srv.Close() })
+
+	client := &http.Client{Transport: &http.Transport{
+		DialContext: func(ctx context.Context, _, _ string) (net.Conn, error) {
+			return (&net.Dialer{}).DialContext(ctx, "unix", sockFile)
+		},
+	}}
+	resp, err := client.Post("http://doublezero/provision", "application/json", nil)
+	require.NoError(t, err)
+	resp.Body.Close()
+
+	// The daemon's own user passes an empty policy.
+	require.Equal(t, http.StatusOK, resp.StatusCode)
+	peer := <-peers
+	require.Equal(t, uint32(os.Getuid()), peer.UID)
+	require.Equal(t, int32(os.Getpid()), peer.PID)
+}
+
+func itoa(v uint32) string {
+	return strconv.FormatUint(uint64(v), 10)
+}
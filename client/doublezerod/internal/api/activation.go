@@ -0,0 +1,41 @@
+package api
+
+import (
+	"fmt"
+	"net"
+	"os"
+	"strconv"
+)
+
+// listenFdsStart is the first file descriptor systemd passes to a
+// socket-activated service.
+const listenFdsStart = 3
+
+// SystemdListener returns the listener systemd passed to the daemon through
+// socket activation (a doublezerod.socket unit), or nil if the daemon was
+// not socket-activated.
+func SystemdListener() (net.Listener, error) {
+	pid, err := strconv.Atoi(os.Getenv("LISTEN_PID"))
+	if err != nil || pid != os.Getpid() {
+		return nil, nil
+	}
+	fds, err := strconv.Atoi(os.Getenv("LISTEN_FDS"))
+	if err != nil || fds < 1 {
+		return nil, nil
+	}
+	if fds > 1 {
+		return nil, fmt.Errorf("expected one socket from systemd, got %d", fds)
+	}
+	// Keep the variables from leaking into child processes.
+	for _, name := range []string{"LISTEN_PID", "LISTEN_FDS", "LISTEN_FDNAMES"} {
+		_ = os.Unsetenv(name)
+	}
+
+	f := os.NewFile(uintptr(listenFdsStart), "doublezerod.sock")
+	defer f.Close()
+	lis, err := net.FileListener(f)
+	if err != nil {
+		return nil, fmt.Errorf("error using systemd socket: %v", err)
+	}
+	return lis, nil
+}
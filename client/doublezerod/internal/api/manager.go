@@ -9,6 +9,7 @@ import (
 type ApiServer struct {
 	*http.Server
 	sockFile string
+	policy   *AccessPolicy
 }
 
 type Option func(*ApiServer)
@@ -20,6 +21,10 @@ func NewApiServer(options ...Option) *ApiServer {
 	for _, o := range options {
 		o(api)
 	}
+	api.ConnContext = peerConnContext
+	if api.Handler != nil {
+		api.Handler = authorize(api.Handler, api.policy)
+	}
 	return api
 }
 
@@ -40,3 +45,12 @@ func WithHandler(mux *http.ServeMux) Option {
 		a.Handler = mux
 	}
 }
+
+// WithAccessPolicy restricts the privileged operations to the callers the
+// policy allows. Without a policy every local user may call them; each call
+// is audit-logged either way.
+func WithAccessPolicy(policy *AccessPolicy) Option {
+	return func(a *ApiServer) {
+		a.policy = policy
+	}
+}
@@ -45,4 +45,22 @@ var (
 		},
 		latencyLabels,
 	)
+
+	pathLabels = []string{"user_type", "device_code", "tunnel_dst"}
+
+	MetricPathRttAvg = promauto.NewGaugeVec(
+		prometheus.GaugeOpts{
+			Name: "doublezero_path_rtt_avg_nanoseconds",
+			Help: "Average round-trip time from the client to the device terminating each connected tunnel in nanoseconds.",
+		},
+		pathLabels,
+	)
+
+	MetricPathLoss = promauto.NewGaugeVec(
+		prometheus.GaugeOpts{
+			Name: "doublezero_path_loss_percentage",
+			Help: "Packet loss percentage from the client to the device terminating each connected tunnel.",
+		},
+		pathLabels,
+	)
 )
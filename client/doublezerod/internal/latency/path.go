@@ -0,0 +1,252 @@
+package latency
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net"
+	"net/http"
+	"os"
+	"sync"
+	"time"
+
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"github.com/mr-tron/base58"
+)
+
+const (
+	defaultPathSampleInterval   = 60 * time.Second
+	defaultPathSampleBufferSize = 1440 // one day of samples at the default interval
+	defaultPathJournalMaxBytes  = 64 << 20
+)
+
+// PathProvider reports the services currently provisioned by the daemon.
+type PathProvider interface {
+	GetProvisionedServices() []*api.ProvisionRequest
+}
+
+// DeviceProvider returns the cached onchain devices, used to attribute a
+// tunnel endpoint to the device that owns it.
+type DeviceProvider interface {
+	GetDeviceCache() []serviceability.Device
+}
+
+// PathSamplerConfig configures background sampling of the connected path.
+type PathSamplerConfig struct {
+	// Interval between two samples of each connected service.
+	Interval time.Duration
+	// JournalPath is the file samples are appended to as JSON lines. An empty
+	// path keeps samples in memory only.
+	JournalPath string
+	// JournalMaxBytes rotates the journal to JournalPath + ".1" once exceeded.
+	JournalMaxBytes int64
+	// BufferSize is the number of most recent samples served over the API.
+	BufferSize int
+}
+
+// PathSample is one RTT measurement from the client to the device terminating
+// one of its tunnels.
+type PathSample struct {
+	Timestamp  time.Time `json:"timestamp"`
+	UserType   string    `json:"user_type"`
+	TunnelDst  string    `json:"tunnel_dst"`
+	DevicePk   string    `json:"device_pk,omitempty"`
+	DeviceCode string    `json:"device_code,omitempty"`
+	Min        int64     `json:"min_latency_ns"`
+	Max        int64     `json:"max_latency_ns"`
+	Avg        int64     `json:"avg_latency_ns"`
+	Loss       float64   `json:"loss_percentage"`
+	Reachable  bool      `json:"reachable"`
+}
+
+// PathSampler periodically measures RTT to the device at the other end of
+// each provisioned tunnel. It complements the device-side telemetry with
+// end-user-side measurements that can back SLA disputes.
+type PathSampler struct {
+	cfg     PathSamplerConfig
+	paths   PathProvider
+	devices DeviceProvider
+	prober  ProberFunc
+	now     func() time.Time
+
+	mu      sync.RWMutex
+	samples []PathSample
+}
+
+type PathSamplerOption func(*PathSampler)
+
+// WithPathProberFunc overrides the prober used to measure each path.
+func WithPathProberFunc(f ProberFunc) PathSamplerOption {
+	return func(p *PathSampler) {
+		p.prober = f
+	}
+}
+
+// WithPathDeviceProvider sets the source used to attribute samples to devices.
+func WithPathDeviceProvider(d DeviceProvider) PathSamplerOption {
+	return func(p *PathSampler) {
+		p.devices = d
+	}
+}
+
+// WithPathClock overrides the clock used to timestamp samples.
+func WithPathClock(now func() time.Time) PathSamplerOption {
+	return func(p *PathSampler) {
+		p.now = now
+	}
+}
+
+func NewPathSampler(cfg PathSamplerConfig, paths PathProvider, options ...PathSamplerOption) *PathSampler {
+	if cfg.Interval <= 0 {
+		cfg.Interval = defaultPathSampleInterval
+	}
+	if cfg.BufferSize <= 0 {
+		cfg.BufferSize = defaultPathSampleBufferSize
+	}
+	if cfg.JournalMaxBytes <= 0 {
+		cfg.JournalMaxBytes = defaultPathJournalMaxBytes
+	}
+	p := &PathSampler{
+		cfg:    cfg,
+		paths:  paths,
+		prober: UdpPing,
+		now:    time.Now,
+	}
+	for _, o := range options {
+		o(p)
+	}
+	return p
+}
+
+// Start samples every connected path on each interval until ctx is done.
+func (p *PathSampler) Start(ctx context.Context) error {
+	ticker := time.NewTicker(p.cfg.Interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("latency: closing path sampler")
+			return nil
+		case <-ticker.C:
+			p.Sample(ctx)
+		}
+	}
+}
+
+// Sample measures every connected path once, records the results and appends
+// them to the journal. It returns the samples taken.
+func (p *PathSampler) Sample(ctx context.Context) []PathSample {
+	services := p.paths.GetProvisionedServices()
+	if len(services) == 0 {
+		return nil
+	}
+
+	var devices []serviceability.Device
+	if p.devices != nil {
+		devices = p.devices.GetDeviceCache()
+	}
+
+	var taken []PathSample
+	for _, svc := range services {
+		if svc == nil || svc.TunnelDst == nil || svc.TunnelDst.IsUnspecified() {
+			continue
+		}
+		target := ProbeTarget{IP: svc.TunnelDst, Device: deviceForEndpoint(devices, svc.TunnelDst)}
+		result := p.prober(ctx, target)
+
+		sample := PathSample{
+			Timestamp: p.now().UTC(),
+			UserType:  svc.UserType.String(),
+			TunnelDst: svc.TunnelDst.String(),
+			Min:       result.Min,
+			Max:       result.Max,
+			Avg:       result.Avg,
+			Loss:      result.Loss,
+			Reachable: result.Reachable,
+		}
+		if target.Device.PubKey != [32]byte{} {
+			sample.DevicePk = base58.Encode(target.Device.PubKey[:])
+			sample.DeviceCode = target.Device.Code
+		}
+		taken = append(taken, sample)
+
+		MetricPathRttAvg.WithLabelValues(sample.UserType, sample.DeviceCode, sample.TunnelDst).Set(float64(sample.Avg))
+		MetricPathLoss.WithLabelValues(sample.UserType, sample.DeviceCode, sample.TunnelDst).Set(sample.Loss)
+	}
+
+	p.record(taken)
+	if err := p.appendJournal(taken); err != nil {
+		slog.Error("latency: error writing path samples journal", "path", p.cfg.JournalPath, "error", err)
+	}
+	return taken
+}
+
+// Samples returns a copy of the most recent samples, oldest first.
+func (p *PathSampler) Samples() []PathSample {
+	p.mu.RLock()
+	defer p.mu.RUnlock()
+	samples := make([]PathSample, len(p.samples))
+	copy(samples, p.samples)
+	return samples
+}
+
+// ServePathLatency handles GET /v2/latency/path requests.
+func (p *PathSampler) ServePathLatency(w http.ResponseWriter, _ *http.Request) {
+	data, err := json.Marshal(p.Samples())
+	if err != nil {
+		w.WriteHeader(http.StatusInternalServerError)
+		_, _ = fmt.Fprintf(w, "error generating path latency: %v", err)
+		return
+	}
+	w.Header().Set("Content-Type", "application/json")
+	_, _ = w.Write(data)
+}
+
+func (p *PathSampler) record(samples []PathSample) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	p.samples = append(p.samples, samples...)
+	if extra := len(p.samples) - p.cfg.BufferSize; extra > 0 {
+		p.samples = append([]PathSample(nil), p.samples[extra:]...)
+	}
+}
+
+func (p *PathSampler) appendJournal(samples []PathSample) error {
+	if p.cfg.JournalPath == "" || len(samples) == 0 {
+		return nil
+	}
+	if info, err := os.Stat(p.cfg.JournalPath); err == nil && info.Size() >= p.cfg.JournalMaxBytes {
+		if err := os.Rename(p.cfg.JournalPath, p.cfg.JournalPath+".1"); err != nil {
+			return fmt.Errorf("error rotating journal: %w", err)
+		}
+	}
+
+	f, err := os.OpenFile(p.cfg.JournalPath, os.O_CREATE|os.O_APPEND|os.O_WRONLY, 0644)
+	if err != nil {
+		return fmt.Errorf("error opening journal: %w", err)
+	}
+	defer f.Close()
+
+	enc := json.NewEncoder(f)
+	for _, s := range samples {
+		if err := enc.Encode(s); err != nil {
+			return fmt.Errorf("error encoding sample: %w", err)
+		}
+	}
+	return nil
+}
+
+// deviceForEndpoint returns the device whose public IP or user tunnel
+// endpoint interface matches ip, or a zero DeviceInfo if none does.
+func deviceForEndpoint(devices []serviceability.Device, ip net.IP) DeviceInfo {
+	for _, d := range devices {
+		for _, target := range GetProbeTargets(d, true) {
+			if target.IP.Equal(ip) {
+				return target.Device
+			}
+		}
+	}
+	return DeviceInfo{}
+}
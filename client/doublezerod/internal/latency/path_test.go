@@ -0,0 +1,161 @@
+package latency_test
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"net"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/google/go-cmp/cmp"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/latency"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"github.com/mr-tron/base58"
+)
+
+type mockPathProvider struct {
+	services []*api.ProvisionRequest
+}
+
+func (m *mockPathProvider) GetProvisionedServices() []*api.ProvisionRequest {
+	return m.services
+}
+
+type mockDeviceProvider struct {
+	devices []serviceability.Device
+}
+
+func (m *mockDeviceProvider) GetDeviceCache() []serviceability.Device {
+	return m.devices
+}
+
+func TestPathSampler_Sample(t *testing.T) {
+	now := time.Date(2026, 1, 2, 3, 4, 5, 0, time.UTC)
+	journal := filepath.Join(t.TempDir(), "path-samples.jsonl")
+
+	paths := &mockPathProvider{
+		services: []*api.ProvisionRequest{
+			{UserType: api.UserTypeIBRL, TunnelDst: net.IP{10, 0, 0, 1}},
+			{UserType: api.UserTypeMulticast, TunnelDst: net.IP{10, 0, 0, 9}},
+			{UserType: api.UserTypeIBRL}, // no tunnel yet
+		},
+	}
+	devices := &mockDeviceProvider{
+		devices: []serviceability.Device{
+			{PubKey: [32]byte{1}, Code: "dev01", PublicIp: [4]uint8{10, 0, 0, 1}},
+		},
+	}
+	prober := func(_ context.Context, target latency.ProbeTarget) latency.LatencyResult {
+		if target.IP.Equal(net.IP{10, 0, 0, 9}) {
+			return latency.LatencyResult{Device: target.Device, IP: target.IP, Loss: 100}
+		}
+		return latency.LatencyResult{Device: target.Device, IP: target.IP, Min: 1, Avg: 2, Max: 3, Reachable: true}
+	}
+
+	sampler := latency.NewPathSampler(
+		latency.PathSamplerConfig{JournalPath: journal},
+		paths,
+		latency.WithPathProberFunc(prober),
+		latency.WithPathDeviceProvider(devices),
+		latency.WithPathClock(func() time.Time { return now }),
+	)
+
+	want := []latency.PathSample{
+		{
+			Timestamp:  now,
+			UserType:   api.UserTypeIBRL.String(),
+			TunnelDst:  "10.0.0.1",
+			DevicePk:   base58.Encode([]byte{1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0}),
+			DeviceCode: "dev01",
+			Min:        1,
+			Avg:        2,
+			Max:        3,
+			Reachable:  true,
+		},
+		{
+			Timestamp: now,
+			UserType:  api.UserTypeMulticast.String(),
+			TunnelDst: "10.0.0.9",
+			Loss:      100,
+		},
+	}
+
+	got := sampler.Sample(context.Background())
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Fatalf("Sample() mismatch (-want +got): %s", diff)
+	}
+	if diff := cmp.Diff(want, sampler.Samples()); diff != "" {
+		t.Fatalf("Samples() mismatch (-want +got): %s", diff)
+	}
+
+	f, err := os.Open(journal)
+	if err != nil {
+		t.Fatalf("error opening journal: %v", err)
+	}
+	defer f.Close()
+	var journaled []latency.PathSample
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		var s latency.PathSample
+		if err := json.Unmarshal(scanner.Bytes(), &s); err != nil {
+			t.Fatalf("error decoding journal line %q: %v", scanner.Text(), err)
+		}
+		journaled = append(journaled, s)
+	}
+	if diff := cmp.Diff(want, journaled); diff != "" {
+		t.Fatalf("journal mismatch (-want +got): %s", diff)
+	}
+}
+
+func TestPathSampler_BufferSize(t *testing.T) {
+	paths := &mockPathProvider{
+		services: []*api.ProvisionRequest{
+			{UserType: api.UserTypeIBRL, TunnelDst: net.IP{10, 0, 0, 1}},
+		},
+	}
+	var avg int64
+	prober := func(_ context.Context, target latency.ProbeTarget) latency.LatencyResult {
+		avg++
+		return latency.LatencyResult{IP: target.IP, Avg: avg, Reachable: true}
+	}
+
+	sampler := latency.NewPathSampler(
+		latency.PathSamplerConfig{BufferSize: 2},
+		paths,
+		latency.WithPathProberFunc(prober),
+	)
+	for range 3 {
+		sampler.Sample(context.Background())
+	}
+
+	samples := sampler.Samples()
+	if len(samples) != 2 {
+		t.Fatalf("expected 2 buffered samples, got %d", len(samples))
+	}
+	if samples[0].Avg != 2 || samples[1].Avg != 3 {
+		t.Fatalf("expected the two most recent samples, got %+v", samples)
+	}
+}
+
+func TestPathSampler_NoServices(t *testing.T) {
+	journal := filepath.Join(t.TempDir(), "path-samples.jsonl")
+	sampler := latency.NewPathSampler(
+		latency.PathSamplerConfig{JournalPath: journal},
+		&mockPathProvider{},
+		latency.WithPathProberFunc(func(context.Context, latency.ProbeTarget) latency.LatencyResult {
+			t.Fatal("prober should not be called without connected services")
+			return latency.LatencyResult{}
+		}),
+	)
+
+	if got := sampler.Sample(context.Background()); len(got) != 0 {
+		t.Fatalf("expected no samples, got %+v", got)
+	}
+	if _, err := os.Stat(journal); !os.IsNotExist(err) {
+		t.Fatalf("expected no journal to be written, got err=%v", err)
+	}
+}
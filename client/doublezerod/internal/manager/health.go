@@ -0,0 +1,243 @@
+package manager
+
+import (
+	"context"
+	"log/slog"
+	"net"
+	"sync"
+	"time"
+
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/bgp"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/latency"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/services"
+)
+
+const (
+	defaultHealthCheckInterval    = 30 * time.Second
+	defaultHealthFailureThreshold = 3
+	defaultHealthRecoveryBackoff  = 5 * time.Minute
+)
+
+// TunnelHealthConfig configures the tunnel health monitor, which watches each
+// provisioned service and re-provisions it when its tunnel stops working, so
+// that clients recover from device or host outages without a manual
+// reconnect.
+type TunnelHealthConfig struct {
+	// Interval between two health checks of each service.
+	Interval time.Duration
+	// FailureThreshold is the number of consecutive failed checks after which
+	// the service is re-provisioned.
+	FailureThreshold int
+	// MaxRecoveryBackoff caps the delay between two recovery attempts of a
+	// service that stays unhealthy. The delay starts at twice
+	// FailureThreshold * Interval and doubles after each attempt.
+	MaxRecoveryBackoff time.Duration
+	// Prober probes the device tunnel endpoint. Defaults to latency.UdpPing.
+	Prober latency.ProberFunc
+}
+
+// ServiceHealth is the health monitor's view of one provisioned service.
+type ServiceHealth struct {
+	Healthy             bool       `json:"healthy"`
+	EndpointReachable   bool       `json:"endpoint_reachable"`
+	BGPSessionUp        bool       `json:"bgp_session_up"`
+	ConsecutiveFailures int        `json:"consecutive_failures"`
+	LastCheck           time.Time  `json:"last_check"`
+	Recoveries          int        `json:"recoveries"`
+	LastRecovery        *time.Time `json:"last_recovery,omitempty"`
+	LastRecoveryError   string     `json:"last_recovery_error,omitempty"`
+
+	nextRecovery time.Time
+	backoff      time.Duration
+}
+
+// WithTunnelHealth enables the tunnel health monitor started by
+// StartHealthMonitor.
+func WithTunnelHealth(cfg TunnelHealthConfig) Option {
+	return func(n *NetlinkManager) {
+		if cfg.Interval <= 0 {
+			cfg.Interval = defaultHealthCheckInterval
+		}
+		if cfg.FailureThreshold <= 0 {
+			cfg.FailureThreshold = defaultHealthFailureThreshold
+		}
+		if cfg.MaxRecoveryBackoff <= 0 {
+			cfg.MaxRecoveryBackoff = defaultHealthRecoveryBackoff
+		}
+		if cfg.Prober == nil {
+			cfg.Prober = latency.UdpPing
+		}
+		n.healthCfg = &cfg
+	}
+}
+
+// healthMonitor holds the per-service health state, keyed by service name
+// (serviceUnicast or serviceMulticast).
+type healthMonitor struct {
+	mu       sync.Mutex
+	services map[string]*ServiceHealth
+}
+
+// StartHealthMonitor checks the health of every provisioned service until the
+// context is cancelled. It returns immediately if the monitor is not enabled.
+func (n *NetlinkManager) StartHealthMonitor(ctx context.Context) error {
+	if n.healthCfg == nil {
+		return nil
+	}
+	slog.Info("health: starting tunnel health monitor", "interval", n.healthCfg.Interval, "failure_threshold", n.healthCfg.FailureThreshold)
+	ticker := time.NewTicker(n.healthCfg.Interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("health: stopping")
+			return nil
+		case <-ticker.C:
+			n.CheckHealth(ctx, time.Now())
+		}
+	}
+}
+
+// CheckHealth runs one health check of every provisioned service. A service
+// is healthy when its device tunnel endpoint answers probes and its BGP
+// session is up. After FailureThreshold consecutive failures the service is
+// re-provisioned from its last provision request, backing off between
+// attempts while it stays unhealthy.
+func (n *NetlinkManager) CheckHealth(ctx context.Context, now time.Time) {
+	if n.healthCfg == nil {
+		return
+	}
+
+	current := map[string]Provisioner{}
+	n.mu.Lock()
+	for _, serviceType := range []string{serviceUnicast, serviceMulticast} {
+		if svc := n.currentService(serviceType); svc != nil {
+			current[serviceType] = svc
+		}
+	}
+	n.mu.Unlock()
+
+	n.health.mu.Lock()
+	if n.health.services == nil {
+		n.health.services = make(map[string]*ServiceHealth)
+	}
+	// Forget services that have been removed since the last check.
+	for serviceType := range n.health.services {
+		if _, ok := current[serviceType]; !ok {
+			delete(n.health.services, serviceType)
+		}
+	}
+	n.health.mu.Unlock()
+
+	for serviceType, svc := range current {
+		reachable, bgpUp := n.probeService(ctx, svc)
+
+		n.health.mu.Lock()
+		h, ok := n.health.services[serviceType]
+		if !ok {
+			h = &ServiceHealth{}
+			n.health.services[serviceType] = h
+		}
+		h.LastCheck = now
+		h.EndpointReachable = reachable
+		h.BGPSessionUp = bgpUp
+		h.Healthy = reachable && bgpUp
+		if h.Healthy {
+			h.ConsecutiveFailures = 0
+			h.backoff = 0
+			h.nextRecovery = time.Time{}
+			n.health.mu.Unlock()
+			continue
+		}
+		h.ConsecutiveFailures++
+		shouldRecover := h.ConsecutiveFailures >= n.healthCfg.FailureThreshold && !now.Before(h.nextRecovery)
+		n.health.mu.Unlock()
+
+		if !shouldRecover {
+			continue
+		}
+		slog.Warn("health: service unhealthy, re-provisioning", "service", serviceType, "endpoint_reachable", reachable, "bgp_session_up", bgpUp)
+		err := n.reprovisionIfCurrent(serviceType, svc)
+
+		n.health.mu.Lock()
+		h.Recoveries++
+		recoveredAt := now
+		h.LastRecovery = &recoveredAt
+		h.LastRecoveryError = ""
+		if err != nil {
+			slog.Error("health: error re-provisioning service", "service", serviceType, "error", err)
+			h.LastRecoveryError = err.Error()
+			metricHealthRecoveriesTotal.WithLabelValues(serviceType, statusError).Inc()
+		} else {
+			metricHealthRecoveriesTotal.WithLabelValues(serviceType, statusSuccess).Inc()
+		}
+		if h.backoff == 0 {
+			h.backoff = time.Duration(n.healthCfg.FailureThreshold) * n.healthCfg.Interval
+		}
+		h.backoff = min(2*h.backoff, n.healthCfg.MaxRecoveryBackoff)
+		h.nextRecovery = now.Add(h.backoff)
+		h.ConsecutiveFailures = 0
+		n.health.mu.Unlock()
+	}
+}
+
+// probeService reports whether the service's device tunnel endpoint answers
+// probes and whether its BGP session is up.
+func (n *NetlinkManager) probeService(ctx context.Context, svc Provisioner) (bool, bool) {
+	status, err := svc.Status()
+	if err != nil || status == nil {
+		return false, false
+	}
+	bgpUp := status.DoubleZeroStatus.SessionStatus == bgp.SessionStatusUp
+
+	dst := status.TunnelDst
+	if dst == nil || dst.To4() == nil || dst.IsUnspecified() {
+		return false, bgpUp
+	}
+	result := n.healthCfg.Prober(ctx, latency.ProbeTarget{IP: net.IP(dst.To4())})
+	return result.Reachable, bgpUp
+}
+
+// reprovisionIfCurrent tears down and provisions svc again from its last
+// provision request, unless the reconciler or an API call has replaced or
+// removed it since it was checked.
+func (n *NetlinkManager) reprovisionIfCurrent(serviceType string, svc Provisioner) error {
+	n.mu.Lock()
+	defer n.mu.Unlock()
+
+	if n.currentService(serviceType) != svc {
+		return nil
+	}
+	pr := svc.ProvisionRequest()
+	if pr == nil {
+		return nil
+	}
+	req := *pr
+	if err := n.removeLocked(req.UserType); err != nil {
+		return err
+	}
+	return n.provisionLocked(req)
+}
+
+// serviceHealth returns a copy of the health state of the service serving
+// the given user type, or nil if the monitor is disabled or has not checked
+// it yet.
+func (n *NetlinkManager) serviceHealth(u api.UserType) *ServiceHealth {
+	if n.healthCfg == nil {
+		return nil
+	}
+	serviceType := serviceUnicast
+	if services.IsMulticastUser(u) {
+		serviceType = serviceMulticast
+	}
+
+	n.health.mu.Lock()
+	defer n.health.mu.Unlock()
+	h, ok := n.health.services[serviceType]
+	if !ok {
+		return nil
+	}
+	c := *h
+	return &c
+}
@@ -0,0 +1,188 @@
+package manager
+
+import (
+	"context"
+	"net"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/bgp"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/latency"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+)
+
+type healthBgpServer struct {
+	mockBgpServer
+	mu     sync.Mutex
+	status bgp.SessionStatus
+	adds   int
+}
+
+func (m *healthBgpServer) AddPeer(*bgp.PeerConfig, []bgp.NLRI) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.adds++
+	return nil
+}
+
+func (m *healthBgpServer) GetPeerStatus(net.IP) bgp.Session {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return bgp.Session{SessionStatus: m.status}
+}
+
+func (m *healthBgpServer) setStatus(s bgp.SessionStatus) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.status = s
+}
+
+func (m *healthBgpServer) Adds() int {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return m.adds
+}
+
+func newHealthTestNLM(bgpSrv *healthBgpServer, reachable *bool) *NetlinkManager {
+	prober := func(_ context.Context, target latency.ProbeTarget) latency.LatencyResult {
+		return latency.LatencyResult{IP: target.IP, Reachable: *reachable}
+	}
+	fetcher := &mockFetcher{data: &serviceability.ProgramData{GlobalConfig: testGlobalConfig()}}
+	return NewNetlinkManager(&mockNetlink{}, bgpSrv, &mockPIMServer{}, &mockHeartbeatSender{}, &mockRegisterSender{},
+		WithFetcher(fetcher),
+		WithTunnelHealth(TunnelHealthConfig{Interval: time.Second, FailureThreshold: 2, MaxRecoveryBackoff: 4 * time.Second, Prober: prober}),
+	)
+}
+
+func TestCheckHealth_HealthyService(t *testing.T) {
+	bgpSrv := &healthBgpServer{status: bgp.SessionStatusUp}
+	reachable := true
+	n := newHealthTestNLM(bgpSrv, &reachable)
+	provisionUnicast(t, n)
+
+	now := time.Unix(1_700_000_000, 0)
+	n.CheckHealth(context.Background(), now)
+
+	h := n.serviceHealth(n.UnicastService.ProvisionRequest().UserType)
+	if h == nil {
+		t.Fatal("expected health state for unicast service")
+	}
+	if !h.Healthy || !h.EndpointReachable || !h.BGPSessionUp {
+		t.Fatalf("expected healthy service, got %+v", h)
+	}
+	if !h.LastCheck.Equal(now) {
+		t.Fatalf("expected last check %v, got %v", now, h.LastCheck)
+	}
+	if h.Recoveries != 0 || h.ConsecutiveFailures != 0 {
+		t.Fatalf("expected no failures or recoveries, got %+v", h)
+	}
+}
+
+func TestCheckHealth_ReprovisionsAfterThreshold(t *testing.T) {
+	bgpSrv := &healthBgpServer{status: bgp.SessionStatusDown}
+	reachable := true
+	n := newHealthTestNLM(bgpSrv, &reachable)
+	provisionUnicast(t, n)
+	before := n.UnicastService
+	addsBefore := bgpSrv.Adds()
+
+	now := time.Unix(1_700_000_000, 0)
+	n.CheckHealth(context.Background(), now)
+	if n.UnicastService != before {
+		t.Fatal("expected no re-provision below the failure threshold")
+	}
+	h := n.serviceHealth(before.ProvisionRequest().UserType)
+	if h.Healthy || h.BGPSessionUp || !h.EndpointReachable || h.ConsecutiveFailures != 1 {
+		t.Fatalf("expected one failed check with the endpoint reachable, got %+v", h)
+	}
+
+	now = now.Add(time.Second)
+	n.CheckHealth(context.Background(), now)
+	if n.UnicastService == nil || n.UnicastService == before {
+		t.Fatal("expected unicast service to be re-provisioned")
+	}
+	if !n.UnicastService.ProvisionRequest().TunnelDst.Equal(before.ProvisionRequest().TunnelDst) {
+		t.Fatal("expected the re-provisioned service to keep its provision request")
+	}
+	if bgpSrv.Adds() != addsBefore+1 {
+		t.Fatalf("expected the bgp peer to be added again, got %d adds", bgpSrv.Adds()-addsBefore)
+	}
+	h = n.serviceHealth(n.UnicastService.ProvisionRequest().UserType)
+	if h.Recoveries != 1 || h.LastRecovery == nil || !h.LastRecovery.Equal(now) || h.LastRecoveryError != "" {
+		t.Fatalf("expected one successful recovery, got %+v", h)
+	}
+
+	// Still unhealthy: the next attempt waits for the backoff (twice
+	// threshold * interval) to elapse.
+	recovered := n.UnicastService
+	for range 3 {
+		now = now.Add(time.Second)
+		n.CheckHealth(context.Background(), now)
+	}
+	if n.UnicastService != recovered {
+		t.Fatal("expected no re-provision before the backoff elapsed")
+	}
+	now = now.Add(time.Second)
+	n.CheckHealth(context.Background(), now)
+	if n.UnicastService == recovered {
+		t.Fatal("expected re-provision once the backoff elapsed")
+	}
+	if h := n.serviceHealth(n.UnicastService.ProvisionRequest().UserType); h.Recoveries != 2 {
+		t.Fatalf("expected two recoveries, got %d", h.Recoveries)
+	}
+
+	// Recovering resets the failure count.
+	bgpSrv.setStatus(bgp.SessionStatusUp)
+	n.CheckHealth(context.Background(), now.Add(time.Second))
+	if h := n.serviceHealth(n.UnicastService.ProvisionRequest().UserType); !h.Healthy || h.ConsecutiveFailures != 0 {
+		t.Fatalf("expected healthy service, got %+v", h)
+	}
+}
+
+func TestCheckHealth_UnreachableEndpoint(t *testing.T) {
+	bgpSrv := &healthBgpServer{status: bgp.SessionStatusUp}
+	reachable := false
+	n := newHealthTestNLM(bgpSrv, &reachable)
+	provisionMulticast(t, n)
+
+	n.CheckHealth(context.Background(), time.Unix(1_700_000_000, 0))
+	h := n.serviceHealth(n.MulticastService.ProvisionRequest().UserType)
+	if h == nil || h.Healthy || h.EndpointReachable || !h.BGPSessionUp {
+		t.Fatalf("expected unreachable endpoint to be unhealthy, got %+v", h)
+	}
+}
+
+func TestCheckHealth_ForgetsRemovedServices(t *testing.T) {
+	bgpSrv := &healthBgpServer{status: bgp.SessionStatusUp}
+	reachable := true
+	n := newHealthTestNLM(bgpSrv, &reachable)
+	provisionUnicast(t, n)
+	userType := n.UnicastService.ProvisionRequest().UserType
+
+	n.CheckHealth(context.Background(), time.Unix(1_700_000_000, 0))
+	if n.serviceHealth(userType) == nil {
+		t.Fatal("expected health state for unicast service")
+	}
+
+	if err := n.Remove(userType); err != nil {
+		t.Fatalf("error removing service: %v", err)
+	}
+	n.CheckHealth(context.Background(), time.Unix(1_700_000_001, 0))
+	if n.serviceHealth(userType) != nil {
+		t.Fatal("expected health state to be dropped with the service")
+	}
+}
+
+func TestCheckHealth_Disabled(t *testing.T) {
+	n := newTestNLM(&mockFetcher{data: &serviceability.ProgramData{GlobalConfig: testGlobalConfig()}})
+	provisionUnicast(t, n)
+
+	n.CheckHealth(context.Background(), time.Now())
+	if h := n.serviceHealth(n.UnicastService.ProvisionRequest().UserType); h != nil {
+		t.Fatalf("expected no health state when the monitor is disabled, got %+v", h)
+	}
+	if err := n.StartHealthMonitor(context.Background()); err != nil {
+		t.Fatalf("expected disabled monitor to return immediately, got %v", err)
+	}
+}
@@ -44,6 +44,7 @@ type V2ServiceStatus struct {
 	Tenant                      string          `json:"tenant"`
 	MulticastGroups             MulticastGroups `json:"multicast_groups"`
 	Subscriptions               []Subscription  `json:"subscriptions"`
+	Health                      *ServiceHealth  `json:"health,omitempty"`
 }
 
 // V2StatusResponse is the response for the /v2/status endpoint.
@@ -310,6 +311,7 @@ func (n *NetlinkManager) enrichStatuses(statuses []*api.StatusResponse) []V2Serv
 				Subscriber: []string{},
 			},
 			Subscriptions: []Subscription{},
+			Health:        n.serviceHealth(svc.UserType),
 		}
 
 		if data == nil {
@@ -143,6 +143,10 @@ type NetlinkManager struct {
 	// Status enrichment fields
 	latencyProvider LatencyProvider
 	network         string
+
+	// Tunnel health monitor fields
+	healthCfg *TunnelHealthConfig
+	health    healthMonitor
 }
 
 // CreateService creates the appropriate service based on the provisioned
@@ -49,6 +49,14 @@ var (
 		[]string{labelServiceType, labelStatus},
 	)
 
+	metricHealthRecoveriesTotal = promauto.NewCounterVec(
+		prometheus.CounterOpts{
+			Name: "doublezero_health_recoveries_total",
+			Help: "Total number of services re-provisioned by the tunnel health monitor",
+		},
+		[]string{labelServiceType, labelStatus},
+	)
+
 	metricMatchedUsers = promauto.NewGaugeVec(
 		prometheus.GaugeOpts{
 			Name: "doublezero_reconciler_matched_users",
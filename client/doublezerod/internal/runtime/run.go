@@ -21,6 +21,7 @@ import (
 	"github.com/malbeclabs/doublezero/client/doublezerod/internal/onchain"
 	"github.com/malbeclabs/doublezero/client/doublezerod/internal/pim"
 	"github.com/malbeclabs/doublezero/client/doublezerod/internal/routing"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/usage"
 	"github.com/malbeclabs/doublezero/config"
 	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
 	"golang.org/x/sys/unix"
@@ -30,7 +31,7 @@ const (
 	updateInstalledRoutesGaugeInterval = 10 * time.Second
 )
 
-func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLatencyProbing, enableLatencyMetrics, latencyProbeTunnelEndpoints, latencySingleSocket bool, networkConfig *config.NetworkConfig, probeInterval, cacheUpdateInterval int, lmc *liveness.ManagerConfig, clientIP string, reconcilerPollInterval int, reconcilerFetchTimeout int, stateDir string, onchainRPCTimeout time.Duration) error {
+func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLatencyProbing, enableLatencyMetrics, latencyProbeTunnelEndpoints, latencySingleSocket bool, networkConfig *config.NetworkConfig, probeInterval, cacheUpdateInterval int, lmc *liveness.ManagerConfig, clientIP string, reconcilerPollInterval int, reconcilerFetchTimeout int, stateDir string, onchainRPCTimeout time.Duration, psc *latency.PathSamplerConfig, thc *manager.TunnelHealthConfig, uc *usage.Config, policy *api.AccessPolicy) error {
 	nlr := routing.Netlink{}
 	var crw bgp.RouteReaderWriter
 	var cr *routing.ConfiguredRoutes
@@ -127,6 +128,9 @@ func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLat
 	if latencyManager != nil {
 		nlmOpts = append(nlmOpts, manager.WithLatencyProvider(latencyManager))
 	}
+	if thc != nil {
+		nlmOpts = append(nlmOpts, manager.WithTunnelHealth(*thc))
+	}
 	nlm := manager.NewNetlinkManager(nlr, bgp, pim, heartbeat, register, nlmOpts...)
 
 	errCh := make(chan error)
@@ -143,6 +147,15 @@ func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLat
 		errCh <- err
 	}()
 
+	// The tunnel health monitor re-provisions services whose tunnel endpoint
+	// or BGP session stays down. It is disabled when thc is nil.
+	if thc != nil {
+		go func() {
+			err := nlm.StartHealthMonitor(ctx)
+			errCh <- err
+		}()
+	}
+
 	mux := http.NewServeMux()
 	mux.HandleFunc("POST /provision", nlm.ServeProvision)
 	mux.HandleFunc("POST /remove", nlm.ServeRemove)
@@ -162,6 +175,35 @@ func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLat
 		mux.HandleFunc("GET /v2/latency", latencyManager.ServeV2Latency)
 	}
 
+	// The path sampler measures RTT to the device terminating each connected
+	// tunnel. It is disabled when psc is nil.
+	if psc != nil {
+		var psOpts []latency.PathSamplerOption
+		if latencyManager != nil {
+			psOpts = append(psOpts, latency.WithPathDeviceProvider(latencyManager))
+		}
+		pathSampler := latency.NewPathSampler(*psc, nlm, psOpts...)
+		go func() {
+			err := pathSampler.Start(ctx)
+			errCh <- err
+		}()
+		mux.HandleFunc("GET /v2/latency/path", pathSampler.ServePathLatency)
+	}
+
+	// The usage accountant tracks bytes in and out of each connected session
+	// from the tunnel interface counters. It is disabled when uc is nil.
+	if uc != nil {
+		accountant, err := usage.NewAccountant(*uc, nlm)
+		if err != nil {
+			return fmt.Errorf("error creating usage accountant: %v", err)
+		}
+		go func() {
+			err := accountant.Start(ctx)
+			errCh <- err
+		}()
+		mux.HandleFunc("GET /v2/usage", accountant.ServeUsage)
+	}
+
 	// /config endpoint returns:
 	// {
 	//   "program_id": "<string>", // The program ID used by the client
@@ -186,20 +228,34 @@ func Run(ctx context.Context, sockFile string, routeConfigPath string, enableLat
 	if sockFile != "" {
 		opts = append(opts, api.WithSockFile(sockFile))
 	}
+	if policy != nil {
+		opts = append(opts, api.WithAccessPolicy(policy))
+	}
 
-	api := api.NewApiServer(opts...)
-
-	lis, err := net.Listen("unix", sockFile)
+	// Under socket activation systemd owns the socket file and its
+	// permissions; otherwise create it and open it to every local user, who
+	// are then restricted by the access policy.
+	lis, err := api.SystemdListener()
 	if err != nil {
-		return fmt.Errorf("error creating listener: %v", err)
+		return err
 	}
-	defer unix.Unlink(sockFile) //nolint
+	if lis != nil {
+		slog.Info("http: using socket passed by systemd")
+	} else {
+		lis, err = net.Listen("unix", sockFile)
+		if err != nil {
+			return fmt.Errorf("error creating listener: %v", err)
+		}
+		defer unix.Unlink(sockFile) //nolint
 
-	err = os.Chmod(sockFile, 0666)
-	if err != nil {
-		slog.Error("error setting socket file perms", "error", err)
+		err = os.Chmod(sockFile, 0666)
+		if err != nil {
+			slog.Error("error setting socket file perms", "error", err)
+		}
 	}
 
+	api := api.NewApiServer(opts...)
+
 	slog.Info("http: starting api manager")
 	go func() {
 		err := api.Serve(lis)
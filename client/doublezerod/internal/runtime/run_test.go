@@ -164,7 +164,7 @@ func runIBRLTest(t *testing.T, userType api.UserType, provisioningRequest map[st
 	t.Run("IBRL", func(t *testing.T) {
 		sockFile := filepath.Join(rootPath, "doublezerod.sock")
 		go func() {
-			err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+			err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 			errChan <- err
 		}()
 
@@ -421,7 +421,7 @@ func TestEndToEnd_EdgeFiltering(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -666,7 +666,7 @@ func TestMulticastPublisher(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -947,7 +947,7 @@ func TestMulticastSubscriber(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -1311,7 +1311,7 @@ func TestServiceCoexistence(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -1461,7 +1461,7 @@ func TestRuntime_Run_ReturnsOnContextCancel(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, newTestLivenessManagerConfig(), "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -1497,7 +1497,7 @@ func TestRuntime_Run_PropagatesLivenessStartupError(t *testing.T) {
 
 	sockFile := filepath.Join(rootPath, "doublezerod.sock")
 	go func() {
-		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, &bad, "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		err := runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, &bad, "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 		errChan <- err
 	}()
 
@@ -1537,7 +1537,7 @@ func TestRuntime_Run_PropagatesLivenessError_FromUDPClosure(t *testing.T) {
 
 	// Start the runtime.
 	go func() {
-		errCh <- runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, cfg, "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout)
+		errCh <- runtime.Run(ctx, sockFile, "", false, false, false, false, newTestNetworkConfig(t), 30, 30, cfg, "", 10, 60, t.TempDir(), onchain.DefaultRPCTimeout, nil, nil, nil, nil)
 	}()
 
 	// Give the liveness receiver a moment to start, then close the UDP socket.
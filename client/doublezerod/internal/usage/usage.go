@@ -0,0 +1,412 @@
+package usage
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+)
+
+const (
+	defaultInterval  = 60 * time.Second
+	defaultRetention = 400 * 24 * time.Hour // a bit over a year of history
+
+	// FileName is the name of the usage file in the daemon state directory.
+	FileName = "usage.json"
+
+	dayLayout   = "2006-01-02"
+	monthLayout = "2006-01"
+)
+
+// StatusProvider reports the services currently provisioned by the daemon,
+// including the name of the tunnel interface carrying each of them.
+type StatusProvider interface {
+	Status() ([]*api.StatusResponse, error)
+}
+
+// CounterFunc returns the cumulative bytes received and transmitted on a
+// network interface.
+type CounterFunc func(iface string) (rx uint64, tx uint64, err error)
+
+// Config configures usage accounting.
+type Config struct {
+	// Interval between two reads of the tunnel interface counters.
+	Interval time.Duration
+	// Path is the file the usage history is persisted to. An empty path keeps
+	// it in memory only.
+	Path string
+	// Retention is how long closed sessions and daily rollups are kept.
+	Retention time.Duration
+}
+
+// Session is the traffic carried by one tunnel from the time it came up until
+// it was torn down or replaced.
+type Session struct {
+	UserType   string    `json:"user_type"`
+	TunnelName string    `json:"tunnel_name"`
+	TunnelDst  string    `json:"tunnel_dst"`
+	Start      time.Time `json:"start"`
+	LastSeen   time.Time `json:"last_seen"`
+	End        time.Time `json:"end,omitzero"`
+	RxBytes    uint64    `json:"rx_bytes"`
+	TxBytes    uint64    `json:"tx_bytes"`
+
+	// Last counter values read for the session, used to compute deltas.
+	LastRx uint64 `json:"last_rx"`
+	LastTx uint64 `json:"last_tx"`
+}
+
+// Open reports whether the session's tunnel was still up at the last read.
+func (s *Session) Open() bool {
+	return s.End.IsZero()
+}
+
+// DailyUsage is the traffic of one user type on one UTC day.
+type DailyUsage struct {
+	Date     string `json:"date"`
+	UserType string `json:"user_type"`
+	RxBytes  uint64 `json:"rx_bytes"`
+	TxBytes  uint64 `json:"tx_bytes"`
+}
+
+// History is the persisted usage state.
+type History struct {
+	Sessions []*Session   `json:"sessions"`
+	Daily    []DailyUsage `json:"daily"`
+}
+
+// MonthlyUsage is the usage reported for one calendar month.
+type MonthlyUsage struct {
+	Month    string       `json:"month"`
+	RxBytes  uint64       `json:"rx_bytes"`
+	TxBytes  uint64       `json:"tx_bytes"`
+	Daily    []DailyUsage `json:"daily"`
+	Sessions []Session    `json:"sessions"`
+}
+
+// Accountant tracks the bytes in and out of each connected session by reading
+// the tunnel interface counters, and rolls them up per day so usage can be
+// reported per month.
+type Accountant struct {
+	cfg      Config
+	status   StatusProvider
+	counters CounterFunc
+	now      func() time.Time
+
+	mu      sync.Mutex
+	history History
+}
+
+type Option func(*Accountant)
+
+// WithCounterFunc overrides how interface counters are read.
+func WithCounterFunc(f CounterFunc) Option {
+	return func(a *Accountant) {
+		a.counters = f
+	}
+}
+
+// WithClock overrides the clock used to attribute traffic to days.
+func WithClock(now func() time.Time) Option {
+	return func(a *Accountant) {
+		a.now = now
+	}
+}
+
+// NewAccountant returns an accountant resuming from the history persisted at
+// cfg.Path, if any. Sessions left open by a previous run are closed at their
+// last read since the counters they were tracking are gone.
+func NewAccountant(cfg Config, status StatusProvider, options ...Option) (*Accountant, error) {
+	if cfg.Interval <= 0 {
+		cfg.Interval = defaultInterval
+	}
+	if cfg.Retention <= 0 {
+		cfg.Retention = defaultRetention
+	}
+	a := &Accountant{
+		cfg:      cfg,
+		status:   status,
+		counters: ReadSysfsCounters,
+		now:      time.Now,
+	}
+	for _, o := range options {
+		o(a)
+	}
+
+	history, err := LoadHistory(cfg.Path)
+	if err != nil {
+		return nil, err
+	}
+	for _, s := range history.Sessions {
+		if s.Open() {
+			s.End = s.LastSeen
+		}
+	}
+	a.history = *history
+	return a, nil
+}
+
+// Start reads the counters on each interval until ctx is done.
+func (a *Accountant) Start(ctx context.Context) error {
+	ticker := time.NewTicker(a.cfg.Interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			slog.Info("usage: closing accountant")
+			return nil
+		case <-ticker.C:
+			if err := a.Sample(); err != nil {
+				slog.Error("usage: error sampling tunnel counters", "error", err)
+			}
+		}
+	}
+}
+
+// Sample reads the counters of every connected tunnel once, attributes the
+// traffic since the previous read to its session and day, and persists the
+// history.
+func (a *Accountant) Sample() error {
+	statuses, err := a.status.Status()
+	if err != nil {
+		return fmt.Errorf("error getting service status: %w", err)
+	}
+	now := a.now().UTC()
+
+	a.mu.Lock()
+	defer a.mu.Unlock()
+
+	seen := make(map[*Session]bool)
+	for _, st := range statuses {
+		if st == nil || st.TunnelName == "" {
+			continue
+		}
+		rx, tx, err := a.counters(st.TunnelName)
+		if err != nil {
+			slog.Warn("usage: error reading tunnel counters", "tunnel", st.TunnelName, "error", err)
+			continue
+		}
+
+		userType := st.UserType.String()
+		tunnelDst := st.TunnelDst.String()
+		s := a.openSession(userType, tunnelDst)
+		if s == nil {
+			// Traffic counted before the first read is not attributed to the
+			// session, since it may predate it.
+			s = &Session{
+				UserType:   userType,
+				TunnelName: st.TunnelName,
+				TunnelDst:  tunnelDst,
+				Start:      now,
+				LastSeen:   now,
+				LastRx:     rx,
+				LastTx:     tx,
+			}
+			a.history.Sessions = append(a.history.Sessions, s)
+			seen[s] = true
+			continue
+		}
+
+		drx, dtx := delta(s.LastRx, rx), delta(s.LastTx, tx)
+		s.RxBytes += drx
+		s.TxBytes += dtx
+		s.LastRx, s.LastTx = rx, tx
+		s.LastSeen = now
+		a.addDaily(now, userType, drx, dtx)
+		seen[s] = true
+	}
+
+	for _, s := range a.history.Sessions {
+		if s.Open() && !seen[s] {
+			s.End = s.LastSeen
+		}
+	}
+	a.prune(now)
+
+	return SaveHistory(a.cfg.Path, &a.history)
+}
+
+// Month returns the usage of the calendar month formatted as YYYY-MM.
+func (a *Accountant) Month(month string) (*MonthlyUsage, error) {
+	start, err := time.Parse(monthLayout, month)
+	if err != nil {
+		return nil, fmt.Errorf("invalid month %q, expected YYYY-MM", month)
+	}
+	end := start.AddDate(0, 1, 0)
+
+	a.mu.Lock()
+	defer a.mu.Unlock()
+
+	usage := &MonthlyUsage{Month: month, Daily: []DailyUsage{}, Sessions: []Session{}}
+	for _, d := range a.history.Daily {
+		if strings.HasPrefix(d.Date, month+"-") {
+			usage.Daily = append(usage.Daily, d)
+			usage.RxBytes += d.RxBytes
+			usage.TxBytes += d.TxBytes
+		}
+	}
+	for _, s := range a.history.Sessions {
+		if s.Start.Before(end) && !s.LastSeen.Before(start) {
+			usage.Sessions = append(usage.Sessions, *s)
+		}
+	}
+	return usage, nil
+}
+
+// ServeUsage handles GET /v2/usage?month=YYYY-MM requests. The month defaults
+// to the current one.
+func (a *Accountant) ServeUsage(w http.ResponseWriter, r *http.Request) {
+	month := r.URL.Query().Get("month")
+	if month == "" {
+		month = a.now().UTC().Format(monthLayout)
+	}
+	usage, err := a.Month(month)
+	if err != nil {
+		w.WriteHeader(http.StatusBadRequest)
+		_, _ = fmt.Fprintf(w, "%v", err)
+		return
+	}
+	data, err := json.Marshal(usage)
+	if err != nil {
+		w.WriteHeader(http.StatusInternalServerError)
+		_, _ = fmt.Fprintf(w, "error generating usage: %v", err)
+		return
+	}
+	w.Header().Set("Content-Type", "application/json")
+	_, _ = w.Write(data)
+}
+
+func (a *Accountant) openSession(userType, tunnelDst string) *Session {
+	for _, s := range a.history.Sessions {
+		if s.Open() && s.UserType == userType && s.TunnelDst == tunnelDst {
+			return s
+		}
+	}
+	return nil
+}
+
+func (a *Accountant) addDaily(now time.Time, userType string, rx, tx uint64) {
+	if rx == 0 && tx == 0 {
+		return
+	}
+	date := now.Format(dayLayout)
+	for i := range a.history.Daily {
+		d := &a.history.Daily[i]
+		if d.Date == date && d.UserType == userType {
+			d.RxBytes += rx
+			d.TxBytes += tx
+			return
+		}
+	}
+	a.history.Daily = append(a.history.Daily, DailyUsage{Date: date, UserType: userType, RxBytes: rx, TxBytes: tx})
+	sort.SliceStable(a.history.Daily, func(i, j int) bool {
+		return a.history.Daily[i].Date < a.history.Daily[j].Date
+	})
+}
+
+func (a *Accountant) prune(now time.Time) {
+	cutoff := now.Add(-a.cfg.Retention)
+
+	sessions := a.history.Sessions[:0]
+	for _, s := range a.history.Sessions {
+		if s.Open() || !s.End.Before(cutoff) {
+			sessions = append(sessions, s)
+		}
+	}
+	a.history.Sessions = sessions
+
+	cutoffDate := cutoff.Format(dayLayout)
+	daily := a.history.Daily[:0]
+	for _, d := range a.history.Daily {
+		if d.Date >= cutoffDate {
+			daily = append(daily, d)
+		}
+	}
+	a.history.Daily = daily
+}
+
+// delta returns the bytes counted since last. A counter lower than the last
+// read means the interface was recreated and its counters restarted from zero.
+func delta(last, current uint64) uint64 {
+	if current < last {
+		return current
+	}
+	return current - last
+}
+
+// ReadSysfsCounters reads the interface byte counters from
+// /sys/class/net/<iface>/statistics.
+func ReadSysfsCounters(iface string) (uint64, uint64, error) {
+	dir := filepath.Join("/sys/class/net", iface, "statistics")
+	rx, err := readCounter(filepath.Join(dir, "rx_bytes"))
+	if err != nil {
+		return 0, 0, err
+	}
+	tx, err := readCounter(filepath.Join(dir, "tx_bytes"))
+	if err != nil {
+		return 0, 0, err
+	}
+	return rx, tx, nil
+}
+
+func readCounter(path string) (uint64, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return 0, err
+	}
+	return strconv.ParseUint(strings.TrimSpace(string(data)), 10, 64)
+}
+
+// LoadHistory reads the usage history at path. A missing file or empty path
+// yields an empty history.
+func LoadHistory(path string) (*History, error) {
+	history := &History{}
+	if path == "" {
+		return history, nil
+	}
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return history, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("error reading usage file: %w", err)
+	}
+	if err := json.Unmarshal(data, history); err != nil {
+		return nil, fmt.Errorf("error parsing usage file: %w", err)
+	}
+	return history, nil
+}
+
+// SaveHistory writes the usage history to path atomically. It writes to a
+// temporary file and renames it to prevent corruption on crash.
+func SaveHistory(path string, history *History) error {
+	if path == "" {
+		return nil
+	}
+	data, err := json.Marshal(history)
+	if err != nil {
+		return fmt.Errorf("error marshaling usage: %w", err)
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return fmt.Errorf("error creating usage directory: %w", err)
+	}
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return fmt.Errorf("error writing usage file: %w", err)
+	}
+	if err := os.Rename(tmp, path); err != nil {
+		return fmt.Errorf("error renaming usage file: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,137 @@
+package usage_test
+
+import (
+	"encoding/json"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/api"
+	"github.com/malbeclabs/doublezero/client/doublezerod/internal/usage"
+)
+
+type mockStatusProvider struct {
+	statuses []*api.StatusResponse
+}
+
+func (m *mockStatusProvider) Status() ([]*api.StatusResponse, error) {
+	return m.statuses, nil
+}
+
+type counters map[string][2]uint64
+
+func (c counters) read(iface string) (uint64, uint64, error) {
+	v := c[iface]
+	return v[0], v[1], nil
+}
+
+func TestAccountant_Sample(t *testing.T) {
+	path := filepath.Join(t.TempDir(), usage.FileName)
+	now := time.Date(2025, 6, 30, 23, 59, 0, 0, time.UTC)
+	ifaces := counters{"doublezero0": {1_000, 500}}
+	status := &mockStatusProvider{statuses: []*api.StatusResponse{{
+		TunnelName: "doublezero0",
+		TunnelDst:  net.IPv4(5, 6, 7, 8),
+		UserType:   api.UserTypeIBRL,
+	}}}
+
+	a, err := usage.NewAccountant(usage.Config{Path: path}, status,
+		usage.WithCounterFunc(ifaces.read),
+		usage.WithClock(func() time.Time { return now }),
+	)
+	if err != nil {
+		t.Fatalf("error creating accountant: %v", err)
+	}
+
+	// The first read only sets the baseline of the session.
+	if err := a.Sample(); err != nil {
+		t.Fatalf("error sampling: %v", err)
+	}
+
+	ifaces["doublezero0"] = [2]uint64{3_000, 1_500}
+	if err := a.Sample(); err != nil {
+		t.Fatalf("error sampling: %v", err)
+	}
+
+	// Counters restarting from zero count from zero, on the next day.
+	now = now.Add(2 * time.Minute)
+	ifaces["doublezero0"] = [2]uint64{400, 100}
+	if err := a.Sample(); err != nil {
+		t.Fatalf("error sampling: %v", err)
+	}
+
+	june, err := a.Month("2025-06")
+	if err != nil {
+		t.Fatalf("error getting month: %v", err)
+	}
+	if june.RxBytes != 2_000 || june.TxBytes != 1_000 {
+		t.Fatalf("unexpected june usage: rx=%d tx=%d", june.RxBytes, june.TxBytes)
+	}
+	if len(june.Sessions) != 1 || june.Sessions[0].RxBytes != 2_400 || !june.Sessions[0].Open() {
+		t.Fatalf("unexpected june sessions: %+v", june.Sessions)
+	}
+
+	july, err := a.Month("2025-07")
+	if err != nil {
+		t.Fatalf("error getting month: %v", err)
+	}
+	if july.RxBytes != 400 || july.TxBytes != 100 || len(july.Daily) != 1 || july.Daily[0].Date != "2025-07-01" {
+		t.Fatalf("unexpected july usage: %+v", july)
+	}
+
+	// The tunnel going away closes the session.
+	status.statuses = nil
+	if err := a.Sample(); err != nil {
+		t.Fatalf("error sampling: %v", err)
+	}
+	july, _ = a.Month("2025-07")
+	if july.Sessions[0].Open() {
+		t.Fatalf("expected session to be closed: %+v", july.Sessions[0])
+	}
+
+	// History is persisted and reloaded.
+	reloaded, err := usage.NewAccountant(usage.Config{Path: path}, status)
+	if err != nil {
+		t.Fatalf("error reloading accountant: %v", err)
+	}
+	june, _ = reloaded.Month("2025-06")
+	if june.RxBytes != 2_000 || len(june.Sessions) != 1 {
+		t.Fatalf("unexpected reloaded june usage: %+v", june)
+	}
+
+	if _, err := a.Month("June"); err == nil {
+		t.Fatalf("expected invalid month to be rejected")
+	}
+}
+
+func TestAccountant_ServeUsage(t *testing.T) {
+	status := &mockStatusProvider{}
+	a, err := usage.NewAccountant(usage.Config{}, status,
+		usage.WithClock(func() time.Time { return time.Date(2025, 6, 3, 0, 0, 0, 0, time.UTC) }),
+	)
+	if err != nil {
+		t.Fatalf("error creating accountant: %v", err)
+	}
+
+	rec := httptest.NewRecorder()
+	a.ServeUsage(rec, httptest.NewRequest(http.MethodGet, "/v2/usage", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("unexpected status: %d", rec.Code)
+	}
+	var got usage.MonthlyUsage
+	if err := json.Unmarshal(rec.Body.Bytes(), &got); err != nil {
+		t.Fatalf("error decoding response: %v", err)
+	}
+	if got.Month != "2025-06" {
+		t.Fatalf("expected current month, got %q", got.Month)
+	}
+
+	rec = httptest.NewRecorder()
+	a.ServeUsage(rec, httptest.NewRequest(http.MethodGet, "/v2/usage?month=2025-13", nil))
+	if rec.Code != http.StatusBadRequest {
+		t.Fatalf("expected bad request, got %d", rec.Code)
+	}
+}
@@ -3,28 +3,35 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 use std::fmt;
 
-use crate::constants::*;
+use crate::{constants::*, manifest::*};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum Environment {
     MainnetBeta,
     Testnet,
     #[default]
     Devnet,
     Local,
+    /// A user-defined environment from the environment manifest, selected
+    /// with `custom:<name>`.
+    Custom(Box<CustomEnvironment>),
 }
 
 impl std::str::FromStr for Environment {
     type Err = eyre::Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix(ENV_CUSTOM_PREFIX) {
+            let manifest = EnvironmentManifest::load()?;
+            return Ok(Environment::Custom(Box::new(manifest.get(name)?.clone())));
+        }
         match s {
             ENV_MAINNET_BETA_SHORT_NAME | ENV_MAINNET_BETA_NAME => Ok(Environment::MainnetBeta),
             ENV_TESTNET_SHORT_NAME | ENV_TESTNET_NAME => Ok(Environment::Testnet),
             ENV_DEVNET_SHORT_NAME | ENV_DEVNET_NAME => Ok(Environment::Devnet),
             ENV_LOCALNET_SHORT_NAME | ENV_LOCALNET_NAME => Ok(Environment::Local),
             _ => Err(eyre::eyre!(
-                "Invalid environment {s}, must be one of: {ENV_MAINNET_BETA_NAME}, {ENV_TESTNET_NAME}, {ENV_DEVNET_NAME}, {ENV_LOCALNET_NAME}",
+                "Invalid environment {s}, must be one of: {ENV_MAINNET_BETA_NAME}, {ENV_TESTNET_NAME}, {ENV_DEVNET_NAME}, {ENV_LOCALNET_NAME}, {ENV_CUSTOM_PREFIX}<name>",
             )),
         }
     }
@@ -37,11 +44,14 @@ impl fmt::Display for Environment {
             Environment::Testnet => write!(f, "testnet"),
             Environment::Devnet => write!(f, "devnet"),
             Environment::Local => write!(f, "local"),
+            Environment::Custom(env) => write!(f, "{ENV_CUSTOM_PREFIX}{}", env.name),
         }
     }
 }
 
 impl Environment {
+    /// Match a serviceability program ID to a built-in environment, or to one
+    /// in the local environment manifest.
     pub fn from_program_id(program_id: &str) -> eyre::Result<Environment> {
         if program_id.eq(&ENV_MAINNET_BETA_SERVICEABILITY_PUBKEY.to_string()) {
             return Ok(Environment::MainnetBeta);
@@ -52,6 +62,14 @@ impl Environment {
         } else if program_id.eq(&ENV_LOCAL_SERVICEABILITY_PUBKEY.to_string()) {
             return Ok(Environment::Local);
         }
+        if let Some(env) = EnvironmentManifest::load().ok().and_then(|manifest| {
+            manifest
+                .environments
+                .into_iter()
+                .find(|env| env.serviceability_program_id.to_string() == program_id)
+        }) {
+            return Ok(Environment::Custom(Box::new(env)));
+        }
         Err(eyre::eyre!(
             "Could not match environment from Program ID: {program_id}"
         ))
@@ -95,6 +113,15 @@ impl Environment {
                 internet_latency_collector_pk: ENV_LOCAL_INTERNET_LATENCY_COLLECTOR_PUBKEY,
                 geolocation_program_id: ENV_LOCAL_GEOLOCATION_PUBKEY,
             },
+            Environment::Custom(env) => NetworkConfig {
+                ledger_public_rpc_url: env.ledger_rpc_url.clone(),
+                ledger_public_ws_rpc_url: env.ledger_ws_rpc_url.clone(),
+                solana_l1_rpc_url: env.solana_rpc_url.clone(),
+                serviceability_program_id: env.serviceability_program_id,
+                telemetry_program_id: env.telemetry_program_id,
+                internet_latency_collector_pk: env.internet_latency_collector_pk,
+                geolocation_program_id: env.geolocation_program_id,
+            },
         };
 
         if std::env::var("DZ_LEDGER_RPC_URL").is_ok() {
@@ -113,7 +140,8 @@ impl Environment {
     /// Connection details for the shred-subscription program on Solana L1.
     ///
     /// Returns `None` for environments where the shred-subscription program is
-    /// not deployed (devnet, local). Note the Solana cluster differs from
+    /// not deployed (devnet, local, and custom environments whose manifest
+    /// entry has no `shred_subscription`). Note the Solana cluster differs from
     /// [`NetworkConfig::solana_l1_rpc_url`]: DZ testnet's shred-subscription
     /// program runs on Solana devnet, not Solana testnet.
     pub fn shred_subscription_config(&self) -> Option<ShredSubscriptionConfig> {
@@ -126,6 +154,13 @@ impl Environment {
                 program_id: SHRED_SUBSCRIPTION_PROGRAM_ID,
                 solana_rpc_url: ENV_TESTNET_SHRED_SOLANA_RPC_URL.to_string(),
             },
+            Environment::Custom(env) => {
+                let shred = env.shred_subscription.as_ref()?;
+                ShredSubscriptionConfig {
+                    program_id: shred.program_id,
+                    solana_rpc_url: shred.solana_rpc_url.clone(),
+                }
+            }
             Environment::Devnet | Environment::Local => return None,
         };
 
@@ -400,4 +435,37 @@ mod tests {
         let err = Environment::from_program_id(&Pubkey::default().to_string());
         assert!(err.is_err());
     }
+
+    #[test]
+    #[serial]
+    fn test_custom_environment() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("environments.json");
+        std::fs::write(&path, crate::manifest::tests::manifest_json()).unwrap();
+        std::env::set_var(ENV_MANIFEST_VAR, &path);
+
+        let env = "custom:acme".parse::<Environment>().unwrap();
+        assert_eq!(env.to_string(), "custom:acme");
+        let config = env.config().unwrap();
+        assert_eq!(config.ledger_public_rpc_url, "https://ledger.acme.example");
+        assert_eq!(config.ledger_public_ws_rpc_url, "wss://ledger.acme.example");
+        assert_eq!(config.solana_l1_rpc_url, "https://solana.acme.example");
+        assert!(env.shred_subscription_config().is_none());
+
+        // The persisted program ID maps back to the manifest environment.
+        let from_id =
+            Environment::from_program_id(&config.serviceability_program_id.to_string()).unwrap();
+        assert_eq!(from_id, env);
+
+        assert!("custom:missing".parse::<Environment>().is_err());
+
+        // An explicitly configured manifest must exist.
+        std::env::set_var(ENV_MANIFEST_VAR, dir.path().join("missing.json"));
+        assert!("custom:acme".parse::<Environment>().is_err());
+
+        std::env::set_var(ENV_MANIFEST_VAR, "https://example.com/environments.json");
+        assert!("custom:acme".parse::<Environment>().is_err());
+
+        std::env::remove_var(ENV_MANIFEST_VAR);
+    }
 }
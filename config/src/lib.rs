@@ -1,4 +1,6 @@
 mod constants;
 mod env;
+mod manifest;
 
 pub use env::*;
+pub use manifest::*;
@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::{fs, path::PathBuf};
+
+/// Environment variable selecting the environment manifest: a file path or an
+/// `http(s)://` URL. Defaults to `~/.config/doublezero/environments.json`.
+pub const ENV_MANIFEST_VAR: &str = "DZ_ENV_MANIFEST";
+
+/// Prefix selecting a manifest environment, as in `--env custom:myname`.
+pub const ENV_CUSTOM_PREFIX: &str = "custom:";
+
+/// A user-defined environment, for private test clusters and enterprise
+/// deployments that stock binaries do not know about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomEnvironment {
+    pub name: String,
+    pub ledger_rpc_url: String,
+    pub ledger_ws_rpc_url: String,
+    pub solana_rpc_url: String,
+    #[serde(with = "pubkey_str")]
+    pub serviceability_program_id: Pubkey,
+    #[serde(with = "pubkey_str")]
+    pub telemetry_program_id: Pubkey,
+    #[serde(with = "pubkey_str")]
+    pub internet_latency_collector_pk: Pubkey,
+    #[serde(with = "pubkey_str")]
+    pub geolocation_program_id: Pubkey,
+    /// Omitted when the cluster has no shred-subscription program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shred_subscription: Option<CustomShredSubscription>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomShredSubscription {
+    #[serde(with = "pubkey_str")]
+    pub program_id: Pubkey,
+    pub solana_rpc_url: String,
+}
+
+/// Where the environment manifest is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestSource {
+    File(PathBuf),
+    Url(String),
+}
+
+impl ManifestSource {
+    /// The manifest named by `DZ_ENV_MANIFEST`, or the default file.
+    ///
+    /// It will only be `None` if it is unable to identify the user's home
+    /// directory, which should not happen under typical OS environments.
+    pub fn from_env() -> Option<Self> {
+        match std::env::var(ENV_MANIFEST_VAR) {
+            Ok(s) if s.starts_with("http://") || s.starts_with("https://") => {
+                Some(ManifestSource::Url(s))
+            }
+            Ok(s) => Some(ManifestSource::File(PathBuf::from(s))),
+            Err(_) => dirs_next::home_dir().map(|mut path| {
+                path.extend([".config", "doublezero", "environments.json"]);
+                ManifestSource::File(path)
+            }),
+        }
+    }
+}
+
+/// A set of [`CustomEnvironment`]s.
+///
+/// ```json
+/// {"environments": [{"name": "myname", "ledger_rpc_url": "...", ...}]}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentManifest {
+    pub environments: Vec<CustomEnvironment>,
+}
+
+impl EnvironmentManifest {
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        let manifest: EnvironmentManifest = serde_json::from_str(json)
+            .map_err(|e| eyre::eyre!("Invalid environment manifest: {e}"))?;
+        for (i, env) in manifest.environments.iter().enumerate() {
+            validate_name(&env.name)?;
+            if manifest.environments[..i]
+                .iter()
+                .any(|other| other.name == env.name)
+            {
+                return Err(eyre::eyre!(
+                    "Environment {} is defined more than once in the manifest",
+                    env.name
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Read the manifest from `DZ_ENV_MANIFEST` or the default file. A missing
+    /// default file is an empty manifest. URLs are not fetched here; callers
+    /// that support them download the manifest and use [`Self::from_json`].
+    pub fn load() -> eyre::Result<Self> {
+        let explicit = std::env::var_os(ENV_MANIFEST_VAR).is_some();
+        match ManifestSource::from_env() {
+            Some(ManifestSource::File(path)) => match fs::read_to_string(&path) {
+                Ok(json) => Self::from_json(&json)
+                    .map_err(|e| eyre::eyre!("{}: {e}", path.display())),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound && !explicit => {
+                    Ok(Self::default())
+                }
+                Err(e) => Err(eyre::eyre!(
+                    "Unable to read environment manifest {}: {e}",
+                    path.display()
+                )),
+            },
+            Some(ManifestSource::Url(url)) => Err(eyre::eyre!(
+                "Environment manifest {url} is a URL, which this command cannot fetch; download it and set {ENV_MANIFEST_VAR} to the file"
+            )),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> eyre::Result<&CustomEnvironment> {
+        self.environments
+            .iter()
+            .find(|env| env.name == name)
+            .ok_or_else(|| eyre::eyre!("Environment {name} not found in the environment manifest"))
+    }
+}
+
+fn validate_name(name: &str) -> eyre::Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(eyre::eyre!(
+            "Invalid environment name {name:?}, must be letters, digits, '-' or '_'"
+        ));
+    }
+    Ok(())
+}
+
+mod pubkey_str {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(d)?;
+        Pubkey::from_str(&s).map_err(|e| D::Error::custom(format!("invalid pubkey {s}: {e}")))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn manifest_json() -> String {
+        r#"{
+            "environments": [
+                {
+                    "name": "acme",
+                    "ledger_rpc_url": "https://ledger.acme.example",
+                    "ledger_ws_rpc_url": "wss://ledger.acme.example",
+                    "solana_rpc_url": "https://solana.acme.example",
+                    "serviceability_program_id": "Vote111111111111111111111111111111111111111",
+                    "telemetry_program_id": "C9xqH76NSm11pBS6maNnY163tWHT8Govww47uyEmSnoG",
+                    "internet_latency_collector_pk": "3fXen9LP5JUAkaaDJtyLo1ohPiJ2LdzVqAnmhtGgAmwJ",
+                    "geolocation_program_id": "3fXen9LP5JUAkaaDJtyLo1ohPiJ2LdzVqAnmhtGgAmwJ"
+                }
+            ]
+        }"#
+        .to_string()
+    }
+
+    #[test]
+    fn test_manifest_from_json() {
+        let manifest = EnvironmentManifest::from_json(&manifest_json()).unwrap();
+        let env = manifest.get("acme").unwrap();
+        assert_eq!(env.ledger_rpc_url, "https://ledger.acme.example");
+        assert_eq!(
+            env.serviceability_program_id.to_string(),
+            "Vote111111111111111111111111111111111111111"
+        );
+        assert!(env.shred_subscription.is_none());
+        assert!(manifest.get("other").is_err());
+
+        // Round-trips with pubkeys as base58 strings.
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(json.contains("\"Vote111111111111111111111111111111111111111\""));
+        assert_eq!(EnvironmentManifest::from_json(&json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_manifest_from_json_invalid() {
+        let json = manifest_json();
+
+        let bad_pubkey = json.replace("Vote111111111111111111111111111111111111111", "nope");
+        assert!(EnvironmentManifest::from_json(&bad_pubkey).is_err());
+
+        let bad_name = json.replace("\"acme\"", "\"ac:me\"");
+        assert!(EnvironmentManifest::from_json(&bad_name).is_err());
+
+        let mut manifest = EnvironmentManifest::from_json(&json).unwrap();
+        manifest.environments.push(manifest.environments[0].clone());
+        let duplicate = serde_json::to_string(&manifest).unwrap();
+        assert!(EnvironmentManifest::from_json(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("more than once"));
+    }
+}
@@ -11,6 +11,7 @@ import (
 	"net/http"
 	"os"
 	"os/signal"
+	"regexp"
 	"slices"
 	"strings"
 	"syscall"
@@ -131,6 +132,10 @@ func NewControllerCommand() *ControllerCommand {
 	c.fs.BoolVar(&c.enablePprof, "enable-pprof", false, "enable pprof server")
 	c.fs.StringVar(&c.tlsListenPort, "tls-listen-port", "", "listening port for controller grpc server")
 	c.fs.IntVar(&c.maxUserTunnelSlots, "max-user-tunnel-slots", controllerconfig.DefaultMaxUserTunnelSlots, "per-device user tunnel slot count (must be positive)")
+	c.fs.IntVar(&c.driftEAPIPort, "drift-eapi-port", 0, "port of the EOS eAPI gRPC service on devices, used to check for config drift (0 disables drift detection)")
+	c.fs.DurationVar(&c.driftInterval, "drift-interval", controller.DefaultDriftInterval, "how often to compare each device's running config against its rendered config")
+	c.fs.StringVar(&c.driftKeypair, "drift-keypair", "", "path to a keypair allowed to set device health; when set, drifted devices are flagged onchain")
+	c.fs.StringVar(&c.driftIgnore, "drift-ignore", "", "comma separated regular expressions matching config lines never reported as drift")
 	return c
 }
 
@@ -150,6 +155,10 @@ type ControllerCommand struct {
 	tlsListenPort      string
 	enablePprof        bool
 	maxUserTunnelSlots int
+	driftEAPIPort      int
+	driftInterval      time.Duration
+	driftKeypair       string
+	driftIgnore        string
 }
 
 func (c *ControllerCommand) Fs() *flag.FlagSet {
@@ -333,6 +342,35 @@ func (c *ControllerCommand) Run() error {
 		}
 	}
 
+	// Drift detection runs in the no-tls controller only, so devices are
+	// checked (and flagged onchain) once.
+	if c.driftEAPIPort != 0 {
+		driftConfig := controller.DriftConfig{
+			Source:   &controller.EAPIRunningConfigSource{Port: c.driftEAPIPort},
+			Interval: c.driftInterval,
+		}
+		if c.driftIgnore != "" {
+			for _, pattern := range strings.Split(c.driftIgnore, ",") {
+				re, err := regexp.Compile(pattern)
+				if err != nil {
+					log.Error("invalid drift-ignore pattern", "pattern", pattern, "error", err)
+					os.Exit(1)
+				}
+				driftConfig.Ignore = append(driftConfig.Ignore, re)
+			}
+		}
+		if c.driftKeypair != "" {
+			signer, err := solana.PrivateKeyFromSolanaKeygenFile(c.driftKeypair)
+			if err != nil {
+				log.Error("failed to load drift keypair", "path", c.driftKeypair, "error", err)
+				os.Exit(1)
+			}
+			driftConfig.Health = serviceability.NewExecutor(log, ledgerRPCClient, &signer, serviceabilityClient.ProgramID())
+		}
+		options = append(options, controller.WithDriftDetection(driftConfig))
+		log.Info("config drift detection enabled", "eapi_port", c.driftEAPIPort, "interval", c.driftInterval, "onchain", c.driftKeypair != "")
+	}
+
 	log = log.With("mode", "no-tls")
 	options = append(options, controller.WithLogger(log))
 
@@ -0,0 +1,442 @@
+package controller
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	aristapb "github.com/malbeclabs/doublezero/controlplane/proto/arista/gen/pb-go/arista/EosSdkRpc"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/credentials/insecure"
+)
+
+const (
+	// DefaultDriftInterval is how often each device's running config is
+	// compared against its rendered config.
+	DefaultDriftInterval = 5 * time.Minute
+
+	// driftFetchTimeout bounds fetching one device's running config, so a
+	// single unreachable device can't stall the check for the rest.
+	driftFetchTimeout = 30 * time.Second
+
+	driftKindMissing    = "missing"
+	driftKindUnexpected = "unexpected"
+)
+
+// RunningConfigSource fetches the running config of a device, as the JSON
+// output of `show running-config` on EOS.
+type RunningConfigSource interface {
+	RunningConfig(ctx context.Context, device *Device) (string, error)
+}
+
+// DeviceHealthWriter records device health onchain.
+type DeviceHealthWriter interface {
+	SetDeviceHealthBatch(ctx context.Context, updates []serviceability.DeviceHealthUpdate, globalStatePubkey solana.PublicKey) (solana.Signature, error)
+}
+
+// DriftConfig configures config drift detection.
+type DriftConfig struct {
+	Source   RunningConfigSource
+	Interval time.Duration
+	// Ignore lists patterns for config lines, at any depth, that are never
+	// reported as drift.
+	Ignore []*regexp.Regexp
+	// Health, when set, flags drifted devices onchain with
+	// DeviceHealthDriftDetected and restores their previous health once the
+	// running config matches again.
+	Health DeviceHealthWriter
+}
+
+// WithDriftDetection enables periodic comparison of each device's running
+// config against the config the controller renders for it.
+func WithDriftDetection(cfg DriftConfig) Option {
+	return func(c *Controller) {
+		c.drift = &driftDetector{
+			DriftConfig: cfg,
+			prevHealth:  make(map[string]serviceability.DeviceHealth),
+		}
+	}
+}
+
+// ConfigDrift is one config line that differs between the running and the
+// rendered config of a device. Path holds the enclosing block lines followed
+// by the line itself.
+type ConfigDrift struct {
+	Kind string
+	Path []string
+}
+
+func (d ConfigDrift) String() string {
+	return fmt.Sprintf("%s: %s", d.Kind, strings.Join(d.Path, " > "))
+}
+
+// EAPIRunningConfigSource reads the running config through the EOS SDK eAPI
+// gRPC service, the same one the agent uses locally, on each device's public IP.
+type EAPIRunningConfigSource struct {
+	Port int
+}
+
+func (s *EAPIRunningConfigSource) RunningConfig(ctx context.Context, device *Device) (string, error) {
+	target := net.JoinHostPort(device.PublicIP.String(), strconv.Itoa(s.Port))
+	conn, err := grpc.NewClient(target, grpc.WithTransportCredentials(insecure.NewCredentials()))
+	if err != nil {
+		return "", fmt.Errorf("failed to connect to device: %w", err)
+	}
+	defer conn.Close()
+
+	cmd := &aristapb.RunShowCmdRequest{Command: "show running-config"}
+	resp, err := aristapb.NewEapiMgrServiceClient(conn).RunShowCmd(ctx, cmd)
+	if err != nil {
+		return "", fmt.Errorf("failed to run '%s': %w", cmd.Command, err)
+	}
+	if !resp.GetResponse().GetSuccess() {
+		return "", fmt.Errorf("error running eAPI cmd '%s': %s", cmd.Command, resp.GetResponse().GetErrorMessage())
+	}
+	if len(resp.GetResponse().GetResponses()) == 0 {
+		return "", fmt.Errorf("empty response to eAPI cmd '%s'", cmd.Command)
+	}
+	return resp.GetResponse().GetResponses()[0], nil
+}
+
+type driftDetector struct {
+	DriftConfig
+
+	// prevHealth remembers the health a device had before it was flagged, so
+	// it can be restored when the drift clears. Only the drift goroutine
+	// touches it.
+	prevHealth map[string]serviceability.DeviceHealth
+}
+
+// configLine is a rendered config line and the lines nested under it, in
+// render order.
+type configLine struct {
+	text     string
+	children []configLine
+}
+
+// configBlock is a running config block: each line maps to the block nested
+// under it (nil for a leaf line).
+type configBlock map[string]configBlock
+
+// parseRenderedConfig parses rendered EOS config text into a tree by
+// indentation, dropping blank lines, `!` comments and `end`.
+func parseRenderedConfig(text string) []configLine {
+	type frame struct {
+		indent int
+		lines  *[]configLine
+	}
+	var root []configLine
+	stack := []frame{{indent: -1, lines: &root}}
+	for _, raw := range strings.Split(text, "\n") {
+		line := strings.TrimRight(raw, " \t\r")
+		trimmed := strings.TrimLeft(line, " ")
+		if trimmed == "" || strings.HasPrefix(trimmed, "!") || trimmed == "end" {
+			continue
+		}
+		indent := len(line) - len(trimmed)
+		for len(stack) > 1 && stack[len(stack)-1].indent >= indent {
+			stack = stack[:len(stack)-1]
+		}
+		parent := stack[len(stack)-1].lines
+		*parent = append(*parent, configLine{text: trimmed})
+		stack = append(stack, frame{indent: indent, lines: &(*parent)[len(*parent)-1].children})
+	}
+	return root
+}
+
+// eosRunningConfig is the JSON form of `show running-config` on EOS.
+type eosRunningConfig struct {
+	Cmds map[string]*eosRunningConfig `json:"cmds"`
+}
+
+func (r *eosRunningConfig) block() configBlock {
+	if r == nil || len(r.Cmds) == 0 {
+		return nil
+	}
+	block := make(configBlock, len(r.Cmds))
+	for line, sub := range r.Cmds {
+		block[line] = sub.block()
+	}
+	return block
+}
+
+// parseRunningConfig parses the JSON output of `show running-config`.
+func parseRunningConfig(data string) (configBlock, error) {
+	var cfg eosRunningConfig
+	if err := json.Unmarshal([]byte(data), &cfg); err != nil {
+		return nil, fmt.Errorf("failed to parse running config: %w", err)
+	}
+	if len(cfg.Cmds) == 0 {
+		return nil, errors.New("running config is empty")
+	}
+	return cfg.block(), nil
+}
+
+var aclSequenceNumber = regexp.MustCompile(`^\d+ `)
+
+// normalizeACL strips the sequence numbers from access-list entries on both
+// sides, since EOS numbers the entries the controller renders without one.
+func normalizeACL(parent string, rendered []configLine, running configBlock) ([]configLine, configBlock) {
+	if !strings.HasPrefix(parent, "ip access-list ") {
+		return rendered, running
+	}
+	outRendered := make([]configLine, len(rendered))
+	for i, line := range rendered {
+		outRendered[i] = configLine{text: aclSequenceNumber.ReplaceAllString(line.text, ""), children: line.children}
+	}
+	outRunning := make(configBlock, len(running))
+	for line, sub := range running {
+		outRunning[aclSequenceNumber.ReplaceAllString(line, "")] = sub
+	}
+	return outRendered, outRunning
+}
+
+// diffConfig reports the lines of the rendered config that the running config
+// does not match:
+//   - a positive line missing from the running config is missing;
+//   - a `no X` line whose X is present in the running config is unexpected,
+//     unless a later sibling re-adds X (the controller's reset idiom, e.g.
+//     `no neighbor A` before `neighbor A remote-as ...`);
+//   - a block recreated that way is owned by the controller, so any line in it
+//     that the rendered config does not produce is unexpected.
+//
+// Lines the controller does not render outside such blocks are left alone, as
+// are `default ...` lines and lines matching an ignore pattern.
+func diffConfig(rendered []configLine, running configBlock, ignore []*regexp.Regexp) []ConfigDrift {
+	var drift []ConfigDrift
+	diffBlock(nil, rendered, running, false, ignore, &drift)
+	sort.Slice(drift, func(i, j int) bool {
+		return strings.Join(drift[i].Path, "\n") < strings.Join(drift[j].Path, "\n")
+	})
+	return drift
+}
+
+func diffBlock(path []string, rendered []configLine, running configBlock, owned bool, ignore []*regexp.Regexp, drift *[]ConfigDrift) {
+	ignored := func(line string) bool {
+		for _, re := range ignore {
+			if re.MatchString(line) {
+				return true
+			}
+		}
+		return false
+	}
+	report := func(kind, line string) {
+		p := append(append([]string{}, path...), line)
+		*drift = append(*drift, ConfigDrift{Kind: kind, Path: p})
+	}
+	if len(path) > 0 {
+		rendered, running = normalizeACL(path[len(path)-1], rendered, running)
+	}
+
+	expected := make(map[string]bool)
+	for i, line := range rendered {
+		if ignored(line.text) || strings.HasPrefix(line.text, "default ") {
+			expected[line.text] = true
+			continue
+		}
+		if negated, ok := strings.CutPrefix(line.text, "no "); ok {
+			// EOS shows some negations, e.g. `no switchport`, verbatim.
+			if _, present := running[line.text]; present {
+				expected[line.text] = true
+				continue
+			}
+			if !resetBySibling(negated, rendered[i+1:]) {
+				if _, present := running[negated]; present {
+					report(driftKindUnexpected, negated)
+					expected[negated] = true
+				}
+			}
+			continue
+		}
+		expected[line.text] = true
+		sub, present := running[line.text]
+		if !present {
+			report(driftKindMissing, line.text)
+			continue
+		}
+		childOwned := owned || recreated(line.text, rendered[:i])
+		diffBlock(append(path, line.text), line.children, sub, childOwned, ignore, drift)
+	}
+
+	if !owned {
+		return
+	}
+	for line := range running {
+		if !expected[line] && !ignored(line) {
+			report(driftKindUnexpected, line)
+		}
+	}
+}
+
+// resetBySibling reports whether one of the following siblings re-adds the
+// negated line, either verbatim or as a more specific line under it.
+func resetBySibling(negated string, following []configLine) bool {
+	for _, line := range following {
+		if line.text == negated || strings.HasPrefix(line.text, negated+" ") {
+			return true
+		}
+	}
+	return false
+}
+
+// recreated reports whether the block was removed by an earlier `no` sibling
+// before being rendered, i.e. the controller owns its whole content.
+func recreated(line string, preceding []configLine) bool {
+	for _, prev := range preceding {
+		if prev.text == "no "+line {
+			return true
+		}
+	}
+	return false
+}
+
+// runDrift compares every device's running config against its rendered
+// config on each interval tick until ctx is done.
+func (c *Controller) runDrift(ctx context.Context) {
+	interval := c.drift.Interval
+	if interval <= 0 {
+		interval = DefaultDriftInterval
+	}
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			c.checkDrift(ctx)
+		}
+	}
+}
+
+// checkDrift runs one drift check across all devices that can be rendered.
+func (c *Controller) checkDrift(ctx context.Context) {
+	type rendered struct {
+		device *Device
+		config string
+	}
+	var devices []rendered
+	c.mu.RLock()
+	for _, device := range c.cache.Devices {
+		if len(device.DevicePathologies) > 0 {
+			continue
+		}
+		deviceForRender := *device
+		deviceForRender.Tunnels = c.deduplicateTunnels(device)
+		config, err := c.renderDeviceConfig(&deviceForRender, nil)
+		if err != nil {
+			c.log.Warn("drift: failed to render device config", "device_pubkey", device.PubKey, "error", err)
+			configDriftChecks.WithLabelValues(device.PubKey, "error").Inc()
+			continue
+		}
+		devices = append(devices, rendered{device: device, config: config})
+	}
+	c.mu.RUnlock()
+
+	var updates []serviceability.DeviceHealthUpdate
+	for _, r := range devices {
+		drifted, err := c.checkDeviceDrift(ctx, r.device, r.config)
+		if err != nil {
+			c.log.Warn("drift: failed to check device config", "device_pubkey", r.device.PubKey, "device_code", r.device.Code, "error", err)
+			configDriftChecks.WithLabelValues(r.device.PubKey, "error").Inc()
+			continue
+		}
+		if update, ok := c.drift.healthUpdate(r.device, drifted); ok {
+			updates = append(updates, update)
+		}
+	}
+
+	if c.drift.Health == nil || len(updates) == 0 {
+		return
+	}
+	globalStatePubkey, _, err := serviceability.GetGlobalStatePDA(c.serviceability.ProgramID())
+	if err != nil {
+		c.log.Error("drift: failed to derive globalstate PDA", "error", err)
+		return
+	}
+	sig, err := c.drift.Health.SetDeviceHealthBatch(ctx, updates, globalStatePubkey)
+	if err != nil {
+		c.log.Error("drift: failed to update device health", "devices", len(updates), "error", err)
+		return
+	}
+	c.log.Info("drift: updated device health", "devices", len(updates), "signature", sig)
+}
+
+// checkDeviceDrift fetches one device's running config, diffs it against the
+// rendered config and records the result. It reports whether drift was found.
+func (c *Controller) checkDeviceDrift(ctx context.Context, device *Device, renderedConfig string) (bool, error) {
+	fetchCtx, cancel := context.WithTimeout(ctx, driftFetchTimeout)
+	defer cancel()
+	data, err := c.drift.Source.RunningConfig(fetchCtx, device)
+	if err != nil {
+		return false, err
+	}
+	running, err := parseRunningConfig(data)
+	if err != nil {
+		return false, err
+	}
+
+	drift := diffConfig(parseRenderedConfig(renderedConfig), running, c.drift.Ignore)
+	counts := map[string]int{driftKindMissing: 0, driftKindUnexpected: 0}
+	for _, d := range drift {
+		counts[d.Kind]++
+	}
+	for kind, n := range counts {
+		configDriftLines.WithLabelValues(device.PubKey, device.Code, kind).Set(float64(n))
+	}
+	if len(drift) == 0 {
+		configDriftChecks.WithLabelValues(device.PubKey, "in_sync").Inc()
+		return false, nil
+	}
+
+	configDriftChecks.WithLabelValues(device.PubKey, "drift").Inc()
+	lines := make([]string, len(drift))
+	for i, d := range drift {
+		lines[i] = d.String()
+	}
+	c.log.Warn("drift: running config differs from rendered config",
+		"device_pubkey", device.PubKey,
+		"device_code", device.Code,
+		"missing", counts[driftKindMissing],
+		"unexpected", counts[driftKindUnexpected],
+		"lines", lines)
+	return true, nil
+}
+
+// healthUpdate returns the onchain health change, if any, that brings a
+// device's health in line with the outcome of its latest drift check.
+func (d *driftDetector) healthUpdate(device *Device, drifted bool) (serviceability.DeviceHealthUpdate, bool) {
+	if d.Health == nil {
+		return serviceability.DeviceHealthUpdate{}, false
+	}
+	pubkey, err := solana.PublicKeyFromBase58(device.PubKey)
+	if err != nil {
+		return serviceability.DeviceHealthUpdate{}, false
+	}
+	flagged := device.Health == serviceability.DeviceHealthDriftDetected
+	switch {
+	case drifted && !flagged:
+		d.prevHealth[device.PubKey] = device.Health
+		return serviceability.DeviceHealthUpdate{DevicePubkey: pubkey, Health: serviceability.DeviceHealthDriftDetected}, true
+	case !drifted && flagged:
+		// Without a remembered health (e.g. the controller restarted while the
+		// device was flagged), fall back to Pending and let the health oracle
+		// promote it again.
+		health, ok := d.prevHealth[device.PubKey]
+		if !ok {
+			health = serviceability.DeviceHealthPending
+		}
+		delete(d.prevHealth, device.PubKey)
+		return serviceability.DeviceHealthUpdate{DevicePubkey: pubkey, Health: health}, true
+	}
+	return serviceability.DeviceHealthUpdate{}, false
+}
@@ -0,0 +1,317 @@
+package controller
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net"
+	"regexp"
+	"strings"
+	"testing"
+
+	"github.com/gagliardetto/solana-go"
+	"github.com/google/go-cmp/cmp"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+)
+
+// runningJSON renders a running config block as the JSON `show running-config`
+// returns.
+func runningJSON(t *testing.T, block configBlock) string {
+	t.Helper()
+	var encode func(configBlock) *eosRunningConfig
+	encode = func(b configBlock) *eosRunningConfig {
+		if b == nil {
+			return nil
+		}
+		out := &eosRunningConfig{Cmds: make(map[string]*eosRunningConfig, len(b))}
+		for line, sub := range b {
+			out.Cmds[line] = encode(sub)
+		}
+		return out
+	}
+	data, err := json.Marshal(encode(block))
+	if err != nil {
+		t.Fatalf("failed to marshal running config: %v", err)
+	}
+	return string(data)
+}
+
+// runningFromRendered builds the running config a device would have after
+// applying the rendered config: every positive line, nested as rendered, with
+// repeated blocks merged as EOS does.
+func runningFromRendered(lines []configLine) configBlock {
+	block := configBlock{}
+	for _, line := range lines {
+		if strings.HasPrefix(line.text, "no ") || strings.HasPrefix(line.text, "default ") {
+			continue
+		}
+		if len(line.children) == 0 {
+			if _, ok := block[line.text]; !ok {
+				block[line.text] = nil
+			}
+			continue
+		}
+		sub := block[line.text]
+		if sub == nil {
+			sub = configBlock{}
+		}
+		for k, v := range runningFromRendered(line.children) {
+			sub[k] = v
+		}
+		block[line.text] = sub
+	}
+	return block
+}
+
+func TestParseRenderedConfig(t *testing.T) {
+	got := parseRenderedConfig(`!
+hostname dz1
+!
+router bgp 65342
+   router-id 1.1.1.1
+   address-family ipv4
+      neighbor 2.2.2.2 activate
+   !
+   vrf vrf1
+      rd 65342:1
+!
+end
+`)
+	want := []configLine{
+		{text: "hostname dz1"},
+		{text: "router bgp 65342", children: []configLine{
+			{text: "router-id 1.1.1.1"},
+			{text: "address-family ipv4", children: []configLine{
+				{text: "neighbor 2.2.2.2 activate"},
+			}},
+			{text: "vrf vrf1", children: []configLine{
+				{text: "rd 65342:1"},
+			}},
+		}},
+	}
+	if diff := cmp.Diff(want, got, cmp.AllowUnexported(configLine{})); diff != "" {
+		t.Errorf("parseRenderedConfig() mismatch (-want +got):\n%s", diff)
+	}
+}
+
+func TestParseRunningConfig(t *testing.T) {
+	got, err := parseRunningConfig(`{"cmds": {"hostname dz1": null, "interface Ethernet1": {"cmds": {"no switchport": null}, "comments": []}}, "header": ["! device: dz1"]}`)
+	if err != nil {
+		t.Fatalf("parseRunningConfig() error: %v", err)
+	}
+	want := configBlock{
+		"hostname dz1":        nil,
+		"interface Ethernet1": configBlock{"no switchport": nil},
+	}
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("parseRunningConfig() mismatch (-want +got):\n%s", diff)
+	}
+
+	if _, err := parseRunningConfig(`{"cmds": {}}`); err == nil {
+		t.Error("expected an error for an empty running config")
+	}
+	if _, err := parseRunningConfig(`not json`); err == nil {
+		t.Error("expected an error for malformed output")
+	}
+}
+
+func TestDiffConfig(t *testing.T) {
+	rendered := `no logging console
+router bgp 65342
+   timers bgp 1 3
+   no neighbor 12.12.12.12
+   neighbor 12.12.12.12 remote-as 65342
+   no neighbor 13.13.13.13
+!
+interface Ethernet1/1
+   mtu 9000
+   no switchport
+   default isis passive
+!
+no ip access-list MAIN-CONTROL-PLANE-ACL
+ip access-list MAIN-CONTROL-PLANE-ACL
+   counters per-entry
+   10 permit icmp any any
+   permit tcp any any eq bgp
+`
+	inSync := func() configBlock {
+		return configBlock{
+			"no logging console": nil,
+			"hostname dz1":       nil,
+			"router bgp 65342": configBlock{
+				"timers bgp 1 3":                       nil,
+				"neighbor 12.12.12.12 remote-as 65342": nil,
+				"router-id 1.1.1.1":                    nil,
+			},
+			"interface Ethernet1/1": configBlock{
+				"mtu 9000":      nil,
+				"no switchport": nil,
+				"isis passive":  nil,
+			},
+			"ip access-list MAIN-CONTROL-PLANE-ACL": configBlock{
+				"counters per-entry":           nil,
+				"10 permit icmp any any":       nil,
+				"20 permit tcp any any eq bgp": nil,
+			},
+		}
+	}
+
+	tests := []struct {
+		name   string
+		edit   func(configBlock)
+		ignore []*regexp.Regexp
+		want   []string
+	}{
+		{
+			name: "in sync, including lines the controller does not render",
+			edit: func(configBlock) {},
+		},
+		{
+			name: "missing line",
+			edit: func(b configBlock) { delete(b["router bgp 65342"], "timers bgp 1 3") },
+			want: []string{"missing: router bgp 65342 > timers bgp 1 3"},
+		},
+		{
+			name: "missing block",
+			edit: func(b configBlock) { delete(b, "interface Ethernet1/1") },
+			want: []string{"missing: interface Ethernet1/1"},
+		},
+		{
+			name: "negated line present",
+			edit: func(b configBlock) { b["router bgp 65342"]["neighbor 13.13.13.13"] = nil },
+			want: []string{"unexpected: router bgp 65342 > neighbor 13.13.13.13"},
+		},
+		{
+			name: "extra line in a block the controller recreates",
+			edit: func(b configBlock) { b["ip access-list MAIN-CONTROL-PLANE-ACL"]["30 permit ip any any"] = nil },
+			want: []string{"unexpected: ip access-list MAIN-CONTROL-PLANE-ACL > permit ip any any"},
+		},
+		{
+			name:   "ignored line",
+			edit:   func(b configBlock) { delete(b["router bgp 65342"], "timers bgp 1 3") },
+			ignore: []*regexp.Regexp{regexp.MustCompile(`^timers bgp `)},
+		},
+		{
+			name: "drift is sorted by path",
+			edit: func(b configBlock) {
+				delete(b["router bgp 65342"], "timers bgp 1 3")
+				delete(b["interface Ethernet1/1"], "mtu 9000")
+			},
+			want: []string{
+				"missing: interface Ethernet1/1 > mtu 9000",
+				"missing: router bgp 65342 > timers bgp 1 3",
+			},
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			running := inSync()
+			tt.edit(running)
+			var got []string
+			for _, d := range diffConfig(parseRenderedConfig(rendered), running, tt.ignore) {
+				got = append(got, d.String())
+			}
+			if diff := cmp.Diff(tt.want, got); diff != "" {
+				t.Errorf("diffConfig() mismatch (-want +got):\n%s", diff)
+			}
+		})
+	}
+}
+
+type fakeRunningConfigSource struct {
+	config string
+}
+
+func (f *fakeRunningConfigSource) RunningConfig(context.Context, *Device) (string, error) {
+	return f.config, nil
+}
+
+type fakeDeviceHealthWriter struct {
+	updates []serviceability.DeviceHealthUpdate
+}
+
+func (f *fakeDeviceHealthWriter) SetDeviceHealthBatch(_ context.Context, updates []serviceability.DeviceHealthUpdate, _ solana.PublicKey) (solana.Signature, error) {
+	f.updates = append(f.updates, updates...)
+	return solana.Signature{}, nil
+}
+
+func TestCheckDrift_FlagsAndRestoresDeviceHealth(t *testing.T) {
+	const pubkey = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM"
+	device := &Device{
+		PubKey:          pubkey,
+		Code:            "dz1",
+		PublicIP:        net.IP{7, 7, 7, 7},
+		Vpn4vLoopbackIP: net.IP{14, 14, 14, 14},
+		IsisNet:         "49.0000.0e0e.0e0e.0000.00",
+		ExchangeCode:    "tst",
+		BgpCommunity:    10050,
+		Interfaces:      []Interface{},
+		Health:          serviceability.DeviceHealthReadyForUsers,
+	}
+	source := &fakeRunningConfigSource{}
+	health := &fakeDeviceHealthWriter{}
+	c := &Controller{
+		log:            slog.New(slog.NewTextHandler(&bytes.Buffer{}, nil)),
+		deviceLocalASN: 65342,
+		serviceability: &mockServiceabilityProgramClient{
+			ProgramIDFunc: func() solana.PublicKey {
+				return solana.MustPublicKeyFromBase58("11111111111111111111111111111111")
+			},
+		},
+		cache: stateCache{
+			GlobalConfig: &serviceability.GlobalConfig{},
+			Devices:      map[string]*Device{pubkey: device},
+		},
+	}
+	WithDriftDetection(DriftConfig{Source: source, Health: health})(c)
+
+	c.mu.RLock()
+	config, err := c.renderDeviceConfig(device, nil)
+	c.mu.RUnlock()
+	if err != nil {
+		t.Fatalf("renderDeviceConfig() error: %v", err)
+	}
+	running := runningFromRendered(parseRenderedConfig(config))
+
+	// In sync: nothing is written.
+	source.config = runningJSON(t, running)
+	c.checkDrift(context.Background())
+	if len(health.updates) != 0 {
+		t.Fatalf("expected no health updates for an in-sync device, got %v", health.updates)
+	}
+
+	// A line removed by hand flags the device.
+	var removed string
+	for line := range running {
+		if strings.HasPrefix(line, "router bgp ") {
+			removed = line
+			break
+		}
+	}
+	if removed == "" {
+		t.Fatal("rendered config has no router bgp block")
+	}
+	edited := runningFromRendered(parseRenderedConfig(config))
+	delete(edited, removed)
+	source.config = runningJSON(t, edited)
+	c.checkDrift(context.Background())
+	want := []serviceability.DeviceHealthUpdate{
+		{DevicePubkey: solana.MustPublicKeyFromBase58(pubkey), Health: serviceability.DeviceHealthDriftDetected},
+	}
+	if diff := cmp.Diff(want, health.updates); diff != "" {
+		t.Fatalf("unexpected health updates (-want +got):\n%s", diff)
+	}
+
+	// Once the running config matches again, the previous health is restored.
+	device.Health = serviceability.DeviceHealthDriftDetected
+	health.updates = nil
+	source.config = runningJSON(t, running)
+	c.checkDrift(context.Background())
+	want = []serviceability.DeviceHealthUpdate{
+		{DevicePubkey: solana.MustPublicKeyFromBase58(pubkey), Health: serviceability.DeviceHealthReadyForUsers},
+	}
+	if diff := cmp.Diff(want, health.updates); diff != "" {
+		t.Fatalf("unexpected health updates (-want +got):\n%s", diff)
+	}
+}
@@ -87,6 +87,20 @@ var (
 		[]string{"link_pubkey", "device_code", "interface"},
 	)
 
+	// config drift metrics
+	configDriftLines = prometheus.NewGaugeVec(prometheus.GaugeOpts{
+		Name: "controller_device_config_drift_lines",
+		Help: "Number of lines by which a device's running config differs from its rendered config",
+	},
+		[]string{"pubkey", "device_code", "kind"},
+	)
+	configDriftChecks = prometheus.NewCounterVec(prometheus.CounterOpts{
+		Name: "controller_device_config_drift_checks_total",
+		Help: "The total number of config drift checks by result (in_sync, drift, error)",
+	},
+		[]string{"pubkey", "result"},
+	)
+
 	srvMetrics = grpcprom.NewServerMetrics(
 		grpcprom.WithServerHandlingTimeHistogram(
 			grpcprom.WithHistogramBuckets([]float64{0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 5}),
@@ -115,6 +129,10 @@ func init() {
 	prometheus.MustRegister(linkMetrics)
 	prometheus.MustRegister(linkMetricInvalid)
 
+	// config drift metrics
+	prometheus.MustRegister(configDriftLines)
+	prometheus.MustRegister(configDriftChecks)
+
 	// gRPC middleware metrics
 	prometheus.MustRegister(srvMetrics)
 }
@@ -140,6 +158,8 @@ func deleteDeviceMetrics(pubkey, code string) {
 	getConfigOps.DeletePartialMatch(byPubkey)
 	getConfigRenderErrors.DeletePartialMatch(byPubkey)
 	duplicateTunnelPairs.DeletePartialMatch(byPubkey)
+	configDriftLines.DeletePartialMatch(byPubkey)
+	configDriftChecks.DeletePartialMatch(byPubkey)
 	linkMetrics.DeletePartialMatch(prometheus.Labels{"device_pubkey": pubkey})
 	linkMetricInvalid.DeletePartialMatch(prometheus.Labels{"device_code": code})
 }
@@ -80,6 +80,8 @@ func seedDeviceMetrics(pubkey, code string) {
 	getConfigOps.WithLabelValues(pubkey, code, "contrib", "exch", "loc", "Activated", "v1", "abc", "2026-01-01").Inc()
 	getConfigRenderErrors.WithLabelValues(pubkey).Inc()
 	duplicateTunnelPairs.WithLabelValues(pubkey, code).Inc()
+	configDriftLines.WithLabelValues(pubkey, code, "missing").Set(1)
+	configDriftChecks.WithLabelValues(pubkey, "drift").Inc()
 	linkMetrics.WithLabelValues(code, "Ethernet1", pubkey).Set(1)
 	// linkMetricInvalid is keyed by link_pubkey/device_code/interface; the
 	// link_pubkey is derived from the code so each seeded device is distinct.
@@ -126,6 +128,8 @@ func TestSwapCache_PrunesRemovedDeviceMetrics(t *testing.T) {
 		{"getConfigOps", getConfigOps, "pubkey"},
 		{"getConfigRenderErrors", getConfigRenderErrors, "pubkey"},
 		{"duplicateTunnelPairs", duplicateTunnelPairs, "pubkey"},
+		{"configDriftLines", configDriftLines, "pubkey"},
+		{"configDriftChecks", configDriftChecks, "pubkey"},
 		{"linkMetrics", linkMetrics, "device_pubkey"},
 	} {
 		if got := countSeriesWithLabel(tc.vec, tc.label, removed); got != 0 {
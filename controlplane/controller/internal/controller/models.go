@@ -201,6 +201,7 @@ type Device struct {
 	BgpCommunity          uint16
 	ExchangeCode          string
 	Status                serviceability.DeviceStatus
+	Health                serviceability.DeviceHealth
 	// Additional fields for metric labels
 	Code            string
 	ContributorCode string
@@ -328,6 +329,7 @@ type templateData struct {
 	UnicastVrfs              []uint16
 	Strings                  StringsHelper
 	AllTopologies            []TopologyModel
+	AnycastPrefixes          []string        // enabled anycast service prefixes, ordered by code
 	Config                   *FeaturesConfig // nil when no features config is loaded
 }
 
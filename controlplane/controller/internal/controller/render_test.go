@@ -763,6 +763,7 @@ func TestRenderConfig(t *testing.T) {
 						PeerName: "remote-dzd",
 					},
 				},
+				AnycastPrefixes: []string{"100.64.255.53/32", "100.64.255.123/32"},
 			},
 			Want: "fixtures/base.config.txt",
 		},
@@ -76,6 +76,7 @@ type stateCache struct {
 	Tenants         map[string]serviceability.Tenant
 	Topologies      map[string]serviceability.TopologyInfo // keyed by base58 pubkey
 	UnicastVrfs     []uint16
+	AnycastPrefixes []string // enabled anycast service prefixes, ordered by code
 	Vpnv4BgpPeers   []BgpPeer
 	Ipv4BgpPeers    []BgpPeer
 }
@@ -96,6 +97,7 @@ type Controller struct {
 	clickhouse         *ClickhouseWriter
 	featuresConfig     *FeaturesConfig
 	maxUserTunnelSlots int
+	drift              *driftDetector
 
 	// lastUnknownPubkeyWarnNanos rate-limits the unknown-pubkey warning log.
 	// It holds the unix-nanos timestamp of the last emitted warning.
@@ -370,6 +372,7 @@ func (c *Controller) updateStateCache(ctx context.Context) error {
 		d.MgmtVrf = device.MgmtVrf
 		d.Code = device.Code
 		d.Status = device.Status
+		d.Health = device.DeviceHealth
 
 		if contributor, ok := contributorMap[device.ContributorPubKey]; ok {
 			d.ContributorCode = contributor.Code
@@ -481,8 +484,9 @@ func (c *Controller) updateStateCache(ctx context.Context) error {
 				}
 			}
 
-			// override all delay values if link is soft drained
-			if link.Status == serviceability.LinkStatusSoftDrained {
+			// override all delay values if link is soft drained, or suppressed by
+			// flap damping so paths avoid it while it keeps forwarding as a last resort
+			if link.Status == serviceability.LinkStatusSoftDrained || link.IsFlapDamped() {
 				microseconds = 1000000
 			}
 
@@ -551,6 +555,9 @@ func (c *Controller) updateStateCache(ctx context.Context) error {
 	sort.Slice(vrfs, func(i, j int) bool { return vrfs[i] < vrfs[j] })
 	cache.UnicastVrfs = vrfs
 
+	// Anycast service prefixes are global: every device advertises all enabled ones.
+	cache.AnycastPrefixes = anycastPrefixes(data.AnycastPrefixes)
+
 	// create user tunnels and add to the appropriate device
 	for _, user := range users {
 		if user.Status != serviceability.UserStatusActivated {
@@ -829,6 +836,10 @@ func (c *Controller) Run(ctx context.Context) error {
 		}
 	}()
 
+	if c.drift != nil {
+		go c.runDrift(ctx)
+	}
+
 	// start gRPC server
 	opts := []grpc.ServerOption{
 		grpc.UnaryInterceptor(srvMetrics.UnaryServerInterceptor()),
@@ -982,6 +993,35 @@ func (c *Controller) GetConfig(ctx context.Context, req *pb.ConfigRequest) (*pb.
 		agentDate,
 	).Inc()
 
+	config, err := c.renderDeviceConfig(&deviceForRender, req.GetBgpPeers())
+	if err != nil {
+		getConfigRenderErrors.WithLabelValues(req.GetPubkey()).Inc()
+		return nil, err
+	}
+	resp := &pb.ConfigResponse{Config: config}
+	getConfigMsgSize.Observe(float64(proto.Size(resp)))
+	getConfigDuration.Observe(float64(time.Since(reqStart).Seconds()))
+	if c.clickhouse != nil {
+		c.clickhouse.Record(getConfigEvent{
+			Timestamp:    reqStart,
+			DevicePubkey: req.GetPubkey(),
+		})
+		c.clickhouse.RecordVersion(agentVersionReport{
+			DevicePubkey: req.GetPubkey(),
+			UpdatedAt:    reqStart,
+			AgentVersion: agentVersion,
+			AgentCommit:  agentCommit,
+			AgentDate:    agentDate,
+		})
+	}
+	return resp, nil
+}
+
+// renderDeviceConfig renders the intended config for a device from the cached
+// onchain state. bgpPeers are the peers currently configured on the device; any
+// DZ-assigned peer among them that is unknown onchain is rendered for removal.
+// Callers must hold c.mu.
+func (c *Controller) renderDeviceConfig(deviceForRender *Device, bgpPeers []string) (string, error) {
 	// compare peers from device to on-chain
 	peerFound := func(peer net.IP) bool {
 		for _, tun := range deviceForRender.Tunnels {
@@ -1003,7 +1043,7 @@ func (c *Controller) GetConfig(ctx context.Context, req *pb.ConfigRequest) (*pb.
 	}
 
 	unknownPeers := []net.IP{}
-	for _, peer := range req.GetBgpPeers() {
+	for _, peer := range bgpPeers {
 		ip := net.ParseIP(peer)
 		if ip == nil {
 			continue
@@ -1037,15 +1077,11 @@ func (c *Controller) GetConfig(ctx context.Context, req *pb.ConfigRequest) (*pb.
 		// Get ASN from environment
 		networkConfig, err := config.NetworkConfigForEnv(c.environment)
 		if err != nil {
-			getConfigRenderErrors.WithLabelValues(req.GetPubkey()).Inc()
-			err := status.Errorf(codes.Internal, "failed to get network config for environment %s: %v", c.environment, err)
-			return nil, err
+			return "", status.Errorf(codes.Internal, "failed to get network config for environment %s: %v", c.environment, err)
 		}
 		localASN = networkConfig.DeviceLocalASN
 	} else {
-		getConfigRenderErrors.WithLabelValues(req.GetPubkey()).Inc()
-		err := status.Errorf(codes.Internal, "device local ASN not configured")
-		return nil, err
+		return "", status.Errorf(codes.Internal, "device local ASN not configured")
 	}
 
 	var allTopologies []TopologyModel
@@ -1064,7 +1100,7 @@ func (c *Controller) GetConfig(ctx context.Context, req *pb.ConfigRequest) (*pb.
 
 	data := templateData{
 		MulticastGroupBlock:      multicastGroupBlock,
-		Device:                   &deviceForRender,
+		Device:                   deviceForRender,
 		Vpnv4BgpPeers:            c.cache.Vpnv4BgpPeers,
 		Ipv4BgpPeers:             ipv4Peers,
 		UnknownBgpPeers:          unknownPeers,
@@ -1074,32 +1110,15 @@ func (c *Controller) GetConfig(ctx context.Context, req *pb.ConfigRequest) (*pb.
 		UnicastVrfs:              c.cache.UnicastVrfs,
 		Strings:                  StringsHelper{},
 		AllTopologies:            allTopologies,
+		AnycastPrefixes:          c.cache.AnycastPrefixes,
 		Config:                   c.featuresConfig,
 	}
 
 	config, err := renderConfig(data)
 	if err != nil {
-		getConfigRenderErrors.WithLabelValues(req.GetPubkey()).Inc()
-		err := status.Errorf(codes.Aborted, "config rendering for pubkey %s failed: %v", req.Pubkey, err)
-		return nil, err
-	}
-	resp := &pb.ConfigResponse{Config: config}
-	getConfigMsgSize.Observe(float64(proto.Size(resp)))
-	getConfigDuration.Observe(float64(time.Since(reqStart).Seconds()))
-	if c.clickhouse != nil {
-		c.clickhouse.Record(getConfigEvent{
-			Timestamp:    reqStart,
-			DevicePubkey: req.GetPubkey(),
-		})
-		c.clickhouse.RecordVersion(agentVersionReport{
-			DevicePubkey: req.GetPubkey(),
-			UpdatedAt:    reqStart,
-			AgentVersion: agentVersion,
-			AgentCommit:  agentCommit,
-			AgentDate:    agentDate,
-		})
+		return "", status.Errorf(codes.Aborted, "config rendering for pubkey %s failed: %v", deviceForRender.PubKey, err)
 	}
-	return resp, nil
+	return config, nil
 }
 
 // peerAddr returns the remote address of the gRPC caller, or "unknown" if it
@@ -1112,6 +1131,23 @@ func peerAddr(ctx context.Context) string {
 }
 
 // formatCIDR formats a 5-byte network block into CIDR notation
+// anycastPrefixes returns the enabled anycast prefixes in CIDR form, ordered by code so the
+// primary address of the anycast loopback is stable across renders.
+func anycastPrefixes(prefixes []serviceability.AnycastPrefix) []string {
+	enabled := make([]serviceability.AnycastPrefix, 0, len(prefixes))
+	for _, p := range prefixes {
+		if p.Enabled {
+			enabled = append(enabled, p)
+		}
+	}
+	sort.Slice(enabled, func(i, j int) bool { return enabled[i].Code < enabled[j].Code })
+	var out []string
+	for _, p := range enabled {
+		out = append(out, formatCIDR(&p.Prefix))
+	}
+	return out
+}
+
 func formatCIDR(b *[5]byte) string {
 	ip := net.IPv4(b[0], b[1], b[2], b[3])
 	mask := net.CIDRMask(int(b[4]), 32)
@@ -714,6 +714,7 @@ func TestStateCache(t *testing.T) {
 		MulticastGroups []serviceability.MulticastGroup
 		Exchanges       []serviceability.Exchange
 		Tenants         []serviceability.Tenant
+		AnycastPrefixes []serviceability.AnycastPrefix
 		StateCache      stateCache
 	}{
 		{
@@ -1413,6 +1414,11 @@ func TestStateCache(t *testing.T) {
 					VrfId:  2,
 				},
 			},
+			AnycastPrefixes: []serviceability.AnycastPrefix{
+				{Code: "ntp", Prefix: [5]uint8{100, 64, 255, 123, 32}, Enabled: true},
+				{Code: "internal", Prefix: [5]uint8{100, 64, 255, 1, 32}},
+				{Code: "dns", Prefix: [5]uint8{100, 64, 255, 53, 32}, Enabled: true},
+			},
 			Users: []serviceability.User{
 				{
 					AccountType:  serviceability.AccountType(0),
@@ -1511,6 +1517,7 @@ func TestStateCache(t *testing.T) {
 						PeerName: "abc01",
 					},
 				},
+				AnycastPrefixes: []string{"100.64.255.53/32", "100.64.255.123/32"},
 				Devices: map[string]*Device{
 					"4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM": {
 						PubKey:            "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM",
@@ -1607,6 +1614,7 @@ func TestStateCache(t *testing.T) {
 						MulticastGroups: test.MulticastGroups,
 						Exchanges:       test.Exchanges,
 						Tenants:         test.Tenants,
+						AnycastPrefixes: test.AnycastPrefixes,
 					}, nil
 				},
 				ProgramIDFunc: func() solana.PublicKey {
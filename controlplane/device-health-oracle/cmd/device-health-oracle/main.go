@@ -15,6 +15,7 @@ import (
 	"github.com/gagliardetto/solana-go"
 	solanarpc "github.com/gagliardetto/solana-go/rpc"
 	"github.com/malbeclabs/doublezero/config"
+	"github.com/malbeclabs/doublezero/controlplane/device-health-oracle/internal/prober"
 	"github.com/malbeclabs/doublezero/controlplane/device-health-oracle/internal/worker"
 	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
 	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
@@ -22,7 +23,9 @@ import (
 )
 
 const (
-	defaultInterval = 1 * time.Minute
+	defaultInterval      = 1 * time.Minute
+	defaultProbeInterval = 30 * time.Second
+	defaultProbeTimeout  = 5 * time.Second
 
 	// Default burn-in slot counts for devices/links.
 	// Provisioning: ~20 hours (200,000 slots * 400ms per slot)
@@ -44,6 +47,9 @@ var (
 	slackWebhookURL         = flag.String("slack-webhook-url", "", "The Slack webhook URL to send alerts")
 	provisioningSlotCount   = flag.Uint64("provisioning-slot-count", defaultProvisioningSlotCount, "Burn-in slot count for new devices/links (~20 hours at 200000)")
 	drainedSlotCount        = flag.Uint64("drained-slot-count", defaultDrainedSlotCount, "Burn-in slot count for reactivated devices/links (~30 min at 5000)")
+	probeClientIP           = flag.String("probe-client-ip", "", "client IP of the user tunnels to send synthetic path probes through (disabled if empty)")
+	probeInterval           = flag.Duration("probe-interval", defaultProbeInterval, "interval between synthetic path probe rounds")
+	probeTimeout            = flag.Duration("probe-timeout", defaultProbeTimeout, "how long to wait for synthetic path probe replies")
 	version                 = "dev"
 	commit                  = "none"
 	date                    = "unknown"
@@ -204,6 +210,42 @@ func main() {
 	ctx, cancel := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
 	defer cancel()
 
+	// Start the synthetic path prober if a probe client IP is configured.
+	if *probeClientIP != "" {
+		clientIP := net.ParseIP(*probeClientIP)
+		if clientIP == nil || clientIP.To4() == nil {
+			log.Error("Failed to parse probe client IP", "ip", *probeClientIP)
+			os.Exit(1)
+		}
+		conn, err := net.ListenPacket("ip4:gre", clientIP.String())
+		if err != nil {
+			log.Error("Failed to open GRE socket for path probes", "ip", clientIP, "error", err)
+			os.Exit(1)
+		}
+		defer conn.Close()
+
+		p, err := prober.New(&prober.Config{
+			Logger:                  log,
+			LedgerRPCClient:         rpcClient,
+			Serviceability:          serviceabilityClient,
+			ServiceabilityProgramID: networkConfig.ServiceabilityProgramID,
+			Telemetry:               telemetry.New(log, rpcClient, &signer, networkConfig.TelemetryProgramID),
+			Conn:                    conn,
+			ClientIP:                clientIP,
+			Interval:                *probeInterval,
+			Timeout:                 *probeTimeout,
+		})
+		if err != nil {
+			log.Error("Failed to create path prober", "error", err)
+			os.Exit(1)
+		}
+		go func() {
+			if err := p.Run(ctx); err != nil {
+				log.Error("Failed to run path prober", "error", err)
+			}
+		}()
+	}
+
 	err = w.Run(ctx)
 	if err != nil {
 		log.Error("Failed to run worker", "error", err)
@@ -0,0 +1,87 @@
+package prober
+
+import (
+	"context"
+	"errors"
+	"log/slog"
+	"net"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	solanarpc "github.com/gagliardetto/solana-go/rpc"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+)
+
+type LedgerRPCClient interface {
+	GetEpochInfo(ctx context.Context, commitment solanarpc.CommitmentType) (*solanarpc.GetEpochInfoResult, error)
+}
+
+type ServiceabilityClient interface {
+	GetProgramData(context.Context) (*serviceability.ProgramData, error)
+}
+
+type TelemetryProgramClient interface {
+	InitializePathProbeSamples(ctx context.Context, config telemetry.InitializePathProbeSamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error)
+	WritePathProbeSamples(ctx context.Context, config telemetry.WritePathProbeSamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error)
+}
+
+// Conn is the subset of net.PacketConn used to exchange GRE packets with the
+// devices terminating the probed user tunnels. In production this is an
+// "ip4:gre" raw socket bound to the probe client IP.
+type Conn interface {
+	WriteTo(b []byte, addr net.Addr) (int, error)
+	ReadFrom(b []byte) (int, net.Addr, error)
+	SetReadDeadline(t time.Time) error
+}
+
+type Config struct {
+	Logger                  *slog.Logger
+	LedgerRPCClient         LedgerRPCClient
+	Serviceability          ServiceabilityClient
+	ServiceabilityProgramID solana.PublicKey
+	Telemetry               TelemetryProgramClient
+	Conn                    Conn
+
+	// ClientIP selects the user tunnels to probe through: only activated users
+	// whose client IP matches are probed.
+	ClientIP net.IP
+
+	// Interval between probe rounds; Timeout bounds how long a round waits for replies.
+	Interval time.Duration
+	Timeout  time.Duration
+}
+
+func (c *Config) Validate() error {
+	if c.Logger == nil {
+		return errors.New("logger is required")
+	}
+	if c.LedgerRPCClient == nil {
+		return errors.New("ledger rpc client is required")
+	}
+	if c.Serviceability == nil {
+		return errors.New("serviceability client is required")
+	}
+	if c.ServiceabilityProgramID.IsZero() {
+		return errors.New("serviceability program ID is required")
+	}
+	if c.Telemetry == nil {
+		return errors.New("telemetry client is required")
+	}
+	if c.Conn == nil {
+		return errors.New("conn is required")
+	}
+	if c.ClientIP.To4() == nil {
+		return errors.New("client IPv4 address is required")
+	}
+	if c.Interval <= 0 {
+		return errors.New("interval must be greater than 0")
+	}
+	if c.Timeout <= 0 {
+		return errors.New("timeout must be greater than 0")
+	}
+	if c.Timeout >= c.Interval {
+		return errors.New("timeout must be less than interval")
+	}
+	return nil
+}
@@ -0,0 +1,38 @@
+package prober
+
+import (
+	"github.com/prometheus/client_golang/prometheus"
+	"github.com/prometheus/client_golang/prometheus/promauto"
+)
+
+const (
+	MetricNameProbesSent = "doublezero_device_health_oracle_path_probes_sent_total"
+	MetricNameProbesLost = "doublezero_device_health_oracle_path_probes_lost_total"
+	MetricNameErrors     = "doublezero_device_health_oracle_path_prober_errors_total"
+
+	MetricLabelErrorType = "error_type"
+)
+
+var (
+	MetricProbesSent = promauto.NewCounter(
+		prometheus.CounterOpts{
+			Name: MetricNameProbesSent,
+			Help: "Number of synthetic path probes sent through user tunnels",
+		},
+	)
+
+	MetricProbesLost = promauto.NewCounter(
+		prometheus.CounterOpts{
+			Name: MetricNameProbesLost,
+			Help: "Number of synthetic path probes that got no reply within the timeout",
+		},
+	)
+
+	MetricErrors = promauto.NewCounterVec(
+		prometheus.CounterOpts{
+			Name: MetricNameErrors,
+			Help: "Number of errors encountered by the path prober",
+		},
+		[]string{MetricLabelErrorType},
+	)
+)
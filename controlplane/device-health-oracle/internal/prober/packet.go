@@ -0,0 +1,57 @@
+package prober
+
+import (
+	"fmt"
+	"net"
+
+	"github.com/google/gopacket"
+	"github.com/google/gopacket/layers"
+)
+
+const probeTTL = 64
+
+// buildProbe returns a GRE packet carrying an ICMP echo request from the user's
+// DZ IP to the target address. Written to the raw GRE socket, the kernel adds
+// the outer IPv4 header towards the tunnel endpoint, so the packet enters the
+// user's tunnel exactly like client traffic would.
+func buildProbe(src, dst net.IP, id, seq uint16) ([]byte, error) {
+	return buildEcho(layers.ICMPv4TypeEchoRequest, src, dst, id, seq)
+}
+
+func buildEcho(typ uint8, src, dst net.IP, id, seq uint16) ([]byte, error) {
+	gre := &layers.GRE{Protocol: layers.EthernetTypeIPv4}
+	ip := &layers.IPv4{
+		Version:  4,
+		TTL:      probeTTL,
+		Protocol: layers.IPProtocolICMPv4,
+		SrcIP:    src.To4(),
+		DstIP:    dst.To4(),
+	}
+	icmp := &layers.ICMPv4{
+		TypeCode: layers.CreateICMPv4TypeCode(typ, 0),
+		Id:       id,
+		Seq:      seq,
+	}
+
+	buf := gopacket.NewSerializeBuffer()
+	opts := gopacket.SerializeOptions{FixLengths: true, ComputeChecksums: true}
+	if err := gopacket.SerializeLayers(buf, opts, gre, ip, icmp); err != nil {
+		return nil, fmt.Errorf("failed to serialize echo: %w", err)
+	}
+	return buf.Bytes(), nil
+}
+
+// parseReply decodes a GRE packet read from the raw socket and returns the id
+// and sequence of the ICMP echo reply it carries. ok is false for anything else.
+func parseReply(data []byte) (id, seq uint16, ok bool) {
+	packet := gopacket.NewPacket(data, layers.LayerTypeGRE, gopacket.DecodeOptions{Lazy: true, NoCopy: true})
+	layer := packet.Layer(layers.LayerTypeICMPv4)
+	if layer == nil {
+		return 0, 0, false
+	}
+	icmp := layer.(*layers.ICMPv4)
+	if icmp.TypeCode.Type() != layers.ICMPv4TypeEchoReply {
+		return 0, 0, false
+	}
+	return icmp.Id, icmp.Seq, true
+}
@@ -0,0 +1,319 @@
+package prober
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+	"net"
+	"os"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	solanarpc "github.com/gagliardetto/solana-go/rpc"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+)
+
+const maxReplySize = 1500
+
+// path is one probed route: through a user's tunnel into its device, then across
+// the DZ network to the loopback of a target device.
+type path struct {
+	userPK   solana.PublicKey
+	targetPK solana.PublicKey
+	// endpoint is the device side of the user's GRE tunnel (outer destination).
+	endpoint net.IP
+	// src is the user's DZ IP and dst the target device's tunnel loopback (inner header).
+	src net.IP
+	dst net.IP
+}
+
+type seriesKey struct {
+	userPK   solana.PublicKey
+	targetPK solana.PublicKey
+	epoch    uint64
+}
+
+// series buffers the samples of a path for one epoch until they are written onchain.
+type series struct {
+	startTimestampMicroseconds uint64
+	samples                    []uint32
+}
+
+// Prober sends synthetic ICMP probes through the tunnels of selected users and
+// records round-trip times, or lost probes, to PathProbeSamples accounts.
+type Prober struct {
+	log *slog.Logger
+	cfg *Config
+
+	globalStatePK solana.PublicKey
+	id            uint16
+	seq           uint16
+	series        map[seriesKey]*series
+}
+
+func New(cfg *Config) (*Prober, error) {
+	if err := cfg.Validate(); err != nil {
+		return nil, err
+	}
+
+	globalStatePK, _, err := serviceability.GetGlobalStatePDA(cfg.ServiceabilityProgramID)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive global state PDA: %w", err)
+	}
+
+	return &Prober{
+		log:           cfg.Logger,
+		cfg:           cfg,
+		globalStatePK: globalStatePK,
+		id:            uint16(os.Getpid()),
+		series:        make(map[seriesKey]*series),
+	}, nil
+}
+
+func (p *Prober) Run(ctx context.Context) error {
+	p.log.Info("Starting path prober", "clientIP", p.cfg.ClientIP, "interval", p.cfg.Interval, "timeout", p.cfg.Timeout)
+
+	ticker := time.NewTicker(p.cfg.Interval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			p.log.Info("Shutting down path prober")
+			return nil
+		case <-ticker.C:
+			p.tick(ctx)
+		}
+	}
+}
+
+func (p *Prober) tick(ctx context.Context) {
+	epochInfo, err := p.cfg.LedgerRPCClient.GetEpochInfo(ctx, solanarpc.CommitmentFinalized)
+	if err != nil {
+		p.log.Error("Failed to get epoch info", "error", err)
+		MetricErrors.WithLabelValues("get_epoch_info").Inc()
+		return
+	}
+
+	programData, err := p.cfg.Serviceability.GetProgramData(ctx)
+	if err != nil {
+		p.log.Error("Failed to get program data", "error", err)
+		MetricErrors.WithLabelValues("get_program_data").Inc()
+		return
+	}
+
+	paths := selectPaths(programData, p.cfg.ClientIP)
+	if len(paths) == 0 {
+		p.log.Debug("No user paths to probe", "clientIP", p.cfg.ClientIP)
+	} else {
+		now := uint64(time.Now().UnixMicro())
+		rtts := p.probe(paths)
+		for i, path := range paths {
+			p.record(seriesKey{userPK: path.userPK, targetPK: path.targetPK, epoch: epochInfo.Epoch}, now, rtts[i])
+		}
+	}
+
+	p.flush(ctx, epochInfo.Epoch)
+}
+
+// selectPaths pairs every activated user connected from clientIP with every
+// other device that accepts latency probes and has a DZ prefix to address.
+func selectPaths(data *serviceability.ProgramData, clientIP net.IP) []path {
+	devices := make(map[solana.PublicKey]*serviceability.Device, len(data.Devices))
+	for i := range data.Devices {
+		devices[solana.PublicKeyFromBytes(data.Devices[i].PubKey[:])] = &data.Devices[i]
+	}
+
+	var paths []path
+	for _, user := range data.Users {
+		if user.Status != serviceability.UserStatusActivated || !net.IP(user.ClientIp[:]).Equal(clientIP) {
+			continue
+		}
+		originPK := solana.PublicKeyFromBytes(user.DevicePubKey[:])
+		origin, ok := devices[originPK]
+		if !ok {
+			continue
+		}
+
+		// 0.0.0.0 means the tunnel terminates on the device's public IP.
+		endpoint := net.IP(user.TunnelEndpoint[:])
+		if endpoint.IsUnspecified() {
+			endpoint = net.IP(origin.PublicIp[:])
+		}
+
+		for _, target := range data.Devices {
+			targetPK := solana.PublicKeyFromBytes(target.PubKey[:])
+			if targetPK == originPK || !allowProbe(target.Status) || len(target.DzPrefixes) == 0 {
+				continue
+			}
+			paths = append(paths, path{
+				userPK:   solana.PublicKeyFromBytes(user.PubKey[:]),
+				targetPK: targetPK,
+				endpoint: endpoint,
+				src:      net.IP(user.DzIp[:]),
+				dst:      tunnelLoopbackIP(target.DzPrefixes[0]),
+			})
+		}
+	}
+	return paths
+}
+
+// allowProbe mirrors the device statuses the telemetry program accepts as a
+// probe target.
+func allowProbe(status serviceability.DeviceStatus) bool {
+	switch status {
+	case serviceability.DeviceStatusActivated,
+		serviceability.DeviceStatusLinkProvisioning,
+		serviceability.DeviceStatusDeviceProvisioning,
+		serviceability.DeviceStatusDrained:
+		return true
+	}
+	return false
+}
+
+// tunnelLoopbackIP returns the first IP of a DZ prefix, which contributors
+// configure on the device loopback terminating user tunnels.
+func tunnelLoopbackIP(prefix [5]uint8) net.IP {
+	ip := net.IPv4(prefix[0], prefix[1], prefix[2], prefix[3]).To4()
+	return ip.Mask(net.CIDRMask(int(prefix[4]), 32))
+}
+
+// probe sends one echo request per path and waits up to the configured timeout
+// for the replies. It returns the round-trip time in microseconds per path, or
+// telemetry.PathProbeSampleLost when no reply arrived.
+func (p *Prober) probe(paths []path) []uint32 {
+	rtts := make([]uint32, len(paths))
+	sentAt := make([]time.Time, len(paths))
+	pending := make(map[uint16]int, len(paths))
+
+	for i, path := range paths {
+		rtts[i] = telemetry.PathProbeSampleLost
+		p.seq++
+		pkt, err := buildProbe(path.src, path.dst, p.id, p.seq)
+		if err != nil {
+			p.log.Error("Failed to build probe", "user", path.userPK, "target", path.targetPK, "error", err)
+			MetricErrors.WithLabelValues("build_probe").Inc()
+			continue
+		}
+		sentAt[i] = time.Now()
+		if _, err := p.cfg.Conn.WriteTo(pkt, &net.IPAddr{IP: path.endpoint}); err != nil {
+			p.log.Warn("Failed to send probe", "user", path.userPK, "target", path.targetPK, "endpoint", path.endpoint, "error", err)
+			MetricErrors.WithLabelValues("send_probe").Inc()
+			continue
+		}
+		pending[p.seq] = i
+		MetricProbesSent.Inc()
+	}
+
+	if err := p.cfg.Conn.SetReadDeadline(time.Now().Add(p.cfg.Timeout)); err != nil {
+		p.log.Error("Failed to set read deadline", "error", err)
+		MetricErrors.WithLabelValues("set_read_deadline").Inc()
+		pending = nil
+	}
+
+	buf := make([]byte, maxReplySize)
+	for len(pending) > 0 {
+		n, _, err := p.cfg.Conn.ReadFrom(buf)
+		if err != nil {
+			if !errors.Is(err, os.ErrDeadlineExceeded) {
+				p.log.Error("Failed to read reply", "error", err)
+				MetricErrors.WithLabelValues("read_reply").Inc()
+			}
+			break
+		}
+		id, seq, ok := parseReply(buf[:n])
+		if !ok || id != p.id {
+			continue
+		}
+		i, ok := pending[seq]
+		if !ok {
+			continue
+		}
+		delete(pending, seq)
+		// Zero is reserved for lost probes, so report sub-microsecond replies as 1µs.
+		rtts[i] = uint32(max(time.Since(sentAt[i]).Microseconds(), 1))
+	}
+
+	for _, rtt := range rtts {
+		if rtt == telemetry.PathProbeSampleLost {
+			MetricProbesLost.Inc()
+		}
+	}
+	return rtts
+}
+
+func (p *Prober) record(key seriesKey, timestampMicroseconds uint64, rtt uint32) {
+	s, ok := p.series[key]
+	if !ok {
+		s = &series{}
+		p.series[key] = s
+	}
+	if len(s.samples) >= telemetry.MaxPathProbeSamplesPerAccount {
+		return
+	}
+	if len(s.samples) == 0 {
+		s.startTimestampMicroseconds = timestampMicroseconds
+	}
+	s.samples = append(s.samples, rtt)
+}
+
+// flush writes the buffered samples of every series, initializing the epoch's
+// account on first use. Samples that fail to write stay buffered for the next
+// tick; series of past epochs are dropped once written, or after one epoch of
+// retries.
+func (p *Prober) flush(ctx context.Context, currentEpoch uint64) {
+	for key, s := range p.series {
+		initialized := false
+		for len(s.samples) > 0 {
+			batch := s.samples[:min(len(s.samples), telemetry.MaxPathProbeSamplesPerBatch)]
+			_, _, err := p.cfg.Telemetry.WritePathProbeSamples(ctx, telemetry.WritePathProbeSamplesInstructionConfig{
+				UserPK:                     key.userPK,
+				TargetDevicePK:             key.targetPK,
+				Epoch:                      key.epoch,
+				StartTimestampMicroseconds: s.startTimestampMicroseconds,
+				Samples:                    batch,
+			})
+			if errors.Is(err, telemetry.ErrAccountNotFound) && !initialized {
+				initialized = true
+				if err := p.initialize(ctx, key); err != nil {
+					p.log.Error("Failed to initialize path probe samples account", "user", key.userPK, "target", key.targetPK, "epoch", key.epoch, "error", err)
+					MetricErrors.WithLabelValues("initialize_samples").Inc()
+					break
+				}
+				continue
+			}
+			if errors.Is(err, telemetry.ErrSamplesAccountFull) {
+				p.log.Warn("Path probe samples account is full, dropping samples", "user", key.userPK, "target", key.targetPK, "epoch", key.epoch, "count", len(s.samples))
+				s.samples = nil
+				break
+			}
+			if err != nil {
+				p.log.Error("Failed to write path probe samples", "user", key.userPK, "target", key.targetPK, "epoch", key.epoch, "error", err)
+				MetricErrors.WithLabelValues("write_samples").Inc()
+				break
+			}
+			s.samples = s.samples[len(batch):]
+		}
+
+		if (len(s.samples) == 0 && key.epoch < currentEpoch) || key.epoch+1 < currentEpoch {
+			delete(p.series, key)
+		}
+	}
+}
+
+func (p *Prober) initialize(ctx context.Context, key seriesKey) error {
+	_, _, err := p.cfg.Telemetry.InitializePathProbeSamples(ctx, telemetry.InitializePathProbeSamplesInstructionConfig{
+		UserPK:                       key.userPK,
+		TargetDevicePK:               key.targetPK,
+		ServiceabilityGlobalStatePK:  p.globalStatePK,
+		Epoch:                        key.epoch,
+		SamplingIntervalMicroseconds: uint64(p.cfg.Interval.Microseconds()),
+	})
+	if err != nil {
+		return err
+	}
+	p.log.Info("Initialized path probe samples account", "user", key.userPK, "target", key.targetPK, "epoch", key.epoch)
+	return nil
+}
@@ -0,0 +1,299 @@
+package prober
+
+import (
+	"context"
+	"log/slog"
+	"net"
+	"os"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	solanarpc "github.com/gagliardetto/solana-go/rpc"
+	"github.com/google/gopacket"
+	"github.com/google/gopacket/layers"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/serviceability"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func testLogger() *slog.Logger {
+	return slog.New(slog.NewTextHandler(os.Stderr, &slog.HandlerOptions{Level: slog.LevelDebug}))
+}
+
+type mockLedger struct {
+	epoch uint64
+}
+
+func (m *mockLedger) GetEpochInfo(_ context.Context, _ solanarpc.CommitmentType) (*solanarpc.GetEpochInfoResult, error) {
+	return &solanarpc.GetEpochInfoResult{Epoch: m.epoch}, nil
+}
+
+type mockServiceability struct {
+	data *serviceability.ProgramData
+}
+
+func (m *mockServiceability) GetProgramData(_ context.Context) (*serviceability.ProgramData, error) {
+	return m.data, nil
+}
+
+// mockTelemetry emulates the onchain accounts: writes fail with ErrAccountNotFound
+// until the account is initialized, and with ErrSamplesAccountFull past capacity.
+type mockTelemetry struct {
+	capacity    int
+	initialized map[seriesKey]bool
+	samples     map[seriesKey][]uint32
+}
+
+func newMockTelemetry() *mockTelemetry {
+	return &mockTelemetry{
+		capacity:    telemetry.MaxPathProbeSamplesPerAccount,
+		initialized: make(map[seriesKey]bool),
+		samples:     make(map[seriesKey][]uint32),
+	}
+}
+
+func (m *mockTelemetry) InitializePathProbeSamples(_ context.Context, config telemetry.InitializePathProbeSamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+	m.initialized[seriesKey{userPK: config.UserPK, targetPK: config.TargetDevicePK, epoch: config.Epoch}] = true
+	return solana.Signature{}, nil, nil
+}
+
+func (m *mockTelemetry) WritePathProbeSamples(_ context.Context, config telemetry.WritePathProbeSamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+	key := seriesKey{userPK: config.UserPK, targetPK: config.TargetDevicePK, epoch: config.Epoch}
+	if !m.initialized[key] {
+		return solana.Signature{}, nil, telemetry.ErrAccountNotFound
+	}
+	if len(m.samples[key])+len(config.Samples) > m.capacity {
+		return solana.Signature{}, nil, telemetry.ErrSamplesAccountFull
+	}
+	m.samples[key] = append(m.samples[key], config.Samples...)
+	return solana.Signature{}, nil, nil
+}
+
+// mockConn answers every probe with an echo reply, except probes addressed to
+// a dropped target address.
+type mockConn struct {
+	mu      sync.Mutex
+	drop    map[string]bool
+	sent    []sentProbe
+	replies [][]byte
+}
+
+type sentProbe struct {
+	endpoint string
+	src      string
+	dst      string
+}
+
+func (c *mockConn) WriteTo(b []byte, addr net.Addr) (int, error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	packet := gopacket.NewPacket(b, layers.LayerTypeGRE, gopacket.Default)
+	ip := packet.Layer(layers.LayerTypeIPv4).(*layers.IPv4)
+	icmp := packet.Layer(layers.LayerTypeICMPv4).(*layers.ICMPv4)
+	c.sent = append(c.sent, sentProbe{endpoint: addr.String(), src: ip.SrcIP.String(), dst: ip.DstIP.String()})
+
+	if !c.drop[ip.DstIP.String()] {
+		reply, err := buildEcho(layers.ICMPv4TypeEchoReply, ip.DstIP, ip.SrcIP, icmp.Id, icmp.Seq)
+		if err != nil {
+			return 0, err
+		}
+		c.replies = append(c.replies, reply)
+	}
+	return len(b), nil
+}
+
+func (c *mockConn) ReadFrom(b []byte) (int, net.Addr, error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	if len(c.replies) == 0 {
+		return 0, nil, os.ErrDeadlineExceeded
+	}
+	n := copy(b, c.replies[0])
+	c.replies = c.replies[1:]
+	return n, &net.IPAddr{}, nil
+}
+
+func (c *mockConn) SetReadDeadline(_ time.Time) error { return nil }
+
+var (
+	originPK   = solana.NewWallet().PublicKey()
+	targetPK   = solana.NewWallet().PublicKey()
+	droppedPK  = solana.NewWallet().PublicKey()
+	deletingPK = solana.NewWallet().PublicKey()
+	userPK     = solana.NewWallet().PublicKey()
+)
+
+func testProgramData() *serviceability.ProgramData {
+	return &serviceability.ProgramData{
+		Devices: []serviceability.Device{
+			{PubKey: originPK, Status: serviceability.DeviceStatusActivated, PublicIp: [4]uint8{203, 0, 113, 1}, DzPrefixes: [][5]uint8{{100, 0, 1, 0, 29}}},
+			{PubKey: targetPK, Status: serviceability.DeviceStatusActivated, PublicIp: [4]uint8{203, 0, 113, 2}, DzPrefixes: [][5]uint8{{100, 0, 2, 0, 29}}},
+			{PubKey: droppedPK, Status: serviceability.DeviceStatusDrained, PublicIp: [4]uint8{203, 0, 113, 3}, DzPrefixes: [][5]uint8{{100, 0, 3, 0, 29}}},
+			// Not probed: deleting, and activated without a DZ prefix.
+			{PubKey: deletingPK, Status: serviceability.DeviceStatusDeleting, DzPrefixes: [][5]uint8{{100, 0, 4, 0, 29}}},
+			{PubKey: solana.NewWallet().PublicKey(), Status: serviceability.DeviceStatusActivated},
+		},
+		Users: []serviceability.User{
+			{PubKey: userPK, Status: serviceability.UserStatusActivated, DevicePubKey: originPK, ClientIp: [4]uint8{192, 0, 2, 10}, DzIp: [4]uint8{100, 0, 1, 5}},
+			// Not probed: other client IP, and not activated.
+			{PubKey: solana.NewWallet().PublicKey(), Status: serviceability.UserStatusActivated, DevicePubKey: originPK, ClientIp: [4]uint8{192, 0, 2, 11}},
+			{PubKey: solana.NewWallet().PublicKey(), Status: serviceability.UserStatusBanned, DevicePubKey: originPK, ClientIp: [4]uint8{192, 0, 2, 10}},
+		},
+	}
+}
+
+func newTestProber(t *testing.T, conn Conn, tel TelemetryProgramClient, epoch uint64) *Prober {
+	t.Helper()
+	p, err := New(&Config{
+		Logger:                  testLogger(),
+		LedgerRPCClient:         &mockLedger{epoch: epoch},
+		Serviceability:          &mockServiceability{data: testProgramData()},
+		ServiceabilityProgramID: solana.NewWallet().PublicKey(),
+		Telemetry:               tel,
+		Conn:                    conn,
+		ClientIP:                net.IPv4(192, 0, 2, 10),
+		Interval:                30 * time.Second,
+		Timeout:                 time.Second,
+	})
+	require.NoError(t, err)
+	return p
+}
+
+func TestSelectPaths(t *testing.T) {
+	paths := selectPaths(testProgramData(), net.IPv4(192, 0, 2, 10))
+	require.Len(t, paths, 2)
+
+	assert.Equal(t, targetPK, paths[0].targetPK)
+	assert.Equal(t, droppedPK, paths[1].targetPK)
+	for _, p := range paths {
+		assert.Equal(t, userPK, p.userPK)
+		// The user has no explicit tunnel endpoint, so the origin's public IP is used.
+		assert.Equal(t, "203.0.113.1", p.endpoint.String())
+		assert.Equal(t, "100.0.1.5", p.src.String())
+	}
+	assert.Equal(t, "100.0.2.0", paths[0].dst.String())
+	assert.Equal(t, "100.0.3.0", paths[1].dst.String())
+}
+
+func TestSelectPaths_TunnelEndpoint(t *testing.T) {
+	data := testProgramData()
+	data.Users[0].TunnelEndpoint = [4]uint8{203, 0, 113, 100}
+
+	paths := selectPaths(data, net.IPv4(192, 0, 2, 10))
+	require.NotEmpty(t, paths)
+	assert.Equal(t, "203.0.113.100", paths[0].endpoint.String())
+}
+
+func TestProbePacket_RoundTrip(t *testing.T) {
+	pkt, err := buildProbe(net.IPv4(100, 0, 1, 5), net.IPv4(100, 0, 2, 0), 7, 42)
+	require.NoError(t, err)
+
+	packet := gopacket.NewPacket(pkt, layers.LayerTypeGRE, gopacket.Default)
+	gre := packet.Layer(layers.LayerTypeGRE).(*layers.GRE)
+	assert.Equal(t, layers.EthernetTypeIPv4, gre.Protocol)
+	ip := packet.Layer(layers.LayerTypeIPv4).(*layers.IPv4)
+	assert.Equal(t, "100.0.1.5", ip.SrcIP.String())
+	assert.Equal(t, "100.0.2.0", ip.DstIP.String())
+
+	// Only echo replies are accepted.
+	_, _, ok := parseReply(pkt)
+	assert.False(t, ok)
+
+	reply, err := buildEcho(layers.ICMPv4TypeEchoReply, ip.DstIP, ip.SrcIP, 7, 42)
+	require.NoError(t, err)
+	id, seq, ok := parseReply(reply)
+	require.True(t, ok)
+	assert.Equal(t, uint16(7), id)
+	assert.Equal(t, uint16(42), seq)
+
+	_, _, ok = parseReply([]byte{0x00, 0x01})
+	assert.False(t, ok)
+}
+
+func TestProber_Tick_RecordsAndWritesSamples(t *testing.T) {
+	conn := &mockConn{drop: map[string]bool{"100.0.3.0": true}}
+	tel := newMockTelemetry()
+	p := newTestProber(t, conn, tel, 10)
+
+	p.tick(context.Background())
+	p.tick(context.Background())
+
+	require.Len(t, conn.sent, 4)
+	assert.Equal(t, sentProbe{endpoint: "203.0.113.1", src: "100.0.1.5", dst: "100.0.2.0"}, conn.sent[0])
+
+	reached := seriesKey{userPK: userPK, targetPK: targetPK, epoch: 10}
+	dropped := seriesKey{userPK: userPK, targetPK: droppedPK, epoch: 10}
+	assert.True(t, tel.initialized[reached])
+	assert.True(t, tel.initialized[dropped])
+
+	require.Len(t, tel.samples[reached], 2)
+	for _, rtt := range tel.samples[reached] {
+		assert.NotEqual(t, telemetry.PathProbeSampleLost, rtt)
+	}
+	assert.Equal(t, []uint32{telemetry.PathProbeSampleLost, telemetry.PathProbeSampleLost}, tel.samples[dropped])
+
+	// Everything was written, but the current epoch's series are kept.
+	assert.Len(t, p.series, 2)
+	for _, s := range p.series {
+		assert.Empty(t, s.samples)
+	}
+}
+
+func TestProber_Flush_DropsPastEpochs(t *testing.T) {
+	tel := newMockTelemetry()
+	p := newTestProber(t, &mockConn{}, tel, 10)
+
+	written := seriesKey{userPK: userPK, targetPK: targetPK, epoch: 9}
+	p.record(written, 1, 1_200)
+	p.flush(context.Background(), 10)
+	assert.Equal(t, []uint32{1_200}, tel.samples[written])
+	assert.NotContains(t, p.series, written)
+}
+
+func TestProber_Flush_AccountFullDropsSamples(t *testing.T) {
+	tel := newMockTelemetry()
+	tel.capacity = 1
+	p := newTestProber(t, &mockConn{}, tel, 10)
+
+	key := seriesKey{userPK: userPK, targetPK: targetPK, epoch: 10}
+	p.record(key, 1, 1_200)
+	p.record(key, 2, 1_300)
+	p.flush(context.Background(), 10)
+
+	assert.Empty(t, tel.samples[key])
+	assert.Empty(t, p.series[key].samples)
+}
+
+func TestConfig_Validate(t *testing.T) {
+	valid := func() *Config {
+		return &Config{
+			Logger:                  testLogger(),
+			LedgerRPCClient:         &mockLedger{},
+			Serviceability:          &mockServiceability{},
+			ServiceabilityProgramID: solana.NewWallet().PublicKey(),
+			Telemetry:               newMockTelemetry(),
+			Conn:                    &mockConn{},
+			ClientIP:                net.IPv4(192, 0, 2, 10),
+			Interval:                30 * time.Second,
+			Timeout:                 time.Second,
+		}
+	}
+	require.NoError(t, valid().Validate())
+
+	cfg := valid()
+	cfg.ClientIP = nil
+	assert.EqualError(t, cfg.Validate(), "client IPv4 address is required")
+
+	cfg = valid()
+	cfg.Timeout = cfg.Interval
+	assert.EqualError(t, cfg.Validate(), "timeout must be less than interval")
+
+	cfg = valid()
+	cfg.Conn = nil
+	assert.EqualError(t, cfg.Validate(), "conn is required")
+}
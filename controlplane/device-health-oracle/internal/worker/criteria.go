@@ -72,6 +72,11 @@ func (e *DeviceHealthEvaluator) Evaluate(ctx context.Context, device serviceabil
 		return current
 	}
 
+	// Drift is set and cleared by the controller once the running config matches again.
+	if current == serviceability.DeviceHealthDriftDetected {
+		return current
+	}
+
 	// Stage 1: Pending/Unknown → ReadyForLinks.
 	// Evaluate advances at most one stage per call, so a device needs a minimum
 	// of two ticks (two worker intervals) to go from Pending to ReadyForUsers.
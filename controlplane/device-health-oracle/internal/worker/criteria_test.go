@@ -97,6 +97,14 @@ func TestDeviceHealthEvaluator_StagesNotSkipped(t *testing.T) {
 	assert.Equal(t, serviceability.DeviceHealthReadyForLinks, result, "should advance one stage at a time")
 }
 
+func TestDeviceHealthEvaluator_DriftDetected_NotPromoted(t *testing.T) {
+	eval := &DeviceHealthEvaluator{Log: testLogger()}
+
+	device := serviceability.Device{DeviceHealth: serviceability.DeviceHealthDriftDetected}
+	result := eval.Evaluate(context.Background(), device)
+	assert.Equal(t, serviceability.DeviceHealthDriftDetected, result, "drift is cleared by the controller, not the oracle")
+}
+
 func TestDeviceHealthEvaluator_ReadyForLinks_UserCriterionFails(t *testing.T) {
 	passingCriterion := &mockDeviceCriterion{name: "links_pass", result: true}
 	failingCriterion := &mockDeviceCriterion{name: "users_fail", result: false, reason: "not ready"}
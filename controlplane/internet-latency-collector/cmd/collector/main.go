@@ -66,6 +66,7 @@ var (
 	wheresitupStateFile          string
 	ripeatlasProbesPerLocation   int
 	ripeatlasMeasurementInterval time.Duration
+	ripeatlasDailyCreditBudget   float64
 	ledgerSubmissionInterval     time.Duration
 	ledgerRPCTimeout             time.Duration
 	ledgerRPCMaxConns            int
@@ -169,6 +170,7 @@ RIPE Atlas measurements hourly, and exports RIPE Atlas results periodically.`,
 		ripeatlasCollector := ripeatlas.NewCollector(log, exporter, env, func(ctx context.Context) []collector.LocationMatch {
 			return collector.GetLocations(ctx, log, serviceabilityClient)
 		})
+		ripeatlasCollector.SetDailyCreditBudget(ripeatlasDailyCreditBudget)
 		wheresitupCollector := wheresitup.NewCollector(log, exporter, env, func(ctx context.Context) []collector.LocationMatch {
 			return collector.GetLocations(ctx, log, serviceabilityClient)
 		})
@@ -297,6 +299,7 @@ var ripeatlasCreateMeasurementsCmd = &cobra.Command{
 		ripeCollector := ripeatlas.NewCollector(log, nil, env, func(ctx context.Context) []collector.LocationMatch {
 			return collector.GetLocations(ctx, log, serviceabilityClient)
 		})
+		ripeCollector.SetDailyCreditBudget(ripeatlasDailyCreditBudget)
 
 		if err := ripeCollector.RunRipeAtlasMeasurementCreation(context.Background(), dryRun, ripeatlasProbesPerLocation, stateDir, defaultRipeAtlasSamplingInterval); err != nil {
 			log.Error("Operation failed: create_ripeatlas_measurements", slog.String("error", err.Error()))
@@ -445,10 +448,12 @@ func init() {
 	runCmd.Flags().StringVar(&wheresitupStateFile, "wheresitup-job-state-file", defaultWheresitupStateFile, "File to track processed Wheresitup job IDs (JSON format)")
 	runCmd.Flags().IntVar(&ripeatlasProbesPerLocation, "ripeatlas-probes-per-location", defaultAtlasProbesPerLocation, "Number of RIPE Atlas probes to associate with each DoubleZero location")
 	runCmd.Flags().DurationVar(&ripeatlasMeasurementInterval, "ripeatlas-measurement-interval", defaultRipeAtlasMeasurementInterval, "Interval at which to run RIPE Atlas measurements")
+	runCmd.Flags().Float64Var(&ripeatlasDailyCreditBudget, "ripeatlas-daily-credit-budget", 0, "Daily RIPE Atlas credits the measurements may be expected to use; measurements over it are not created (0 for no limit)")
 	runCmd.Flags().DurationVar(&ledgerSubmissionInterval, "ledger-submission-interval", defaultLedgerSubmissionInterval, "Interval at which to submit metrics to the ledger")
 	runCmd.Flags().StringVar(&metricsAddr, "metrics-addr", "127.0.0.1:2113", "Address to bind the metrics server to")
 
 	ripeatlasCreateMeasurementsCmd.Flags().IntVar(&ripeatlasProbesPerLocation, "probes-per-location", defaultAtlasProbesPerLocation, "Number of RIPE Atlas probes to associate with each DoubleZero location")
+	ripeatlasCreateMeasurementsCmd.Flags().Float64Var(&ripeatlasDailyCreditBudget, "daily-credit-budget", 0, "Daily RIPE Atlas credits the measurements may be expected to use; measurements over it are not created (0 for no limit)")
 
 	cobra.EnableCommandSorting = false
 
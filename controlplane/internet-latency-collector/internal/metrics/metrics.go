@@ -110,6 +110,21 @@ var (
 		Help: "Estimated daily RIPE Atlas credit usage (rough estimate, actual may vary)",
 	})
 
+	RipeatlasDailyCreditBudget = promauto.NewGauge(prometheus.GaugeOpts{
+		Name: "doublezero_internet_latency_collector_ripeatlas_daily_credit_budget",
+		Help: "Configured daily RIPE Atlas credit budget (0 when unlimited)",
+	})
+
+	RipeatlasMeasurementsOverBudgetTotal = promauto.NewCounter(prometheus.CounterOpts{
+		Name: "doublezero_internet_latency_collector_ripeatlas_measurements_over_budget_total",
+		Help: "Total number of RIPE Atlas measurements not created because they would exceed the daily credit budget",
+	})
+
+	RipeatlasAPIRetriesTotal = promauto.NewCounter(prometheus.CounterOpts{
+		Name: "doublezero_internet_latency_collector_ripeatlas_api_retries_total",
+		Help: "Total number of RIPE Atlas API requests retried after a network error, 429 or 5xx",
+	})
+
 	RipeatlasExpectedDailyResults = promauto.NewGauge(prometheus.GaugeOpts{
 		Name: "doublezero_internet_latency_collector_ripeatlas_expected_daily_results",
 		Help: "Expected daily RIPE Atlas results (constrained by 100k daily limit)",
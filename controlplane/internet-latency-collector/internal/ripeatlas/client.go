@@ -9,18 +9,34 @@ import (
 	"log/slog"
 	"net/http"
 	"os"
+	"strconv"
 	"time"
 
 	"github.com/malbeclabs/doublezero/controlplane/internet-latency-collector/internal/collector"
+	"github.com/malbeclabs/doublezero/controlplane/internet-latency-collector/internal/metrics"
 )
 
 const CallDelay = 100 * time.Millisecond
 
+const (
+	defaultMaxRetries = 3
+	maxRetryDelay     = time.Minute // caps both the exponential backoff and a server's Retry-After
+)
+
 type Client struct {
 	BaseURL    string
 	APIKey     string
 	HTTPClient collector.HTTPClient
 	log        *slog.Logger
+
+	// MaxRetries is how many times a GET that fails with a network error, a 429 or a
+	// 5xx is retried. Measurement creation and deletion are never retried, since a
+	// request that timed out may still have been applied.
+	MaxRetries int
+
+	// RetryBackoff returns the delay before the given retry, starting at 1. When nil,
+	// the delay doubles from one second. A Retry-After header takes precedence.
+	RetryBackoff func(attempt int) time.Duration
 }
 
 type Probe struct {
@@ -115,7 +131,8 @@ func NewClient(logger *slog.Logger) *Client {
 		HTTPClient: &http.Client{
 			Timeout: 30 * time.Second,
 		},
-		log: logger,
+		log:        logger,
+		MaxRetries: defaultMaxRetries,
 	}
 }
 
@@ -165,24 +182,73 @@ func (c *Client) fetchProbesWithErrorHandling(ctx context.Context, lat, lng floa
 func (c *Client) makeRequest(ctx context.Context, endpoint string) (*http.Response, error) {
 	url := c.BaseURL + endpoint
 
-	req, err := http.NewRequestWithContext(ctx, "GET", url, nil)
-	if err != nil {
-		return nil, fmt.Errorf("failed to create request: %w", err)
-	}
+	for attempt := 0; ; attempt++ {
+		req, err := http.NewRequestWithContext(ctx, "GET", url, nil)
+		if err != nil {
+			return nil, fmt.Errorf("failed to create request: %w", err)
+		}
 
-	c.setCommonHeaders(req, "")
+		c.setCommonHeaders(req, "")
 
-	resp, err := c.HTTPClient.Do(req)
-	if err != nil {
-		return nil, fmt.Errorf("failed to make request: %w", err)
+		var retryAfter time.Duration
+		resp, err := c.HTTPClient.Do(req)
+		if err != nil {
+			err = fmt.Errorf("failed to make request: %w", err)
+		} else if resp.StatusCode == http.StatusOK {
+			return resp, nil
+		} else {
+			retryAfter = parseRetryAfter(resp.Header.Get("Retry-After"))
+			resp.Body.Close()
+			err = fmt.Errorf("API request failed with status: %d", resp.StatusCode)
+			if !isRetryableStatus(resp.StatusCode) {
+				return nil, err
+			}
+		}
+
+		if attempt >= c.MaxRetries || ctx.Err() != nil {
+			return nil, err
+		}
+
+		delay := c.retryDelay(attempt+1, retryAfter)
+		c.log.Warn("Retrying RIPE Atlas API request",
+			slog.String("endpoint", endpoint),
+			slog.Int("attempt", attempt+1),
+			slog.Duration("delay", delay),
+			slog.String("error", err.Error()))
+		metrics.RipeatlasAPIRetriesTotal.Inc()
+
+		select {
+		case <-ctx.Done():
+			return nil, fmt.Errorf("%w (retry aborted: %w)", err, ctx.Err())
+		case <-time.After(delay):
+		}
 	}
+}
+
+func isRetryableStatus(statusCode int) bool {
+	return statusCode == http.StatusTooManyRequests || statusCode >= http.StatusInternalServerError
+}
 
-	if resp.StatusCode != http.StatusOK {
-		resp.Body.Close()
-		return nil, fmt.Errorf("API request failed with status: %d", resp.StatusCode)
+// parseRetryAfter reads a Retry-After header given in seconds; dates and invalid
+// values are ignored.
+func parseRetryAfter(value string) time.Duration {
+	seconds, err := strconv.Atoi(value)
+	if err != nil || seconds <= 0 {
+		return 0
 	}
+	return time.Duration(seconds) * time.Second
+}
 
-	return resp, nil
+func (c *Client) retryDelay(attempt int, retryAfter time.Duration) time.Duration {
+	delay := retryAfter
+	if delay == 0 {
+		if c.RetryBackoff != nil {
+			delay = c.RetryBackoff(attempt)
+		} else {
+			delay = time.Second << (attempt - 1)
+		}
+	}
+	return min(delay, maxRetryDelay)
 }
 
 func (c *Client) GetProbesInRadius(ctx context.Context, latitude, longitude float64, radiusKm int, anchorsOnly bool) ([]Probe, error) {
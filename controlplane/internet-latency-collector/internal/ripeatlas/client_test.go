@@ -909,6 +909,95 @@ func TestInternetLatency_RIPEAtlas_APIErrorResponses(t *testing.T) {
 	}
 }
 
+func TestInternetLatency_RIPEAtlas_MakeRequest_Retries(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name         string
+		responses    []int // status codes in order; 0 is a network error
+		wantAttempts int
+		wantErr      bool
+	}{
+		{
+			name:         "recovers from 429 and 503",
+			responses:    []int{http.StatusTooManyRequests, http.StatusServiceUnavailable, http.StatusOK},
+			wantAttempts: 3,
+		},
+		{
+			name:         "recovers from network error",
+			responses:    []int{0, http.StatusOK},
+			wantAttempts: 2,
+		},
+		{
+			name:         "gives up after max retries",
+			responses:    []int{500, 500, 500, 500, 500},
+			wantAttempts: 3,
+			wantErr:      true,
+		},
+		{
+			name:         "does not retry client errors",
+			responses:    []int{http.StatusNotFound, http.StatusOK},
+			wantAttempts: 1,
+			wantErr:      true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			attempts := 0
+			var backoffs []int
+			client := &Client{
+				log:     logger.With("test", t.Name()),
+				BaseURL: "https://atlas.ripe.net/api/v2",
+				HTTPClient: &MockHTTPClient{
+					DoFunc: func(req *http.Request) (*http.Response, error) {
+						status := tt.responses[attempts]
+						attempts++
+						if status == 0 {
+							return nil, errors.New("connection reset")
+						}
+						return &http.Response{
+							StatusCode: status,
+							Body:       io.NopCloser(strings.NewReader("{}")),
+						}, nil
+					},
+				},
+				MaxRetries: 2,
+				RetryBackoff: func(attempt int) time.Duration {
+					backoffs = append(backoffs, attempt)
+					return time.Millisecond
+				},
+			}
+
+			resp, err := client.makeRequest(t.Context(), "/test")
+			require.Equal(t, tt.wantAttempts, attempts)
+			if tt.wantErr {
+				require.Error(t, err)
+				return
+			}
+			require.NoError(t, err)
+			resp.Body.Close()
+			require.Len(t, backoffs, tt.wantAttempts-1)
+		})
+	}
+}
+
+func TestInternetLatency_RIPEAtlas_RetryDelay(t *testing.T) {
+	t.Parallel()
+
+	client := &Client{}
+	require.Equal(t, time.Second, client.retryDelay(1, 0))
+	require.Equal(t, 4*time.Second, client.retryDelay(3, 0))
+	require.Equal(t, maxRetryDelay, client.retryDelay(10, 0))
+	// Retry-After wins over the backoff, but is capped too.
+	require.Equal(t, 7*time.Second, client.retryDelay(1, parseRetryAfter("7")))
+	require.Equal(t, maxRetryDelay, client.retryDelay(1, parseRetryAfter("3600")))
+	require.Zero(t, parseRetryAfter("Wed, 21 Oct 2015 07:28:00 GMT"))
+	require.Zero(t, parseRetryAfter(""))
+}
+
 func TestInternetLatency_RIPEAtlas_GetMeasurementResultsIncremental(t *testing.T) {
 	tests := []struct {
 		name           string
@@ -63,6 +63,9 @@ type Collector struct {
 	probeToLocation  map[int]string    // Maps probe IDs to location codes
 	mu               sync.RWMutex      // Protects probeToLocation map
 	measurementState *MeasurementState // Shared state; initialized in Run()
+
+	// dailyCreditBudget caps the expected daily credit usage of our measurements; 0 is unlimited.
+	dailyCreditBudget float64
 }
 
 type MeasurementSpec struct {
@@ -88,6 +91,14 @@ func NewCollector(logger *slog.Logger, exporter exporter.Exporter, env string, g
 	}
 }
 
+// SetDailyCreditBudget caps the expected daily credit usage of the measurements the
+// collector keeps. Measurements that would take it over the budget are not created
+// until enough credits are freed; 0 leaves the usage unlimited.
+func (c *Collector) SetDailyCreditBudget(budget float64) {
+	c.dailyCreditBudget = budget
+	metrics.RipeatlasDailyCreditBudget.Set(budget)
+}
+
 func (c *Collector) InitializeCreditBalance(ctx context.Context) error {
 	balance, err := c.client.GetCreditBalance(ctx)
 	if err != nil {
@@ -1079,8 +1090,22 @@ func (c *Collector) configureMeasurements(ctx context.Context, locationMatches [
 		}
 	}
 
-	// Step 8: Create new measurements
+	// Step 8: Create new measurements, as long as they fit in the daily credit budget
+	_, committedDailyCredits, _ := expectedDailyUsage(measurementState, doubleZeroMeasurements, samplingInterval)
 	for _, spec := range toCreate {
+		dailyCredits := estimatedDailyCredits(len(spec.SourceSpecs), samplingInterval)
+		if c.dailyCreditBudget > 0 && committedDailyCredits+dailyCredits > c.dailyCreditBudget {
+			c.log.Warn("Not creating measurement: it would exceed the daily credit budget",
+				slog.String("target_location", spec.TargetLocation),
+				slog.Int("source_count", len(spec.SourceSpecs)),
+				slog.Float64("expected_daily_credits", dailyCredits),
+				slog.Float64("committed_daily_credits", committedDailyCredits),
+				slog.Float64("daily_credit_budget", c.dailyCreditBudget))
+			metrics.RipeatlasMeasurementsOverBudgetTotal.Inc()
+			continue
+		}
+		committedDailyCredits += dailyCredits
+
 		if dryRun {
 			c.log.Info("Would create measurement (dry run)",
 				slog.String("target_location", spec.TargetLocation),
@@ -1171,46 +1196,7 @@ func (c *Collector) configureMeasurements(ctx context.Context, locationMatches [
 	}
 
 	// Step 9: Calculate and update expected daily credits metric
-	// Use API-provided estimates when available
-	totalProbeCount := 0
-	expectedDailyResults := 0.0
-	expectedDailyCredits := 0.0
-
-	// Build maps for API-provided values
-	measurementAPIData := make(map[int]Measurement)
-	for _, m := range doubleZeroMeasurements {
-		measurementAPIData[m.ID] = m
-	}
-
-	allMetadata := measurementState.GetAllMetadata()
-	for id, meta := range allMetadata {
-		probeCount := len(meta.Sources)
-		totalProbeCount += probeCount
-
-		// Use API-provided estimates if available
-		if apiData, ok := measurementAPIData[id]; ok {
-			if apiData.EstimatedResultsPerDay > 0 {
-				expectedDailyResults += apiData.EstimatedResultsPerDay
-			} else {
-				// Fallback calculation if API doesn't provide estimate
-				samplesPerDay := (24 * time.Hour) / samplingInterval
-				expectedDailyResults += float64(probeCount) * float64(samplesPerDay)
-			}
-
-			if apiData.CreditsPerResult > 0 && apiData.EstimatedResultsPerDay > 0 {
-				expectedDailyCredits += apiData.CreditsPerResult * apiData.EstimatedResultsPerDay
-			} else {
-				// Fallback: assume 2 credits per result
-				samplesPerDay := (24 * time.Hour) / samplingInterval
-				expectedDailyCredits += 2.0 * float64(probeCount) * float64(samplesPerDay)
-			}
-		} else {
-			// No API data available, use fallback calculation
-			samplesPerDay := (24 * time.Hour) / samplingInterval
-			expectedDailyResults += float64(probeCount) * float64(samplesPerDay)
-			expectedDailyCredits += 2.0 * float64(probeCount) * float64(samplesPerDay)
-		}
-	}
+	expectedDailyResults, expectedDailyCredits, totalProbeCount := expectedDailyUsage(measurementState, doubleZeroMeasurements, samplingInterval)
 
 	metrics.RipeatlasExpectedDailyResults.Set(expectedDailyResults)
 	metrics.RipeatlasExpectedDailyCredits.Set(expectedDailyCredits)
@@ -1219,12 +1205,48 @@ func (c *Collector) configureMeasurements(ctx context.Context, locationMatches [
 		slog.Float64("expected_daily_results", expectedDailyResults),
 		slog.Float64("expected_daily_credits", expectedDailyCredits),
 		slog.Int("total_probe_count", totalProbeCount),
-		slog.Int("measurement_count", len(allMetadata)),
+		slog.Int("measurement_count", len(measurementState.GetAllMetadata())),
 		slog.String("source", "api_estimates_with_fallback"))
 
 	return nil
 }
 
+// expectedDailyUsage estimates the daily results and credits of the measurements in
+// measurementState, using the API's estimates when it provides them.
+func expectedDailyUsage(measurementState *MeasurementState, measurements []Measurement, samplingInterval time.Duration) (results, credits float64, probeCount int) {
+	measurementAPIData := make(map[int]Measurement)
+	for _, m := range measurements {
+		measurementAPIData[m.ID] = m
+	}
+
+	samplesPerDay := float64((24 * time.Hour) / samplingInterval)
+	for id, meta := range measurementState.GetAllMetadata() {
+		sources := len(meta.Sources)
+		probeCount += sources
+
+		apiData, ok := measurementAPIData[id]
+		if ok && apiData.EstimatedResultsPerDay > 0 {
+			results += apiData.EstimatedResultsPerDay
+		} else {
+			results += float64(sources) * samplesPerDay
+		}
+
+		if ok && apiData.CreditsPerResult > 0 && apiData.EstimatedResultsPerDay > 0 {
+			credits += apiData.CreditsPerResult * apiData.EstimatedResultsPerDay
+		} else {
+			credits += estimatedDailyCredits(sources, samplingInterval)
+		}
+	}
+	return results, credits, probeCount
+}
+
+// estimatedDailyCredits is the daily credit usage of a measurement with probeCount
+// sources before the API has estimated it, assuming 2 credits per result.
+func estimatedDailyCredits(probeCount int, samplingInterval time.Duration) float64 {
+	samplesPerDay := (24 * time.Hour) / samplingInterval
+	return 2.0 * float64(probeCount) * float64(samplesPerDay)
+}
+
 // fetchFallbackProbesForUnresponsiveLocations returns a copy of locationMatches where
 // locations that have probes but all are marked unresponsive in measurementState are
 // augmented with non-anchor Connected probes fetched from the RIPE Atlas API.
@@ -1115,6 +1115,79 @@ func TestInternetLatency_RIPEAtlas_ConfigureMeasurements_CreateNew(t *testing.T)
 	require.Equal(t, 100, measurement.Probes[0].Value) // NYC probe ID (source)
 }
 
+func TestInternetLatency_RIPEAtlas_ConfigureMeasurements_DailyCreditBudget(t *testing.T) {
+	t.Parallel()
+
+	locationMatches := []LocationProbeMatch{
+		{
+			LocationMatch: collector.LocationMatch{
+				LocationCode: "nyc",
+				Latitude:     40.7128,
+				Longitude:    -74.0060,
+			},
+			NearbyProbes: []Probe{
+				{ID: 100, Address: "1.1.1.1", Latitude: 40.7128, Longitude: -74.0060},
+			},
+			ProbeCount: 1,
+		},
+		{
+			LocationMatch: collector.LocationMatch{
+				LocationCode: "lon",
+				Latitude:     51.5074,
+				Longitude:    -0.1278,
+			},
+			NearbyProbes: []Probe{
+				{ID: 200, Address: "2.2.2.2", Latitude: 51.5074, Longitude: -0.1278},
+			},
+			ProbeCount: 1,
+		},
+	}
+
+	// The single nyc -> lon measurement samples once a minute from one source,
+	// which is expected to use 2 * 1440 = 2880 credits a day.
+	tests := []struct {
+		name        string
+		budget      float64
+		wantCreated int
+	}{
+		{name: "unlimited", budget: 0, wantCreated: 1},
+		{name: "within budget", budget: 2880, wantCreated: 1},
+		{name: "over budget", budget: 2879, wantCreated: 0},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			var created []MeasurementRequest
+			var mu sync.Mutex
+			mockClient := &MockClient{
+				GetAllMeasurementsFunc: func(ctx context.Context, env string) ([]Measurement, error) {
+					return []Measurement{}, nil
+				},
+				CreateMeasurementFunc: func(ctx context.Context, request MeasurementRequest) (*MeasurementResponse, error) {
+					mu.Lock()
+					defer mu.Unlock()
+					created = append(created, request)
+					return &MeasurementResponse{Measurements: []int{2000 + len(created)}}, nil
+				},
+			}
+
+			c := &Collector{client: mockClient, log: logger.With("test", t.Name()), getLocationsFunc: func(ctx context.Context) []collector.LocationMatch {
+				return []collector.LocationMatch{}
+			}}
+			c.SetDailyCreditBudget(tt.budget)
+
+			err := c.configureMeasurements(t.Context(), locationMatches, false, 1, t.TempDir(), 1*time.Minute)
+			require.NoError(t, err)
+
+			mu.Lock()
+			defer mu.Unlock()
+			require.Len(t, created, tt.wantCreated)
+		})
+	}
+}
+
 func TestInternetLatency_RIPEAtlas_ConfigureMeasurements_RemoveUnwanted(t *testing.T) {
 	t.Parallel()
 
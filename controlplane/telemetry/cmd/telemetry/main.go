@@ -54,6 +54,8 @@ const (
 	defaultBGPStatusInterval          = 60 * time.Second
 	defaultBGPStatusRefreshInterval   = 6 * time.Hour
 	defaultCachingFetcherRPCTimeout   = 30 * time.Second
+	defaultWALMaxAge                  = 24 * time.Hour
+	defaultWALMaxBytes                = 64 << 20
 
 	waitForNamespaceTimeout             = 30 * time.Second
 	defaultStateIngestHTTPClientTimeout = 10 * time.Second
@@ -96,6 +98,11 @@ var (
 	// caching fetcher flags
 	cachingFetcherRPCTimeout = flag.Duration("caching-fetcher-rpc-timeout", defaultCachingFetcherRPCTimeout, "Timeout for GetProgramData RPC calls inside the caching fetcher.")
 
+	// sample wal flags
+	walDir      = flag.String("wal-dir", "", "Directory of the disk-backed WAL that buffers samples while ledger writes fail. Disabled if empty.")
+	walMaxAge   = flag.Duration("wal-max-age", defaultWALMaxAge, "Maximum age of samples kept in the WAL before they are dropped.")
+	walMaxBytes = flag.Int64("wal-max-bytes", defaultWALMaxBytes, "Maximum size of the WAL on disk; the oldest samples are dropped first when reached.")
+
 	// bgp status submitter flags
 	bgpStatusEnable          = flag.Bool("bgp-status-enable", false, "Enable onchain BGP status submission after each collection tick.")
 	bgpStatusInterval        = flag.Duration("bgp-status-interval", defaultBGPStatusInterval, "Interval between BGP status collection ticks.")
@@ -348,6 +355,9 @@ func main() {
 		GeolocationClient:          geolocationClient,
 		AgentVersion:               version,
 		AgentCommit:                commit,
+		WALDir:                     *walDir,
+		WALMaxAge:                  *walMaxAge,
+		WALMaxBytes:                *walMaxBytes,
 	})
 	if err != nil {
 		log.Error("failed to create telemetry collector", "error", err)
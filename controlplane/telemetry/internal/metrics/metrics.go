@@ -10,6 +10,10 @@ const (
 	MetricNameBuildInfo                        = "doublezero_device_telemetry_agent_build_info"
 	MetricNameErrors                           = "doublezero_device_telemetry_agent_errors_total"
 	MetricNamePeerDiscoveryLocalTunnelNotFound = "doublezero_device_telemetry_agent_peer_discovery_not_found_tunnels"
+	MetricNameDroppedSamples                   = "doublezero_device_telemetry_agent_dropped_samples_total"
+	MetricNameWALReplayedSamples               = "doublezero_device_telemetry_agent_wal_replayed_samples_total"
+	MetricNameWALSamples                       = "doublezero_device_telemetry_agent_wal_samples"
+	MetricNameWALBytes                         = "doublezero_device_telemetry_agent_wal_bytes"
 
 	// Labels.
 	LabelVersion       = "version"
@@ -17,6 +21,7 @@ const (
 	LabelDate          = "date"
 	LabelErrorType     = "error_type"
 	LabelLocalDevicePK = "local_device_pk"
+	LabelReason        = "reason"
 
 	// Error types.
 	ErrorTypeCollectorSubmitSamplesOnClose       = "collector_submit_samples_on_close"
@@ -27,6 +32,15 @@ const (
 	ErrorTypeSubmitterFailedToInitializeAccount  = "submitter_failed_to_initialize_account"
 	ErrorTypeSubmitterFailedToWriteSamples       = "submitter_failed_to_write_samples"
 	ErrorTypeSubmitterRetriesExhausted           = "submitter_retries_exhausted"
+	ErrorTypeWALAppend                           = "wal_append"
+	ErrorTypeWALCorruptEntry                     = "wal_corrupt_entry"
+
+	// Dropped sample reasons.
+	DroppedSamplesReasonBufferFull  = "buffer_full"
+	DroppedSamplesReasonAccountFull = "account_full"
+	DroppedSamplesReasonWALMaxAge   = "wal_max_age"
+	DroppedSamplesReasonWALMaxSize  = "wal_max_size"
+	DroppedSamplesReasonWALWrite    = "wal_write_failed"
 )
 
 var (
@@ -53,4 +67,33 @@ var (
 		},
 		[]string{LabelLocalDevicePK},
 	)
+
+	DroppedSamples = promauto.NewCounterVec(
+		prometheus.CounterOpts{
+			Name: MetricNameDroppedSamples,
+			Help: "Number of samples dropped without being written to the ledger",
+		},
+		[]string{LabelReason},
+	)
+
+	WALReplayedSamples = promauto.NewCounter(
+		prometheus.CounterOpts{
+			Name: MetricNameWALReplayedSamples,
+			Help: "Number of samples written to the ledger from the WAL",
+		},
+	)
+
+	WALSamples = promauto.NewGauge(
+		prometheus.GaugeOpts{
+			Name: MetricNameWALSamples,
+			Help: "Number of samples waiting in the WAL",
+		},
+	)
+
+	WALBytes = promauto.NewGauge(
+		prometheus.GaugeOpts{
+			Name: MetricNameWALBytes,
+			Help: "Size of the WAL on disk in bytes",
+		},
+	)
 )
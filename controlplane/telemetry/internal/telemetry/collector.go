@@ -55,6 +55,20 @@ func New(log *slog.Logger, cfg Config) (*Collector, error) {
 		buffer:    buffer,
 	}
 
+	var wal *WAL
+	if cfg.WALDir != "" {
+		var err error
+		wal, err = OpenWAL(log, &WALConfig{
+			Dir:      cfg.WALDir,
+			MaxAge:   cfg.WALMaxAge,
+			MaxBytes: cfg.WALMaxBytes,
+			NowFunc:  cfg.NowFunc,
+		})
+		if err != nil {
+			return nil, fmt.Errorf("failed to open wal: %w", err)
+		}
+	}
+
 	var err error
 	c.submitter, err = NewSubmitter(log, &SubmitterConfig{
 		Interval:           cfg.SubmissionInterval,
@@ -66,6 +80,7 @@ func New(log *slog.Logger, cfg Config) (*Collector, error) {
 		MaxConcurrency:     cfg.SubmitterMaxConcurrency,
 		AgentVersion:       cfg.AgentVersion,
 		AgentCommit:        cfg.AgentCommit,
+		WAL:                wal,
 	})
 	if err != nil {
 		return nil, fmt.Errorf("failed to create submitter: %w", err)
@@ -74,6 +74,18 @@ type Config struct {
 
 	// AgentCommit is the short git commit hash of this telemetry agent binary.
 	AgentCommit string
+
+	// WALDir is the directory of the disk-backed WAL that buffers samples while ledger writes
+	// fail. The WAL is disabled when empty.
+	WALDir string
+
+	// WALMaxAge is how long samples are kept in the WAL before they are dropped.
+	// Defaults to 24h.
+	WALMaxAge time.Duration
+
+	// WALMaxBytes is the maximum size of the WAL on disk. The oldest samples are dropped
+	// first when it is reached. Defaults to 64 MiB.
+	WALMaxBytes int64
 }
 
 func (c *Config) Validate() error {
@@ -129,6 +141,13 @@ func (c *Config) Validate() error {
 		}
 	}
 
+	if c.WALMaxAge < 0 {
+		return errors.New("wal max age must not be negative")
+	}
+	if c.WALMaxBytes < 0 {
+		return errors.New("wal max bytes must not be negative")
+	}
+
 	if c.GeolocationClient != nil && c.ProbeDiscoveryInterval <= 0 {
 		c.ProbeDiscoveryInterval = 60 * time.Second
 	}
@@ -59,6 +59,14 @@ func (m *mockTelemetryProgramClient) WriteDeviceLatencySamples(ctx context.Conte
 	return solana.Signature{}, nil, nil
 }
 
+func (m *mockTelemetryProgramClient) GetDeviceLatencySamplesHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*telemetryprog.DeviceLatencySamplesHeader, error) {
+	return nil, telemetryprog.ErrAccountNotFound
+}
+
+func (m *mockTelemetryProgramClient) GetDeviceLatencySamplesShardHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64, shardIndex uint8) (*telemetryprog.DeviceLatencySamplesHeader, error) {
+	return nil, telemetryprog.ErrAccountNotFound
+}
+
 func validBaseConfig(keypair solana.PrivateKey) Config {
 	return Config{
 		TWAMPReflector:          &mockReflector{},
@@ -25,4 +25,10 @@ type TelemetryProgramClient interface {
 
 	// WriteDeviceLatencySamples writes the device latency samples to the account.
 	WriteDeviceLatencySamples(ctx context.Context, config telemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error)
+
+	// GetDeviceLatencySamplesHeader returns the header of the device latency samples account.
+	GetDeviceLatencySamplesHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*telemetry.DeviceLatencySamplesHeader, error)
+
+	// GetDeviceLatencySamplesShardHeader returns the header of a shard of the device latency samples account.
+	GetDeviceLatencySamplesShardHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64, shardIndex uint8) (*telemetry.DeviceLatencySamplesHeader, error)
 }
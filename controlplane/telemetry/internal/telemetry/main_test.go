@@ -77,6 +77,9 @@ type mockTelemetryProgramClient struct {
 	InitializeDeviceLatencySamplesFunc func(ctx context.Context, config sdktelemetry.InitializeDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error)
 	WriteDeviceLatencySamplesFunc      func(ctx context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error)
 	GetDeviceLatencySamplesFunc        func(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*sdktelemetry.DeviceLatencySamples, error)
+	GetDeviceLatencySamplesHeaderFunc  func(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*sdktelemetry.DeviceLatencySamplesHeader, error)
+
+	GetDeviceLatencySamplesShardHeaderFunc func(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64, shardIndex uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error)
 }
 
 func (c *mockTelemetryProgramClient) InitializeDeviceLatencySamples(ctx context.Context, config sdktelemetry.InitializeDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
@@ -91,6 +94,14 @@ func (c *mockTelemetryProgramClient) GetDeviceLatencySamples(ctx context.Context
 	return c.GetDeviceLatencySamplesFunc(ctx, originDevicePK, targetDevicePK, linkPK, epoch)
 }
 
+func (c *mockTelemetryProgramClient) GetDeviceLatencySamplesHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+	return c.GetDeviceLatencySamplesHeaderFunc(ctx, originDevicePK, targetDevicePK, linkPK, epoch)
+}
+
+func (c *mockTelemetryProgramClient) GetDeviceLatencySamplesShardHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64, shardIndex uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+	return c.GetDeviceLatencySamplesShardHeaderFunc(ctx, originDevicePK, targetDevicePK, linkPK, epoch, shardIndex)
+}
+
 type memoryTelemetryProgramClient struct {
 	accounts map[telemetry.PartitionKey][]telemetry.Sample
 
@@ -147,6 +158,39 @@ func (c *memoryTelemetryProgramClient) WriteDeviceLatencySamples(ctx context.Con
 	return solana.Signature{}, nil, nil
 }
 
+func (c *memoryTelemetryProgramClient) GetDeviceLatencySamplesHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+	c.mu.RLock()
+	defer c.mu.RUnlock()
+
+	partitionKey := telemetry.PartitionKey{
+		OriginDevicePK: originDevicePK,
+		TargetDevicePK: targetDevicePK,
+		LinkPK:         linkPK,
+		Epoch:          epoch,
+	}
+
+	samples, ok := c.accounts[partitionKey]
+	if !ok {
+		return nil, sdktelemetry.ErrAccountNotFound
+	}
+
+	return &sdktelemetry.DeviceLatencySamplesHeader{
+		Epoch:           epoch,
+		OriginDevicePK:  originDevicePK,
+		TargetDevicePK:  targetDevicePK,
+		LinkPK:          linkPK,
+		NextSampleIndex: uint32(len(samples)),
+	}, nil
+}
+
+func (c *memoryTelemetryProgramClient) GetDeviceLatencySamplesShardHeader(ctx context.Context, originDevicePK solana.PublicKey, targetDevicePK solana.PublicKey, linkPK solana.PublicKey, epoch uint64, shardIndex uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+	// Accounts here never fill up, so there is only ever one shard.
+	if shardIndex > 0 {
+		return nil, sdktelemetry.ErrAccountNotFound
+	}
+	return c.GetDeviceLatencySamplesHeader(ctx, originDevicePK, targetDevicePK, linkPK, epoch)
+}
+
 func (c *memoryTelemetryProgramClient) GetAccounts(t *testing.T) map[telemetry.PartitionKey][]telemetry.Sample {
 	c.mu.RLock()
 	defer c.mu.RUnlock()
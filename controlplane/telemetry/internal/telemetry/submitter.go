@@ -5,6 +5,7 @@ import (
 	"errors"
 	"fmt"
 	"log/slog"
+	"math"
 	"math/rand"
 	"sync"
 	"time"
@@ -33,15 +34,23 @@ type SubmitterConfig struct {
 	GetCurrentEpoch    func(ctx context.Context) (uint64, error)
 	AgentVersion       string
 	AgentCommit        string
+
+	// WAL is optional. When set, samples that fail all attempts are appended to it instead of
+	// being requeued in the buffer, and replayed ahead of newer samples once writes succeed.
+	WAL *WAL
 }
 
 // Submitter periodically flushes collected telemetry samples from the sample
 // buffer and submits them to the on-chain telemetry program. It includes retry
-// logic with jittered exponential backoff for robustness.
+// logic with jittered exponential backoff for robustness, and spills samples to
+// an optional disk-backed WAL when the ledger stays unreachable.
 type Submitter struct {
 	log *slog.Logger
 	cfg *SubmitterConfig
 	rng *rand.Rand
+
+	shardsMu sync.Mutex
+	shards   map[PartitionKey]shardState
 }
 
 func NewSubmitter(log *slog.Logger, cfg *SubmitterConfig) (*Submitter, error) {
@@ -56,9 +65,10 @@ func NewSubmitter(log *slog.Logger, cfg *SubmitterConfig) (*Submitter, error) {
 	}
 	rng := rand.New(rand.NewSource(time.Now().UnixNano()))
 	return &Submitter{
-		log: log,
-		cfg: cfg,
-		rng: rng,
+		log:    log,
+		cfg:    cfg,
+		rng:    rng,
+		shards: make(map[PartitionKey]shardState),
 	}, nil
 }
 
@@ -92,8 +102,13 @@ func (s *Submitter) SubmitSamples(ctx context.Context, partitionKey PartitionKey
 		return nil
 	}
 
-	for i := 0; i < len(samples); i += telemetry.MaxDeviceLatencySamplesPerBatch {
-		end := min(i+telemetry.MaxDeviceLatencySamplesPerBatch, len(samples))
+	for i := 0; i < len(samples); {
+		shard := s.shard(partitionKey)
+		batchSize := telemetry.MaxDeviceLatencySamplesPerBatch
+		if shard.rollOver {
+			batchSize = telemetry.MaxDeviceLatencySamplesPerRollOverBatch
+		}
+		end := min(i+batchSize, len(samples))
 		batch := samples[i:end]
 
 		rtts := make([]uint32, len(batch))
@@ -126,54 +141,138 @@ func (s *Submitter) SubmitSamples(ctx context.Context, partitionKey PartitionKey
 			Samples:                    rtts,
 			AgentVersion:               s.cfg.AgentVersion,
 			AgentCommit:                s.cfg.AgentCommit,
+			ShardIndex:                 shard.index,
+			RollOver:                   shard.rollOver,
 		}
 
 		_, _, err := s.cfg.ProgramClient.WriteDeviceLatencySamples(ctx, writeConfig)
-		if err != nil {
-			if errors.Is(err, telemetry.ErrAccountNotFound) {
-				log.Info("Account not found, initializing new account")
-				_, _, err = s.cfg.ProgramClient.InitializeDeviceLatencySamples(ctx, telemetry.InitializeDeviceLatencySamplesInstructionConfig{
-					AgentPK:                      s.cfg.MetricsPublisherPK,
-					OriginDevicePK:               partitionKey.OriginDevicePK,
-					TargetDevicePK:               partitionKey.TargetDevicePK,
-					LinkPK:                       partitionKey.LinkPK,
-					Epoch:                        &partitionKey.Epoch,
-					SamplingIntervalMicroseconds: uint64(s.cfg.ProbeInterval.Microseconds()),
-					AgentVersion:                 s.cfg.AgentVersion,
-					AgentCommit:                  s.cfg.AgentCommit,
-				})
-				if err != nil {
-					metrics.Errors.WithLabelValues(metrics.ErrorTypeSubmitterFailedToInitializeAccount).Inc()
-					return fmt.Errorf("failed to initialize device latency samples: %w", err)
-				}
-				_, _, err = s.cfg.ProgramClient.WriteDeviceLatencySamples(ctx, writeConfig)
-				if err != nil {
-					if errors.Is(err, telemetry.ErrSamplesAccountFull) {
-						log.Warn("Partition account is full, dropping samples from buffer and moving on", "droppedSamples", len(samples))
-						s.cfg.Buffer.Remove(partitionKey)
-						return nil
-					}
-					metrics.Errors.WithLabelValues(metrics.ErrorTypeSubmitterFailedToWriteSamples).Inc()
-					return fmt.Errorf("failed to write device latency samples after init: %w", err)
+		if errors.Is(err, telemetry.ErrAccountNotFound) && shard.index == 0 {
+			log.Info("Account not found, initializing new account")
+			_, _, err = s.cfg.ProgramClient.InitializeDeviceLatencySamples(ctx, telemetry.InitializeDeviceLatencySamplesInstructionConfig{
+				AgentPK:                      s.cfg.MetricsPublisherPK,
+				OriginDevicePK:               partitionKey.OriginDevicePK,
+				TargetDevicePK:               partitionKey.TargetDevicePK,
+				LinkPK:                       partitionKey.LinkPK,
+				Epoch:                        &partitionKey.Epoch,
+				SamplingIntervalMicroseconds: uint64(s.cfg.ProbeInterval.Microseconds()),
+				AgentVersion:                 s.cfg.AgentVersion,
+				AgentCommit:                  s.cfg.AgentCommit,
+			})
+			if err != nil {
+				metrics.Errors.WithLabelValues(metrics.ErrorTypeSubmitterFailedToInitializeAccount).Inc()
+				return fmt.Errorf("failed to initialize device latency samples: %w", err)
+			}
+			_, _, err = s.cfg.ProgramClient.WriteDeviceLatencySamples(ctx, writeConfig)
+		}
+		if errors.Is(err, telemetry.ErrSamplesAccountFull) {
+			// The shard can't take the batch; retry it wherever the partition's writes go next.
+			if err := s.advanceShard(ctx, partitionKey, shard); err != nil {
+				if errors.Is(err, errNoShardsLeft) {
+					log.Warn("Partition has no shards left, dropping samples from buffer and moving on", "droppedSamples", len(samples)-i)
+					recordDroppedSamples(metrics.DroppedSamplesReasonAccountFull, len(samples)-i)
+					s.cfg.Buffer.Remove(partitionKey)
+					s.forgetShard(partitionKey)
+					return nil
 				}
-			} else if errors.Is(err, telemetry.ErrSamplesAccountFull) {
-				log.Warn("Partition account is full, dropping samples from buffer and moving on", "droppedSamples", len(samples))
-				s.cfg.Buffer.Remove(partitionKey)
-				return nil
-			} else {
 				metrics.Errors.WithLabelValues(metrics.ErrorTypeSubmitterFailedToWriteSamples).Inc()
-				return fmt.Errorf("failed to write device latency samples: %w", err)
+				return err
 			}
+			continue
+		}
+		if err != nil {
+			metrics.Errors.WithLabelValues(metrics.ErrorTypeSubmitterFailedToWriteSamples).Inc()
+			return fmt.Errorf("failed to write device latency samples: %w", err)
 		}
 
-		log.Debug("Submitted account samples batch", "count", len(samples), "samples", rtts)
+		if shard.rollOver {
+			s.wroteRollOver(partitionKey, shard, len(batch))
+		}
+		log.Debug("Submitted account samples batch", "count", len(batch), "shard", shard.index, "samples", rtts)
+		i = end
 	}
 
 	return nil
 }
 
+// shardState is where a partition's samples are written. Once a shard can't take a whole
+// batch, writes also pass the next shard's account, until one of them overflows into it.
+type shardState struct {
+	index    uint8
+	rollOver bool
+
+	// room is how many more samples the shard takes, known once rollOver is set.
+	room int
+}
+
+// errNoShardsLeft is returned when a partition's last shard is full.
+var errNoShardsLeft = errors.New("no device latency samples shards left")
+
+func (s *Submitter) shard(partitionKey PartitionKey) shardState {
+	s.shardsMu.Lock()
+	defer s.shardsMu.Unlock()
+	return s.shards[partitionKey]
+}
+
+func (s *Submitter) setShard(partitionKey PartitionKey, shard shardState) {
+	s.shardsMu.Lock()
+	defer s.shardsMu.Unlock()
+	s.shards[partitionKey] = shard
+}
+
+func (s *Submitter) forgetShard(partitionKey PartitionKey) {
+	s.shardsMu.Lock()
+	defer s.shardsMu.Unlock()
+	delete(s.shards, partitionKey)
+}
+
+// advanceShard moves a partition on from a shard that reported being full: to the next shard
+// if writes already rolled over to it, which is the case when the agent restarts, or else to
+// writing the shard along with the next one so that samples that don't fit start it.
+func (s *Submitter) advanceShard(ctx context.Context, partitionKey PartitionKey, shard shardState) error {
+	hdr, err := s.cfg.ProgramClient.GetDeviceLatencySamplesShardHeader(ctx, partitionKey.OriginDevicePK, partitionKey.TargetDevicePK, partitionKey.LinkPK, partitionKey.Epoch, shard.index)
+	if err != nil {
+		return fmt.Errorf("failed to get device latency samples shard header: %w", err)
+	}
+
+	switch {
+	case hdr.IsRolledOver() && shard.index < math.MaxUint8:
+		s.setShard(partitionKey, shardState{index: shard.index + 1})
+	case hdr.IsRolledOver() || shard.rollOver || shard.index == math.MaxUint8:
+		return errNoShardsLeft
+	default:
+		s.setShard(partitionKey, shardState{
+			index:    shard.index,
+			rollOver: true,
+			room:     telemetry.MaxDeviceLatencySamplesPerAccount - int(hdr.NextSampleIndex),
+		})
+	}
+	return nil
+}
+
+// wroteRollOver records a write that passed the next shard's account. A batch larger than the
+// shard's remaining room overflowed into the next shard, where writes continue.
+func (s *Submitter) wroteRollOver(partitionKey PartitionKey, shard shardState, count int) {
+	if count > shard.room {
+		s.setShard(partitionKey, shardState{index: shard.index + 1})
+		return
+	}
+	shard.room -= count
+	s.setShard(partitionKey, shard)
+}
+
 func (s *Submitter) Tick(ctx context.Context) {
 	partitions := s.cfg.Buffer.FlushWithoutReset()
+	if s.cfg.WAL != nil {
+		s.cfg.WAL.EvictExpired()
+		for _, partitionKey := range s.cfg.WAL.Partitions() {
+			if _, ok := partitions[partitionKey]; !ok {
+				if partitions == nil {
+					partitions = make(map[PartitionKey][]Sample)
+				}
+				partitions[partitionKey] = nil
+			}
+		}
+	}
 	if len(partitions) == 0 {
 		return
 	}
@@ -190,6 +289,17 @@ func (s *Submitter) Tick(ctx context.Context) {
 
 			log := s.log.With("partition", partitionKey)
 
+			// Samples in the WAL are older than anything in the buffer, so they have to land
+			// first. While they can't be written, newer samples queue up behind them on disk.
+			if s.cfg.WAL != nil && s.cfg.WAL.Has(partitionKey) {
+				if err := s.replayWAL(ctx, partitionKey); err != nil {
+					log.Debug("WAL replay failed, appending samples behind it", "count", len(tmp), "error", err)
+					s.appendToWAL(log, partitionKey, tmp)
+					s.cfg.Buffer.Recycle(partitionKey, tmp)
+					return
+				}
+			}
+
 			log.Debug("Submitting samples", "count", len(tmp))
 
 			if len(tmp) == 0 {
@@ -204,6 +314,7 @@ func (s *Submitter) Tick(ctx context.Context) {
 				}
 				if partitionKey.Epoch < epoch {
 					s.cfg.Buffer.Remove(partitionKey)
+					s.forgetShard(partitionKey)
 					log.Debug("Removed account key")
 				}
 				return
@@ -243,12 +354,19 @@ func (s *Submitter) Tick(ctx context.Context) {
 				}
 			}
 
-			// If submission failed and the buffer is not at capacity, prepend the samples back to the
-			// buffer. If the buffer is at capacity and we have failed all attempts, don't prepend the
-			// samples back to the buffer.
-			overCapacity := s.cfg.Buffer.Len(partitionKey)+len(tmp) >= s.cfg.Buffer.Capacity(partitionKey)
-			if !success && !overCapacity {
-				s.cfg.Buffer.PriorityPrepend(partitionKey, tmp)
+			// If submission failed and a WAL is configured, spill the samples to it. Otherwise, if
+			// the buffer is not at capacity, prepend the samples back to the buffer. If the buffer is
+			// at capacity and we have failed all attempts, don't prepend the samples back to the buffer.
+			if !success {
+				overCapacity := s.cfg.Buffer.Len(partitionKey)+len(tmp) >= s.cfg.Buffer.Capacity(partitionKey)
+				switch {
+				case s.cfg.WAL != nil:
+					s.appendToWAL(log, partitionKey, tmp)
+				case !overCapacity:
+					s.cfg.Buffer.PriorityPrepend(partitionKey, tmp)
+				default:
+					recordDroppedSamples(metrics.DroppedSamplesReasonBufferFull, len(tmp))
+				}
 			}
 
 			// Always recycle the slice for reuse
@@ -259,6 +377,75 @@ func (s *Submitter) Tick(ctx context.Context) {
 	wg.Wait()
 }
 
+// replayWAL writes the partition's WAL entries to the ledger, oldest first, stopping at the
+// first entry that can't be written. The number of samples in the partition's shards is read
+// before replaying and after a failed write, so an entry whose write landed despite the error
+// is not written twice.
+func (s *Submitter) replayWAL(ctx context.Context, partitionKey PartitionKey) error {
+	log := s.log.With("partition", partitionKey)
+
+	nextSampleIndex, err := s.getNextSampleIndex(ctx, partitionKey)
+	if err != nil {
+		return err
+	}
+
+	for {
+		seq, samples, ok, err := s.cfg.WAL.Next(partitionKey)
+		if err != nil {
+			return err
+		}
+		if !ok {
+			return nil
+		}
+
+		if err := s.SubmitSamples(ctx, partitionKey, samples); err != nil {
+			after, herr := s.getNextSampleIndex(ctx, partitionKey)
+			if herr != nil || after < nextSampleIndex+uint64(len(samples)) {
+				return err
+			}
+			log.Debug("WAL entry write reported an error but landed", "count", len(samples), "error", err)
+		}
+
+		log.Debug("Replayed WAL entry", "count", len(samples))
+		metrics.WALReplayedSamples.Add(float64(len(samples)))
+		nextSampleIndex += uint64(len(samples))
+		s.cfg.WAL.Remove(seq)
+	}
+}
+
+// getNextSampleIndex returns the sum of next_sample_index over the partition's shards, or 0 if
+// the account does not exist yet.
+func (s *Submitter) getNextSampleIndex(ctx context.Context, partitionKey PartitionKey) (uint64, error) {
+	var total uint64
+	for shardIndex := 0; shardIndex <= math.MaxUint8; shardIndex++ {
+		hdr, err := s.cfg.ProgramClient.GetDeviceLatencySamplesShardHeader(ctx, partitionKey.OriginDevicePK, partitionKey.TargetDevicePK, partitionKey.LinkPK, partitionKey.Epoch, uint8(shardIndex))
+		if err != nil {
+			if errors.Is(err, telemetry.ErrAccountNotFound) {
+				return total, nil
+			}
+			return 0, fmt.Errorf("failed to get device latency samples header: %w", err)
+		}
+		total += uint64(hdr.NextSampleIndex)
+		if !hdr.IsRolledOver() {
+			break
+		}
+	}
+	return total, nil
+}
+
+func (s *Submitter) appendToWAL(log *slog.Logger, partitionKey PartitionKey, samples []Sample) {
+	if len(samples) == 0 {
+		return
+	}
+	if err := s.cfg.WAL.Append(partitionKey, samples); err != nil {
+		metrics.Errors.WithLabelValues(metrics.ErrorTypeWALAppend).Inc()
+		log.Error("Failed to append samples to WAL, dropping them", "count", len(samples), "error", err)
+		recordDroppedSamples(metrics.DroppedSamplesReasonWALWrite, len(samples))
+		return
+	}
+	log.Debug("Appended samples to WAL", "count", len(samples))
+}
+
 func (s *Submitter) defaultBackoff(attempt int) time.Duration {
 	base := 500 * time.Millisecond
 	max := 5 * time.Second
@@ -605,7 +605,7 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 		assert.False(t, submissionCalled, "should skip submission if GetCurrentEpoch fails")
 	})
 
-	t.Run("drops_samples_if_account_full", func(t *testing.T) {
+	t.Run("drops_samples_if_account_cannot_roll_over", func(t *testing.T) {
 		t.Parallel()
 
 		log := log.With("test", t.Name())
@@ -617,11 +617,18 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 			Loss:      false,
 		}
 
-		// This client always returns ErrSamplesAccountFull
+		// This client always returns ErrSamplesAccountFull, even when passed the next shard.
+		var rollOverAttempted atomic.Bool
 		telemetryProgram := &mockTelemetryProgramClient{
 			WriteDeviceLatencySamplesFunc: func(ctx context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				if config.RollOver {
+					rollOverAttempted.Store(true)
+				}
 				return solana.Signature{}, nil, sdktelemetry.ErrSamplesAccountFull
 			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(context.Context, solana.PublicKey, solana.PublicKey, solana.PublicKey, uint64, uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				return &sdktelemetry.DeviceLatencySamplesHeader{NextSampleIndex: sdktelemetry.MaxDeviceLatencySamplesPerAccount}, nil
+			},
 		}
 
 		buffer := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
@@ -644,9 +651,10 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 
 		samplesAfter := buffer.CopyAndReset(key)
 		assert.Len(t, samplesAfter, 0, "samples should be dropped on account full")
+		assert.True(t, rollOverAttempted.Load(), "should try rolling over to the next shard before dropping")
 	})
 
-	t.Run("initializes_then_drops_samples_if_account_full", func(t *testing.T) {
+	t.Run("initializes_then_drops_samples_if_account_cannot_roll_over", func(t *testing.T) {
 		t.Parallel()
 
 		log := log.With("test", t.Name())
@@ -670,6 +678,9 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 				atomic.StoreInt32(&initCalled, 1)
 				return solana.Signature{}, nil, nil
 			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(context.Context, solana.PublicKey, solana.PublicKey, solana.PublicKey, uint64, uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				return &sdktelemetry.DeviceLatencySamplesHeader{}, nil
+			},
 		}
 
 		buffer := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
@@ -693,7 +704,135 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 		samplesAfter := buffer.CopyAndReset(key)
 		assert.Len(t, samplesAfter, 0, "samples should be dropped after account init + full")
 		assert.Equal(t, int32(1), atomic.LoadInt32(&initCalled), "should initialize account before dropping")
-		assert.Equal(t, int32(2), atomic.LoadInt32(&writeCalled), "should try write twice (before and after init)")
+		assert.Equal(t, int32(3), atomic.LoadInt32(&writeCalled), "should try write before and after init, then with the next shard")
+	})
+
+	t.Run("rolls_over_to_next_shard_when_account_full", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+
+		// Shard 0 has room for one more sample; the program fills it up and starts shard 1
+		// with the rest when passed the next shard.
+		var mu sync.Mutex
+		shards := map[uint8][]uint32{0: make([]uint32, sdktelemetry.MaxDeviceLatencySamplesPerAccount-1)}
+		rolledOver := map[uint8]bool{}
+		var writes []sdktelemetry.WriteDeviceLatencySamplesInstructionConfig
+		telemetryProgram := &mockTelemetryProgramClient{
+			WriteDeviceLatencySamplesFunc: func(_ context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				writes = append(writes, config)
+				room := sdktelemetry.MaxDeviceLatencySamplesPerAccount - len(shards[config.ShardIndex])
+				if len(config.Samples) <= room {
+					shards[config.ShardIndex] = append(shards[config.ShardIndex], config.Samples...)
+					return solana.Signature{}, nil, nil
+				}
+				if !config.RollOver || rolledOver[config.ShardIndex] {
+					return solana.Signature{}, nil, sdktelemetry.ErrSamplesAccountFull
+				}
+				shards[config.ShardIndex] = append(shards[config.ShardIndex], config.Samples[:room]...)
+				rolledOver[config.ShardIndex] = true
+				shards[config.ShardIndex+1] = append([]uint32{}, config.Samples[room:]...)
+				return solana.Signature{}, nil, nil
+			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(_ context.Context, _, _, _ solana.PublicKey, _ uint64, shardIndex uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				samples, ok := shards[shardIndex]
+				if !ok {
+					return nil, sdktelemetry.ErrAccountNotFound
+				}
+				hdr := &sdktelemetry.DeviceLatencySamplesHeader{ShardIndex: shardIndex, NextSampleIndex: uint32(len(samples))}
+				if rolledOver[shardIndex] {
+					hdr.Flags = sdktelemetry.SamplesFlagRolledOver
+				}
+				return hdr, nil
+			},
+		}
+
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		submitter, err := telemetry.NewSubmitter(log, &telemetry.SubmitterConfig{
+			Interval:        time.Hour,
+			Buffer:          buf,
+			ProgramClient:   telemetryProgram,
+			MaxAttempts:     1,
+			MaxConcurrency:  10,
+			BackoffFunc:     func(_ int) time.Duration { return 0 },
+			GetCurrentEpoch: func(context.Context) (uint64, error) { return 100, nil },
+		})
+		require.NoError(t, err)
+
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 1 * time.Microsecond})
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 2 * time.Microsecond})
+		submitter.Tick(context.Background())
+
+		// Later ticks write straight to the new shard.
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 3 * time.Microsecond})
+		submitter.Tick(context.Background())
+
+		mu.Lock()
+		defer mu.Unlock()
+		assert.Equal(t, uint32(1), shards[0][len(shards[0])-1])
+		assert.True(t, rolledOver[0])
+		assert.Equal(t, []uint32{2, 3}, shards[1])
+		require.Len(t, writes, 3)
+		assert.False(t, writes[0].RollOver)
+		assert.True(t, writes[1].RollOver)
+		assert.Equal(t, uint8(0), writes[1].ShardIndex)
+		assert.False(t, writes[2].RollOver)
+		assert.Equal(t, uint8(1), writes[2].ShardIndex)
+		assert.Empty(t, buf.Read(key))
+	})
+
+	t.Run("follows_shards_that_already_rolled_over", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+
+		// Shards 0 and 1 were filled up before the agent started; shard 2 has room.
+		var mu sync.Mutex
+		var written []uint32
+		var shardIndexes []uint8
+		telemetryProgram := &mockTelemetryProgramClient{
+			WriteDeviceLatencySamplesFunc: func(_ context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				shardIndexes = append(shardIndexes, config.ShardIndex)
+				if config.ShardIndex < 2 {
+					return solana.Signature{}, nil, sdktelemetry.ErrSamplesAccountFull
+				}
+				written = append(written, config.Samples...)
+				return solana.Signature{}, nil, nil
+			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(_ context.Context, _, _, _ solana.PublicKey, _ uint64, shardIndex uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				return &sdktelemetry.DeviceLatencySamplesHeader{
+					ShardIndex:      shardIndex,
+					NextSampleIndex: sdktelemetry.MaxDeviceLatencySamplesPerAccount,
+					Flags:           sdktelemetry.SamplesFlagRolledOver,
+				}, nil
+			},
+		}
+
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		submitter, err := telemetry.NewSubmitter(log, &telemetry.SubmitterConfig{
+			Interval:        time.Hour,
+			Buffer:          buf,
+			ProgramClient:   telemetryProgram,
+			MaxAttempts:     1,
+			MaxConcurrency:  10,
+			BackoffFunc:     func(_ int) time.Duration { return 0 },
+			GetCurrentEpoch: func(context.Context) (uint64, error) { return 100, nil },
+		})
+		require.NoError(t, err)
+
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 7 * time.Microsecond})
+		submitter.Tick(context.Background())
+
+		mu.Lock()
+		defer mu.Unlock()
+		assert.Equal(t, []uint32{7}, written)
+		assert.Equal(t, []uint8{0, 1, 2}, shardIndexes)
 	})
 
 	t.Run("failed_retries_reinsert_at_front_preserving_order", func(t *testing.T) {
@@ -880,3 +1019,159 @@ func TestAgentTelemetry_Submitter(t *testing.T) {
 	})
 
 }
+
+func TestAgentTelemetry_Submitter_WAL(t *testing.T) {
+	t.Parallel()
+
+	newSubmitter := func(t *testing.T, buf buffer.PartitionedBuffer[telemetry.PartitionKey, telemetry.Sample], prog telemetry.TelemetryProgramClient, wal *telemetry.WAL) *telemetry.Submitter {
+		s, err := telemetry.NewSubmitter(log, &telemetry.SubmitterConfig{
+			Interval:        time.Hour,
+			Buffer:          buf,
+			ProgramClient:   prog,
+			MaxAttempts:     1,
+			MaxConcurrency:  10,
+			BackoffFunc:     func(int) time.Duration { return 0 },
+			GetCurrentEpoch: func(context.Context) (uint64, error) { return 42, nil },
+			WAL:             wal,
+		})
+		require.NoError(t, err)
+		return s
+	}
+
+	t.Run("spills_failed_samples_and_replays_them_first", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+		var down atomic.Bool
+		down.Store(true)
+
+		var mu sync.Mutex
+		var written []uint32
+		prog := &mockTelemetryProgramClient{
+			WriteDeviceLatencySamplesFunc: func(_ context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				if down.Load() {
+					return solana.Signature{}, nil, errors.New("rpc unavailable")
+				}
+				mu.Lock()
+				defer mu.Unlock()
+				written = append(written, config.Samples...)
+				return solana.Signature{}, nil, nil
+			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(context.Context, solana.PublicKey, solana.PublicKey, solana.PublicKey, uint64, uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				if down.Load() {
+					return nil, errors.New("rpc unavailable")
+				}
+				mu.Lock()
+				defer mu.Unlock()
+				return &sdktelemetry.DeviceLatencySamplesHeader{NextSampleIndex: uint32(len(written))}, nil
+			},
+		}
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir()})
+		require.NoError(t, err)
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		s := newSubmitter(t, buf, prog, wal)
+
+		// While the ledger is down, samples end up in the WAL rather than the buffer.
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 1 * time.Microsecond})
+		s.Tick(context.Background())
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 2 * time.Microsecond})
+		s.Tick(context.Background())
+		assert.True(t, wal.Has(key))
+		assert.Empty(t, buf.Read(key))
+
+		// Once it's back, the WAL is replayed ahead of the newest buffered sample.
+		down.Store(false)
+		buf.Add(key, telemetry.Sample{Timestamp: time.Now(), RTT: 3 * time.Microsecond})
+		s.Tick(context.Background())
+
+		mu.Lock()
+		defer mu.Unlock()
+		assert.Equal(t, []uint32{1, 2, 3}, written)
+		assert.False(t, wal.Has(key))
+	})
+
+	t.Run("replays_wal_only_partitions", func(t *testing.T) {
+		t.Parallel()
+
+		mem := newMemoryTelemetryProgramClient()
+		key := newTestPartitionKey()
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir()})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(key, []telemetry.Sample{newTestSample(), newTestSample()}))
+
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		newSubmitter(t, buf, mem, wal).Tick(context.Background())
+
+		assert.Len(t, mem.GetSamples(t, key), 2)
+		assert.False(t, wal.Has(key))
+	})
+
+	t.Run("does_not_rewrite_entry_that_landed_despite_error", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+		var mu sync.Mutex
+		var written int
+		var writes int
+		prog := &mockTelemetryProgramClient{
+			WriteDeviceLatencySamplesFunc: func(_ context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				writes++
+				written += len(config.Samples)
+				return solana.Signature{}, nil, errors.New("confirmation timed out")
+			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(context.Context, solana.PublicKey, solana.PublicKey, solana.PublicKey, uint64, uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				mu.Lock()
+				defer mu.Unlock()
+				return &sdktelemetry.DeviceLatencySamplesHeader{NextSampleIndex: uint32(written)}, nil
+			},
+		}
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir()})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(key, []telemetry.Sample{newTestSample()}))
+
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		s := newSubmitter(t, buf, prog, wal)
+		s.Tick(context.Background())
+		s.Tick(context.Background())
+
+		mu.Lock()
+		defer mu.Unlock()
+		assert.Equal(t, 1, writes)
+		assert.Equal(t, 1, written)
+		assert.False(t, wal.Has(key))
+	})
+
+	t.Run("rolls_wal_entries_over_to_next_shard", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+		var rollOvers atomic.Int32
+		prog := &mockTelemetryProgramClient{
+			WriteDeviceLatencySamplesFunc: func(_ context.Context, config sdktelemetry.WriteDeviceLatencySamplesInstructionConfig) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+				if !config.RollOver {
+					return solana.Signature{}, nil, sdktelemetry.ErrSamplesAccountFull
+				}
+				rollOvers.Add(1)
+				return solana.Signature{}, nil, nil
+			},
+			GetDeviceLatencySamplesShardHeaderFunc: func(context.Context, solana.PublicKey, solana.PublicKey, solana.PublicKey, uint64, uint8) (*sdktelemetry.DeviceLatencySamplesHeader, error) {
+				return &sdktelemetry.DeviceLatencySamplesHeader{NextSampleIndex: sdktelemetry.MaxDeviceLatencySamplesPerAccount}, nil
+			},
+		}
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir()})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(key, []telemetry.Sample{newTestSample()}))
+
+		buf := buffer.NewMemoryPartitionedBuffer[telemetry.PartitionKey, telemetry.Sample](1024)
+		newSubmitter(t, buf, prog, wal).Tick(context.Background())
+
+		assert.Equal(t, int32(1), rollOvers.Load())
+		assert.False(t, wal.Has(key))
+	})
+}
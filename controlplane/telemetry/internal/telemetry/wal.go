@@ -0,0 +1,318 @@
+package telemetry
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log/slog"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/malbeclabs/doublezero/controlplane/telemetry/internal/metrics"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+)
+
+const (
+	defaultWALMaxAge   = 24 * time.Hour
+	defaultWALMaxBytes = 64 << 20 // 64 MiB
+
+	walEntryExt = ".json"
+)
+
+type WALConfig struct {
+	// Dir is the directory the WAL entries are stored in. It is created if missing.
+	Dir string
+
+	// MaxAge is how long an entry is kept before it is evicted unsubmitted.
+	MaxAge time.Duration
+
+	// MaxBytes is the maximum total size of the entries on disk. The oldest entries are
+	// evicted first when an append would exceed it.
+	MaxBytes int64
+
+	// NowFunc is the function to get the current time.
+	NowFunc func() time.Time
+}
+
+// WAL is a disk-backed write-ahead log of samples the submitter failed to write to the
+// ledger. Each entry holds at most one write's worth of samples for a single partition, so
+// replaying an entry maps to exactly one WriteDeviceLatencySamples call and its effect on the
+// account's next_sample_index can be checked. Entries survive agent restarts and are replayed
+// in the order they were appended.
+type WAL struct {
+	log *slog.Logger
+	cfg *WALConfig
+
+	mu        sync.Mutex
+	entries   []*walEntryMeta
+	nextSeq   uint64
+	totalSize int64
+}
+
+type walEntryMeta struct {
+	seq         uint64
+	partition   PartitionKey
+	appendedAt  time.Time
+	size        int64
+	sampleCount int
+}
+
+type walEntry struct {
+	Partition  PartitionKey `json:"partition"`
+	AppendedAt time.Time    `json:"appended_at"`
+	Samples    []Sample     `json:"samples"`
+}
+
+// OpenWAL opens the WAL in cfg.Dir, loading any entries left by a previous run. Entries that
+// cannot be decoded are removed.
+func OpenWAL(log *slog.Logger, cfg *WALConfig) (*WAL, error) {
+	if cfg.Dir == "" {
+		return nil, errors.New("wal dir is required")
+	}
+	if cfg.MaxAge <= 0 {
+		cfg.MaxAge = defaultWALMaxAge
+	}
+	if cfg.MaxBytes <= 0 {
+		cfg.MaxBytes = defaultWALMaxBytes
+	}
+	if cfg.NowFunc == nil {
+		cfg.NowFunc = func() time.Time {
+			return time.Now().UTC()
+		}
+	}
+	if err := os.MkdirAll(cfg.Dir, 0o700); err != nil {
+		return nil, fmt.Errorf("failed to create wal dir: %w", err)
+	}
+
+	w := &WAL{
+		log: log,
+		cfg: cfg,
+	}
+
+	files, err := os.ReadDir(cfg.Dir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read wal dir: %w", err)
+	}
+	for _, f := range files {
+		name := f.Name()
+		if f.IsDir() || !strings.HasSuffix(name, walEntryExt) {
+			continue
+		}
+		path := filepath.Join(cfg.Dir, name)
+		seq, err := strconv.ParseUint(strings.TrimSuffix(name, walEntryExt), 10, 64)
+		if err != nil {
+			continue
+		}
+		entry, size, err := readWALEntry(path)
+		if err != nil {
+			log.Warn("Removing unreadable WAL entry", "path", path, "error", err)
+			metrics.Errors.WithLabelValues(metrics.ErrorTypeWALCorruptEntry).Inc()
+			_ = os.Remove(path)
+			continue
+		}
+		w.entries = append(w.entries, &walEntryMeta{
+			seq:         seq,
+			partition:   entry.Partition,
+			appendedAt:  entry.AppendedAt,
+			size:        size,
+			sampleCount: len(entry.Samples),
+		})
+		w.totalSize += size
+		if seq >= w.nextSeq {
+			w.nextSeq = seq + 1
+		}
+	}
+	sort.Slice(w.entries, func(i, j int) bool { return w.entries[i].seq < w.entries[j].seq })
+	w.updateGauges()
+
+	if len(w.entries) > 0 {
+		log.Info("Loaded WAL entries", "dir", cfg.Dir, "entries", len(w.entries), "bytes", w.totalSize)
+	}
+
+	return w, nil
+}
+
+// Append writes samples for the given partition to the WAL, split into entries of at most
+// one write each. The oldest entries are evicted if the WAL would exceed its size limit.
+func (w *WAL) Append(partition PartitionKey, samples []Sample) error {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	for i := 0; i < len(samples); i += telemetry.MaxDeviceLatencySamplesPerBatch {
+		end := min(i+telemetry.MaxDeviceLatencySamplesPerBatch, len(samples))
+		entry := walEntry{
+			Partition:  partition,
+			AppendedAt: w.cfg.NowFunc(),
+			Samples:    samples[i:end],
+		}
+		data, err := json.Marshal(entry)
+		if err != nil {
+			return fmt.Errorf("failed to encode wal entry: %w", err)
+		}
+		size := int64(len(data))
+		if size > w.cfg.MaxBytes {
+			recordDroppedSamples(metrics.DroppedSamplesReasonWALMaxSize, len(entry.Samples))
+			continue
+		}
+		for len(w.entries) > 0 && w.totalSize+size > w.cfg.MaxBytes {
+			oldest := w.entries[0]
+			w.log.Warn("WAL full, evicting oldest entry", "partition", oldest.partition, "droppedSamples", oldest.sampleCount)
+			w.removeLocked(oldest)
+			recordDroppedSamples(metrics.DroppedSamplesReasonWALMaxSize, oldest.sampleCount)
+		}
+
+		seq := w.nextSeq
+		path := w.path(seq)
+		tmp := path + ".tmp"
+		if err := os.WriteFile(tmp, data, 0o600); err != nil {
+			return fmt.Errorf("failed to write wal entry: %w", err)
+		}
+		if err := os.Rename(tmp, path); err != nil {
+			_ = os.Remove(tmp)
+			return fmt.Errorf("failed to commit wal entry: %w", err)
+		}
+		w.nextSeq++
+		w.entries = append(w.entries, &walEntryMeta{
+			seq:         seq,
+			partition:   partition,
+			appendedAt:  entry.AppendedAt,
+			size:        size,
+			sampleCount: len(entry.Samples),
+		})
+		w.totalSize += size
+	}
+	w.updateGauges()
+
+	return nil
+}
+
+// EvictExpired removes entries older than the configured max age.
+func (w *WAL) EvictExpired() {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	cutoff := w.cfg.NowFunc().Add(-w.cfg.MaxAge)
+	for _, e := range append([]*walEntryMeta(nil), w.entries...) {
+		if e.appendedAt.Before(cutoff) {
+			w.log.Warn("Evicting expired WAL entry", "partition", e.partition, "appendedAt", e.appendedAt, "droppedSamples", e.sampleCount)
+			w.removeLocked(e)
+			recordDroppedSamples(metrics.DroppedSamplesReasonWALMaxAge, e.sampleCount)
+		}
+	}
+	w.updateGauges()
+}
+
+// Partitions returns the partitions that have entries in the WAL.
+func (w *WAL) Partitions() []PartitionKey {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	seen := make(map[PartitionKey]struct{})
+	var keys []PartitionKey
+	for _, e := range w.entries {
+		if _, ok := seen[e.partition]; ok {
+			continue
+		}
+		seen[e.partition] = struct{}{}
+		keys = append(keys, e.partition)
+	}
+	return keys
+}
+
+// Has returns whether the partition has entries in the WAL.
+func (w *WAL) Has(partition PartitionKey) bool {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	for _, e := range w.entries {
+		if e.partition == partition {
+			return true
+		}
+	}
+	return false
+}
+
+// Next returns the sequence number and samples of the oldest entry for the partition, or
+// ok=false if there is none.
+func (w *WAL) Next(partition PartitionKey) (seq uint64, samples []Sample, ok bool, err error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	for _, e := range w.entries {
+		if e.partition != partition {
+			continue
+		}
+		entry, _, err := readWALEntry(w.path(e.seq))
+		if err != nil {
+			w.log.Warn("Removing unreadable WAL entry", "seq", e.seq, "error", err)
+			metrics.Errors.WithLabelValues(metrics.ErrorTypeWALCorruptEntry).Inc()
+			w.removeLocked(e)
+			w.updateGauges()
+			return 0, nil, false, fmt.Errorf("failed to read wal entry %d: %w", e.seq, err)
+		}
+		return e.seq, entry.Samples, true, nil
+	}
+	return 0, nil, false, nil
+}
+
+// Remove deletes the entry with the given sequence number, once it has been written to the
+// ledger or dropped.
+func (w *WAL) Remove(seq uint64) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	for _, e := range w.entries {
+		if e.seq == seq {
+			w.removeLocked(e)
+			break
+		}
+	}
+	w.updateGauges()
+}
+
+func (w *WAL) removeLocked(target *walEntryMeta) {
+	for i, e := range w.entries {
+		if e == target {
+			w.entries = append(w.entries[:i], w.entries[i+1:]...)
+			w.totalSize -= e.size
+			if err := os.Remove(w.path(e.seq)); err != nil && !errors.Is(err, os.ErrNotExist) {
+				w.log.Warn("Failed to remove WAL entry file", "seq", e.seq, "error", err)
+			}
+			return
+		}
+	}
+}
+
+func (w *WAL) updateGauges() {
+	samples := 0
+	for _, e := range w.entries {
+		samples += e.sampleCount
+	}
+	metrics.WALSamples.Set(float64(samples))
+	metrics.WALBytes.Set(float64(w.totalSize))
+}
+
+func (w *WAL) path(seq uint64) string {
+	return filepath.Join(w.cfg.Dir, fmt.Sprintf("%020d%s", seq, walEntryExt))
+}
+
+func readWALEntry(path string) (*walEntry, int64, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, 0, err
+	}
+	var entry walEntry
+	if err := json.Unmarshal(data, &entry); err != nil {
+		return nil, 0, err
+	}
+	return &entry, int64(len(data)), nil
+}
+
+func recordDroppedSamples(reason string, count int) {
+	metrics.DroppedSamples.WithLabelValues(reason).Add(float64(count))
+}
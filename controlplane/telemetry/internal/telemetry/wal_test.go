@@ -0,0 +1,136 @@
+package telemetry_test
+
+import (
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	"github.com/malbeclabs/doublezero/controlplane/telemetry/internal/telemetry"
+	sdktelemetry "github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestAgentTelemetry_WAL(t *testing.T) {
+	t.Parallel()
+
+	t.Run("entries_survive_reopen_in_order", func(t *testing.T) {
+		t.Parallel()
+
+		dir := t.TempDir()
+		key := newTestPartitionKey()
+		first := []telemetry.Sample{{Timestamp: time.Unix(1, 0).UTC(), RTT: time.Millisecond}}
+		second := []telemetry.Sample{{Timestamp: time.Unix(2, 0).UTC(), Loss: true}}
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: dir})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(key, first))
+		require.NoError(t, wal.Append(key, second))
+
+		wal, err = telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: dir})
+		require.NoError(t, err)
+		require.Equal(t, []telemetry.PartitionKey{key}, wal.Partitions())
+
+		seq, samples, ok, err := wal.Next(key)
+		require.NoError(t, err)
+		require.True(t, ok)
+		assert.Equal(t, first, samples)
+		wal.Remove(seq)
+
+		seq, samples, ok, err = wal.Next(key)
+		require.NoError(t, err)
+		require.True(t, ok)
+		assert.Equal(t, second, samples)
+		wal.Remove(seq)
+
+		assert.False(t, wal.Has(key))
+		files, err := os.ReadDir(dir)
+		require.NoError(t, err)
+		assert.Empty(t, files)
+	})
+
+	t.Run("splits_appends_into_write_sized_entries", func(t *testing.T) {
+		t.Parallel()
+
+		key := newTestPartitionKey()
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir()})
+		require.NoError(t, err)
+
+		samples := make([]telemetry.Sample, sdktelemetry.MaxDeviceLatencySamplesPerBatch+1)
+		require.NoError(t, wal.Append(key, samples))
+
+		seq, got, ok, err := wal.Next(key)
+		require.NoError(t, err)
+		require.True(t, ok)
+		assert.Len(t, got, sdktelemetry.MaxDeviceLatencySamplesPerBatch)
+		wal.Remove(seq)
+
+		_, got, ok, err = wal.Next(key)
+		require.NoError(t, err)
+		require.True(t, ok)
+		assert.Len(t, got, 1)
+	})
+
+	t.Run("evicts_oldest_entries_over_max_bytes", func(t *testing.T) {
+		t.Parallel()
+
+		oldKey := newTestPartitionKey()
+		newKey := telemetry.PartitionKey{OriginDevicePK: solana.PublicKey{4}, TargetDevicePK: solana.PublicKey{5}, LinkPK: solana.PublicKey{6}, Epoch: 42}
+		samples := []telemetry.Sample{{Timestamp: time.Unix(1, 0).UTC(), RTT: time.Millisecond}}
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: t.TempDir(), MaxBytes: 400})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(oldKey, samples))
+		require.NoError(t, wal.Append(newKey, samples))
+
+		assert.False(t, wal.Has(oldKey))
+		assert.True(t, wal.Has(newKey))
+	})
+
+	t.Run("evicts_entries_older_than_max_age", func(t *testing.T) {
+		t.Parallel()
+
+		var mu sync.Mutex
+		now := time.Unix(1_700_000_000, 0).UTC()
+		key := newTestPartitionKey()
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{
+			Dir:    t.TempDir(),
+			MaxAge: time.Hour,
+			NowFunc: func() time.Time {
+				mu.Lock()
+				defer mu.Unlock()
+				return now
+			},
+		})
+		require.NoError(t, err)
+		require.NoError(t, wal.Append(key, []telemetry.Sample{newTestSample()}))
+
+		wal.EvictExpired()
+		assert.True(t, wal.Has(key))
+
+		mu.Lock()
+		now = now.Add(2 * time.Hour)
+		mu.Unlock()
+
+		wal.EvictExpired()
+		assert.False(t, wal.Has(key))
+	})
+
+	t.Run("removes_unreadable_entries_on_open", func(t *testing.T) {
+		t.Parallel()
+
+		dir := t.TempDir()
+		path := filepath.Join(dir, "00000000000000000007.json")
+		require.NoError(t, os.WriteFile(path, []byte("{not json"), 0o600))
+
+		wal, err := telemetry.OpenWAL(log, &telemetry.WALConfig{Dir: dir})
+		require.NoError(t, err)
+		assert.Empty(t, wal.Partitions())
+		_, err = os.Stat(path)
+		assert.True(t, os.IsNotExist(err))
+	})
+}
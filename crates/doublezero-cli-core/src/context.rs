@@ -72,6 +72,11 @@ pub struct CliContext {
     /// Daemon Unix socket path, if provided.
     pub daemon_socket_path: Option<PathBuf>,
 
+    /// Per-user config directory (`~/.config/doublezero`), if the binary could
+    /// resolve one. Modules keep local state such as the latency history under
+    /// it instead of locating the home directory themselves.
+    pub config_dir: Option<PathBuf>,
+
     /// Default output-format hint.
     pub output_format: OutputFormat,
 
@@ -97,6 +102,7 @@ pub struct CliContextBuilder {
     telemetry_program_id: Option<Pubkey>,
     keypair_path: Option<PathBuf>,
     daemon_socket_path: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
     output_format: OutputFormat,
     client_version: Option<String>,
 }
@@ -151,6 +157,11 @@ impl CliContextBuilder {
         self
     }
 
+    pub fn with_config_dir(mut self, path: PathBuf) -> Self {
+        self.config_dir = Some(path);
+        self
+    }
+
     pub fn with_output_format(mut self, format: OutputFormat) -> Self {
         self.output_format = format;
         self
@@ -202,6 +213,7 @@ impl CliContextBuilder {
                 .unwrap_or(config.telemetry_program_id),
             keypair_path: self.keypair_path,
             daemon_socket_path: self.daemon_socket_path,
+            config_dir: self.config_dir,
             output_format: self.output_format,
             client_version: self.client_version.unwrap_or_default(),
         })
@@ -237,6 +249,7 @@ impl CliContextBuilder {
             telemetry_program_id,
             keypair_path: self.keypair_path,
             daemon_socket_path: self.daemon_socket_path,
+            config_dir: self.config_dir,
             output_format: self.output_format,
             client_version: self.client_version.unwrap_or_default(),
         })
@@ -0,0 +1,312 @@
+//! Machine-readable description of a CLI command tree.
+//!
+//! `doublezero schema dump` walks the clap [`Command`] of the binary and
+//! emits every command with its flags, plus the record type each verb prints
+//! where the module crate registered one. Doc generators and third-party
+//! wrappers consume the JSON instead of scraping `--help` text, so they stay
+//! in sync with the binary they were generated from.
+
+use std::collections::BTreeMap;
+
+use clap::{Arg, ArgAction, Command};
+use serde::Serialize;
+use tabled::Tabled;
+
+/// Bumped whenever the shape of the dump changes incompatibly.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CliSchema {
+    pub schema_version: u32,
+    pub name: String,
+    pub version: Option<String>,
+    pub args: Vec<ArgSchema>,
+    pub commands: Vec<CommandSchema>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CommandSchema {
+    pub name: String,
+    /// Space-separated invocation below the binary name, e.g. `device list`.
+    pub path: String,
+    pub about: Option<String>,
+    pub hidden: bool,
+    pub args: Vec<ArgSchema>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputSchema>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<CommandSchema>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ArgSchema {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short: Option<char>,
+    pub help: Option<String>,
+    /// `flag` for switches, `value` for options and positionals taking values.
+    pub kind: ArgKind,
+    pub positional: bool,
+    pub required: bool,
+    pub multiple: bool,
+    pub global: bool,
+    pub hidden: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub value_names: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub default_values: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub possible_values: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgKind {
+    Flag,
+    Value,
+}
+
+/// What a verb prints: one record (`get`) or a collection of them (`list`),
+/// rendered through [`crate::render_record`] / [`crate::render_collection`].
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct OutputSchema {
+    pub shape: OutputShape,
+    /// Name of the display type, for cross-referencing the source.
+    pub record: String,
+    /// Table columns in order. `--json` output carries the same fields under
+    /// these names, plus any the table hides.
+    pub columns: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputShape {
+    Record,
+    Collection,
+}
+
+impl OutputSchema {
+    pub fn record<T: Tabled>() -> Self {
+        Self::new::<T>(OutputShape::Record)
+    }
+
+    pub fn collection<T: Tabled>() -> Self {
+        Self::new::<T>(OutputShape::Collection)
+    }
+
+    fn new<T: Tabled>(shape: OutputShape) -> Self {
+        let type_name = std::any::type_name::<T>();
+        Self {
+            shape,
+            record: type_name
+                .rsplit("::")
+                .next()
+                .unwrap_or(type_name)
+                .to_string(),
+            columns: T::headers().into_iter().map(|h| h.to_string()).collect(),
+        }
+    }
+}
+
+/// Output schemas keyed by command path (`device list`).
+pub type OutputSchemas = BTreeMap<String, OutputSchema>;
+
+/// Describe `command` and everything below it. The implicit `help` command
+/// and `--help`/`--version` flags are left out.
+pub fn cli_schema(command: &Command, outputs: &OutputSchemas) -> CliSchema {
+    CliSchema {
+        schema_version: SCHEMA_VERSION,
+        name: command.get_name().to_string(),
+        version: command.get_version().map(str::to_string),
+        args: args_schema(command),
+        commands: subcommands_schema(command, "", outputs),
+    }
+}
+
+fn subcommands_schema(
+    command: &Command,
+    prefix: &str,
+    outputs: &OutputSchemas,
+) -> Vec<CommandSchema> {
+    command
+        .get_subcommands()
+        .filter(|sub| sub.get_name() != "help")
+        .map(|sub| {
+            let path = if prefix.is_empty() {
+                sub.get_name().to_string()
+            } else {
+                format!("{prefix} {}", sub.get_name())
+            };
+            CommandSchema {
+                name: sub.get_name().to_string(),
+                about: sub.get_about().map(|about| about.to_string()),
+                hidden: sub.is_hide_set(),
+                args: args_schema(sub),
+                output: outputs.get(&path).cloned(),
+                commands: subcommands_schema(sub, &path, outputs),
+                path,
+            }
+        })
+        .collect()
+}
+
+fn args_schema(command: &Command) -> Vec<ArgSchema> {
+    command
+        .get_arguments()
+        .filter(|arg| !matches!(arg.get_action(), ArgAction::Help | ArgAction::Version))
+        .map(arg_schema)
+        .collect()
+}
+
+fn arg_schema(arg: &Arg) -> ArgSchema {
+    let takes_value = arg.get_action().takes_values();
+    ArgSchema {
+        name: arg.get_id().to_string(),
+        long: arg.get_long().map(str::to_string),
+        short: arg.get_short(),
+        help: arg.get_help().map(|help| help.to_string()),
+        kind: if takes_value {
+            ArgKind::Value
+        } else {
+            ArgKind::Flag
+        },
+        positional: arg.is_positional(),
+        required: arg.is_required_set(),
+        multiple: matches!(arg.get_action(), ArgAction::Append | ArgAction::Count)
+            || arg
+                .get_num_args()
+                .is_some_and(|range| range.max_values() > 1),
+        global: arg.is_global_set(),
+        hidden: arg.is_hide_set(),
+        value_names: arg
+            .get_value_names()
+            .map(|names| names.iter().map(|name| name.to_string()).collect())
+            .unwrap_or_default(),
+        default_values: if takes_value {
+            arg.get_default_values()
+                .iter()
+                .map(|value| value.to_string_lossy().into_owned())
+                .collect()
+        } else {
+            Vec::new()
+        },
+        possible_values: if takes_value {
+            arg.get_possible_values()
+                .iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| value.get_name().to_string())
+                .collect()
+        } else {
+            Vec::new()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+
+    #[derive(Parser)]
+    #[command(name = "tool", version = "1.2.3")]
+    struct Tool {
+        /// Increase verbosity
+        #[arg(long, global = true)]
+        verbose: bool,
+        #[command(subcommand)]
+        command: ToolCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum ToolCommand {
+        /// Manage widgets
+        Widget {
+            #[command(subcommand)]
+            command: WidgetCommand,
+        },
+        /// Internal
+        #[command(hide = true)]
+        Debug,
+    }
+
+    #[derive(Subcommand)]
+    enum WidgetCommand {
+        /// List widgets
+        List(ListArgs),
+    }
+
+    #[derive(Clone, ValueEnum)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    #[derive(Args)]
+    struct ListArgs {
+        /// Filter by color
+        #[arg(long, short, value_enum, default_value = "red")]
+        color: Color,
+        /// Codes to include
+        #[arg(long)]
+        code: Vec<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    }
+
+    #[derive(Tabled)]
+    struct WidgetDisplay {
+        code: String,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        secret: String,
+        #[tabled(rename = "colour")]
+        color: String,
+    }
+
+    #[test]
+    fn test_cli_schema() {
+        let outputs = OutputSchemas::from([(
+            "widget list".to_string(),
+            OutputSchema::collection::<WidgetDisplay>(),
+        )]);
+        let schema = cli_schema(&Tool::command(), &outputs);
+
+        assert_eq!(schema.schema_version, SCHEMA_VERSION);
+        assert_eq!(schema.name, "tool");
+        assert_eq!(schema.version.as_deref(), Some("1.2.3"));
+        assert_eq!(schema.args.len(), 1);
+        assert!(schema.args[0].global);
+        assert_eq!(schema.args[0].kind, ArgKind::Flag);
+
+        let names: Vec<&str> = schema.commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["widget", "debug"]);
+        assert!(schema.commands[1].hidden);
+
+        let list = &schema.commands[0].commands[0];
+        assert_eq!(list.path, "widget list");
+        assert_eq!(list.about.as_deref(), Some("List widgets"));
+        assert_eq!(
+            list.output,
+            Some(OutputSchema {
+                shape: OutputShape::Collection,
+                record: "WidgetDisplay".to_string(),
+                columns: vec!["code".to_string(), "colour".to_string()],
+            })
+        );
+
+        let color = &list.args[0];
+        assert_eq!(color.long.as_deref(), Some("color"));
+        assert_eq!(color.short, Some('c'));
+        assert_eq!(color.help.as_deref(), Some("Filter by color"));
+        assert_eq!(color.kind, ArgKind::Value);
+        assert_eq!(color.default_values, ["red"]);
+        assert_eq!(color.possible_values, ["red", "blue"]);
+        assert!(!color.multiple);
+        assert!(list.args[1].multiple);
+        assert_eq!(list.args[2].kind, ArgKind::Flag);
+        assert!(list.args[2].default_values.is_empty());
+    }
+}
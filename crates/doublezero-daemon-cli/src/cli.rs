@@ -11,6 +11,7 @@ use std::io::Write;
 use crate::{
     client::DaemonClient, connect::Connect, disable::Disable, disconnect::Disconnect,
     enable::Enable, latency::Latency, ledger::LedgerClient, routes::Routes, status::Status,
+    usage::Usage,
 };
 
 /// Daemon-control verbs hoisted to the binary's top level.
@@ -32,6 +33,8 @@ pub enum DaemonCommand {
     Latency(Latency),
     /// View your installed routes
     Routes(Routes),
+    /// Show usage history of your connection
+    Usage(Usage),
 }
 
 impl DaemonCommand {
@@ -50,6 +53,7 @@ impl DaemonCommand {
             Self::Disconnect(cmd) => cmd.execute(ctx, daemon, ledger, out).await,
             Self::Latency(cmd) => cmd.execute(ctx, daemon, ledger, out).await,
             Self::Routes(cmd) => cmd.execute(ctx, daemon, ledger, out).await,
+            Self::Usage(cmd) => cmd.execute(ctx, daemon, ledger, out).await,
         }
     }
 }
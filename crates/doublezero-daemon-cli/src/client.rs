@@ -5,7 +5,7 @@ use chrono::DateTime;
 use doublezero_config::Environment;
 use eyre::eyre;
 use http_body_util::{BodyExt, Empty, Full};
-use hyper::{body::Bytes, Method, Request};
+use hyper::{body::Bytes, Method, Request, StatusCode};
 use hyper_util::{client::legacy::Client, rt::TokioExecutor};
 use hyperlocal::{UnixConnector, Uri};
 use mockall::automock;
@@ -182,6 +182,28 @@ pub struct Subscription {
     pub subscriber: bool,
 }
 
+/// Health of a service as seen by the daemon's tunnel health monitor, which
+/// re-provisions a service whose tunnel endpoint or BGP session stays down.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceHealth {
+    #[serde(default)]
+    pub healthy: bool,
+    #[serde(default)]
+    pub endpoint_reachable: bool,
+    #[serde(default)]
+    pub bgp_session_up: bool,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub last_check: Option<String>,
+    #[serde(default)]
+    pub recoveries: u32,
+    #[serde(default)]
+    pub last_recovery: Option<String>,
+    #[serde(default)]
+    pub last_recovery_error: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct V2ServiceStatus {
     #[serde(flatten)]
@@ -198,6 +220,9 @@ pub struct V2ServiceStatus {
     pub multicast_groups: MulticastGroups,
     #[serde(default)]
     pub subscriptions: Vec<Subscription>,
+    /// Present when the daemon runs with `-tunnel-health`.
+    #[serde(default)]
+    pub health: Option<ServiceHealth>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -210,6 +235,42 @@ pub struct V2StatusResponse {
     pub services: Vec<V2ServiceStatus>,
 }
 
+/// Traffic carried by one tunnel, as tracked by the daemon's usage accountant.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageSession {
+    pub user_type: String,
+    pub tunnel_name: String,
+    pub tunnel_dst: String,
+    pub start: String,
+    pub last_seen: String,
+    /// Absent while the tunnel is still up.
+    #[serde(default)]
+    pub end: Option<String>,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Traffic of one user type on one UTC day.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DailyUsage {
+    pub date: String,
+    pub user_type: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Usage of one calendar month, returned by `/v2/usage`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageResponse {
+    pub month: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    #[serde(default)]
+    pub daily: Vec<DailyUsage>,
+    #[serde(default)]
+    pub sessions: Vec<UsageSession>,
+}
+
 // ---------------------------------------------------------------------------
 // DaemonClient trait (renamed from ServiceController)
 // ---------------------------------------------------------------------------
@@ -227,6 +288,7 @@ pub trait DaemonClient: Send + Sync {
     async fn enable(&self) -> eyre::Result<()>;
     async fn disable(&self) -> eyre::Result<()>;
     async fn routes(&self) -> eyre::Result<Vec<RouteRecord>>;
+    async fn usage(&self, month: Option<String>) -> eyre::Result<UsageResponse>;
 }
 
 // ---------------------------------------------------------------------------
@@ -368,6 +430,11 @@ impl DaemonClient for DaemonClientImpl {
             .request(req)
             .await
             .map_err(|e| eyre!("Unable to connect to doublezero daemon: {e}"))?;
+        if res.status() == StatusCode::FORBIDDEN {
+            eyre::bail!(
+                "Failed to enable reconciler: this user is not allowed to enable by the doublezerod access policy"
+            );
+        }
         if res.status() != 200 {
             eyre::bail!("Failed to enable reconciler: {}", res.status());
         }
@@ -385,6 +452,11 @@ impl DaemonClient for DaemonClientImpl {
             .request(req)
             .await
             .map_err(|e| eyre!("Unable to connect to doublezero daemon: {e}"))?;
+        if res.status() == StatusCode::FORBIDDEN {
+            eyre::bail!(
+                "Failed to disable reconciler: this user is not allowed to disable by the doublezerod access policy"
+            );
+        }
         if res.status() != 200 {
             eyre::bail!("Failed to disable reconciler: {}", res.status());
         }
@@ -412,6 +484,41 @@ impl DaemonClient for DaemonClientImpl {
             .to_bytes();
         parse_daemon_response::<Vec<RouteRecord>>(&data, "/routes")
     }
+
+    async fn usage(&self, month: Option<String>) -> eyre::Result<UsageResponse> {
+        let path = match month {
+            Some(month) => format!("/v2/usage?month={month}"),
+            None => "/v2/usage".to_string(),
+        };
+        let client = Client::builder(TokioExecutor::new()).build(UnixConnector);
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(Uri::new(&self.socket_path, &path))
+            .body(Empty::<Bytes>::new())?;
+        let res = client
+            .request(req)
+            .await
+            .map_err(|e| eyre!("Unable to connect to doublezero daemon: {e}"))?;
+        let status = res.status();
+        let data = res
+            .into_body()
+            .collect()
+            .await
+            .map_err(|e| eyre!("Unable to read response body: {e}"))?
+            .to_bytes();
+        if status == StatusCode::NOT_FOUND {
+            eyre::bail!(
+                "Usage accounting is not enabled on the doublezero daemon (start doublezerod with -usage-accounting)"
+            );
+        }
+        if status != 200 {
+            eyre::bail!(
+                "Failed to get usage: {}",
+                String::from_utf8_lossy(&data).trim()
+            );
+        }
+        parse_daemon_response::<UsageResponse>(&data, "/v2/usage")
+    }
 }
 
 #[cfg(test)]
@@ -618,6 +725,63 @@ mod tests {
         assert!(svc.multicast_groups.subscriber.is_empty());
     }
 
+    #[test]
+    fn test_v2_service_status_serde_health() {
+        let json = r#"{
+            "doublezero_status": {"session_status": "BGP Session Down", "last_session_update": null},
+            "tunnel_name": "doublezero0", "tunnel_src": null, "tunnel_dst": "5.6.7.8",
+            "doublezero_ip": null, "user_type": "IBRL",
+            "health": {
+                "healthy": false, "endpoint_reachable": true, "bgp_session_up": false,
+                "consecutive_failures": 2, "last_check": "2026-01-02T03:04:05Z",
+                "recoveries": 1, "last_recovery": "2026-01-02T03:00:00Z"
+            }
+        }"#;
+        let svc: V2ServiceStatus = serde_json::from_str(json).unwrap();
+        let health = svc.health.unwrap();
+        assert!(!health.healthy);
+        assert!(health.endpoint_reachable);
+        assert_eq!(health.consecutive_failures, 2);
+        assert_eq!(health.recoveries, 1);
+        assert!(health.last_recovery_error.is_none());
+
+        // Daemons without the health monitor omit the field.
+        let json = r#"{
+            "doublezero_status": {"session_status": "BGP Session Up", "last_session_update": null},
+            "tunnel_name": null, "tunnel_src": null, "tunnel_dst": null,
+            "doublezero_ip": null, "user_type": "IBRL"
+        }"#;
+        let svc: V2ServiceStatus = serde_json::from_str(json).unwrap();
+        assert!(svc.health.is_none());
+    }
+
+    #[test]
+    fn test_usage_response_serde() {
+        let json = r#"{
+            "month": "2025-06", "rx_bytes": 3000, "tx_bytes": 1500,
+            "daily": [{"date": "2025-06-30", "user_type": "IBRL", "rx_bytes": 3000, "tx_bytes": 1500}],
+            "sessions": [
+                {"user_type": "IBRL", "tunnel_name": "doublezero0", "tunnel_dst": "5.6.7.8",
+                 "start": "2025-06-30T08:00:00Z", "last_seen": "2025-06-30T09:00:00Z",
+                 "end": "2025-06-30T09:00:00Z", "rx_bytes": 1000, "tx_bytes": 500,
+                 "last_rx": 1000, "last_tx": 500},
+                {"user_type": "IBRL", "tunnel_name": "doublezero0", "tunnel_dst": "5.6.7.8",
+                 "start": "2025-06-30T10:00:00Z", "last_seen": "2025-06-30T11:00:00Z",
+                 "rx_bytes": 2000, "tx_bytes": 1000, "last_rx": 2000, "last_tx": 1000}
+            ]
+        }"#;
+        let usage: UsageResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(usage.rx_bytes, 3000);
+        assert_eq!(usage.daily.len(), 1);
+        assert_eq!(usage.sessions.len(), 2);
+        assert_eq!(
+            usage.sessions[0].end.as_deref(),
+            Some("2025-06-30T09:00:00Z")
+        );
+        // Open sessions have no end.
+        assert!(usage.sessions[1].end.is_none());
+    }
+
     #[test]
     fn test_daemon_client_impl_uses_explicit_socket_path() {
         let socket_path =
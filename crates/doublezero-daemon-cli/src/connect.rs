@@ -23,12 +23,21 @@ use solana_sdk::pubkey::Pubkey;
 
 use crate::{
     client::{DaemonClient, StatusResponse},
-    helpers::{init_spinner, resolve_client_ip},
+    helpers::{init_spinner, poll_for_user_closed, resolve_client_ip},
     latency::{best_latency, retrieve_latencies, select_tunnel_endpoint},
     ledger::LedgerClient,
     requirements::check_daemon,
 };
 
+/// Daemon session status of a tunnel whose BGP session is established.
+const SESSION_UP: &str = "BGP Session Up";
+
+/// Delay between daemon status polls while waiting for a tunnel.
+#[cfg(not(test))]
+const PROVISION_POLL_DELAY: Duration = Duration::from_secs(5);
+#[cfg(test)]
+const PROVISION_POLL_DELAY: Duration = Duration::from_millis(10);
+
 #[derive(Clone, Debug, PartialEq, ValueEnum)]
 pub enum MulticastMode {
     Publisher,
@@ -208,17 +217,40 @@ impl Connect {
         out: &mut W,
     ) -> eyre::Result<()> {
         // Look for user
-        let (_user_pubkey, user) = self
+        let (mut user_pubkey, mut user, mut fallbacks) = self
             .find_or_create_user(ledger, daemon, &client_ip, spinner, user_type, tenant, out)
             .await?;
 
-        // Check user status
-        match user.status {
-            UserStatus::Activated => {
-                self.user_activated(daemon, user_type, spinner, out).await?;
-                Ok(())
+        loop {
+            // Check user status
+            if user.status != UserStatus::Activated {
+                eyre::bail!("User status not expected");
             }
-            _ => eyre::bail!("User status not expected"),
+
+            // While other CYOA modes remain, only a session that comes up counts
+            // as success; otherwise recreate the user over the next mode.
+            let require_session_up = !fallbacks.is_empty();
+            if self
+                .user_activated(
+                    daemon,
+                    user_type,
+                    user.cyoa_type,
+                    require_session_up,
+                    spinner,
+                    out,
+                )
+                .await?
+            {
+                return Ok(());
+            }
+
+            let next = fallbacks.remove(0);
+            writeln!(
+                out,
+                "⚠️  Tunnel over {} did not come up, retrying over {}...",
+                user.cyoa_type, next.cyoa_type
+            )?;
+            (user_pubkey, user) = self.recreate_user(ledger, &user_pubkey, next, spinner)?;
         }
     }
 
@@ -342,8 +374,15 @@ impl Connect {
 
         match user.status {
             UserStatus::Activated => {
-                self.user_activated(daemon, UserType::Multicast, spinner, out)
-                    .await?;
+                self.user_activated(
+                    daemon,
+                    UserType::Multicast,
+                    user.cyoa_type,
+                    false,
+                    spinner,
+                    out,
+                )
+                .await?;
                 Ok(true)
             }
             _ => eyre::bail!("User status not expected"),
@@ -493,7 +532,7 @@ impl Connect {
         user_type: UserType,
         tenant: Option<String>,
         out: &mut W,
-    ) -> eyre::Result<(Pubkey, User)> {
+    ) -> eyre::Result<(Pubkey, User, Vec<CreateUserCommand>)> {
         spinner.set_message("Searching for user account...");
         spinner.inc(1);
 
@@ -513,6 +552,10 @@ impl Connect {
             .iter()
             .find(|(_, u)| u.client_ip == *client_ip && u.user_type == user_type);
 
+        // Commands recreating a newly created user over the device's other
+        // CYOA modes, tried in order if the tunnel does not come up.
+        let mut fallbacks = Vec::new();
+
         let user_pubkey = match matched_user {
             Some((pubkey, user)) => {
                 writeln!(out, "    An account already exists with Pubkey: {pubkey}")?;
@@ -586,16 +629,24 @@ impl Connect {
                     None => None,
                 };
 
-                let res = ledger.create_user(CreateUserCommand {
+                let mut modes = cyoa_candidates(&device).into_iter();
+                let cmd = CreateUserCommand {
                     user_type,
                     device_pk,
-                    cyoa_type: UserCYOA::GREOverDIA,
+                    cyoa_type: modes.next().unwrap_or(UserCYOA::GREOverDIA),
                     client_ip: *client_ip,
                     tunnel_endpoint,
                     tenant_pk,
-                });
+                    bandwidth_tier: None,
+                };
+                fallbacks = modes
+                    .map(|cyoa_type| CreateUserCommand {
+                        cyoa_type,
+                        ..cmd.clone()
+                    })
+                    .collect();
 
-                match res {
+                match ledger.create_user(cmd) {
                     Ok(pubkey) => {
                         spinner.set_message("User created");
                         pubkey
@@ -612,6 +663,28 @@ impl Connect {
 
         let user = self.poll_for_user_activated(ledger, &user_pubkey, spinner)?;
 
+        Ok((user_pubkey, user, fallbacks))
+    }
+
+    /// Close the user at `user_pubkey` and create it again with `cmd`, to retry
+    /// the tunnel over another CYOA mode.
+    fn recreate_user<L: LedgerClient>(
+        &self,
+        ledger: &L,
+        user_pubkey: &Pubkey,
+        cmd: CreateUserCommand,
+        spinner: &ProgressBar,
+    ) -> eyre::Result<(Pubkey, User)> {
+        spinner.set_message("Deleting user account...");
+        ledger.delete_user(*user_pubkey)?;
+        poll_for_user_closed(ledger, user_pubkey, spinner)?;
+
+        spinner.set_message("Creating user account...");
+        let user_pubkey = ledger
+            .create_user(cmd)
+            .map_err(|e| eyre::eyre!("Error creating user: {e:?}"))?;
+        let user = self.poll_for_user_activated(ledger, &user_pubkey, spinner)?;
+
         Ok((user_pubkey, user))
     }
 
@@ -708,6 +781,7 @@ impl Connect {
                     tunnel_endpoint,
                     owner: None,
                     feed_pk: None,
+                    bandwidth_tier: None,
                 });
 
                 let user_pk = match res {
@@ -853,6 +927,7 @@ impl Connect {
                     tunnel_endpoint,
                     owner: None,
                     feed_pk: None,
+                    bandwidth_tier: None,
                 });
 
                 let user_pk = match res {
@@ -922,13 +997,19 @@ impl Connect {
             .map_err(|_| eyre::eyre!("Timeout reading user account"))
     }
 
+    /// Enable the reconciler and wait for the daemon to provision the tunnel.
+    ///
+    /// Returns `false` only when `require_session_up` is set and the session
+    /// did not come up in time, so the caller can retry over another CYOA mode.
     async fn user_activated<D: DaemonClient, W: Write>(
         &self,
         daemon: &D,
         user_type: UserType,
+        cyoa_type: UserCYOA,
+        require_session_up: bool,
         spinner: &ProgressBar,
         out: &mut W,
-    ) -> eyre::Result<()> {
+    ) -> eyre::Result<bool> {
         spinner.inc(1);
 
         // Enable the reconciler (no-op if already enabled).
@@ -944,7 +1025,7 @@ impl Connect {
                     out,
                     "    Error: failed to enable reconciler: {e}. Tunnel will not be provisioned."
                 )?;
-                return Ok(());
+                return Ok(true);
             }
         }
 
@@ -952,7 +1033,7 @@ impl Connect {
 
         let user_type_str = user_type.to_string();
         match self
-            .poll_for_daemon_provisioned(daemon, &user_type_str, spinner)
+            .poll_for_daemon_provisioned(daemon, &user_type_str, require_session_up, spinner)
             .await
         {
             Ok(status) => {
@@ -966,12 +1047,14 @@ impl Connect {
                 if let Some(ip) = &status.doublezero_ip {
                     writeln!(out, "    DoubleZero IP: {ip}")?;
                 }
+                writeln!(out, "    CYOA: {cyoa_type}")?;
                 writeln!(
                     out,
                     "    Session: {}",
                     status.doublezero_status.session_status
                 )?;
             }
+            Err(_) if require_session_up => return Ok(false),
             Err(e) => {
                 spinner.inc(1);
                 writeln!(
@@ -981,18 +1064,19 @@ impl Connect {
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 
     async fn poll_for_daemon_provisioned<D: DaemonClient>(
         &self,
         daemon: &D,
         user_type_str: &str,
+        require_session_up: bool,
         spinner: &ProgressBar,
     ) -> eyre::Result<StatusResponse> {
         // Poll for up to ~60s (reconciler polls every 10s by default)
         let max_attempts = 12;
-        let delay = Duration::from_secs(5);
+        let delay = PROVISION_POLL_DELAY;
 
         for attempt in 0..max_attempts {
             if attempt > 0 {
@@ -1005,7 +1089,10 @@ impl Connect {
                     .iter()
                     .find(|s| s.user_type.as_ref().is_some_and(|ut| ut == user_type_str))
                 {
-                    return Ok(status.clone());
+                    if !require_session_up || status.doublezero_status.session_status == SESSION_UP
+                    {
+                        return Ok(status.clone());
+                    }
                 }
             }
         }
@@ -1014,6 +1101,19 @@ impl Connect {
     }
 }
 
+/// CYOA modes to try, in order, for a new user on `device`: GREOverDIA first,
+/// then each other mode offered by one of the device's interfaces.
+fn cyoa_candidates(device: &Device) -> Vec<UserCYOA> {
+    let mut modes = vec![UserCYOA::GREOverDIA];
+    for iface in &device.interfaces {
+        let mode = UserCYOA::from(iface.interface_cyoa as u8);
+        if mode != UserCYOA::None && !modes.contains(&mode) {
+            modes.push(mode);
+        }
+    }
+    modes
+}
+
 fn exclude_ips(
     users: &HashMap<Pubkey, User>,
     client_ip: &Ipv4Addr,
@@ -1053,6 +1153,7 @@ mod tests {
         accesspass::{AccessPass, AccessPassStatus, AccessPassType},
         accounttype::AccountType,
         device::{Device, DeviceStatus, DeviceType},
+        interface::{Interface, InterfaceCYOA},
         multicastgroup::{MulticastGroup, MulticastGroupStatus},
         tenant::{Tenant, TenantBillingConfig, TenantPaymentStatus},
     };
@@ -1094,6 +1195,9 @@ mod tests {
         /// what the reconciler would do). The status mock only returns entries
         /// for types in this set.
         pub provisioned_services: Arc<Mutex<HashSet<String>>>,
+        /// CYOA modes whose tunnels the status mock reports with the BGP
+        /// session down.
+        pub failing_cyoa: Arc<Mutex<Vec<UserCYOA>>>,
     }
 
     impl TestFixture {
@@ -1170,6 +1274,8 @@ mod tests {
                 max_unicast_users: 1,
                 multicast_user_count: 0,
                 max_multicast_users: 1,
+                bandwidth_tier: Default::default(),
+                referrer: Pubkey::default(),
             }));
 
             let mut fixture = Self {
@@ -1183,6 +1289,7 @@ mod tests {
                 default_tenant_pk,
                 accesspass,
                 provisioned_services: Arc::new(Mutex::new(HashSet::new())),
+                failing_cyoa: Arc::new(Mutex::new(vec![])),
             };
 
             fixture.ledger.expect_get_payer().return_const(payer);
@@ -1209,8 +1316,12 @@ mod tests {
             // provisioned. Test helpers (expect_create_user, etc.) add entries to
             // `provisioned_services` when they simulate successful onchain txs.
             let status_provisioned = fixture.provisioned_services.clone();
+            let status_users = fixture.users.clone();
+            let failing_cyoa = fixture.failing_cyoa.clone();
             fixture.daemon.expect_status().returning_st(move || {
                 let provisioned = status_provisioned.lock().unwrap();
+                let users = status_users.lock().unwrap();
+                let failing_cyoa = failing_cyoa.lock().unwrap();
                 let mut statuses = Vec::new();
                 for (user_type, tunnel_name, tunnel_dst, dz_ip) in [
                     ("IBRL", "doublezero1", "5.6.7.1", "10.1.1.1"),
@@ -1219,9 +1330,18 @@ mod tests {
                     ("Multicast", "doublezero2", "5.6.7.2", "10.1.1.2"),
                 ] {
                     if provisioned.contains(user_type) {
+                        let session_down = users.values().any(|u| {
+                            u.user_type.to_string() == user_type
+                                && failing_cyoa.contains(&u.cyoa_type)
+                        });
+                        let session_status = if session_down {
+                            "BGP Session Down"
+                        } else {
+                            "BGP Session Up"
+                        };
                         statuses.push(StatusResponse {
                             doublezero_status: DoubleZeroStatus {
-                                session_status: "BGP Session Up".to_string(),
+                                session_status: session_status.to_string(),
                                 last_session_update: Some(0),
                             },
                             tunnel_name: Some(tunnel_name.to_string()),
@@ -1473,6 +1593,7 @@ mod tests {
                 last_bgp_reported_at: 0,
                 bgp_rtt_ns: 0,
                 feed_pk: Pubkey::default(),
+                bandwidth_tier: Default::default(),
             }
         }
 
@@ -1504,6 +1625,7 @@ mod tests {
                 client_ip: user.client_ip,
                 tunnel_endpoint: user.tunnel_endpoint,
                 tenant_pk,
+                bandwidth_tier: None,
             };
 
             let users = self.users.clone();
@@ -1541,6 +1663,7 @@ mod tests {
                 tunnel_endpoint: user.tunnel_endpoint,
                 owner: None,
                 feed_pk: None,
+                bandwidth_tier: None,
             };
 
             let users = self.users.clone();
@@ -1684,6 +1807,92 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_connect_command_ibrl_falls_back_to_next_cyoa() {
+        block_on(async {
+            let mut fixture = TestFixture::new();
+            let (tenant_pk, tenant) = fixture.add_tenant("cyoa-tenant");
+
+            let (device_pk, _device) = fixture.add_device(DeviceType::Hybrid, 100, true);
+            fixture
+                .devices
+                .lock()
+                .unwrap()
+                .get_mut(&device_pk)
+                .unwrap()
+                .interfaces
+                .push(Interface {
+                    name: "Ethernet1".to_string(),
+                    interface_cyoa: InterfaceCYOA::GREOverFabric,
+                    ..Default::default()
+                });
+            fixture
+                .failing_cyoa
+                .lock()
+                .unwrap()
+                .push(UserCYOA::GREOverDIA);
+
+            let user = fixture.create_user(UserType::IBRL, device_pk, "1.2.3.4");
+            let dia_user_pk = Pubkey::new_unique();
+            fixture.expect_create_user_with_tenant(dia_user_pk, &user, Some(tenant_pk));
+
+            let users = fixture.users.clone();
+            fixture
+                .ledger
+                .expect_delete_user()
+                .times(1)
+                .with(predicate::eq(dia_user_pk))
+                .returning_st(move |pk| {
+                    users.lock().unwrap().remove(&pk);
+                    Ok(())
+                });
+
+            let fabric_user = User {
+                cyoa_type: UserCYOA::GREOverFabric,
+                ..user.clone()
+            };
+            let fabric_user_pk = Pubkey::new_unique();
+            let users = fixture.users.clone();
+            fixture
+                .ledger
+                .expect_create_user()
+                .times(1)
+                .with(predicate::eq(CreateUserCommand {
+                    user_type: UserType::IBRL,
+                    device_pk,
+                    cyoa_type: UserCYOA::GREOverFabric,
+                    client_ip: user.client_ip,
+                    tunnel_endpoint: user.tunnel_endpoint,
+                    tenant_pk: Some(tenant_pk),
+                    bandwidth_tier: None,
+                }))
+                .returning_st(move |_| {
+                    users
+                        .lock()
+                        .unwrap()
+                        .insert(fabric_user_pk, fabric_user.clone());
+                    Ok(fabric_user_pk)
+                });
+
+            let command = Connect {
+                dz_mode: DzMode::IBRL {
+                    tenant: Some(tenant.code.clone()),
+                    allocate_addr: false,
+                },
+                client_ip: Some(user.client_ip.to_string()),
+                device: None,
+                verbose: false,
+            };
+
+            let (result, output) = run(&fixture, command).await;
+            assert!(result.is_ok(), "{result:?}");
+            assert!(output
+                .contains("Tunnel over GREOverDIA did not come up, retrying over GREOverFabric"));
+            assert!(output.contains("CYOA: GREOverFabric"));
+            assert!(output.contains("Session: BGP Session Up"));
+        });
+    }
+
     #[test]
     fn test_connect_command_ibrl_edge() {
         block_on(async {
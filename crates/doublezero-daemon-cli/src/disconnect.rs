@@ -4,19 +4,23 @@
 //! deprovision the corresponding tunnel(s). Progress animation is rendered on a
 //! stderr spinner (transient UI); informational and result lines route through
 //! the shared writer.
+//!
+//! Progress is checkpointed locally (see [`crate::disconnect_state`]), so an
+//! interrupted disconnect picks up where it stopped instead of resubmitting
+//! deletions that are already in flight.
 
-use std::{io::Write, time::Duration};
+use std::{io::Write, path::PathBuf, time::Duration};
 
-use backon::{BlockingRetryable, ExponentialBuilder};
 use clap::{Args, ValueEnum};
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::UserType;
+use doublezero_sdk::{UserStatus, UserType};
 use indicatif::ProgressBar;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::{
     client::DaemonClient,
-    helpers::{init_spinner, resolve_client_ip},
+    disconnect_state::{DisconnectState, DisconnectStateStore, DisconnectStep},
+    helpers::{init_spinner, poll_for_user_closed, resolve_client_ip},
     ledger::LedgerClient,
     requirements::check_daemon,
 };
@@ -48,12 +52,15 @@ pub struct Disconnect {
     pub no_wait: bool,
     #[arg(value_enum)]
     pub dz_mode: Option<DzMode>,
+    /// File holding the checkpoint of an interrupted disconnect
+    #[arg(long, hide = true)]
+    pub state_path: Option<PathBuf>,
 }
 
 impl Disconnect {
     pub async fn execute<D: DaemonClient, L: LedgerClient, W: Write>(
         self,
-        _ctx: &CliContext,
+        ctx: &CliContext,
         daemon: &D,
         ledger: &L,
         out: &mut W,
@@ -71,8 +78,37 @@ impl Disconnect {
         let client_ip = resolve_client_ip(daemon).await?;
         writeln!(out, "    Client IP: {client_ip}")?;
 
+        let store = DisconnectStateStore::resolve(self.state_path.clone(), ctx)?;
+        let mut state = match store.load() {
+            Ok(Some(state)) if state.client_ip == client_ip.to_string() => {
+                writeln!(
+                    out,
+                    "    Resuming interrupted disconnect: {}",
+                    state.summary()
+                )?;
+                state
+            }
+            Ok(_) => DisconnectState::new(client_ip.to_string()),
+            Err(e) => {
+                writeln!(
+                    out,
+                    "⚠️  Ignoring unreadable disconnect checkpoint {}: {e}",
+                    store.path().display()
+                )?;
+                DisconnectState::new(client_ip.to_string())
+            }
+        };
+
         let gstate = ledger.get_globalstate()?;
-        self.delete_users(ledger, client_ip, gstate.feed_authority_pk, &spinner, out)?;
+        self.delete_users(
+            ledger,
+            client_ip,
+            gstate.feed_authority_pk,
+            &store,
+            &mut state,
+            &spinner,
+            out,
+        )?;
 
         if self.no_wait {
             writeln!(
@@ -108,19 +144,27 @@ impl Disconnect {
             writeln!(out, "✅  Deprovisioning Complete")?;
         }
 
+        if let Err(e) = store.clear() {
+            tracing::warn!("failed to remove disconnect checkpoint: {e}");
+        }
         spinner.finish_and_clear();
 
         Ok(())
     }
 
     /// Delete DZ Ledger users matching `client_ip`, skipping any that are
-    /// owned by a different keypair (e.g. the shred oracle). Extracted from
-    /// `execute` so it can be tested without filesystem/daemon dependencies.
+    /// owned by a different keypair (e.g. the shred oracle). Each step is
+    /// recorded in `state` and saved to `store`; users whose deletion was
+    /// already submitted are only waited on. Extracted from `execute` so it
+    /// can be tested without daemon dependencies.
+    #[allow(clippy::too_many_arguments)]
     fn delete_users<L: LedgerClient, W: Write>(
         &self,
         ledger: &L,
         client_ip: std::net::Ipv4Addr,
         feed_authority: Pubkey,
+        store: &DisconnectStateStore,
+        state: &mut DisconnectState,
         spinner: &ProgressBar,
         out: &mut W,
     ) -> eyre::Result<()> {
@@ -130,6 +174,19 @@ impl Disconnect {
         let users = ledger.list_user()?;
         let payer = ledger.get_payer();
 
+        // Users from an earlier run that are gone from the ledger are closed.
+        let closed: Vec<Pubkey> = state
+            .pending()
+            .filter_map(|user| user.pubkey.parse().ok())
+            .filter(|pubkey| !users.contains_key(pubkey))
+            .collect();
+        for pubkey in &closed {
+            state.set_step(pubkey, DisconnectStep::AccountClosed);
+        }
+        if !closed.is_empty() {
+            checkpoint(store, state);
+        }
+
         for (pubkey, user) in users.iter().filter(|(_, u)| u.client_ip == client_ip) {
             match self.dz_mode {
                 Some(DzMode::IBRL) => {
@@ -171,16 +228,31 @@ impl Disconnect {
 
             spinner.inc(1);
             writeln!(out, "⚡  Removing account: {pubkey}")?;
-            match ledger.delete_user(*pubkey) {
-                Ok(_) => {
-                    writeln!(out, "    Account deletion submitted")?;
-                }
-                Err(e) => {
-                    writeln!(out, "❌  Failed to remove account: {e}")?;
+            let submitted = if state.step(pubkey) == Some(DisconnectStep::DeleteSubmitted)
+                || user.status == UserStatus::Deleting
+            {
+                writeln!(out, "    Account deletion already submitted")?;
+                true
+            } else {
+                match ledger.delete_user(*pubkey) {
+                    Ok(_) => {
+                        writeln!(out, "    Account deletion submitted")?;
+                        true
+                    }
+                    Err(e) => {
+                        writeln!(out, "❌  Failed to remove account: {e}")?;
+                        false
+                    }
                 }
+            };
+            if submitted {
+                state.set_step(pubkey, DisconnectStep::DeleteSubmitted);
+                checkpoint(store, state);
             }
 
-            self.poll_for_user_closed(ledger, pubkey, spinner)?;
+            poll_for_user_closed(ledger, pubkey, spinner)?;
+            state.set_step(pubkey, DisconnectStep::AccountClosed);
+            checkpoint(store, state);
         }
 
         Ok(())
@@ -234,43 +306,16 @@ impl Disconnect {
 
         eyre::bail!("timed out waiting for daemon to remove tunnel")
     }
+}
 
-    fn poll_for_user_closed<L: LedgerClient>(
-        &self,
-        ledger: &L,
-        user_pubkey: &Pubkey,
-        spinner: &ProgressBar,
-    ) -> eyre::Result<()> {
-        spinner.set_message("Waiting for user deletion...");
-
-        let builder = ExponentialBuilder::new()
-            .with_max_times(8) // 1+2+4+8+16+32+32+32 = 127 seconds max
-            .with_min_delay(Duration::from_secs(1))
-            .with_max_delay(Duration::from_secs(32));
-
-        let get_user = || {
-            match ledger.get_user(*user_pubkey) {
-                Ok(user) => Err(user), // User still exists, keep retrying
-                Err(e) => {
-                    Ok(if e.to_string().contains("User not found") {
-                        Ok(()) // User deleted, stop retrying
-                    } else {
-                        Err(e) // Other error, keep retrying
-                    })
-                }
-            }
-        };
-
-        let _ = get_user
-            .retry(builder)
-            .notify(|_, dur| {
-                spinner.set_message(format!(
-                    "Waiting for user deletion (checking in {dur:?})..."
-                ))
-            })
-            .call()
-            .map_err(|_| eyre::eyre!("Timeout waiting for user deletion"))?;
-        Ok(())
+/// Save the checkpoint. A checkpoint that cannot be written only costs the
+/// ability to resume, so it never fails the disconnect itself.
+fn checkpoint(store: &DisconnectStateStore, state: &DisconnectState) {
+    if let Err(e) = store.save(state) {
+        tracing::warn!(
+            "failed to save disconnect checkpoint {}: {e}",
+            store.path().display()
+        );
     }
 }
 
@@ -294,9 +339,16 @@ mod tests {
             verbose: false,
             no_wait: false,
             dz_mode: None,
+            state_path: None,
         }
     }
 
+    fn test_store() -> (tempfile::TempDir, DisconnectStateStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DisconnectStateStore::new(dir.path().join("disconnect.json"));
+        (dir, store)
+    }
+
     fn hidden_spinner() -> ProgressBar {
         ProgressBar::hidden()
     }
@@ -354,6 +406,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
@@ -474,7 +527,11 @@ mod tests {
         let cmd = test_cmd();
         let spinner = hidden_spinner();
         let mut out = Vec::new();
-        let result = cmd.delete_users(&ledger, ip, oracle_key, &spinner, &mut out);
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        let result = cmd.delete_users(
+            &ledger, ip, oracle_key, &store, &mut state, &spinner, &mut out,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -515,7 +572,17 @@ mod tests {
         let cmd = test_cmd();
         let spinner = hidden_spinner();
         let mut out = Vec::new();
-        let result = cmd.delete_users(&ledger, ip, feed_authority, &spinner, &mut out);
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        let result = cmd.delete_users(
+            &ledger,
+            ip,
+            feed_authority,
+            &store,
+            &mut state,
+            &spinner,
+            &mut out,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -546,7 +613,17 @@ mod tests {
         let cmd = test_cmd();
         let spinner = hidden_spinner();
         let mut out = Vec::new();
-        let result = cmd.delete_users(&ledger, ip, feed_authority, &spinner, &mut out);
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        let result = cmd.delete_users(
+            &ledger,
+            ip,
+            feed_authority,
+            &store,
+            &mut state,
+            &spinner,
+            &mut out,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -589,10 +666,133 @@ mod tests {
         let cmd = test_cmd();
         let spinner = hidden_spinner();
         let mut out = Vec::new();
-        let result = cmd.delete_users(&ledger, ip, oracle_key, &spinner, &mut out);
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        let result = cmd.delete_users(
+            &ledger, ip, oracle_key, &store, &mut state, &spinner, &mut out,
+        );
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_delete_users_records_progress() {
+        let mut ledger = MockLedgerClient::new();
+        let payer = Pubkey::new_unique();
+        let ip = Ipv4Addr::new(10, 0, 0, 1);
+
+        let user_pk = Pubkey::new_unique();
+        let mut users = HashMap::new();
+        users.insert(user_pk, make_test_user(ip, payer, UserType::IBRL));
+
+        ledger.expect_get_payer().return_const(payer);
+        ledger
+            .expect_list_user()
+            .returning(move || Ok(users.clone()));
+        ledger.expect_delete_user().once().returning(|_| Ok(()));
+        ledger
+            .expect_get_user()
+            .returning(|_| Err(eyre::eyre!("User not found")));
+
+        let cmd = test_cmd();
+        let spinner = hidden_spinner();
+        let mut out = Vec::new();
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        cmd.delete_users(
+            &ledger,
+            ip,
+            Pubkey::new_unique(),
+            &store,
+            &mut state,
+            &spinner,
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(state.step(&user_pk), Some(DisconnectStep::AccountClosed));
+        assert_eq!(store.load().unwrap(), Some(state));
+    }
+
+    #[test]
+    fn test_delete_users_does_not_resubmit_deletion() {
+        let mut ledger = MockLedgerClient::new();
+        let payer = Pubkey::new_unique();
+        let ip = Ipv4Addr::new(10, 0, 0, 1);
+
+        // One deletion recorded in the checkpoint, one already visible onchain.
+        let checkpointed_pk = Pubkey::new_unique();
+        let deleting_pk = Pubkey::new_unique();
+        let mut deleting = make_test_user(ip, payer, UserType::Multicast);
+        deleting.status = UserStatus::Deleting;
+        let mut users = HashMap::new();
+        users.insert(checkpointed_pk, make_test_user(ip, payer, UserType::IBRL));
+        users.insert(deleting_pk, deleting);
+
+        ledger.expect_get_payer().return_const(payer);
+        ledger
+            .expect_list_user()
+            .returning(move || Ok(users.clone()));
+        ledger.expect_delete_user().never();
+        ledger
+            .expect_get_user()
+            .returning(|_| Err(eyre::eyre!("User not found")));
+
+        let cmd = test_cmd();
+        let spinner = hidden_spinner();
+        let mut out = Vec::new();
+        let (_dir, store) = test_store();
+        let mut state = DisconnectState::new(ip.to_string());
+        state.set_step(&checkpointed_pk, DisconnectStep::DeleteSubmitted);
+        cmd.delete_users(
+            &ledger,
+            ip,
+            Pubkey::new_unique(),
+            &store,
+            &mut state,
+            &spinner,
+            &mut out,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert_eq!(
+            output.matches("Account deletion already submitted").count(),
+            2
+        );
+        assert_eq!(state.pending().count(), 0);
+    }
+
+    #[test]
+    fn test_delete_users_marks_vanished_users_closed() {
+        let mut ledger = MockLedgerClient::new();
+        let ip = Ipv4Addr::new(10, 0, 0, 1);
+
+        ledger.expect_get_payer().return_const(Pubkey::new_unique());
+        ledger.expect_list_user().returning(|| Ok(HashMap::new()));
+        ledger.expect_delete_user().never();
+
+        let cmd = test_cmd();
+        let spinner = hidden_spinner();
+        let mut out = Vec::new();
+        let (_dir, store) = test_store();
+        let user_pk = Pubkey::new_unique();
+        let mut state = DisconnectState::new(ip.to_string());
+        state.set_step(&user_pk, DisconnectStep::DeleteSubmitted);
+        cmd.delete_users(
+            &ledger,
+            ip,
+            Pubkey::new_unique(),
+            &store,
+            &mut state,
+            &spinner,
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(state.step(&user_pk), Some(DisconnectStep::AccountClosed));
+        assert_eq!(store.load().unwrap(), Some(state));
+    }
+
     // --- execute tests: user-exists and user-doesn't-exist decommissioning ---
 
     fn setup_daemon_checks(daemon: &mut MockDaemonClient) {
@@ -645,8 +845,10 @@ mod tests {
 
             let ctx = doublezero_cli_core::testing::cli_context_default_for_tests();
             let mut out = Vec::new();
+            let (_dir, store) = test_store();
             let cmd = Disconnect {
                 no_wait: true,
+                state_path: Some(store.path().to_path_buf()),
                 ..test_cmd()
             };
             let result = cmd.execute(&ctx, &daemon, &ledger, &mut out).await;
@@ -657,6 +859,71 @@ mod tests {
             assert!(output.contains(&format!("Removing account: {user_pk}")));
             assert!(output.contains("Account deletion submitted"));
             assert!(output.contains("Onchain deletion complete"));
+            // A completed disconnect leaves no checkpoint behind.
+            assert_eq!(store.load().unwrap(), None);
+        });
+    }
+
+    /// A checkpoint left by an interrupted run is resumed: the deletion is not
+    /// submitted again, and the checkpoint is removed once done.
+    #[test]
+    fn test_execute_resumes_interrupted_disconnect() {
+        block_on(async {
+            let payer = Pubkey::new_unique();
+            let ip = Ipv4Addr::new(1, 2, 3, 4);
+            let user_pk = Pubkey::new_unique();
+            let user = make_test_user(ip, payer, UserType::IBRL);
+            let mut users = HashMap::new();
+            users.insert(user_pk, user);
+
+            let (_dir, store) = test_store();
+            let mut state = DisconnectState::new(ip.to_string());
+            state.set_step(&user_pk, DisconnectStep::DeleteSubmitted);
+            store.save(&state).unwrap();
+
+            let mut daemon = MockDaemonClient::new();
+            setup_daemon_checks(&mut daemon);
+            daemon.expect_v2_status().returning(move || {
+                Ok(crate::client::V2StatusResponse {
+                    reconciler_enabled: true,
+                    client_ip: "1.2.3.4".to_string(),
+                    network: String::new(),
+                    services: vec![],
+                })
+            });
+
+            let mut ledger = MockLedgerClient::new();
+            ledger
+                .expect_get_environment()
+                .returning(doublezero_config::Environment::default);
+            ledger.expect_check_requirements().returning(|| Ok(()));
+            ledger.expect_get_payer().return_const(payer);
+            ledger
+                .expect_get_globalstate()
+                .returning(|| Ok(GlobalState::default()));
+            ledger
+                .expect_list_user()
+                .returning(move || Ok(users.clone()));
+            ledger.expect_delete_user().never();
+            ledger
+                .expect_get_user()
+                .returning(|_| Err(eyre::eyre!("User not found")));
+
+            let ctx = doublezero_cli_core::testing::cli_context_default_for_tests();
+            let mut out = Vec::new();
+            let cmd = Disconnect {
+                no_wait: true,
+                state_path: Some(store.path().to_path_buf()),
+                ..test_cmd()
+            };
+            let result = cmd.execute(&ctx, &daemon, &ledger, &mut out).await;
+            assert!(result.is_ok(), "{result:?}");
+
+            let output = String::from_utf8(out).unwrap();
+            assert!(output.contains("Resuming interrupted disconnect"));
+            assert!(output.contains("Account deletion already submitted"));
+            assert!(output.contains("Onchain deletion complete"));
+            assert_eq!(store.load().unwrap(), None);
         });
     }
 
@@ -693,8 +960,10 @@ mod tests {
 
             let ctx = doublezero_cli_core::testing::cli_context_default_for_tests();
             let mut out = Vec::new();
+            let (_dir, store) = test_store();
             let cmd = Disconnect {
                 no_wait: true,
+                state_path: Some(store.path().to_path_buf()),
                 ..test_cmd()
             };
             let result = cmd.execute(&ctx, &daemon, &ledger, &mut out).await;
@@ -0,0 +1,215 @@
+//! Local checkpoint for `doublezero disconnect`.
+//!
+//! Disconnecting deletes each onchain user, waits for its account to close,
+//! then waits for the daemon to tear down the tunnel(s). The progress of each
+//! user is recorded as it happens in `disconnect.json` under the config
+//! directory resolved by the binary, so an interrupted disconnect resumes
+//! where it stopped when run again, and `doublezero status` can show how far
+//! it got. The file is removed once the disconnect completes.
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use doublezero_cli_core::CliContext;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// How far the disconnect of one user got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisconnectStep {
+    /// The user's deletion was submitted onchain.
+    DeleteSubmitted,
+    /// The user account is closed.
+    AccountClosed,
+}
+
+impl fmt::Display for DisconnectStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisconnectStep::DeleteSubmitted => write!(f, "deletion submitted"),
+            DisconnectStep::AccountClosed => write!(f, "account closed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisconnectUser {
+    pub pubkey: String,
+    pub step: DisconnectStep,
+}
+
+/// Checkpoint of a disconnect in progress.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DisconnectState {
+    /// Unix timestamp in seconds of when the disconnect started.
+    pub started_at: i64,
+    pub client_ip: String,
+    pub users: Vec<DisconnectUser>,
+}
+
+impl DisconnectState {
+    pub fn new(client_ip: String) -> Self {
+        Self {
+            started_at: chrono::Utc::now().timestamp(),
+            client_ip,
+            users: vec![],
+        }
+    }
+
+    pub fn step(&self, pubkey: &Pubkey) -> Option<DisconnectStep> {
+        let pubkey = pubkey.to_string();
+        self.users
+            .iter()
+            .find(|user| user.pubkey == pubkey)
+            .map(|user| user.step)
+    }
+
+    pub fn set_step(&mut self, pubkey: &Pubkey, step: DisconnectStep) {
+        let pubkey = pubkey.to_string();
+        match self.users.iter_mut().find(|user| user.pubkey == pubkey) {
+            Some(user) => user.step = step,
+            None => self.users.push(DisconnectUser { pubkey, step }),
+        }
+    }
+
+    /// Users whose account is not closed yet.
+    pub fn pending(&self) -> impl Iterator<Item = &DisconnectUser> {
+        self.users
+            .iter()
+            .filter(|user| user.step != DisconnectStep::AccountClosed)
+    }
+
+    /// One-line description for `doublezero status`.
+    pub fn summary(&self) -> String {
+        let pending: Vec<String> = self
+            .pending()
+            .map(|user| format!("{} ({})", user.pubkey, user.step))
+            .collect();
+        if pending.is_empty() {
+            "onchain cleanup complete, waiting for tunnel teardown".to_string()
+        } else {
+            format!("onchain cleanup incomplete: {}", pending.join(", "))
+        }
+    }
+}
+
+/// The checkpoint file of a disconnect in progress.
+#[derive(Debug, Clone)]
+pub struct DisconnectStateStore {
+    path: PathBuf,
+}
+
+impl DisconnectStateStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The store at `path` if given, else `disconnect.json` under the config
+    /// directory the binary put in the context.
+    pub fn resolve(path: Option<PathBuf>, ctx: &CliContext) -> eyre::Result<Self> {
+        match (path, &ctx.config_dir) {
+            (Some(path), _) => Ok(Self::new(path)),
+            (None, Some(config_dir)) => Ok(Self::new(config_dir.join("disconnect.json"))),
+            (None, None) => Err(eyre::eyre!(
+                "Cannot locate the disconnect checkpoint: no config directory is available"
+            )),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The checkpoint of an interrupted disconnect, if there is one.
+    pub fn load(&self) -> eyre::Result<Option<DisconnectState>> {
+        match fs::read(&self.path) {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write the checkpoint, replacing the file atomically so an interruption
+    /// never leaves a truncated one behind.
+    pub fn save(&self, state: &DisconnectState) -> eyre::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(state)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    pub fn clear(&self) -> eyre::Result<()> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_roundtrip_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DisconnectStateStore::new(dir.path().join("nested").join("disconnect.json"));
+        assert_eq!(store.load().unwrap(), None);
+
+        let user = Pubkey::new_unique();
+        let mut state = DisconnectState::new("1.2.3.4".to_string());
+        state.set_step(&user, DisconnectStep::DeleteSubmitted);
+        store.save(&state).unwrap();
+        assert_eq!(store.load().unwrap(), Some(state.clone()));
+
+        state.set_step(&user, DisconnectStep::AccountClosed);
+        store.save(&state).unwrap();
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.users.len(), 1);
+        assert_eq!(loaded.step(&user), Some(DisconnectStep::AccountClosed));
+
+        store.clear().unwrap();
+        assert_eq!(store.load().unwrap(), None);
+        // Clearing twice is fine.
+        store.clear().unwrap();
+    }
+
+    #[test]
+    fn test_resolve_under_config_dir() {
+        let mut ctx = doublezero_cli_core::testing::cli_context_default_for_tests();
+        assert!(DisconnectStateStore::resolve(None, &ctx).is_err());
+
+        ctx.config_dir = Some(PathBuf::from("/cfg/doublezero"));
+        let store = DisconnectStateStore::resolve(None, &ctx).unwrap();
+        assert_eq!(store.path(), Path::new("/cfg/doublezero/disconnect.json"));
+
+        let store =
+            DisconnectStateStore::resolve(Some(PathBuf::from("/tmp/d.json")), &ctx).unwrap();
+        assert_eq!(store.path(), Path::new("/tmp/d.json"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let closed = Pubkey::new_unique();
+        let submitted = Pubkey::new_unique();
+        let mut state = DisconnectState::new("1.2.3.4".to_string());
+        state.set_step(&closed, DisconnectStep::AccountClosed);
+        assert_eq!(
+            state.summary(),
+            "onchain cleanup complete, waiting for tunnel teardown"
+        );
+
+        state.set_step(&submitted, DisconnectStep::DeleteSubmitted);
+        assert_eq!(
+            state.summary(),
+            format!("onchain cleanup incomplete: {submitted} (deletion submitted)")
+        );
+    }
+}
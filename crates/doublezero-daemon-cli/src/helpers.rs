@@ -2,10 +2,12 @@
 
 use std::{io::Write, net::Ipv4Addr, time::Duration};
 
+use backon::{BlockingRetryable, ExponentialBuilder};
 use indicatif::{ProgressBar, ProgressStyle};
+use solana_sdk::pubkey::Pubkey;
 use tabled::{settings::Style, Table, Tabled};
 
-use crate::client::DaemonClient;
+use crate::{client::DaemonClient, ledger::LedgerClient};
 
 /// Build the standard daemon-verb progress spinner (stderr; transient UI).
 /// Informational and result lines route through the shared writer instead.
@@ -23,6 +25,46 @@ pub(crate) fn init_spinner(len: u64) -> ProgressBar {
     spinner
 }
 
+/// Wait until the user account at `user_pubkey` is gone from the ledger.
+///
+/// Used by `disconnect` and by `connect` when it recreates a user.
+pub(crate) fn poll_for_user_closed<L: LedgerClient>(
+    ledger: &L,
+    user_pubkey: &Pubkey,
+    spinner: &ProgressBar,
+) -> eyre::Result<()> {
+    spinner.set_message("Waiting for user deletion...");
+
+    let builder = ExponentialBuilder::new()
+        .with_max_times(8) // 1+2+4+8+16+32+32+32 = 127 seconds max
+        .with_min_delay(Duration::from_secs(1))
+        .with_max_delay(Duration::from_secs(32));
+
+    let get_user = || {
+        match ledger.get_user(*user_pubkey) {
+            Ok(user) => Err(user), // User still exists, keep retrying
+            Err(e) => {
+                Ok(if e.to_string().contains("User not found") {
+                    Ok(()) // User deleted, stop retrying
+                } else {
+                    Err(e) // Other error, keep retrying
+                })
+            }
+        }
+    };
+
+    let _ = get_user
+        .retry(builder)
+        .notify(|_, dur| {
+            spinner.set_message(format!(
+                "Waiting for user deletion (checking in {dur:?})..."
+            ))
+        })
+        .call()
+        .map_err(|_| eyre::eyre!("Timeout waiting for user deletion"))?;
+    Ok(())
+}
+
 /// Resolve the daemon-discovered client IP.
 ///
 /// Used by `connect`, `disconnect`, and the multicast transport verbs.
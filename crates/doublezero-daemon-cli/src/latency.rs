@@ -2,9 +2,11 @@
 //! and latency-polling utilities shared with `connect` (migrated in a later
 //! RFC-20 PR).
 
-use std::{collections::HashMap, io::Write, net::Ipv4Addr, str::FromStr, time::Duration};
+use std::{
+    collections::HashMap, io::Write, net::Ipv4Addr, path::PathBuf, str::FromStr, time::Duration,
+};
 
-use clap::Args;
+use clap::{Args, Subcommand};
 use doublezero_cli_core::CliContext;
 use doublezero_sdk::{Device, DeviceStatus};
 use indicatif::{ProgressBar, ProgressStyle};
@@ -13,6 +15,7 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     client::{DaemonClient, LatencyRecord},
     helpers,
+    latency_history::{summarize, HistoryStore},
     ledger::LedgerClient,
     requirements::check_daemon,
 };
@@ -256,21 +259,59 @@ pub async fn best_latency<D: DaemonClient>(
 // ---------------------------------------------------------------------------
 
 /// Get device latencies
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Default)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Latency {
+    #[command(subcommand)]
+    command: Option<LatencyCommands>,
     /// Output as json
     #[arg(long, default_value = "false")]
     json: bool,
+    /// Keep measuring every INTERVAL seconds and record the results in the
+    /// local latency history
+    #[arg(long, value_name = "INTERVAL")]
+    interval: Option<u64>,
+    /// Stop after COUNT measurements (implies continuous mode)
+    #[arg(long, value_name = "COUNT")]
+    count: Option<u64>,
+    /// Directory holding the latency history
+    #[arg(long, hide = true)]
+    history_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LatencyCommands {
+    /// Show recorded latency trends for a device
+    History(LatencyHistory),
+}
+
+/// Default interval of the continuous mode when only `--count` is given.
+const DEFAULT_PROBE_INTERVAL_SECS: u64 = 30;
+
+/// History directory: the hidden `--history-dir` override, else `latency/`
+/// under the config directory the binary put in the context.
+fn resolve_history_dir(history_dir: Option<PathBuf>, ctx: &CliContext) -> eyre::Result<PathBuf> {
+    match (history_dir, &ctx.config_dir) {
+        (Some(dir), _) => Ok(dir),
+        (None, Some(config_dir)) => Ok(config_dir.join("latency")),
+        (None, None) => Err(eyre::eyre!(
+            "Cannot locate the latency history: no config directory is available"
+        )),
+    }
 }
 
 impl Latency {
     pub async fn execute<D: DaemonClient, L: LedgerClient, W: Write>(
         self,
-        _ctx: &CliContext,
+        ctx: &CliContext,
         daemon: &D,
         ledger: &L,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if let Some(LatencyCommands::History(cmd)) = self.command {
+            return cmd.execute(ctx, out);
+        }
+
         let spinner = ProgressBar::new_spinner();
         spinner.set_style(
             ProgressStyle::default_spinner()
@@ -286,15 +327,87 @@ impl Latency {
         spinner.set_message("Fetching devices...");
         let devices = ledger.list_device()?;
 
-        let latencies = retrieve_latencies(daemon, &devices, false, Some(&spinner)).await?;
+        if self.interval.is_none() && self.count.is_none() {
+            let latencies = retrieve_latencies(daemon, &devices, false, Some(&spinner)).await?;
+
+            spinner.finish_and_clear();
+            helpers::show_output(latencies, self.json, out)?;
+            return Ok(());
+        }
 
+        // Continuous mode: measure, record, print, repeat.
+        let store = HistoryStore::new(resolve_history_dir(self.history_dir, ctx)?);
+        let interval = Duration::from_secs(self.interval.unwrap_or(DEFAULT_PROBE_INTERVAL_SECS));
+        let mut round = 0;
+        loop {
+            round += 1;
+            let latencies = retrieve_latencies(daemon, &devices, false, Some(&spinner)).await?;
+            let now = chrono::Utc::now();
+            store.record(now.timestamp(), &latencies)?;
+
+            spinner.suspend(|| -> eyre::Result<()> {
+                if self.json {
+                    writeln!(out, "{}", serde_json::to_string(&latencies)?)?;
+                } else {
+                    writeln!(out, "{}", now.format("%Y-%m-%d %H:%M:%S UTC"))?;
+                    helpers::show_output(latencies, false, out)?;
+                }
+                Ok(())
+            })?;
+
+            if self.count.is_some_and(|count| round >= count) {
+                break;
+            }
+            spinner.set_message("Waiting for next measurement...");
+            tokio::time::sleep(interval).await;
+        }
         spinner.finish_and_clear();
-        helpers::show_output(latencies, self.json, out)?;
 
         Ok(())
     }
 }
 
+/// Show min/p50/p95 latency trends recorded by `latency --interval/--count`
+#[derive(Args, Debug)]
+pub struct LatencyHistory {
+    /// Device pubkey or code
+    device: String,
+    /// Length of the window to summarize, in hours
+    #[arg(long, default_value_t = 24)]
+    hours: u64,
+    /// Length of each period within the window, in minutes
+    #[arg(long, default_value_t = 60)]
+    bucket_minutes: u64,
+    /// Output as json
+    #[arg(long, default_value = "false")]
+    json: bool,
+    /// Directory holding the latency history
+    #[arg(long, hide = true)]
+    history_dir: Option<PathBuf>,
+}
+
+impl LatencyHistory {
+    pub fn execute<W: Write>(self, ctx: &CliContext, out: &mut W) -> eyre::Result<()> {
+        let store = HistoryStore::new(resolve_history_dir(self.history_dir, ctx)?);
+        let entries = store.load(&self.device)?;
+
+        let buckets = summarize(
+            &entries,
+            chrono::Utc::now().timestamp(),
+            self.hours.saturating_mul(3_600) as i64,
+            self.bucket_minutes.saturating_mul(60) as i64,
+        );
+        if buckets.is_empty() {
+            eyre::bail!(
+                "No latency samples for device {} in the last {} hours",
+                self.device,
+                self.hours
+            );
+        }
+        helpers::show_output(buckets, self.json, out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1480,9 +1593,12 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Latency { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Latency {
+                json: true,
+                ..Default::default()
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -1505,11 +1621,107 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Latency { json: false }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Latency {
+                json: false,
+                ..Default::default()
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_err());
         });
     }
+
+    #[test]
+    fn test_latency_verb_continuous_records_history() {
+        block_on(async {
+            let (pk1, dev1) = make_device(DeviceStatus::Activated, 0);
+            let device_map = HashMap::from([(pk1, dev1)]);
+            let latencies = vec![make_latency(
+                &pk1.to_string(),
+                5_000_000,
+                6_000_000,
+                7_000_000,
+                true,
+            )];
+
+            let mut daemon = MockDaemonClient::new();
+            let mut ledger = MockLedgerClient::new();
+            setup_passing_checks(&mut daemon, &mut ledger);
+            daemon.expect_latency().times(2).returning(move || {
+                Ok(LatencyResponse {
+                    ready: true,
+                    results: latencies.clone(),
+                })
+            });
+            ledger
+                .expect_list_device()
+                .returning(move || Ok(device_map.clone()));
+
+            let dir = tempfile::tempdir().unwrap();
+            let ctx = cli_context_default_for_tests();
+            let mut out = Vec::new();
+            Latency {
+                json: true,
+                interval: Some(0),
+                count: Some(2),
+                history_dir: Some(dir.path().to_path_buf()),
+                ..Default::default()
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+
+            // One JSON line per measurement.
+            let output = String::from_utf8(out).unwrap();
+            assert_eq!(output.lines().count(), 2);
+            for line in output.lines() {
+                let parsed: Vec<LatencyRecord> = serde_json::from_str(line).unwrap();
+                assert_eq!(parsed[0].device_pk, pk1.to_string());
+            }
+
+            let entries = HistoryStore::new(dir.path())
+                .load(&pk1.to_string())
+                .unwrap();
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].min_latency_ns, 5_000_000);
+
+            // The history verb summarizes what was recorded.
+            let mut out = Vec::new();
+            Latency {
+                command: Some(LatencyCommands::History(LatencyHistory {
+                    device: pk1.to_string(),
+                    hours: 24,
+                    bucket_minutes: 60,
+                    json: true,
+                    history_dir: Some(dir.path().to_path_buf()),
+                })),
+                ..Default::default()
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+            let buckets: serde_json::Value = serde_json::from_slice(&out).unwrap();
+            let total = buckets.as_array().unwrap().last().unwrap();
+            assert_eq!(total["period"], "total");
+            assert_eq!(total["samples"], 2);
+            assert_eq!(total["p50_latency_ns"], 6_000_000);
+        });
+    }
+
+    #[test]
+    fn test_history_dir_resolves_under_config_dir() {
+        let mut ctx = cli_context_default_for_tests();
+        assert!(resolve_history_dir(None, &ctx).is_err());
+
+        ctx.config_dir = Some(PathBuf::from("/cfg/doublezero"));
+        assert_eq!(
+            resolve_history_dir(None, &ctx).unwrap(),
+            PathBuf::from("/cfg/doublezero/latency")
+        );
+        assert_eq!(
+            resolve_history_dir(Some(PathBuf::from("/tmp/h")), &ctx).unwrap(),
+            PathBuf::from("/tmp/h")
+        );
+    }
 }
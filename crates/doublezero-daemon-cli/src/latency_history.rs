@@ -0,0 +1,322 @@
+//! Local latency probe history for `doublezero latency --interval/--count`
+//! and `doublezero latency history`.
+//!
+//! Each device gets a JSON-lines file under `latency/` in the config directory
+//! resolved by the binary, named after its pubkey and capped at
+//! [`MAX_ENTRIES_PER_DEVICE`] entries so the store behaves as a ring buffer.
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+
+use crate::client::LatencyRecord;
+
+/// Two days of samples at a 30s interval.
+pub const MAX_ENTRIES_PER_DEVICE: usize = 5_760;
+
+const NANOS_TO_MS: f64 = 1_000_000.0;
+
+/// One recorded probe result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix timestamp in seconds.
+    pub timestamp: i64,
+    pub device_code: String,
+    pub min_latency_ns: i64,
+    pub avg_latency_ns: i64,
+    pub max_latency_ns: i64,
+    pub reachable: bool,
+}
+
+/// Latency statistics over one time bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Tabled)]
+pub struct HistoryBucket {
+    #[tabled(rename = "Period")]
+    pub period: String,
+    #[tabled(rename = "Samples")]
+    pub samples: usize,
+    #[tabled(rename = "Reachable", display = "display_percent")]
+    pub reachable_percent: f64,
+    #[tabled(rename = "Min", display = "display_as_ms")]
+    pub min_latency_ns: i64,
+    #[tabled(rename = "P50", display = "display_as_ms")]
+    pub p50_latency_ns: i64,
+    #[tabled(rename = "P95", display = "display_as_ms")]
+    pub p95_latency_ns: i64,
+}
+
+fn display_as_ms(latency: &i64) -> String {
+    format!("{:.2}ms", *latency as f64 / NANOS_TO_MS)
+}
+
+fn display_percent(percent: &f64) -> String {
+    format!("{percent:.0}%")
+}
+
+/// Flat-file ring buffer of probe results, one file per device.
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    dir: PathBuf,
+    max_entries: usize,
+}
+
+impl HistoryStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_entries: MAX_ENTRIES_PER_DEVICE,
+        }
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    fn device_path(&self, device_pk: &str) -> PathBuf {
+        self.dir.join(format!("{device_pk}.jsonl"))
+    }
+
+    /// Append one entry per record, dropping the oldest entries of a device
+    /// once it holds more than the configured maximum.
+    pub fn record(&self, timestamp: i64, records: &[LatencyRecord]) -> eyre::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        for record in records {
+            let path = self.device_path(&record.device_pk);
+            let mut entries = read_entries(&path)?;
+            entries.push(HistoryEntry {
+                timestamp,
+                device_code: record.device_code.clone(),
+                min_latency_ns: record.min_latency_ns,
+                avg_latency_ns: record.avg_latency_ns,
+                max_latency_ns: record.max_latency_ns,
+                reachable: record.reachable,
+            });
+            if entries.len() > self.max_entries {
+                entries.drain(..entries.len() - self.max_entries);
+            }
+            write_entries(&path, &entries)?;
+        }
+        Ok(())
+    }
+
+    /// Entries for a device, looked up by pubkey or code, oldest first.
+    pub fn load(&self, device: &str) -> eyre::Result<Vec<HistoryEntry>> {
+        let path = self.device_path(device);
+        if path.exists() {
+            return read_entries(&path);
+        }
+
+        if self.dir.exists() {
+            for dir_entry in fs::read_dir(&self.dir)? {
+                let path = dir_entry?.path();
+                if path.extension().is_none_or(|ext| ext != "jsonl") {
+                    continue;
+                }
+                let entries = read_entries(&path)?;
+                if entries.last().is_some_and(|e| e.device_code == device) {
+                    return Ok(entries);
+                }
+            }
+        }
+        eyre::bail!("No latency history for device {device}")
+    }
+}
+
+fn read_entries(path: &Path) -> eyre::Result<Vec<HistoryEntry>> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let mut entries = vec![];
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        // Skip lines torn by an interrupted write rather than losing the file.
+        if let Ok(entry) = serde_json::from_str(&line) {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+fn write_entries(path: &Path, entries: &[HistoryEntry]) -> eyre::Result<()> {
+    let tmp = path.with_extension("jsonl.tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        for entry in entries {
+            serde_json::to_writer(&mut file, entry)?;
+            file.write_all(b"\n")?;
+        }
+        file.flush()?;
+    }
+    fs::rename(tmp, path)?;
+    Ok(())
+}
+
+/// Percentile of sorted values using the nearest-rank method.
+fn percentile(sorted: &[i64], percentile: usize) -> i64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn bucket(period: String, entries: &[&HistoryEntry]) -> HistoryBucket {
+    let mut reachable: Vec<&HistoryEntry> =
+        entries.iter().copied().filter(|e| e.reachable).collect();
+    reachable.sort_by_key(|e| e.avg_latency_ns);
+    let avgs: Vec<i64> = reachable.iter().map(|e| e.avg_latency_ns).collect();
+    HistoryBucket {
+        period,
+        samples: entries.len(),
+        reachable_percent: if entries.is_empty() {
+            0.0
+        } else {
+            reachable.len() as f64 * 100.0 / entries.len() as f64
+        },
+        min_latency_ns: reachable
+            .iter()
+            .map(|e| e.min_latency_ns)
+            .min()
+            .unwrap_or_default(),
+        p50_latency_ns: percentile(&avgs, 50),
+        p95_latency_ns: percentile(&avgs, 95),
+    }
+}
+
+/// Summarize the entries recorded in `[now - window_secs, now]` into buckets
+/// of `bucket_secs`, oldest first, followed by a total over the window.
+/// Empty buckets are omitted. P50 and P95 are taken over the average RTT of
+/// reachable samples, Min over their minimum RTT.
+pub fn summarize(
+    entries: &[HistoryEntry],
+    now: i64,
+    window_secs: i64,
+    bucket_secs: i64,
+) -> Vec<HistoryBucket> {
+    let start = now - window_secs;
+    let in_window: Vec<&HistoryEntry> = entries
+        .iter()
+        .filter(|e| e.timestamp > start && e.timestamp <= now)
+        .collect();
+    if in_window.is_empty() {
+        return vec![];
+    }
+
+    let bucket_secs = bucket_secs.max(1);
+    let mut buckets = vec![];
+    let mut bucket_start = start;
+    while bucket_start < now {
+        let bucket_end = (bucket_start + bucket_secs).min(now);
+        let members: Vec<&HistoryEntry> = in_window
+            .iter()
+            .copied()
+            .filter(|e| e.timestamp > bucket_start && e.timestamp <= bucket_end)
+            .collect();
+        if !members.is_empty() {
+            buckets.push(bucket(format_period(bucket_start, bucket_end), &members));
+        }
+        bucket_start = bucket_end;
+    }
+    buckets.push(bucket("total".to_string(), &in_window));
+    buckets
+}
+
+fn format_period(start: i64, end: i64) -> String {
+    let fmt = |ts: i64| {
+        chrono::DateTime::from_timestamp(ts, 0)
+            .map(|dt| dt.format("%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| ts.to_string())
+    };
+    format!("{} - {}", fmt(start), fmt(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(device_pk: &str, code: &str, avg: i64, reachable: bool) -> LatencyRecord {
+        LatencyRecord {
+            device_pk: device_pk.to_string(),
+            device_code: code.to_string(),
+            device_ip: "1.2.3.4".to_string(),
+            min_latency_ns: avg - 1_000_000,
+            max_latency_ns: avg + 1_000_000,
+            avg_latency_ns: avg,
+            reachable,
+        }
+    }
+
+    #[test]
+    fn test_history_store_ring_buffer() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HistoryStore::new(dir.path()).with_max_entries(3);
+
+        for i in 0..5 {
+            store
+                .record(
+                    i,
+                    &[
+                        record("pk1", "dev1", 10_000_000 + i, true),
+                        record("pk2", "dev2", 20_000_000, false),
+                    ],
+                )
+                .unwrap();
+        }
+
+        let entries = store.load("pk1").unwrap();
+        let timestamps: Vec<i64> = entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![2, 3, 4]);
+        assert_eq!(entries[2].avg_latency_ns, 10_000_004);
+
+        // Devices can also be looked up by code.
+        assert_eq!(store.load("dev2").unwrap().len(), 3);
+        assert!(store.load("unknown").is_err());
+    }
+
+    #[test]
+    fn test_summarize() {
+        let entry = |timestamp, avg, reachable| HistoryEntry {
+            timestamp,
+            device_code: "dev1".to_string(),
+            min_latency_ns: avg - 500_000,
+            avg_latency_ns: avg,
+            max_latency_ns: avg + 500_000,
+            reachable,
+        };
+        let now = 7_200;
+        let mut entries: Vec<HistoryEntry> = (1..=20)
+            .map(|i| entry(i * 100, i * 1_000_000, true))
+            .collect();
+        entries.push(entry(3_700, 0, false));
+        // Outside the window.
+        entries.push(entry(-10, 1, true));
+
+        let buckets = summarize(&entries, now, 7_200, 3_600);
+        assert_eq!(buckets.len(), 3);
+
+        // First hour: avgs of 1..=20ms.
+        assert_eq!(buckets[0].samples, 20);
+        assert_eq!(buckets[0].reachable_percent, 100.0);
+        assert_eq!(buckets[0].min_latency_ns, 500_000);
+        assert_eq!(buckets[0].p50_latency_ns, 10_000_000);
+        assert_eq!(buckets[0].p95_latency_ns, 19_000_000);
+
+        // Second hour: one unreachable sample only.
+        assert_eq!(buckets[1].samples, 1);
+        assert_eq!(buckets[1].reachable_percent, 0.0);
+        assert_eq!(buckets[1].p50_latency_ns, 0);
+
+        assert_eq!(buckets[2].period, "total");
+        assert_eq!(buckets[2].samples, 21);
+
+        assert!(summarize(&entries, 100_000, 3_600, 3_600).is_empty());
+    }
+}
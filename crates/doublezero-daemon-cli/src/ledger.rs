@@ -14,6 +14,7 @@ use doublezero_sdk::{
         multicastgroup::subscribe::UpdateMulticastGroupRolesCommand,
         user::{create::CreateUserCommand, create_subscribe::CreateSubscribeUserCommand},
     },
+    health::NetworkHealth,
     Device, GlobalState, MulticastGroup, Tenant, User,
 };
 use doublezero_serviceability::state::accesspass::AccessPass;
@@ -71,6 +72,9 @@ pub trait LedgerClient: Send + Sync {
     /// Fetch a tenant by pubkey or code, returning its pubkey and account.
     fn get_tenant(&self, pubkey_or_code: String) -> eyre::Result<(Pubkey, Tenant)>;
 
+    /// Summarize the health of the whole network (used by `status --network`).
+    fn network_health(&self) -> eyre::Result<NetworkHealth>;
+
     /// List all multicast groups on the ledger, keyed by pubkey.
     fn list_multicastgroup(&self) -> eyre::Result<HashMap<Pubkey, MulticastGroup>>;
 
@@ -1,5 +1,5 @@
 //! RFC-20 module crate for daemon-control verbs (`connect`, `disconnect`,
-//! `status`, `enable`, `disable`, `latency`, `routes`, and the multicast
+//! `status`, `enable`, `disable`, `latency`, `routes`, `usage`, and the multicast
 //! transport verbs `subscribe`/`unsubscribe`/`publish`/`unpublish`).
 //!
 //! See `rfcs/rfc20-cli-standardization.md` and `docs/cli-standard.md`.
@@ -9,14 +9,17 @@ pub mod client;
 pub mod connect;
 pub mod disable;
 pub mod disconnect;
+pub mod disconnect_state;
 pub mod enable;
 pub mod helpers;
 pub mod latency;
+pub mod latency_history;
 pub mod ledger;
 pub mod multicast;
 mod requirements;
 pub mod routes;
 pub mod status;
+pub mod usage;
 
 pub use cli::DaemonCommand;
 pub use client::{DaemonClient, DaemonClientImpl};
@@ -416,6 +416,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
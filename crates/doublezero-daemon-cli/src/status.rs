@@ -1,16 +1,21 @@
 //! `doublezero status` — show daemon service status.
 
-use std::io::Write;
+use std::{collections::BTreeMap, io::Write, path::PathBuf};
 
 use backon::{ExponentialBuilder, Retryable};
 use clap::Args;
 use doublezero_cli_core::CliContext;
+use doublezero_sdk::health::NetworkHealth;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use tabled::Tabled;
+use tabled::{settings::Style, Table, Tabled};
 
 use crate::{
-    client::{DaemonClient, DoubleZeroStatus, MulticastGroups, StatusResponse, Subscription},
+    client::{
+        DaemonClient, DoubleZeroStatus, MulticastGroups, ServiceHealth, StatusResponse,
+        Subscription,
+    },
+    disconnect_state::{DisconnectState, DisconnectStateStore},
     helpers,
     ledger::LedgerClient,
     requirements::check_daemon,
@@ -22,6 +27,72 @@ pub struct Status {
     /// Output as json
     #[arg(long, default_value = "false")]
     json: bool,
+    /// Show a health summary of the whole network instead of your service
+    #[arg(long, default_value = "false")]
+    network: bool,
+    /// File holding the checkpoint of an interrupted disconnect
+    #[arg(long, hide = true)]
+    state_path: Option<PathBuf>,
+}
+
+#[derive(Tabled)]
+struct AllocatorRow {
+    #[tabled(rename = "Resource")]
+    resource: String,
+    #[tabled(rename = "Device")]
+    device: String,
+    #[tabled(rename = "Allocated")]
+    allocated: u64,
+    #[tabled(rename = "Capacity")]
+    capacity: u64,
+    #[tabled(rename = "Used")]
+    used: String,
+}
+
+fn format_health_counts(counts: &BTreeMap<String, u64>) -> String {
+    if counts.is_empty() {
+        return "none".to_string();
+    }
+    counts
+        .iter()
+        .map(|(health, count)| format!("{count} {health}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn write_network_health<W: Write>(health: &NetworkHealth, out: &mut W) -> eyre::Result<()> {
+    writeln!(out, "Epoch: {}", health.epoch)?;
+    writeln!(out, "Devices: {}", format_health_counts(&health.devices))?;
+    writeln!(out, "Links: {}", format_health_counts(&health.links))?;
+    writeln!(out, "Pending users: {}", health.pending_users)?;
+    match (
+        health.oracle.last_report_epoch,
+        health.oracle.epochs_since_report,
+    ) {
+        (Some(last), Some(since)) => writeln!(
+            out,
+            "Health oracle: last report in epoch {last} ({since} epochs ago)"
+        )?,
+        _ => writeln!(out, "Health oracle: no reports")?,
+    }
+
+    let rows: Vec<AllocatorRow> = health
+        .allocators
+        .iter()
+        .map(|allocator| AllocatorRow {
+            resource: allocator.resource.clone(),
+            device: allocator.device.clone().unwrap_or_default(),
+            allocated: allocator.allocated,
+            capacity: allocator.capacity,
+            used: format!("{:.1}%", allocator.ratio() * 100.0),
+        })
+        .collect();
+    writeln!(
+        out,
+        "{}",
+        Table::new(rows).with(Style::psql().remove_horizontals())
+    )?;
+    Ok(())
 }
 
 #[derive(Tabled, Debug, Deserialize, Serialize)]
@@ -44,6 +115,34 @@ struct AppendedStatusResponse {
     multicast_groups: String,
     #[tabled(skip)]
     subscriptions: Vec<Subscription>,
+    #[tabled(rename = "Health", display = "display_health")]
+    health: Option<ServiceHealth>,
+    /// Checkpoint of an interrupted `doublezero disconnect`, if any.
+    #[tabled(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    disconnect: Option<DisconnectState>,
+}
+
+fn display_health(health: &Option<ServiceHealth>) -> String {
+    let Some(health) = health else {
+        return "N/A".to_string();
+    };
+    let mut display = if health.healthy {
+        "healthy".to_string()
+    } else {
+        let mut causes = Vec::new();
+        if !health.endpoint_reachable {
+            causes.push("endpoint unreachable");
+        }
+        if !health.bgp_session_up {
+            causes.push("bgp down");
+        }
+        format!("unhealthy ({})", causes.join(", "))
+    };
+    if health.recoveries > 0 {
+        display.push_str(&format!(", {} reconnects", health.recoveries));
+    }
+    display
 }
 
 fn format_multicast_groups(groups: &MulticastGroups) -> String {
@@ -60,14 +159,43 @@ fn format_multicast_groups(groups: &MulticastGroups) -> String {
 impl Status {
     pub async fn execute<D: DaemonClient, L: LedgerClient, W: Write>(
         self,
-        _ctx: &CliContext,
+        ctx: &CliContext,
         daemon: &D,
         ledger: &L,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.network {
+            let health = ledger.network_health()?;
+            if self.json {
+                writeln!(out, "{}", serde_json::to_string_pretty(&health)?)?;
+            } else {
+                write_network_health(&health, out)?;
+            }
+            return Ok(());
+        }
+
         check_daemon(daemon, ledger).await?;
-        let responses = self.build_status(daemon, ledger).await?;
+        let mut responses = self.build_status(daemon, ledger).await?;
+
+        let disconnect = DisconnectStateStore::resolve(self.state_path.clone(), ctx)
+            .and_then(|store| store.load())
+            .unwrap_or_else(|e| {
+                tracing::warn!("failed to read disconnect checkpoint: {e}");
+                None
+            });
+        for response in &mut responses {
+            response.disconnect = disconnect.clone();
+        }
+
         helpers::show_output(responses, self.json, out)?;
+        if let Some(state) = disconnect.filter(|_| !self.json) {
+            writeln!(
+                out,
+                "⚠️  Disconnect of {} did not finish ({}). Run `doublezero disconnect` to resume.",
+                state.client_ip,
+                state.summary()
+            )?;
+        }
         Ok(())
     }
 
@@ -110,6 +238,8 @@ impl Status {
                 },
                 multicast_groups: String::new(),
                 subscriptions: Vec::new(),
+                health: None,
+                disconnect: None,
             }]);
         }
 
@@ -155,6 +285,8 @@ impl Status {
                 tenant: svc.tenant.clone(),
                 multicast_groups: format_multicast_groups(&svc.multicast_groups),
                 subscriptions: svc.subscriptions.clone(),
+                health: svc.health.clone(),
+                disconnect: None,
             });
         }
 
@@ -171,11 +303,18 @@ mod tests {
     };
     use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
     use doublezero_config::Environment;
+    use doublezero_sdk::health::{AllocatorOccupancy, OracleFreshness};
+    use solana_sdk::pubkey::Pubkey;
     use std::sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     };
 
+    /// Checkpoint path with no interrupted disconnect behind it.
+    fn missing_state_path() -> PathBuf {
+        PathBuf::from("/nonexistent/doublezero/disconnect.json")
+    }
+
     fn setup_passing_checks(daemon: &mut MockDaemonClient, ledger: &mut MockLedgerClient) {
         daemon.expect_daemon_check().return_const(true);
         daemon.expect_daemon_can_open().return_const(true);
@@ -218,6 +357,7 @@ mod tests {
             tenant: tenant.to_string(),
             multicast_groups: MulticastGroups::default(),
             subscriptions: Vec::new(),
+            health: None,
         }
     }
 
@@ -256,9 +396,13 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -316,15 +460,20 @@ mod tests {
                         tenant: String::new(),
                         multicast_groups: MulticastGroups::default(),
                         subscriptions: Vec::new(),
+                        health: None,
                     }],
                 },
             );
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -370,9 +519,13 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -411,9 +564,13 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -440,9 +597,13 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -455,9 +616,62 @@ mod tests {
             assert!(!parsed[0].reconciler_enabled);
             assert_eq!(parsed[0].current_device, "N/A");
             assert_eq!(parsed[0].network, "testnet");
+            assert!(parsed[0].disconnect.is_none());
         });
     }
 
+    #[test]
+    fn test_status_shows_interrupted_disconnect() {
+        use crate::disconnect_state::DisconnectStep;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = DisconnectStateStore::new(dir.path().join("disconnect.json"));
+        let user_pk = Pubkey::new_unique();
+        let mut state = DisconnectState::new("1.2.3.4".to_string());
+        state.set_step(&user_pk, DisconnectStep::DeleteSubmitted);
+        store.save(&state).unwrap();
+
+        for json in [true, false] {
+            block_on(async {
+                let mut daemon = MockDaemonClient::new();
+                let mut ledger = MockLedgerClient::new();
+                setup_passing_checks(&mut daemon, &mut ledger);
+                make_status_response(
+                    &mut daemon,
+                    V2StatusResponse {
+                        reconciler_enabled: true,
+                        client_ip: String::new(),
+                        network: "testnet".to_string(),
+                        services: vec![],
+                    },
+                );
+
+                let ctx = cli_context_default_for_tests();
+                let mut out = Vec::new();
+                Status {
+                    json,
+                    network: false,
+                    state_path: Some(store.path().to_path_buf()),
+                }
+                .execute(&ctx, &daemon, &ledger, &mut out)
+                .await
+                .unwrap();
+
+                let output = String::from_utf8(out).unwrap();
+                if json {
+                    let parsed: Vec<AppendedStatusResponse> =
+                        serde_json::from_str(output.trim()).unwrap();
+                    assert_eq!(parsed[0].disconnect.as_ref(), Some(&state));
+                } else {
+                    assert!(output.contains(&format!(
+                        "Disconnect of 1.2.3.4 did not finish (onchain cleanup incomplete: \
+                         {user_pk} (deletion submitted))"
+                    )));
+                }
+            });
+        }
+    }
+
     #[test]
     fn test_status_multicast_groups_display() {
         block_on(async {
@@ -507,15 +721,20 @@ mod tests {
                                 subscriber: true,
                             },
                         ],
+                        health: None,
                     }],
                 })
             });
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: true }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_ok());
             let output = String::from_utf8(out).unwrap();
@@ -579,6 +798,8 @@ mod tests {
                 publisher: true,
                 subscriber: false,
             }],
+            health: None,
+            disconnect: None,
         };
 
         let json_response = vec![appended_response];
@@ -699,6 +920,8 @@ mod tests {
             tenant: "".to_string(),
             multicast_groups: String::new(),
             subscriptions: Vec::new(),
+            health: None,
+            disconnect: None,
         };
 
         let json_response = vec![appended_response];
@@ -741,17 +964,25 @@ mod tests {
             });
 
             // json=true: raw device code
-            let result = Status { json: true }
-                .build_status(&daemon, &ledger)
-                .await
-                .unwrap();
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .build_status(&daemon, &ledger)
+            .await
+            .unwrap();
             assert_eq!(result[0].lowest_latency_device, "device2");
 
             // json=false, different devices: warning emoji
-            let result = Status { json: false }
-                .build_status(&daemon, &ledger)
-                .await
-                .unwrap();
+            let result = Status {
+                json: false,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .build_status(&daemon, &ledger)
+            .await
+            .unwrap();
             assert_eq!(result[0].lowest_latency_device, "⚠️ device2");
         });
     }
@@ -814,7 +1045,13 @@ mod tests {
                 }
             });
 
-            let result = Status { json: true }.build_status(&daemon, &ledger).await;
+            let result = Status {
+                json: true,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .build_status(&daemon, &ledger)
+            .await;
 
             assert!(result.is_ok());
             assert_eq!(calls.load(Ordering::SeqCst), 3);
@@ -833,11 +1070,70 @@ mod tests {
 
             let ctx = cli_context_default_for_tests();
             let mut out = Vec::new();
-            let result = Status { json: false }
-                .execute(&ctx, &daemon, &ledger, &mut out)
-                .await;
+            let result = Status {
+                json: false,
+                network: false,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await;
 
             assert!(result.is_err());
         });
     }
+
+    #[test]
+    fn test_status_network_health() {
+        block_on(async {
+            // The summary comes from the ledger alone; the daemon is not consulted.
+            let daemon = MockDaemonClient::new();
+            let mut ledger = MockLedgerClient::new();
+            ledger.expect_network_health().returning(|| {
+                Ok(NetworkHealth {
+                    epoch: 100,
+                    devices: BTreeMap::from([
+                        ("impaired".to_string(), 1),
+                        ("ready-for-users".to_string(), 2),
+                    ]),
+                    links: BTreeMap::from([("ready-for-service".to_string(), 3)]),
+                    pending_users: 4,
+                    allocators: vec![AllocatorOccupancy {
+                        pubkey: Pubkey::new_unique(),
+                        resource: "TunnelIds".to_string(),
+                        device: Some("ams-dz1".to_string()),
+                        allocated: 16,
+                        capacity: 64,
+                    }],
+                    oracle: OracleFreshness {
+                        health_oracle_pk: Pubkey::new_unique(),
+                        last_report_epoch: Some(98),
+                        epochs_since_report: Some(2),
+                    },
+                })
+            });
+
+            let ctx = cli_context_default_for_tests();
+            let mut out = Vec::new();
+            Status {
+                json: false,
+                network: true,
+                state_path: Some(missing_state_path()),
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+
+            let output = String::from_utf8(out).unwrap();
+            assert!(output.starts_with(
+                "Epoch: 100\n\
+                 Devices: 1 impaired, 2 ready-for-users\n\
+                 Links: 3 ready-for-service\n\
+                 Pending users: 4\n\
+                 Health oracle: last report in epoch 98 (2 epochs ago)\n"
+            ));
+            assert!(output.contains("TunnelIds"));
+            assert!(output.contains("ams-dz1"));
+            assert!(output.contains("25.0%"));
+        });
+    }
 }
@@ -0,0 +1,278 @@
+//! `doublezero usage` — show the bytes in and out of connected sessions, as
+//! tracked by the daemon when it runs with `-usage-accounting`.
+
+use std::io::Write;
+
+use chrono::NaiveDate;
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::{
+    client::{DaemonClient, DailyUsage, UsageSession},
+    ledger::LedgerClient,
+    requirements::check_daemon,
+};
+
+/// Show usage history of your connection
+#[derive(Args, Debug)]
+pub struct Usage {
+    /// Month to report, as YYYY-MM (defaults to the current month)
+    #[arg(long, value_parser = parse_month)]
+    month: Option<String>,
+    /// List each session instead of daily totals
+    #[arg(long, default_value = "false")]
+    sessions: bool,
+    /// Output as json
+    #[arg(long, default_value = "false")]
+    json: bool,
+}
+
+fn parse_month(month: &str) -> Result<String, String> {
+    NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")
+        .map(|_| month.to_string())
+        .map_err(|_| format!("invalid month '{month}', expected YYYY-MM"))
+}
+
+/// Format a byte count with binary units.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.2} {}", UNITS[unit])
+    }
+}
+
+fn display_bytes(bytes: &u64) -> String {
+    format_bytes(*bytes)
+}
+
+#[derive(Tabled, Serialize)]
+struct DailyRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "User Type")]
+    user_type: String,
+    #[tabled(rename = "In", display = "display_bytes")]
+    rx_bytes: u64,
+    #[tabled(rename = "Out", display = "display_bytes")]
+    tx_bytes: u64,
+}
+
+impl From<DailyUsage> for DailyRow {
+    fn from(d: DailyUsage) -> Self {
+        Self {
+            date: d.date,
+            user_type: d.user_type,
+            rx_bytes: d.rx_bytes,
+            tx_bytes: d.tx_bytes,
+        }
+    }
+}
+
+#[derive(Tabled, Serialize)]
+struct SessionRow {
+    #[tabled(rename = "User Type")]
+    user_type: String,
+    #[tabled(rename = "Tunnel")]
+    tunnel_name: String,
+    #[tabled(rename = "Tunnel Dst")]
+    tunnel_dst: String,
+    #[tabled(rename = "Start")]
+    start: String,
+    #[tabled(rename = "End")]
+    end: String,
+    #[tabled(rename = "In", display = "display_bytes")]
+    rx_bytes: u64,
+    #[tabled(rename = "Out", display = "display_bytes")]
+    tx_bytes: u64,
+}
+
+impl From<UsageSession> for SessionRow {
+    fn from(s: UsageSession) -> Self {
+        Self {
+            user_type: s.user_type,
+            tunnel_name: s.tunnel_name,
+            tunnel_dst: s.tunnel_dst,
+            start: s.start,
+            end: s.end.unwrap_or_else(|| "connected".to_string()),
+            rx_bytes: s.rx_bytes,
+            tx_bytes: s.tx_bytes,
+        }
+    }
+}
+
+fn print_table<T: Tabled, W: Write>(rows: Vec<T>, out: &mut W) -> eyre::Result<()> {
+    let table = Table::new(rows)
+        .with(Style::psql().remove_horizontals())
+        .to_string();
+    writeln!(out, "{table}")?;
+    Ok(())
+}
+
+impl Usage {
+    pub async fn execute<D: DaemonClient, L: LedgerClient, W: Write>(
+        self,
+        _ctx: &CliContext,
+        daemon: &D,
+        ledger: &L,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        check_daemon(daemon, ledger).await?;
+
+        let usage = daemon.usage(self.month).await?;
+        if self.json {
+            writeln!(out, "{}", serde_json::to_string_pretty(&usage)?)?;
+            return Ok(());
+        }
+
+        writeln!(
+            out,
+            "Usage for {}: {} in, {} out",
+            usage.month,
+            format_bytes(usage.rx_bytes),
+            format_bytes(usage.tx_bytes)
+        )?;
+        if self.sessions {
+            if !usage.sessions.is_empty() {
+                print_table(
+                    usage
+                        .sessions
+                        .into_iter()
+                        .map(SessionRow::from)
+                        .collect::<Vec<_>>(),
+                    out,
+                )?;
+            }
+        } else if !usage.daily.is_empty() {
+            print_table(
+                usage
+                    .daily
+                    .into_iter()
+                    .map(DailyRow::from)
+                    .collect::<Vec<_>>(),
+                out,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{MockDaemonClient, UsageResponse},
+        ledger::MockLedgerClient,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_config::Environment;
+    use mockall::predicate;
+
+    fn setup_passing_checks(daemon: &mut MockDaemonClient, ledger: &mut MockLedgerClient) {
+        daemon.expect_daemon_check().return_const(true);
+        daemon.expect_daemon_can_open().return_const(true);
+        daemon
+            .expect_get_env()
+            .returning(|| Ok(Environment::default()));
+        ledger
+            .expect_get_environment()
+            .returning(Environment::default);
+    }
+
+    fn june() -> UsageResponse {
+        UsageResponse {
+            month: "2025-06".to_string(),
+            rx_bytes: 3 * 1024 * 1024 * 1024,
+            tx_bytes: 512,
+            daily: vec![DailyUsage {
+                date: "2025-06-30".to_string(),
+                user_type: "IBRL".to_string(),
+                rx_bytes: 3 * 1024 * 1024 * 1024,
+                tx_bytes: 512,
+            }],
+            sessions: vec![UsageSession {
+                user_type: "IBRL".to_string(),
+                tunnel_name: "doublezero0".to_string(),
+                tunnel_dst: "5.6.7.8".to_string(),
+                start: "2025-06-30T08:00:00Z".to_string(),
+                last_seen: "2025-06-30T09:00:00Z".to_string(),
+                end: None,
+                rx_bytes: 3 * 1024 * 1024 * 1024,
+                tx_bytes: 512,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.00 GiB");
+        assert!(parse_month("2025-06").is_ok());
+        assert!(parse_month("2025-13").is_err());
+        assert!(parse_month("June").is_err());
+    }
+
+    #[test]
+    fn test_usage_verb_output() {
+        block_on(async {
+            let mut daemon = MockDaemonClient::new();
+            let mut ledger = MockLedgerClient::new();
+            setup_passing_checks(&mut daemon, &mut ledger);
+            daemon
+                .expect_usage()
+                .with(predicate::eq(Some("2025-06".to_string())))
+                .returning(|_| Ok(june()));
+
+            let ctx = cli_context_default_for_tests();
+            let mut out = Vec::new();
+            Usage {
+                month: Some("2025-06".to_string()),
+                sessions: false,
+                json: false,
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+            let output = String::from_utf8(out).unwrap();
+            assert!(output.starts_with("Usage for 2025-06: 3.00 GiB in, 512 B out\n"));
+            assert!(output.contains("2025-06-30"));
+
+            let mut out = Vec::new();
+            Usage {
+                month: Some("2025-06".to_string()),
+                sessions: true,
+                json: false,
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+            let output = String::from_utf8(out).unwrap();
+            assert!(output.contains("doublezero0"));
+            assert!(output.contains("connected"));
+
+            let mut out = Vec::new();
+            Usage {
+                month: Some("2025-06".to_string()),
+                sessions: false,
+                json: true,
+            }
+            .execute(&ctx, &daemon, &ledger, &mut out)
+            .await
+            .unwrap();
+            let parsed: UsageResponse = serde_json::from_slice(&out).unwrap();
+            assert_eq!(parsed, june());
+        });
+    }
+}
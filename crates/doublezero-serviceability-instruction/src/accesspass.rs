@@ -6,20 +6,23 @@
 use crate::common;
 use doublezero_serviceability::{
     instructions::DoubleZeroInstruction,
-    pda::{get_accesspass_pda, get_globalstate_pda},
+    pda::{get_accesspass_pda, get_globalstate_pda, get_referrer_stats_pda},
     processors::accesspass::{
         check_status::CheckStatusAccessPassArgs, close::CloseAccessPassArgs,
         set::SetAccessPassArgs, set_feeds::SetAccessPassFeedsArgs,
+        settle_lease::SettleAccessPassLeaseArgs,
     },
 };
 use solana_program::{
+    incinerator,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
 use std::net::Ipv4Addr;
 
 /// `SetAccessPass` (variant 67).
-/// Accounts: `[accesspass, globalstate(readonly), user_payer]`, plus
+/// Accounts: `[accesspass, globalstate(readonly), user_payer]`, then the
+/// referrer's stats PDA when `args.referrer` is set, plus
 /// `[current_tenant, new_tenant]` when either is non-default.
 ///
 /// The access-pass PDA is derived from `args.client_ip` and `user_payer`.
@@ -39,6 +42,10 @@ pub fn set_access_pass(
         AccountMeta::new_readonly(globalstate, false),
         AccountMeta::new(*user_payer, false),
     ];
+    if let Some(referrer) = args.referrer {
+        let (referrer_stats, _) = get_referrer_stats_pda(program_id, &referrer);
+        accounts.push(AccountMeta::new(referrer_stats, false));
+    }
     if *current_tenant != Pubkey::default() || *new_tenant != Pubkey::default() {
         accounts.push(AccountMeta::new(*current_tenant, false));
         accounts.push(AccountMeta::new(*new_tenant, false));
@@ -95,6 +102,29 @@ pub fn check_status_access_pass(
     )
 }
 
+/// `SettleAccessPassLease` (variant 130).
+/// Accounts: `[accesspass, globalstate(readonly), incinerator]`.
+///
+/// Permissionless: the expired lease's deposit is burned whoever settles it.
+pub fn settle_access_pass_lease(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    accesspass: &Pubkey,
+    args: SettleAccessPassLeaseArgs,
+) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    common::build(
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(args),
+        vec![
+            AccountMeta::new(*accesspass, false),
+            AccountMeta::new_readonly(globalstate, false),
+            AccountMeta::new(incinerator::ID, false),
+        ],
+        payer,
+    )
+}
+
 /// `SetAccessPassFeeds` (variant 115).
 /// Accounts: `[accesspass, globalstate(readonly), feed[i]...(readonly)]`.
 ///
@@ -151,6 +181,8 @@ mod tests {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }
     }
 
@@ -218,6 +250,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_access_pass_with_referrer_inserts_stats() {
+        let pid = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let user_payer = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let new_tenant = Pubkey::new_unique();
+        let client_ip = Ipv4Addr::new(10, 0, 0, 1);
+        let ix = set_access_pass(
+            &pid,
+            &payer,
+            &user_payer,
+            &Pubkey::default(),
+            &new_tenant,
+            SetAccessPassArgs {
+                referrer: Some(referrer),
+                ..set_args(client_ip)
+            },
+        );
+        let (accesspass, _) = get_accesspass_pda(&pid, &client_ip, &user_payer);
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        let (referrer_stats, _) = get_referrer_stats_pda(&pid, &referrer);
+        // The stats account sits between user_payer and the tenant pair.
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(accesspass, false),
+                AccountMeta::new_readonly(globalstate, false),
+                AccountMeta::new(user_payer, false),
+                AccountMeta::new(referrer_stats, false),
+                AccountMeta::new(Pubkey::default(), false),
+                AccountMeta::new(new_tenant, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
+
     #[test]
     fn test_close_and_check_status() {
         let pid = Pubkey::new_unique();
@@ -259,6 +329,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_settle_access_pass_lease() {
+        let pid = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let accesspass = Pubkey::new_unique();
+        let (globalstate, _) = get_globalstate_pda(&pid);
+
+        let ix = settle_access_pass_lease(&pid, &payer, &accesspass, SettleAccessPassLeaseArgs {});
+        assert_eq!(ix.data[0], 130);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(accesspass, false),
+                AccountMeta::new_readonly(globalstate, false),
+                AccountMeta::new(incinerator::ID, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
+
     #[test]
     fn test_set_access_pass_feeds() {
         let pid = Pubkey::new_unique();
@@ -9,8 +9,9 @@ use doublezero_serviceability::{
     pda::{get_contributor_pda, get_globalstate_pda},
     processors::contributor::{
         create::ContributorCreateArgs, delete::ContributorDeleteArgs,
-        resume::ContributorResumeArgs, suspend::ContributorSuspendArgs,
-        update::ContributorUpdateArgs,
+        emergency_drain::ContributorEmergencyDrainArgs,
+        emergency_suspend::ContributorEmergencySuspendArgs, resume::ContributorResumeArgs,
+        suspend::ContributorSuspendArgs, update::ContributorUpdateArgs,
     },
 };
 use solana_program::{
@@ -118,6 +119,51 @@ pub fn delete_contributor(
     )
 }
 
+/// `EmergencySuspendContributor` (variant 129). Accounts: `[contributor, globalstate]`.
+pub fn emergency_suspend_contributor(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    contributor: &Pubkey,
+    args: ContributorEmergencySuspendArgs,
+) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    common::build_with_permission(
+        program_id,
+        DoubleZeroInstruction::EmergencySuspendContributor(args),
+        vec![
+            AccountMeta::new(*contributor, false),
+            AccountMeta::new(globalstate, false),
+        ],
+        payer,
+    )
+}
+
+/// `EmergencyDrainContributor` (variant 145). Accounts:
+/// `[contributor, globalstate, resources...]`.
+///
+/// `resources` is one batch of at most `MAX_EMERGENCY_DRAIN_BATCH` of the
+/// suspended contributor's devices and links.
+pub fn emergency_drain_contributor(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    contributor: &Pubkey,
+    resources: &[Pubkey],
+    args: ContributorEmergencyDrainArgs,
+) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    let mut accounts = vec![
+        AccountMeta::new(*contributor, false),
+        AccountMeta::new(globalstate, false),
+    ];
+    accounts.extend(resources.iter().map(|pk| AccountMeta::new(*pk, false)));
+    common::build_with_permission(
+        program_id,
+        DoubleZeroInstruction::EmergencyDrainContributor(args),
+        accounts,
+        payer,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +224,57 @@ mod tests {
             assert_eq!(ix.accounts, expected);
         }
     }
+
+    #[test]
+    fn test_emergency_suspend_contributor() {
+        let pid = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let ix = emergency_suspend_contributor(
+            &pid,
+            &payer,
+            &contributor,
+            ContributorEmergencySuspendArgs {},
+        );
+        assert_eq!(ix.data[0], 129);
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_emergency_drain_contributor_includes_resources() {
+        let pid = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let device = Pubkey::new_unique();
+        let link = Pubkey::new_unique();
+        let ix = emergency_drain_contributor(
+            &pid,
+            &payer,
+            &contributor,
+            &[device, link],
+            ContributorEmergencyDrainArgs {},
+        );
+        assert_eq!(ix.data[0], 145);
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new(device, false),
+                AccountMeta::new(link, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
 }
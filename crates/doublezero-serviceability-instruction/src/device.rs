@@ -11,8 +11,10 @@ use doublezero_serviceability::{
         create::DeviceCreateArgs,
         delete::DeviceDeleteArgs,
         interface::{
-            create::DeviceInterfaceCreateArgs, DeviceInterfaceDeleteArgs, DeviceInterfaceUpdateArgs,
+            create::DeviceInterfaceCreateArgs, DeviceInterfaceBulkUpdateArgs,
+            DeviceInterfaceDeleteArgs, DeviceInterfaceUpdateArgs,
         },
+        rotate_metrics_publisher::DeviceRotateMetricsPublisherArgs,
         sethealth::DeviceSetHealthArgs,
         update::DeviceUpdateArgs,
     },
@@ -325,6 +327,7 @@ pub fn set_device_health(
 /// device                (writable)
 /// contributor           (writable)  — device.contributor_pk
 /// globalstate           (writable)
+/// ip_allocation         (readonly)  — ONLY when `ip_allocation` is given
 /// device_tunnel_block   (writable)  — ResourceType::DeviceTunnelBlock
 /// segment_routing_ids   (writable)  — ResourceType::SegmentRoutingIds
 /// topology[i]           (readonly)  — one per topology_name, ONLY for Vpnv4 loopbacks
@@ -332,11 +335,13 @@ pub fn set_device_health(
 ///
 /// Topology PDAs are appended (and `args.topology_count` set) only when
 /// `args.loopback_type == Vpnv4`; otherwise `topology_names` is ignored.
+/// `args.with_ip_allocation` is written from `ip_allocation`.
 pub fn create_device_interface(
     program_id: &Pubkey,
     payer: &Pubkey,
     device: &Pubkey,
     contributor: &Pubkey,
+    ip_allocation: Option<&Pubkey>,
     topology_names: &[String],
     mut args: DeviceInterfaceCreateArgs,
 ) -> Instruction {
@@ -349,9 +354,13 @@ pub fn create_device_interface(
         AccountMeta::new(*device, false),
         AccountMeta::new(*contributor, false),
         AccountMeta::new(globalstate, false),
-        AccountMeta::new(device_tunnel_block, false),
-        AccountMeta::new(segment_routing_ids, false),
     ];
+    args.with_ip_allocation = ip_allocation.is_some();
+    if let Some(ip_allocation) = ip_allocation {
+        accounts.push(AccountMeta::new_readonly(*ip_allocation, false));
+    }
+    accounts.push(AccountMeta::new(device_tunnel_block, false));
+    accounts.push(AccountMeta::new(segment_routing_ids, false));
 
     let is_vpnv4 = args.loopback_type == LoopbackType::Vpnv4;
     if is_vpnv4 {
@@ -431,6 +440,7 @@ pub fn delete_device_interface(
 /// device                (writable)
 /// contributor           (writable)  — device.contributor_pk
 /// globalstate           (writable)
+/// ip_allocation         (readonly)  — ONLY when `ip_allocation` is given
 /// // present when args.node_segment_idx.is_some() OR topology_names.is_some():
 /// segment_routing_ids   (writable)  — ResourceType::SegmentRoutingIds
 /// // present when topology_names.is_some():
@@ -439,12 +449,14 @@ pub fn delete_device_interface(
 ///
 /// `topology_names` is `None` to leave the flex-algo topology set alone,
 /// `Some(&[])` to clear it, or `Some(names)` to set it exactly.
-/// `args.update_topologies` / `args.topology_count` are written from that choice.
+/// `args.update_topologies` / `args.topology_count` are written from that choice,
+/// and `args.with_ip_allocation` from `ip_allocation`.
 pub fn update_device_interface(
     program_id: &Pubkey,
     payer: &Pubkey,
     device: &Pubkey,
     contributor: &Pubkey,
+    ip_allocation: Option<&Pubkey>,
     topology_names: Option<&[String]>,
     mut args: DeviceInterfaceUpdateArgs,
 ) -> Instruction {
@@ -462,6 +474,10 @@ pub fn update_device_interface(
         AccountMeta::new(*contributor, false),
         AccountMeta::new(globalstate, false),
     ];
+    args.with_ip_allocation = ip_allocation.is_some();
+    if let Some(ip_allocation) = ip_allocation {
+        accounts.push(AccountMeta::new_readonly(*ip_allocation, false));
+    }
 
     if args.node_segment_idx.is_some() || update_topologies {
         let (segment_routing_ids, _, _) =
@@ -483,6 +499,74 @@ pub fn update_device_interface(
     )
 }
 
+/// `BulkUpdateDeviceInterfaces` (variant 131).
+///
+/// Account layout, before the trailing accounts:
+///
+/// ```text
+/// device                (writable)
+/// contributor           (writable)  — device.contributor_pk
+/// globalstate           (writable)
+/// ip_allocation[i]      (readonly)  — one per allocation covering a new CYOA/DIA ip_net
+/// ```
+pub fn bulk_update_device_interfaces(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    device: &Pubkey,
+    contributor: &Pubkey,
+    ip_allocations: &[Pubkey],
+    args: DeviceInterfaceBulkUpdateArgs,
+) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    let mut accounts = vec![
+        AccountMeta::new(*device, false),
+        AccountMeta::new(*contributor, false),
+        AccountMeta::new(globalstate, false),
+    ];
+    accounts.extend(
+        ip_allocations
+            .iter()
+            .map(|ip_allocation| AccountMeta::new_readonly(*ip_allocation, false)),
+    );
+
+    common::build_with_permission(
+        program_id,
+        DoubleZeroInstruction::BulkUpdateDeviceInterfaces(args),
+        accounts,
+        payer,
+    )
+}
+
+/// `RotateDeviceMetricsPublisher` (variant 132).
+///
+/// Account layout, before the trailing accounts:
+///
+/// ```text
+/// device                (writable)
+/// contributor           (writable)  — device.contributor_pk
+/// globalstate           (writable)
+/// ```
+pub fn rotate_device_metrics_publisher(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    device: &Pubkey,
+    contributor: &Pubkey,
+    args: DeviceRotateMetricsPublisherArgs,
+) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    let accounts = vec![
+        AccountMeta::new(*device, false),
+        AccountMeta::new(*contributor, false),
+        AccountMeta::new(globalstate, false),
+    ];
+    common::build_with_permission(
+        program_id,
+        DoubleZeroInstruction::RotateDeviceMetricsPublisher(args),
+        accounts,
+        payer,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -778,6 +862,7 @@ mod tests {
             &payer,
             &device,
             &contributor,
+            None,
             &["TOPO".to_string()],
             DeviceInterfaceCreateArgs::default(),
         );
@@ -823,6 +908,7 @@ mod tests {
             &payer,
             &device,
             &contributor,
+            None,
             &["TOPO-A".to_string(), "TOPO-B".to_string()],
             args,
         );
@@ -893,6 +979,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_device_interface_with_ip_allocation() {
+        let pid = program_id();
+        let payer = Pubkey::new_unique();
+        let device = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let ip_allocation = Pubkey::new_unique();
+        let ix = create_device_interface(
+            &pid,
+            &payer,
+            &device,
+            &contributor,
+            Some(&ip_allocation),
+            &[],
+            DeviceInterfaceCreateArgs::default(),
+        );
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        let (dtb, _, _) = get_resource_extension_pda(&pid, ResourceType::DeviceTunnelBlock);
+        let (sri, _, _) = get_resource_extension_pda(&pid, ResourceType::SegmentRoutingIds);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(device, false),
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new_readonly(ip_allocation, false),
+                AccountMeta::new(dtb, false),
+                AccountMeta::new(sri, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+        match DoubleZeroInstruction::unpack(&ix.data).unwrap() {
+            DoubleZeroInstruction::CreateDeviceInterface(a) => assert!(a.with_ip_allocation),
+            other => panic!("unexpected: {other:?}"),
+        }
+    }
+
     #[test]
     fn test_update_device_interface_none_leaves_topologies() {
         let pid = program_id();
@@ -905,6 +1029,7 @@ mod tests {
             &device,
             &contributor,
             None,
+            None,
             DeviceInterfaceUpdateArgs::default(),
         );
         assert_eq!(ix.data[0], 76);
@@ -931,7 +1056,7 @@ mod tests {
             node_segment_idx: Some(42),
             ..Default::default()
         };
-        let ix = update_device_interface(&pid, &payer, &device, &contributor, None, args);
+        let ix = update_device_interface(&pid, &payer, &device, &contributor, None, None, args);
         let (globalstate, _) = get_globalstate_pda(&pid);
         let (sri, _, _) = get_resource_extension_pda(&pid, ResourceType::SegmentRoutingIds);
         assert_eq!(
@@ -959,6 +1084,7 @@ mod tests {
             &payer,
             &device,
             &contributor,
+            None,
             Some(&names),
             DeviceInterfaceUpdateArgs::default(),
         );
@@ -987,4 +1113,62 @@ mod tests {
             other => panic!("unexpected: {other:?}"),
         }
     }
+
+    #[test]
+    fn test_bulk_update_device_interfaces_appends_ip_allocations() {
+        let pid = program_id();
+        let payer = Pubkey::new_unique();
+        let device = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let allocations = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let ix = bulk_update_device_interfaces(
+            &pid,
+            &payer,
+            &device,
+            &contributor,
+            &allocations,
+            DeviceInterfaceBulkUpdateArgs::default(),
+        );
+        assert_eq!(ix.data[0], 131);
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(device, false),
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new_readonly(allocations[0], false),
+                AccountMeta::new_readonly(allocations[1], false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotate_device_metrics_publisher() {
+        let pid = program_id();
+        let payer = Pubkey::new_unique();
+        let device = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let ix = rotate_device_metrics_publisher(
+            &pid,
+            &payer,
+            &device,
+            &contributor,
+            DeviceRotateMetricsPublisherArgs::default(),
+        );
+        assert_eq!(ix.data[0], 132);
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(device, false),
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
 }
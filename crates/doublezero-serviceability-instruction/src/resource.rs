@@ -10,7 +10,7 @@ use doublezero_serviceability::{
     pda::{get_globalconfig_pda, get_globalstate_pda, get_resource_extension_pda},
     processors::resource::{
         allocate::ResourceAllocateArgs, closeaccount::ResourceExtensionCloseAccountArgs,
-        create::ResourceCreateArgs, deallocate::ResourceDeallocateArgs,
+        create::ResourceCreateArgs, deallocate::ResourceDeallocateArgs, resize::ResourceResizeArgs,
     },
     resource::ResourceType,
 };
@@ -74,6 +74,30 @@ pub fn create_resource(
     )
 }
 
+/// `ResizeResourceExtension` (variant 116).
+/// Accounts: `[resource, associated_account, globalstate, globalconfig]`.
+pub fn resize_resource(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    args: ResourceResizeArgs,
+) -> Instruction {
+    let (resource, _, _) = get_resource_extension_pda(program_id, args.resource_type);
+    let associated = associated_account(args.resource_type);
+    let (globalstate, _) = get_globalstate_pda(program_id);
+    let (globalconfig, _) = get_globalconfig_pda(program_id);
+    common::build_with_permission(
+        program_id,
+        DoubleZeroInstruction::ResizeResourceExtension(args),
+        vec![
+            AccountMeta::new(resource, false),
+            AccountMeta::new(associated, false),
+            AccountMeta::new(globalstate, false),
+            AccountMeta::new(globalconfig, false),
+        ],
+        payer,
+    )
+}
+
 /// `DeallocateResource` (variant 82).
 /// Accounts: `[resource, associated_account, globalstate]`.
 pub fn deallocate_resource(
@@ -177,6 +201,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resize_resource() {
+        let pid = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let device = Pubkey::new_unique();
+        let args = ResourceResizeArgs {
+            resource_type: ResourceType::DzPrefixBlock(device, 0),
+        };
+        let ix = resize_resource(&pid, &payer, args);
+        assert_eq!(ix.data[0], 116);
+        let (resource, _, _) =
+            get_resource_extension_pda(&pid, ResourceType::DzPrefixBlock(device, 0));
+        let (globalstate, _) = get_globalstate_pda(&pid);
+        let (globalconfig, _) = get_globalconfig_pda(&pid);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(resource, false),
+                AccountMeta::new(device, false),
+                AccountMeta::new(globalstate, false),
+                AccountMeta::new(globalconfig, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new(system_program::ID, false),
+            ]
+        );
+    }
+
     #[test]
     fn test_deallocate_resource() {
         let pid = Pubkey::new_unique();
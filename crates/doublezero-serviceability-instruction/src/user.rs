@@ -458,7 +458,9 @@ pub fn check_user_access_pass(
 ///
 /// The processor authorizes by checking that the payer equals the device's
 /// `metrics_publisher_pk` — it does NOT call `authorize()`, so this is assigned to
-/// [`common::build`] (no Permission account). Note there is no globalstate account.
+/// [`common::build`] (no Permission account). Globalstate is appended after
+/// payer/system as an optional account read only by the entrypoint's pause check,
+/// so programs built before the pause ignore it.
 pub fn set_user_bgp_status(
     program_id: &Pubkey,
     payer: &Pubkey,
@@ -466,16 +468,20 @@ pub fn set_user_bgp_status(
     device: &Pubkey,
     args: SetUserBGPStatusArgs,
 ) -> Instruction {
+    let (globalstate, _) = get_globalstate_pda(program_id);
     let accounts = vec![
         AccountMeta::new(*user, false),
         AccountMeta::new_readonly(*device, false),
     ];
-    common::build(
+    let mut ix = common::build(
         program_id,
         DoubleZeroInstruction::SetUserBGPStatus(args),
         accounts,
         payer,
-    )
+    );
+    ix.accounts
+        .push(AccountMeta::new_readonly(globalstate, false));
+    ix
 }
 
 #[cfg(test)]
@@ -495,6 +501,7 @@ mod tests {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 0,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }
     }
 
@@ -599,6 +606,7 @@ mod tests {
             client_ip,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 0,
+            bandwidth_tier: None,
         }
     }
 
@@ -983,6 +991,7 @@ mod tests {
         };
         let ix = set_user_bgp_status(&pid, &payer, &user, &device, args);
         assert_eq!(ix.data[0], 106);
+        let (globalstate, _) = get_globalstate_pda(&pid);
         assert_eq!(
             ix.accounts,
             vec![
@@ -990,6 +999,7 @@ mod tests {
                 AccountMeta::new_readonly(device, false),
                 AccountMeta::new(payer, true),
                 AccountMeta::new(system_program::ID, false),
+                AccountMeta::new_readonly(globalstate, false),
             ]
         );
     }
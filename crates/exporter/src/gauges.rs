@@ -0,0 +1,424 @@
+use std::collections::{BTreeMap, HashMap};
+
+use doublezero_sdk::{health::NetworkHealth, AccountData};
+use doublezero_serviceability::state::accesspass::AccessPass;
+use solana_sdk::pubkey::Pubkey;
+
+/// Access passes expiring within each of these many epochs are counted, so
+/// dashboards can warn before users get locked out.
+pub const EXPIRY_WINDOWS_EPOCHS: [u64; 3] = [1, 7, 30];
+
+/// One gauge value, as published to the Prometheus recorder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gauge {
+    pub name: &'static str,
+    pub labels: Vec<(&'static str, String)>,
+    pub value: f64,
+}
+
+impl Gauge {
+    fn new(name: &'static str, labels: Vec<(&'static str, String)>, value: f64) -> Self {
+        Self {
+            name,
+            labels,
+            value,
+        }
+    }
+
+    pub fn publish(&self) {
+        let labels: Vec<metrics::Label> = self
+            .labels
+            .iter()
+            .map(|(key, value)| metrics::Label::new(*key, value.clone()))
+            .collect();
+        metrics::gauge!(self.name, labels).set(self.value);
+    }
+}
+
+/// Derive every gauge from one snapshot of the serviceability accounts plus
+/// the link telemetry statistics.
+pub fn gauges(
+    program_id: &Pubkey,
+    epoch: u64,
+    accounts: &[(Pubkey, AccountData)],
+    link_latency: &[doublezero_sdk::telemetry::LinkLatencyStats],
+) -> Vec<Gauge> {
+    let mut gauges = vec![Gauge::new("doublezero_epoch", vec![], epoch as f64)];
+
+    let device_codes: HashMap<Pubkey, &str> = accounts
+        .iter()
+        .filter_map(|(pubkey, account)| match account {
+            AccountData::Device(device) => Some((*pubkey, device.code.as_str())),
+            _ => None,
+        })
+        .collect();
+    let device_label = |pubkey: &Pubkey| {
+        device_codes
+            .get(pubkey)
+            .map(|code| code.to_string())
+            .unwrap_or_else(|| pubkey.to_string())
+    };
+
+    let mut by_status: BTreeMap<(&'static str, String), u64> = BTreeMap::new();
+    let mut passes: Vec<&AccessPass> = Vec::new();
+
+    for (_, account) in accounts {
+        match account {
+            AccountData::Device(device) => {
+                *by_status
+                    .entry(("doublezero_devices", device.status.to_string()))
+                    .or_default() += 1;
+                let labels = vec![("device", device.code.clone())];
+                gauges.push(Gauge::new(
+                    "doublezero_device_users",
+                    labels.clone(),
+                    device.users_count as f64,
+                ));
+                gauges.push(Gauge::new(
+                    "doublezero_device_max_users",
+                    labels,
+                    device.max_users as f64,
+                ));
+            }
+            AccountData::Link(link) => {
+                *by_status
+                    .entry(("doublezero_links", link.status.to_string()))
+                    .or_default() += 1;
+                let labels = vec![("link", link.code.clone())];
+                gauges.push(Gauge::new(
+                    "doublezero_link_delay_seconds",
+                    labels.clone(),
+                    link.delay_ns as f64 / 1e9,
+                ));
+                gauges.push(Gauge::new(
+                    "doublezero_link_jitter_seconds",
+                    labels,
+                    link.jitter_ns as f64 / 1e9,
+                ));
+            }
+            AccountData::User(user) => {
+                *by_status
+                    .entry(("doublezero_users", user.status.to_string()))
+                    .or_default() += 1;
+            }
+            AccountData::AccessPass(pass) => {
+                *by_status
+                    .entry(("doublezero_access_passes", pass.status.to_string()))
+                    .or_default() += 1;
+                passes.push(pass);
+            }
+            _ => {}
+        }
+    }
+
+    gauges.extend(
+        by_status.into_iter().map(|((name, status), count)| {
+            Gauge::new(name, vec![("status", status)], count as f64)
+        }),
+    );
+    gauges.extend(access_pass_expiry(epoch, &passes));
+    gauges.extend(network_health(&NetworkHealth::from_accounts(
+        program_id, epoch, accounts,
+    )));
+
+    for stats in link_latency {
+        let labels = vec![
+            (
+                "link",
+                stats
+                    .link_code
+                    .clone()
+                    .unwrap_or_else(|| stats.link_pk.to_string()),
+            ),
+            ("origin", device_label(&stats.origin_device_pk)),
+        ];
+        // Statistics are in milliseconds.
+        for (name, value) in [
+            ("doublezero_link_rtt_mean_seconds", stats.mean / 1e3),
+            ("doublezero_link_rtt_p50_seconds", stats.p50 / 1e3),
+            ("doublezero_link_rtt_p99_seconds", stats.p99 / 1e3),
+            ("doublezero_link_rtt_stddev_seconds", stats.stddev / 1e3),
+            ("doublezero_link_rtt_samples", stats.sample_count as f64),
+        ] {
+            gauges.push(Gauge::new(name, labels.clone(), value));
+        }
+    }
+
+    gauges
+}
+
+/// Devices and links by health, users waiting for BGP, allocator occupancy
+/// and how long ago the health oracle last reported.
+fn network_health(health: &NetworkHealth) -> Vec<Gauge> {
+    let mut gauges = vec![Gauge::new(
+        "doublezero_users_pending",
+        vec![],
+        health.pending_users as f64,
+    )];
+    gauges.extend(health.devices.iter().map(|(state, count)| {
+        Gauge::new(
+            "doublezero_devices_by_health",
+            vec![("health", state.clone())],
+            *count as f64,
+        )
+    }));
+    gauges.extend(health.links.iter().map(|(state, count)| {
+        Gauge::new(
+            "doublezero_links_by_health",
+            vec![("health", state.clone())],
+            *count as f64,
+        )
+    }));
+    if let Some(since) = health.oracle.epochs_since_report {
+        gauges.push(Gauge::new(
+            "doublezero_health_oracle_epochs_since_report",
+            vec![],
+            since as f64,
+        ));
+    }
+    for allocator in &health.allocators {
+        let labels = vec![
+            ("resource", allocator.resource.clone()),
+            ("device", allocator.device.clone().unwrap_or_default()),
+            ("account", allocator.pubkey.to_string()),
+        ];
+        gauges.push(Gauge::new(
+            "doublezero_resource_allocated",
+            labels.clone(),
+            allocator.allocated as f64,
+        ));
+        gauges.push(Gauge::new(
+            "doublezero_resource_capacity",
+            labels,
+            allocator.capacity as f64,
+        ));
+    }
+    gauges
+}
+
+/// Passes already expired, and those expiring within each window. Passes
+/// without an expiry (`u64::MAX`) or not yet paid for (`0`) are skipped.
+fn access_pass_expiry(epoch: u64, passes: &[&AccessPass]) -> Vec<Gauge> {
+    let expiring: Vec<u64> = passes
+        .iter()
+        .map(|pass| pass.last_access_epoch)
+        .filter(|last| *last != 0 && *last != u64::MAX)
+        .collect();
+
+    let mut gauges = vec![Gauge::new(
+        "doublezero_access_passes_expired",
+        vec![],
+        expiring.iter().filter(|last| **last < epoch).count() as f64,
+    )];
+    gauges.extend(EXPIRY_WINDOWS_EPOCHS.iter().map(|window| {
+        let count = expiring
+            .iter()
+            .filter(|last| **last >= epoch && **last - epoch < *window)
+            .count();
+        Gauge::new(
+            "doublezero_access_passes_expiring",
+            vec![("within_epochs", window.to_string())],
+            count as f64,
+        )
+    }));
+    gauges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_sdk::{
+        get_resource_extension_pda, telemetry::LinkLatencyStats, AccountType, Device, DeviceStatus,
+        Link, LinkStatus, ResourceExtensionOwned, ResourceType,
+    };
+    use doublezero_serviceability::{
+        id_allocator::IdAllocator,
+        state::{
+            accesspass::{AccessPassStatus, AccessPassType},
+            device::DeviceHealth,
+            link::LinkHealth,
+            resource_extension::Allocator,
+        },
+    };
+
+    fn find<'a>(gauges: &'a [Gauge], name: &str, labels: &[(&str, &str)]) -> Option<&'a Gauge> {
+        gauges.iter().find(|gauge| {
+            gauge.name == name
+                && labels
+                    .iter()
+                    .all(|(key, value)| gauge.labels.iter().any(|(k, v)| k == key && v == value))
+        })
+    }
+
+    fn access_pass(last_access_epoch: u64) -> AccessPass {
+        AccessPass {
+            account_type: AccountType::AccessPass,
+            owner: Pubkey::new_unique(),
+            bump_seed: 1,
+            accesspass_type: AccessPassType::Prepaid,
+            client_ip: [1, 2, 3, 4].into(),
+            user_payer: Pubkey::new_unique(),
+            last_access_epoch,
+            connection_count: 0,
+            status: AccessPassStatus::Connected,
+            mgroup_pub_allowlist: vec![],
+            mgroup_sub_allowlist: vec![],
+            tenant_allowlist: vec![],
+            flags: 0,
+            unicast_user_count: 0,
+            max_unicast_users: 1,
+            multicast_user_count: 0,
+            max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn test_gauges() {
+        let program_id = Pubkey::new_unique();
+        let device_pk = Pubkey::new_unique();
+        let link_pk = Pubkey::new_unique();
+        let link_ids_pk = get_resource_extension_pda(&program_id, ResourceType::LinkIds).0;
+
+        let device = Device {
+            code: "ams-dz1".to_string(),
+            status: DeviceStatus::Activated,
+            device_health: DeviceHealth::ReadyForUsers,
+            users_count: 12,
+            max_users: 128,
+            ..Default::default()
+        };
+        let link = Link {
+            code: "ams-fra".to_string(),
+            status: LinkStatus::Activated,
+            link_health: LinkHealth::ReadyForService,
+            flap_epoch: 98,
+            delay_ns: 2_500_000,
+            jitter_ns: 100_000,
+            ..Default::default()
+        };
+        let link_ids = ResourceExtensionOwned {
+            account_type: AccountType::ResourceExtension,
+            owner: program_id,
+            bump_seed: 255,
+            associated_with: Pubkey::default(),
+            allocator: Allocator::Id(IdAllocator::new((0, 64)).unwrap()),
+            storage: vec![0b1011, 0, 0, 0, 0, 0, 0, 0],
+        };
+        let accounts = vec![
+            (device_pk, AccountData::Device(device)),
+            (link_pk, AccountData::Link(link)),
+            (link_ids_pk, AccountData::ResourceExtension(link_ids)),
+            (
+                Pubkey::new_unique(),
+                AccountData::AccessPass(access_pass(99)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::AccessPass(access_pass(100)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::AccessPass(access_pass(105)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::AccessPass(access_pass(u64::MAX)),
+            ),
+        ];
+        let latency = LinkLatencyStats {
+            epoch: 100,
+            link_pk,
+            link_code: Some("ams-fra".to_string()),
+            origin_device_pk: device_pk,
+            target_device_pk: Pubkey::new_unique(),
+            sample_count: 10,
+            p50: 2.0,
+            p90: 2.5,
+            p95: 2.6,
+            p99: 3.0,
+            mean: 2.1,
+            min: 1.9,
+            max: 3.1,
+            stddev: 0.2,
+        };
+
+        let gauges = gauges(&program_id, 100, &accounts, &[latency]);
+        let value = |name: &str, labels: &[(&str, &str)]| {
+            find(&gauges, name, labels)
+                .unwrap_or_else(|| panic!("missing {name} {labels:?}"))
+                .value
+        };
+
+        assert_eq!(value("doublezero_epoch", &[]), 100.0);
+        assert_eq!(value("doublezero_devices", &[("status", "activated")]), 1.0);
+        assert_eq!(value("doublezero_links", &[("status", "activated")]), 1.0);
+        assert_eq!(
+            value(
+                "doublezero_devices_by_health",
+                &[("health", "ready-for-users")]
+            ),
+            1.0
+        );
+        assert_eq!(
+            value(
+                "doublezero_links_by_health",
+                &[("health", "ready-for-service")]
+            ),
+            1.0
+        );
+        assert_eq!(value("doublezero_users_pending", &[]), 0.0);
+        assert_eq!(
+            value("doublezero_health_oracle_epochs_since_report", &[]),
+            2.0
+        );
+        assert_eq!(
+            value("doublezero_device_users", &[("device", "ams-dz1")]),
+            12.0
+        );
+        assert_eq!(
+            value("doublezero_device_max_users", &[("device", "ams-dz1")]),
+            128.0
+        );
+        assert_eq!(
+            value("doublezero_link_delay_seconds", &[("link", "ams-fra")]),
+            0.0025
+        );
+        assert_eq!(
+            value(
+                "doublezero_link_rtt_p50_seconds",
+                &[("link", "ams-fra"), ("origin", "ams-dz1")]
+            ),
+            0.002
+        );
+        assert_eq!(
+            value("doublezero_resource_allocated", &[("resource", "LinkIds")]),
+            3.0
+        );
+        assert_eq!(
+            value("doublezero_resource_capacity", &[("resource", "LinkIds")]),
+            64.0
+        );
+        assert_eq!(
+            value("doublezero_access_passes", &[("status", "connected")]),
+            4.0
+        );
+        assert_eq!(value("doublezero_access_passes_expired", &[]), 1.0);
+        assert_eq!(
+            value(
+                "doublezero_access_passes_expiring",
+                &[("within_epochs", "1")]
+            ),
+            1.0
+        );
+        assert_eq!(
+            value(
+                "doublezero_access_passes_expiring",
+                &[("within_epochs", "7")]
+            ),
+            2.0
+        );
+        assert!(find(&gauges, "doublezero_users", &[]).is_none());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod gauges;
+pub mod settings;
+pub mod snapshot;
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use clap::Parser;
+use doublezero_exporter::{gauges::gauges, settings::AppArgs, snapshot::Snapshot};
+use doublezero_sdk::DZClient;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use metrics_util::MetricKindMask;
+use tokio::signal;
+use tracing::{error, info};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let args = AppArgs::parse();
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(&args.log))
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let interval = args.interval();
+    // Drop series for accounts that went away: anything not refreshed by
+    // the last two scrapes is stale.
+    PrometheusBuilder::new()
+        .with_http_listener(args.metrics_addr)
+        .idle_timeout(
+            MetricKindMask::GAUGE,
+            Some(interval * 2 + Duration::from_secs(1)),
+        )
+        .install()?;
+
+    export_build_info();
+
+    let config = args.network_config()?;
+    info!(
+        env = %args.env,
+        dz_rpc_url = %config.ledger_public_rpc_url,
+        metrics_addr = %args.metrics_addr,
+        interval_secs = interval.as_secs(),
+        "DoubleZero Exporter starting"
+    );
+
+    let client = DZClient::new(
+        Some(config.ledger_public_rpc_url.clone()),
+        Some(config.ledger_public_ws_rpc_url.clone()),
+        Some(config.serviceability_program_id.to_string()),
+        None,
+    )?;
+    let program_id = config.serviceability_program_id;
+    let telemetry_program_id = config.telemetry_program_id;
+
+    let mut ticker = tokio::time::interval(interval);
+    let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())?;
+    loop {
+        tokio::select! {
+            biased;
+            _ = sigterm.recv() => break,
+            _ = signal::ctrl_c() => break,
+            _ = ticker.tick() => {
+                // RPC reads are blocking; keep them off the async workers.
+                let snapshot = tokio::task::block_in_place(|| {
+                    Snapshot::fetch(&client, &telemetry_program_id)
+                });
+                match snapshot {
+                    Ok(snapshot) => {
+                        let gauges = gauges(
+                            &program_id,
+                            snapshot.epoch,
+                            &snapshot.accounts,
+                            &snapshot.link_latency,
+                        );
+                        gauges.iter().for_each(|gauge| gauge.publish());
+                        metrics::gauge!("doublezero_exporter_last_success_timestamp_seconds")
+                            .set(std::time::SystemTime::now()
+                                .duration_since(std::time::UNIX_EPOCH)
+                                .unwrap_or_default()
+                                .as_secs_f64());
+                        info!(accounts = snapshot.accounts.len(), gauges = gauges.len(), "scraped");
+                    }
+                    Err(err) => {
+                        metrics::counter!("doublezero_exporter_scrape_errors").increment(1);
+                        error!(?err, "failed to read onchain state");
+                    }
+                }
+            }
+        }
+    }
+
+    info!("DoubleZero Exporter shutting down");
+
+    Ok(())
+}
+
+fn export_build_info() {
+    let version = option_env!("BUILD_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
+    let build_commit = option_env!("BUILD_COMMIT").unwrap_or("UNKNOWN");
+    let build_date = option_env!("DATE").unwrap_or("UNKNOWN");
+    let pkg_version = env!("CARGO_PKG_VERSION");
+
+    metrics::gauge!(
+        "doublezero_exporter_build_info",
+        "version" => version,
+        "commit" => build_commit,
+        "date" => build_date,
+        "pkg_version" => pkg_version
+    )
+    .set(1);
+}
@@ -0,0 +1,74 @@
+use std::{net::SocketAddr, time::Duration};
+
+use clap::Parser;
+use doublezero_config::{Environment, NetworkConfig};
+
+#[derive(Debug, Parser)]
+#[command(
+    term_width = 0,
+    name = "DoubleZero Exporter",
+    version = option_env!("BUILD_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"))
+)]
+pub struct AppArgs {
+    /// DZ ledger environment (devnet, testnet, mainnet-beta, local, or custom:<name>).
+    #[arg(long)]
+    pub env: Environment,
+
+    /// DZ ledger RPC URL. Defaults to the environment's public RPC.
+    #[arg(long)]
+    pub dz_rpc: Option<String>,
+
+    /// Log filter (e.g. "doublezero_exporter=debug").
+    #[arg(long, default_value = "doublezero_exporter=info")]
+    pub log: String,
+
+    /// Metrics listen address.
+    #[arg(long, default_value = "127.0.0.1:2113")]
+    pub metrics_addr: SocketAddr,
+
+    /// Seconds between reads of the onchain state.
+    #[arg(long, default_value = "60")]
+    pub interval: u64,
+}
+
+impl AppArgs {
+    pub fn network_config(&self) -> eyre::Result<NetworkConfig> {
+        let mut config = self.env.config()?;
+        if let Some(dz_rpc) = &self.dz_rpc {
+            config.ledger_public_rpc_url = dz_rpc.clone();
+            config.ledger_public_ws_rpc_url = doublezero_sdk::convert_url_to_ws(dz_rpc)?;
+        }
+        Ok(config)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dz_rpc_overrides_environment() {
+        let args = AppArgs::parse_from([
+            "doublezero-exporter",
+            "--env",
+            "devnet",
+            "--dz-rpc",
+            "http://localhost:8899",
+        ]);
+        let config = args.network_config().unwrap();
+        assert_eq!(config.ledger_public_rpc_url, "http://localhost:8899");
+        assert_eq!(config.ledger_public_ws_rpc_url, "ws://localhost:8899/");
+        assert_eq!(
+            config.serviceability_program_id,
+            Environment::Devnet
+                .config()
+                .unwrap()
+                .serviceability_program_id
+        );
+        assert_eq!(args.interval(), Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,65 @@
+use doublezero_sdk::{
+    telemetry::{calculate_stats, get_all_device_latency_samples, LinkLatencyStats},
+    AccountData, DoubleZeroClient,
+};
+use solana_sdk::pubkey::Pubkey;
+use tracing::warn;
+
+/// Everything one scrape reads from the ledger.
+#[derive(Debug, Default, Clone)]
+pub struct Snapshot {
+    pub epoch: u64,
+    pub accounts: Vec<(Pubkey, AccountData)>,
+    /// Per-link RTT statistics from the current epoch's telemetry samples.
+    pub link_latency: Vec<LinkLatencyStats>,
+}
+
+impl Snapshot {
+    /// Read all serviceability accounts and the current epoch's link
+    /// telemetry. Telemetry is best effort: when it can't be read the
+    /// snapshot still carries the serviceability state.
+    pub fn fetch(
+        client: &dyn DoubleZeroClient,
+        telemetry_program_id: &Pubkey,
+    ) -> eyre::Result<Self> {
+        let epoch = client.get_epoch()?;
+        let accounts: Vec<(Pubkey, AccountData)> = client
+            .get_all()?
+            .into_iter()
+            .map(|(pubkey, account)| (*pubkey, *account))
+            .collect();
+
+        let samples = get_all_device_latency_samples(client, telemetry_program_id, epoch)
+            .unwrap_or_else(|err| {
+                warn!(?err, epoch, "failed to read link telemetry");
+                Default::default()
+            });
+        // Links without samples yet this epoch are skipped rather than
+        // failing the whole scrape.
+        let link_latency = samples
+            .values()
+            .filter_map(|samples| {
+                let link_pk = samples.header.link_pk;
+                let code = accounts.iter().find_map(|(pubkey, account)| match account {
+                    AccountData::Link(link) if *pubkey == link_pk => Some(link.code.clone()),
+                    _ => None,
+                });
+                calculate_stats(
+                    epoch,
+                    link_pk,
+                    code,
+                    samples.header.origin_device_pk,
+                    samples.header.target_device_pk,
+                    &samples.samples,
+                )
+                .ok()
+            })
+            .collect();
+
+        Ok(Self {
+            epoch,
+            accounts,
+            link_latency,
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod rows;
+pub mod schema;
+pub mod settings;
+pub mod store;
+pub mod sync;
@@ -0,0 +1,93 @@
+use clap::Parser;
+use doublezero_indexer::{
+    settings::AppArgs,
+    store::Store,
+    sync::{self, Update},
+};
+use doublezero_sdk::DZClient;
+use tokio::{signal, sync::mpsc};
+use tokio_postgres::NoTls;
+use tracing::{error, info};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let args = AppArgs::parse();
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(&args.log))
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let config = args.network_config()?;
+    let reconcile_interval = args.reconcile_interval();
+    info!(
+        env = %args.env,
+        dz_rpc_url = %config.ledger_public_rpc_url,
+        reconcile_interval_secs = reconcile_interval.as_secs(),
+        "DoubleZero Indexer starting"
+    );
+
+    let (pg_client, connection) = tokio_postgres::connect(&args.database_url, NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            error!(?err, "postgres connection failed");
+        }
+    });
+    let mut store = Store::new(pg_client);
+    store.migrate().await?;
+
+    let client = DZClient::new(
+        Some(config.ledger_public_rpc_url.clone()),
+        Some(config.ledger_public_ws_rpc_url.clone()),
+        Some(config.serviceability_program_id.to_string()),
+        None,
+    )?;
+
+    let (sender, mut updates) = mpsc::channel(1024);
+    let ws_url = config.ledger_public_ws_rpc_url.clone();
+    let program_id = config.serviceability_program_id;
+    std::thread::spawn(move || sync::subscribe(&ws_url, &program_id, sender));
+
+    // The first tick fires immediately: start from a full snapshot.
+    let mut ticker = tokio::time::interval(reconcile_interval);
+    let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())?;
+    loop {
+        tokio::select! {
+            biased;
+            _ = sigterm.recv() => break,
+            _ = signal::ctrl_c() => break,
+            _ = ticker.tick() => {
+                // RPC reads are blocking; keep them off the async workers.
+                let snapshot = tokio::task::block_in_place(|| sync::snapshot(&client));
+                match snapshot {
+                    Ok(snapshot) => match store.reconcile(&snapshot).await {
+                        Ok(stats) => info!(
+                            accounts = snapshot.rows.len(),
+                            slot = snapshot.slot,
+                            changed = stats.changed,
+                            deleted = stats.deleted,
+                            "reconciled"
+                        ),
+                        Err(err) => error!(?err, "failed to reconcile"),
+                    },
+                    Err(err) => error!(?err, "failed to read program accounts"),
+                }
+            }
+            Some(update) = updates.recv() => {
+                let result = match &update {
+                    Update::Upsert(row) => store.upsert(row).await,
+                    Update::Closed { pubkey, slot } => store.delete(pubkey, *slot).await,
+                };
+                if let Err(err) = result {
+                    // The next reconciliation picks it up.
+                    error!(?err, ?update, "failed to apply update");
+                }
+            }
+        }
+    }
+
+    info!("DoubleZero Indexer shutting down");
+
+    Ok(())
+}
@@ -0,0 +1,305 @@
+//! Mapping of serviceability accounts onto rows of the [`crate::schema`]
+//! tables.
+
+use doublezero_serviceability::state::accountdata::AccountData;
+use solana_sdk::pubkey::Pubkey;
+use tokio_postgres::types::ToSql;
+
+use crate::schema::{Table, ACCESS_PASSES, DEVICES, LINKS, LOCATIONS, USERS};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Integer(i32),
+    BigInt(i64),
+    Double(f64),
+}
+
+impl Value {
+    fn text(value: impl ToString) -> Self {
+        Value::Text(value.to_string())
+    }
+
+    /// Postgres has no unsigned 64-bit type; values past `i64::MAX` (such as
+    /// the `u64::MAX` of a pass that never expires) are stored as `i64::MAX`.
+    fn bigint(value: u64) -> Self {
+        Value::BigInt(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+
+    fn as_sql(&self) -> &(dyn ToSql + Sync) {
+        match self {
+            Value::Text(value) => value,
+            Value::Integer(value) => value,
+            Value::BigInt(value) => value,
+            Value::Double(value) => value,
+        }
+    }
+}
+
+/// One account as a row of `table`, with values in the table's column order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub table: &'static Table,
+    pub pubkey: String,
+    pub values: Vec<Value>,
+    /// Context slot the account was read at.
+    pub slot: i64,
+}
+
+impl Row {
+    /// Statement parameters: the pubkey, then the column values, then the
+    /// slot.
+    pub fn params(&self) -> Vec<&(dyn ToSql + Sync)> {
+        std::iter::once(&self.pubkey as &(dyn ToSql + Sync))
+            .chain(self.values.iter().map(Value::as_sql))
+            .chain(std::iter::once(&self.slot as &(dyn ToSql + Sync)))
+            .collect()
+    }
+}
+
+/// A slot as stored in the `slot` columns.
+pub fn slot_param(slot: u64) -> i64 {
+    i64::try_from(slot).unwrap_or(i64::MAX)
+}
+
+/// The row for `account` read at `slot`, or `None` for account types that
+/// are not indexed.
+pub fn row(pubkey: &Pubkey, account: &AccountData, slot: u64) -> Option<Row> {
+    let (table, values) = match account {
+        AccountData::Location(location) => (
+            &LOCATIONS,
+            vec![
+                Value::text(location.owner),
+                Value::text(&location.code),
+                Value::text(&location.name),
+                Value::text(&location.country),
+                Value::Double(location.lat),
+                Value::Double(location.lng),
+                Value::text(location.status),
+            ],
+        ),
+        AccountData::Device(device) => (
+            &DEVICES,
+            vec![
+                Value::text(device.owner),
+                Value::text(&device.code),
+                Value::text(device.contributor_pk),
+                Value::text(device.location_pk),
+                Value::text(device.exchange_pk),
+                Value::text(device.device_type),
+                Value::text(device.public_ip),
+                Value::text(&device.dz_prefixes),
+                Value::text(device.status),
+                Value::text(device.device_health),
+                Value::text(device.desired_status),
+                Value::Integer(device.users_count.into()),
+                Value::Integer(device.max_users.into()),
+            ],
+        ),
+        AccountData::Link(link) => (
+            &LINKS,
+            vec![
+                Value::text(link.owner),
+                Value::text(&link.code),
+                Value::text(link.contributor_pk),
+                Value::text(link.side_a_pk),
+                Value::text(&link.side_a_iface_name),
+                Value::text(link.side_z_pk),
+                Value::text(&link.side_z_iface_name),
+                Value::text(link.link_type),
+                Value::bigint(link.bandwidth),
+                Value::bigint(link.mtu.into()),
+                Value::bigint(link.delay_ns),
+                Value::bigint(link.jitter_ns),
+                Value::bigint(link.delay_override_ns),
+                Value::Integer(link.tunnel_id.into()),
+                Value::text(link.tunnel_net),
+                Value::text(link.status),
+                Value::text(link.link_health),
+                Value::text(link.desired_status),
+            ],
+        ),
+        AccountData::User(user) => (
+            &USERS,
+            vec![
+                Value::text(user.owner),
+                Value::text(user.user_type),
+                Value::text(user.tenant_pk),
+                Value::text(user.device_pk),
+                Value::text(user.cyoa_type),
+                Value::text(user.client_ip),
+                Value::text(user.dz_ip),
+                Value::Integer(user.tunnel_id.into()),
+                Value::text(user.tunnel_net),
+                Value::text(user.status),
+                Value::text(user.validator_pubkey),
+                Value::text(user.bgp_status),
+                Value::text(user.bandwidth_tier),
+            ],
+        ),
+        AccountData::AccessPass(accesspass) => (
+            &ACCESS_PASSES,
+            vec![
+                Value::text(accesspass.owner),
+                Value::text(&accesspass.accesspass_type),
+                Value::text(accesspass.client_ip),
+                Value::text(accesspass.user_payer),
+                Value::bigint(accesspass.last_access_epoch),
+                Value::Integer(accesspass.connection_count.into()),
+                Value::text(accesspass.status),
+                Value::text(accesspass.bandwidth_tier),
+            ],
+        ),
+        _ => return None,
+    };
+    debug_assert_eq!(values.len(), table.columns.len(), "{}", table.name);
+
+    Some(Row {
+        table,
+        pubkey: pubkey.to_string(),
+        values,
+        slot: slot_param(slot),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{SqlType, TABLES};
+    use doublezero_serviceability::state::{
+        accesspass::{AccessPass, AccessPassStatus, AccessPassType},
+        accounttype::AccountType,
+        device::{Device, DeviceStatus},
+        link::Link,
+        location::{Location, LocationStatus},
+        user::{BGPStatus, User, UserCYOA, UserStatus, UserType},
+    };
+
+    fn location() -> Location {
+        Location {
+            account_type: AccountType::Location,
+            owner: Pubkey::new_unique(),
+            index: 1,
+            bump_seed: 1,
+            lat: 52.37,
+            lng: 4.9,
+            loc_id: 1,
+            status: LocationStatus::Activated,
+            code: "ams".to_string(),
+            name: "Amsterdam".to_string(),
+            country: "NL".to_string(),
+            reference_count: 0,
+        }
+    }
+
+    fn user() -> User {
+        User {
+            account_type: AccountType::User,
+            owner: Pubkey::new_unique(),
+            index: 1,
+            bump_seed: 1,
+            user_type: UserType::IBRL,
+            tenant_pk: Pubkey::new_unique(),
+            device_pk: Pubkey::new_unique(),
+            cyoa_type: UserCYOA::GREOverDIA,
+            client_ip: [1, 2, 3, 4].into(),
+            dz_ip: [10, 0, 0, 1].into(),
+            tunnel_id: 500,
+            tunnel_net: "169.254.0.0/31".parse().unwrap(),
+            status: UserStatus::Activated,
+            publishers: vec![],
+            subscribers: vec![],
+            validator_pubkey: Pubkey::new_unique(),
+            tunnel_endpoint: [0, 0, 0, 0].into(),
+            tunnel_flags: 0,
+            bgp_status: BGPStatus::Up,
+            last_bgp_up_at: 0,
+            last_bgp_reported_at: 0,
+            bgp_rtt_ns: 0,
+            feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
+        }
+    }
+
+    fn access_pass() -> AccessPass {
+        AccessPass {
+            account_type: AccountType::AccessPass,
+            owner: Pubkey::new_unique(),
+            bump_seed: 1,
+            accesspass_type: AccessPassType::Prepaid,
+            client_ip: [1, 2, 3, 4].into(),
+            user_payer: Pubkey::new_unique(),
+            last_access_epoch: u64::MAX,
+            connection_count: 2,
+            status: AccessPassStatus::Connected,
+            mgroup_pub_allowlist: vec![],
+            mgroup_sub_allowlist: vec![],
+            tenant_allowlist: vec![],
+            flags: 0,
+            unicast_user_count: 0,
+            max_unicast_users: 1,
+            multicast_user_count: 0,
+            max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn test_rows_match_table_columns() {
+        let accounts = [
+            AccountData::Location(location()),
+            AccountData::Device(Device::default()),
+            AccountData::Link(Link::default()),
+            AccountData::User(user()),
+            AccountData::AccessPass(access_pass()),
+        ];
+        for (account, table) in accounts.iter().zip(TABLES) {
+            let row = row(&Pubkey::new_unique(), account, 1).unwrap();
+            assert_eq!(row.table, table);
+            for (value, column) in row.values.iter().zip(table.columns) {
+                let sql_type = match value {
+                    Value::Text(_) => SqlType::Text,
+                    Value::Integer(_) => SqlType::Integer,
+                    Value::BigInt(_) => SqlType::BigInt,
+                    Value::Double(_) => SqlType::Double,
+                };
+                assert_eq!(sql_type, column.sql_type, "{}.{}", table.name, column.name);
+            }
+            assert_eq!(row.params().len(), table.columns.len() + 2);
+        }
+    }
+
+    #[test]
+    fn test_device_row() {
+        let pubkey = Pubkey::new_unique();
+        let device = Device {
+            code: "ams-dz1".to_string(),
+            status: DeviceStatus::Activated,
+            users_count: 12,
+            ..Default::default()
+        };
+        let row = row(&pubkey, &AccountData::Device(device), 42).unwrap();
+        assert_eq!(row.pubkey, pubkey.to_string());
+        assert_eq!(row.slot, 42);
+        assert_eq!(row.values[1], Value::Text("ams-dz1".to_string()));
+        assert_eq!(row.values[8], Value::Text("activated".to_string()));
+        assert_eq!(row.values[11], Value::Integer(12));
+    }
+
+    #[test]
+    fn test_access_pass_never_expires() {
+        let row = row(
+            &Pubkey::new_unique(),
+            &AccountData::AccessPass(access_pass()),
+            1,
+        )
+        .unwrap();
+        assert_eq!(row.values[4], Value::BigInt(i64::MAX));
+    }
+
+    #[test]
+    fn test_unindexed_account() {
+        assert_eq!(row(&Pubkey::new_unique(), &AccountData::None, 1), None);
+    }
+}
@@ -0,0 +1,316 @@
+//! Postgres tables the indexer maintains.
+//!
+//! Every indexed account type has a table holding the current row per
+//! account, keyed by pubkey, and a `_history` table holding every version
+//! of it with the `[valid_from, valid_to)` range it was current for. The open
+//! version has a NULL `valid_to`. Both are derived from the same column list,
+//! so the SQL below is generated rather than written per table.
+//!
+//! Rows carry the context slot they were read at. Updates from the websocket
+//! and from snapshots race, so a write only lands if its slot is not older
+//! than the row's, and closed accounts leave a tombstone in
+//! `closed_accounts` so that a stale update cannot bring them back.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlType {
+    Text,
+    Integer,
+    BigInt,
+    Double,
+}
+
+impl SqlType {
+    fn as_sql(self) -> &'static str {
+        match self {
+            SqlType::Text => "TEXT",
+            SqlType::Integer => "INTEGER",
+            SqlType::BigInt => "BIGINT",
+            SqlType::Double => "DOUBLE PRECISION",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub sql_type: SqlType,
+}
+
+const fn col(name: &'static str, sql_type: SqlType) -> Column {
+    Column { name, sql_type }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Table {
+    pub name: &'static str,
+    pub columns: &'static [Column],
+}
+
+pub const LOCATIONS: Table = Table {
+    name: "locations",
+    columns: &[
+        col("owner", SqlType::Text),
+        col("code", SqlType::Text),
+        col("name", SqlType::Text),
+        col("country", SqlType::Text),
+        col("lat", SqlType::Double),
+        col("lng", SqlType::Double),
+        col("status", SqlType::Text),
+    ],
+};
+
+pub const DEVICES: Table = Table {
+    name: "devices",
+    columns: &[
+        col("owner", SqlType::Text),
+        col("code", SqlType::Text),
+        col("contributor_pk", SqlType::Text),
+        col("location_pk", SqlType::Text),
+        col("exchange_pk", SqlType::Text),
+        col("device_type", SqlType::Text),
+        col("public_ip", SqlType::Text),
+        col("dz_prefixes", SqlType::Text),
+        col("status", SqlType::Text),
+        col("health", SqlType::Text),
+        col("desired_status", SqlType::Text),
+        col("users_count", SqlType::Integer),
+        col("max_users", SqlType::Integer),
+    ],
+};
+
+pub const LINKS: Table = Table {
+    name: "links",
+    columns: &[
+        col("owner", SqlType::Text),
+        col("code", SqlType::Text),
+        col("contributor_pk", SqlType::Text),
+        col("side_a_pk", SqlType::Text),
+        col("side_a_iface_name", SqlType::Text),
+        col("side_z_pk", SqlType::Text),
+        col("side_z_iface_name", SqlType::Text),
+        col("link_type", SqlType::Text),
+        col("bandwidth", SqlType::BigInt),
+        col("mtu", SqlType::BigInt),
+        col("delay_ns", SqlType::BigInt),
+        col("jitter_ns", SqlType::BigInt),
+        col("delay_override_ns", SqlType::BigInt),
+        col("tunnel_id", SqlType::Integer),
+        col("tunnel_net", SqlType::Text),
+        col("status", SqlType::Text),
+        col("health", SqlType::Text),
+        col("desired_status", SqlType::Text),
+    ],
+};
+
+pub const USERS: Table = Table {
+    name: "users",
+    columns: &[
+        col("owner", SqlType::Text),
+        col("user_type", SqlType::Text),
+        col("tenant_pk", SqlType::Text),
+        col("device_pk", SqlType::Text),
+        col("cyoa_type", SqlType::Text),
+        col("client_ip", SqlType::Text),
+        col("dz_ip", SqlType::Text),
+        col("tunnel_id", SqlType::Integer),
+        col("tunnel_net", SqlType::Text),
+        col("status", SqlType::Text),
+        col("validator_pubkey", SqlType::Text),
+        col("bgp_status", SqlType::Text),
+        col("bandwidth_tier", SqlType::Text),
+    ],
+};
+
+pub const ACCESS_PASSES: Table = Table {
+    name: "access_passes",
+    columns: &[
+        col("owner", SqlType::Text),
+        col("accesspass_type", SqlType::Text),
+        col("client_ip", SqlType::Text),
+        col("user_payer", SqlType::Text),
+        // i64::MAX for passes that never expire.
+        col("last_access_epoch", SqlType::BigInt),
+        col("connection_count", SqlType::Integer),
+        col("status", SqlType::Text),
+        col("bandwidth_tier", SqlType::Text),
+    ],
+};
+
+pub const TABLES: [&Table; 5] = [&LOCATIONS, &DEVICES, &LINKS, &USERS, &ACCESS_PASSES];
+
+/// Idempotent DDL for the tombstones of closed accounts.
+pub const CREATE_TOMBSTONES: &str =
+    "CREATE TABLE IF NOT EXISTS closed_accounts (pubkey TEXT PRIMARY KEY, slot BIGINT NOT NULL)";
+
+/// Record that `$1` was closed as of slot `$2`, keeping the latest slot.
+pub const RECORD_TOMBSTONE: &str = "INSERT INTO closed_accounts (pubkey, slot) VALUES ($1, $2) \
+     ON CONFLICT (pubkey) DO UPDATE SET slot = GREATEST(closed_accounts.slot, EXCLUDED.slot)";
+
+/// Slot at which `$1` was last seen closed, if ever.
+pub const TOMBSTONE_SLOT: &str = "SELECT slot FROM closed_accounts WHERE pubkey = $1";
+
+impl Table {
+    pub fn history_name(&self) -> String {
+        format!("{}_history", self.name)
+    }
+
+    fn column_list(&self) -> String {
+        self.columns
+            .iter()
+            .map(|column| column.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// `$1` is the pubkey, `$2..` the columns in order, then the slot.
+    fn placeholders(&self) -> String {
+        (1..=self.columns.len() + 2)
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Idempotent DDL for the current and history tables.
+    pub fn create_statements(&self) -> Vec<String> {
+        let columns: String = self
+            .columns
+            .iter()
+            .map(|column| format!(", {} {}", column.name, column.sql_type.as_sql()))
+            .collect();
+        let history = self.history_name();
+        vec![
+            format!(
+                "CREATE TABLE IF NOT EXISTS {} (pubkey TEXT PRIMARY KEY{columns}, slot BIGINT NOT NULL, updated_at TIMESTAMPTZ NOT NULL)",
+                self.name
+            ),
+            format!(
+                "CREATE TABLE IF NOT EXISTS {history} (pubkey TEXT NOT NULL{columns}, slot BIGINT NOT NULL, valid_from TIMESTAMPTZ NOT NULL, valid_to TIMESTAMPTZ, PRIMARY KEY (pubkey, valid_from))"
+            ),
+            format!(
+                "CREATE UNIQUE INDEX IF NOT EXISTS {history}_open ON {history} (pubkey) WHERE valid_to IS NULL"
+            ),
+        ]
+    }
+
+    /// Insert or update the current row. Affects no row when nothing
+    /// changed or the row was read at a later slot, which is how callers tell
+    /// whether to record a new version.
+    pub fn upsert_statement(&self) -> String {
+        let updates: String = self
+            .columns
+            .iter()
+            .map(|column| format!("{0} = EXCLUDED.{0}, ", column.name))
+            .collect();
+        let current = self
+            .columns
+            .iter()
+            .map(|column| format!("{}.{}", self.name, column.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let excluded = self
+            .columns
+            .iter()
+            .map(|column| format!("EXCLUDED.{}", column.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "INSERT INTO {0} (pubkey, {1}, slot, updated_at) VALUES ({2}, now()) \
+             ON CONFLICT (pubkey) DO UPDATE SET {updates}slot = EXCLUDED.slot, updated_at = EXCLUDED.updated_at \
+             WHERE EXCLUDED.slot >= {0}.slot AND ({current}) IS DISTINCT FROM ({excluded})",
+            self.name,
+            self.column_list(),
+            self.placeholders(),
+        )
+    }
+
+    /// Move the slot of an unchanged row forward to `$2`, so that an older
+    /// update arriving later is still recognized as stale.
+    pub fn touch_statement(&self) -> String {
+        format!(
+            "UPDATE {} SET slot = $2 WHERE pubkey = $1 AND slot < $2",
+            self.name
+        )
+    }
+
+    /// Delete the row of `$1` unless it was read after slot `$2`.
+    pub fn delete_statement(&self) -> String {
+        format!("DELETE FROM {} WHERE pubkey = $1 AND slot <= $2", self.name)
+    }
+
+    pub fn pubkeys_statement(&self) -> String {
+        format!("SELECT pubkey FROM {}", self.name)
+    }
+
+    /// End the open version of an account, if any.
+    pub fn close_history_statement(&self) -> String {
+        format!(
+            "UPDATE {} SET valid_to = now() WHERE pubkey = $1 AND valid_to IS NULL",
+            self.history_name()
+        )
+    }
+
+    /// Open a new version, with the same parameters as the upsert.
+    pub fn insert_history_statement(&self) -> String {
+        format!(
+            "INSERT INTO {} (pubkey, {}, slot, valid_from) VALUES ({}, now())",
+            self.history_name(),
+            self.column_list(),
+            self.placeholders(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDGETS: Table = Table {
+        name: "widgets",
+        columns: &[col("code", SqlType::Text), col("size", SqlType::BigInt)],
+    };
+
+    #[test]
+    fn test_statements() {
+        assert_eq!(
+            WIDGETS.create_statements(),
+            [
+                "CREATE TABLE IF NOT EXISTS widgets (pubkey TEXT PRIMARY KEY, code TEXT, size BIGINT, slot BIGINT NOT NULL, updated_at TIMESTAMPTZ NOT NULL)",
+                "CREATE TABLE IF NOT EXISTS widgets_history (pubkey TEXT NOT NULL, code TEXT, size BIGINT, slot BIGINT NOT NULL, valid_from TIMESTAMPTZ NOT NULL, valid_to TIMESTAMPTZ, PRIMARY KEY (pubkey, valid_from))",
+                "CREATE UNIQUE INDEX IF NOT EXISTS widgets_history_open ON widgets_history (pubkey) WHERE valid_to IS NULL",
+            ]
+        );
+        assert_eq!(
+            WIDGETS.upsert_statement(),
+            "INSERT INTO widgets (pubkey, code, size, slot, updated_at) VALUES ($1, $2, $3, $4, now()) \
+             ON CONFLICT (pubkey) DO UPDATE SET code = EXCLUDED.code, size = EXCLUDED.size, slot = EXCLUDED.slot, updated_at = EXCLUDED.updated_at \
+             WHERE EXCLUDED.slot >= widgets.slot AND (widgets.code, widgets.size) IS DISTINCT FROM (EXCLUDED.code, EXCLUDED.size)"
+        );
+        assert_eq!(
+            WIDGETS.insert_history_statement(),
+            "INSERT INTO widgets_history (pubkey, code, size, slot, valid_from) VALUES ($1, $2, $3, $4, now())"
+        );
+        assert_eq!(
+            WIDGETS.delete_statement(),
+            "DELETE FROM widgets WHERE pubkey = $1 AND slot <= $2"
+        );
+        assert_eq!(
+            WIDGETS.close_history_statement(),
+            "UPDATE widgets_history SET valid_to = now() WHERE pubkey = $1 AND valid_to IS NULL"
+        );
+    }
+
+    #[test]
+    fn test_tables_have_unique_names() {
+        for table in TABLES {
+            assert!(
+                table.columns.len() > 1,
+                "{} needs a row comparison",
+                table.name
+            );
+            let mut names: Vec<_> = table.columns.iter().map(|c| c.name).collect();
+            names.sort();
+            names.dedup();
+            assert_eq!(names.len(), table.columns.len(), "{}", table.name);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use clap::Parser;
+use doublezero_config::{Environment, NetworkConfig};
+
+#[derive(Debug, Parser)]
+#[command(
+    term_width = 0,
+    name = "DoubleZero Indexer",
+    version = option_env!("BUILD_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"))
+)]
+pub struct AppArgs {
+    /// DZ ledger environment (devnet, testnet, mainnet-beta, local, or custom:<name>).
+    #[arg(long)]
+    pub env: Environment,
+
+    /// DZ ledger RPC URL. Defaults to the environment's public RPC.
+    #[arg(long)]
+    pub dz_rpc: Option<String>,
+
+    /// Postgres connection string (e.g. "postgres://indexer@localhost/doublezero").
+    #[arg(long)]
+    pub database_url: String,
+
+    /// Log filter (e.g. "doublezero_indexer=debug").
+    #[arg(long, default_value = "doublezero_indexer=info")]
+    pub log: String,
+
+    /// Seconds between full reconciliations against getProgramAccounts.
+    #[arg(long, default_value = "300")]
+    pub reconcile_interval: u64,
+}
+
+impl AppArgs {
+    pub fn network_config(&self) -> eyre::Result<NetworkConfig> {
+        let mut config = self.env.config()?;
+        if let Some(dz_rpc) = &self.dz_rpc {
+            config.ledger_public_rpc_url = dz_rpc.clone();
+            config.ledger_public_ws_rpc_url = doublezero_sdk::convert_url_to_ws(dz_rpc)?;
+        }
+        Ok(config)
+    }
+
+    pub fn reconcile_interval(&self) -> Duration {
+        Duration::from_secs(self.reconcile_interval.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dz_rpc_overrides_environment() {
+        let args = AppArgs::parse_from([
+            "doublezero-indexer",
+            "--env",
+            "devnet",
+            "--dz-rpc",
+            "http://localhost:8899",
+            "--database-url",
+            "postgres://localhost/doublezero",
+        ]);
+        let config = args.network_config().unwrap();
+        assert_eq!(config.ledger_public_rpc_url, "http://localhost:8899");
+        assert_eq!(config.ledger_public_ws_rpc_url, "ws://localhost:8899/");
+        assert_eq!(args.reconcile_interval(), Duration::from_secs(300));
+    }
+}
@@ -0,0 +1,304 @@
+//! Writes rows to Postgres, keeping the history tables in step.
+
+use std::collections::HashSet;
+
+use tokio_postgres::{Client, GenericClient};
+
+use crate::{
+    rows::{slot_param, Row},
+    schema::{CREATE_TOMBSTONES, RECORD_TOMBSTONE, TABLES, TOMBSTONE_SLOT},
+    sync::Snapshot,
+};
+
+pub struct Store {
+    client: Client,
+}
+
+impl Store {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Create any missing tables.
+    pub async fn migrate(&self) -> eyre::Result<()> {
+        for table in TABLES {
+            for statement in table.create_statements() {
+                self.client.batch_execute(&statement).await?;
+            }
+        }
+        self.client.batch_execute(CREATE_TOMBSTONES).await?;
+        Ok(())
+    }
+
+    /// Write `row`, opening a new history version if it changed. Returns
+    /// whether it did; a row read before the stored one, or before the
+    /// account was closed, is ignored.
+    pub async fn upsert(&mut self, row: &Row) -> eyre::Result<bool> {
+        let tx = self.client.transaction().await?;
+        let changed = upsert(&tx, row).await?;
+        tx.commit().await?;
+        Ok(changed)
+    }
+
+    /// Remove the account closed at `slot` from whichever table holds it and
+    /// close its history. Returns whether any table held it; a row read after
+    /// `slot` is kept.
+    pub async fn delete(&mut self, pubkey: &str, slot: u64) -> eyre::Result<bool> {
+        let tx = self.client.transaction().await?;
+        let deleted = delete(&tx, pubkey, slot_param(slot)).await?;
+        tx.commit().await?;
+        Ok(deleted)
+    }
+
+    /// Bring the tables in line with a full snapshot of the program's
+    /// accounts: write every row and delete accounts that are gone, unless
+    /// they were written after the snapshot's slot.
+    pub async fn reconcile(&mut self, snapshot: &Snapshot) -> eyre::Result<ReconcileStats> {
+        let mut stats = ReconcileStats::default();
+        let tx = self.client.transaction().await?;
+
+        for row in &snapshot.rows {
+            if upsert(&tx, row).await? {
+                stats.changed += 1;
+            }
+        }
+
+        let present: HashSet<&str> = snapshot
+            .rows
+            .iter()
+            .map(|row| row.pubkey.as_str())
+            .collect();
+        let slot = slot_param(snapshot.slot);
+        for table in TABLES {
+            for existing in tx.query(&table.pubkeys_statement(), &[]).await? {
+                let pubkey: String = existing.get(0);
+                if !present.contains(pubkey.as_str()) && delete(&tx, &pubkey, slot).await? {
+                    stats.deleted += 1;
+                }
+            }
+        }
+
+        tx.commit().await?;
+        Ok(stats)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReconcileStats {
+    pub changed: usize,
+    pub deleted: usize,
+}
+
+async fn upsert(client: &impl GenericClient, row: &Row) -> eyre::Result<bool> {
+    if let Some(closed) = client.query_opt(TOMBSTONE_SLOT, &[&row.pubkey]).await? {
+        if closed.get::<_, i64>(0) >= row.slot {
+            return Ok(false);
+        }
+    }
+    let params = row.params();
+    if client
+        .execute(&row.table.upsert_statement(), &params)
+        .await?
+        == 0
+    {
+        client
+            .execute(&row.table.touch_statement(), &[&row.pubkey, &row.slot])
+            .await?;
+        return Ok(false);
+    }
+    client
+        .execute(&row.table.close_history_statement(), &[&row.pubkey])
+        .await?;
+    client
+        .execute(&row.table.insert_history_statement(), &params)
+        .await?;
+    Ok(true)
+}
+
+async fn delete(client: &impl GenericClient, pubkey: &str, slot: i64) -> eyre::Result<bool> {
+    client.execute(RECORD_TOMBSTONE, &[&pubkey, &slot]).await?;
+    let mut deleted = false;
+    for table in TABLES {
+        if client
+            .execute(&table.delete_statement(), &[&pubkey, &slot])
+            .await?
+            > 0
+        {
+            client
+                .execute(&table.close_history_statement(), &[&pubkey])
+                .await?;
+            deleted = true;
+        }
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rows::Value, schema::LOCATIONS};
+    use tokio_postgres::NoTls;
+
+    /// A store on a fresh schema of the database at
+    /// `INDEXER_TEST_DATABASE_URL`, or `None` to skip the test when it is
+    /// unset.
+    async fn test_store(name: &str) -> Option<Store> {
+        let Ok(url) = std::env::var("INDEXER_TEST_DATABASE_URL") else {
+            eprintln!("INDEXER_TEST_DATABASE_URL is unset; skipping {name}");
+            return None;
+        };
+        let (client, connection) = tokio_postgres::connect(&url, NoTls).await.unwrap();
+        tokio::spawn(connection);
+        let schema = format!("indexer_test_{name}");
+        client
+            .batch_execute(&format!(
+                "DROP SCHEMA IF EXISTS {schema} CASCADE; CREATE SCHEMA {schema}; SET search_path TO {schema}"
+            ))
+            .await
+            .unwrap();
+        let store = Store::new(client);
+        store.migrate().await.unwrap();
+        Some(store)
+    }
+
+    fn location(pubkey: &str, code: &str, slot: i64) -> Row {
+        Row {
+            table: &LOCATIONS,
+            pubkey: pubkey.to_string(),
+            values: vec![
+                Value::Text("owner".to_string()),
+                Value::Text(code.to_string()),
+                Value::Text("Amsterdam".to_string()),
+                Value::Text("NL".to_string()),
+                Value::Double(52.37),
+                Value::Double(4.9),
+                Value::Text("activated".to_string()),
+            ],
+            slot,
+        }
+    }
+
+    /// The code and slot of the current row of `pubkey`, if any.
+    async fn current(store: &Store, pubkey: &str) -> Option<(String, i64)> {
+        store
+            .client
+            .query_opt(
+                "SELECT code, slot FROM locations WHERE pubkey = $1",
+                &[&pubkey],
+            )
+            .await
+            .unwrap()
+            .map(|row| (row.get(0), row.get(1)))
+    }
+
+    /// The codes of every version of `pubkey` and whether each is still open,
+    /// oldest first.
+    async fn history(store: &Store, pubkey: &str) -> Vec<(String, bool)> {
+        store
+            .client
+            .query(
+                "SELECT code, valid_to IS NULL FROM locations_history WHERE pubkey = $1 ORDER BY valid_from",
+                &[&pubkey],
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_upsert_records_history() {
+        let Some(mut store) = test_store("upsert").await else {
+            return;
+        };
+
+        assert!(store.upsert(&location("loc", "ams", 10)).await.unwrap());
+        // An unchanged row opens no version but moves the slot forward.
+        assert!(!store.upsert(&location("loc", "ams", 11)).await.unwrap());
+        assert_eq!(current(&store, "loc").await, Some(("ams".to_string(), 11)));
+        assert!(store.upsert(&location("loc", "ams1", 12)).await.unwrap());
+
+        assert_eq!(current(&store, "loc").await, Some(("ams1".to_string(), 12)));
+        assert_eq!(
+            history(&store, "loc").await,
+            [("ams".to_string(), false), ("ams1".to_string(), true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_out_of_order_upserts_are_ignored() {
+        let Some(mut store) = test_store("out_of_order").await else {
+            return;
+        };
+
+        assert!(store.upsert(&location("loc", "new", 20)).await.unwrap());
+        assert!(!store.upsert(&location("loc", "old", 10)).await.unwrap());
+        assert_eq!(current(&store, "loc").await, Some(("new".to_string(), 20)));
+
+        // An unchanged update still fences off older ones delivered after it.
+        assert!(!store.upsert(&location("loc", "new", 30)).await.unwrap());
+        assert!(!store.upsert(&location("loc", "old", 25)).await.unwrap());
+        assert_eq!(current(&store, "loc").await, Some(("new".to_string(), 30)));
+        assert_eq!(history(&store, "loc").await, [("new".to_string(), true)]);
+    }
+
+    #[tokio::test]
+    async fn test_close() {
+        let Some(mut store) = test_store("close").await else {
+            return;
+        };
+
+        assert!(store.upsert(&location("loc", "ams", 10)).await.unwrap());
+        // A close older than the row keeps it.
+        assert!(!store.delete("loc", 5).await.unwrap());
+        assert!(store.delete("loc", 20).await.unwrap());
+        assert_eq!(current(&store, "loc").await, None);
+        assert_eq!(history(&store, "loc").await, [("ams".to_string(), false)]);
+
+        // A stale update delivered after the close does not bring it back,
+        // but the account can be created again later.
+        assert!(!store.upsert(&location("loc", "ams", 15)).await.unwrap());
+        assert_eq!(current(&store, "loc").await, None);
+        assert!(store.upsert(&location("loc", "ams2", 25)).await.unwrap());
+        assert_eq!(current(&store, "loc").await, Some(("ams2".to_string(), 25)));
+        assert!(!store.delete("missing", 30).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile() {
+        let Some(mut store) = test_store("reconcile").await else {
+            return;
+        };
+
+        assert!(store.upsert(&location("gone", "fra", 10)).await.unwrap());
+        assert!(store.upsert(&location("newer", "lon", 30)).await.unwrap());
+        assert!(store.upsert(&location("kept", "ams", 10)).await.unwrap());
+
+        // The snapshot at slot 20 predates `newer`, which is kept, and no
+        // longer holds `gone`.
+        let stats = store
+            .reconcile(&Snapshot {
+                slot: 20,
+                rows: vec![location("kept", "ams1", 20)],
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            stats,
+            ReconcileStats {
+                changed: 1,
+                deleted: 1,
+            }
+        );
+        assert_eq!(current(&store, "gone").await, None);
+        assert_eq!(
+            current(&store, "newer").await,
+            Some(("lon".to_string(), 30))
+        );
+        assert_eq!(
+            current(&store, "kept").await,
+            Some(("ams1".to_string(), 20))
+        );
+    }
+}
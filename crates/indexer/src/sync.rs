@@ -0,0 +1,168 @@
+//! Sources of account updates: the `programSubscribe` websocket stream and
+//! full `getProgramAccounts` snapshots used to reconcile what it missed.
+
+use std::{str::FromStr, thread, time::Duration};
+
+use base64::{engine::general_purpose, Engine};
+use doublezero_sdk::{DZClient, DoubleZeroClient};
+use doublezero_serviceability::state::accountdata::AccountData;
+use solana_account_decoder::{UiAccountData, UiAccountEncoding};
+use solana_client::{
+    pubsub_client::PubsubClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_rpc_client_api::response::RpcKeyedAccount;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use crate::rows::{row, Row};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Update {
+    Upsert(Row),
+    Closed { pubkey: String, slot: u64 },
+}
+
+/// Every indexed account of the program, read at `slot` or later.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub slot: u64,
+    pub rows: Vec<Row>,
+}
+
+/// Turn a websocket notification sent at `slot` into an update. Closed
+/// accounts come through with no data; accounts of types that are not
+/// indexed yield `None`.
+pub fn update_from_keyed_account(keyed_account: &RpcKeyedAccount, slot: u64) -> Option<Update> {
+    let pubkey = Pubkey::from_str(&keyed_account.pubkey).ok()?;
+    let UiAccountData::Binary(data, UiAccountEncoding::Base64) = &keyed_account.account.data else {
+        return None;
+    };
+    let bytes = general_purpose::STANDARD.decode(data).ok()?;
+    if keyed_account.account.lamports == 0 || bytes.is_empty() {
+        return Some(Update::Closed {
+            pubkey: pubkey.to_string(),
+            slot,
+        });
+    }
+    let account = AccountData::try_from(&bytes[..]).ok()?;
+    row(&pubkey, &account, slot).map(Update::Upsert)
+}
+
+/// Stream updates for every account of `program_id` into `sender`,
+/// resubscribing after a dropped connection. Blocks until the receiver is
+/// dropped; run it on its own thread.
+pub fn subscribe(ws_url: &str, program_id: &Pubkey, sender: mpsc::Sender<Update>) {
+    let options = RpcProgramAccountsConfig {
+        filters: None,
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: None,
+            commitment: Some(CommitmentConfig::confirmed()),
+            min_context_slot: None,
+        },
+        with_context: None,
+        sort_results: None,
+    };
+
+    while !sender.is_closed() {
+        match PubsubClient::program_subscribe(ws_url, program_id, Some(options.clone())) {
+            Ok((_subscription, receiver)) => {
+                debug!("subscribed to program accounts");
+                for response in receiver {
+                    let Some(update) =
+                        update_from_keyed_account(&response.value, response.context.slot)
+                    else {
+                        continue;
+                    };
+                    if sender.blocking_send(update).is_err() {
+                        return;
+                    }
+                }
+                warn!("program subscription closed; resubscribing");
+            }
+            Err(err) => warn!(?err, "failed to subscribe to program accounts"),
+        }
+        thread::sleep(Duration::from_secs(5));
+    }
+}
+
+/// Rows for every indexed account of the program. The snapshot is stamped
+/// with the slot read just before it, a lower bound of the slot the accounts
+/// were read at: a websocket update it raced with may then overwrite it, but
+/// is never dropped, and the next snapshot settles the difference.
+pub fn snapshot(client: &DZClient) -> eyre::Result<Snapshot> {
+    let slot = client.get_slot()?;
+    let rows = client
+        .get_all()?
+        .iter()
+        .filter_map(|(pubkey, account)| row(pubkey, account, slot))
+        .collect();
+    Ok(Snapshot { slot, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        location::{Location, LocationStatus},
+    };
+    use solana_account_decoder::UiAccount;
+
+    fn keyed_account(pubkey: &Pubkey, lamports: u64, data: &[u8]) -> RpcKeyedAccount {
+        RpcKeyedAccount {
+            pubkey: pubkey.to_string(),
+            account: UiAccount {
+                lamports,
+                data: UiAccountData::Binary(
+                    general_purpose::STANDARD.encode(data),
+                    UiAccountEncoding::Base64,
+                ),
+                owner: Pubkey::new_unique().to_string(),
+                executable: false,
+                rent_epoch: 0,
+                space: Some(data.len() as u64),
+            },
+        }
+    }
+
+    #[test]
+    fn test_update_from_keyed_account() {
+        let pubkey = Pubkey::new_unique();
+        let location = Location {
+            account_type: AccountType::Location,
+            owner: Pubkey::new_unique(),
+            index: 1,
+            bump_seed: 1,
+            lat: 52.37,
+            lng: 4.9,
+            loc_id: 1,
+            status: LocationStatus::Activated,
+            code: "ams".to_string(),
+            name: "Amsterdam".to_string(),
+            country: "NL".to_string(),
+            reference_count: 0,
+        };
+        let data = borsh::to_vec(&location).unwrap();
+
+        let Some(Update::Upsert(row)) =
+            update_from_keyed_account(&keyed_account(&pubkey, 1, &data), 7)
+        else {
+            panic!("expected an upsert");
+        };
+        assert_eq!(row.table.name, "locations");
+        assert_eq!(row.pubkey, pubkey.to_string());
+        assert_eq!(row.slot, 7);
+
+        assert_eq!(
+            update_from_keyed_account(&keyed_account(&pubkey, 0, &[]), 8),
+            Some(Update::Closed {
+                pubkey: pubkey.to_string(),
+                slot: 8,
+            })
+        );
+    }
+}
@@ -22,6 +22,8 @@ use solana_sdk::pubkey::Pubkey;
 /// DZ Ledger user info.
 #[derive(Debug, Clone)]
 pub struct DzUser {
+    /// User account pubkey.
+    pub pk: Pubkey,
     pub owner: Pubkey,
     pub client_ip: Ipv4Addr,
     pub device_pk: Pubkey,
@@ -111,7 +113,7 @@ impl DzLedgerReader for RpcDzLedgerReader {
             .context("failed to fetch User accounts from DZ Ledger")?;
 
         let mut users = Vec::new();
-        for (_pk, account) in accounts {
+        for (pk, account) in accounts {
             let Ok(ad) = AccountData::try_from(account.data.as_slice()) else {
                 continue;
             };
@@ -122,6 +124,7 @@ impl DzLedgerReader for RpcDzLedgerReader {
                 continue;
             }
             users.push(DzUser {
+                pk,
                 owner: user.owner,
                 client_ip: user.client_ip,
                 device_pk: user.device_pk,
@@ -60,6 +60,8 @@ pub fn build_create_multicast_publisher_instructions(
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pda, false),
@@ -139,6 +141,7 @@ pub fn build_create_multicast_publisher_instructions(
             tunnel_endpoint,
             dz_prefix_count,
             owner: *owner,
+            bandwidth_tier: None,
         }),
         create_user_accounts,
     )?;
@@ -177,6 +180,7 @@ mod tests {
         let payer = Pubkey::new_unique();
         let multicast_group = Pubkey::new_unique();
         let user = DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::new_unique(),
             client_ip: Ipv4Addr::new(10, 0, 0, 1),
             device_pk: Pubkey::new_unique(),
@@ -224,6 +228,7 @@ mod tests {
         let payer = Pubkey::new_unique();
         let multicast_group = Pubkey::new_unique();
         let user = DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::new_unique(),
             client_ip: Ipv4Addr::new(10, 0, 0, 1),
             device_pk: Pubkey::new_unique(),
@@ -267,6 +272,7 @@ mod tests {
         let payer = Pubkey::new_unique();
         let multicast_group = Pubkey::new_unique();
         let user = DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::new_unique(),
             client_ip: Ipv4Addr::new(10, 0, 0, 1),
             device_pk: Pubkey::new_unique(),
@@ -132,6 +132,7 @@ mod tests {
 
     fn make_ibrl_user(ip: [u8; 4], owner: Pubkey, device_pk: Pubkey) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner,
             client_ip: Ipv4Addr::from(ip),
             device_pk,
@@ -144,6 +145,7 @@ mod tests {
 
     fn make_multicast_user(ip: [u8; 4], groups: Vec<Pubkey>) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::new_unique(),
             client_ip: Ipv4Addr::from(ip),
             device_pk: Pubkey::new_unique(),
@@ -396,7 +396,7 @@ impl MulticastDzLedgerClient for RpcMulticastDzLedgerClient {
             .await?;
 
         let mut users = Vec::new();
-        for (_pk, account) in accounts {
+        for (pk, account) in accounts {
             let Ok(ad) = AccountData::try_from(account.data.as_slice()) else {
                 continue;
             };
@@ -413,6 +413,7 @@ impl MulticastDzLedgerClient for RpcMulticastDzLedgerClient {
                 continue;
             }
             users.push(DzUser {
+                pk,
                 owner: user.owner,
                 client_ip: user.client_ip,
                 device_pk: user.device_pk,
@@ -652,6 +653,7 @@ mod tests {
 
     fn make_ibrl_user(ip: [u8; 4], device_pk: Pubkey) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::default(),
             client_ip: Ipv4Addr::from(ip),
             device_pk,
@@ -664,6 +666,7 @@ mod tests {
 
     fn make_ibrl_with_allocated_ip_user(ip: [u8; 4], device_pk: Pubkey) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::default(),
             client_ip: Ipv4Addr::from(ip),
             device_pk,
@@ -676,6 +679,7 @@ mod tests {
 
     fn make_multicast_publisher(ip: [u8; 4], groups: Vec<Pubkey>) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::default(),
             client_ip: Ipv4Addr::from(ip),
             device_pk: Pubkey::new_unique(),
@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use std::{collections::HashMap, net::Ipv4Addr};
 
 use solana_sdk::pubkey::Pubkey;
 
@@ -59,7 +59,7 @@ pub fn select_tunnel_endpoint(
 pub fn in_use_tunnel_endpoints<'a, I>(
     users: I,
     client_ip: Ipv4Addr,
-    device_endpoints: &std::collections::HashMap<Pubkey, DeviceEndpoints>,
+    device_endpoints: &HashMap<Pubkey, DeviceEndpoints>,
 ) -> Vec<Ipv4Addr>
 where
     I: IntoIterator<Item = &'a crate::dz_ledger_reader::DzUser>,
@@ -82,29 +82,152 @@ where
 }
 
 /// Pick a tunnel endpoint for a user on `device_pk`, given all existing users
-/// (to derive the exclude list) and the device endpoint map. Returns
-/// `Ipv4Addr::UNSPECIFIED` when the device is unknown or has no free endpoint.
+/// (to derive the exclude list and per-endpoint load) and the device endpoint
+/// map. Among the free `user_tunnel_endpoint` IPs the least-loaded one wins, so
+/// users spread across a device's uplinks instead of piling onto the first.
+/// Returns `Ipv4Addr::UNSPECIFIED` when the device is unknown or has no free
+/// endpoint.
 pub fn select_tunnel_endpoint_for_user(
     device_pk: &Pubkey,
     client_ip: Ipv4Addr,
     all_users: &[crate::dz_ledger_reader::DzUser],
-    device_endpoints: &std::collections::HashMap<Pubkey, DeviceEndpoints>,
+    device_endpoints: &HashMap<Pubkey, DeviceEndpoints>,
 ) -> Ipv4Addr {
     let Some(endpoints) = device_endpoints.get(device_pk) else {
         return Ipv4Addr::UNSPECIFIED;
     };
     let exclude = in_use_tunnel_endpoints(all_users, client_ip, device_endpoints);
-    select_tunnel_endpoint(
+    let load = tunnel_endpoint_load(
+        all_users,
+        device_pk,
+        endpoints.public_ip,
+        &endpoints.user_tunnel_endpoints,
+    );
+    select_least_loaded_tunnel_endpoint(
         endpoints.public_ip,
         &endpoints.user_tunnel_endpoints,
         &exclude,
+        &load,
     )
 }
 
+/// Count the users on `device_pk` terminating on each of its tunnel endpoints.
+/// Every `user_tunnel_endpoint` IP is present in the result, with 0 if unused.
+/// Legacy users with `tunnel_endpoint == UNSPECIFIED` count against `public_ip`.
+pub fn tunnel_endpoint_load<'a, I>(
+    users: I,
+    device_pk: &Pubkey,
+    public_ip: Ipv4Addr,
+    user_tunnel_endpoints: &[Ipv4Addr],
+) -> HashMap<Ipv4Addr, usize>
+where
+    I: IntoIterator<Item = &'a crate::dz_ledger_reader::DzUser>,
+{
+    let mut load: HashMap<Ipv4Addr, usize> =
+        user_tunnel_endpoints.iter().map(|ep| (*ep, 0)).collect();
+    for user in users.into_iter().filter(|u| u.device_pk == *device_pk) {
+        let ep = if user.tunnel_endpoint != Ipv4Addr::UNSPECIFIED {
+            user.tunnel_endpoint
+        } else {
+            public_ip
+        };
+        if ep != Ipv4Addr::UNSPECIFIED {
+            *load.entry(ep).or_default() += 1;
+        }
+    }
+    load
+}
+
+/// Like [`select_tunnel_endpoint`], but picks the `user_tunnel_endpoint` IP
+/// with the fewest users in `load` rather than the first free one. Ties keep
+/// interface order. Falls back to `public_ip` only when every UTE is excluded.
+pub fn select_least_loaded_tunnel_endpoint(
+    public_ip: Ipv4Addr,
+    user_tunnel_endpoints: &[Ipv4Addr],
+    exclude_ips: &[Ipv4Addr],
+    load: &HashMap<Ipv4Addr, usize>,
+) -> Ipv4Addr {
+    let least_loaded = user_tunnel_endpoints
+        .iter()
+        .filter(|ep| !exclude_ips.contains(ep))
+        .min_by_key(|ep| load.get(ep).copied().unwrap_or(0));
+    if let Some(ep) = least_loaded {
+        return *ep;
+    }
+
+    select_tunnel_endpoint(public_ip, &[], exclude_ips)
+}
+
+/// A proposed move of one user to a less-loaded tunnel endpoint on the same
+/// device. Applied with `doublezero user update --tunnel-endpoint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TunnelEndpointMove {
+    pub user_pk: Pubkey,
+    pub client_ip: Ipv4Addr,
+    pub from: Ipv4Addr,
+    pub to: Ipv4Addr,
+}
+
+/// Plan moves that even out user load across the `user_tunnel_endpoint` IPs
+/// of one device. Moves are greedy, one user at a time from the busiest to the
+/// idlest endpoint, until no two endpoints differ by more than one user. A user
+/// is never moved onto an endpoint its `client_ip` already uses elsewhere, and
+/// users on `public_ip` (including legacy UNSPECIFIED ones) are left alone.
+pub fn plan_tunnel_endpoint_rebalance(
+    device_pk: &Pubkey,
+    users: &[crate::dz_ledger_reader::DzUser],
+    public_ip: Ipv4Addr,
+    user_tunnel_endpoints: &[Ipv4Addr],
+) -> Vec<TunnelEndpointMove> {
+    let mut load = tunnel_endpoint_load(users, device_pk, public_ip, user_tunnel_endpoints);
+    // Current endpoint of every user, updated as moves are planned.
+    let mut placement: Vec<(usize, Ipv4Addr)> = users
+        .iter()
+        .enumerate()
+        .filter(|(_, u)| {
+            u.device_pk == *device_pk && user_tunnel_endpoints.contains(&u.tunnel_endpoint)
+        })
+        .map(|(i, u)| (i, u.tunnel_endpoint))
+        .collect();
+    let mut moves = Vec::new();
+
+    loop {
+        let mut by_load: Vec<Ipv4Addr> = user_tunnel_endpoints.to_vec();
+        by_load.sort_by_key(|ep| load[ep]);
+        let (Some(&idlest), Some(&busiest)) = (by_load.first(), by_load.last()) else {
+            break;
+        };
+        if load[&busiest] <= load[&idlest] + 1 {
+            break;
+        }
+
+        let candidate = placement.iter().position(|(i, ep)| {
+            *ep == busiest
+                && !placement.iter().any(|(j, other)| {
+                    *other == idlest && users[*j].client_ip == users[*i].client_ip
+                })
+        });
+        let Some(pos) = candidate else {
+            break;
+        };
+        let (i, ep) = &mut placement[pos];
+
+        *ep = idlest;
+        *load.get_mut(&busiest).unwrap() -= 1;
+        *load.get_mut(&idlest).unwrap() += 1;
+        moves.push(TunnelEndpointMove {
+            user_pk: users[*i].pk,
+            client_ip: users[*i].client_ip,
+            from: busiest,
+            to: idlest,
+        });
+    }
+
+    moves
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
-
     use doublezero_sdk::UserType;
 
     use super::*;
@@ -166,6 +289,7 @@ mod tests {
 
     fn make_user(ip: [u8; 4], device_pk: Pubkey, tunnel_endpoint: Ipv4Addr) -> DzUser {
         DzUser {
+            pk: Pubkey::new_unique(),
             owner: Pubkey::default(),
             client_ip: Ipv4Addr::from(ip),
             device_pk,
@@ -260,4 +384,194 @@ mod tests {
             Ipv4Addr::UNSPECIFIED,
         );
     }
+
+    #[test]
+    fn tunnel_endpoint_load_counts_per_endpoint() {
+        let device_pk = Pubkey::new_unique();
+        let public_ip = Ipv4Addr::new(1, 1, 1, 1);
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 2], device_pk, ute1),
+            make_user([10, 0, 0, 3], device_pk, Ipv4Addr::UNSPECIFIED),
+            make_user([10, 0, 0, 4], Pubkey::new_unique(), ute2),
+        ];
+
+        let load = tunnel_endpoint_load(&users, &device_pk, public_ip, &[ute1, ute2]);
+        assert_eq!(load[&ute1], 2);
+        assert_eq!(load[&ute2], 0);
+        assert_eq!(load[&public_ip], 1);
+    }
+
+    #[test]
+    fn select_least_loaded_prefers_idle_ute() {
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let load = HashMap::from([(ute1, 3), (ute2, 1)]);
+        assert_eq!(
+            select_least_loaded_tunnel_endpoint(
+                Ipv4Addr::new(1, 1, 1, 1),
+                &[ute1, ute2],
+                &[],
+                &load
+            ),
+            ute2,
+        );
+    }
+
+    #[test]
+    fn select_least_loaded_breaks_ties_in_interface_order() {
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        assert_eq!(
+            select_least_loaded_tunnel_endpoint(
+                Ipv4Addr::new(1, 1, 1, 1),
+                &[ute1, ute2],
+                &[],
+                &HashMap::new(),
+            ),
+            ute1,
+        );
+    }
+
+    #[test]
+    fn select_least_loaded_falls_back_to_public_ip() {
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let public_ip = Ipv4Addr::new(1, 1, 1, 1);
+        assert_eq!(
+            select_least_loaded_tunnel_endpoint(public_ip, &[ute1], &[ute1], &HashMap::new()),
+            public_ip,
+        );
+    }
+
+    #[test]
+    fn select_for_user_spreads_across_utes() {
+        let device_pk = Pubkey::new_unique();
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+
+        let mut device_endpoints = HashMap::new();
+        device_endpoints.insert(
+            device_pk,
+            DeviceEndpoints {
+                public_ip: Ipv4Addr::new(1, 1, 1, 1),
+                user_tunnel_endpoints: vec![ute1, ute2],
+                dz_prefix_count: 0,
+            },
+        );
+
+        // A different client already sits on ute1, so a new client lands on ute2.
+        let users = vec![make_user([10, 0, 0, 2], device_pk, ute1)];
+        let ep = select_tunnel_endpoint_for_user(
+            &device_pk,
+            Ipv4Addr::new(10, 0, 0, 1),
+            &users,
+            &device_endpoints,
+        );
+        assert_eq!(ep, ute2);
+    }
+
+    #[test]
+    fn rebalance_moves_users_off_busiest_ute() {
+        let device_pk = Pubkey::new_unique();
+        let public_ip = Ipv4Addr::new(1, 1, 1, 1);
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 2], device_pk, ute1),
+            make_user([10, 0, 0, 3], device_pk, ute1),
+            make_user([10, 0, 0, 4], device_pk, ute1),
+        ];
+
+        let moves = plan_tunnel_endpoint_rebalance(&device_pk, &users, public_ip, &[ute1, ute2]);
+        assert_eq!(moves.len(), 2);
+        assert!(moves.iter().all(|m| m.from == ute1 && m.to == ute2));
+        assert_eq!(moves[0].user_pk, users[0].pk);
+        assert_eq!(moves[1].user_pk, users[1].pk);
+    }
+
+    #[test]
+    fn rebalance_is_noop_when_balanced() {
+        let device_pk = Pubkey::new_unique();
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 2], device_pk, ute1),
+            make_user([10, 0, 0, 3], device_pk, ute2),
+        ];
+
+        let moves = plan_tunnel_endpoint_rebalance(
+            &device_pk,
+            &users,
+            Ipv4Addr::new(1, 1, 1, 1),
+            &[ute1, ute2],
+        );
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn rebalance_never_doubles_up_a_client_ip() {
+        let device_pk = Pubkey::new_unique();
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        // Every user on ute1 shares a client IP with the one already on ute2.
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute2),
+        ];
+
+        let moves = plan_tunnel_endpoint_rebalance(
+            &device_pk,
+            &users,
+            Ipv4Addr::new(1, 1, 1, 1),
+            &[ute1, ute2],
+        );
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn rebalance_leaves_legacy_users_alone() {
+        let device_pk = Pubkey::new_unique();
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, Ipv4Addr::UNSPECIFIED),
+            make_user([10, 0, 0, 2], device_pk, Ipv4Addr::UNSPECIFIED),
+            make_user([10, 0, 0, 3], device_pk, Ipv4Addr::UNSPECIFIED),
+        ];
+
+        let moves = plan_tunnel_endpoint_rebalance(
+            &device_pk,
+            &users,
+            Ipv4Addr::new(1, 1, 1, 1),
+            &[ute1, ute2],
+        );
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn rebalance_moves_at_most_one_user_per_client_ip_to_an_endpoint() {
+        let device_pk = Pubkey::new_unique();
+        let ute1 = Ipv4Addr::new(192, 168, 1, 11);
+        let ute2 = Ipv4Addr::new(192, 168, 1, 12);
+        let users = vec![
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute1),
+            make_user([10, 0, 0, 1], device_pk, ute1),
+        ];
+
+        let moves = plan_tunnel_endpoint_rebalance(
+            &device_pk,
+            &users,
+            Ipv4Addr::new(1, 1, 1, 1),
+            &[ute1, ute2],
+        );
+        assert_eq!(moves.len(), 1);
+    }
 }
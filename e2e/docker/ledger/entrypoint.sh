@@ -13,8 +13,17 @@ ACCOUNTS_DIR="/tmp/fork-accounts"
 #
 # CLONE_FROM_URL: the RPC URL to fetch from (e.g., mainnet-beta)
 # CLONE_PROGRAM_IDS: comma-separated list of program IDs to fetch accounts for
+# CLONE_ACCOUNT_TYPES: optional comma-separated account types to keep (e.g. device,link,user)
+# CLONE_MAX_ACCOUNT_SIZE: optional size cap in bytes; larger accounts are skipped
 if [ -n "${CLONE_FROM_URL:-}" ] && [ -n "${CLONE_PROGRAM_IDS:-}" ]; then
-  fork-accounts fetch "${CLONE_FROM_URL}" "${CLONE_PROGRAM_IDS}" "${ACCOUNTS_DIR}"
+  fetch_args=()
+  if [ -n "${CLONE_ACCOUNT_TYPES:-}" ]; then
+    fetch_args+=(--account-types "${CLONE_ACCOUNT_TYPES}")
+  fi
+  if [ -n "${CLONE_MAX_ACCOUNT_SIZE:-}" ]; then
+    fetch_args+=(--max-account-size "${CLONE_MAX_ACCOUNT_SIZE}")
+  fi
+  fork-accounts fetch "${CLONE_FROM_URL}" "${CLONE_PROGRAM_IDS}" "${ACCOUNTS_DIR}" "${fetch_args[@]}"
   extra_args="${extra_args} --account-dir ${ACCOUNTS_DIR}"
 fi
 
@@ -26,6 +35,25 @@ if [ -n "${PATCH_GLOBALSTATE_AUTHORITY:-}" ] && [ -d "${ACCOUNTS_DIR}" ]; then
   fork-accounts patch-globalstate "${ACCOUNTS_DIR}" "${PATCH_GLOBALSTATE_AUTHORITY}"
 fi
 
+# Patch the GlobalConfig account, e.g. to point a forked ledger at test IP space.
+#
+# PATCH_GLOBALCONFIG_ARGS: flags for `fork-accounts patch-globalconfig`, e.g.
+#   "--user-tunnel-block 169.254.0.0/16 --local-asn 65000"
+if [ -n "${PATCH_GLOBALCONFIG_ARGS:-}" ] && [ -d "${ACCOUNTS_DIR}" ]; then
+  # shellcheck disable=SC2086
+  fork-accounts patch-globalconfig "${ACCOUNTS_DIR}" ${PATCH_GLOBALCONFIG_ARGS}
+fi
+
+# Patch the ProgramConfig account so dev binaries pass the version check without an
+# onchain upgrade.
+#
+# PATCH_PROGRAMCONFIG_ARGS: flags for `fork-accounts patch-programconfig`, e.g.
+#   "--version 0.99.0 --min-compatible-version 0.0.0"
+if [ -n "${PATCH_PROGRAMCONFIG_ARGS:-}" ] && [ -d "${ACCOUNTS_DIR}" ]; then
+  # shellcheck disable=SC2086
+  fork-accounts patch-programconfig "${ACCOUNTS_DIR}" ${PATCH_PROGRAMCONFIG_ARGS}
+fi
+
 # Support deploying upgraded programs at startup with a specific upgrade authority.
 # UPGRADE_PROGRAM_ID: program ID to upgrade
 # UPGRADE_PROGRAM_SO: path to the .so file inside the container
@@ -1,20 +1,25 @@
 use std::{env, fs, path::Path, process};
 
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
-use doublezero_sdk::{AccountType, GlobalState};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_sdk::{AccountType, GlobalConfig, GlobalState, ProgramConfig, ProgramVersion};
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        eprintln!("Usage: fork-accounts <fetch|patch-globalstate> [args...]");
+        eprintln!(
+            "Usage: fork-accounts <fetch|patch-globalstate|patch-globalconfig|patch-programconfig> [args...]"
+        );
         process::exit(1);
     }
 
     match args[1].as_str() {
         "fetch" => cmd_fetch(&args[2..]),
         "patch-globalstate" => cmd_patch_globalstate(&args[2..]),
+        "patch-globalconfig" => cmd_patch_globalconfig(&args[2..]),
+        "patch-programconfig" => cmd_patch_programconfig(&args[2..]),
         _ => {
             eprintln!("Unknown command: {}", args[1]);
             process::exit(1);
@@ -66,15 +71,39 @@ struct RpcAccountData {
 
 /// Fetch all accounts owned by one or more programs and write them as JSON files.
 /// Program IDs can be comma-separated.
+///
+/// `--account-types` keeps only accounts whose first byte (the `AccountType`
+/// discriminator) matches one of the given types, filtered server-side with a
+/// memcmp so skipped accounts are never downloaded. `--max-account-size` drops
+/// accounts larger than the given number of bytes.
 fn cmd_fetch(args: &[String]) {
+    const USAGE: &str = "Usage: fork-accounts fetch <rpc-url> <program-ids> <output-dir> \
+        [--account-types <type,...>] [--max-account-size <bytes>]";
     if args.len() < 3 {
-        eprintln!("Usage: fork-accounts fetch <rpc-url> <program-ids> <output-dir>");
+        eprintln!("{USAGE}");
         process::exit(1);
     }
     let rpc_url = &args[0];
     let program_ids_str = &args[1];
     let output_dir = &args[2];
 
+    let mut account_types: Vec<u8> = Vec::new();
+    let mut max_account_size: Option<u64> = None;
+    for (flag, value) in parse_flags(
+        &args[3..],
+        &["--account-types", "--max-account-size"],
+        USAGE,
+    ) {
+        match flag.as_str() {
+            "--account-types" => {
+                for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                    account_types.push(parse_account_type(name));
+                }
+            }
+            _ => max_account_size = Some(parse_or_exit(&flag, &value)),
+        }
+    }
+
     fs::create_dir_all(output_dir).expect("failed to create output directory");
 
     for program_id in program_ids_str.split(',') {
@@ -85,26 +114,32 @@ fn cmd_fetch(args: &[String]) {
 
         eprintln!("==> Fetching accounts for program {program_id} from {rpc_url}");
 
-        let body = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "getProgramAccounts",
-            "params": [program_id, {"encoding": "base64"}]
-        });
-
-        let response: RpcResponse = ureq::post(rpc_url)
-            .set("Content-Type", "application/json")
-            .send_json(&body)
-            .expect("RPC request failed")
-            .into_json()
-            .expect("failed to parse RPC response");
+        // A memcmp filter matches a single value, so fetch once per account type.
+        let mut accounts = Vec::new();
+        if account_types.is_empty() {
+            accounts.extend(get_program_accounts(rpc_url, program_id, None));
+        } else {
+            for account_type in &account_types {
+                accounts.extend(get_program_accounts(
+                    rpc_url,
+                    program_id,
+                    Some(*account_type),
+                ));
+            }
+        }
 
-        if let Some(err) = response.error {
-            eprintln!("RPC error: {err}");
-            process::exit(1);
+        let fetched = accounts.len();
+        if let Some(max) = max_account_size {
+            accounts.retain(|entry| entry.account.space <= max);
+        }
+        if accounts.len() < fetched {
+            eprintln!(
+                "Skipped {} accounts larger than {} bytes",
+                fetched - accounts.len(),
+                max_account_size.unwrap_or_default()
+            );
         }
 
-        let accounts = response.result.unwrap_or_default();
         for entry in &accounts {
             let file = AccountFile {
                 pubkey: entry.pubkey.clone(),
@@ -126,6 +161,63 @@ fn cmd_fetch(args: &[String]) {
     }
 }
 
+/// Parse an `AccountType` by name (case-insensitive, e.g. `device`,
+/// `multicastgroup`) or by its numeric discriminator.
+fn parse_account_type(name: &str) -> u8 {
+    if let Ok(value) = name.parse::<u8>() {
+        return value;
+    }
+    (1..=u8::MAX)
+        .find(|b| {
+            let account_type = AccountType::from(*b);
+            account_type as u8 == *b && format!("{account_type:?}").eq_ignore_ascii_case(name)
+        })
+        .unwrap_or_else(|| {
+            eprintln!("Unknown account type: {name}");
+            process::exit(1);
+        })
+}
+
+/// Call getProgramAccounts, optionally restricted to accounts whose first byte
+/// is `account_type`.
+fn get_program_accounts(
+    rpc_url: &str,
+    program_id: &str,
+    account_type: Option<u8>,
+) -> Vec<RpcAccount> {
+    let mut config = serde_json::json!({"encoding": "base64"});
+    if let Some(account_type) = account_type {
+        config["filters"] = serde_json::json!([{
+            "memcmp": {
+                "offset": 0,
+                "bytes": BASE64.encode([account_type]),
+                "encoding": "base64"
+            }
+        }]);
+    }
+
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getProgramAccounts",
+        "params": [program_id, config]
+    });
+
+    let response: RpcResponse = ureq::post(rpc_url)
+        .set("Content-Type", "application/json")
+        .send_json(&body)
+        .expect("RPC request failed")
+        .into_json()
+        .expect("failed to parse RPC response");
+
+    if let Some(err) = response.error {
+        eprintln!("RPC error: {err}");
+        process::exit(1);
+    }
+
+    response.result.unwrap_or_default()
+}
+
 /// Patch a GlobalState account's foundation_allowlist and legacy authority key.
 fn cmd_patch_globalstate(args: &[String]) {
     if args.len() < 2 {
@@ -142,7 +234,69 @@ fn cmd_patch_globalstate(args: &[String]) {
 
     eprintln!("==> Patching GlobalState foundation_allowlist with {authority_b58}");
 
-    let mut patched = false;
+    let mut allowlist_len = 0;
+    let patched = patch_account(
+        accounts_dir,
+        AccountType::GlobalState,
+        |global_state: &mut GlobalState| {
+            // Add authority to foundation_allowlist.
+            global_state.foundation_allowlist.push(authority);
+            allowlist_len = global_state.foundation_allowlist.len();
+
+            // Set the legacy authority key to the same authority.
+            global_state.activator_authority_pk = authority;
+        },
+    );
+
+    match patched {
+        Some(pubkey) => eprintln!(
+            "Patched GlobalState account {pubkey}: added authority to foundation_allowlist (now {allowlist_len} entries) and set legacy authority key"
+        ),
+        None => eprintln!("WARNING: No GlobalState account found to patch"),
+    }
+}
+
+/// Parse `--flag value` pairs, exiting with `usage` on an unknown flag or a
+/// missing value.
+fn parse_flags(args: &[String], known: &[&str], usage: &str) -> Vec<(String, String)> {
+    let mut flags = Vec::new();
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        if !known.contains(&flag.as_str()) {
+            eprintln!("Unknown flag: {flag}\n{usage}");
+            process::exit(1);
+        }
+        let Some(value) = iter.next() else {
+            eprintln!("Missing value for {flag}\n{usage}");
+            process::exit(1);
+        };
+        flags.push((flag.clone(), value.clone()));
+    }
+    flags
+}
+
+fn parse_or_exit<T: std::str::FromStr>(flag: &str, value: &str) -> T
+where
+    T::Err: std::fmt::Display,
+{
+    value.parse().unwrap_or_else(|e| {
+        eprintln!("Invalid value for {flag} '{value}': {e}");
+        process::exit(1);
+    })
+}
+
+/// Find the first account in `accounts_dir` whose first byte is `account_type`,
+/// decode it as `T`, apply `patch` and write it back. Returns the patched
+/// account's pubkey, or `None` if no account of that type was found.
+fn patch_account<T>(
+    accounts_dir: &str,
+    account_type: AccountType,
+    patch: impl FnOnce(&mut T),
+) -> Option<String>
+where
+    T: for<'a> TryFrom<&'a [u8]> + borsh::BorshSerialize,
+    for<'a> <T as TryFrom<&'a [u8]>>::Error: std::fmt::Display,
+{
     for entry in fs::read_dir(accounts_dir).expect("failed to read accounts directory") {
         let entry = entry.expect("failed to read directory entry");
         let path = entry.path();
@@ -154,54 +308,168 @@ fn cmd_patch_globalstate(args: &[String]) {
         let mut file: AccountFile =
             serde_json::from_str(&content).expect("failed to parse account file");
 
-        // Decode the account data.
         let data = BASE64
             .decode(&file.account.data.0)
             .expect("failed to decode base64 data");
-
-        // GlobalState has account_type == 1 as first byte.
-        if data.is_empty() || data[0] != AccountType::GlobalState as u8 {
+        if data.is_empty() || data[0] != account_type as u8 {
             continue;
         }
 
-        // Deserialize using the SDK's TryFrom<&[u8]>.
-        let mut global_state = GlobalState::try_from(data.as_slice()).unwrap_or_else(|e| {
+        let mut account = T::try_from(data.as_slice()).unwrap_or_else(|e| {
             eprintln!(
-                "Failed to deserialize GlobalState from {}: {e}",
+                "Failed to deserialize {account_type:?} from {}: {e}",
                 path.display()
             );
             process::exit(1);
         });
+        patch(&mut account);
 
-        // Add authority to foundation_allowlist.
-        let prev_len = global_state.foundation_allowlist.len();
-        global_state.foundation_allowlist.push(authority);
-
-        // Set the legacy authority key to the same authority.
-        global_state.activator_authority_pk = authority;
-
-        // Re-serialize and write back.
-        let new_data = borsh::to_vec(&global_state).unwrap_or_else(|e| {
-            eprintln!("Failed to serialize GlobalState: {e}");
+        let new_data = borsh::to_vec(&account).unwrap_or_else(|e| {
+            eprintln!("Failed to serialize {account_type:?}: {e}");
             process::exit(1);
         });
-
         file.account.data.0 = BASE64.encode(&new_data);
         file.account.space = new_data.len() as u64;
 
         let json = serde_json::to_string(&file).expect("failed to serialize account file");
         fs::write(&path, json).expect("failed to write account file");
 
-        eprintln!(
-            "Patched GlobalState account {}: added authority to foundation_allowlist (now {} entries) and set legacy authority key",
-            path.file_stem().unwrap().to_str().unwrap(),
-            prev_len + 1
+        return Some(path.file_stem().unwrap().to_str().unwrap().to_string());
+    }
+    None
+}
+
+/// Override GlobalConfig ASNs and tunnel/multicast blocks, e.g. to point a
+/// forked mainnet ledger at test IP space.
+fn cmd_patch_globalconfig(args: &[String]) {
+    const USAGE: &str = "Usage: fork-accounts patch-globalconfig <accounts-dir> \
+        [--local-asn <asn>] [--remote-asn <asn>] \
+        [--device-tunnel-block <cidr>] [--user-tunnel-block <cidr>] \
+        [--multicastgroup-block <cidr>] [--multicast-publisher-block <cidr>]";
+    if args.is_empty() {
+        eprintln!("{USAGE}");
+        process::exit(1);
+    }
+    let accounts_dir = &args[0];
+    let flags = parse_flags(
+        &args[1..],
+        &[
+            "--local-asn",
+            "--remote-asn",
+            "--device-tunnel-block",
+            "--user-tunnel-block",
+            "--multicastgroup-block",
+            "--multicast-publisher-block",
+        ],
+        USAGE,
+    );
+    if flags.is_empty() {
+        eprintln!("Nothing to patch\n{USAGE}");
+        process::exit(1);
+    }
+
+    // Validate every value before touching any file.
+    let mut local_asn = None;
+    let mut remote_asn = None;
+    let mut blocks: Vec<(&str, NetworkV4)> = Vec::new();
+    for (flag, value) in &flags {
+        match flag.as_str() {
+            "--local-asn" => local_asn = Some(parse_or_exit::<u32>(flag, value)),
+            "--remote-asn" => remote_asn = Some(parse_or_exit::<u32>(flag, value)),
+            block => blocks.push((block, parse_or_exit::<NetworkV4>(flag, value))),
+        }
+    }
+
+    eprintln!("==> Patching GlobalConfig");
+
+    let patched = patch_account(
+        accounts_dir,
+        AccountType::GlobalConfig,
+        |config: &mut GlobalConfig| {
+            if let Some(asn) = local_asn {
+                config.local_asn = asn;
+            }
+            if let Some(asn) = remote_asn {
+                config.remote_asn = asn;
+            }
+            for (block, net) in &blocks {
+                let field = match *block {
+                    "--device-tunnel-block" => &mut config.device_tunnel_block,
+                    "--user-tunnel-block" => &mut config.user_tunnel_block,
+                    "--multicastgroup-block" => &mut config.multicastgroup_block,
+                    _ => &mut config.multicast_publisher_block,
+                };
+                *field = *net;
+            }
+            eprintln!(
+            "GlobalConfig now: local_asn={} remote_asn={} device_tunnel_block={} user_tunnel_block={} multicastgroup_block={} multicast_publisher_block={}",
+            config.local_asn,
+            config.remote_asn,
+            config.device_tunnel_block,
+            config.user_tunnel_block,
+            config.multicastgroup_block,
+            config.multicast_publisher_block,
         );
-        patched = true;
-        break;
+        },
+    );
+
+    match patched {
+        Some(pubkey) => eprintln!("Patched GlobalConfig account {pubkey}"),
+        None => eprintln!("WARNING: No GlobalConfig account found to patch"),
     }
+}
+
+/// Force ProgramConfig version/min_compatible_version so dev client binaries
+/// pass the onchain compatibility check without a program upgrade.
+fn cmd_patch_programconfig(args: &[String]) {
+    const USAGE: &str = "Usage: fork-accounts patch-programconfig <accounts-dir> \
+        [--version <x.y.z>] [--min-compatible-version <x.y.z>]";
+    if args.is_empty() {
+        eprintln!("{USAGE}");
+        process::exit(1);
+    }
+    let accounts_dir = &args[0];
+    let flags = parse_flags(
+        &args[1..],
+        &["--version", "--min-compatible-version"],
+        USAGE,
+    );
+    if flags.is_empty() {
+        eprintln!("Nothing to patch\n{USAGE}");
+        process::exit(1);
+    }
+
+    let mut version = None;
+    let mut min_compatible_version = None;
+    for (flag, value) in &flags {
+        let parsed = parse_or_exit::<ProgramVersion>(flag, value);
+        match flag.as_str() {
+            "--version" => version = Some(parsed),
+            _ => min_compatible_version = Some(parsed),
+        }
+    }
+
+    eprintln!("==> Patching ProgramConfig");
+
+    let patched = patch_account(
+        accounts_dir,
+        AccountType::ProgramConfig,
+        |config: &mut ProgramConfig| {
+            if let Some(v) = version {
+                config.version = v;
+            }
+            if let Some(v) = min_compatible_version {
+                config.min_compatible_version = v;
+            }
+            eprintln!(
+                "ProgramConfig now: version={} min_compatible_version={}",
+                config.version, config.min_compatible_version,
+            );
+        },
+    );
 
-    if !patched {
-        eprintln!("WARNING: No GlobalState account found to patch");
+    match patched {
+        Some(pubkey) => eprintln!("Patched ProgramConfig account {pubkey}"),
+        None => eprintln!("WARNING: No ProgramConfig account found to patch"),
     }
 }
@@ -51,6 +51,13 @@ type LedgerSpec struct {
 	// Each program and its owned accounts will be cloned via --clone-upgradeable-program.
 	CloneProgramIDs []string
 
+	// CloneAccountTypes, when set, only clones accounts whose AccountType discriminator
+	// (first byte) is one of these names, e.g. "device", "link", "user".
+	CloneAccountTypes []string
+
+	// CloneMaxAccountSize, when non-zero, skips cloned accounts larger than this many bytes.
+	CloneMaxAccountSize int
+
 	// UpgradeProgramID, when set along with UpgradeProgramSOPath and UpgradeAuthority,
 	// deploys an upgraded program at validator startup via --upgradeable-program.
 	// This overrides the program binary while preserving cloned accounts.
@@ -66,6 +73,16 @@ type LedgerSpec struct {
 	// this pubkey to the foundation_allowlist. This allows the test manager to execute
 	// write operations against the cloned mainnet state.
 	PatchGlobalStateAuthority string
+
+	// PatchGlobalConfigArgs, when set, are passed as flags to `fork-accounts
+	// patch-globalconfig` to override the cloned GlobalConfig's ASNs and tunnel/multicast
+	// blocks (e.g. "--user-tunnel-block", "169.254.0.0/16").
+	PatchGlobalConfigArgs []string
+
+	// PatchProgramConfigArgs, when set, are passed as flags to `fork-accounts
+	// patch-programconfig` to force the cloned ProgramConfig's version and
+	// min_compatible_version (e.g. "--min-compatible-version", "0.0.0").
+	PatchProgramConfigArgs []string
 }
 
 func (s *LedgerSpec) Validate() error {
@@ -211,6 +228,12 @@ func (l *Ledger) Start(ctx context.Context) error {
 			if l.dn.Spec.Ledger.CloneFromURL != "" && len(l.dn.Spec.Ledger.CloneProgramIDs) > 0 {
 				env["CLONE_FROM_URL"] = l.dn.Spec.Ledger.CloneFromURL
 				env["CLONE_PROGRAM_IDS"] = strings.Join(l.dn.Spec.Ledger.CloneProgramIDs, ",")
+				if len(l.dn.Spec.Ledger.CloneAccountTypes) > 0 {
+					env["CLONE_ACCOUNT_TYPES"] = strings.Join(l.dn.Spec.Ledger.CloneAccountTypes, ",")
+				}
+				if l.dn.Spec.Ledger.CloneMaxAccountSize > 0 {
+					env["CLONE_MAX_ACCOUNT_SIZE"] = fmt.Sprintf("%d", l.dn.Spec.Ledger.CloneMaxAccountSize)
+				}
 			}
 			if l.dn.Spec.Ledger.UpgradeProgramID != "" {
 				env["UPGRADE_PROGRAM_ID"] = l.dn.Spec.Ledger.UpgradeProgramID
@@ -220,6 +243,12 @@ func (l *Ledger) Start(ctx context.Context) error {
 			if l.dn.Spec.Ledger.PatchGlobalStateAuthority != "" {
 				env["PATCH_GLOBALSTATE_AUTHORITY"] = l.dn.Spec.Ledger.PatchGlobalStateAuthority
 			}
+			if len(l.dn.Spec.Ledger.PatchGlobalConfigArgs) > 0 {
+				env["PATCH_GLOBALCONFIG_ARGS"] = strings.Join(l.dn.Spec.Ledger.PatchGlobalConfigArgs, " ")
+			}
+			if len(l.dn.Spec.Ledger.PatchProgramConfigArgs) > 0 {
+				env["PATCH_PROGRAMCONFIG_ARGS"] = strings.Join(l.dn.Spec.Ledger.PatchProgramConfigArgs, " ")
+			}
 			return env
 		}(),
 		// Use HTTP health check instead of exec-based check. The exec strategy uses
@@ -0,0 +1,88 @@
+package main
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/gagliardetto/solana-go"
+	"github.com/gagliardetto/solana-go/rpc"
+	revdist "github.com/malbeclabs/doublezero/sdk/revdist/go"
+)
+
+// ledgerClient reads DZ Ledger records over RPC.
+type ledgerClient struct {
+	rpc *rpc.Client
+}
+
+func (c *ledgerClient) GetRecordData(ctx context.Context, account solana.PublicKey) ([]byte, error) {
+	result, err := c.rpc.GetAccountInfo(ctx, account)
+	if err != nil {
+		return nil, err
+	}
+	if result == nil || result.Value == nil {
+		return nil, revdist.ErrAccountNotFound
+	}
+	return result.Value.Data.GetBinary(), nil
+}
+
+func main() {
+	env := flag.String("env", "mainnet-beta", "Environment: mainnet-beta, testnet, devnet, localnet")
+	epoch := flag.Uint64("epoch", 0, "DZ epoch to preview rewards for (0 = latest completed epoch from config)")
+	flag.Parse()
+
+	validEnvs := map[string]bool{"mainnet-beta": true, "testnet": true, "devnet": true, "localnet": true}
+	if !validEnvs[*env] {
+		fmt.Fprintf(os.Stderr, "Invalid environment: %s\n", *env)
+		os.Exit(1)
+	}
+
+	client := revdist.NewForEnvWithLedger(*env, &ledgerClient{rpc: revdist.NewRPCClient(revdist.LedgerRPCURLs[*env])})
+
+	ctx, cancel := context.WithTimeout(context.Background(), 60*time.Second)
+	defer cancel()
+
+	targetEpoch := *epoch
+	if targetEpoch == 0 {
+		config, err := client.FetchConfig(ctx)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error fetching config: %v\n", err)
+			os.Exit(1)
+		}
+		if config.NextCompletedDZEpoch == 0 {
+			fmt.Fprintf(os.Stderr, "No completed epoch yet; pass -epoch\n")
+			os.Exit(1)
+		}
+		targetEpoch = config.NextCompletedDZEpoch - 1
+	}
+
+	preview, err := client.PreviewContributorRewards(ctx, targetEpoch)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error previewing rewards for epoch %d: %v\n", targetEpoch, err)
+		os.Exit(1)
+	}
+
+	fmt.Printf("=== Reward Preview (epoch %d) ===\n", preview.Epoch)
+	fmt.Printf("Distributable 2Z:       %d\n", preview.Distributable2Z)
+	fmt.Printf("Community Burn Rate:    %.2f%%\n", float64(preview.CommunityBurnRate)/revdist.UnitShare32Max*100)
+	fmt.Println()
+
+	fmt.Printf("%-44s %9s %9s %16s %16s\n", "CONTRIBUTOR", "SHARE", "BURN", "BURNED 2Z", "EXPECTED 2Z")
+	for _, c := range preview.Contributors {
+		burn := fmt.Sprintf("%.2f%%", float64(c.BurnRate)/revdist.UnitShare32Max*100)
+		if c.Blocked {
+			burn = "blocked"
+		}
+		fmt.Printf("%-44s %8.4f%% %9s %16d %16d\n",
+			c.ContributorKey,
+			float64(c.UnitShare)/revdist.UnitShare32Max*100,
+			burn,
+			c.Burned2Z,
+			c.Expected2Z)
+		for _, r := range c.Recipients {
+			fmt.Printf("  -> %-40s %8.2f%% %43d\n", r.RecipientKey, float64(r.Share)/revdist.UnitShare16Max*100, r.Amount)
+		}
+	}
+}
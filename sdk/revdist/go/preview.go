@@ -0,0 +1,146 @@
+package revdist
+
+import (
+	"context"
+	"encoding/binary"
+	"fmt"
+	"math/bits"
+	"sort"
+
+	"github.com/gagliardetto/solana-go"
+)
+
+const (
+	// UnitShare32Max is the denominator of a UnitShare32 (reward shares and burn rates).
+	UnitShare32Max = 1_000_000_000
+	// UnitShare16Max is the denominator of a UnitShare16 (recipient shares and validator fees).
+	UnitShare16Max = 10_000
+
+	rewardShareBlockedBit           = 1 << 31
+	rewardShareEconomicBurnRateMask = 0x3FFFFFFF
+)
+
+// IsBlocked reports whether the contributor's rewards are withheld for the epoch.
+func (r RewardShare) IsBlocked() bool {
+	return binary.LittleEndian.Uint32(r.RemainingBytes[:])&rewardShareBlockedBit != 0
+}
+
+// EconomicBurnRate returns the contributor's economic burn rate as a UnitShare32.
+func (r RewardShare) EconomicBurnRate() uint32 {
+	return binary.LittleEndian.Uint32(r.RemainingBytes[:]) & rewardShareEconomicBurnRateMask
+}
+
+// RecipientRewardPreview is the expected 2Z amount for one reward recipient.
+type RecipientRewardPreview struct {
+	RecipientKey solana.PublicKey
+	Share        uint16 // UnitShare16
+	Amount       uint64
+}
+
+// ContributorRewardPreview is the expected reward for one contributor in an epoch.
+type ContributorRewardPreview struct {
+	ContributorKey solana.PublicKey
+	UnitShare      uint32 // UnitShare32 of the distributable 2Z
+	BurnRate       uint32 // UnitShare32; max of community and economic burn rate
+	Blocked        bool
+	Gross2Z        uint64 // share of the distributable 2Z before burning
+	Burned2Z       uint64
+	Expected2Z     uint64 // Gross2Z - Burned2Z
+
+	// Recipients splits Expected2Z by the contributor's configured recipient
+	// shares. It is empty when the contributor has no rewards account.
+	Recipients []RecipientRewardPreview
+}
+
+// RewardPreview is the expected contributor payout for a DZ epoch.
+type RewardPreview struct {
+	Epoch             uint64
+	Distributable2Z   uint64 // prepaid 2Z plus 2Z converted from SOL
+	CommunityBurnRate uint32 // UnitShare32
+	Contributors      []ContributorRewardPreview
+}
+
+// PreviewContributorRewards computes the expected 2Z payout per contributor
+// for the given DZ epoch from the epoch's Distribution account, the rewards
+// accountant's Shapley output record and the contributors' rewards accounts.
+// It is read-only and can be run before the epoch's rewards are finalized.
+func (c *Client) PreviewContributorRewards(ctx context.Context, epoch uint64) (*RewardPreview, error) {
+	dist, err := c.FetchDistribution(ctx, epoch)
+	if err != nil {
+		return nil, fmt.Errorf("fetching distribution: %w", err)
+	}
+	shares, err := c.FetchRewardShares(ctx, epoch)
+	if err != nil {
+		return nil, err
+	}
+	rewards, err := c.FetchAllContributorRewards(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("fetching contributor rewards: %w", err)
+	}
+	return ComputeRewardPreview(dist, shares, rewards)
+}
+
+// ComputeRewardPreview derives each contributor's expected 2Z amount the way
+// the program distributes rewards: the contributor's unit share of the
+// distributable 2Z, less the greater of the community and economic burn rates.
+// A blocked contributor's whole share is burned. Amounts round down, so the
+// sum of the expected amounts can be slightly below the distributable total.
+func ComputeRewardPreview(dist *Distribution, shares *ShapleyOutputStorage, rewards []ContributorRewards) (*RewardPreview, error) {
+	if shares.Epoch != dist.DZEpoch {
+		return nil, fmt.Errorf("reward shares are for epoch %d, distribution is for epoch %d", shares.Epoch, dist.DZEpoch)
+	}
+
+	byServiceKey := make(map[solana.PublicKey]*ContributorRewards, len(rewards))
+	for i := range rewards {
+		byServiceKey[rewards[i].ServiceKey] = &rewards[i]
+	}
+
+	preview := &RewardPreview{
+		Epoch:             dist.DZEpoch,
+		Distributable2Z:   dist.CollectedPrepaid2ZPayments + dist.Collected2ZConvertedFromSOL,
+		CommunityBurnRate: dist.CommunityBurnRate,
+		Contributors:      make([]ContributorRewardPreview, 0, len(shares.Rewards)),
+	}
+	for _, share := range shares.Rewards {
+		p := ContributorRewardPreview{
+			ContributorKey: share.ContributorKey,
+			UnitShare:      share.UnitShare,
+			BurnRate:       max(dist.CommunityBurnRate, share.EconomicBurnRate()),
+			Blocked:        share.IsBlocked(),
+			Gross2Z:        mulShare(preview.Distributable2Z, uint64(share.UnitShare), UnitShare32Max),
+		}
+		if p.Blocked {
+			p.Burned2Z = p.Gross2Z
+		} else {
+			p.Burned2Z = mulShare(p.Gross2Z, uint64(p.BurnRate), UnitShare32Max)
+		}
+		p.Expected2Z = p.Gross2Z - p.Burned2Z
+
+		if cr, ok := byServiceKey[share.ContributorKey]; ok {
+			for _, r := range cr.RecipientShares {
+				if r.RecipientKey.IsZero() || r.Share == 0 {
+					continue
+				}
+				p.Recipients = append(p.Recipients, RecipientRewardPreview{
+					RecipientKey: r.RecipientKey,
+					Share:        r.Share,
+					Amount:       mulShare(p.Expected2Z, uint64(r.Share), UnitShare16Max),
+				})
+			}
+		}
+		preview.Contributors = append(preview.Contributors, p)
+	}
+	sort.SliceStable(preview.Contributors, func(i, j int) bool {
+		return preview.Contributors[i].Expected2Z > preview.Contributors[j].Expected2Z
+	})
+	return preview, nil
+}
+
+// mulShare returns amount * share / denom rounded down, clamping share to
+// denom. The 128-bit intermediate keeps large 2Z amounts from overflowing.
+func mulShare(amount, share, denom uint64) uint64 {
+	share = min(share, denom)
+	hi, lo := bits.Mul64(amount, share)
+	q, _ := bits.Div64(hi, lo, denom)
+	return q
+}
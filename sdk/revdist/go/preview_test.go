@@ -0,0 +1,121 @@
+package revdist
+
+import (
+	"encoding/binary"
+	"math"
+	"testing"
+
+	"github.com/gagliardetto/solana-go"
+)
+
+func rewardShare(key solana.PublicKey, unitShare uint32, blocked bool, economicBurnRate uint32) RewardShare {
+	remaining := economicBurnRate
+	if blocked {
+		remaining |= 1 << 31
+	}
+	share := RewardShare{ContributorKey: key, UnitShare: unitShare}
+	binary.LittleEndian.PutUint32(share.RemainingBytes[:], remaining)
+	return share
+}
+
+func TestRewardShareFlags(t *testing.T) {
+	share := rewardShare(solana.PublicKey{1}, 0, true, 250_000_000)
+	if !share.IsBlocked() {
+		t.Error("IsBlocked = false, want true")
+	}
+	if got := share.EconomicBurnRate(); got != 250_000_000 {
+		t.Errorf("EconomicBurnRate = %d, want 250000000", got)
+	}
+	if rewardShare(solana.PublicKey{1}, 0, false, 0).IsBlocked() {
+		t.Error("IsBlocked = true, want false")
+	}
+}
+
+func TestComputeRewardPreview(t *testing.T) {
+	alice := solana.PublicKey{1}
+	bob := solana.PublicKey{2}
+	carol := solana.PublicKey{3}
+	recipientA := solana.PublicKey{10}
+	recipientB := solana.PublicKey{11}
+
+	dist := &Distribution{
+		DZEpoch:                     42,
+		CommunityBurnRate:           100_000_000, // 10%
+		CollectedPrepaid2ZPayments:  400_000,
+		Collected2ZConvertedFromSOL: 600_000,
+	}
+	shares := &ShapleyOutputStorage{
+		Epoch: 42,
+		Rewards: []RewardShare{
+			rewardShare(alice, 600_000_000, false, 0),         // community burn rate applies
+			rewardShare(bob, 300_000_000, false, 500_000_000), // economic burn rate is higher
+			rewardShare(carol, 100_000_000, true, 0),          // blocked
+		},
+		TotalUnitShares: UnitShare32Max,
+	}
+	var aliceRecipients RecipientShares
+	aliceRecipients[0] = RecipientShare{RecipientKey: recipientA, Share: 7_500}
+	aliceRecipients[1] = RecipientShare{RecipientKey: recipientB, Share: 2_500}
+	rewards := []ContributorRewards{{ServiceKey: alice, RecipientShares: aliceRecipients}}
+
+	preview, err := ComputeRewardPreview(dist, shares, rewards)
+	if err != nil {
+		t.Fatalf("ComputeRewardPreview: %v", err)
+	}
+	if preview.Distributable2Z != 1_000_000 {
+		t.Errorf("Distributable2Z = %d, want 1000000", preview.Distributable2Z)
+	}
+	if len(preview.Contributors) != 3 {
+		t.Fatalf("got %d contributors, want 3", len(preview.Contributors))
+	}
+
+	want := []struct {
+		key                     solana.PublicKey
+		gross, burned, expected uint64
+		recipients              int
+	}{
+		{alice, 600_000, 60_000, 540_000, 2},
+		{bob, 300_000, 150_000, 150_000, 0},
+		{carol, 100_000, 100_000, 0, 0},
+	}
+	for i, w := range want {
+		got := preview.Contributors[i]
+		if got.ContributorKey != w.key {
+			t.Errorf("contributor %d = %s, want %s", i, got.ContributorKey, w.key)
+		}
+		if got.Gross2Z != w.gross || got.Burned2Z != w.burned || got.Expected2Z != w.expected {
+			t.Errorf("contributor %d amounts = %d/%d/%d, want %d/%d/%d", i,
+				got.Gross2Z, got.Burned2Z, got.Expected2Z, w.gross, w.burned, w.expected)
+		}
+		if len(got.Recipients) != w.recipients {
+			t.Errorf("contributor %d has %d recipients, want %d", i, len(got.Recipients), w.recipients)
+		}
+	}
+
+	recipients := preview.Contributors[0].Recipients
+	if recipients[0].RecipientKey != recipientA || recipients[0].Amount != 405_000 {
+		t.Errorf("recipient A = %s %d, want %s 405000", recipients[0].RecipientKey, recipients[0].Amount, recipientA)
+	}
+	if recipients[1].RecipientKey != recipientB || recipients[1].Amount != 135_000 {
+		t.Errorf("recipient B = %s %d, want %s 135000", recipients[1].RecipientKey, recipients[1].Amount, recipientB)
+	}
+}
+
+func TestComputeRewardPreviewEpochMismatch(t *testing.T) {
+	_, err := ComputeRewardPreview(&Distribution{DZEpoch: 42}, &ShapleyOutputStorage{Epoch: 41}, nil)
+	if err == nil {
+		t.Fatal("expected error for mismatched epochs")
+	}
+}
+
+func TestMulShareDoesNotOverflow(t *testing.T) {
+	if got := mulShare(math.MaxUint64, UnitShare32Max, UnitShare32Max); got != math.MaxUint64 {
+		t.Errorf("mulShare(max, 1) = %d, want %d", got, uint64(math.MaxUint64))
+	}
+	if got := mulShare(math.MaxUint64, 2*UnitShare32Max, UnitShare32Max); got != math.MaxUint64 {
+		t.Errorf("mulShare clamps share above denom: got %d", got)
+	}
+	if got := mulShare(999, UnitShare32Max/2, UnitShare32Max); got != 499 {
+		t.Errorf("mulShare rounds down: got %d, want 499", got)
+	}
+}
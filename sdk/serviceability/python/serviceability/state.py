@@ -115,6 +115,7 @@ class DeviceHealth(IntEnum):
     READY_FOR_LINKS = 2
     READY_FOR_USERS = 3
     IMPAIRED = 4
+    DRIFT_DETECTED = 5
 
     def __str__(self) -> str:
         _names = {
@@ -123,6 +124,7 @@ class DeviceHealth(IntEnum):
             2: "ready_for_links",
             3: "ready_for_users",
             4: "impaired",
+            5: "drift_detected",
         }
         return _names.get(self.value, "unknown")
 
@@ -355,6 +357,26 @@ class BGPStatus(IntEnum):
         return _names.get(self.value, "unknown")
 
 
+class BandwidthTier(IntEnum):
+    UNSHAPED = 0
+    BASIC = 1
+    STANDARD = 2
+    PREMIUM = 3
+
+    def __str__(self) -> str:
+        _names = {0: "unshaped", 1: "basic", 2: "standard", 3: "premium"}
+        return _names.get(self.value, "unshaped")
+
+
+def _read_bandwidth_tier(r: DefensiveReader) -> BandwidthTier:
+    # Unknown values and accounts that predate the field read as UNSHAPED,
+    # matching the program's unwrap_or_default().
+    try:
+        return BandwidthTier(r.read_u8())
+    except ValueError:
+        return BandwidthTier.UNSHAPED
+
+
 class MulticastGroupStatus(IntEnum):
     PENDING_DEPRECATED = 0  # deprecated; unreachable for new accounts
     ACTIVATED = 1
@@ -379,6 +401,7 @@ class AccessPassTypeTag(IntEnum):
     SOLANA_RPC = 2
     OTHERS = 3
     EDGE_SEAT = 4
+    LEASED = 5
 
     def __str__(self) -> str:
         _names = {
@@ -387,6 +410,7 @@ class AccessPassTypeTag(IntEnum):
             2: "solana_rpc",
             3: "others",
             4: "edge_seat",
+            5: "leased",
         }
         return _names.get(self.value, "unknown")
 
@@ -552,6 +576,7 @@ class GlobalState:
     qa_allowlist: list[Pubkey] = field(default_factory=list)
     feature_flags: int = 0
     feed_authority_pk: Pubkey = Pubkey.default()
+    paused: int = 0
 
     @classmethod
     def from_bytes(cls, data: bytes) -> GlobalState:
@@ -571,6 +596,7 @@ class GlobalState:
         gs.qa_allowlist = _read_pubkey_vec(r)
         gs.feature_flags = r.read_u128()
         gs.feed_authority_pk = _read_pubkey(r)
+        gs.paused = r.read_u64()
         return gs
 
 
@@ -675,6 +701,27 @@ class Exchange:
         return ex
 
 
+@dataclass
+class OpsLock:
+    """Freezes a Device or Link against changes by anyone but locked_by until
+    expires_at (unix seconds). All zeros when unlocked."""
+
+    locked_by: Pubkey = Pubkey.default()
+    expires_at: int = 0
+    reason: str = ""
+
+    def is_active(self, now: int) -> bool:
+        return self.locked_by != Pubkey.default() and now < self.expires_at
+
+    @classmethod
+    def from_reader(cls, r: DefensiveReader) -> OpsLock:
+        lock = cls()
+        lock.locked_by = _read_pubkey(r)
+        lock.expires_at = _read_i64(r)
+        lock.reason = r.read_string()
+        return lock
+
+
 @dataclass
 class Device:
     account_type: int = 0
@@ -709,6 +756,42 @@ class Device:
     # by from_bytes. When populated from the wire, len(interfaces) ==
     # len(deprecated_interfaces) is enforced.
     interfaces: list[Interface] = field(default_factory=list)
+    # config_generation is bumped by every change the controller renders for the
+    # device; applied_config_generation is the last generation it acknowledged.
+    config_generation: int = 0
+    applied_config_generation: int = 0
+    # previous_metrics_publisher_pub_key is the publisher replaced by the last
+    # rotation; it is still accepted up to metrics_publisher_overlap_until_epoch.
+    previous_metrics_publisher_pub_key: Pubkey = Pubkey.default()
+    metrics_publisher_overlap_until_epoch: int = 0
+    # attestation_challenge is the pending nonce the metrics publisher must echo
+    # back (all zeros when none); attested_key last answered a challenge.
+    attestation_challenge: bytes = b"\x00" * 32
+    attested_key: Pubkey = Pubkey.default()
+    ops_lock: OpsLock = field(default_factory=OpsLock)
+    # co_owner_pub_key holds the contributor owner's update rights on this device
+    # only (e.g. a managed-service provider); the default key when there is none.
+    co_owner_pub_key: Pubkey = Pubkey.default()
+
+    @property
+    def is_config_lagging(self) -> bool:
+        return self.applied_config_generation < self.config_generation
+
+    def is_metrics_publisher(self, key: Pubkey, epoch: int) -> bool:
+        if key == self.metrics_publisher_pub_key:
+            return True
+        return (
+            self.previous_metrics_publisher_pub_key != Pubkey.default()
+            and key == self.previous_metrics_publisher_pub_key
+            and epoch <= self.metrics_publisher_overlap_until_epoch
+        )
+
+    @property
+    def is_key_attested(self) -> bool:
+        return (
+            self.attested_key != Pubkey.default()
+            and self.attested_key == self.metrics_publisher_pub_key
+        )
 
     @classmethod
     def from_bytes(cls, data: bytes) -> Device:
@@ -780,10 +863,27 @@ class Device:
             dev.interfaces = [
                 Interface.from_reader_sized(r) for _ in range(new_len)
             ]
+            # Accounts written before config generations existed end here; both read as 0.
+            dev.config_generation = r.read_u64()
+            dev.applied_config_generation = r.read_u64()
+            # Likewise for the metrics publisher rotation fields.
+            dev.previous_metrics_publisher_pub_key = _read_pubkey(r)
+            dev.metrics_publisher_overlap_until_epoch = r.read_u64()
+            # And for the key attestation fields.
+            dev.attestation_challenge = r.read_bytes(32)
+            dev.attested_key = _read_pubkey(r)
+            # And for the ops lock, which reads as unlocked.
+            dev.ops_lock = OpsLock.from_reader(r)
+            # And for the co-owner, which reads as none.
+            dev.co_owner_pub_key = _read_pubkey(r)
 
         return dev
 
 
+# Bit 1 of Link.link_flags: the link is suppressed by flap damping.
+LINK_FLAG_FLAP_DAMPED = 0x02
+
+
 @dataclass
 class Link:
     account_type: int = 0
@@ -809,6 +909,11 @@ class Link:
     link_desired_status: LinkDesiredStatus = LinkDesiredStatus.PENDING
     link_topologies: list[Pubkey] = field(default_factory=list)
     link_flags: int = 0
+    flap_count: int = 0
+    flap_penalty: int = 0
+    flap_epoch: int = 0
+    damped_until_epoch: int = 0
+    ops_lock: OpsLock = field(default_factory=OpsLock)
 
     @classmethod
     def from_bytes(cls, data: bytes) -> Link:
@@ -836,9 +941,18 @@ class Link:
         lk.link_health = LinkHealth(r.read_u8())
         lk.link_desired_status = LinkDesiredStatus(r.read_u8())
         lk.link_topologies = _read_pubkey_vec(r)
-        lk.link_flags = r.read_u8()
+        lk.link_flags = r.read_u32()
+        lk.flap_count = r.read_u32()
+        lk.flap_penalty = r.read_u32()
+        lk.flap_epoch = r.read_u64()
+        lk.damped_until_epoch = r.read_u64()
+        lk.ops_lock = OpsLock.from_reader(r)
         return lk
 
+    @property
+    def is_flap_damped(self) -> bool:
+        return self.link_flags & LINK_FLAG_FLAP_DAMPED != 0
+
 
 @dataclass
 class User:
@@ -868,6 +982,7 @@ class User:
     bgp_rtt_ns: int = 0
     # EdgeSeat Feed whose per-feed seat this user consumed at connect (default pubkey if none).
     feed_pk: Pubkey = Pubkey.default()
+    bandwidth_tier: BandwidthTier = BandwidthTier.UNSHAPED
 
     @classmethod
     def from_bytes(cls, data: bytes) -> User:
@@ -899,6 +1014,7 @@ class User:
         u.bgp_rtt_ns = r.read_u64()
         # _read_pubkey returns the zero pubkey on EOF, so old accounts default feed_pk.
         u.feed_pk = _read_pubkey(r)
+        u.bandwidth_tier = _read_bandwidth_tier(r)
         return u
 
 
@@ -1063,6 +1179,7 @@ class AccessPass:
     others_type_name: str = ""  # for Others variant
     others_key: str = ""  # for Others variant
     feed_seats: list[FeedSeat] = field(default_factory=list)  # for EdgeSeat variant
+    lease_deposit: int = 0  # for Leased variant, in lamports
     client_ip: bytes = b"\x00" * 4
     user_payer: Pubkey = Pubkey.default()
     last_access_epoch: int = 0
@@ -1076,6 +1193,8 @@ class AccessPass:
     max_unicast_users: int = 1
     multicast_user_count: int = 0
     max_multicast_users: int = 1
+    bandwidth_tier: BandwidthTier = BandwidthTier.UNSHAPED
+    referrer: Pubkey = Pubkey.default()
 
     @classmethod
     def from_bytes(cls, data: bytes) -> AccessPass:
@@ -1113,6 +1232,9 @@ class AccessPass:
                 )
                 for _ in range(count)
             ]
+        # Leased carries the deposit it holds, in lamports.
+        elif tag == 5:
+            ap.lease_deposit = r.read_u64()
         # Prepaid (0) carries no associated data.
         ap.client_ip = r.read_ipv4()
         ap.user_payer = _read_pubkey(r)
@@ -1128,6 +1250,9 @@ class AccessPass:
         ap.max_unicast_users = r.read_u16() if r.remaining >= 2 else 1
         ap.multicast_user_count = r.read_u16()
         ap.max_multicast_users = r.read_u16() if r.remaining >= 2 else 1
+        ap.bandwidth_tier = _read_bandwidth_tier(r)
+        # Passes created before referrals read as unreferred.
+        ap.referrer = _read_pubkey(r)
         return ap
 
 
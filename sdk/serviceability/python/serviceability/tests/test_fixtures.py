@@ -1,6 +1,8 @@
 """Fixture-based compatibility tests."""
 
+import ipaddress
 import json
+import struct
 from pathlib import Path
 
 from solders.pubkey import Pubkey  # type: ignore[import-untyped]
@@ -8,6 +10,7 @@ from solders.pubkey import Pubkey  # type: ignore[import-untyped]
 from serviceability.state import (
     CURRENT_INTERFACE_VERSION,
     AccessPass,
+    BandwidthTier,
     BGPStatus,
     Contributor,
     Device,
@@ -18,6 +21,7 @@ from serviceability.state import (
     Link,
     Location,
     MulticastGroup,
+    OpsLock,
     ProgramConfig,
     Tenant,
     User,
@@ -80,6 +84,7 @@ class TestFixtureGlobalState:
                 "SentinelAuthorityPk": gs.sentinel_authority_pk,
                 "HealthOraclePk": gs.health_oracle_pk,
                 "FeedAuthorityPk": gs.feed_authority_pk,
+                "Paused": gs.paused,
             },
         )
 
@@ -167,8 +172,23 @@ class TestFixtureDevice:
                 "MaxMulticastPublishers": dev.max_multicast_publishers,
                 "MetricsPublisherPk": dev.metrics_publisher_pub_key,
                 "ContributorPk": dev.contributor_pub_key,
+                "ConfigGeneration": dev.config_generation,
+                "AppliedConfigGeneration": dev.applied_config_generation,
+                "PreviousMetricsPublisherPk": dev.previous_metrics_publisher_pub_key,
+                "MetricsPublisherOverlapUntilEpoch": dev.metrics_publisher_overlap_until_epoch,
+                "AttestedKey": dev.attested_key,
+                "OpsLockLockedBy": dev.ops_lock.locked_by,
+                "OpsLockExpiresAt": dev.ops_lock.expires_at,
+                "OpsLockReason": dev.ops_lock.reason,
+                "CoOwnerPk": dev.co_owner_pub_key,
             },
         )
+        assert dev.attestation_challenge == b"\x47" * 32
+        assert not dev.is_key_attested
+        assert dev.is_config_lagging
+        previous = dev.previous_metrics_publisher_pub_key
+        assert dev.is_metrics_publisher(previous, 42)
+        assert not dev.is_metrics_publisher(previous, 43)
         # Legacy slot is the V2 projection of new_interfaces (always V2 per #3653);
         # both entries carry version 1 and no FlexAlgoNodeSegments.
         assert len(dev.deprecated_interfaces) == 2
@@ -251,6 +271,14 @@ class TestFixtureDeviceLegacy:
         assert dev.interfaces[0].loopback_type.value == 1  # Vpnv4
         assert dev.interfaces[1].name == "Ethernet1"
         assert dev.interfaces[1].user_tunnel_endpoint is True
+        assert dev.config_generation == 0
+        assert dev.applied_config_generation == 0
+        assert dev.previous_metrics_publisher_pub_key == Pubkey.default()
+        assert dev.metrics_publisher_overlap_until_epoch == 0
+        assert dev.attestation_challenge == b"\x00" * 32
+        assert dev.attested_key == Pubkey.default()
+        assert dev.ops_lock == OpsLock()
+        assert dev.co_owner_pub_key == Pubkey.default()
 
 
 class TestFixtureDeviceFutureVersion:
@@ -278,6 +306,12 @@ class TestFixtureDeviceFutureVersion:
         assert ni1.size == _expected_new_interface_size(ni1) + 8
         assert ni1.name == "Ethernet1"
         assert ni1.user_tunnel_endpoint is True
+        # Fields after the trailing vec are still read from the right offset.
+        assert dev.config_generation == 9
+        assert dev.applied_config_generation == 7
+        assert dev.metrics_publisher_overlap_until_epoch == 42
+        assert dev.attested_key == Pubkey(bytes([0x48]) + bytes(31))
+        assert dev.co_owner_pub_key == Pubkey(bytes([0x4A]) + bytes(31))
 
 
 class TestFixtureLink:
@@ -301,8 +335,32 @@ class TestFixtureLink:
                 "DelayOverrideNs": lk.delay_override_ns,
                 "LinkHealth": lk.link_health,
                 "DesiredStatus": lk.link_desired_status,
+                "LinkFlags": lk.link_flags,
+                "FlapCount": lk.flap_count,
+                "FlapPenalty": lk.flap_penalty,
+                "FlapEpoch": lk.flap_epoch,
+                "DampedUntilEpoch": lk.damped_until_epoch,
+                "OpsLockLockedBy": lk.ops_lock.locked_by,
+                "OpsLockExpiresAt": lk.ops_lock.expires_at,
+                "OpsLockReason": lk.ops_lock.reason,
             },
         )
+        assert lk.is_flap_damped
+        assert lk.ops_lock.is_active(1_699_999_999)
+
+    def test_deserialize_without_flap_damping(self):
+        # An account that predates flap damping lacks flap_count (4) + flap_penalty (4)
+        # + flap_epoch (8) + damped_until_epoch (8) = 24 bytes, which default to 0,
+        # and the ops lock written after them.
+        ops_lock_len = 32 + 8 + 4 + len("OTDR trace")
+        data, _ = _load_fixture("link")
+        lk = Link.from_bytes(data[: -24 - ops_lock_len])
+        assert lk.link_flags == 2
+        assert lk.flap_count == 0
+        assert lk.flap_penalty == 0
+        assert lk.flap_epoch == 0
+        assert lk.damped_until_epoch == 0
+        assert lk.ops_lock == OpsLock()
 
 
 class TestFixtureUser:
@@ -328,6 +386,7 @@ class TestFixtureUser:
                 "LastBgpReportedAt": u.last_bgp_reported_at,
                 "BgpRttNs": u.bgp_rtt_ns,
                 "FeedPk": u.feed_pk,
+                "BandwidthTier": u.bandwidth_tier,
             },
         )
 
@@ -335,15 +394,16 @@ class TestFixtureUser:
         # Deserializing an account binary that predates the BGP fields must
         # return zero values for those fields rather than failing.
         data, _ = _load_fixture("user")
-        # Remove feed_pk (32) + bgp_status (1) + last_bgp_up_at (8) + last_bgp_reported_at (8)
-        # + bgp_rtt_ns (8) = 57 bytes.
-        truncated = data[:-57]
+        # Remove bandwidth_tier (1) + feed_pk (32) + bgp_status (1) + last_bgp_up_at (8)
+        # + last_bgp_reported_at (8) + bgp_rtt_ns (8) = 58 bytes.
+        truncated = data[:-58]
         u = User.from_bytes(truncated)
         assert u.bgp_status == BGPStatus.UNKNOWN
         assert u.last_bgp_up_at == 0
         assert u.last_bgp_reported_at == 0
         assert u.bgp_rtt_ns == 0
         assert u.feed_pk == Pubkey.default()
+        assert u.bandwidth_tier == BandwidthTier.UNSHAPED
 
 
 class TestFixtureMulticastGroup:
@@ -442,6 +502,8 @@ class TestFixtureAccessPass:
                 "MaxUnicastUsers": ap.max_unicast_users,
                 "MulticastUserCount": ap.multicast_user_count,
                 "MaxMulticastUsers": ap.max_multicast_users,
+                "BandwidthTier": ap.bandwidth_tier,
+                "Referrer": ap.referrer,
             },
         )
         assert ap.unicast_user_count == 2
@@ -552,6 +614,34 @@ class TestFixtureAccessPassEdgeSeat:
         assert ap.max_multicast_users == 3
 
 
+class TestFixtureAccessPassLeased:
+    def test_deserialize(self):
+        data, meta = _load_fixture("access_pass_leased")
+        ap = AccessPass.from_bytes(data)
+        _assert_fields(
+            meta["fields"],
+            {
+                "AccountType": ap.account_type,
+                "Owner": ap.owner,
+                "BumpSeed": ap.bump_seed,
+                "AccessPassType": ap.access_pass_type_tag,
+                "LeaseDeposit": ap.lease_deposit,
+                "UserPayer": ap.user_payer,
+                "LastAccessEpoch": ap.last_access_epoch,
+                "ConnectionCount": ap.connection_count,
+                "Status": ap.status,
+                "Flags": ap.flags,
+                "MaxUnicastUsers": ap.max_unicast_users,
+                "MaxMulticastUsers": ap.max_multicast_users,
+            },
+        )
+        # Leased is tag 5 and carries the deposit in lamports.
+        assert ap.access_pass_type_tag == 5
+        assert ap.associated_pubkey is None
+        assert ap.feed_seats == []
+        assert ap.lease_deposit == 5000000
+
+
 class TestFixtureFeed:
     def test_deserialize(self):
         data, meta = _load_fixture("feed")
@@ -579,11 +669,139 @@ class TestFixtureFeed:
 
 class TestFixtureAccessPassLegacyCapDefaults:
     def test_deserialize(self):
-        # A pre-migration account lacks the 8 trailing cap bytes; counts default to 0 and caps to 1,
-        # matching the Rust program's TryFrom unwrap_or defaults.
+        # A pre-migration account lacks the 8 trailing cap bytes, the bandwidth tier and the
+        # referrer; counts default to 0 and caps to 1, matching the Rust program's TryFrom
+        # unwrap_or defaults.
         data, _ = _load_fixture("access_pass")
-        ap = AccessPass.from_bytes(data[:-8])
+        ap = AccessPass.from_bytes(data[:-41])
         assert ap.unicast_user_count == 0
         assert ap.max_unicast_users == 1
         assert ap.multicast_user_count == 0
         assert ap.max_multicast_users == 1
+        assert ap.bandwidth_tier == BandwidthTier.UNSHAPED
+        assert ap.referrer == Pubkey.default()
+
+
+NEGATIVE_DIR = FIXTURES_DIR / "negative"
+
+
+def _negative_fixture_names() -> list[str]:
+    manifest = json.loads((NEGATIVE_DIR / "manifest.json").read_text())
+    return manifest["fixtures"]
+
+
+def _decode_negative(account: str, data: bytes) -> dict:
+    if account == "Location":
+        loc = Location.from_bytes(data)
+        return {"AccountType": loc.account_type, "Status": loc.status, "Code": loc.code}
+    if account == "Device":
+        dev = Device.from_bytes(data)
+        return {"AccountType": dev.account_type, "Status": dev.status, "Code": dev.code}
+    if account == "User":
+        user = User.from_bytes(data)
+        return {"AccountType": user.account_type, "UserType": user.user_type, "Status": user.status}
+    raise AssertionError(f"no decoder for account {account}")
+
+
+class TestFixtureNegative:
+    """Corrupted fixtures carrying the Rust reader's verdict (see generate-fixtures/src/negative.rs)."""
+
+    def test_manifest_not_empty(self):
+        assert _negative_fixture_names()
+
+    def test_matches_rust_verdict(self):
+        for name in _negative_fixture_names():
+            data = (NEGATIVE_DIR / f"{name}.bin").read_bytes()
+            meta = json.loads((NEGATIVE_DIR / f"{name}.json").read_text())
+            try:
+                got = _decode_negative(meta["account"], data)
+            except ValueError:
+                # Rejecting is fine where Rust rejects, or where an out-of-range enum
+                # discriminant was written (Rust falls back to the enum default there).
+                assert meta["error"] is not None or meta["corrupted_field"] is not None, name
+                continue
+            if meta["error"] is not None:
+                assert got["AccountType"] != meta["account_type"], name
+                continue
+            _assert_fields(meta["fields"], got)
+
+
+MATRIX_DIR = FIXTURES_DIR / "matrix"
+
+
+class TestFixtureMatrix:
+    """Enum-variant matrices (see generate-fixtures/src/matrix.rs): one Device per
+    combination of the declared axes."""
+
+    def _check(self, name: str) -> None:
+        meta = json.loads((MATRIX_DIR / f"{name}.json").read_text())
+        assert meta["entries"]
+        for entry in meta["entries"]:
+            dev = Device.from_bytes(bytes.fromhex(entry["data"]))
+            got = {
+                "AccountType": dev.account_type,
+                "DeviceType": dev.device_type,
+                "Status": dev.status,
+                "DeviceHealth": dev.device_health,
+                "Code": dev.code,
+            }
+            if dev.interfaces:
+                iface = dev.interfaces[0]
+                got.update(
+                    {
+                        "InterfacesLen": len(dev.interfaces),
+                        "Interface0Version": iface.version,
+                        "Interface0Name": iface.name,
+                        "Interface0UserTunnelEndpoint": iface.user_tunnel_endpoint,
+                        "Interface0FlexAlgoNodeSegmentsLen": len(iface.flex_algo_node_segments),
+                    }
+                )
+            _assert_fields(entry["fields"], got)
+
+    def test_device_matrix(self):
+        self._check("device_matrix")
+
+    def test_interface_version_matrix(self):
+        self._check("interface_version_matrix")
+
+
+INSTRUCTIONS_DIR = FIXTURES_DIR / "instructions"
+
+_INT_FORMATS = {"u8": "<B", "u16": "<H", "u32": "<I", "u64": "<Q", "i32": "<i", "i64": "<q"}
+
+
+def _encode_instruction(meta: dict) -> bytes:
+    """Writes the variant byte and then each flattened field (see
+    generate-fixtures/src/instructions.rs)."""
+    out = bytearray([meta["variant"]])
+    for f in meta["fields"]:
+        typ, value = f["typ"], f["value"]
+        if typ in _INT_FORMATS:
+            out += struct.pack(_INT_FORMATS[typ], int(value))
+        elif typ == "bool":
+            out.append(1 if value == "true" else 0)
+        elif typ == "string":
+            encoded = value.encode()
+            out += struct.pack("<I", len(encoded)) + encoded
+        elif typ == "pubkey":
+            out += bytes(Pubkey.from_string(value))
+        elif typ == "ipv4":
+            out += ipaddress.IPv4Address(value).packed
+        elif typ == "networkv4":
+            net = ipaddress.IPv4Interface(value)
+            out += net.ip.packed + bytes([net.network.prefixlen])
+        else:
+            raise AssertionError(f"{meta['name']}.{f['name']}: unsupported field type {typ}")
+    return bytes(out)
+
+
+class TestInstructionFixtures:
+    """Encoded instructions: every fixture can be rebuilt byte-for-byte from its metadata."""
+
+    def test_encode_from_metadata(self):
+        names = sorted(p.stem for p in INSTRUCTIONS_DIR.glob("*.json"))
+        assert names
+        for name in names:
+            meta = json.loads((INSTRUCTIONS_DIR / f"{name}.json").read_text())
+            data = (INSTRUCTIONS_DIR / f"{name}.bin").read_bytes()
+            assert _encode_instruction(meta) == data, name
@@ -0,0 +1,364 @@
+//! Instruction-encoding fixtures, written to `../instructions/`. Each `.bin` is the full
+//! instruction data a client puts in a transaction: the 1-byte `DoubleZeroInstruction`
+//! discriminant followed by the Borsh-encoded args, as produced by
+//! `DoubleZeroInstruction::pack`.
+//!
+//! The `.json` lists the wire fields in order, flattened to primitives: a `Vec` is its
+//! `u32` length followed by its elements, and an `Option` or an enum carrying data is its
+//! `u8` tag followed by the payload (if any). Writing `variant` and then each field in
+//! order reproduces the `.bin` exactly; the generator checks that before writing, and the
+//! Go/TypeScript/Python tests do the same, so an SDK can build these instructions
+//! byte-for-byte from the metadata alone.
+
+use std::fs;
+use std::net::Ipv4Addr;
+use std::path::Path;
+use std::str::FromStr;
+
+use doublezero_serviceability::instructions::DoubleZeroInstruction;
+use doublezero_serviceability::processors::{
+    accesspass::set::SetAccessPassArgs,
+    device::{create::DeviceCreateArgs, sethealth::DeviceSetHealthArgs},
+    interface_oper_status::set::InterfaceOperStatusSetArgs,
+    link::sethealth::LinkSetHealthArgs,
+    user::{create::UserCreateArgs, delete::UserDeleteArgs, set_bgp_status::SetUserBGPStatusArgs},
+};
+use doublezero_serviceability::state::{
+    accesspass::AccessPassType,
+    device::{DeviceDesiredStatus, DeviceHealth, DeviceType},
+    interface_oper_status::{InterfaceOperState, InterfaceOperStatusEntry},
+    link::LinkHealth,
+    user::{BGPStatus, BandwidthTier, UserCYOA, UserType},
+};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::{pubkey_bs58, pubkey_from_byte, FieldValue};
+
+#[derive(Serialize)]
+struct InstructionMeta {
+    name: String,
+    variant: u8,
+    fields: Vec<FieldValue>,
+}
+
+fn field(name: &str, value: impl ToString, typ: &str) -> FieldValue {
+    FieldValue {
+        name: name.into(),
+        value: value.to_string(),
+        typ: typ.into(),
+    }
+}
+
+/// Encodes flattened fields the way a client SDK would, to prove the metadata is
+/// complete: anything the fields miss shows up as a mismatch against `pack`.
+fn encode_fields(fields: &[FieldValue]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for f in fields {
+        let v = f.value.as_str();
+        match f.typ.as_str() {
+            "u8" => out.push(v.parse::<u8>().unwrap()),
+            "u16" => out.extend(v.parse::<u16>().unwrap().to_le_bytes()),
+            "u32" => out.extend(v.parse::<u32>().unwrap().to_le_bytes()),
+            "u64" => out.extend(v.parse::<u64>().unwrap().to_le_bytes()),
+            "i32" => out.extend(v.parse::<i32>().unwrap().to_le_bytes()),
+            "i64" => out.extend(v.parse::<i64>().unwrap().to_le_bytes()),
+            "bool" => out.push(v.parse::<bool>().unwrap() as u8),
+            "string" => {
+                out.extend((v.len() as u32).to_le_bytes());
+                out.extend(v.as_bytes());
+            }
+            "pubkey" => out.extend(Pubkey::from_str(v).unwrap().to_bytes()),
+            "ipv4" => out.extend(v.parse::<Ipv4Addr>().unwrap().octets()),
+            "networkv4" => {
+                let (ip, len) = v.split_once('/').unwrap();
+                out.extend(ip.parse::<Ipv4Addr>().unwrap().octets());
+                out.push(len.parse::<u8>().unwrap());
+            }
+            other => panic!("{}: unsupported field type {other}", f.name),
+        }
+    }
+    out
+}
+
+fn write_instruction_fixture(
+    dir: &Path,
+    file: &str,
+    instruction: DoubleZeroInstruction,
+    fields: Vec<FieldValue>,
+) {
+    let data = instruction.pack();
+    assert_eq!(
+        DoubleZeroInstruction::unpack(&data).unwrap(),
+        instruction,
+        "{file}: packed instruction does not unpack to itself"
+    );
+    assert_eq!(
+        encode_fields(&fields),
+        data[1..],
+        "{file}: fields do not encode to the packed instruction"
+    );
+
+    let meta = InstructionMeta {
+        name: instruction.get_name(),
+        variant: data[0],
+        fields,
+    };
+    fs::write(dir.join(format!("{file}.bin")), &data).unwrap();
+    let json = serde_json::to_string_pretty(&meta).unwrap();
+    fs::write(dir.join(format!("{file}.json")), json).unwrap();
+    println!(
+        "wrote instructions/{file}.bin ({} bytes) and instructions/{file}.json",
+        data.len()
+    );
+}
+
+pub fn generate_instruction_fixtures(fixtures_dir: &Path) {
+    let dir = fixtures_dir.join("instructions");
+    fs::create_dir_all(&dir).unwrap();
+
+    generate_create_device(&dir);
+    generate_activate_link(&dir);
+    generate_create_user(&dir);
+    generate_delete_user(&dir);
+    generate_set_access_pass(&dir);
+    generate_set_device_health(&dir);
+    generate_set_link_health(&dir);
+    generate_set_user_bgp_status(&dir);
+    generate_set_interface_oper_status(&dir);
+}
+
+fn generate_create_device(dir: &Path) {
+    let metrics_publisher_pk = pubkey_from_byte(0x21);
+    let args = DeviceCreateArgs {
+        code: "dz1".into(),
+        device_type: DeviceType::Transit,
+        public_ip: Ipv4Addr::new(203, 0, 113, 1),
+        dz_prefixes: "100.0.0.0/29,100.0.1.0/29".parse().unwrap(),
+        metrics_publisher_pk,
+        mgmt_vrf: "mgmt".into(),
+        desired_status: Some(DeviceDesiredStatus::Drained),
+        resource_count: 3,
+    };
+
+    let fields = vec![
+        field("Code", "dz1", "string"),
+        field("DeviceType", 1, "u8"),
+        field("PublicIp", "203.0.113.1", "ipv4"),
+        field("DzPrefixesLen", 2, "u32"),
+        field("DzPrefix0", "100.0.0.0/29", "networkv4"),
+        field("DzPrefix1", "100.0.1.0/29", "networkv4"),
+        field(
+            "MetricsPublisherPk",
+            pubkey_bs58(&metrics_publisher_pk),
+            "pubkey",
+        ),
+        field("MgmtVrf", "mgmt", "string"),
+        field("DesiredStatusTag", 1, "u8"),
+        field("DesiredStatus", 6, "u8"),
+        field("ResourceCount", 3, "u8"),
+    ];
+
+    write_instruction_fixture(
+        dir,
+        "create_device",
+        DoubleZeroInstruction::CreateDevice(args),
+        fields,
+    );
+}
+
+/// ActivateLink carries no args; the instruction data is the discriminant alone.
+fn generate_activate_link(dir: &Path) {
+    write_instruction_fixture(
+        dir,
+        "activate_link",
+        DoubleZeroInstruction::ActivateLink(),
+        vec![],
+    );
+}
+
+/// Unlike `user_create_args.bin`, this carries the trailing `bandwidth_tier`.
+fn generate_create_user(dir: &Path) {
+    let args = UserCreateArgs {
+        user_type: UserType::IBRL,
+        cyoa_type: UserCYOA::GREOverDIA,
+        client_ip: Ipv4Addr::new(10, 11, 12, 13),
+        tunnel_endpoint: Ipv4Addr::new(192, 168, 1, 2),
+        dz_prefix_count: 2,
+        bandwidth_tier: Some(BandwidthTier::Standard),
+    };
+
+    let fields = vec![
+        field("UserType", 0, "u8"),
+        field("CyoaType", 1, "u8"),
+        field("ClientIp", "10.11.12.13", "ipv4"),
+        field("TunnelEndpoint", "192.168.1.2", "ipv4"),
+        field("DzPrefixCount", 2, "u8"),
+        field("BandwidthTierTag", 1, "u8"),
+        field("BandwidthTier", 2, "u8"),
+    ];
+
+    write_instruction_fixture(
+        dir,
+        "create_user",
+        DoubleZeroInstruction::CreateUser(args),
+        fields,
+    );
+}
+
+fn generate_delete_user(dir: &Path) {
+    let args = UserDeleteArgs {
+        dz_prefix_count: 3,
+        multicast_publisher_count: 1,
+    };
+
+    let fields = vec![
+        field("DzPrefixCount", 3, "u8"),
+        field("MulticastPublisherCount", 1, "u8"),
+    ];
+
+    write_instruction_fixture(
+        dir,
+        "delete_user",
+        DoubleZeroInstruction::DeleteUser(args),
+        fields,
+    );
+}
+
+fn generate_set_access_pass(dir: &Path) {
+    let validator_pk = pubkey_from_byte(0x31);
+    let referrer = pubkey_from_byte(0x32);
+    let args = SetAccessPassArgs {
+        accesspass_type: AccessPassType::SolanaValidator(validator_pk),
+        client_ip: Ipv4Addr::new(10, 11, 12, 13),
+        last_access_epoch: 1_234,
+        allow_multiple_ip: true,
+        max_unicast_users: 2,
+        max_multicast_users: 5,
+        bandwidth_tier: Some(BandwidthTier::Premium),
+        referrer: Some(referrer),
+    };
+
+    let fields = vec![
+        field("AccessPassTypeTag", 1, "u8"),
+        field("ValidatorPk", pubkey_bs58(&validator_pk), "pubkey"),
+        field("ClientIp", "10.11.12.13", "ipv4"),
+        field("LastAccessEpoch", 1_234, "u64"),
+        field("AllowMultipleIp", true, "bool"),
+        field("MaxUnicastUsers", 2, "u16"),
+        field("MaxMulticastUsers", 5, "u16"),
+        field("BandwidthTierTag", 1, "u8"),
+        field("BandwidthTier", 3, "u8"),
+        field("ReferrerTag", 1, "u8"),
+        field("Referrer", pubkey_bs58(&referrer), "pubkey"),
+    ];
+
+    write_instruction_fixture(
+        dir,
+        "set_access_pass",
+        DoubleZeroInstruction::SetAccessPass(args),
+        fields,
+    );
+}
+
+fn generate_set_device_health(dir: &Path) {
+    let args = DeviceSetHealthArgs {
+        health: DeviceHealth::ReadyForUsers,
+    };
+    let fields = vec![field("Health", 3, "u8")];
+    write_instruction_fixture(
+        dir,
+        "set_device_health",
+        DoubleZeroInstruction::SetDeviceHealth(args),
+        fields,
+    );
+}
+
+fn generate_set_link_health(dir: &Path) {
+    let args = LinkSetHealthArgs {
+        health: LinkHealth::ReadyForService,
+    };
+    let fields = vec![field("Health", 2, "u8")];
+    write_instruction_fixture(
+        dir,
+        "set_link_health",
+        DoubleZeroInstruction::SetLinkHealth(args),
+        fields,
+    );
+}
+
+fn generate_set_user_bgp_status(dir: &Path) {
+    let args = SetUserBGPStatusArgs {
+        bgp_status: BGPStatus::Up,
+        bgp_rtt_ns: 1_234_567,
+    };
+    let fields = vec![
+        field("BgpStatus", 1, "u8"),
+        field("BgpRttNs", 1_234_567, "u64"),
+    ];
+    write_instruction_fixture(
+        dir,
+        "set_user_bgp_status",
+        DoubleZeroInstruction::SetUserBGPStatus(args),
+        fields,
+    );
+}
+
+/// Negative light levels and large counters make sign and width mistakes detectable.
+fn generate_set_interface_oper_status(dir: &Path) {
+    let args = InterfaceOperStatusSetArgs {
+        interfaces: vec![
+            InterfaceOperStatusEntry {
+                name: "Ethernet1/1".into(),
+                oper_status: InterfaceOperState::Up,
+                rx_power_mdbm: -2_345,
+                tx_power_mdbm: 1_250,
+                in_errors: 7,
+                out_errors: 0,
+                in_discards: 4_294_967_296,
+                out_discards: 12,
+                updated_at: 0,
+            },
+            InterfaceOperStatusEntry {
+                name: "Ethernet2".into(),
+                oper_status: InterfaceOperState::LowerLayerDown,
+                rx_power_mdbm: i32::MIN,
+                tx_power_mdbm: i32::MIN,
+                in_errors: 0,
+                out_errors: 3,
+                in_discards: 0,
+                out_discards: 0,
+                updated_at: 1_700_000_000,
+            },
+        ],
+    };
+
+    let fields = vec![
+        field("InterfacesLen", 2, "u32"),
+        field("Interface0Name", "Ethernet1/1", "string"),
+        field("Interface0OperStatus", 1, "u8"),
+        field("Interface0RxPowerMdbm", -2_345, "i32"),
+        field("Interface0TxPowerMdbm", 1_250, "i32"),
+        field("Interface0InErrors", 7, "u64"),
+        field("Interface0OutErrors", 0, "u64"),
+        field("Interface0InDiscards", 4_294_967_296u64, "u64"),
+        field("Interface0OutDiscards", 12, "u64"),
+        field("Interface0UpdatedAt", 0, "i64"),
+        field("Interface1Name", "Ethernet2", "string"),
+        field("Interface1OperStatus", 3, "u8"),
+        field("Interface1RxPowerMdbm", i32::MIN, "i32"),
+        field("Interface1TxPowerMdbm", i32::MIN, "i32"),
+        field("Interface1InErrors", 0, "u64"),
+        field("Interface1OutErrors", 3, "u64"),
+        field("Interface1InDiscards", 0, "u64"),
+        field("Interface1OutDiscards", 0, "u64"),
+        field("Interface1UpdatedAt", 1_700_000_000, "i64"),
+    ];
+
+    write_instruction_fixture(
+        dir,
+        "set_interface_oper_status",
+        DoubleZeroInstruction::SetInterfaceOperStatus(args),
+        fields,
+    );
+}
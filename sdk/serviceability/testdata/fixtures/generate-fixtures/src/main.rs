@@ -8,6 +8,14 @@
 //! Key difference from revdist fixtures: these use Borsh serialization (not repr(C)/bytemuck),
 //! and the 1-byte AccountType discriminator is the first byte of the Borsh serialization itself
 //! (no separate 8-byte discriminator prefix).
+//!
+//! Corrupted negative-path fixtures are written to ../fixtures/negative/ (see negative.rs),
+//! enum-variant matrices to ../fixtures/matrix/ (see matrix.rs) and encoded instructions
+//! to ../fixtures/instructions/ (see instructions.rs).
+
+mod instructions;
+mod matrix;
+mod negative;
 
 use std::fs;
 use std::net::Ipv4Addr;
@@ -35,13 +43,14 @@ use doublezero_serviceability::state::{
         InterfaceType, InterfaceV1, InterfaceV2, LoopbackType, RoutingMode,
         CURRENT_INTERFACE_SCHEMA_VERSION,
     },
-    link::{Link, LinkDesiredStatus, LinkHealth, LinkLinkType, LinkStatus},
+    link::{Link, LinkDesiredStatus, LinkHealth, LinkLinkType, LinkStatus, LINK_FLAG_FLAP_DAMPED},
     location::{Location, LocationStatus},
     multicastgroup::{MulticastGroup, MulticastGroupStatus},
+    ops_lock::OpsLock,
     programconfig::ProgramConfig,
     tenant::{Tenant, TenantBillingConfig, TenantPaymentStatus},
     topology::FlexAlgoNodeSegment,
-    user::{BGPStatus, User, UserCYOA, UserStatus, UserType},
+    user::{BGPStatus, BandwidthTier, User, UserCYOA, UserStatus, UserType},
 };
 use serde::Serialize;
 
@@ -97,6 +106,7 @@ fn main() {
     generate_access_pass(&fixtures_dir);
     generate_access_pass_validator(&fixtures_dir);
     generate_access_pass_edge_seat(&fixtures_dir);
+    generate_access_pass_leased(&fixtures_dir);
     generate_feed(&fixtures_dir);
     generate_tenant(&fixtures_dir);
     generate_resource_extension_id(&fixtures_dir);
@@ -104,6 +114,12 @@ fn main() {
     generate_user_create_args(&fixtures_dir);
     generate_user_delete_args(&fixtures_dir);
 
+    matrix::generate_matrix_fixtures(&fixtures_dir);
+    instructions::generate_instruction_fixtures(&fixtures_dir);
+
+    // Derived from the .bin files above, so must run last.
+    negative::generate_negative_fixtures(&fixtures_dir);
+
     println!("
 all fixtures generated in {}", fixtures_dir.display());
 }
@@ -118,9 +134,13 @@ fn generate_user_create_args(dir: &Path) {
         client_ip: Ipv4Addr::new(10, 11, 12, 13),
         tunnel_endpoint: Ipv4Addr::new(192, 168, 1, 2),
         dz_prefix_count: 2,
+        bandwidth_tier: None,
     };
 
-    let data = borsh::to_vec(&val).unwrap();
+    // Leave off the trailing `bandwidth_tier: None` tag, as clients that predate
+    // the field do; the program reads the missing tail as `None`.
+    let mut data = borsh::to_vec(&val).unwrap();
+    data.pop();
 
     let meta = FixtureMeta {
         name: "UserCreateArgs".into(),
@@ -183,6 +203,7 @@ fn generate_global_state(dir: &Path) {
         qa_allowlist: vec![qa_pk],
         feature_flags: 1,
         feed_authority_pk: feed_authority_pk,
+        paused: 5,
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -207,6 +228,7 @@ fn generate_global_state(dir: &Path) {
             FieldValue { name: "QaAllowlist0".into(), value: pubkey_bs58(&qa_pk), typ: "pubkey".into() },
             FieldValue { name: "FeatureFlags".into(), value: "1".into(), typ: "u128".into() },
             FieldValue { name: "FeedAuthorityPk".into(), value: pubkey_bs58(&feed_authority_pk), typ: "pubkey".into() },
+            FieldValue { name: "Paused".into(), value: "5".into(), typ: "u64".into() },
         ],
     };
 
@@ -345,6 +367,9 @@ fn generate_exchange(dir: &Path) {
 /// The trailing `interfaces` vec carries one Vpnv4 loopback with a `FlexAlgoNodeSegment`
 /// and one physical user-tunnel-endpoint. `deprecated_interfaces: vec![]` because the custom Device
 /// serializer projects the legacy on-disk slot from `interfaces` (always V2 per #3653).
+/// Reason carried by the canonical Device's ops lock.
+const DEVICE_OPS_LOCK_REASON: &str = "optics swap";
+
 fn canonical_device() -> (
     Device,
     solana_program::pubkey::Pubkey, // owner
@@ -436,6 +461,18 @@ fn canonical_device() -> (
         reserved_seats: 3,
         multicast_publishers_count: 1,
         max_multicast_publishers: 10,
+        config_generation: 9,
+        applied_config_generation: 7,
+        previous_metrics_publisher_pk: pubkey_from_byte(0x46),
+        metrics_publisher_overlap_until_epoch: 42,
+        attestation_challenge: [0x47; 32],
+        attested_key: pubkey_from_byte(0x48),
+        ops_lock: OpsLock {
+            locked_by: pubkey_from_byte(0x49),
+            expires_at: 1_700_000_000,
+            reason: DEVICE_OPS_LOCK_REASON.into(),
+        },
+        co_owner_pk: pubkey_from_byte(0x4a),
     };
 
     (val, owner, location_pk, exchange_pk, metrics_publisher_pk, contributor_pk, topology_pk)
@@ -560,6 +597,17 @@ fn canonical_device_fields(
         FieldValue { name: "NewInterface1NodeSegmentIdx".into(), value: "200".into(), typ: "u16".into() },
         FieldValue { name: "NewInterface1UserTunnelEndpoint".into(), value: "true".into(), typ: "bool".into() },
         FieldValue { name: "NewInterface1FlexAlgoNodeSegmentsLen".into(), value: "0".into(), typ: "u32".into() },
+        // Scalars written after the trailing interfaces vec.
+        FieldValue { name: "ConfigGeneration".into(), value: "9".into(), typ: "u64".into() },
+        FieldValue { name: "AppliedConfigGeneration".into(), value: "7".into(), typ: "u64".into() },
+        FieldValue { name: "PreviousMetricsPublisherPk".into(), value: pubkey_bs58(&pubkey_from_byte(0x46)), typ: "pubkey".into() },
+        FieldValue { name: "MetricsPublisherOverlapUntilEpoch".into(), value: "42".into(), typ: "u64".into() },
+        // attestation_challenge ([0x47; 32]) has no scalar encoding; readers assert it directly.
+        FieldValue { name: "AttestedKey".into(), value: pubkey_bs58(&pubkey_from_byte(0x48)), typ: "pubkey".into() },
+        FieldValue { name: "OpsLockLockedBy".into(), value: pubkey_bs58(&pubkey_from_byte(0x49)), typ: "pubkey".into() },
+        FieldValue { name: "OpsLockExpiresAt".into(), value: "1700000000".into(), typ: "i64".into() },
+        FieldValue { name: "OpsLockReason".into(), value: DEVICE_OPS_LOCK_REASON.into(), typ: "string".into() },
+        FieldValue { name: "CoOwnerPk".into(), value: pubkey_bs58(&pubkey_from_byte(0x4a)), typ: "pubkey".into() },
     ]
 }
 
@@ -589,6 +637,41 @@ fn generate_device(dir: &Path) {
     write_fixture(dir, "device", &data, &meta);
 }
 
+/// Writes `val` field by field, with `deprecated_interfaces` (an already-encoded legacy
+/// `Vec<InterfaceDeprecated>`, length prefix included) in the legacy slot and **no**
+/// trailing `interfaces` vec — the pre-#3667 on-disk shape.
+fn serialize_device_without_trailing(val: &Device, deprecated_interfaces: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    BorshSerialize::serialize(&val.account_type, &mut data).unwrap();
+    BorshSerialize::serialize(&val.owner, &mut data).unwrap();
+    BorshSerialize::serialize(&val.index, &mut data).unwrap();
+    BorshSerialize::serialize(&val.bump_seed, &mut data).unwrap();
+    BorshSerialize::serialize(&val.location_pk, &mut data).unwrap();
+    BorshSerialize::serialize(&val.exchange_pk, &mut data).unwrap();
+    BorshSerialize::serialize(&val.device_type, &mut data).unwrap();
+    BorshSerialize::serialize(&val.public_ip, &mut data).unwrap();
+    BorshSerialize::serialize(&val.status, &mut data).unwrap();
+    BorshSerialize::serialize(&val.code, &mut data).unwrap();
+    BorshSerialize::serialize(&val.dz_prefixes, &mut data).unwrap();
+    BorshSerialize::serialize(&val.metrics_publisher_pk, &mut data).unwrap();
+    BorshSerialize::serialize(&val.contributor_pk, &mut data).unwrap();
+    BorshSerialize::serialize(&val.mgmt_vrf, &mut data).unwrap();
+    data.extend_from_slice(deprecated_interfaces);
+    BorshSerialize::serialize(&val.reference_count, &mut data).unwrap();
+    BorshSerialize::serialize(&val.users_count, &mut data).unwrap();
+    BorshSerialize::serialize(&val.max_users, &mut data).unwrap();
+    BorshSerialize::serialize(&val.device_health, &mut data).unwrap();
+    BorshSerialize::serialize(&val.desired_status, &mut data).unwrap();
+    BorshSerialize::serialize(&val.unicast_users_count, &mut data).unwrap();
+    BorshSerialize::serialize(&val.multicast_subscribers_count, &mut data).unwrap();
+    BorshSerialize::serialize(&val.max_unicast_users, &mut data).unwrap();
+    BorshSerialize::serialize(&val.max_multicast_subscribers, &mut data).unwrap();
+    BorshSerialize::serialize(&val.reserved_seats, &mut data).unwrap();
+    BorshSerialize::serialize(&val.multicast_publishers_count, &mut data).unwrap();
+    BorshSerialize::serialize(&val.max_multicast_publishers, &mut data).unwrap();
+    data
+}
+
 /// Hand-serialized device with the legacy `interfaces` vec populated and **no** trailing
 /// `interfaces` vec — the pre-#3667 on-disk format. SDKs detect the absent trailing
 /// bytes and rebuild `interfaces` from the legacy enum vec, stamping each entry with
@@ -656,38 +739,19 @@ fn generate_device_legacy(dir: &Path) {
         reserved_seats: 3,
         multicast_publishers_count: 1,
         max_multicast_publishers: 10,
+        config_generation: 0,
+        applied_config_generation: 0,
+        previous_metrics_publisher_pk: Default::default(),
+        metrics_publisher_overlap_until_epoch: 0,
+        attestation_challenge: [0; 32],
+        attested_key: Default::default(),
+        ops_lock: Default::default(),
+        co_owner_pk: Default::default(),
     };
 
     // Bypass Device::serialize so we don't write the trailing interfaces vec —
     // this is exactly the pre-#3667 byte shape the SDK legacy-fallback path consumes.
-    let mut data = Vec::new();
-    BorshSerialize::serialize(&val.account_type, &mut data).unwrap();
-    BorshSerialize::serialize(&val.owner, &mut data).unwrap();
-    BorshSerialize::serialize(&val.index, &mut data).unwrap();
-    BorshSerialize::serialize(&val.bump_seed, &mut data).unwrap();
-    BorshSerialize::serialize(&val.location_pk, &mut data).unwrap();
-    BorshSerialize::serialize(&val.exchange_pk, &mut data).unwrap();
-    BorshSerialize::serialize(&val.device_type, &mut data).unwrap();
-    BorshSerialize::serialize(&val.public_ip, &mut data).unwrap();
-    BorshSerialize::serialize(&val.status, &mut data).unwrap();
-    BorshSerialize::serialize(&val.code, &mut data).unwrap();
-    BorshSerialize::serialize(&val.dz_prefixes, &mut data).unwrap();
-    BorshSerialize::serialize(&val.metrics_publisher_pk, &mut data).unwrap();
-    BorshSerialize::serialize(&val.contributor_pk, &mut data).unwrap();
-    BorshSerialize::serialize(&val.mgmt_vrf, &mut data).unwrap();
-    BorshSerialize::serialize(&val.deprecated_interfaces, &mut data).unwrap();
-    BorshSerialize::serialize(&val.reference_count, &mut data).unwrap();
-    BorshSerialize::serialize(&val.users_count, &mut data).unwrap();
-    BorshSerialize::serialize(&val.max_users, &mut data).unwrap();
-    BorshSerialize::serialize(&val.device_health, &mut data).unwrap();
-    BorshSerialize::serialize(&val.desired_status, &mut data).unwrap();
-    BorshSerialize::serialize(&val.unicast_users_count, &mut data).unwrap();
-    BorshSerialize::serialize(&val.multicast_subscribers_count, &mut data).unwrap();
-    BorshSerialize::serialize(&val.max_unicast_users, &mut data).unwrap();
-    BorshSerialize::serialize(&val.max_multicast_subscribers, &mut data).unwrap();
-    BorshSerialize::serialize(&val.reserved_seats, &mut data).unwrap();
-    BorshSerialize::serialize(&val.multicast_publishers_count, &mut data).unwrap();
-    BorshSerialize::serialize(&val.max_multicast_publishers, &mut data).unwrap();
+    let data = serialize_device_without_trailing(&val, &borsh::to_vec(&val.deprecated_interfaces).unwrap());
 
     let v = CURRENT_INTERFACE_SCHEMA_VERSION.to_string();
     let meta = FixtureMeta {
@@ -796,6 +860,11 @@ fn generate_device_legacy(dir: &Path) {
 /// then `seek(start + size)` over the junk — exercising the constant-time skip path.
 const FUTURE_VERSION: u8 = 5;
 const FUTURE_VERSION_JUNK: usize = 8;
+/// `config_generation`, `applied_config_generation`, `previous_metrics_publisher_pk`,
+/// `metrics_publisher_overlap_until_epoch`, `attestation_challenge`, `attested_key`,
+/// `ops_lock` and `co_owner_pk`, written after the trailing vec.
+const TRAILING_SCALARS_LEN: usize =
+    16 + 32 + 8 + 32 + 32 + (32 + 8 + 4 + DEVICE_OPS_LOCK_REASON.len()) + 32;
 
 fn generate_device_future_version(dir: &Path) {
     let (val, owner, location_pk, exchange_pk, metrics_publisher_pk, contributor_pk, topology_pk) =
@@ -803,18 +872,21 @@ fn generate_device_future_version(dir: &Path) {
 
     let mut data = borsh::to_vec(&val).unwrap();
 
-    // The trailing vec elements are written contiguously at end-of-buffer; the last
-    // element ends exactly at buf.len(). Locate its size+version header by subtracting
-    // the precomputed on-disk size.
+    // The trailing vec elements are written contiguously, followed only by the config
+    // generations, the metrics publisher rotation fields, the attestation fields, the
+    // ops lock and the co-owner.
+    // Locate the last element's size+version header by subtracting the precomputed
+    // on-disk size from where those scalars begin.
     let last = val.interfaces.last().expect("non-empty");
     let last_size = last.compute_on_disk_size().unwrap();
     let new_last_size = last_size + FUTURE_VERSION_JUNK as u16;
-    let last_start = data.len() - last_size as usize;
+    let last_end = data.len() - TRAILING_SCALARS_LEN;
+    let last_start = last_end - last_size as usize;
 
-    // Bump size and version in place, then append junk bytes after the body.
+    // Bump size and version in place, then splice junk bytes in after the body.
     data[last_start..last_start + 2].copy_from_slice(&new_last_size.to_le_bytes());
     data[last_start + 2] = FUTURE_VERSION;
-    data.extend(std::iter::repeat_n(0xAB, FUTURE_VERSION_JUNK));
+    data.splice(last_end..last_end, std::iter::repeat_n(0xAB, FUTURE_VERSION_JUNK));
 
     let size0 = val.interfaces[0].compute_on_disk_size().unwrap();
 
@@ -860,7 +932,16 @@ fn generate_link(dir: &Path) {
         link_health: LinkHealth::ReadyForService,
         desired_status: LinkDesiredStatus::Activated,
         link_topologies: vec![],
-        link_flags: 0,
+        link_flags: LINK_FLAG_FLAP_DAMPED,
+        flap_count: 4,
+        flap_penalty: 3000,
+        flap_epoch: 812,
+        damped_until_epoch: 814,
+        ops_lock: OpsLock {
+            locked_by: pubkey_from_byte(0x54),
+            expires_at: 1_700_000_000,
+            reason: "OTDR trace".into(),
+        },
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -890,6 +971,14 @@ fn generate_link(dir: &Path) {
             FieldValue { name: "DelayOverrideNs".into(), value: "0".into(), typ: "u64".into() },
             FieldValue { name: "LinkHealth".into(), value: "2".into(), typ: "u8".into() },
             FieldValue { name: "DesiredStatus".into(), value: "1".into(), typ: "u8".into() },
+            FieldValue { name: "LinkFlags".into(), value: "2".into(), typ: "u32".into() },
+            FieldValue { name: "FlapCount".into(), value: "4".into(), typ: "u32".into() },
+            FieldValue { name: "FlapPenalty".into(), value: "3000".into(), typ: "u32".into() },
+            FieldValue { name: "FlapEpoch".into(), value: "812".into(), typ: "u64".into() },
+            FieldValue { name: "DampedUntilEpoch".into(), value: "814".into(), typ: "u64".into() },
+            FieldValue { name: "OpsLockLockedBy".into(), value: pubkey_bs58(&pubkey_from_byte(0x54)), typ: "pubkey".into() },
+            FieldValue { name: "OpsLockExpiresAt".into(), value: "1700000000".into(), typ: "i64".into() },
+            FieldValue { name: "OpsLockReason".into(), value: "OTDR trace".into(), typ: "string".into() },
         ],
     };
 
@@ -929,6 +1018,7 @@ fn generate_user(dir: &Path) {
         last_bgp_reported_at: 1_700_000_100,
         bgp_rtt_ns: 5_500_000,
         feed_pk,
+        bandwidth_tier: BandwidthTier::Standard,
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -962,6 +1052,7 @@ fn generate_user(dir: &Path) {
             FieldValue { name: "LastBgpReportedAt".into(), value: "1700000100".into(), typ: "u64".into() },
             FieldValue { name: "BgpRttNs".into(), value: "5500000".into(), typ: "u64".into() },
             FieldValue { name: "FeedPk".into(), value: pubkey_bs58(&feed_pk), typ: "pubkey".into() },
+            FieldValue { name: "BandwidthTier".into(), value: "2".into(), typ: "u8".into() },
         ],
     };
 
@@ -1083,6 +1174,7 @@ fn generate_contributor(dir: &Path) {
 fn generate_access_pass(dir: &Path) {
     let owner = pubkey_from_byte(0x90);
     let user_payer = pubkey_from_byte(0x91);
+    let referrer = pubkey_from_byte(0x92);
 
     let val = AccessPass {
         account_type: AccountType::AccessPass,
@@ -1102,6 +1194,8 @@ fn generate_access_pass(dir: &Path) {
         max_unicast_users: 4,
         multicast_user_count: 1,
         max_multicast_users: 3,
+        bandwidth_tier: BandwidthTier::Premium,
+        referrer,
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -1127,6 +1221,8 @@ fn generate_access_pass(dir: &Path) {
             FieldValue { name: "MaxUnicastUsers".into(), value: "4".into(), typ: "u16".into() },
             FieldValue { name: "MulticastUserCount".into(), value: "1".into(), typ: "u16".into() },
             FieldValue { name: "MaxMulticastUsers".into(), value: "3".into(), typ: "u16".into() },
+            FieldValue { name: "BandwidthTier".into(), value: "3".into(), typ: "u8".into() },
+            FieldValue { name: "Referrer".into(), value: pubkey_bs58(&referrer), typ: "pubkey".into() },
         ],
     };
 
@@ -1158,6 +1254,8 @@ fn generate_access_pass_validator(dir: &Path) {
         max_unicast_users: 5,
         multicast_user_count: 0,
         max_multicast_users: 2,
+        bandwidth_tier: Default::default(),
+        referrer: solana_program::pubkey::Pubkey::default(),
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -1226,6 +1324,8 @@ fn generate_access_pass_edge_seat(dir: &Path) {
         max_unicast_users: 4,
         multicast_user_count: 1,
         max_multicast_users: 3,
+        bandwidth_tier: Default::default(),
+        referrer: solana_program::pubkey::Pubkey::default(),
     };
 
     let data = borsh::to_vec(&val).unwrap();
@@ -1265,6 +1365,61 @@ fn generate_access_pass_edge_seat(dir: &Path) {
     write_fixture(dir, "access_pass_edge_seat", &data, &meta);
 }
 
+fn generate_access_pass_leased(dir: &Path) {
+    let owner = pubkey_from_byte(0xC0);
+    let user_payer = pubkey_from_byte(0xC1);
+
+    // Leased pass still holding its deposit, with a finite lease.
+    let val = AccessPass {
+        account_type: AccountType::AccessPass,
+        owner,
+        bump_seed: 241,
+        accesspass_type: AccessPassType::Leased(5_000_000),
+        client_ip: Ipv4Addr::new(10, 0, 0, 60),
+        user_payer,
+        last_access_epoch: 120,
+        connection_count: 0,
+        status: AccessPassStatus::Requested,
+        mgroup_pub_allowlist: vec![],
+        mgroup_sub_allowlist: vec![],
+        flags: 0,
+        tenant_allowlist: vec![],
+        unicast_user_count: 0,
+        max_unicast_users: 1,
+        multicast_user_count: 0,
+        max_multicast_users: 1,
+        bandwidth_tier: Default::default(),
+        referrer: solana_program::pubkey::Pubkey::default(),
+    };
+
+    let data = borsh::to_vec(&val).unwrap();
+
+    let meta = FixtureMeta {
+        name: "AccessPassLeased".into(),
+        account_type: 11,
+        fields: vec![
+            FieldValue { name: "AccountType".into(), value: "11".into(), typ: "u8".into() },
+            FieldValue { name: "Owner".into(), value: pubkey_bs58(&owner), typ: "pubkey".into() },
+            FieldValue { name: "BumpSeed".into(), value: "241".into(), typ: "u8".into() },
+            FieldValue { name: "AccessPassType".into(), value: "5".into(), typ: "u8".into() },
+            FieldValue { name: "LeaseDeposit".into(), value: "5000000".into(), typ: "u64".into() },
+            FieldValue { name: "ClientIp".into(), value: "10.0.0.60".into(), typ: "ipv4".into() },
+            FieldValue { name: "UserPayer".into(), value: pubkey_bs58(&user_payer), typ: "pubkey".into() },
+            FieldValue { name: "LastAccessEpoch".into(), value: "120".into(), typ: "u64".into() },
+            FieldValue { name: "ConnectionCount".into(), value: "0".into(), typ: "u16".into() },
+            FieldValue { name: "Status".into(), value: "0".into(), typ: "u8".into() },
+            FieldValue { name: "MgroupPubAllowlistLen".into(), value: "0".into(), typ: "u32".into() },
+            FieldValue { name: "MgroupSubAllowlistLen".into(), value: "0".into(), typ: "u32".into() },
+            FieldValue { name: "Flags".into(), value: "0".into(), typ: "u8".into() },
+            FieldValue { name: "TenantAllowlistLen".into(), value: "0".into(), typ: "u32".into() },
+            FieldValue { name: "MaxUnicastUsers".into(), value: "1".into(), typ: "u16".into() },
+            FieldValue { name: "MaxMulticastUsers".into(), value: "1".into(), typ: "u16".into() },
+        ],
+    };
+
+    write_fixture(dir, "access_pass_leased", &data, &meta);
+}
+
 /// Borsh-encoded `Feed` account. Field order: account_type, owner, bump_seed, code, name,
 /// exchange (Pubkey), groups (Vec<Pubkey>). Two groups, so the vec decoding is exercised.
 fn generate_feed(dir: &Path) {
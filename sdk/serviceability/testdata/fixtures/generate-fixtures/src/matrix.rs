@@ -0,0 +1,259 @@
+//! Enum-variant matrix fixtures, written to `../matrix/`. Instead of one hand-written
+//! function per fixture, each matrix declares a set of axes; the generator takes the
+//! cartesian product, applies every combination to the canonical `Device` and records
+//! what the Rust reader decodes back.
+//!
+//! Axis variants are discovered by probing each enum's `From<u8>` impl (a byte is a
+//! variant iff it round-trips), so adding a variant onchain adds fixtures here on the
+//! next `make generate-fixtures` without touching this file.
+//!
+//! Each matrix is a single JSON file: `axes` lists the values per axis and `entries`
+//! carries one fixture per combination with the hex-encoded account bytes and the
+//! expected field values.
+
+use std::fs;
+use std::path::Path;
+
+use borsh::BorshSerialize;
+use doublezero_serviceability::state::{
+    device::{Device, DeviceHealth, DeviceStatus, DeviceType},
+    interface::{Interface, InterfaceDeprecated, InterfaceV1, InterfaceV2},
+    topology::FlexAlgoNodeSegment,
+};
+use serde::Serialize;
+
+use crate::{canonical_device, pubkey_from_byte, serialize_device_without_trailing, FieldValue};
+
+/// Every discriminant of a `#[repr(u8)]` enum whose `From<u8>` maps unknown bytes to
+/// a fallback variant.
+macro_rules! variants {
+    ($t:ty) => {
+        (0..=u8::MAX).filter(|b| <$t>::from(*b) as u8 == *b).collect::<Vec<u8>>()
+    };
+}
+
+struct Axis {
+    name: &'static str,
+    values: fn() -> Vec<u8>,
+    apply: fn(&mut Device, u8),
+}
+
+#[derive(Serialize)]
+struct AxisMeta {
+    name: String,
+    values: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct MatrixEntry {
+    name: String,
+    /// Hex-encoded account bytes.
+    data: String,
+    fields: Vec<FieldValue>,
+}
+
+#[derive(Serialize)]
+struct MatrixMeta {
+    name: String,
+    account_type: u8,
+    axes: Vec<AxisMeta>,
+    entries: Vec<MatrixEntry>,
+}
+
+fn device_axes() -> Vec<Axis> {
+    vec![
+        Axis {
+            name: "DeviceType",
+            values: || variants!(DeviceType),
+            apply: |d, v| d.device_type = DeviceType::from(v),
+        },
+        Axis {
+            name: "Status",
+            values: || variants!(DeviceStatus),
+            apply: |d, v| d.status = DeviceStatus::from(v),
+        },
+        Axis {
+            name: "DeviceHealth",
+            values: || variants!(DeviceHealth),
+            apply: |d, v| d.device_health = DeviceHealth::from(v),
+        },
+    ]
+}
+
+/// Wire encodings a device's interfaces can be stored in. Versions 0-3 are the legacy
+/// `InterfaceDeprecated` discriminants with no trailing vec (2 is reserved and never
+/// written); 4 is the current layout, the V2 projection plus the size-prefixed
+/// trailing `interfaces` vec.
+const INTERFACE_VERSIONS: &[u8] = &[0, 1, 3, 4];
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn u8_field(name: &str, value: u8) -> FieldValue {
+    FieldValue { name: name.into(), value: value.to_string(), typ: "u8".into() }
+}
+
+fn cartesian(axes: &[Axis]) -> Vec<Vec<u8>> {
+    axes.iter().fold(vec![vec![]], |acc, axis| {
+        let values = (axis.values)();
+        acc.into_iter()
+            .flat_map(|prefix| {
+                values.iter().map(move |v| {
+                    let mut combo = prefix.clone();
+                    combo.push(*v);
+                    combo
+                })
+            })
+            .collect()
+    })
+}
+
+fn generate_device_matrix(dir: &Path) {
+    let axes = device_axes();
+    let mut entries = vec![];
+
+    for combo in cartesian(&axes) {
+        let (mut device, ..) = canonical_device();
+        for (axis, value) in axes.iter().zip(&combo) {
+            (axis.apply)(&mut device, *value);
+        }
+        let data = borsh::to_vec(&device).unwrap();
+        let decoded = Device::try_from(&data[..]).unwrap();
+
+        let name = axes
+            .iter()
+            .zip(&combo)
+            .map(|(axis, value)| format!("{}{value}", axis.name))
+            .collect::<Vec<_>>()
+            .join("_");
+        entries.push(MatrixEntry {
+            name,
+            data: to_hex(&data),
+            fields: vec![
+                u8_field("AccountType", decoded.account_type as u8),
+                u8_field("DeviceType", decoded.device_type as u8),
+                u8_field("Status", decoded.status as u8),
+                u8_field("DeviceHealth", decoded.device_health as u8),
+                FieldValue { name: "Code".into(), value: decoded.code, typ: "string".into() },
+            ],
+        });
+    }
+
+    let meta = MatrixMeta {
+        name: "DeviceMatrix".into(),
+        account_type: 5,
+        axes: axes
+            .iter()
+            .map(|axis| AxisMeta { name: axis.name.into(), values: (axis.values)() })
+            .collect(),
+        entries,
+    };
+    write_matrix(dir, "device_matrix", &meta);
+}
+
+/// The canonical physical user-tunnel-endpoint interface plus one flex-algo segment,
+/// so the V3 and V4 encodings carry segment bytes the readers must drop or keep.
+fn matrix_interface() -> Interface {
+    let (device, ..) = canonical_device();
+    Interface {
+        flex_algo_node_segments: vec![FlexAlgoNodeSegment {
+            topology: pubkey_from_byte(0x45),
+            node_segment_idx: 300,
+        }],
+        ..device.interfaces[1].clone()
+    }
+}
+
+fn encode_device_with_interface_version(version: u8) -> Vec<u8> {
+    let (mut device, ..) = canonical_device();
+    let iface = matrix_interface();
+    let v2 = InterfaceV2::from(&iface);
+
+    let legacy_entry = match version {
+        0 => borsh::to_vec(&InterfaceDeprecated::V1(InterfaceV1 {
+            status: v2.status,
+            name: v2.name.clone(),
+            interface_type: v2.interface_type,
+            loopback_type: v2.loopback_type,
+            vlan_id: v2.vlan_id,
+            ip_net: v2.ip_net,
+            node_segment_idx: v2.node_segment_idx,
+            user_tunnel_endpoint: v2.user_tunnel_endpoint,
+        }))
+        .unwrap(),
+        1 => borsh::to_vec(&InterfaceDeprecated::V2(v2.clone())).unwrap(),
+        // Legacy V3: discriminant 3, V2 body, then the segments vec.
+        3 => {
+            let mut bytes = vec![3u8];
+            v2.serialize(&mut bytes).unwrap();
+            iface.flex_algo_node_segments.serialize(&mut bytes).unwrap();
+            bytes
+        }
+        4 => {
+            device.interfaces = vec![iface];
+            return borsh::to_vec(&device).unwrap();
+        }
+        other => panic!("unsupported interface version {other}"),
+    };
+
+    let mut legacy = 1u32.to_le_bytes().to_vec();
+    legacy.extend_from_slice(&legacy_entry);
+    serialize_device_without_trailing(&device, &legacy)
+}
+
+fn generate_interface_version_matrix(dir: &Path) {
+    let mut entries = vec![];
+    for &version in INTERFACE_VERSIONS {
+        let data = encode_device_with_interface_version(version);
+        let decoded = Device::try_from(&data[..]).unwrap();
+        let iface = &decoded.interfaces[0];
+
+        entries.push(MatrixEntry {
+            name: format!("InterfaceVersion{version}"),
+            data: to_hex(&data),
+            fields: vec![
+                u8_field("AccountType", decoded.account_type as u8),
+                FieldValue {
+                    name: "InterfacesLen".into(),
+                    value: decoded.interfaces.len().to_string(),
+                    typ: "u32".into(),
+                },
+                u8_field("Interface0Version", iface.version),
+                FieldValue { name: "Interface0Name".into(), value: iface.name.clone(), typ: "string".into() },
+                FieldValue {
+                    name: "Interface0UserTunnelEndpoint".into(),
+                    value: iface.user_tunnel_endpoint.to_string(),
+                    typ: "bool".into(),
+                },
+                FieldValue {
+                    name: "Interface0FlexAlgoNodeSegmentsLen".into(),
+                    value: iface.flex_algo_node_segments.len().to_string(),
+                    typ: "u32".into(),
+                },
+            ],
+        });
+    }
+
+    let meta = MatrixMeta {
+        name: "InterfaceVersionMatrix".into(),
+        account_type: 5,
+        axes: vec![AxisMeta { name: "InterfaceVersion".into(), values: INTERFACE_VERSIONS.to_vec() }],
+        entries,
+    };
+    write_matrix(dir, "interface_version_matrix", &meta);
+}
+
+fn write_matrix(dir: &Path, name: &str, meta: &MatrixMeta) {
+    let json = serde_json::to_string_pretty(meta).unwrap();
+    fs::write(dir.join(format!("{name}.json")), json).unwrap();
+    println!("wrote matrix/{name}.json ({} entries)", meta.entries.len());
+}
+
+pub fn generate_matrix_fixtures(fixtures_dir: &Path) {
+    let dir = fixtures_dir.join("matrix");
+    fs::create_dir_all(&dir).unwrap();
+
+    generate_device_matrix(&dir);
+    generate_interface_version_matrix(&dir);
+}
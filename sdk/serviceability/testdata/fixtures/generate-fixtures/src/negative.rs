@@ -0,0 +1,297 @@
+//! Negative-path fixtures: deliberately corrupted copies of the canonical happy-path
+//! fixtures, written to `../negative/`. Each case records the outcome of the Rust
+//! `TryFrom<&[u8]>` reader on the corrupted bytes so the Go/TypeScript/Python SDKs can
+//! assert the same verdict:
+//!
+//! - `error: Some(..)` — Rust rejects the buffer. SDKs must reject it too, either by
+//!   raising/returning an error or by decoding an `AccountType` that differs from
+//!   `account_type` (the SDK readers do not check the discriminator themselves).
+//! - `error: None` — Rust decodes the buffer defensively (trailing fields default).
+//!   SDKs must not crash, and any field listed in `fields` must match. The field named
+//!   by `corrupted_field` is left out: Rust falls back to the enum default there, while
+//!   SDKs may surface the raw byte or raise, and either is acceptable.
+//!
+//! Offsets are into the canonical fixture produced by `main.rs`; the cases are built
+//! by reading those `.bin` files back, so they must be generated first.
+
+use std::fs;
+use std::path::Path;
+
+use doublezero_serviceability::state::{
+    accounttype::AccountType, device::Device, location::Location, user::User,
+};
+use serde::Serialize;
+
+use crate::FieldValue;
+
+/// Byte offset of `status` in `location.bin`: account_type(1) + owner(32) + index(16)
+/// + bump_seed(1) + lat(8) + lng(8) + loc_id(4).
+const LOCATION_STATUS_OFFSET: usize = 70;
+/// Byte offset of the `code` length prefix in `location.bin`.
+const LOCATION_CODE_OFFSET: usize = 71;
+/// Byte offset of `status` in `device.bin`: account_type(1) + owner(32) + index(16)
+/// + bump_seed(1) + location_pk(32) + exchange_pk(32) + device_type(1) + public_ip(4).
+const DEVICE_STATUS_OFFSET: usize = 119;
+/// Byte offset of the `code` length prefix in `device.bin`.
+const DEVICE_CODE_OFFSET: usize = 120;
+/// Byte offset of `user_type` in `user.bin`: account_type(1) + owner(32) + index(16)
+/// + bump_seed(1).
+const USER_TYPE_OFFSET: usize = 50;
+/// Byte offset of `status` in `user.bin`: user_type(1) + tenant_pk(32) + device_pk(32)
+/// + cyoa_type(1) + client_ip(4) + dz_ip(4) + tunnel_id(2) + tunnel_net(5) after
+/// `USER_TYPE_OFFSET`.
+const USER_STATUS_OFFSET: usize = 131;
+
+#[derive(Serialize)]
+struct NegativeFixtureMeta {
+    name: String,
+    /// Account the buffer is decoded as (`Device`, `Location`, ...).
+    account: String,
+    /// Expected `AccountType` discriminator for `account`.
+    account_type: u8,
+    /// Happy-path fixture the bytes were derived from.
+    base: String,
+    /// One of `truncated`, `wrong_account_type`, `bad_enum_discriminant`,
+    /// `overlong_string`.
+    corruption: String,
+    /// Byte offset the corruption applies at (truncation length for `truncated`).
+    offset: usize,
+    /// Field whose bytes were overwritten, if the corruption targets one.
+    corrupted_field: Option<String>,
+    description: String,
+    /// `ProgramError` name returned by the Rust reader, or `null` if it decoded.
+    error: Option<String>,
+    /// Fields decoded by the Rust reader. Empty when `error` is set.
+    fields: Vec<FieldValue>,
+}
+
+#[derive(Serialize)]
+struct NegativeManifest {
+    fixtures: Vec<String>,
+}
+
+enum Corruption {
+    Truncate(usize),
+    SetByte(usize, u8),
+    OverlongString(usize),
+}
+
+impl Corruption {
+    fn kind(&self) -> &'static str {
+        match self {
+            Corruption::Truncate(_) => "truncated",
+            Corruption::SetByte(0, _) => "wrong_account_type",
+            Corruption::SetByte(_, _) => "bad_enum_discriminant",
+            Corruption::OverlongString(_) => "overlong_string",
+        }
+    }
+
+    fn offset(&self) -> usize {
+        match self {
+            Corruption::Truncate(n) | Corruption::SetByte(n, _) | Corruption::OverlongString(n) => *n,
+        }
+    }
+
+    fn apply(&self, base: &[u8]) -> Vec<u8> {
+        let mut data = base.to_vec();
+        match self {
+            Corruption::Truncate(n) => data.truncate(*n),
+            Corruption::SetByte(off, b) => data[*off] = *b,
+            // Borsh strings are a u32 length prefix followed by the bytes; claim far
+            // more bytes than the buffer holds.
+            Corruption::OverlongString(off) => {
+                data[*off..*off + 4].copy_from_slice(&u32::MAX.to_le_bytes())
+            }
+        }
+        data
+    }
+}
+
+struct Case {
+    name: &'static str,
+    base: &'static str,
+    corruption: Corruption,
+    corrupted_field: Option<&'static str>,
+    description: &'static str,
+}
+
+fn u8_field(name: &str, value: u8) -> FieldValue {
+    FieldValue { name: name.into(), value: value.to_string(), typ: "u8".into() }
+}
+
+fn string_field(name: &str, value: &str) -> FieldValue {
+    FieldValue { name: name.into(), value: value.into(), typ: "string".into() }
+}
+
+/// Decodes `data` with the Rust reader for `base`, returning the error name or the
+/// fields the SDKs are expected to agree on.
+fn decode(base: &str, data: &[u8]) -> (&'static str, AccountType, Result<Vec<FieldValue>, String>) {
+    match base {
+        "location" => (
+            "Location",
+            AccountType::Location,
+            Location::try_from(data).map_err(|e| format!("{e:?}")).map(|v| {
+                vec![
+                    u8_field("AccountType", v.account_type as u8),
+                    u8_field("Status", v.status as u8),
+                    string_field("Code", &v.code),
+                ]
+            }),
+        ),
+        "device" => (
+            "Device",
+            AccountType::Device,
+            Device::try_from(data).map_err(|e| format!("{e:?}")).map(|v| {
+                vec![
+                    u8_field("AccountType", v.account_type as u8),
+                    u8_field("Status", v.status as u8),
+                    string_field("Code", &v.code),
+                ]
+            }),
+        ),
+        "user" => (
+            "User",
+            AccountType::User,
+            User::try_from(data).map_err(|e| format!("{e:?}")).map(|v| {
+                vec![
+                    u8_field("AccountType", v.account_type as u8),
+                    u8_field("UserType", v.user_type as u8),
+                    u8_field("Status", v.status as u8),
+                ]
+            }),
+        ),
+        other => panic!("no negative-fixture decoder for {other}"),
+    }
+}
+
+fn cases() -> Vec<Case> {
+    vec![
+        Case {
+            name: "location_empty",
+            base: "location",
+            corruption: Corruption::Truncate(0),
+            corrupted_field: None,
+            description: "zero-length buffer; every field defaults, including account_type",
+        },
+        Case {
+            name: "location_wrong_account_type",
+            base: "location",
+            corruption: Corruption::SetByte(0, AccountType::Exchange as u8),
+            corrupted_field: None,
+            description: "Location bytes tagged as an Exchange",
+        },
+        Case {
+            name: "location_bad_status",
+            base: "location",
+            corruption: Corruption::SetByte(LOCATION_STATUS_OFFSET, 0xff),
+            corrupted_field: Some("Status"),
+            description: "status discriminant outside the LocationStatus range",
+        },
+        Case {
+            name: "location_overlong_code",
+            base: "location",
+            corruption: Corruption::OverlongString(LOCATION_CODE_OFFSET),
+            corrupted_field: Some("Code"),
+            description: "code length prefix claims u32::MAX bytes",
+        },
+        Case {
+            name: "device_truncated_header",
+            base: "device",
+            corruption: Corruption::Truncate(DEVICE_CODE_OFFSET),
+            corrupted_field: None,
+            description: "buffer ends before code; code and every later field default",
+        },
+        Case {
+            name: "device_truncated_mid",
+            base: "device",
+            corruption: Corruption::Truncate(DEVICE_CODE_OFFSET + 4 + 3),
+            corrupted_field: None,
+            description: "buffer ends right after code; remaining fields default",
+        },
+        Case {
+            name: "device_wrong_account_type",
+            base: "device",
+            corruption: Corruption::SetByte(0, AccountType::Link as u8),
+            corrupted_field: None,
+            description: "Device bytes tagged as a Link",
+        },
+        Case {
+            name: "device_bad_status",
+            base: "device",
+            corruption: Corruption::SetByte(DEVICE_STATUS_OFFSET, 0xff),
+            corrupted_field: Some("Status"),
+            description: "status discriminant outside the DeviceStatus range",
+        },
+        Case {
+            name: "device_overlong_code",
+            base: "device",
+            corruption: Corruption::OverlongString(DEVICE_CODE_OFFSET),
+            corrupted_field: Some("Code"),
+            description: "code length prefix claims u32::MAX bytes",
+        },
+        Case {
+            name: "user_unknown_account_type",
+            base: "user",
+            corruption: Corruption::SetByte(0, 0xff),
+            corrupted_field: None,
+            description: "account_type byte not assigned to any AccountType",
+        },
+        Case {
+            name: "user_bad_user_type",
+            base: "user",
+            corruption: Corruption::SetByte(USER_TYPE_OFFSET, 0xff),
+            corrupted_field: Some("UserType"),
+            description: "user_type discriminant outside the UserType range",
+        },
+        Case {
+            name: "user_bad_status",
+            base: "user",
+            corruption: Corruption::SetByte(USER_STATUS_OFFSET, 0xff),
+            corrupted_field: Some("Status"),
+            description: "status discriminant outside the UserStatus range",
+        },
+    ]
+}
+
+pub fn generate_negative_fixtures(fixtures_dir: &Path) {
+    let dir = fixtures_dir.join("negative");
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut manifest = NegativeManifest { fixtures: vec![] };
+    for case in cases() {
+        let base = fs::read(fixtures_dir.join(format!("{}.bin", case.base))).unwrap();
+        let data = case.corruption.apply(&base);
+        let (account, account_type, result) = decode(case.base, &data);
+
+        let (error, fields) = match result {
+            Ok(fields) => (
+                None,
+                fields.into_iter().filter(|f| Some(f.name.as_str()) != case.corrupted_field).collect(),
+            ),
+            Err(err) => (Some(err), vec![]),
+        };
+
+        let meta = NegativeFixtureMeta {
+            name: case.name.into(),
+            account: account.into(),
+            account_type: account_type as u8,
+            base: case.base.into(),
+            corruption: case.corruption.kind().into(),
+            offset: case.corruption.offset(),
+            corrupted_field: case.corrupted_field.map(Into::into),
+            description: case.description.into(),
+            error,
+            fields,
+        };
+
+        fs::write(dir.join(format!("{}.bin", case.name)), &data).unwrap();
+        let json = serde_json::to_string_pretty(&meta).unwrap();
+        fs::write(dir.join(format!("{}.json", case.name)), json).unwrap();
+        println!("wrote negative/{}.bin ({} bytes) and negative/{}.json", case.name, data.len(), case.name);
+
+        manifest.fixtures.push(case.name.into());
+    }
+
+    let json = serde_json::to_string_pretty(&manifest).unwrap();
+    fs::write(dir.join("manifest.json"), json).unwrap();
+}
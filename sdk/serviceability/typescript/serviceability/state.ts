@@ -84,6 +84,7 @@ const DEVICE_HEALTH_NAMES: Record<number, string> = {
   2: "ready_for_links",
   3: "ready_for_users",
   4: "impaired",
+  5: "drift_detected",
 };
 export function deviceHealthString(v: number): string {
   return DEVICE_HEALTH_NAMES[v] ?? "unknown";
@@ -260,6 +261,21 @@ export function bgpStatusString(v: number): string {
   return BGP_STATUS_NAMES[v] ?? "unknown";
 }
 
+export const BANDWIDTH_TIER_UNSHAPED = 0;
+export const BANDWIDTH_TIER_BASIC = 1;
+export const BANDWIDTH_TIER_STANDARD = 2;
+export const BANDWIDTH_TIER_PREMIUM = 3;
+
+const BANDWIDTH_TIER_NAMES: Record<number, string> = {
+  0: "unshaped",
+  1: "basic",
+  2: "standard",
+  3: "premium",
+};
+export function bandwidthTierString(v: number): string {
+  return BANDWIDTH_TIER_NAMES[v] ?? "unshaped";
+}
+
 const MULTICAST_GROUP_STATUS_NAMES: Record<number, string> = {
   0: "pending (deprecated)",
   1: "activated",
@@ -309,6 +325,7 @@ export interface GlobalState {
   qaAllowlist: PublicKey[];
   featureFlags: bigint;
   feedAuthorityPk: PublicKey;
+  paused: bigint;
 }
 
 export function deserializeGlobalState(data: Uint8Array): GlobalState {
@@ -327,6 +344,7 @@ export function deserializeGlobalState(data: Uint8Array): GlobalState {
   const qaAllowlist = readPubkeyVec(r);
   const featureFlags = r.readU128();
   const feedAuthorityPk = readPubkey(r);
+  const paused = r.readU64();
   return {
     accountType,
     bumpSeed,
@@ -340,6 +358,7 @@ export function deserializeGlobalState(data: Uint8Array): GlobalState {
     qaAllowlist,
     featureFlags,
     feedAuthorityPk,
+    paused,
   };
 }
 
@@ -634,6 +653,33 @@ function deserializeInterfaceSized(r: DefensiveReader): DeviceInterface {
   };
 }
 
+// ---------------------------------------------------------------------------
+// OpsLock
+// ---------------------------------------------------------------------------
+
+/**
+ * Freezes a Device or Link against changes by anyone but `lockedBy` until
+ * `expiresAt` (unix seconds). All zeros when unlocked.
+ */
+export interface OpsLock {
+  lockedBy: PublicKey;
+  expiresAt: bigint;
+  reason: string;
+}
+
+export function opsLockIsActive(lock: OpsLock, now: bigint): boolean {
+  return !lock.lockedBy.equals(PublicKey.default) && now < lock.expiresAt;
+}
+
+function deserializeOpsLock(r: DefensiveReader): OpsLock {
+  return {
+    lockedBy: readPubkey(r),
+    // readU64 is unsigned; reinterpret the sign bit for the i64 timestamp.
+    expiresAt: BigInt.asIntN(64, r.readU64()),
+    reason: r.readString(),
+  };
+}
+
 // ---------------------------------------------------------------------------
 // Device
 // ---------------------------------------------------------------------------
@@ -671,6 +717,22 @@ export interface Device {
   // deprecatedInterfaces by deserializeDevice. When populated from the wire,
   // length parity with deprecatedInterfaces is enforced.
   interfaces: DeviceInterface[];
+  // configGeneration is bumped by every change the controller renders for the
+  // device; appliedConfigGeneration is the last generation it acknowledged.
+  configGeneration: bigint;
+  appliedConfigGeneration: bigint;
+  // previousMetricsPublisherPubKey is the publisher replaced by the last rotation;
+  // it is still accepted up to metricsPublisherOverlapUntilEpoch.
+  previousMetricsPublisherPubKey: PublicKey;
+  metricsPublisherOverlapUntilEpoch: bigint;
+  // attestationChallenge is the pending nonce the metrics publisher must echo
+  // back (all zeros when none); attestedKey last answered a challenge.
+  attestationChallenge: Uint8Array;
+  attestedKey: PublicKey;
+  opsLock: OpsLock;
+  // coOwnerPubKey holds the contributor owner's update rights on this device
+  // only (e.g. a managed-service provider); the default key when there is none.
+  coOwnerPubKey: PublicKey;
 }
 
 export function deserializeDevice(data: Uint8Array): Device {
@@ -713,6 +775,18 @@ export function deserializeDevice(data: Uint8Array): Device {
   // deprecatedInterfaces. Non-empty trailing whose declared length differs from
   // deprecatedInterfaces length is a corrupt-account condition.
   let interfaces: DeviceInterface[];
+  let configGeneration = 0n;
+  let appliedConfigGeneration = 0n;
+  let previousMetricsPublisherPubKey = PublicKey.default;
+  let metricsPublisherOverlapUntilEpoch = 0n;
+  let attestationChallenge = new Uint8Array(32);
+  let attestedKey = PublicKey.default;
+  let opsLock: OpsLock = {
+    lockedBy: PublicKey.default,
+    expiresAt: 0n,
+    reason: "",
+  };
+  let coOwnerPubKey = PublicKey.default;
   if (r.remaining === 0) {
     interfaces = deprecatedInterfaces.map((legacy) => ({
       ...legacy,
@@ -733,6 +807,19 @@ export function deserializeDevice(data: Uint8Array): Device {
     for (let i = 0; i < newLen; i++) {
       interfaces.push(deserializeInterfaceSized(r));
     }
+    // Accounts written before config generations existed end here; both read as 0.
+    configGeneration = r.readU64();
+    appliedConfigGeneration = r.readU64();
+    // Likewise for the metrics publisher rotation fields.
+    previousMetricsPublisherPubKey = readPubkey(r);
+    metricsPublisherOverlapUntilEpoch = r.readU64();
+    // And for the key attestation fields.
+    attestationChallenge = r.readBytes(32);
+    attestedKey = readPubkey(r);
+    // And for the ops lock, which reads as unlocked.
+    opsLock = deserializeOpsLock(r);
+    // And for the co-owner, which reads as none.
+    coOwnerPubKey = readPubkey(r);
   }
 
   return {
@@ -764,6 +851,14 @@ export function deserializeDevice(data: Uint8Array): Device {
     multicastPublishersCount,
     maxMulticastPublishers,
     interfaces,
+    configGeneration,
+    appliedConfigGeneration,
+    previousMetricsPublisherPubKey,
+    metricsPublisherOverlapUntilEpoch,
+    attestationChallenge,
+    attestedKey,
+    opsLock,
+    coOwnerPubKey,
   };
 }
 
@@ -793,8 +888,18 @@ export interface Link {
   delayOverrideNs: bigint;
   linkHealth: number;
   linkDesiredStatus: number;
+  linkTopologies: PublicKey[];
+  linkFlags: number;
+  flapCount: number;
+  flapPenalty: number;
+  flapEpoch: bigint;
+  dampedUntilEpoch: bigint;
+  opsLock: OpsLock;
 }
 
+/** Bit 1 of `Link.linkFlags`: the link is suppressed by flap damping. */
+export const LINK_FLAG_FLAP_DAMPED = 0x02;
+
 export function deserializeLink(data: Uint8Array): Link {
   const r = new DefensiveReader(data);
   return {
@@ -819,6 +924,13 @@ export function deserializeLink(data: Uint8Array): Link {
     delayOverrideNs: r.readU64(),
     linkHealth: r.readU8(),
     linkDesiredStatus: r.readU8(),
+    linkTopologies: readPubkeyVec(r),
+    linkFlags: r.readU32(),
+    flapCount: r.readU32(),
+    flapPenalty: r.readU32(),
+    flapEpoch: r.readU64(),
+    dampedUntilEpoch: r.readU64(),
+    opsLock: deserializeOpsLock(r),
   };
 }
 
@@ -855,6 +967,8 @@ export interface User {
   bgpRttNs: bigint;
   /** EdgeSeat Feed whose per-feed seat this user consumed at connect (default pubkey if none). */
   feedPk: PublicKey;
+  /** Bandwidth tier the user is shaped to; 0 (unshaped) on accounts that predate the field. */
+  bandwidthTier: number;
 }
 
 export function deserializeUser(data: Uint8Array): User {
@@ -886,6 +1000,7 @@ export function deserializeUser(data: Uint8Array): User {
     bgpRttNs: r.readU64(),
     // readPubkey returns the zero pubkey on EOF, so old accounts default feedPk.
     feedPk: readPubkey(r),
+    bandwidthTier: r.readU8(),
   };
 }
 
@@ -1047,6 +1162,7 @@ export const ACCESS_PASS_TYPE_SOLANA_VALIDATOR = 1;
 export const ACCESS_PASS_TYPE_SOLANA_RPC = 2;
 export const ACCESS_PASS_TYPE_OTHERS = 3;
 export const ACCESS_PASS_TYPE_EDGE_SEAT = 4;
+export const ACCESS_PASS_TYPE_LEASED = 5;
 
 // One purchased SKU seat on an EdgeSeat access pass, carrying a feed's whole billing state. The cap
 // is maxUsers before windowEnd and maxFutureUsers from windowEnd until terminatesAt, when the feed
@@ -1071,6 +1187,7 @@ export interface AccessPass {
   othersTypeName: string; // for Others variant
   othersKey: string; // for Others variant
   feedSeats: FeedSeat[]; // for EdgeSeat variant
+  leaseDeposit: bigint; // for Leased variant, in lamports
   clientIp: Uint8Array;
   userPayer: PublicKey;
   lastAccessEpoch: bigint;
@@ -1084,6 +1201,10 @@ export interface AccessPass {
   maxUnicastUsers: number;
   multicastUserCount: number;
   maxMulticastUsers: number;
+  /** Tier given to users created under the pass, and the highest they may request. */
+  bandwidthTier: number;
+  /** Referrer credited when the pass was created; the default pubkey when unreferred. */
+  referrer: PublicKey;
 }
 
 export function deserializeAccessPass(data: Uint8Array): AccessPass {
@@ -1096,6 +1217,7 @@ export function deserializeAccessPass(data: Uint8Array): AccessPass {
   let othersTypeName = "";
   let othersKey = "";
   const feedSeats: FeedSeat[] = [];
+  let leaseDeposit = 0n;
   // SolanaValidator and SolanaRPC carry an associated pubkey.
   if (accessPassType === 1 || accessPassType === 2) {
     associatedPubkey = readPubkey(r);
@@ -1123,6 +1245,10 @@ export function deserializeAccessPass(data: Uint8Array): AccessPass {
       });
     }
   }
+  // Leased carries the deposit it holds, in lamports.
+  else if (accessPassType === 5) {
+    leaseDeposit = r.readU64();
+  }
   // Prepaid (0) carries no associated data.
   const clientIp = r.readIPv4();
   const userPayer = readPubkey(r);
@@ -1138,6 +1264,9 @@ export function deserializeAccessPass(data: Uint8Array): AccessPass {
   const maxUnicastUsers = r.remaining >= 2 ? r.readU16() : 1;
   const multicastUserCount = r.readU16();
   const maxMulticastUsers = r.remaining >= 2 ? r.readU16() : 1;
+  const bandwidthTier = r.readU8();
+  // Passes created before referrals read as unreferred.
+  const referrer = readPubkey(r);
   return {
     accountType,
     owner,
@@ -1147,6 +1276,7 @@ export function deserializeAccessPass(data: Uint8Array): AccessPass {
     othersTypeName,
     othersKey,
     feedSeats,
+    leaseDeposit,
     clientIp,
     userPayer,
     lastAccessEpoch,
@@ -1160,6 +1290,8 @@ export function deserializeAccessPass(data: Uint8Array): AccessPass {
     maxUnicastUsers,
     multicastUserCount,
     maxMulticastUsers,
+    bandwidthTier,
+    referrer,
   };
 }
 
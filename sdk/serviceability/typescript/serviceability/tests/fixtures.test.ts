@@ -3,7 +3,7 @@
  */
 
 import { describe, expect, test } from "bun:test";
-import { readFileSync } from "fs";
+import { readdirSync, readFileSync } from "fs";
 import { join } from "path";
 import { PublicKey } from "@solana/web3.js";
 import {
@@ -20,6 +20,8 @@ import {
   deserializeAccessPass,
   deserializeTenant,
   deserializeFeed,
+  LINK_FLAG_FLAP_DAMPED,
+  opsLockIsActive,
 } from "../state.js";
 
 const FIXTURES_DIR = join(
@@ -95,6 +97,7 @@ describe("GlobalState fixture", () => {
       SentinelAuthorityPk: gs.sentinelAuthorityPk,
       HealthOraclePk: gs.healthOraclePk,
       FeedAuthorityPk: gs.feedAuthorityPk,
+      Paused: gs.paused,
     });
   });
 });
@@ -190,7 +193,17 @@ describe("Device fixture", () => {
       ReservedSeats: dev.reservedSeats,
       MetricsPublisherPk: dev.metricsPublisherPubKey,
       ContributorPk: dev.contributorPubKey,
+      ConfigGeneration: dev.configGeneration,
+      AppliedConfigGeneration: dev.appliedConfigGeneration,
+      PreviousMetricsPublisherPk: dev.previousMetricsPublisherPubKey,
+      MetricsPublisherOverlapUntilEpoch: dev.metricsPublisherOverlapUntilEpoch,
+      AttestedKey: dev.attestedKey,
+      OpsLockLockedBy: dev.opsLock.lockedBy,
+      OpsLockExpiresAt: dev.opsLock.expiresAt,
+      OpsLockReason: dev.opsLock.reason,
+      CoOwnerPk: dev.coOwnerPubKey,
     });
+    expect(dev.attestationChallenge).toEqual(new Uint8Array(32).fill(0x47));
 
     // index
     expect(dev.index).toBe(7n);
@@ -289,6 +302,18 @@ describe("Device legacy fixture", () => {
     expect(dev.interfaces[0].loopbackType).toBe(1); // Vpnv4
     expect(dev.interfaces[1].name).toBe("Ethernet1");
     expect(dev.interfaces[1].userTunnelEndpoint).toBe(true);
+    expect(dev.configGeneration).toBe(0n);
+    expect(dev.appliedConfigGeneration).toBe(0n);
+    expect(dev.previousMetricsPublisherPubKey.toBase58()).toBe(
+      PublicKey.default.toBase58(),
+    );
+    expect(dev.metricsPublisherOverlapUntilEpoch).toBe(0n);
+    expect(dev.attestationChallenge).toEqual(new Uint8Array(32));
+    expect(dev.attestedKey.toBase58()).toBe(PublicKey.default.toBase58());
+    expect(dev.opsLock.lockedBy.toBase58()).toBe(PublicKey.default.toBase58());
+    expect(dev.opsLock.expiresAt).toBe(0n);
+    expect(dev.opsLock.reason).toBe("");
+    expect(dev.coOwnerPubKey.toBase58()).toBe(PublicKey.default.toBase58());
   });
 });
 
@@ -314,6 +339,14 @@ describe("Device future-version fixture", () => {
     expect(ni1.size).toBe(expectedInterfaceSize(ni1) + 8);
     expect(ni1.name).toBe("Ethernet1");
     expect(ni1.userTunnelEndpoint).toBe(true);
+
+    // Fields after the trailing vec are still read from the right offset.
+    expect(dev.configGeneration).toBe(9n);
+    expect(dev.appliedConfigGeneration).toBe(7n);
+    expect(dev.metricsPublisherOverlapUntilEpoch).toBe(42n);
+    expect(dev.attestedKey.toBytes()[0]).toBe(0x48);
+    expect(dev.opsLock.reason).toBe("optics swap");
+    expect(dev.coOwnerPubKey.toBytes()[0]).toBe(0x4a);
   });
 });
 
@@ -336,7 +369,33 @@ describe("Link fixture", () => {
       DelayOverrideNs: lk.delayOverrideNs,
       LinkHealth: lk.linkHealth,
       DesiredStatus: lk.linkDesiredStatus,
+      LinkFlags: lk.linkFlags,
+      FlapCount: lk.flapCount,
+      FlapPenalty: lk.flapPenalty,
+      FlapEpoch: lk.flapEpoch,
+      DampedUntilEpoch: lk.dampedUntilEpoch,
+      OpsLockLockedBy: lk.opsLock.lockedBy,
+      OpsLockExpiresAt: lk.opsLock.expiresAt,
+      OpsLockReason: lk.opsLock.reason,
     });
+    expect(lk.linkFlags & LINK_FLAG_FLAP_DAMPED).not.toBe(0);
+    expect(opsLockIsActive(lk.opsLock, 1_699_999_999n)).toBe(true);
+    expect(opsLockIsActive(lk.opsLock, 1_700_000_000n)).toBe(false);
+  });
+
+  test("backward compat: account without flap damping yields zeros", () => {
+    const [data] = loadFixture("link");
+    // Remove flap_count (4) + flap_penalty (4) + flap_epoch (8) + damped_until_epoch (8) = 24 bytes,
+    // and the ops lock written after them.
+    const opsLockLen = 32 + 8 + 4 + "OTDR trace".length;
+    const lk = deserializeLink(data.slice(0, data.length - 24 - opsLockLen));
+    expect(lk.linkFlags).toBe(2);
+    expect(lk.flapCount).toBe(0);
+    expect(lk.flapPenalty).toBe(0);
+    expect(lk.flapEpoch).toBe(0n);
+    expect(lk.dampedUntilEpoch).toBe(0n);
+    expect(lk.opsLock.expiresAt).toBe(0n);
+    expect(lk.opsLock.reason).toBe("");
   });
 });
 
@@ -361,20 +420,22 @@ describe("User fixture", () => {
       LastBgpReportedAt: u.lastBgpReportedAt,
       BgpRttNs: u.bgpRttNs,
       FeedPk: u.feedPk,
+      BandwidthTier: u.bandwidthTier,
     });
   });
 
   test("backward compat: old layout yields zero for new fields", () => {
     const [data] = loadFixture("user");
-    // Remove feed_pk (32) + bgp_status (1) + last_bgp_up_at (8) + last_bgp_reported_at (8)
-    // + bgp_rtt_ns (8) = 57 bytes
-    const truncated = data.slice(0, data.length - 57);
+    // Remove bandwidth_tier (1) + feed_pk (32) + bgp_status (1) + last_bgp_up_at (8)
+    // + last_bgp_reported_at (8) + bgp_rtt_ns (8) = 58 bytes
+    const truncated = data.slice(0, data.length - 58);
     const u = deserializeUser(truncated);
     expect(u.bgpStatus).toBe(0);
     expect(u.lastBgpUpAt).toBe(0n);
     expect(u.lastBgpReportedAt).toBe(0n);
     expect(u.bgpRttNs).toBe(0n);
     expect(u.feedPk.toBase58()).toBe(PublicKey.default.toBase58());
+    expect(u.bandwidthTier).toBe(0);
   });
 });
 
@@ -464,6 +525,8 @@ describe("AccessPass fixture", () => {
       MaxUnicastUsers: ap.maxUnicastUsers,
       MulticastUserCount: ap.multicastUserCount,
       MaxMulticastUsers: ap.maxMulticastUsers,
+      BandwidthTier: ap.bandwidthTier,
+      Referrer: ap.referrer,
     });
     expect(ap.unicastUserCount).toBe(2);
     expect(ap.maxUnicastUsers).toBe(4);
@@ -565,6 +628,33 @@ describe("AccessPassEdgeSeat fixture", () => {
   });
 });
 
+describe("AccessPassLeased fixture", () => {
+  test("deserialize", () => {
+    const [data, meta] = loadFixture("access_pass_leased");
+    const ap = deserializeAccessPass(data);
+    assertFields(meta.fields, {
+      AccountType: ap.accountType,
+      Owner: ap.owner,
+      BumpSeed: ap.bumpSeed,
+      AccessPassType: ap.accessPassType,
+      LeaseDeposit: ap.leaseDeposit,
+      UserPayer: ap.userPayer,
+      LastAccessEpoch: ap.lastAccessEpoch,
+      ConnectionCount: ap.connectionCount,
+      Status: ap.status,
+      Flags: ap.flags,
+      MaxUnicastUsers: ap.maxUnicastUsers,
+      MaxMulticastUsers: ap.maxMulticastUsers,
+    });
+
+    // Leased is tag 5 and carries the deposit in lamports.
+    expect(ap.accessPassType).toBe(5);
+    expect(ap.associatedPubkey).toBeNull();
+    expect(ap.feedSeats).toHaveLength(0);
+    expect(ap.leaseDeposit).toBe(5000000n);
+  });
+});
+
 describe("Feed fixture", () => {
   test("deserialize", () => {
     const [data, meta] = loadFixture("feed");
@@ -591,14 +681,231 @@ describe("Feed fixture", () => {
 
 describe("AccessPass legacy cap defaults", () => {
   test("pre-migration account decodes caps to 1", () => {
-    // A pre-migration account lacks the 8 trailing cap bytes; counts default to 0 and caps to 1,
-    // matching the Rust program's TryFrom unwrap_or defaults.
+    // A pre-migration account lacks the 8 trailing cap bytes, the bandwidth tier and the
+    // referrer; counts default to 0 and caps to 1, matching the Rust program's TryFrom
+    // unwrap_or defaults.
     const [data] = loadFixture("access_pass");
-    const legacy = data.slice(0, data.length - 8);
+    const legacy = data.slice(0, data.length - 41);
     const ap = deserializeAccessPass(legacy);
     expect(ap.unicastUserCount).toBe(0);
     expect(ap.maxUnicastUsers).toBe(1);
     expect(ap.multicastUserCount).toBe(0);
     expect(ap.maxMulticastUsers).toBe(1);
+    expect(ap.bandwidthTier).toBe(0);
+    expect(ap.referrer.toBase58()).toBe(PublicKey.default.toBase58());
   });
 });
+
+interface NegativeFixtureMeta {
+  name: string;
+  account: string;
+  account_type: number;
+  corruption: string;
+  corrupted_field: string | null;
+  error: string | null;
+  fields: FieldValue[];
+}
+
+const NEGATIVE_DIR = join(FIXTURES_DIR, "negative");
+
+function decodeNegative(
+  account: string,
+  data: Uint8Array,
+): Record<string, unknown> {
+  switch (account) {
+    case "Location": {
+      const loc = deserializeLocation(data);
+      return {
+        AccountType: loc.accountType,
+        Status: loc.status,
+        Code: loc.code,
+      };
+    }
+    case "Device": {
+      const dev = deserializeDevice(data);
+      return {
+        AccountType: dev.accountType,
+        Status: dev.status,
+        Code: dev.code,
+      };
+    }
+    case "User": {
+      const user = deserializeUser(data);
+      return {
+        AccountType: user.accountType,
+        UserType: user.userType,
+        Status: user.status,
+      };
+    }
+    default:
+      throw new Error(`no decoder for account ${account}`);
+  }
+}
+
+// Corrupted fixtures carrying the Rust reader's verdict
+// (see generate-fixtures/src/negative.rs).
+describe("Negative fixtures", () => {
+  const manifest: { fixtures: string[] } = JSON.parse(
+    readFileSync(join(NEGATIVE_DIR, "manifest.json"), "utf-8"),
+  );
+
+  test("manifest is not empty", () => {
+    expect(manifest.fixtures.length).toBeGreaterThan(0);
+  });
+
+  for (const name of manifest.fixtures) {
+    test(name, () => {
+      const data = new Uint8Array(
+        readFileSync(join(NEGATIVE_DIR, `${name}.bin`)),
+      );
+      const meta: NegativeFixtureMeta = JSON.parse(
+        readFileSync(join(NEGATIVE_DIR, `${name}.json`), "utf-8"),
+      );
+      let got: Record<string, unknown>;
+      try {
+        got = decodeNegative(meta.account, data);
+      } catch {
+        expect(meta.error !== null || meta.corrupted_field !== null).toBe(true);
+        return;
+      }
+      if (meta.error !== null) {
+        expect(got.AccountType).not.toBe(meta.account_type);
+        return;
+      }
+      assertFields(meta.fields, got);
+    });
+  }
+});
+
+interface MatrixMeta {
+  name: string;
+  entries: { name: string; data: string; fields: FieldValue[] }[];
+}
+
+// Enum-variant matrices (see generate-fixtures/src/matrix.rs): one Device per
+// combination of the declared axes.
+describe("Matrix fixtures", () => {
+  for (const name of ["device_matrix", "interface_version_matrix"]) {
+    test(name, () => {
+      const meta: MatrixMeta = JSON.parse(
+        readFileSync(join(FIXTURES_DIR, "matrix", `${name}.json`), "utf-8"),
+      );
+      expect(meta.entries.length).toBeGreaterThan(0);
+      for (const entry of meta.entries) {
+        const dev = deserializeDevice(
+          new Uint8Array(Buffer.from(entry.data, "hex")),
+        );
+        const got: Record<string, unknown> = {
+          AccountType: dev.accountType,
+          DeviceType: dev.deviceType,
+          Status: dev.status,
+          DeviceHealth: dev.deviceHealth,
+          Code: dev.code,
+        };
+        if (dev.interfaces.length > 0) {
+          const iface = dev.interfaces[0];
+          got.InterfacesLen = dev.interfaces.length;
+          got.Interface0Version = iface.version;
+          got.Interface0Name = iface.name;
+          got.Interface0UserTunnelEndpoint = iface.userTunnelEndpoint;
+          got.Interface0FlexAlgoNodeSegmentsLen = (
+            iface.flexAlgoNodeSegments ?? []
+          ).length;
+        }
+        assertFields(entry.fields, got);
+      }
+    });
+  }
+});
+
+interface InstructionMeta {
+  name: string;
+  variant: number;
+  fields: FieldValue[];
+}
+
+const INSTRUCTIONS_DIR = join(FIXTURES_DIR, "instructions");
+
+// Writes the variant byte and then each flattened field (see
+// generate-fixtures/src/instructions.rs).
+function encodeInstruction(meta: InstructionMeta): Uint8Array {
+  const parts: Buffer[] = [Buffer.from([meta.variant])];
+  for (const f of meta.fields) {
+    let b: Buffer;
+    switch (f.typ) {
+      case "u8":
+        b = Buffer.from([Number(f.value)]);
+        break;
+      case "u16":
+        b = Buffer.alloc(2);
+        b.writeUInt16LE(Number(f.value));
+        break;
+      case "u32":
+        b = Buffer.alloc(4);
+        b.writeUInt32LE(Number(f.value));
+        break;
+      case "u64":
+        b = Buffer.alloc(8);
+        b.writeBigUInt64LE(BigInt(f.value));
+        break;
+      case "i32":
+        b = Buffer.alloc(4);
+        b.writeInt32LE(Number(f.value));
+        break;
+      case "i64":
+        b = Buffer.alloc(8);
+        b.writeBigInt64LE(BigInt(f.value));
+        break;
+      case "bool":
+        b = Buffer.from([f.value === "true" ? 1 : 0]);
+        break;
+      case "string": {
+        const s = Buffer.from(f.value, "utf-8");
+        b = Buffer.alloc(4 + s.length);
+        b.writeUInt32LE(s.length);
+        s.copy(b, 4);
+        break;
+      }
+      case "pubkey":
+        b = new PublicKey(f.value).toBuffer();
+        break;
+      case "ipv4":
+        b = Buffer.from(f.value.split(".").map(Number));
+        break;
+      case "networkv4": {
+        const [ip, len] = f.value.split("/");
+        b = Buffer.from([...ip.split(".").map(Number), Number(len)]);
+        break;
+      }
+      default:
+        throw new Error(
+          `${meta.name}.${f.name}: unsupported field type ${f.typ}`,
+        );
+    }
+    parts.push(b);
+  }
+  return new Uint8Array(Buffer.concat(parts));
+}
+
+// Encoded instructions: every fixture can be rebuilt byte-for-byte from its metadata.
+describe("Instruction fixtures", () => {
+  const names = readdirSync(INSTRUCTIONS_DIR)
+    .filter((f) => f.endsWith(".json"))
+    .map((f) => f.slice(0, -".json".length));
+
+  test("fixtures exist", () => {
+    expect(names.length).toBeGreaterThan(0);
+  });
+
+  for (const name of names) {
+    test(name, () => {
+      const meta: InstructionMeta = JSON.parse(
+        readFileSync(join(INSTRUCTIONS_DIR, `${name}.json`), "utf-8"),
+      );
+      const data = new Uint8Array(
+        readFileSync(join(INSTRUCTIONS_DIR, `${name}.bin`)),
+      );
+      expect(encodeInstruction(meta)).toEqual(data);
+    });
+  }
+});
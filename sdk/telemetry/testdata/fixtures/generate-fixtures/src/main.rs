@@ -83,7 +83,12 @@ fn generate_device_latency_samples(dir: &Path) {
             next_sample_index: samples.len() as u32,
             agent_version: *b"1.0.0-test\0\0\0\0\0\0",
             agent_commit: *b"abcd1234",
-            _unused: [0; 104],
+            flags: 0,
+            shard_index: 0,
+            previous_agent_pk: Pubkey::default(),
+            agent_rotation_epoch: 0,
+            previous_agent_until_epoch: 0,
+            _unused: [0; 54],
         },
         samples,
     };
@@ -131,7 +136,8 @@ fn generate_internet_latency_samples(dir: &Path) {
             sampling_interval_microseconds: 60_000_000,
             start_timestamp_microseconds: 1_700_000_000_000_000,
             next_sample_index: samples.len() as u32,
-            _unused: [0; 128],
+            flags: 0,
+            _unused: [0; 127],
         },
         samples,
     };
@@ -23,9 +23,6 @@ pub struct FundAccessPassCliCommand {
     /// Minimum balance in SOL each payer should hold (in addition to rent)
     #[arg(long)]
     pub min_balance: Option<f64>,
-    /// Dry run: print what would be transferred without sending
-    #[arg(long, default_value_t = false)]
-    pub dry_run: bool,
     /// Skip confirmation prompt and transfer immediately
     #[arg(long, default_value_t = false)]
     pub force: bool,
@@ -63,7 +60,8 @@ impl FundAccessPassCliCommand {
                         .entry((user.owner, user.client_ip))
                         .or_default() += 1;
                 }
-                UserType::EdgeFiltering => {}
+                // EdgeFiltering (and any type added later) doesn't hold a connection slot.
+                _ => {}
             }
         }
 
@@ -155,7 +153,7 @@ impl FundAccessPassCliCommand {
             )?;
         }
 
-        if self.dry_run {
+        if client.is_dry_run() {
             writeln!(out, "[dry-run] no transfers sent.")?;
             return Ok(());
         }
@@ -218,14 +216,18 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         }
     }
 
     fn setup_client_with_balance(
         payer: Pubkey,
         balance: u64,
+        dry_run: bool,
     ) -> crate::doublezerocommand::MockCliCommand {
         let mut client = create_test_client();
+        client.expect_is_dry_run().return_const(dry_run);
         let ap_key = Pubkey::new_unique();
         let ap = make_ibrl_access_pass(payer);
 
@@ -251,7 +253,7 @@ mod tests {
     fn test_fund_all_sufficiently_funded() {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
         // balance > required (wallet_rent_min + needs_rent = 1_000_000 + 1_250_000 = 2_250_000)
-        let client = setup_client_with_balance(payer, 2_500_000);
+        let client = setup_client_with_balance(payer, 2_500_000, false);
 
         let ctx = cli_context_default_for_tests();
         let mut out = Vec::new();
@@ -273,17 +275,16 @@ mod tests {
     fn test_fund_dry_run_shows_summary_without_transferring() {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
         // balance = 500_000 < required (wallet_rent_min + needs_rent = 1_000_000 + 1_250_000 = 2_250_000), deficit = 1_750_000
-        let client = setup_client_with_balance(payer, 500_000);
+        let client = setup_client_with_balance(payer, 500_000, true);
 
         let ctx = cli_context_default_for_tests();
         let mut out = Vec::new();
-        let res = block_on(
-            FundAccessPassCliCommand {
-                dry_run: true,
-                ..Default::default()
-            }
-            .execute(&ctx, &client, &mut out, &mut "".as_bytes()),
-        );
+        let res = block_on(FundAccessPassCliCommand::default().execute(
+            &ctx,
+            &client,
+            &mut out,
+            &mut "".as_bytes(),
+        ));
 
         assert!(res.is_ok());
         let output = String::from_utf8(out).unwrap();
@@ -297,7 +298,7 @@ mod tests {
     #[test]
     fn test_fund_confirmation_yes_transfers() {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
-        let mut client = setup_client_with_balance(payer, 500_000);
+        let mut client = setup_client_with_balance(payer, 500_000, false);
         client
             .expect_transfer_sol()
             .returning(|_, _| Ok(solana_sdk::signature::Signature::default()));
@@ -320,7 +321,7 @@ mod tests {
     #[test]
     fn test_fund_confirmation_no_aborts() {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
-        let client = setup_client_with_balance(payer, 500_000);
+        let client = setup_client_with_balance(payer, 500_000, false);
 
         let ctx = cli_context_default_for_tests();
         let mut out = Vec::new();
@@ -343,14 +344,13 @@ mod tests {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
         // balance = 1_500_000 > needs_rent (1_250_000) but < required
         // required = wallet_rent_min + max(needs_rent, min_balance) = 1_000_000 + max(1_250_000, 2_000_000) = 3_000_000, deficit = 1_500_000
-        let client = setup_client_with_balance(payer, 1_500_000);
+        let client = setup_client_with_balance(payer, 1_500_000, true);
 
         let ctx = cli_context_default_for_tests();
         let mut out = Vec::new();
         let res = block_on(
             FundAccessPassCliCommand {
                 min_balance: Some(0.002), // 2_000_000 lamports
-                dry_run: true,
                 ..Default::default()
             }
             .execute(&ctx, &client, &mut out, &mut "".as_bytes()),
@@ -367,14 +367,13 @@ mod tests {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
         // balance = 500_000, min_balance = ~1 lamport
         // required = wallet_rent_min + max(needs_rent, min_balance) = 1_000_000 + max(1_250_000, 1) = 2_250_000, deficit = 1_750_000
-        let client = setup_client_with_balance(payer, 500_000);
+        let client = setup_client_with_balance(payer, 500_000, true);
 
         let ctx = cli_context_default_for_tests();
         let mut out = Vec::new();
         let res = block_on(
             FundAccessPassCliCommand {
                 min_balance: Some(0.000_000_001),
-                dry_run: true,
                 ..Default::default()
             }
             .execute(&ctx, &client, &mut out, &mut "".as_bytes()),
@@ -389,7 +388,7 @@ mod tests {
     #[test]
     fn test_fund_force_skips_confirmation() {
         let payer = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
-        let mut client = setup_client_with_balance(payer, 500_000);
+        let mut client = setup_client_with_balance(payer, 500_000, false);
         client
             .expect_transfer_sol()
             .returning(|_, _| Ok(solana_sdk::signature::Signature::default()));
@@ -1,9 +1,12 @@
 use crate::doublezerocommand::CliCommand;
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::{
-    accesspass::get::GetAccessPassCommand, multicastgroup::list::ListMulticastGroupCommand,
-    tenant::list::ListTenantCommand,
+use doublezero_sdk::{
+    commands::{
+        accesspass::get::GetAccessPassCommand, multicastgroup::list::ListMulticastGroupCommand,
+        tenant::list::ListTenantCommand,
+    },
+    labels::LabeledPubkey,
 };
 use doublezero_serviceability::state::accesspass::{AccessPassType, FeedSeat};
 use serde::Serialize;
@@ -25,7 +28,7 @@ pub struct GetAccessPassCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct AccessPassDisplay {
+pub(crate) struct AccessPassDisplay {
     pub account: String,
     #[tabled(rename = "type")]
     #[serde(rename = "type")]
@@ -33,7 +36,7 @@ struct AccessPassDisplay {
     #[tabled(skip)]
     pub feed_seats: Vec<FeedSeat>,
     pub client_ip: String,
-    pub user_payer: String,
+    pub user_payer: LabeledPubkey,
     pub tenant: String,
     pub multicast_pub: String,
     pub multicast_sub: String,
@@ -44,7 +47,8 @@ struct AccessPassDisplay {
     pub unicast_users: String,
     pub multicast_users: String,
     pub status: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
+    pub referrer: String,
 }
 
 impl GetAccessPassCliCommand {
@@ -54,6 +58,7 @@ impl GetAccessPassCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let epoch = client.get_epoch()?;
 
         let (pubkey, accesspass) = client
@@ -108,7 +113,7 @@ impl GetAccessPassCliCommand {
                 _ => Vec::new(),
             },
             client_ip: accesspass.client_ip.to_string(),
-            user_payer: accesspass.user_payer.to_string(),
+            user_payer: book.labeled(&accesspass.user_payer),
             tenant: tenant_display.join(", "),
             multicast_pub: pub_display.join(", "),
             multicast_sub: sub_display.join(", "),
@@ -125,7 +130,12 @@ impl GetAccessPassCliCommand {
                 accesspass.multicast_user_count, accesspass.max_multicast_users
             ),
             status: accesspass.status.to_string(),
-            owner: accesspass.owner.to_string(),
+            owner: book.labeled(&accesspass.owner),
+            referrer: if accesspass.referrer == Pubkey::default() {
+                String::new()
+            } else {
+                book.display(&accesspass.referrer)
+            },
         };
 
         if self.json {
@@ -221,6 +231,8 @@ mod tests {
             max_unicast_users: 5,
             multicast_user_count: 1,
             max_multicast_users: 3,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::new_unique(),
         };
 
         let accesspass_clone = accesspass.clone();
@@ -315,6 +327,10 @@ mod tests {
             has_row("owner", &accesspass.owner.to_string()),
             "owner row should contain value"
         );
+        assert!(
+            has_row("referrer", &accesspass.referrer.to_string()),
+            "referrer row should contain value"
+        );
     }
 
     #[test]
@@ -352,6 +368,8 @@ mod tests {
             max_unicast_users: 0,
             multicast_user_count: 1,
             max_multicast_users: 2,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         let accesspass_clone = accesspass.clone();
 
@@ -7,6 +7,7 @@ use doublezero_sdk::{
         accesspass::list::ListAccessPassCommand, multicastgroup::list::ListMulticastGroupCommand,
         tenant::list::ListTenantCommand,
     },
+    labels::LabeledPubkey,
     MulticastGroup,
 };
 use doublezero_serviceability::state::accesspass::{AccessPassStatus, AccessPassType};
@@ -81,8 +82,7 @@ pub struct AccessPassDisplay {
     pub unicast_users: String,
     pub multicast_users: String,
     pub status: AccessPassStatus,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
     #[tabled(skip)]
     #[serde(skip)]
     pub accesspass_type_value: AccessPassType,
@@ -143,7 +143,7 @@ fn accesspass_type_short(t: &AccessPassType) -> String {
         }
         // Compact form: the discriminant only (feed details are shown in the get/JSON views).
         AccessPassType::EdgeSeat(_) => t.to_discriminant_string(),
-        AccessPassType::Prepaid => t.to_string(),
+        AccessPassType::Prepaid | AccessPassType::Leased(_) => t.to_string(),
     }
 }
 
@@ -171,7 +171,7 @@ impl AccessPassDisplayNarrow {
             unicast_users: d.unicast_users.clone(),
             multicast_users: d.multicast_users.clone(),
             status: d.status,
-            owner: d.owner,
+            owner: d.owner.pubkey,
         }
     }
 }
@@ -183,6 +183,7 @@ impl ListAccessPassCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let epoch = client.get_epoch()?;
 
         let mgroups = client.list_multicastgroup(ListMulticastGroupCommand {})?;
@@ -354,7 +355,7 @@ impl ListAccessPassCliCommand {
                     access_pass.multicast_user_count, access_pass.max_multicast_users
                 ),
                 status: access_pass.status,
-                owner: access_pass.owner,
+                owner: book.labeled(&access_pass.owner),
                 accesspass_type_value: access_pass.accesspass_type.clone(),
                 mgroup_pub_allowlist: access_pass.mgroup_pub_allowlist.clone(),
                 mgroup_sub_allowlist: access_pass.mgroup_sub_allowlist.clone(),
@@ -436,6 +437,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let access2_pubkey = Pubkey::from_str_const("1111111QLbz7JHiBTspS962RLKV8GndWFwiEaqKM");
@@ -459,6 +462,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let access3_pubkey = Pubkey::from_str_const("11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9");
@@ -480,6 +485,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client.expect_get_epoch().returning(move || Ok(123));
@@ -705,6 +712,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         // access2: subscriber of "test", IP 0.0.0.0
@@ -727,6 +736,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         // access3: publisher of "test", IP 2.3.4.5
@@ -749,6 +760,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client.expect_list_multicastgroup().returning(move |_| {
@@ -2,5 +2,7 @@ pub mod close;
 pub mod fund;
 pub mod get;
 pub mod list;
+pub mod referrals;
 pub mod set;
+pub mod settle;
 pub mod user_balances;
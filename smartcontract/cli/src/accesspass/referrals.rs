@@ -0,0 +1,178 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use doublezero_sdk::{
+    commands::referrer_stats::{get::GetReferrerStatsCommand, list::ListReferrerStatsCommand},
+    ReferrerStats,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ReferralsAccessPassCliCommand {
+    /// Only show this referrer
+    #[arg(long, value_name = "PUBKEY")]
+    pub referrer: Option<Pubkey>,
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct ReferrerStatsDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub referrer: Pubkey,
+    pub referrals: u64,
+    pub first_referral_epoch: u64,
+    pub last_referral_epoch: u64,
+}
+
+impl From<ReferrerStats> for ReferrerStatsDisplay {
+    fn from(stats: ReferrerStats) -> Self {
+        ReferrerStatsDisplay {
+            referrer: stats.referrer,
+            referrals: stats.referral_count,
+            first_referral_epoch: stats.first_referral_epoch,
+            last_referral_epoch: stats.last_referral_epoch,
+        }
+    }
+}
+
+impl ReferralsAccessPassCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let mut stats: Vec<ReferrerStats> = match self.referrer {
+            Some(referrer) => {
+                let (_, stats) = client
+                    .get_referrer_stats(GetReferrerStatsCommand { referrer })
+                    .map_err(|_| eyre::eyre!("No referrals recorded for {referrer}"))?;
+                vec![stats]
+            }
+            None => client
+                .list_referrer_stats(ListReferrerStatsCommand)?
+                .into_values()
+                .collect(),
+        };
+        // Most referrals first; ties in referrer order so the output is stable.
+        stats.sort_by(|a, b| {
+            b.referral_count
+                .cmp(&a.referral_count)
+                .then_with(|| a.referrer.cmp(&b.referrer))
+        });
+
+        let displays: Vec<ReferrerStatsDisplay> = stats.into_iter().map(Into::into).collect();
+
+        render_collection(
+            out,
+            displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        accesspass::referrals::ReferralsAccessPassCliCommand, tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::referrer_stats::get::GetReferrerStatsCommand, AccountType, ReferrerStats,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    fn stats(referrer: Pubkey, referral_count: u64) -> ReferrerStats {
+        ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer,
+            referral_count,
+            first_referral_epoch: 10,
+            last_referral_epoch: 20,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cli_accesspass_referrals_list() {
+        let mut client = create_test_client();
+
+        let few = Pubkey::from_str_const("11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo");
+        let many = Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+        client.expect_list_referrer_stats().returning(move |_| {
+            Ok(HashMap::from([
+                (Pubkey::new_unique(), stats(few, 1)),
+                (Pubkey::new_unique(), stats(many, 4)),
+            ]))
+        });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ReferralsAccessPassCliCommand {
+                referrer: None,
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, "[{\"referrer\":\"11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V\",\"referrals\":4,\"first_referral_epoch\":10,\"last_referral_epoch\":20},{\"referrer\":\"11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo\",\"referrals\":1,\"first_referral_epoch\":10,\"last_referral_epoch\":20}]\n");
+    }
+
+    #[test]
+    fn test_cli_accesspass_referrals_single_referrer() {
+        let mut client = create_test_client();
+
+        let referrer = Pubkey::new_unique();
+        let missing = Pubkey::new_unique();
+        client
+            .expect_get_referrer_stats()
+            .with(predicate::eq(GetReferrerStatsCommand { referrer }))
+            .returning(move |_| Ok((Pubkey::new_unique(), stats(referrer, 2))));
+        client
+            .expect_get_referrer_stats()
+            .with(predicate::eq(GetReferrerStatsCommand { referrer: missing }))
+            .returning(|_| Err(eyre::eyre!("account not found")));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ReferralsAccessPassCliCommand {
+                referrer: Some(referrer),
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains(&format!("\"referrer\":\"{referrer}\",\"referrals\":2")));
+
+        let res = block_on(
+            ReferralsAccessPassCliCommand {
+                referrer: Some(missing),
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut Vec::new()),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            format!("No referrals recorded for {missing}")
+        );
+    }
+}
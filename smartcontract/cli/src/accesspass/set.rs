@@ -4,13 +4,15 @@ use crate::{
 };
 use clap::{Args, ValueEnum};
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::accesspass::set::SetAccessPassCommand;
+use doublezero_sdk::commands::accesspass::{
+    set::SetAccessPassCommand, set_batch::SetAccessPassBatchCommand,
+};
 use doublezero_serviceability::{
     pda::{get_accesspass_pda, get_tenant_pda},
-    state::accesspass::AccessPassType,
+    state::{accesspass::AccessPassType, user::BandwidthTier},
 };
 use solana_sdk::pubkey::Pubkey;
-use std::{io::Write, net::Ipv4Addr, str::FromStr};
+use std::{collections::HashSet, fs, io::Write, net::Ipv4Addr, str::FromStr};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum CliAccessPassType {
@@ -19,8 +21,14 @@ pub enum CliAccessPassType {
     SolanaRPC,
     Others,
     EdgeSeat,
+    Leased,
 }
 
+/// Header of the CSV read by `--from-csv`.
+const CSV_HEADER: &str = "client_ip,payer,type,epochs";
+/// Header of the result CSV written for `--from-csv`.
+const RESULT_CSV_HEADER: &str = "client_ip,payer,accesspass,signature,error";
+
 #[derive(Args, Debug)]
 pub struct SetAccessPassCliCommand {
     /// Specifies the access pass type
@@ -30,8 +38,8 @@ pub struct SetAccessPassCliCommand {
     #[arg(long)]
     pub client_ip: Option<Ipv4Addr>,
     /// Specifies the payer of the access pass.
-    #[arg(long)]
-    pub user_payer: String,
+    #[arg(long, required_unless_present = "from_csv")]
+    pub user_payer: Option<String>,
     /// Specifies the number of epochs for the access pass.
     #[arg(long, default_value = "max")]
     pub epochs: String,
@@ -56,6 +64,45 @@ pub struct SetAccessPassCliCommand {
     /// Max multicast users admitted by an EdgeSeat access pass.
     #[arg(long, default_value_t = 1)]
     pub max_multicast_users: u16,
+    /// Bandwidth tier of users created under the access pass, and the highest they may request (foundation only)
+    #[arg(long)]
+    pub bandwidth_tier: Option<BandwidthTier>,
+    /// Referrer credited with the onboarding when this creates the access pass
+    #[arg(long, value_name = "PUBKEY")]
+    pub referrer: Option<Pubkey>,
+    /// Lamports deposited by a leased access pass, burned once it expires. Required if accesspass_type is leased.
+    #[arg(long, required_if_eq("accesspass_type", "leased"))]
+    pub deposit: Option<u64>,
+    /// Set the access passes listed in a CSV file with a `client_ip,payer,type,epochs` header.
+    /// `type` is prepaid, solana-validator:<NODE_ID>, solana-rpc[:<NODE_ID>], others:<NAME>:<KEY>,
+    /// edge-seat or leased:<LAMPORTS>; the remaining flags apply to every row.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "accesspass_type",
+            "client_ip",
+            "user_payer",
+            "epochs",
+            "solana_validator",
+            "others_name",
+            "others_key",
+            "deposit",
+        ]
+    )]
+    pub from_csv: Option<String>,
+    /// Write the per-row results of --from-csv to this file instead of stdout
+    #[arg(long, value_name = "FILE", requires = "from_csv")]
+    pub output: Option<String>,
+}
+
+/// One validated row of a `--from-csv` file.
+#[derive(Debug, PartialEq)]
+struct CsvRow {
+    client_ip: Ipv4Addr,
+    user_payer: Pubkey,
+    accesspass_type: AccessPassType,
+    last_access_epoch: u64,
 }
 
 impl SetAccessPassCliCommand {
@@ -68,20 +115,16 @@ impl SetAccessPassCliCommand {
         // Check requirements
         client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
 
-        let user_payer = {
-            if self.user_payer.eq_ignore_ascii_case("me") {
-                client.get_payer()
-            } else {
-                Pubkey::from_str(&self.user_payer)?
-            }
-        };
+        if let Some(path) = &self.from_csv {
+            return self.execute_csv(path, client, out);
+        }
 
-        let current_epoch = client.get_epoch()?;
-        let last_access_epoch = match self.epochs.to_ascii_lowercase().as_str() {
-            "0" => 0,
-            "max" => u64::MAX,
-            _ => current_epoch + self.epochs.parse::<u64>()?,
+        let Some(user_payer) = &self.user_payer else {
+            eyre::bail!("--user-payer is required");
         };
+        let user_payer = parse_user_payer(client, user_payer)?;
+
+        let last_access_epoch = parse_epochs(client.get_epoch()?, &self.epochs)?;
 
         let accesspass_type = match self.accesspass_type {
             CliAccessPassType::Prepaid => AccessPassType::Prepaid,
@@ -101,17 +144,13 @@ impl SetAccessPassCliCommand {
                 ),
             },
             CliAccessPassType::EdgeSeat => AccessPassType::EdgeSeat(vec![]),
+            CliAccessPassType::Leased => match self.deposit {
+                Some(deposit) => AccessPassType::Leased(deposit),
+                None => eyre::bail!("Leased access pass type requires --deposit <LAMPORTS>"),
+            },
         };
 
-        // Convert tenant code to PDA if provided
-        let tenant = if let Some(code) = &self.tenant {
-            if code.len() > 32 {
-                eyre::bail!("Tenant code '{}' exceeds 32 bytes", code);
-            }
-            get_tenant_pda(&client.get_program_id(), code).0
-        } else {
-            Pubkey::default()
-        };
+        let tenant = tenant_pda(client, self.tenant.as_deref())?;
 
         let (accesspass_pubkey, _) = get_accesspass_pda(
             &client.get_program_id(),
@@ -129,11 +168,207 @@ impl SetAccessPassCliCommand {
             tenant,
             max_unicast_users: self.max_unicast_users,
             max_multicast_users: self.max_multicast_users,
+            bandwidth_tier: self.bandwidth_tier,
+            referrer: self.referrer,
         })?;
         writeln!(out, "Signature: {signature}")?;
 
         Ok(())
     }
+
+    /// Validates every row of the CSV before sending anything, sets the passes in
+    /// as few transactions as fit, and writes one result row per input row.
+    fn execute_csv<C: CliCommand, W: Write>(
+        &self,
+        path: &str,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let contents =
+            fs::read_to_string(path).map_err(|err| eyre::eyre!("Failed to read {path}: {err}"))?;
+        let rows = parse_csv(client, client.get_epoch()?, &contents)?;
+        let tenant = tenant_pda(client, self.tenant.as_deref())?;
+
+        let passes = rows
+            .iter()
+            .map(|row| SetAccessPassCommand {
+                accesspass_type: row.accesspass_type.clone(),
+                client_ip: row.client_ip,
+                user_payer: row.user_payer,
+                last_access_epoch: row.last_access_epoch,
+                allow_multiple_ip: self.allow_multiple_ip,
+                tenant,
+                max_unicast_users: self.max_unicast_users,
+                max_multicast_users: self.max_multicast_users,
+                bandwidth_tier: self.bandwidth_tier,
+                referrer: self.referrer,
+            })
+            .collect();
+        let results = client.set_accesspass_batch(SetAccessPassBatchCommand { passes })?;
+
+        let program_id = client.get_program_id();
+        let mut report = format!("{RESULT_CSV_HEADER}\n");
+        for (row, result) in rows.iter().zip(&results) {
+            let (accesspass_pubkey, _) =
+                get_accesspass_pda(&program_id, &row.client_ip, &row.user_payer);
+            let (signature, error) = match result {
+                Ok(signature) => (signature.to_string(), String::new()),
+                Err(err) => (String::new(), csv_field(&err.to_string())),
+            };
+            report.push_str(&format!(
+                "{},{},{accesspass_pubkey},{signature},{error}\n",
+                row.client_ip, row.user_payer
+            ));
+        }
+
+        match &self.output {
+            Some(output) => {
+                fs::write(output, &report)?;
+                let transactions = results
+                    .iter()
+                    .filter_map(|result| result.as_ref().ok())
+                    .collect::<HashSet<_>>()
+                    .len();
+                writeln!(
+                    out,
+                    "Set {} of {} access pass(es) in {transactions} transaction(s); results written to {output}",
+                    results.iter().filter(|result| result.is_ok()).count(),
+                    results.len(),
+                )?;
+            }
+            None => write!(out, "{report}")?,
+        }
+
+        let failed = results.iter().filter(|result| result.is_err()).count();
+        if failed > 0 {
+            eyre::bail!("{failed} of {} access pass(es) failed", results.len());
+        }
+        Ok(())
+    }
+}
+
+fn parse_user_payer<C: CliCommand>(client: &C, value: &str) -> eyre::Result<Pubkey> {
+    if value.eq_ignore_ascii_case("me") {
+        Ok(client.get_payer())
+    } else {
+        Ok(Pubkey::from_str(value)?)
+    }
+}
+
+fn parse_epochs(current_epoch: u64, epochs: &str) -> eyre::Result<u64> {
+    Ok(match epochs.to_ascii_lowercase().as_str() {
+        "0" => 0,
+        "max" => u64::MAX,
+        _ => current_epoch + epochs.parse::<u64>()?,
+    })
+}
+
+/// Convert tenant code to PDA if provided
+fn tenant_pda<C: CliCommand>(client: &C, code: Option<&str>) -> eyre::Result<Pubkey> {
+    match code {
+        Some(code) if code.len() > 32 => eyre::bail!("Tenant code '{}' exceeds 32 bytes", code),
+        Some(code) => Ok(get_tenant_pda(&client.get_program_id(), code).0),
+        None => Ok(Pubkey::default()),
+    }
+}
+
+/// Parses the `type` column of a `--from-csv` row.
+fn parse_csv_type(value: &str) -> eyre::Result<AccessPassType> {
+    let mut parts = value.split(':');
+    let kind = CliAccessPassType::from_str(parts.next().unwrap_or_default(), true)
+        .map_err(|_| eyre::eyre!("unknown access pass type '{value}'"))?;
+    let params: Vec<&str> = parts.collect();
+    let node_id =
+        |param: &str| Pubkey::from_str(param).map_err(|_| eyre::eyre!("invalid node id '{param}'"));
+    Ok(match (kind, params.as_slice()) {
+        (CliAccessPassType::Prepaid, []) => AccessPassType::Prepaid,
+        (CliAccessPassType::SolanaValidator, [node]) => {
+            AccessPassType::SolanaValidator(node_id(node)?)
+        }
+        (CliAccessPassType::SolanaRPC, []) => AccessPassType::SolanaRPC(Pubkey::default()),
+        (CliAccessPassType::SolanaRPC, [node]) => AccessPassType::SolanaRPC(node_id(node)?),
+        (CliAccessPassType::Others, [name, key]) => {
+            AccessPassType::Others(name.to_string(), key.to_string())
+        }
+        (CliAccessPassType::EdgeSeat, []) => AccessPassType::EdgeSeat(vec![]),
+        (CliAccessPassType::Leased, [deposit]) => AccessPassType::Leased(
+            deposit
+                .parse()
+                .map_err(|_| eyre::eyre!("invalid deposit '{deposit}'"))?,
+        ),
+        _ => eyre::bail!(
+            "malformed access pass type '{value}', expected prepaid, solana-validator:<NODE_ID>, solana-rpc[:<NODE_ID>], others:<NAME>:<KEY>, edge-seat or leased:<LAMPORTS>"
+        ),
+    })
+}
+
+/// Parses and validates a `--from-csv` file, reporting every invalid row at once.
+fn parse_csv<C: CliCommand>(
+    client: &C,
+    current_epoch: u64,
+    contents: &str,
+) -> eyre::Result<Vec<CsvRow>> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+    let header = lines.next().map(|(_, line)| line.replace(' ', ""));
+    if header.as_deref() != Some(CSV_HEADER) {
+        eyre::bail!("CSV must start with the header '{CSV_HEADER}'");
+    }
+
+    let mut rows = vec![];
+    let mut errors = vec![];
+    let mut seen = HashSet::new();
+    for (line_number, line) in lines {
+        let row = (|| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [client_ip, payer, accesspass_type, epochs] = fields.as_slice() else {
+                eyre::bail!("expected 4 fields, found {}", fields.len());
+            };
+            let row = CsvRow {
+                client_ip: client_ip
+                    .parse()
+                    .map_err(|_| eyre::eyre!("invalid client_ip '{client_ip}'"))?,
+                user_payer: parse_user_payer(client, payer)
+                    .map_err(|_| eyre::eyre!("invalid payer '{payer}'"))?,
+                accesspass_type: parse_csv_type(accesspass_type)?,
+                last_access_epoch: parse_epochs(current_epoch, epochs)
+                    .map_err(|_| eyre::eyre!("invalid epochs '{epochs}'"))?,
+            };
+            if !seen.insert((row.client_ip, row.user_payer)) {
+                eyre::bail!(
+                    "duplicate access pass for client_ip {} and payer {}",
+                    row.client_ip,
+                    row.user_payer
+                );
+            }
+            Ok(row)
+        })();
+        match row {
+            Ok(row) => rows.push(row),
+            Err(err) => errors.push(format!("line {line_number}: {err}")),
+        }
+    }
+
+    if !errors.is_empty() {
+        eyre::bail!("Invalid CSV, nothing was sent:\n{}", errors.join("\n"));
+    }
+    if rows.is_empty() {
+        eyre::bail!("CSV contains no access passes");
+    }
+    Ok(rows)
+}
+
+/// Quotes a result CSV field if it contains a separator, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 #[cfg(test)]
@@ -144,7 +379,9 @@ mod tests {
         tests::utils::create_test_client,
     };
     use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
-    use doublezero_sdk::commands::accesspass::set::SetAccessPassCommand;
+    use doublezero_sdk::commands::accesspass::{
+        set::SetAccessPassCommand, set_batch::SetAccessPassBatchCommand,
+    };
     use doublezero_serviceability::{
         pda::{get_accesspass_pda, get_tenant_pda},
         state::accesspass::AccessPassType,
@@ -186,6 +423,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -195,7 +434,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -204,6 +443,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -215,6 +459,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cli_accesspass_set_with_referrer() {
+        let mut client = create_test_client();
+
+        let client_ip = [100, 0, 0, 2].into();
+        let payer = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(10));
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_set_accesspass()
+            .with(predicate::eq(SetAccessPassCommand {
+                accesspass_type: AccessPassType::Prepaid,
+                client_ip,
+                user_payer: payer,
+                last_access_epoch: u64::MAX,
+                allow_multiple_ip: false,
+                tenant: Pubkey::default(),
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: Some(referrer),
+            }))
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(
+            SetAccessPassCliCommand {
+                accesspass_type: CliAccessPassType::Prepaid,
+                client_ip: Some(client_ip),
+                user_payer: Some(payer.to_string()),
+                epochs: "max".into(),
+                solana_validator: None,
+                allow_multiple_ip: false,
+                others_name: None,
+                others_key: None,
+                tenant: None,
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: Some(referrer),
+                deposit: None,
+                from_csv: None,
+                output: None,
+            }
+            .execute(&ctx, &client, &mut Vec::new()),
+        );
+        assert!(res.is_ok());
+    }
+
     #[test]
     fn test_cli_accesspass_set_solana_validator_missing_validator() {
         let mut client = create_test_client();
@@ -234,7 +533,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::SolanaValidator,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -243,6 +542,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -287,6 +591,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -296,7 +602,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::SolanaValidator,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: Some(solana_validator),
                 allow_multiple_ip: false,
@@ -305,6 +611,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -348,6 +659,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -357,7 +670,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::SolanaRPC,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -366,6 +679,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -406,6 +724,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -415,7 +735,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::SolanaRPC,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: Some(solana_validator),
                 allow_multiple_ip: false,
@@ -424,6 +744,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -454,7 +779,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Others,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -463,6 +788,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -492,7 +822,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Others,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -501,6 +831,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -546,6 +881,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -555,7 +892,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Others,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -564,6 +901,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -591,7 +933,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some([100, 0, 0, 1].into()),
-                user_payer: "not-a-valid-pubkey".to_string(),
+                user_payer: Some("not-a-valid-pubkey".to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -600,6 +942,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -622,8 +969,9 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some([100, 0, 0, 1].into()),
-                user_payer: Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB")
-                    .to_string(),
+                user_payer: Some(
+                    Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB").to_string(),
+                ),
                 epochs: "not-a-number".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -632,6 +980,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -655,8 +1008,9 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some([100, 0, 0, 1].into()),
-                user_payer: Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB")
-                    .to_string(),
+                user_payer: Some(
+                    Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB").to_string(),
+                ),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -665,6 +1019,11 @@ mod tests {
                 tenant: Some(too_long.clone()),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -699,6 +1058,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -708,7 +1069,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: true,
@@ -717,6 +1078,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -749,6 +1115,8 @@ mod tests {
                 tenant: tenant_pda,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -758,7 +1126,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -767,6 +1135,11 @@ mod tests {
                 tenant: Some("acme".to_string()),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -796,6 +1169,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -805,7 +1180,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: None,
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -814,6 +1189,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -844,6 +1224,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -853,7 +1235,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "0".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -862,6 +1244,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -893,6 +1280,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -902,7 +1291,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::Prepaid,
                 client_ip: Some(client_ip),
-                user_payer: "me".to_string(),
+                user_payer: Some("me".to_string()),
                 epochs: "max".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -911,6 +1300,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -949,6 +1343,8 @@ mod tests {
                 tenant: Pubkey::default(),
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -958,7 +1354,7 @@ mod tests {
             SetAccessPassCliCommand {
                 accesspass_type: CliAccessPassType::EdgeSeat,
                 client_ip: Some(client_ip),
-                user_payer: payer.to_string(),
+                user_payer: Some(payer.to_string()),
                 epochs: "1".into(),
                 solana_validator: None,
                 allow_multiple_ip: false,
@@ -967,6 +1363,11 @@ mod tests {
                 tenant: None,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+                deposit: None,
+                from_csv: None,
+                output: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -977,4 +1378,219 @@ mod tests {
             "AccessPass PDA: 6pw9fvwzjjkkocGuwxhmv1TwHHnYTFjGvV9GKX6nkFMw\nSignature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
         );
     }
+
+    #[test]
+    fn test_cli_accesspass_set_leased() {
+        let mut client = create_test_client();
+
+        let client_ip = [100, 0, 0, 1].into();
+        let payer = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(10));
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_set_accesspass()
+            .with(predicate::eq(SetAccessPassCommand {
+                accesspass_type: AccessPassType::Leased(5_000_000),
+                client_ip,
+                user_payer: payer,
+                last_access_epoch: 40,
+                allow_multiple_ip: false,
+                tenant: Pubkey::default(),
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+            }))
+            .returning(move |_| Ok(signature));
+
+        let command = |deposit| SetAccessPassCliCommand {
+            accesspass_type: CliAccessPassType::Leased,
+            client_ip: Some(client_ip),
+            user_payer: Some(payer.to_string()),
+            epochs: "30".into(),
+            solana_validator: None,
+            allow_multiple_ip: false,
+            others_name: None,
+            others_key: None,
+            tenant: None,
+            max_unicast_users: 1,
+            max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
+            deposit,
+            from_csv: None,
+            output: None,
+        };
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(command(Some(5_000_000)).execute(&ctx, &client, &mut output)).unwrap();
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with(&format!("Signature: {signature}\n")));
+
+        let res = block_on(command(None).execute(&ctx, &client, &mut Vec::new()));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Leased access pass type requires --deposit <LAMPORTS>"
+        );
+        assert_eq!(
+            parse_csv_type("leased:5000000").unwrap(),
+            AccessPassType::Leased(5_000_000)
+        );
+        assert!(parse_csv_type("leased").is_err());
+    }
+
+    fn csv_command(from_csv: String, output: Option<String>) -> SetAccessPassCliCommand {
+        SetAccessPassCliCommand {
+            accesspass_type: CliAccessPassType::Prepaid,
+            client_ip: None,
+            user_payer: None,
+            epochs: "max".into(),
+            solana_validator: None,
+            allow_multiple_ip: false,
+            others_name: None,
+            others_key: None,
+            tenant: None,
+            max_unicast_users: 1,
+            max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
+            deposit: None,
+            from_csv: Some(from_csv),
+            output,
+        }
+    }
+
+    #[test]
+    fn test_cli_accesspass_set_from_csv() {
+        let mut client = create_test_client();
+
+        let payer1 = Pubkey::new_unique();
+        let payer2 = Pubkey::new_unique();
+        let node_id = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(10));
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        let passes = vec![
+            SetAccessPassCommand {
+                accesspass_type: AccessPassType::Prepaid,
+                client_ip: [100, 0, 0, 1].into(),
+                user_payer: payer1,
+                last_access_epoch: u64::MAX,
+                allow_multiple_ip: false,
+                tenant: Pubkey::default(),
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+            },
+            SetAccessPassCommand {
+                accesspass_type: AccessPassType::SolanaValidator(node_id),
+                client_ip: [100, 0, 0, 2].into(),
+                user_payer: payer2,
+                last_access_epoch: 15,
+                allow_multiple_ip: false,
+                tenant: Pubkey::default(),
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
+            },
+        ];
+        client
+            .expect_set_accesspass_batch()
+            .with(predicate::eq(SetAccessPassBatchCommand { passes }))
+            .returning(move |_| Ok(vec![Ok(signature), Err(eyre::eyre!("NotAllowed, denied"))]));
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let input = dir.path().join("passes.csv");
+        let results = dir.path().join("results.csv");
+        fs::write(
+            &input,
+            format!(
+                "client_ip,payer,type,epochs\n100.0.0.1,{payer1},prepaid,max\n\n100.0.0.2,{payer2},solana-validator:{node_id},5\n"
+            ),
+        )
+        .unwrap();
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            csv_command(
+                input.to_string_lossy().to_string(),
+                Some(results.to_string_lossy().to_string()),
+            )
+            .execute(&ctx, &client, &mut output),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "1 of 2 access pass(es) failed"
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .starts_with("Set 1 of 2 access pass(es) in 1 transaction(s)"));
+
+        let program_id = client.get_program_id();
+        let (pda1, _) = get_accesspass_pda(&program_id, &[100, 0, 0, 1].into(), &payer1);
+        let (pda2, _) = get_accesspass_pda(&program_id, &[100, 0, 0, 2].into(), &payer2);
+        assert_eq!(
+            fs::read_to_string(&results).unwrap(),
+            format!(
+                "client_ip,payer,accesspass,signature,error\n100.0.0.1,{payer1},{pda1},{signature},\n100.0.0.2,{payer2},{pda2},,\"NotAllowed, denied\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_cli_accesspass_set_from_csv_invalid_rows() {
+        let mut client = create_test_client();
+
+        let payer = Pubkey::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(10));
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client.expect_set_accesspass_batch().never();
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let input = dir.path().join("passes.csv");
+        fs::write(
+            &input,
+            format!(
+                "client_ip,payer,type,epochs\n100.0.0.1,{payer},prepaid,max\n100.0.0.1,{payer},prepaid,1\n100.0.0.x,{payer},prepaid,max\n100.0.0.3,{payer},solana-validator,max\n100.0.0.4,{payer},prepaid\n"
+            ),
+        )
+        .unwrap();
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            csv_command(input.to_string_lossy().to_string(), None).execute(
+                &ctx,
+                &client,
+                &mut output,
+            ),
+        );
+        let err = res.unwrap_err().to_string();
+        assert!(err.starts_with("Invalid CSV, nothing was sent:"));
+        assert!(err.contains(&format!(
+            "line 3: duplicate access pass for client_ip 100.0.0.1 and payer {payer}"
+        )));
+        assert!(err.contains("line 4: invalid client_ip '100.0.0.x'"));
+        assert!(err.contains("line 5: malformed access pass type 'solana-validator'"));
+        assert!(err.contains("line 6: expected 4 fields, found 3"));
+        assert!(output.is_empty());
+    }
 }
@@ -0,0 +1,77 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::accesspass::settle_lease::SettleAccessPassLeaseCommand;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SettleAccessPassCliCommand {
+    /// Access pass public key
+    #[arg(long)]
+    pub pubkey: Pubkey,
+}
+
+impl SettleAccessPassCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let signature = client.settle_accesspass_lease(SettleAccessPassLeaseCommand {
+            pubkey: self.pubkey,
+        })?;
+        writeln!(out, "Signature: {signature}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use mockall::predicate;
+    use solana_sdk::signature::Signature;
+
+    #[test]
+    fn test_cli_accesspass_settle() {
+        let mut client = create_test_client();
+
+        let accesspass_pubkey = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_settle_accesspass_lease()
+            .with(predicate::eq(SettleAccessPassLeaseCommand {
+                pubkey: accesspass_pubkey,
+            }))
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            SettleAccessPassCliCommand {
+                pubkey: accesspass_pubkey,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+    }
+}
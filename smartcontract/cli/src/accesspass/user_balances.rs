@@ -141,7 +141,8 @@ impl UserBalancesAccessPassCliCommand {
                         .entry((user.owner, user.client_ip))
                         .or_default() += 1;
                 }
-                UserType::EdgeFiltering => {}
+                // EdgeFiltering (and any type added later) doesn't hold a connection slot.
+                _ => {}
             }
         }
 
@@ -0,0 +1,307 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::{
+    accesspass::list::ListAccessPassCommand, device::list::ListDeviceCommand,
+    multicastgroup::list::ListMulticastGroupCommand, tenant::list::ListTenantCommand,
+    user::list::ListUserCommand,
+};
+use doublezero_serviceability::state::{
+    accesspass::AccessPassType,
+    user::{BandwidthTier, UserCYOA, UserType},
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, fs, io::Write, net::Ipv4Addr};
+
+/// Version of the snapshot format written by `admin backup`.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Access passes and users of a serviceability program, as written by
+/// `admin backup` and read by `admin restore`.
+///
+/// Tenants, devices and multicast groups are referenced by code rather than
+/// pubkey, so a snapshot can be restored into a redeployed program where the
+/// same resources live at different addresses.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Snapshot {
+    pub version: u32,
+    pub access_passes: Vec<AccessPassRecord>,
+    pub users: Vec<UserRecord>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AccessPassRecord {
+    pub accesspass_type: AccessPassType,
+    pub client_ip: Ipv4Addr,
+    #[serde(
+        serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+        deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+    )]
+    pub user_payer: Pubkey,
+    pub last_access_epoch: u64,
+    pub allow_multiple_ip: bool,
+    /// Tenant code, if the pass is bound to a tenant.
+    pub tenant: Option<String>,
+    pub max_unicast_users: u16,
+    pub max_multicast_users: u16,
+    pub bandwidth_tier: BandwidthTier,
+    /// Codes of the multicast groups the pass may publish to.
+    pub mgroup_pub_allowlist: Vec<String>,
+    /// Codes of the multicast groups the pass may subscribe to.
+    pub mgroup_sub_allowlist: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct UserRecord {
+    #[serde(
+        serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+        deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+    )]
+    pub owner: Pubkey,
+    pub user_type: UserType,
+    pub cyoa_type: UserCYOA,
+    pub client_ip: Ipv4Addr,
+    pub tunnel_endpoint: Ipv4Addr,
+    /// Device code.
+    pub device: String,
+    pub bandwidth_tier: BandwidthTier,
+    /// Codes of the multicast groups the user publishes to.
+    pub publishers: Vec<String>,
+    /// Codes of the multicast groups the user subscribes to.
+    pub subscribers: Vec<String>,
+}
+
+impl Snapshot {
+    /// Read the access passes and users of the client's program.
+    pub fn capture<C: CliCommand>(client: &C) -> eyre::Result<Self> {
+        let tenants: HashMap<Pubkey, String> = client
+            .list_tenant(ListTenantCommand {})?
+            .into_iter()
+            .map(|(pk, tenant)| (pk, tenant.code))
+            .collect();
+        let devices: HashMap<Pubkey, String> = client
+            .list_device(ListDeviceCommand)?
+            .into_iter()
+            .map(|(pk, device)| (pk, device.code))
+            .collect();
+        let mgroups: HashMap<Pubkey, String> = client
+            .list_multicastgroup(ListMulticastGroupCommand)?
+            .into_iter()
+            .map(|(pk, mgroup)| (pk, mgroup.code))
+            .collect();
+
+        let code = |codes: &HashMap<Pubkey, String>, kind: &str, pk: &Pubkey| {
+            codes
+                .get(pk)
+                .cloned()
+                .ok_or_else(|| eyre::eyre!("{kind} {pk} referenced by the snapshot does not exist"))
+        };
+        let codes = |pks: &[Pubkey]| {
+            pks.iter()
+                .map(|pk| code(&mgroups, "Multicast group", pk))
+                .collect::<eyre::Result<Vec<_>>>()
+        };
+
+        let mut access_passes = client
+            .list_accesspass(ListAccessPassCommand)?
+            .into_values()
+            .map(|accesspass| {
+                let tenant = match accesspass.tenant_allowlist.first() {
+                    Some(pk) if *pk != Pubkey::default() => Some(code(&tenants, "Tenant", pk)?),
+                    _ => None,
+                };
+                Ok(AccessPassRecord {
+                    allow_multiple_ip: accesspass.allow_multiple_ip(),
+                    accesspass_type: accesspass.accesspass_type,
+                    client_ip: accesspass.client_ip,
+                    user_payer: accesspass.user_payer,
+                    last_access_epoch: accesspass.last_access_epoch,
+                    tenant,
+                    max_unicast_users: accesspass.max_unicast_users,
+                    max_multicast_users: accesspass.max_multicast_users,
+                    bandwidth_tier: accesspass.bandwidth_tier,
+                    mgroup_pub_allowlist: codes(&accesspass.mgroup_pub_allowlist)?,
+                    mgroup_sub_allowlist: codes(&accesspass.mgroup_sub_allowlist)?,
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        access_passes.sort_by_key(|record| (record.user_payer, record.client_ip));
+
+        let mut users = client
+            .list_user(ListUserCommand)?
+            .into_values()
+            .map(|user| {
+                Ok(UserRecord {
+                    owner: user.owner,
+                    user_type: user.user_type,
+                    cyoa_type: user.cyoa_type,
+                    client_ip: user.client_ip,
+                    tunnel_endpoint: user.tunnel_endpoint,
+                    device: code(&devices, "Device", &user.device_pk)?,
+                    bandwidth_tier: user.bandwidth_tier,
+                    publishers: codes(&user.publishers)?,
+                    subscribers: codes(&user.subscribers)?,
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        users.sort_by_key(|record| (record.owner, record.client_ip, record.user_type.to_string()));
+
+        Ok(Snapshot {
+            version: SNAPSHOT_VERSION,
+            access_passes,
+            users,
+        })
+    }
+}
+
+/// Writes the access passes and users of the program to a JSON snapshot that
+/// `admin restore` can re-create them from.
+#[derive(Args, Debug)]
+pub struct AdminBackupCliCommand {
+    /// Path of the snapshot file to write
+    #[arg(long)]
+    pub to: String,
+}
+
+impl AdminBackupCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let snapshot = Snapshot::capture(client)?;
+        fs::write(&self.to, serde_json::to_string_pretty(&snapshot)?)?;
+        writeln!(
+            out,
+            "Wrote {} access pass(es) and {} user(s) to {}",
+            snapshot.access_passes.len(),
+            snapshot.users.len(),
+            self.to
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_serviceability::state::{
+        accesspass::{AccessPass, AccessPassStatus},
+        accounttype::AccountType,
+        device::Device,
+        multicastgroup::MulticastGroup,
+        user::{User, UserStatus},
+    };
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cli_admin_backup() {
+        let mut client = create_test_client();
+        let device_pk = Pubkey::new_unique();
+        let mgroup_pk = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        client
+            .expect_list_tenant()
+            .returning(|_| Ok(HashMap::new()));
+        client.expect_list_device().returning(move |_| {
+            Ok(HashMap::from([(
+                device_pk,
+                Device {
+                    account_type: AccountType::Device,
+                    code: "dev1".to_string(),
+                    ..Device::default()
+                },
+            )]))
+        });
+        client.expect_list_multicastgroup().returning(move |_| {
+            Ok(HashMap::from([(
+                mgroup_pk,
+                MulticastGroup {
+                    account_type: AccountType::MulticastGroup,
+                    code: "mg01".to_string(),
+                    ..MulticastGroup::default()
+                },
+            )]))
+        });
+        client.expect_list_accesspass().returning(move |_| {
+            Ok(HashMap::from([(
+                Pubkey::new_unique(),
+                AccessPass {
+                    account_type: AccountType::AccessPass,
+                    owner,
+                    bump_seed: 255,
+                    accesspass_type: AccessPassType::Prepaid,
+                    client_ip: [100, 0, 0, 1].into(),
+                    user_payer: owner,
+                    last_access_epoch: u64::MAX,
+                    connection_count: 1,
+                    status: AccessPassStatus::Connected,
+                    mgroup_pub_allowlist: vec![],
+                    mgroup_sub_allowlist: vec![mgroup_pk],
+                    flags: 0,
+                    tenant_allowlist: vec![],
+                    unicast_user_count: 0,
+                    max_unicast_users: 1,
+                    multicast_user_count: 1,
+                    max_multicast_users: 1,
+                    bandwidth_tier: BandwidthTier::Basic,
+                    referrer: Pubkey::default(),
+                },
+            )]))
+        });
+        client.expect_list_user().returning(move |_| {
+            Ok(HashMap::from([(
+                Pubkey::new_unique(),
+                User {
+                    account_type: AccountType::User,
+                    owner,
+                    index: 1,
+                    bump_seed: 255,
+                    user_type: UserType::Multicast,
+                    tenant_pk: Pubkey::default(),
+                    device_pk,
+                    cyoa_type: UserCYOA::GREOverDIA,
+                    client_ip: [100, 0, 0, 1].into(),
+                    dz_ip: [100, 0, 0, 1].into(),
+                    tunnel_id: 500,
+                    tunnel_net: "169.254.0.0/31".parse().unwrap(),
+                    status: UserStatus::Activated,
+                    publishers: vec![],
+                    subscribers: vec![mgroup_pk],
+                    validator_pubkey: Pubkey::default(),
+                    tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+                    tunnel_flags: 0,
+                    bgp_status: Default::default(),
+                    last_bgp_up_at: 0,
+                    last_bgp_reported_at: 0,
+                    bgp_rtt_ns: 0,
+                    feed_pk: Pubkey::default(),
+                    bandwidth_tier: BandwidthTier::Basic,
+                },
+            )]))
+        });
+
+        let dir = TempDir::new().unwrap();
+        let to = dir.path().join("backup.json").to_string_lossy().to_string();
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res =
+            block_on(AdminBackupCliCommand { to: to.clone() }.execute(&ctx, &client, &mut output));
+        assert!(res.is_ok());
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Wrote 1 access pass(es) and 1 user(s)"));
+
+        let snapshot: Snapshot = serde_json::from_str(&fs::read_to_string(&to).unwrap()).unwrap();
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(snapshot.access_passes[0].user_payer, owner);
+        assert_eq!(snapshot.access_passes[0].mgroup_sub_allowlist, vec!["mg01"]);
+        assert_eq!(snapshot.users[0].device, "dev1");
+        assert_eq!(snapshot.users[0].subscribers, vec!["mg01"]);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::doublezerocommand::CliCommand;
+use clap::{Args, ValueEnum};
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::migrateaccount::{
+    ListPendingMigrationsCommand, MigrateAccountCommand,
+};
+use doublezero_serviceability::state::accounttype::AccountType;
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MigratableAccountType {
+    GlobalState,
+    GlobalConfig,
+    Location,
+    Exchange,
+    Device,
+    Link,
+    User,
+    MulticastGroup,
+    Contributor,
+    AccessPass,
+    Tenant,
+}
+
+impl From<MigratableAccountType> for AccountType {
+    fn from(value: MigratableAccountType) -> Self {
+        match value {
+            MigratableAccountType::GlobalState => AccountType::GlobalState,
+            MigratableAccountType::GlobalConfig => AccountType::GlobalConfig,
+            MigratableAccountType::Location => AccountType::Location,
+            MigratableAccountType::Exchange => AccountType::Exchange,
+            MigratableAccountType::Device => AccountType::Device,
+            MigratableAccountType::Link => AccountType::Link,
+            MigratableAccountType::User => AccountType::User,
+            MigratableAccountType::MulticastGroup => AccountType::MulticastGroup,
+            MigratableAccountType::Contributor => AccountType::Contributor,
+            MigratableAccountType::AccessPass => AccountType::AccessPass,
+            MigratableAccountType::Tenant => AccountType::Tenant,
+        }
+    }
+}
+
+/// Walks every account of the given type and rewrites the ones not at the
+/// current layout. Honours the global `--dry-run` flag: prints the accounts
+/// that would be migrated without submitting transactions.
+#[derive(Args, Debug)]
+pub struct AdminMigrateCliCommand {
+    /// Account type to migrate
+    #[arg(long, value_enum)]
+    pub account_type: MigratableAccountType,
+}
+
+impl AdminMigrateCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let dry_run = client.is_dry_run();
+        let account_type = AccountType::from(self.account_type);
+        let label = self
+            .account_type
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_else(|| account_type.to_string());
+
+        let pending =
+            client.list_pending_migrations(ListPendingMigrationsCommand { account_type })?;
+
+        let mut migrated = 0u32;
+        let mut failed = 0u32;
+        for pubkey in &pending {
+            writeln!(out, "  [{label}] {pubkey} — would migrate")?;
+            if dry_run {
+                continue;
+            }
+            match client.migrate_account(MigrateAccountCommand { pubkey: *pubkey }) {
+                Ok(sig) => {
+                    migrated += 1;
+                    writeln!(out, "    migrated: {sig}")?;
+                }
+                Err(e) => {
+                    failed += 1;
+                    writeln!(out, "    WARNING: failed to migrate {pubkey}: {e}")?;
+                }
+            }
+        }
+
+        if dry_run {
+            writeln!(
+                out,
+                "{} {label} account(s) would be migrated",
+                pending.len()
+            )?;
+        } else {
+            writeln!(
+                out,
+                "{migrated} {label} account(s) migrated, {failed} failed"
+            )?;
+        }
+
+        if failed > 0 {
+            eyre::bail!("{failed} account(s) failed to migrate");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_admin_migrate() {
+        let mut client = create_test_client();
+        let (ok_pk, bad_pk) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        client.expect_is_dry_run().returning(|| false);
+        client
+            .expect_list_pending_migrations()
+            .with(predicate::eq(ListPendingMigrationsCommand {
+                account_type: AccountType::Device,
+            }))
+            .returning(move |_| Ok(vec![ok_pk, bad_pk]));
+        client
+            .expect_migrate_account()
+            .with(predicate::eq(MigrateAccountCommand { pubkey: ok_pk }))
+            .returning(|_| Ok(Signature::new_unique()));
+        client
+            .expect_migrate_account()
+            .with(predicate::eq(MigrateAccountCommand { pubkey: bad_pk }))
+            .returning(|_| Err(eyre::eyre!("boom")));
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(
+            AdminMigrateCliCommand {
+                account_type: MigratableAccountType::Device,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_err());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(&format!("WARNING: failed to migrate {bad_pk}: boom")));
+        assert!(output_str.contains("1 device account(s) migrated, 1 failed"));
+    }
+
+    #[test]
+    fn test_cli_admin_migrate_dry_run() {
+        let mut client = create_test_client();
+        let pubkey = Pubkey::new_unique();
+
+        client.expect_is_dry_run().returning(|| true);
+        client
+            .expect_list_pending_migrations()
+            .returning(move |_| Ok(vec![pubkey]));
+        client.expect_migrate_account().never();
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(
+            AdminMigrateCliCommand {
+                account_type: MigratableAccountType::Link,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(&format!("[link] {pubkey} — would migrate")));
+        assert!(output_str.contains("1 link account(s) would be migrated"));
+    }
+}
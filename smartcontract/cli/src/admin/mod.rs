@@ -0,0 +1,3 @@
+pub mod backup;
+pub mod migrate;
+pub mod restore;
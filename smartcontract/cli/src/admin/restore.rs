@@ -0,0 +1,683 @@
+use crate::{
+    admin::backup::{AccessPassRecord, Snapshot, UserRecord, SNAPSHOT_VERSION},
+    doublezerocommand::CliCommand,
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::{
+    accesspass::{list::ListAccessPassCommand, set::SetAccessPassCommand},
+    device::list::ListDeviceCommand,
+    multicastgroup::{
+        allowlist::{
+            publisher::add::AddMulticastGroupPubAllowlistCommand,
+            subscriber::add::AddMulticastGroupSubAllowlistCommand,
+        },
+        list::ListMulticastGroupCommand,
+    },
+    tenant::list::ListTenantCommand,
+    user::{create_subscribe::CreateSubscribeUserCommand, list::ListUserCommand},
+};
+use doublezero_serviceability::{
+    error::Validate,
+    pda::{get_tenant_pda, get_user_pda},
+    state::accesspass::{AccessPass, AccessPassType},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Write,
+    net::Ipv4Addr,
+};
+
+/// Re-creates access passes and multicast users from a snapshot written by
+/// `admin backup`, e.g. after redeploying the program in a test environment.
+///
+/// The whole snapshot is validated against the target program before anything
+/// is written, and the changes are printed as a diff first. Honours the global
+/// `--dry-run` flag: only the diff is printed.
+///
+/// A user can only be created on behalf of its owner together with a
+/// multicast group, so unicast users are reported as skipped; their owners
+/// re-create them on the next connect.
+#[derive(Args, Debug)]
+pub struct AdminRestoreCliCommand {
+    /// Snapshot file written by `admin backup`
+    #[arg(long)]
+    pub from: String,
+}
+
+enum Action {
+    SetAccessPass(SetAccessPassCommand),
+    AddPubAllowlist(AddMulticastGroupPubAllowlistCommand),
+    AddSubAllowlist(AddMulticastGroupSubAllowlistCommand),
+    CreateUser(CreateSubscribeUserCommand),
+}
+
+#[derive(Default)]
+struct Plan {
+    changes: Vec<(String, Action)>,
+    skipped: Vec<String>,
+    unchanged: usize,
+    errors: Vec<String>,
+}
+
+/// The target program's resources that snapshot codes resolve against.
+struct Target {
+    program_id: Pubkey,
+    epoch: u64,
+    tenants: HashSet<Pubkey>,
+    devices: HashMap<String, Pubkey>,
+    mgroups: HashMap<String, Pubkey>,
+    access_passes: HashMap<(Ipv4Addr, Pubkey), AccessPass>,
+    users: HashSet<Pubkey>,
+}
+
+impl AdminRestoreCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let snapshot: Snapshot = serde_json::from_str(&fs::read_to_string(&self.from)?)
+            .map_err(|e| eyre::eyre!("{} is not a valid snapshot: {e}", self.from))?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            eyre::bail!(
+                "Unsupported snapshot version {} (expected {SNAPSHOT_VERSION})",
+                snapshot.version
+            );
+        }
+
+        let target = Target {
+            program_id: client.get_program_id(),
+            epoch: client.get_epoch()?,
+            tenants: client
+                .list_tenant(ListTenantCommand {})?
+                .into_keys()
+                .collect(),
+            devices: client
+                .list_device(ListDeviceCommand)?
+                .into_iter()
+                .map(|(pk, device)| (device.code, pk))
+                .collect(),
+            mgroups: client
+                .list_multicastgroup(ListMulticastGroupCommand)?
+                .into_iter()
+                .map(|(pk, mgroup)| (mgroup.code, pk))
+                .collect(),
+            access_passes: client
+                .list_accesspass(ListAccessPassCommand)?
+                .into_values()
+                .map(|accesspass| ((accesspass.client_ip, accesspass.user_payer), accesspass))
+                .collect(),
+            users: client.list_user(ListUserCommand)?.into_keys().collect(),
+        };
+
+        let plan = plan(&snapshot, &target);
+        if !plan.errors.is_empty() {
+            for error in &plan.errors {
+                writeln!(out, "  error: {error}")?;
+            }
+            eyre::bail!(
+                "{} validation error(s) in {}; nothing was restored",
+                plan.errors.len(),
+                self.from
+            );
+        }
+
+        for (description, _) in &plan.changes {
+            writeln!(out, "  {description}")?;
+        }
+        for reason in &plan.skipped {
+            writeln!(out, "  ! {reason}")?;
+        }
+        writeln!(
+            out,
+            "{} change(s), {} unchanged, {} skipped",
+            plan.changes.len(),
+            plan.unchanged,
+            plan.skipped.len()
+        )?;
+
+        if client.is_dry_run() {
+            return Ok(());
+        }
+
+        let mut applied = 0u32;
+        let mut failed = 0u32;
+        for (description, action) in plan.changes {
+            let result: eyre::Result<Signature> = match action {
+                Action::SetAccessPass(cmd) => client.set_accesspass(cmd),
+                Action::AddPubAllowlist(cmd) => client.add_multicastgroup_pub_allowlist(cmd),
+                Action::AddSubAllowlist(cmd) => client.add_multicastgroup_sub_allowlist(cmd),
+                Action::CreateUser(cmd) => client.create_subscribe_user(cmd).map(|(sig, _)| sig),
+            };
+            match result {
+                Ok(_) => applied += 1,
+                Err(e) => {
+                    failed += 1;
+                    writeln!(out, "  WARNING: failed: {description}: {e}")?;
+                }
+            }
+        }
+        writeln!(out, "{applied} change(s) applied, {failed} failed")?;
+
+        if failed > 0 {
+            eyre::bail!("{failed} change(s) failed to restore");
+        }
+        Ok(())
+    }
+}
+
+fn plan(snapshot: &Snapshot, target: &Target) -> Plan {
+    let mut plan = Plan::default();
+
+    let mut seen = HashSet::new();
+    for record in &snapshot.access_passes {
+        let label = format!("access pass {} / {}", record.client_ip, record.user_payer);
+        if !seen.insert((record.client_ip, record.user_payer)) {
+            plan.errors.push(format!("{label} appears more than once"));
+            continue;
+        }
+        plan_access_pass(&mut plan, target, record, &label);
+    }
+
+    let mut seen = HashSet::new();
+    for record in &snapshot.users {
+        let label = format!(
+            "{} user {} ({})",
+            record.user_type, record.client_ip, record.owner
+        );
+        if !seen.insert((record.client_ip, record.user_type.to_string())) {
+            plan.errors.push(format!("{label} appears more than once"));
+            continue;
+        }
+        plan_user(&mut plan, snapshot, target, record, &label);
+    }
+
+    plan
+}
+
+fn plan_access_pass(plan: &mut Plan, target: &Target, record: &AccessPassRecord, label: &str) {
+    if let Err(e) = record.accesspass_type.validate() {
+        plan.errors.push(format!("{label}: {e}"));
+    }
+    if record.last_access_epoch != 0
+        && record.last_access_epoch != u64::MAX
+        && record.last_access_epoch < target.epoch
+    {
+        plan.errors.push(format!(
+            "{label}: expired in epoch {} (current epoch is {})",
+            record.last_access_epoch, target.epoch
+        ));
+    }
+    let tenant = match &record.tenant {
+        Some(code) => {
+            let (pk, _) = get_tenant_pda(&target.program_id, code);
+            if !target.tenants.contains(&pk) {
+                plan.errors
+                    .push(format!("{label}: tenant {code} does not exist"));
+            }
+            pk
+        }
+        None => Pubkey::default(),
+    };
+    let resolve = |plan: &mut Plan, codes: &[String]| -> Vec<(String, Pubkey)> {
+        codes
+            .iter()
+            .filter_map(|code| match target.mgroups.get(code) {
+                Some(pk) => Some((code.clone(), *pk)),
+                None => {
+                    plan.errors
+                        .push(format!("{label}: multicast group {code} does not exist"));
+                    None
+                }
+            })
+            .collect()
+    };
+    let pub_groups = resolve(plan, &record.mgroup_pub_allowlist);
+    let sub_groups = resolve(plan, &record.mgroup_sub_allowlist);
+
+    // EdgeSeat passes carry program-specific feed accounts and are provisioned by the oracle.
+    if matches!(record.accesspass_type, AccessPassType::EdgeSeat(_)) {
+        plan.skipped.push(format!(
+            "skip {label}: EdgeSeat passes are provisioned by the feed oracle"
+        ));
+        return;
+    }
+
+    let existing = target
+        .access_passes
+        .get(&(record.client_ip, record.user_payer));
+    let set = SetAccessPassCommand {
+        accesspass_type: record.accesspass_type.clone(),
+        client_ip: record.client_ip,
+        user_payer: record.user_payer,
+        last_access_epoch: record.last_access_epoch,
+        allow_multiple_ip: record.allow_multiple_ip,
+        tenant,
+        max_unicast_users: record.max_unicast_users,
+        max_multicast_users: record.max_multicast_users,
+        bandwidth_tier: Some(record.bandwidth_tier),
+        referrer: None,
+    };
+    match existing {
+        None => plan.changes.push((
+            format!("+ {label} ({})", record.accesspass_type),
+            Action::SetAccessPass(set),
+        )),
+        Some(accesspass) => {
+            let changed = changed_fields(accesspass, &set);
+            if changed.is_empty() {
+                plan.unchanged += 1;
+            } else {
+                plan.changes.push((
+                    format!("~ {label}: {}", changed.join(", ")),
+                    Action::SetAccessPass(set),
+                ));
+            }
+        }
+    }
+
+    for (code, pk) in pub_groups {
+        if existing.is_some_and(|ap| ap.mgroup_pub_allowlist.contains(&pk)) {
+            continue;
+        }
+        plan.changes.push((
+            format!("+ publisher allowlist {code} for {label}"),
+            Action::AddPubAllowlist(AddMulticastGroupPubAllowlistCommand {
+                pubkey_or_code: pk.to_string(),
+                client_ip: record.client_ip,
+                user_payer: record.user_payer,
+            }),
+        ));
+    }
+    for (code, pk) in sub_groups {
+        if existing.is_some_and(|ap| ap.mgroup_sub_allowlist.contains(&pk)) {
+            continue;
+        }
+        plan.changes.push((
+            format!("+ subscriber allowlist {code} for {label}"),
+            Action::AddSubAllowlist(AddMulticastGroupSubAllowlistCommand {
+                pubkey_or_code: pk.to_string(),
+                client_ip: record.client_ip,
+                user_payer: record.user_payer,
+            }),
+        ));
+    }
+}
+
+fn changed_fields(accesspass: &AccessPass, set: &SetAccessPassCommand) -> Vec<String> {
+    let epoch = |epoch: u64| match epoch {
+        u64::MAX => "max".to_string(),
+        epoch => epoch.to_string(),
+    };
+    let mut changed = Vec::new();
+    if accesspass.accesspass_type != set.accesspass_type {
+        changed.push(format!(
+            "type {} -> {}",
+            accesspass.accesspass_type, set.accesspass_type
+        ));
+    }
+    if accesspass.last_access_epoch != set.last_access_epoch {
+        changed.push(format!(
+            "last_access_epoch {} -> {}",
+            epoch(accesspass.last_access_epoch),
+            epoch(set.last_access_epoch)
+        ));
+    }
+    if accesspass.allow_multiple_ip() != set.allow_multiple_ip {
+        changed.push(format!(
+            "allow_multiple_ip {} -> {}",
+            accesspass.allow_multiple_ip(),
+            set.allow_multiple_ip
+        ));
+    }
+    let tenant = accesspass
+        .tenant_allowlist
+        .first()
+        .copied()
+        .unwrap_or_default();
+    if tenant != set.tenant {
+        changed.push(format!("tenant {tenant} -> {}", set.tenant));
+    }
+    if accesspass.max_unicast_users != set.max_unicast_users {
+        changed.push(format!(
+            "max_unicast_users {} -> {}",
+            accesspass.max_unicast_users, set.max_unicast_users
+        ));
+    }
+    if accesspass.max_multicast_users != set.max_multicast_users {
+        changed.push(format!(
+            "max_multicast_users {} -> {}",
+            accesspass.max_multicast_users, set.max_multicast_users
+        ));
+    }
+    if let Some(tier) = set.bandwidth_tier {
+        if accesspass.bandwidth_tier != tier {
+            changed.push(format!(
+                "bandwidth_tier {} -> {tier}",
+                accesspass.bandwidth_tier
+            ));
+        }
+    }
+    changed
+}
+
+fn plan_user(
+    plan: &mut Plan,
+    snapshot: &Snapshot,
+    target: &Target,
+    record: &UserRecord,
+    label: &str,
+) {
+    let device_pk = match target.devices.get(&record.device) {
+        Some(pk) => *pk,
+        None => {
+            plan.errors
+                .push(format!("{label}: device {} does not exist", record.device));
+            return;
+        }
+    };
+    for code in record.publishers.iter().chain(&record.subscribers) {
+        if !target.mgroups.contains_key(code) {
+            plan.errors
+                .push(format!("{label}: multicast group {code} does not exist"));
+        }
+    }
+
+    // Same lookup as user creation: a shared dynamic pass first, then the client IP's own.
+    let pass = [Ipv4Addr::UNSPECIFIED, record.client_ip]
+        .into_iter()
+        .find_map(|ip| {
+            snapshot
+                .access_passes
+                .iter()
+                .find(|ap| ap.client_ip == ip && ap.user_payer == record.owner)
+                .map(|ap| ap.accesspass_type.clone())
+                .or_else(|| {
+                    target
+                        .access_passes
+                        .get(&(ip, record.owner))
+                        .map(|ap| ap.accesspass_type.clone())
+                })
+        });
+    let Some(accesspass_type) = pass else {
+        plan.errors.push(format!(
+            "{label}: owner has no access pass for this client IP"
+        ));
+        return;
+    };
+
+    let (user_pk, _) = get_user_pda(&target.program_id, &record.client_ip, record.user_type);
+    if target.users.contains(&user_pk) {
+        plan.unchanged += 1;
+        return;
+    }
+
+    let Some(group) = record
+        .publishers
+        .first()
+        .or_else(|| record.subscribers.first())
+    else {
+        plan.skipped.push(format!(
+            "skip {label}: unicast users are re-created by their owner on connect"
+        ));
+        return;
+    };
+    if matches!(accesspass_type, AccessPassType::EdgeSeat(_)) {
+        plan.skipped.push(format!(
+            "skip {label}: EdgeSeat users connect through the feed gate"
+        ));
+        return;
+    }
+    let Some(mgroup_pk) = target.mgroups.get(group) else {
+        return;
+    };
+
+    let publisher = record.publishers.contains(group);
+    let subscriber = record.subscribers.contains(group);
+    let rest: HashSet<&String> = record
+        .publishers
+        .iter()
+        .chain(&record.subscribers)
+        .filter(|code| *code != group)
+        .collect();
+    let mut description = format!(
+        "+ {label} on {} as {} of {group}",
+        record.device,
+        match (publisher, subscriber) {
+            (true, true) => "publisher and subscriber",
+            (true, false) => "publisher",
+            _ => "subscriber",
+        }
+    );
+    if !rest.is_empty() {
+        description.push_str(&format!(
+            " ({} further group(s) to be re-joined by the owner)",
+            rest.len()
+        ));
+    }
+
+    plan.changes.push((
+        description,
+        Action::CreateUser(CreateSubscribeUserCommand {
+            user_type: record.user_type,
+            device_pk,
+            cyoa_type: record.cyoa_type,
+            client_ip: record.client_ip,
+            mgroup_pk: *mgroup_pk,
+            publisher,
+            subscriber,
+            tunnel_endpoint: record.tunnel_endpoint,
+            owner: Some(record.owner),
+            feed_pk: None,
+            bandwidth_tier: Some(record.bandwidth_tier),
+        }),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        device::Device,
+        multicastgroup::MulticastGroup,
+        user::{BandwidthTier, UserCYOA, UserType},
+    };
+    use mockall::predicate;
+    use tempfile::TempDir;
+
+    const DEVICE_PK: Pubkey = Pubkey::from_str_const("11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9");
+    const MGROUP_PK: Pubkey = Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+    const OWNER: Pubkey = Pubkey::from_str_const("1111111QLbz7JHiBTspS962RLKV8GndWFwiEaqKM");
+
+    fn user(user_type: UserType, subscribers: Vec<String>) -> UserRecord {
+        UserRecord {
+            owner: OWNER,
+            user_type,
+            cyoa_type: UserCYOA::GREOverDIA,
+            client_ip: [100, 0, 0, 1].into(),
+            tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+            device: "dev1".to_string(),
+            bandwidth_tier: BandwidthTier::Basic,
+            publishers: vec![],
+            subscribers,
+        }
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            access_passes: vec![AccessPassRecord {
+                accesspass_type: AccessPassType::Prepaid,
+                client_ip: [100, 0, 0, 1].into(),
+                user_payer: OWNER,
+                last_access_epoch: u64::MAX,
+                allow_multiple_ip: false,
+                tenant: None,
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: BandwidthTier::Basic,
+                mgroup_pub_allowlist: vec![],
+                mgroup_sub_allowlist: vec!["mg01".to_string()],
+            }],
+            users: vec![
+                user(UserType::Multicast, vec!["mg01".to_string()]),
+                user(UserType::IBRL, vec![]),
+            ],
+        }
+    }
+
+    fn write_snapshot(dir: &TempDir, snapshot: &Snapshot) -> String {
+        let path = dir.path().join("backup.json");
+        fs::write(&path, serde_json::to_string(snapshot).unwrap()).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn mock_target() -> crate::doublezerocommand::MockCliCommand {
+        let mut client = create_test_client();
+        client
+            .expect_list_tenant()
+            .returning(|_| Ok(HashMap::new()));
+        client.expect_list_device().returning(|_| {
+            Ok(HashMap::from([(
+                DEVICE_PK,
+                Device {
+                    account_type: AccountType::Device,
+                    code: "dev1".to_string(),
+                    ..Device::default()
+                },
+            )]))
+        });
+        client.expect_list_multicastgroup().returning(|_| {
+            Ok(HashMap::from([(
+                MGROUP_PK,
+                MulticastGroup {
+                    account_type: AccountType::MulticastGroup,
+                    code: "mg01".to_string(),
+                    ..MulticastGroup::default()
+                },
+            )]))
+        });
+        client
+            .expect_list_accesspass()
+            .returning(|_| Ok(HashMap::new()));
+        client.expect_list_user().returning(|_| Ok(HashMap::new()));
+        client
+    }
+
+    #[test]
+    fn test_cli_admin_restore_dry_run() {
+        let dir = TempDir::new().unwrap();
+        let from = write_snapshot(&dir, &snapshot());
+
+        let mut client = mock_target();
+        client.expect_is_dry_run().returning(|| true);
+        client.expect_set_accesspass().never();
+        client.expect_add_multicastgroup_sub_allowlist().never();
+        client.expect_create_subscribe_user().never();
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(AdminRestoreCliCommand { from }.execute(&ctx, &client, &mut output));
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains(&format!("+ access pass 100.0.0.1 / {OWNER} (prepaid)")));
+        assert!(output_str.contains("+ subscriber allowlist mg01 for access pass 100.0.0.1"));
+        assert!(output_str.contains(&format!(
+            "+ Multicast user 100.0.0.1 ({OWNER}) on dev1 as subscriber of mg01"
+        )));
+        assert!(output_str.contains("unicast users are re-created by their owner on connect"));
+        assert!(output_str.contains("3 change(s), 0 unchanged, 1 skipped"));
+    }
+
+    #[test]
+    fn test_cli_admin_restore() {
+        let dir = TempDir::new().unwrap();
+        let from = write_snapshot(&dir, &snapshot());
+
+        let mut client = mock_target();
+        client.expect_is_dry_run().returning(|| false);
+        client
+            .expect_set_accesspass()
+            .with(predicate::eq(SetAccessPassCommand {
+                accesspass_type: AccessPassType::Prepaid,
+                client_ip: [100, 0, 0, 1].into(),
+                user_payer: OWNER,
+                last_access_epoch: u64::MAX,
+                allow_multiple_ip: false,
+                tenant: Pubkey::default(),
+                max_unicast_users: 1,
+                max_multicast_users: 1,
+                bandwidth_tier: Some(BandwidthTier::Basic),
+                referrer: None,
+            }))
+            .times(1)
+            .returning(|_| Ok(Signature::new_unique()));
+        client
+            .expect_add_multicastgroup_sub_allowlist()
+            .with(predicate::eq(AddMulticastGroupSubAllowlistCommand {
+                pubkey_or_code: MGROUP_PK.to_string(),
+                client_ip: [100, 0, 0, 1].into(),
+                user_payer: OWNER,
+            }))
+            .times(1)
+            .returning(|_| Ok(Signature::new_unique()));
+        client
+            .expect_create_subscribe_user()
+            .with(predicate::eq(CreateSubscribeUserCommand {
+                user_type: UserType::Multicast,
+                device_pk: DEVICE_PK,
+                cyoa_type: UserCYOA::GREOverDIA,
+                client_ip: [100, 0, 0, 1].into(),
+                mgroup_pk: MGROUP_PK,
+                publisher: false,
+                subscriber: true,
+                tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+                owner: Some(OWNER),
+                feed_pk: None,
+                bandwidth_tier: Some(BandwidthTier::Basic),
+            }))
+            .times(1)
+            .returning(|_| Ok((Signature::new_unique(), Pubkey::new_unique())));
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(AdminRestoreCliCommand { from }.execute(&ctx, &client, &mut output));
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("3 change(s) applied, 0 failed"));
+    }
+
+    #[test]
+    fn test_cli_admin_restore_rejects_unknown_references() {
+        let mut snapshot = snapshot();
+        snapshot.access_passes[0].tenant = Some("acme".to_string());
+        snapshot.access_passes[0].last_access_epoch = 5;
+        snapshot.users[0].device = "dev9".to_string();
+        let dir = TempDir::new().unwrap();
+        let from = write_snapshot(&dir, &snapshot);
+
+        let mut client = mock_target();
+        client.expect_set_accesspass().never();
+        client.expect_add_multicastgroup_sub_allowlist().never();
+        client.expect_create_subscribe_user().never();
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(AdminRestoreCliCommand { from }.execute(&ctx, &client, &mut output));
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains("3 validation error(s)"));
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("tenant acme does not exist"));
+        assert!(output_str.contains("expired in epoch 5 (current epoch is 10)"));
+        assert!(output_str.contains("device dev9 does not exist"));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_sdk::commands::anycast_prefix::create::CreateAnycastPrefixCommand;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct CreateAnycastPrefixCliCommand {
+    /// Unique code of the service, e.g. dns or ntp
+    #[arg(long)]
+    pub code: String,
+    /// Service address, as a /32 (e.g. 100.64.255.53/32)
+    #[arg(long)]
+    pub prefix: NetworkV4,
+    /// Register the address without advertising it yet
+    #[arg(long, default_value_t = false)]
+    pub disabled: bool,
+}
+
+impl CreateAnycastPrefixCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        if self.prefix.prefix() != 32 {
+            return Err(eyre::eyre!(
+                "anycast prefix must be a single address (/32): {}",
+                self.prefix
+            ));
+        }
+
+        let (signature, _pubkey) = client.create_anycast_prefix(CreateAnycastPrefixCommand {
+            code: self.code,
+            prefix: self.prefix,
+            enabled: !self.disabled,
+        })?;
+
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        anycast_prefix::create::CreateAnycastPrefixCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::anycast_prefix::create::CreateAnycastPrefixCommand, get_anycast_prefix_pda,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_anycast_prefix_create() {
+        let mut client = create_test_client();
+
+        let program_id = Pubkey::from_str_const("GYhQDKuESrasNZGyhMJhGYFtbzNijYhcrN9poSqCQVah");
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&program_id, "dns");
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_create_anycast_prefix()
+            .with(predicate::eq(CreateAnycastPrefixCommand {
+                code: "dns".to_string(),
+                prefix: "100.64.255.53/32".parse().unwrap(),
+                enabled: true,
+            }))
+            .returning(move |_| Ok((signature, pda_pubkey)));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateAnycastPrefixCliCommand {
+                code: "dns".to_string(),
+                prefix: "100.64.255.53/32".parse().unwrap(),
+                disabled: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,"Signature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
+        );
+    }
+
+    #[test]
+    fn test_cli_anycast_prefix_create_rejects_non_host_prefix() {
+        let mut client = create_test_client();
+        client.expect_check_requirements().returning(|_| Ok(()));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateAnycastPrefixCliCommand {
+                code: "dns".to_string(),
+                prefix: "100.64.255.0/24".parse().unwrap(),
+                disabled: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_err());
+    }
+}
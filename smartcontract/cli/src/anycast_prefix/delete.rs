@@ -1,13 +1,17 @@
 use crate::doublezerocommand::CliCommand;
 use clap::Args;
 use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
-use doublezero_sdk::commands::globalstate::init::InitGlobalStateCommand;
+use doublezero_sdk::commands::anycast_prefix::delete::DeleteAnycastPrefixCommand;
 use std::io::Write;
 
 #[derive(Args, Debug)]
-pub struct InitCliCommand;
+pub struct DeleteAnycastPrefixCliCommand {
+    /// Code of the service to delete; devices withdraw its address
+    #[arg(long)]
+    pub code: String,
+}
 
-impl InitCliCommand {
+impl DeleteAnycastPrefixCliCommand {
     pub async fn execute<C: CliCommand, W: Write>(
         self,
         _ctx: &CliContext,
@@ -19,7 +23,9 @@ impl InitCliCommand {
             RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
         );
 
-        let signature = client.init_globalstate(InitGlobalStateCommand)?;
+        let signature =
+            client.delete_anycast_prefix(DeleteAnycastPrefixCommand { code: self.code })?;
+
         print_signature(out, &signature)
     }
 }
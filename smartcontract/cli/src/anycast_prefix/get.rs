@@ -0,0 +1,33 @@
+use crate::{anycast_prefix::list::AnycastPrefixDisplay, doublezerocommand::CliCommand};
+use clap::Args;
+use doublezero_cli_core::{render_record, CliContext, OutputFormat};
+use doublezero_sdk::commands::anycast_prefix::get::GetAnycastPrefixCommand;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct GetAnycastPrefixCliCommand {
+    /// Code of the service
+    #[arg(long)]
+    pub code: String,
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl GetAnycastPrefixCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (pubkey, anycast_prefix) =
+            client.get_anycast_prefix(GetAnycastPrefixCommand { code: self.code })?;
+
+        render_record(
+            out,
+            &AnycastPrefixDisplay::new(pubkey, anycast_prefix),
+            OutputFormat::from_flags(self.json, false),
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use doublezero_sdk::{commands::anycast_prefix::list::ListAnycastPrefixCommand, AnycastPrefix};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListAnycastPrefixCliCommand {
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct AnycastPrefixDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub account: Pubkey,
+    pub code: String,
+    pub prefix: String,
+    pub enabled: bool,
+}
+
+impl AnycastPrefixDisplay {
+    pub(crate) fn new(account: Pubkey, anycast_prefix: AnycastPrefix) -> Self {
+        AnycastPrefixDisplay {
+            account,
+            code: anycast_prefix.code,
+            prefix: anycast_prefix.prefix.to_string(),
+            enabled: anycast_prefix.enabled,
+        }
+    }
+}
+
+impl ListAnycastPrefixCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let mut prefixes: Vec<(Pubkey, AnycastPrefix)> = client
+            .list_anycast_prefix(ListAnycastPrefixCommand)?
+            .into_iter()
+            .collect();
+        prefixes.sort_by(|(_, a), (_, b)| a.code.cmp(&b.code));
+
+        let displays: Vec<AnycastPrefixDisplay> = prefixes
+            .into_iter()
+            .map(|(pubkey, anycast_prefix)| AnycastPrefixDisplay::new(pubkey, anycast_prefix))
+            .collect();
+
+        render_collection(
+            out,
+            displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        anycast_prefix::list::ListAnycastPrefixCliCommand, tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{AccountType, AnycastPrefix};
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_cli_anycast_prefix_list() {
+        let mut client = create_test_client();
+
+        let ntp_pubkey = Pubkey::from_str_const("11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo");
+        let dns_pubkey = Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+        let ntp = AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            code: "ntp".to_string(),
+            prefix: "100.64.255.123/32".parse().unwrap(),
+            enabled: false,
+            ..Default::default()
+        };
+        let dns = AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+            ..Default::default()
+        };
+        client.expect_list_anycast_prefix().returning(move |_| {
+            Ok(HashMap::from([
+                (ntp_pubkey, ntp.clone()),
+                (dns_pubkey, dns.clone()),
+            ]))
+        });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ListAnycastPrefixCliCommand {
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, "[{\"account\":\"11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V\",\"code\":\"dns\",\"prefix\":\"100.64.255.53/32\",\"enabled\":true},{\"account\":\"11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo\",\"code\":\"ntp\",\"prefix\":\"100.64.255.123/32\",\"enabled\":false}]\n");
+    }
+}
@@ -0,0 +1,41 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_sdk::commands::anycast_prefix::update::UpdateAnycastPrefixCommand;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct UpdateAnycastPrefixCliCommand {
+    /// Code of the service to update
+    #[arg(long)]
+    pub code: String,
+    /// New service address, as a /32
+    #[arg(long)]
+    pub prefix: Option<NetworkV4>,
+    /// Advertise (true) or withdraw (false) the address on every device
+    #[arg(long)]
+    pub enabled: Option<bool>,
+}
+
+impl UpdateAnycastPrefixCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let signature = client.update_anycast_prefix(UpdateAnycastPrefixCommand {
+            code: self.code,
+            prefix: self.prefix,
+            enabled: self.enabled,
+        })?;
+
+        print_signature(out, &signature)
+    }
+}
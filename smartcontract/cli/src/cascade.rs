@@ -0,0 +1,151 @@
+use crate::doublezerocommand::CliCommand;
+use doublezero_sdk::{
+    cascade::{CascadePreview, CascadeSnapshot, DeleteTarget},
+    commands::{
+        accesspass::list::ListAccessPassCommand, contributor::list::ListContributorCommand,
+        device::list::ListDeviceCommand, exchange::list::ListExchangeCommand,
+        link::list::ListLinkCommand, location::list::ListLocationCommand,
+        multicastgroup::list::ListMulticastGroupCommand, tenant::list::ListTenantCommand,
+        user::list::ListUserCommand,
+    },
+};
+use std::io::Write;
+
+/// Load every account that can block a deletion.
+pub fn load_cascade_snapshot<C: CliCommand>(client: &C) -> eyre::Result<CascadeSnapshot> {
+    Ok(CascadeSnapshot {
+        contributors: client.list_contributor(ListContributorCommand {})?,
+        locations: client.list_location(ListLocationCommand)?,
+        exchanges: client.list_exchange(ListExchangeCommand)?,
+        devices: client.list_device(ListDeviceCommand)?,
+        links: client.list_link(ListLinkCommand)?,
+        users: client.list_user(ListUserCommand)?,
+        multicast_groups: client.list_multicastgroup(ListMulticastGroupCommand)?,
+        tenants: client.list_tenant(ListTenantCommand {})?,
+        access_passes: client.list_accesspass(ListAccessPassCommand)?,
+    })
+}
+
+/// Print what blocks deleting `target` and the steps to unblock it, for the
+/// `--cascade-preview` flag of the delete commands. Nothing is sent.
+pub fn print_cascade_preview<C: CliCommand, W: Write>(
+    client: &C,
+    target: DeleteTarget,
+    out: &mut W,
+) -> eyre::Result<()> {
+    let preview = load_cascade_snapshot(client)?.preview(target)?;
+    write_cascade_preview(&preview, out)
+}
+
+pub fn write_cascade_preview<W: Write>(preview: &CascadePreview, out: &mut W) -> eyre::Result<()> {
+    let target = &preview.target_label;
+
+    if !preview.is_blocked() {
+        writeln!(out, "Nothing blocks deleting {target}.")?;
+        return Ok(());
+    }
+
+    if !preview.blockers.is_empty() {
+        writeln!(
+            out,
+            "Deleting {target} is blocked by {} reference(s):",
+            preview.blockers.len()
+        )?;
+        for blocker in &preview.blockers {
+            writeln!(out, "  - {blocker}")?;
+        }
+    }
+    if preview.unexplained_references > 0 {
+        writeln!(
+            out,
+            "Warning: {} reference(s) to {target} come from accounts this preview does not track; the deletion fails until they are released.",
+            preview.unexplained_references
+        )?;
+    }
+
+    writeln!(out, "Steps, in order:")?;
+    for (i, step) in preview.steps.iter().enumerate() {
+        writeln!(out, "  {}. {step}", i + 1)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_sdk::{AccountType, Device, DeviceStatus, Link, LinkStatus};
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_print_cascade_preview() {
+        let mut client = create_test_client();
+
+        let [d1, d2, l1] = std::array::from_fn(|_| Pubkey::new_unique());
+        let device = |code: &str| Device {
+            account_type: AccountType::Device,
+            code: code.to_string(),
+            reference_count: 1,
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        };
+        let link = Link {
+            account_type: AccountType::Link,
+            code: "dz1:dz2".to_string(),
+            side_a_pk: d1,
+            side_z_pk: d2,
+            status: LinkStatus::Activated,
+            ..Default::default()
+        };
+        let devices = HashMap::from([(d1, device("dz1")), (d2, device("dz2"))]);
+
+        client
+            .expect_list_contributor()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_location()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_exchange()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_device()
+            .returning(move |_| Ok(devices.clone()));
+        client
+            .expect_list_link()
+            .returning(move |_| Ok(HashMap::from([(l1, link.clone())])));
+        client.expect_list_user().returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_multicastgroup()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_tenant()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_accesspass()
+            .returning(|_| Ok(HashMap::new()));
+
+        let mut output = Vec::new();
+        print_cascade_preview(&client, DeleteTarget::Device(d1), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "Deleting device dz1 ({d1}) is blocked by 1 reference(s):\n  \
+                 - delete link dz1:dz2 ({l1})\n\
+                 Steps, in order:\n  \
+                 1. drain link dz1:dz2 ({l1}) (activated; set it to soft-drained or hard-drained)\n  \
+                 2. delete link dz1:dz2 ({l1})\n  \
+                 3. delete device dz1 ({d1})\n"
+            )
+        );
+
+        let mut output = Vec::new();
+        assert!(print_cascade_preview(
+            &client,
+            DeleteTarget::Link(Pubkey::new_unique()),
+            &mut output,
+        )
+        .is_err());
+    }
+}
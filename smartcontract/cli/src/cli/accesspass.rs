@@ -1,6 +1,7 @@
 use crate::accesspass::{
     close::CloseAccessPassCliCommand, fund::FundAccessPassCliCommand, get::GetAccessPassCliCommand,
-    list::ListAccessPassCliCommand, set::SetAccessPassCliCommand,
+    list::ListAccessPassCliCommand, referrals::ReferralsAccessPassCliCommand,
+    set::SetAccessPassCliCommand, settle::SettleAccessPassCliCommand,
     user_balances::UserBalancesAccessPassCliCommand,
 };
 use clap::{Args, Subcommand};
@@ -19,6 +20,9 @@ pub enum AccessPassCommands {
     /// Close access pass
     #[clap()]
     Close(CloseAccessPassCliCommand),
+    /// Burn the deposit of an expired leased access pass
+    #[clap()]
+    Settle(SettleAccessPassCliCommand),
     /// List access passes
     #[clap()]
     List(ListAccessPassCliCommand),
@@ -31,4 +35,7 @@ pub enum AccessPassCommands {
     /// Fund user payers that have insufficient balance
     #[clap()]
     Fund(FundAccessPassCliCommand),
+    /// List referrers and the access passes credited to them
+    #[clap()]
+    Referrals(ReferralsAccessPassCliCommand),
 }
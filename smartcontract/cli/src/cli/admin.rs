@@ -0,0 +1,21 @@
+use clap::{Args, Subcommand};
+
+use crate::admin::{
+    backup::AdminBackupCliCommand, migrate::AdminMigrateCliCommand, restore::AdminRestoreCliCommand,
+};
+
+#[derive(Args, Debug)]
+pub struct AdminCliCommand {
+    #[command(subcommand)]
+    pub command: AdminCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AdminCommands {
+    /// Upgrade accounts of one type to the current layout after a program upgrade
+    Migrate(AdminMigrateCliCommand),
+    /// Write access passes and users to a JSON snapshot
+    Backup(AdminBackupCliCommand),
+    /// Re-create access passes and multicast users from a snapshot
+    Restore(AdminRestoreCliCommand),
+}
@@ -0,0 +1,28 @@
+use clap::{Args, Subcommand};
+
+use crate::anycast_prefix::{create::*, delete::*, get::*, list::*, update::*};
+
+#[derive(Args, Debug)]
+pub struct AnycastPrefixCliCommand {
+    #[command(subcommand)]
+    pub command: AnycastPrefixCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AnycastPrefixCommands {
+    /// Register an anycast service address advertised by every device
+    #[clap()]
+    Create(CreateAnycastPrefixCliCommand),
+    /// Update an anycast service address
+    #[clap()]
+    Update(UpdateAnycastPrefixCliCommand),
+    /// List all anycast service addresses
+    #[clap()]
+    List(ListAnycastPrefixCliCommand),
+    /// Get an anycast service address
+    #[clap()]
+    Get(GetAnycastPrefixCliCommand),
+    /// Delete an anycast service address
+    #[clap()]
+    Delete(DeleteAnycastPrefixCliCommand),
+}
@@ -16,21 +16,31 @@ use crate::{
     balance::BalanceCliCommand,
     cli::{
         accesspass::{AccessPassCliCommand, AccessPassCommands},
-        config::{ConfigCliCommand, ConfigCommands},
-        contributor::{ContributorCliCommand, ContributorCommands},
+        admin::{AdminCliCommand, AdminCommands},
+        anycast_prefix::{AnycastPrefixCliCommand, AnycastPrefixCommands},
+        config::{ConfigCliCommand, ConfigCommands, LabelCommands},
+        contributor::{
+            ApiKeyCommands, ContributorCliCommand, ContributorCommands, IpAllocationCommands,
+        },
         device::{DeviceCliCommand, DeviceCommands, InterfaceCommands},
+        env::{EnvCliCommand, EnvCommands},
         exchange::{ExchangeCliCommand, ExchangeCommands},
         feed::{FeedCliCommand, FeedCommands},
         globalconfig::{
             AirdropCommands, AuthorityCommands, FeatureFlagsCommands, FoundationAllowlistCommands,
-            GlobalConfigCliCommand, GlobalConfigCommands, QaAllowlistCommands,
+            GlobalConfigCliCommand, GlobalConfigCommands, PauseCommands, QaAllowlistCommands,
         },
         link::{CreateLinkCommands, LinkCliCommand, LinkCommands, TopologyCommands},
         location::{LocationCliCommand, LocationCommands},
         migrate::{MigrateCliCommand, MigrateCommands},
         permission::{PermissionCliCommand, PermissionCommands},
+        qos_profile::{QosProfileCliCommand, QosProfileCommands},
         resource::{ResourceCliCommand, ResourceCommands},
+        scheduler::{SchedulerCliCommand, SchedulerCommands},
+        state_commitment::{StateCommitmentCliCommand, StateCommitmentCommands},
+        telemetry::{TelemetryCliCommand, TelemetryCommands},
         tenant::{AdministratorCommands, TenantCliCommand, TenantCommands},
+        topology::{NetworkTopologyCommands, TopologyCliCommand},
         user::{UserCliCommand, UserCommands},
     },
     doublezerocommand::CliCommand,
@@ -44,10 +54,12 @@ use crate::{
 
 #[derive(Subcommand, Debug)]
 pub enum ServiceabilityCommand {
-    #[command(hide = true)]
+    /// Onboard a new contributor (`init contributor`); bare `init` creates the global state
     Init(InitCliCommand),
     #[command(hide = true)]
     Migrate(MigrateCliCommand),
+    #[command(hide = true)]
+    Admin(AdminCliCommand),
 
     /// Get your public key
     Address(AddressCliCommand),
@@ -56,6 +68,8 @@ pub enum ServiceabilityCommand {
 
     /// local configuration
     Config(ConfigCliCommand),
+    /// List environments and probe their endpoints and programs
+    Env(EnvCliCommand),
     /// Global network configuration
     GlobalConfig(GlobalConfigCliCommand),
 
@@ -79,14 +93,26 @@ pub enum ServiceabilityCommand {
     AccessPass(AccessPassCliCommand),
     /// Manage users
     User(UserCliCommand),
+    /// Manage per-tier QoS profiles
+    QosProfile(QosProfileCliCommand),
+    /// Manage anycast service addresses advertised by every device
+    AnycastPrefix(AnycastPrefixCliCommand),
 
     /// Export all data to files
     Export(ExportCliCommand),
+    /// Merkle root commitments over all accounts, for light clients and auditors
+    StateCommitment(StateCommitmentCliCommand),
+    /// Execution receipts recorded by offchain schedulers
+    Scheduler(SchedulerCliCommand),
     /// Create a new user identity
     Keygen(KeyGenCliCommand),
 
     /// IP/ID Resource Management
     Resource(ResourceCliCommand),
+    /// Telemetry samples account management
+    Telemetry(TelemetryCliCommand),
+    /// Inspect the network topology
+    Topology(TopologyCliCommand),
 
     /// Print version information
     Version(VersionCliCommand),
@@ -119,6 +145,11 @@ impl ServiceabilityCommand {
                 MigrateCommands::UserPda(cmd) => cmd.execute(ctx, client, out).await,
                 MigrateCommands::FlexAlgo(cmd) => cmd.execute(ctx, client, out).await,
             },
+            Self::Admin(args) => match args.command {
+                AdminCommands::Migrate(cmd) => cmd.execute(ctx, client, out).await,
+                AdminCommands::Backup(cmd) => cmd.execute(ctx, client, out).await,
+                AdminCommands::Restore(cmd) => cmd.execute(ctx, client, out).await,
+            },
             Self::Address(args) => args.execute(ctx, client, out).await,
             Self::Balance(args) => args.execute(ctx, client, out).await,
             Self::Export(args) => args.execute(ctx, client, out).await,
@@ -127,6 +158,15 @@ impl ServiceabilityCommand {
             Self::Config(cmd) => match cmd.command {
                 ConfigCommands::Get(args) => args.execute(ctx, client, out).await,
                 ConfigCommands::Set(args) => args.execute(ctx, client, out).await,
+                ConfigCommands::Label(c) => match c.command {
+                    LabelCommands::Set(args) => args.execute(ctx, client, out).await,
+                    LabelCommands::Remove(args) => args.execute(ctx, client, out).await,
+                    LabelCommands::List(args) => args.execute(ctx, client, out).await,
+                },
+            },
+            Self::Env(cmd) => match cmd.command {
+                EnvCommands::List(args) => args.execute(ctx, client, out).await,
+                EnvCommands::Show(args) => args.execute(ctx, client, out).await,
             },
             Self::GlobalConfig(cmd) => match cmd.command {
                 GlobalConfigCommands::Set(args) => args.execute(ctx, client, out).await,
@@ -156,6 +196,10 @@ impl ServiceabilityCommand {
                     FeatureFlagsCommands::Get(args) => args.execute(ctx, client, out).await,
                     FeatureFlagsCommands::Set(args) => args.execute(ctx, client, out).await,
                 },
+                GlobalConfigCommands::Pause(c) => match c.command {
+                    PauseCommands::Get(args) => args.execute(ctx, client, out).await,
+                    PauseCommands::Set(args) => args.execute(ctx, client, out).await,
+                },
             },
 
             Self::Location(cmd) => match cmd.command {
@@ -180,12 +224,46 @@ impl ServiceabilityCommand {
                 FeedCommands::Get(args) => args.execute(ctx, client, out).await,
                 FeedCommands::Delete(args) => args.execute(ctx, client, out).await,
             },
+            Self::QosProfile(cmd) => match cmd.command {
+                QosProfileCommands::Create(args) => args.execute(ctx, client, out).await,
+                QosProfileCommands::Update(args) => args.execute(ctx, client, out).await,
+                QosProfileCommands::List(args) => args.execute(ctx, client, out).await,
+                QosProfileCommands::Get(args) => args.execute(ctx, client, out).await,
+                QosProfileCommands::Delete(args) => args.execute(ctx, client, out).await,
+            },
+            Self::StateCommitment(cmd) => match cmd.command {
+                StateCommitmentCommands::Record(args) => args.execute(ctx, client, out).await,
+                StateCommitmentCommands::Get(args) => args.execute(ctx, client, out).await,
+                StateCommitmentCommands::Verify(args) => args.execute(ctx, client, out).await,
+            },
+            Self::Scheduler(cmd) => match cmd.command {
+                SchedulerCommands::History(args) => args.execute(ctx, client, out).await,
+            },
+            Self::AnycastPrefix(cmd) => match cmd.command {
+                AnycastPrefixCommands::Create(args) => args.execute(ctx, client, out).await,
+                AnycastPrefixCommands::Update(args) => args.execute(ctx, client, out).await,
+                AnycastPrefixCommands::List(args) => args.execute(ctx, client, out).await,
+                AnycastPrefixCommands::Get(args) => args.execute(ctx, client, out).await,
+                AnycastPrefixCommands::Delete(args) => args.execute(ctx, client, out).await,
+            },
             Self::Contributor(cmd) => match cmd.command {
                 ContributorCommands::Create(args) => args.execute(ctx, client, out).await,
                 ContributorCommands::Update(args) => args.execute(ctx, client, out).await,
                 ContributorCommands::List(args) => args.execute(ctx, client, out).await,
                 ContributorCommands::Get(args) => args.execute(ctx, client, out).await,
                 ContributorCommands::Delete(args) => args.execute(ctx, client, out).await,
+                ContributorCommands::ApiKey(c) => match c.command {
+                    ApiKeyCommands::Create(args) => args.execute(ctx, client, out).await,
+                    ApiKeyCommands::List(args) => args.execute(ctx, client, out).await,
+                    ApiKeyCommands::Revoke(args) => args.execute(ctx, client, out).await,
+                    ApiKeyCommands::Delete(args) => args.execute(ctx, client, out).await,
+                },
+                ContributorCommands::IpAllocation(c) => match c.command {
+                    IpAllocationCommands::Create(args) => args.execute(ctx, client, out).await,
+                    IpAllocationCommands::List(args) => args.execute(ctx, client, out).await,
+                    IpAllocationCommands::SetStatus(args) => args.execute(ctx, client, out).await,
+                    IpAllocationCommands::Delete(args) => args.execute(ctx, client, out).await,
+                },
             },
             Self::Permission(cmd) => match cmd.command {
                 PermissionCommands::Set(args) => args.execute(ctx, client, out).await,
@@ -216,11 +294,24 @@ impl ServiceabilityCommand {
                 DeviceCommands::Interface(c) => match c.command {
                     InterfaceCommands::Create(args) => args.execute(ctx, client, out).await,
                     InterfaceCommands::Update(args) => args.execute(ctx, client, out).await,
+                    InterfaceCommands::Apply(args) => args.execute(ctx, client, out).await,
                     InterfaceCommands::List(args) => args.execute(ctx, client, out).await,
                     InterfaceCommands::Get(args) => args.execute(ctx, client, out).await,
+                    InterfaceCommands::Status(args) => args.execute(ctx, client, out).await,
                     InterfaceCommands::Delete(args) => args.execute(ctx, client, out).await,
                 },
+                DeviceCommands::Diff(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::RotateMetricsPublisher(args) => {
+                    args.execute(ctx, client, out).await
+                }
+                DeviceCommands::Challenge(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::ProveKey(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::Lock(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::Unlock(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::GrantCoOwner(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::RevokeCoOwner(args) => args.execute(ctx, client, out).await,
                 DeviceCommands::SetHealth(args) => args.execute(ctx, client, out).await,
+                DeviceCommands::AckConfig(args) => args.execute(ctx, client, out).await,
                 DeviceCommands::MigrateMulticastCounts(args) => {
                     args.execute(ctx, client, out).await
                 }
@@ -236,7 +327,10 @@ impl ServiceabilityCommand {
                 LinkCommands::List(args) => args.execute(ctx, client, out).await,
                 LinkCommands::Get(args) => args.execute(ctx, client, out).await,
                 LinkCommands::Latency(args) => args.execute(ctx, client, out).await,
+                LinkCommands::BurnIn(args) => args.execute(ctx, client, out).await,
                 LinkCommands::Delete(args) => args.execute(ctx, client, out).await,
+                LinkCommands::Lock(args) => args.execute(ctx, client, out).await,
+                LinkCommands::Unlock(args) => args.execute(ctx, client, out).await,
                 LinkCommands::SetHealth(args) => args.execute(ctx, client, out).await,
                 LinkCommands::Topology(t) => match t.command {
                     TopologyCommands::Create(args) => args.execute(ctx, client, out).await,
@@ -251,6 +345,7 @@ impl ServiceabilityCommand {
             Self::AccessPass(cmd) => match cmd.command {
                 AccessPassCommands::Set(args) => args.execute(ctx, client, out).await,
                 AccessPassCommands::Close(args) => args.execute(ctx, client, out).await,
+                AccessPassCommands::Settle(args) => args.execute(ctx, client, out).await,
                 AccessPassCommands::List(args) => args.execute(ctx, client, out).await,
                 AccessPassCommands::Get(args) => args.execute(ctx, client, out).await,
                 AccessPassCommands::UserBalances(args) => args.execute(ctx, client, out).await,
@@ -258,6 +353,7 @@ impl ServiceabilityCommand {
                     args.execute(ctx, client, out, &mut std::io::stdin().lock())
                         .await
                 }
+                AccessPassCommands::Referrals(args) => args.execute(ctx, client, out).await,
             },
             Self::User(cmd) => match cmd.command {
                 UserCommands::Create(args) => args.execute(ctx, client, out).await,
@@ -274,9 +370,18 @@ impl ServiceabilityCommand {
                 ResourceCommands::Create(args) => args.execute(ctx, client, out).await,
                 ResourceCommands::Deallocate(args) => args.execute(ctx, client, out).await,
                 ResourceCommands::Get(args) => args.execute(ctx, client, out).await,
+                ResourceCommands::Resize(args) => args.execute(ctx, client, out).await,
                 ResourceCommands::Close(args) => args.execute(ctx, client, out).await,
                 ResourceCommands::Verify(args) => args.execute(ctx, client, out).await,
             },
+            Self::Telemetry(cmd) => match cmd.command {
+                TelemetryCommands::Prune(args) => args.execute(ctx, client, out).await,
+                TelemetryCommands::Reclaim(args) => args.execute(ctx, client, out).await,
+                TelemetryCommands::SetBudget(args) => args.execute(ctx, client, out).await,
+            },
+            Self::Topology(cmd) => match cmd.command {
+                NetworkTopologyCommands::Map(args) => args.execute(ctx, client, out).await,
+            },
 
             Self::Version(args) => args.execute(ctx, client, out).await,
             Self::Account(args) => args.execute(ctx, client, out).await,
@@ -300,7 +405,20 @@ mod tests {
     //! The tests cover parse-time routing only - they do not invoke `execute`.
     //! Per-verb behavior is covered by inline tests next to each leaf command.
     use super::*;
-    use crate::cli::{device::InterfaceCliCommand, link::CreateLinkCommand};
+    use crate::{
+        accesspass::set::SetAccessPassCliCommand,
+        admin::{
+            migrate::{AdminMigrateCliCommand, MigratableAccountType},
+            restore::AdminRestoreCliCommand,
+        },
+        cli::{
+            contributor::{ApiKeyCliCommand, IpAllocationCliCommand},
+            device::InterfaceCliCommand,
+            link::CreateLinkCommand,
+        },
+        init::InitCommands,
+        resource::verify::VerifyResourceCliCommand,
+    };
     use clap::Parser;
 
     #[derive(Parser, Debug)]
@@ -347,6 +465,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parses_contributor_api_key_create() {
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "contributor",
+            "api-key",
+            "create",
+            "--contributor",
+            TEST_PUBKEY,
+            "--expires-in-days",
+            "30",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Contributor(ContributorCliCommand {
+                command: ContributorCommands::ApiKey(ApiKeyCliCommand {
+                    command: ApiKeyCommands::Create(_),
+                }),
+            })
+        ));
+    }
+
+    #[test]
+    fn parses_contributor_ip_allocation_set_status() {
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "contributor",
+            "ip-allocation",
+            "set-status",
+            "--prefix",
+            "45.76.12.0/24",
+            "--status",
+            "verified",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Contributor(ContributorCliCommand {
+                command: ContributorCommands::IpAllocation(IpAllocationCliCommand {
+                    command: IpAllocationCommands::SetStatus(_),
+                }),
+            })
+        ));
+    }
+
     #[test]
     fn parses_link_create_wan() {
         let parsed = TestCli::try_parse_from([
@@ -395,6 +559,146 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parses_access_pass_set_from_csv() {
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "access-pass",
+            "set",
+            "--from-csv",
+            "passes.csv",
+            "--output",
+            "results.csv",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::AccessPass(AccessPassCliCommand {
+                command: AccessPassCommands::Set(SetAccessPassCliCommand {
+                    user_payer: None,
+                    ref from_csv,
+                    ref output,
+                    ..
+                }),
+            }) if from_csv.as_deref() == Some("passes.csv")
+                && output.as_deref() == Some("results.csv")
+        ));
+
+        // Per-pass flags come from the CSV rows, and a single pass still needs a payer.
+        assert!(TestCli::try_parse_from([
+            "test",
+            "access-pass",
+            "set",
+            "--from-csv",
+            "passes.csv",
+            "--user-payer",
+            "me",
+        ])
+        .is_err());
+        assert!(TestCli::try_parse_from(["test", "access-pass", "set"]).is_err());
+    }
+
+    #[test]
+    fn parses_device_diff_password_file() {
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "device",
+            "diff",
+            "--device",
+            "dz1",
+            "--controller",
+            "http://controller:7000",
+            "--eapi-url",
+            "https://10.0.0.1/command-api",
+            "--password-file",
+            "eapi-password",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Device(DeviceCliCommand {
+                command: DeviceCommands::Diff(crate::device::diff::DiffDeviceConfigCliCommand {
+                    ref code,
+                    ref password_file,
+                    ..
+                }),
+            }) if code == "dz1"
+                && password_file.as_deref() == Some(std::path::Path::new("eapi-password"))
+        ));
+
+        // The password is only used to log in to eAPI.
+        assert!(TestCli::try_parse_from([
+            "test",
+            "device",
+            "diff",
+            "--code",
+            "dz1",
+            "--controller",
+            "http://controller:7000",
+            "--password-file",
+            "eapi-password",
+        ])
+        .is_err());
+
+        // The device is a named flag, like `device get`.
+        assert!(TestCli::try_parse_from([
+            "test",
+            "device",
+            "diff",
+            "dz1",
+            "--controller",
+            "http://controller:7000",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parses_config_label_named_flags() {
+        use crate::cli::config::{ConfigCliCommand, ConfigCommands, LabelCommands};
+
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "config",
+            "label",
+            "set",
+            "--pubkey",
+            TEST_PUBKEY,
+            "--label",
+            "ops",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Config(ConfigCliCommand {
+                command: ConfigCommands::Label(crate::cli::config::LabelCliCommand {
+                    command: LabelCommands::Set(crate::config::label::set::SetLabelCliCommand {
+                        ref pubkey,
+                        ref label,
+                    }),
+                }),
+            }) if pubkey == TEST_PUBKEY && label == "ops"
+        ));
+        assert!(
+            TestCli::try_parse_from(["test", "config", "label", "set", TEST_PUBKEY, "ops"])
+                .is_err()
+        );
+
+        let parsed =
+            TestCli::try_parse_from(["test", "config", "label", "remove", "--pubkey", TEST_PUBKEY])
+                .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Config(ConfigCliCommand {
+                command: ConfigCommands::Label(crate::cli::config::LabelCliCommand {
+                    command: LabelCommands::Remove(_),
+                }),
+            })
+        ));
+        assert!(
+            TestCli::try_parse_from(["test", "config", "label", "remove", TEST_PUBKEY]).is_err()
+        );
+    }
+
     #[test]
     fn parses_resource_verify() {
         let parsed = TestCli::try_parse_from(["test", "resource", "verify"]).unwrap();
@@ -406,6 +710,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parses_resource_audit_alias() {
+        let parsed = TestCli::try_parse_from(["test", "resource", "audit", "--fix"]).unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Resource(ResourceCliCommand {
+                command: ResourceCommands::Verify(VerifyResourceCliCommand { fix: true }),
+            })
+        ));
+    }
+
     #[test]
     fn parses_permission_audit() {
         let parsed = TestCli::try_parse_from(["test", "permission", "audit"]).unwrap();
@@ -417,14 +732,38 @@ mod tests {
         ));
     }
 
-    // `hide = true` must not gate parsing - operators and automation rely on
-    // these verbs being reachable even though they do not appear in --help.
     #[test]
-    fn parses_hidden_init() {
+    fn parses_bare_init() {
         let parsed = TestCli::try_parse_from(["test", "init"]).unwrap();
-        assert!(matches!(parsed.command, ServiceabilityCommand::Init(_)));
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Init(InitCliCommand { command: None })
+        ));
     }
 
+    #[test]
+    fn parses_init_contributor() {
+        let parsed = TestCli::try_parse_from([
+            "test",
+            "init",
+            "contributor",
+            "--env",
+            "devnet",
+            "--code",
+            "acme",
+            "--yes",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Init(InitCliCommand {
+                command: Some(InitCommands::Contributor(_)),
+            })
+        ));
+    }
+
+    // `hide = true` must not gate parsing - operators and automation rely on
+    // these verbs being reachable even though they do not appear in --help.
     // Bare `migrate` now requires a subcommand; assert the two leaves route.
     #[test]
     fn parses_hidden_migrate_user_pda() {
@@ -439,8 +778,7 @@ mod tests {
 
     #[test]
     fn parses_hidden_migrate_flex_algo() {
-        let parsed =
-            TestCli::try_parse_from(["test", "migrate", "flex-algo", "--dry-run"]).unwrap();
+        let parsed = TestCli::try_parse_from(["test", "migrate", "flex-algo"]).unwrap();
         assert!(matches!(
             parsed.command,
             ServiceabilityCommand::Migrate(MigrateCliCommand {
@@ -449,6 +787,33 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parses_hidden_admin_migrate() {
+        let parsed =
+            TestCli::try_parse_from(["test", "admin", "migrate", "--account-type", "device"])
+                .unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Admin(AdminCliCommand {
+                command: AdminCommands::Migrate(AdminMigrateCliCommand {
+                    account_type: MigratableAccountType::Device,
+                }),
+            })
+        ));
+    }
+
+    #[test]
+    fn parses_hidden_admin_restore() {
+        let parsed =
+            TestCli::try_parse_from(["test", "admin", "restore", "--from", "backup.json"]).unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Admin(AdminCliCommand {
+                command: AdminCommands::Restore(AdminRestoreCliCommand { ref from }),
+            }) if from == "backup.json"
+        ));
+    }
+
     #[test]
     fn parses_hidden_device_migrate_counts() {
         let multicast =
@@ -461,8 +826,7 @@ mod tests {
         ));
 
         let unicast =
-            TestCli::try_parse_from(["test", "device", "migrate-unicast-counts", "--dry-run"])
-                .unwrap();
+            TestCli::try_parse_from(["test", "device", "migrate-unicast-counts"]).unwrap();
         assert!(matches!(
             unicast.command,
             ServiceabilityCommand::Device(DeviceCliCommand {
@@ -493,6 +857,13 @@ mod tests {
     fn parses_log() {
         let parsed = TestCli::try_parse_from(["test", "log", "--pubkey", TEST_PUBKEY]).unwrap();
         assert!(matches!(parsed.command, ServiceabilityCommand::Log(_)));
+
+        let parsed = TestCli::try_parse_from(["test", "log", "--follow"]).unwrap();
+        assert!(matches!(
+            parsed.command,
+            ServiceabilityCommand::Log(LogCliCommand { follow: true, .. })
+        ));
+        assert!(TestCli::try_parse_from(["test", "log"]).is_err());
     }
 
     #[test]
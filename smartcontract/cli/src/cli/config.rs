@@ -1,6 +1,10 @@
 use clap::{Args, Subcommand};
 
-use crate::config::{get::GetConfigCliCommand, set::SetConfigCliCommand};
+use crate::config::{
+    get::GetConfigCliCommand,
+    label::{list::ListLabelCliCommand, remove::RemoveLabelCliCommand, set::SetLabelCliCommand},
+    set::SetConfigCliCommand,
+};
 
 #[derive(Args, Debug)]
 pub struct ConfigCliCommand {
@@ -16,4 +20,26 @@ pub enum ConfigCommands {
     /// Set a config setting
     #[command()]
     Set(SetConfigCliCommand),
+    /// Manage local labels shown next to pubkeys in CLI output
+    #[command()]
+    Label(LabelCliCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct LabelCliCommand {
+    #[command(subcommand)]
+    pub command: LabelCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LabelCommands {
+    /// Label a pubkey
+    #[command()]
+    Set(SetLabelCliCommand),
+    /// Remove the label of a pubkey
+    #[command()]
+    Remove(RemoveLabelCliCommand),
+    /// List labels
+    #[command()]
+    List(ListLabelCliCommand),
 }
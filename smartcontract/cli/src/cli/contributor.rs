@@ -1,6 +1,64 @@
 use clap::{Args, Subcommand};
 
-use crate::contributor::{create::*, delete::*, get::*, list::*, update::*};
+use crate::contributor::{
+    api_key::{
+        create::CreateApiKeyCliCommand, delete::DeleteApiKeyCliCommand, list::ListApiKeyCliCommand,
+        revoke::RevokeApiKeyCliCommand,
+    },
+    create::*,
+    delete::*,
+    get::*,
+    ip_allocation::{
+        create::CreateIpAllocationCliCommand, delete::DeleteIpAllocationCliCommand,
+        list::ListIpAllocationCliCommand, set_status::SetIpAllocationStatusCliCommand,
+    },
+    list::*,
+    update::*,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum ApiKeyCommands {
+    /// Create a new read API key; the key is printed once
+    #[clap()]
+    Create(CreateApiKeyCliCommand),
+    /// List read API keys
+    #[clap()]
+    List(ListApiKeyCliCommand),
+    /// Revoke a read API key
+    #[clap()]
+    Revoke(RevokeApiKeyCliCommand),
+    /// Delete a revoked or expired read API key
+    #[clap()]
+    Delete(DeleteApiKeyCliCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct ApiKeyCliCommand {
+    #[command(subcommand)]
+    pub command: ApiKeyCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IpAllocationCommands {
+    /// Register a public prefix for CYOA/DIA interfaces; it starts out pending
+    #[clap()]
+    Create(CreateIpAllocationCliCommand),
+    /// List IP allocations
+    #[clap()]
+    List(ListIpAllocationCliCommand),
+    /// Verify or reject an IP allocation (foundation only)
+    #[clap()]
+    SetStatus(SetIpAllocationStatusCliCommand),
+    /// Delete an IP allocation
+    #[clap()]
+    Delete(DeleteIpAllocationCliCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct IpAllocationCliCommand {
+    #[command(subcommand)]
+    pub command: IpAllocationCommands,
+}
 
 #[derive(Args, Debug)]
 pub struct ContributorCliCommand {
@@ -25,4 +83,10 @@ pub enum ContributorCommands {
     /// Delete a contributor
     #[clap()]
     Delete(DeleteContributorCliCommand),
+    /// Read API key commands
+    #[clap()]
+    ApiKey(ApiKeyCliCommand),
+    /// IP allocation commands
+    #[clap()]
+    IpAllocation(IpAllocationCliCommand),
 }
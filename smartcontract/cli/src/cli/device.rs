@@ -1,15 +1,23 @@
 use crate::device::{
+    ackconfig::AckDeviceConfigCliCommand,
+    challenge::IssueDeviceChallengeCliCommand,
+    co_owner::{GrantDeviceCoOwnerCliCommand, RevokeDeviceCoOwnerCliCommand},
     create::CreateDeviceCliCommand,
     delete::DeleteDeviceCliCommand,
+    diff::DiffDeviceConfigCliCommand,
     get::GetDeviceCliCommand,
     interface::{
-        create::CreateDeviceInterfaceCliCommand, delete::DeleteDeviceInterfaceCliCommand,
-        get::GetDeviceInterfaceCliCommand, list::ListDeviceInterfaceCliCommand,
+        apply::ApplyDeviceInterfacesCliCommand, create::CreateDeviceInterfaceCliCommand,
+        delete::DeleteDeviceInterfaceCliCommand, get::GetDeviceInterfaceCliCommand,
+        list::ListDeviceInterfaceCliCommand, status::StatusDeviceInterfaceCliCommand,
         update::UpdateDeviceInterfaceCliCommand,
     },
     list::ListDeviceCliCommand,
+    lock::{LockDeviceCliCommand, UnlockDeviceCliCommand},
     migrate_multicast_counts::MigrateMulticastCountsCliCommand,
     migrate_unicast_counts::MigrateUnicastCountsCliCommand,
+    prove_key::ProveDeviceKeyCliCommand,
+    rotate_metrics_publisher::RotateDeviceMetricsPublisherCliCommand,
     sethealth::SetDeviceHealthCliCommand,
     update::UpdateDeviceCliCommand,
 };
@@ -23,12 +31,18 @@ pub enum InterfaceCommands {
     /// Update an existing device interface
     #[clap()]
     Update(UpdateDeviceInterfaceCliCommand),
+    /// Update several interfaces of a device at once from a YAML file
+    #[clap()]
+    Apply(ApplyDeviceInterfacesCliCommand),
     /// List all device interfaces for a given device
     #[clap()]
     List(ListDeviceInterfaceCliCommand),
     /// Get details for a specific device interface
     #[clap()]
     Get(GetDeviceInterfaceCliCommand),
+    /// Compare each interface's configured status with what the device reports
+    #[clap()]
+    Status(StatusDeviceInterfaceCliCommand),
     /// Delete a device interface
     #[clap()]
     Delete(DeleteDeviceInterfaceCliCommand),
@@ -66,10 +80,38 @@ pub enum DeviceCommands {
     /// Interface commands
     #[clap()]
     Interface(InterfaceCliCommand),
+    /// Show the config the controller renders for a device, or diff it against the device's
+    #[clap()]
+    Diff(DiffDeviceConfigCliCommand),
+    /// Hand a device's metrics publisher over to a new key with an overlap window
+    #[clap()]
+    RotateMetricsPublisher(RotateDeviceMetricsPublisherCliCommand),
+    /// Challenge a device's metrics publisher to prove it holds its key
+    #[clap()]
+    Challenge(IssueDeviceChallengeCliCommand),
+    /// Answer a device's attestation challenge (run with the device's key)
+    #[clap()]
+    ProveKey(ProveDeviceKeyCliCommand),
+    /// Freeze a device against changes by anyone else while troubleshooting it
+    #[clap()]
+    Lock(LockDeviceCliCommand),
+    /// Release a device's lock
+    #[clap()]
+    Unlock(UnlockDeviceCliCommand),
+    /// Give another operator (e.g. a managed-service provider) update rights on a device
+    #[clap()]
+    GrantCoOwner(GrantDeviceCoOwnerCliCommand),
+    /// Take back a device's co-owner rights
+    #[clap()]
+    RevokeCoOwner(RevokeDeviceCoOwnerCliCommand),
     /// Set the health status of a device interface
     // Hidden because this is an internal/testing command and not part of the public CLI surface.
     #[clap(hide = true)]
     SetHealth(SetDeviceHealthCliCommand),
+    /// Acknowledge that a device's config generation has been applied
+    // Hidden because the controller, not operators, acknowledges applied config.
+    #[clap(hide = true)]
+    AckConfig(AckDeviceConfigCliCommand),
     /// Correct stale multicast subscriber/publisher counts on all devices
     #[clap(hide = true)]
     MigrateMulticastCounts(MigrateMulticastCountsCliCommand),
@@ -0,0 +1,18 @@
+use crate::env::{list::ListEnvCliCommand, show::ShowEnvCliCommand};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct EnvCliCommand {
+    #[command(subcommand)]
+    pub command: EnvCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EnvCommands {
+    /// List the built-in environments and those of the environment manifest
+    #[clap()]
+    List(ListEnvCliCommand),
+    /// Probe an environment's RPC health, current epoch and deployed program versions
+    #[clap()]
+    Show(ShowEnvCliCommand),
+}
@@ -11,6 +11,7 @@ use crate::{
         authority::{get::GetAuthorityCliCommand, set::SetAuthorityCliCommand},
         featureflags::{get::GetFeatureFlagsCliCommand, set::SetFeatureFlagsCliCommand},
         get::GetGlobalConfigCliCommand,
+        pause::{get::GetPauseCliCommand, set::SetPauseCliCommand},
         set::SetGlobalConfigCliCommand,
         setversion::SetVersionCliCommand,
     },
@@ -49,6 +50,9 @@ pub enum GlobalConfigCommands {
     /// Manage feature flags
     #[clap(hide = true)]
     FeatureFlags(FeatureFlagsCommand),
+    /// Pause or resume classes of mutating instructions
+    #[clap()]
+    Pause(PauseCommand),
 }
 
 #[derive(Args, Debug)]
@@ -136,3 +140,19 @@ pub enum FeatureFlagsCommands {
     #[clap()]
     Set(SetFeatureFlagsCliCommand),
 }
+
+#[derive(Args, Debug)]
+pub struct PauseCommand {
+    #[command(subcommand)]
+    pub command: PauseCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PauseCommands {
+    /// Get the currently paused instruction classes
+    #[clap()]
+    Get(GetPauseCliCommand),
+    /// Pause or resume instruction classes
+    #[clap()]
+    Set(SetPauseCliCommand),
+}
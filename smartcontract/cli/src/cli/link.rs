@@ -1,7 +1,15 @@
 use crate::{
     link::{
-        accept::AcceptLinkCliCommand, delete::*, dzx_create::CreateDZXLinkCliCommand, get::*,
-        latency::LinkLatencyCliCommand, list::*, sethealth::SetLinkHealthCliCommand, update::*,
+        accept::AcceptLinkCliCommand,
+        burnin::LinkBurnInCliCommand,
+        delete::*,
+        dzx_create::CreateDZXLinkCliCommand,
+        get::*,
+        latency::LinkLatencyCliCommand,
+        list::*,
+        lock::{LockLinkCliCommand, UnlockLinkCliCommand},
+        sethealth::SetLinkHealthCliCommand,
+        update::*,
         wan_create::*,
     },
     topology::{
@@ -54,8 +62,17 @@ pub enum LinkCommands {
     /// Display latency statistics for a link
     #[clap()]
     Latency(LinkLatencyCliCommand),
+    /// Check a newly activated link's telemetry against acceptance thresholds
+    #[clap()]
+    BurnIn(LinkBurnInCliCommand),
     /// Delete a link
     Delete(DeleteLinkCliCommand),
+    /// Freeze a link against changes by anyone else while troubleshooting it
+    #[clap()]
+    Lock(LockLinkCliCommand),
+    /// Release a link's lock
+    #[clap()]
+    Unlock(UnlockLinkCliCommand),
     /// Set the health status of a link interface
     // Hidden because this is an internal/operational command not intended for general CLI users.
     #[clap(hide = true)]
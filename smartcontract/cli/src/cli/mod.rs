@@ -8,12 +8,15 @@
 //! unified `doublezero` binary via `#[command(flatten)]`.
 
 pub mod accesspass;
+pub mod admin;
+pub mod anycast_prefix;
 pub mod command;
 pub mod config;
 
 pub use command::ServiceabilityCommand;
 pub mod contributor;
 pub mod device;
+pub mod env;
 pub mod exchange;
 pub mod feed;
 pub mod globalconfig;
@@ -22,6 +25,11 @@ pub mod location;
 pub mod migrate;
 pub mod multicastgroup;
 pub mod permission;
+pub mod qos_profile;
 pub mod resource;
+pub mod scheduler;
+pub mod state_commitment;
+pub mod telemetry;
 pub mod tenant;
+pub mod topology;
 pub mod user;
@@ -0,0 +1,28 @@
+use clap::{Args, Subcommand};
+
+use crate::qos_profile::{create::*, delete::*, get::*, list::*, update::*};
+
+#[derive(Args, Debug)]
+pub struct QosProfileCliCommand {
+    #[command(subcommand)]
+    pub command: QosProfileCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum QosProfileCommands {
+    /// Create the QoS profile for a bandwidth tier
+    #[clap()]
+    Create(CreateQosProfileCliCommand),
+    /// Update the QoS profile of a bandwidth tier
+    #[clap()]
+    Update(UpdateQosProfileCliCommand),
+    /// List all QoS profiles
+    #[clap()]
+    List(ListQosProfileCliCommand),
+    /// Get the QoS profile of a bandwidth tier
+    #[clap()]
+    Get(GetQosProfileCliCommand),
+    /// Delete the QoS profile of a bandwidth tier
+    #[clap()]
+    Delete(DeleteQosProfileCliCommand),
+}
@@ -1,7 +1,7 @@
 use crate::resource::{
     allocate::AllocateResourceCliCommand, close::CloseResourceCliCommand,
     create::CreateResourceCliCommand, deallocate::DeallocateResourceCliCommand,
-    get::GetResourceCliCommand, verify::VerifyResourceCliCommand,
+    get::GetResourceCliCommand, resize::ResizeResourceCliCommand, verify::VerifyResourceCliCommand,
 };
 use clap::{Args, Subcommand};
 
@@ -25,10 +25,13 @@ pub enum ResourceCommands {
     /// Get a resource
     #[clap()]
     Get(GetResourceCliCommand),
+    /// Resize a resource to its current range, preserving allocations
+    #[clap()]
+    Resize(ResizeResourceCliCommand),
     /// Close a resource
     #[clap()]
     Close(CloseResourceCliCommand),
     /// Verify resource allocations against onchain accounts
-    #[clap()]
+    #[clap(visible_alias = "audit")]
     Verify(VerifyResourceCliCommand),
 }
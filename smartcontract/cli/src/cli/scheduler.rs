@@ -0,0 +1,16 @@
+use clap::{Args, Subcommand};
+
+use crate::scheduler::history::*;
+
+#[derive(Args, Debug)]
+pub struct SchedulerCliCommand {
+    #[command(subcommand)]
+    pub command: SchedulerCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SchedulerCommands {
+    /// List the execution receipts a scheduler recorded for a job
+    #[clap()]
+    History(SchedulerHistoryCliCommand),
+}
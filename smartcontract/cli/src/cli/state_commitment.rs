@@ -0,0 +1,22 @@
+use clap::{Args, Subcommand};
+
+use crate::state_commitment::{get::*, record::*, verify::*};
+
+#[derive(Args, Debug)]
+pub struct StateCommitmentCliCommand {
+    #[command(subcommand)]
+    pub command: StateCommitmentCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StateCommitmentCommands {
+    /// Snapshot every account and record the merkle root onchain
+    #[clap()]
+    Record(RecordStateCommitmentCliCommand),
+    /// Get the recorded state commitment
+    #[clap()]
+    Get(GetStateCommitmentCliCommand),
+    /// Check the recorded root, or prove one account against it
+    #[clap()]
+    Verify(VerifyStateCommitmentCliCommand),
+}
@@ -0,0 +1,24 @@
+use crate::telemetry::{
+    budget::SetProviderSamplingBudgetCliCommand, prune::PruneTelemetryCliCommand,
+    reclaim::ReclaimTelemetryCliCommand,
+};
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct TelemetryCliCommand {
+    #[command(subcommand)]
+    pub command: TelemetryCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TelemetryCommands {
+    /// Close aged latency samples accounts, refunding their rent
+    #[clap()]
+    Prune(PruneTelemetryCliCommand),
+    /// Finalize samples accounts from ended epochs and close expired ones
+    #[clap()]
+    Reclaim(ReclaimTelemetryCliCommand),
+    /// Cap the internet latency samples an oracle agent may write for a data provider
+    #[clap()]
+    SetBudget(SetProviderSamplingBudgetCliCommand),
+}
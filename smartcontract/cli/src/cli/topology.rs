@@ -0,0 +1,15 @@
+use crate::topology::map::MapTopologyCliCommand;
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct TopologyCliCommand {
+    #[command(subcommand)]
+    pub command: NetworkTopologyCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NetworkTopologyCommands {
+    /// Export the network as a GraphViz or D3 map
+    #[clap()]
+    Map(MapTopologyCliCommand),
+}
@@ -18,7 +18,7 @@ impl GetConfigCliCommand {
 
         writeln!(
             out,
-            "Config File: {}\nRPC URL: {}\nWebSocket URL: {}\nKeypair Path: {}\nProgram ID: {}\nGeolocation Program ID: {}\nTenant: {}\n",
+            "Config File: {}\nRPC URL: {}\nWebSocket URL: {}\nKeypair Path: {}\nProgram ID: {}\nGeolocation Program ID: {}\nTenant: {}\nFallback RPC URLs: {}\nRPC Read Mode: {}\n",
             filename.display(),
             config.json_rpc_url,
             config.websocket_url.unwrap_or(format!(
@@ -34,7 +34,18 @@ impl GetConfigCliCommand {
                 "{} (computed)",
                 doublezero_sdk::default_geolocation_program_id()
             )),
-            config.tenant.unwrap_or("(not set)".to_string())
+            config.tenant.unwrap_or("(not set)".to_string()),
+            if config.fallback_endpoints.is_empty() {
+                "(none)".to_string()
+            } else {
+                config
+                    .fallback_endpoints
+                    .iter()
+                    .map(|endpoint| endpoint.json_rpc_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+            config.rpc_read_mode
         )?;
 
         Ok(())
@@ -125,6 +136,9 @@ mod tests {
             geo_program_id: None,
             tenant: None,
             address_labels: Default::default(),
+            client_policy: None,
+            fallback_endpoints: Default::default(),
+            rpc_read_mode: Default::default(),
         };
 
         mutator(&mut cfg);
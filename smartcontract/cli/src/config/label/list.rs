@@ -0,0 +1,94 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_sdk::read_doublezero_config;
+use serde::Serialize;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListLabelCliCommand {
+    /// Output in JSON format
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output in compact JSON format
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub struct LabelDisplay {
+    pub label: String,
+    pub pubkey: String,
+}
+
+impl ListLabelCliCommand {
+    pub async fn execute<W: Write>(
+        self,
+        _ctx: &CliContext,
+        _client: &dyn CliCommand,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (_, config) = read_doublezero_config()?;
+
+        let mut labels: Vec<LabelDisplay> = config
+            .address_labels
+            .into_iter()
+            .map(|(pubkey, label)| LabelDisplay { label, pubkey })
+            .collect();
+        labels.sort_by(|a, b| a.label.cmp(&b.label));
+
+        render_collection(
+            out,
+            labels,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{write_doublezero_config, ClientConfig};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    const CONFIG_ENV_VAR: &str = "DOUBLEZERO_CONFIG_FILE";
+
+    #[test]
+    #[serial]
+    fn test_cli_config_label_list() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.yml");
+
+        temp_env::with_var(CONFIG_ENV_VAR, Some(&config_path.to_str().unwrap()), || {
+            let mut config = ClientConfig::default();
+            config.address_labels.insert(
+                "HQ3UUt18uJqKaQFJhgV9zaTdQxUZjNrsKFgoEDquBkcx".to_string(),
+                "ops".to_string(),
+            );
+            config.address_labels.insert(
+                "DDddB7bhR9azxLAUEH7ZVtW168wRdreiDKhi4McDfKZt".to_string(),
+                "foundation".to_string(),
+            );
+            write_doublezero_config(&config).unwrap();
+
+            let client = create_test_client();
+            let mut output = Vec::new();
+            block_on(
+                ListLabelCliCommand {
+                    json: false,
+                    json_compact: true,
+                }
+                .execute(&cli_context_default_for_tests(), &client, &mut output),
+            )
+            .unwrap();
+            assert_eq!(
+                String::from_utf8(output).unwrap(),
+                "[{\"label\":\"foundation\",\"pubkey\":\"DDddB7bhR9azxLAUEH7ZVtW168wRdreiDKhi4McDfKZt\"},{\"label\":\"ops\",\"pubkey\":\"HQ3UUt18uJqKaQFJhgV9zaTdQxUZjNrsKFgoEDquBkcx\"}]\n"
+            );
+        });
+    }
+}
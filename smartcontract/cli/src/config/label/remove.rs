@@ -0,0 +1,77 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::{read_doublezero_config, write_doublezero_config};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct RemoveLabelCliCommand {
+    /// Pubkey whose label to remove
+    #[arg(long, value_parser = validate_pubkey)]
+    pub pubkey: String,
+}
+
+impl RemoveLabelCliCommand {
+    pub async fn execute<W: Write>(
+        self,
+        _ctx: &CliContext,
+        _client: &dyn CliCommand,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (_, mut config) = read_doublezero_config()?;
+        let Some(label) = config.address_labels.remove(&self.pubkey) else {
+            eyre::bail!("{} has no label", self.pubkey);
+        };
+        write_doublezero_config(&config)?;
+
+        writeln!(out, "Removed label {label} from {}", self.pubkey)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::ClientConfig;
+    use serial_test::serial;
+    use solana_sdk::pubkey::Pubkey;
+    use tempfile::TempDir;
+
+    const CONFIG_ENV_VAR: &str = "DOUBLEZERO_CONFIG_FILE";
+
+    #[test]
+    #[serial]
+    fn test_cli_config_label_remove() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.yml");
+        let pubkey = Pubkey::new_unique();
+
+        temp_env::with_var(CONFIG_ENV_VAR, Some(&config_path.to_str().unwrap()), || {
+            let mut config = ClientConfig::default();
+            config
+                .address_labels
+                .insert(pubkey.to_string(), "ams-ops".to_string());
+            write_doublezero_config(&config).unwrap();
+
+            let client = create_test_client();
+            let ctx = cli_context_default_for_tests();
+            let remove = || RemoveLabelCliCommand {
+                pubkey: pubkey.to_string(),
+            };
+
+            let mut output = Vec::new();
+            block_on(remove().execute(&ctx, &client, &mut output)).unwrap();
+            assert_eq!(
+                String::from_utf8(output).unwrap(),
+                format!("Removed label ams-ops from {pubkey}\n")
+            );
+            let (_, config) = read_doublezero_config().unwrap();
+            assert!(config.address_labels.is_empty());
+
+            assert!(block_on(remove().execute(&ctx, &client, &mut Vec::new())).is_err());
+        });
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::{labels::validate_label, read_doublezero_config, write_doublezero_config};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SetLabelCliCommand {
+    /// Pubkey to label
+    #[arg(long, value_parser = validate_pubkey)]
+    pub pubkey: String,
+    /// Label shown next to the pubkey in CLI output
+    #[arg(long)]
+    pub label: String,
+}
+
+impl SetLabelCliCommand {
+    pub async fn execute<W: Write>(
+        self,
+        _ctx: &CliContext,
+        _client: &dyn CliCommand,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        validate_label(&self.label)?;
+
+        let (_, mut config) = read_doublezero_config()?;
+        if let Some((other, _)) = config
+            .address_labels
+            .iter()
+            .find(|(pubkey, label)| **label == self.label && **pubkey != self.pubkey)
+        {
+            eyre::bail!("label '{}' is already assigned to {other}", self.label);
+        }
+
+        config
+            .address_labels
+            .insert(self.pubkey.clone(), self.label.clone());
+        write_doublezero_config(&config)?;
+
+        writeln!(out, "Labeled {} as {}", self.pubkey, self.label)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::ClientConfig;
+    use serial_test::serial;
+    use solana_sdk::pubkey::Pubkey;
+    use tempfile::TempDir;
+
+    const CONFIG_ENV_VAR: &str = "DOUBLEZERO_CONFIG_FILE";
+
+    fn set_label(pubkey: &Pubkey, label: &str) -> eyre::Result<String> {
+        let client = create_test_client();
+        let mut output = Vec::new();
+        block_on(
+            SetLabelCliCommand {
+                pubkey: pubkey.to_string(),
+                label: label.to_string(),
+            }
+            .execute(&cli_context_default_for_tests(), &client, &mut output),
+        )?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    #[serial]
+    fn test_cli_config_label_set() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.yml");
+        let pubkey = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        temp_env::with_var(CONFIG_ENV_VAR, Some(&config_path.to_str().unwrap()), || {
+            write_doublezero_config(&ClientConfig::default()).unwrap();
+
+            let output = set_label(&pubkey, "ams-ops").unwrap();
+            assert_eq!(output, format!("Labeled {pubkey} as ams-ops\n"));
+
+            // Relabeling the same pubkey replaces its label.
+            set_label(&pubkey, "ams-ops2").unwrap();
+            let (_, config) = read_doublezero_config().unwrap();
+            assert_eq!(
+                config.address_labels.get(&pubkey.to_string()).unwrap(),
+                "ams-ops2"
+            );
+
+            // Labels are unique and validated.
+            assert!(set_label(&other, "ams-ops2").is_err());
+            assert!(set_label(&other, "has space").is_err());
+            let (_, config) = read_doublezero_config().unwrap();
+            assert_eq!(config.address_labels.len(), 1);
+        });
+    }
+}
@@ -1,2 +1,3 @@
 pub mod get;
+pub mod label;
 pub mod set;
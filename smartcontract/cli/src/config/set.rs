@@ -4,14 +4,15 @@ use doublezero_cli_core::CliContext;
 use doublezero_config::Environment;
 use doublezero_sdk::{
     convert_geo_program_moniker, convert_program_moniker, convert_url_moniker, convert_url_to_ws,
-    convert_ws_moniker, read_doublezero_config, write_doublezero_config,
+    convert_ws_moniker, read_doublezero_config, write_doublezero_config, RpcEndpointConfig,
+    RpcReadMode,
 };
 use std::{io::Write, path::PathBuf};
 
 #[derive(Args, Debug)]
 #[clap(group(
     ArgGroup::new("mandatory")
-        .args(&["env", "url", "ws", "keypair", "program_id", "geo_program_id", "tenant", "no_tenant"])
+        .args(&["env", "url", "ws", "keypair", "program_id", "geo_program_id", "tenant", "no_tenant", "fallback_urls", "no_fallback_urls", "rpc_read_mode"])
         .required(true)
         .multiple(true)
 ))]
@@ -42,6 +43,21 @@ pub struct SetConfigCliCommand {
     /// Clear the default tenant
     #[arg(long, conflicts_with = "tenant")]
     pub no_tenant: bool,
+    /// JSON RPC URL to fall back to when the primary fails (devnet, testnet, mainnet, localhost).
+    /// Repeat for several, tried in order; replaces the configured fallbacks
+    #[arg(
+        long = "fallback-url",
+        value_name = "URL",
+        conflicts_with = "no_fallback_urls"
+    )]
+    pub fallback_urls: Vec<String>,
+    /// Remove all fallback RPC URLs
+    #[arg(long, conflicts_with = "fallback_urls")]
+    pub no_fallback_urls: bool,
+    /// How to pick among the primary and fallback RPC endpoints: failover (primary first)
+    /// or fastest (whichever answers first)
+    #[arg(long, value_name = "MODE")]
+    pub rpc_read_mode: Option<RpcReadMode>,
 }
 
 impl SetConfigCliCommand {
@@ -87,6 +103,9 @@ impl SetConfigCliCommand {
             && geo_program_id.is_none()
             && self.tenant.is_none()
             && !self.no_tenant
+            && self.fallback_urls.is_empty()
+            && !self.no_fallback_urls
+            && self.rpc_read_mode.is_none()
         {
             writeln!(out, "No arguments provided")?;
             return Ok(());
@@ -115,12 +134,27 @@ impl SetConfigCliCommand {
         } else if self.tenant.is_some() {
             config.tenant = self.tenant;
         }
+        if self.no_fallback_urls {
+            config.fallback_endpoints.clear();
+        } else if !self.fallback_urls.is_empty() {
+            config.fallback_endpoints = self
+                .fallback_urls
+                .into_iter()
+                .map(|url| RpcEndpointConfig {
+                    json_rpc_url: convert_url_moniker(url),
+                    websocket_url: None,
+                })
+                .collect();
+        }
+        if let Some(rpc_read_mode) = self.rpc_read_mode {
+            config.rpc_read_mode = rpc_read_mode;
+        }
 
         write_doublezero_config(&config)?;
 
         writeln!(
             out,
-            "Config File: {}\nRPC URL: {}\nWebSocket URL: {}\nKeypair Path: {}\nProgram ID: {}\nGeolocation Program ID: {}\nTenant: {}\n",
+            "Config File: {}\nRPC URL: {}\nWebSocket URL: {}\nKeypair Path: {}\nProgram ID: {}\nGeolocation Program ID: {}\nTenant: {}\nFallback RPC URLs: {}\nRPC Read Mode: {}\n",
             filename.display(),
             config.json_rpc_url,
             config.websocket_url.unwrap_or(format!(
@@ -136,7 +170,18 @@ impl SetConfigCliCommand {
                 "{} (computed)",
                 doublezero_sdk::default_geolocation_program_id()
             )),
-            config.tenant.unwrap_or("(not set)".to_string())
+            config.tenant.unwrap_or("(not set)".to_string()),
+            if config.fallback_endpoints.is_empty() {
+                "(none)".to_string()
+            } else {
+                config
+                    .fallback_endpoints
+                    .iter()
+                    .map(|endpoint| endpoint.json_rpc_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+            config.rpc_read_mode
         )?;
 
         Ok(())
@@ -178,6 +223,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -215,6 +263,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -247,6 +298,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -285,6 +339,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -323,6 +380,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -353,6 +413,9 @@ mod tests {
             geo_program_id: None,
             tenant: None,
             address_labels: Default::default(),
+            client_policy: None,
+            fallback_endpoints: Default::default(),
+            rpc_read_mode: Default::default(),
         };
 
         mutator(&mut cfg);
@@ -383,6 +446,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: Some("my-tenant".to_string()),
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -396,6 +462,63 @@ mod tests {
         });
     }
 
+    #[test]
+    #[serial]
+    fn test_cli_config_set_fallback_urls() {
+        let (_tmp, config_path, cfg) = new_test_config(|_cfg| {});
+
+        temp_env::with_var(CONFIG_ENV_VAR, Some(&config_path.to_str().unwrap()), || {
+            write_doublezero_config(&cfg).unwrap();
+            create_new_pubkey_user(false, Some(cfg.keypair_path.clone())).unwrap();
+
+            let client = create_test_client();
+            let ctx = cli_context_default_for_tests();
+            let set = |fallback_urls: Vec<String>, no_fallback_urls, rpc_read_mode| {
+                let mut output = Vec::new();
+                block_on(
+                    SetConfigCliCommand {
+                        env: None,
+                        url: None,
+                        ws: None,
+                        keypair: None,
+                        program_id: None,
+                        geo_program_id: None,
+                        tenant: None,
+                        no_tenant: false,
+                        fallback_urls,
+                        no_fallback_urls,
+                        rpc_read_mode,
+                    }
+                    .execute(&ctx, &client, &mut output),
+                )
+                .unwrap();
+                String::from_utf8(output).unwrap()
+            };
+
+            let output_str = set(
+                vec![
+                    "https://backup.example.com".to_string(),
+                    "localhost".to_string(),
+                ],
+                false,
+                Some(RpcReadMode::Fastest),
+            );
+            assert!(output_str
+                .contains("Fallback RPC URLs: https://backup.example.com, http://localhost:8899"));
+            assert!(output_str.contains("RPC Read Mode: fastest"));
+
+            let (_, saved_config) = read_doublezero_config().unwrap();
+            assert_eq!(saved_config.fallback_endpoints.len(), 2);
+            assert_eq!(saved_config.rpc_read_mode, RpcReadMode::Fastest);
+
+            let output_str = set(vec![], true, None);
+            assert!(output_str.contains("Fallback RPC URLs: (none)"));
+            let (_, saved_config) = read_doublezero_config().unwrap();
+            assert!(saved_config.fallback_endpoints.is_empty());
+            assert_eq!(saved_config.rpc_read_mode, RpcReadMode::Fastest);
+        });
+    }
+
     #[test]
     #[serial]
     fn test_cli_config_set_no_tenant() {
@@ -421,6 +544,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: true,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -457,6 +583,9 @@ mod tests {
                     geo_program_id: Some("MyGeoProgram123".to_string()),
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -493,6 +622,9 @@ mod tests {
                     geo_program_id: Some("MyGeoProgram123".to_string()),
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
@@ -537,6 +669,9 @@ mod tests {
                     geo_program_id: None,
                     tenant: None,
                     no_tenant: false,
+                    fallback_urls: vec![],
+                    no_fallback_urls: false,
+                    rpc_read_mode: None,
                 }
                 .execute(&ctx, &client, &mut output),
             )
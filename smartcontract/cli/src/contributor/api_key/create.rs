@@ -0,0 +1,173 @@
+use crate::{
+    doublezerocommand::CliCommand, helpers::resolve_contributor_pk,
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::read_api_key::create::CreateReadApiKeyCommand;
+use solana_sdk::signature::Keypair;
+use std::io::Write;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(Args, Debug)]
+pub struct CreateApiKeyCliCommand {
+    /// Contributor Pubkey or code that owns the key
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub contributor: String,
+    /// Free-form label to identify the key
+    #[arg(long, default_value = "")]
+    pub label: String,
+    /// Number of days until the key expires; omit for a key that never expires
+    #[arg(long)]
+    pub expires_in_days: Option<u32>,
+}
+
+/// Generate a new random read-API key as 64 hex characters.
+pub fn generate_read_api_key() -> String {
+    Keypair::new()
+        .secret_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// SHA-256 hash of a read-API key, as stored onchain.
+pub fn hash_read_api_key(key: &str) -> [u8; 32] {
+    solana_sdk::hash::hash(key.as_bytes()).to_bytes()
+}
+
+impl CreateApiKeyCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let contributor_pk = resolve_contributor_pk(client, &self.contributor)?;
+        let expires_at = match self.expires_in_days {
+            Some(days) => chrono::Utc::now().timestamp() + i64::from(days) * SECONDS_PER_DAY,
+            None => 0,
+        };
+
+        let key = generate_read_api_key();
+        let (signature, pubkey) = client.create_read_api_key(CreateReadApiKeyCommand {
+            contributor_pk,
+            key_hash: hash_read_api_key(&key),
+            label: self.label,
+            expires_at,
+        })?;
+
+        writeln!(out, "Account: {pubkey}")?;
+        writeln!(out, "API key: {key}")?;
+        writeln!(
+            out,
+            "Store this key now; only its hash is kept onchain and it cannot be shown again."
+        )?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::api_key::create::{hash_read_api_key, CreateApiKeyCliCommand},
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::{
+            contributor::get::GetContributorCommand, read_api_key::create::CreateReadApiKeyCommand,
+        },
+        get_contributor_pda, get_read_api_key_pda, AccountType, Contributor, ContributorStatus,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_cli_contributor_api_key_create() {
+        let mut client = create_test_client();
+
+        let (contributor_pubkey, _) = get_contributor_pda(&client.get_program_id(), 1);
+        let contributor = Contributor {
+            account_type: AccountType::Contributor,
+            index: 1,
+            bump_seed: 255,
+            code: "co01".to_string(),
+            reference_count: 0,
+            status: ContributorStatus::Activated,
+            owner: Pubkey::default(),
+            ops_manager_pk: Pubkey::default(),
+        };
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_contributor()
+            .with(predicate::eq(GetContributorCommand {
+                pubkey_or_code: "co01".to_string(),
+            }))
+            .returning(move |_| Ok((contributor_pubkey, contributor.clone())));
+
+        let program_id = client.get_program_id();
+        let sent_hash = Arc::new(Mutex::new(None));
+        let sent_hash_cloned = sent_hash.clone();
+        client
+            .expect_create_read_api_key()
+            .with(predicate::function(move |cmd: &CreateReadApiKeyCommand| {
+                cmd.contributor_pk == contributor_pubkey
+                    && cmd.label == "indexer"
+                    && cmd.expires_at == 0
+            }))
+            .returning(move |cmd| {
+                *sent_hash_cloned.lock().unwrap() = Some(cmd.key_hash);
+                let (pda, _) =
+                    get_read_api_key_pda(&program_id, &cmd.contributor_pk, &cmd.key_hash);
+                Ok((signature, pda))
+            });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateApiKeyCliCommand {
+                contributor: "co01".to_string(),
+                label: "indexer".to_string(),
+                expires_in_days: None,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+
+        let output_str = String::from_utf8(output).unwrap();
+        let key = output_str
+            .lines()
+            .find_map(|line| line.strip_prefix("API key: "))
+            .expect("key printed");
+        assert_eq!(key.len(), 64);
+
+        // The printed key is the preimage of the hash sent onchain.
+        let key_hash = sent_hash.lock().unwrap().expect("create called");
+        assert_eq!(hash_read_api_key(key), key_hash);
+        let (pda, _) = get_read_api_key_pda(&program_id, &contributor_pubkey, &key_hash);
+        assert!(output_str.contains(&format!("Account: {pda}\n")));
+        assert!(output_str.ends_with(
+            "Signature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
+        ));
+    }
+}
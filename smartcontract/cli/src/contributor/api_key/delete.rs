@@ -0,0 +1,80 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::read_api_key::delete::DeleteReadApiKeyCommand;
+use solana_sdk::pubkey::Pubkey;
+use std::{io::Write, str::FromStr};
+
+#[derive(Args, Debug)]
+pub struct DeleteApiKeyCliCommand {
+    /// Read API key Pubkey to delete
+    #[arg(long, value_parser = validate_pubkey)]
+    pub pubkey: String,
+}
+
+impl DeleteApiKeyCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let pubkey = Pubkey::from_str(&self.pubkey)?;
+        let signature = client.delete_read_api_key(DeleteReadApiKeyCommand { pubkey })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::api_key::delete::DeleteApiKeyCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::commands::read_api_key::delete::DeleteReadApiKeyCommand;
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_contributor_api_key_delete() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_delete_read_api_key()
+            .with(predicate::eq(DeleteReadApiKeyCommand { pubkey }))
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            DeleteApiKeyCliCommand {
+                pubkey: pubkey.to_string(),
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,"Signature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
+        );
+    }
+}
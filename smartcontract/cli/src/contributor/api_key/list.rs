@@ -0,0 +1,154 @@
+use crate::{
+    doublezerocommand::CliCommand, helpers::resolve_contributor_pk,
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use doublezero_sdk::{
+    commands::read_api_key::list::ListReadApiKeyCommand, ReadApiKey, ReadApiKeyStatus,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListApiKeyCliCommand {
+    /// Only list keys for this contributor Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub contributor: Option<String>,
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub struct ApiKeyDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub account: Pubkey,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub contributor: Pubkey,
+    pub label: String,
+    pub status: ReadApiKeyStatus,
+    pub created_at: i64,
+    #[tabled(display = "display_expires_at")]
+    pub expires_at: i64,
+}
+
+fn display_expires_at(expires_at: &i64) -> String {
+    if *expires_at == 0 {
+        "never".to_string()
+    } else {
+        expires_at.to_string()
+    }
+}
+
+impl ListApiKeyCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let contributor_pk = self
+            .contributor
+            .as_deref()
+            .map(|contributor| resolve_contributor_pk(client, contributor))
+            .transpose()?;
+
+        let mut keys: Vec<(Pubkey, ReadApiKey)> = client
+            .list_read_api_key(ListReadApiKeyCommand)?
+            .into_iter()
+            .filter(|(_, key)| contributor_pk.is_none_or(|pk| key.contributor_pk == pk))
+            .collect();
+
+        keys.sort_by(|(_, a), (_, b)| {
+            a.contributor_pk
+                .cmp(&b.contributor_pk)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+
+        let key_displays: Vec<ApiKeyDisplay> = keys
+            .into_iter()
+            .map(|(pubkey, key)| ApiKeyDisplay {
+                account: pubkey,
+                contributor: key.contributor_pk,
+                label: key.label,
+                status: key.status,
+                created_at: key.created_at,
+                expires_at: key.expires_at,
+            })
+            .collect();
+
+        render_collection(
+            out,
+            key_displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::api_key::list::ListApiKeyCliCommand, tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{AccountType, ReadApiKey, ReadApiKeyStatus};
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_cli_contributor_api_key_list() {
+        let mut client = create_test_client();
+
+        let key_pubkey = Pubkey::from_str_const("11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo");
+        let contributor_pubkey =
+            Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+        let key = ReadApiKey {
+            account_type: AccountType::ReadApiKey,
+            owner: Pubkey::default(),
+            bump_seed: 255,
+            status: ReadApiKeyStatus::Activated,
+            contributor_pk: contributor_pubkey,
+            key_hash: [1; 32],
+            label: "indexer".to_string(),
+            created_at: 1_700_000_000,
+            expires_at: 0,
+        };
+        client
+            .expect_list_read_api_key()
+            .returning(move |_| Ok(HashMap::from([(key_pubkey, key.clone())])));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ListApiKeyCliCommand {
+                contributor: None,
+                json: false,
+                json_compact: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, " account                                   | contributor                               | label   | status    | created_at | expires_at \n 11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo | 11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V | indexer | activated | 1700000000 | never      \n");
+
+        let mut output = Vec::new();
+        let res = block_on(
+            ListApiKeyCliCommand {
+                contributor: None,
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, "[{\"account\":\"11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo\",\"contributor\":\"11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V\",\"label\":\"indexer\",\"status\":\"Activated\",\"created_at\":1700000000,\"expires_at\":0}]\n");
+    }
+}
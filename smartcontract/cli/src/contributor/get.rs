@@ -1,7 +1,7 @@
 use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
-use doublezero_sdk::commands::contributor::get::GetContributorCommand;
+use doublezero_sdk::{commands::contributor::get::GetContributorCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use std::io::Write;
 use tabled::Tabled;
@@ -17,13 +17,13 @@ pub struct GetContributorCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct ContributorDisplay {
+pub(crate) struct ContributorDisplay {
     pub account: String,
     pub code: String,
     pub reference_count: u32,
     pub status: String,
-    pub owner: String,
-    pub ops_manager_key: String,
+    pub owner: LabeledPubkey,
+    pub ops_manager_key: LabeledPubkey,
 }
 
 impl GetContributorCliCommand {
@@ -33,6 +33,7 @@ impl GetContributorCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (pubkey, contributor) = client.get_contributor(GetContributorCommand {
             pubkey_or_code: self.code,
         })?;
@@ -42,8 +43,8 @@ impl GetContributorCliCommand {
             code: contributor.code,
             reference_count: contributor.reference_count,
             status: contributor.status.to_string(),
-            owner: contributor.owner.to_string(),
-            ops_manager_key: contributor.ops_manager_pk.to_string(),
+            owner: book.labeled(&contributor.owner),
+            ops_manager_key: book.labeled(&contributor.ops_manager_pk),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
@@ -0,0 +1,131 @@
+use crate::{
+    doublezerocommand::CliCommand, helpers::resolve_contributor_pk,
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_sdk::commands::ip_allocation::create::CreateIpAllocationCommand;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct CreateIpAllocationCliCommand {
+    /// Contributor Pubkey or code that holds the block
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub contributor: String,
+    /// Public prefix, written as its network address (e.g. 45.76.12.0/24)
+    #[arg(long)]
+    pub prefix: NetworkV4,
+    /// Upstream provider the block was assigned by (e.g. its ASN)
+    #[arg(long, default_value = "")]
+    pub upstream: String,
+}
+
+impl CreateIpAllocationCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let contributor_pk = resolve_contributor_pk(client, &self.contributor)?;
+        let (signature, pubkey) = client.create_ip_allocation(CreateIpAllocationCommand {
+            contributor_pk,
+            prefix: self.prefix,
+            upstream: self.upstream,
+        })?;
+
+        writeln!(out, "Account: {pubkey}")?;
+        writeln!(
+            out,
+            "The allocation is pending until the foundation verifies it."
+        )?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::ip_allocation::create::CreateIpAllocationCliCommand,
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::{
+            contributor::get::GetContributorCommand,
+            ip_allocation::create::CreateIpAllocationCommand,
+        },
+        get_contributor_pda, get_ip_allocation_pda, AccountType, Contributor, ContributorStatus,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_contributor_ip_allocation_create() {
+        let mut client = create_test_client();
+
+        let (contributor_pubkey, _) = get_contributor_pda(&client.get_program_id(), 1);
+        let contributor = Contributor {
+            account_type: AccountType::Contributor,
+            index: 1,
+            bump_seed: 255,
+            code: "co01".to_string(),
+            reference_count: 0,
+            status: ContributorStatus::Activated,
+            owner: Pubkey::default(),
+            ops_manager_pk: Pubkey::default(),
+        };
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &prefix);
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_contributor()
+            .with(predicate::eq(GetContributorCommand {
+                pubkey_or_code: "co01".to_string(),
+            }))
+            .returning(move |_| Ok((contributor_pubkey, contributor.clone())));
+        client
+            .expect_create_ip_allocation()
+            .with(predicate::eq(CreateIpAllocationCommand {
+                contributor_pk: contributor_pubkey,
+                prefix,
+                upstream: "AS64500".to_string(),
+            }))
+            .returning(move |_| Ok((signature, pda_pubkey)));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateIpAllocationCliCommand {
+                contributor: "co01".to_string(),
+                prefix,
+                upstream: "AS64500".to_string(),
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,
+            format!("Account: {pda_pubkey}\nThe allocation is pending until the foundation verifies it.\nSignature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n")
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    doublezerocommand::CliCommand, helpers::resolve_contributor_pk,
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::{serializer, types::NetworkV4};
+use doublezero_sdk::{
+    commands::ip_allocation::list::ListIpAllocationCommand, IpAllocation, IpAllocationStatus,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListIpAllocationCliCommand {
+    /// Only list allocations of this contributor Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub contributor: Option<String>,
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub struct IpAllocationDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub account: Pubkey,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub contributor: Pubkey,
+    pub prefix: NetworkV4,
+    pub upstream: String,
+    pub status: IpAllocationStatus,
+}
+
+impl ListIpAllocationCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let contributor_pk = self
+            .contributor
+            .as_deref()
+            .map(|contributor| resolve_contributor_pk(client, contributor))
+            .transpose()?;
+
+        let mut allocations: Vec<(Pubkey, IpAllocation)> = client
+            .list_ip_allocation(ListIpAllocationCommand)?
+            .into_iter()
+            .filter(|(_, allocation)| {
+                contributor_pk.is_none_or(|pk| allocation.contributor_pk == pk)
+            })
+            .collect();
+
+        allocations.sort_by(|(_, a), (_, b)| {
+            a.contributor_pk
+                .cmp(&b.contributor_pk)
+                .then(a.prefix.ip().cmp(&b.prefix.ip()))
+        });
+
+        let allocation_displays: Vec<IpAllocationDisplay> = allocations
+            .into_iter()
+            .map(|(pubkey, allocation)| IpAllocationDisplay {
+                account: pubkey,
+                contributor: allocation.contributor_pk,
+                prefix: allocation.prefix,
+                upstream: allocation.upstream,
+                status: allocation.status,
+            })
+            .collect();
+
+        render_collection(
+            out,
+            allocation_displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::ip_allocation::list::ListIpAllocationCliCommand,
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{AccountType, IpAllocation, IpAllocationStatus};
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_cli_contributor_ip_allocation_list() {
+        let mut client = create_test_client();
+
+        let allocation_pubkey = Pubkey::from_str_const("11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo");
+        let contributor_pubkey =
+            Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+        let allocation = IpAllocation {
+            account_type: AccountType::IpAllocation,
+            owner: Pubkey::default(),
+            bump_seed: 255,
+            status: IpAllocationStatus::Verified,
+            contributor_pk: contributor_pubkey,
+            prefix: "45.76.12.0/24".parse().unwrap(),
+            upstream: "AS64500".to_string(),
+        };
+        client
+            .expect_list_ip_allocation()
+            .returning(move |_| Ok(HashMap::from([(allocation_pubkey, allocation.clone())])));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ListIpAllocationCliCommand {
+                contributor: None,
+                json: false,
+                json_compact: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, " account                                   | contributor                               | prefix        | upstream | status   \n 11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo | 11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V | 45.76.12.0/24 | AS64500  | verified \n");
+
+        let mut output = Vec::new();
+        let res = block_on(
+            ListIpAllocationCliCommand {
+                contributor: None,
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, "[{\"account\":\"11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo\",\"contributor\":\"11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V\",\"prefix\":\"45.76.12.0/24\",\"upstream\":\"AS64500\",\"status\":\"Verified\"}]\n");
+    }
+}
@@ -0,0 +1,93 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_sdk::{
+    commands::ip_allocation::setstatus::SetIpAllocationStatusCommand, IpAllocationStatus,
+};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SetIpAllocationStatusCliCommand {
+    /// Registered prefix
+    #[arg(long)]
+    pub prefix: NetworkV4,
+    /// New status: verified or rejected
+    #[arg(long)]
+    pub status: IpAllocationStatus,
+}
+
+impl SetIpAllocationStatusCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let signature = client.set_ip_allocation_status(SetIpAllocationStatusCommand {
+            prefix: self.prefix,
+            status: self.status,
+        })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contributor::ip_allocation::set_status::SetIpAllocationStatusCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::ip_allocation::setstatus::SetIpAllocationStatusCommand, IpAllocationStatus,
+    };
+    use mockall::predicate;
+    use solana_sdk::signature::Signature;
+
+    #[test]
+    fn test_cli_contributor_ip_allocation_set_status() {
+        let mut client = create_test_client();
+
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_set_ip_allocation_status()
+            .with(predicate::eq(SetIpAllocationStatusCommand {
+                prefix,
+                status: IpAllocationStatus::Verified,
+            }))
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            SetIpAllocationStatusCliCommand {
+                prefix,
+                status: IpAllocationStatus::Verified,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,"Signature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
+        );
+    }
+}
@@ -3,7 +3,8 @@ use clap::Args;
 use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
 use doublezero_sdk::{
-    commands::contributor::list::ListContributorCommand, Contributor, ContributorStatus,
+    commands::contributor::list::ListContributorCommand, labels::LabeledPubkey, Contributor,
+    ContributorStatus,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -26,8 +27,7 @@ pub struct ContributorDisplay {
     pub account: Pubkey,
     pub code: String,
     pub status: ContributorStatus,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListContributorCliCommand {
@@ -37,6 +37,7 @@ impl ListContributorCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let contributors = client.list_contributor(ListContributorCommand {})?;
 
         let mut contributors: Vec<(Pubkey, Contributor)> = contributors.into_iter().collect();
@@ -49,7 +50,7 @@ impl ListContributorCliCommand {
                 account: pubkey,
                 code: tunnel.code,
                 status: tunnel.status,
-                owner: tunnel.owner,
+                owner: book.labeled(&tunnel.owner),
             })
             .collect();
 
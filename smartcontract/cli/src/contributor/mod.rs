@@ -1,5 +1,7 @@
+pub mod api_key;
 pub mod create;
 pub mod delete;
 pub mod get;
+pub mod ip_allocation;
 pub mod list;
 pub mod update;
@@ -0,0 +1,117 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::device::{ackconfig::AckDeviceConfigCommand, get::GetDeviceCommand};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct AckDeviceConfigCliCommand {
+    /// Device Pubkey or code to acknowledge
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+
+    /// Config generation applied to the device (defaults to the current one)
+    #[arg(long)]
+    pub generation: Option<u64>,
+}
+
+impl AckDeviceConfigCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let (pubkey, device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+        let generation = self.generation.unwrap_or(device.config_generation);
+        if generation > device.config_generation {
+            eyre::bail!(
+                "generation {generation} is ahead of the device's config generation {}",
+                device.config_generation
+            );
+        }
+
+        let signature = client.ack_device_config(AckDeviceConfigCommand { pubkey, generation })?;
+        writeln!(out, "Signature: {signature}",)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        device::ackconfig::AckDeviceConfigCliCommand,
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::device::{ackconfig::AckDeviceConfigCommand, get::GetDeviceCommand},
+        get_device_pda, Device,
+    };
+    use mockall::predicate;
+    use solana_sdk::signature::Signature;
+
+    #[test]
+    fn test_cli_device_ack_config() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_device_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let device = Device {
+            code: "test".to_string(),
+            config_generation: 5,
+            applied_config_generation: 3,
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "test".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, device.clone())));
+        client
+            .expect_ack_device_config()
+            .with(predicate::eq(AckDeviceConfigCommand {
+                pubkey: pda_pubkey,
+                generation: 5,
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let ack = |generation| AckDeviceConfigCliCommand {
+            pubkey: "test".to_string(),
+            generation,
+        };
+
+        // Without --generation the current generation is acked.
+        let mut output = Vec::new();
+        block_on(ack(None).execute(&ctx, &client, &mut output)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+
+        // A generation the device has not reached is rejected before sending.
+        let res = block_on(ack(Some(6)).execute(&ctx, &client, &mut Vec::new()));
+        assert!(res.is_err());
+    }
+}
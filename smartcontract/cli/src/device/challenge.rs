@@ -0,0 +1,143 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::device::{
+    get::GetDeviceCommand, issue_challenge::IssueDeviceChallengeCommand,
+};
+use solana_sdk::signature::Keypair;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct IssueDeviceChallengeCliCommand {
+    /// Device Pubkey or code to challenge
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// Challenge nonce as 64 hex characters (random when omitted)
+    #[arg(long, value_parser = parse_nonce)]
+    pub nonce: Option<[u8; 32]>,
+}
+
+/// Parse a 32-byte attestation nonce from 64 hex characters.
+pub fn parse_nonce(val: &str) -> Result<[u8; 32], String> {
+    if val.len() != 64 || !val.is_ascii() {
+        return Err(String::from("nonce must be 64 hex characters"));
+    }
+    let mut nonce = [0u8; 32];
+    for (i, byte) in nonce.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&val[2 * i..2 * i + 2], 16)
+            .map_err(|_| String::from("nonce must be 64 hex characters"))?;
+    }
+    Ok(nonce)
+}
+
+pub fn format_nonce(nonce: &[u8; 32]) -> String {
+    nonce.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl IssueDeviceChallengeCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let nonce = match self.nonce {
+            Some(nonce) if nonce == [0; 32] => eyre::bail!("nonce must not be all zeros"),
+            Some(nonce) => nonce,
+            None => Keypair::new().secret_bytes().to_owned(),
+        };
+
+        let (pubkey, _device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+
+        let signature =
+            client.issue_device_challenge(IssueDeviceChallengeCommand { pubkey, nonce })?;
+        writeln!(out, "Nonce: {}", format_nonce(&nonce))?;
+        writeln!(out, "Signature: {signature}",)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        device::challenge::{format_nonce, parse_nonce, IssueDeviceChallengeCliCommand},
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::device::{get::GetDeviceCommand, issue_challenge::IssueDeviceChallengeCommand},
+        get_device_pda, Device,
+    };
+    use mockall::predicate;
+    use solana_sdk::signature::Signature;
+
+    #[test]
+    fn test_cli_device_parse_nonce() {
+        let hex = "0a".repeat(32);
+        assert_eq!(parse_nonce(&hex), Ok([0x0a; 32]));
+        assert_eq!(format_nonce(&[0x0a; 32]), hex);
+        assert!(parse_nonce("0a").is_err());
+        assert!(parse_nonce(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_cli_device_challenge() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_device_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let device = Device {
+            code: "test".to_string(),
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "test".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, device.clone())));
+        client
+            .expect_issue_device_challenge()
+            .with(predicate::eq(IssueDeviceChallengeCommand {
+                pubkey: pda_pubkey,
+                nonce: [0x0a; 32],
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let challenge = |nonce| IssueDeviceChallengeCliCommand {
+            pubkey: "test".to_string(),
+            nonce,
+        };
+
+        let mut output = Vec::new();
+        block_on(challenge(Some([0x0a; 32])).execute(&ctx, &client, &mut output)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Nonce: {}\nSignature: {signature}\n", "0a".repeat(32))
+        );
+
+        // An all-zero nonce would read as "no challenge" onchain.
+        let res = block_on(challenge(Some([0; 32])).execute(&ctx, &client, &mut Vec::new()));
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    validators::{validate_pubkey, validate_pubkey_or_code},
+};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::device::{
+    get::GetDeviceCommand, grant_co_owner::GrantDeviceCoOwnerCommand,
+    revoke_co_owner::RevokeDeviceCoOwnerCommand,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::{io::Write, str::FromStr};
+
+#[derive(Args, Debug)]
+pub struct GrantDeviceCoOwnerCliCommand {
+    /// Device Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// Pubkey of the operator (e.g. a managed-service provider) given update rights on the device
+    #[arg(long, value_parser = validate_pubkey)]
+    pub co_owner: String,
+}
+
+impl GrantDeviceCoOwnerCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let (pubkey, _device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+
+        let signature = client.grant_device_co_owner(GrantDeviceCoOwnerCommand {
+            pubkey,
+            co_owner_pk: Pubkey::from_str(&self.co_owner)
+                .map_err(|_| eyre::eyre!("Invalid co-owner Pubkey"))?,
+        })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct RevokeDeviceCoOwnerCliCommand {
+    /// Device Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+}
+
+impl RevokeDeviceCoOwnerCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let (pubkey, device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+        if device.co_owner_pk == Pubkey::default() {
+            eyre::bail!("Device {} has no co-owner", device.code);
+        }
+
+        let signature = client.revoke_device_co_owner(RevokeDeviceCoOwnerCommand { pubkey })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        device::co_owner::{GrantDeviceCoOwnerCliCommand, RevokeDeviceCoOwnerCliCommand},
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::device::{
+            get::GetDeviceCommand, grant_co_owner::GrantDeviceCoOwnerCommand,
+            revoke_co_owner::RevokeDeviceCoOwnerCommand,
+        },
+        get_device_pda, Device,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_device_co_owner_grant_revoke() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_device_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let co_owner_pk = Pubkey::new_unique();
+        let device = Device {
+            code: "test".to_string(),
+            ..Default::default()
+        };
+        let co_owned_device = Device {
+            code: "co-owned".to_string(),
+            co_owner_pk,
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "test".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, device.clone())));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "co-owned".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, co_owned_device.clone())));
+        client
+            .expect_grant_device_co_owner()
+            .with(predicate::eq(GrantDeviceCoOwnerCommand {
+                pubkey: pda_pubkey,
+                co_owner_pk,
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+        client
+            .expect_revoke_device_co_owner()
+            .with(predicate::eq(RevokeDeviceCoOwnerCommand {
+                pubkey: pda_pubkey,
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            GrantDeviceCoOwnerCliCommand {
+                pubkey: "test".to_string(),
+                co_owner: co_owner_pk.to_string(),
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+
+        // Revoking on a device without a co-owner is rejected before sending.
+        let res = block_on(
+            RevokeDeviceCoOwnerCliCommand {
+                pubkey: "test".to_string(),
+            }
+            .execute(&ctx, &client, &mut Vec::new()),
+        );
+        assert!(res.is_err());
+
+        let mut output = Vec::new();
+        block_on(
+            RevokeDeviceCoOwnerCliCommand {
+                pubkey: "co-owned".to_string(),
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+    }
+}
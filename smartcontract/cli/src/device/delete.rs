@@ -1,4 +1,5 @@
 use crate::{
+    cascade::print_cascade_preview,
     device::shred_guard::ensure_device_not_enabled_in_shred_subscription,
     doublezerocommand::CliCommand,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
@@ -6,7 +7,10 @@ use crate::{
 };
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::device::{delete::DeleteDeviceCommand, get::GetDeviceCommand};
+use doublezero_sdk::{
+    cascade::DeleteTarget,
+    commands::device::{delete::DeleteDeviceCommand, get::GetDeviceCommand},
+};
 use std::io::Write;
 
 #[derive(Args, Debug)]
@@ -14,6 +18,10 @@ pub struct DeleteDeviceCliCommand {
     /// Device Pubkey to delete
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteDeviceCliCommand {
@@ -23,6 +31,15 @@ impl DeleteDeviceCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let (pubkey, _) = client
+                .get_device(GetDeviceCommand {
+                    pubkey_or_code: self.pubkey,
+                })
+                .map_err(|_| eyre::eyre!("Device not found"))?;
+            return print_cascade_preview(client, DeleteTarget::Device(pubkey), out);
+        }
+
         // Check requirements
         client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
 
@@ -32,7 +49,7 @@ impl DeleteDeviceCliCommand {
             })
             .map_err(|_| eyre::eyre!("Device not found"))?;
 
-        ensure_device_not_enabled_in_shred_subscription(ctx.env, &pubkey).await?;
+        ensure_device_not_enabled_in_shred_subscription(&ctx.env, &pubkey).await?;
 
         let signature = client.delete_device(DeleteDeviceCommand { pubkey })?;
         writeln!(out, "Signature: {signature}",)?;
@@ -175,6 +192,7 @@ mod tests {
         let res = block_on(
             DeleteDeviceCliCommand {
                 pubkey: pda_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -0,0 +1,390 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::device::get::GetDeviceCommand;
+use http_body_util::BodyExt;
+use similar::TextDiff;
+use solana_sdk::pubkey::Pubkey;
+use std::{io::Write, path::PathBuf, time::Duration};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Args, Debug)]
+pub struct DiffDeviceConfigCliCommand {
+    /// Device Pubkey or code
+    #[arg(long, visible_alias = "device", value_parser = validate_pubkey_or_code)]
+    pub code: String,
+    /// Controller gRPC endpoint, e.g. http://controller.example.com:7000
+    #[arg(long)]
+    pub controller: String,
+    /// Saved output of `show running-config` to compare against
+    #[arg(long, conflicts_with = "eapi_url")]
+    pub snapshot: Option<PathBuf>,
+    /// Device eAPI endpoint to read the running config from, e.g.
+    /// https://10.0.0.1/command-api
+    #[arg(long)]
+    pub eapi_url: Option<String>,
+    /// Username for --eapi-url
+    #[arg(long, default_value = "admin", requires = "eapi_url")]
+    pub username: String,
+    /// File holding the password for --eapi-url
+    #[arg(long, requires = "eapi_url")]
+    pub password_file: Option<PathBuf>,
+    /// Accept the device's self-signed certificate
+    #[arg(long, default_value_t = false, requires = "eapi_url")]
+    pub insecure: bool,
+    /// Lines of context around each change
+    #[arg(long, default_value_t = 3)]
+    pub context: usize,
+}
+
+impl DiffDeviceConfigCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (device_pk, device) = client
+            .get_device(GetDeviceCommand {
+                pubkey_or_code: self.code.clone(),
+            })
+            .map_err(|_| eyre::eyre!("Device not found"))?;
+
+        let intended = fetch_intended_config(&self.controller, &device_pk).await?;
+
+        let (actual, source) = if let Some(path) = &self.snapshot {
+            let actual = std::fs::read_to_string(path)
+                .map_err(|e| eyre::eyre!("Failed to read {}: {e}", path.display()))?;
+            (actual, path.display().to_string())
+        } else if let Some(url) = &self.eapi_url {
+            let password = match &self.password_file {
+                Some(path) => std::fs::read_to_string(path)
+                    .map_err(|e| eyre::eyre!("Failed to read {}: {e}", path.display()))?
+                    .trim_end_matches(['\r', '\n'])
+                    .to_string(),
+                None => String::new(),
+            };
+            let actual =
+                fetch_running_config(url, &self.username, &password, self.insecure).await?;
+            (actual, url.clone())
+        } else {
+            // Without the actual config there is nothing to compare; show what
+            // the controller would push.
+            write!(out, "{intended}")?;
+            if !intended.ends_with('\n') {
+                writeln!(out)?;
+            }
+            return Ok(());
+        };
+
+        write_config_diff(
+            &intended,
+            &actual,
+            &format!("intended ({} from controller)", device.code),
+            &format!("actual ({source})"),
+            self.context,
+            out,
+        )
+    }
+}
+
+/// Fetch the config the controller renders for a device, the same way the
+/// device agent does.
+async fn fetch_intended_config(controller: &str, device_pk: &Pubkey) -> eyre::Result<String> {
+    let client = reqwest::Client::builder()
+        .http2_prior_knowledge()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    let url = format!(
+        "{}/controller.Controller/GetConfig",
+        controller.trim_end_matches('/')
+    );
+    let response: http::Response<reqwest::Body> = client
+        .post(&url)
+        .header(http::header::CONTENT_TYPE, "application/grpc")
+        .header(http::header::TE, "trailers")
+        .body(grpc_frame(&encode_config_request(&device_pk.to_string())))
+        .send()
+        .await
+        .map_err(|e| eyre::eyre!("Failed to reach the controller at {controller}: {e}"))?
+        .into();
+
+    if !response.status().is_success() {
+        eyre::bail!("Controller returned HTTP {}", response.status());
+    }
+    let (parts, body) = response.into_parts();
+    let body = body.collect().await?;
+    // Errors come back as a headers-only response; otherwise the status
+    // follows the message in the trailers.
+    let trailers = body.trailers().cloned().unwrap_or_default();
+    let header = |name: &str| {
+        trailers
+            .get(name)
+            .or_else(|| parts.headers.get(name))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let status = header("grpc-status").unwrap_or_else(|| "0".to_string());
+    if status != "0" {
+        eyre::bail!(
+            "Controller refused to render the config (gRPC status {status}): {}",
+            header("grpc-message").unwrap_or_default()
+        );
+    }
+
+    decode_config_response(&body.to_bytes())
+}
+
+/// Read the running config from the device over Arista eAPI.
+async fn fetch_running_config(
+    url: &str,
+    username: &str,
+    password: &str,
+    insecure: bool,
+) -> eyre::Result<String> {
+    let client = reqwest::Client::builder()
+        .tls_danger_accept_invalid_certs(insecure)
+        .timeout(REQUEST_TIMEOUT)
+        .build()?;
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "runCmds",
+        "params": {
+            "version": 1,
+            "cmds": ["show running-config"],
+            "format": "text",
+        },
+        "id": "doublezero",
+    });
+    let response = client
+        .post(url)
+        .basic_auth(username, Some(password))
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(&request)?)
+        .send()
+        .await
+        .map_err(|e| eyre::eyre!("Failed to reach the device at {url}: {e}"))?;
+    if !response.status().is_success() {
+        eyre::bail!("Device returned HTTP {}", response.status());
+    }
+    parse_eapi_response(&response.bytes().await?)
+}
+
+fn parse_eapi_response(data: &[u8]) -> eyre::Result<String> {
+    let response: serde_json::Value = serde_json::from_slice(data)?;
+    if let Some(error) = response.get("error") {
+        eyre::bail!(
+            "Device returned an error: {}",
+            error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error")
+        );
+    }
+    response["result"][0]["output"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| eyre::eyre!("Unexpected eAPI response"))
+}
+
+/// Print a unified diff from the intended to the actual config. Blank lines,
+/// `!` comments and the trailing `end` are left out of the comparison.
+fn write_config_diff<W: Write>(
+    intended: &str,
+    actual: &str,
+    intended_name: &str,
+    actual_name: &str,
+    context: usize,
+    out: &mut W,
+) -> eyre::Result<()> {
+    let intended = normalize_config(intended);
+    let actual = normalize_config(actual);
+    let diff = TextDiff::from_lines(&intended, &actual);
+    if intended == actual {
+        writeln!(out, "The device config matches the intended config")?;
+        return Ok(());
+    }
+    write!(
+        out,
+        "{}",
+        diff.unified_diff()
+            .context_radius(context)
+            .header(intended_name, actual_name)
+    )?;
+    Ok(())
+}
+
+fn normalize_config(config: &str) -> String {
+    config
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            !trimmed.is_empty() && !trimmed.starts_with('!') && trimmed != "end"
+        })
+        .fold(String::new(), |mut normalized, line| {
+            normalized.push_str(line);
+            normalized.push('\n');
+            normalized
+        })
+}
+
+// The controller's gRPC messages are small enough to encode by hand:
+//
+//   message ConfigRequest { string pubkey = 1; ... }
+//   message ConfigResponse { string config = 1; string hash = 2; }
+
+fn encode_config_request(pubkey: &str) -> Vec<u8> {
+    let mut message = vec![0x0a]; // field 1, length-delimited
+    encode_varint(pubkey.len() as u64, &mut message);
+    message.extend_from_slice(pubkey.as_bytes());
+    message
+}
+
+fn decode_config_response(frame: &[u8]) -> eyre::Result<String> {
+    let message = grpc_unframe(frame)?;
+    let mut config = String::new();
+    let mut pos = 0;
+    while pos < message.len() {
+        let key = decode_varint(message, &mut pos)?;
+        match key & 0x7 {
+            0 => {
+                decode_varint(message, &mut pos)?;
+            }
+            1 => pos += 8,
+            2 => {
+                let len = decode_varint(message, &mut pos)? as usize;
+                let value = message
+                    .get(pos..pos + len)
+                    .ok_or_else(|| eyre::eyre!("Truncated controller response"))?;
+                if key >> 3 == 1 {
+                    config = String::from_utf8(value.to_vec())?;
+                }
+                pos += len;
+            }
+            5 => pos += 4,
+            wire_type => eyre::bail!("Unexpected wire type {wire_type} in controller response"),
+        }
+    }
+    Ok(config)
+}
+
+/// Length-prefix a message as an uncompressed gRPC frame.
+fn grpc_frame(message: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(message.len() + 5);
+    frame.push(0);
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(message);
+    frame
+}
+
+fn grpc_unframe(frame: &[u8]) -> eyre::Result<&[u8]> {
+    if frame.len() < 5 {
+        eyre::bail!("Empty controller response");
+    }
+    if frame[0] != 0 {
+        eyre::bail!("Compressed controller responses are not supported");
+    }
+    let len = u32::from_be_bytes([frame[1], frame[2], frame[3], frame[4]]) as usize;
+    frame
+        .get(5..5 + len)
+        .ok_or_else(|| eyre::eyre!("Truncated controller response"))
+}
+
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn decode_varint(buf: &[u8], pos: &mut usize) -> eyre::Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf
+            .get(*pos)
+            .ok_or_else(|| eyre::eyre!("Truncated controller response"))?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    eyre::bail!("Malformed varint in controller response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_request_encoding() {
+        let pubkey = "4uQeVj5tqViQh7yWWGStvkEG1Zmhx6uasJtWCJziofM";
+        let message = encode_config_request(pubkey);
+        assert_eq!(message[0], 0x0a);
+        assert_eq!(message[1] as usize, pubkey.len());
+        assert_eq!(&message[2..], pubkey.as_bytes());
+
+        let frame = grpc_frame(&message);
+        assert_eq!(frame[0], 0);
+        assert_eq!(&frame[1..5], &(message.len() as u32).to_be_bytes());
+        assert_eq!(grpc_unframe(&frame).unwrap(), message.as_slice());
+    }
+
+    #[test]
+    fn test_config_response_decoding() {
+        // A config longer than 127 bytes needs a multi-byte length.
+        let config = "hostname dz1\n".repeat(20);
+        let mut message = vec![0x0a];
+        encode_varint(config.len() as u64, &mut message);
+        message.extend_from_slice(config.as_bytes());
+        // The hash field is skipped.
+        message.extend_from_slice(&[0x12, 0x03, b'a', b'b', b'c']);
+
+        assert_eq!(
+            decode_config_response(&grpc_frame(&message)).unwrap(),
+            config
+        );
+        assert!(decode_config_response(&grpc_frame(&message[..10])).is_err());
+        assert!(decode_config_response(&[]).is_err());
+    }
+
+    #[test]
+    fn test_parse_eapi_response() {
+        let output = parse_eapi_response(
+            br#"{"jsonrpc": "2.0", "id": "doublezero", "result": [{"output": "hostname dz1\n"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(output, "hostname dz1\n");
+
+        let err = parse_eapi_response(
+            br#"{"jsonrpc": "2.0", "id": "doublezero", "error": {"code": 1002, "message": "invalid command"}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid command"));
+    }
+
+    #[test]
+    fn test_config_diff() {
+        let intended =
+            "!\nhostname dz1\n!\ninterface Ethernet1\n   mtu 9000\n   no switchport\n!\nend\n";
+
+        let mut out = Vec::new();
+        let actual = "! device: dz1\nhostname dz1\n\ninterface Ethernet1   \n   mtu 9000\n   no switchport\nend\n";
+        write_config_diff(intended, actual, "intended", "actual", 3, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "The device config matches the intended config\n"
+        );
+
+        let mut out = Vec::new();
+        let actual = "hostname dz1\ninterface Ethernet1\n   mtu 1500\n   no switchport\n";
+        write_config_diff(intended, actual, "intended", "actual", 3, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "--- intended\n+++ actual\n@@ -1,4 +1,4 @@\n hostname dz1\n interface Ethernet1\n-   mtu 9000\n+   mtu 1500\n    no switchport\n"
+        );
+    }
+}
@@ -7,6 +7,7 @@ use doublezero_sdk::{
         contributor::get::GetContributorCommand, device::get::GetDeviceCommand,
         exchange::get::GetExchangeCommand,
     },
+    labels::LabeledPubkey,
     GetLocationCommand, Interface,
 };
 use serde::Serialize;
@@ -25,7 +26,7 @@ pub struct GetDeviceCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct InterfaceDisplay {
+pub(crate) struct InterfaceDisplay {
     pub name: String,
     pub status: String,
     pub interface_type: String,
@@ -70,7 +71,7 @@ impl From<&Interface> for InterfaceDisplay {
 }
 
 #[derive(Tabled, Serialize)]
-struct DeviceDisplay {
+pub(crate) struct DeviceDisplay {
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
     pub account: Pubkey,
     pub code: String,
@@ -91,7 +92,11 @@ struct DeviceDisplay {
     pub dz_prefixes: String,
     #[tabled(display = "crate::util::display_string_vec")]
     pub cyoa_ips: Vec<String>,
-    pub metrics_publisher: String,
+    pub metrics_publisher: LabeledPubkey,
+    pub previous_metrics_publisher: LabeledPubkey,
+    pub metrics_publisher_overlap_until_epoch: u64,
+    pub key_attested: bool,
+    pub attestation_pending: bool,
     pub mgmt_vrf: String,
     #[tabled(skip)]
     pub interfaces: Vec<InterfaceDisplay>,
@@ -108,8 +113,11 @@ struct DeviceDisplay {
     pub desired_status: String,
     pub status: String,
     pub health: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub config_generation: u64,
+    pub applied_config_generation: u64,
+    pub ops_lock: String,
+    pub co_owner: LabeledPubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl GetDeviceCliCommand {
@@ -119,9 +127,11 @@ impl GetDeviceCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (pubkey, device) = client.get_device(GetDeviceCommand {
             pubkey_or_code: self.code,
         })?;
+        let key_attested = device.is_key_attested();
 
         let display = DeviceDisplay {
             account: pubkey,
@@ -153,7 +163,11 @@ impl GetDeviceCliCommand {
                 .filter(|iface| iface.user_tunnel_endpoint)
                 .map(|iface| iface.ip_net.to_string())
                 .collect(),
-            metrics_publisher: device.metrics_publisher_pk.to_string(),
+            metrics_publisher: book.labeled(&device.metrics_publisher_pk),
+            previous_metrics_publisher: book.labeled(&device.previous_metrics_publisher_pk),
+            metrics_publisher_overlap_until_epoch: device.metrics_publisher_overlap_until_epoch,
+            key_attested,
+            attestation_pending: device.attestation_challenge != [0; 32],
             mgmt_vrf: device.mgmt_vrf,
             interfaces: device
                 .interfaces
@@ -173,7 +187,11 @@ impl GetDeviceCliCommand {
             desired_status: device.desired_status.to_string(),
             status: device.status.to_string(),
             health: device.device_health.to_string(),
-            owner: device.owner,
+            config_generation: device.config_generation,
+            applied_config_generation: device.applied_config_generation,
+            ops_lock: device.ops_lock.to_string(),
+            co_owner: book.labeled(&device.co_owner_pk),
+            owner: book.labeled(&device.owner),
         };
 
         if self.json {
@@ -0,0 +1,288 @@
+use crate::{
+    device::interface::types,
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+    validators::{validate_parse_bandwidth, validate_pubkey_or_code},
+};
+use clap::{Args, ValueEnum};
+use doublezero_cli_core::CliContext;
+use doublezero_program_common::validate_iface;
+use doublezero_sdk::commands::device::{
+    get::GetDeviceCommand, interface::bulk_update::BulkUpdateDeviceInterfacesCommand,
+};
+use doublezero_serviceability::{
+    processors::device::interface::DeviceInterfaceFieldsUpdate, state::interface::InterfaceType,
+};
+use serde::Deserialize;
+use std::{io::Write, path::PathBuf};
+
+#[derive(Args, Debug)]
+pub struct ApplyDeviceInterfacesCliCommand {
+    /// Device Pubkey or Code
+    #[arg(long, value_parser = validate_pubkey_or_code, required = true)]
+    pub device: String,
+    /// YAML file listing the interface updates, e.g.
+    ///
+    /// interfaces:
+    ///   - name: Ethernet1
+    ///     status: activated
+    ///     interface_cyoa: gre-over-dia
+    ///     bandwidth: 10Gbps
+    ///     mtu: 1500
+    #[arg(short = 'f', long = "file", required = true)]
+    pub file: PathBuf,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct InterfacesFile {
+    interfaces: Vec<InterfaceSpec>,
+}
+
+/// One interface of the file. Fields left out are not changed. Values take the
+/// same spelling as the `device interface update` flags.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct InterfaceSpec {
+    name: String,
+    loopback_type: Option<String>,
+    interface_cyoa: Option<String>,
+    interface_dia: Option<String>,
+    bandwidth: Option<String>,
+    cir: Option<String>,
+    mtu: Option<u16>,
+    routing_mode: Option<String>,
+    vlan_id: Option<u16>,
+    user_tunnel_endpoint: Option<bool>,
+    status: Option<String>,
+    ip_net: Option<String>,
+}
+
+fn parse_value_enum<T: ValueEnum>(field: &str, value: &Option<String>) -> eyre::Result<Option<T>> {
+    value
+        .as_deref()
+        .map(|value| T::from_str(value, true))
+        .transpose()
+        .map_err(|e| eyre::eyre!("Invalid {field}: {e}"))
+}
+
+fn parse_bandwidth(field: &str, value: &Option<String>) -> eyre::Result<Option<u64>> {
+    value
+        .as_deref()
+        .map(validate_parse_bandwidth)
+        .transpose()
+        .map_err(|e| eyre::eyre!("Invalid {field}: {e}"))
+}
+
+impl InterfaceSpec {
+    fn into_fields(self) -> eyre::Result<DeviceInterfaceFieldsUpdate> {
+        let name = validate_iface(&self.name).map_err(|e| eyre::eyre!(e))?;
+        let with_name = |e: eyre::Report| eyre::eyre!("{name}: {e}");
+        Ok(DeviceInterfaceFieldsUpdate {
+            loopback_type: parse_value_enum::<types::LoopbackType>(
+                "loopback_type",
+                &self.loopback_type,
+            )
+            .map_err(with_name)?
+            .map(Into::into),
+            interface_cyoa: parse_value_enum::<types::InterfaceCYOA>(
+                "interface_cyoa",
+                &self.interface_cyoa,
+            )
+            .map_err(with_name)?
+            .map(Into::into),
+            interface_dia: parse_value_enum::<types::InterfaceDIA>(
+                "interface_dia",
+                &self.interface_dia,
+            )
+            .map_err(with_name)?
+            .map(Into::into),
+            bandwidth: parse_bandwidth("bandwidth", &self.bandwidth).map_err(with_name)?,
+            cir: parse_bandwidth("cir", &self.cir).map_err(with_name)?,
+            mtu: self.mtu,
+            routing_mode: parse_value_enum::<types::RoutingMode>(
+                "routing_mode",
+                &self.routing_mode,
+            )
+            .map_err(with_name)?
+            .map(Into::into),
+            vlan_id: self.vlan_id,
+            user_tunnel_endpoint: self.user_tunnel_endpoint,
+            status: self
+                .status
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(|e| eyre::eyre!("{name}: Invalid status: {e}"))?,
+            ip_net: self
+                .ip_net
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(|e| eyre::eyre!("{name}: Invalid IP network: {e}"))?,
+            name,
+        })
+    }
+}
+
+impl ApplyDeviceInterfacesCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let content = std::fs::read_to_string(&self.file)
+            .map_err(|e| eyre::eyre!("Failed to read {}: {e}", self.file.display()))?;
+        let file: InterfacesFile = serde_yaml::from_str(&content)
+            .map_err(|e| eyre::eyre!("Invalid interfaces file {}: {e}", self.file.display()))?;
+        let updates = file
+            .interfaces
+            .into_iter()
+            .map(InterfaceSpec::into_fields)
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        let (device_pk, device) = client
+            .get_device(GetDeviceCommand {
+                pubkey_or_code: self.device.clone(),
+            })
+            .map_err(|_| eyre::eyre!("Device with pubkey/code '{}' not found", self.device))?;
+
+        // Catch typos before sending; the program re-validates everything else.
+        for update in &updates {
+            let (_, interface) = device
+                .find_interface(&update.name)
+                .map_err(|e| eyre::eyre!("{}: {e}", update.name))?;
+            if interface.interface_type == InterfaceType::Physical && update.loopback_type.is_some()
+            {
+                eyre::bail!(
+                    "{}: Loopback type cannot be set on Physical interface type",
+                    update.name
+                );
+            }
+        }
+
+        let count = updates.len();
+        let signature =
+            client.bulk_update_device_interfaces(BulkUpdateDeviceInterfacesCommand {
+                pubkey: device_pk,
+                updates,
+            })?;
+        writeln!(out, "Signature: {signature}")?;
+        writeln!(out, "Updated {count} interfaces on {}", device.code)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{AccountType, Device, DeviceStatus, Interface};
+    use doublezero_serviceability::state::interface::{InterfaceCYOA, InterfaceStatus};
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_device_interface_apply() {
+        let mut client = create_test_client();
+
+        let signature = Signature::new_unique();
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            code: "device1".to_string(),
+            status: DeviceStatus::Activated,
+            interfaces: ["Ethernet1", "Ethernet2"]
+                .into_iter()
+                .map(|name| Interface {
+                    name: name.to_string(),
+                    interface_type: InterfaceType::Physical,
+                    status: InterfaceStatus::Pending,
+                    mtu: 9000,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "device1".to_string(),
+            }))
+            .returning(move |_| Ok((device_pubkey, device.clone())));
+        client
+            .expect_bulk_update_device_interfaces()
+            .with(predicate::eq(BulkUpdateDeviceInterfacesCommand {
+                pubkey: device_pubkey,
+                updates: vec![
+                    DeviceInterfaceFieldsUpdate {
+                        name: "Ethernet1".to_string(),
+                        status: Some(InterfaceStatus::Activated),
+                        interface_cyoa: Some(InterfaceCYOA::GREOverDIA),
+                        bandwidth: Some(10_000_000_000),
+                        mtu: Some(1500),
+                        ip_net: Some("100.0.0.1/31".parse().unwrap()),
+                        ..Default::default()
+                    },
+                    DeviceInterfaceFieldsUpdate {
+                        name: "Ethernet2".to_string(),
+                        status: Some(InterfaceStatus::Activated),
+                        ..Default::default()
+                    },
+                ],
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = cli_context_default_for_tests();
+        let apply = |yaml: &str| {
+            let file = dir.path().join("ifaces.yaml");
+            std::fs::write(&file, yaml).unwrap();
+            let mut output = Vec::new();
+            let res = block_on(
+                ApplyDeviceInterfacesCliCommand {
+                    device: "device1".to_string(),
+                    file,
+                }
+                .execute(&ctx, &client, &mut output),
+            );
+            res.map(|_| String::from_utf8(output).unwrap())
+        };
+
+        let output = apply(
+            "interfaces:
+  - name: ethernet1
+    status: activated
+    interface_cyoa: gre-over-dia
+    bandwidth: 10Gbps
+    mtu: 1500
+    ip_net: 100.0.0.1/31
+  - name: Ethernet2
+    status: activated
+",
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            format!("Signature: {signature}\nUpdated 2 interfaces on device1\n")
+        );
+
+        // Unknown interfaces and unknown keys are rejected before sending.
+        let err = apply("interfaces:\n  - name: Ethernet9\n    mtu: 9000\n").unwrap_err();
+        assert!(err.to_string().contains("Ethernet9"), "{err}");
+        let err = apply("interfaces:\n  - name: Ethernet1\n    speed: 10Gbps\n").unwrap_err();
+        assert!(err.to_string().contains("speed"), "{err}");
+    }
+}
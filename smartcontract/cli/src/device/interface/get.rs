@@ -2,8 +2,9 @@ use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::validate_iface;
-use doublezero_sdk::commands::{
-    device::get::GetDeviceCommand, topology::list::ListTopologyCommand,
+use doublezero_sdk::{
+    commands::{device::get::GetDeviceCommand, topology::list::ListTopologyCommand},
+    labels::LabeledPubkey,
 };
 use serde::Serialize;
 use std::io::Write;
@@ -23,7 +24,7 @@ pub struct GetDeviceInterfaceCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct InterfaceDisplay {
+pub(crate) struct InterfaceDisplay {
     pub name: String,
     pub status: String,
     pub loopback_type: String,
@@ -37,7 +38,7 @@ struct InterfaceDisplay {
     pub node_segment_idx: u16,
     pub user_tunnel_endpoint: bool,
     pub flex_algo_node_segments: String,
-    pub device_pk: String,
+    pub device_pk: LabeledPubkey,
 }
 
 impl GetDeviceInterfaceCliCommand {
@@ -47,6 +48,7 @@ impl GetDeviceInterfaceCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (device_pk, device) = client.get_device(GetDeviceCommand {
             pubkey_or_code: self.device,
         })?;
@@ -97,7 +99,7 @@ impl GetDeviceInterfaceCliCommand {
             node_segment_idx: interface.node_segment_idx,
             user_tunnel_endpoint: interface.user_tunnel_endpoint,
             flex_algo_node_segments,
-            device_pk: device_pk.to_string(),
+            device_pk: book.labeled(&device_pk),
         };
 
         if self.json {
@@ -1,6 +1,8 @@
+pub mod apply;
 pub mod create;
 pub mod delete;
 pub mod get;
 pub mod list;
+pub mod status;
 pub mod types;
 pub mod update;
@@ -0,0 +1,224 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
+use chrono::{TimeZone, Utc};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::{
+    commands::{
+        device::get::GetDeviceCommand, interface_oper_status::get::GetInterfaceOperStatusCommand,
+    },
+    InterfaceOperStatusEntry,
+};
+use serde::Serialize;
+use std::io::Write;
+use tabled::{settings::Style, Table, Tabled};
+
+#[derive(Args, Debug)]
+pub struct StatusDeviceInterfaceCliCommand {
+    /// Device Pubkey or Code
+    #[arg(value_parser = validate_pubkey_or_code, required = true)]
+    pub device: String,
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub struct DeviceInterfaceStatusDisplay {
+    pub name: String,
+    pub configured: String,
+    pub oper_status: String,
+    pub rx_dbm: String,
+    pub tx_dbm: String,
+    pub in_errors: String,
+    pub out_errors: String,
+    pub in_discards: String,
+    pub out_discards: String,
+    pub updated_at: String,
+}
+
+impl StatusDeviceInterfaceCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (device_pk, device) = client
+            .get_device(GetDeviceCommand {
+                pubkey_or_code: self.device,
+            })
+            .map_err(|_| eyre::eyre!("Device not found"))?;
+
+        // Nothing has been reported until the health oracle first writes the account.
+        let oper_status = client
+            .get_interface_oper_status(GetInterfaceOperStatusCommand { device_pk })
+            .ok()
+            .map(|(_, oper_status)| oper_status);
+
+        let displays: Vec<DeviceInterfaceStatusDisplay> = device
+            .interfaces
+            .iter()
+            .map(|iface| {
+                let entry = oper_status.as_ref().and_then(|s| s.find(&iface.name));
+                build_display(&iface.name, &iface.status.to_string(), entry)
+            })
+            .collect();
+
+        let res = if self.json {
+            serde_json::to_string_pretty(&displays)?
+        } else if self.json_compact {
+            serde_json::to_string(&displays)?
+        } else {
+            Table::new(displays)
+                .with(Style::psql().remove_horizontals())
+                .to_string()
+        };
+
+        writeln!(out, "{res}")?;
+
+        Ok(())
+    }
+}
+
+fn build_display(
+    name: &str,
+    configured: &str,
+    entry: Option<&InterfaceOperStatusEntry>,
+) -> DeviceInterfaceStatusDisplay {
+    let unreported = || "-".to_string();
+    let light_level = |dbm: Option<f64>| dbm.map(|v| format!("{v:.2}")).unwrap_or_else(unreported);
+
+    DeviceInterfaceStatusDisplay {
+        name: name.to_string(),
+        configured: configured.to_string(),
+        oper_status: entry
+            .map(|e| e.oper_status.to_string())
+            .unwrap_or_else(unreported),
+        rx_dbm: light_level(entry.and_then(|e| e.rx_power_dbm())),
+        tx_dbm: light_level(entry.and_then(|e| e.tx_power_dbm())),
+        in_errors: entry
+            .map(|e| e.in_errors.to_string())
+            .unwrap_or_else(unreported),
+        out_errors: entry
+            .map(|e| e.out_errors.to_string())
+            .unwrap_or_else(unreported),
+        in_discards: entry
+            .map(|e| e.in_discards.to_string())
+            .unwrap_or_else(unreported),
+        out_discards: entry
+            .map(|e| e.out_discards.to_string())
+            .unwrap_or_else(unreported),
+        updated_at: entry
+            .and_then(|e| Utc.timestamp_opt(e.updated_at, 0).single())
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(unreported),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        device::interface::status::StatusDeviceInterfaceCliCommand,
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::{
+            device::get::GetDeviceCommand,
+            interface_oper_status::get::GetInterfaceOperStatusCommand,
+        },
+        AccountType, Device, Interface, InterfaceOperState, InterfaceOperStatus,
+        InterfaceOperStatusEntry,
+    };
+    use doublezero_serviceability::state::{
+        interface::InterfaceStatus, interface_oper_status::LIGHT_LEVEL_UNAVAILABLE,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_cli_device_interface_status() {
+        let mut client = create_test_client();
+
+        let device_pk = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            code: "dz1".to_string(),
+            interfaces: vec![
+                Interface {
+                    status: InterfaceStatus::Activated,
+                    name: "Ethernet1".to_string(),
+                    ..Default::default()
+                },
+                Interface {
+                    status: InterfaceStatus::Activated,
+                    name: "Ethernet2".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let oper_status = InterfaceOperStatus {
+            account_type: AccountType::InterfaceOperStatus,
+            device_pk,
+            updated_at: 1_760_000_000,
+            interfaces: vec![InterfaceOperStatusEntry {
+                name: "ethernet1".to_string(),
+                oper_status: InterfaceOperState::LowerLayerDown,
+                rx_power_mdbm: -31_250,
+                tx_power_mdbm: LIGHT_LEVEL_UNAVAILABLE,
+                in_errors: 17,
+                updated_at: 1_760_000_000,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "dz1".to_string(),
+            }))
+            .returning(move |_| Ok((device_pk, device.clone())));
+        client
+            .expect_get_interface_oper_status()
+            .with(predicate::eq(GetInterfaceOperStatusCommand { device_pk }))
+            .returning(move |_| Ok((Pubkey::new_unique(), oper_status.clone())));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            StatusDeviceInterfaceCliCommand {
+                device: "dz1".to_string(),
+                json: false,
+                json_compact: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok(), "{:?}", res.err());
+        let output_str = String::from_utf8(output).unwrap();
+        let row = |name: &str| {
+            output_str
+                .lines()
+                .find(|l| l.contains(name))
+                .unwrap_or_else(|| panic!("no row for {name}:\n{output_str}"))
+                .to_string()
+        };
+
+        let ethernet1 = row("Ethernet1");
+        assert!(ethernet1.contains("activated"), "{ethernet1}");
+        assert!(ethernet1.contains("lower-layer-down"), "{ethernet1}");
+        assert!(ethernet1.contains("-31.25"), "{ethernet1}");
+        assert!(ethernet1.contains("17"), "{ethernet1}");
+        assert!(ethernet1.contains("2025-10-09 08:53:20 UTC"), "{ethernet1}");
+
+        // Interfaces the oracle has not reported on show as such.
+        let ethernet2 = row("Ethernet2");
+        assert!(ethernet2.contains("activated"), "{ethernet2}");
+        assert!(!ethernet2.contains("up"), "{ethernet2}");
+    }
+}
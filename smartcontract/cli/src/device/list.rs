@@ -9,6 +9,7 @@ use doublezero_sdk::{
         exchange::list::ListExchangeCommand,
         location::list::ListLocationCommand,
     },
+    labels::LabeledPubkey,
     DeviceStatus, DeviceType,
 };
 use doublezero_serviceability::state::device::{DeviceDesiredStatus, DeviceHealth};
@@ -52,6 +53,9 @@ pub struct ListDeviceCliCommand {
     /// Narrow table output: drops dz_prefixes, mgmt_vrf, and owner.
     #[arg(long, default_value_t = false)]
     pub narrow: bool,
+    /// Only list devices whose latest config generation the controller has not acknowledged
+    #[arg(long, default_value_t = false)]
+    pub lagging: bool,
 }
 
 #[derive(Tabled, Serialize)]
@@ -108,8 +112,11 @@ pub struct DeviceDisplay {
     pub metrics_publisher_pk: Pubkey,
     #[tabled(skip)]
     pub reference_count: u32,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    #[tabled(skip)]
+    pub config_generation: u64,
+    #[tabled(skip)]
+    pub applied_config_generation: u64,
+    pub owner: LabeledPubkey,
 }
 
 /// Narrow variant of [`DeviceDisplay`] for terminals: drops dz_prefixes,
@@ -160,6 +167,7 @@ impl ListDeviceCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let contributors = client.list_contributor(ListContributorCommand {})?;
         let locations = client.list_location(ListLocationCommand {})?;
         let exchanges = client.list_exchange(ListExchangeCommand {})?;
@@ -238,6 +246,10 @@ impl ListDeviceCliCommand {
             devices.retain(|_, device| device.code.contains(code_filter));
         }
 
+        if self.lagging {
+            devices.retain(|_, device| device.is_config_lagging());
+        }
+
         let mut device_displays: Vec<DeviceDisplay> = devices
             .into_iter()
             .map(|(pubkey, device)| {
@@ -291,10 +303,12 @@ impl ListDeviceCliCommand {
                     multicast_publishers_count: device.multicast_publishers_count,
                     max_multicast_publishers: device.max_multicast_publishers,
                     reference_count: device.reference_count,
+                    config_generation: device.config_generation,
+                    applied_config_generation: device.applied_config_generation,
                     health: device.device_health,
                     desired_status: device.desired_status,
                     metrics_publisher_pk: device.metrics_publisher_pk,
-                    owner: device.owner,
+                    owner: book.labeled(&device.owner),
                 }
             })
             .collect();
@@ -465,6 +479,7 @@ mod tests {
                 json: false,
                 json_compact: false,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -486,12 +501,13 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
         assert!(res.is_ok());
         let output_str = String::from_utf8(output).unwrap();
-        assert_eq!(output_str, "[{\"account\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB\",\"code\":\"device1_code\",\"bump_seed\":2,\"location_pk\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR\",\"contributor_code\":\"contributor1_code\",\"location_code\":\"location1_code\",\"location_name\":\"location1_name\",\"exchange_pk\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPA\",\"exchange_code\":\"exchange1_code\",\"exchange_name\":\"exchange1_name\",\"device_type\":\"Hybrid\",\"public_ip\":\"1.2.3.4\",\"dz_prefixes\":\"1.2.3.4/32\",\"cyoa_ips\":[],\"users\":0,\"max_users\":255,\"unicast_users_count\":0,\"max_unicast_users\":0,\"multicast_subscribers_count\":0,\"max_multicast_subscribers\":0,\"multicast_publishers_count\":0,\"max_multicast_publishers\":0,\"status\":\"Activated\",\"health\":\"ReadyForUsers\",\"desired_status\":\"Activated\",\"mgmt_vrf\":\"default\",\"metrics_publisher_pk\":\"11111111111111111111111111111111\",\"reference_count\":0,\"owner\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB\"}]\n");
+        assert_eq!(output_str, "[{\"account\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB\",\"code\":\"device1_code\",\"bump_seed\":2,\"location_pk\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR\",\"contributor_code\":\"contributor1_code\",\"location_code\":\"location1_code\",\"location_name\":\"location1_name\",\"exchange_pk\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPA\",\"exchange_code\":\"exchange1_code\",\"exchange_name\":\"exchange1_name\",\"device_type\":\"Hybrid\",\"public_ip\":\"1.2.3.4\",\"dz_prefixes\":\"1.2.3.4/32\",\"cyoa_ips\":[],\"users\":0,\"max_users\":255,\"unicast_users_count\":0,\"max_unicast_users\":0,\"multicast_subscribers_count\":0,\"max_multicast_subscribers\":0,\"multicast_publishers_count\":0,\"max_multicast_publishers\":0,\"status\":\"Activated\",\"health\":\"ReadyForUsers\",\"desired_status\":\"Activated\",\"mgmt_vrf\":\"default\",\"metrics_publisher_pk\":\"11111111111111111111111111111111\",\"reference_count\":0,\"config_generation\":0,\"applied_config_generation\":0,\"owner\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB\"}]\n");
         // Narrow output: drops dz_prefixes, mgmt_vrf, owner; fits within 240 cols.
         let mut output = Vec::new();
         let res = block_on(
@@ -507,6 +523,7 @@ mod tests {
                 json: false,
                 json_compact: false,
                 narrow: true,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -701,6 +718,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -867,6 +885,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1032,6 +1051,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1215,6 +1235,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1404,6 +1425,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1589,6 +1611,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1772,6 +1795,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1938,6 +1962,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2103,6 +2128,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2112,6 +2138,69 @@ mod tests {
         assert!(!output_str.contains("device2"));
     }
 
+    #[test]
+    fn test_cli_device_list_filter_lagging() {
+        let mut client = create_test_client();
+
+        client
+            .expect_list_location()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_exchange()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_contributor()
+            .returning(|_| Ok(HashMap::new()));
+
+        let device1_pubkey = Pubkey::new_unique();
+        let device1 = Device {
+            code: "device1".to_string(),
+            config_generation: 4,
+            applied_config_generation: 4,
+            ..Default::default()
+        };
+        let device2_pubkey = Pubkey::new_unique();
+        let device2 = Device {
+            code: "device2".to_string(),
+            config_generation: 5,
+            applied_config_generation: 3,
+            ..Default::default()
+        };
+
+        client.expect_list_device().returning(move |_| {
+            let mut devices = HashMap::new();
+            devices.insert(device1_pubkey, device1.clone());
+            devices.insert(device2_pubkey, device2.clone());
+            Ok(devices)
+        });
+
+        // Only device2 has config the controller has not acknowledged yet.
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ListDeviceCliCommand {
+                contributor: None,
+                exchange: None,
+                location: None,
+                device_type: None,
+                status: None,
+                health: None,
+                desired_status: None,
+                code: None,
+                json: false,
+                json_compact: true,
+                narrow: false,
+                lagging: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(!output_str.contains("device1"));
+        assert!(output_str.contains("device2"));
+        assert!(output_str.contains("\"config_generation\":5,\"applied_config_generation\":3"));
+    }
+
     #[test]
     fn test_cli_device_list_json_pretty() {
         let mut client = create_test_client();
@@ -2235,6 +2324,7 @@ mod tests {
                 json: true,
                 json_compact: false,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2278,6 +2368,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2322,6 +2413,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2365,6 +2457,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2408,6 +2501,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2451,6 +2545,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2491,6 +2586,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2531,6 +2627,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2571,6 +2668,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2791,6 +2889,7 @@ mod tests {
                 json: false,
                 json_compact: true,
                 narrow: false,
+                lagging: false,
             }
             .execute(&ctx, &client, &mut output),
         );
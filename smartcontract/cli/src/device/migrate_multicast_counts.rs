@@ -12,12 +12,10 @@ use doublezero_serviceability::state::user::TunnelFlags;
 use solana_sdk::pubkey::Pubkey;
 use std::{collections::HashMap, io::Write};
 
+/// Honours the global `--dry-run` flag: prints what would be corrected
+/// without submitting transactions.
 #[derive(Args, Debug)]
-pub struct MigrateMulticastCountsCliCommand {
-    /// Print what would be corrected without submitting transactions
-    #[arg(long, default_value_t = false)]
-    pub dry_run: bool,
-}
+pub struct MigrateMulticastCountsCliCommand;
 
 impl MigrateMulticastCountsCliCommand {
     pub async fn execute<C: CliCommand, W: Write>(
@@ -78,7 +76,7 @@ impl MigrateMulticastCountsCliCommand {
             )?;
         }
 
-        if self.dry_run {
+        if client.is_dry_run() {
             writeln!(out, "[dry-run] no transactions sent.")?;
             return Ok(());
         }
@@ -198,6 +196,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
@@ -230,8 +229,10 @@ mod tests {
             .returning(move |_| Ok(devices.clone()));
         client.expect_update_device().times(0);
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateMulticastCountsCliCommand { dry_run: false }
+        let res = MigrateMulticastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -272,8 +273,10 @@ mod tests {
             .times(1)
             .returning(|_| Ok(Signature::new_unique()));
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateMulticastCountsCliCommand { dry_run: false }
+        let res = MigrateMulticastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -320,8 +323,10 @@ mod tests {
             .times(1)
             .returning(|_| Ok(Signature::new_unique()));
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateMulticastCountsCliCommand { dry_run: false }
+        let res = MigrateMulticastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -354,8 +359,10 @@ mod tests {
             .returning(move |_| Ok(devices.clone()));
         client.expect_update_device().times(0); // must NOT be called
 
+        client.expect_is_dry_run().return_const(true);
+
         let mut out = Vec::new();
-        let res = MigrateMulticastCountsCliCommand { dry_run: true }
+        let res = MigrateMulticastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -409,8 +416,10 @@ mod tests {
         // publishers-list tally would flip both devices and call update twice.
         client.expect_update_device().times(0);
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateMulticastCountsCliCommand { dry_run: false }
+        let res = MigrateMulticastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
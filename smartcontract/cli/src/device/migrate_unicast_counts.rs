@@ -11,12 +11,10 @@ use doublezero_sdk::{
 use solana_sdk::pubkey::Pubkey;
 use std::{collections::HashMap, io::Write};
 
+/// Honours the global `--dry-run` flag: prints what would be corrected
+/// without submitting transactions.
 #[derive(Args, Debug)]
-pub struct MigrateUnicastCountsCliCommand {
-    /// Print what would be corrected without submitting transactions
-    #[arg(long, default_value_t = false)]
-    pub dry_run: bool,
-}
+pub struct MigrateUnicastCountsCliCommand;
 
 impl MigrateUnicastCountsCliCommand {
     pub async fn execute<C: CliCommand, W: Write>(
@@ -64,7 +62,7 @@ impl MigrateUnicastCountsCliCommand {
             )?;
         }
 
-        if self.dry_run {
+        if client.is_dry_run() {
             writeln!(out, "[dry-run] no transactions sent.")?;
             return Ok(());
         }
@@ -198,6 +196,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
@@ -221,8 +220,10 @@ mod tests {
             .returning(move |_| Ok(devices.clone()));
         client.expect_update_device().times(0);
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateUnicastCountsCliCommand { dry_run: false }
+        let res = MigrateUnicastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -252,8 +253,10 @@ mod tests {
             .times(1)
             .returning(|_| Ok(Signature::new_unique()));
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateUnicastCountsCliCommand { dry_run: false }
+        let res = MigrateUnicastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -292,8 +295,10 @@ mod tests {
             .times(1)
             .returning(|_| Ok(Signature::new_unique()));
 
+        client.expect_is_dry_run().return_const(false);
+
         let mut out = Vec::new();
-        let res = MigrateUnicastCountsCliCommand { dry_run: false }
+        let res = MigrateUnicastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -320,8 +325,10 @@ mod tests {
             .returning(move |_| Ok(devices.clone()));
         client.expect_update_device().times(0);
 
+        client.expect_is_dry_run().return_const(true);
+
         let mut out = Vec::new();
-        let res = MigrateUnicastCountsCliCommand { dry_run: true }
+        let res = MigrateUnicastCountsCliCommand
             .execute(&create_test_context(), &client, &mut out)
             .await;
         assert!(res.is_ok());
@@ -1,10 +1,17 @@
+pub mod ackconfig;
+pub mod challenge;
+pub mod co_owner;
 pub mod create;
 pub mod delete;
+pub mod diff;
 pub mod get;
 pub mod interface;
 pub mod list;
+pub mod lock;
 pub mod migrate_multicast_counts;
 pub mod migrate_unicast_counts;
+pub mod prove_key;
+pub mod rotate_metrics_publisher;
 pub mod sethealth;
 pub mod shred_guard;
 pub mod update;
@@ -0,0 +1,139 @@
+use crate::{
+    device::challenge::parse_nonce,
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+    validators::validate_pubkey_or_code,
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::device::{get::GetDeviceCommand, prove_key::ProveDeviceKeyCommand};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct ProveDeviceKeyCliCommand {
+    /// Device Pubkey or code whose challenge to answer
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// Challenge nonce as 64 hex characters, as printed by `device challenge`
+    #[arg(long, value_parser = parse_nonce)]
+    pub nonce: [u8; 32],
+}
+
+impl ProveDeviceKeyCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let (pubkey, device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+        let payer = client.get_payer();
+        if device.metrics_publisher_pk != payer {
+            eyre::bail!(
+                "{payer} is not the metrics publisher of {}; run with the device's key (--keypair)",
+                device.code
+            );
+        }
+        if device.attestation_challenge == [0; 32] {
+            eyre::bail!("{} has no pending attestation challenge", device.code);
+        }
+
+        let signature = client.prove_device_key(ProveDeviceKeyCommand {
+            pubkey,
+            nonce: self.nonce,
+        })?;
+        writeln!(out, "Signature: {signature}",)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        device::prove_key::ProveDeviceKeyCliCommand,
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::device::{get::GetDeviceCommand, prove_key::ProveDeviceKeyCommand},
+        get_device_pda, Device,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_device_prove_key() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_device_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let payer = client.get_payer();
+        let device = Device {
+            code: "test".to_string(),
+            metrics_publisher_pk: payer,
+            attestation_challenge: [0x0a; 32],
+            ..Default::default()
+        };
+        let unchallenged = Device {
+            code: "idle".to_string(),
+            metrics_publisher_pk: payer,
+            ..Default::default()
+        };
+        let other = Device {
+            code: "other".to_string(),
+            metrics_publisher_pk: Pubkey::new_unique(),
+            attestation_challenge: [0x0a; 32],
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .returning(
+                move |cmd: GetDeviceCommand| match cmd.pubkey_or_code.as_str() {
+                    "test" => Ok((pda_pubkey, device.clone())),
+                    "idle" => Ok((pda_pubkey, unchallenged.clone())),
+                    _ => Ok((pda_pubkey, other.clone())),
+                },
+            );
+        client
+            .expect_prove_device_key()
+            .with(predicate::eq(ProveDeviceKeyCommand {
+                pubkey: pda_pubkey,
+                nonce: [0x0a; 32],
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let prove = |code: &str| ProveDeviceKeyCliCommand {
+            pubkey: code.to_string(),
+            nonce: [0x0a; 32],
+        };
+
+        let mut output = Vec::new();
+        block_on(prove("test").execute(&ctx, &client, &mut output)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+
+        // Nothing is sent without a pending challenge or from a key other than the publisher.
+        let res = block_on(prove("idle").execute(&ctx, &client, &mut Vec::new()));
+        assert!(res.is_err());
+        let res = block_on(prove("other").execute(&ctx, &client, &mut Vec::new()));
+        assert!(res.is_err());
+    }
+}
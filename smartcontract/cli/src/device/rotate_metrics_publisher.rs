@@ -0,0 +1,137 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+    validators::{validate_pubkey, validate_pubkey_or_code},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::device::{
+    get::GetDeviceCommand, rotate_metrics_publisher::RotateDeviceMetricsPublisherCommand,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::{io::Write, str::FromStr};
+
+#[derive(Args, Debug)]
+pub struct RotateDeviceMetricsPublisherCliCommand {
+    /// Device Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// New metrics publisher Pubkey
+    #[arg(long, value_parser = validate_pubkey)]
+    pub metrics_publisher: String,
+    /// Epochs after the current one during which the current publisher is still accepted
+    #[arg(long, default_value_t = 1)]
+    pub overlap_epochs: u64,
+}
+
+impl RotateDeviceMetricsPublisherCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let metrics_publisher_pk = if self.metrics_publisher == "me" {
+            client.get_payer()
+        } else {
+            Pubkey::from_str(&self.metrics_publisher)
+                .map_err(|_| eyre::eyre!("Invalid metrics publisher Pubkey"))?
+        };
+
+        let (pubkey, device) = client.get_device(GetDeviceCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+        if device.metrics_publisher_pk == metrics_publisher_pk {
+            eyre::bail!(
+                "{metrics_publisher_pk} is already the metrics publisher of {}",
+                device.code
+            );
+        }
+
+        let signature =
+            client.rotate_device_metrics_publisher(RotateDeviceMetricsPublisherCommand {
+                pubkey,
+                metrics_publisher_pk,
+                overlap_epochs: self.overlap_epochs,
+            })?;
+        writeln!(out, "Signature: {signature}",)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        device::rotate_metrics_publisher::RotateDeviceMetricsPublisherCliCommand,
+        doublezerocommand::CliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::device::{
+            get::GetDeviceCommand, rotate_metrics_publisher::RotateDeviceMetricsPublisherCommand,
+        },
+        get_device_pda, Device,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_device_rotate_metrics_publisher() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_device_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let current_publisher = Pubkey::new_unique();
+        let new_publisher = Pubkey::new_unique();
+        let device = Device {
+            code: "test".to_string(),
+            metrics_publisher_pk: current_publisher,
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_device()
+            .with(predicate::eq(GetDeviceCommand {
+                pubkey_or_code: "test".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, device.clone())));
+        client
+            .expect_rotate_device_metrics_publisher()
+            .with(predicate::eq(RotateDeviceMetricsPublisherCommand {
+                pubkey: pda_pubkey,
+                metrics_publisher_pk: new_publisher,
+                overlap_epochs: 3,
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let rotate = |metrics_publisher: Pubkey| RotateDeviceMetricsPublisherCliCommand {
+            pubkey: "test".to_string(),
+            metrics_publisher: metrics_publisher.to_string(),
+            overlap_epochs: 3,
+        };
+
+        let mut output = Vec::new();
+        block_on(rotate(new_publisher).execute(&ctx, &client, &mut output)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+
+        // Rotating to the current publisher is rejected before sending.
+        let res = block_on(rotate(current_publisher).execute(&ctx, &client, &mut Vec::new()));
+        assert!(res.is_err());
+    }
+}
@@ -58,7 +58,7 @@ struct DeviceHistoryHeader {
 /// a potentially unsafe delete. Environments without a deployed
 /// shred-subscription program (devnet, local) are skipped.
 pub async fn ensure_device_not_enabled_in_shred_subscription(
-    env: Environment,
+    env: &Environment,
     device_pubkey: &Pubkey,
 ) -> eyre::Result<()> {
     let Some(cfg) = env.shred_subscription_config() else {
@@ -363,12 +363,12 @@ mod tests {
         // a no-op and makes no network call.
         let device = Pubkey::new_unique();
         assert!(block_on(ensure_device_not_enabled_in_shred_subscription(
-            Environment::Devnet,
+            &Environment::Devnet,
             &device
         ))
         .is_ok());
         assert!(block_on(ensure_device_not_enabled_in_shred_subscription(
-            Environment::Local,
+            &Environment::Local,
             &device
         ))
         .is_ok());
@@ -1,5 +1,6 @@
 use crate::{
     doublezerocommand::CliCommand,
+    helpers::check_status_transition,
     poll_for_activation::poll_for_device_activated,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
     validators::{validate_code, validate_pubkey, validate_pubkey_or_code},
@@ -99,6 +100,9 @@ impl UpdateDeviceCliCommand {
         })?;
 
         let devices = client.list_device(ListDeviceCommand)?;
+        if let (Some(status), Some(device)) = (self.status, devices.get(&pubkey)) {
+            check_status_transition(device.status, status, "UpdateDevice")?;
+        }
         if let Some(code) = &self.code {
             if devices
                 .iter()
@@ -3,7 +3,8 @@ use doublezero_sdk::{
     commands::{
         accesspass::{
             close::CloseAccessPassCommand, get::GetAccessPassCommand, list::ListAccessPassCommand,
-            set::SetAccessPassCommand,
+            set::SetAccessPassCommand, set_batch::SetAccessPassBatchCommand,
+            settle_lease::SettleAccessPassLeaseCommand,
         },
         allowlist::{
             foundation::{
@@ -15,21 +16,34 @@ use doublezero_sdk::{
                 remove::RemoveQaAllowlistCommand,
             },
         },
+        anycast_prefix::{
+            create::CreateAnycastPrefixCommand, delete::DeleteAnycastPrefixCommand,
+            get::GetAnycastPrefixCommand, list::ListAnycastPrefixCommand,
+            update::UpdateAnycastPrefixCommand,
+        },
         contributor::{
             create::CreateContributorCommand, delete::DeleteContributorCommand,
-            get::GetContributorCommand, list::ListContributorCommand,
-            resume::ResumeContributorCommand, suspend::SuspendContributorCommand,
-            update::UpdateContributorCommand,
+            emergency_suspend::EmergencySuspendContributorCommand, get::GetContributorCommand,
+            list::ListContributorCommand, resume::ResumeContributorCommand,
+            suspend::SuspendContributorCommand, update::UpdateContributorCommand,
         },
         device::{
+            ackconfig::AckDeviceConfigCommand,
             create::CreateDeviceCommand,
             delete::DeleteDeviceCommand,
             get::GetDeviceCommand,
+            grant_co_owner::GrantDeviceCoOwnerCommand,
             interface::{
+                bulk_update::BulkUpdateDeviceInterfacesCommand,
                 create::CreateDeviceInterfaceCommand, delete::DeleteDeviceInterfaceCommand,
                 update::UpdateDeviceInterfaceCommand,
             },
+            issue_challenge::IssueDeviceChallengeCommand,
             list::ListDeviceCommand,
+            prove_key::ProveDeviceKeyCommand,
+            revoke_co_owner::RevokeDeviceCoOwnerCommand,
+            rotate_metrics_publisher::RotateDeviceMetricsPublisherCommand,
+            set_ops_lock::SetDeviceOpsLockCommand,
             sethealth::SetDeviceHealthCommand,
             update::UpdateDeviceCommand,
         },
@@ -46,18 +60,31 @@ use doublezero_sdk::{
         globalstate::{
             init::InitGlobalStateCommand, setairdrop::SetAirdropCommand,
             setauthority::SetAuthorityCommand, setfeatureflags::SetFeatureFlagsCommand,
-            setversion::SetVersionCommand,
+            setpause::SetPauseCommand, setversion::SetVersionCommand,
+        },
+        interface_oper_status::get::GetInterfaceOperStatusCommand,
+        ip_allocation::{
+            create::CreateIpAllocationCommand, delete::DeleteIpAllocationCommand,
+            list::ListIpAllocationCommand, setstatus::SetIpAllocationStatusCommand,
         },
         link::{
-            accept::AcceptLinkCommand, create::CreateLinkCommand, delete::DeleteLinkCommand,
-            get::GetLinkCommand, latency::LatencyLinkCommand, list::ListLinkCommand,
-            sethealth::SetLinkHealthCommand, update::UpdateLinkCommand,
+            accept::AcceptLinkCommand,
+            burnin::{BurnInLinkCommand, BurnInReport},
+            create::CreateLinkCommand,
+            delete::DeleteLinkCommand,
+            get::GetLinkCommand,
+            latency::LatencyLinkCommand,
+            list::ListLinkCommand,
+            set_ops_lock::SetLinkOpsLockCommand,
+            sethealth::SetLinkHealthCommand,
+            update::UpdateLinkCommand,
         },
         location::{
             create::CreateLocationCommand, delete::DeleteLocationCommand, get::GetLocationCommand,
             list::ListLocationCommand, update::UpdateLocationCommand,
         },
         migrate::MigrateCommand,
+        migrateaccount::{ListPendingMigrationsCommand, MigrateAccountCommand},
         multicastgroup::{
             allowlist::{
                 publisher::{
@@ -83,12 +110,34 @@ use doublezero_sdk::{
             update::UpdatePermissionCommand,
         },
         programconfig::get::GetProgramConfigCommand,
+        qos_profile::{
+            create::CreateQosProfileCommand, delete::DeleteQosProfileCommand,
+            get::GetQosProfileCommand, list::ListQosProfileCommand,
+            update::UpdateQosProfileCommand,
+        },
+        read_api_key::{
+            create::CreateReadApiKeyCommand, delete::DeleteReadApiKeyCommand,
+            list::ListReadApiKeyCommand, revoke::RevokeReadApiKeyCommand,
+        },
+        referrer_stats::{get::GetReferrerStatsCommand, list::ListReferrerStatsCommand},
         resource::{
             allocate::AllocateResourceCommand,
             closeaccount::{CloseResourceByPubkeyCommand, CloseResourceCommand},
             create::CreateResourceCommand,
             deallocate::DeallocateResourceCommand,
             get::GetResourceCommand,
+            resize::ResizeResourceCommand,
+        },
+        scheduler::history::GetExecutionHistoryCommand,
+        state_commitment::{
+            get::GetStateCommitmentCommand,
+            record::RecordStateCommitmentCommand,
+            snapshot::{SnapshotStateCommand, StateSnapshot},
+        },
+        telemetry::{
+            budget::SetProviderSamplingBudgetCommand,
+            prune::PruneTelemetrySamplesCommand,
+            reclaim::{ReclaimAction, ReclaimTelemetrySamplesCommand},
         },
         tenant::{
             add_administrator::AddAdministratorTenantCommand, create::CreateTenantCommand,
@@ -109,10 +158,15 @@ use doublezero_sdk::{
             requestban::RequestBanUserCommand, update::UpdateUserCommand,
         },
     },
+    health::NetworkHealth,
+    labels::AddressBook,
+    read_doublezero_config,
+    record::receipts::ExecutionRecord,
     telemetry::LinkLatencyStats,
-    DZClient, DZTransaction, Device, DoubleZeroClient, Exchange, Feed, GetGlobalConfigCommand,
-    GetGlobalStateCommand, GlobalConfig, GlobalState, Link, Location, MulticastGroup,
-    ResourceExtensionOwned, TopologyInfo, User,
+    AnycastPrefix, DZClient, DZTransaction, Device, DoubleZeroClient, Exchange, Feed,
+    GetGlobalConfigCommand, GetGlobalStateCommand, GlobalConfig, GlobalState, InterfaceOperStatus,
+    IpAllocation, Link, Location, MulticastGroup, QosProfile, ReadApiKey, ReferrerStats,
+    ResourceExtensionOwned, StateCommitment, TopologyInfo, User,
 };
 use doublezero_serviceability::state::{
     accesspass::AccessPass, accountdata::AccountData, contributor::Contributor,
@@ -123,6 +177,13 @@ use solana_client::rpc_config::RpcProgramAccountsConfig;
 use solana_sdk::{account::Account, pubkey::Pubkey, signature::Signature};
 use std::collections::HashMap;
 
+/// Receives the transactions streamed by [`CliCommand::follow_logs`]. Mockall
+/// cannot mock `dyn FnMut` parameters, hence a named trait.
+pub trait LogHandler {
+    /// Handle one confirmed transaction's logs; return `false` to stop.
+    fn handle(&mut self, signature: String, logs: Vec<String>) -> bool;
+}
+
 #[automock]
 pub trait CliCommand {
     fn check_requirements(&self, checks: u8) -> eyre::Result<()>;
@@ -136,6 +197,12 @@ pub trait CliCommand {
         false
     }
 
+    /// Whether the global `--dry-run` flag is set, in which case transactions
+    /// are recorded instead of sent.
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+
     fn get_program_config(
         &self,
         cmd: GetProgramConfigCommand,
@@ -144,15 +211,21 @@ pub trait CliCommand {
     fn get_program_id(&self) -> Pubkey;
     fn get_environment(&self) -> Environment;
     fn get_payer(&self) -> Pubkey;
+    /// Petnames from the local config, used to label pubkeys in displays.
+    fn get_address_book(&self) -> AddressBook;
     fn get_balance(&self) -> eyre::Result<u64>;
     fn get_epoch(&self) -> eyre::Result<u64>;
+    fn get_slot(&self) -> eyre::Result<u64>;
     fn get_block_time(&self, slot: u64) -> eyre::Result<Option<i64>>;
     fn get_logs(&self, pubkey: &Pubkey) -> eyre::Result<Vec<String>>;
+    fn follow_logs(&self, pubkey: &Pubkey, handler: &mut dyn LogHandler) -> eyre::Result<()>;
     fn get_account(&self, pubkey: Pubkey) -> eyre::Result<Account>;
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> eyre::Result<u64>;
     fn get_multiple_accounts(&self, pubkeys: Vec<Pubkey>) -> eyre::Result<Vec<Option<Account>>>;
     fn transfer_sol(&self, to: Pubkey, lamports: u64) -> eyre::Result<Signature>;
+    fn request_airdrop(&self, lamports: u64) -> eyre::Result<Signature>;
     fn get_all(&self) -> eyre::Result<HashMap<Box<Pubkey>, Box<AccountData>>>;
+    fn network_health(&self) -> eyre::Result<NetworkHealth>;
     fn get_account_data(&self, pubkey: Pubkey) -> eyre::Result<AccountData>;
     fn get_transactions(&self, pubkey: Pubkey) -> eyre::Result<Vec<DZTransaction>>;
     fn get_program_accounts(
@@ -163,6 +236,11 @@ pub trait CliCommand {
 
     fn init_globalstate(&self, cmd: InitGlobalStateCommand) -> eyre::Result<Signature>;
     fn migrate(&self, cmd: MigrateCommand) -> eyre::Result<Vec<Signature>>;
+    fn list_pending_migrations(
+        &self,
+        cmd: ListPendingMigrationsCommand,
+    ) -> eyre::Result<Vec<Pubkey>>;
+    fn migrate_account(&self, cmd: MigrateAccountCommand) -> eyre::Result<Signature>;
     fn get_globalstate(&self, cmd: GetGlobalStateCommand) -> eyre::Result<(Pubkey, GlobalState)>;
     fn get_globalconfig(&self, cmd: GetGlobalConfigCommand)
         -> eyre::Result<(Pubkey, GlobalConfig)>;
@@ -171,6 +249,7 @@ pub trait CliCommand {
     fn set_globalconfig(&self, cmd: SetGlobalConfigCommand) -> eyre::Result<Signature>;
     fn set_minversion(&self, cmd: SetVersionCommand) -> eyre::Result<Signature>;
     fn set_feature_flags(&self, cmd: SetFeatureFlagsCommand) -> eyre::Result<Signature>;
+    fn set_pause(&self, cmd: SetPauseCommand) -> eyre::Result<Signature>;
 
     fn create_location(&self, cmd: CreateLocationCommand) -> eyre::Result<(Signature, Pubkey)>;
     fn get_location(&self, cmd: GetLocationCommand) -> eyre::Result<(Pubkey, Location)>;
@@ -190,6 +269,69 @@ pub trait CliCommand {
     fn list_feed(&self, cmd: ListFeedCommand) -> eyre::Result<HashMap<Pubkey, Feed>>;
     fn update_feed(&self, cmd: UpdateFeedCommand) -> eyre::Result<Signature>;
     fn delete_feed(&self, cmd: DeleteFeedCommand) -> eyre::Result<Signature>;
+    fn create_read_api_key(
+        &self,
+        cmd: CreateReadApiKeyCommand,
+    ) -> eyre::Result<(Signature, Pubkey)>;
+    fn list_read_api_key(
+        &self,
+        cmd: ListReadApiKeyCommand,
+    ) -> eyre::Result<HashMap<Pubkey, ReadApiKey>>;
+    fn revoke_read_api_key(&self, cmd: RevokeReadApiKeyCommand) -> eyre::Result<Signature>;
+    fn delete_read_api_key(&self, cmd: DeleteReadApiKeyCommand) -> eyre::Result<Signature>;
+    fn create_ip_allocation(
+        &self,
+        cmd: CreateIpAllocationCommand,
+    ) -> eyre::Result<(Signature, Pubkey)>;
+    fn list_ip_allocation(
+        &self,
+        cmd: ListIpAllocationCommand,
+    ) -> eyre::Result<HashMap<Pubkey, IpAllocation>>;
+    fn set_ip_allocation_status(
+        &self,
+        cmd: SetIpAllocationStatusCommand,
+    ) -> eyre::Result<Signature>;
+    fn delete_ip_allocation(&self, cmd: DeleteIpAllocationCommand) -> eyre::Result<Signature>;
+    fn get_interface_oper_status(
+        &self,
+        cmd: GetInterfaceOperStatusCommand,
+    ) -> eyre::Result<(Pubkey, InterfaceOperStatus)>;
+    fn create_qos_profile(&self, cmd: CreateQosProfileCommand)
+        -> eyre::Result<(Signature, Pubkey)>;
+    fn get_qos_profile(&self, cmd: GetQosProfileCommand) -> eyre::Result<(Pubkey, QosProfile)>;
+    fn list_qos_profile(
+        &self,
+        cmd: ListQosProfileCommand,
+    ) -> eyre::Result<HashMap<Pubkey, QosProfile>>;
+    fn update_qos_profile(&self, cmd: UpdateQosProfileCommand) -> eyre::Result<Signature>;
+    fn delete_qos_profile(&self, cmd: DeleteQosProfileCommand) -> eyre::Result<Signature>;
+    fn create_anycast_prefix(
+        &self,
+        cmd: CreateAnycastPrefixCommand,
+    ) -> eyre::Result<(Signature, Pubkey)>;
+    fn get_anycast_prefix(
+        &self,
+        cmd: GetAnycastPrefixCommand,
+    ) -> eyre::Result<(Pubkey, AnycastPrefix)>;
+    fn list_anycast_prefix(
+        &self,
+        cmd: ListAnycastPrefixCommand,
+    ) -> eyre::Result<HashMap<Pubkey, AnycastPrefix>>;
+    fn update_anycast_prefix(&self, cmd: UpdateAnycastPrefixCommand) -> eyre::Result<Signature>;
+    fn delete_anycast_prefix(&self, cmd: DeleteAnycastPrefixCommand) -> eyre::Result<Signature>;
+    fn snapshot_state(&self, cmd: SnapshotStateCommand) -> eyre::Result<StateSnapshot>;
+    fn record_state_commitment(
+        &self,
+        cmd: RecordStateCommitmentCommand,
+    ) -> eyre::Result<(Signature, Pubkey)>;
+    fn get_state_commitment(
+        &self,
+        cmd: GetStateCommitmentCommand,
+    ) -> eyre::Result<(Pubkey, StateCommitment)>;
+    fn get_execution_history(
+        &self,
+        cmd: GetExecutionHistoryCommand,
+    ) -> eyre::Result<(Pubkey, Vec<ExecutionRecord>)>;
 
     fn create_contributor(
         &self,
@@ -197,6 +339,10 @@ pub trait CliCommand {
     ) -> eyre::Result<(Signature, Pubkey)>;
     fn get_contributor(&self, cmd: GetContributorCommand) -> eyre::Result<(Pubkey, Contributor)>;
     fn suspend_contributor(&self, cmd: SuspendContributorCommand) -> eyre::Result<Signature>;
+    fn emergency_suspend_contributor(
+        &self,
+        cmd: EmergencySuspendContributorCommand,
+    ) -> eyre::Result<Vec<Signature>>;
     fn resume_contributor(&self, cmd: ResumeContributorCommand) -> eyre::Result<Signature>;
     fn list_contributor(
         &self,
@@ -239,6 +385,16 @@ pub trait CliCommand {
     fn update_device(&self, cmd: UpdateDeviceCommand) -> eyre::Result<Signature>;
     fn delete_device(&self, cmd: DeleteDeviceCommand) -> eyre::Result<Signature>;
     fn set_device_health(&self, cmd: SetDeviceHealthCommand) -> eyre::Result<Signature>;
+    fn ack_device_config(&self, cmd: AckDeviceConfigCommand) -> eyre::Result<Signature>;
+    fn rotate_device_metrics_publisher(
+        &self,
+        cmd: RotateDeviceMetricsPublisherCommand,
+    ) -> eyre::Result<Signature>;
+    fn issue_device_challenge(&self, cmd: IssueDeviceChallengeCommand) -> eyre::Result<Signature>;
+    fn prove_device_key(&self, cmd: ProveDeviceKeyCommand) -> eyre::Result<Signature>;
+    fn set_device_ops_lock(&self, cmd: SetDeviceOpsLockCommand) -> eyre::Result<Signature>;
+    fn grant_device_co_owner(&self, cmd: GrantDeviceCoOwnerCommand) -> eyre::Result<Signature>;
+    fn revoke_device_co_owner(&self, cmd: RevokeDeviceCoOwnerCommand) -> eyre::Result<Signature>;
 
     fn create_device_interface(
         &self,
@@ -248,6 +404,10 @@ pub trait CliCommand {
         -> eyre::Result<Signature>;
     fn update_device_interface(&self, cmd: UpdateDeviceInterfaceCommand)
         -> eyre::Result<Signature>;
+    fn bulk_update_device_interfaces(
+        &self,
+        cmd: BulkUpdateDeviceInterfacesCommand,
+    ) -> eyre::Result<Signature>;
 
     fn create_link(&self, cmd: CreateLinkCommand) -> eyre::Result<(Signature, Pubkey)>;
     fn accept_link(&self, cmd: AcceptLinkCommand) -> eyre::Result<Signature>;
@@ -256,7 +416,9 @@ pub trait CliCommand {
     fn update_link(&self, cmd: UpdateLinkCommand) -> eyre::Result<Signature>;
     fn delete_link(&self, cmd: DeleteLinkCommand) -> eyre::Result<Signature>;
     fn latency_link(&self, cmd: LatencyLinkCommand) -> eyre::Result<Vec<LinkLatencyStats>>;
+    fn burn_in_link(&self, cmd: BurnInLinkCommand) -> eyre::Result<BurnInReport>;
     fn set_link_health(&self, cmd: SetLinkHealthCommand) -> eyre::Result<Signature>;
+    fn set_link_ops_lock(&self, cmd: SetLinkOpsLockCommand) -> eyre::Result<Signature>;
 
     fn create_user(&self, cmd: CreateUserCommand) -> eyre::Result<(Signature, Pubkey)>;
     fn create_subscribe_user(
@@ -319,6 +481,10 @@ pub trait CliCommand {
         cmd: RemoveMulticastGroupSubAllowlistCommand,
     ) -> eyre::Result<Signature>;
     fn set_accesspass(&self, cmd: SetAccessPassCommand) -> eyre::Result<Signature>;
+    fn set_accesspass_batch(
+        &self,
+        cmd: SetAccessPassBatchCommand,
+    ) -> eyre::Result<Vec<eyre::Result<Signature>>>;
     fn get_accesspass(
         &self,
         cmd: GetAccessPassCommand,
@@ -327,11 +493,22 @@ pub trait CliCommand {
         &self,
         cmd: ListAccessPassCommand,
     ) -> eyre::Result<HashMap<Pubkey, AccessPass>>;
+    fn get_referrer_stats(
+        &self,
+        cmd: GetReferrerStatsCommand,
+    ) -> eyre::Result<(Pubkey, ReferrerStats)>;
+    fn list_referrer_stats(
+        &self,
+        cmd: ListReferrerStatsCommand,
+    ) -> eyre::Result<HashMap<Pubkey, ReferrerStats>>;
     fn close_accesspass(&self, cmd: CloseAccessPassCommand) -> eyre::Result<Signature>;
+    fn settle_accesspass_lease(&self, cmd: SettleAccessPassLeaseCommand)
+        -> eyre::Result<Signature>;
 
     fn allocate_resource(&self, cmd: AllocateResourceCommand) -> eyre::Result<Signature>;
     fn create_resource(&self, cmd: CreateResourceCommand) -> eyre::Result<Signature>;
     fn deallocate_resource(&self, cmd: DeallocateResourceCommand) -> eyre::Result<Signature>;
+    fn resize_resource(&self, cmd: ResizeResourceCommand) -> eyre::Result<Signature>;
     fn get_resource(
         &self,
         cmd: GetResourceCommand,
@@ -342,6 +519,19 @@ pub trait CliCommand {
         cmd: CloseResourceByPubkeyCommand,
     ) -> eyre::Result<Signature>;
 
+    fn prune_telemetry_samples(
+        &self,
+        cmd: PruneTelemetrySamplesCommand,
+    ) -> eyre::Result<Vec<(Pubkey, Signature)>>;
+    fn reclaim_telemetry_samples(
+        &self,
+        cmd: ReclaimTelemetrySamplesCommand,
+    ) -> eyre::Result<Vec<(Pubkey, ReclaimAction, Signature)>>;
+    fn set_provider_sampling_budget(
+        &self,
+        cmd: SetProviderSamplingBudgetCommand,
+    ) -> eyre::Result<(Signature, Pubkey)>;
+
     fn create_topology(&self, cmd: CreateTopologyCommand) -> eyre::Result<CreateTopologyResult>;
     fn delete_topology(&self, cmd: DeleteTopologyCommand) -> eyre::Result<Signature>;
     fn clear_topology(&self, cmd: ClearTopologyCommand) -> eyre::Result<Vec<Signature>>;
@@ -383,6 +573,10 @@ impl CliCommand for CliCommandImpl<'_> {
         self.keypair_source
     }
 
+    fn is_dry_run(&self) -> bool {
+        self.client.is_dry_run()
+    }
+
     fn get_program_config(
         &self,
         cmd: GetProgramConfigCommand,
@@ -399,18 +593,31 @@ impl CliCommand for CliCommandImpl<'_> {
     fn get_payer(&self) -> Pubkey {
         self.client.get_payer()
     }
+    fn get_address_book(&self) -> AddressBook {
+        // A missing or unreadable config only costs the labels.
+        read_doublezero_config()
+            .map(|(_, config)| AddressBook::from_config(&config))
+            .unwrap_or_default()
+    }
     fn get_balance(&self) -> eyre::Result<u64> {
         self.client.get_balance()
     }
     fn get_epoch(&self) -> eyre::Result<u64> {
         self.client.get_epoch()
     }
+    fn get_slot(&self) -> eyre::Result<u64> {
+        self.client.get_slot()
+    }
     fn get_block_time(&self, slot: u64) -> eyre::Result<Option<i64>> {
         self.client.get_block_time(slot)
     }
     fn get_logs(&self, pubkey: &Pubkey) -> eyre::Result<Vec<String>> {
         self.client.get_logs(pubkey)
     }
+    fn follow_logs(&self, pubkey: &Pubkey, handler: &mut dyn LogHandler) -> eyre::Result<()> {
+        self.client
+            .follow_logs(pubkey, |signature, logs| handler.handle(signature, logs))
+    }
     fn get_account(&self, pubkey: Pubkey) -> eyre::Result<Account> {
         self.client.get_account(pubkey)
     }
@@ -425,9 +632,15 @@ impl CliCommand for CliCommandImpl<'_> {
     fn transfer_sol(&self, to: Pubkey, lamports: u64) -> eyre::Result<Signature> {
         self.client.transfer_sol(to, lamports)
     }
+    fn request_airdrop(&self, lamports: u64) -> eyre::Result<Signature> {
+        self.client.request_airdrop(lamports)
+    }
     fn get_all(&self) -> eyre::Result<HashMap<Box<Pubkey>, Box<AccountData>>> {
         self.client.get_all()
     }
+    fn network_health(&self) -> eyre::Result<NetworkHealth> {
+        self.client.network_health()
+    }
     fn get_account_data(&self, pubkey: Pubkey) -> eyre::Result<AccountData> {
         self.client.get(pubkey)
     }
@@ -448,6 +661,15 @@ impl CliCommand for CliCommandImpl<'_> {
     fn migrate(&self, cmd: MigrateCommand) -> eyre::Result<Vec<Signature>> {
         cmd.execute(self.client)
     }
+    fn list_pending_migrations(
+        &self,
+        cmd: ListPendingMigrationsCommand,
+    ) -> eyre::Result<Vec<Pubkey>> {
+        cmd.execute(self.client)
+    }
+    fn migrate_account(&self, cmd: MigrateAccountCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn get_globalstate(&self, cmd: GetGlobalStateCommand) -> eyre::Result<(Pubkey, GlobalState)> {
         cmd.execute(self.client)
     }
@@ -472,6 +694,9 @@ impl CliCommand for CliCommandImpl<'_> {
     fn set_feature_flags(&self, cmd: SetFeatureFlagsCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn set_pause(&self, cmd: SetPauseCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
 
     fn create_location(&self, cmd: CreateLocationCommand) -> eyre::Result<(Signature, Pubkey)> {
         cmd.execute(self.client)
@@ -521,6 +746,117 @@ impl CliCommand for CliCommandImpl<'_> {
     fn delete_feed(&self, cmd: DeleteFeedCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn create_read_api_key(
+        &self,
+        cmd: CreateReadApiKeyCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
+    fn list_read_api_key(
+        &self,
+        cmd: ListReadApiKeyCommand,
+    ) -> eyre::Result<HashMap<Pubkey, ReadApiKey>> {
+        cmd.execute(self.client)
+    }
+    fn revoke_read_api_key(&self, cmd: RevokeReadApiKeyCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn delete_read_api_key(&self, cmd: DeleteReadApiKeyCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn create_ip_allocation(
+        &self,
+        cmd: CreateIpAllocationCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
+    fn list_ip_allocation(
+        &self,
+        cmd: ListIpAllocationCommand,
+    ) -> eyre::Result<HashMap<Pubkey, IpAllocation>> {
+        cmd.execute(self.client)
+    }
+    fn set_ip_allocation_status(
+        &self,
+        cmd: SetIpAllocationStatusCommand,
+    ) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn delete_ip_allocation(&self, cmd: DeleteIpAllocationCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn get_interface_oper_status(
+        &self,
+        cmd: GetInterfaceOperStatusCommand,
+    ) -> eyre::Result<(Pubkey, InterfaceOperStatus)> {
+        cmd.execute(self.client)
+    }
+    fn create_qos_profile(
+        &self,
+        cmd: CreateQosProfileCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
+    fn get_qos_profile(&self, cmd: GetQosProfileCommand) -> eyre::Result<(Pubkey, QosProfile)> {
+        cmd.execute(self.client)
+    }
+    fn list_qos_profile(
+        &self,
+        cmd: ListQosProfileCommand,
+    ) -> eyre::Result<HashMap<Pubkey, QosProfile>> {
+        cmd.execute(self.client)
+    }
+    fn update_qos_profile(&self, cmd: UpdateQosProfileCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn delete_qos_profile(&self, cmd: DeleteQosProfileCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn create_anycast_prefix(
+        &self,
+        cmd: CreateAnycastPrefixCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
+    fn get_anycast_prefix(
+        &self,
+        cmd: GetAnycastPrefixCommand,
+    ) -> eyre::Result<(Pubkey, AnycastPrefix)> {
+        cmd.execute(self.client)
+    }
+    fn list_anycast_prefix(
+        &self,
+        cmd: ListAnycastPrefixCommand,
+    ) -> eyre::Result<HashMap<Pubkey, AnycastPrefix>> {
+        cmd.execute(self.client)
+    }
+    fn update_anycast_prefix(&self, cmd: UpdateAnycastPrefixCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn delete_anycast_prefix(&self, cmd: DeleteAnycastPrefixCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn snapshot_state(&self, cmd: SnapshotStateCommand) -> eyre::Result<StateSnapshot> {
+        cmd.execute(self.client)
+    }
+    fn record_state_commitment(
+        &self,
+        cmd: RecordStateCommitmentCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
+    fn get_state_commitment(
+        &self,
+        cmd: GetStateCommitmentCommand,
+    ) -> eyre::Result<(Pubkey, StateCommitment)> {
+        cmd.execute(self.client)
+    }
+    fn get_execution_history(
+        &self,
+        cmd: GetExecutionHistoryCommand,
+    ) -> eyre::Result<(Pubkey, Vec<ExecutionRecord>)> {
+        cmd.execute(self.client)
+    }
     fn create_contributor(
         &self,
         cmd: CreateContributorCommand,
@@ -533,6 +869,12 @@ impl CliCommand for CliCommandImpl<'_> {
     fn suspend_contributor(&self, cmd: SuspendContributorCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn emergency_suspend_contributor(
+        &self,
+        cmd: EmergencySuspendContributorCommand,
+    ) -> eyre::Result<Vec<Signature>> {
+        cmd.execute(self.client)
+    }
     fn resume_contributor(&self, cmd: ResumeContributorCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
@@ -625,6 +967,30 @@ impl CliCommand for CliCommandImpl<'_> {
     fn set_device_health(&self, cmd: SetDeviceHealthCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn ack_device_config(&self, cmd: AckDeviceConfigCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn rotate_device_metrics_publisher(
+        &self,
+        cmd: RotateDeviceMetricsPublisherCommand,
+    ) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn issue_device_challenge(&self, cmd: IssueDeviceChallengeCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn prove_device_key(&self, cmd: ProveDeviceKeyCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn set_device_ops_lock(&self, cmd: SetDeviceOpsLockCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn grant_device_co_owner(&self, cmd: GrantDeviceCoOwnerCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
+    fn revoke_device_co_owner(&self, cmd: RevokeDeviceCoOwnerCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn create_device_interface(
         &self,
         cmd: CreateDeviceInterfaceCommand,
@@ -643,6 +1009,12 @@ impl CliCommand for CliCommandImpl<'_> {
     ) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn bulk_update_device_interfaces(
+        &self,
+        cmd: BulkUpdateDeviceInterfacesCommand,
+    ) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn create_link(&self, cmd: CreateLinkCommand) -> eyre::Result<(Signature, Pubkey)> {
         cmd.execute(self.client)
     }
@@ -664,9 +1036,15 @@ impl CliCommand for CliCommandImpl<'_> {
     fn latency_link(&self, cmd: LatencyLinkCommand) -> eyre::Result<Vec<LinkLatencyStats>> {
         cmd.execute(self.client)
     }
+    fn burn_in_link(&self, cmd: BurnInLinkCommand) -> eyre::Result<BurnInReport> {
+        cmd.execute(self.client)
+    }
     fn set_link_health(&self, cmd: SetLinkHealthCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn set_link_ops_lock(&self, cmd: SetLinkOpsLockCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn create_user(&self, cmd: CreateUserCommand) -> eyre::Result<(Signature, Pubkey)> {
         cmd.execute(self.client)
     }
@@ -775,6 +1153,12 @@ impl CliCommand for CliCommandImpl<'_> {
     fn set_accesspass(&self, cmd: SetAccessPassCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn set_accesspass_batch(
+        &self,
+        cmd: SetAccessPassBatchCommand,
+    ) -> eyre::Result<Vec<eyre::Result<Signature>>> {
+        cmd.execute(self.client)
+    }
     fn get_accesspass(
         &self,
         cmd: GetAccessPassCommand,
@@ -787,9 +1171,27 @@ impl CliCommand for CliCommandImpl<'_> {
     ) -> eyre::Result<HashMap<Pubkey, AccessPass>> {
         cmd.execute(self.client)
     }
+    fn get_referrer_stats(
+        &self,
+        cmd: GetReferrerStatsCommand,
+    ) -> eyre::Result<(Pubkey, ReferrerStats)> {
+        cmd.execute(self.client)
+    }
+    fn list_referrer_stats(
+        &self,
+        cmd: ListReferrerStatsCommand,
+    ) -> eyre::Result<HashMap<Pubkey, ReferrerStats>> {
+        cmd.execute(self.client)
+    }
     fn close_accesspass(&self, cmd: CloseAccessPassCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn settle_accesspass_lease(
+        &self,
+        cmd: SettleAccessPassLeaseCommand,
+    ) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn allocate_resource(&self, cmd: AllocateResourceCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
@@ -799,6 +1201,9 @@ impl CliCommand for CliCommandImpl<'_> {
     fn deallocate_resource(&self, cmd: DeallocateResourceCommand) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn resize_resource(&self, cmd: ResizeResourceCommand) -> eyre::Result<Signature> {
+        cmd.execute(self.client)
+    }
     fn get_resource(
         &self,
         cmd: GetResourceCommand,
@@ -814,6 +1219,24 @@ impl CliCommand for CliCommandImpl<'_> {
     ) -> eyre::Result<Signature> {
         cmd.execute(self.client)
     }
+    fn prune_telemetry_samples(
+        &self,
+        cmd: PruneTelemetrySamplesCommand,
+    ) -> eyre::Result<Vec<(Pubkey, Signature)>> {
+        cmd.execute(self.client)
+    }
+    fn reclaim_telemetry_samples(
+        &self,
+        cmd: ReclaimTelemetrySamplesCommand,
+    ) -> eyre::Result<Vec<(Pubkey, ReclaimAction, Signature)>> {
+        cmd.execute(self.client)
+    }
+    fn set_provider_sampling_budget(
+        &self,
+        cmd: SetProviderSamplingBudgetCommand,
+    ) -> eyre::Result<(Signature, Pubkey)> {
+        cmd.execute(self.client)
+    }
     fn create_topology(&self, cmd: CreateTopologyCommand) -> eyre::Result<CreateTopologyResult> {
         cmd.execute(self.client)
     }
@@ -0,0 +1,126 @@
+use crate::{doublezerocommand::CliCommand, env::known_environments};
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListEnvCliCommand {
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct EnvDisplay {
+    pub name: String,
+    pub current: bool,
+    pub ledger_rpc_url: String,
+    pub solana_rpc_url: String,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub serviceability_program_id: Pubkey,
+}
+
+impl ListEnvCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        ctx: &CliContext,
+        _client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let displays = known_environments()?
+            .into_iter()
+            .map(|env| {
+                let config = env.config()?;
+                Ok(EnvDisplay {
+                    name: env.to_string(),
+                    current: env == ctx.env,
+                    ledger_rpc_url: config.ledger_public_rpc_url,
+                    solana_rpc_url: config.solana_l1_rpc_url,
+                    serviceability_program_id: config.serviceability_program_id,
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        render_collection(
+            out,
+            displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_cli_core::testing::{block_on, cli_context_for_tests};
+    use doublezero_config::{Environment, ENV_MANIFEST_VAR};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"{
+        "environments": [
+            {
+                "name": "lab",
+                "ledger_rpc_url": "https://ledger.lab.example",
+                "ledger_ws_rpc_url": "wss://ledger.lab.example",
+                "solana_rpc_url": "https://solana.lab.example",
+                "serviceability_program_id": "Vote111111111111111111111111111111111111111",
+                "telemetry_program_id": "C9xqH76NSm11pBS6maNnY163tWHT8Govww47uyEmSnoG",
+                "internet_latency_collector_pk": "3fXen9LP5JUAkaaDJtyLo1ohPiJ2LdzVqAnmhtGgAmwJ",
+                "geolocation_program_id": "3fXen9LP5JUAkaaDJtyLo1ohPiJ2LdzVqAnmhtGgAmwJ"
+            }
+        ]
+    }"#;
+
+    #[test]
+    #[serial]
+    fn test_cli_env_list() {
+        let tmp = TempDir::new().unwrap();
+        let manifest_path = tmp.path().join("environments.json");
+        std::fs::write(&manifest_path, MANIFEST).unwrap();
+
+        temp_env::with_var(ENV_MANIFEST_VAR, Some(&manifest_path), || {
+            let client = create_test_client();
+            let ctx = cli_context_for_tests()
+                .with_env(Environment::Testnet)
+                .build()
+                .unwrap();
+
+            let mut output = Vec::new();
+            let res = block_on(
+                ListEnvCliCommand {
+                    json: true,
+                    json_compact: false,
+                }
+                .execute(&ctx, &client, &mut output),
+            );
+            assert!(res.is_ok(), "{res:?}");
+
+            let rows: Vec<serde_json::Value> = serde_json::from_slice(&output).unwrap();
+            let names: Vec<&str> = rows.iter().map(|r| r["name"].as_str().unwrap()).collect();
+            assert_eq!(
+                names,
+                vec!["mainnet-beta", "testnet", "devnet", "local", "custom:lab"]
+            );
+            let current: Vec<&str> = rows
+                .iter()
+                .filter(|r| r["current"].as_bool().unwrap())
+                .map(|r| r["name"].as_str().unwrap())
+                .collect();
+            assert_eq!(current, vec!["testnet"]);
+            assert_eq!(rows[4]["ledger_rpc_url"], "https://ledger.lab.example");
+            assert_eq!(
+                rows[4]["serviceability_program_id"],
+                "Vote111111111111111111111111111111111111111"
+            );
+        });
+    }
+}
@@ -0,0 +1,21 @@
+pub mod list;
+pub mod show;
+
+use doublezero_config::{Environment, EnvironmentManifest};
+
+/// The built-in environments followed by those of the environment manifest.
+pub(crate) fn known_environments() -> eyre::Result<Vec<Environment>> {
+    let mut envs = vec![
+        Environment::MainnetBeta,
+        Environment::Testnet,
+        Environment::Devnet,
+        Environment::Local,
+    ];
+    envs.extend(
+        EnvironmentManifest::load()?
+            .environments
+            .into_iter()
+            .map(|env| Environment::Custom(Box::new(env))),
+    );
+    Ok(envs)
+}
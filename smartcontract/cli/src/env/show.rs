@@ -0,0 +1,402 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_record, CliContext, OutputFormat};
+use doublezero_geolocation::state::program_config::GeolocationProgramConfig;
+use doublezero_program_common::serializer;
+use doublezero_serviceability::{pda::get_program_config_pda, state::programconfig::ProgramConfig};
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{account::Account, epoch_info::EpochInfo, pubkey::Pubkey};
+use std::{io::Write, time::Duration};
+use tabled::Tabled;
+
+/// Bounds each probe so an unreachable endpoint can't hang the command.
+const PROBE_RPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Probes the environment selected by the global `--env` flag, or the
+/// configured one when it is omitted.
+#[derive(Args, Debug)]
+pub struct ShowEnvCliCommand {
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+/// Endpoints and program IDs of the environment being probed.
+struct ProbeTarget {
+    name: String,
+    ledger_rpc_url: String,
+    solana_rpc_url: String,
+    serviceability_program_id: Pubkey,
+    telemetry_program_id: Pubkey,
+    geolocation_program_id: Pubkey,
+}
+
+impl ProbeTarget {
+    /// The running configuration, including any `--url` or program ID overrides.
+    fn from_ctx(ctx: &CliContext) -> Self {
+        Self {
+            name: ctx.env.to_string(),
+            ledger_rpc_url: ctx.ledger_rpc_url.clone(),
+            solana_rpc_url: ctx.solana_l1_rpc_url.clone(),
+            serviceability_program_id: ctx.serviceability_program_id,
+            telemetry_program_id: ctx.telemetry_program_id,
+            geolocation_program_id: ctx.geolocation_program_id,
+        }
+    }
+}
+
+/// The RPC calls made by the probe. Split from the RPC client so they can be
+/// mocked.
+trait ProbeRpc {
+    fn health(&self) -> eyre::Result<()>;
+    fn epoch_info(&self) -> eyre::Result<EpochInfo>;
+    fn account(&self, pubkey: &Pubkey) -> eyre::Result<Option<Account>>;
+}
+
+impl ProbeRpc for RpcClient {
+    fn health(&self) -> eyre::Result<()> {
+        Ok(self.get_health()?)
+    }
+
+    fn epoch_info(&self) -> eyre::Result<EpochInfo> {
+        Ok(self.get_epoch_info()?)
+    }
+
+    fn account(&self, pubkey: &Pubkey) -> eyre::Result<Option<Account>> {
+        Ok(self
+            .get_account_with_commitment(pubkey, self.commitment())?
+            .value)
+    }
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct EnvProbeDisplay {
+    pub environment: String,
+    pub ledger_rpc_url: String,
+    pub ledger_rpc_health: String,
+    pub ledger_epoch: String,
+    pub ledger_slot: String,
+    pub solana_rpc_url: String,
+    pub solana_rpc_health: String,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub serviceability_program_id: Pubkey,
+    pub serviceability_version: String,
+    pub serviceability_min_compatible_version: String,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub telemetry_program_id: Pubkey,
+    pub telemetry_program: String,
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub geolocation_program_id: Pubkey,
+    pub geolocation_version: String,
+    pub geolocation_min_compatible_version: String,
+}
+
+impl ShowEnvCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        ctx: &CliContext,
+        _client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let target = ProbeTarget::from_ctx(ctx);
+
+        let ledger = RpcClient::new_with_timeout(target.ledger_rpc_url.clone(), PROBE_RPC_TIMEOUT);
+        let solana = RpcClient::new_with_timeout(target.solana_rpc_url.clone(), PROBE_RPC_TIMEOUT);
+        let display = probe(&target, &ledger, &solana);
+
+        render_record(
+            out,
+            &display,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+/// Probes every endpoint and program of `target`. A failed probe is reported
+/// in its field rather than aborting the others.
+fn probe(target: &ProbeTarget, ledger: &dyn ProbeRpc, solana: &dyn ProbeRpc) -> EnvProbeDisplay {
+    let (ledger_epoch, ledger_slot) = match ledger.epoch_info() {
+        Ok(info) => (
+            format!(
+                "{} (slot {} of {})",
+                info.epoch, info.slot_index, info.slots_in_epoch
+            ),
+            info.absolute_slot.to_string(),
+        ),
+        Err(e) => (unavailable(&e), unavailable(&e)),
+    };
+
+    let (serviceability_version, serviceability_min_compatible_version) =
+        match program_config::<ProgramConfig>(
+            ledger,
+            &target.serviceability_program_id,
+            &get_program_config_pda(&target.serviceability_program_id).0,
+        ) {
+            Ok(config) => (
+                config.version.to_string(),
+                config.min_compatible_version.to_string(),
+            ),
+            Err(e) => (unavailable(&e), unavailable(&e)),
+        };
+
+    let (geolocation_version, geolocation_min_compatible_version) =
+        match program_config::<GeolocationProgramConfig>(
+            ledger,
+            &target.geolocation_program_id,
+            &doublezero_geolocation::pda::get_program_config_pda(&target.geolocation_program_id).0,
+        ) {
+            Ok(config) => (
+                config.version.to_string(),
+                config.min_compatible_version.to_string(),
+            ),
+            Err(e) => (unavailable(&e), unavailable(&e)),
+        };
+
+    // The telemetry program keeps no version onchain; report whether it is deployed.
+    let telemetry_program = match program_account(ledger, &target.telemetry_program_id) {
+        Ok(()) => "deployed".to_string(),
+        Err(e) => unavailable(&e),
+    };
+
+    EnvProbeDisplay {
+        environment: target.name.clone(),
+        ledger_rpc_url: target.ledger_rpc_url.clone(),
+        ledger_rpc_health: health(ledger),
+        ledger_epoch,
+        ledger_slot,
+        solana_rpc_url: target.solana_rpc_url.clone(),
+        solana_rpc_health: health(solana),
+        serviceability_program_id: target.serviceability_program_id,
+        serviceability_version,
+        serviceability_min_compatible_version,
+        telemetry_program_id: target.telemetry_program_id,
+        telemetry_program,
+        geolocation_program_id: target.geolocation_program_id,
+        geolocation_version,
+        geolocation_min_compatible_version,
+    }
+}
+
+fn health(rpc: &dyn ProbeRpc) -> String {
+    match rpc.health() {
+        Ok(()) => "ok".to_string(),
+        Err(e) => unavailable(&e),
+    }
+}
+
+fn unavailable(e: &eyre::Report) -> String {
+    format!("unavailable: {e}")
+}
+
+/// Errors unless `program_id` is a deployed program.
+fn program_account(rpc: &dyn ProbeRpc, program_id: &Pubkey) -> eyre::Result<()> {
+    match rpc.account(program_id)? {
+        Some(account) if account.executable => Ok(()),
+        Some(_) => Err(eyre::eyre!("{program_id} is not a program")),
+        None => Err(eyre::eyre!("program not deployed")),
+    }
+}
+
+/// Reads the program config account of a deployed program.
+fn program_config<T>(
+    rpc: &dyn ProbeRpc,
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+) -> eyre::Result<T>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    program_account(rpc, program_id)?;
+    let account = rpc
+        .account(config_pda)?
+        .ok_or_else(|| eyre::eyre!("program config {config_pda} not initialized"))?;
+    T::try_from(&account.data[..])
+        .map_err(|_| eyre::eyre!("program config {config_pda} could not be decoded"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_geolocation::state::accounttype::AccountType as GeolocationAccountType;
+    use doublezero_serviceability::{
+        programversion::ProgramVersion, state::accounttype::AccountType,
+    };
+    use std::collections::HashMap;
+
+    /// RPC stub answering from a fixed set of accounts.
+    struct FakeRpc {
+        healthy: bool,
+        accounts: HashMap<Pubkey, Account>,
+    }
+
+    impl ProbeRpc for FakeRpc {
+        fn health(&self) -> eyre::Result<()> {
+            if self.healthy {
+                Ok(())
+            } else {
+                Err(eyre::eyre!("connection refused"))
+            }
+        }
+
+        fn epoch_info(&self) -> eyre::Result<EpochInfo> {
+            self.health()?;
+            Ok(EpochInfo {
+                epoch: 42,
+                slot_index: 100,
+                slots_in_epoch: 432_000,
+                absolute_slot: 18_144_100,
+                block_height: 18_000_000,
+                transaction_count: None,
+            })
+        }
+
+        fn account(&self, pubkey: &Pubkey) -> eyre::Result<Option<Account>> {
+            self.health()?;
+            Ok(self.accounts.get(pubkey).cloned())
+        }
+    }
+
+    fn program() -> Account {
+        Account {
+            executable: true,
+            ..Account::default()
+        }
+    }
+
+    fn data_account(data: Vec<u8>) -> Account {
+        Account {
+            data,
+            ..Account::default()
+        }
+    }
+
+    fn target() -> ProbeTarget {
+        ProbeTarget {
+            name: "devnet".to_string(),
+            ledger_rpc_url: "http://ledger.example".to_string(),
+            solana_rpc_url: "http://solana.example".to_string(),
+            serviceability_program_id: Pubkey::new_unique(),
+            telemetry_program_id: Pubkey::new_unique(),
+            geolocation_program_id: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn test_cli_env_show_probe() {
+        let target = target();
+        let serviceability_config = ProgramConfig {
+            account_type: AccountType::ProgramConfig,
+            bump_seed: 255,
+            version: ProgramVersion {
+                major: 0,
+                minor: 9,
+                patch: 1,
+            },
+            min_compatible_version: ProgramVersion {
+                major: 0,
+                minor: 8,
+                patch: 0,
+            },
+        };
+        let geolocation_config = GeolocationProgramConfig {
+            account_type: GeolocationAccountType::ProgramConfig,
+            bump_seed: 254,
+            version: 3,
+            min_compatible_version: 2,
+        };
+        let ledger = FakeRpc {
+            healthy: true,
+            accounts: HashMap::from([
+                (target.serviceability_program_id, program()),
+                (
+                    get_program_config_pda(&target.serviceability_program_id).0,
+                    data_account(borsh::to_vec(&serviceability_config).unwrap()),
+                ),
+                (target.telemetry_program_id, program()),
+                (target.geolocation_program_id, program()),
+                (
+                    doublezero_geolocation::pda::get_program_config_pda(
+                        &target.geolocation_program_id,
+                    )
+                    .0,
+                    data_account(borsh::to_vec(&geolocation_config).unwrap()),
+                ),
+            ]),
+        };
+        let solana = FakeRpc {
+            healthy: false,
+            accounts: HashMap::new(),
+        };
+
+        let display = probe(&target, &ledger, &solana);
+        assert_eq!(display.environment, "devnet");
+        assert_eq!(display.ledger_rpc_health, "ok");
+        assert_eq!(display.ledger_epoch, "42 (slot 100 of 432000)");
+        assert_eq!(display.ledger_slot, "18144100");
+        assert_eq!(display.solana_rpc_health, "unavailable: connection refused");
+        assert_eq!(display.serviceability_version, "0.9.1");
+        assert_eq!(display.serviceability_min_compatible_version, "0.8.0");
+        assert_eq!(display.telemetry_program, "deployed");
+        assert_eq!(display.geolocation_version, "3");
+        assert_eq!(display.geolocation_min_compatible_version, "2");
+    }
+
+    #[test]
+    fn test_cli_env_show_probe_missing_programs() {
+        let target = target();
+        let ledger = FakeRpc {
+            healthy: true,
+            accounts: HashMap::from([(target.serviceability_program_id, program())]),
+        };
+        let solana = FakeRpc {
+            healthy: true,
+            accounts: HashMap::new(),
+        };
+
+        let display = probe(&target, &ledger, &solana);
+        assert_eq!(display.solana_rpc_health, "ok");
+        assert_eq!(
+            display.serviceability_version,
+            format!(
+                "unavailable: program config {} not initialized",
+                get_program_config_pda(&target.serviceability_program_id).0
+            )
+        );
+        assert_eq!(
+            display.telemetry_program,
+            "unavailable: program not deployed"
+        );
+        assert_eq!(
+            display.geolocation_version,
+            "unavailable: program not deployed"
+        );
+    }
+
+    #[test]
+    fn test_cli_env_show_probe_unreachable_ledger() {
+        let target = target();
+        let ledger = FakeRpc {
+            healthy: false,
+            accounts: HashMap::new(),
+        };
+        let solana = FakeRpc {
+            healthy: true,
+            accounts: HashMap::new(),
+        };
+
+        let display = probe(&target, &ledger, &solana);
+        for field in [
+            &display.ledger_rpc_health,
+            &display.ledger_epoch,
+            &display.serviceability_version,
+            &display.telemetry_program,
+            &display.geolocation_version,
+        ] {
+            assert_eq!(field, "unavailable: connection refused");
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
-    doublezerocommand::CliCommand, helpers::resolve_exchange_pk,
+    cascade::print_cascade_preview, doublezerocommand::CliCommand, helpers::resolve_exchange_pk,
     validators::validate_pubkey_or_code,
 };
 use clap::Args;
 use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
-use doublezero_sdk::commands::exchange::delete::DeleteExchangeCommand;
+use doublezero_sdk::{cascade::DeleteTarget, commands::exchange::delete::DeleteExchangeCommand};
 use std::io::Write;
 
 #[derive(Args, Debug)]
@@ -12,6 +12,10 @@ pub struct DeleteExchangeCliCommand {
     /// Exchange Pubkey or code to delete
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteExchangeCliCommand {
@@ -21,6 +25,11 @@ impl DeleteExchangeCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let pubkey = resolve_exchange_pk(client, &self.pubkey)?;
+            return print_cascade_preview(client, DeleteTarget::Exchange(pubkey), out);
+        }
+
         require!(
             client,
             RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
@@ -99,6 +108,7 @@ mod tests {
         let res = block_on(
             DeleteExchangeCliCommand {
                 pubkey: pda_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
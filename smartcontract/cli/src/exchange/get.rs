@@ -2,8 +2,9 @@ use crate::{doublezerocommand::CliCommand, validators::validate_code};
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::{
-    device::list::ListDeviceCommand, exchange::get::GetExchangeCommand,
+use doublezero_sdk::{
+    commands::{device::list::ListDeviceCommand, exchange::get::GetExchangeCommand},
+    labels::LabeledPubkey,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -21,7 +22,7 @@ pub struct GetExchangeCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct ExchangeDisplay {
+pub(crate) struct ExchangeDisplay {
     pub account: String,
     pub code: String,
     pub name: String,
@@ -38,7 +39,7 @@ struct ExchangeDisplay {
     pub bgp_community: u16,
     pub reference_count: u32,
     pub status: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 impl GetExchangeCliCommand {
@@ -48,6 +49,7 @@ impl GetExchangeCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (pubkey, exchange) = client.get_exchange(GetExchangeCommand {
             pubkey_or_code: self.code,
         })?;
@@ -82,7 +84,7 @@ impl GetExchangeCliCommand {
             bgp_community: exchange.bgp_community,
             reference_count: exchange.reference_count,
             status: exchange.status.to_string(),
-            owner: exchange.owner.to_string(),
+            owner: book.labeled(&exchange.owner),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
@@ -4,6 +4,7 @@ use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
 use doublezero_sdk::{
     commands::{device::list::ListDeviceCommand, exchange::list::ListExchangeCommand},
+    labels::LabeledPubkey,
     ExchangeStatus,
 };
 use serde::Serialize;
@@ -33,8 +34,7 @@ pub struct ExchangeDisplay {
     pub lng: f64,
     pub bgp_community: u16,
     pub status: ExchangeStatus,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListExchangeCliCommand {
@@ -44,6 +44,7 @@ impl ListExchangeCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let exchanges = client.list_exchange(ListExchangeCommand)?;
         let devices = client.list_device(ListDeviceCommand)?;
 
@@ -75,7 +76,7 @@ impl ListExchangeCliCommand {
                 lng: tunnel.lng,
                 bgp_community: tunnel.bgp_community,
                 status: tunnel.status,
-                owner: tunnel.owner,
+                owner: book.labeled(&tunnel.owner),
             })
             .collect();
 
@@ -0,0 +1,175 @@
+use super::Data;
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use serde_yaml::Value;
+use std::{collections::BTreeMap, fs, io::Write, path::Path};
+
+#[derive(Args, Debug)]
+pub struct ExportDiffCliCommand {
+    /// Directory of the older export
+    pub from: String,
+    /// Directory of the newer export
+    pub to: String,
+}
+
+/// Entity kinds in the order they are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Device,
+    Link,
+    User,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Device => write!(f, "device"),
+            Kind::Link => write!(f, "link"),
+            Kind::User => write!(f, "user"),
+        }
+    }
+}
+
+/// An exported entity flattened to dotted field names, so two exports can be
+/// compared field by field.
+#[derive(Debug, Default, PartialEq)]
+struct Entity {
+    label: String,
+    fields: BTreeMap<String, String>,
+}
+
+/// Entities of one export, keyed by kind and pubkey.
+type Entities = BTreeMap<(Kind, String), Entity>;
+
+impl ExportDiffCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        _client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let from = load_entities(Path::new(&self.from))?;
+        let to = load_entities(Path::new(&self.to))?;
+
+        let (mut added, mut removed, mut changed) = (0, 0, 0);
+        let keys: std::collections::BTreeSet<_> = from.keys().chain(to.keys()).collect();
+        for key @ (kind, pubkey) in keys {
+            match (from.get(key), to.get(key)) {
+                (None, Some(new)) => {
+                    added += 1;
+                    writeln!(out, "+ {kind} {} ({pubkey})", new.label)?;
+                }
+                (Some(old), None) => {
+                    removed += 1;
+                    writeln!(out, "- {kind} {} ({pubkey})", old.label)?;
+                }
+                (Some(old), Some(new)) if old != new => {
+                    changed += 1;
+                    writeln!(out, "~ {kind} {} ({pubkey})", new.label)?;
+                    let names: std::collections::BTreeSet<_> =
+                        old.fields.keys().chain(new.fields.keys()).collect();
+                    for name in names {
+                        let (before, after) = (old.fields.get(name), new.fields.get(name));
+                        if before != after {
+                            writeln!(
+                                out,
+                                "    {name}: {} -> {}",
+                                before.map_or("-", String::as_str),
+                                after.map_or("-", String::as_str)
+                            )?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        writeln!(out, "{added} added, {removed} removed, {changed} changed")?;
+        Ok(())
+    }
+}
+
+/// Read every device file of an export into its devices, links and users.
+/// A link appears in the files of both its devices; its endpoints are merged.
+fn load_entities(dir: &Path) -> eyre::Result<Entities> {
+    let mut entities = Entities::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "yml") {
+            continue;
+        }
+        let data: Data = serde_yaml::from_str(&fs::read_to_string(&path)?)
+            .map_err(|e| eyre::eyre!("Unable to parse {}: {e}", path.display()))?;
+        let device = data.device;
+
+        let mut fields = BTreeMap::new();
+        flatten("", &serde_yaml::to_value(&device)?, &mut fields);
+        entities.insert(
+            (Kind::Device, device.pubkey.clone()),
+            Entity {
+                label: device.name.clone(),
+                fields,
+            },
+        );
+
+        for link in &device.tunnels {
+            let entity = entities
+                .entry((Kind::Link, link.pubkey.clone()))
+                .or_default();
+            entity.label = link.code.clone();
+            let mut fields = BTreeMap::new();
+            flatten("", &serde_yaml::to_value(link)?, &mut fields);
+            fields.retain(|name, _| name != "iface_name" && !name.starts_with("side."));
+            fields.insert(format!("endpoint.{}", device.name), link.iface_name.clone());
+            fields.insert(
+                format!("endpoint.{}", link.side.name),
+                link.side.iface_name.clone(),
+            );
+            entity.fields.extend(fields);
+        }
+
+        for user in &device.users {
+            let mut fields = BTreeMap::new();
+            flatten("", &serde_yaml::to_value(user)?, &mut fields);
+            fields.insert("device".to_string(), device.name.clone());
+            entities.insert(
+                (Kind::User, user.pubkey.clone()),
+                Entity {
+                    label: format!("{} {}", user.user_type, user.client_ip),
+                    fields,
+                },
+            );
+        }
+    }
+    Ok(entities)
+}
+
+/// Flatten the scalars of `value` into `out` under dotted names. Sequences are
+/// skipped; their items are compared as entities of their own.
+fn flatten(prefix: &str, value: &Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let Some(key) = key.as_str() else { continue };
+                let name = if prefix.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&name, value, out);
+            }
+        }
+        Value::Sequence(_) => {}
+        Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        Value::Number(n) => {
+            out.insert(prefix.to_string(), n.to_string());
+        }
+        Value::Bool(b) => {
+            out.insert(prefix.to_string(), b.to_string());
+        }
+        Value::Null | Value::Tagged(_) => {}
+    }
+}
@@ -0,0 +1,628 @@
+pub mod diff;
+
+use crate::{doublezerocommand::CliCommand, export::diff::ExportDiffCliCommand};
+use clap::{Args, Subcommand};
+use doublezero_cli_core::CliContext;
+use doublezero_program_common::types::parse_utils::bandwidth_to_string;
+use doublezero_sdk::commands::{
+    contributor, device::list::ListDeviceCommand, exchange::list::ListExchangeCommand,
+    link::list::ListLinkCommand, location::list::ListLocationCommand, user::list::ListUserCommand,
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::hash;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Snapshot history kept next to the exported files.
+const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct ExportCliCommand {
+    #[command(subcommand)]
+    pub command: Option<ExportCommands>,
+    /// Path to export the YAML files
+    #[arg(long, required = true)]
+    pub path: Option<String>,
+    /// Only write files that changed since the snapshot taken at this slot or
+    /// timestamp (RFC 3339); the latest snapshot at or before it is the baseline
+    #[arg(long, value_parser = parse_since)]
+    pub since: Option<Since>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ExportCommands {
+    /// Compare two exports and print added, removed and changed entities
+    Diff(ExportDiffCliCommand),
+}
+
+/// Point in time selecting the baseline snapshot of an incremental export.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Since {
+    Slot(u64),
+    /// Unix timestamp in seconds.
+    Timestamp(i64),
+}
+
+fn parse_since(value: &str) -> Result<Since, String> {
+    if let Ok(slot) = value.parse::<u64>() {
+        return Ok(Since::Slot(slot));
+    }
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|ts| Since::Timestamp(ts.timestamp()))
+        .map_err(|_| format!("expected a slot or an RFC 3339 timestamp, got '{value}'"))
+}
+
+/// Every export run appends a snapshot: when it ran and the hash of each file
+/// it would write, so a later run can tell what changed.
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct Manifest {
+    snapshots: Vec<Snapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Snapshot {
+    slot: u64,
+    timestamp: i64,
+    /// File name to the hash of its content.
+    files: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    fn load(dir: &Path) -> eyre::Result<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    fn save(&self, dir: &Path) -> eyre::Result<()> {
+        fs::write(dir.join(MANIFEST_FILE), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Latest snapshot taken at or before `since`.
+    fn baseline(&self, since: Since) -> Option<&Snapshot> {
+        self.snapshots
+            .iter()
+            .filter(|s| match since {
+                Since::Slot(slot) => s.slot <= slot,
+                Since::Timestamp(ts) => s.timestamp <= ts,
+            })
+            .max_by_key(|s| (s.slot, s.timestamp))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Data {
+    device: DeviceData,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct DeviceData {
+    name: String,
+    pubkey: String,
+    contributor: String,
+    public_ip: String,
+    location: LocationData,
+    exchange: ExchangeData,
+    tunnels: Vec<LinkData>,
+    users: Vec<UserData>,
+    owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LocationData {
+    code: String,
+    name: String,
+    pubkey: String,
+    country: String,
+    lat: f64,
+    lng: f64,
+    owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ExchangeData {
+    code: String,
+    name: String,
+    pubkey: String,
+    lat: f64,
+    lng: f64,
+    owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LinkData {
+    pub pubkey: String,
+    pub code: String,
+    pub iface_name: String,
+    pub side: LinkSideData,
+    pub tunnel_net: String,
+    pub link_type: String,
+    pub bandwidth: String,
+    pub mtu: u32,
+    pub delay_ms: f32,
+    pub jitter_ms: f32,
+    pub owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LinkSideData {
+    pub name: String,
+    pub pubkey: String,
+    pub tunnel_id: u16,
+    pub tunnel_net: String,
+    pub public_ip: String,
+    pub iface_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct UserData {
+    pub pubkey: String,
+    pub user_type: String,
+    pub cyoa_type: String,
+    pub client_ip: String,
+    pub tunnel_id: u16,
+    pub tunnel_net: String,
+    pub dz_ip: String,
+    pub status: String,
+    pub owner: String,
+    pub validator_pubkey: String,
+}
+
+impl ExportCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        if let Some(ExportCommands::Diff(args)) = self.command {
+            return args.execute(ctx, client, out).await;
+        }
+
+        let dir = PathBuf::from(self.path.ok_or_else(|| eyre::eyre!("--path is required"))?);
+        let mut manifest = Manifest::load(&dir)?;
+        let baseline = match self.since {
+            Some(since) => Some(
+                manifest
+                    .baseline(since)
+                    .cloned()
+                    .ok_or_else(|| eyre::eyre!("No export snapshot at or before {since:?}"))?,
+            ),
+            None => None,
+        };
+
+        let slot = client.get_slot()?;
+        let files = render_files(client)?;
+        let hashes: BTreeMap<String, String> = files
+            .iter()
+            .map(|(name, content)| (name.clone(), hash(content.as_bytes()).to_string()))
+            .collect();
+
+        for (name, content) in &files {
+            let unchanged = baseline
+                .as_ref()
+                .is_some_and(|b| b.files.get(name) == hashes.get(name));
+            if unchanged {
+                continue;
+            }
+            let path = dir.join(name);
+            writeln!(out, "{}", path.display())?;
+            fs::write(path, content)?;
+        }
+
+        // An incremental export also drops the files of entities that are gone.
+        if let Some(baseline) = &baseline {
+            for name in baseline.files.keys().filter(|n| !files.contains_key(*n)) {
+                let path = dir.join(name);
+                if path.exists() {
+                    fs::remove_file(&path)?;
+                }
+                writeln!(out, "{} (removed)", path.display())?;
+            }
+        }
+
+        manifest.snapshots.push(Snapshot {
+            slot,
+            timestamp: chrono::Utc::now().timestamp(),
+            files: hashes,
+        });
+        manifest.save(&dir)
+    }
+}
+
+/// Render one YAML file per device, keyed by file name.
+fn render_files<C: CliCommand>(client: &C) -> eyre::Result<BTreeMap<String, String>> {
+    let locations = client.list_location(ListLocationCommand)?;
+    let exchanges = client.list_exchange(ListExchangeCommand)?;
+
+    let contributors = client.list_contributor(contributor::list::ListContributorCommand {})?;
+    let devices = client.list_device(ListDeviceCommand)?;
+    let tunnels = client.list_link(ListLinkCommand)?;
+    let users = client.list_user(ListUserCommand)?;
+
+    let mut files = BTreeMap::new();
+    for (pubkey, data) in devices.clone() {
+        let name = format!("{}.yml", data.code);
+        let location = locations
+            .get(&data.location_pk)
+            .ok_or(eyre::eyre!("Unable to retrieve Location"))?;
+        let exchange = exchanges
+            .get(&data.exchange_pk)
+            .ok_or(eyre::eyre!("Unable to retrieve Exchange"))?;
+
+        let contributor = contributors
+            .get(&data.contributor_pk)
+            .ok_or(eyre::eyre!("Unable to retrieve Contributor"))?;
+
+        let mut config = Data {
+            device: DeviceData {
+                name: data.code,
+                pubkey: pubkey.to_string(),
+                contributor: contributor.code.clone(),
+                location: LocationData {
+                    code: location.code.clone(),
+                    name: location.name.clone(),
+                    country: location.country.clone(),
+                    pubkey: data.location_pk.to_string(),
+                    lat: location.lat,
+                    lng: location.lng,
+                    owner: location.owner.to_string(),
+                },
+                exchange: ExchangeData {
+                    code: exchange.code.clone(),
+                    name: exchange.name.clone(),
+                    pubkey: data.exchange_pk.to_string(),
+                    lat: exchange.lat,
+                    lng: exchange.lng,
+                    owner: exchange.owner.to_string(),
+                },
+                public_ip: data.public_ip.to_string(),
+                tunnels: tunnels
+                    .clone()
+                    .into_iter()
+                    .filter(|(_, tunnel)| tunnel.side_a_pk == pubkey || tunnel.side_z_pk == pubkey)
+                    .filter_map(|(key, link)| {
+                        let iface_name = if link.side_a_pk == pubkey {
+                            link.side_a_iface_name.clone()
+                        } else {
+                            link.side_z_iface_name.clone()
+                        };
+
+                        let side_pubkey = if link.side_a_pk == pubkey {
+                            link.side_z_pk
+                        } else {
+                            link.side_a_pk
+                        };
+
+                        let iface_name2 = if link.side_a_pk == pubkey {
+                            link.side_z_iface_name.clone()
+                        } else {
+                            link.side_a_iface_name.clone()
+                        };
+
+                        let side_device = devices.get(&side_pubkey)?;
+
+                        Some(LinkData {
+                            pubkey: key.to_string(),
+                            code: link.code.clone(),
+                            tunnel_net: link.tunnel_net.to_string(),
+                            iface_name,
+                            side: LinkSideData {
+                                name: side_device.code.clone(),
+                                pubkey: side_pubkey.to_string(),
+                                iface_name: iface_name2,
+                                public_ip: side_device.public_ip.to_string(),
+                                tunnel_id: link.tunnel_id,
+                                tunnel_net: link.tunnel_net.to_string(),
+                            },
+                            link_type: link.link_type.to_string(),
+                            bandwidth: bandwidth_to_string(&link.bandwidth),
+                            mtu: link.mtu,
+                            delay_ms: link.delay_ns as f32 / 1_000_000.0,
+                            jitter_ms: link.jitter_ns as f32 / 1_000_000.0,
+                            owner: link.owner.to_string(),
+                        })
+                    })
+                    .collect(),
+                users: users
+                    .iter()
+                    .filter(|(_, user)| user.device_pk == pubkey)
+                    .map(|(key, user)| UserData {
+                        pubkey: key.to_string(),
+                        user_type: user.user_type.to_string(),
+                        client_ip: user.client_ip.to_string(),
+                        cyoa_type: user.cyoa_type.to_string(),
+                        tunnel_id: user.tunnel_id,
+                        tunnel_net: user.tunnel_net.to_string(),
+                        dz_ip: user.dz_ip.to_string(),
+                        status: user.status.to_string(),
+                        owner: user.owner.to_string(),
+                        validator_pubkey: user.validator_pubkey.to_string(),
+                    })
+                    .collect(),
+                owner: data.owner.to_string(),
+            },
+        };
+
+        // Accounts are listed in hash map order; sort so unchanged devices
+        // render byte-identical files across runs.
+        config
+            .device
+            .tunnels
+            .sort_by(|a, b| a.pubkey.cmp(&b.pubkey));
+        config.device.users.sort_by(|a, b| a.pubkey.cmp(&b.pubkey));
+
+        files.insert(name, serde_yaml::to_string(&config)?);
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        doublezerocommand::MockCliCommand, export::diff::ExportDiffCliCommand,
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        AccountType, Contributor, ContributorStatus, Device, Exchange, ExchangeStatus, Link,
+        Location, LocationStatus, User, UserCYOA, UserStatus, UserType,
+    };
+    use solana_sdk::pubkey::Pubkey;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    const LOCATION_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR");
+    const EXCHANGE_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPA");
+    const CONTRIBUTOR_PK: Pubkey =
+        Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPB");
+    const DEVICE1_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPC");
+    const DEVICE2_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPD");
+    const LINK_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPE");
+    const USER_PK: Pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPF");
+
+    fn device(code: &str) -> Device {
+        Device {
+            code: code.to_string(),
+            location_pk: LOCATION_PK,
+            exchange_pk: EXCHANGE_PK,
+            contributor_pk: CONTRIBUTOR_PK,
+            public_ip: "1.0.0.1".parse().unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn user(status: UserStatus) -> User {
+        User {
+            account_type: AccountType::User,
+            owner: Pubkey::default(),
+            index: 1,
+            bump_seed: 1,
+            user_type: UserType::IBRL,
+            device_pk: DEVICE1_PK,
+            tenant_pk: Pubkey::default(),
+            cyoa_type: UserCYOA::GREOverDIA,
+            client_ip: "10.0.0.1".parse().unwrap(),
+            dz_ip: "10.0.0.1".parse().unwrap(),
+            tunnel_id: 500,
+            tunnel_net: "169.254.0.0/31".parse().unwrap(),
+            status,
+            publishers: vec![],
+            subscribers: vec![],
+            validator_pubkey: Pubkey::default(),
+            tunnel_endpoint: "1.0.0.1".parse().unwrap(),
+            tunnel_flags: 0,
+            bgp_status: Default::default(),
+            last_bgp_up_at: 0,
+            last_bgp_reported_at: 0,
+            bgp_rtt_ns: 0,
+            feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
+        }
+    }
+
+    /// Mock client serving two linked devices and a user whose status is
+    /// read from `status`, so a test can change it between exports.
+    fn create_client(status: Arc<Mutex<UserStatus>>, slot: u64) -> MockCliCommand {
+        let mut client = create_test_client();
+        client.expect_get_slot().returning(move || Ok(slot));
+        client.expect_list_location().returning(|_| {
+            Ok(HashMap::from([(
+                LOCATION_PK,
+                Location {
+                    account_type: AccountType::Location,
+                    owner: Pubkey::default(),
+                    index: 1,
+                    bump_seed: 1,
+                    lat: 1.0,
+                    lng: 2.0,
+                    loc_id: 3,
+                    status: LocationStatus::Activated,
+                    code: "loc".to_string(),
+                    name: "Location".to_string(),
+                    country: "US".to_string(),
+                    reference_count: 0,
+                },
+            )]))
+        });
+        client.expect_list_exchange().returning(|_| {
+            Ok(HashMap::from([(
+                EXCHANGE_PK,
+                Exchange {
+                    account_type: AccountType::Exchange,
+                    index: 1,
+                    bump_seed: 1,
+                    reference_count: 0,
+                    code: "xchg".to_string(),
+                    name: "Exchange".to_string(),
+                    device1_pk: Pubkey::default(),
+                    device2_pk: Pubkey::default(),
+                    lat: 1.0,
+                    lng: 2.0,
+                    bgp_community: 3,
+                    unused: 0,
+                    status: ExchangeStatus::Activated,
+                    owner: Pubkey::default(),
+                },
+            )]))
+        });
+        client.expect_list_contributor().returning(|_| {
+            Ok(HashMap::from([(
+                CONTRIBUTOR_PK,
+                Contributor {
+                    account_type: AccountType::Contributor,
+                    owner: Pubkey::default(),
+                    index: 1,
+                    bump_seed: 1,
+                    status: ContributorStatus::Activated,
+                    code: "co".to_string(),
+                    reference_count: 0,
+                    ops_manager_pk: Pubkey::default(),
+                },
+            )]))
+        });
+        client.expect_list_device().returning(|_| {
+            Ok(HashMap::from([
+                (DEVICE1_PK, device("dev1")),
+                (DEVICE2_PK, device("dev2")),
+            ]))
+        });
+        client.expect_list_link().returning(|_| {
+            Ok(HashMap::from([(
+                LINK_PK,
+                Link {
+                    code: "dev1:dev2".to_string(),
+                    side_a_pk: DEVICE1_PK,
+                    side_z_pk: DEVICE2_PK,
+                    side_a_iface_name: "Ethernet1".to_string(),
+                    side_z_iface_name: "Ethernet2".to_string(),
+                    ..Default::default()
+                },
+            )]))
+        });
+        client
+            .expect_list_user()
+            .returning(move |_| Ok(HashMap::from([(USER_PK, user(*status.lock().unwrap()))])));
+        client
+    }
+
+    fn export(client: &MockCliCommand, dir: &Path, since: Option<Since>) -> String {
+        let mut output = Vec::new();
+        block_on(
+            ExportCliCommand {
+                command: None,
+                path: Some(dir.to_string_lossy().to_string()),
+                since,
+            }
+            .execute(&cli_context_default_for_tests(), client, &mut output),
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(parse_since("1234"), Ok(Since::Slot(1234)));
+        assert_eq!(
+            parse_since("2026-01-02T03:04:05Z"),
+            Ok(Since::Timestamp(1767323045))
+        );
+        assert!(parse_since("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_cli_export_since_writes_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let status = Arc::new(Mutex::new(UserStatus::Activated));
+
+        let output = export(&create_client(status.clone(), 100), dir.path(), None);
+        assert!(output.contains("dev1.yml"));
+        assert!(output.contains("dev2.yml"));
+
+        // Nothing changed since slot 100.
+        let output = export(
+            &create_client(status.clone(), 200),
+            dir.path(),
+            Some(Since::Slot(150)),
+        );
+        assert_eq!(output, "");
+
+        // Only the device carrying the user is rewritten.
+        *status.lock().unwrap() = UserStatus::OutOfCredits;
+        let output = export(
+            &create_client(status.clone(), 300),
+            dir.path(),
+            Some(Since::Slot(250)),
+        );
+        assert!(output.contains("dev1.yml"));
+        assert!(!output.contains("dev2.yml"));
+        let content = fs::read_to_string(dir.path().join("dev1.yml")).unwrap();
+        assert!(content.contains("status: out_of_credits"), "{content}");
+
+        let manifest = Manifest::load(dir.path()).unwrap();
+        let slots: Vec<u64> = manifest.snapshots.iter().map(|s| s.slot).collect();
+        assert_eq!(slots, vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn test_cli_export_since_without_snapshot_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = create_client(Arc::new(Mutex::new(UserStatus::Activated)), 100);
+
+        let res = block_on(
+            ExportCliCommand {
+                command: None,
+                path: Some(dir.path().to_string_lossy().to_string()),
+                since: Some(Since::Slot(50)),
+            }
+            .execute(&cli_context_default_for_tests(), &client, &mut Vec::new()),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_cli_export_diff() {
+        let from = tempfile::tempdir().unwrap();
+        let to = tempfile::tempdir().unwrap();
+        let status = Arc::new(Mutex::new(UserStatus::Activated));
+        export(&create_client(status.clone(), 100), from.path(), None);
+        *status.lock().unwrap() = UserStatus::OutOfCredits;
+        export(&create_client(status, 200), to.path(), None);
+        fs::remove_file(to.path().join("dev2.yml")).unwrap();
+
+        let client = create_test_client();
+        let mut output = Vec::new();
+        block_on(
+            ExportCliCommand {
+                command: Some(ExportCommands::Diff(ExportDiffCliCommand {
+                    from: from.path().to_string_lossy().to_string(),
+                    to: to.path().to_string_lossy().to_string(),
+                })),
+                path: None,
+                since: None,
+            }
+            .execute(&cli_context_default_for_tests(), &client, &mut output),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains(&format!("- device dev2 ({DEVICE2_PK})")));
+        // dev1's file still describes both ends of the link.
+        assert!(!output.contains("link dev1:dev2"));
+        assert!(output.contains(&format!("~ user IBRL 10.0.0.1 ({USER_PK})")));
+        assert!(output.contains("    status: activated -> out_of_credits"));
+        assert!(output.contains("0 added, 1 removed, 1 changed"));
+    }
+}
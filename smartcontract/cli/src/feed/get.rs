@@ -4,7 +4,7 @@ use crate::{
 };
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
-use doublezero_sdk::commands::feed::get::GetFeedCommand;
+use doublezero_sdk::{commands::feed::get::GetFeedCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use std::io::Write;
 use tabled::Tabled;
@@ -23,7 +23,7 @@ pub struct GetFeedCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct FeedDisplay {
+pub(crate) struct FeedDisplay {
     pub account: String,
     pub code: String,
     pub name: String,
@@ -31,7 +31,7 @@ struct FeedDisplay {
     pub exchange: String,
     /// Number of multicast groups joinable in this metro.
     pub groups: usize,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 impl GetFeedCliCommand {
@@ -41,6 +41,7 @@ impl GetFeedCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let exchange = self
             .exchange
             .as_deref()
@@ -57,7 +58,7 @@ impl GetFeedCliCommand {
             name: feed.name,
             exchange: feed.exchange.to_string(),
             groups: feed.groups.len(),
-            owner: feed.owner.to_string(),
+            owner: book.labeled(&feed.owner),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
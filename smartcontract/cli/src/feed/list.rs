@@ -2,7 +2,7 @@ use crate::doublezerocommand::CliCommand;
 use clap::Args;
 use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::feed::list::ListFeedCommand;
+use doublezero_sdk::{commands::feed::list::ListFeedCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
@@ -27,8 +27,7 @@ pub struct FeedDisplay {
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
     pub exchange: Pubkey,
     pub groups: usize,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListFeedCliCommand {
@@ -38,6 +37,7 @@ impl ListFeedCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let feeds = client.list_feed(ListFeedCommand)?;
 
         let mut displays = feeds
@@ -48,7 +48,7 @@ impl ListFeedCliCommand {
                 name: feed.name,
                 exchange: feed.exchange,
                 groups: feed.groups.len(),
-                owner: feed.owner,
+                owner: book.labeled(&feed.owner),
             })
             .collect::<Vec<FeedDisplay>>();
 
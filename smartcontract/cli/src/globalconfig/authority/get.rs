@@ -1,10 +1,8 @@
 use crate::doublezerocommand::CliCommand;
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_program_common::serializer;
-use doublezero_sdk::GetGlobalStateCommand;
+use doublezero_sdk::{labels::LabeledPubkey, GetGlobalStateCommand};
 use serde::Serialize;
-use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
 use tabled::Tabled;
 
@@ -17,14 +15,10 @@ pub struct GetAuthorityCliCommand {
 
 #[derive(Tabled, Serialize)]
 pub struct AuthorityDisplay {
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub activator_authority: Pubkey,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub access_authority: Pubkey,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub feed_authority: Pubkey,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub health_oracle: Pubkey,
+    pub activator_authority: LabeledPubkey,
+    pub access_authority: LabeledPubkey,
+    pub feed_authority: LabeledPubkey,
+    pub health_oracle: LabeledPubkey,
 }
 
 impl GetAuthorityCliCommand {
@@ -34,13 +28,14 @@ impl GetAuthorityCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (_, gstate) = client.get_globalstate(GetGlobalStateCommand)?;
 
         let config_display = AuthorityDisplay {
-            activator_authority: gstate.activator_authority_pk,
-            access_authority: gstate.sentinel_authority_pk,
-            feed_authority: gstate.feed_authority_pk,
-            health_oracle: gstate.health_oracle_pk,
+            activator_authority: book.labeled(&gstate.activator_authority_pk),
+            access_authority: book.labeled(&gstate.sentinel_authority_pk),
+            feed_authority: book.labeled(&gstate.feed_authority_pk),
+            health_oracle: book.labeled(&gstate.health_oracle_pk),
         };
 
         if self.json {
@@ -94,6 +89,7 @@ mod tests {
             qa_allowlist: vec![],
             feature_flags: 0,
             feed_authority_pk: feed_authority,
+            paused: 0,
         };
 
         client
@@ -15,7 +15,7 @@ pub struct GetFeatureFlagsCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct FeatureFlagsDisplay {
+pub(crate) struct FeatureFlagsDisplay {
     flags: String,
     raw: u128,
 }
@@ -77,6 +77,7 @@ mod tests {
             qa_allowlist: vec![],
             feature_flags: 1,
             feed_authority_pk: Pubkey::default(),
+            paused: 0,
         };
 
         client
@@ -114,6 +115,7 @@ mod tests {
             qa_allowlist: vec![],
             feature_flags: 0,
             feed_authority_pk: Pubkey::default(),
+            paused: 0,
         };
 
         client
@@ -151,6 +153,7 @@ mod tests {
             qa_allowlist: vec![],
             feature_flags: 1,
             feed_authority_pk: Pubkey::default(),
+            paused: 0,
         };
 
         client
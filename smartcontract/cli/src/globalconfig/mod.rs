@@ -2,5 +2,6 @@ pub mod airdrop;
 pub mod authority;
 pub mod featureflags;
 pub mod get;
+pub mod pause;
 pub mod set;
 pub mod setversion;
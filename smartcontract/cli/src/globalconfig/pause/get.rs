@@ -0,0 +1,91 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_record, CliContext, OutputFormat};
+use doublezero_sdk::GetGlobalStateCommand;
+use doublezero_serviceability::state::pause_flags::paused_flags;
+use serde::Serialize;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct GetPauseCliCommand {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct PauseDisplay {
+    paused: String,
+    raw: u64,
+}
+
+impl GetPauseCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (_, gstate) = client.get_globalstate(GetGlobalStateCommand)?;
+
+        let flag_names: Vec<String> = paused_flags(gstate.paused)
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+
+        let display = PauseDisplay {
+            paused: flag_names.join(", "),
+            raw: gstate.paused,
+        };
+
+        render_record(out, &display, OutputFormat::from_flags(self.json, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{globalconfig::pause::get::GetPauseCliCommand, tests::utils::create_test_client};
+    use doublezero_sdk::{AccountType, GetGlobalStateCommand, GlobalState};
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_cli_globalconfig_pause_get() {
+        let mut client = create_test_client();
+
+        let gstate_pubkey = Pubkey::new_unique();
+        let globalstate = GlobalState {
+            account_type: AccountType::GlobalState,
+            bump_seed: 0,
+            account_index: 0,
+            foundation_allowlist: vec![],
+            _device_allowlist: vec![],
+            _user_allowlist: vec![],
+            activator_authority_pk: Pubkey::default(),
+            sentinel_authority_pk: Pubkey::default(),
+            contributor_airdrop_lamports: 0,
+            user_airdrop_lamports: 0,
+            health_oracle_pk: Pubkey::default(),
+            qa_allowlist: vec![],
+            feature_flags: 0,
+            feed_authority_pk: Pubkey::default(),
+            paused: 5,
+        };
+
+        client
+            .expect_get_globalstate()
+            .with(predicate::eq(GetGlobalStateCommand))
+            .returning(move |_| Ok((gstate_pubkey, globalstate.clone())));
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(GetPauseCliCommand { json: true }.execute(&ctx, &client, &mut output));
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("create, delete"));
+        assert!(output_str.contains("5"));
+    }
+}
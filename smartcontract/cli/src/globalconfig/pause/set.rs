@@ -0,0 +1,159 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::{commands::globalstate::setpause::SetPauseCommand, GetGlobalStateCommand};
+use doublezero_serviceability::state::pause_flags::PauseFlag;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SetPauseCliCommand {
+    /// Instruction classes to pause (comma-separated: create, update, delete or all)
+    #[arg(long, value_delimiter = ',')]
+    pub pause: Vec<String>,
+
+    /// Instruction classes to resume (comma-separated: create, update, delete or all)
+    #[arg(long, value_delimiter = ',')]
+    pub resume: Vec<String>,
+}
+
+fn parse_mask(names: &[String]) -> eyre::Result<u64> {
+    names.iter().try_fold(0, |mask, name| {
+        if name == "all" {
+            return Ok(mask | PauseFlag::all_mask());
+        }
+        let flag: PauseFlag = name.parse().map_err(|e: String| eyre::eyre!(e))?;
+        Ok(mask | flag.to_mask())
+    })
+}
+
+impl SetPauseCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        if self.pause.is_empty() && self.resume.is_empty() {
+            return Err(eyre::eyre!(
+                "at least one of --pause or --resume must be provided"
+            ));
+        }
+
+        let pause_mask = parse_mask(&self.pause)?;
+        let resume_mask = parse_mask(&self.resume)?;
+
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let (_, gstate) = client.get_globalstate(GetGlobalStateCommand)?;
+        let paused = (gstate.paused | pause_mask) & !resume_mask;
+
+        let signature = client.set_pause(SetPauseCommand { paused })?;
+        writeln!(out, "Signature: {signature}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+
+    use crate::{
+        globalconfig::pause::set::SetPauseCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_sdk::{
+        commands::globalstate::setpause::SetPauseCommand, AccountType, GetGlobalStateCommand,
+        GlobalState,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    fn test_globalstate(paused: u64) -> GlobalState {
+        GlobalState {
+            account_type: AccountType::GlobalState,
+            bump_seed: 0,
+            account_index: 0,
+            foundation_allowlist: vec![],
+            _device_allowlist: vec![],
+            _user_allowlist: vec![],
+            activator_authority_pk: Pubkey::default(),
+            sentinel_authority_pk: Pubkey::default(),
+            contributor_airdrop_lamports: 0,
+            user_airdrop_lamports: 0,
+            health_oracle_pk: Pubkey::default(),
+            qa_allowlist: vec![],
+            feature_flags: 0,
+            feed_authority_pk: Pubkey::default(),
+            paused,
+        }
+    }
+
+    fn run(client_paused: u64, pause: &[&str], resume: &[&str], expected: u64) {
+        let mut client = create_test_client();
+        let gstate_pubkey = Pubkey::new_unique();
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_globalstate()
+            .with(predicate::eq(GetGlobalStateCommand))
+            .returning(move |_| Ok((gstate_pubkey, test_globalstate(client_paused))));
+        client
+            .expect_set_pause()
+            .with(predicate::eq(SetPauseCommand { paused: expected }))
+            .returning(|_| Ok(Signature::new_unique()));
+
+        let mut output = Vec::new();
+        let ctx = cli_context_default_for_tests();
+        let res = block_on(
+            SetPauseCliCommand {
+                pause: pause.iter().map(|s| s.to_string()).collect(),
+                resume: resume.iter().map(|s| s.to_string()).collect(),
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.starts_with("Signature: "));
+    }
+
+    #[test]
+    fn test_cli_globalconfig_pause_set() {
+        run(0, &["create", "delete"], &[], 5);
+        run(0, &["all"], &[], 7);
+        run(7, &[], &["update"], 5);
+        run(7, &[], &["all"], 0);
+    }
+
+    #[test]
+    fn test_cli_globalconfig_pause_set_errors() {
+        let client = create_test_client();
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+
+        let res = block_on(
+            SetPauseCliCommand {
+                pause: vec![],
+                resume: vec![],
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_err());
+
+        let res = block_on(
+            SetPauseCliCommand {
+                pause: vec!["everything".to_string()],
+                resume: vec![],
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.unwrap_err().to_string().contains("unknown pause flag"));
+    }
+}
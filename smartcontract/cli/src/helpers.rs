@@ -1,12 +1,16 @@
 use crate::doublezerocommand::CliCommand;
 use chrono::{TimeZone, Utc};
-use doublezero_sdk::commands::{
-    contributor::get::GetContributorCommand, exchange::get::GetExchangeCommand,
-    location::get::GetLocationCommand, multicastgroup::get::GetMulticastGroupCommand,
-    tenant::get::GetTenantCommand,
+use doublezero_sdk::{
+    commands::{
+        contributor::get::GetContributorCommand, exchange::get::GetExchangeCommand,
+        location::get::GetLocationCommand, multicastgroup::get::GetMulticastGroupCommand,
+        tenant::get::GetTenantCommand,
+    },
+    find_transition, next_statuses, StatusMachine,
 };
 use eyre::WrapErr;
 use std::{
+    fmt::Display,
     io::{Read, Write},
     str,
 };
@@ -41,6 +45,34 @@ pub fn parse_pubkey(input: &str) -> Option<Pubkey> {
     Pubkey::from_str(input).ok()
 }
 
+/// Reject a `--status` change the program would refuse for any caller, before
+/// sending the transaction. Whether the payer may make an admin-only change is
+/// left to the program.
+pub fn check_status_transition<S: StatusMachine + Display>(
+    from: S,
+    to: S,
+    instruction: &str,
+) -> eyre::Result<()> {
+    if from == to || find_transition(from, to, instruction).is_some() {
+        return Ok(());
+    }
+    let allowed: Vec<String> = next_statuses(from, true)
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    eyre::bail!(
+        "{} status cannot change from {} to {} (allowed: {})",
+        S::ENTITY,
+        from,
+        to,
+        if allowed.is_empty() {
+            "none".to_string()
+        } else {
+            allowed.join(", ")
+        }
+    )
+}
+
 /// Resolve a `--pubkey`/`--code` argument to the location's on-chain pubkey.
 ///
 /// Update and delete verbs accept either a base58 pubkey or a location code.
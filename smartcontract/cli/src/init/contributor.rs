@@ -0,0 +1,561 @@
+use crate::{config::set::SetConfigCliCommand, doublezerocommand::CliCommand};
+use clap::Args;
+use doublezero_cli_core::{validators::validate_code, CliContext};
+use doublezero_config::Environment;
+use doublezero_sdk::{
+    commands::{
+        contributor::{create::CreateContributorCommand, list::ListContributorCommand},
+        globalstate::get::GetGlobalStateCommand,
+    },
+    create_new_pubkey_user,
+    keypair::parse_keypair_json,
+    read_doublezero_config, DZClient,
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+/// Below this balance the payer cannot reliably pay for the onboarding
+/// transactions, and the wizard offers to fund it.
+const MIN_BALANCE_LAMPORTS: u64 = 100_000_000;
+/// Amount requested from the faucet on networks that have one.
+const AIRDROP_LAMPORTS: u64 = 1_000_000_000;
+
+#[derive(Args, Debug)]
+pub struct InitContributorCliCommand {
+    /// Environment to onboard to (testnet [t], devnet [d], or mainnet-beta [m]); asked when omitted
+    #[arg(long, value_name = "ENV")]
+    pub env: Option<String>,
+    /// Import an existing keypair file instead of using or generating the configured one
+    #[arg(long, value_name = "PATH")]
+    pub keypair: Option<PathBuf>,
+    /// Code of the contributor to create when the keypair does not own one yet; asked when omitted
+    #[arg(long, value_parser = validate_code)]
+    pub code: Option<String>,
+    /// Do not request an airdrop, even when the balance is low
+    #[arg(long)]
+    pub no_airdrop: bool,
+    /// Accept the default answer to every question
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+impl InitContributorCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let stdin = std::io::stdin();
+        let mut prompter = Prompter::new(stdin.lock(), self.yes);
+
+        writeln!(out, "Step 1/5: keypair")?;
+        let keypair_path = self.setup_keypair(&mut prompter, out)?;
+
+        writeln!(out, "\nStep 2/5: environment")?;
+        let env = match &self.env {
+            Some(env) => env.parse::<Environment>()?,
+            None => prompter
+                .ask(
+                    out,
+                    "Environment (testnet, devnet, mainnet-beta)",
+                    &ctx.env.to_string(),
+                )?
+                .parse::<Environment>()?,
+        };
+
+        let options = OnboardOptions {
+            env,
+            code: self.code,
+            airdrop: !self.no_airdrop,
+        };
+
+        if keypair_path.is_none() && options.env == ctx.env {
+            writeln!(out, "Using {} as configured", options.env)?;
+            return onboard(client, &options, &mut prompter, out);
+        }
+
+        SetConfigCliCommand {
+            env: Some(options.env.to_string()),
+            url: None,
+            ws: None,
+            keypair: keypair_path,
+            program_id: None,
+            geo_program_id: None,
+            tenant: None,
+            no_tenant: false,
+            fallback_urls: vec![],
+            no_fallback_urls: false,
+            rpc_read_mode: None,
+        }
+        .execute(ctx, client, out)
+        .await?;
+
+        // The running client was built from the previous configuration; the
+        // remaining steps must talk to the environment and keypair just saved.
+        let dzclient = DZClient::new(None, None, None, None)?;
+        let client = crate::doublezerocommand::CliCommandImpl::new(&dzclient);
+        onboard(&client, &options, &mut prompter, out)
+    }
+
+    /// Makes sure a usable keypair exists. Returns the path to record in the
+    /// configuration when it differs from the configured one.
+    fn setup_keypair<R: BufRead, W: Write>(
+        &self,
+        prompter: &mut Prompter<R>,
+        out: &mut W,
+    ) -> eyre::Result<Option<PathBuf>> {
+        let (_, config) = read_doublezero_config()?;
+
+        if let Some(path) = &self.keypair {
+            let keypair = read_keypair(path)?;
+            writeln!(
+                out,
+                "Imported keypair {} ({})",
+                path.display(),
+                keypair.pubkey()
+            )?;
+            return Ok(Some(path.clone()));
+        }
+
+        if let Ok(keypair) = read_keypair(&config.keypair_path) {
+            writeln!(
+                out,
+                "Using keypair {} ({})",
+                config.keypair_path.display(),
+                keypair.pubkey()
+            )?;
+            return Ok(None);
+        }
+
+        writeln!(out, "No keypair found at {}", config.keypair_path.display())?;
+        if prompter.confirm(out, "Generate a new keypair?", true)? {
+            let keypair = create_new_pubkey_user(false, None)?;
+            writeln!(
+                out,
+                "Generated keypair {} ({})",
+                config.keypair_path.display(),
+                keypair.pubkey()
+            )?;
+            return Ok(None);
+        }
+
+        let answer = prompter.ask(out, "Path of the keypair file to import", "")?;
+        if answer.is_empty() {
+            eyre::bail!("A keypair is required (generate one or pass --keypair <PATH>)");
+        }
+        let path = PathBuf::from(answer);
+        let keypair = read_keypair(&path)?;
+        writeln!(
+            out,
+            "Imported keypair {} ({})",
+            path.display(),
+            keypair.pubkey()
+        )?;
+        Ok(Some(path))
+    }
+}
+
+fn read_keypair(path: &Path) -> eyre::Result<Keypair> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| eyre::eyre!("Unable to read keypair {}: {e}", path.display()))?;
+    Ok(parse_keypair_json(&content, &path.display().to_string())?)
+}
+
+struct OnboardOptions {
+    env: Environment,
+    code: Option<String>,
+    airdrop: bool,
+}
+
+/// Funding, contributor and validation steps, run against the client for the
+/// chosen environment and keypair.
+fn onboard<C: CliCommand, R: BufRead, W: Write>(
+    client: &C,
+    options: &OnboardOptions,
+    prompter: &mut Prompter<R>,
+    out: &mut W,
+) -> eyre::Result<()> {
+    let payer = client.get_payer();
+
+    writeln!(out, "\nStep 3/5: funding")?;
+    let balance = client.get_balance()?;
+    writeln!(
+        out,
+        "Balance of {payer}: {} Credits",
+        lamports_to_credits(balance)
+    )?;
+    if balance < MIN_BALANCE_LAMPORTS {
+        let has_faucet = matches!(
+            options.env,
+            Environment::Devnet | Environment::Testnet | Environment::Local
+        );
+        if has_faucet
+            && options.airdrop
+            && prompter.confirm(
+                out,
+                &format!(
+                    "Request an airdrop of {} Credits?",
+                    lamports_to_credits(AIRDROP_LAMPORTS)
+                ),
+                true,
+            )?
+        {
+            match client.request_airdrop(AIRDROP_LAMPORTS) {
+                Ok(signature) => writeln!(out, "Airdrop confirmed: {signature}")?,
+                Err(e) => writeln!(out, "Airdrop failed: {e}")?,
+            }
+        } else {
+            writeln!(
+                out,
+                "Fund {payer} with at least {} Credits on {} before continuing",
+                lamports_to_credits(MIN_BALANCE_LAMPORTS),
+                options.env
+            )?;
+        }
+    }
+
+    writeln!(out, "\nStep 4/5: contributor")?;
+    let contributors = client.list_contributor(ListContributorCommand {})?;
+    match contributors.iter().find(|(_, c)| c.owner == payer) {
+        Some((pubkey, contributor)) => writeln!(
+            out,
+            "Keypair already owns contributor {} ({pubkey})",
+            contributor.code
+        )?,
+        None => {
+            let code = match &options.code {
+                Some(code) => code.clone(),
+                None => validate_code(&prompter.ask(out, "Contributor code", "")?)
+                    .map_err(|e| eyre::eyre!(e))?,
+            };
+            if contributors.values().any(|c| c.code == code) {
+                eyre::bail!("Contributor code {code} is already taken by another owner");
+            }
+            match client.create_contributor(CreateContributorCommand {
+                code: code.clone(),
+                owner: payer,
+            }) {
+                Ok((signature, pubkey)) => {
+                    writeln!(out, "Created contributor {code} ({pubkey}): {signature}")?
+                }
+                Err(e) => {
+                    writeln!(out, "Could not create contributor {code}: {e}")?;
+                    writeln!(
+                        out,
+                        "Contributors are created by the DoubleZero Foundation. Send them this command:"
+                    )?;
+                    writeln!(
+                        out,
+                        "  doublezero contributor create --code {code} --owner {payer}"
+                    )?;
+                }
+            }
+        }
+    }
+
+    writeln!(out, "\nStep 5/5: validation")?;
+    validate(client, options, out)
+}
+
+/// Re-reads the state the wizard just set up and prints a checklist; fails
+/// when any item is missing so scripted runs exit non-zero.
+fn validate<C: CliCommand, W: Write>(
+    client: &C,
+    options: &OnboardOptions,
+    out: &mut W,
+) -> eyre::Result<()> {
+    let payer = client.get_payer();
+    let checks = [
+        (
+            "ledger",
+            client
+                .get_globalstate(GetGlobalStateCommand)
+                .map(|_| format!("{} reachable", options.env))
+                .map_err(|e| e.to_string()),
+        ),
+        (
+            "balance",
+            match client.get_balance() {
+                Ok(balance) if balance >= MIN_BALANCE_LAMPORTS => {
+                    Ok(format!("{} Credits", lamports_to_credits(balance)))
+                }
+                Ok(balance) => Err(format!(
+                    "{} Credits is not enough",
+                    lamports_to_credits(balance)
+                )),
+                Err(e) => Err(e.to_string()),
+            },
+        ),
+        (
+            "contributor",
+            match client.list_contributor(ListContributorCommand {}) {
+                Ok(contributors) => contributors
+                    .iter()
+                    .find(|(_, c)| c.owner == payer)
+                    .map(|(pubkey, c)| format!("{} ({pubkey}) {}", c.code, c.status))
+                    .ok_or_else(|| format!("none owned by {payer}")),
+                Err(e) => Err(e.to_string()),
+            },
+        ),
+    ];
+
+    let mut failed = 0;
+    for (name, result) in &checks {
+        match result {
+            Ok(detail) => writeln!(out, "✓ {name}: {detail}")?,
+            Err(detail) => {
+                failed += 1;
+                writeln!(out, "✗ {name}: {detail}")?
+            }
+        }
+    }
+
+    if failed > 0 {
+        eyre::bail!(
+            "Setup incomplete: fix the items marked ✗ and run `doublezero init contributor` again"
+        );
+    }
+    writeln!(out, "Setup complete")?;
+    Ok(())
+}
+
+fn lamports_to_credits(lamports: u64) -> f64 {
+    lamports as f64 / 1_000_000_000.0
+}
+
+/// Reads answers line by line; with `assume_yes` every question takes its
+/// default without reading input.
+struct Prompter<R: BufRead> {
+    input: R,
+    assume_yes: bool,
+}
+
+impl<R: BufRead> Prompter<R> {
+    fn new(input: R, assume_yes: bool) -> Self {
+        Self { input, assume_yes }
+    }
+
+    fn ask<W: Write>(
+        &mut self,
+        out: &mut W,
+        question: &str,
+        default: &str,
+    ) -> eyre::Result<String> {
+        if default.is_empty() {
+            write!(out, "{question}: ")?;
+        } else {
+            write!(out, "{question} [{default}]: ")?;
+        }
+        if self.assume_yes {
+            writeln!(out, "{default}")?;
+            return Ok(default.to_string());
+        }
+        out.flush()?;
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            eyre::bail!("No answer to \"{question}\" (pass --yes to accept the defaults)");
+        }
+        let answer = line.trim();
+        Ok(if answer.is_empty() { default } else { answer }.to_string())
+    }
+
+    fn confirm<W: Write>(
+        &mut self,
+        out: &mut W,
+        question: &str,
+        default: bool,
+    ) -> eyre::Result<bool> {
+        let hint = if default { "Y/n" } else { "y/N" };
+        let answer = self.ask(out, question, hint)?;
+        match answer.to_lowercase().as_str() {
+            _ if answer == hint => Ok(default),
+            "y" | "yes" => Ok(true),
+            "n" | "no" => Ok(false),
+            _ => eyre::bail!("Expected yes or no, got \"{answer}\""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_sdk::{
+        commands::contributor::create::CreateContributorCommand, AccountType, Contributor,
+        ContributorStatus, GlobalState,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+    use std::{collections::HashMap, io::Cursor};
+
+    fn contributor(owner: Pubkey, code: &str) -> Contributor {
+        Contributor {
+            account_type: AccountType::Contributor,
+            owner,
+            index: 1,
+            reference_count: 0,
+            code: code.to_string(),
+            status: ContributorStatus::Activated,
+            bump_seed: 0,
+            ops_manager_pk: Pubkey::default(),
+        }
+    }
+
+    fn options(env: Environment, code: Option<&str>) -> OnboardOptions {
+        OnboardOptions {
+            env,
+            code: code.map(str::to_string),
+            airdrop: true,
+        }
+    }
+
+    #[test]
+    fn test_onboard_reports_existing_contributor() {
+        let mut client = create_test_client();
+        let payer = client.get_payer();
+        client.checkpoint();
+        client.expect_get_payer().returning(move || payer);
+        client
+            .expect_get_balance()
+            .returning(|| Ok(AIRDROP_LAMPORTS));
+        let pubkey = Pubkey::new_unique();
+        client
+            .expect_list_contributor()
+            .returning(move |_| Ok(HashMap::from([(pubkey, contributor(payer, "acme"))])));
+        client.expect_create_contributor().never();
+        client.expect_request_airdrop().never();
+        client
+            .expect_get_globalstate()
+            .returning(|_| Ok((Pubkey::new_unique(), GlobalState::default())));
+
+        let mut prompter = Prompter::new(Cursor::new(""), false);
+        let mut out = Vec::new();
+        onboard(
+            &client,
+            &options(Environment::Devnet, None),
+            &mut prompter,
+            &mut out,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains(&format!("Keypair already owns contributor acme ({pubkey})")));
+        assert!(output.contains("✓ contributor: acme"));
+        assert!(output.contains("Setup complete"));
+    }
+
+    #[test]
+    fn test_onboard_airdrops_and_creates_contributor() {
+        let mut client = create_test_client();
+        let payer = client.get_payer();
+        client.checkpoint();
+        client.expect_get_payer().returning(move || payer);
+        let mut balances = vec![0, AIRDROP_LAMPORTS].into_iter();
+        client
+            .expect_get_balance()
+            .returning(move || Ok(balances.next().unwrap_or(AIRDROP_LAMPORTS)));
+        client
+            .expect_request_airdrop()
+            .with(predicate::eq(AIRDROP_LAMPORTS))
+            .times(1)
+            .returning(|_| Ok(Signature::default()));
+
+        let pubkey = Pubkey::new_unique();
+        let mut listings = vec![
+            HashMap::from([(
+                Pubkey::new_unique(),
+                contributor(Pubkey::new_unique(), "other"),
+            )]),
+            HashMap::from([(pubkey, contributor(payer, "acme"))]),
+        ]
+        .into_iter();
+        client
+            .expect_list_contributor()
+            .returning(move |_| Ok(listings.next().unwrap()));
+        client
+            .expect_create_contributor()
+            .with(predicate::eq(CreateContributorCommand {
+                code: "acme".to_string(),
+                owner: payer,
+            }))
+            .times(1)
+            .returning(move |_| Ok((Signature::default(), pubkey)));
+        client
+            .expect_get_globalstate()
+            .returning(|_| Ok((Pubkey::new_unique(), GlobalState::default())));
+
+        // Accept the airdrop, then answer the contributor code.
+        let mut prompter = Prompter::new(Cursor::new("\nacme\n"), false);
+        let mut out = Vec::new();
+        onboard(
+            &client,
+            &options(Environment::Devnet, None),
+            &mut prompter,
+            &mut out,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("Airdrop confirmed"));
+        assert!(output.contains(&format!("Created contributor acme ({pubkey})")));
+        assert!(output.contains("Setup complete"));
+    }
+
+    #[test]
+    fn test_onboard_without_permission_prints_foundation_command() {
+        let mut client = create_test_client();
+        let payer = client.get_payer();
+        client.checkpoint();
+        client.expect_get_payer().returning(move || payer);
+        client.expect_get_balance().returning(|| Ok(0));
+        client.expect_request_airdrop().never();
+        client
+            .expect_list_contributor()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_create_contributor()
+            .returning(|_| Err(eyre::eyre!("Not allowed")));
+        client
+            .expect_get_globalstate()
+            .returning(|_| Ok((Pubkey::new_unique(), GlobalState::default())));
+
+        let mut prompter = Prompter::new(Cursor::new(""), true);
+        let mut out = Vec::new();
+        let res = onboard(
+            &client,
+            &options(Environment::MainnetBeta, Some("acme")),
+            &mut prompter,
+            &mut out,
+        );
+        assert!(res.is_err());
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains(&format!(
+            "Fund {payer} with at least 0.1 Credits on mainnet-beta"
+        )));
+        assert!(output.contains(&format!(
+            "doublezero contributor create --code acme --owner {payer}"
+        )));
+        assert!(output.contains("✗ balance"));
+        assert!(output.contains("✗ contributor"));
+    }
+
+    #[test]
+    fn test_prompter_answers() {
+        let mut out = Vec::new();
+        let mut prompter = Prompter::new(Cursor::new("\nno\nvalue\n"), false);
+        assert!(prompter.confirm(&mut out, "Continue?", true).unwrap());
+        assert!(!prompter.confirm(&mut out, "Continue?", true).unwrap());
+        assert_eq!(prompter.ask(&mut out, "Name", "x").unwrap(), "value");
+        assert!(prompter.ask(&mut out, "Name", "x").is_err());
+
+        let mut prompter = Prompter::new(Cursor::new(""), true);
+        assert_eq!(prompter.ask(&mut out, "Name", "x").unwrap(), "x");
+        assert!(!prompter.confirm(&mut out, "Continue?", false).unwrap());
+    }
+}
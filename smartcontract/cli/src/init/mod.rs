@@ -0,0 +1,44 @@
+pub mod contributor;
+
+use crate::{doublezerocommand::CliCommand, init::contributor::InitContributorCliCommand};
+use clap::{Args, Subcommand};
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::globalstate::init::InitGlobalStateCommand;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct InitCliCommand {
+    #[command(subcommand)]
+    pub command: Option<InitCommands>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum InitCommands {
+    /// Set up this machine for a new contributor: keypair, environment, funding and
+    /// the contributor account, then check that everything is in place
+    Contributor(InitContributorCliCommand),
+}
+
+impl InitCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Bare `init` keeps initialising the global state; e2e setup and the
+        // deployment docs call it without a subcommand.
+        if let Some(InitCommands::Contributor(args)) = self.command {
+            return args.execute(ctx, client, out).await;
+        }
+
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let signature = client.init_globalstate(InitGlobalStateCommand)?;
+        print_signature(out, &signature)
+    }
+}
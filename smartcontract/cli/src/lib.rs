@@ -2,14 +2,18 @@ pub mod accesspass;
 pub mod account;
 pub mod accounts;
 pub mod address;
+pub mod admin;
 pub mod allowlist;
+pub mod anycast_prefix;
 pub mod balance;
+pub mod cascade;
 pub mod checkversion;
 pub mod cli;
 pub mod config;
 pub mod contributor;
 pub mod device;
 pub mod doublezerocommand;
+pub mod env;
 pub mod exchange;
 pub mod export;
 pub mod feed;
@@ -25,9 +29,14 @@ pub mod migrate;
 pub mod multicastgroup;
 pub mod permission;
 pub mod poll_for_activation;
+pub mod qos_profile;
 pub mod requirements;
 pub mod resource;
+pub mod scheduler;
+pub mod schema;
+pub mod state_commitment;
 pub mod subscribe;
+pub mod telemetry;
 pub mod tenant;
 pub mod tests;
 pub mod topology;
@@ -258,6 +258,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client
@@ -0,0 +1,324 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::{
+    commands::{
+        device::get::GetDeviceCommand,
+        link::{
+            burnin::{BurnInLinkCommand, BurnInThresholds},
+            get::GetLinkCommand,
+            sethealth::SetLinkHealthCommand,
+        },
+    },
+    units::Nanoseconds,
+};
+use doublezero_serviceability::state::link::LinkHealth;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::{settings::Style, Table, Tabled};
+
+#[derive(Tabled)]
+struct BurnInRow {
+    #[tabled(rename = "Direction")]
+    direction: String,
+    #[tabled(rename = "Samples")]
+    samples: usize,
+    #[tabled(rename = "Lost")]
+    lost: usize,
+    #[tabled(rename = "Loss")]
+    loss: String,
+    #[tabled(rename = "P99 RTT")]
+    rtt_p99: String,
+    #[tabled(rename = "Jitter")]
+    jitter: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+#[derive(Args, Debug)]
+pub struct LinkBurnInCliCommand {
+    /// Link Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// Epoch whose telemetry is evaluated, the current one by default
+    #[arg(long)]
+    pub epoch: Option<u64>,
+    /// Minutes of the most recent telemetry to evaluate
+    #[arg(long, default_value_t = 60)]
+    pub window_minutes: u64,
+    /// Highest p99 RTT allowed in either direction, in milliseconds
+    #[arg(long, default_value_t = 100.0)]
+    pub max_latency_ms: f64,
+    /// Highest jitter allowed in either direction, in milliseconds
+    #[arg(long, default_value_t = 5.0)]
+    pub max_jitter_ms: f64,
+    /// Highest share of lost probes allowed in either direction, in percent
+    #[arg(long, default_value_t = 0.1)]
+    pub max_loss_pct: f64,
+    /// Fewest probes each direction must have sent
+    #[arg(long, default_value_t = 100)]
+    pub min_samples: usize,
+    /// Record the verdict as the link's health: ready-for-service on a pass,
+    /// pending (or impaired, if it was ready for service) on a failure
+    #[arg(long, default_value_t = false)]
+    pub set_health: bool,
+}
+
+impl LinkBurnInCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        if self.set_health {
+            require!(
+                client,
+                RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+            );
+        }
+
+        let config = client.get_environment().config()?;
+        let (_, link) = client.get_link(GetLinkCommand {
+            pubkey_or_code: self.pubkey.clone(),
+        })?;
+
+        let report = client.burn_in_link(BurnInLinkCommand {
+            pubkey_or_code: self.pubkey,
+            epoch: self.epoch,
+            window_us: Some(self.window_minutes * 60 * 1_000_000),
+            thresholds: BurnInThresholds {
+                max_rtt_p99: Nanoseconds::from_millis_f64(self.max_latency_ms),
+                max_jitter: Nanoseconds::from_millis_f64(self.max_jitter_ms),
+                max_loss_ppm: (self.max_loss_pct * 10_000.0) as u32,
+                min_samples: self.min_samples,
+            },
+            telemetry_program_id: config.telemetry_program_id,
+        })?;
+        let thresholds = &report.thresholds;
+
+        writeln!(
+            out,
+            "Burn-in of link {} ({}), epoch {}, last {} minutes",
+            report.link_code, report.link_pk, report.epoch, self.window_minutes
+        )?;
+        writeln!(
+            out,
+            "Thresholds: p99 RTT {}, jitter {}, loss {:.3}%, at least {} samples\n",
+            thresholds.max_rtt_p99,
+            thresholds.max_jitter,
+            thresholds.max_loss_ppm as f64 / 10_000.0,
+            thresholds.min_samples
+        )?;
+
+        let device_code = |pk: &Pubkey| {
+            client
+                .get_device(GetDeviceCommand {
+                    pubkey_or_code: pk.to_string(),
+                })
+                .map(|(_, device)| device.code)
+                .unwrap_or_else(|_| pk.to_string())
+        };
+        let mut rows = vec![];
+        let mut failures = vec![];
+        for direction in &report.directions {
+            let label = format!(
+                "{} -> {}",
+                device_code(&direction.origin_device_pk),
+                device_code(&direction.target_device_pk)
+            );
+            let direction_failures = direction.failures(thresholds);
+            rows.push(BurnInRow {
+                direction: label.clone(),
+                samples: direction.sample_count,
+                lost: direction.lost,
+                loss: format!("{:.3}%", direction.loss_ppm() as f64 / 10_000.0),
+                rtt_p99: direction.rtt_p99.to_string(),
+                jitter: direction.jitter.to_string(),
+                result: if direction_failures.is_empty() {
+                    "pass".to_string()
+                } else {
+                    "fail".to_string()
+                },
+            });
+            failures.extend(
+                direction_failures
+                    .into_iter()
+                    .map(|failure| format!("{label}: {failure}")),
+            );
+        }
+        writeln!(out, "{}", Table::new(rows).with(Style::psql()))?;
+        for failure in &failures {
+            writeln!(out, "✗ {failure}")?;
+        }
+
+        let passed = report.passed();
+        writeln!(out, "\nVerdict: {}", if passed { "PASS" } else { "FAIL" })?;
+
+        if self.set_health {
+            let health = match (passed, link.link_health) {
+                (true, _) => LinkHealth::ReadyForService,
+                (false, LinkHealth::ReadyForService) => LinkHealth::Impaired,
+                (false, _) => LinkHealth::Pending,
+            };
+            if health == link.link_health {
+                writeln!(out, "Link health already {health}")?;
+            } else {
+                let signature = client.set_link_health(SetLinkHealthCommand {
+                    pubkey: report.link_pk,
+                    health,
+                })?;
+                writeln!(out, "Link health set to {health}")?;
+                print_signature(out, &signature)?;
+            }
+        }
+
+        if !passed {
+            eyre::bail!("Link {} failed burn-in", report.link_code);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        doublezerocommand::CliCommand,
+        link::burnin::LinkBurnInCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_config::Environment;
+    use doublezero_sdk::{
+        commands::link::{
+            burnin::{BurnInDirection, BurnInLinkCommand, BurnInReport},
+            get::GetLinkCommand,
+            sethealth::SetLinkHealthCommand,
+        },
+        get_link_pda,
+        units::Nanoseconds,
+        AccountType, Link, LinkLinkType, LinkStatus,
+    };
+    use doublezero_serviceability::state::link::LinkHealth;
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    fn test_link(side_a_pk: Pubkey, side_z_pk: Pubkey) -> Link {
+        Link {
+            account_type: AccountType::Link,
+            index: 1,
+            code: "nyc-lax".to_string(),
+            side_a_pk,
+            side_z_pk,
+            link_type: LinkLinkType::WAN,
+            status: LinkStatus::Activated,
+            link_health: LinkHealth::Pending,
+            ..Default::default()
+        }
+    }
+
+    fn run(samples_a: &[u32], set_health: bool) -> (eyre::Result<()>, String) {
+        let mut client = create_test_client();
+        let (link_pk, _) = get_link_pda(&client.get_program_id(), 1);
+        let (side_a_pk, side_z_pk) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let link = test_link(side_a_pk, side_z_pk);
+        let healthy: Vec<u32> = vec![40_000; 200];
+        let report = BurnInReport {
+            link_pk,
+            link_code: link.code.clone(),
+            epoch: 12,
+            thresholds: Default::default(),
+            directions: vec![
+                BurnInDirection::from_samples(side_a_pk, side_z_pk, samples_a),
+                BurnInDirection::from_samples(side_z_pk, side_a_pk, &healthy),
+            ],
+        };
+
+        let env = Environment::Devnet;
+        let telemetry_program_id = env.config().unwrap().telemetry_program_id;
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .times(usize::from(set_health))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
+        client
+            .expect_get_link()
+            .with(predicate::eq(GetLinkCommand {
+                pubkey_or_code: "nyc-lax".to_string(),
+            }))
+            .returning(move |_| Ok((link_pk, link.clone())));
+        client
+            .expect_get_device()
+            .returning(|_| Err(eyre::eyre!("not found")));
+        client
+            .expect_burn_in_link()
+            .with(predicate::function(move |cmd: &BurnInLinkCommand| {
+                cmd.pubkey_or_code == "nyc-lax"
+                    && cmd.window_us == Some(30 * 60 * 1_000_000)
+                    && cmd.thresholds.max_rtt_p99 == Nanoseconds::from_millis(50)
+                    && cmd.thresholds.max_loss_ppm == 1_000
+                    && cmd.telemetry_program_id == telemetry_program_id
+            }))
+            .returning(move |cmd| {
+                Ok(BurnInReport {
+                    thresholds: cmd.thresholds,
+                    ..report.clone()
+                })
+            });
+        client
+            .expect_set_link_health()
+            .with(predicate::eq(SetLinkHealthCommand {
+                pubkey: link_pk,
+                health: LinkHealth::ReadyForService,
+            }))
+            .times(usize::from(set_health))
+            .returning(|_| Ok(Signature::new_unique()));
+
+        let mut output = Vec::new();
+        let res = block_on(
+            LinkBurnInCliCommand {
+                pubkey: "nyc-lax".to_string(),
+                epoch: None,
+                window_minutes: 30,
+                max_latency_ms: 50.0,
+                max_jitter_ms: 5.0,
+                max_loss_pct: 0.1,
+                min_samples: 100,
+                set_health,
+            }
+            .execute(&cli_context_default_for_tests(), &client, &mut output),
+        );
+        (res, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_cli_link_burn_in_pass_sets_health() {
+        let (res, output) = run(&[40_000; 200], true);
+        assert!(res.is_ok(), "{res:?}");
+        assert!(output.contains("Burn-in of link nyc-lax"));
+        assert!(output.contains("Thresholds: p99 RTT 50.00ms, jitter 5.00ms, loss 0.100%"));
+        assert!(output.contains("Verdict: PASS"));
+        assert!(output.contains("Link health set to ready-for-service"));
+        assert!(!output.contains('✗'));
+    }
+
+    #[test]
+    fn test_cli_link_burn_in_failure_reports_reasons() {
+        let mut samples = vec![40_000; 200];
+        samples[..10].fill(0);
+        samples[10] = 90_000;
+        samples[11] = 95_000;
+        samples[12] = 90_000;
+
+        let (res, output) = run(&samples, false);
+        assert_eq!(res.unwrap_err().to_string(), "Link nyc-lax failed burn-in");
+        assert!(output.contains("p99 RTT 90.00ms above 50.00ms"));
+        assert!(output.contains("loss 5.000% above 0.100%"));
+        assert!(output.contains("Verdict: FAIL"));
+        assert!(!output.contains("Link health"));
+    }
+}
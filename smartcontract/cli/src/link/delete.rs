@@ -1,11 +1,15 @@
 use crate::{
+    cascade::print_cascade_preview,
     doublezerocommand::CliCommand,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
     validators::validate_pubkey,
 };
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::link::{delete::DeleteLinkCommand, get::GetLinkCommand};
+use doublezero_sdk::{
+    cascade::DeleteTarget,
+    commands::link::{delete::DeleteLinkCommand, get::GetLinkCommand},
+};
 use std::io::Write;
 
 #[derive(Args, Debug)]
@@ -13,6 +17,10 @@ pub struct DeleteLinkCliCommand {
     /// Link Pubkey or code to delete
     #[arg(long, value_parser = validate_pubkey)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteLinkCliCommand {
@@ -22,6 +30,13 @@ impl DeleteLinkCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let (pubkey, _) = client.get_link(GetLinkCommand {
+                pubkey_or_code: self.pubkey,
+            })?;
+            return print_cascade_preview(client, DeleteTarget::Link(pubkey), out);
+        }
+
         // Check requirements
         client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
 
@@ -163,6 +178,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client
@@ -198,6 +218,7 @@ mod tests {
         let res = block_on(
             DeleteLinkCliCommand {
                 pubkey: pda_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
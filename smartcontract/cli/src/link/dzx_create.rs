@@ -17,6 +17,7 @@ use doublezero_sdk::{
         device::get::GetDeviceCommand,
         link::{create::CreateLinkCommand, get::GetLinkCommand},
     },
+    units::{Bps, Nanoseconds},
     *,
 };
 use doublezero_serviceability::state::{
@@ -165,10 +166,10 @@ impl CreateDZXLinkCliCommand {
             side_a_pk,
             side_z_pk,
             link_type: LinkLinkType::DZX,
-            bandwidth: self.bandwidth,
+            bandwidth: Bps(self.bandwidth),
             mtu: self.mtu,
-            delay_ns: (self.delay_ms * 1_000_000.0) as u64,
-            jitter_ns: (self.jitter_ms * 1_000_000.0) as u64,
+            delay_ns: Nanoseconds::from_millis_f64(self.delay_ms),
+            jitter_ns: Nanoseconds::from_millis_f64(self.jitter_ms),
             side_a_iface_name: self.side_a_interface.clone(),
             side_z_iface_name: None, // External links do not require side Z interface name
         })?;
@@ -200,8 +201,10 @@ mod tests {
             device::get::GetDeviceCommand,
             link::{create::CreateLinkCommand, get::GetLinkCommand},
         },
-        get_device_pda, AccountType, Device, DeviceStatus, DeviceType, Interface, InterfaceStatus,
-        Link, LinkLinkType, LinkStatus,
+        get_device_pda,
+        units::{Bps, Nanoseconds},
+        AccountType, Device, DeviceStatus, DeviceType, Interface, InterfaceStatus, Link,
+        LinkLinkType, LinkStatus,
     };
     use doublezero_serviceability::state::interface::{InterfaceCYOA, InterfaceType, LoopbackType};
     use mockall::predicate;
@@ -381,6 +384,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client
@@ -421,10 +429,10 @@ mod tests {
                 side_a_pk: device1_pk,
                 side_z_pk: device2_pk,
                 link_type: LinkLinkType::DZX,
-                bandwidth: 1_000_000_000,
+                bandwidth: Bps::from_gbps(1),
                 mtu: 9000,
-                delay_ns: 10_000_000_000,
-                jitter_ns: 5_000_000_000,
+                delay_ns: Nanoseconds::from_millis(10_000),
+                jitter_ns: Nanoseconds::from_millis(5_000),
                 side_a_iface_name: "Ethernet1/1".to_string(),
                 side_z_iface_name: None,
             }))
@@ -4,7 +4,7 @@ use crate::{
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::link::get::GetLinkCommand;
+use doublezero_sdk::{commands::link::get::GetLinkCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
@@ -21,7 +21,7 @@ pub struct GetLinkCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct LinkDisplay {
+pub(crate) struct LinkDisplay {
     pub account: String,
     pub code: String,
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
@@ -49,9 +49,14 @@ struct LinkDisplay {
     pub desired_status: String,
     pub status: String,
     pub health: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
     pub link_topologies: String,
     pub unicast_drained: bool,
+    pub flap_damped: bool,
+    pub flap_count: u32,
+    pub flap_penalty: u32,
+    pub damped_until_epoch: u64,
+    pub ops_lock: String,
 }
 
 impl GetLinkCliCommand {
@@ -61,6 +66,7 @@ impl GetLinkCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (pubkey, link) = client.get_link(GetLinkCommand {
             pubkey_or_code: self.code,
         })?;
@@ -105,11 +111,18 @@ impl GetLinkCliCommand {
             desired_status: link.desired_status.to_string(),
             status: link.status.to_string(),
             health: link.link_health.to_string(),
-            owner: link.owner.to_string(),
+            owner: book.labeled(&link.owner),
             link_topologies: resolve_topology_names(&link.link_topologies, &topology_map),
             unicast_drained: link.link_flags
                 & doublezero_serviceability::state::link::LINK_FLAG_UNICAST_DRAINED
                 != 0,
+            flap_damped: link.link_flags
+                & doublezero_serviceability::state::link::LINK_FLAG_FLAP_DAMPED
+                != 0,
+            flap_count: link.flap_count,
+            flap_penalty: link.flap_penalty,
+            damped_until_epoch: link.damped_until_epoch,
+            ops_lock: link.ops_lock.to_string(),
         };
 
         if self.json {
@@ -144,6 +157,7 @@ mod tests {
         get_link_pda, AccountType, Contributor, ContributorStatus, Device, DeviceStatus,
         DeviceType, Link, LinkLinkType, LinkStatus,
     };
+    use doublezero_serviceability::state::ops_lock::OpsLock;
     use mockall::predicate;
     use solana_sdk::pubkey::Pubkey;
     use std::collections::HashMap;
@@ -180,7 +194,16 @@ mod tests {
             link_health: doublezero_serviceability::state::link::LinkHealth::ReadyForService,
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
-            link_flags: 0,
+            link_flags: doublezero_serviceability::state::link::LINK_FLAG_FLAP_DAMPED,
+            flap_count: 3,
+            flap_penalty: 3000,
+            flap_epoch: 812,
+            damped_until_epoch: 814,
+            ops_lock: OpsLock {
+                locked_by: contributor_pk,
+                expires_at: 1_700_000_000,
+                reason: "OTDR trace".to_string(),
+            },
         };
 
         let contributor = Contributor {
@@ -309,6 +332,14 @@ mod tests {
             has_row("status", "activated"),
             "status row should contain value"
         );
+        assert!(
+            has_row("damped_until_epoch", "814"),
+            "damped_until_epoch row should contain value"
+        );
+        assert!(
+            has_row("ops_lock", "until 1700000000: OTDR trace"),
+            "ops_lock row should contain the lock"
+        );
 
         // Expected success by code (JSON)
         let mut output = Vec::new();
@@ -331,5 +362,8 @@ mod tests {
         assert_eq!(json["contributor"].as_str().unwrap(), "test-contributor");
         assert_eq!(json["side_a"].as_str().unwrap(), "side-a-device");
         assert_eq!(json["side_z"].as_str().unwrap(), "side-z-device");
+        assert!(json["flap_damped"].as_bool().unwrap());
+        assert_eq!(json["flap_count"].as_u64().unwrap(), 3);
+        assert_eq!(json["damped_until_epoch"].as_u64().unwrap(), 814);
     }
 }
@@ -200,6 +200,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         }
     }
 
@@ -236,7 +241,9 @@ mod tests {
         let env = Environment::Devnet;
         let telemetry_program_id = env.config().unwrap().telemetry_program_id;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_get_link()
@@ -312,7 +319,9 @@ mod tests {
 
         let env = Environment::Devnet;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_get_link()
@@ -380,7 +389,9 @@ mod tests {
 
         let env = Environment::Devnet;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_get_link()
@@ -448,7 +459,9 @@ mod tests {
 
         let env = Environment::Devnet;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_get_link()
@@ -500,7 +513,9 @@ mod tests {
         let env = Environment::Devnet;
         let telemetry_program_id = env.config().unwrap().telemetry_program_id;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_get_link()
@@ -557,7 +572,9 @@ mod tests {
 
         let env = Environment::Devnet;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_latency_link()
@@ -587,7 +604,9 @@ mod tests {
 
         let env = Environment::Devnet;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_latency_link()
@@ -672,7 +691,9 @@ mod tests {
         let env = Environment::Devnet;
         let telemetry_program_id = env.config().unwrap().telemetry_program_id;
 
-        client.expect_get_environment().returning(move || env);
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
 
         client
             .expect_latency_link()
@@ -12,6 +12,7 @@ use doublezero_sdk::{
         link::list::ListLinkCommand,
         topology::list::ListTopologyCommand,
     },
+    labels::LabeledPubkey,
     Link, LinkLinkType, LinkStatus, TopologyInfo,
 };
 use doublezero_serviceability::state::link::{LinkDesiredStatus, LinkHealth};
@@ -102,10 +103,10 @@ pub struct LinkDisplay {
     pub desired_status: LinkDesiredStatus,
     pub status: LinkStatus,
     pub health: LinkHealth,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
     pub link_topologies: String,
     pub unicast_drained: bool,
+    pub flap_damped: bool,
     /// Raw topology pubkeys, moved from the source link, so `--narrow` can build
     /// an abbreviated `topos` cell lazily without precomputing it for every row.
     #[tabled(skip)]
@@ -141,6 +142,8 @@ pub struct LinkDisplayNarrow {
     #[tabled(rename = "topos")]
     pub link_topologies: String,
     pub unicast_drained: bool,
+    #[tabled(rename = "damped")]
+    pub flap_damped: bool,
 }
 
 /// Abbreviate the drained statuses for narrow output; pass others through.
@@ -178,6 +181,7 @@ impl LinkDisplayNarrow {
             health: abbreviate_link_health(&d.health),
             link_topologies: resolve_topology_names_short(&d.link_topologies_raw, topology_map),
             unicast_drained: d.unicast_drained,
+            flap_damped: d.flap_damped,
         }
     }
 }
@@ -189,6 +193,7 @@ impl ListLinkCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let contributors = client.list_contributor(ListContributorCommand {})?;
         let devices = client.list_device(ListDeviceCommand)?;
         let mut links = client.list_link(ListLinkCommand)?;
@@ -327,11 +332,14 @@ impl ListLinkCliCommand {
                     desired_status: link.desired_status,
                     status: link.status,
                     health: link.link_health,
-                    owner: link.owner,
+                    owner: book.labeled(&link.owner),
                     link_topologies: resolve_topology_names(&link.link_topologies, &topology_map),
                     unicast_drained: link.link_flags
                         & doublezero_serviceability::state::link::LINK_FLAG_UNICAST_DRAINED
                         != 0,
+                    flap_damped: link.link_flags
+                        & doublezero_serviceability::state::link::LINK_FLAG_FLAP_DAMPED
+                        != 0,
                     link_topologies_raw: link.link_topologies,
                 }
             })
@@ -508,6 +516,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
@@ -544,7 +557,7 @@ mod tests {
         assert!(res.is_ok());
 
         let output_str = String::from_utf8(output).unwrap();
-        assert_eq!(output_str, " account                                   | code        | contributor       | side_a_name  | side_a_iface_name | side_z_name  | side_z_iface_name | link_type | bandwidth | mtu  | delay_ms | jitter_ms | delay_override_ms | tunnel_id | tunnel_net | status    | health            | owner                                     | link_topologies | unicast_drained \n 1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR | tunnel_code | contributor1_code | device2_code | eth0              | device2_code | eth1              | WAN       | 10Gbps    | 4500 | 0.02ms   | 0.00ms    | 0.00ms            | 1234      | 1.2.3.4/32 | activated | ready-for-service | 11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9 | default         | false           \n");
+        assert_eq!(output_str, " account                                   | code        | contributor       | side_a_name  | side_a_iface_name | side_z_name  | side_z_iface_name | link_type | bandwidth | mtu  | delay_ms | jitter_ms | delay_override_ms | tunnel_id | tunnel_net | status    | health            | owner                                     | link_topologies | unicast_drained | flap_damped \n 1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR | tunnel_code | contributor1_code | device2_code | eth0              | device2_code | eth1              | WAN       | 10Gbps    | 4500 | 0.02ms   | 0.00ms    | 0.00ms            | 1234      | 1.2.3.4/32 | activated | ready-for-service | 11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9 | default         | false           | false       \n");
 
         let mut output = Vec::new();
         let res = block_on(
@@ -569,7 +582,7 @@ mod tests {
         assert!(res.is_ok());
 
         let output_str = String::from_utf8(output).unwrap();
-        assert_eq!(output_str, "[{\"account\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR\",\"code\":\"tunnel_code\",\"contributor_code\":\"contributor1_code\",\"side_a_pk\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"side_a_name\":\"device2_code\",\"side_a_iface_name\":\"eth0\",\"side_z_pk\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"side_z_name\":\"device2_code\",\"side_z_iface_name\":\"eth1\",\"link_type\":\"WAN\",\"bandwidth\":10000000000,\"mtu\":4500,\"delay_ns\":20000,\"jitter_ns\":1121,\"delay_override_ns\":0,\"tunnel_id\":1234,\"tunnel_net\":\"1.2.3.4/32\",\"desired_status\":\"Activated\",\"status\":\"Activated\",\"health\":\"ReadyForService\",\"owner\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"link_topologies\":\"default\",\"unicast_drained\":false}]\n");
+        assert_eq!(output_str, "[{\"account\":\"1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPR\",\"code\":\"tunnel_code\",\"contributor_code\":\"contributor1_code\",\"side_a_pk\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"side_a_name\":\"device2_code\",\"side_a_iface_name\":\"eth0\",\"side_z_pk\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"side_z_name\":\"device2_code\",\"side_z_iface_name\":\"eth1\",\"link_type\":\"WAN\",\"bandwidth\":10000000000,\"mtu\":4500,\"delay_ns\":20000,\"jitter_ns\":1121,\"delay_override_ns\":0,\"tunnel_id\":1234,\"tunnel_net\":\"1.2.3.4/32\",\"desired_status\":\"Activated\",\"status\":\"Activated\",\"health\":\"ReadyForService\",\"owner\":\"11111115q4EpJaTXAZWpCg3J2zppWGSZ46KXozzo9\",\"link_topologies\":\"default\",\"unicast_drained\":false,\"flap_damped\":false}]\n");
         // Narrow output: drops side names, mtu, tunnel_net, owner; abbreviates
         // health/status; shortens headers; fits within 240 cols.
         let mut output = Vec::new();
@@ -611,6 +624,7 @@ mod tests {
             "health",
             "topos",
             "unicast_drained",
+            "damped",
         ] {
             assert!(header.contains(expected), "missing header {expected}");
         }
@@ -818,6 +832,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
         let tunnel2_pubkey = Pubkey::new_unique();
         let tunnel2 = Link {
@@ -845,6 +864,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
@@ -1007,6 +1031,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         let link2_pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPS");
@@ -1035,6 +1064,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
@@ -1196,6 +1230,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         let link2_pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPS");
@@ -1224,6 +1263,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
@@ -1351,6 +1395,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         let link2_pubkey = Pubkey::from_str_const("1111111FVAiSujNZVgYSc27t6zUTWoKfAGxbRzzPS");
@@ -1379,6 +1428,11 @@ mod tests {
 
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
@@ -0,0 +1,155 @@
+use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::link::{get::GetLinkCommand, set_ops_lock::SetLinkOpsLockCommand};
+use std::io::Write;
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+
+#[derive(Args, Debug)]
+pub struct LockLinkCliCommand {
+    /// Link Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+    /// Why the link is frozen, shown to anyone whose change is rejected
+    #[arg(long)]
+    pub reason: String,
+    /// Hours until the lock lapses on its own, at most a week
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=168))]
+    pub hours: u32,
+}
+
+impl LockLinkCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let (pubkey, _link) = client.get_link(GetLinkCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+
+        let signature = client.set_link_ops_lock(SetLinkOpsLockCommand {
+            pubkey,
+            reason: self.reason,
+            expires_at: chrono::Utc::now().timestamp() + i64::from(self.hours) * SECONDS_PER_HOUR,
+        })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct UnlockLinkCliCommand {
+    /// Link Pubkey or code
+    #[arg(long, value_parser = validate_pubkey_or_code)]
+    pub pubkey: String,
+}
+
+impl UnlockLinkCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let (pubkey, link) = client.get_link(GetLinkCommand {
+            pubkey_or_code: self.pubkey,
+        })?;
+        if link.ops_lock == Default::default() {
+            eyre::bail!("Link {} is not locked", link.code);
+        }
+
+        let signature = client.set_link_ops_lock(SetLinkOpsLockCommand {
+            pubkey,
+            reason: String::new(),
+            expires_at: 0,
+        })?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        doublezerocommand::CliCommand,
+        link::lock::{LockLinkCliCommand, UnlockLinkCliCommand},
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::link::{get::GetLinkCommand, set_ops_lock::SetLinkOpsLockCommand},
+        get_link_pda, Link,
+    };
+    use mockall::predicate;
+    use solana_sdk::signature::Signature;
+
+    #[test]
+    fn test_cli_link_lock_unlock() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _bump_seed) = get_link_pda(&client.get_program_id(), 1);
+        let signature = Signature::new_unique();
+        let link = Link {
+            code: "test".to_string(),
+            ..Default::default()
+        };
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_get_link()
+            .with(predicate::eq(GetLinkCommand {
+                pubkey_or_code: "test".to_string(),
+            }))
+            .returning(move |_| Ok((pda_pubkey, link.clone())));
+        let now = chrono::Utc::now().timestamp();
+        client
+            .expect_set_link_ops_lock()
+            .with(predicate::function(move |cmd: &SetLinkOpsLockCommand| {
+                cmd.pubkey == pda_pubkey
+                    && cmd.reason == "OTDR trace"
+                    && (now + 2 * 3600..now + 2 * 3600 + 60).contains(&cmd.expires_at)
+            }))
+            .times(1)
+            .returning(move |_| Ok(signature));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            LockLinkCliCommand {
+                pubkey: "test".to_string(),
+                reason: "OTDR trace".to_string(),
+                hours: 2,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Signature: {signature}\n")
+        );
+
+        // Unlocking a link that holds no lock is rejected before sending.
+        let res = block_on(
+            UnlockLinkCliCommand {
+                pubkey: "test".to_string(),
+            }
+            .execute(&ctx, &client, &mut Vec::new()),
+        );
+        assert!(res.is_err());
+    }
+}
@@ -1,9 +1,11 @@
 pub mod accept;
+pub mod burnin;
 pub mod delete;
 pub mod dzx_create;
 pub mod get;
 pub mod latency;
 pub mod list;
+pub mod lock;
 pub mod sethealth;
 pub mod update;
 pub mod wan_create;
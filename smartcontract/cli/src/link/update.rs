@@ -1,5 +1,6 @@
 use crate::{
     doublezerocommand::CliCommand,
+    helpers::check_status_transition,
     poll_for_activation::poll_for_link_activated,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
     validators::{
@@ -11,10 +12,13 @@ use crate::{
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::types::NetworkV4;
-use doublezero_sdk::commands::{
-    contributor::get::GetContributorCommand,
-    link::{get::GetLinkCommand, update::UpdateLinkCommand},
-    topology::list::ListTopologyCommand,
+use doublezero_sdk::{
+    commands::{
+        contributor::get::GetContributorCommand,
+        link::{get::GetLinkCommand, update::UpdateLinkCommand},
+        topology::list::ListTopologyCommand,
+    },
+    units::{Bps, Nanoseconds},
 };
 use doublezero_serviceability::state::link::LinkDesiredStatus;
 use eyre::eyre;
@@ -109,6 +113,9 @@ impl UpdateLinkCliCommand {
             .map(|s| s.parse())
             .transpose()
             .map_err(|e| eyre!("Invalid status: {e}"))?;
+        if let Some(status) = status {
+            check_status_transition(link.status, status, "UpdateLink")?;
+        }
 
         if let Some(mtu) = self.mtu {
             if mtu != 9000 {
@@ -153,17 +160,11 @@ impl UpdateLinkCliCommand {
             code: self.code.clone(),
             contributor_pk,
             tunnel_type,
-            bandwidth: self.bandwidth,
+            bandwidth: self.bandwidth.map(Bps),
             mtu: self.mtu,
-            delay_ns: self
-                .delay_ms
-                .map(|delay_ms| (delay_ms * 1_000_000.0) as u64),
-            jitter_ns: self
-                .jitter_ms
-                .map(|jitter_ms| (jitter_ms * 1_000_000.0) as u64),
-            delay_override_ns: self
-                .delay_override_ms
-                .map(|delay_override_ms| (delay_override_ms * 1_000_000.0) as u64),
+            delay_ns: self.delay_ms.map(Nanoseconds::from_millis_f64),
+            jitter_ns: self.jitter_ms.map(Nanoseconds::from_millis_f64),
+            delay_override_ns: self.delay_override_ms.map(Nanoseconds::from_millis_f64),
             status,
             desired_status: self.desired_status,
             tunnel_id: self.tunnel_id,
@@ -197,7 +198,9 @@ mod tests {
             contributor::get::GetContributorCommand,
             link::{get::GetLinkCommand, update::UpdateLinkCommand},
         },
-        get_link_pda, AccountType, Contributor, ContributorStatus, Link, LinkLinkType, LinkStatus,
+        get_link_pda,
+        units::{Bps, Nanoseconds},
+        AccountType, Contributor, ContributorStatus, Link, LinkLinkType, LinkStatus,
     };
     use mockall::predicate;
     use solana_sdk::{pubkey::Pubkey, signature::Signature};
@@ -253,6 +256,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         let link2 = Link {
@@ -279,6 +287,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client
@@ -316,10 +329,10 @@ mod tests {
                 code: Some("new_code".to_string()),
                 contributor_pk: Some(contributor_pk),
                 tunnel_type: None,
-                bandwidth: Some(1_000_000_000),
+                bandwidth: Some(Bps::from_gbps(1)),
                 mtu: Some(9000),
-                delay_ns: Some(10_000_000),
-                jitter_ns: Some(5_000_000),
+                delay_ns: Some(Nanoseconds::from_millis(10)),
+                jitter_ns: Some(Nanoseconds::from_millis(5)),
                 delay_override_ns: None,
                 status: None,
                 desired_status: None,
@@ -387,5 +400,33 @@ mod tests {
             res.unwrap_err().to_string(),
             "Link with code 'test2' already exists"
         );
+
+        // status changes outside the transition table are rejected before sending
+        let mut output = Vec::new();
+        let res = block_on(
+            UpdateLinkCliCommand {
+                pubkey: pda_pubkey.to_string(),
+                code: None,
+                contributor: None,
+                tunnel_type: None,
+                bandwidth: None,
+                mtu: None,
+                delay_ms: None,
+                jitter_ms: None,
+                delay_override_ms: None,
+                status: Some("requested".to_string()),
+                desired_status: None,
+                tunnel_id: None,
+                tunnel_net: None,
+                link_topology: None,
+                unicast_drained: None,
+                wait: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Link status cannot change from activated to requested (allowed: soft-drained, hard-drained, provisioning)"
+        );
     }
 }
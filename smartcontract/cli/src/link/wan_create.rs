@@ -17,6 +17,7 @@ use doublezero_sdk::{
         device::get::GetDeviceCommand,
         link::{create::CreateLinkCommand, get::GetLinkCommand},
     },
+    units::{Bps, Nanoseconds},
     *,
 };
 use doublezero_serviceability::state::{
@@ -216,10 +217,10 @@ impl CreateWANLinkCliCommand {
             side_a_pk,
             side_z_pk,
             link_type: LinkLinkType::WAN,
-            bandwidth: self.bandwidth,
+            bandwidth: Bps(self.bandwidth),
             mtu: self.mtu,
-            delay_ns: (self.delay_ms * 1_000_000.0) as u64,
-            jitter_ns: (self.jitter_ms * 1_000_000.0) as u64,
+            delay_ns: Nanoseconds::from_millis_f64(self.delay_ms),
+            jitter_ns: Nanoseconds::from_millis_f64(self.jitter_ms),
             side_a_iface_name: self.side_a_interface.clone(),
             side_z_iface_name: Some(self.side_z_interface.clone()),
         })?;
@@ -251,8 +252,9 @@ mod tests {
             device::get::GetDeviceCommand,
             link::{create::CreateLinkCommand, get::GetLinkCommand},
         },
-        get_device_pda, AccountType, Device, DeviceStatus, DeviceType, Interface, Link,
-        LinkLinkType, LinkStatus,
+        get_device_pda,
+        units::{Bps, Nanoseconds},
+        AccountType, Device, DeviceStatus, DeviceType, Interface, Link, LinkLinkType, LinkStatus,
     };
     use doublezero_serviceability::state::interface::{
         InterfaceCYOA, InterfaceStatus, InterfaceType, LoopbackType,
@@ -435,6 +437,11 @@ mod tests {
             desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client
@@ -475,10 +482,10 @@ mod tests {
                 side_a_pk: device1_pk,
                 side_z_pk: device2_pk,
                 link_type: LinkLinkType::WAN,
-                bandwidth: 1_000_000_000,
+                bandwidth: Bps::from_gbps(1),
                 mtu: 9000,
-                delay_ns: 10_000_000_000,
-                jitter_ns: 5_000_000_000,
+                delay_ns: Nanoseconds::from_millis(10_000),
+                jitter_ns: Nanoseconds::from_millis(5_000),
                 side_a_iface_name: "Ethernet1/1".to_string(),
                 side_z_iface_name: Some("Ethernet1/2".to_string()),
             }))
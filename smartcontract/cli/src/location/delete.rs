@@ -1,10 +1,10 @@
 use crate::{
-    doublezerocommand::CliCommand, helpers::resolve_location_pk,
+    cascade::print_cascade_preview, doublezerocommand::CliCommand, helpers::resolve_location_pk,
     validators::validate_pubkey_or_code,
 };
 use clap::Args;
 use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
-use doublezero_sdk::commands::location::delete::DeleteLocationCommand;
+use doublezero_sdk::{cascade::DeleteTarget, commands::location::delete::DeleteLocationCommand};
 use std::io::Write;
 
 #[derive(Args, Debug)]
@@ -12,6 +12,10 @@ pub struct DeleteLocationCliCommand {
     /// Location Pubkey or code to delete
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteLocationCliCommand {
@@ -21,6 +25,11 @@ impl DeleteLocationCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let pubkey = resolve_location_pk(client, &self.pubkey)?;
+            return print_cascade_preview(client, DeleteTarget::Location(pubkey), out);
+        }
+
         require!(
             client,
             RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
@@ -97,6 +106,7 @@ mod tests {
         let res = block_on(
             DeleteLocationCliCommand {
                 pubkey: pda_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
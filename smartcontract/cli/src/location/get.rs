@@ -1,6 +1,6 @@
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
-use doublezero_sdk::commands::location::get::GetLocationCommand;
+use doublezero_sdk::{commands::location::get::GetLocationCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use std::io::Write;
 use tabled::Tabled;
@@ -27,7 +27,7 @@ pub struct GetLocationCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct LocationDisplay {
+pub(crate) struct LocationDisplay {
     pub account: String,
     pub code: String,
     pub name: String,
@@ -37,7 +37,7 @@ struct LocationDisplay {
     pub loc_id: u32,
     pub reference_count: u32,
     pub status: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 impl GetLocationCliCommand {
@@ -53,6 +53,7 @@ impl GetLocationCliCommand {
     ) -> eyre::Result<()> {
         tracing::debug!(env = %ctx.env, code = %self.code, "location get");
 
+        let book = client.get_address_book();
         let (pubkey, location) = client.get_location(GetLocationCommand {
             pubkey_or_code: self.code,
         })?;
@@ -67,7 +68,7 @@ impl GetLocationCliCommand {
             loc_id: location.loc_id,
             reference_count: location.reference_count,
             status: location.status.to_string(),
-            owner: location.owner.to_string(),
+            owner: book.labeled(&location.owner),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
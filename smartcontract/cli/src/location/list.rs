@@ -2,7 +2,9 @@ use crate::doublezerocommand::CliCommand;
 use clap::Args;
 use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::{commands::location::list::ListLocationCommand, LocationStatus};
+use doublezero_sdk::{
+    commands::location::list::ListLocationCommand, labels::LabeledPubkey, LocationStatus,
+};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
@@ -28,8 +30,7 @@ pub struct LocationDisplay {
     pub lat: f64,
     pub lng: f64,
     pub status: LocationStatus,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListLocationCliCommand {
@@ -39,6 +40,7 @@ impl ListLocationCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let locations = client.list_location(ListLocationCommand)?;
 
         let mut location_displays: Vec<LocationDisplay> = locations
@@ -51,7 +53,7 @@ impl ListLocationCliCommand {
                 lat: tunnel.lat,
                 lng: tunnel.lng,
                 status: tunnel.status,
-                owner: tunnel.owner,
+                owner: book.labeled(&tunnel.owner),
             })
             .collect();
 
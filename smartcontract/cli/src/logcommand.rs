@@ -1,19 +1,151 @@
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use std::io::Write;
+use doublezero_sdk::{
+    events::{decode_event_line, decode_events, DoubleZeroEvent},
+    labels::AddressBook,
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::{fmt, io::Write};
 
-use crate::{doublezerocommand::CliCommand, validators::validate_pubkey};
+use crate::{
+    doublezerocommand::{CliCommand, LogHandler},
+    validators::validate_pubkey,
+};
 
 #[derive(Args, Debug)]
 pub struct LogCliCommand {
-    /// Public key of the user to get logs for
-    #[arg(long, value_parser = validate_pubkey)]
-    pub pubkey: String,
+    /// Public key of the account to get logs for. With --follow, only
+    /// transactions touching it are streamed
+    #[arg(long, value_parser = validate_pubkey, required_unless_present = "follow")]
+    pub pubkey: Option<String>,
+    /// Stream new transactions as they are confirmed instead of exiting
+    #[arg(long)]
+    pub follow: bool,
+    /// Print the raw transaction logs instead of the decoded event timeline
+    #[arg(long)]
+    pub raw: bool,
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
 }
 
+/// One decoded event, rendered as `<entity> <label>: <change>`.
+#[derive(Serialize, Debug, PartialEq)]
+struct TimelineEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    entity: &'static str,
+    account: String,
+    label: String,
+    change: String,
+}
+
+impl fmt::Display for TimelineEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.entity, self.label, self.change)
+    }
+}
+
+/// Human-readable names for the accounts events refer to: local petnames,
+/// then the client IP for users and the code for everything that has one.
+#[derive(Default)]
+struct EntityLabels(AddressBook);
+
+impl EntityLabels {
+    fn load<C: CliCommand>(client: &C) -> eyre::Result<Self> {
+        let mut book = client.get_address_book();
+        let accounts = client.get_all()?;
+        book.observe_accounts(
+            accounts
+                .iter()
+                .map(|(pubkey, account)| (pubkey.as_ref(), account.as_ref())),
+        );
+        Ok(Self(book))
+    }
+
+    fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.0.contains(pubkey)
+    }
+
+    fn label(&self, pubkey: &Pubkey) -> String {
+        self.0.display(pubkey)
+    }
+
+    /// Learn labels carried by the event itself, so users created after the
+    /// labels were loaded still show up by client IP.
+    fn observe(&mut self, event: &DoubleZeroEvent) {
+        if let DoubleZeroEvent::UserActivated {
+            user, client_ip, ..
+        } = event
+        {
+            self.0.insert_code(*user, client_ip.to_string());
+        }
+    }
+
+    fn entry(&self, signature: Option<&str>, event: &DoubleZeroEvent) -> TimelineEntry {
+        let (entity, change) = match event {
+            DoubleZeroEvent::UserActivated {
+                device,
+                dz_ip,
+                tunnel_id,
+                ..
+            } => (
+                "user",
+                format!(
+                    "pending → activated (tunnel {tunnel_id}, dz_ip {dz_ip}, device {})",
+                    self.label(device)
+                ),
+            ),
+            DoubleZeroEvent::UserStatusChanged { from, to, .. } => {
+                ("user", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::LinkStatusChanged { from, to, .. } => {
+                ("link", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::LinkHealthChanged { from, to, .. } => {
+                ("link", format!("health {from} → {to}"))
+            }
+            DoubleZeroEvent::DeviceStatusChanged { from, to, .. } => {
+                ("device", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::DeviceHealthChanged { from, to, .. } => {
+                ("device", format!("health {from} → {to}"))
+            }
+            DoubleZeroEvent::ContributorStatusChanged { from, to, .. } => {
+                ("contributor", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::ExchangeStatusChanged { from, to, .. } => {
+                ("exchange", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::LocationStatusChanged { from, to, .. } => {
+                ("location", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::MulticastGroupStatusChanged { from, to, .. } => {
+                ("multicastgroup", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::PermissionStatusChanged { from, to, .. } => {
+                ("permission", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::ReadApiKeyStatusChanged { from, to, .. } => {
+                ("read-api-key", format!("{from} → {to}"))
+            }
+            DoubleZeroEvent::IpAllocationStatusChanged { from, to, .. } => {
+                ("ip-allocation", format!("{from} → {to}"))
+            }
+            _ => ("event", event.to_string()),
+        };
+
+        TimelineEntry {
+            signature: signature.map(str::to_string),
+            entity,
+            account: event.account().to_string(),
+            label: self.label(event.account()),
+            change,
+        }
+    }
+}
+
 impl LogCliCommand {
     pub async fn execute<C: CliCommand, W: Write>(
         self,
@@ -21,38 +153,192 @@ impl LogCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
-        tracing::debug!(env = %ctx.env, pubkey = %self.pubkey, "log");
+        tracing::debug!(env = %ctx.env, pubkey = ?self.pubkey, follow = self.follow, "log");
 
-        let pubkey: solana_sdk::pubkey::Pubkey = self
+        let pubkey = self
             .pubkey
-            .parse()
-            .map_err(|_| eyre::eyre!("Invalid pubkey"))?;
+            .as_deref()
+            .map(|pubkey| {
+                pubkey
+                    .parse::<Pubkey>()
+                    .map_err(|_| eyre::eyre!("Invalid pubkey"))
+            })
+            .transpose()?;
 
+        if self.follow {
+            return self.follow(client, pubkey, out);
+        }
+        let pubkey = pubkey.ok_or_else(|| eyre::eyre!("--pubkey is required"))?;
         let logs = client.get_logs(&pubkey)?;
 
+        if self.raw {
+            if self.json {
+                serde_json::to_writer_pretty(&mut *out, &logs)?;
+                writeln!(out)?;
+            } else {
+                write_raw(out, &logs)?;
+            }
+            return Ok(());
+        }
+
+        let mut labels = EntityLabels::load(client)?;
+        // Transactions come back newest first; the timeline reads oldest first.
+        let mut events = decode_events(&client.get_program_id(), &logs);
+        events.reverse();
+        let entries: Vec<TimelineEntry> = events
+            .iter()
+            .map(|event| {
+                labels.observe(event);
+                labels.entry(None, event)
+            })
+            .collect();
+
         if self.json {
-            serde_json::to_writer_pretty(&mut *out, &logs)?;
+            serde_json::to_writer_pretty(&mut *out, &entries)?;
             writeln!(out)?;
         } else {
-            for msg in &logs {
-                writeln!(out, "{msg}")?;
+            for entry in &entries {
+                writeln!(out, "{entry}")?;
             }
         }
 
         Ok(())
     }
+
+    fn follow<C: CliCommand, W: Write>(
+        &self,
+        client: &C,
+        pubkey: Option<Pubkey>,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let program_id = client.get_program_id();
+        let labels = if self.raw {
+            EntityLabels::default()
+        } else {
+            EntityLabels::load(client)?
+        };
+        let mut follower = Follower {
+            command: self,
+            client,
+            program_id,
+            labels,
+            out,
+            result: Ok(()),
+        };
+
+        client.follow_logs(&pubkey.unwrap_or(program_id), &mut follower)?;
+        follower.result
+    }
+}
+
+/// Prints each streamed transaction as it arrives, stopping on the first
+/// error.
+struct Follower<'a, C, W> {
+    command: &'a LogCliCommand,
+    client: &'a C,
+    program_id: Pubkey,
+    labels: EntityLabels,
+    out: &'a mut W,
+    result: eyre::Result<()>,
+}
+
+impl<C: CliCommand, W: Write> Follower<'_, C, W> {
+    fn write_transaction(&mut self, signature: &str, logs: &[String]) -> eyre::Result<()> {
+        let out = &mut *self.out;
+        if self.command.raw {
+            if self.command.json {
+                serde_json::to_writer(
+                    &mut *out,
+                    &serde_json::json!({ "signature": signature, "logs": logs }),
+                )?;
+                writeln!(out)?;
+            } else {
+                writeln!(out, "{signature}")?;
+                write_raw(out, logs)?;
+            }
+            return Ok(out.flush()?);
+        }
+
+        for event in decode_events(&self.program_id, logs) {
+            self.labels.observe(&event);
+            if !self.labels.contains(event.account()) {
+                // Created after the labels were loaded.
+                self.labels = EntityLabels::load(self.client)?;
+            }
+            let entry = self.labels.entry(Some(signature), &event);
+            if self.command.json {
+                serde_json::to_writer(&mut *out, &entry)?;
+                writeln!(out)?;
+            } else {
+                writeln!(out, "{entry}")?;
+            }
+        }
+
+        Ok(out.flush()?)
+    }
+}
+
+impl<C: CliCommand, W: Write> LogHandler for Follower<'_, C, W> {
+    fn handle(&mut self, signature: String, logs: Vec<String>) -> bool {
+        self.result = self.write_transaction(&signature, &logs);
+        self.result.is_ok()
+    }
+}
+
+fn write_raw<W: Write>(out: &mut W, logs: &[String]) -> eyre::Result<()> {
+    for msg in logs {
+        // Show structured events decoded instead of as raw base64.
+        match decode_event_line(msg) {
+            Some(event) => writeln!(out, "Program event: {event}")?,
+            None => writeln!(out, "{msg}")?,
+        }
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::utils::create_test_client;
+    use crate::{doublezerocommand::MockCliCommand, tests::utils::create_test_client};
     use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        events::event_log_line, AccountData, AccountType, Contributor, ContributorStatus,
+        LinkStatus, UserStatus,
+    };
+    use doublezero_serviceability::state::link::LinkHealth;
     use mockall::predicate;
-    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    fn short(pk: &Pubkey) -> String {
+        pk.to_string().chars().take(4).collect()
+    }
+
+    fn program_logs(program_id: &Pubkey, events: &[DoubleZeroEvent]) -> Vec<String> {
+        let mut logs = vec![format!("Program {program_id} invoke [1]")];
+        logs.extend(events.iter().map(event_log_line));
+        logs.push(format!("Program {program_id} success"));
+        logs
+    }
+
+    fn contributor_accounts(pk: Pubkey) -> HashMap<Box<Pubkey>, Box<AccountData>> {
+        let contributor = Contributor {
+            account_type: AccountType::Contributor,
+            owner: pk,
+            index: 1,
+            bump_seed: 255,
+            status: ContributorStatus::Activated,
+            code: "co01".to_string(),
+            reference_count: 0,
+            ops_manager_pk: Pubkey::default(),
+        };
+        HashMap::from([(
+            Box::new(pk),
+            Box::new(AccountData::Contributor(contributor)),
+        )])
+    }
 
     #[test]
-    fn test_log_table_output() {
+    fn test_log_raw_output() {
         let mut client = create_test_client();
         let pk = Pubkey::new_unique();
 
@@ -65,7 +351,9 @@ mod tests {
         let mut output = Vec::new();
         let res = block_on(
             LogCliCommand {
-                pubkey: pk.to_string(),
+                pubkey: Some(pk.to_string()),
+                follow: false,
+                raw: true,
                 json: false,
             }
             .execute(&ctx, &client, &mut output),
@@ -77,7 +365,42 @@ mod tests {
     }
 
     #[test]
-    fn test_log_json_output() {
+    fn test_log_raw_decodes_events() {
+        let mut client = create_test_client();
+        let pk = Pubkey::new_unique();
+        let event = DoubleZeroEvent::LinkStatusChanged {
+            link: pk,
+            from: LinkStatus::Activated,
+            to: LinkStatus::SoftDrained,
+        };
+        let line = event_log_line(&event);
+
+        client
+            .expect_get_logs()
+            .with(predicate::eq(pk))
+            .returning(move |_| Ok(vec!["Program log: suspend".to_string(), line.clone()]));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            LogCliCommand {
+                pubkey: Some(pk.to_string()),
+                follow: false,
+                raw: true,
+                json: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert!(output_str.contains("Program log: suspend"));
+        assert!(output_str.contains(&format!(
+            "Program event: LinkStatusChanged link={pk} activated -> soft-drained"
+        )));
+    }
+
+    #[test]
+    fn test_log_raw_json_output() {
         let mut client = create_test_client();
         let pk = Pubkey::new_unique();
 
@@ -90,7 +413,9 @@ mod tests {
         let mut output = Vec::new();
         let res = block_on(
             LogCliCommand {
-                pubkey: pk.to_string(),
+                pubkey: Some(pk.to_string()),
+                follow: false,
+                raw: true,
                 json: true,
             }
             .execute(&ctx, &client, &mut output),
@@ -101,4 +426,168 @@ mod tests {
         assert!(json.is_array());
         assert_eq!(json[0].as_str().unwrap(), "log line 1");
     }
+
+    #[test]
+    fn test_log_timeline_output() {
+        let mut client = create_test_client();
+        let program_id = client.get_program_id();
+        let contributor_pk = Pubkey::new_unique();
+        let link_pk = Pubkey::new_unique();
+
+        // Newest transaction first, as returned by the RPC.
+        let mut logs = program_logs(
+            &program_id,
+            &[DoubleZeroEvent::LinkHealthChanged {
+                link: link_pk,
+                from: LinkHealth::ReadyForService,
+                to: LinkHealth::Impaired,
+            }],
+        );
+        logs.extend(program_logs(
+            &program_id,
+            &[DoubleZeroEvent::ContributorStatusChanged {
+                contributor: contributor_pk,
+                from: ContributorStatus::Activated,
+                to: ContributorStatus::Suspended,
+            }],
+        ));
+
+        client
+            .expect_get_logs()
+            .with(predicate::eq(contributor_pk))
+            .returning(move |_| Ok(logs.clone()));
+        client
+            .expect_get_all()
+            .returning(move || Ok(contributor_accounts(contributor_pk)));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            LogCliCommand {
+                pubkey: Some(contributor_pk.to_string()),
+                follow: false,
+                raw: false,
+                json: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,
+            format!(
+                "contributor co01 ({}…): activated → suspended\nlink {link_pk}: health ready-for-service → impaired\n",
+                short(&contributor_pk)
+            )
+        );
+    }
+
+    #[test]
+    fn test_log_timeline_prefers_petnames() {
+        let mut client = MockCliCommand::new();
+        let program_id = Pubkey::new_unique();
+        let contributor_pk = Pubkey::new_unique();
+        let logs = program_logs(
+            &program_id,
+            &[DoubleZeroEvent::ContributorStatusChanged {
+                contributor: contributor_pk,
+                from: ContributorStatus::Activated,
+                to: ContributorStatus::Suspended,
+            }],
+        );
+
+        client.expect_get_program_id().return_const(program_id);
+        client
+            .expect_get_logs()
+            .returning(move |_| Ok(logs.clone()));
+        client
+            .expect_get_all()
+            .returning(move || Ok(contributor_accounts(contributor_pk)));
+        client.expect_get_address_book().returning(move || {
+            AddressBook::from_petnames(&HashMap::from([(
+                contributor_pk.to_string(),
+                "partner".to_string(),
+            )]))
+        });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            LogCliCommand {
+                pubkey: Some(contributor_pk.to_string()),
+                follow: false,
+                raw: false,
+                json: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "contributor partner ({}…): activated → suspended\n",
+                short(&contributor_pk)
+            )
+        );
+    }
+
+    #[test]
+    fn test_log_follow_output() {
+        let mut client = create_test_client();
+        let program_id = client.get_program_id();
+        let contributor_pk = Pubkey::new_unique();
+        let user_pk = Pubkey::new_unique();
+
+        let activated = program_logs(
+            &program_id,
+            &[DoubleZeroEvent::UserActivated {
+                user: user_pk,
+                device: contributor_pk,
+                client_ip: [1, 2, 3, 4].into(),
+                dz_ip: [10, 0, 0, 1].into(),
+                tunnel_id: 513,
+            }],
+        );
+        let banned = program_logs(
+            &program_id,
+            &[DoubleZeroEvent::UserStatusChanged {
+                user: user_pk,
+                from: UserStatus::Activated,
+                to: UserStatus::Banned,
+            }],
+        );
+
+        client
+            .expect_get_all()
+            .returning(move || Ok(contributor_accounts(contributor_pk)));
+        client
+            .expect_follow_logs()
+            .withf(move |pubkey, _| *pubkey == program_id)
+            .returning(move |_, handler| {
+                assert!(handler.handle("sig1".to_string(), activated.clone()));
+                assert!(handler.handle("sig2".to_string(), banned.clone()));
+                Ok(())
+            });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            LogCliCommand {
+                pubkey: None,
+                follow: true,
+                raw: false,
+                json: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        let (user, device) = (short(&user_pk), short(&contributor_pk));
+        assert_eq!(
+            output_str,
+            format!(
+                "user 1.2.3.4 ({user}…): pending → activated (tunnel 513, dz_ip 10.0.0.1, device co01 ({device}…))\nuser 1.2.3.4 ({user}…): activated → banned\n"
+            )
+        );
+    }
 }
@@ -12,12 +12,10 @@ use doublezero_serviceability::{pda::get_topology_pda, state::interface::Loopbac
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
 
+/// Honours the global `--dry-run` flag: prints what would change without
+/// submitting transactions.
 #[derive(Args, Debug)]
-pub struct FlexAlgoMigrateCliCommand {
-    /// Print what would be changed without submitting transactions
-    #[arg(long, default_value_t = false)]
-    pub dry_run: bool,
-}
+pub struct FlexAlgoMigrateCliCommand;
 
 impl FlexAlgoMigrateCliCommand {
     pub async fn execute<C: CliCommand, W: Write>(
@@ -27,6 +25,7 @@ impl FlexAlgoMigrateCliCommand {
         out: &mut W,
     ) -> eyre::Result<()> {
         let program_id = client.get_program_id();
+        let dry_run = client.is_dry_run();
 
         // Verify UNICAST-DEFAULT topology PDA exists on chain.
         let (unicast_default_pda, _) = get_topology_pda(&program_id, "UNICAST-DEFAULT");
@@ -52,7 +51,7 @@ impl FlexAlgoMigrateCliCommand {
                     "  [link] {pubkey} ({}) — would tag UNICAST-DEFAULT",
                     link.code
                 )?;
-                if !self.dry_run {
+                if !dry_run {
                     let result = client.update_link(UpdateLinkCommand {
                         pubkey: *pubkey,
                         code: None,
@@ -128,7 +127,7 @@ impl FlexAlgoMigrateCliCommand {
                 devices_needing_backfill.len()
             )?;
 
-            if !self.dry_run {
+            if !dry_run {
                 let result =
                     client.assign_topology_node_segments(AssignTopologyNodeSegmentsCommand {
                         name: topology.name.clone(),
@@ -151,17 +150,17 @@ impl FlexAlgoMigrateCliCommand {
 
         // ── Summary ──────────────────────────────────────────────────────────────
 
-        let dry_run_suffix = if self.dry_run {
+        let dry_run_suffix = if dry_run {
             " [DRY RUN — no changes made]"
         } else {
             ""
         };
-        let tagged_summary = if self.dry_run {
+        let tagged_summary = if dry_run {
             format!("{links_needing_tag} link(s) would be tagged")
         } else {
             format!("{links_tagged} link(s) tagged")
         };
-        let loopback_summary = if self.dry_run {
+        let loopback_summary = if dry_run {
             format!("{topologies_backfilled} topology(s) would be backfilled")
         } else {
             format!("{topologies_backfilled} topology(s) backfilled")
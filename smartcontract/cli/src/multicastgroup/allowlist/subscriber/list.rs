@@ -134,6 +134,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let accesspass2_pk = Pubkey::from_str_const("11111112D1oxKts8YPdTJRG5FzxTNpMtWmq8hkVx3");
@@ -155,6 +157,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client
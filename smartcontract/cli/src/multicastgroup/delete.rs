@@ -1,19 +1,23 @@
 use crate::{
+    cascade::print_cascade_preview,
     doublezerocommand::CliCommand,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
     validators::validate_pubkey_or_code,
 };
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::{
-    accesspass::list::ListAccessPassCommand,
-    multicastgroup::{
-        allowlist::{
-            publisher::remove::RemoveMulticastGroupPubAllowlistCommand,
-            subscriber::remove::RemoveMulticastGroupSubAllowlistCommand,
+use doublezero_sdk::{
+    cascade::DeleteTarget,
+    commands::{
+        accesspass::list::ListAccessPassCommand,
+        multicastgroup::{
+            allowlist::{
+                publisher::remove::RemoveMulticastGroupPubAllowlistCommand,
+                subscriber::remove::RemoveMulticastGroupSubAllowlistCommand,
+            },
+            delete::DeleteMulticastGroupCommand,
+            get::GetMulticastGroupCommand,
         },
-        delete::DeleteMulticastGroupCommand,
-        get::GetMulticastGroupCommand,
     },
 };
 use indicatif::{ProgressBar, ProgressStyle};
@@ -24,6 +28,10 @@ pub struct DeleteMulticastGroupCliCommand {
     /// Multicast group Pubkey to delete
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 struct RemovalFailure {
@@ -39,6 +47,13 @@ impl DeleteMulticastGroupCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let (pubkey, _) = client.get_multicastgroup(GetMulticastGroupCommand {
+                pubkey_or_code: self.pubkey,
+            })?;
+            return print_cascade_preview(client, DeleteTarget::MulticastGroup(pubkey), out);
+        }
+
         // Check requirements
         client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
 
@@ -267,6 +282,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         // AccessPass with group in subscriber allowlist
@@ -290,6 +307,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         // AccessPass with no reference to the group (should not trigger remove)
@@ -311,6 +330,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client
@@ -370,6 +391,7 @@ mod tests {
         let res = block_on(
             DeleteMulticastGroupCliCommand {
                 pubkey: mgroup_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -2,10 +2,13 @@ use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::{serializer, types::parse_utils::bandwidth_to_string};
-use doublezero_sdk::commands::{
-    accesspass::list::ListAccessPassCommand, device::list::ListDeviceCommand,
-    location::list::ListLocationCommand, multicastgroup::get::GetMulticastGroupCommand,
-    tenant::list::ListTenantCommand, user::list::ListUserCommand,
+use doublezero_sdk::{
+    commands::{
+        accesspass::list::ListAccessPassCommand, device::list::ListDeviceCommand,
+        location::list::ListLocationCommand, multicastgroup::get::GetMulticastGroupCommand,
+        tenant::list::ListTenantCommand, user::list::ListUserCommand,
+    },
+    labels::LabeledPubkey,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -23,7 +26,7 @@ pub struct GetMulticastGroupCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct MulticastGroupDisplay {
+pub(crate) struct MulticastGroupDisplay {
     pub account: String,
     pub code: String,
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
@@ -35,7 +38,7 @@ struct MulticastGroupDisplay {
     pub publisher_count: u32,
     pub subscriber_count: u32,
     pub status: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 #[derive(Tabled, Serialize)]
@@ -44,8 +47,7 @@ pub struct MulticastAllowlistDisplay {
     pub account: Pubkey,
     pub mode: String,
     pub client_ip: Ipv4Addr,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub user_payer: Pubkey,
+    pub user_payer: LabeledPubkey,
 }
 
 #[derive(Tabled, Serialize)]
@@ -60,7 +62,7 @@ struct MulticastUserDisplay {
     pub tunnel_net: String,
     pub dz_ip: String,
     pub status: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 #[derive(Serialize)]
@@ -78,6 +80,7 @@ impl GetMulticastGroupCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (mgroup_pubkey, mgroup) = client.get_multicastgroup(GetMulticastGroupCommand {
             pubkey_or_code: self.code,
         })?;
@@ -106,7 +109,7 @@ impl GetMulticastGroupCliCommand {
                     "S".to_string()
                 },
                 client_ip: accesspass.client_ip,
-                user_payer: accesspass.user_payer,
+                user_payer: book.labeled(&accesspass.user_payer),
             })
             .collect();
 
@@ -157,7 +160,7 @@ impl GetMulticastGroupCliCommand {
                     tunnel_net: user.tunnel_net.to_string(),
                     dz_ip: user.dz_ip.to_string(),
                     status: user.status.to_string(),
-                    owner: user.owner.to_string(),
+                    owner: book.labeled(&user.owner),
                 }
             })
             .collect();
@@ -182,7 +185,7 @@ impl GetMulticastGroupCliCommand {
             publisher_count: mgroup.publisher_count,
             subscriber_count: mgroup.subscriber_count,
             status: mgroup.status.to_string(),
-            owner: mgroup.owner.to_string(),
+            owner: book.labeled(&mgroup.owner),
         };
 
         if self.json {
@@ -356,6 +359,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let multicastgroup = MulticastGroup {
@@ -405,6 +409,8 @@ mod tests {
                     max_unicast_users: 1,
                     multicast_user_count: 0,
                     max_multicast_users: 1,
+                    bandwidth_tier: Default::default(),
+                    referrer: Pubkey::default(),
                 },
             );
             Ok(accesspasses)
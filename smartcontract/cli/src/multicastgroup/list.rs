@@ -3,7 +3,8 @@ use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::serializer;
 use doublezero_sdk::{
-    commands::multicastgroup::list::ListMulticastGroupCommand, MulticastGroup, MulticastGroupStatus,
+    commands::multicastgroup::list::ListMulticastGroupCommand, labels::LabeledPubkey,
+    MulticastGroup, MulticastGroupStatus,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -32,8 +33,7 @@ pub struct MulticastGroupDisplay {
     pub publishers: u32,
     pub subscribers: u32,
     pub status: MulticastGroupStatus,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListMulticastGroupCliCommand {
@@ -43,6 +43,7 @@ impl ListMulticastGroupCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let multicastgroups = client.list_multicastgroup(ListMulticastGroupCommand)?;
 
         let mut multicastgroups: Vec<(Pubkey, MulticastGroup)> =
@@ -54,7 +55,7 @@ impl ListMulticastGroupCliCommand {
             .map(|(pubkey, multicastgroup)| MulticastGroupDisplay {
                 account: pubkey,
                 code: multicastgroup.code,
-                owner: multicastgroup.owner,
+                owner: book.labeled(&multicastgroup.owner),
                 multicast_ip: multicastgroup.multicast_ip,
                 max_bandwidth: multicastgroup.max_bandwidth,
                 publishers: multicastgroup.publisher_count,
@@ -2,7 +2,7 @@ use crate::{doublezerocommand::CliCommand, permission::flags::bitmask_to_names};
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::permission::get::GetPermissionCommand;
+use doublezero_sdk::{commands::permission::get::GetPermissionCommand, labels::LabeledPubkey};
 use doublezero_serviceability::pda::get_permission_pda;
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -20,21 +20,19 @@ pub struct GetPermissionCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct PermissionDisplay {
+pub(crate) struct PermissionDisplay {
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
     pub account: Pubkey,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub user_payer: Pubkey,
+    pub user_payer: LabeledPubkey,
     pub permissions: PermissionList,
     pub status: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 /// Wraps `Vec<String>` to display as comma-separated in tables and as a JSON array.
 #[derive(Serialize)]
 #[serde(transparent)]
-struct PermissionList(Vec<String>);
+pub(crate) struct PermissionList(Vec<String>);
 
 impl std::fmt::Display for PermissionList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -52,6 +50,7 @@ impl GetPermissionCliCommand {
         let user_payer = Pubkey::from_str(&self.user_payer)
             .map_err(|e| eyre::eyre!("invalid user_payer pubkey: {e}"))?;
 
+        let book = client.get_address_book();
         let program_id = client.get_program_id();
         let (permission_pda, _) = get_permission_pda(&program_id, &user_payer);
 
@@ -61,10 +60,10 @@ impl GetPermissionCliCommand {
 
         let display = PermissionDisplay {
             account: pubkey,
-            user_payer: permission.user_payer,
+            user_payer: book.labeled(&permission.user_payer),
             permissions: PermissionList(bitmask_to_names(permission.permissions)),
             status: permission.status.to_string(),
-            owner: permission.owner,
+            owner: book.labeled(&permission.owner),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
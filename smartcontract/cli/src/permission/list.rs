@@ -2,7 +2,7 @@ use crate::{doublezerocommand::CliCommand, permission::flags::bitmask_to_names};
 use clap::Args;
 use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::permission::list::ListPermissionCommand;
+use doublezero_sdk::{commands::permission::list::ListPermissionCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
@@ -37,8 +37,7 @@ pub struct PermissionDisplay {
     pub user_payer: Pubkey,
     pub permissions: PermissionList,
     pub status: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListPermissionCliCommand {
@@ -48,6 +47,7 @@ impl ListPermissionCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let permissions = client.list_permission(ListPermissionCommand {})?;
 
         let mut displays: Vec<PermissionDisplay> = permissions
@@ -57,7 +57,7 @@ impl ListPermissionCliCommand {
                 user_payer: p.user_payer,
                 permissions: PermissionList(bitmask_to_names(p.permissions)),
                 status: p.status.to_string(),
-                owner: p.owner,
+                owner: book.labeled(&p.owner),
             })
             .collect();
 
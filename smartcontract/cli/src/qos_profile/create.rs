@@ -0,0 +1,129 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::{commands::qos_profile::create::CreateQosProfileCommand, BandwidthTier};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct CreateQosProfileCliCommand {
+    /// Bandwidth tier the profile applies to (basic, standard or premium)
+    #[arg(long)]
+    pub bandwidth_tier: BandwidthTier,
+    /// Shaping rate per user tunnel, in Mbps
+    #[arg(long)]
+    pub rate_mbps: u32,
+    /// Shaper burst size in KB; 0 uses the device default
+    #[arg(long, default_value_t = 0)]
+    pub burst_kbytes: u32,
+    /// DSCP value to mark the tunnel's traffic with (0-63)
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=63))]
+    pub dscp: u8,
+}
+
+impl CreateQosProfileCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        if self.bandwidth_tier == BandwidthTier::Unshaped {
+            return Err(eyre::eyre!(
+                "QoS profiles apply to shaped tiers only (basic, standard or premium)"
+            ));
+        }
+
+        let (signature, _pubkey) = client.create_qos_profile(CreateQosProfileCommand {
+            bandwidth_tier: self.bandwidth_tier,
+            rate_mbps: self.rate_mbps,
+            burst_kbytes: self.burst_kbytes,
+            dscp: self.dscp,
+        })?;
+
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        qos_profile::create::CreateQosProfileCliCommand,
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::qos_profile::create::CreateQosProfileCommand, get_qos_profile_pda, BandwidthTier,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_qos_profile_create() {
+        let mut client = create_test_client();
+
+        let program_id = Pubkey::from_str_const("GYhQDKuESrasNZGyhMJhGYFtbzNijYhcrN9poSqCQVah");
+        let (pda_pubkey, _) = get_qos_profile_pda(&program_id, BandwidthTier::Premium);
+        let signature = Signature::from([
+            120, 138, 162, 185, 59, 209, 241, 157, 71, 157, 74, 131, 4, 87, 54, 28, 38, 180, 222,
+            82, 64, 62, 61, 62, 22, 46, 17, 203, 187, 136, 62, 43, 11, 38, 235, 17, 239, 82, 240,
+            139, 130, 217, 227, 214, 9, 242, 141, 223, 94, 29, 184, 110, 62, 32, 87, 137, 63, 139,
+            100, 221, 20, 137, 4, 5,
+        ]);
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client
+            .expect_create_qos_profile()
+            .with(predicate::eq(CreateQosProfileCommand {
+                bandwidth_tier: BandwidthTier::Premium,
+                rate_mbps: 10_000,
+                burst_kbytes: 0,
+                dscp: 46,
+            }))
+            .returning(move |_| Ok((signature, pda_pubkey)));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateQosProfileCliCommand {
+                bandwidth_tier: BandwidthTier::Premium,
+                rate_mbps: 10_000,
+                burst_kbytes: 0,
+                dscp: 46,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,"Signature: 3QnHBSdd4doEF6FgpLCejqEw42UQjfvNhQJwoYDSpoBszpCCqVft4cGoneDCnZ6Ez3ujzavzUu85u6F79WtLhcsv\n"
+        );
+    }
+
+    #[test]
+    fn test_cli_qos_profile_create_rejects_unshaped() {
+        let mut client = create_test_client();
+        client.expect_check_requirements().returning(|_| Ok(()));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            CreateQosProfileCliCommand {
+                bandwidth_tier: BandwidthTier::Unshaped,
+                rate_mbps: 10_000,
+                burst_kbytes: 0,
+                dscp: 0,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::{commands::qos_profile::delete::DeleteQosProfileCommand, BandwidthTier};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct DeleteQosProfileCliCommand {
+    /// Bandwidth tier of the profile to delete; its users fall back to unshaped
+    #[arg(long)]
+    pub bandwidth_tier: BandwidthTier,
+}
+
+impl DeleteQosProfileCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let signature = client.delete_qos_profile(DeleteQosProfileCommand {
+            bandwidth_tier: self.bandwidth_tier,
+        })?;
+
+        print_signature(out, &signature)
+    }
+}
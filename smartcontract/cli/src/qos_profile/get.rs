@@ -0,0 +1,34 @@
+use crate::{doublezerocommand::CliCommand, qos_profile::list::QosProfileDisplay};
+use clap::Args;
+use doublezero_cli_core::{render_record, CliContext, OutputFormat};
+use doublezero_sdk::{commands::qos_profile::get::GetQosProfileCommand, BandwidthTier};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct GetQosProfileCliCommand {
+    /// Bandwidth tier of the profile
+    #[arg(long)]
+    pub bandwidth_tier: BandwidthTier,
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl GetQosProfileCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (pubkey, qos_profile) = client.get_qos_profile(GetQosProfileCommand {
+            bandwidth_tier: self.bandwidth_tier,
+        })?;
+
+        render_record(
+            out,
+            &QosProfileDisplay::new(pubkey, qos_profile),
+            OutputFormat::from_flags(self.json, false),
+        )
+    }
+}
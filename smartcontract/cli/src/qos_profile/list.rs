@@ -0,0 +1,121 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use doublezero_sdk::{commands::qos_profile::list::ListQosProfileCommand, QosProfile};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct ListQosProfileCliCommand {
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+#[derive(Tabled, Serialize)]
+pub(crate) struct QosProfileDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub account: Pubkey,
+    pub bandwidth_tier: String,
+    pub profile_id: u16,
+    pub rate_mbps: u32,
+    pub burst_kbytes: u32,
+    pub dscp: u8,
+}
+
+impl QosProfileDisplay {
+    pub(crate) fn new(account: Pubkey, qos_profile: QosProfile) -> Self {
+        QosProfileDisplay {
+            account,
+            bandwidth_tier: qos_profile.bandwidth_tier.to_string(),
+            profile_id: qos_profile.profile_id,
+            rate_mbps: qos_profile.rate_mbps,
+            burst_kbytes: qos_profile.burst_kbytes,
+            dscp: qos_profile.dscp,
+        }
+    }
+}
+
+impl ListQosProfileCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let mut profiles: Vec<(Pubkey, QosProfile)> = client
+            .list_qos_profile(ListQosProfileCommand)?
+            .into_iter()
+            .collect();
+        profiles.sort_by_key(|(_, profile)| profile.bandwidth_tier as u8);
+
+        let displays: Vec<QosProfileDisplay> = profiles
+            .into_iter()
+            .map(|(pubkey, profile)| QosProfileDisplay::new(pubkey, profile))
+            .collect();
+
+        render_collection(
+            out,
+            displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{qos_profile::list::ListQosProfileCliCommand, tests::utils::create_test_client};
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{AccountType, BandwidthTier, QosProfile};
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_cli_qos_profile_list() {
+        let mut client = create_test_client();
+
+        let premium_pubkey = Pubkey::from_str_const("11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo");
+        let basic_pubkey = Pubkey::from_str_const("11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V");
+        let premium = QosProfile {
+            account_type: AccountType::QosProfile,
+            bandwidth_tier: BandwidthTier::Premium,
+            profile_id: 1,
+            rate_mbps: 10_000,
+            burst_kbytes: 512,
+            dscp: 46,
+            ..Default::default()
+        };
+        let basic = QosProfile {
+            account_type: AccountType::QosProfile,
+            bandwidth_tier: BandwidthTier::Basic,
+            profile_id: 2,
+            rate_mbps: 100,
+            ..Default::default()
+        };
+        client.expect_list_qos_profile().returning(move |_| {
+            Ok(HashMap::from([
+                (premium_pubkey, premium.clone()),
+                (basic_pubkey, basic.clone()),
+            ]))
+        });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let res = block_on(
+            ListQosProfileCliCommand {
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        );
+        assert!(res.is_ok());
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(output_str, "[{\"account\":\"11111116EPqoQskEM2Pddp8KTL9JdYEBZMGF3aq7V\",\"bandwidth_tier\":\"basic\",\"profile_id\":2,\"rate_mbps\":100,\"burst_kbytes\":0,\"dscp\":0},{\"account\":\"11111115RidqCHAoz6dzmXxGcfWLNzevYqNpaRAUo\",\"bandwidth_tier\":\"premium\",\"profile_id\":1,\"rate_mbps\":10000,\"burst_kbytes\":512,\"dscp\":46}]\n");
+    }
+}
@@ -0,0 +1,44 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::{commands::qos_profile::update::UpdateQosProfileCommand, BandwidthTier};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct UpdateQosProfileCliCommand {
+    /// Bandwidth tier of the profile to update
+    #[arg(long)]
+    pub bandwidth_tier: BandwidthTier,
+    /// Shaping rate per user tunnel, in Mbps
+    #[arg(long)]
+    pub rate_mbps: Option<u32>,
+    /// Shaper burst size in KB; 0 uses the device default
+    #[arg(long)]
+    pub burst_kbytes: Option<u32>,
+    /// DSCP value to mark the tunnel's traffic with (0-63)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=63))]
+    pub dscp: Option<u8>,
+}
+
+impl UpdateQosProfileCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let signature = client.update_qos_profile(UpdateQosProfileCommand {
+            bandwidth_tier: self.bandwidth_tier,
+            rate_mbps: self.rate_mbps,
+            burst_kbytes: self.burst_kbytes,
+            dscp: self.dscp,
+        })?;
+
+        print_signature(out, &signature)
+    }
+}
@@ -45,7 +45,8 @@ impl From<AllocateResourceCliCommand> for AllocateResourceCommand {
             | ResourceType::LinkIds
             | ResourceType::SegmentRoutingIds
             | ResourceType::VrfIds
-            | ResourceType::AdminGroupBits => {
+            | ResourceType::AdminGroupBits
+            | ResourceType::QosProfileIds => {
                 IdOrIp::Id(x.parse::<u16>().expect("Failed to parse ID"))
             }
         });
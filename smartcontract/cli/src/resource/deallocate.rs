@@ -47,7 +47,8 @@ impl From<DeallocateResourceCliCommand> for DeallocateResourceCommand {
             | ResourceType::LinkIds
             | ResourceType::SegmentRoutingIds
             | ResourceType::VrfIds
-            | ResourceType::AdminGroupBits => {
+            | ResourceType::AdminGroupBits
+            | ResourceType::QosProfileIds => {
                 IdOrIp::Id(cmd.value.parse::<u16>().expect("Failed to parse ID"))
             }
         };
@@ -7,6 +7,7 @@ pub mod close;
 pub mod create;
 pub mod deallocate;
 pub mod get;
+pub mod resize;
 pub mod verify;
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -21,6 +22,7 @@ pub enum ResourceType {
     SegmentRoutingIds,
     VrfIds,
     AdminGroupBits,
+    QosProfileIds,
 }
 
 pub fn resource_type_from(
@@ -47,6 +49,7 @@ pub fn resource_type_from(
         ResourceType::SegmentRoutingIds => SdkResourceType::SegmentRoutingIds,
         ResourceType::VrfIds => SdkResourceType::VrfIds,
         ResourceType::AdminGroupBits => SdkResourceType::AdminGroupBits,
+        ResourceType::QosProfileIds => SdkResourceType::QosProfileIds,
     }
 }
 
@@ -152,4 +155,10 @@ mod tests {
         let result = resource_type_from(ResourceType::AdminGroupBits, None, None);
         assert_eq!(result, SdkResourceType::AdminGroupBits);
     }
+
+    #[test]
+    fn test_qos_profile_ids() {
+        let result = resource_type_from(ResourceType::QosProfileIds, None, None);
+        assert_eq!(result, SdkResourceType::QosProfileIds);
+    }
 }
@@ -660,8 +660,9 @@ fn detect_orphaned_extensions(
 ) {
     let mut expected: HashSet<Pubkey> = HashSet::new();
 
-    // Global singletons. VrfIds and AdminGroupBits aren't verified against
-    // usage above but must still be treated as legitimate, not orphans.
+    // Global singletons. VrfIds, AdminGroupBits and QosProfileIds aren't
+    // verified against usage above but must still be treated as legitimate,
+    // not orphans.
     for resource_type in [
         ResourceType::DeviceTunnelBlock,
         ResourceType::UserTunnelBlock,
@@ -671,6 +672,7 @@ fn detect_orphaned_extensions(
         ResourceType::SegmentRoutingIds,
         ResourceType::VrfIds,
         ResourceType::AdminGroupBits,
+        ResourceType::QosProfileIds,
     ] {
         let (pda, _, _) = get_resource_extension_pda(program_id, resource_type);
         expected.insert(pda);
@@ -1655,6 +1657,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
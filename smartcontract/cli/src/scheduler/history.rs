@@ -0,0 +1,153 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
+use doublezero_sdk::{
+    commands::scheduler::history::GetExecutionHistoryCommand,
+    record::receipts::{ExecutionRecord, MAX_JOB_ID_LEN},
+};
+use serde::Serialize;
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct SchedulerHistoryCliCommand {
+    /// Job id
+    #[arg(long, value_parser = validate_job_id)]
+    pub job: String,
+    /// Scheduler authority keeping the job's receipt log
+    #[arg(long)]
+    pub authority: Pubkey,
+    /// Only show the most recent N executions
+    #[arg(long)]
+    pub limit: Option<usize>,
+    /// Output as pretty JSON
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+    /// Output as compact JSON
+    #[arg(long, default_value_t = false)]
+    pub json_compact: bool,
+}
+
+fn validate_job_id(job: &str) -> Result<String, String> {
+    if job.is_empty() || job.len() > MAX_JOB_ID_LEN {
+        return Err(format!("job id must be 1 to {MAX_JOB_ID_LEN} bytes"));
+    }
+    Ok(job.to_string())
+}
+
+#[derive(Tabled, Serialize)]
+pub struct ExecutionRecordDisplay {
+    pub sequence: u64,
+    pub tick: u64,
+    pub signature: String,
+    pub result_hash: String,
+}
+
+impl From<&ExecutionRecord> for ExecutionRecordDisplay {
+    fn from(record: &ExecutionRecord) -> Self {
+        ExecutionRecordDisplay {
+            sequence: record.sequence(),
+            tick: record.tick(),
+            signature: record.signature().to_string(),
+            result_hash: Hash::new_from_array(record.result_hash).to_string(),
+        }
+    }
+}
+
+impl SchedulerHistoryCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (_, records) = client.get_execution_history(GetExecutionHistoryCommand {
+            authority: self.authority,
+            job_id: self.job,
+        })?;
+
+        let skip = self
+            .limit
+            .map_or(0, |limit| records.len().saturating_sub(limit));
+        let displays: Vec<ExecutionRecordDisplay> =
+            records.iter().skip(skip).map(Into::into).collect();
+
+        render_collection(
+            out,
+            displays,
+            OutputFormat::from_flags(self.json, self.json_compact),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{scheduler::history::SchedulerHistoryCliCommand, tests::utils::create_test_client};
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::scheduler::history::GetExecutionHistoryCommand,
+        record::receipts::{receipt_log_key, ExecutionRecord},
+    };
+    use mockall::predicate;
+    use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_scheduler_history() {
+        let mut client = create_test_client();
+
+        let authority = Pubkey::new_unique();
+        let signatures = [Signature::new_unique(), Signature::new_unique()];
+        let records: Vec<ExecutionRecord> = (1..=3)
+            .map(|sequence| {
+                ExecutionRecord::new(
+                    sequence,
+                    b"rewards-snapshot",
+                    sequence * 100,
+                    &signatures[sequence as usize % 2],
+                    [sequence as u8; 32],
+                )
+                .unwrap()
+            })
+            .collect();
+
+        client
+            .expect_get_execution_history()
+            .with(predicate::eq(GetExecutionHistoryCommand {
+                authority,
+                job_id: "rewards-snapshot".to_string(),
+            }))
+            .returning(move |_| {
+                Ok((
+                    receipt_log_key(&authority, b"rewards-snapshot"),
+                    records.clone(),
+                ))
+            });
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            SchedulerHistoryCliCommand {
+                job: "rewards-snapshot".to_string(),
+                authority,
+                limit: Some(2),
+                json: false,
+                json_compact: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output_str,
+            format!(
+                "[{{\"sequence\":2,\"tick\":200,\"signature\":\"{}\",\"result_hash\":\"{}\"}},{{\"sequence\":3,\"tick\":300,\"signature\":\"{}\",\"result_hash\":\"{}\"}}]\n",
+                signatures[0],
+                Hash::new_from_array([2u8; 32]),
+                signatures[1],
+                Hash::new_from_array([3u8; 32]),
+            )
+        );
+    }
+}
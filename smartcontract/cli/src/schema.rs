@@ -0,0 +1,155 @@
+//! Output schemas of the serviceability verbs, for `doublezero schema dump`.
+//!
+//! Paths are the invocations of the unified `doublezero` binary, which hoists
+//! these verbs to the top level and nests multicast groups under
+//! `multicast group`.
+
+use doublezero_cli_core::schema::{OutputSchema, OutputSchemas};
+
+use crate::{
+    accesspass, contributor, device, env, exchange, feed, globalconfig, link, location,
+    multicastgroup, permission, resource, tenant, user,
+};
+
+pub fn output_schemas() -> OutputSchemas {
+    [
+        (
+            "access-pass list",
+            OutputSchema::collection::<accesspass::list::AccessPassDisplay>(),
+        ),
+        (
+            "access-pass get",
+            OutputSchema::record::<accesspass::get::AccessPassDisplay>(),
+        ),
+        (
+            "access-pass user-balances",
+            OutputSchema::collection::<accesspass::user_balances::UserBalanceDisplay>(),
+        ),
+        (
+            "contributor list",
+            OutputSchema::collection::<contributor::list::ContributorDisplay>(),
+        ),
+        (
+            "contributor get",
+            OutputSchema::record::<contributor::get::ContributorDisplay>(),
+        ),
+        (
+            "contributor api-key list",
+            OutputSchema::collection::<contributor::api_key::list::ApiKeyDisplay>(),
+        ),
+        (
+            "device list",
+            OutputSchema::collection::<device::list::DeviceDisplay>(),
+        ),
+        (
+            "device get",
+            OutputSchema::record::<device::get::DeviceDisplay>(),
+        ),
+        (
+            "device interface list",
+            OutputSchema::collection::<device::interface::list::DeviceInterfaceDisplay>(),
+        ),
+        (
+            "device interface get",
+            OutputSchema::record::<device::interface::get::InterfaceDisplay>(),
+        ),
+        (
+            "env list",
+            OutputSchema::collection::<env::list::EnvDisplay>(),
+        ),
+        (
+            "env show",
+            OutputSchema::record::<env::show::EnvProbeDisplay>(),
+        ),
+        (
+            "exchange list",
+            OutputSchema::collection::<exchange::list::ExchangeDisplay>(),
+        ),
+        (
+            "exchange get",
+            OutputSchema::record::<exchange::get::ExchangeDisplay>(),
+        ),
+        (
+            "feed list",
+            OutputSchema::collection::<feed::list::FeedDisplay>(),
+        ),
+        ("feed get", OutputSchema::record::<feed::get::FeedDisplay>()),
+        (
+            "global-config get",
+            OutputSchema::record::<globalconfig::get::ConfigDisplay>(),
+        ),
+        (
+            "global-config airdrop get",
+            OutputSchema::record::<globalconfig::airdrop::get::AirdropDisplay>(),
+        ),
+        (
+            "global-config authority get",
+            OutputSchema::record::<globalconfig::authority::get::AuthorityDisplay>(),
+        ),
+        (
+            "global-config feature-flags get",
+            OutputSchema::record::<globalconfig::featureflags::get::FeatureFlagsDisplay>(),
+        ),
+        (
+            "link list",
+            OutputSchema::collection::<link::list::LinkDisplay>(),
+        ),
+        ("link get", OutputSchema::record::<link::get::LinkDisplay>()),
+        (
+            "location list",
+            OutputSchema::collection::<location::list::LocationDisplay>(),
+        ),
+        (
+            "location get",
+            OutputSchema::record::<location::get::LocationDisplay>(),
+        ),
+        (
+            "multicast group list",
+            OutputSchema::collection::<multicastgroup::list::MulticastGroupDisplay>(),
+        ),
+        (
+            "multicast group get",
+            OutputSchema::record::<multicastgroup::get::MulticastGroupDisplay>(),
+        ),
+        (
+            "multicast group allowlist publisher list",
+            OutputSchema::collection::<
+                multicastgroup::allowlist::publisher::list::MulticastAllowlistDisplay,
+            >(),
+        ),
+        (
+            "multicast group allowlist subscriber list",
+            OutputSchema::collection::<
+                multicastgroup::allowlist::subscriber::list::MulticastAllowlistDisplay,
+            >(),
+        ),
+        (
+            "permission list",
+            OutputSchema::collection::<permission::list::PermissionDisplay>(),
+        ),
+        (
+            "permission get",
+            OutputSchema::record::<permission::get::PermissionDisplay>(),
+        ),
+        (
+            "resource get",
+            OutputSchema::record::<resource::get::ResourceDisplay>(),
+        ),
+        (
+            "tenant list",
+            OutputSchema::collection::<tenant::list::TenantDisplay>(),
+        ),
+        (
+            "tenant get",
+            OutputSchema::record::<tenant::get::TenantDisplay>(),
+        ),
+        (
+            "user list",
+            OutputSchema::collection::<user::list::UserDisplay>(),
+        ),
+        ("user get", OutputSchema::record::<user::get::UserDisplay>()),
+    ]
+    .into_iter()
+    .map(|(path, schema)| (path.to_string(), schema))
+    .collect()
+}
@@ -0,0 +1,62 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{render_record, CliContext, OutputFormat};
+use doublezero_program_common::serializer;
+use doublezero_sdk::{
+    commands::state_commitment::get::GetStateCommitmentCommand,
+    labels::{AddressBook, LabeledPubkey},
+    StateCommitment,
+};
+use serde::Serialize;
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use std::io::Write;
+use tabled::Tabled;
+
+#[derive(Args, Debug)]
+pub struct GetStateCommitmentCliCommand {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Tabled, Serialize)]
+struct StateCommitmentDisplay {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub account: Pubkey,
+    pub slot: u64,
+    pub merkle_root: String,
+    pub account_count: u32,
+    pub recorded_by: LabeledPubkey,
+    pub recorded_at_slot: u64,
+}
+
+impl StateCommitmentDisplay {
+    fn new(account: Pubkey, state_commitment: StateCommitment, book: &AddressBook) -> Self {
+        StateCommitmentDisplay {
+            account,
+            slot: state_commitment.slot,
+            merkle_root: Hash::new_from_array(state_commitment.merkle_root).to_string(),
+            account_count: state_commitment.account_count,
+            recorded_by: book.labeled(&state_commitment.recorded_by),
+            recorded_at_slot: state_commitment.recorded_at_slot,
+        }
+    }
+}
+
+impl GetStateCommitmentCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let book = client.get_address_book();
+        let (pubkey, state_commitment) = client.get_state_commitment(GetStateCommitmentCommand)?;
+
+        render_record(
+            out,
+            &StateCommitmentDisplay::new(pubkey, state_commitment, &book),
+            OutputFormat::from_flags(self.json, false),
+        )
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get;
+pub mod record;
+pub mod verify;
@@ -0,0 +1,105 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::{print_signature, require, CliContext, RequirementCheck};
+use doublezero_sdk::commands::state_commitment::{
+    record::RecordStateCommitmentCommand, snapshot::SnapshotStateCommand,
+};
+use solana_sdk::hash::Hash;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct RecordStateCommitmentCliCommand {}
+
+impl RecordStateCommitmentCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        require!(
+            client,
+            RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
+        );
+
+        let slot = client.get_slot()?;
+        let snapshot = client.snapshot_state(SnapshotStateCommand { slot })?;
+        if snapshot.leaves.is_empty() {
+            return Err(eyre::eyre!("no accounts to commit to"));
+        }
+        let merkle_root = snapshot.merkle_root();
+
+        let (signature, _pubkey) =
+            client.record_state_commitment(RecordStateCommitmentCommand {
+                slot,
+                merkle_root,
+                account_count: snapshot.leaves.len() as u32,
+            })?;
+
+        writeln!(
+            out,
+            "Recorded merkle root {} over {} accounts at slot {}",
+            Hash::new_from_array(merkle_root),
+            snapshot.leaves.len(),
+            slot
+        )?;
+        print_signature(out, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+        state_commitment::record::RecordStateCommitmentCliCommand,
+        tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::commands::state_commitment::{
+        record::RecordStateCommitmentCommand,
+        snapshot::{SnapshotStateCommand, StateSnapshot},
+    };
+    use mockall::predicate;
+    use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_cli_state_commitment_record() {
+        let mut client = create_test_client();
+
+        let snapshot = StateSnapshot {
+            slot: 1_000,
+            leaves: vec![(Pubkey::new_unique(), [3u8; 32])],
+        };
+        let merkle_root = snapshot.merkle_root();
+        let signature = Signature::new_unique();
+
+        client
+            .expect_check_requirements()
+            .with(predicate::eq(CHECK_ID_JSON | CHECK_BALANCE))
+            .returning(|_| Ok(()));
+        client.expect_get_slot().returning(|| Ok(1_000));
+        client
+            .expect_snapshot_state()
+            .with(predicate::eq(SnapshotStateCommand { slot: 1_000 }))
+            .returning(move |_| Ok(snapshot.clone()));
+        client
+            .expect_record_state_commitment()
+            .with(predicate::eq(RecordStateCommitmentCommand {
+                slot: 1_000,
+                merkle_root,
+                account_count: 1,
+            }))
+            .returning(move |_| Ok((signature, Pubkey::new_unique())));
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(RecordStateCommitmentCliCommand {}.execute(&ctx, &client, &mut output)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "Recorded merkle root {} over 1 accounts at slot 1000\nSignature: {signature}\n",
+                Hash::new_from_array(merkle_root)
+            )
+        );
+    }
+}
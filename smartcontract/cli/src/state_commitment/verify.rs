@@ -0,0 +1,163 @@
+use crate::doublezerocommand::CliCommand;
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::state_commitment::{
+    get::GetStateCommitmentCommand, snapshot::SnapshotStateCommand,
+};
+use doublezero_serviceability::state::state_commitment::verify_proof;
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct VerifyStateCommitmentCliCommand {
+    /// Print the merkle proof of this account and check it against the recorded root
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+}
+
+impl VerifyStateCommitmentCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let (_, commitment) = client.get_state_commitment(GetStateCommitmentCommand)?;
+        let snapshot = client.snapshot_state(SnapshotStateCommand {
+            slot: commitment.slot,
+        })?;
+
+        let recorded = Hash::new_from_array(commitment.merkle_root);
+        let computed = snapshot.merkle_root();
+        if computed != commitment.merkle_root {
+            return Err(eyre::eyre!(
+                "recorded root {recorded} ({} accounts, slot {}) does not match the current state ({} over {} accounts); accounts changed after the snapshot",
+                commitment.account_count,
+                commitment.slot,
+                Hash::new_from_array(computed),
+                snapshot.leaves.len()
+            ));
+        }
+        writeln!(
+            out,
+            "Recorded root {recorded} matches the current state ({} accounts, slot {})",
+            commitment.account_count, commitment.slot
+        )?;
+
+        let Some(account) = self.account else {
+            return Ok(());
+        };
+        let (index, proof) = snapshot
+            .proof(&account)
+            .ok_or_else(|| eyre::eyre!("account {account} is not part of the snapshot"))?;
+        let leaf = snapshot.leaves[index].1;
+
+        writeln!(out, "Account: {account}")?;
+        writeln!(out, "Index: {index}")?;
+        writeln!(out, "Leaf: {}", Hash::new_from_array(leaf))?;
+        writeln!(out, "Proof:")?;
+        for sibling in &proof {
+            writeln!(out, "  {}", Hash::new_from_array(*sibling))?;
+        }
+        if !verify_proof(
+            &commitment.merkle_root,
+            &leaf,
+            index,
+            commitment.account_count as usize,
+            &proof,
+        ) {
+            return Err(eyre::eyre!("proof of {account} does not verify"));
+        }
+        writeln!(out, "Proof verified")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        state_commitment::verify::VerifyStateCommitmentCliCommand, tests::utils::create_test_client,
+    };
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::{
+        commands::state_commitment::{
+            get::GetStateCommitmentCommand,
+            snapshot::{SnapshotStateCommand, StateSnapshot},
+        },
+        AccountType, StateCommitment,
+    };
+    use doublezero_serviceability::state::state_commitment::leaf_hash;
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn snapshot(accounts: &[Pubkey]) -> StateSnapshot {
+        let mut leaves: Vec<(Pubkey, [u8; 32])> = accounts
+            .iter()
+            .map(|pk| (*pk, leaf_hash(pk, &[AccountType::Device as u8])))
+            .collect();
+        leaves.sort_by_key(|(pk, _)| *pk);
+        StateSnapshot { slot: 900, leaves }
+    }
+
+    fn mock_client(
+        recorded: &StateSnapshot,
+        current: StateSnapshot,
+    ) -> impl crate::doublezerocommand::CliCommand {
+        let mut client = create_test_client();
+        let commitment = StateCommitment {
+            account_type: AccountType::StateCommitment,
+            slot: 900,
+            merkle_root: recorded.merkle_root(),
+            account_count: recorded.leaves.len() as u32,
+            ..Default::default()
+        };
+        client
+            .expect_get_state_commitment()
+            .with(predicate::eq(GetStateCommitmentCommand))
+            .returning(move |_| Ok((Pubkey::new_unique(), commitment.clone())));
+        client
+            .expect_snapshot_state()
+            .with(predicate::eq(SnapshotStateCommand { slot: 900 }))
+            .returning(move |_| Ok(current.clone()));
+        client
+    }
+
+    #[test]
+    fn test_cli_state_commitment_verify_account_proof() {
+        let accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let recorded = snapshot(&accounts);
+        let client = mock_client(&recorded, recorded.clone());
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        block_on(
+            VerifyStateCommitmentCliCommand {
+                account: Some(accounts[3]),
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("matches the current state (5 accounts, slot 900)"));
+        assert!(output.contains(&format!("Account: {}", accounts[3])));
+        assert!(output.ends_with("Proof verified\n"));
+    }
+
+    #[test]
+    fn test_cli_state_commitment_verify_detects_changed_state() {
+        let accounts: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let recorded = snapshot(&accounts);
+        let current = snapshot(&accounts[..2]);
+        let client = mock_client(&recorded, current);
+
+        let ctx = cli_context_default_for_tests();
+        let mut output = Vec::new();
+        let err = block_on(VerifyStateCommitmentCliCommand { account: None }.execute(
+            &ctx,
+            &client,
+            &mut output,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("does not match the current state"));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::telemetry::budget::SetProviderSamplingBudgetCommand;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct SetProviderSamplingBudgetCliCommand {
+    /// Oracle agent writing the data provider's samples
+    #[arg(long)]
+    pub oracle_agent: Pubkey,
+    /// Data provider name, as used in the samples accounts
+    #[arg(long)]
+    pub data_provider: String,
+    /// Maximum samples per epoch and exchange pair
+    #[arg(long)]
+    pub max_samples_per_epoch: u32,
+}
+
+impl SetProviderSamplingBudgetCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let env = client.get_environment();
+        let config = env.config()?;
+
+        let (signature, pubkey) =
+            client.set_provider_sampling_budget(SetProviderSamplingBudgetCommand {
+                telemetry_program_id: config.telemetry_program_id,
+                oracle_agent_pk: self.oracle_agent,
+                data_provider_name: self.data_provider,
+                max_samples_per_epoch: self.max_samples_per_epoch,
+            })?;
+        writeln!(out, "Set sampling budget {pubkey}: {signature}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doublezerocommand::MockCliCommand;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_config::Environment;
+    use mockall::predicate::eq;
+    use solana_sdk::signature::Signature;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_cli_telemetry_set_budget() {
+        let mut client = MockCliCommand::new();
+
+        let env = Environment::Devnet;
+        let telemetry_program_id = env.config().unwrap().telemetry_program_id;
+        let oracle_agent = Pubkey::new_unique();
+        let budget_pk = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client.expect_check_requirements().returning(|_| Ok(()));
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
+        client
+            .expect_set_provider_sampling_budget()
+            .with(eq(SetProviderSamplingBudgetCommand {
+                telemetry_program_id,
+                oracle_agent_pk: oracle_agent,
+                data_provider_name: "ripeatlas".to_string(),
+                max_samples_per_epoch: 1_440,
+            }))
+            .returning(move |_| Ok((signature, budget_pk)));
+
+        let cmd = SetProviderSamplingBudgetCliCommand {
+            oracle_agent,
+            data_provider: "ripeatlas".to_string(),
+            max_samples_per_epoch: 1_440,
+        };
+        let ctx = cli_context_default_for_tests();
+        let mut out = Cursor::new(Vec::new());
+        block_on(cmd.execute(&ctx, &client, &mut out)).unwrap();
+
+        let output = String::from_utf8(out.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            format!("Set sampling budget {budget_pk}: {signature}\n")
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::telemetry::prune::PruneTelemetrySamplesCommand;
+use doublezero_telemetry::processors::telemetry::close_samples_account::MIN_SAMPLES_RETENTION_EPOCHS;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct PruneTelemetryCliCommand {
+    /// Close samples accounts from epochs strictly before this one
+    #[arg(long)]
+    pub epoch_before: u64,
+    /// Number of epochs samples are retained; the program enforces a minimum
+    #[arg(long, default_value_t = MIN_SAMPLES_RETENTION_EPOCHS)]
+    pub retention_epochs: u64,
+    /// Account receiving the refunded rent (defaults to the payer)
+    #[arg(long)]
+    pub treasury: Option<Pubkey>,
+}
+
+impl PruneTelemetryCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let env = client.get_environment();
+        let config = env.config()?;
+
+        let closed = client.prune_telemetry_samples(PruneTelemetrySamplesCommand {
+            telemetry_program_id: config.telemetry_program_id,
+            epoch_before: self.epoch_before,
+            retention_epochs: self.retention_epochs,
+            treasury: self.treasury,
+        })?;
+
+        if closed.is_empty() {
+            writeln!(out, "No samples accounts to prune")?;
+        }
+        for (pubkey, signature) in closed {
+            writeln!(out, "Closed {pubkey}: {signature}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doublezerocommand::MockCliCommand;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_config::Environment;
+    use mockall::predicate::eq;
+    use solana_sdk::signature::Signature;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_cli_telemetry_prune() {
+        let mut client = MockCliCommand::new();
+
+        let env = Environment::Devnet;
+        let telemetry_program_id = env.config().unwrap().telemetry_program_id;
+        let samples_pk = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        client.expect_check_requirements().returning(|_| Ok(()));
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
+        client
+            .expect_prune_telemetry_samples()
+            .with(eq(PruneTelemetrySamplesCommand {
+                telemetry_program_id,
+                epoch_before: 42,
+                retention_epochs: MIN_SAMPLES_RETENTION_EPOCHS,
+                treasury: None,
+            }))
+            .returning(move |_| Ok(vec![(samples_pk, signature)]));
+
+        let cmd = PruneTelemetryCliCommand {
+            epoch_before: 42,
+            retention_epochs: MIN_SAMPLES_RETENTION_EPOCHS,
+            treasury: None,
+        };
+        let ctx = cli_context_default_for_tests();
+        let mut out = Cursor::new(Vec::new());
+        block_on(cmd.execute(&ctx, &client, &mut out)).unwrap();
+
+        let output = String::from_utf8(out.into_inner()).unwrap();
+        assert_eq!(output, format!("Closed {samples_pk}: {signature}\n"));
+    }
+}
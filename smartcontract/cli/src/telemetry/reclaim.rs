@@ -0,0 +1,121 @@
+use crate::{
+    doublezerocommand::CliCommand,
+    requirements::{CHECK_BALANCE, CHECK_ID_JSON},
+};
+use clap::Args;
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::commands::telemetry::reclaim::{ReclaimAction, ReclaimTelemetrySamplesCommand};
+use doublezero_telemetry::processors::telemetry::close_samples_account::MIN_SAMPLES_RETENTION_EPOCHS;
+use solana_sdk::pubkey::Pubkey;
+use std::io::Write;
+
+#[derive(Args, Debug)]
+pub struct ReclaimTelemetryCliCommand {
+    /// Only reclaim samples accounts from epochs strictly before this one
+    #[arg(long)]
+    pub before_epoch: u64,
+    /// Number of epochs samples are retained before being closed
+    #[arg(long, default_value_t = MIN_SAMPLES_RETENTION_EPOCHS)]
+    pub retention_epochs: u64,
+    /// Account receiving the rent of closed accounts (defaults to the payer)
+    #[arg(long)]
+    pub treasury: Option<Pubkey>,
+    /// Shrink finalized accounts and refund the surplus rent to their agent
+    #[arg(long, default_value_t = false)]
+    pub compact: bool,
+}
+
+impl ReclaimTelemetryCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        // Check requirements
+        client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
+
+        let env = client.get_environment();
+        let config = env.config()?;
+
+        let reclaimed = client.reclaim_telemetry_samples(ReclaimTelemetrySamplesCommand {
+            telemetry_program_id: config.telemetry_program_id,
+            before_epoch: self.before_epoch,
+            retention_epochs: self.retention_epochs,
+            treasury: self.treasury,
+            compact: self.compact,
+        })?;
+
+        if reclaimed.is_empty() {
+            writeln!(out, "No samples accounts to reclaim")?;
+        }
+        for (pubkey, action, signature) in reclaimed {
+            let action = match action {
+                ReclaimAction::Finalized => "Finalized",
+                ReclaimAction::Closed => "Closed",
+            };
+            writeln!(out, "{action} {pubkey}: {signature}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doublezerocommand::MockCliCommand;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_config::Environment;
+    use mockall::predicate::eq;
+    use solana_sdk::signature::Signature;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_cli_telemetry_reclaim() {
+        let mut client = MockCliCommand::new();
+
+        let env = Environment::Devnet;
+        let telemetry_program_id = env.config().unwrap().telemetry_program_id;
+        let finalized_pk = Pubkey::new_unique();
+        let closed_pk = Pubkey::new_unique();
+        let signature1 = Signature::new_unique();
+        let signature2 = Signature::new_unique();
+
+        client.expect_check_requirements().returning(|_| Ok(()));
+        client
+            .expect_get_environment()
+            .returning(move || env.clone());
+        client
+            .expect_reclaim_telemetry_samples()
+            .with(eq(ReclaimTelemetrySamplesCommand {
+                telemetry_program_id,
+                before_epoch: 42,
+                retention_epochs: MIN_SAMPLES_RETENTION_EPOCHS,
+                treasury: None,
+                compact: true,
+            }))
+            .returning(move |_| {
+                Ok(vec![
+                    (closed_pk, ReclaimAction::Closed, signature1),
+                    (finalized_pk, ReclaimAction::Finalized, signature2),
+                ])
+            });
+
+        let cmd = ReclaimTelemetryCliCommand {
+            before_epoch: 42,
+            retention_epochs: MIN_SAMPLES_RETENTION_EPOCHS,
+            treasury: None,
+            compact: true,
+        };
+        let ctx = cli_context_default_for_tests();
+        let mut out = Cursor::new(Vec::new());
+        block_on(cmd.execute(&ctx, &client, &mut out)).unwrap();
+
+        let output = String::from_utf8(out.into_inner()).unwrap();
+        assert_eq!(
+            output,
+            format!("Closed {closed_pk}: {signature1}\nFinalized {finalized_pk}: {signature2}\n")
+        );
+    }
+}
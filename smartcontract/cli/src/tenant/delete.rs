@@ -1,10 +1,16 @@
-use crate::{doublezerocommand::CliCommand, validators::validate_pubkey_or_code};
+use crate::{
+    cascade::print_cascade_preview, doublezerocommand::CliCommand, helpers::resolve_tenant_pk,
+    validators::validate_pubkey_or_code,
+};
 use clap::Args;
 use doublezero_cli_core::{require, CliContext, RequirementCheck};
-use doublezero_sdk::commands::{
-    accesspass::{list::ListAccessPassCommand, set::SetAccessPassCommand},
-    tenant::{delete::DeleteTenantCommand, get::GetTenantCommand},
-    user::{delete::DeleteUserCommand, list::ListUserCommand},
+use doublezero_sdk::{
+    cascade::DeleteTarget,
+    commands::{
+        accesspass::{list::ListAccessPassCommand, set::SetAccessPassCommand},
+        tenant::{delete::DeleteTenantCommand, get::GetTenantCommand},
+        user::{delete::DeleteUserCommand, list::ListUserCommand},
+    },
 };
 use indicatif::{ProgressBar, ProgressStyle};
 use solana_sdk::pubkey::Pubkey;
@@ -19,6 +25,10 @@ pub struct DeleteTenantCliCommand {
     /// Delete all users in the tenant and close related access passes before deleting
     #[arg(long, default_value_t = false)]
     pub allow_delete_users: bool,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteTenantCliCommand {
@@ -28,6 +38,11 @@ impl DeleteTenantCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let pubkey = resolve_tenant_pk(client, &self.pubkey)?;
+            return print_cascade_preview(client, DeleteTarget::Tenant(pubkey), out);
+        }
+
         require!(
             client,
             RequirementCheck::KEYPAIR | RequirementCheck::BALANCE
@@ -113,6 +128,8 @@ impl DeleteTenantCliCommand {
                         tenant: Pubkey::default(),
                         max_unicast_users: ap.max_unicast_users,
                         max_multicast_users: ap.max_multicast_users,
+                        bandwidth_tier: None,
+                        referrer: None,
                     })?;
                     spinner.inc(1);
                 }
@@ -198,6 +215,8 @@ impl DeleteTenantCliCommand {
                         tenant: Pubkey::default(),
                         max_unicast_users: ap.max_unicast_users,
                         max_multicast_users: ap.max_multicast_users,
+                        bandwidth_tier: None,
+                        referrer: None,
                     })?;
                     spinner.inc(1);
                 }
@@ -298,6 +317,7 @@ mod tests {
         let res = block_on(
             DeleteTenantCliCommand {
                 pubkey: tenant_pubkey.to_string(),
+                cascade_preview: false,
                 allow_delete_users: false,
             }
             .execute(&ctx, &client, &mut output),
@@ -346,6 +366,7 @@ mod tests {
         let res = block_on(
             DeleteTenantCliCommand {
                 pubkey: tenant_pubkey.to_string(),
+                cascade_preview: false,
                 allow_delete_users: false,
             }
             .execute(&ctx, &client, &mut output),
@@ -406,6 +427,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let tenant_after = Tenant {
@@ -484,6 +506,7 @@ mod tests {
         let res = block_on(
             DeleteTenantCliCommand {
                 pubkey: tenant_pubkey.to_string(),
+                cascade_preview: false,
                 allow_delete_users: true,
             }
             .execute(&ctx, &client, &mut output),
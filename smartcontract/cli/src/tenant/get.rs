@@ -5,7 +5,7 @@ use crate::{
 use clap::Args;
 use doublezero_cli_core::{render_record, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::tenant::get::GetTenantCommand;
+use doublezero_sdk::{commands::tenant::get::GetTenantCommand, labels::LabeledPubkey};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use std::io::Write;
@@ -22,7 +22,7 @@ pub struct GetTenantCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct TenantDisplay {
+pub(crate) struct TenantDisplay {
     #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
     pub account: Pubkey,
     pub code: String,
@@ -35,8 +35,7 @@ struct TenantDisplay {
     pub token_account: String,
     pub reference_count: u32,
     pub include_topologies: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl GetTenantCliCommand {
@@ -46,6 +45,7 @@ impl GetTenantCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let (pubkey, tenant) = client.get_tenant(GetTenantCommand {
             pubkey_or_code: self.code,
         })?;
@@ -65,13 +65,13 @@ impl GetTenantCliCommand {
             administrators: tenant
                 .administrators
                 .iter()
-                .map(|pk| pk.to_string())
+                .map(|pk| book.display(pk))
                 .collect::<Vec<_>>()
                 .join(", "),
             token_account: tenant.token_account.to_string(),
             reference_count: tenant.reference_count,
             include_topologies: resolve_topology_names(&tenant.include_topologies, &topology_map),
-            owner: tenant.owner,
+            owner: book.labeled(&tenant.owner),
         };
 
         render_record(out, &display, OutputFormat::from_flags(self.json, false))
@@ -80,9 +80,14 @@ impl GetTenantCliCommand {
 
 #[cfg(test)]
 mod tests {
-    use crate::{tenant::get::GetTenantCliCommand, tests::utils::create_test_client};
+    use crate::{
+        doublezerocommand::MockCliCommand, tenant::get::GetTenantCliCommand,
+        tests::utils::create_test_client,
+    };
     use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
-    use doublezero_sdk::{commands::tenant::get::GetTenantCommand, AccountType};
+    use doublezero_sdk::{
+        commands::tenant::get::GetTenantCommand, labels::AddressBook, AccountType,
+    };
     use doublezero_serviceability::state::tenant::{
         Tenant, TenantBillingConfig, TenantPaymentStatus,
     };
@@ -199,4 +204,75 @@ mod tests {
         assert_eq!(json["reference_count"].as_u64().unwrap(), 0);
         assert_eq!(json["owner"].as_str().unwrap(), tenant_pubkey.to_string());
     }
+
+    #[test]
+    fn test_cli_tenant_get_labels_owner() {
+        let mut client = MockCliCommand::new();
+
+        let tenant_pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let tenant = Tenant {
+            account_type: AccountType::Tenant,
+            owner,
+            bump_seed: 0,
+            code: "test-tenant".to_string(),
+            vrf_id: 100,
+            reference_count: 0,
+            administrators: vec![],
+            token_account: Pubkey::default(),
+            payment_status: TenantPaymentStatus::Paid,
+            metro_routing: true,
+            route_liveness: false,
+            billing: TenantBillingConfig::default(),
+            include_topologies: vec![],
+        };
+
+        client
+            .expect_get_tenant()
+            .returning(move |_| Ok((tenant_pubkey, tenant.clone())));
+        client
+            .expect_list_topology()
+            .returning(|_| Ok(HashMap::new()));
+        client.expect_get_address_book().returning(move || {
+            AddressBook::from_petnames(&HashMap::from([(
+                owner.to_string(),
+                "treasury".to_string(),
+            )]))
+        });
+
+        let ctx = cli_context_default_for_tests();
+
+        let mut output = Vec::new();
+        block_on(
+            GetTenantCliCommand {
+                code: "test-tenant".to_string(),
+                json: false,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        let output_str = String::from_utf8(output).unwrap();
+        let owner_str = owner.to_string();
+        assert!(
+            output_str
+                .lines()
+                .any(|l| l.contains("owner")
+                    && l.contains(&format!("treasury ({}…)", &owner_str[..4]))),
+            "owner row should show the petname: {output_str}"
+        );
+
+        // JSON keeps the bare pubkey.
+        let mut output = Vec::new();
+        block_on(
+            GetTenantCliCommand {
+                code: "test-tenant".to_string(),
+                json: true,
+            }
+            .execute(&ctx, &client, &mut output),
+        )
+        .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(output).unwrap()).unwrap();
+        assert_eq!(json["owner"].as_str().unwrap(), owner_str);
+    }
 }
@@ -2,8 +2,9 @@ use crate::{doublezerocommand::CliCommand, topology::resolve_topology_names};
 use clap::Args;
 use doublezero_cli_core::{render_collection, CliContext, OutputFormat};
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::{
-    tenant::list::ListTenantCommand, topology::list::ListTopologyCommand,
+use doublezero_sdk::{
+    commands::{tenant::list::ListTenantCommand, topology::list::ListTopologyCommand},
+    labels::LabeledPubkey,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -29,8 +30,7 @@ pub struct TenantDisplay {
     pub metro_routing: bool,
     pub route_liveness: bool,
     pub include_topologies: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 impl ListTenantCliCommand {
@@ -40,6 +40,7 @@ impl ListTenantCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let tenants = client.list_tenant(ListTenantCommand {})?;
         let topology_map = client
             .list_topology(ListTopologyCommand)
@@ -57,7 +58,7 @@ impl ListTenantCliCommand {
                     &tenant.include_topologies,
                     &topology_map,
                 ),
-                owner: tenant.owner,
+                owner: book.labeled(&tenant.owner),
             })
             .collect();
 
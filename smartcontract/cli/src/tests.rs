@@ -1,6 +1,7 @@
 pub mod utils {
     use doublezero_cli_core::{CliContext, CliContextBuilder};
     use doublezero_config::Environment;
+    use doublezero_sdk::labels::AddressBook;
     use solana_sdk::pubkey::Pubkey;
 
     use crate::doublezerocommand::MockCliCommand;
@@ -23,6 +24,9 @@ pub mod utils {
         client.expect_get_balance().returning(|| Ok(10));
         client.expect_get_epoch().returning(|| Ok(10));
         client.expect_get_program_id().returning(move || program_id);
+        client
+            .expect_get_address_book()
+            .returning(AddressBook::default);
         // Pre-flight checks call has_keypair_source() to decide whether to skip
         // the default-path keypair file check. Tests provide the keypair via the
         // mock, so report a source as present.
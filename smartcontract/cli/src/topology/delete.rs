@@ -128,6 +128,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: vec![topology_pda],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         client.expect_list_link().returning(move |_| {
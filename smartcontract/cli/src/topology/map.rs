@@ -0,0 +1,412 @@
+use crate::doublezerocommand::CliCommand;
+use clap::{Args, ValueEnum};
+use doublezero_cli_core::CliContext;
+use doublezero_sdk::{
+    commands::{
+        device::list::ListDeviceCommand, exchange::list::ListExchangeCommand,
+        link::list::ListLinkCommand, location::list::ListLocationCommand,
+    },
+    topology::{TopologySnapshot, WeightConfig},
+    Device, DeviceStatus, Link, LinkStatus,
+};
+use doublezero_serviceability::state::{device::DeviceHealth, link::LinkHealth};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashSet, io::Write, path::PathBuf};
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MapFormat {
+    /// GraphViz DOT, e.g. `| dot -Tsvg > network.svg`
+    #[default]
+    Dot,
+    /// D3 force-graph JSON (`nodes` and `links` arrays)
+    D3,
+}
+
+#[derive(Args, Debug)]
+pub struct MapTopologyCliCommand {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = MapFormat::Dot)]
+    pub format: MapFormat,
+    /// Only include devices and links operated by this contributor
+    #[arg(long)]
+    pub contributor: Option<Pubkey>,
+    /// Write the map to this file instead of stdout
+    #[arg(short, long)]
+    pub outfile: Option<PathBuf>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct MapNode {
+    pub id: String,
+    pub code: String,
+    pub status: String,
+    pub health: String,
+    pub exchange: String,
+    pub location: String,
+    pub contributor: String,
+    /// Whether losing this device would partition the network.
+    pub single_point_of_failure: bool,
+    pub color: &'static str,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct MapLink {
+    pub id: String,
+    pub code: String,
+    pub source: String,
+    pub target: String,
+    pub delay_ns: u64,
+    pub bandwidth: u64,
+    /// Routing weight in microseconds, absent for links that carry no traffic.
+    pub weight: Option<u64>,
+    pub status: String,
+    pub health: String,
+    /// Whether losing this link would partition the network.
+    pub single_point_of_failure: bool,
+    pub color: &'static str,
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct NetworkMap {
+    pub nodes: Vec<MapNode>,
+    pub links: Vec<MapLink>,
+}
+
+impl MapTopologyCliCommand {
+    pub async fn execute<C: CliCommand, W: Write>(
+        self,
+        _ctx: &CliContext,
+        client: &C,
+        out: &mut W,
+    ) -> eyre::Result<()> {
+        let snapshot = TopologySnapshot {
+            devices: client.list_device(ListDeviceCommand)?,
+            links: client.list_link(ListLinkCommand)?,
+            exchanges: client.list_exchange(ListExchangeCommand)?,
+            locations: client.list_location(ListLocationCommand)?,
+        };
+        let map = build_map(&snapshot, self.contributor.as_ref());
+
+        let rendered = match self.format {
+            MapFormat::Dot => render_dot(&map),
+            MapFormat::D3 => serde_json::to_string_pretty(&map)? + "\n",
+        };
+        match self.outfile {
+            Some(path) => {
+                std::fs::write(&path, rendered)?;
+                writeln!(
+                    out,
+                    "Wrote {} devices and {} links to {}",
+                    map.nodes.len(),
+                    map.links.len(),
+                    path.display()
+                )?;
+            }
+            None => write!(out, "{rendered}")?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Build the map of every device and link, or only those operated by
+/// `contributor`. Nodes and links are sorted by code.
+pub fn build_map(snapshot: &TopologySnapshot, contributor: Option<&Pubkey>) -> NetworkMap {
+    let config = WeightConfig::default();
+    let graph = match contributor {
+        Some(contributor_pk) => snapshot.contributor_graph(contributor_pk, &config),
+        None => snapshot.graph(&config),
+    };
+    let cut_devices: HashSet<Pubkey> = graph.articulation_points().into_iter().collect();
+    let cut_links: HashSet<Pubkey> = graph.single_link_failures().into_iter().collect();
+
+    let owned = |contributor_pk: &Pubkey| contributor.is_none_or(|pk| pk == contributor_pk);
+
+    let mut nodes: Vec<MapNode> = snapshot
+        .devices
+        .iter()
+        .filter(|(_, device)| owned(&device.contributor_pk))
+        .map(|(pk, device)| MapNode {
+            id: pk.to_string(),
+            code: device.code.clone(),
+            status: device.status.to_string(),
+            health: device.device_health.to_string(),
+            exchange: snapshot
+                .exchange_of(pk)
+                .map(|e| e.code.clone())
+                .unwrap_or_default(),
+            location: snapshot
+                .location_of(pk)
+                .map(|l| l.code.clone())
+                .unwrap_or_default(),
+            contributor: device.contributor_pk.to_string(),
+            single_point_of_failure: cut_devices.contains(pk),
+            color: device_color(device),
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.code.cmp(&b.code).then_with(|| a.id.cmp(&b.id)));
+
+    let node_ids: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+    let mut links: Vec<MapLink> = snapshot
+        .links
+        .iter()
+        .filter(|(_, link)| owned(&link.contributor_pk))
+        .filter(|(_, link)| {
+            node_ids.contains(link.side_a_pk.to_string().as_str())
+                && node_ids.contains(link.side_z_pk.to_string().as_str())
+        })
+        .map(|(pk, link)| MapLink {
+            id: pk.to_string(),
+            code: link.code.clone(),
+            source: link.side_a_pk.to_string(),
+            target: link.side_z_pk.to_string(),
+            delay_ns: link.delay_ns,
+            bandwidth: link.bandwidth,
+            weight: config.link_weight(link),
+            status: link.status.to_string(),
+            health: link.link_health.to_string(),
+            single_point_of_failure: cut_links.contains(pk),
+            color: link_color(link),
+        })
+        .collect();
+    links.sort_by(|a, b| a.code.cmp(&b.code).then_with(|| a.id.cmp(&b.id)));
+
+    NetworkMap { nodes, links }
+}
+
+fn device_color(device: &Device) -> &'static str {
+    match (device.status, device.device_health) {
+        (DeviceStatus::Activated, DeviceHealth::Impaired | DeviceHealth::DriftDetected) => "orange",
+        (DeviceStatus::Activated, DeviceHealth::ReadyForUsers) => "green",
+        (DeviceStatus::Activated, _) => "yellowgreen",
+        (DeviceStatus::Drained, _) => "gray",
+        (DeviceStatus::Deleting, _) => "red",
+        _ => "lightblue",
+    }
+}
+
+fn link_color(link: &Link) -> &'static str {
+    match (link.status, link.link_health) {
+        (LinkStatus::Activated, LinkHealth::Impaired) => "orange",
+        (LinkStatus::Activated, LinkHealth::ReadyForService) => "green",
+        (LinkStatus::Activated, _) => "yellowgreen",
+        (LinkStatus::SoftDrained, _) => "gold",
+        (LinkStatus::HardDrained, _) => "gray",
+        (LinkStatus::Deleting, _) => "red",
+        _ => "lightblue",
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Render the map as an undirected GraphViz graph. Links are labelled with
+/// their delay and bandwidth; single points of failure are drawn bold.
+pub fn render_dot(map: &NetworkMap) -> String {
+    let mut dot = String::from("graph doublezero {\n");
+    dot.push_str("  node [shape=box, style=filled];\n");
+    for node in &map.nodes {
+        let style = if node.single_point_of_failure {
+            ", penwidth=3"
+        } else {
+            ""
+        };
+        let label = if node.exchange.is_empty() {
+            dot_escape(&node.code)
+        } else {
+            format!(
+                "{} ({})",
+                dot_escape(&node.code),
+                dot_escape(&node.exchange)
+            )
+        };
+        dot.push_str(&format!(
+            "  \"{}\" [label=\"{}\\n{}\", fillcolor=\"{}\"{}];\n",
+            node.id, label, node.status, node.color, style
+        ));
+    }
+    for link in &map.links {
+        let style = if link.single_point_of_failure {
+            ", style=bold"
+        } else {
+            ""
+        };
+        dot.push_str(&format!(
+            "  \"{}\" -- \"{}\" [label=\"{}\\n{:.2}ms {}Gbps\", color=\"{}\"{}];\n",
+            link.source,
+            link.target,
+            dot_escape(&link.code),
+            link.delay_ns as f64 / 1_000_000.0,
+            link.bandwidth / 1_000_000_000,
+            link.color,
+            style
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doublezerocommand::MockCliCommand;
+    use doublezero_cli_core::testing::{block_on, cli_context_default_for_tests};
+    use doublezero_sdk::AccountType;
+    use std::{collections::HashMap, io::Cursor};
+
+    fn device(code: &str, contributor_pk: Pubkey) -> Device {
+        Device {
+            account_type: AccountType::Device,
+            code: code.to_string(),
+            contributor_pk,
+            status: DeviceStatus::Activated,
+            device_health: DeviceHealth::ReadyForUsers,
+            ..Default::default()
+        }
+    }
+
+    fn link(code: &str, side_a_pk: Pubkey, side_z_pk: Pubkey, contributor_pk: Pubkey) -> Link {
+        Link {
+            account_type: AccountType::Link,
+            code: code.to_string(),
+            contributor_pk,
+            side_a_pk,
+            side_z_pk,
+            bandwidth: 10_000_000_000,
+            delay_ns: 1_500_000,
+            status: LinkStatus::Activated,
+            link_health: LinkHealth::ReadyForService,
+            ..Default::default()
+        }
+    }
+
+    /// la1 -- ny1 -- ld1, with ld1 operated by another contributor and its
+    /// link soft-drained.
+    fn snapshot() -> (TopologySnapshot, [Pubkey; 3], Pubkey) {
+        let contributor = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let [la, ny, ld] = std::array::from_fn(|_| Pubkey::new_unique());
+
+        let mut ny_ld = link("ny1:ld1", ny, ld, other);
+        ny_ld.status = LinkStatus::SoftDrained;
+        let snapshot = TopologySnapshot {
+            devices: HashMap::from([
+                (la, device("la1", contributor)),
+                (ny, device("ny1", contributor)),
+                (ld, device("ld1", other)),
+            ]),
+            links: HashMap::from([
+                (Pubkey::new_unique(), link("la1:ny1", la, ny, contributor)),
+                (Pubkey::new_unique(), ny_ld),
+            ]),
+            ..Default::default()
+        };
+        (snapshot, [la, ny, ld], contributor)
+    }
+
+    #[test]
+    fn test_build_map() {
+        let (snapshot, [_, ny, ld], contributor) = snapshot();
+
+        let map = build_map(&snapshot, None);
+        let codes: Vec<&str> = map.nodes.iter().map(|n| n.code.as_str()).collect();
+        assert_eq!(codes, vec!["la1", "ld1", "ny1"]);
+        assert_eq!(map.links.len(), 2);
+        assert!(map.nodes.iter().all(|n| n.color == "green"));
+        // ny1 joins the two other devices.
+        let cut: Vec<&str> = map
+            .nodes
+            .iter()
+            .filter(|n| n.single_point_of_failure)
+            .map(|n| n.code.as_str())
+            .collect();
+        assert_eq!(cut, vec!["ny1"]);
+        assert!(map.links.iter().all(|l| l.single_point_of_failure));
+        assert_eq!(map.links[1].color, "gold");
+        assert_eq!(map.links[1].weight, Some(1_000_000));
+        assert_eq!(map.links[0].weight, Some(1_500));
+
+        let map = build_map(&snapshot, Some(&contributor));
+        assert_eq!(map.nodes.len(), 2);
+        assert!(!map.nodes.iter().any(|n| n.id == ld.to_string()));
+        assert_eq!(map.links.len(), 1);
+        assert_eq!(map.links[0].target, ny.to_string());
+    }
+
+    #[test]
+    fn test_cli_topology_map_dot() {
+        let (snapshot, [la, ny, _], contributor) = snapshot();
+        let mut client = MockCliCommand::new();
+        let devices = snapshot.devices.clone();
+        let links = snapshot.links.clone();
+        client
+            .expect_list_device()
+            .returning(move |_| Ok(devices.clone()));
+        client
+            .expect_list_link()
+            .returning(move |_| Ok(links.clone()));
+        client
+            .expect_list_exchange()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_location()
+            .returning(|_| Ok(HashMap::new()));
+
+        let cmd = MapTopologyCliCommand {
+            format: MapFormat::Dot,
+            contributor: Some(contributor),
+            outfile: None,
+        };
+        let ctx = cli_context_default_for_tests();
+        let mut out = Cursor::new(Vec::new());
+        block_on(cmd.execute(&ctx, &client, &mut out)).unwrap();
+
+        let output = String::from_utf8(out.into_inner()).unwrap();
+        assert!(output.starts_with("graph doublezero {\n"));
+        assert!(output.contains(&format!(
+            "  \"{la}\" [label=\"la1\\nactivated\", fillcolor=\"green\"];\n"
+        )));
+        assert!(output.contains(&format!(
+            "  \"{la}\" -- \"{ny}\" [label=\"la1:ny1\\n1.50ms 10Gbps\", color=\"green\", style=bold];\n"
+        )));
+        assert!(!output.contains("ld1"));
+        assert!(output.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_cli_topology_map_d3() {
+        let (snapshot, _, _) = snapshot();
+        let mut client = MockCliCommand::new();
+        let devices = snapshot.devices.clone();
+        let links = snapshot.links.clone();
+        client
+            .expect_list_device()
+            .returning(move |_| Ok(devices.clone()));
+        client
+            .expect_list_link()
+            .returning(move |_| Ok(links.clone()));
+        client
+            .expect_list_exchange()
+            .returning(|_| Ok(HashMap::new()));
+        client
+            .expect_list_location()
+            .returning(|_| Ok(HashMap::new()));
+
+        let cmd = MapTopologyCliCommand {
+            format: MapFormat::D3,
+            contributor: None,
+            outfile: None,
+        };
+        let ctx = cli_context_default_for_tests();
+        let mut out = Cursor::new(Vec::new());
+        block_on(cmd.execute(&ctx, &client, &mut out)).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out.into_inner()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["links"].as_array().unwrap().len(), 2);
+        assert_eq!(json["links"][0]["code"], "la1:ny1");
+        assert_eq!(json["links"][0]["delay_ns"], 1_500_000);
+    }
+}
@@ -3,6 +3,7 @@ pub mod clear;
 pub mod create;
 pub mod delete;
 pub mod list;
+pub mod map;
 
 use doublezero_sdk::TopologyInfo;
 use solana_sdk::pubkey::Pubkey;
@@ -13,7 +13,7 @@ use doublezero_sdk::{
         tenant::get::GetTenantCommand,
         user::{create::CreateUserCommand, get::GetUserCommand},
     },
-    UserCYOA, UserType,
+    BandwidthTier, UserCYOA, UserType,
 };
 use solana_sdk::pubkey::Pubkey;
 use std::{io::Write, net::Ipv4Addr};
@@ -32,6 +32,9 @@ pub struct CreateUserCliCommand {
     /// Tenant Pubkey or code (optional)
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub tenant: Option<String>,
+    /// Bandwidth tier to request, at most the access pass's tier (default: the access pass's tier)
+    #[arg(long)]
+    pub bandwidth_tier: Option<BandwidthTier>,
     /// Wait for the user to be activated
     #[arg(short, long, default_value_t = false)]
     pub wait: bool,
@@ -108,6 +111,7 @@ impl CreateUserCliCommand {
             client_ip: self.client_ip,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             tenant_pk,
+            bandwidth_tier: self.bandwidth_tier,
         })?;
         writeln!(out, "Signature: {signature}",)?;
 
@@ -224,6 +228,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         client
             .expect_get_accesspass()
@@ -242,6 +248,7 @@ mod tests {
                 client_ip: [100, 0, 0, 1].into(),
                 tenant_pk: None,
                 tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+                bandwidth_tier: None,
             }))
             .times(1)
             .returning(move |_| Ok((signature, pda_pubkey)));
@@ -256,6 +263,7 @@ mod tests {
                 allocate_addr: false,
                 tenant: None,
                 wait: false,
+                bandwidth_tier: None,
             }
             .execute(&ctx, &client, &mut output),
         );
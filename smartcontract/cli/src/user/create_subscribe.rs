@@ -33,6 +33,9 @@ pub struct CreateSubscribeUserCliCommand {
     /// Multicast group subscriber Pubkey or code
     #[arg(long)]
     pub subscriber: Option<String>,
+    /// Bandwidth tier to request, at most the access pass's tier (default: the access pass's tier)
+    #[arg(long)]
+    pub bandwidth_tier: Option<BandwidthTier>,
     /// Wait for the user to be activated
     #[arg(short, long, default_value_t = false)]
     pub wait: bool,
@@ -112,6 +115,7 @@ impl CreateSubscribeUserCliCommand {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             owner: owner_pk,
             feed_pk: None,
+            bandwidth_tier: self.bandwidth_tier,
         })?;
         writeln!(out, "Signature: {signature}",)?;
 
@@ -236,6 +240,7 @@ mod tests {
                 tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
                 owner: None,
                 feed_pk: None,
+                bandwidth_tier: None,
             }))
             .times(1)
             .returning(move |_| Ok((signature, pda_pubkey)));
@@ -252,6 +257,7 @@ mod tests {
                 subscriber: Some(mgroup_pubkey.to_string()),
                 wait: false,
                 owner: None,
+                bandwidth_tier: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1,11 +1,12 @@
 use crate::{
+    cascade::print_cascade_preview,
     doublezerocommand::CliCommand,
     requirements::{CHECK_BALANCE, CHECK_ID_JSON},
     validators::validate_pubkey,
 };
 use clap::Args;
 use doublezero_cli_core::CliContext;
-use doublezero_sdk::commands::user::delete::DeleteUserCommand;
+use doublezero_sdk::{cascade::DeleteTarget, commands::user::delete::DeleteUserCommand};
 use solana_sdk::pubkey::Pubkey;
 use std::{io::Write, str::FromStr};
 
@@ -14,6 +15,10 @@ pub struct DeleteUserCliCommand {
     /// User Pubkey to delete
     #[arg(long, value_parser = validate_pubkey)]
     pub pubkey: String,
+    /// Print what blocks the deletion and the order in which to drain or
+    /// delete it, without sending a transaction
+    #[arg(long, default_value_t = false)]
+    pub cascade_preview: bool,
 }
 
 impl DeleteUserCliCommand {
@@ -23,6 +28,11 @@ impl DeleteUserCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        if self.cascade_preview {
+            let pubkey = Pubkey::from_str(&self.pubkey)?;
+            return print_cascade_preview(client, DeleteTarget::User(pubkey), out);
+        }
+
         // Check requirements
         client.check_requirements(CHECK_ID_JSON | CHECK_BALANCE)?;
 
@@ -88,6 +98,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client
@@ -110,6 +121,7 @@ mod tests {
         let res = block_on(
             DeleteUserCliCommand {
                 pubkey: pda_pubkey.to_string(),
+                cascade_preview: false,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -4,10 +4,13 @@ use crate::{
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::serializer;
-use doublezero_sdk::commands::{
-    accesspass::get::GetAccessPassCommand, device::list::ListDeviceCommand,
-    multicastgroup::list::ListMulticastGroupCommand, tenant::list::ListTenantCommand,
-    user::get::GetUserCommand,
+use doublezero_sdk::{
+    commands::{
+        accesspass::get::GetAccessPassCommand, device::list::ListDeviceCommand,
+        multicastgroup::list::ListMulticastGroupCommand, tenant::list::ListTenantCommand,
+        user::get::GetUserCommand,
+    },
+    labels::LabeledPubkey,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
@@ -25,7 +28,7 @@ pub struct GetUserCliCommand {
 }
 
 #[derive(Tabled, Serialize)]
-struct UserDisplay {
+pub(crate) struct UserDisplay {
     pub account: String,
     pub user_type: String,
     pub tenant: String,
@@ -45,6 +48,7 @@ struct UserDisplay {
     pub subscribers: String,
     pub status: String,
     pub bgp_status: String,
+    pub bandwidth_tier: String,
     #[tabled(skip)]
     pub last_bgp_reported_at: u64,
     #[tabled(rename = "last_bgp_reported_at")]
@@ -59,7 +63,7 @@ struct UserDisplay {
     /// Pretty-printed BGP RTT (e.g. "5.50 ms" or "-" when no sample yet).
     #[tabled(rename = "bgp_rtt")]
     pub bgp_rtt_str: String,
-    pub owner: String,
+    pub owner: LabeledPubkey,
 }
 
 /// Formats a BGP RTT value in nanoseconds as a human-readable millisecond
@@ -78,6 +82,7 @@ impl GetUserCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let pubkey = Pubkey::from_str(&self.pubkey)?;
         let (pubkey, user) = client.get_user(GetUserCommand { pubkey })?;
 
@@ -143,13 +148,14 @@ impl GetUserCliCommand {
                 .join(", "),
             status: user.status.to_string(),
             bgp_status: user.bgp_status.to_string(),
+            bandwidth_tier: user.bandwidth_tier.to_string(),
             last_bgp_reported_at: user.last_bgp_reported_at,
             last_bgp_reported_at_str: slot_to_datetime(client, user.last_bgp_reported_at),
             last_bgp_up_at: user.last_bgp_up_at,
             last_bgp_up_at_str: slot_to_datetime(client, user.last_bgp_up_at),
             bgp_rtt_ns: user.bgp_rtt_ns,
             bgp_rtt_str: format_bgp_rtt_ns(user.bgp_rtt_ns),
-            owner: user.owner.to_string(),
+            owner: book.labeled(&user.owner),
         };
 
         if self.json {
@@ -297,6 +303,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let (accesspass_pubkey, _) =
@@ -319,6 +326,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client
@@ -485,6 +494,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client
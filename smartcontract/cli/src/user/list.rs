@@ -8,6 +8,7 @@ use doublezero_sdk::{
         location::list::ListLocationCommand, multicastgroup::list::ListMulticastGroupCommand,
         tenant::list::ListTenantCommand, user::list::ListUserCommand,
     },
+    labels::LabeledPubkey,
     read_doublezero_config, BGPStatus, MulticastGroup, User, UserCYOA, UserStatus, UserType,
 };
 use doublezero_serviceability::pda::get_accesspass_pda;
@@ -122,8 +123,7 @@ pub struct UserDisplay {
     /// Pretty-printed BGP RTT (e.g. "5.50 ms" or "-" when no sample yet).
     #[tabled(rename = "rtt")]
     pub bgp_rtt: String,
-    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
-    pub owner: Pubkey,
+    pub owner: LabeledPubkey,
 }
 
 /// Formats a BGP RTT value in nanoseconds as a human-readable millisecond
@@ -142,6 +142,7 @@ impl ListUserCliCommand {
         client: &C,
         out: &mut W,
     ) -> eyre::Result<()> {
+        let book = client.get_address_book();
         let devices = client.list_device(ListDeviceCommand)?;
         let locations = client.list_location(ListLocationCommand)?;
         let mgroups = client.list_multicastgroup(ListMulticastGroupCommand)?;
@@ -389,7 +390,7 @@ impl ListUserCliCommand {
                     bgp_status: user.bgp_status,
                     bgp_rtt_ns: user.bgp_rtt_ns,
                     bgp_rtt: format_bgp_rtt_ns(user.bgp_rtt_ns),
-                    owner: user.owner,
+                    owner: book.labeled(&user.owner),
                 }
             })
             .collect();
@@ -476,7 +477,7 @@ impl UserDisplayNarrow {
             tunnel_id: d.tunnel_id,
             status: d.status,
             bgp_status: d.bgp_status,
-            owner: d.owner,
+            owner: d.owner.pubkey,
         }
     }
 }
@@ -504,6 +505,7 @@ fn abbreviate_user_type(ut: &UserType) -> String {
         UserType::IBRLWithAllocatedIP => "IBRL+IP".to_string(),
         UserType::EdgeFiltering => "EdgeFilt".to_string(),
         UserType::Multicast => "Multicast".to_string(),
+        other => other.to_string(),
     }
 }
 
@@ -826,6 +828,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let (accesspass1_pubkey, _) =
@@ -848,6 +851,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let user2 = User {
@@ -874,6 +879,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let (accesspass2_pubkey, _) =
@@ -896,6 +902,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1073,6 +1081,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1099,6 +1108,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1190,6 +1200,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1216,6 +1227,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1307,6 +1319,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1333,6 +1346,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1424,6 +1438,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1450,6 +1465,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1541,6 +1557,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1567,6 +1584,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1673,6 +1691,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1699,6 +1718,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -1828,6 +1848,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -1854,6 +1875,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
@@ -2016,6 +2038,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let user2 = User {
@@ -2042,6 +2065,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client.expect_list_user().returning(move |_| {
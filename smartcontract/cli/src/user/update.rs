@@ -7,7 +7,10 @@ use crate::{
 use clap::Args;
 use doublezero_cli_core::CliContext;
 use doublezero_program_common::types::NetworkV4;
-use doublezero_sdk::commands::{tenant::get::GetTenantCommand, user::update::UpdateUserCommand};
+use doublezero_sdk::{
+    commands::{tenant::get::GetTenantCommand, user::update::UpdateUserCommand},
+    BandwidthTier,
+};
 use solana_sdk::pubkey::Pubkey;
 use std::{io::Write, net::Ipv4Addr, str::FromStr};
 
@@ -34,6 +37,9 @@ pub struct UpdateUserCliCommand {
     /// New Tenant Pubkey or code (foundation only)
     #[arg(long, value_parser = validate_pubkey_or_code)]
     pub tenant: Option<String>,
+    /// New bandwidth tier (foundation only)
+    #[arg(long)]
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl UpdateUserCliCommand {
@@ -76,6 +82,7 @@ impl UpdateUserCliCommand {
                 .transpose()?,
             tenant_pk,
             tunnel_endpoint: self.tunnel_endpoint,
+            bandwidth_tier: self.bandwidth_tier,
         })?;
         writeln!(out, "Signature: {signature}",)?;
 
@@ -139,6 +146,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client
@@ -166,6 +174,7 @@ mod tests {
                 validator_pubkey: None,
                 tenant_pk: None,
                 tunnel_endpoint: Some([1, 2, 3, 4].into()),
+                bandwidth_tier: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -181,6 +190,7 @@ mod tests {
                 validator_pubkey: None,
                 tunnel_endpoint: Some([1, 2, 3, 4].into()),
                 tenant: None,
+                bandwidth_tier: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -220,6 +230,7 @@ mod tests {
                 validator_pubkey: None,
                 tenant_pk: Some(tenant_pubkey),
                 tunnel_endpoint: None,
+                bandwidth_tier: None,
             }))
             .returning(move |_| Ok(signature));
 
@@ -234,6 +245,7 @@ mod tests {
                 validator_pubkey: None,
                 tunnel_endpoint: None,
                 tenant: Some(tenant_pubkey.to_string()),
+                bandwidth_tier: None,
             }
             .execute(&ctx, &client, &mut output),
         );
@@ -1,10 +1,8 @@
-use doublezero_program_common::types::parse_utils::bandwidth_to_string;
+use doublezero_sdk::units::{Bps, Nanoseconds};
 use solana_sdk::pubkey::Pubkey;
 
-const NANOS_TO_MS: f32 = 1_000_000.0;
-
 pub fn display_as_ms(latency: &u64) -> String {
-    format!("{:.2}ms", (*latency as f32 / NANOS_TO_MS))
+    Nanoseconds(*latency).to_string()
 }
 
 pub fn display_pks(pks: &[Pubkey]) -> String {
@@ -19,7 +17,7 @@ pub fn display_count(pks: &[Pubkey]) -> String {
 }
 
 pub fn display_as_bandwidth(bandwidth: &u64) -> String {
-    bandwidth_to_string(bandwidth)
+    Bps(*bandwidth).to_string()
 }
 
 /// Number of leading characters kept when abbreviating a pubkey or key for
@@ -0,0 +1,82 @@
+use crate::{create_account::try_create_account, resize_account::resize_account_if_needed};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+// Shared by every program so account creation, writes and closes behave the
+// same everywhere; programs that validate their state wrap these in their own
+// `serializer` module.
+
+/// Creates the PDA `account` sized for `value`, funded by `payer_account`, and
+/// writes `value` into it.
+pub fn try_acc_create<'a, T>(
+    value: &T,
+    account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    new_account_signer_seeds: &[&[u8]],
+) -> ProgramResult
+where
+    T: BorshSerialize,
+{
+    let account_space = borsh::object_length(value)?;
+
+    try_create_account(
+        payer_account.key,
+        account.key,
+        account.lamports(),
+        account_space,
+        program_id,
+        &[
+            account.clone(),
+            payer_account.clone(),
+            system_program.clone(),
+        ],
+        new_account_signer_seeds,
+    )?;
+
+    let mut account_data = &mut account.data.borrow_mut()[..];
+    value.serialize(&mut account_data)?;
+
+    Ok(())
+}
+
+/// Writes `value` into `account`, resizing it (and topping up its rent from
+/// `payer`) when the serialized length changed.
+pub fn try_acc_write<T>(
+    value: &T,
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> ProgramResult
+where
+    T: BorshSerialize,
+{
+    resize_account_if_needed(account, payer, accounts, borsh::object_length(value)?)?;
+
+    let mut data = &mut account.data.borrow_mut()[..];
+    value.serialize(&mut data)?;
+
+    Ok(())
+}
+
+/// Moves all of `close_account`'s lamports to `receiving_account`, then empties
+/// it and hands it back to the system program.
+pub fn try_acc_close(
+    close_account: &AccountInfo,
+    receiving_account: &AccountInfo,
+) -> ProgramResult {
+    **receiving_account.lamports.borrow_mut() = receiving_account
+        .lamports()
+        .checked_add(close_account.lamports())
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **close_account.lamports.borrow_mut() = 0;
+
+    close_account.resize(0)?;
+    close_account.assign(&solana_system_interface::program::ID);
+
+    Ok(())
+}
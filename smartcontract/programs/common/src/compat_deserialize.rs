@@ -0,0 +1,201 @@
+//! Compatibility checks for tolerant account readers.
+//!
+//! Account structs are read field by field with every missing trailing field
+//! defaulted, so accounts written by older program versions stay readable.
+//! The checks below pin down the contract such a reader must keep with its
+//! serializer:
+//!
+//! - [`check_round_trip`]: a value reads back unchanged from its own bytes,
+//!   and reads back unchanged when a newer program appended fields after it.
+//! - [`check_truncated`]: every prefix of the bytes, as left by an older
+//!   program, is read or rejected without panicking.
+//! - [`check_reread_stable`]: whatever a reader accepts from arbitrary bytes
+//!   it writes back in a form it reads and writes again unchanged.
+//!
+//! They return a description of the first violation so they can be driven
+//! from unit tests, property tests and fuzz targets alike. The `proptest`
+//! feature adds [`strategies`] for the field types shared across programs.
+
+use borsh::BorshSerialize;
+use std::fmt::Debug;
+
+/// Bytes a newer program version could have appended after the fields known
+/// to this reader.
+const TRAILING_BYTES: [u8; 16] = [0xa5; 16];
+
+fn read<T>(data: &[u8]) -> Option<T>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    T::try_from(data).ok()
+}
+
+/// Checks that `value` reads back unchanged from its serialized bytes, with
+/// and without unknown trailing bytes.
+pub fn check_round_trip<T>(value: &T) -> Result<(), String>
+where
+    T: BorshSerialize + for<'a> TryFrom<&'a [u8]> + PartialEq + Debug,
+{
+    let bytes = borsh::to_vec(value).map_err(|e| format!("serialize failed: {e}"))?;
+
+    let decoded: T = read(&bytes).ok_or("reading its own bytes failed")?;
+    if decoded != *value {
+        return Err(format!("round trip changed {value:?} into {decoded:?}"));
+    }
+
+    let mut extended = bytes.clone();
+    extended.extend_from_slice(&TRAILING_BYTES);
+    let decoded: T = read(&extended).ok_or("reading with trailing bytes failed")?;
+    if decoded != *value {
+        return Err(format!("trailing bytes changed {value:?} into {decoded:?}"));
+    }
+
+    Ok(())
+}
+
+/// Checks that every strict prefix of `value`'s bytes is read without
+/// panicking, and that whatever is read from it is stable.
+pub fn check_truncated<T>(value: &T) -> Result<(), String>
+where
+    T: BorshSerialize + for<'a> TryFrom<&'a [u8]> + Debug,
+{
+    let bytes = borsh::to_vec(value).map_err(|e| format!("serialize failed: {e}"))?;
+    for len in 0..bytes.len() {
+        check_reread_stable::<T>(&bytes[..len])
+            .map_err(|e| format!("prefix of {len} bytes: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Checks that if `data` is accepted, the bytes written for the value read
+/// are read and written back identically. Bytes are compared rather than
+/// values so floating point NaNs read from arbitrary data do not fail the
+/// check. Rejecting `data` is fine; panicking is not, and fails the calling
+/// test or fuzz target.
+pub fn check_reread_stable<T>(data: &[u8]) -> Result<(), String>
+where
+    T: BorshSerialize + for<'a> TryFrom<&'a [u8]> + Debug,
+{
+    let Some(value) = read::<T>(data) else {
+        return Ok(());
+    };
+    let written = borsh::to_vec(&value).map_err(|e| format!("serialize failed: {e}"))?;
+    let reread: T = read(&written).ok_or("rereading written bytes failed")?;
+    let rewritten = borsh::to_vec(&reread).map_err(|e| format!("serialize failed: {e}"))?;
+    if rewritten != written {
+        return Err(format!("reread changed {value:?} into {reread:?}"));
+    }
+    Ok(())
+}
+
+/// Proptest strategies for the field types shared by account structs.
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use crate::types::{NetworkV4, NetworkV4List};
+    use borsh::BorshDeserialize;
+    use proptest::{collection::vec, prelude::*};
+    use solana_program::pubkey::Pubkey;
+    use std::{fmt::Debug, net::Ipv4Addr};
+
+    pub fn pubkey() -> impl Strategy<Value = Pubkey> {
+        any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+    }
+
+    pub fn pubkeys(max: usize) -> impl Strategy<Value = Vec<Pubkey>> {
+        vec(pubkey(), 0..=max)
+    }
+
+    pub fn ipv4() -> impl Strategy<Value = Ipv4Addr> {
+        any::<[u8; 4]>().prop_map(Ipv4Addr::from)
+    }
+
+    pub fn network_v4() -> impl Strategy<Value = NetworkV4> {
+        (ipv4(), 0u8..=32).prop_map(|(ip, prefix)| NetworkV4::new(ip, prefix).unwrap())
+    }
+
+    pub fn network_v4_list(max: usize) -> impl Strategy<Value = NetworkV4List> {
+        vec(network_v4(), 0..=max).prop_map(NetworkV4List::from)
+    }
+
+    /// Account codes and names, including non-ASCII text.
+    pub fn text() -> impl Strategy<Value = String> {
+        "\\PC{0,32}"
+    }
+
+    /// Coordinates. NaN is left out since it never compares equal.
+    pub fn coordinate() -> impl Strategy<Value = f64> {
+        -180.0f64..=180.0
+    }
+
+    /// Any value of a borsh enum whose discriminant is below `variants`,
+    /// including variants carrying data.
+    pub fn borsh_enum<T>(variants: u8) -> impl Strategy<Value = T>
+    where
+        T: BorshDeserialize + Debug,
+    {
+        (0..variants, vec(any::<u8>(), 64)).prop_filter_map(
+            "not a valid variant",
+            |(discriminant, payload)| {
+                let mut bytes = vec![discriminant];
+                bytes.extend(payload);
+                T::deserialize(&mut bytes.as_slice()).ok()
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshDeserialize;
+
+    #[derive(BorshSerialize, Debug, PartialEq)]
+    struct Record {
+        code: String,
+        count: u32,
+    }
+
+    /// Tolerant reader: missing trailing fields are defaulted.
+    impl TryFrom<&[u8]> for Record {
+        type Error = ();
+
+        fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+            Ok(Self {
+                code: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+                count: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            })
+        }
+    }
+
+    #[derive(BorshSerialize, Debug, PartialEq)]
+    struct Strict {
+        count: u32,
+    }
+
+    /// Strict reader: rejects bytes appended by a newer layout.
+    impl TryFrom<&[u8]> for Strict {
+        type Error = ();
+
+        fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+            let count = u32::try_from_slice(data).map_err(|_| ())?;
+            Ok(Self { count })
+        }
+    }
+
+    #[test]
+    fn test_compat_checks() {
+        let record = Record {
+            code: "ams".to_string(),
+            count: 7,
+        };
+        assert_eq!(check_round_trip(&record), Ok(()));
+        assert_eq!(check_truncated(&record), Ok(()));
+        assert_eq!(check_reread_stable::<Record>(&[0xff; 9]), Ok(()));
+
+        let strict = Strict { count: 7 };
+        assert_eq!(
+            check_round_trip(&strict),
+            Err("reading with trailing bytes failed".to_string())
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Common helpers for various SVM programs.
 
+pub mod account_io;
+pub mod compat_deserialize;
 pub mod create_account;
 pub mod resize_account;
 pub mod serializer;
@@ -7,6 +9,7 @@ pub mod types;
 pub mod validate_account_code;
 pub mod validate_iface;
 
+pub use account_io::{try_acc_close, try_acc_create, try_acc_write};
 pub use create_account::try_create_account;
 pub use validate_account_code::validate_account_code;
 pub use validate_iface::validate_iface;
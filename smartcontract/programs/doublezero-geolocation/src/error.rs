@@ -50,6 +50,35 @@ impl From<GeolocationError> for ProgramError {
     }
 }
 
+impl TryFrom<u32> for GeolocationError {
+    type Error = u32;
+
+    fn try_from(code: u32) -> Result<Self, u32> {
+        match code {
+            1 => Ok(Self::InvalidAccountType),
+            2 => Ok(Self::NotAllowed),
+            4 => Ok(Self::InvalidCodeLength),
+            5 => Ok(Self::InvalidIpAddress),
+            6 => Ok(Self::MaxParentDevicesReached),
+            7 => Ok(Self::ParentDeviceAlreadyExists),
+            8 => Ok(Self::ParentDeviceNotFound),
+            11 => Ok(Self::InvalidServiceabilityProgramId),
+            12 => Ok(Self::InvalidAccountCode),
+            15 => Ok(Self::ReferenceCountNotZero),
+            17 => Ok(Self::UnauthorizedInitializer),
+            18 => Ok(Self::InvalidMinCompatibleVersion),
+            19 => Ok(Self::Unauthorized),
+            20 => Ok(Self::TargetsNotEmpty),
+            21 => Ok(Self::MaxTargetsReached),
+            22 => Ok(Self::TargetNotFound),
+            23 => Ok(Self::TargetAlreadyExists),
+            24 => Ok(Self::InvalidPaymentStatus),
+            25 => Ok(Self::ProbeAccountCountMismatch),
+            _ => Err(code),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,6 +122,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_codes_round_trip() {
+        for (variant, code) in all_variants() {
+            assert_eq!(GeolocationError::try_from(code), Ok(variant));
+        }
+        assert_eq!(GeolocationError::try_from(3), Err(3));
+        assert_eq!(GeolocationError::try_from(1000), Err(1000));
+    }
+
     #[test]
     fn test_error_display_messages() {
         assert_eq!(
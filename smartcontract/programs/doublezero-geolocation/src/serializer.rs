@@ -1,90 +1 @@
-use borsh::BorshSerialize;
-use doublezero_program_common::{
-    create_account::try_create_account, resize_account::resize_account_if_needed,
-};
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
-};
-use solana_sdk_ids::system_program;
-
-#[cfg(test)]
-use solana_program::msg;
-
-pub fn try_acc_create<'a, T>(
-    value: &T,
-    account: &AccountInfo<'a>,
-    payer_account: &AccountInfo<'a>,
-    system_program: &AccountInfo<'a>,
-    program_id: &Pubkey,
-    new_account_signer_seeds: &[&[u8]],
-) -> ProgramResult
-where
-    T: BorshSerialize + std::fmt::Debug,
-{
-    let account_space = borsh::object_length(value)?;
-
-    #[cfg(test)]
-    {
-        use solana_sdk::{rent::Rent, sysvar::Sysvar};
-
-        let rent = Rent::get().expect("Unable to get rent");
-        let required_lamports = rent.minimum_balance(account_space);
-        msg!("Rent: {}", required_lamports);
-    }
-
-    try_create_account(
-        payer_account.key,
-        account.key,
-        account.lamports(),
-        account_space,
-        program_id,
-        &[
-            account.clone(),
-            payer_account.clone(),
-            system_program.clone(),
-        ],
-        new_account_signer_seeds,
-    )?;
-
-    let mut account_data = &mut account.data.borrow_mut()[..];
-    value.serialize(&mut account_data)?;
-
-    #[cfg(test)]
-    msg!("Created: {:?}", value);
-
-    Ok(())
-}
-
-pub fn try_acc_write<T>(
-    value: &T,
-    account: &AccountInfo,
-    payer: &AccountInfo,
-    accounts: &[AccountInfo],
-) -> ProgramResult
-where
-    T: borsh::BorshSerialize,
-{
-    resize_account_if_needed(account, payer, accounts, borsh::object_length(value)?)?;
-
-    let mut data = &mut account.data.borrow_mut()[..];
-    value.serialize(&mut data)?;
-
-    Ok(())
-}
-
-pub fn try_acc_close(
-    close_account: &AccountInfo,
-    receiving_account: &AccountInfo,
-) -> ProgramResult {
-    **receiving_account.lamports.borrow_mut() = receiving_account
-        .lamports()
-        .checked_add(close_account.lamports())
-        .ok_or(ProgramError::InsufficientFunds)?;
-    **close_account.lamports.borrow_mut() = 0;
-
-    close_account.resize(0)?;
-    close_account.assign(&system_program::ID);
-
-    Ok(())
-}
+pub use doublezero_program_common::account_io::{try_acc_close, try_acc_create, try_acc_write};
@@ -14,6 +14,39 @@ pub enum RecordError {
     /// Calculation overflow
     #[error("Calculation overflow")]
     Overflow,
+
+    /// Section grants are empty, too many, zero-length, overflowing or
+    /// overlapping
+    #[error("Invalid section grants")]
+    InvalidSectionGrants,
+
+    /// Write does not lie entirely within a section granted to the signer
+    #[error("Write outside of the signer's section")]
+    WriteOutsideSection,
+
+    /// Namespace prefix is empty, too long, or does not match the namespace
+    /// account address
+    #[error("Invalid namespace")]
+    InvalidNamespace,
+
+    /// Namespace is already registered to another authority
+    #[error("Namespace already registered to another authority")]
+    NamespaceTaken,
+
+    /// Lease name is empty, too long, or does not match the lease account
+    /// address, or the lease duration is zero or too long
+    #[error("Invalid lease")]
+    InvalidLease,
+
+    /// Lease is held by another account, or another instance of the same
+    /// account, and has not expired
+    #[error("Lease held by another account")]
+    LeaseHeld,
+
+    /// Fenced write whose signer does not hold the lease at the expected
+    /// generation, or whose lease has expired
+    #[error("Write fenced off by the lease")]
+    LeaseFenced,
 }
 impl From<RecordError> for ProgramError {
     fn from(e: RecordError) -> Self {
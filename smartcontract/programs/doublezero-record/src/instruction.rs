@@ -7,7 +7,10 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::ID;
+use crate::{
+    state::{find_lease_address, find_namespace_address, SectionGrant},
+    ID,
+};
 
 /// Instructions supported by the program
 #[derive(Clone, Debug, PartialEq)]
@@ -22,15 +25,25 @@ pub enum RecordInstruction<'a> {
 
     /// Write to the provided record account
     ///
+    /// A fenced write also names a single-writer lease and the generation the
+    /// writer acquired it at; it fails with
+    /// [`RecordError::LeaseFenced`](crate::error::RecordError::LeaseFenced)
+    /// unless the signer still holds that lease at that generation, so a
+    /// writer that lost its lease cannot overwrite its successor.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[writable]` Record account, must be previously initialized
-    /// 1. `[signer]` Current record authority
+    /// 1. `[signer]` Current record authority, or for a sectioned record, a
+    ///    writer whose section covers the whole write
+    /// 2. `[]` Lease account, for a fenced write only
     Write {
         /// Offset to start writing record, expressed as `u64`.
         offset: u64,
         /// Data to replace the existing record data
         data: &'a [u8],
+        /// Lease generation the write is fenced with, packed after the data
+        generation: Option<u64>,
     },
 
     /// TODO: Remove this instruction. We do not want the ability for an author
@@ -57,6 +70,75 @@ pub enum RecordInstruction<'a> {
     /// 0. `[writable]` The record account to reallocate
     /// 1. `[signer]` The account's owner
     Reallocate(u64),
+
+    /// Create a new sectioned record, whose header holds a table of section
+    /// grants. The authority may write anywhere in the record; each grant's
+    /// writer may only write inside its own section. Offsets are relative to
+    /// the writable data, which starts after the grant table.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    InitializeSectioned {
+        /// Section grants, which must not overlap
+        grants: &'a [SectionGrant],
+    },
+
+    /// Register a record seed namespace, claiming `prefix` for the authority
+    /// so that subsystems choosing seeds independently cannot collide. The
+    /// namespace account is the program address derived from
+    /// [`NamespaceData::SEED`](crate::state::NamespaceData::SEED) and the
+    /// prefix, and is created and funded by the authority. Registering a
+    /// namespace the authority already holds does nothing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Namespace account
+    /// 1. `[writable, signer]` Namespace authority, which pays for the account
+    /// 2. `[]` System program
+    RegisterNamespace {
+        /// Seed prefix to claim, at most 32 bytes
+        prefix: &'a [u8],
+    },
+
+    /// Acquire or renew the single-writer lease `name` until
+    /// `duration_slots` after the current slot. The lease account is the
+    /// program address derived from
+    /// [`LeaseData::SEED`](crate::state::LeaseData::SEED) and the name, and is
+    /// created and funded by the first holder. The holder instance may renew
+    /// its lease at any time; another account, or another instance of the
+    /// holder picking a different nonce, may only take it over once it has
+    /// expired, which increments the lease generation.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Lease account
+    /// 1. `[writable, signer]` Lease holder, which pays for the account
+    /// 2. `[]` System program
+    AcquireLease {
+        /// Lease name, at most 32 bytes
+        name: &'a [u8],
+        /// Slots the lease is held for, at most
+        /// [`LeaseData::MAX_DURATION_SLOTS`](crate::state::LeaseData::MAX_DURATION_SLOTS)
+        duration_slots: u64,
+        /// Nonce picked once by the holder instance, e.g. at random on startup
+        nonce: u64,
+    },
+
+    /// Give up the lease `name` so that another account can take it over
+    /// right away, e.g. on orderly shutdown.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Lease account
+    /// 1. `[signer]` Lease holder
+    ReleaseLease {
+        /// Lease name, at most 32 bytes
+        name: &'a [u8],
+        /// Nonce the holder instance acquired the lease with
+        nonce: u64,
+    },
 }
 
 impl<'a> RecordInstruction<'a> {
@@ -65,6 +147,10 @@ impl<'a> RecordInstruction<'a> {
     const SET_AUTHORITY: u8 = 2;
     const CLOSE_ACCOUNT: u8 = 3;
     const REALLOCATE: u8 = 4;
+    const INITIALIZE_SECTIONED: u8 = 5;
+    const REGISTER_NAMESPACE: u8 = 6;
+    const ACQUIRE_LEASE: u8 = 7;
+    const RELEASE_LEASE: u8 = 8;
 
     /// Unpacks a byte buffer into a [`RecordInstruction`].
     pub fn unpack(input: &'a [u8]) -> Option<Self> {
@@ -82,10 +168,15 @@ impl<'a> RecordInstruction<'a> {
                     .map(u64::from_le_bytes)?;
                 let (length, data) = rest[U64_BYTES..].split_at(U32_BYTES);
                 let length = length.try_into().map(u32::from_le_bytes).ok()? as usize;
+                let generation = match data.get(length..)? {
+                    [] => None,
+                    fence => Some(fence.try_into().map(u64::from_le_bytes).ok()?),
+                };
 
                 Some(Self::Write {
                     offset,
                     data: &data[..length],
+                    generation,
                 })
             }
             Self::SET_AUTHORITY => Some(Self::SetAuthority),
@@ -98,6 +189,47 @@ impl<'a> RecordInstruction<'a> {
 
                 Some(Self::Reallocate(data_length))
             }
+            Self::INITIALIZE_SECTIONED => {
+                let (&count, rest) = rest.split_first()?;
+                let table = rest.get(..(count as usize) * size_of::<SectionGrant>())?;
+                let grants = bytemuck::try_cast_slice(table).ok()?;
+
+                Some(Self::InitializeSectioned { grants })
+            }
+            Self::REGISTER_NAMESPACE => {
+                let (&length, rest) = rest.split_first()?;
+                let prefix = rest.get(..length as usize)?;
+
+                Some(Self::RegisterNamespace { prefix })
+            }
+            Self::ACQUIRE_LEASE => {
+                let duration_slots = rest
+                    .get(..U64_BYTES)
+                    .and_then(|slice| slice.try_into().ok())
+                    .map(u64::from_le_bytes)?;
+                let nonce = rest
+                    .get(U64_BYTES..2 * U64_BYTES)
+                    .and_then(|slice| slice.try_into().ok())
+                    .map(u64::from_le_bytes)?;
+                let (&length, rest) = rest[2 * U64_BYTES..].split_first()?;
+                let name = rest.get(..length as usize)?;
+
+                Some(Self::AcquireLease {
+                    name,
+                    duration_slots,
+                    nonce,
+                })
+            }
+            Self::RELEASE_LEASE => {
+                let nonce = rest
+                    .get(..U64_BYTES)
+                    .and_then(|slice| slice.try_into().ok())
+                    .map(u64::from_le_bytes)?;
+                let (&length, rest) = rest[U64_BYTES..].split_first()?;
+                let name = rest.get(..length as usize)?;
+
+                Some(Self::ReleaseLease { name, nonce })
+            }
             _ => None,
         }
     }
@@ -107,11 +239,18 @@ impl<'a> RecordInstruction<'a> {
         let mut buf = Vec::with_capacity(size_of::<Self>());
         match self {
             Self::Initialize => buf.push(Self::INITIALIZE),
-            Self::Write { offset, data } => {
+            Self::Write {
+                offset,
+                data,
+                generation,
+            } => {
                 buf.push(Self::WRITE);
                 buf.extend_from_slice(&offset.to_le_bytes());
                 buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
                 buf.extend_from_slice(data);
+                if let Some(generation) = generation {
+                    buf.extend_from_slice(&generation.to_le_bytes());
+                }
             }
             Self::SetAuthority => buf.push(Self::SET_AUTHORITY),
             Self::CloseAccount => buf.push(Self::CLOSE_ACCOUNT),
@@ -119,6 +258,33 @@ impl<'a> RecordInstruction<'a> {
                 buf.push(Self::REALLOCATE);
                 buf.extend_from_slice(&data_length.to_le_bytes());
             }
+            Self::InitializeSectioned { grants } => {
+                buf.push(Self::INITIALIZE_SECTIONED);
+                buf.push(grants.len() as u8);
+                buf.extend_from_slice(bytemuck::cast_slice(grants));
+            }
+            Self::RegisterNamespace { prefix } => {
+                buf.push(Self::REGISTER_NAMESPACE);
+                buf.push(prefix.len() as u8);
+                buf.extend_from_slice(prefix);
+            }
+            Self::AcquireLease {
+                name,
+                duration_slots,
+                nonce,
+            } => {
+                buf.push(Self::ACQUIRE_LEASE);
+                buf.extend_from_slice(&duration_slots.to_le_bytes());
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.push(name.len() as u8);
+                buf.extend_from_slice(name);
+            }
+            Self::ReleaseLease { name, nonce } => {
+                buf.push(Self::RELEASE_LEASE);
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.push(name.len() as u8);
+                buf.extend_from_slice(name);
+            }
         };
         buf
     }
@@ -144,7 +310,42 @@ pub fn write(record_account: &Pubkey, signer: &Pubkey, offset: u64, data: &[u8])
             AccountMeta::new(*record_account, false),
             AccountMeta::new_readonly(*signer, true),
         ],
-        data: RecordInstruction::Write { offset, data }.pack(),
+        data: RecordInstruction::Write {
+            offset,
+            data,
+            generation: None,
+        }
+        .pack(),
+    }
+}
+
+/// Create a fenced `RecordInstruction::Write` instruction, which only lands
+/// while `signer` holds the lease `lease_name` at `generation`
+///
+/// Panics if `lease_name` is longer than 32 bytes, since no lease address can
+/// be derived for it.
+pub fn write_fenced(
+    record_account: &Pubkey,
+    signer: &Pubkey,
+    lease_name: &[u8],
+    generation: u64,
+    offset: u64,
+    data: &[u8],
+) -> Instruction {
+    let (lease_account, _) = find_lease_address(lease_name);
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(lease_account, false),
+        ],
+        data: RecordInstruction::Write {
+            offset,
+            data,
+            generation: Some(generation),
+        }
+        .pack(),
     }
 }
 
@@ -190,9 +391,80 @@ pub fn reallocate(record_account: &Pubkey, signer: &Pubkey, data_length: u64) ->
     }
 }
 
+/// Create a `RecordInstruction::InitializeSectioned` instruction
+pub fn initialize_sectioned(
+    record_account: &Pubkey,
+    authority: &Pubkey,
+    grants: &[SectionGrant],
+) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::InitializeSectioned { grants }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::RegisterNamespace` instruction
+///
+/// Panics if `prefix` is longer than 32 bytes, since no namespace address
+/// can be derived for it.
+pub fn register_namespace(authority: &Pubkey, prefix: &[u8]) -> Instruction {
+    let (namespace_account, _) = find_namespace_address(prefix);
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(namespace_account, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+        ],
+        data: RecordInstruction::RegisterNamespace { prefix }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::AcquireLease` instruction
+///
+/// Panics if `name` is longer than 32 bytes, since no lease address can be
+/// derived for it.
+pub fn acquire_lease(holder: &Pubkey, name: &[u8], nonce: u64, duration_slots: u64) -> Instruction {
+    let (lease_account, _) = find_lease_address(name);
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(lease_account, false),
+            AccountMeta::new(*holder, true),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+        ],
+        data: RecordInstruction::AcquireLease {
+            name,
+            duration_slots,
+            nonce,
+        }
+        .pack(),
+    }
+}
+
+/// Create a `RecordInstruction::ReleaseLease` instruction
+///
+/// Panics if `name` is longer than 32 bytes, since no lease address can be
+/// derived for it.
+pub fn release_lease(holder: &Pubkey, name: &[u8], nonce: u64) -> Instruction {
+    let (lease_account, _) = find_lease_address(name);
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(lease_account, false),
+            AccountMeta::new_readonly(*holder, true),
+        ],
+        data: RecordInstruction::ReleaseLease { name, nonce }.pack(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::state::tests::TEST_BYTES;
+    use crate::state::tests::{TEST_BYTES, TEST_PUBKEY};
 
     use super::*;
 
@@ -208,7 +480,11 @@ mod tests {
     fn serialize_write() {
         let data = &TEST_BYTES;
         let offset = 0u64;
-        let instruction = RecordInstruction::Write { offset: 0, data };
+        let instruction = RecordInstruction::Write {
+            offset: 0,
+            data,
+            generation: None,
+        };
         let mut expected = vec![1];
         expected.extend_from_slice(&offset.to_le_bytes());
         expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
@@ -217,6 +493,26 @@ mod tests {
         assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
     }
 
+    #[test]
+    fn serialize_fenced_write() {
+        let data = &TEST_BYTES;
+        let instruction = RecordInstruction::Write {
+            offset: 0,
+            data,
+            generation: Some(3),
+        };
+        let mut expected = vec![1];
+        expected.extend_from_slice(&0u64.to_le_bytes());
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(data);
+        expected.extend_from_slice(&3u64.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        // A truncated generation is rejected.
+        assert!(RecordInstruction::unpack(&expected[..expected.len() - 1]).is_none());
+    }
+
     #[test]
     fn serialize_set_authority() {
         let instruction = RecordInstruction::SetAuthority;
@@ -243,6 +539,70 @@ mod tests {
         assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
     }
 
+    #[test]
+    fn serialize_initialize_sectioned() {
+        let grants = [
+            SectionGrant::new(TEST_PUBKEY, 0, 16),
+            SectionGrant::new(Pubkey::new_from_array([7; 32]), 16, 8),
+        ];
+        let instruction = RecordInstruction::InitializeSectioned { grants: &grants };
+        let mut expected = vec![5, 2];
+        expected.extend_from_slice(bytemuck::cast_slice(&grants));
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        // A grant table shorter than its count is rejected.
+        assert!(RecordInstruction::unpack(&expected[..expected.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn serialize_register_namespace() {
+        let instruction = RecordInstruction::RegisterNamespace {
+            prefix: b"telemetry",
+        };
+        let mut expected = vec![6, 9];
+        expected.extend_from_slice(b"telemetry");
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        // A prefix shorter than its length is rejected.
+        assert!(RecordInstruction::unpack(&expected[..expected.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn serialize_acquire_lease() {
+        let instruction = RecordInstruction::AcquireLease {
+            name: b"scheduler",
+            duration_slots: 150,
+            nonce: 42,
+        };
+        let mut expected = vec![7];
+        expected.extend_from_slice(&150u64.to_le_bytes());
+        expected.extend_from_slice(&42u64.to_le_bytes());
+        expected.push(9);
+        expected.extend_from_slice(b"scheduler");
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        // A name shorter than its length is rejected.
+        assert!(RecordInstruction::unpack(&expected[..expected.len() - 1]).is_none());
+        assert!(RecordInstruction::unpack(&expected[..13]).is_none());
+    }
+
+    #[test]
+    fn serialize_release_lease() {
+        let instruction = RecordInstruction::ReleaseLease {
+            name: b"scheduler",
+            nonce: 42,
+        };
+        let mut expected = vec![8];
+        expected.extend_from_slice(&42u64.to_le_bytes());
+        expected.push(9);
+        expected.extend_from_slice(b"scheduler");
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
     #[test]
     fn deserialize_invalid_instruction() {
         let mut expected = vec![12];
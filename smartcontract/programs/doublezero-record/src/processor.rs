@@ -2,14 +2,25 @@
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
+    program::invoke_signed,
     program_error::ProgramError,
     program_pack::IsInitialized,
     pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
-use crate::{error::RecordError, instruction::RecordInstruction, state::RecordData};
+use crate::{
+    error::RecordError,
+    instruction::RecordInstruction,
+    state::{
+        find_lease_address, find_namespace_address, split_record_data, validate_section_grants,
+        LeaseData, NamespaceData, RecordData, SectionGrant,
+    },
+};
 
 fn check_authority(authority_info: &AccountInfo, expected_authority: &Pubkey) -> ProgramResult {
     if expected_authority != authority_info.key {
@@ -23,6 +34,140 @@ fn check_authority(authority_info: &AccountInfo, expected_authority: &Pubkey) ->
     Ok(())
 }
 
+/// Check that the signer may write `length` bytes at `offset`: either the
+/// record authority, or a writer holding a section that covers the whole
+/// write
+fn check_writer(
+    writer_info: &AccountInfo,
+    account_data: &RecordData,
+    grants: &[SectionGrant],
+    offset: u64,
+    length: u64,
+) -> ProgramResult {
+    if writer_info.key == &account_data.authority || !account_data.is_sectioned() {
+        return check_authority(writer_info, &account_data.authority);
+    }
+    let writer_grants = grants
+        .iter()
+        .filter(|grant| &grant.writer == writer_info.key);
+    let mut has_grant = false;
+    for grant in writer_grants {
+        has_grant = true;
+        if grant.contains(offset, length) {
+            if !writer_info.is_signer {
+                msg!("Section writer signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            return Ok(());
+        }
+    }
+    if has_grant {
+        msg!("Write is outside of the writer's sections");
+        Err(RecordError::WriteOutsideSection.into())
+    } else {
+        msg!("Incorrect record authority provided");
+        Err(RecordError::IncorrectAuthority.into())
+    }
+}
+
+/// Whether raw account data already holds a record, a namespace entry or a
+/// lease
+fn is_claimed(account_data: &RecordData) -> bool {
+    account_data.is_initialized()
+        || account_data.version == NamespaceData::VERSION
+        || account_data.version == LeaseData::VERSION
+}
+
+/// Create a namespace or lease account of `space` bytes at its program
+/// address, funded by the authority. Lamports sent to the address ahead of
+/// time are kept, so the creation cannot be blocked by pre-funding the
+/// account.
+fn create_program_account<'a>(
+    program_account_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let rent_exemption_lamports = Rent::get()?.minimum_balance(space);
+    let accounts = [
+        program_account_info.clone(),
+        authority_info.clone(),
+        system_program_info.clone(),
+    ];
+
+    let lamport_diff = rent_exemption_lamports.saturating_sub(program_account_info.lamports());
+    if lamport_diff != 0 {
+        invoke_signed(
+            &solana_system_interface::instruction::transfer(
+                authority_info.key,
+                program_account_info.key,
+                lamport_diff,
+            ),
+            &accounts,
+            &[],
+        )?;
+    }
+    invoke_signed(
+        &solana_system_interface::instruction::allocate(program_account_info.key, space as u64),
+        &accounts,
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &solana_system_interface::instruction::assign(program_account_info.key, &crate::ID),
+        &accounts,
+        &[signer_seeds],
+    )
+}
+
+/// Check that `lease_info` is the lease account for `name`, returning its
+/// bump seed
+fn check_lease_address(lease_info: &AccountInfo, name: &[u8]) -> Result<u8, ProgramError> {
+    if name.is_empty() || name.len() > LeaseData::MAX_NAME_LEN {
+        msg!("Lease name must be 1 to 32 bytes");
+        return Err(RecordError::InvalidLease.into());
+    }
+    let (expected_key, bump_seed) = find_lease_address(name);
+    if lease_info.key != &expected_key {
+        msg!("Lease account does not match the name");
+        return Err(RecordError::InvalidLease.into());
+    }
+    Ok(bump_seed)
+}
+
+/// Check that `writer_info` holds the live lease in `lease_info` at
+/// `generation`, so that a writer which lost its lease is fenced off
+fn check_fence(
+    lease_info: &AccountInfo,
+    writer_info: &AccountInfo,
+    generation: u64,
+) -> ProgramResult {
+    if lease_info.owner != &crate::ID {
+        msg!("Lease not acquired");
+        return Err(RecordError::LeaseFenced.into());
+    }
+    let raw_data = lease_info.data.borrow();
+    let lease = bytemuck::try_from_bytes::<LeaseData>(&raw_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if !lease.is_lease() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if &lease.holder != writer_info.key || lease.generation() != generation {
+        msg!(
+            "Lease generation {} held by {}, write fenced with generation {}",
+            lease.generation(),
+            lease.holder,
+            generation
+        );
+        return Err(RecordError::LeaseFenced.into());
+    }
+    if !lease.is_held_at(Clock::get()?.slot) {
+        msg!("Lease expired at slot {}", lease.expiry_slot());
+        return Err(RecordError::LeaseFenced.into());
+    }
+    Ok(())
+}
+
 /// Instruction processor
 pub fn process_instruction(
     _program_id: &Pubkey,
@@ -49,7 +194,7 @@ pub fn process_instruction(
                 &mut raw_data[..RecordData::WRITABLE_START_INDEX],
             )
             .map_err(|_| ProgramError::InvalidArgument)?;
-            if account_data.is_initialized() {
+            if is_claimed(account_data) {
                 msg!("Record account already initialized");
                 return Err(ProgramError::AccountAlreadyInitialized);
             }
@@ -59,26 +204,35 @@ pub fn process_instruction(
             Ok(())
         }
 
-        RecordInstruction::Write { offset, data } => {
+        RecordInstruction::Write {
+            offset,
+            data,
+            generation,
+        } => {
             msg!("RecordInstruction::Write");
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
-            {
+            if let Some(generation) = generation {
+                let lease_info = next_account_info(account_info_iter)?;
+                check_fence(lease_info, authority_info, generation)?;
+            }
+            let writable_start_index = {
                 let raw_data = &data_info.data.borrow();
-                if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                    return Err(ProgramError::InvalidAccountData);
-                }
-                let account_data = bytemuck::try_from_bytes::<RecordData>(
-                    &raw_data[..RecordData::WRITABLE_START_INDEX],
-                )
-                .map_err(|_| ProgramError::InvalidArgument)?;
+                let (account_data, grants, body) = split_record_data(raw_data)?;
                 if !account_data.is_initialized() {
                     msg!("Record account not initialized");
                     return Err(ProgramError::UninitializedAccount);
                 }
-                check_authority(authority_info, &account_data.authority)?;
-            }
-            let start = RecordData::WRITABLE_START_INDEX.saturating_add(offset as usize);
+                check_writer(
+                    authority_info,
+                    account_data,
+                    grants,
+                    offset,
+                    data.len() as u64,
+                )?;
+                raw_data.len() - body.len()
+            };
+            let start = writable_start_index.saturating_add(offset as usize);
             let end = start.saturating_add(data.len());
             if end > data_info.data.borrow().len() {
                 Err(ProgramError::AccountDataTooSmall)
@@ -142,25 +296,20 @@ pub fn process_instruction(
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
 
-            {
-                let raw_data = &mut data_info.data.borrow_mut();
-                if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                    return Err(ProgramError::InvalidAccountData);
-                }
-                let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
-                    &mut raw_data[..RecordData::WRITABLE_START_INDEX],
-                )
-                .map_err(|_| ProgramError::InvalidArgument)?;
+            let writable_start_index = {
+                let raw_data = &data_info.data.borrow();
+                let (account_data, _grants, body) = split_record_data(raw_data)?;
                 if !account_data.is_initialized() {
                     msg!("Record not initialized");
                     return Err(ProgramError::UninitializedAccount);
                 }
                 check_authority(authority_info, &account_data.authority)?;
-            }
+                raw_data.len() - body.len()
+            };
 
-            // needed account length is the sum of the meta data length and the specified
-            // data length
-            let needed_account_length = std::mem::size_of::<RecordData>()
+            // needed account length is the sum of the meta data length (including
+            // any section grant table) and the specified data length
+            let needed_account_length = writable_start_index
                 .checked_add(
                     usize::try_from(data_length).map_err(|_| ProgramError::InvalidArgument)?,
                 )
@@ -180,5 +329,198 @@ pub fn process_instruction(
             data_info.resize(needed_account_length)?;
             Ok(())
         }
+
+        RecordInstruction::InitializeSectioned { grants } => {
+            msg!("RecordInstruction::InitializeSectioned");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+
+            if !validate_section_grants(grants) {
+                msg!("Invalid section grants");
+                return Err(RecordError::InvalidSectionGrants.into());
+            }
+
+            let raw_data = &mut data_info.data.borrow_mut();
+            let writable_start_index = RecordData::sectioned_writable_start_index(grants.len());
+            if raw_data.len() < writable_start_index {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let (header_data, rest) = raw_data.split_at_mut(RecordData::WRITABLE_START_INDEX);
+            let account_data = bytemuck::try_from_bytes_mut::<RecordData>(header_data)
+                .map_err(|_| ProgramError::InvalidArgument)?;
+            if is_claimed(account_data) {
+                msg!("Record account already initialized");
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+
+            account_data.authority = *authority_info.key;
+            account_data.version = RecordData::SECTIONED_VERSION;
+            rest[0] = grants.len() as u8;
+            rest[1..writable_start_index - RecordData::WRITABLE_START_INDEX]
+                .copy_from_slice(bytemuck::cast_slice(grants));
+            Ok(())
+        }
+
+        RecordInstruction::RegisterNamespace { prefix } => {
+            msg!("RecordInstruction::RegisterNamespace");
+
+            let namespace_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+
+            if !authority_info.is_signer {
+                msg!("Namespace authority signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let namespace = NamespaceData::new(*authority_info.key, prefix).ok_or_else(|| {
+                msg!("Namespace prefix must be 1 to 32 bytes");
+                ProgramError::from(RecordError::InvalidNamespace)
+            })?;
+            let (expected_key, bump_seed) = find_namespace_address(prefix);
+            if namespace_info.key != &expected_key {
+                msg!("Namespace account does not match the prefix");
+                return Err(RecordError::InvalidNamespace.into());
+            }
+
+            if namespace_info.owner == &crate::ID {
+                let raw_data = namespace_info.data.borrow();
+                let existing = bytemuck::try_from_bytes::<NamespaceData>(&raw_data)
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+                if existing.authority != *authority_info.key {
+                    msg!("Namespace already registered to {}", existing.authority);
+                    return Err(RecordError::NamespaceTaken.into());
+                }
+                msg!("Namespace already registered to this authority");
+                return Ok(());
+            }
+
+            create_program_account(
+                namespace_info,
+                authority_info,
+                system_program_info,
+                std::mem::size_of::<NamespaceData>(),
+                &[NamespaceData::SEED, prefix, &[bump_seed]],
+            )?;
+            namespace_info
+                .data
+                .borrow_mut()
+                .copy_from_slice(bytemuck::bytes_of(&namespace));
+            Ok(())
+        }
+
+        RecordInstruction::AcquireLease {
+            name,
+            duration_slots,
+            nonce,
+        } => {
+            msg!("RecordInstruction::AcquireLease");
+
+            let lease_info = next_account_info(account_info_iter)?;
+            let holder_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+
+            if !holder_info.is_signer {
+                msg!("Lease holder signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let bump_seed = check_lease_address(lease_info, name)?;
+            if duration_slots == 0 || duration_slots > LeaseData::MAX_DURATION_SLOTS {
+                msg!(
+                    "Lease duration must be 1 to {} slots",
+                    LeaseData::MAX_DURATION_SLOTS
+                );
+                return Err(RecordError::InvalidLease.into());
+            }
+
+            let current_slot = Clock::get()?.slot;
+            let expiry_slot = current_slot
+                .checked_add(duration_slots)
+                .ok_or(RecordError::Overflow)?;
+
+            if lease_info.owner != &crate::ID {
+                create_program_account(
+                    lease_info,
+                    holder_info,
+                    system_program_info,
+                    std::mem::size_of::<LeaseData>(),
+                    &[LeaseData::SEED, name, &[bump_seed]],
+                )?;
+                let lease = LeaseData::new(*holder_info.key, nonce, name, expiry_slot, 1)
+                    .ok_or(RecordError::InvalidLease)?;
+                lease_info
+                    .data
+                    .borrow_mut()
+                    .copy_from_slice(bytemuck::bytes_of(&lease));
+                msg!("Lease acquired until slot {}", expiry_slot);
+                return Ok(());
+            }
+
+            let raw_data = &mut lease_info.data.borrow_mut();
+            let lease = bytemuck::try_from_bytes_mut::<LeaseData>(raw_data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if !lease.is_lease() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if !lease.is_held_by(holder_info.key, nonce) {
+                if lease.is_held_at(current_slot) {
+                    msg!(
+                        "Lease held by {} until slot {}",
+                        lease.holder,
+                        lease.expiry_slot()
+                    );
+                    return Err(RecordError::LeaseHeld.into());
+                }
+                // Take over the expired lease, also when another instance of
+                // the same holder acquired it.
+                let generation = lease
+                    .generation()
+                    .checked_add(1)
+                    .ok_or(RecordError::Overflow)?;
+                msg!("Lease taken over from {}", lease.holder);
+                lease.holder = *holder_info.key;
+                lease.nonce = nonce.to_le_bytes();
+                lease.generation = generation.to_le_bytes();
+            }
+            lease.expiry_slot = expiry_slot.to_le_bytes();
+            msg!(
+                "Lease generation {} held until slot {}",
+                lease.generation(),
+                expiry_slot
+            );
+            Ok(())
+        }
+
+        RecordInstruction::ReleaseLease { name, nonce } => {
+            msg!("RecordInstruction::ReleaseLease");
+
+            let lease_info = next_account_info(account_info_iter)?;
+            let holder_info = next_account_info(account_info_iter)?;
+
+            check_lease_address(lease_info, name)?;
+            if lease_info.owner != &crate::ID {
+                msg!("Lease not acquired");
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let raw_data = &mut lease_info.data.borrow_mut();
+            let lease = bytemuck::try_from_bytes_mut::<LeaseData>(raw_data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if !lease.is_lease() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            check_authority(holder_info, &lease.holder)?;
+
+            // Expire the lease now; the holder stays recorded until taken over.
+            let current_slot = Clock::get()?.slot;
+            if lease.is_held_at(current_slot) {
+                if lease.nonce() != nonce {
+                    msg!("Lease held by another instance of {}", lease.holder);
+                    return Err(RecordError::LeaseHeld.into());
+                }
+                lease.expiry_slot = current_slot.to_le_bytes();
+            }
+            Ok(())
+        }
     }
 }
@@ -1,6 +1,8 @@
 //! Program state
+use std::mem::size_of;
+
 use bytemuck::{Pod, Zeroable};
-use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+use solana_program::{program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey};
 
 /// Header type for recorded account data
 #[repr(C)]
@@ -17,15 +19,306 @@ impl RecordData {
     /// Version to fill in on new created accounts
     pub const CURRENT_VERSION: u8 = 1;
 
+    /// Version to fill in on new sectioned records, whose header is followed
+    /// by a table of [`SectionGrant`]s
+    pub const SECTIONED_VERSION: u8 = 2;
+
     /// Start of writable account data, after version and authority
     pub const WRITABLE_START_INDEX: usize = 33;
+
+    /// Maximum number of section grants a sectioned record may hold
+    pub const MAX_SECTION_GRANTS: usize = 32;
+
+    /// Whether the record carries a section grant table
+    pub fn is_sectioned(&self) -> bool {
+        self.version == Self::SECTIONED_VERSION
+    }
+
+    /// Start of writable account data for a sectioned record with
+    /// `grant_count` grants, after the header, the grant count byte and the
+    /// grant table
+    pub const fn sectioned_writable_start_index(grant_count: usize) -> usize {
+        Self::WRITABLE_START_INDEX + 1 + grant_count * size_of::<SectionGrant>()
+    }
 }
 
 impl IsInitialized for RecordData {
     /// Is initialized
     fn is_initialized(&self) -> bool {
-        self.version == Self::CURRENT_VERSION
+        self.version == Self::CURRENT_VERSION || self.version == Self::SECTIONED_VERSION
+    }
+}
+
+/// Grant allowing a writer to update one byte range of a sectioned record
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SectionGrant {
+    /// The account allowed to write the section
+    pub writer: Pubkey,
+
+    /// Start of the section relative to the writable data, as little-endian
+    /// `u64`
+    pub offset: [u8; 8],
+
+    /// Length of the section in bytes, as little-endian `u64`
+    pub length: [u8; 8],
+}
+
+impl SectionGrant {
+    /// Create a grant for `writer` over `length` bytes starting at `offset`
+    pub fn new(writer: Pubkey, offset: u64, length: u64) -> Self {
+        Self {
+            writer,
+            offset: offset.to_le_bytes(),
+            length: length.to_le_bytes(),
+        }
+    }
+
+    /// Start of the section relative to the writable data
+    pub fn offset(&self) -> u64 {
+        u64::from_le_bytes(self.offset)
+    }
+
+    /// Length of the section in bytes
+    pub fn length(&self) -> u64 {
+        u64::from_le_bytes(self.length)
+    }
+
+    /// End of the section (exclusive), or `None` on overflow
+    pub fn end(&self) -> Option<u64> {
+        self.offset().checked_add(self.length())
+    }
+
+    /// Whether `length` bytes starting at `offset` lie entirely inside the
+    /// section
+    pub fn contains(&self, offset: u64, length: u64) -> bool {
+        match (self.end(), offset.checked_add(length)) {
+            (Some(section_end), Some(end)) => offset >= self.offset() && end <= section_end,
+            _ => false,
+        }
+    }
+}
+
+/// Check that a section grant table is usable: between one and
+/// [`RecordData::MAX_SECTION_GRANTS`] grants, none empty or overflowing, and
+/// no two sections overlapping
+pub fn validate_section_grants(grants: &[SectionGrant]) -> bool {
+    if grants.is_empty() || grants.len() > RecordData::MAX_SECTION_GRANTS {
+        return false;
+    }
+    let mut ranges = Vec::with_capacity(grants.len());
+    for grant in grants {
+        match grant.end() {
+            Some(end) if grant.length() > 0 => ranges.push((grant.offset(), end)),
+            _ => return false,
+        }
+    }
+    ranges.sort_unstable();
+    ranges.windows(2).all(|pair| pair[0].1 <= pair[1].0)
+}
+
+/// Namespace registry entry, stored at the program address derived from
+/// [`NamespaceData::SEED`] and the seed prefix it claims
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct NamespaceData {
+    /// Struct version, always [`NamespaceData::VERSION`]
+    pub version: u8,
+
+    /// The account that registered the namespace
+    pub authority: Pubkey,
+
+    /// Length of the claimed prefix
+    pub prefix_len: u8,
+
+    /// The claimed prefix, zero-padded
+    pub prefix: [u8; 32],
+}
+
+impl NamespaceData {
+    /// Version identifying namespace accounts, distinct from record versions
+    /// so a namespace can never be initialized or written as a record
+    pub const VERSION: u8 = 3;
+
+    /// Seed prefixing the namespace program address
+    pub const SEED: &'static [u8] = b"namespace";
+
+    /// Maximum length of a namespace prefix, bounded by the PDA seed length
+    pub const MAX_PREFIX_LEN: usize = 32;
+
+    /// Create a namespace entry claiming `prefix` for `authority`, or `None`
+    /// if the prefix is empty or longer than [`NamespaceData::MAX_PREFIX_LEN`]
+    pub fn new(authority: Pubkey, prefix: &[u8]) -> Option<Self> {
+        if prefix.is_empty() || prefix.len() > Self::MAX_PREFIX_LEN {
+            return None;
+        }
+        let mut padded = [0; 32];
+        padded[..prefix.len()].copy_from_slice(prefix);
+        Some(Self {
+            version: Self::VERSION,
+            authority,
+            prefix_len: prefix.len() as u8,
+            prefix: padded,
+        })
+    }
+
+    /// The claimed prefix
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix[..(self.prefix_len as usize).min(Self::MAX_PREFIX_LEN)]
+    }
+
+    /// Whether the account holds a registered namespace
+    pub fn is_registered(&self) -> bool {
+        self.version == Self::VERSION
+    }
+}
+
+/// Program address of the namespace entry for `prefix`
+pub fn find_namespace_address(prefix: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NamespaceData::SEED, prefix], &crate::ID)
+}
+
+/// Single-writer lease, stored at the program address derived from
+/// [`LeaseData::SEED`] and the lease name. Whoever holds an unexpired lease is
+/// the only process that should write the records it guards; the holder
+/// renews it before each batch of writes, and anyone may take it over once it
+/// has expired. Writes can carry the lease generation as a fencing token, see
+/// [`RecordInstruction::Write`](crate::instruction::RecordInstruction::Write).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct LeaseData {
+    /// Struct version, always [`LeaseData::VERSION`]
+    pub version: u8,
+
+    /// The account holding the lease
+    pub holder: Pubkey,
+
+    /// First slot at which the lease is expired, as little-endian `u64`
+    pub expiry_slot: [u8; 8],
+
+    /// Number of times the lease changed hands, as little-endian `u64`.
+    /// Writers can stamp it on what they write to fence off a previous holder
+    /// that has not noticed it lost the lease.
+    pub generation: [u8; 8],
+
+    /// Nonce the holding process picked when it acquired the lease, as
+    /// little-endian `u64`. It tells apart two instances signing with the same
+    /// key: only the one that acquired a live lease may renew or release it.
+    pub nonce: [u8; 8],
+
+    /// Length of the lease name
+    pub name_len: u8,
+
+    /// The lease name, zero-padded
+    pub name: [u8; 32],
+}
+
+impl LeaseData {
+    /// Version identifying lease accounts, distinct from record and namespace
+    /// versions
+    pub const VERSION: u8 = 4;
+
+    /// Seed prefixing the lease program address
+    pub const SEED: &'static [u8] = b"lease";
+
+    /// Maximum length of a lease name, bounded by the PDA seed length
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Longest lease that may be acquired or renewed at once, about a day of
+    /// 400ms slots
+    pub const MAX_DURATION_SLOTS: u64 = 216_000;
+
+    /// Create a lease named `name` held by the `holder` instance picking
+    /// `nonce`, or `None` if the name is empty or longer than
+    /// [`LeaseData::MAX_NAME_LEN`]
+    pub fn new(
+        holder: Pubkey,
+        nonce: u64,
+        name: &[u8],
+        expiry_slot: u64,
+        generation: u64,
+    ) -> Option<Self> {
+        if name.is_empty() || name.len() > Self::MAX_NAME_LEN {
+            return None;
+        }
+        let mut padded = [0; 32];
+        padded[..name.len()].copy_from_slice(name);
+        Some(Self {
+            version: Self::VERSION,
+            holder,
+            expiry_slot: expiry_slot.to_le_bytes(),
+            generation: generation.to_le_bytes(),
+            nonce: nonce.to_le_bytes(),
+            name_len: name.len() as u8,
+            name: padded,
+        })
+    }
+
+    /// The lease name
+    pub fn name(&self) -> &[u8] {
+        &self.name[..(self.name_len as usize).min(Self::MAX_NAME_LEN)]
+    }
+
+    /// First slot at which the lease is expired
+    pub fn expiry_slot(&self) -> u64 {
+        u64::from_le_bytes(self.expiry_slot)
+    }
+
+    /// Number of times the lease changed hands
+    pub fn generation(&self) -> u64 {
+        u64::from_le_bytes(self.generation)
+    }
+
+    /// Nonce of the holding instance
+    pub fn nonce(&self) -> u64 {
+        u64::from_le_bytes(self.nonce)
+    }
+
+    /// Whether the lease is still held at `slot`
+    pub fn is_held_at(&self, slot: u64) -> bool {
+        slot < self.expiry_slot()
+    }
+
+    /// Whether the lease was acquired by the `holder` instance picking `nonce`
+    pub fn is_held_by(&self, holder: &Pubkey, nonce: u64) -> bool {
+        &self.holder == holder && self.nonce() == nonce
+    }
+
+    /// Whether the account holds a lease
+    pub fn is_lease(&self) -> bool {
+        self.version == Self::VERSION
+    }
+}
+
+/// Program address of the lease named `name`
+pub fn find_lease_address(name: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LeaseData::SEED, name], &crate::ID)
+}
+
+/// Split initialized record account data into its header, its section grant
+/// table (empty for a non-sectioned record) and its writable data
+pub fn split_record_data(
+    raw_data: &[u8],
+) -> Result<(&RecordData, &[SectionGrant], &[u8]), ProgramError> {
+    if raw_data.len() < RecordData::WRITABLE_START_INDEX {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let (header_data, rest) = raw_data.split_at(RecordData::WRITABLE_START_INDEX);
+    let header = bytemuck::try_from_bytes::<RecordData>(header_data)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    if !header.is_sectioned() {
+        return Ok((header, &[], rest));
     }
+
+    let (&grant_count, rest) = rest.split_first().ok_or(ProgramError::InvalidAccountData)?;
+    let table_len = (grant_count as usize) * size_of::<SectionGrant>();
+    if rest.len() < table_len {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let (table_data, body) = rest.split_at(table_len);
+    let grants = bytemuck::try_cast_slice::<u8, SectionGrant>(table_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok((header, grants, body))
 }
 
 #[cfg(test)]
@@ -57,6 +350,128 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn section_grant_contains() {
+        let grant = SectionGrant::new(TEST_PUBKEY, 8, 16);
+        assert!(grant.contains(8, 16));
+        assert!(grant.contains(10, 4));
+        assert!(grant.contains(24, 0));
+        assert!(!grant.contains(7, 2));
+        assert!(!grant.contains(20, 8));
+        assert!(!grant.contains(u64::MAX, 2));
+        assert_eq!(size_of::<SectionGrant>(), 48);
+    }
+
+    #[test]
+    fn validate_grants() {
+        let a = Pubkey::new_from_array([1; 32]);
+        let b = Pubkey::new_from_array([2; 32]);
+        assert!(validate_section_grants(&[
+            SectionGrant::new(b, 16, 16),
+            SectionGrant::new(a, 0, 16),
+        ]));
+        // Overlapping, empty and overflowing sections are rejected.
+        assert!(!validate_section_grants(&[
+            SectionGrant::new(a, 0, 16),
+            SectionGrant::new(b, 15, 16),
+        ]));
+        assert!(!validate_section_grants(&[SectionGrant::new(a, 0, 0)]));
+        assert!(!validate_section_grants(&[SectionGrant::new(
+            a,
+            u64::MAX,
+            2
+        )]));
+        assert!(!validate_section_grants(&[]));
+        assert!(!validate_section_grants(
+            &[SectionGrant::new(a, 0, 1); RecordData::MAX_SECTION_GRANTS + 1]
+        ));
+    }
+
+    #[test]
+    fn split_sectioned_record() {
+        let grants = [
+            SectionGrant::new(Pubkey::new_from_array([1; 32]), 0, 4),
+            SectionGrant::new(Pubkey::new_from_array([2; 32]), 4, 4),
+        ];
+        let mut raw = vec![RecordData::SECTIONED_VERSION];
+        raw.extend_from_slice(&TEST_PUBKEY.to_bytes());
+        raw.push(grants.len() as u8);
+        raw.extend_from_slice(bytemuck::cast_slice(&grants));
+        raw.extend_from_slice(&TEST_BYTES);
+        assert_eq!(
+            RecordData::sectioned_writable_start_index(grants.len()),
+            raw.len() - TEST_BYTES.len()
+        );
+
+        let (header, table, body) = split_record_data(&raw).unwrap();
+        assert!(header.is_sectioned());
+        assert!(header.is_initialized());
+        assert_eq!(table, grants);
+        assert_eq!(body, TEST_BYTES);
+
+        // A truncated grant table is rejected.
+        assert_eq!(
+            split_record_data(&raw[..RecordData::WRITABLE_START_INDEX + 10]).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        // Non-sectioned records have no grants.
+        let mut plain = bytemuck::bytes_of(&TEST_RECORD_DATA).to_vec();
+        plain.extend_from_slice(&TEST_BYTES);
+        let (_, table, body) = split_record_data(&plain).unwrap();
+        assert!(table.is_empty());
+        assert_eq!(body, TEST_BYTES);
+    }
+
+    #[test]
+    fn namespace_data() {
+        let namespace = NamespaceData::new(TEST_PUBKEY, b"telemetry").unwrap();
+        assert!(namespace.is_registered());
+        assert_eq!(namespace.prefix(), b"telemetry");
+        assert_eq!(size_of::<NamespaceData>(), 66);
+
+        let raw = bytemuck::bytes_of(&namespace);
+        assert_eq!(raw[0], NamespaceData::VERSION);
+        assert_eq!(&raw[1..33], TEST_PUBKEY.as_ref());
+        assert_eq!(raw[33], 9);
+
+        assert!(NamespaceData::new(TEST_PUBKEY, b"").is_none());
+        assert!(NamespaceData::new(TEST_PUBKEY, &[1; 33]).is_none());
+        assert!(NamespaceData::new(TEST_PUBKEY, &[1; 32]).is_some());
+
+        // Namespace entries are never mistaken for records.
+        let header =
+            bytemuck::try_from_bytes::<RecordData>(&raw[..RecordData::WRITABLE_START_INDEX])
+                .unwrap();
+        assert!(!header.is_initialized());
+    }
+
+    #[test]
+    fn lease_data() {
+        let lease = LeaseData::new(TEST_PUBKEY, 7, b"scheduler", 100, 1).unwrap();
+        assert!(lease.is_lease());
+        assert_eq!(lease.name(), b"scheduler");
+        assert_eq!(lease.expiry_slot(), 100);
+        assert_eq!(lease.generation(), 1);
+        assert_eq!(lease.nonce(), 7);
+        assert!(lease.is_held_at(99));
+        assert!(!lease.is_held_at(100));
+        assert!(lease.is_held_by(&TEST_PUBKEY, 7));
+        assert!(!lease.is_held_by(&TEST_PUBKEY, 8));
+        assert!(!lease.is_held_by(&Pubkey::new_from_array([1; 32]), 7));
+        assert_eq!(size_of::<LeaseData>(), 90);
+
+        assert!(LeaseData::new(TEST_PUBKEY, 7, b"", 100, 1).is_none());
+        assert!(LeaseData::new(TEST_PUBKEY, 7, &[1; 33], 100, 1).is_none());
+
+        // Leases are never mistaken for records.
+        let raw = bytemuck::bytes_of(&lease);
+        let header =
+            bytemuck::try_from_bytes::<RecordData>(&raw[..RecordData::WRITABLE_START_INDEX])
+                .unwrap();
+        assert!(!header.is_initialized());
+    }
+
     #[test]
     fn deserialize_invalid_slice() {
         let mut expected = vec![TEST_VERSION];
@@ -1,5 +1,12 @@
 use doublezero_record::{
-    error::RecordError, instruction, processor::process_instruction, state::RecordData, ID,
+    error::RecordError,
+    instruction,
+    processor::process_instruction,
+    state::{
+        find_lease_address, find_namespace_address, split_record_data, LeaseData, NamespaceData,
+        RecordData, SectionGrant,
+    },
+    ID,
 };
 use solana_program_test::{processor, tokio, ProgramTest, ProgramTestContext};
 use solana_sdk::{
@@ -240,7 +247,12 @@ async fn write_fail_unsigned() {
                 AccountMeta::new(account.pubkey(), false),
                 AccountMeta::new_readonly(authority.pubkey(), false),
             ],
-            data: instruction::RecordInstruction::Write { offset: 0, data }.pack(),
+            data: instruction::RecordInstruction::Write {
+                offset: 0,
+                data,
+                generation: None,
+            }
+            .pack(),
         }],
         Some(&context.payer.pubkey()),
         &[&context.payer],
@@ -685,3 +697,752 @@ async fn reallocate_fail_unsigned() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
     );
 }
+
+async fn initialize_sectioned_account(
+    context: &mut ProgramTestContext,
+    authority: &Keypair,
+    account: &Keypair,
+    grants: &[SectionGrant],
+    data_length: usize,
+) -> Result<(), TransactionError> {
+    let account_length = RecordData::sectioned_writable_start_index(grants.len())
+        .checked_add(data_length)
+        .unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            solana_system_interface::instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                1.max(Rent::default().minimum_balance(account_length)),
+                account_length as u64,
+                &ID,
+            ),
+            instruction::initialize_sectioned(&account.pubkey(), &authority.pubkey(), grants),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, account],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn write_as(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    writer: &Keypair,
+    offset: u64,
+    data: &[u8],
+) -> Result<(), TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction::write(account, &writer.pubkey(), offset, data)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, writer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn sectioned_write_success() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    let account = Keypair::new();
+    let writer_a = Keypair::new();
+    let writer_b = Keypair::new();
+    let grants = [
+        SectionGrant::new(writer_a.pubkey(), 0, 8),
+        SectionGrant::new(writer_b.pubkey(), 8, 8),
+    ];
+    initialize_sectioned_account(&mut context, &authority, &account, &grants, 24)
+        .await
+        .unwrap();
+
+    write_as(&mut context, &account.pubkey(), &writer_a, 0, &[1u8; 8])
+        .await
+        .unwrap();
+    write_as(&mut context, &account.pubkey(), &writer_b, 12, &[2u8; 4])
+        .await
+        .unwrap();
+    // The authority may write anywhere, including outside of every section.
+    write_as(&mut context, &account.pubkey(), &authority, 16, &[3u8; 8])
+        .await
+        .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let (account_data, table, body) = split_record_data(&account.data).unwrap();
+    assert_eq!(account_data.authority, authority.pubkey());
+    assert_eq!(account_data.version, RecordData::SECTIONED_VERSION);
+    assert_eq!(table, grants);
+    let mut expected = vec![1u8; 8];
+    expected.extend_from_slice(&[0u8; 4]);
+    expected.extend_from_slice(&[2u8; 4]);
+    expected.extend_from_slice(&[3u8; 8]);
+    assert_eq!(body, expected);
+}
+
+#[tokio::test]
+async fn sectioned_write_fail_outside_section() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    let account = Keypair::new();
+    let writer_a = Keypair::new();
+    let writer_b = Keypair::new();
+    let grants = [
+        SectionGrant::new(writer_a.pubkey(), 0, 8),
+        SectionGrant::new(writer_b.pubkey(), 8, 8),
+    ];
+    initialize_sectioned_account(&mut context, &authority, &account, &grants, 16)
+        .await
+        .unwrap();
+
+    // Spilling into the next writer's section is rejected.
+    assert_eq!(
+        write_as(&mut context, &account.pubkey(), &writer_a, 4, &[1u8; 8])
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(RecordError::WriteOutsideSection as u32)
+        )
+    );
+
+    // A signer without any grant is treated like a wrong authority.
+    let stranger = Keypair::new();
+    assert_eq!(
+        write_as(&mut context, &account.pubkey(), &stranger, 0, &[1u8; 8])
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(RecordError::IncorrectAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn sectioned_write_fail_unsigned() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    let account = Keypair::new();
+    let writer = Keypair::new();
+    let grants = [SectionGrant::new(writer.pubkey(), 0, 8)];
+    initialize_sectioned_account(&mut context, &authority, &account, &grants, 8)
+        .await
+        .unwrap();
+
+    let data = &[1u8; 8];
+    let transaction = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: ID,
+            accounts: vec![
+                AccountMeta::new(account.pubkey(), false),
+                AccountMeta::new_readonly(writer.pubkey(), false),
+            ],
+            data: instruction::RecordInstruction::Write {
+                offset: 0,
+                data,
+                generation: None,
+            }
+            .pack(),
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn initialize_sectioned_fail_overlapping() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    let account = Keypair::new();
+    let grants = [
+        SectionGrant::new(Pubkey::new_unique(), 0, 8),
+        SectionGrant::new(Pubkey::new_unique(), 4, 8),
+    ];
+    assert_eq!(
+        initialize_sectioned_account(&mut context, &authority, &account, &grants, 16)
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::InvalidSectionGrants as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn sectioned_reallocate_success() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    let account = Keypair::new();
+    let grants = [SectionGrant::new(Pubkey::new_unique(), 0, 8)];
+    initialize_sectioned_account(&mut context, &authority, &account, &grants, 8)
+        .await
+        .unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            solana_system_interface::instruction::transfer(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                Rent::default().minimum_balance(1_024),
+            ),
+            instruction::reallocate(&account.pubkey(), &authority.pubkey(), 32),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // The grant table is kept and the writable data grows to the requested
+    // length.
+    let account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let (_, table, body) = split_record_data(&account.data).unwrap();
+    assert_eq!(table, grants);
+    assert_eq!(body.len(), 32);
+}
+
+async fn register_namespace_as(
+    context: &mut ProgramTestContext,
+    authority: &Keypair,
+    prefix: &[u8],
+) -> Result<(), TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            solana_system_interface::instruction::transfer(
+                &context.payer.pubkey(),
+                &authority.pubkey(),
+                1_000_000_000,
+            ),
+            instruction::register_namespace(&authority.pubkey(), prefix),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn register_namespace_success() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    register_namespace_as(&mut context, &authority, b"telemetry")
+        .await
+        .unwrap();
+
+    let (namespace_key, _) = find_namespace_address(b"telemetry");
+    let account = context
+        .banks_client
+        .get_account(namespace_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, ID);
+    let namespace = bytemuck::try_from_bytes::<NamespaceData>(&account.data).unwrap();
+    assert!(namespace.is_registered());
+    assert_eq!(namespace.authority, authority.pubkey());
+    assert_eq!(namespace.prefix(), b"telemetry");
+
+    // Registering again as the same authority is a no-op.
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    register_namespace_as(&mut context, &authority, b"telemetry")
+        .await
+        .unwrap();
+
+    // The namespace account can never be initialized as a record.
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction::initialize(
+            &namespace_key,
+            &Keypair::new().pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn register_namespace_fail_taken() {
+    let mut context = program_test().start_with_context().await;
+
+    register_namespace_as(&mut context, &Keypair::new(), b"sentinel")
+        .await
+        .unwrap();
+    assert_eq!(
+        register_namespace_as(&mut context, &Keypair::new(), b"sentinel")
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::NamespaceTaken as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn register_namespace_prefunded_success() {
+    let mut context = program_test().start_with_context().await;
+
+    // Lamports sent to the namespace address ahead of time do not block the
+    // registration.
+    let (namespace_key, _) = find_namespace_address(b"geolocation");
+    let transaction = Transaction::new_signed_with_payer(
+        &[solana_system_interface::instruction::transfer(
+            &context.payer.pubkey(),
+            &namespace_key,
+            1_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let authority = Keypair::new();
+    register_namespace_as(&mut context, &authority, b"geolocation")
+        .await
+        .unwrap();
+    let account = context
+        .banks_client
+        .get_account(namespace_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        account.lamports,
+        Rent::default().minimum_balance(std::mem::size_of::<NamespaceData>())
+    );
+}
+
+#[tokio::test]
+async fn register_namespace_fail_invalid() {
+    let mut context = program_test().start_with_context().await;
+
+    let authority = Keypair::new();
+    assert_eq!(
+        register_namespace_as(&mut context, &authority, b"")
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::InvalidNamespace as u32)
+        )
+    );
+
+    // The namespace account must be derived from the prefix.
+    let mut ix = instruction::register_namespace(&authority.pubkey(), b"telemetry");
+    ix.accounts[0].pubkey = find_namespace_address(b"sentinel").0;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(RecordError::InvalidNamespace as u32)
+        )
+    );
+}
+
+async fn send_as(
+    context: &mut ProgramTestContext,
+    signer: &Keypair,
+    ix: Instruction,
+) -> Result<(), TransactionError> {
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            solana_system_interface::instruction::transfer(
+                &context.payer.pubkey(),
+                &signer.pubkey(),
+                1_000_000_000,
+            ),
+            ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn get_lease(context: &mut ProgramTestContext, name: &[u8]) -> LeaseData {
+    let account = context
+        .banks_client
+        .get_account(find_lease_address(name).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, ID);
+    *bytemuck::try_from_bytes::<LeaseData>(&account.data).unwrap()
+}
+
+async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+    context.banks_client.get_root_slot().await.unwrap()
+}
+
+#[tokio::test]
+async fn lease_acquire_renew_and_takeover() {
+    let mut context = program_test().start_with_context().await;
+    let first = Keypair::new();
+    let second = Keypair::new();
+
+    send_as(
+        &mut context,
+        &first,
+        instruction::acquire_lease(&first.pubkey(), b"scheduler", 1, 100),
+    )
+    .await
+    .unwrap();
+    let lease = get_lease(&mut context, b"scheduler").await;
+    assert!(lease.is_lease());
+    assert_eq!(lease.holder, first.pubkey());
+    assert_eq!(lease.generation(), 1);
+    assert_eq!(lease.name(), b"scheduler");
+    let slot = current_slot(&mut context).await;
+    assert!(lease.is_held_at(slot));
+
+    // Another account cannot take over an unexpired lease.
+    assert_eq!(
+        send_as(
+            &mut context,
+            &second,
+            instruction::acquire_lease(&second.pubkey(), b"scheduler", 2, 100),
+        )
+        .await
+        .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::LeaseHeld as u32)
+        )
+    );
+
+    // The holder renews without changing the generation.
+    context.warp_to_slot(slot + 50).unwrap();
+    send_as(
+        &mut context,
+        &first,
+        instruction::acquire_lease(&first.pubkey(), b"scheduler", 1, 100),
+    )
+    .await
+    .unwrap();
+    let renewed = get_lease(&mut context, b"scheduler").await;
+    assert!(renewed.expiry_slot() > lease.expiry_slot());
+    assert_eq!(renewed.generation(), 1);
+
+    // Once expired, the lease can be taken over and its generation moves on.
+    context.warp_to_slot(renewed.expiry_slot() + 1).unwrap();
+    send_as(
+        &mut context,
+        &second,
+        instruction::acquire_lease(&second.pubkey(), b"scheduler", 2, 100),
+    )
+    .await
+    .unwrap();
+    let taken = get_lease(&mut context, b"scheduler").await;
+    assert_eq!(taken.holder, second.pubkey());
+    assert_eq!(taken.generation(), 2);
+
+    // The lease account can never be initialized as a record.
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction::initialize(
+            &find_lease_address(b"scheduler").0,
+            &first.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn lease_release_allows_takeover() {
+    let mut context = program_test().start_with_context().await;
+    let first = Keypair::new();
+    let second = Keypair::new();
+
+    send_as(
+        &mut context,
+        &first,
+        instruction::acquire_lease(&first.pubkey(), b"scheduler", 1, 1_000),
+    )
+    .await
+    .unwrap();
+
+    // Only the holder may release the lease.
+    assert_eq!(
+        send_as(
+            &mut context,
+            &second,
+            instruction::release_lease(&second.pubkey(), b"scheduler", 2),
+        )
+        .await
+        .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::IncorrectAuthority as u32)
+        )
+    );
+
+    send_as(
+        &mut context,
+        &first,
+        instruction::release_lease(&first.pubkey(), b"scheduler", 1),
+    )
+    .await
+    .unwrap();
+    send_as(
+        &mut context,
+        &second,
+        instruction::acquire_lease(&second.pubkey(), b"scheduler", 2, 1_000),
+    )
+    .await
+    .unwrap();
+    let lease = get_lease(&mut context, b"scheduler").await;
+    assert_eq!(lease.holder, second.pubkey());
+    assert_eq!(lease.generation(), 2);
+}
+
+#[tokio::test]
+async fn lease_fail_invalid() {
+    let mut context = program_test().start_with_context().await;
+    let holder = Keypair::new();
+
+    for duration_slots in [0, LeaseData::MAX_DURATION_SLOTS + 1] {
+        assert_eq!(
+            send_as(
+                &mut context,
+                &holder,
+                instruction::acquire_lease(&holder.pubkey(), b"scheduler", 1, duration_slots),
+            )
+            .await
+            .unwrap_err(),
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(RecordError::InvalidLease as u32)
+            )
+        );
+    }
+
+    // The lease account must be derived from the name.
+    let mut ix = instruction::acquire_lease(&holder.pubkey(), b"scheduler", 1, 100);
+    ix.accounts[0].pubkey = find_lease_address(b"other").0;
+    assert_eq!(
+        send_as(&mut context, &holder, ix).await.unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::InvalidLease as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn lease_same_key_race_is_fenced() {
+    let mut context = program_test().start_with_context().await;
+    let holder = Keypair::new();
+    let record = Keypair::new();
+    initialize_storage_account(&mut context, &holder, &record, &[0; 8]).await;
+
+    // Two instances of a job sign with the same key and pick different nonces.
+    let (first_nonce, second_nonce) = (1, 2);
+    send_as(
+        &mut context,
+        &holder,
+        instruction::acquire_lease(&holder.pubkey(), b"scheduler", first_nonce, 100),
+    )
+    .await
+    .unwrap();
+    let lease = get_lease(&mut context, b"scheduler").await;
+    assert_eq!(lease.nonce(), first_nonce);
+    send_as(
+        &mut context,
+        &holder,
+        instruction::write_fenced(
+            &record.pubkey(),
+            &holder.pubkey(),
+            b"scheduler",
+            1,
+            0,
+            &[1; 8],
+        ),
+    )
+    .await
+    .unwrap();
+
+    // The second instance can neither renew nor release the live lease.
+    for ix in [
+        instruction::acquire_lease(&holder.pubkey(), b"scheduler", second_nonce, 100),
+        instruction::release_lease(&holder.pubkey(), b"scheduler", second_nonce),
+    ] {
+        assert_eq!(
+            send_as(&mut context, &holder, ix).await.unwrap_err(),
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(RecordError::LeaseHeld as u32)
+            )
+        );
+    }
+
+    // Once the lease expires, the first instance's writes are fenced off.
+    context.warp_to_slot(lease.expiry_slot() + 1).unwrap();
+    assert_eq!(
+        send_as(
+            &mut context,
+            &holder,
+            instruction::write_fenced(
+                &record.pubkey(),
+                &holder.pubkey(),
+                b"scheduler",
+                1,
+                0,
+                &[1; 8]
+            ),
+        )
+        .await
+        .unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(RecordError::LeaseFenced as u32)
+        )
+    );
+
+    // The second instance takes over, which moves the generation on.
+    send_as(
+        &mut context,
+        &holder,
+        instruction::acquire_lease(&holder.pubkey(), b"scheduler", second_nonce, 100),
+    )
+    .await
+    .unwrap();
+    let taken = get_lease(&mut context, b"scheduler").await;
+    assert_eq!(taken.holder, holder.pubkey());
+    assert_eq!(taken.nonce(), second_nonce);
+    assert_eq!(taken.generation(), 2);
+
+    // The first instance can no longer renew, and its writes at the old
+    // generation stay fenced off while the new holder's land.
+    let race = [
+        (
+            instruction::acquire_lease(&holder.pubkey(), b"scheduler", first_nonce, 100),
+            RecordError::LeaseHeld,
+        ),
+        (
+            instruction::write_fenced(
+                &record.pubkey(),
+                &holder.pubkey(),
+                b"scheduler",
+                1,
+                0,
+                &[3; 8],
+            ),
+            RecordError::LeaseFenced,
+        ),
+    ];
+    for (ix, error) in race {
+        assert_eq!(
+            send_as(&mut context, &holder, ix).await.unwrap_err(),
+            TransactionError::InstructionError(1, InstructionError::Custom(error as u32))
+        );
+    }
+    send_as(
+        &mut context,
+        &holder,
+        instruction::write_fenced(
+            &record.pubkey(),
+            &holder.pubkey(),
+            b"scheduler",
+            2,
+            0,
+            &[2; 8],
+        ),
+    )
+    .await
+    .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(record.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let (_, _, body) = split_record_data(&account.data).unwrap();
+    assert_eq!(body, [2; 8]);
+}
@@ -0,0 +1,59 @@
+//! Reads arbitrary bytes with the account reader selected by their first byte.
+//! Readers may reject the data but must not panic, and whatever they accept
+//! must be stable across a write and a second read.
+
+#![no_main]
+
+use doublezero_program_common::compat_deserialize::check_reread_stable;
+use doublezero_serviceability::state::{
+    accesspass::AccessPass, accounttype::AccountType, anycast_prefix::AnycastPrefix,
+    contributor::Contributor, device::Device, exchange::Exchange, feed::Feed,
+    globalconfig::GlobalConfig, globalstate::GlobalState, index::Index,
+    interface_oper_status::InterfaceOperStatus, ip_allocation::IpAllocation, link::Link,
+    location::Location, multicastgroup::MulticastGroup, permission::Permission,
+    programconfig::ProgramConfig, qos_profile::QosProfile, read_api_key::ReadApiKey,
+    referrer_stats::ReferrerStats, resource_extension::ResourceExtensionOwned,
+    state_commitment::StateCommitment, tenant::Tenant, topology::TopologyInfo, user::User,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some(&first) = data.first() else {
+        return;
+    };
+    let check = match AccountType::from(first) {
+        AccountType::GlobalState => check_reread_stable::<GlobalState>(data),
+        AccountType::GlobalConfig => check_reread_stable::<GlobalConfig>(data),
+        AccountType::Location => check_reread_stable::<Location>(data),
+        AccountType::Exchange => check_reread_stable::<Exchange>(data),
+        AccountType::Device => check_reread_stable::<Device>(data),
+        AccountType::Link => check_reread_stable::<Link>(data),
+        AccountType::User => check_reread_stable::<User>(data),
+        AccountType::MulticastGroup => check_reread_stable::<MulticastGroup>(data),
+        AccountType::ProgramConfig => check_reread_stable::<ProgramConfig>(data),
+        AccountType::Contributor => check_reread_stable::<Contributor>(data),
+        AccountType::AccessPass => check_reread_stable::<AccessPass>(data),
+        AccountType::Tenant => check_reread_stable::<Tenant>(data),
+        AccountType::Permission => check_reread_stable::<Permission>(data),
+        AccountType::Index => check_reread_stable::<Index>(data),
+        AccountType::Topology => check_reread_stable::<TopologyInfo>(data),
+        AccountType::Feed => check_reread_stable::<Feed>(data),
+        AccountType::ReadApiKey => check_reread_stable::<ReadApiKey>(data),
+        AccountType::QosProfile => check_reread_stable::<QosProfile>(data),
+        AccountType::IpAllocation => check_reread_stable::<IpAllocation>(data),
+        AccountType::InterfaceOperStatus => check_reread_stable::<InterfaceOperStatus>(data),
+        AccountType::AnycastPrefix => check_reread_stable::<AnycastPrefix>(data),
+        AccountType::ReferrerStats => check_reread_stable::<ReferrerStats>(data),
+        AccountType::StateCommitment => check_reread_stable::<StateCommitment>(data),
+        // The bitmap is stored raw after a fixed-size header, so the
+        // extension is only required not to panic.
+        AccountType::ResourceExtension => {
+            let _ = ResourceExtensionOwned::try_from(data);
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+    if let Err(err) = check {
+        panic!("{err}");
+    }
+});
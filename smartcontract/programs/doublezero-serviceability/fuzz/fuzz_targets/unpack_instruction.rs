@@ -0,0 +1,16 @@
+//! Unpacks arbitrary instruction data. Unpacking may fail but must not panic,
+//! and an unpacked instruction must unpack again from its packed form.
+
+#![no_main]
+
+use doublezero_serviceability::instructions::DoubleZeroInstruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(instruction) = DoubleZeroInstruction::unpack(data) else {
+        return;
+    };
+    if let Err(err) = DoubleZeroInstruction::unpack(&instruction.pack()) {
+        panic!("{instruction:?} does not unpack from its packed form: {err}");
+    }
+});
@@ -5,6 +5,7 @@ use crate::{
         accesspass::{
             check_status::process_check_status_access_pass, close::process_close_access_pass,
             set::process_set_access_pass, set_feeds::process_set_access_pass_feeds,
+            settle_lease::process_settle_access_pass_lease,
         },
         allowlist::{
             foundation::{
@@ -16,18 +17,32 @@ use crate::{
                 remove::process_remove_qa_allowlist_globalconfig,
             },
         },
+        anycast_prefix::{
+            create::process_create_anycast_prefix, delete::process_delete_anycast_prefix,
+            update::process_update_anycast_prefix,
+        },
         contributor::{
             create::process_create_contributor, delete::process_delete_contributor,
+            emergency_drain::process_emergency_drain_contributor,
+            emergency_suspend::process_emergency_suspend_contributor,
             resume::process_resume_contributor, suspend::process_suspend_contributor,
             update::process_update_contributor,
         },
         device::{
+            ackconfig::process_ack_config_device,
             create::process_create_device,
             delete::process_delete_device,
+            grant_co_owner::process_grant_device_co_owner,
             interface::{
+                bulk_update::process_bulk_update_device_interfaces,
                 create::process_create_device_interface, delete::process_delete_device_interface,
                 update::process_update_device_interface,
             },
+            issue_challenge::process_issue_challenge_device,
+            prove_key::process_prove_key_device,
+            revoke_co_owner::process_revoke_device_co_owner,
+            rotate_metrics_publisher::process_rotate_device_metrics_publisher,
+            set_ops_lock::process_set_device_ops_lock,
             sethealth::process_set_health_device,
             update::process_update_device,
         },
@@ -43,12 +58,20 @@ use crate::{
         globalstate::{
             initialize::initialize_global_state, setairdrop::process_set_airdrop,
             setauthority::process_set_authority, setfeatureflags::process_set_feature_flags,
-            setversion::process_set_version,
+            setpause::process_set_pause, setversion::process_set_version,
         },
         index::{create::process_create_index, delete::process_delete_index},
+        interface_oper_status::{
+            delete::process_delete_interface_oper_status, set::process_set_interface_oper_status,
+        },
+        ip_allocation::{
+            create::process_create_ip_allocation, delete::process_delete_ip_allocation,
+            setstatus::process_set_ip_allocation_status,
+        },
         link::{
             accept::process_accept_link, create::process_create_link, delete::process_delete_link,
-            sethealth::process_set_health_link, update::process_update_link,
+            set_ops_lock::process_set_link_ops_lock, sethealth::process_set_health_link,
+            update::process_update_link,
         },
         location::{
             create::process_create_location, delete::process_delete_location,
@@ -56,6 +79,7 @@ use crate::{
             update::process_update_location,
         },
         migrate::process_migrate,
+        migrateaccount::process_migrate_account,
         multicastgroup::{
             allowlist::{
                 publisher::{
@@ -79,11 +103,20 @@ use crate::{
             resume::process_resume_permission, suspend::process_suspend_permission,
             update::process_update_permission,
         },
+        qos_profile::{
+            create::process_create_qos_profile, delete::process_delete_qos_profile,
+            update::process_update_qos_profile,
+        },
+        read_api_key::{
+            create::process_create_read_api_key, delete::process_delete_read_api_key,
+            revoke::process_revoke_read_api_key,
+        },
         resource::{
             allocate::process_allocate_resource,
             closeaccount::process_closeaccount_resource_extension, create::process_create_resource,
-            deallocate::process_deallocate_resource,
+            deallocate::process_deallocate_resource, resize::process_resize_resource,
         },
+        state_commitment::record::process_record_state_commitment,
         tenant::{
             add_administrator::process_add_administrator_tenant, create::process_create_tenant,
             delete::process_delete_tenant,
@@ -102,6 +135,7 @@ use crate::{
             update::process_update_user,
         },
     },
+    state::{accounttype::AccountType, globalstate::GlobalState, pause_flags::is_paused},
 };
 
 use solana_program::{
@@ -123,6 +157,8 @@ pub fn process_instruction(
 
     msg!("Instruction: {:?}", instruction);
 
+    check_not_paused(program_id, accounts, &instruction)?;
+
     match instruction {
         DoubleZeroInstruction::Migrate(value) => process_migrate(program_id, accounts, &value)?,
         DoubleZeroInstruction::InitGlobalState() => initialize_global_state(program_id, accounts)?,
@@ -290,6 +326,12 @@ pub fn process_instruction(
         DoubleZeroInstruction::ResumeContributor(value) => {
             process_resume_contributor(program_id, accounts, &value)?
         }
+        DoubleZeroInstruction::EmergencySuspendContributor(value) => {
+            process_emergency_suspend_contributor(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::EmergencyDrainContributor(value) => {
+            process_emergency_drain_contributor(program_id, accounts, &value)?
+        }
         DoubleZeroInstruction::DeleteContributor(value) => {
             process_delete_contributor(program_id, accounts, &value)?
         }
@@ -311,6 +353,15 @@ pub fn process_instruction(
         DoubleZeroInstruction::CheckUserAccessPass(value) => {
             process_check_access_pass_user(program_id, accounts, &value)?
         }
+        DoubleZeroInstruction::SettleAccessPassLease(value) => {
+            process_settle_access_pass_lease(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::BulkUpdateDeviceInterfaces(value) => {
+            process_bulk_update_device_interfaces(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::RotateDeviceMetricsPublisher(value) => {
+            process_rotate_device_metrics_publisher(program_id, accounts, &value)?
+        }
         DoubleZeroInstruction::CreateDeviceInterface(value) => {
             process_create_device_interface(program_id, accounts, &value)?
         }
@@ -423,6 +474,119 @@ pub fn process_instruction(
         DoubleZeroInstruction::SetAccessPassFeeds(value) => {
             process_set_access_pass_feeds(program_id, accounts, &value)?
         }
+        DoubleZeroInstruction::ResizeResourceExtension(value) => {
+            process_resize_resource(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::CreateReadApiKey(value) => {
+            process_create_read_api_key(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::RevokeReadApiKey(value) => {
+            process_revoke_read_api_key(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::DeleteReadApiKey(value) => {
+            process_delete_read_api_key(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::CreateQosProfile(value) => {
+            process_create_qos_profile(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::UpdateQosProfile(value) => {
+            process_update_qos_profile(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::DeleteQosProfile(value) => {
+            process_delete_qos_profile(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::AckDeviceConfig(value) => {
+            process_ack_config_device(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::SetPause(value) => process_set_pause(program_id, accounts, &value)?,
+        DoubleZeroInstruction::MigrateAccount(value) => {
+            process_migrate_account(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::CreateIpAllocation(value) => {
+            process_create_ip_allocation(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::SetIpAllocationStatus(value) => {
+            process_set_ip_allocation_status(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::DeleteIpAllocation(value) => {
+            process_delete_ip_allocation(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::SetInterfaceOperStatus(value) => {
+            process_set_interface_oper_status(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::DeleteInterfaceOperStatus(value) => {
+            process_delete_interface_oper_status(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::CreateAnycastPrefix(value) => {
+            process_create_anycast_prefix(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::UpdateAnycastPrefix(value) => {
+            process_update_anycast_prefix(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::DeleteAnycastPrefix(value) => {
+            process_delete_anycast_prefix(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::IssueDeviceChallenge(value) => {
+            process_issue_challenge_device(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::ProveDeviceKey(value) => {
+            process_prove_key_device(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::SetDeviceOpsLock(value) => {
+            process_set_device_ops_lock(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::SetLinkOpsLock(value) => {
+            process_set_link_ops_lock(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::RecordStateCommitment(value) => {
+            process_record_state_commitment(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::GrantDeviceCoOwner(value) => {
+            process_grant_device_co_owner(program_id, accounts, &value)?
+        }
+        DoubleZeroInstruction::RevokeDeviceCoOwner(value) => {
+            process_revoke_device_co_owner(program_id, accounts, &value)?
+        }
+    };
+    Ok(())
+}
+
+/// Rejects the instruction with `ProgramPaused` when its pause class is set in
+/// `GlobalState::paused`. GlobalState is located among the instruction's accounts by
+/// its account type byte (only the program can write it), which avoids deriving the
+/// PDA on every instruction. Every pausable instruction must take GlobalState; one
+/// that arrives without it fails with `NotEnoughAccountKeys` rather than bypassing
+/// the pause, except the agent instructions for which it is an optional trailing
+/// account (see `DoubleZeroInstruction::optional_globalstate`).
+fn check_not_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: &DoubleZeroInstruction,
+) -> ProgramResult {
+    let Some(flag) = instruction.pause_flag() else {
+        return Ok(());
+    };
+    let globalstate_account = accounts.iter().find(|account| {
+        account.owner == program_id
+            && account
+                .try_borrow_data()
+                .map(|data| data.first() == Some(&(AccountType::GlobalState as u8)))
+                .unwrap_or(false)
+    });
+    let Some(globalstate_account) = globalstate_account else {
+        if instruction.optional_globalstate() {
+            return Ok(());
+        }
+        msg!(
+            "Pausable {} instruction is missing the GlobalState account",
+            flag
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    if is_paused(globalstate.paused, flag) {
+        msg!("Program is paused for {} instructions", flag);
+        return Err(DoubleZeroError::ProgramPaused.into());
+    }
     Ok(())
 }
@@ -208,6 +208,52 @@ pub enum DoubleZeroError {
         "Feed billing window is invalid (window_end must be in the future and <= terminates_at)"
     )]
     FeedInvalidBillingWindow, // variant 100
+    #[error("Resource extension has allocations outside the requested range")]
+    ResourceInUse, // variant 101
+    #[error("Bandwidth tier exceeds the access pass tier")]
+    BandwidthTierNotAllowed, // variant 102
+    #[error("QoS profile is invalid (tier must be shaped, rate_mbps > 0 and dscp <= 63)")]
+    InvalidQosProfile, // variant 103
+    #[error("Config generation is ahead of the device's config generation")]
+    InvalidConfigGeneration, // variant 104
+    #[error("Program is paused")]
+    ProgramPaused, // variant 105
+    #[error("IP allocation is invalid (prefix must be a publicly routable network address)")]
+    InvalidIpAllocation, // variant 106
+    #[error(
+        "Interface ip_net must fall within a verified IP allocation of the device's contributor"
+    )]
+    UnregisteredInterfaceIp, // variant 107
+    #[error("Contributor is suspended")]
+    ContributorSuspended, // variant 108
+    #[error(
+        "Lease deposit is invalid (must be non-zero, cannot shrink, and the lease must expire)"
+    )]
+    InvalidLeaseDeposit, // variant 109
+    #[error("Access pass lease has not expired")]
+    LeaseNotExpired, // variant 110
+    #[error("Access pass still holds a lease deposit")]
+    LeaseDepositUnsettled, // variant 111
+    #[error("Invalid metrics publisher rotation")]
+    InvalidMetricsPublisherRotation, // variant 112
+    #[error("Invalid interface oper status")]
+    InvalidInterfaceOperStatus, // variant 113
+    #[error("Anycast prefix is invalid (must be a non-zero /32)")]
+    InvalidAnycastPrefix, // variant 114
+    #[error("Device key has not been attested")]
+    DeviceNotAttested, // variant 115
+    #[error("Invalid device attestation (no pending challenge, or the nonce does not match)")]
+    InvalidDeviceAttestation, // variant 116
+    #[error("Invalid referrer (default, self-referral, or a change to an existing pass)")]
+    InvalidReferrer, // variant 117
+    #[error("Entity is locked by another signer")]
+    EntityLocked, // variant 118
+    #[error("Ops lock is invalid (expiry must be in the future and the reason at most 64 bytes)")]
+    InvalidOpsLock, // variant 119
+    #[error("State commitment is invalid (empty, or its slot is not after the last one and not in the future)")]
+    InvalidStateCommitment, // variant 120
+    #[error("Device co-owner is invalid (it must be set, and differ from the contributor owner)")]
+    InvalidCoOwner, // variant 121
 }
 
 impl From<DoubleZeroError> for ProgramError {
@@ -314,6 +360,27 @@ impl From<DoubleZeroError> for ProgramError {
             DoubleZeroError::FeedMaxFutureUsersBelowMaxUsers => ProgramError::Custom(98),
             DoubleZeroError::FeedInvalidAnniversaryDay => ProgramError::Custom(99),
             DoubleZeroError::FeedInvalidBillingWindow => ProgramError::Custom(100),
+            DoubleZeroError::ResourceInUse => ProgramError::Custom(101),
+            DoubleZeroError::BandwidthTierNotAllowed => ProgramError::Custom(102),
+            DoubleZeroError::InvalidQosProfile => ProgramError::Custom(103),
+            DoubleZeroError::InvalidConfigGeneration => ProgramError::Custom(104),
+            DoubleZeroError::ProgramPaused => ProgramError::Custom(105),
+            DoubleZeroError::InvalidIpAllocation => ProgramError::Custom(106),
+            DoubleZeroError::UnregisteredInterfaceIp => ProgramError::Custom(107),
+            DoubleZeroError::ContributorSuspended => ProgramError::Custom(108),
+            DoubleZeroError::InvalidLeaseDeposit => ProgramError::Custom(109),
+            DoubleZeroError::LeaseNotExpired => ProgramError::Custom(110),
+            DoubleZeroError::LeaseDepositUnsettled => ProgramError::Custom(111),
+            DoubleZeroError::InvalidMetricsPublisherRotation => ProgramError::Custom(112),
+            DoubleZeroError::InvalidInterfaceOperStatus => ProgramError::Custom(113),
+            DoubleZeroError::InvalidAnycastPrefix => ProgramError::Custom(114),
+            DoubleZeroError::DeviceNotAttested => ProgramError::Custom(115),
+            DoubleZeroError::InvalidDeviceAttestation => ProgramError::Custom(116),
+            DoubleZeroError::InvalidReferrer => ProgramError::Custom(117),
+            DoubleZeroError::EntityLocked => ProgramError::Custom(118),
+            DoubleZeroError::InvalidOpsLock => ProgramError::Custom(119),
+            DoubleZeroError::InvalidStateCommitment => ProgramError::Custom(120),
+            DoubleZeroError::InvalidCoOwner => ProgramError::Custom(121),
         }
     }
 }
@@ -421,6 +488,27 @@ impl From<u32> for DoubleZeroError {
             98 => DoubleZeroError::FeedMaxFutureUsersBelowMaxUsers,
             99 => DoubleZeroError::FeedInvalidAnniversaryDay,
             100 => DoubleZeroError::FeedInvalidBillingWindow,
+            101 => DoubleZeroError::ResourceInUse,
+            102 => DoubleZeroError::BandwidthTierNotAllowed,
+            103 => DoubleZeroError::InvalidQosProfile,
+            104 => DoubleZeroError::InvalidConfigGeneration,
+            105 => DoubleZeroError::ProgramPaused,
+            106 => DoubleZeroError::InvalidIpAllocation,
+            107 => DoubleZeroError::UnregisteredInterfaceIp,
+            108 => DoubleZeroError::ContributorSuspended,
+            109 => DoubleZeroError::InvalidLeaseDeposit,
+            110 => DoubleZeroError::LeaseNotExpired,
+            111 => DoubleZeroError::LeaseDepositUnsettled,
+            112 => DoubleZeroError::InvalidMetricsPublisherRotation,
+            113 => DoubleZeroError::InvalidInterfaceOperStatus,
+            114 => DoubleZeroError::InvalidAnycastPrefix,
+            115 => DoubleZeroError::DeviceNotAttested,
+            116 => DoubleZeroError::InvalidDeviceAttestation,
+            117 => DoubleZeroError::InvalidReferrer,
+            118 => DoubleZeroError::EntityLocked,
+            119 => DoubleZeroError::InvalidOpsLock,
+            120 => DoubleZeroError::InvalidStateCommitment,
+            121 => DoubleZeroError::InvalidCoOwner,
             _ => DoubleZeroError::Custom(e),
         }
     }
@@ -455,7 +543,7 @@ mod tests {
         }
 
         // EnumIter generates Custom(0) by default, so we explicitly test values
-        // outside the known variant range (currently 0-100) to ensure the conversion
+        // outside the known variant range (currently 0-121) to ensure the conversion
         // logic handles arbitrary custom codes correctly.
         for code in [1000u32, 100_000, u32::MAX] {
             let err = DoubleZeroError::Custom(code);
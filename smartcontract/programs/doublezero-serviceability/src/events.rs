@@ -0,0 +1,280 @@
+//! Structured events for state transitions.
+//!
+//! Processors emit a [`DoubleZeroEvent`] with `sol_log_data` whenever they
+//! change an account's status or health, so indexers can follow transitions
+//! without parsing `msg!` text. Each event is logged as two data fields, the
+//! [`EVENT_TAG`] and the Borsh encoding of the event, and shows up in the
+//! transaction logs as `Program data: <base64 tag> <base64 event>`. The SDK's
+//! `events` module decodes them back.
+
+use crate::state::{
+    contributor::ContributorStatus,
+    device::{DeviceHealth, DeviceStatus},
+    exchange::ExchangeStatus,
+    ip_allocation::IpAllocationStatus,
+    link::{LinkHealth, LinkStatus},
+    location::LocationStatus,
+    multicastgroup::MulticastGroupStatus,
+    permission::PermissionStatus,
+    read_api_key::ReadApiKeyStatus,
+    user::{User, UserStatus},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use core::fmt;
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+use std::net::Ipv4Addr;
+
+/// First data field of every event, marking the second as a Borsh-encoded
+/// [`DoubleZeroEvent`]. The trailing digit versions the encoding.
+pub const EVENT_TAG: &[u8; 8] = b"dzevent1";
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DoubleZeroEvent {
+    /// A user was created and activated with its allocated resources.
+    UserActivated {
+        user: Pubkey,
+        device: Pubkey,
+        client_ip: Ipv4Addr,
+        dz_ip: Ipv4Addr,
+        tunnel_id: u16,
+    },
+    UserStatusChanged {
+        user: Pubkey,
+        from: UserStatus,
+        to: UserStatus,
+    },
+    LinkStatusChanged {
+        link: Pubkey,
+        from: LinkStatus,
+        to: LinkStatus,
+    },
+    LinkHealthChanged {
+        link: Pubkey,
+        from: LinkHealth,
+        to: LinkHealth,
+    },
+    DeviceStatusChanged {
+        device: Pubkey,
+        from: DeviceStatus,
+        to: DeviceStatus,
+    },
+    DeviceHealthChanged {
+        device: Pubkey,
+        from: DeviceHealth,
+        to: DeviceHealth,
+    },
+    ContributorStatusChanged {
+        contributor: Pubkey,
+        from: ContributorStatus,
+        to: ContributorStatus,
+    },
+    ExchangeStatusChanged {
+        exchange: Pubkey,
+        from: ExchangeStatus,
+        to: ExchangeStatus,
+    },
+    LocationStatusChanged {
+        location: Pubkey,
+        from: LocationStatus,
+        to: LocationStatus,
+    },
+    MulticastGroupStatusChanged {
+        multicastgroup: Pubkey,
+        from: MulticastGroupStatus,
+        to: MulticastGroupStatus,
+    },
+    PermissionStatusChanged {
+        permission: Pubkey,
+        from: PermissionStatus,
+        to: PermissionStatus,
+    },
+    ReadApiKeyStatusChanged {
+        read_api_key: Pubkey,
+        from: ReadApiKeyStatus,
+        to: ReadApiKeyStatus,
+    },
+    IpAllocationStatusChanged {
+        ip_allocation: Pubkey,
+        from: IpAllocationStatus,
+        to: IpAllocationStatus,
+    },
+}
+
+impl DoubleZeroEvent {
+    pub fn user_activated(pubkey: &Pubkey, user: &User) -> Self {
+        DoubleZeroEvent::UserActivated {
+            user: *pubkey,
+            device: user.device_pk,
+            client_ip: user.client_ip,
+            dz_ip: user.dz_ip,
+            tunnel_id: user.tunnel_id,
+        }
+    }
+
+    /// Log the event. Transitions that leave the value unchanged are not
+    /// logged.
+    pub fn emit(&self) {
+        if self.is_noop() {
+            return;
+        }
+        if let Ok(data) = borsh::to_vec(self) {
+            sol_log_data(&[EVENT_TAG, &data]);
+        }
+    }
+
+    /// Decode the data fields of one `sol_log_data` call. `None` if they are
+    /// not an event, or an event this version does not know.
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [tag, data] if *tag == EVENT_TAG => borsh::from_slice(data).ok(),
+            _ => None,
+        }
+    }
+
+    /// The account the event is about.
+    pub fn account(&self) -> &Pubkey {
+        match self {
+            DoubleZeroEvent::UserActivated { user, .. }
+            | DoubleZeroEvent::UserStatusChanged { user, .. } => user,
+            DoubleZeroEvent::LinkStatusChanged { link, .. }
+            | DoubleZeroEvent::LinkHealthChanged { link, .. } => link,
+            DoubleZeroEvent::DeviceStatusChanged { device, .. }
+            | DoubleZeroEvent::DeviceHealthChanged { device, .. } => device,
+            DoubleZeroEvent::ContributorStatusChanged { contributor, .. } => contributor,
+            DoubleZeroEvent::ExchangeStatusChanged { exchange, .. } => exchange,
+            DoubleZeroEvent::LocationStatusChanged { location, .. } => location,
+            DoubleZeroEvent::MulticastGroupStatusChanged { multicastgroup, .. } => multicastgroup,
+            DoubleZeroEvent::PermissionStatusChanged { permission, .. } => permission,
+            DoubleZeroEvent::ReadApiKeyStatusChanged { read_api_key, .. } => read_api_key,
+            DoubleZeroEvent::IpAllocationStatusChanged { ip_allocation, .. } => ip_allocation,
+        }
+    }
+
+    fn is_noop(&self) -> bool {
+        match self {
+            DoubleZeroEvent::UserActivated { .. } => false,
+            DoubleZeroEvent::UserStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::LinkStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::LinkHealthChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::DeviceStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::DeviceHealthChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::ContributorStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::ExchangeStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::LocationStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::MulticastGroupStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::PermissionStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::ReadApiKeyStatusChanged { from, to, .. } => from == to,
+            DoubleZeroEvent::IpAllocationStatusChanged { from, to, .. } => from == to,
+        }
+    }
+}
+
+impl fmt::Display for DoubleZeroEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoubleZeroEvent::UserActivated {
+                user,
+                device,
+                client_ip,
+                dz_ip,
+                tunnel_id,
+            } => write!(
+                f,
+                "UserActivated user={user} device={device} client_ip={client_ip} dz_ip={dz_ip} tunnel_id={tunnel_id}"
+            ),
+            DoubleZeroEvent::UserStatusChanged { user, from, to } => {
+                write!(f, "UserStatusChanged user={user} {from} -> {to}")
+            }
+            DoubleZeroEvent::LinkStatusChanged { link, from, to } => {
+                write!(f, "LinkStatusChanged link={link} {from} -> {to}")
+            }
+            DoubleZeroEvent::LinkHealthChanged { link, from, to } => {
+                write!(f, "LinkHealthChanged link={link} {from} -> {to}")
+            }
+            DoubleZeroEvent::DeviceStatusChanged { device, from, to } => {
+                write!(f, "DeviceStatusChanged device={device} {from} -> {to}")
+            }
+            DoubleZeroEvent::DeviceHealthChanged { device, from, to } => {
+                write!(f, "DeviceHealthChanged device={device} {from} -> {to}")
+            }
+            DoubleZeroEvent::ContributorStatusChanged {
+                contributor,
+                from,
+                to,
+            } => write!(
+                f,
+                "ContributorStatusChanged contributor={contributor} {from} -> {to}"
+            ),
+            DoubleZeroEvent::ExchangeStatusChanged { exchange, from, to } => {
+                write!(f, "ExchangeStatusChanged exchange={exchange} {from} -> {to}")
+            }
+            DoubleZeroEvent::LocationStatusChanged { location, from, to } => {
+                write!(f, "LocationStatusChanged location={location} {from} -> {to}")
+            }
+            DoubleZeroEvent::MulticastGroupStatusChanged {
+                multicastgroup,
+                from,
+                to,
+            } => write!(
+                f,
+                "MulticastGroupStatusChanged multicastgroup={multicastgroup} {from} -> {to}"
+            ),
+            DoubleZeroEvent::PermissionStatusChanged {
+                permission,
+                from,
+                to,
+            } => write!(
+                f,
+                "PermissionStatusChanged permission={permission} {from} -> {to}"
+            ),
+            DoubleZeroEvent::ReadApiKeyStatusChanged {
+                read_api_key,
+                from,
+                to,
+            } => write!(
+                f,
+                "ReadApiKeyStatusChanged read_api_key={read_api_key} {from} -> {to}"
+            ),
+            DoubleZeroEvent::IpAllocationStatusChanged {
+                ip_allocation,
+                from,
+                to,
+            } => write!(
+                f,
+                "IpAllocationStatusChanged ip_allocation={ip_allocation} {from} -> {to}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_roundtrip() {
+        let link = Pubkey::new_unique();
+        let event = DoubleZeroEvent::LinkStatusChanged {
+            link,
+            from: LinkStatus::Activated,
+            to: LinkStatus::SoftDrained,
+        };
+        let data = borsh::to_vec(&event).unwrap();
+
+        assert_eq!(
+            DoubleZeroEvent::decode(&[EVENT_TAG, &data]),
+            Some(event.clone())
+        );
+        assert_eq!(event.account(), &link);
+        assert_eq!(
+            event.to_string(),
+            format!("LinkStatusChanged link={link} activated -> soft-drained")
+        );
+
+        // Other programs' data and unknown variants are not events.
+        assert_eq!(DoubleZeroEvent::decode(&[b"other", &data]), None);
+        assert_eq!(DoubleZeroEvent::decode(&[&data]), None);
+        assert_eq!(DoubleZeroEvent::decode(&[EVENT_TAG, &[255]]), None);
+    }
+}
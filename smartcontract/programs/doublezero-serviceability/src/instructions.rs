@@ -1,88 +1,125 @@
-use crate::processors::{
-    accesspass::{
-        check_status::CheckStatusAccessPassArgs, close::CloseAccessPassArgs,
-        set::SetAccessPassArgs, set_feeds::SetAccessPassFeedsArgs,
-    },
-    allowlist::{
-        foundation::{add::AddFoundationAllowlistArgs, remove::RemoveFoundationAllowlistArgs},
-        qa::{add::AddQaAllowlistArgs, remove::RemoveQaAllowlistArgs},
-    },
-    contributor::{
-        create::ContributorCreateArgs, delete::ContributorDeleteArgs,
-        resume::ContributorResumeArgs, suspend::ContributorSuspendArgs,
-        update::ContributorUpdateArgs,
-    },
-    device::{
-        create::DeviceCreateArgs,
-        delete::DeviceDeleteArgs,
-        interface::{
-            create::DeviceInterfaceCreateArgs, delete::DeviceInterfaceDeleteArgs,
-            update::DeviceInterfaceUpdateArgs,
+use crate::{
+    processors::{
+        accesspass::{
+            check_status::CheckStatusAccessPassArgs, close::CloseAccessPassArgs,
+            set::SetAccessPassArgs, set_feeds::SetAccessPassFeedsArgs,
+            settle_lease::SettleAccessPassLeaseArgs,
         },
-        sethealth::DeviceSetHealthArgs,
-        update::DeviceUpdateArgs,
-    },
-    exchange::{
-        create::ExchangeCreateArgs, delete::ExchangeDeleteArgs, resume::ExchangeResumeArgs,
-        setdevice::ExchangeSetDeviceArgs, suspend::ExchangeSuspendArgs, update::ExchangeUpdateArgs,
-    },
-    feed::{create::FeedCreateArgs, delete::FeedDeleteArgs, update::FeedUpdateArgs},
-    globalconfig::set::SetGlobalConfigArgs,
-    globalstate::{
-        setairdrop::SetAirdropArgs, setauthority::SetAuthorityArgs,
-        setfeatureflags::SetFeatureFlagsArgs, setversion::SetVersionArgs,
-    },
-    index::{create::IndexCreateArgs, delete::IndexDeleteArgs},
-    link::{
-        accept::LinkAcceptArgs, create::LinkCreateArgs, delete::LinkDeleteArgs,
-        sethealth::LinkSetHealthArgs, update::LinkUpdateArgs,
-    },
-    location::{
-        create::LocationCreateArgs, delete::LocationDeleteArgs, resume::LocationResumeArgs,
-        suspend::LocationSuspendArgs, update::LocationUpdateArgs,
-    },
-    migrate::MigrateArgs,
-    multicastgroup::{
         allowlist::{
-            publisher::{
-                add::AddMulticastGroupPubAllowlistArgs,
-                remove::RemoveMulticastGroupPubAllowlistArgs,
+            foundation::{add::AddFoundationAllowlistArgs, remove::RemoveFoundationAllowlistArgs},
+            qa::{add::AddQaAllowlistArgs, remove::RemoveQaAllowlistArgs},
+        },
+        anycast_prefix::{
+            create::AnycastPrefixCreateArgs, delete::AnycastPrefixDeleteArgs,
+            update::AnycastPrefixUpdateArgs,
+        },
+        contributor::{
+            create::ContributorCreateArgs, delete::ContributorDeleteArgs,
+            emergency_drain::ContributorEmergencyDrainArgs,
+            emergency_suspend::ContributorEmergencySuspendArgs, resume::ContributorResumeArgs,
+            suspend::ContributorSuspendArgs, update::ContributorUpdateArgs,
+        },
+        device::{
+            ackconfig::DeviceAckConfigArgs,
+            create::DeviceCreateArgs,
+            delete::DeviceDeleteArgs,
+            grant_co_owner::DeviceGrantCoOwnerArgs,
+            interface::{
+                bulk_update::DeviceInterfaceBulkUpdateArgs, create::DeviceInterfaceCreateArgs,
+                delete::DeviceInterfaceDeleteArgs, update::DeviceInterfaceUpdateArgs,
             },
-            subscriber::{
-                add::AddMulticastGroupSubAllowlistArgs,
-                remove::RemoveMulticastGroupSubAllowlistArgs,
+            issue_challenge::DeviceIssueChallengeArgs,
+            prove_key::DeviceProveKeyArgs,
+            revoke_co_owner::DeviceRevokeCoOwnerArgs,
+            rotate_metrics_publisher::DeviceRotateMetricsPublisherArgs,
+            set_ops_lock::DeviceSetOpsLockArgs,
+            sethealth::DeviceSetHealthArgs,
+            update::DeviceUpdateArgs,
+        },
+        exchange::{
+            create::ExchangeCreateArgs, delete::ExchangeDeleteArgs, resume::ExchangeResumeArgs,
+            setdevice::ExchangeSetDeviceArgs, suspend::ExchangeSuspendArgs,
+            update::ExchangeUpdateArgs,
+        },
+        feed::{create::FeedCreateArgs, delete::FeedDeleteArgs, update::FeedUpdateArgs},
+        globalconfig::set::SetGlobalConfigArgs,
+        globalstate::{
+            setairdrop::SetAirdropArgs, setauthority::SetAuthorityArgs,
+            setfeatureflags::SetFeatureFlagsArgs, setpause::SetPauseArgs,
+            setversion::SetVersionArgs,
+        },
+        index::{create::IndexCreateArgs, delete::IndexDeleteArgs},
+        interface_oper_status::{
+            delete::InterfaceOperStatusDeleteArgs, set::InterfaceOperStatusSetArgs,
+        },
+        ip_allocation::{
+            create::IpAllocationCreateArgs, delete::IpAllocationDeleteArgs,
+            setstatus::IpAllocationSetStatusArgs,
+        },
+        link::{
+            accept::LinkAcceptArgs, create::LinkCreateArgs, delete::LinkDeleteArgs,
+            set_ops_lock::LinkSetOpsLockArgs, sethealth::LinkSetHealthArgs, update::LinkUpdateArgs,
+        },
+        location::{
+            create::LocationCreateArgs, delete::LocationDeleteArgs, resume::LocationResumeArgs,
+            suspend::LocationSuspendArgs, update::LocationUpdateArgs,
+        },
+        migrate::MigrateArgs,
+        migrateaccount::MigrateAccountArgs,
+        multicastgroup::{
+            allowlist::{
+                publisher::{
+                    add::AddMulticastGroupPubAllowlistArgs,
+                    remove::RemoveMulticastGroupPubAllowlistArgs,
+                },
+                subscriber::{
+                    add::AddMulticastGroupSubAllowlistArgs,
+                    remove::RemoveMulticastGroupSubAllowlistArgs,
+                },
             },
+            create::MulticastGroupCreateArgs,
+            delete::MulticastGroupDeleteArgs,
+            reactivate::MulticastGroupReactivateArgs,
+            subscribe::UpdateMulticastGroupRolesArgs,
+            suspend::MulticastGroupSuspendArgs,
+            update::MulticastGroupUpdateArgs,
+        },
+        permission::{
+            create::PermissionCreateArgs, delete::PermissionDeleteArgs,
+            resume::PermissionResumeArgs, suspend::PermissionSuspendArgs,
+            update::PermissionUpdateArgs,
+        },
+        qos_profile::{
+            create::QosProfileCreateArgs, delete::QosProfileDeleteArgs,
+            update::QosProfileUpdateArgs,
+        },
+        read_api_key::{
+            create::ReadApiKeyCreateArgs, delete::ReadApiKeyDeleteArgs,
+            revoke::ReadApiKeyRevokeArgs,
+        },
+        resource::{
+            allocate::ResourceAllocateArgs, closeaccount::ResourceExtensionCloseAccountArgs,
+            create::ResourceCreateArgs, deallocate::ResourceDeallocateArgs,
+            resize::ResourceResizeArgs,
+        },
+        state_commitment::record::RecordStateCommitmentArgs,
+        tenant::{
+            add_administrator::TenantAddAdministratorArgs, create::TenantCreateArgs,
+            delete::TenantDeleteArgs, remove_administrator::TenantRemoveAdministratorArgs,
+            update::TenantUpdateArgs, update_payment_status::UpdatePaymentStatusArgs,
+        },
+        topology::{
+            assign_node_segments::AssignTopologyNodeSegmentsArgs, clear::TopologyClearArgs,
+            create::TopologyCreateArgs, delete::TopologyDeleteArgs,
+        },
+        user::{
+            check_access_pass::CheckUserAccessPassArgs, create::UserCreateArgs,
+            create_subscribe::UserCreateSubscribeArgs, delete::UserDeleteArgs,
+            requestban::UserRequestBanArgs, set_bgp_status::SetUserBGPStatusArgs,
+            update::UserUpdateArgs,
         },
-        create::MulticastGroupCreateArgs,
-        delete::MulticastGroupDeleteArgs,
-        reactivate::MulticastGroupReactivateArgs,
-        subscribe::UpdateMulticastGroupRolesArgs,
-        suspend::MulticastGroupSuspendArgs,
-        update::MulticastGroupUpdateArgs,
-    },
-    permission::{
-        create::PermissionCreateArgs, delete::PermissionDeleteArgs, resume::PermissionResumeArgs,
-        suspend::PermissionSuspendArgs, update::PermissionUpdateArgs,
-    },
-    resource::{
-        allocate::ResourceAllocateArgs, closeaccount::ResourceExtensionCloseAccountArgs,
-        create::ResourceCreateArgs, deallocate::ResourceDeallocateArgs,
-    },
-    tenant::{
-        add_administrator::TenantAddAdministratorArgs, create::TenantCreateArgs,
-        delete::TenantDeleteArgs, remove_administrator::TenantRemoveAdministratorArgs,
-        update::TenantUpdateArgs, update_payment_status::UpdatePaymentStatusArgs,
-    },
-    topology::{
-        assign_node_segments::AssignTopologyNodeSegmentsArgs, clear::TopologyClearArgs,
-        create::TopologyCreateArgs, delete::TopologyDeleteArgs,
-    },
-    user::{
-        check_access_pass::CheckUserAccessPassArgs, create::UserCreateArgs,
-        create_subscribe::UserCreateSubscribeArgs, delete::UserDeleteArgs,
-        requestban::UserRequestBanArgs, set_bgp_status::SetUserBGPStatusArgs,
-        update::UserUpdateArgs,
     },
+    state::pause_flags::PauseFlag,
 };
 use borsh::BorshSerialize;
 use solana_program::program_error::ProgramError;
@@ -247,10 +284,41 @@ pub enum DoubleZeroInstruction {
 
     Deprecated111(), // variant 111, (was MigrateDeviceInterfaces)
 
-    CreateFeed(FeedCreateArgs),                 // variant 112
-    UpdateFeed(FeedUpdateArgs),                 // variant 113
-    DeleteFeed(FeedDeleteArgs),                 // variant 114
-    SetAccessPassFeeds(SetAccessPassFeedsArgs), // variant 115
+    CreateFeed(FeedCreateArgs),                       // variant 112
+    UpdateFeed(FeedUpdateArgs),                       // variant 113
+    DeleteFeed(FeedDeleteArgs),                       // variant 114
+    SetAccessPassFeeds(SetAccessPassFeedsArgs),       // variant 115
+    ResizeResourceExtension(ResourceResizeArgs),      // variant 116
+    CreateReadApiKey(ReadApiKeyCreateArgs),           // variant 117
+    RevokeReadApiKey(ReadApiKeyRevokeArgs),           // variant 118
+    DeleteReadApiKey(ReadApiKeyDeleteArgs),           // variant 119
+    CreateQosProfile(QosProfileCreateArgs),           // variant 120
+    UpdateQosProfile(QosProfileUpdateArgs),           // variant 121
+    DeleteQosProfile(QosProfileDeleteArgs),           // variant 122
+    AckDeviceConfig(DeviceAckConfigArgs),             // variant 123
+    SetPause(SetPauseArgs),                           // variant 124
+    MigrateAccount(MigrateAccountArgs),               // variant 125
+    CreateIpAllocation(IpAllocationCreateArgs),       // variant 126
+    SetIpAllocationStatus(IpAllocationSetStatusArgs), // variant 127
+    DeleteIpAllocation(IpAllocationDeleteArgs),       // variant 128
+
+    EmergencySuspendContributor(ContributorEmergencySuspendArgs), // variant 129
+    SettleAccessPassLease(SettleAccessPassLeaseArgs),             // variant 130
+    BulkUpdateDeviceInterfaces(DeviceInterfaceBulkUpdateArgs),    // variant 131
+    RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs), // variant 132
+    SetInterfaceOperStatus(InterfaceOperStatusSetArgs),           // variant 133
+    DeleteInterfaceOperStatus(InterfaceOperStatusDeleteArgs),     // variant 134
+    CreateAnycastPrefix(AnycastPrefixCreateArgs),                 // variant 135
+    UpdateAnycastPrefix(AnycastPrefixUpdateArgs),                 // variant 136
+    DeleteAnycastPrefix(AnycastPrefixDeleteArgs),                 // variant 137
+    IssueDeviceChallenge(DeviceIssueChallengeArgs),               // variant 138
+    ProveDeviceKey(DeviceProveKeyArgs),                           // variant 139
+    SetDeviceOpsLock(DeviceSetOpsLockArgs),                       // variant 140
+    SetLinkOpsLock(LinkSetOpsLockArgs),                           // variant 141
+    RecordStateCommitment(RecordStateCommitmentArgs),             // variant 142
+    GrantDeviceCoOwner(DeviceGrantCoOwnerArgs),                   // variant 143
+    RevokeDeviceCoOwner(DeviceRevokeCoOwnerArgs),                 // variant 144
+    EmergencyDrainContributor(ContributorEmergencyDrainArgs),     // variant 145
 }
 
 impl DoubleZeroInstruction {
@@ -397,6 +465,37 @@ impl DoubleZeroInstruction {
             113 => Ok(Self::UpdateFeed(FeedUpdateArgs::try_from(rest).unwrap())),
             114 => Ok(Self::DeleteFeed(FeedDeleteArgs::try_from(rest).unwrap())),
             115 => Ok(Self::SetAccessPassFeeds(SetAccessPassFeedsArgs::try_from(rest).unwrap())),
+            116 => Ok(Self::ResizeResourceExtension(ResourceResizeArgs::try_from(rest).unwrap())),
+            117 => Ok(Self::CreateReadApiKey(ReadApiKeyCreateArgs::try_from(rest).unwrap())),
+            118 => Ok(Self::RevokeReadApiKey(ReadApiKeyRevokeArgs::try_from(rest).unwrap())),
+            119 => Ok(Self::DeleteReadApiKey(ReadApiKeyDeleteArgs::try_from(rest).unwrap())),
+            120 => Ok(Self::CreateQosProfile(QosProfileCreateArgs::try_from(rest).unwrap())),
+            121 => Ok(Self::UpdateQosProfile(QosProfileUpdateArgs::try_from(rest).unwrap())),
+            122 => Ok(Self::DeleteQosProfile(QosProfileDeleteArgs::try_from(rest).unwrap())),
+            123 => Ok(Self::AckDeviceConfig(DeviceAckConfigArgs::try_from(rest).unwrap())),
+            124 => Ok(Self::SetPause(SetPauseArgs::try_from(rest).unwrap())),
+            125 => Ok(Self::MigrateAccount(MigrateAccountArgs::try_from(rest).unwrap())),
+            126 => Ok(Self::CreateIpAllocation(IpAllocationCreateArgs::try_from(rest).unwrap())),
+            127 => Ok(Self::SetIpAllocationStatus(IpAllocationSetStatusArgs::try_from(rest).unwrap())),
+            128 => Ok(Self::DeleteIpAllocation(IpAllocationDeleteArgs::try_from(rest).unwrap())),
+
+            129 => Ok(Self::EmergencySuspendContributor(ContributorEmergencySuspendArgs::try_from(rest).unwrap())),
+            130 => Ok(Self::SettleAccessPassLease(SettleAccessPassLeaseArgs::try_from(rest).unwrap())),
+            131 => Ok(Self::BulkUpdateDeviceInterfaces(DeviceInterfaceBulkUpdateArgs::try_from(rest).unwrap())),
+            132 => Ok(Self::RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs::try_from(rest).unwrap())),
+            133 => Ok(Self::SetInterfaceOperStatus(InterfaceOperStatusSetArgs::try_from(rest).unwrap())),
+            134 => Ok(Self::DeleteInterfaceOperStatus(InterfaceOperStatusDeleteArgs::try_from(rest).unwrap())),
+            135 => Ok(Self::CreateAnycastPrefix(AnycastPrefixCreateArgs::try_from(rest).unwrap())),
+            136 => Ok(Self::UpdateAnycastPrefix(AnycastPrefixUpdateArgs::try_from(rest).unwrap())),
+            137 => Ok(Self::DeleteAnycastPrefix(AnycastPrefixDeleteArgs::try_from(rest).unwrap())),
+            138 => Ok(Self::IssueDeviceChallenge(DeviceIssueChallengeArgs::try_from(rest).unwrap())),
+            139 => Ok(Self::ProveDeviceKey(DeviceProveKeyArgs::try_from(rest).unwrap())),
+            140 => Ok(Self::SetDeviceOpsLock(DeviceSetOpsLockArgs::try_from(rest).unwrap())),
+            141 => Ok(Self::SetLinkOpsLock(LinkSetOpsLockArgs::try_from(rest).unwrap())),
+            142 => Ok(Self::RecordStateCommitment(RecordStateCommitmentArgs::try_from(rest).unwrap())),
+            143 => Ok(Self::GrantDeviceCoOwner(DeviceGrantCoOwnerArgs::try_from(rest).unwrap())),
+            144 => Ok(Self::RevokeDeviceCoOwner(DeviceRevokeCoOwnerArgs::try_from(rest).unwrap())),
+            145 => Ok(Self::EmergencyDrainContributor(ContributorEmergencyDrainArgs::try_from(rest).unwrap())),
 
             _ => Err(ProgramError::InvalidInstructionData),
         }
@@ -546,6 +645,37 @@ impl DoubleZeroInstruction {
             Self::UpdateFeed(_) => "UpdateFeed".to_string(), // variant 113
             Self::DeleteFeed(_) => "DeleteFeed".to_string(), // variant 114
             Self::SetAccessPassFeeds(_) => "SetAccessPassFeeds".to_string(), // variant 115
+            Self::ResizeResourceExtension(_) => "ResizeResourceExtension".to_string(), // variant 116
+            Self::CreateReadApiKey(_) => "CreateReadApiKey".to_string(), // variant 117
+            Self::RevokeReadApiKey(_) => "RevokeReadApiKey".to_string(), // variant 118
+            Self::DeleteReadApiKey(_) => "DeleteReadApiKey".to_string(), // variant 119
+            Self::CreateQosProfile(_) => "CreateQosProfile".to_string(), // variant 120
+            Self::UpdateQosProfile(_) => "UpdateQosProfile".to_string(), // variant 121
+            Self::DeleteQosProfile(_) => "DeleteQosProfile".to_string(), // variant 122
+            Self::AckDeviceConfig(_) => "AckDeviceConfig".to_string(),   // variant 123
+            Self::SetPause(_) => "SetPause".to_string(),                 // variant 124
+            Self::MigrateAccount(_) => "MigrateAccount".to_string(),     // variant 125
+            Self::CreateIpAllocation(_) => "CreateIpAllocation".to_string(), // variant 126
+            Self::SetIpAllocationStatus(_) => "SetIpAllocationStatus".to_string(), // variant 127
+            Self::DeleteIpAllocation(_) => "DeleteIpAllocation".to_string(), // variant 128
+
+            Self::EmergencySuspendContributor(_) => "EmergencySuspendContributor".to_string(), // variant 129
+            Self::SettleAccessPassLease(_) => "SettleAccessPassLease".to_string(), // variant 130
+            Self::BulkUpdateDeviceInterfaces(_) => "BulkUpdateDeviceInterfaces".to_string(), // variant 131
+            Self::RotateDeviceMetricsPublisher(_) => "RotateDeviceMetricsPublisher".to_string(), // variant 132
+            Self::SetInterfaceOperStatus(_) => "SetInterfaceOperStatus".to_string(), // variant 133
+            Self::DeleteInterfaceOperStatus(_) => "DeleteInterfaceOperStatus".to_string(), // variant 134
+            Self::CreateAnycastPrefix(_) => "CreateAnycastPrefix".to_string(), // variant 135
+            Self::UpdateAnycastPrefix(_) => "UpdateAnycastPrefix".to_string(), // variant 136
+            Self::DeleteAnycastPrefix(_) => "DeleteAnycastPrefix".to_string(), // variant 137
+            Self::IssueDeviceChallenge(_) => "IssueDeviceChallenge".to_string(), // variant 138
+            Self::ProveDeviceKey(_) => "ProveDeviceKey".to_string(),           // variant 139
+            Self::SetDeviceOpsLock(_) => "SetDeviceOpsLock".to_string(),       // variant 140
+            Self::SetLinkOpsLock(_) => "SetLinkOpsLock".to_string(),           // variant 141
+            Self::RecordStateCommitment(_) => "RecordStateCommitment".to_string(), // variant 142
+            Self::GrantDeviceCoOwner(_) => "GrantDeviceCoOwner".to_string(),   // variant 143
+            Self::RevokeDeviceCoOwner(_) => "RevokeDeviceCoOwner".to_string(), // variant 144
+            Self::EmergencyDrainContributor(_) => "EmergencyDrainContributor".to_string(), // variant 145
         }
     }
 
@@ -687,6 +817,201 @@ impl DoubleZeroInstruction {
             Self::UpdateFeed(args) => format!("{args:?}"), // variant 113
             Self::DeleteFeed(args) => format!("{args:?}"), // variant 114
             Self::SetAccessPassFeeds(args) => format!("{args:?}"), // variant 115
+            Self::ResizeResourceExtension(args) => format!("{args:?}"), // variant 116
+            Self::CreateReadApiKey(args) => format!("{args:?}"), // variant 117
+            Self::RevokeReadApiKey(args) => format!("{args:?}"), // variant 118
+            Self::DeleteReadApiKey(args) => format!("{args:?}"), // variant 119
+            Self::CreateQosProfile(args) => format!("{args:?}"), // variant 120
+            Self::UpdateQosProfile(args) => format!("{args:?}"), // variant 121
+            Self::DeleteQosProfile(args) => format!("{args:?}"), // variant 122
+            Self::AckDeviceConfig(args) => format!("{args:?}"), // variant 123
+            Self::SetPause(args) => format!("{args:?}"),   // variant 124
+            Self::MigrateAccount(args) => format!("{args:?}"), // variant 125
+            Self::CreateIpAllocation(args) => format!("{args:?}"), // variant 126
+            Self::SetIpAllocationStatus(args) => format!("{args:?}"), // variant 127
+            Self::DeleteIpAllocation(args) => format!("{args:?}"), // variant 128
+
+            Self::EmergencySuspendContributor(args) => format!("{args:?}"), // variant 129
+            Self::SettleAccessPassLease(args) => format!("{args:?}"),       // variant 130
+            Self::BulkUpdateDeviceInterfaces(args) => format!("{args:?}"),  // variant 131
+            Self::RotateDeviceMetricsPublisher(args) => format!("{args:?}"), // variant 132
+            Self::SetInterfaceOperStatus(args) => format!("{args:?}"),      // variant 133
+            Self::DeleteInterfaceOperStatus(args) => format!("{args:?}"),   // variant 134
+            Self::CreateAnycastPrefix(args) => format!("{args:?}"),         // variant 135
+            Self::UpdateAnycastPrefix(args) => format!("{args:?}"),         // variant 136
+            Self::DeleteAnycastPrefix(args) => format!("{args:?}"),         // variant 137
+            Self::IssueDeviceChallenge(args) => format!("{args:?}"),        // variant 138
+            Self::ProveDeviceKey(args) => format!("{args:?}"),              // variant 139
+            Self::SetDeviceOpsLock(args) => format!("{args:?}"),            // variant 140
+            Self::SetLinkOpsLock(args) => format!("{args:?}"),              // variant 141
+            Self::RecordStateCommitment(args) => format!("{args:?}"),       // variant 142
+            Self::GrantDeviceCoOwner(args) => format!("{args:?}"),          // variant 143
+            Self::RevokeDeviceCoOwner(args) => format!("{args:?}"),         // variant 144
+            Self::EmergencyDrainContributor(args) => format!("{args:?}"),   // variant 145
+        }
+    }
+
+    /// Whether GlobalState is an optional trailing account of this instruction, so
+    /// device agents built before the emergency pause keep working. Such an
+    /// instruction is treated as unpaused when sent without it.
+    pub fn optional_globalstate(&self) -> bool {
+        matches!(self, Self::SetUserBGPStatus(_) | Self::ProveDeviceKey(_))
+    }
+
+    /// The pause class this instruction falls under, or `None` for instructions that
+    /// are never paused: governance (authorities, allowlists, permissions, feature
+    /// flags, `SetPause` itself) and deprecated variants, which fail regardless.
+    pub fn pause_flag(&self) -> Option<PauseFlag> {
+        match self {
+            Self::Migrate(_)
+            | Self::InitGlobalState()
+            | Self::SetAuthority(_)
+            | Self::SetGlobalConfig(_)
+            | Self::AddFoundationAllowlist(_)
+            | Self::RemoveFoundationAllowlist(_)
+            | Self::AddDeviceAllowlist()
+            | Self::RemoveDeviceAllowlist()
+            | Self::AddUserAllowlist()
+            | Self::RemoveUserAllowlist()
+            | Self::SetAirdrop(_)
+            | Self::SetMinVersion(_)
+            | Self::AddQaAllowlist(_)
+            | Self::RemoveQaAllowlist(_)
+            | Self::SetFeatureFlags(_)
+            | Self::CreatePermission(_)
+            | Self::UpdatePermission(_)
+            | Self::SuspendPermission(_)
+            | Self::ResumePermission(_)
+            | Self::DeletePermission(_)
+            | Self::SetPause(_)
+            | Self::EmergencySuspendContributor(_)
+            | Self::EmergencyDrainContributor(_)
+            | Self::ActivateDevice()
+            | Self::RejectDevice()
+            | Self::CloseAccountDevice()
+            | Self::ActivateLink()
+            | Self::RejectLink()
+            | Self::CloseAccountLink()
+            | Self::ActivateUser()
+            | Self::RejectUser()
+            | Self::CloseAccountUser()
+            | Self::BanUser()
+            | Self::ActivateMulticastGroup()
+            | Self::RejectMulticastGroup()
+            | Self::DeactivateMulticastGroup()
+            | Self::ActivateDeviceInterface()
+            | Self::RemoveDeviceInterface()
+            | Self::UnlinkDeviceInterface()
+            | Self::RejectDeviceInterface()
+            | Self::Deprecated95()
+            | Self::Deprecated96()
+            | Self::Deprecated102()
+            | Self::Deprecated103()
+            | Self::Deprecated111() => None,
+
+            Self::CreateLocation(_)
+            | Self::CreateExchange(_)
+            | Self::CreateDevice(_)
+            | Self::CreateLink(_)
+            | Self::CreateUser(_)
+            | Self::CreateMulticastGroup(_)
+            | Self::CreateSubscribeUser(_)
+            | Self::CreateContributor(_)
+            | Self::CreateDeviceInterface(_)
+            | Self::AllocateResource(_)
+            | Self::CreateResource(_)
+            | Self::CreateTenant(_)
+            | Self::CreateIndex(_)
+            | Self::CreateTopology(_)
+            | Self::CreateFeed(_)
+            | Self::CreateReadApiKey(_)
+            | Self::CreateQosProfile(_)
+            | Self::CreateIpAllocation(_)
+            | Self::CreateAnycastPrefix(_) => Some(PauseFlag::Create),
+
+            Self::DeleteLocation(_)
+            | Self::DeleteExchange(_)
+            | Self::DeleteDevice(_)
+            | Self::DeleteLink(_)
+            | Self::DeleteUser(_)
+            | Self::DeleteMulticastGroup(_)
+            | Self::DeleteContributor(_)
+            | Self::CloseAccessPass(_)
+            | Self::DeleteDeviceInterface(_)
+            | Self::DeallocateResource(_)
+            | Self::CloseResource(_)
+            | Self::DeleteTenant(_)
+            | Self::DeleteIndex(_)
+            | Self::DeleteTopology(_)
+            | Self::DeleteFeed(_)
+            | Self::DeleteReadApiKey(_)
+            | Self::DeleteQosProfile(_)
+            | Self::DeleteIpAllocation(_)
+            | Self::DeleteInterfaceOperStatus(_)
+            | Self::DeleteAnycastPrefix(_) => Some(PauseFlag::Delete),
+
+            Self::UpdateLocation(_)
+            | Self::SuspendLocation(_)
+            | Self::ResumeLocation(_)
+            | Self::UpdateExchange(_)
+            | Self::SuspendExchange(_)
+            | Self::ResumeExchange(_)
+            | Self::UpdateDevice(_)
+            | Self::SuspendDevice()
+            | Self::ResumeDevice()
+            | Self::UpdateLink(_)
+            | Self::SuspendLink()
+            | Self::ResumeLink()
+            | Self::UpdateUser(_)
+            | Self::SuspendUser()
+            | Self::ResumeUser()
+            | Self::RequestBanUser(_)
+            | Self::UpdateMulticastGroup(_)
+            | Self::SuspendMulticastGroup(_)
+            | Self::ReactivateMulticastGroup(_)
+            | Self::AddMulticastGroupPubAllowlist(_)
+            | Self::RemoveMulticastGroupPubAllowlist(_)
+            | Self::AddMulticastGroupSubAllowlist(_)
+            | Self::RemoveMulticastGroupSubAllowlist(_)
+            | Self::UpdateMulticastGroupRoles(_)
+            | Self::UpdateContributor(_)
+            | Self::SuspendContributor(_)
+            | Self::ResumeContributor(_)
+            | Self::SetDeviceExchange(_)
+            | Self::AcceptLink(_)
+            | Self::SetAccessPass(_)
+            | Self::CheckStatusAccessPass(_)
+            | Self::CheckUserAccessPass(_)
+            | Self::UpdateDeviceInterface(_)
+            | Self::BulkUpdateDeviceInterfaces(_)
+            | Self::RotateDeviceMetricsPublisher(_)
+            | Self::IssueDeviceChallenge(_)
+            | Self::ProveDeviceKey(_)
+            | Self::SetDeviceOpsLock(_)
+            | Self::SetLinkOpsLock(_)
+            | Self::GrantDeviceCoOwner(_)
+            | Self::RevokeDeviceCoOwner(_)
+            | Self::SetDeviceHealth(_)
+            | Self::SetInterfaceOperStatus(_)
+            | Self::SetLinkHealth(_)
+            | Self::RecordStateCommitment(_)
+            | Self::UpdateTenant(_)
+            | Self::TenantAddAdministrator(_)
+            | Self::TenantRemoveAdministrator(_)
+            | Self::UpdatePaymentStatus(_)
+            | Self::SetUserBGPStatus(_)
+            | Self::ClearTopology(_)
+            | Self::AssignTopologyNodeSegments(_)
+            | Self::UpdateFeed(_)
+            | Self::SetAccessPassFeeds(_)
+            | Self::SettleAccessPassLease(_)
+            | Self::ResizeResourceExtension(_)
+            | Self::RevokeReadApiKey(_)
+            | Self::UpdateQosProfile(_)
+            | Self::UpdateAnycastPrefix(_)
+            | Self::AckDeviceConfig(_)
+            | Self::MigrateAccount(_)
+            | Self::SetIpAllocationStatus(_) => Some(PauseFlag::Update),
         }
     }
 }
@@ -694,14 +1019,19 @@ impl DoubleZeroInstruction {
 #[cfg(test)]
 mod tests {
     use crate::{
-        processors::exchange::setdevice::SetDeviceOption,
+        processors::{
+            device::interface::update::DeviceInterfaceFieldsUpdate,
+            exchange::setdevice::SetDeviceOption,
+        },
         resource::{IdOrIp, ResourceType},
         state::{
             device::{DeviceHealth, DeviceType},
             interface::{LoopbackType, RoutingMode},
+            interface_oper_status::{InterfaceOperState, InterfaceOperStatusEntry},
+            ip_allocation::IpAllocationStatus,
             link::{LinkHealth, LinkLinkType},
             permission::permission_flags,
-            user::{BGPStatus, UserCYOA, UserType},
+            user::{BGPStatus, BandwidthTier, UserCYOA, UserType},
         },
     };
     use solana_program::pubkey::Pubkey;
@@ -896,6 +1226,7 @@ mod tests {
                 client_ip: [1, 2, 3, 4].into(),
                 tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
                 dz_prefix_count: 0,
+                bandwidth_tier: None,
             }),
             "CreateUser",
         );
@@ -912,6 +1243,7 @@ mod tests {
                 dz_prefix_count: 0,
                 multicast_publisher_count: 0,
                 tunnel_endpoint: None,
+                bandwidth_tier: None,
             }),
             "UpdateUser",
         );
@@ -1095,6 +1427,7 @@ mod tests {
                 tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
                 dz_prefix_count: 0,
                 owner: Pubkey::default(),
+                bandwidth_tier: None,
             }),
             "CreateSubscribeUser",
         );
@@ -1120,6 +1453,14 @@ mod tests {
             DoubleZeroInstruction::ResumeContributor(ContributorResumeArgs {}),
             "ResumeContributor",
         );
+        test_instruction(
+            DoubleZeroInstruction::EmergencySuspendContributor(ContributorEmergencySuspendArgs {}),
+            "EmergencySuspendContributor",
+        );
+        test_instruction(
+            DoubleZeroInstruction::EmergencyDrainContributor(ContributorEmergencyDrainArgs {}),
+            "EmergencyDrainContributor",
+        );
         test_instruction(
             DoubleZeroInstruction::DeleteContributor(ContributorDeleteArgs {}),
             "DeleteContributor",
@@ -1148,6 +1489,8 @@ mod tests {
                 allow_multiple_ip: false,
                 max_unicast_users: 1,
                 max_multicast_users: 1,
+                bandwidth_tier: None,
+                referrer: None,
             }),
             "SetAccessPass",
         );
@@ -1170,6 +1513,10 @@ mod tests {
             DoubleZeroInstruction::CheckUserAccessPass(CheckUserAccessPassArgs {}),
             "CheckUserAccessPass",
         );
+        test_instruction(
+            DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+            "SettleAccessPassLease",
+        );
         test_instruction(
             DoubleZeroInstruction::ActivateDeviceInterface(),
             "ActivateDeviceInterface",
@@ -1189,6 +1536,7 @@ mod tests {
                 user_tunnel_endpoint: false,
                 use_onchain_allocation: false,
                 topology_count: 0,
+                with_ip_allocation: false,
             }),
             "CreateDeviceInterface",
         );
@@ -1220,9 +1568,35 @@ mod tests {
                 status: None,
                 topology_count: 0,
                 update_topologies: false,
+                with_ip_allocation: false,
             }),
             "UpdateDeviceInterface",
         );
+        test_instruction(
+            DoubleZeroInstruction::BulkUpdateDeviceInterfaces(DeviceInterfaceBulkUpdateArgs {
+                updates: vec![
+                    DeviceInterfaceFieldsUpdate {
+                        name: "Ethernet1".to_string(),
+                        status: Some(crate::state::interface::InterfaceStatus::Activated),
+                        mtu: Some(2048),
+                        ..Default::default()
+                    },
+                    DeviceInterfaceFieldsUpdate {
+                        name: "Ethernet2".to_string(),
+                        bandwidth: Some(10_000_000_000),
+                        ..Default::default()
+                    },
+                ],
+            }),
+            "BulkUpdateDeviceInterfaces",
+        );
+        test_instruction(
+            DoubleZeroInstruction::RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs {
+                metrics_publisher_pk: Pubkey::new_unique(),
+                overlap_epochs: 2,
+            }),
+            "RotateDeviceMetricsPublisher",
+        );
         test_instruction(
             DoubleZeroInstruction::UnlinkDeviceInterface(),
             "UnlinkDeviceInterface",
@@ -1397,5 +1771,181 @@ mod tests {
             }),
             "SetAccessPassFeeds",
         );
+        test_instruction(
+            DoubleZeroInstruction::ResizeResourceExtension(ResourceResizeArgs {
+                resource_type: ResourceType::UserTunnelBlock,
+            }),
+            "ResizeResourceExtension",
+        );
+        test_instruction(
+            DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+                key_hash: [1; 32],
+                label: "indexer".to_string(),
+                expires_at: 1_900_000_000,
+            }),
+            "CreateReadApiKey",
+        );
+        test_instruction(
+            DoubleZeroInstruction::RevokeReadApiKey(ReadApiKeyRevokeArgs {}),
+            "RevokeReadApiKey",
+        );
+        test_instruction(
+            DoubleZeroInstruction::DeleteReadApiKey(ReadApiKeyDeleteArgs {}),
+            "DeleteReadApiKey",
+        );
+        test_instruction(
+            DoubleZeroInstruction::CreateQosProfile(QosProfileCreateArgs {
+                bandwidth_tier: BandwidthTier::Premium,
+                rate_mbps: 10_000,
+                burst_kbytes: 512,
+                dscp: 46,
+            }),
+            "CreateQosProfile",
+        );
+        test_instruction(
+            DoubleZeroInstruction::UpdateQosProfile(QosProfileUpdateArgs {
+                rate_mbps: Some(5_000),
+                burst_kbytes: None,
+                dscp: Some(34),
+            }),
+            "UpdateQosProfile",
+        );
+        test_instruction(
+            DoubleZeroInstruction::DeleteQosProfile(QosProfileDeleteArgs {}),
+            "DeleteQosProfile",
+        );
+        test_instruction(
+            DoubleZeroInstruction::AckDeviceConfig(DeviceAckConfigArgs { generation: 42 }),
+            "AckDeviceConfig",
+        );
+        test_instruction(
+            DoubleZeroInstruction::SetPause(SetPauseArgs { paused: 3 }),
+            "SetPause",
+        );
+        test_instruction(
+            DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+            "MigrateAccount",
+        );
+        test_instruction(
+            DoubleZeroInstruction::CreateIpAllocation(IpAllocationCreateArgs {
+                prefix: "45.76.12.0/24".parse().unwrap(),
+                upstream: "AS64500".to_string(),
+            }),
+            "CreateIpAllocation",
+        );
+        test_instruction(
+            DoubleZeroInstruction::SetIpAllocationStatus(IpAllocationSetStatusArgs {
+                status: IpAllocationStatus::Verified,
+            }),
+            "SetIpAllocationStatus",
+        );
+        test_instruction(
+            DoubleZeroInstruction::DeleteIpAllocation(IpAllocationDeleteArgs {}),
+            "DeleteIpAllocation",
+        );
+        test_instruction(
+            DoubleZeroInstruction::SetInterfaceOperStatus(InterfaceOperStatusSetArgs {
+                interfaces: vec![InterfaceOperStatusEntry {
+                    name: "Ethernet1".to_string(),
+                    oper_status: InterfaceOperState::Down,
+                    rx_power_mdbm: -40_000,
+                    tx_power_mdbm: -2_100,
+                    in_errors: 7,
+                    ..Default::default()
+                }],
+            }),
+            "SetInterfaceOperStatus",
+        );
+        test_instruction(
+            DoubleZeroInstruction::DeleteInterfaceOperStatus(InterfaceOperStatusDeleteArgs {}),
+            "DeleteInterfaceOperStatus",
+        );
+        test_instruction(
+            DoubleZeroInstruction::CreateAnycastPrefix(AnycastPrefixCreateArgs {
+                code: "dns".to_string(),
+                prefix: "100.64.255.53/32".parse().unwrap(),
+                enabled: true,
+            }),
+            "CreateAnycastPrefix",
+        );
+        test_instruction(
+            DoubleZeroInstruction::UpdateAnycastPrefix(AnycastPrefixUpdateArgs {
+                prefix: None,
+                enabled: Some(false),
+            }),
+            "UpdateAnycastPrefix",
+        );
+        test_instruction(
+            DoubleZeroInstruction::DeleteAnycastPrefix(AnycastPrefixDeleteArgs {}),
+            "DeleteAnycastPrefix",
+        );
+        test_instruction(
+            DoubleZeroInstruction::IssueDeviceChallenge(DeviceIssueChallengeArgs {
+                nonce: [7; 32],
+            }),
+            "IssueDeviceChallenge",
+        );
+        test_instruction(
+            DoubleZeroInstruction::ProveDeviceKey(DeviceProveKeyArgs { nonce: [7; 32] }),
+            "ProveDeviceKey",
+        );
+        test_instruction(
+            DoubleZeroInstruction::SetDeviceOpsLock(DeviceSetOpsLockArgs {
+                reason: "optics swap".to_string(),
+                expires_at: 1_700_000_000,
+            }),
+            "SetDeviceOpsLock",
+        );
+        test_instruction(
+            DoubleZeroInstruction::SetLinkOpsLock(LinkSetOpsLockArgs {
+                reason: "OTDR trace".to_string(),
+                expires_at: 1_700_000_000,
+            }),
+            "SetLinkOpsLock",
+        );
+        test_instruction(
+            DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+                slot: 1_000,
+                merkle_root: [7u8; 32],
+                account_count: 42,
+            }),
+            "RecordStateCommitment",
+        );
+        test_instruction(
+            DoubleZeroInstruction::GrantDeviceCoOwner(DeviceGrantCoOwnerArgs {
+                co_owner_pk: Pubkey::new_unique(),
+            }),
+            "GrantDeviceCoOwner",
+        );
+        test_instruction(
+            DoubleZeroInstruction::RevokeDeviceCoOwner(DeviceRevokeCoOwnerArgs {}),
+            "RevokeDeviceCoOwner",
+        );
+    }
+
+    #[test]
+    fn test_pause_flag() {
+        assert_eq!(
+            DoubleZeroInstruction::CreateLink(LinkCreateArgs::default()).pause_flag(),
+            Some(PauseFlag::Create)
+        );
+        assert_eq!(
+            DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs::default()).pause_flag(),
+            Some(PauseFlag::Update)
+        );
+        assert_eq!(
+            DoubleZeroInstruction::DeleteQosProfile(QosProfileDeleteArgs {}).pause_flag(),
+            Some(PauseFlag::Delete)
+        );
+        // The brake itself and the governance needed during an incident are never paused.
+        assert_eq!(
+            DoubleZeroInstruction::SetPause(SetPauseArgs { paused: 0 }).pause_flag(),
+            None
+        );
+        assert_eq!(
+            DoubleZeroInstruction::SetFeatureFlags(SetFeatureFlagsArgs { feature_flags: 0 })
+                .pause_flag(),
+            None
+        );
     }
 }
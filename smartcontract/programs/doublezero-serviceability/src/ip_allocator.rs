@@ -157,6 +157,75 @@ impl IpAllocator {
         true
     }
 
+    /// Allocate `count` contiguous addresses, not necessarily aligned to a
+    /// prefix boundary, using a first-fit search from the start of the block.
+    /// Returns the first address of the range, or None if no free run of
+    /// `count` addresses exists.
+    pub fn allocate_contiguous(&mut self, bitmap: &mut [u8], count: usize) -> Option<Ipv4Addr> {
+        let total_addresses = (self.base_net.size() as usize).min(bitmap.len() * 8);
+        if count == 0 || count > total_addresses {
+            return None;
+        }
+
+        let mut run_start = 0;
+        let mut bit_index = 0;
+        while bit_index < total_addresses {
+            // Skip fully allocated bytes without testing each bit.
+            if bit_index % 8 == 0 && bitmap[bit_index / 8] == u8::MAX {
+                bit_index += 8;
+                run_start = bit_index;
+                continue;
+            }
+
+            if bitmap[bit_index / 8] & (1 << (bit_index % 8)) != 0 {
+                run_start = bit_index + 1;
+            } else if bit_index + 1 - run_start == count {
+                self.set_range(bitmap, run_start, count, true);
+
+                let base_ip_int = u32::from_be_bytes(self.base_net.ip().octets());
+                return Some(Ipv4Addr::from(base_ip_int + run_start as u32));
+            }
+            bit_index += 1;
+        }
+
+        None
+    }
+
+    /// Release a range previously returned by `allocate_contiguous`.
+    /// Returns false, leaving the bitmap untouched, if any address in the
+    /// range is outside the block or not allocated.
+    pub fn deallocate_contiguous(
+        &mut self,
+        bitmap: &mut [u8],
+        start: Ipv4Addr,
+        count: usize,
+    ) -> bool {
+        let base_ip_int = u32::from_be_bytes(self.base_net.ip().octets());
+        let Some(offset) = u32::from_be_bytes(start.octets()).checked_sub(base_ip_int) else {
+            return false;
+        };
+        let offset = offset as usize;
+
+        let total_addresses = (self.base_net.size() as usize).min(bitmap.len() * 8);
+        if count == 0 || offset + count > total_addresses {
+            return false;
+        }
+
+        if !self.is_range_allocated(bitmap, offset, count) {
+            return false;
+        }
+
+        self.set_range(bitmap, offset, count, false);
+
+        // Update first_free_index hint
+        let u64_index = offset / 64;
+        if u64_index < self.first_free_index {
+            self.first_free_index = u64_index;
+        }
+
+        true
+    }
+
     /// Check if a range of bits is entirely free (all zeros)
     fn is_range_free(&self, bitmap: &[u8], start_bit: usize, count: usize) -> bool {
         for i in 0..count {
@@ -320,6 +389,154 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_allocate_contiguous_first_fit() {
+        let mut aligned_data = AlignedBitmap([0u8; 8]);
+        let mut allocator = IpAllocator::new("10.0.0.0/26".parse().unwrap());
+
+        let a = allocator
+            .allocate_contiguous(&mut aligned_data.0, 3)
+            .unwrap();
+        let b = allocator
+            .allocate_contiguous(&mut aligned_data.0, 5)
+            .unwrap();
+        let c = allocator
+            .allocate_contiguous(&mut aligned_data.0, 2)
+            .unwrap();
+        assert_eq!(a, "10.0.0.0".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(b, "10.0.0.3".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(c, "10.0.0.8".parse::<Ipv4Addr>().unwrap());
+
+        // Freeing the middle range leaves a hole of 5: a request for 4 lands
+        // in it, a request for 6 goes past the last allocation.
+        assert!(allocator.deallocate_contiguous(&mut aligned_data.0, b, 5));
+        let d = allocator
+            .allocate_contiguous(&mut aligned_data.0, 4)
+            .unwrap();
+        assert_eq!(d, b);
+        let e = allocator
+            .allocate_contiguous(&mut aligned_data.0, 6)
+            .unwrap();
+        assert_eq!(e, "10.0.0.10".parse::<Ipv4Addr>().unwrap());
+
+        // Ranges never cross the end of the block.
+        assert!(allocator
+            .allocate_contiguous(&mut aligned_data.0, 64)
+            .is_none());
+        assert!(allocator
+            .allocate_contiguous(&mut aligned_data.0, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_deallocate_contiguous_invalid() {
+        let mut aligned_data = AlignedBitmap([0u8; 8]);
+        let mut allocator = IpAllocator::new("10.0.0.0/26".parse().unwrap());
+
+        let start = allocator
+            .allocate_contiguous(&mut aligned_data.0, 4)
+            .unwrap();
+
+        // Partially unallocated range is rejected and nothing is released.
+        assert!(!allocator.deallocate_contiguous(&mut aligned_data.0, start, 5));
+        // Below the base network.
+        assert!(!allocator.deallocate_contiguous(
+            &mut aligned_data.0,
+            "9.255.255.255".parse().unwrap(),
+            1
+        ));
+        // Past the end of the block.
+        assert!(!allocator.deallocate_contiguous(
+            &mut aligned_data.0,
+            "10.0.0.63".parse().unwrap(),
+            2
+        ));
+        assert_eq!(allocator.iter_allocated(&aligned_data.0).count(), 4);
+
+        assert!(allocator.deallocate_contiguous(&mut aligned_data.0, start, 4));
+        assert_eq!(allocator.iter_allocated(&aligned_data.0).count(), 0);
+    }
+
+    /// Randomized allocate/deallocate sequences checked against a plain
+    /// `Vec<bool>` model: every allocation is the lowest free run that fits,
+    /// a request fails only when no run is long enough (even if enough
+    /// addresses are free in total), and freeing everything coalesces the
+    /// fragments back into a single run.
+    #[test]
+    fn test_allocate_contiguous_fragmentation_properties() {
+        // xorshift64: deterministic, no extra dependency
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        }
+
+        fn first_fit(model: &[bool], count: usize) -> Option<usize> {
+            (0..=model.len().saturating_sub(count))
+                .find(|&start| model[start..start + count].iter().all(|used| !used))
+        }
+
+        #[repr(align(8))]
+        struct AlignedBitmap256([u8; 32]);
+
+        let base_net: NetworkV4 = "10.0.0.0/24".parse().unwrap();
+        let base_ip = base_net.ip().to_bits();
+
+        for seed in 1..=32u64 {
+            let mut rng = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            let mut bitmap = AlignedBitmap256([0u8; 32]);
+            let mut allocator = IpAllocator::new(base_net);
+            let mut model = vec![false; 256];
+            let mut live: Vec<(Ipv4Addr, usize)> = vec![];
+
+            for _ in 0..400 {
+                if live.is_empty() || next(&mut rng) % 3 != 0 {
+                    let count = (next(&mut rng) % 24 + 1) as usize;
+                    let expected = first_fit(&model, count);
+                    let got = allocator.allocate_contiguous(&mut bitmap.0, count);
+                    assert_eq!(
+                        got.map(|ip| (ip.to_bits() - base_ip) as usize),
+                        expected,
+                        "seed {seed}: first-fit mismatch for {count} addresses"
+                    );
+                    if let Some(ip) = got {
+                        let start = (ip.to_bits() - base_ip) as usize;
+                        model[start..start + count].fill(true);
+                        live.push((ip, count));
+                    } else {
+                        // Failing is only allowed when no run fits.
+                        assert!(first_fit(&model, count).is_none());
+                    }
+                } else {
+                    let victim = (next(&mut rng) as usize) % live.len();
+                    let (ip, count) = live.swap_remove(victim);
+                    assert!(allocator.deallocate_contiguous(&mut bitmap.0, ip, count));
+                    // Double free is rejected.
+                    assert!(!allocator.deallocate_contiguous(&mut bitmap.0, ip, count));
+                    let start = (ip.to_bits() - base_ip) as usize;
+                    model[start..start + count].fill(false);
+                }
+
+                let allocated: Vec<usize> = allocator
+                    .iter_allocated(&bitmap.0)
+                    .map(|ip| (ip.to_bits() - base_ip) as usize)
+                    .collect();
+                let expected: Vec<usize> = (0..256).filter(|&i| model[i]).collect();
+                assert_eq!(allocated, expected, "seed {seed}: bitmap diverged");
+            }
+
+            for (ip, count) in live.drain(..) {
+                assert!(allocator.deallocate_contiguous(&mut bitmap.0, ip, count));
+            }
+            assert!(bitmap.0.iter().all(|&b| b == 0));
+            assert_eq!(
+                allocator.allocate_contiguous(&mut bitmap.0, 256),
+                Some(base_net.ip())
+            );
+        }
+    }
+
     #[test]
     fn test_iter_allocated() {
         let base_net = "192.168.0.0/24".parse().unwrap();
@@ -4,10 +4,12 @@ pub mod addresses;
 pub mod authorize;
 pub mod entrypoint;
 pub mod error;
+pub mod events;
 mod helper;
 pub mod id_allocator;
 pub mod instructions;
 pub mod ip_allocator;
+pub mod migration;
 mod min_version;
 pub mod pda;
 pub mod processors;
@@ -16,3 +18,4 @@ pub mod resource;
 pub mod seeds;
 mod serializer;
 pub mod state;
+pub mod state_machine;
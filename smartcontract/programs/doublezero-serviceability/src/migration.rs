@@ -0,0 +1,171 @@
+//! In-place upgrade of accounts written with an older layout.
+//!
+//! Every account type grows by appending trailing fields, and its reader fills
+//! the fields missing from older accounts with defaults (device interfaces are
+//! rebuilt from the legacy `InterfaceV1`/`InterfaceV2` vec). Migrating an account
+//! means reading it with that tolerant reader and writing it back with the
+//! current serializer, so later layout changes only need the reader to keep
+//! handling the old shape. [`MIGRATIONS`] lists the account types this applies
+//! to; account types whose layout never changed, and raw bitmaps such as
+//! resource extensions, are left out.
+
+use crate::state::{
+    accesspass::AccessPass, accounttype::AccountType, contributor::Contributor, device::Device,
+    exchange::Exchange, globalconfig::GlobalConfig, globalstate::GlobalState, link::Link,
+    location::Location, multicastgroup::MulticastGroup, tenant::Tenant, user::User,
+};
+use borsh::BorshSerialize;
+use solana_program::program_error::ProgramError;
+
+/// Migration of one account type to its current layout.
+pub struct Migration {
+    pub account_type: AccountType,
+    /// Layout changes older accounts are brought through.
+    pub description: &'static str,
+    upgrade: fn(&[u8]) -> Result<Vec<u8>, ProgramError>,
+}
+
+impl Migration {
+    /// The account's data at the current layout.
+    pub fn upgrade(&self, data: &[u8]) -> Result<Vec<u8>, ProgramError> {
+        (self.upgrade)(data)
+    }
+}
+
+fn reserialize<T>(data: &[u8]) -> Result<Vec<u8>, ProgramError>
+where
+    T: for<'a> TryFrom<&'a [u8], Error = ProgramError> + BorshSerialize,
+{
+    let value = T::try_from(data)?;
+    borsh::to_vec(&value).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        account_type: AccountType::GlobalState,
+        description: "trailing health oracle, QA allowlist, feature flags, feed authority and pause fields",
+        upgrade: reserialize::<GlobalState>,
+    },
+    Migration {
+        account_type: AccountType::GlobalConfig,
+        description: "trailing next BGP community and multicast publisher block",
+        upgrade: reserialize::<GlobalConfig>,
+    },
+    Migration {
+        account_type: AccountType::Location,
+        description: "current location layout",
+        upgrade: reserialize::<Location>,
+    },
+    Migration {
+        account_type: AccountType::Exchange,
+        description: "current exchange layout",
+        upgrade: reserialize::<Exchange>,
+    },
+    Migration {
+        account_type: AccountType::Device,
+        description: "InterfaceV1 to InterfaceV2 legacy interfaces, trailing current interfaces, seat counters and config generation",
+        upgrade: reserialize::<Device>,
+    },
+    Migration {
+        account_type: AccountType::Link,
+        description: "trailing delay override, health, desired status, topologies, flags and flap damping fields",
+        upgrade: reserialize::<Link>,
+    },
+    Migration {
+        account_type: AccountType::User,
+        description: "trailing tunnel endpoint, tunnel flags, BGP status, feed and bandwidth tier fields",
+        upgrade: reserialize::<User>,
+    },
+    Migration {
+        account_type: AccountType::MulticastGroup,
+        description: "current multicast group layout",
+        upgrade: reserialize::<MulticastGroup>,
+    },
+    Migration {
+        account_type: AccountType::Contributor,
+        description: "current contributor layout",
+        upgrade: reserialize::<Contributor>,
+    },
+    Migration {
+        account_type: AccountType::AccessPass,
+        description: "trailing flags, tenant allowlist, per-type user limits and bandwidth tier fields",
+        upgrade: reserialize::<AccessPass>,
+    },
+    Migration {
+        account_type: AccountType::Tenant,
+        description: "current tenant layout",
+        upgrade: reserialize::<Tenant>,
+    },
+];
+
+/// The registered migration for `account_type`, if any.
+pub fn find_migration(account_type: AccountType) -> Option<&'static Migration> {
+    MIGRATIONS
+        .iter()
+        .find(|migration| migration.account_type == account_type)
+}
+
+/// The account's data at the current layout, or `None` if it already is.
+/// Fails for empty data and for account types without a registered migration.
+pub fn migrate_account_data(data: &[u8]) -> Result<Option<Vec<u8>>, ProgramError> {
+    let account_type = AccountType::from(*data.first().ok_or(ProgramError::InvalidAccountData)?);
+    let migration = find_migration(account_type).ok_or(ProgramError::InvalidAccountData)?;
+    let upgraded = migration.upgrade(data)?;
+    Ok((upgraded != data).then_some(upgraded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::location::LocationStatus;
+    use solana_program::pubkey::Pubkey;
+
+    fn location() -> Location {
+        Location {
+            account_type: AccountType::Location,
+            owner: Pubkey::new_unique(),
+            index: 1,
+            bump_seed: 255,
+            lat: 52.37,
+            lng: 4.9,
+            loc_id: 1,
+            status: LocationStatus::Activated,
+            code: "ams".to_string(),
+            name: "Amsterdam".to_string(),
+            country: "NL".to_string(),
+            reference_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_migrate_account_data() {
+        let current = borsh::to_vec(&location()).unwrap();
+        assert_eq!(migrate_account_data(&current).unwrap(), None);
+
+        // A GlobalState written before the trailing fields existed is padded
+        // with their defaults.
+        let globalstate = GlobalState {
+            account_type: AccountType::GlobalState,
+            ..GlobalState::default()
+        };
+        let current = borsh::to_vec(&globalstate).unwrap();
+        let legacy = &current[..current.len() - 8];
+        assert_eq!(migrate_account_data(legacy).unwrap(), Some(current));
+
+        assert!(migrate_account_data(&[]).is_err());
+        assert!(migrate_account_data(&[AccountType::ResourceExtension as u8]).is_err());
+    }
+
+    #[test]
+    fn test_migrations_are_unique() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert!(
+                MIGRATIONS[i + 1..]
+                    .iter()
+                    .all(|other| other.account_type != migration.account_type),
+                "duplicate migration for {}",
+                migration.account_type
+            );
+        }
+    }
+}
@@ -1,17 +1,20 @@
 use std::net::Ipv4Addr;
 
+use doublezero_program_common::types::NetworkV4;
 use solana_program::pubkey::Pubkey;
 
 use crate::{
     seeds::{
-        SEED_ACCESS_PASS, SEED_ADMIN_GROUP_BITS, SEED_CONFIG, SEED_CONTRIBUTOR, SEED_DEVICE,
-        SEED_DEVICE_TUNNEL_BLOCK, SEED_DZ_PREFIX_BLOCK, SEED_EXCHANGE, SEED_FEED, SEED_GLOBALSTATE,
-        SEED_INDEX, SEED_LINK, SEED_LINK_IDS, SEED_LOCATION, SEED_MULTICASTGROUP_BLOCK,
+        SEED_ACCESS_PASS, SEED_ADMIN_GROUP_BITS, SEED_ANYCAST_PREFIX, SEED_CONFIG,
+        SEED_CONTRIBUTOR, SEED_DEVICE, SEED_DEVICE_TUNNEL_BLOCK, SEED_DZ_PREFIX_BLOCK,
+        SEED_EXCHANGE, SEED_FEED, SEED_GLOBALSTATE, SEED_INDEX, SEED_INTERFACE_OPER_STATUS,
+        SEED_IP_ALLOCATION, SEED_LINK, SEED_LINK_IDS, SEED_LOCATION, SEED_MULTICASTGROUP_BLOCK,
         SEED_MULTICAST_GROUP, SEED_MULTICAST_PUBLISHER_BLOCK, SEED_PERMISSION, SEED_PREFIX,
-        SEED_PROGRAM_CONFIG, SEED_SEGMENT_ROUTING_IDS, SEED_TENANT, SEED_TOPOLOGY, SEED_TUNNEL_IDS,
-        SEED_USER, SEED_USER_TUNNEL_BLOCK, SEED_VRF_IDS,
+        SEED_PROGRAM_CONFIG, SEED_QOS_PROFILE, SEED_QOS_PROFILE_IDS, SEED_READ_API_KEY,
+        SEED_REFERRER, SEED_SEGMENT_ROUTING_IDS, SEED_STATE_COMMITMENT, SEED_TENANT, SEED_TOPOLOGY,
+        SEED_TUNNEL_IDS, SEED_USER, SEED_USER_TUNNEL_BLOCK, SEED_VRF_IDS,
     },
-    state::user::UserType,
+    state::user::{BandwidthTier, UserType},
 };
 
 pub fn get_globalstate_pda(program_id: &Pubkey) -> (Pubkey, u8) {
@@ -113,6 +116,24 @@ pub fn get_feed_pda(program_id: &Pubkey, code: &str, exchange: &Pubkey) -> (Pubk
     )
 }
 
+/// A ReadApiKey PDA is seeded by the contributor and the SHA-256 hash of the key, so a key can be
+/// looked up from the presented secret alone and the same key cannot be registered twice.
+pub fn get_read_api_key_pda(
+    program_id: &Pubkey,
+    contributor_pk: &Pubkey,
+    key_hash: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_READ_API_KEY,
+            contributor_pk.as_ref(),
+            key_hash,
+        ],
+        program_id,
+    )
+}
+
 /// Name of the default unicast topology that `CreateLink` auto-tags every link
 /// into. This is the canonical spelling; consumers should reference this constant
 /// rather than re-typing the literal.
@@ -123,6 +144,54 @@ pub fn get_topology_pda(program_id: &Pubkey, name: &str) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEED_PREFIX, SEED_TOPOLOGY, upper.as_bytes()], program_id)
 }
 
+pub fn get_qos_profile_pda(program_id: &Pubkey, bandwidth_tier: BandwidthTier) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_QOS_PROFILE, &[bandwidth_tier as u8]],
+        program_id,
+    )
+}
+
+pub fn get_ip_allocation_pda(program_id: &Pubkey, prefix: &NetworkV4) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_IP_ALLOCATION,
+            &prefix.ip().octets(),
+            &[prefix.prefix()],
+        ],
+        program_id,
+    )
+}
+
+pub fn get_interface_oper_status_pda(program_id: &Pubkey, device_pk: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_INTERFACE_OPER_STATUS,
+            &device_pk.to_bytes(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_anycast_prefix_pda(program_id: &Pubkey, code: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_ANYCAST_PREFIX, code.as_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_referrer_stats_pda(program_id: &Pubkey, referrer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_REFERRER, &referrer.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_state_commitment_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, SEED_STATE_COMMITMENT], program_id)
+}
+
 pub fn get_index_pda(program_id: &Pubkey, entity_seed: &[u8], key: &str) -> (Pubkey, u8) {
     let lowercase_key = key.to_ascii_lowercase();
     Pubkey::find_program_address(
@@ -207,5 +276,10 @@ pub fn get_resource_extension_pda(
                 Pubkey::find_program_address(&[SEED_PREFIX, SEED_ADMIN_GROUP_BITS], program_id);
             (pda, bump_seed, SEED_ADMIN_GROUP_BITS)
         }
+        crate::resource::ResourceType::QosProfileIds => {
+            let (pda, bump_seed) =
+                Pubkey::find_program_address(&[SEED_PREFIX, SEED_QOS_PROFILE_IDS], program_id);
+            (pda, bump_seed, SEED_QOS_PROFILE_IDS)
+        }
     }
 }
@@ -108,6 +108,15 @@ pub fn process_close_access_pass(
             return Err(DoubleZeroError::AccessPassInUse.into());
         }
 
+        // Closing would hand the deposit to the payer, so a lease has to be settled first.
+        if accesspass.lease_deposit() != 0 {
+            msg!(
+                "AccessPass holds {} lamports of lease deposit, cannot close",
+                accesspass.lease_deposit()
+            );
+            return Err(DoubleZeroError::LeaseDepositUnsettled.into());
+        }
+
         msg!("AccountType is AccessPass and there are no active connections, proceeding to close");
     } else {
         msg!("Failed to borrow account data, cannot close");
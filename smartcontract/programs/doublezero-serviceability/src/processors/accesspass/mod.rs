@@ -2,6 +2,7 @@ pub mod check_status;
 pub mod close;
 pub mod set;
 pub mod set_feeds;
+pub mod settle_lease;
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed_unchecked,
@@ -9,8 +10,8 @@ use solana_program::{
 };
 
 // Value to rent exempt three `User` accounts + configurable amount for connect/disconnect txns.
-// `User` account size assumes a single publisher and subscriber pubkey registered (298 bytes each).
-pub const AIRDROP_USER_RENT_LAMPORTS_BYTES: usize = 298 * 3; // 298 bytes per User account x 3 accounts = 894 bytes
+// `User` account size assumes a single publisher and subscriber pubkey registered (299 bytes each).
+pub const AIRDROP_USER_RENT_LAMPORTS_BYTES: usize = 299 * 3; // 299 bytes per User account x 3 accounts = 897 bytes
 
 /// Default per-user airdrop seeded into `GlobalState.user_airdrop_lamports` at initialization.
 /// Admins can override it via the `SetAirdrop` instruction.
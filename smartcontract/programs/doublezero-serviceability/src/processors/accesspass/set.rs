@@ -1,16 +1,20 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    format_option,
+    helper::format_option_displayable,
     pda::*,
     processors::accesspass::airdrop_user_credits,
-    seeds::{SEED_ACCESS_PASS, SEED_PREFIX},
+    seeds::{SEED_ACCESS_PASS, SEED_PREFIX, SEED_REFERRER},
     serializer::{try_acc_create, try_acc_write},
     state::{
         accesspass::{AccessPass, AccessPassStatus, AccessPassType, ALLOW_MULTIPLE_IP},
         accounttype::AccountType,
         globalstate::GlobalState,
         permission::permission_flags,
+        referrer_stats::ReferrerStats,
         tenant::Tenant,
+        user::BandwidthTier,
     },
 };
 use borsh::BorshSerialize;
@@ -21,7 +25,9 @@ use solana_program::{
     clock::Clock,
     entrypoint::ProgramResult,
     msg,
+    program::invoke_signed_unchecked,
     pubkey::Pubkey,
+    rent::Rent,
     sysvar::Sysvar,
 };
 
@@ -38,19 +44,27 @@ pub struct SetAccessPassArgs {
     pub max_unicast_users: u16, // 2
     #[incremental(default = 1)]
     pub max_multicast_users: u16, // 2
+    /// Bandwidth tier for users of the pass. `None` leaves an existing pass's tier
+    /// unchanged and creates new passes unshaped.
+    pub bandwidth_tier: Option<BandwidthTier>, // 1 or 2
+    /// Referrer credited with a new pass; its ReferrerStats account follows `user_payer`.
+    /// An existing pass keeps the referrer it was created with.
+    pub referrer: Option<Pubkey>, // 1 or 33
 }
 
 impl fmt::Debug for SetAccessPassArgs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "accesspass_type: {}, ip: {}, last_access_epoch: {}, allow_multiple_ip: {}, max_unicast_users: {}, max_multicast_users: {}",
+            "accesspass_type: {}, ip: {}, last_access_epoch: {}, allow_multiple_ip: {}, max_unicast_users: {}, max_multicast_users: {}, bandwidth_tier: {}, referrer: {}",
             self.accesspass_type,
             self.client_ip,
             self.last_access_epoch,
             self.allow_multiple_ip,
             self.max_unicast_users,
             self.max_multicast_users,
+            format_option!(self.bandwidth_tier),
+            format_option!(self.referrer),
         )
     }
 }
@@ -66,12 +80,21 @@ pub fn process_set_access_pass(
     let globalstate_account = next_account_info(accounts_iter)?;
     let user_payer = next_account_info(accounts_iter)?;
 
+    // The referrer's stats account is present exactly when the args name a referrer.
+    let referrer_stats_account = if value.referrer.is_some() {
+        Some(next_account_info(accounts_iter)?)
+    } else {
+        None
+    };
+    let referrer_accounts = usize::from(referrer_stats_account.is_some());
+
     // Optional tenant accounts for reference counting (backwards compatible).
     //
     // The account layout is a fixed prefix `[accesspass, globalstate, user_payer]`,
-    // an optional `[tenant_remove, tenant_add]` pair, the fixed `[payer, system]`,
-    // and an optional trailing Permission account appended by the SDK for
-    // `authorize()`. That yields exactly four possible lengths:
+    // the optional referrer stats account, an optional `[tenant_remove, tenant_add]`
+    // pair, the fixed `[payer, system]`, and an optional trailing Permission account
+    // appended by the SDK for `authorize()`. Without a referrer that yields exactly
+    // four possible lengths (each one more with a referrer):
     //   5 = no tenant, no permission        6 = no tenant, permission
     //   7 = tenant,   no permission         8 = tenant,   permission
     // so `>= 7` unambiguously selects the tenant-present shapes. The trailing
@@ -79,7 +102,7 @@ pub fn process_set_access_pass(
     // by `authorize()`, which independently verifies its PDA address, program
     // ownership, and `AccountType::Permission` discriminator — a misclassified
     // account can never be accepted as either a tenant or a permission account.
-    let (tenant_remove_account, tenant_add_account) = if accounts.len() >= 7 {
+    let (tenant_remove_account, tenant_add_account) = if accounts.len() >= 7 + referrer_accounts {
         let remove = next_account_info(accounts_iter)?;
         let add = next_account_info(accounts_iter)?;
         (Some(remove), Some(add))
@@ -163,6 +186,13 @@ pub fn process_set_access_pass(
         return Err(DoubleZeroError::NotAllowed.into());
     }
 
+    // Tiers decide how users are shaped, so only privileged callers may assign them; a tenant
+    // administrator can manage its passes but not upgrade them.
+    if value.bandwidth_tier.is_some() && !is_privileged {
+        msg!("Only an ACCESS_PASS_ADMIN may set the bandwidth tier");
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
     if let AccessPassType::SolanaValidator(node_id) = value.accesspass_type {
         if node_id == Pubkey::default() {
             msg!("Solana validator access pass type requires a validator pubkey");
@@ -170,6 +200,14 @@ pub fn process_set_access_pass(
         }
     }
 
+    if let Some(referrer) = value.referrer {
+        // Self-referral would let a holder credit its own onboarding.
+        if referrer == Pubkey::default() || referrer == *user_payer.key {
+            msg!("Invalid referrer: {}", referrer);
+            return Err(DoubleZeroError::InvalidReferrer.into());
+        }
+    }
+
     let clock = Clock::get()?;
     let current_epoch = clock.epoch;
 
@@ -177,12 +215,27 @@ pub fn process_set_access_pass(
         return Err(DoubleZeroError::InvalidLastAccessEpoch.into());
     }
 
+    // A lease holds a deposit until it expires, so it needs both a deposit and an expiry.
+    let lease_deposit = match value.accesspass_type {
+        AccessPassType::Leased(deposit) => {
+            if deposit == 0 || value.last_access_epoch == 0 || value.last_access_epoch == u64::MAX {
+                msg!("Leased access pass requires a deposit and a finite last_access_epoch");
+                return Err(DoubleZeroError::InvalidLeaseDeposit.into());
+            }
+            deposit
+        }
+        _ => 0,
+    };
+
     // Flags
     let mut flags = 0;
     if value.allow_multiple_ip {
         flags |= ALLOW_MULTIPLE_IP;
     }
 
+    // Whether this call creates the pass, and so credits the referrer.
+    let mut created = false;
+
     // If account does not exist, create it
     if *accesspass_account.owner == solana_system_interface::program::ID {
         let accesspass = AccessPass {
@@ -207,6 +260,8 @@ pub fn process_set_access_pass(
             max_unicast_users: value.max_unicast_users,
             multicast_user_count: 0,
             max_multicast_users: value.max_multicast_users,
+            bandwidth_tier: value.bandwidth_tier.unwrap_or_default(),
+            referrer: value.referrer.unwrap_or_default(),
         };
 
         try_acc_create(
@@ -226,6 +281,8 @@ pub fn process_set_access_pass(
 
         #[cfg(test)]
         msg!("Created: {:?}", accesspass);
+
+        created = true;
     } else {
         // Read or create Access Pass
         // Old bug where close accounts were not fully zeroed out instead of being closed
@@ -244,8 +301,42 @@ pub fn process_set_access_pass(
                 return Err(DoubleZeroError::NotAllowed.into());
             }
 
+            // A held deposit can only grow while its lease runs, and must be settled once the
+            // lease has expired or before the pass stops being a lease.
+            let held_deposit = ap.lease_deposit();
+            if held_deposit > 0 {
+                if current_epoch > ap.last_access_epoch || lease_deposit == 0 {
+                    msg!(
+                        "AccessPass holds {} lamports of lease deposit",
+                        held_deposit
+                    );
+                    return Err(DoubleZeroError::LeaseDepositUnsettled.into());
+                }
+                if lease_deposit < held_deposit {
+                    msg!(
+                        "Lease deposit cannot shrink from {} to {}",
+                        held_deposit,
+                        lease_deposit
+                    );
+                    return Err(DoubleZeroError::InvalidLeaseDeposit.into());
+                }
+            }
+
+            // Attribution is fixed at creation: an existing pass keeps its referrer.
+            if let Some(referrer) = value.referrer {
+                if ap.referrer != referrer {
+                    msg!(
+                        "AccessPass referrer is {} and cannot change to {}",
+                        ap.referrer,
+                        referrer
+                    );
+                    return Err(DoubleZeroError::InvalidReferrer.into());
+                }
+            }
+
             ap
         } else {
+            created = true;
             AccessPass {
                 account_type: AccountType::AccessPass,
                 bump_seed,
@@ -264,6 +355,8 @@ pub fn process_set_access_pass(
                 max_unicast_users: value.max_unicast_users,
                 multicast_user_count: 0,
                 max_multicast_users: value.max_multicast_users,
+                bandwidth_tier: value.bandwidth_tier.unwrap_or_default(),
+                referrer: value.referrer.unwrap_or_default(),
             }
         };
 
@@ -284,6 +377,9 @@ pub fn process_set_access_pass(
         accesspass.flags = flags;
         accesspass.max_unicast_users = value.max_unicast_users;
         accesspass.max_multicast_users = value.max_multicast_users;
+        if let Some(bandwidth_tier) = value.bandwidth_tier {
+            accesspass.bandwidth_tier = bandwidth_tier;
+        }
 
         if let Some(tenant_remove) = tenant_remove_account {
             accesspass
@@ -305,6 +401,45 @@ pub fn process_set_access_pass(
         msg!("Updated: {:?}", accesspass);
     }
 
+    // The pass account holds the lease deposit on top of its rent.
+    if lease_deposit > 0 {
+        let target = Rent::get()?
+            .minimum_balance(accesspass_account.data_len())
+            .saturating_add(lease_deposit);
+        let top_up = target.saturating_sub(accesspass_account.lamports());
+        if top_up > 0 {
+            msg!("Depositing {} lamports for the lease", top_up);
+            invoke_signed_unchecked(
+                &solana_system_interface::instruction::transfer(
+                    payer_account.key,
+                    accesspass_account.key,
+                    top_up,
+                ),
+                &[
+                    payer_account.clone(),
+                    accesspass_account.clone(),
+                    system_program.clone(),
+                ],
+                &[],
+            )?;
+        }
+    }
+
+    if let (Some(referrer), Some(referrer_stats_account)) = (value.referrer, referrer_stats_account)
+    {
+        if created {
+            record_referral(
+                program_id,
+                referrer,
+                referrer_stats_account,
+                payer_account,
+                system_program,
+                accounts,
+                current_epoch,
+            )?;
+        }
+    }
+
     // Manage tenant reference counting for added/removed tenants (if provided)
     if let Some(tenant_remove_acc) = tenant_remove_account {
         if tenant_remove_acc.key != &Pubkey::default() {
@@ -385,6 +520,61 @@ pub fn process_set_access_pass(
     Ok(())
 }
 
+/// Credit `referrer` with a new pass, creating its stats account on the first referral.
+fn record_referral<'a>(
+    program_id: &Pubkey,
+    referrer: Pubkey,
+    referrer_stats_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+    epoch: u64,
+) -> ProgramResult {
+    let (expected_pda, bump_seed) = get_referrer_stats_pda(program_id, &referrer);
+    assert_eq!(
+        referrer_stats_account.key, &expected_pda,
+        "Invalid ReferrerStats PubKey"
+    );
+    assert!(
+        referrer_stats_account.is_writable,
+        "ReferrerStats Account is not writable"
+    );
+
+    if referrer_stats_account.data_is_empty() {
+        let mut stats = ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer,
+            bump_seed,
+            ..Default::default()
+        };
+        stats.record_referral(epoch);
+
+        try_acc_create(
+            &stats,
+            referrer_stats_account,
+            payer_account,
+            system_program,
+            program_id,
+            &[
+                SEED_PREFIX,
+                SEED_REFERRER,
+                &referrer.to_bytes(),
+                &[bump_seed],
+            ],
+        )?;
+    } else {
+        assert_eq!(
+            referrer_stats_account.owner, program_id,
+            "Invalid ReferrerStats Account Owner"
+        );
+        let mut stats = ReferrerStats::try_from(referrer_stats_account)?;
+        stats.record_referral(epoch);
+        try_acc_write(&stats, referrer_stats_account, payer_account, accounts)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -427,6 +617,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         // User with 1 subscriber only (publisher use case)
@@ -454,6 +645,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         // User with both 1 publisher and 1 subscriber (future simultaneous pub/sub)
@@ -481,6 +673,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let size_with_publisher = borsh::object_length(&user_with_publisher).unwrap();
@@ -488,26 +681,26 @@ mod tests {
         let size_with_both = borsh::object_length(&user_with_both).unwrap();
 
         // Verify our understanding of the sizes
-        // Base User size (empty vecs) = 234 bytes (includes tunnel_flags, bgp_status, last_bgp_up_at,
-        // last_bgp_reported_at, bgp_rtt_ns, and the 32-byte feed_pk)
+        // Base User size (empty vecs) = 235 bytes (includes tunnel_flags, bgp_status, last_bgp_up_at,
+        // last_bgp_reported_at, bgp_rtt_ns, the 32-byte feed_pk and bandwidth_tier)
         // Each Pubkey in publishers/subscribers adds 32 bytes
         assert_eq!(
-            size_with_publisher, 266,
-            "User with 1 publisher should be 266 bytes"
+            size_with_publisher, 267,
+            "User with 1 publisher should be 267 bytes"
         );
         assert_eq!(
-            size_with_subscriber, 266,
-            "User with 1 subscriber should be 266 bytes"
+            size_with_subscriber, 267,
+            "User with 1 subscriber should be 267 bytes"
         );
         assert_eq!(
-            size_with_both, 298,
-            "User with 1 publisher + 1 subscriber should be 298 bytes"
+            size_with_both, 299,
+            "User with 1 publisher + 1 subscriber should be 299 bytes"
         );
 
-        // The constant should be sized for 3 accounts with both pub+sub (298 * 3 = 894)
+        // The constant should be sized for 3 accounts with both pub+sub (299 * 3 = 897)
         assert_eq!(
             AIRDROP_USER_RENT_LAMPORTS_BYTES,
-            298 * 3,
+            299 * 3,
             "AIRDROP_USER_RENT_LAMPORTS_BYTES should be sized for 3 User accounts with pub+sub"
         );
 
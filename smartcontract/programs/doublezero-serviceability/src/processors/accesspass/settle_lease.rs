@@ -0,0 +1,117 @@
+use crate::{
+    error::DoubleZeroError,
+    serializer::try_acc_write,
+    state::accesspass::{AccessPass, AccessPassType},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    incinerator, msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct SettleAccessPassLeaseArgs {}
+
+impl fmt::Debug for SettleAccessPassLeaseArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// Burns the deposit of an expired lease by moving it to the incinerator, leaving the pass as a
+/// settled `Leased(0)`. The outcome is fixed by the pass itself, so anyone may settle it.
+pub fn process_settle_access_pass_lease(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &SettleAccessPassLeaseArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let accesspass_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let incinerator_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_settle_access_pass_lease({:?})", _value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    if accesspass_account.data_is_empty() {
+        return Err(DoubleZeroError::AccessPassNotFound.into());
+    }
+    assert_eq!(
+        accesspass_account.owner, program_id,
+        "Invalid AccessPass Account Owner"
+    );
+    assert_eq!(
+        *globalstate_account.owner,
+        program_id.clone(),
+        "Invalid GlobalState Account Owner"
+    );
+    assert_eq!(
+        *incinerator_account.key,
+        incinerator::ID,
+        "Invalid Incinerator Account"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+    assert!(
+        accesspass_account.is_writable,
+        "PDA Account is not writable"
+    );
+    assert!(
+        incinerator_account.is_writable,
+        "Incinerator Account is not writable"
+    );
+
+    let mut accesspass = AccessPass::try_from(accesspass_account)?;
+    let deposit = accesspass.lease_deposit();
+    if deposit == 0 {
+        msg!("AccessPass holds no lease deposit: {}", accesspass);
+        return Err(DoubleZeroError::InvalidLeaseDeposit.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    if current_epoch <= accesspass.last_access_epoch {
+        msg!(
+            "Lease runs until epoch {}, current epoch {}",
+            accesspass.last_access_epoch,
+            current_epoch
+        );
+        return Err(DoubleZeroError::LeaseNotExpired.into());
+    }
+
+    // Never dip into the rent of the pass account.
+    let rent = Rent::get()?.minimum_balance(accesspass_account.data_len());
+    let burn = deposit.min(accesspass_account.lamports().saturating_sub(rent));
+
+    **accesspass_account.lamports.borrow_mut() = accesspass_account
+        .lamports()
+        .checked_sub(burn)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **incinerator_account.lamports.borrow_mut() = incinerator_account
+        .lamports()
+        .checked_add(burn)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    accesspass.accesspass_type = AccessPassType::Leased(0);
+    try_acc_write(&accesspass, accesspass_account, payer_account, accounts)?;
+
+    msg!("Burned {} lamports of lease deposit", burn);
+
+    Ok(())
+}
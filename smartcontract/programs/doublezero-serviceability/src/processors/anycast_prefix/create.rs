@@ -0,0 +1,120 @@
+use crate::{
+    authorize::authorize,
+    error::{DoubleZeroError, Validate},
+    pda::{get_anycast_prefix_pda, get_globalstate_pda},
+    processors::validation::validate_program_account,
+    seeds::{SEED_ANYCAST_PREFIX, SEED_PREFIX},
+    serializer::try_acc_create,
+    state::{
+        accounttype::AccountType, anycast_prefix::AnycastPrefix, globalstate::GlobalState,
+        permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::{types::NetworkV4, validate_account_code};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq)]
+pub struct AnycastPrefixCreateArgs {
+    pub code: String,
+    pub prefix: NetworkV4,
+    pub enabled: bool,
+}
+
+/// Accounts layout:
+/// [0] anycast_prefix PDA (writable, to be created)
+/// [1] globalstate        (readonly)
+/// [2] payer              (writable, signer, must hold NETWORK_ADMIN)
+/// [3] system_program
+/// [4] permission         (readonly, optional — payer's Permission PDA)
+pub fn process_create_anycast_prefix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &AnycastPrefixCreateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let anycast_prefix_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_create_anycast_prefix({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer account must be a signer");
+
+    let code =
+        validate_account_code(&value.code).map_err(|_| DoubleZeroError::InvalidAccountCode)?;
+
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // The account is still empty, so check the PDA and writability directly.
+    let (expected_pda, bump_seed) = get_anycast_prefix_pda(program_id, &code);
+    assert_eq!(
+        anycast_prefix_account.key, &expected_pda,
+        "Invalid AnycastPrefix PubKey"
+    );
+    assert!(
+        anycast_prefix_account.is_writable,
+        "AnycastPrefix Account is not writable"
+    );
+
+    if !anycast_prefix_account.data_is_empty() {
+        msg!("Anycast prefix {} already exists", code);
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let anycast_prefix = AnycastPrefix {
+        account_type: AccountType::AnycastPrefix,
+        owner: *payer_account.key,
+        bump_seed,
+        code,
+        prefix: value.prefix,
+        enabled: value.enabled,
+    };
+    anycast_prefix.validate()?;
+
+    try_acc_create(
+        &anycast_prefix,
+        anycast_prefix_account,
+        payer_account,
+        system_program,
+        program_id,
+        &[
+            SEED_PREFIX,
+            SEED_ANYCAST_PREFIX,
+            anycast_prefix.code.as_bytes(),
+            &[bump_seed],
+        ],
+    )?;
+
+    msg!(
+        "Created anycast prefix {} ({})",
+        anycast_prefix.code,
+        anycast_prefix.prefix
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{
+    authorize::authorize,
+    pda::get_globalstate_pda,
+    processors::validation::validate_program_account,
+    serializer::try_acc_close,
+    state::{
+        anycast_prefix::AnycastPrefix, globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq, Default)]
+pub struct AnycastPrefixDeleteArgs {}
+
+/// Accounts layout:
+/// [0] anycast_prefix PDA (writable, to be closed)
+/// [1] globalstate        (readonly)
+/// [2] payer              (writable, signer, must hold NETWORK_ADMIN)
+/// [3] system_program
+/// [4] permission         (readonly, optional — payer's Permission PDA)
+pub fn process_delete_anycast_prefix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &AnycastPrefixDeleteArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let anycast_prefix_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(
+        anycast_prefix_account,
+        program_id,
+        writable = true,
+        "AnycastPrefix"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // Devices withdraw the address on their next config push.
+    let anycast_prefix = AnycastPrefix::try_from(anycast_prefix_account)?;
+    try_acc_close(anycast_prefix_account, payer_account)?;
+
+    msg!(
+        "Deleted anycast prefix {} ({})",
+        anycast_prefix.code,
+        anycast_prefix.prefix
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::{
+    authorize::authorize,
+    error::Validate,
+    pda::get_globalstate_pda,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{
+        anycast_prefix::AnycastPrefix, globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::types::NetworkV4;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+#[cfg(test)]
+use solana_program::msg;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq, Default)]
+pub struct AnycastPrefixUpdateArgs {
+    pub prefix: Option<NetworkV4>,
+    pub enabled: Option<bool>,
+}
+
+/// Accounts layout:
+/// [0] anycast_prefix PDA (writable)
+/// [1] globalstate        (readonly)
+/// [2] payer              (writable, signer, must hold NETWORK_ADMIN)
+/// [3] system_program
+/// [4] permission         (readonly, optional — payer's Permission PDA)
+pub fn process_update_anycast_prefix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &AnycastPrefixUpdateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let anycast_prefix_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_update_anycast_prefix({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(
+        anycast_prefix_account,
+        program_id,
+        writable = true,
+        "AnycastPrefix"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // code is fixed for the life of the record.
+    let mut anycast_prefix = AnycastPrefix::try_from(anycast_prefix_account)?;
+    if let Some(prefix) = value.prefix {
+        anycast_prefix.prefix = prefix;
+    }
+    if let Some(enabled) = value.enabled {
+        anycast_prefix.enabled = enabled;
+    }
+    anycast_prefix.validate()?;
+    try_acc_write(
+        &anycast_prefix,
+        anycast_prefix_account,
+        payer_account,
+        accounts,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::DoubleZeroError,
+    events::DoubleZeroEvent,
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType,
+        contributor::*,
+        device::{Device, DeviceDesiredStatus},
+        globalstate::GlobalState,
+        link::{Link, LinkDesiredStatus},
+        permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Upper bound on the devices and links drained by a single call, chosen so a
+/// full batch plus the fixed accounts still fits in one transaction.
+pub const MAX_EMERGENCY_DRAIN_BATCH: usize = 24;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct ContributorEmergencyDrainArgs {}
+
+impl fmt::Debug for ContributorEmergencyDrainArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// Drain a batch of an emergency-suspended contributor's devices and links.
+///
+/// Foundation only. Accounts: `[contributor, globalstate, resources..., payer,
+/// system_program, (permission)]`, where `resources` are between 1 and
+/// `MAX_EMERGENCY_DRAIN_BATCH` of the contributor's devices and links. The
+/// contributor must already be suspended by `EmergencySuspendContributor`.
+/// Each device's desired status is set to `Drained` and each link's to
+/// `HardDrained`; the activator and health oracle then move their status.
+/// Draining a resource again is harmless, so a failed batch can be retried.
+pub fn process_emergency_drain_contributor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &ContributorEmergencyDrainArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    // The batch of devices and links sits between globalstate and the payer.
+    let remaining: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, system_program, resource_accounts, permission_account) =
+        split_trailing_permission(program_id, &remaining)?;
+
+    #[cfg(test)]
+    msg!("process_emergency_drain_contributor({:?})", _value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    assert_eq!(
+        contributor_account.owner, program_id,
+        "Invalid PDA Account Owner"
+    );
+    assert_eq!(
+        globalstate_account.owner, program_id,
+        "Invalid GlobalState Account Owner"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    // Authorization: FOUNDATION (Permission account) or foundation allowlist (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::FOUNDATION,
+    )?;
+
+    let contributor: Contributor = Contributor::try_from(contributor_account)?;
+    if contributor.status != ContributorStatus::Suspended {
+        msg!(
+            "Contributor {} is {:?}, not suspended",
+            contributor_account.key,
+            contributor.status
+        );
+        return Err(DoubleZeroError::InvalidStatus.into());
+    }
+
+    if resource_accounts.is_empty() || resource_accounts.len() > MAX_EMERGENCY_DRAIN_BATCH {
+        msg!(
+            "Expected 1 to {} devices and links, got {}",
+            MAX_EMERGENCY_DRAIN_BATCH,
+            resource_accounts.len()
+        );
+        return Err(DoubleZeroError::InvalidArgument.into());
+    }
+
+    for (i, account) in resource_accounts.iter().enumerate() {
+        if resource_accounts[..i].iter().any(|a| a.key == account.key) {
+            msg!("Account {} is passed more than once", account.key);
+            return Err(ProgramError::InvalidArgument);
+        }
+        if account.owner != program_id {
+            return Err(DoubleZeroError::InvalidAccountOwner.into());
+        }
+        assert!(account.is_writable, "Resource Account is not writable");
+
+        let account_type =
+            AccountType::from(account.try_borrow_data()?.first().copied().unwrap_or(0));
+        match account_type {
+            AccountType::Device => {
+                let mut device = Device::try_from(*account)?;
+                if device.contributor_pk != *contributor_account.key {
+                    msg!(
+                        "Device {} belongs to contributor {}",
+                        account.key,
+                        device.contributor_pk
+                    );
+                    return Err(DoubleZeroError::InvalidContributorPubkey.into());
+                }
+
+                let from = device.status;
+                device.desired_status = DeviceDesiredStatus::Drained;
+                device.check_status_transition();
+                device.bump_config_generation();
+                try_acc_write(&device, account, payer_account, accounts)?;
+
+                DoubleZeroEvent::DeviceStatusChanged {
+                    device: *account.key,
+                    from,
+                    to: device.status,
+                }
+                .emit();
+            }
+            AccountType::Link => {
+                let mut link = Link::try_from(*account)?;
+                if link.contributor_pk != *contributor_account.key {
+                    msg!(
+                        "Link {} belongs to contributor {}",
+                        account.key,
+                        link.contributor_pk
+                    );
+                    return Err(DoubleZeroError::InvalidContributorPubkey.into());
+                }
+
+                let from = link.status;
+                link.desired_status = LinkDesiredStatus::HardDrained;
+                link.check_status_transition();
+                try_acc_write(&link, account, payer_account, accounts)?;
+
+                DoubleZeroEvent::LinkStatusChanged {
+                    link: *account.key,
+                    from,
+                    to: link.status,
+                }
+                .emit();
+            }
+            _ => {
+                msg!("Account {} is not a device or link", account.key);
+                return Err(DoubleZeroError::InvalidAccountType.into());
+            }
+        }
+    }
+
+    Ok(())
+}
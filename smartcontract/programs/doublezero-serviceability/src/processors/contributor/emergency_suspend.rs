@@ -0,0 +1,104 @@
+use crate::{
+    authorize::authorize,
+    events::DoubleZeroEvent,
+    serializer::try_acc_write,
+    state::{contributor::*, globalstate::GlobalState, permission::permission_flags},
+    state_machine::validate_transition,
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+#[cfg(test)]
+use solana_program::msg;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct ContributorEmergencySuspendArgs {}
+
+impl fmt::Debug for ContributorEmergencySuspendArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// Suspend a contributor in response to a severe violation.
+///
+/// Foundation only. Accounts: `[contributor, globalstate, payer, system_program,
+/// (permission)]`. This is the first step of an emergency suspension: once the
+/// contributor is suspended it cannot create devices, links or IP allocations.
+/// Its existing devices and links are then drained in batches with
+/// `EmergencyDrainContributor`.
+pub fn process_emergency_suspend_contributor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &ContributorEmergencySuspendArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_emergency_suspend_contributor({:?})", _value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    assert_eq!(
+        contributor_account.owner, program_id,
+        "Invalid PDA Account Owner"
+    );
+    assert_eq!(
+        globalstate_account.owner, program_id,
+        "Invalid GlobalState Account Owner"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+    assert!(
+        contributor_account.is_writable,
+        "PDA Account is not writable"
+    );
+
+    // Authorization: FOUNDATION (Permission account) or foundation allowlist (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::FOUNDATION,
+    )?;
+
+    let mut contributor: Contributor = Contributor::try_from(contributor_account)?;
+    validate_transition(
+        contributor.status,
+        ContributorStatus::Suspended,
+        "EmergencySuspendContributor",
+        true,
+    )?;
+
+    let from = contributor.status;
+    contributor.status = ContributorStatus::Suspended;
+
+    try_acc_write(&contributor, contributor_account, payer_account, accounts)?;
+
+    #[cfg(test)]
+    msg!("Suspended: {:?}", contributor);
+
+    DoubleZeroEvent::ContributorStatusChanged {
+        contributor: *contributor_account.key,
+        from,
+        to: contributor.status,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 pub mod create;
 pub mod delete;
+pub mod emergency_drain;
+pub mod emergency_suspend;
 pub mod resume;
 pub mod suspend;
 pub mod update;
@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{contributor::*, globalstate::GlobalState, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -79,10 +80,14 @@ pub fn process_resume_contributor(
     let mut contributor: Contributor = Contributor::try_from(contributor_account)?;
 
     // Only resume contributors that are currently Suspended
-    if contributor.status != ContributorStatus::Suspended {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        contributor.status,
+        ContributorStatus::Activated,
+        "ResumeContributor",
+        true,
+    )?;
 
+    let from = contributor.status;
     contributor.status = ContributorStatus::Activated;
 
     try_acc_write(&contributor, contributor_account, payer_account, accounts)?;
@@ -90,5 +95,12 @@ pub fn process_resume_contributor(
     #[cfg(test)]
     msg!("Resumed: {:?}", contributor);
 
+    DoubleZeroEvent::ContributorStatusChanged {
+        contributor: *contributor_account.key,
+        from,
+        to: contributor.status,
+    }
+    .emit();
+
     Ok(())
 }
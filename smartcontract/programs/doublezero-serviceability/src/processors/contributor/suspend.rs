@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{contributor::*, globalstate::GlobalState, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -78,12 +79,14 @@ pub fn process_suspend_contributor(
 
     let mut contributor: Contributor = Contributor::try_from(contributor_account)?;
 
-    if contributor.status != ContributorStatus::Activated {
-        #[cfg(test)]
-        msg!("{:?}", contributor);
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        contributor.status,
+        ContributorStatus::Suspended,
+        "SuspendContributor",
+        true,
+    )?;
 
+    let from = contributor.status;
     contributor.status = ContributorStatus::Suspended;
 
     try_acc_write(&contributor, contributor_account, payer_account, accounts)?;
@@ -91,5 +94,12 @@ pub fn process_suspend_contributor(
     #[cfg(test)]
     msg!("Suspended: {:?}", contributor);
 
+    DoubleZeroEvent::ContributorStatusChanged {
+        contributor: *contributor_account.key,
+        from,
+        to: contributor.status,
+    }
+    .emit();
+
     Ok(())
 }
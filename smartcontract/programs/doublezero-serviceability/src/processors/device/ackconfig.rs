@@ -0,0 +1,98 @@
+use core::fmt;
+
+use crate::{
+    authorize::authorize,
+    error::DoubleZeroError,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{device::Device, globalstate::GlobalState, permission::permission_flags},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+#[cfg(test)]
+use solana_program::msg;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceAckConfigArgs {
+    /// The `config_generation` the controller has applied to the device.
+    pub generation: u64,
+}
+
+impl fmt::Debug for DeviceAckConfigArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "generation: {}", self.generation)
+    }
+}
+
+pub fn process_ack_config_device(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceAckConfigArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let device_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_ack_config_device({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    let mut device = Device::try_from(device_account)?;
+
+    // Authorization: the device's metrics publisher (the agent applying the
+    // config on the box, including a rotated-out one during its overlap), or
+    // ACTIVATOR / NETWORK_ADMIN via a Permission account or the legacy
+    // activator_authority_pk / foundation_allowlist.
+    if !device.is_metrics_publisher(payer_account.key, Clock::get()?.epoch) {
+        let globalstate = GlobalState::try_from(globalstate_account)?;
+        authorize(
+            program_id,
+            accounts_iter,
+            payer_account.key,
+            &globalstate,
+            permission_flags::ACTIVATOR | permission_flags::NETWORK_ADMIN,
+        )?;
+    }
+
+    if value.generation > device.config_generation {
+        return Err(DoubleZeroError::InvalidConfigGeneration.into());
+    }
+
+    // Acks can arrive out of order when the controller retries; never move the
+    // applied generation backwards.
+    if value.generation <= device.applied_config_generation {
+        return Ok(());
+    }
+    device.applied_config_generation = value.generation;
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    #[cfg(test)]
+    msg!("Acked config: {:?}", device);
+
+    Ok(())
+}
@@ -7,8 +7,14 @@ use crate::{
     seeds::{SEED_DEVICE, SEED_PREFIX},
     serializer::{try_acc_create, try_acc_write},
     state::{
-        accounttype::AccountType, contributor::Contributor, device::*, exchange::Exchange,
-        globalstate::GlobalState, location::Location, permission::permission_flags,
+        accounttype::AccountType,
+        contributor::{Contributor, ContributorStatus},
+        device::*,
+        exchange::Exchange,
+        feature_flags::{is_feature_enabled, FeatureFlag},
+        globalstate::GlobalState,
+        location::Location,
+        permission::permission_flags,
     },
 };
 use borsh::BorshSerialize;
@@ -143,6 +149,10 @@ pub fn process_create_device(
         return Err(DoubleZeroError::InvalidOwnerPubkey.into());
     }
 
+    if contributor.status == ContributorStatus::Suspended {
+        return Err(DoubleZeroError::ContributorSuspended.into());
+    }
+
     let (expected_pda_account, bump_seed) = get_device_pda(program_id, globalstate.account_index);
     assert_eq!(
         device_account.key, &expected_pda_account,
@@ -197,7 +207,16 @@ pub fn process_create_device(
         public_ip: value.public_ip,
         dz_prefixes: value.dz_prefixes.clone(),
         metrics_publisher_pk: value.metrics_publisher_pk,
-        status: DeviceStatus::Activated,
+        // Under `RequireDeviceAttestation` the device waits in provisioning until its
+        // metrics publisher proves possession of its key.
+        status: if is_feature_enabled(
+            globalstate.feature_flags,
+            FeatureFlag::RequireDeviceAttestation,
+        ) {
+            DeviceStatus::DeviceProvisioning
+        } else {
+            DeviceStatus::Activated
+        },
         mgmt_vrf: value.mgmt_vrf.clone(),
         interfaces: vec![],
         users_count: 0,
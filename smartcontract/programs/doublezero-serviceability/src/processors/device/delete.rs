@@ -1,12 +1,15 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     processors::validation::validate_program_account,
     serializer::{try_acc_close, try_acc_write},
     state::{
         accounttype::AccountType, contributor::Contributor, device::*, exchange::Exchange,
-        globalstate::GlobalState, location::Location, permission::permission_flags,
+        globalstate::GlobalState, location::Location, ops_lock::check_ops_lock,
+        permission::permission_flags,
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -121,13 +124,9 @@ pub fn process_delete_device(
     }
 
     let device: Device = Device::try_from(device_account)?;
+    check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
 
-    if matches!(
-        device.status,
-        DeviceStatus::Activated | DeviceStatus::Deleting
-    ) {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(device.status, DeviceStatus::Deleting, "DeleteDevice", true)?;
 
     if device.reference_count > 0 {
         return Err(DoubleZeroError::ReferenceCountNotZero.into());
@@ -199,12 +198,21 @@ pub fn process_delete_device(
     } else {
         // Legacy path: just mark as Deleting
         let mut device: Device = Device::try_from(device_account)?;
+        let from = device.status;
         device.status = DeviceStatus::Deleting;
+        device.bump_config_generation();
 
         try_acc_write(&device, device_account, payer_account, accounts)?;
 
         #[cfg(test)]
         msg!("Deleting: {:?}", device);
+
+        DoubleZeroEvent::DeviceStatusChanged {
+            device: *device_account.key,
+            from,
+            to: device.status,
+        }
+        .emit();
     }
 
     Ok(())
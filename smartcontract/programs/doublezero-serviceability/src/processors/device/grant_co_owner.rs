@@ -0,0 +1,121 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::DoubleZeroError,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType, contributor::Contributor, device::Device,
+        globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceGrantCoOwnerArgs {
+    pub co_owner_pk: Pubkey,
+}
+
+impl fmt::Debug for DeviceGrantCoOwnerArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "co_owner_pk: {}", self.co_owner_pk)
+    }
+}
+
+/// Grant `co_owner_pk` the contributor owner's update rights on this device only,
+/// replacing any previous co-owner. The co-owner can update the device and its
+/// interfaces, rotate its metrics publisher and lock it, but cannot delete it,
+/// move it to another contributor or grant co-ownership itself.
+///
+/// Not subject to the device's ops lock, so a contributor can always take back
+/// a device from its co-owner.
+pub fn process_grant_device_co_owner(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceGrantCoOwnerArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account layout: [device, contributor, globalstate, payer, system, permission?]
+    let device_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let rest: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, _system_program, _, permission_account) =
+        split_trailing_permission(program_id, &rest)?;
+
+    #[cfg(test)]
+    msg!("process_grant_device_co_owner({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    assert_eq!(globalstate.account_type, AccountType::GlobalState);
+
+    let contributor = Contributor::try_from(contributor_account)?;
+
+    // Authorization: the contributor owner, or NETWORK_ADMIN (Permission account) /
+    // foundation (legacy). A co-owner cannot grant co-ownership.
+    let is_privileged = authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )
+    .is_ok();
+    if contributor.owner != *payer_account.key && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    let mut device = Device::try_from(device_account)?;
+
+    // The supplied contributor must be the one the device belongs to, unless the
+    // caller is privileged (foundation or NETWORK_ADMIN). The owner check below
+    // relies on it.
+    if !is_privileged && device.contributor_pk != *contributor_account.key {
+        return Err(DoubleZeroError::InvalidContributorPubkey.into());
+    }
+
+    if value.co_owner_pk == Pubkey::default() || value.co_owner_pk == contributor.owner {
+        msg!(
+            "Cannot grant co-ownership of {} to {}",
+            device_account.key,
+            value.co_owner_pk
+        );
+        return Err(DoubleZeroError::InvalidCoOwner.into());
+    }
+
+    device.co_owner_pk = value.co_owner_pk;
+    msg!(
+        "Device {} is now co-owned by {}",
+        device_account.key,
+        device.co_owner_pk
+    );
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::{DoubleZeroError, Validate},
+    processors::{
+        device::{
+            interface::update::{
+                apply_interface_fields, check_updated_interface, DeviceInterfaceFieldsUpdate,
+            },
+            is_device_owner,
+        },
+        ip_allocation::check_interface_ip_allocation,
+        validation::validate_program_account,
+    },
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType,
+        contributor::Contributor,
+        device::*,
+        feature_flags::{is_feature_enabled, FeatureFlag},
+        globalstate::GlobalState,
+        ops_lock::check_ops_lock,
+        permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+use std::collections::HashSet;
+
+/// Upper bound on the number of interfaces updated in a single call. Bounds the
+/// duplicate scan and the compute spent re-validating the device.
+pub const MAX_BULK_INTERFACE_UPDATES: usize = 32;
+
+/// Update several interfaces of one device at once. Every update is validated
+/// as `UpdateDeviceInterface` would, and either all of them apply or none does.
+/// Node segment and flex-algo topology changes need extra accounts per
+/// interface and are left to `UpdateDeviceInterface`.
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceInterfaceBulkUpdateArgs {
+    pub updates: Vec<DeviceInterfaceFieldsUpdate>,
+}
+
+impl fmt::Debug for DeviceInterfaceBulkUpdateArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.updates.iter().map(|u| u.name.as_str()).collect();
+        write!(f, "interfaces: [{}]", names.join(", "))
+    }
+}
+
+pub fn process_bulk_update_device_interfaces(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceInterfaceBulkUpdateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account layout:
+    //   [device, contributor, globalstate, ip_allocation_0..N, payer, system, permission?]
+    // The IpAllocation accounts are the ones covering the CYOA/DIA addresses
+    // being set; each re-checked interface must be covered by one of them.
+    let device_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let rest: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, _system_program, ip_allocation_accounts, permission_account) =
+        split_trailing_permission(program_id, &rest)?;
+
+    #[cfg(test)]
+    msg!("process_bulk_update_device_interfaces({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    // Validate accounts
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    if value.updates.is_empty() || value.updates.len() > MAX_BULK_INTERFACE_UPDATES {
+        msg!(
+            "BulkUpdateDeviceInterfaces accepts 1 to {} interfaces, got {}",
+            MAX_BULK_INTERFACE_UPDATES,
+            value.updates.len()
+        );
+        return Err(DoubleZeroError::InvalidArgument.into());
+    }
+    let mut names = HashSet::with_capacity(value.updates.len());
+    if let Some(duplicate) = value
+        .updates
+        .iter()
+        .find(|update| !names.insert(update.name.to_ascii_lowercase()))
+    {
+        msg!("Interface {} is updated more than once", duplicate.name);
+        return Err(DoubleZeroError::InvalidArgument.into());
+    }
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    assert_eq!(globalstate.account_type, AccountType::GlobalState);
+
+    let contributor = Contributor::try_from(contributor_account)?;
+
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy), as for UpdateDeviceInterface.
+    let is_privileged = authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )
+    .is_ok();
+    let mut device: Device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+    check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
+
+    // The supplied contributor must be the one the device belongs to, unless the
+    // caller is privileged (foundation or NETWORK_ADMIN).
+    if !is_privileged && device.contributor_pk != *contributor_account.key {
+        return Err(DoubleZeroError::InvalidContributorPubkey.into());
+    }
+
+    let require_ip_allocations =
+        is_feature_enabled(globalstate.feature_flags, FeatureFlag::RequireIpAllocations);
+
+    for fields in &value.updates {
+        let (idx, _) = device.find_interface(&fields.name).map_err(|_| {
+            msg!("Interface {} not found", fields.name);
+            DoubleZeroError::InterfaceNotFound
+        })?;
+        let mut iface = device.interfaces[idx].clone();
+
+        apply_interface_fields(&mut iface, fields)?;
+        check_updated_interface(&iface, fields)?;
+
+        if fields.touches_ip() {
+            if ip_allocation_accounts.is_empty() {
+                check_interface_ip_allocation(
+                    program_id,
+                    None,
+                    require_ip_allocations,
+                    &device,
+                    &iface,
+                )?;
+            } else if !ip_allocation_accounts.iter().any(|ip_allocation_account| {
+                check_interface_ip_allocation(
+                    program_id,
+                    Some(*ip_allocation_account),
+                    require_ip_allocations,
+                    &device,
+                    &iface,
+                )
+                .is_ok()
+            }) {
+                msg!(
+                    "Interface {} ip_net {} is not covered by any supplied IP allocation",
+                    iface.name,
+                    iface.ip_net
+                );
+                return Err(DoubleZeroError::UnregisteredInterfaceIp.into());
+            }
+        }
+
+        iface.validate()?;
+        device.replace_interface(idx, iface);
+    }
+
+    device.bump_config_generation();
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    #[cfg(test)]
+    msg!("Updated: {:?}", device);
+
+    Ok(())
+}
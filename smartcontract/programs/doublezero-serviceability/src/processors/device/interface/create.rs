@@ -3,6 +3,8 @@ use crate::{
     error::DoubleZeroError,
     pda::get_resource_extension_pda,
     processors::{
+        device::is_device_owner,
+        ip_allocation::check_interface_ip_allocation,
         resource::{allocate_id, allocate_ip},
         validation::validate_program_account,
     },
@@ -12,11 +14,13 @@ use crate::{
         accounttype::AccountType,
         contributor::Contributor,
         device::*,
+        feature_flags::{is_feature_enabled, FeatureFlag},
         globalstate::GlobalState,
         interface::{
             Interface, InterfaceCYOA, InterfaceDIA, InterfaceStatus, InterfaceType, LoopbackType,
             RoutingMode, CURRENT_INTERFACE_SCHEMA_VERSION, CYOA_DIA_INTERFACE_MTU, INTERFACE_MTU,
         },
+        ops_lock::check_ops_lock,
         permission::permission_flags,
         topology::FlexAlgoNodeSegment,
     },
@@ -56,6 +60,9 @@ pub struct DeviceInterfaceCreateArgs {
     /// Vpnv4 loopbacks. Zero means no topologies (backward compatible).
     #[incremental(default = 0)]
     pub topology_count: u8,
+    /// When true, an IpAllocation account covering `ip_net` follows globalstate.
+    #[incremental(default = false)]
+    pub with_ip_allocation: bool,
 }
 
 impl fmt::Debug for DeviceInterfaceCreateArgs {
@@ -64,7 +71,7 @@ impl fmt::Debug for DeviceInterfaceCreateArgs {
             f,
             "name: {}, loopback_type: {}, vlan_id: {}, ip_net: {:?}, user_tunnel_endpoint: {}, \
 interface_cyoa: {:?}, interface_dia: {:?}, bandwidth: {}, cir: {}, mtu: {}, routing_mode: {:?}, \
-use_onchain_allocation: {}, topology_count: {}",
+use_onchain_allocation: {}, topology_count: {}, with_ip_allocation: {}",
             self.name,
             self.loopback_type,
             self.vlan_id,
@@ -78,6 +85,7 @@ use_onchain_allocation: {}, topology_count: {}",
             self.routing_mode,
             self.use_onchain_allocation,
             self.topology_count,
+            self.with_ip_allocation,
         )
     }
 }
@@ -96,8 +104,13 @@ pub fn process_create_device_interface(
     let device_account = next_account_info(accounts_iter)?;
     let contributor_account = next_account_info(accounts_iter)?;
     let globalstate_account = next_account_info(accounts_iter)?;
+    let ip_allocation_account = if value.with_ip_allocation {
+        Some(next_account_info(accounts_iter)?)
+    } else {
+        None
+    };
 
-    // Account layout: [device, contributor, globalstate, device_tunnel_block,
+    // Account layout: [device, contributor, globalstate, ip_allocation?, device_tunnel_block,
     //                  segment_routing_ids, topology_0..N, payer, system]
     let device_tunnel_block_ext = next_account_info(accounts_iter)?;
     let segment_routing_ids_ext = next_account_info(accounts_iter)?;
@@ -138,9 +151,11 @@ pub fn process_create_device_interface(
 
     let contributor = Contributor::try_from(contributor_account)?;
 
-    // Authorization: the contributor owner, or NETWORK_ADMIN (Permission account) /
-    // foundation (legacy). Privileged callers also bypass the device-contributor
-    // binding checked below.
+    let mut device: Device = Device::try_from(device_account)?;
+
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy). Privileged callers also bypass the
+    // device-contributor binding checked below.
     let is_privileged = authorize(
         program_id,
         accounts_iter,
@@ -149,7 +164,7 @@ pub fn process_create_device_interface(
         permission_flags::NETWORK_ADMIN,
     )
     .is_ok();
-    if contributor.owner != *payer_account.key && !is_privileged {
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
         return Err(DoubleZeroError::InvalidOwnerPubkey.into());
     }
 
@@ -191,7 +206,7 @@ pub fn process_create_device_interface(
         return Err(DoubleZeroError::InvalidMtu.into());
     }
 
-    let mut device: Device = Device::try_from(device_account)?;
+    check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
 
     // The supplied contributor must be the one the device belongs to,
     // unless the payer is on the foundation allowlist.
@@ -273,7 +288,7 @@ pub fn process_create_device_interface(
     // size is intentionally left at 0 — the Interface serializer derives the
     // on-disk size fresh from the body bytes and ignores this field. It only
     // gets populated on deserialize, from the wire prefix.
-    device.push_interface(Interface {
+    let iface = Interface {
         size: 0,
         version: CURRENT_INTERFACE_SCHEMA_VERSION,
         status,
@@ -291,7 +306,16 @@ pub fn process_create_device_interface(
         node_segment_idx,
         user_tunnel_endpoint: value.user_tunnel_endpoint,
         flex_algo_node_segments,
-    });
+    };
+    check_interface_ip_allocation(
+        program_id,
+        ip_allocation_account,
+        is_feature_enabled(globalstate.feature_flags, FeatureFlag::RequireIpAllocations),
+        &device,
+        &iface,
+    )?;
+    device.push_interface(iface);
+    device.bump_config_generation();
 
     try_acc_write(&device, device_account, payer_account, accounts)?;
 
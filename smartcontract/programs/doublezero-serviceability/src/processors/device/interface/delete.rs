@@ -3,6 +3,7 @@ use crate::{
     error::DoubleZeroError,
     pda::get_resource_extension_pda,
     processors::{
+        device::is_device_owner,
         resource::{deallocate_id, deallocate_ip},
         validation::validate_program_account,
     },
@@ -14,6 +15,7 @@ use crate::{
         device::*,
         globalstate::GlobalState,
         interface::{InterfaceStatus, InterfaceType, LoopbackType},
+        ops_lock::check_ops_lock,
         permission::permission_flags,
     },
 };
@@ -107,9 +109,9 @@ pub fn process_delete_device_interface(
 
     let contributor = Contributor::try_from(contributor_account)?;
 
-    // Authorization: the contributor owner, or NETWORK_ADMIN (Permission account) /
-    // foundation (legacy). Privileged callers also bypass the device-contributor
-    // binding below.
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy). Privileged callers also bypass the
+    // device-contributor binding below.
     let is_privileged = authorize(
         program_id,
         accounts_iter,
@@ -118,11 +120,11 @@ pub fn process_delete_device_interface(
         permission_flags::NETWORK_ADMIN,
     )
     .is_ok();
-    if contributor.owner != *payer_account.key && !is_privileged {
+    let mut device: Device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
         return Err(DoubleZeroError::NotAllowed.into());
     }
-
-    let mut device: Device = Device::try_from(device_account)?;
+    check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
 
     // The supplied contributor must be the one the device belongs to, unless the
     // caller is privileged (foundation or NETWORK_ADMIN).
@@ -179,6 +181,7 @@ pub fn process_delete_device_interface(
 
     // Atomic close: remove interface immediately
     device.interfaces.remove(idx);
+    device.bump_config_generation();
 
     #[cfg(test)]
     msg!(
@@ -1,7 +1,9 @@
+pub mod bulk_update;
 pub mod create;
 pub mod delete;
 pub mod update;
 
+pub use bulk_update::*;
 pub use create::*;
 pub use delete::*;
 pub use update::*;
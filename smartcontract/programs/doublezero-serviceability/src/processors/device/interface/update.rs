@@ -5,6 +5,8 @@ use crate::{
     helper::format_option_displayable,
     pda::get_resource_extension_pda,
     processors::{
+        device::is_device_owner,
+        ip_allocation::check_interface_ip_allocation,
         resource::{allocate_id, allocate_specific_id, deallocate_id},
         validation::validate_program_account,
     },
@@ -14,16 +16,18 @@ use crate::{
         accounttype::AccountType,
         contributor::Contributor,
         device::*,
+        feature_flags::{is_feature_enabled, FeatureFlag},
         globalstate::GlobalState,
         interface::{
-            InterfaceCYOA, InterfaceDIA, InterfaceStatus, InterfaceType, LoopbackType, RoutingMode,
-            CYOA_DIA_INTERFACE_MTU, INTERFACE_MTU,
+            Interface, InterfaceCYOA, InterfaceDIA, InterfaceStatus, InterfaceType, LoopbackType,
+            RoutingMode, CYOA_DIA_INTERFACE_MTU, INTERFACE_MTU,
         },
+        ops_lock::check_ops_lock,
         permission::permission_flags,
         topology::FlexAlgoNodeSegment,
     },
 };
-use borsh::BorshSerialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_incremental::BorshDeserializeIncremental;
 use core::fmt;
 use doublezero_program_common::types::NetworkV4;
@@ -60,6 +64,9 @@ pub struct DeviceInterfaceUpdateArgs {
     /// (deallocate removed, allocate added). Only valid on Vpnv4 loopbacks.
     #[incremental(default = false)]
     pub update_topologies: bool,
+    /// When true, an IpAllocation account covering the resulting `ip_net` follows globalstate.
+    #[incremental(default = false)]
+    pub with_ip_allocation: bool,
 }
 
 impl fmt::Debug for DeviceInterfaceUpdateArgs {
@@ -68,7 +75,8 @@ impl fmt::Debug for DeviceInterfaceUpdateArgs {
             f,
             "name: {}, loopback_type: {}, vlan_id: {}, user_tunnel_endpoint: {}, status: {}, \
 ip_net: {}, node_segment_idx: {}, interface_cyoa: {}, interface_dia: {}, bandwidth: {}, \
-cir: {}, mtu: {}, routing_mode: {}, topology_count: {}, update_topologies: {}",
+cir: {}, mtu: {}, routing_mode: {}, topology_count: {}, update_topologies: {}, \
+with_ip_allocation: {}",
             self.name,
             format_option!(self.loopback_type),
             format_option!(self.vlan_id),
@@ -84,10 +92,160 @@ cir: {}, mtu: {}, routing_mode: {}, topology_count: {}, update_topologies: {}",
             format_option!(self.routing_mode),
             self.topology_count,
             self.update_topologies,
+            self.with_ip_allocation,
         )
     }
 }
 
+impl DeviceInterfaceUpdateArgs {
+    /// The plain field changes of this update, without the segment routing
+    /// and topology changes that need extra accounts.
+    pub fn fields(&self) -> DeviceInterfaceFieldsUpdate {
+        DeviceInterfaceFieldsUpdate {
+            name: self.name.clone(),
+            loopback_type: self.loopback_type,
+            vlan_id: self.vlan_id,
+            user_tunnel_endpoint: self.user_tunnel_endpoint,
+            status: self.status,
+            ip_net: self.ip_net,
+            interface_cyoa: self.interface_cyoa,
+            interface_dia: self.interface_dia,
+            bandwidth: self.bandwidth,
+            cir: self.cir,
+            mtu: self.mtu,
+            routing_mode: self.routing_mode,
+        }
+    }
+}
+
+/// Field changes to one device interface, as applied by `UpdateDeviceInterface`
+/// and, for several interfaces at once, by `BulkUpdateDeviceInterfaces`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+pub struct DeviceInterfaceFieldsUpdate {
+    pub name: String,
+    pub loopback_type: Option<LoopbackType>,
+    pub vlan_id: Option<u16>,
+    pub user_tunnel_endpoint: Option<bool>,
+    pub status: Option<InterfaceStatus>,
+    pub ip_net: Option<NetworkV4>,
+    pub interface_cyoa: Option<InterfaceCYOA>,
+    pub interface_dia: Option<InterfaceDIA>,
+    pub bandwidth: Option<u64>,
+    pub cir: Option<u64>,
+    pub mtu: Option<u16>,
+    pub routing_mode: Option<RoutingMode>,
+}
+
+impl DeviceInterfaceFieldsUpdate {
+    /// Whether the interface's IP allocation must be re-checked: only when the
+    /// address or its CYOA/DIA role changes, so interfaces addressed before
+    /// allocations were registered can still be updated.
+    pub fn touches_ip(&self) -> bool {
+        self.ip_net.is_some() || self.interface_cyoa.is_some() || self.interface_dia.is_some()
+    }
+}
+
+/// Apply the field changes of `fields` to `iface`, rejecting the ones that are
+/// invalid on their own.
+pub(crate) fn apply_interface_fields(
+    iface: &mut Interface,
+    fields: &DeviceInterfaceFieldsUpdate,
+) -> Result<(), DoubleZeroError> {
+    if let Some(loopback_type) = &fields.loopback_type {
+        if *loopback_type == LoopbackType::None {
+            return Err(DoubleZeroError::InvalidLoopbackType);
+        }
+        iface.loopback_type = *loopback_type;
+    }
+    if let Some(interface_cyoa) = &fields.interface_cyoa {
+        if *interface_cyoa != InterfaceCYOA::None
+            && iface.status == InterfaceStatus::Activated
+            && iface.interface_type == InterfaceType::Physical
+        {
+            return Err(DoubleZeroError::InterfaceHasEdgeAssignment);
+        }
+        iface.interface_cyoa = *interface_cyoa;
+    }
+    if let Some(interface_dia) = &fields.interface_dia {
+        if *interface_dia != InterfaceDIA::None
+            && iface.status == InterfaceStatus::Activated
+            && iface.interface_type == InterfaceType::Physical
+        {
+            return Err(DoubleZeroError::InterfaceHasEdgeAssignment);
+        }
+        iface.interface_dia = *interface_dia;
+    }
+    if let Some(bandwidth) = fields.bandwidth {
+        iface.bandwidth = bandwidth;
+    }
+    if let Some(cir) = fields.cir {
+        iface.cir = cir;
+    }
+    if let Some(mtu) = fields.mtu {
+        iface.mtu = mtu;
+    }
+    if let Some(routing_mode) = fields.routing_mode {
+        iface.routing_mode = routing_mode;
+    }
+    if let Some(vlan_id) = fields.vlan_id {
+        iface.vlan_id = vlan_id;
+    }
+    if let Some(user_tunnel_endpoint) = fields.user_tunnel_endpoint {
+        iface.user_tunnel_endpoint = user_tunnel_endpoint;
+    }
+    if let Some(status) = fields.status {
+        iface.status = status;
+    }
+    if let Some(ip_net) = fields.ip_net {
+        // ip_net can only be set on CYOA, DIA, or user-tunnel-endpoint interfaces
+        if iface.interface_cyoa == InterfaceCYOA::None
+            && iface.interface_dia == InterfaceDIA::None
+            && !iface.user_tunnel_endpoint
+        {
+            return Err(DoubleZeroError::InvalidInterfaceIp);
+        }
+        iface.ip_net = ip_net;
+    }
+    Ok(())
+}
+
+/// Check the interface that results from applying `fields`: the rules that
+/// depend on several fields at once.
+pub(crate) fn check_updated_interface(
+    iface: &Interface,
+    fields: &DeviceInterfaceFieldsUpdate,
+) -> Result<(), DoubleZeroError> {
+    // CYOA interfaces must have an ip_net — prevent setting CYOA without ip_net
+    // or clearing ip_net from a CYOA interface via update
+    if iface.interface_cyoa != InterfaceCYOA::None && iface.ip_net == NetworkV4::default() {
+        return Err(DoubleZeroError::InvalidInterfaceIp);
+    }
+
+    // Validate MTU against the resulting CYOA/DIA state after all updates
+    let is_cyoa_or_dia =
+        iface.interface_cyoa != InterfaceCYOA::None || iface.interface_dia != InterfaceDIA::None;
+    let expected_mtu = if is_cyoa_or_dia {
+        CYOA_DIA_INTERFACE_MTU
+    } else {
+        INTERFACE_MTU
+    };
+    if iface.mtu != expected_mtu {
+        return Err(DoubleZeroError::InvalidMtu);
+    }
+
+    // CYOA/DIA interfaces must have a non-zero bandwidth. Only enforce when the
+    // transaction is changing CYOA, DIA, or bandwidth, so legacy zero-bandwidth
+    // CYOA/DIA interfaces created before this rule can still be updated for
+    // unrelated fields without first being repaired.
+    let touches_bw_or_edge = fields.interface_cyoa.is_some()
+        || fields.interface_dia.is_some()
+        || fields.bandwidth.is_some();
+    if touches_bw_or_edge && is_cyoa_or_dia && iface.bandwidth == 0 {
+        return Err(DoubleZeroError::InvalidBandwidth);
+    }
+    Ok(())
+}
+
 pub fn process_update_device_interface(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -98,6 +256,11 @@ pub fn process_update_device_interface(
     let device_account = next_account_info(accounts_iter)?;
     let contributor_account = next_account_info(accounts_iter)?;
     let globalstate_account = next_account_info(accounts_iter)?;
+    let ip_allocation_account = if value.with_ip_allocation {
+        Some(next_account_info(accounts_iter)?)
+    } else {
+        None
+    };
 
     // Optional: SegmentRoutingIds resource extension account, present when
     // node_segment_idx is being updated under onchain allocation, OR when
@@ -108,6 +271,8 @@ pub fn process_update_device_interface(
     //   [device, contributor, globalstate, segment_routing_ids_ext, payer, system]
     // Account layout WITHOUT (legacy):
     //   [device, contributor, globalstate, payer, system]
+    // With with_ip_allocation, the IpAllocation account follows globalstate in
+    // each of these layouts.
     //
     // The presence of update_topologies forces seg_ext consumption; otherwise
     // fall back to the legacy account-count heuristic so callers that set
@@ -158,9 +323,9 @@ pub fn process_update_device_interface(
 
     let contributor = Contributor::try_from(contributor_account)?;
 
-    // Authorization: the contributor owner, or NETWORK_ADMIN (Permission account) /
-    // foundation (legacy). Privileged callers also bypass the device-contributor
-    // binding and the foundation-only gates below.
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy). Privileged callers also bypass the
+    // device-contributor binding and the foundation-only gates below.
     let is_privileged = authorize(
         program_id,
         &mut permission_account.into_iter(),
@@ -169,11 +334,11 @@ pub fn process_update_device_interface(
         permission_flags::NETWORK_ADMIN,
     )
     .is_ok();
-    if contributor.owner != *payer_account.key && !is_privileged {
+    let mut device: Device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
         return Err(DoubleZeroError::NotAllowed.into());
     }
-
-    let mut device: Device = Device::try_from(device_account)?;
+    check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
 
     // The supplied contributor must be the one the device belongs to, unless the
     // caller is privileged (foundation or NETWORK_ADMIN).
@@ -186,61 +351,8 @@ pub fn process_update_device_interface(
         .map_err(|_| DoubleZeroError::InterfaceNotFound)?;
     let mut iface = device.interfaces[idx].clone();
 
-    if let Some(loopback_type) = &value.loopback_type {
-        if *loopback_type == LoopbackType::None {
-            return Err(DoubleZeroError::InvalidLoopbackType.into());
-        }
-        iface.loopback_type = *loopback_type;
-    }
-    if let Some(interface_cyoa) = &value.interface_cyoa {
-        if *interface_cyoa != InterfaceCYOA::None
-            && iface.status == InterfaceStatus::Activated
-            && iface.interface_type == InterfaceType::Physical
-        {
-            return Err(DoubleZeroError::InterfaceHasEdgeAssignment.into());
-        }
-        iface.interface_cyoa = *interface_cyoa;
-    }
-    if let Some(interface_dia) = &value.interface_dia {
-        if *interface_dia != InterfaceDIA::None
-            && iface.status == InterfaceStatus::Activated
-            && iface.interface_type == InterfaceType::Physical
-        {
-            return Err(DoubleZeroError::InterfaceHasEdgeAssignment.into());
-        }
-        iface.interface_dia = *interface_dia;
-    }
-    if let Some(bandwidth) = value.bandwidth {
-        iface.bandwidth = bandwidth;
-    }
-    if let Some(cir) = value.cir {
-        iface.cir = cir;
-    }
-    if let Some(mtu) = value.mtu {
-        iface.mtu = mtu;
-    }
-    if let Some(routing_mode) = value.routing_mode {
-        iface.routing_mode = routing_mode;
-    }
-    if let Some(vlan_id) = value.vlan_id {
-        iface.vlan_id = vlan_id;
-    }
-    if let Some(user_tunnel_endpoint) = value.user_tunnel_endpoint {
-        iface.user_tunnel_endpoint = user_tunnel_endpoint;
-    }
-    if let Some(status) = value.status {
-        iface.status = status;
-    }
-    if let Some(ip_net) = value.ip_net {
-        // ip_net can only be set on CYOA, DIA, or user-tunnel-endpoint interfaces
-        if iface.interface_cyoa == InterfaceCYOA::None
-            && iface.interface_dia == InterfaceDIA::None
-            && !iface.user_tunnel_endpoint
-        {
-            return Err(DoubleZeroError::InvalidInterfaceIp.into());
-        }
-        iface.ip_net = ip_net;
-    }
+    let fields = value.fields();
+    apply_interface_fields(&mut iface, &fields)?;
     if let Some(node_segment_idx) = value.node_segment_idx {
         if !is_privileged {
             return Err(DoubleZeroError::NotAllowed.into());
@@ -276,7 +388,7 @@ pub fn process_update_device_interface(
     // for removed topologies have their SR ID deallocated; new topologies get a
     // freshly allocated SR ID.
     if value.update_topologies {
-        if contributor.owner != *payer_account.key && !is_privileged {
+        if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
             return Err(DoubleZeroError::NotAllowed.into());
         }
 
@@ -336,38 +448,22 @@ pub fn process_update_device_interface(
         iface.flex_algo_node_segments = kept;
     }
 
-    // CYOA interfaces must have an ip_net — prevent setting CYOA without ip_net
-    // or clearing ip_net from a CYOA interface via update
-    if iface.interface_cyoa != InterfaceCYOA::None && iface.ip_net == NetworkV4::default() {
-        return Err(DoubleZeroError::InvalidInterfaceIp.into());
-    }
+    check_updated_interface(&iface, &fields)?;
 
-    // Validate MTU against the resulting CYOA/DIA state after all updates
-    let is_cyoa_or_dia =
-        iface.interface_cyoa != InterfaceCYOA::None || iface.interface_dia != InterfaceDIA::None;
-    let expected_mtu = if is_cyoa_or_dia {
-        CYOA_DIA_INTERFACE_MTU
-    } else {
-        INTERFACE_MTU
-    };
-    if iface.mtu != expected_mtu {
-        return Err(DoubleZeroError::InvalidMtu.into());
-    }
-
-    // CYOA/DIA interfaces must have a non-zero bandwidth. Only enforce when the
-    // transaction is changing CYOA, DIA, or bandwidth, so legacy zero-bandwidth
-    // CYOA/DIA interfaces created before this rule can still be updated for
-    // unrelated fields without first being repaired.
-    let touches_bw_or_edge = value.interface_cyoa.is_some()
-        || value.interface_dia.is_some()
-        || value.bandwidth.is_some();
-    if touches_bw_or_edge && is_cyoa_or_dia && iface.bandwidth == 0 {
-        return Err(DoubleZeroError::InvalidBandwidth.into());
+    if fields.touches_ip() {
+        check_interface_ip_allocation(
+            program_id,
+            ip_allocation_account,
+            is_feature_enabled(globalstate.feature_flags, FeatureFlag::RequireIpAllocations),
+            &device,
+            &iface,
+        )?;
     }
 
     iface.validate()?;
 
     device.replace_interface(idx, iface);
+    device.bump_config_generation();
 
     try_acc_write(&device, device_account, payer_account, accounts)?;
 
@@ -0,0 +1,91 @@
+use core::fmt;
+
+use crate::{
+    authorize::authorize,
+    error::DoubleZeroError,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{device::Device, globalstate::GlobalState, permission::permission_flags},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+#[cfg(test)]
+use solana_program::msg;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceIssueChallengeArgs {
+    /// Random nonce the device's metrics publisher must echo back with `ProveDeviceKey`.
+    pub nonce: [u8; 32],
+}
+
+impl fmt::Debug for DeviceIssueChallengeArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nonce: {:02x?}", self.nonce)
+    }
+}
+
+/// Starts a device key attestation: records `nonce` as the device's outstanding
+/// challenge and drops any earlier proof, so the device has to prove possession of
+/// its key again before it can next be activated.
+pub fn process_issue_challenge_device(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceIssueChallengeArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let device_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_issue_challenge_device({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    // Authorization: NETWORK_ADMIN via a Permission account, or the legacy
+    // foundation_allowlist.
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // An all-zero nonce is how the account records "no challenge pending".
+    if value.nonce == [0; 32] {
+        return Err(DoubleZeroError::InvalidDeviceAttestation.into());
+    }
+
+    let mut device = Device::try_from(device_account)?;
+    device.attestation_challenge = value.nonce;
+    device.attested_key = Pubkey::default();
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    #[cfg(test)]
+    msg!("Issued challenge: {:?}", device);
+
+    Ok(())
+}
@@ -1,5 +1,48 @@
+pub mod ackconfig;
 pub mod create;
 pub mod delete;
+pub mod grant_co_owner;
 pub mod interface;
+pub mod issue_challenge;
+pub mod prove_key;
+pub mod revoke_co_owner;
+pub mod rotate_metrics_publisher;
+pub mod set_ops_lock;
 pub mod sethealth;
 pub mod update;
+
+use crate::{
+    error::DoubleZeroError,
+    state::{
+        contributor::Contributor,
+        device::Device,
+        feature_flags::{is_feature_enabled, FeatureFlag},
+        globalstate::GlobalState,
+    },
+};
+use solana_program::{msg, pubkey::Pubkey};
+
+/// Whether `signer` holds the owner's update rights on `device`: the owner of
+/// `contributor`, or the co-owner it granted on this device. The caller still
+/// checks that `contributor` is the device's.
+pub(crate) fn is_device_owner(contributor: &Contributor, device: &Device, signer: &Pubkey) -> bool {
+    contributor.owner == *signer || device.is_co_owner(signer)
+}
+
+/// With the `RequireDeviceAttestation` feature flag set, a device can only become
+/// `Activated` once its current metrics publisher has answered a challenge with
+/// `ProveDeviceKey`.
+pub(crate) fn check_device_attested(
+    globalstate: &GlobalState,
+    device: &Device,
+) -> Result<(), DoubleZeroError> {
+    if is_feature_enabled(
+        globalstate.feature_flags,
+        FeatureFlag::RequireDeviceAttestation,
+    ) && !device.is_key_attested()
+    {
+        msg!("Device {} has not proven its key", device.code);
+        return Err(DoubleZeroError::DeviceNotAttested);
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use core::fmt;
+
+use crate::{
+    error::DoubleZeroError,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{device::Device, globalstate::GlobalState},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceProveKeyArgs {
+    /// The nonce of the device's outstanding challenge.
+    pub nonce: [u8; 32],
+}
+
+impl fmt::Debug for DeviceProveKeyArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nonce: {:02x?}", self.nonce)
+    }
+}
+
+/// Answers the device's outstanding challenge. The transaction must be signed by the
+/// device's current metrics publisher, the key held by the agent on the box, so a
+/// matching nonce proves whoever registered the device also controls it. Each
+/// challenge can be answered once. GlobalState may be passed as a trailing account
+/// so the instruction is paused with the other updates; agents that omit it are
+/// treated as unpaused.
+pub fn process_prove_key_device(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceProveKeyArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let device_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let globalstate_account = accounts_iter.next();
+
+    #[cfg(test)]
+    msg!("process_prove_key_device({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    if let Some(globalstate_account) = globalstate_account {
+        validate_program_account!(
+            globalstate_account,
+            program_id,
+            writable = false,
+            "GlobalState"
+        );
+        GlobalState::try_from(globalstate_account)?;
+    }
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    let mut device = Device::try_from(device_account)?;
+
+    if device.metrics_publisher_pk != *payer_account.key {
+        msg!("Signer is not the device's metrics publisher");
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+    if device.attestation_challenge == [0; 32] || device.attestation_challenge != value.nonce {
+        return Err(DoubleZeroError::InvalidDeviceAttestation.into());
+    }
+
+    device.attestation_challenge = [0; 32];
+    device.attested_key = *payer_account.key;
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    #[cfg(test)]
+    msg!("Proved device key: {:?}", device);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::{
+    events::DoubleZeroEvent,
     authorize::authorize,
     error::DoubleZeroError,
     serializer::try_acc_write,
@@ -98,12 +99,21 @@ pub fn process_resume_device(
         return Err(DoubleZeroError::InvalidStatus.into());
     }
 
+    let from = device.status;
     device.status = DeviceStatus::Activated;
+    device.bump_config_generation();
 
     try_acc_write(&device, device_account, payer_account, accounts)?;
 
     #[cfg(test)]
     msg!("Resumed: {:?}", device);
 
+    DoubleZeroEvent::DeviceStatusChanged {
+        device: *device_account.key,
+        from,
+        to: device.status,
+    }
+    .emit();
+
     Ok(())
 }
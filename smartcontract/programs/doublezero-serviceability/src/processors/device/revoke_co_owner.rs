@@ -0,0 +1,105 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::DoubleZeroError,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType, contributor::Contributor, device::Device,
+        globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct DeviceRevokeCoOwnerArgs {}
+
+/// Remove the device's co-owner. The contributor owner, NETWORK_ADMIN / foundation
+/// or the co-owner itself may revoke; revoking a device without a co-owner is a
+/// no-op. Like the grant, it is not subject to the device's ops lock.
+pub fn process_revoke_device_co_owner(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &DeviceRevokeCoOwnerArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account layout: [device, contributor, globalstate, payer, system, permission?]
+    let device_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let rest: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, _system_program, _, permission_account) =
+        split_trailing_permission(program_id, &rest)?;
+
+    #[cfg(test)]
+    msg!("process_revoke_device_co_owner({:?})", _value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    assert_eq!(globalstate.account_type, AccountType::GlobalState);
+
+    let contributor = Contributor::try_from(contributor_account)?;
+    let mut device = Device::try_from(device_account)?;
+
+    // Authorization: the contributor owner, the co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy).
+    let is_privileged = authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )
+    .is_ok();
+    if contributor.owner != *payer_account.key
+        && !device.is_co_owner(payer_account.key)
+        && !is_privileged
+    {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    // The supplied contributor must be the one the device belongs to, unless the
+    // caller is privileged (foundation or NETWORK_ADMIN).
+    if !is_privileged && device.contributor_pk != *contributor_account.key {
+        return Err(DoubleZeroError::InvalidContributorPubkey.into());
+    }
+
+    if device.co_owner_pk == Pubkey::default() {
+        msg!("Device {} has no co-owner", device_account.key);
+        return Ok(());
+    }
+
+    msg!(
+        "Revoked co-ownership of {} from {}",
+        device_account.key,
+        device.co_owner_pk
+    );
+    device.co_owner_pk = Pubkey::default();
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::DoubleZeroError,
+    processors::{device::is_device_owner, validation::validate_program_account},
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType, contributor::Contributor, device::Device,
+        globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Longest overlap a rotation may ask for, in epochs.
+pub const MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS: u64 = 10;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceRotateMetricsPublisherArgs {
+    pub metrics_publisher_pk: Pubkey,
+    /// Epochs after the current one during which the replaced publisher is
+    /// still accepted. 0 keeps it valid until the end of the current epoch.
+    pub overlap_epochs: u64,
+}
+
+impl fmt::Debug for DeviceRotateMetricsPublisherArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "metrics_publisher_pk: {}, overlap_epochs: {}",
+            self.metrics_publisher_pk, self.overlap_epochs
+        )
+    }
+}
+
+/// Replace a device's metrics publisher while keeping the old key valid for an
+/// overlap window, so an agent still running under it does not drop samples.
+/// `UpdateDevice` remains the way to revoke a key at once: it sets the publisher
+/// and clears the overlap. Rotating again during an overlap ends the access of
+/// the key replaced first.
+pub fn process_rotate_device_metrics_publisher(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceRotateMetricsPublisherArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account layout: [device, contributor, globalstate, payer, system, permission?]
+    let device_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let rest: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, _system_program, _, permission_account) =
+        split_trailing_permission(program_id, &rest)?;
+
+    #[cfg(test)]
+    msg!("process_rotate_device_metrics_publisher({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    assert_eq!(globalstate.account_type, AccountType::GlobalState);
+
+    let contributor = Contributor::try_from(contributor_account)?;
+
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy), as for UpdateDevice.
+    let is_privileged = authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )
+    .is_ok();
+    let mut device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    // The supplied contributor must be the one the device belongs to, unless the
+    // caller is privileged (foundation or NETWORK_ADMIN).
+    if !is_privileged && device.contributor_pk != *contributor_account.key {
+        return Err(DoubleZeroError::InvalidContributorPubkey.into());
+    }
+
+    if value.metrics_publisher_pk == Pubkey::default()
+        || value.metrics_publisher_pk == device.metrics_publisher_pk
+    {
+        msg!(
+            "New metrics publisher {} must be set and differ from the current one",
+            value.metrics_publisher_pk
+        );
+        return Err(DoubleZeroError::InvalidMetricsPublisherRotation.into());
+    }
+    if value.overlap_epochs > MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS {
+        msg!(
+            "Overlap of {} epochs exceeds the maximum of {}",
+            value.overlap_epochs,
+            MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS
+        );
+        return Err(DoubleZeroError::InvalidMetricsPublisherRotation.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    device.previous_metrics_publisher_pk = device.metrics_publisher_pk;
+    device.metrics_publisher_pk = value.metrics_publisher_pk;
+    device.metrics_publisher_overlap_until_epoch =
+        current_epoch.saturating_add(value.overlap_epochs);
+    device.bump_config_generation();
+
+    msg!(
+        "Rotated metrics publisher of {} from {} to {}; the previous key is accepted until epoch {}",
+        device_account.key,
+        device.previous_metrics_publisher_pk,
+        device.metrics_publisher_pk,
+        device.metrics_publisher_overlap_until_epoch
+    );
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    Ok(())
+}
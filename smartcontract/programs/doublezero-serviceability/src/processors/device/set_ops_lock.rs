@@ -0,0 +1,127 @@
+use crate::{
+    authorize::{authorize, split_trailing_permission},
+    error::DoubleZeroError,
+    processors::{device::is_device_owner, validation::validate_program_account},
+    serializer::try_acc_write,
+    state::{
+        accounttype::AccountType, contributor::Contributor, device::Device,
+        globalstate::GlobalState, permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct DeviceSetOpsLockArgs {
+    pub reason: String,
+    /// Unix timestamp at which the lock lapses. 0 releases the lock.
+    pub expires_at: i64,
+}
+
+impl fmt::Debug for DeviceSetOpsLockArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reason: {:?}, expires_at: {}",
+            self.reason, self.expires_at
+        )
+    }
+}
+
+/// Freeze a device against changes by anyone but the signer until `expires_at`,
+/// or release the freeze. While the lock is live, device and interface updates
+/// and deletes from other signers fail with `EntityLocked`; activator and health
+/// oracle writes are not affected.
+pub fn process_set_device_ops_lock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &DeviceSetOpsLockArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account layout: [device, contributor, globalstate, payer, system, permission?]
+    let device_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let rest: Vec<&AccountInfo> = accounts_iter.collect();
+    let (payer_account, _system_program, _, permission_account) =
+        split_trailing_permission(program_id, &rest)?;
+
+    #[cfg(test)]
+    msg!("process_set_device_ops_lock({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(device_account, program_id, writable = true, "Device");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    assert_eq!(globalstate.account_type, AccountType::GlobalState);
+
+    let contributor = Contributor::try_from(contributor_account)?;
+
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy), as for UpdateDevice.
+    let is_privileged = authorize(
+        program_id,
+        &mut permission_account.into_iter(),
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )
+    .is_ok();
+    let mut device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    // The supplied contributor must be the one the device belongs to, unless the
+    // caller is privileged (foundation or NETWORK_ADMIN).
+    if !is_privileged && device.contributor_pk != *contributor_account.key {
+        return Err(DoubleZeroError::InvalidContributorPubkey.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if let Err(err) = device.ops_lock.set(
+        payer_account.key,
+        is_privileged,
+        &value.reason,
+        value.expires_at,
+        now,
+    ) {
+        msg!(
+            "Cannot set the ops lock of {} ({}) to expire at {}",
+            device_account.key,
+            device.ops_lock,
+            value.expires_at
+        );
+        return Err(err.into());
+    }
+
+    msg!("Device {} is now {}", device_account.key, device.ops_lock);
+
+    try_acc_write(&device, device_account, payer_account, accounts)?;
+
+    Ok(())
+}
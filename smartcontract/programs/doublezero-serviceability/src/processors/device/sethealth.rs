@@ -2,6 +2,7 @@ use core::fmt;
 
 use crate::{
     authorize::authorize,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{
         accounttype::AccountType, device::*, globalstate::GlobalState, permission::permission_flags,
@@ -76,6 +77,8 @@ pub fn process_set_health_device(
     )?;
 
     let mut device: Device = Device::try_from(device_account)?;
+    let from_health = device.device_health;
+    let from = device.status;
     device.device_health = value.health;
     device.check_status_transition();
 
@@ -84,5 +87,18 @@ pub fn process_set_health_device(
     #[cfg(test)]
     msg!("Set health: {:?}", device);
 
+    DoubleZeroEvent::DeviceHealthChanged {
+        device: *device_account.key,
+        from: from_health,
+        to: device.device_health,
+    }
+    .emit();
+    DoubleZeroEvent::DeviceStatusChanged {
+        device: *device_account.key,
+        from,
+        to: device.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,15 +1,25 @@
 use crate::{
     authorize::{authorize, split_trailing_permission},
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     pda::get_resource_extension_pda,
-    processors::resource::create_resource,
+    processors::{
+        device::{check_device_attested, is_device_owner},
+        resource::{create_resource, resize_resource},
+    },
     resource::ResourceType,
     serializer::{try_acc_close, try_acc_write},
     state::{
-        accounttype::AccountType, contributor::Contributor, device::*, globalstate::GlobalState,
-        location::Location, permission::permission_flags,
+        accounttype::AccountType,
+        contributor::Contributor,
+        device::*,
+        globalstate::GlobalState,
+        location::Location,
+        ops_lock::{check_ops_lock, OpsLock},
+        permission::permission_flags,
         resource_extension::ResourceExtensionBorrowed,
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -254,10 +264,11 @@ pub fn process_update_device(
 
     let contributor = Contributor::try_from(contributor_account)?;
 
-    // Authorization: the contributor owner, or NETWORK_ADMIN (Permission account) /
-    // foundation (legacy). `permission_account` is the optional trailing account peeled
-    // off by split_trailing_permission above. Privileged callers bypass the contributor
-    // binding and may edit the privileged fields / set any status below.
+    // Authorization: the contributor owner or the device's co-owner, or NETWORK_ADMIN
+    // (Permission account) / foundation (legacy). `permission_account` is the optional
+    // trailing account peeled off by split_trailing_permission above. Privileged callers
+    // bypass the contributor binding and may edit the privileged fields / set any status
+    // below.
     let is_privileged = authorize(
         program_id,
         &mut permission_account.into_iter(),
@@ -266,11 +277,20 @@ pub fn process_update_device(
         permission_flags::NETWORK_ADMIN,
     )
     .is_ok();
-    if contributor.owner != *payer_account.key && !is_privileged {
+    let mut device: Device = Device::try_from(device_account)?;
+    if !is_device_owner(&contributor, &device, payer_account.key) && !is_privileged {
         return Err(DoubleZeroError::NotAllowed.into());
     }
-
-    let mut device: Device = Device::try_from(device_account)?;
+    // A privileged caller may already release any lock, so moving the device to
+    // another contributor is not held up by one; a lock taken by the outgoing
+    // co-owner is released below.
+    let moves_contributor = is_privileged
+        && value
+            .contributor_pk
+            .is_some_and(|pk| pk != device.contributor_pk);
+    if !moves_contributor {
+        check_ops_lock(&device.ops_lock, payer_account.key, "Device")?;
+    }
 
     // The supplied contributor must be the one the device belongs to, unless the
     // caller is privileged (foundation or NETWORK_ADMIN).
@@ -281,7 +301,22 @@ pub fn process_update_device(
     // Only allow these updates from privileged callers (foundation or NETWORK_ADMIN)
     if is_privileged {
         if let Some(contributor_pk) = value.contributor_pk {
-            device.contributor_pk = contributor_pk;
+            if contributor_pk != device.contributor_pk {
+                // Co-ownership is granted by the old contributor and does not
+                // carry over to the new one, nor does a lock the co-owner holds.
+                if device.co_owner_pk != Pubkey::default() {
+                    if device.ops_lock.locked_by == device.co_owner_pk {
+                        device.ops_lock = OpsLock::default();
+                    }
+                    msg!(
+                        "Revoked co-ownership of {} from {}",
+                        device_account.key,
+                        device.co_owner_pk
+                    );
+                    device.co_owner_pk = Pubkey::default();
+                }
+                device.contributor_pk = contributor_pk;
+            }
         }
         if let Some(users_count) = value.users_count {
             device.users_count = users_count;
@@ -316,6 +351,7 @@ pub fn process_update_device(
     let mut create_dz_prefixes_resources = false;
     let mut new_dz_prefix_count = 0usize;
     let mut old_dz_prefix_count = 0usize;
+    let mut keep_dz_prefix_blocks = vec![];
     if let Some(dz_prefixes) = &value.dz_prefixes {
         let old_count = device.dz_prefixes.len();
         let new_count = dz_prefixes.len();
@@ -331,15 +367,25 @@ pub fn process_update_device(
             "Wrong number of resource accounts provided"
         );
 
-        // Verify existing DzPrefixBlock accounts have no user IP allocations.
-        // Only the loopback reservation at index 0 should be present.
+        // DzPrefixBlocks whose base IP is unchanged are resized in place, keeping their
+        // allocations. Any other existing block is recreated and must have no user IP
+        // allocations; only the loopback reservation at index 0 may be present.
+        keep_dz_prefix_blocks = (0..new_count)
+            .map(|i| {
+                i < old_count
+                    && device.dz_prefixes[i].ip() == dz_prefixes[i].ip()
+                    && !resource_accounts[i + 1].data_is_empty()
+            })
+            .collect();
         for (i, resource_account) in resource_accounts
             .iter()
             .enumerate()
             .take(old_count + 1)
             .skip(1)
         {
-            if !resource_account.data_is_empty() {
+            if !resource_account.data_is_empty()
+                && !keep_dz_prefix_blocks.get(i - 1).copied().unwrap_or(false)
+            {
                 let mut buffer = resource_account.data.borrow_mut();
                 let resource = ResourceExtensionBorrowed::inplace_from(&mut buffer[..])?;
                 assert!(
@@ -354,7 +400,11 @@ pub fn process_update_device(
         create_dz_prefixes_resources = true;
     }
     if let Some(metrics_publisher_pk) = &value.metrics_publisher_pk {
+        // Setting the publisher directly revokes the previous key at once; use
+        // RotateDeviceMetricsPublisher to keep it valid for a while.
         device.metrics_publisher_pk = *metrics_publisher_pk;
+        device.previous_metrics_publisher_pk = Pubkey::default();
+        device.metrics_publisher_overlap_until_epoch = 0;
     }
     if let Some(mgmt_vrf) = &value.mgmt_vrf {
         device.mgmt_vrf = mgmt_vrf.clone();
@@ -399,20 +449,16 @@ pub fn process_update_device(
         }
     }
 
+    let from = device.status;
     if let Some(status) = value.status {
-        // Privileged callers (foundation or NETWORK_ADMIN) can set any status
-        if is_privileged {
-            device.status = status;
-        } else {
-            // Contributors can only transition between Activated <-> Drained states,
-            // This enforces a maintenance step before reactivation.
-            match (device.status, status) {
-                (DeviceStatus::Activated, DeviceStatus::Drained)
-                | (DeviceStatus::Drained, DeviceStatus::Activated) => {
-                    device.status = status;
-                }
-                _ => return Err(DoubleZeroError::NotAllowed.into()),
+        // Contributors can only drain and undrain; privileged callers (foundation
+        // or NETWORK_ADMIN) may also move devices in and out of provisioning.
+        if status != device.status {
+            validate_transition(device.status, status, "UpdateDevice", is_privileged)?;
+            if status == DeviceStatus::Activated {
+                check_device_attested(&globalstate, &device)?;
             }
+            device.status = status;
         }
     }
     if let Some(desired_status) = value.desired_status {
@@ -420,6 +466,7 @@ pub fn process_update_device(
     }
 
     device.check_status_transition();
+    device.bump_config_generation();
 
     try_acc_write(&device, device_account, payer_account, accounts)?;
 
@@ -432,15 +479,28 @@ pub fn process_update_device(
             .take(new_dz_prefix_count + 1)
             .skip(1)
         {
-            create_resource(
-                program_id,
-                resource_account,
-                Some(device_account),
-                globalconfig_account.unwrap(),
-                payer_account,
-                accounts,
-                ResourceType::DzPrefixBlock(*device_account.key, i - 1),
-            )?;
+            let resource_type = ResourceType::DzPrefixBlock(*device_account.key, i - 1);
+            if keep_dz_prefix_blocks[i - 1] {
+                resize_resource(
+                    program_id,
+                    resource_account,
+                    Some(device_account),
+                    globalconfig_account.unwrap(),
+                    payer_account,
+                    accounts,
+                    resource_type,
+                )?;
+            } else {
+                create_resource(
+                    program_id,
+                    resource_account,
+                    Some(device_account),
+                    globalconfig_account.unwrap(),
+                    payer_account,
+                    accounts,
+                    resource_type,
+                )?;
+            }
         }
     }
 
@@ -460,5 +520,12 @@ pub fn process_update_device(
     #[cfg(test)]
     msg!("Updated: {:?}", device);
 
+    DoubleZeroEvent::DeviceStatusChanged {
+        device: *device_account.key,
+        from,
+        to: device.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,12 +1,13 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{
         exchange::{Exchange, ExchangeStatus},
         globalstate::GlobalState,
         permission::permission_flags,
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -84,10 +85,14 @@ pub fn process_resume_exchange(
     )?;
 
     // Only resume exchanges that are currently Suspended
-    if exchange.status != ExchangeStatus::Suspended {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        exchange.status,
+        ExchangeStatus::Activated,
+        "ResumeExchange",
+        true,
+    )?;
 
+    let from = exchange.status;
     exchange.status = ExchangeStatus::Activated;
 
     try_acc_write(&exchange, exchange_account, payer_account, accounts)?;
@@ -95,6 +100,13 @@ pub fn process_resume_exchange(
     #[cfg(test)]
     msg!("Resumed: {:?}", exchange);
 
+    DoubleZeroEvent::ExchangeStatusChanged {
+        exchange: *exchange_account.key,
+        from,
+        to: exchange.status,
+    }
+    .emit();
+
     Ok(())
 }
 
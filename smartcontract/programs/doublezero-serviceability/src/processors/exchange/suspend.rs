@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{exchange::*, globalstate::GlobalState, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -79,12 +80,14 @@ pub fn process_suspend_exchange(
         permission_flags::INFRA_ADMIN,
     )?;
 
-    if exchange.status != ExchangeStatus::Activated {
-        #[cfg(test)]
-        msg!("{:?}", exchange);
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        exchange.status,
+        ExchangeStatus::Suspended,
+        "SuspendExchange",
+        true,
+    )?;
 
+    let from = exchange.status;
     exchange.status = ExchangeStatus::Suspended;
 
     try_acc_write(&exchange, exchange_account, payer_account, accounts)?;
@@ -92,6 +95,13 @@ pub fn process_suspend_exchange(
     #[cfg(test)]
     msg!("Suspended: {:?}", exchange);
 
+    DoubleZeroEvent::ExchangeStatusChanged {
+        exchange: *exchange_account.key,
+        from,
+        to: exchange.status,
+    }
+    .emit();
+
     Ok(())
 }
 
@@ -2,4 +2,5 @@ pub mod initialize;
 pub mod setairdrop;
 pub mod setauthority;
 pub mod setfeatureflags;
+pub mod setpause;
 pub mod setversion;
@@ -0,0 +1,76 @@
+use crate::{
+    error::DoubleZeroError, pda::get_globalstate_pda, serializer::try_acc_write,
+    state::globalstate::GlobalState,
+};
+
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Clone, PartialEq, Default)]
+pub struct SetPauseArgs {
+    /// Bitmask of paused instruction classes, see `pause_flags::PauseFlag`.
+    pub paused: u64,
+}
+
+impl fmt::Debug for SetPauseArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "paused: {}", self.paused)
+    }
+}
+
+pub fn process_set_pause(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &SetPauseArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_set_pause({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    // Check the owner of the accounts
+    assert_eq!(
+        globalstate_account.owner, program_id,
+        "Invalid PDA Account Owner",
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    let (expected_pda_account, _) = get_globalstate_pda(program_id);
+    assert_eq!(
+        globalstate_account.key, &expected_pda_account,
+        "Invalid GlobalState Pubkey",
+    );
+
+    // Authorization: foundation allowlist only. The emergency brake deliberately does
+    // not go through Permission accounts, so it keeps working even if the permission
+    // system itself is what needs to be frozen.
+    let mut globalstate = GlobalState::try_from(globalstate_account)?;
+    if !globalstate.foundation_allowlist.contains(payer_account.key) {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    msg!("Pause flags: {} -> {}", globalstate.paused, value.paused);
+    globalstate.paused = value.paused;
+
+    try_acc_write(&globalstate, globalstate_account, payer_account, accounts)?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    authorize::authorize,
+    processors::validation::validate_program_account,
+    serializer::try_acc_close,
+    state::{
+        globalstate::GlobalState, interface_oper_status::InterfaceOperStatus,
+        permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct InterfaceOperStatusDeleteArgs {}
+
+/// Close a device's InterfaceOperStatus account and reclaim its rent, e.g. once the device
+/// has been deleted. The oracle recreates it on its next write if the device still exists.
+///
+/// Accounts layout:
+/// [0] interface_oper_status PDA (writable, to be closed)
+/// [1] globalstate               (readonly)
+/// [2] payer                     (writable, signer, HEALTH_ORACLE or foundation)
+/// [3] system_program
+/// [4] permission                (readonly, optional — payer's Permission PDA)
+pub fn process_delete_interface_oper_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &InterfaceOperStatusDeleteArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let oper_status_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        oper_status_account,
+        program_id,
+        writable = true,
+        "InterfaceOperStatus"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::HEALTH_ORACLE | permission_flags::NETWORK_ADMIN,
+    )?;
+
+    let oper_status = InterfaceOperStatus::try_from(oper_status_account)?;
+    try_acc_close(oper_status_account, payer_account)?;
+
+    msg!(
+        "Deleted interface oper status of device {}",
+        oper_status.device_pk
+    );
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use crate::{
+    authorize::authorize,
+    error::DoubleZeroError,
+    pda::get_interface_oper_status_pda,
+    processors::validation::validate_program_account,
+    seeds::{SEED_INTERFACE_OPER_STATUS, SEED_PREFIX},
+    serializer::{try_acc_create, try_acc_write},
+    state::{
+        accounttype::AccountType,
+        device::Device,
+        globalstate::GlobalState,
+        interface_oper_status::{InterfaceOperStatus, InterfaceOperStatusEntry},
+        permission::permission_flags,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct InterfaceOperStatusSetArgs {
+    /// Entries to write, replacing any existing entry with the same name. `updated_at` is
+    /// ignored and stamped with the current time.
+    pub interfaces: Vec<InterfaceOperStatusEntry>,
+}
+
+impl fmt::Debug for InterfaceOperStatusSetArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self
+            .interfaces
+            .iter()
+            .map(|e| format!("{}={}", e.name, e.oper_status))
+            .collect();
+        write!(f, "interfaces: [{}]", entries.join(", "))
+    }
+}
+
+/// Record the operational status of some of a device's interfaces, creating the device's
+/// InterfaceOperStatus account on first use. Entries for interfaces the device no longer has
+/// are dropped on every write.
+///
+/// Accounts layout:
+/// [0] interface_oper_status PDA (writable, created if empty)
+/// [1] device                    (readonly)
+/// [2] globalstate               (readonly)
+/// [3] payer                     (writable, signer, HEALTH_ORACLE or foundation)
+/// [4] system_program
+/// [5] permission                (readonly, optional — payer's Permission PDA)
+pub fn process_set_interface_oper_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &InterfaceOperStatusSetArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let oper_status_account = next_account_info(accounts_iter)?;
+    let device_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_set_interface_oper_status({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(device_account, program_id, writable = false, "Device");
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+    assert!(
+        oper_status_account.is_writable,
+        "PDA Account is not writable"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    // Authorization: HEALTH_ORACLE or foundation, as for SetDeviceHealth.
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::HEALTH_ORACLE | permission_flags::NETWORK_ADMIN,
+    )?;
+
+    let device = Device::try_from(device_account)?;
+    for entry in &value.interfaces {
+        if device.find_interface(&entry.name).is_err() {
+            msg!(
+                "Interface {} not found on device {}",
+                entry.name,
+                device_account.key
+            );
+            return Err(DoubleZeroError::InterfaceNotFound.into());
+        }
+    }
+
+    let (expected_pda, bump_seed) = get_interface_oper_status_pda(program_id, device_account.key);
+    assert_eq!(
+        oper_status_account.key, &expected_pda,
+        "Invalid InterfaceOperStatus PubKey"
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let is_new = oper_status_account.data_is_empty();
+    let mut oper_status = if is_new {
+        InterfaceOperStatus {
+            account_type: AccountType::InterfaceOperStatus,
+            owner: *payer_account.key,
+            bump_seed,
+            device_pk: *device_account.key,
+            updated_at: now,
+            interfaces: vec![],
+        }
+    } else {
+        validate_program_account!(
+            oper_status_account,
+            program_id,
+            writable = true,
+            "InterfaceOperStatus"
+        );
+        InterfaceOperStatus::try_from(oper_status_account)?
+    };
+
+    oper_status
+        .interfaces
+        .retain(|entry| device.find_interface(&entry.name).is_ok());
+    for entry in &value.interfaces {
+        let entry = InterfaceOperStatusEntry {
+            updated_at: now,
+            ..entry.clone()
+        };
+        match oper_status
+            .interfaces
+            .iter_mut()
+            .find(|e| e.name.eq_ignore_ascii_case(&entry.name))
+        {
+            Some(existing) => *existing = entry,
+            None => oper_status.interfaces.push(entry),
+        }
+    }
+    oper_status.owner = *payer_account.key;
+    oper_status.updated_at = now;
+
+    if is_new {
+        try_acc_create(
+            &oper_status,
+            oper_status_account,
+            payer_account,
+            system_program,
+            program_id,
+            &[
+                SEED_PREFIX,
+                SEED_INTERFACE_OPER_STATUS,
+                &device_account.key.to_bytes(),
+                &[bump_seed],
+            ],
+        )?;
+    } else {
+        try_acc_write(&oper_status, oper_status_account, payer_account, accounts)?;
+    }
+
+    #[cfg(test)]
+    msg!("Set interface oper status: {:?}", oper_status);
+
+    Ok(())
+}
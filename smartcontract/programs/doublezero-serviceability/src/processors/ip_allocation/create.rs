@@ -0,0 +1,135 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    pda::get_ip_allocation_pda,
+    processors::{ip_allocation::authorize_ip_allocation, validation::validate_program_account},
+    seeds::{SEED_IP_ALLOCATION, SEED_PREFIX},
+    serializer::try_acc_create,
+    state::{
+        accounttype::AccountType,
+        contributor::{Contributor, ContributorStatus},
+        globalstate::GlobalState,
+        ip_allocation::{IpAllocation, IpAllocationStatus},
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::types::NetworkV4;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct IpAllocationCreateArgs {
+    /// The registered block, written as its network address; part of the PDA seed.
+    pub prefix: NetworkV4,
+    /// The upstream provider the block was assigned by, e.g. its name or ASN.
+    pub upstream: String,
+}
+
+/// Accounts layout:
+/// [0] ip_allocation PDA (writable, to be created)
+/// [1] contributor       (readonly)
+/// [2] globalstate       (readonly)
+/// [3] payer             (writable, signer, contributor owner or CONTRIBUTOR_ADMIN)
+/// [4] system_program
+/// [5] permission        (readonly, optional — payer's Permission PDA)
+pub fn process_create_ip_allocation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &IpAllocationCreateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let ip_allocation_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_create_ip_allocation({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+    assert!(
+        ip_allocation_account.is_writable,
+        "PDA Account is not writable"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    let contributor = Contributor::try_from(contributor_account)?;
+    authorize_ip_allocation(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        &contributor,
+    )?;
+
+    if contributor.status != ContributorStatus::Activated {
+        msg!("Contributor {} is not activated", contributor_account.key);
+        return Err(DoubleZeroError::InvalidStatus.into());
+    }
+
+    let (expected_pda, bump_seed) = get_ip_allocation_pda(program_id, &value.prefix);
+    assert_eq!(
+        ip_allocation_account.key, &expected_pda,
+        "Invalid IpAllocation PubKey"
+    );
+
+    // The PDA is derived from the prefix alone, so a block already registered by any
+    // contributor cannot be registered again.
+    if !ip_allocation_account.data_is_empty() {
+        msg!("IP allocation {} is already registered", value.prefix);
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let ip_allocation = IpAllocation {
+        account_type: AccountType::IpAllocation,
+        owner: *payer_account.key,
+        bump_seed,
+        status: IpAllocationStatus::Pending,
+        contributor_pk: *contributor_account.key,
+        prefix: value.prefix,
+        upstream: value.upstream.clone(),
+    };
+    ip_allocation.validate()?;
+
+    try_acc_create(
+        &ip_allocation,
+        ip_allocation_account,
+        payer_account,
+        system_program,
+        program_id,
+        &[
+            SEED_PREFIX,
+            SEED_IP_ALLOCATION,
+            &value.prefix.ip().octets(),
+            &[value.prefix.prefix()],
+            &[bump_seed],
+        ],
+    )?;
+
+    msg!(
+        "Registered IP allocation {} for contributor {}",
+        value.prefix,
+        contributor_account.key
+    );
+
+    Ok(())
+}
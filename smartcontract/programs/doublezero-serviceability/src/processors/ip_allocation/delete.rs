@@ -0,0 +1,87 @@
+use crate::{
+    error::DoubleZeroError,
+    processors::{ip_allocation::authorize_ip_allocation, validation::validate_program_account},
+    serializer::try_acc_close,
+    state::{contributor::Contributor, globalstate::GlobalState, ip_allocation::IpAllocation},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct IpAllocationDeleteArgs {}
+
+/// Close an IP allocation account and reclaim its rent. Interfaces already addressed from the
+/// block are left as they are; the block just can no longer be used for new assignments.
+///
+/// Accounts layout:
+/// [0] ip_allocation PDA (writable, to be closed)
+/// [1] contributor       (readonly, the allocation's contributor)
+/// [2] globalstate       (readonly)
+/// [3] payer             (writable, signer, contributor owner or CONTRIBUTOR_ADMIN)
+/// [4] system_program
+/// [5] permission        (readonly, optional — payer's Permission PDA)
+pub fn process_delete_ip_allocation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &IpAllocationDeleteArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let ip_allocation_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        ip_allocation_account,
+        program_id,
+        writable = true,
+        "IpAllocation"
+    );
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    let contributor = Contributor::try_from(contributor_account)?;
+    authorize_ip_allocation(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        &contributor,
+    )?;
+
+    let ip_allocation = IpAllocation::try_from(ip_allocation_account)?;
+    if ip_allocation.contributor_pk != *contributor_account.key {
+        msg!(
+            "IP allocation {} does not belong to contributor {}",
+            ip_allocation.prefix,
+            contributor_account.key
+        );
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    try_acc_close(ip_allocation_account, payer_account)?;
+
+    msg!("Deleted IP allocation {}", ip_allocation.prefix);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+pub mod create;
+pub mod delete;
+pub mod setstatus;
+
+use crate::{
+    authorize::authorize,
+    error::DoubleZeroError,
+    state::{
+        contributor::Contributor,
+        device::Device,
+        globalstate::GlobalState,
+        interface::{Interface, InterfaceCYOA, InterfaceDIA},
+        ip_allocation::IpAllocation,
+        permission::permission_flags,
+    },
+};
+use doublezero_program_common::types::NetworkV4;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// Authorize an IP allocation instruction on behalf of a contributor: the contributor owner, or
+/// CONTRIBUTOR_ADMIN (Permission account) / foundation (legacy).
+///
+/// Call this after all expected accounts have been consumed from `accounts_iter`.
+pub(crate) fn authorize_ip_allocation<'a, 'b: 'a, I>(
+    program_id: &Pubkey,
+    accounts_iter: &mut I,
+    payer_key: &Pubkey,
+    globalstate: &GlobalState,
+    contributor: &Contributor,
+) -> ProgramResult
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    let is_privileged = authorize(
+        program_id,
+        accounts_iter,
+        payer_key,
+        globalstate,
+        permission_flags::CONTRIBUTOR_ADMIN,
+    )
+    .is_ok();
+
+    if contributor.owner != *payer_key && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Check the `ip_net` of a CYOA or DIA interface against the IP allocation supplied with a
+/// device interface instruction.
+///
+/// A supplied allocation must be a verified allocation of the device's contributor that contains
+/// `ip_net`. Without one the interface is accepted unless `required` (the
+/// `RequireIpAllocations` feature flag) is set. Other interfaces are not checked: their
+/// addresses come from DoubleZero's own blocks.
+pub(crate) fn check_interface_ip_allocation(
+    program_id: &Pubkey,
+    ip_allocation_account: Option<&AccountInfo>,
+    required: bool,
+    device: &Device,
+    iface: &Interface,
+) -> ProgramResult {
+    let is_cyoa_or_dia =
+        iface.interface_cyoa != InterfaceCYOA::None || iface.interface_dia != InterfaceDIA::None;
+    if !is_cyoa_or_dia || iface.ip_net == NetworkV4::default() {
+        return Ok(());
+    }
+
+    let Some(ip_allocation_account) = ip_allocation_account else {
+        if required {
+            msg!(
+                "Interface {} ip_net {} needs an IP allocation",
+                iface.name,
+                iface.ip_net
+            );
+            return Err(DoubleZeroError::UnregisteredInterfaceIp.into());
+        }
+        return Ok(());
+    };
+
+    if ip_allocation_account.owner != program_id {
+        msg!("Invalid IpAllocation account owner");
+        return Err(DoubleZeroError::UnregisteredInterfaceIp.into());
+    }
+    let ip_allocation = IpAllocation::try_from(ip_allocation_account)
+        .map_err(|_| DoubleZeroError::UnregisteredInterfaceIp)?;
+    if !ip_allocation.covers(&device.contributor_pk, &iface.ip_net) {
+        msg!(
+            "Interface {} ip_net {} is not covered by IP allocation {} ({}, {})",
+            iface.name,
+            iface.ip_net,
+            ip_allocation.prefix,
+            ip_allocation.status,
+            ip_allocation.contributor_pk
+        );
+        return Err(DoubleZeroError::UnregisteredInterfaceIp.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::{
+    authorize::authorize,
+    events::DoubleZeroEvent,
+    pda::get_globalstate_pda,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{
+        globalstate::GlobalState,
+        ip_allocation::{IpAllocation, IpAllocationStatus},
+        permission::permission_flags,
+    },
+    state_machine::validate_transition,
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct IpAllocationSetStatusArgs {
+    pub status: IpAllocationStatus,
+}
+
+/// Record the outcome of the foundation's ownership check. Rejecting a verified allocation does
+/// not touch interfaces already using it; it only stops new assignments from the block.
+///
+/// Accounts layout:
+/// [0] ip_allocation PDA (writable)
+/// [1] globalstate       (readonly)
+/// [2] payer             (writable, signer, must hold FOUNDATION)
+/// [3] system_program
+/// [4] permission        (readonly, optional — payer's Permission PDA)
+pub fn process_set_ip_allocation_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &IpAllocationSetStatusArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let ip_allocation_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_set_ip_allocation_status({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(
+        ip_allocation_account,
+        program_id,
+        writable = true,
+        "IpAllocation"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: FOUNDATION (Permission account) or foundation allowlist (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::FOUNDATION,
+    )?;
+
+    let mut ip_allocation = IpAllocation::try_from(ip_allocation_account)?;
+    let from = ip_allocation.status;
+    validate_transition(from, value.status, "SetIpAllocationStatus", true)?;
+    ip_allocation.status = value.status;
+    try_acc_write(
+        &ip_allocation,
+        ip_allocation_account,
+        payer_account,
+        accounts,
+    )?;
+
+    msg!(
+        "IP allocation {} is now {}",
+        ip_allocation.prefix,
+        ip_allocation.status
+    );
+
+    DoubleZeroEvent::IpAllocationStatusChanged {
+        ip_allocation: *ip_allocation_account.key,
+        from,
+        to: ip_allocation.status,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     processors::{
         link::resource_onchain_helpers::validate_and_allocate_link_resources,
         validation::validate_program_account,
@@ -208,7 +209,10 @@ pub fn process_accept_link(
             NetworkV4::new(link.tunnel_net.nth(1).unwrap(), link.tunnel_net.prefix()).unwrap();
     }
     side_z_dev.interfaces[idx_z] = updated_iface_z;
+    side_a_dev.bump_config_generation();
+    side_z_dev.bump_config_generation();
 
+    let from = link.status;
     link.status = LinkStatus::Activated;
     link.check_status_transition();
 
@@ -219,5 +223,12 @@ pub fn process_accept_link(
     #[cfg(test)]
     msg!("Accepted and Activated: {:?}", link);
 
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
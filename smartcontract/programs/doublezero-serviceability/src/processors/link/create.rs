@@ -1,13 +1,14 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     pda::{get_link_pda, get_topology_pda, UNICAST_DEFAULT_TOPOLOGY_NAME},
     processors::validation::validate_program_account,
     seeds::{SEED_LINK, SEED_PREFIX},
     serializer::{try_acc_create, try_acc_write},
     state::{
         accounttype::AccountType,
-        contributor::Contributor,
+        contributor::{Contributor, ContributorStatus},
         device::Device,
         globalstate::GlobalState,
         interface::{InterfaceCYOA, InterfaceDIA, InterfaceStatus, LINK_MTU},
@@ -138,6 +139,10 @@ pub fn process_create_link(
         return Err(DoubleZeroError::InvalidOwnerPubkey.into());
     }
 
+    if contributor.status == ContributorStatus::Suspended {
+        return Err(DoubleZeroError::ContributorSuspended.into());
+    }
+
     let (expected_pda_account, bump_seed) = get_link_pda(program_id, globalstate.account_index);
     assert_eq!(
         link_account.key, &expected_pda_account,
@@ -243,9 +248,15 @@ pub fn process_create_link(
         desired_status: value.desired_status.unwrap_or(LinkDesiredStatus::Activated),
         link_topologies: Vec::new(),
         link_flags: 0,
+        flap_count: 0,
+        flap_penalty: 0,
+        flap_epoch: 0,
+        damped_until_epoch: 0,
+        ops_lock: Default::default(),
     };
 
     link.check_status_transition();
+    let from = link.status;
 
     // Auto-tag with UNICAST-DEFAULT topology at creation.
     // Always validate the PDA derivation to prevent callers passing a wrong account.
@@ -338,9 +349,18 @@ pub fn process_create_link(
         ],
     )?;
     try_acc_write(&contributor, contributor_account, payer_account, accounts)?;
+    side_a_dev.bump_config_generation();
+    side_z_dev.bump_config_generation();
     try_acc_write(&side_a_dev, side_a_account, payer_account, accounts)?;
     try_acc_write(&side_z_dev, side_z_account, payer_account, accounts)?;
     try_acc_write(&globalstate, globalstate_account, payer_account, accounts)?;
 
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
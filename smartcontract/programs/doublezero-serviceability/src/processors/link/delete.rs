@@ -10,6 +10,7 @@ use crate::{
         globalstate::GlobalState,
         interface::{InterfaceCYOA, InterfaceDIA, InterfaceStatus, InterfaceType},
         link::*,
+        ops_lock::check_ops_lock,
         permission::permission_flags,
         topology::TopologyInfo,
     },
@@ -129,6 +130,7 @@ pub fn process_delete_link(
 
     // Any link can be deleted by its contributor or foundation allowlist
     let link: Link = Link::try_from(link_account)?;
+    check_ops_lock(&link.ops_lock, payer_account.key, "Link")?;
 
     if matches!(link.status, LinkStatus::Activated | LinkStatus::Deleting) {
         return Err(DoubleZeroError::InvalidStatus.into());
@@ -191,6 +193,8 @@ pub fn process_delete_link(
     contributor.reference_count = contributor.reference_count.saturating_sub(1);
     side_a_dev.reference_count = side_a_dev.reference_count.saturating_sub(1);
     side_z_dev.reference_count = side_z_dev.reference_count.saturating_sub(1);
+    side_a_dev.bump_config_generation();
+    side_z_dev.bump_config_generation();
 
     try_acc_write(&contributor, contributor_account, payer_account, accounts)?;
     try_acc_write(&side_a_dev, side_a_account, payer_account, accounts)?;
@@ -2,5 +2,6 @@ pub mod accept;
 pub mod create;
 pub mod delete;
 pub mod resource_onchain_helpers;
+pub mod set_ops_lock;
 pub mod sethealth;
 pub mod update;
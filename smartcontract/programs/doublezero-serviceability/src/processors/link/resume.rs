@@ -1,4 +1,5 @@
 use crate::{
+    events::DoubleZeroEvent,
     authorize::authorize,
     error::DoubleZeroError,
     processors::validation::validate_program_account,
@@ -83,6 +84,7 @@ pub fn process_resume_link(
         return Err(DoubleZeroError::InvalidStatus.into());
     }
 
+    let from = link.status;
     link.status = LinkStatus::Activated;
 
     try_acc_write(&link, link_account, payer_account, accounts)?;
@@ -90,5 +92,12 @@ pub fn process_resume_link(
     #[cfg(test)]
     msg!("Resumed: {:?}", link);
 
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,5 +1,6 @@
 use crate::{
     authorize::authorize,
+    events::DoubleZeroEvent,
     processors::validation::validate_program_account,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, link::*, permission::permission_flags},
@@ -9,9 +10,11 @@ use borsh_incremental::BorshDeserializeIncremental;
 use core::fmt;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 #[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
@@ -72,7 +75,10 @@ pub fn process_set_health_link(
 
     let mut link: Link = Link::try_from(link_account)?;
 
+    let from_health = link.link_health;
+    let from = link.status;
     link.link_health = value.health;
+    link.update_flap_damping(from_health, Clock::get()?.epoch);
 
     link.check_status_transition();
 
@@ -80,5 +86,18 @@ pub fn process_set_health_link(
 
     msg!("Set Health: {:?}", link);
 
+    DoubleZeroEvent::LinkHealthChanged {
+        link: *link_account.key,
+        from: from_health,
+        to: link.link_health,
+    }
+    .emit();
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::{
+    events::DoubleZeroEvent,
     authorize::authorize,
     error::DoubleZeroError,
     processors::validation::validate_program_account,
@@ -84,11 +85,19 @@ pub fn process_suspend_link(
         return Err(DoubleZeroError::InvalidStatus.into());
     }
 
+    let from = link.status;
     link.status = LinkStatus::Suspended;
 
     try_acc_write(&link, link_account, payer_account, accounts)?;
 
     msg!("Suspended: {:?}", link);
 
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use crate::{
     authorize::{authorize, split_trailing_permission},
     error::{DoubleZeroError, Validate},
+    events::DoubleZeroEvent,
     pda::{get_globalstate_pda, get_resource_extension_pda},
     processors::{
         resource::{allocate_specific_id, allocate_specific_ip, deallocate_id, deallocate_ip},
@@ -10,8 +11,9 @@ use crate::{
     serializer::try_acc_write,
     state::{
         contributor::Contributor, device::Device, globalstate::GlobalState, link::*,
-        permission::permission_flags, topology::TopologyInfo,
+        ops_lock::check_ops_lock, permission::permission_flags, topology::TopologyInfo,
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -222,6 +224,7 @@ pub fn process_update_link(
 
     // Deserialize the link account
     let mut link: Link = Link::try_from(link_account)?;
+    check_ops_lock(&link.ops_lock, payer_account.key, "Link")?;
 
     if side_z.is_none() {
         // Link should be owned by the contributor A
@@ -275,8 +278,12 @@ pub fn process_update_link(
         link.desired_status = desired_status;
     }
 
+    let from = link.status;
     if let Some(status) = value.status {
-        link.status = status;
+        if status != link.status {
+            validate_transition(link.status, status, "UpdateLink", is_privileged)?;
+            link.status = status;
+        }
     }
 
     // Handle tunnel_id/tunnel_net reallocation (foundation-only).
@@ -369,6 +376,8 @@ pub fn process_update_link(
         updated_iface_z.ip_net =
             NetworkV4::new(link.tunnel_net.nth(1).unwrap(), link.tunnel_net.prefix()).unwrap();
         side_z_dev.replace_interface(idx_z, updated_iface_z);
+        side_a_dev.bump_config_generation();
+        side_z_dev.bump_config_generation();
 
         try_acc_write(&side_a_dev, device_a_account, payer_account, accounts)?;
         try_acc_write(&side_z_dev, device_z_account, payer_account, accounts)?;
@@ -452,6 +461,13 @@ pub fn process_update_link(
     #[cfg(test)]
     msg!("Updated: {:?}", link);
 
+    DoubleZeroEvent::LinkStatusChanged {
+        link: *link_account.key,
+        from,
+        to: link.status,
+    }
+    .emit();
+
     Ok(())
 }
 
@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, location::*, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -71,10 +72,14 @@ pub fn process_resume_location(
     let mut location: Location = Location::try_from(location_account)?;
 
     // Only resume locations that are currently Suspended
-    if location.status != LocationStatus::Suspended {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        location.status,
+        LocationStatus::Activated,
+        "ResumeLocation",
+        true,
+    )?;
 
+    let from = location.status;
     location.status = LocationStatus::Activated;
 
     try_acc_write(&location, location_account, payer_account, accounts)?;
@@ -82,5 +87,12 @@ pub fn process_resume_location(
     #[cfg(test)]
     msg!("Resumed: {:?}", location);
 
+    DoubleZeroEvent::LocationStatusChanged {
+        location: *location_account.key,
+        from,
+        to: location.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, location::*, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -71,12 +72,14 @@ pub fn process_suspend_location(
 
     let mut location: Location = Location::try_from(location_account)?;
 
-    if location.status != LocationStatus::Activated {
-        #[cfg(test)]
-        msg!("{:?}", location);
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        location.status,
+        LocationStatus::Suspended,
+        "SuspendLocation",
+        true,
+    )?;
 
+    let from = location.status;
     location.status = LocationStatus::Suspended;
 
     try_acc_write(&location, location_account, payer_account, accounts)?;
@@ -84,5 +87,12 @@ pub fn process_suspend_location(
     #[cfg(test)]
     msg!("Suspended: {:?}", location);
 
+    DoubleZeroEvent::LocationStatusChanged {
+        location: *location_account.key,
+        from,
+        to: location.status,
+    }
+    .emit();
+
     Ok(())
 }
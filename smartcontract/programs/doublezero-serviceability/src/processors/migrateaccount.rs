@@ -0,0 +1,84 @@
+use crate::{
+    authorize::authorize,
+    migration::migrate_account_data,
+    state::{globalstate::GlobalState, permission::permission_flags},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use doublezero_program_common::resize_account::resize_account_if_needed;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct MigrateAccountArgs {}
+
+impl fmt::Debug for MigrateAccountArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "")
+    }
+}
+
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &MigrateAccountArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_migrate_account({:?})", _value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    // Check the owner of the accounts
+    assert_eq!(account.owner, program_id, "Invalid PDA Account Owner");
+    assert_eq!(
+        globalstate_account.owner, program_id,
+        "Invalid GlobalState Account Owner"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+    // Check if the account is writable
+    assert!(account.is_writable, "PDA Account is not writable");
+
+    // Authorization: GLOBALSTATE_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::GLOBALSTATE_ADMIN,
+    )?;
+
+    let upgraded = migrate_account_data(&account.try_borrow_data()?)?;
+    let Some(upgraded) = upgraded else {
+        msg!("Account {} is already at the current layout", account.key);
+        return Ok(());
+    };
+
+    msg!(
+        "Migrating account {} from {} to {} bytes",
+        account.key,
+        account.data_len(),
+        upgraded.len()
+    );
+    resize_account_if_needed(account, payer_account, accounts, upgraded.len())?;
+    account.try_borrow_mut_data()?.copy_from_slice(&upgraded);
+
+    Ok(())
+}
@@ -134,6 +134,8 @@ pub fn process_add_multicastgroup_sub_allowlist(
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         try_acc_create(
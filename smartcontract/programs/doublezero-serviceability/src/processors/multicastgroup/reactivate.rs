@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, multicastgroup::*, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -73,10 +74,14 @@ pub fn process_reactivate_multicastgroup(
 
     let mut multicastgroup: MulticastGroup = MulticastGroup::try_from(multicastgroup_account)?;
 
-    if multicastgroup.status != MulticastGroupStatus::Suspended {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        multicastgroup.status,
+        MulticastGroupStatus::Activated,
+        "ReactivateMulticastGroup",
+        true,
+    )?;
 
+    let from = multicastgroup.status;
     multicastgroup.status = MulticastGroupStatus::Activated;
 
     try_acc_write(
@@ -89,5 +94,12 @@ pub fn process_reactivate_multicastgroup(
     #[cfg(test)]
     msg!("Reactivated: {:?}", multicastgroup);
 
+    DoubleZeroEvent::MulticastGroupStatusChanged {
+        multicastgroup: *multicastgroup_account.key,
+        from,
+        to: multicastgroup.status,
+    }
+    .emit();
+
     Ok(())
 }
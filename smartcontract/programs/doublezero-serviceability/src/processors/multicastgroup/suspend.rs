@@ -1,8 +1,9 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, multicastgroup::*, permission::permission_flags},
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -74,12 +75,14 @@ pub fn process_suspend_multicastgroup(
 
     let mut multicastgroup: MulticastGroup = MulticastGroup::try_from(multicastgroup_account)?;
 
-    if multicastgroup.status != MulticastGroupStatus::Activated {
-        #[cfg(test)]
-        msg!("{:?}", multicastgroup);
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        multicastgroup.status,
+        MulticastGroupStatus::Suspended,
+        "SuspendMulticastGroup",
+        true,
+    )?;
 
+    let from = multicastgroup.status;
     multicastgroup.status = MulticastGroupStatus::Suspended;
 
     try_acc_write(
@@ -92,5 +95,12 @@ pub fn process_suspend_multicastgroup(
     #[cfg(test)]
     msg!("Suspended: {:?}", multicastgroup);
 
+    DoubleZeroEvent::MulticastGroupStatusChanged {
+        multicastgroup: *multicastgroup_account.key,
+        from,
+        to: multicastgroup.status,
+    }
+    .emit();
+
     Ok(())
 }
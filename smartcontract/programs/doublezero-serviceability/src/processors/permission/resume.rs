@@ -1,6 +1,6 @@
 use crate::{
     authorize::authorize,
-    error::DoubleZeroError,
+    events::DoubleZeroEvent,
     pda::get_permission_pda,
     processors::validation::validate_program_account,
     serializer::try_acc_write,
@@ -8,6 +8,7 @@ use crate::{
         globalstate::GlobalState,
         permission::{permission_flags, Permission, PermissionStatus},
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -64,9 +65,12 @@ pub fn process_resume_permission(
     // Unlike update/suspend/delete, resume has no self-guard by design: restoring one's
     // own access can never cause a lockout.
 
-    if permission.status != PermissionStatus::Suspended {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        permission.status,
+        PermissionStatus::Activated,
+        "ResumePermission",
+        true,
+    )?;
 
     let globalstate = GlobalState::try_from(globalstate_account)?;
     authorize(
@@ -77,8 +81,16 @@ pub fn process_resume_permission(
         permission_flags::PERMISSION_ADMIN,
     )?;
 
+    let from = permission.status;
     permission.status = PermissionStatus::Activated;
     try_acc_write(&permission, permission_account, payer_account, accounts)?;
 
+    DoubleZeroEvent::PermissionStatusChanged {
+        permission: *permission_account.key,
+        from,
+        to: permission.status,
+    }
+    .emit();
+
     Ok(())
 }
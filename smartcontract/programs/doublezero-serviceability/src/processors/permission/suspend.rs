@@ -1,6 +1,7 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     pda::get_permission_pda,
     processors::validation::validate_program_account,
     serializer::try_acc_write,
@@ -8,6 +9,7 @@ use crate::{
         globalstate::GlobalState,
         permission::{permission_flags, Permission, PermissionStatus},
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -69,9 +71,12 @@ pub fn process_suspend_permission(
         return Err(DoubleZeroError::InvalidArgument.into());
     }
 
-    if permission.status != PermissionStatus::Activated {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        permission.status,
+        PermissionStatus::Suspended,
+        "SuspendPermission",
+        true,
+    )?;
 
     let globalstate = GlobalState::try_from(globalstate_account)?;
     authorize(
@@ -82,8 +87,16 @@ pub fn process_suspend_permission(
         permission_flags::PERMISSION_ADMIN,
     )?;
 
+    let from = permission.status;
     permission.status = PermissionStatus::Suspended;
     try_acc_write(&permission, permission_account, payer_account, accounts)?;
 
+    DoubleZeroEvent::PermissionStatusChanged {
+        permission: *permission_account.key,
+        from,
+        to: permission.status,
+    }
+    .emit();
+
     Ok(())
 }
@@ -0,0 +1,137 @@
+use crate::{
+    authorize::authorize,
+    error::Validate,
+    pda::{get_globalstate_pda, get_qos_profile_pda, get_resource_extension_pda},
+    processors::{resource::allocate_id, validation::validate_program_account},
+    resource::ResourceType,
+    seeds::{SEED_PREFIX, SEED_QOS_PROFILE},
+    serializer::try_acc_create,
+    state::{
+        accounttype::AccountType, globalstate::GlobalState, permission::permission_flags,
+        qos_profile::QosProfile, user::BandwidthTier,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq)]
+pub struct QosProfileCreateArgs {
+    pub bandwidth_tier: BandwidthTier,
+    pub rate_mbps: u32,
+    pub burst_kbytes: u32,
+    pub dscp: u8,
+}
+
+/// Accounts layout:
+/// [0]  qos_profile PDA     (writable, to be created)
+/// [1]  qos_profile_ids     (writable, ResourceExtension)
+/// [2]  globalstate         (readonly)
+/// [3]  payer               (writable, signer, must hold NETWORK_ADMIN)
+/// [4]  system_program
+/// [5]  permission          (readonly, optional — payer's Permission PDA)
+pub fn process_create_qos_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &QosProfileCreateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let qos_profile_account = next_account_info(accounts_iter)?;
+    let qos_profile_ids_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_create_qos_profile({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer account must be a signer");
+
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // The account is still empty, so check the PDA and writability directly.
+    let (expected_pda, bump_seed) = get_qos_profile_pda(program_id, value.bandwidth_tier);
+    assert_eq!(
+        qos_profile_account.key, &expected_pda,
+        "Invalid QosProfile PubKey"
+    );
+    assert!(
+        qos_profile_account.is_writable,
+        "QosProfile Account is not writable"
+    );
+
+    if !qos_profile_account.data_is_empty() {
+        msg!(
+            "QoS profile for tier {} already exists",
+            value.bandwidth_tier
+        );
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let mut qos_profile = QosProfile {
+        account_type: AccountType::QosProfile,
+        owner: *payer_account.key,
+        bump_seed,
+        bandwidth_tier: value.bandwidth_tier,
+        profile_id: 0,
+        rate_mbps: value.rate_mbps,
+        burst_kbytes: value.burst_kbytes,
+        dscp: value.dscp,
+    };
+    // Reject bad arguments before touching the id pool.
+    qos_profile.validate()?;
+
+    let (expected_ids_pda, _, _) =
+        get_resource_extension_pda(program_id, ResourceType::QosProfileIds);
+    validate_program_account!(
+        qos_profile_ids_account,
+        program_id,
+        writable = true,
+        pda = &expected_ids_pda,
+        "QosProfileIds"
+    );
+    qos_profile.profile_id = allocate_id(qos_profile_ids_account)?;
+
+    try_acc_create(
+        &qos_profile,
+        qos_profile_account,
+        payer_account,
+        system_program,
+        program_id,
+        &[
+            SEED_PREFIX,
+            SEED_QOS_PROFILE,
+            &[value.bandwidth_tier as u8],
+            &[bump_seed],
+        ],
+    )?;
+
+    msg!(
+        "Created QoS profile {} for tier {}",
+        qos_profile.profile_id,
+        value.bandwidth_tier
+    );
+    Ok(())
+}
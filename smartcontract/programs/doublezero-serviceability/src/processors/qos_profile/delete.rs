@@ -0,0 +1,86 @@
+use crate::{
+    authorize::authorize,
+    pda::{get_globalstate_pda, get_resource_extension_pda},
+    processors::{resource::deallocate_id, validation::validate_program_account},
+    resource::ResourceType,
+    serializer::try_acc_close,
+    state::{globalstate::GlobalState, permission::permission_flags, qos_profile::QosProfile},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq, Default)]
+pub struct QosProfileDeleteArgs {}
+
+/// Accounts layout:
+/// [0] qos_profile PDA  (writable, to be closed)
+/// [1] qos_profile_ids  (writable, ResourceExtension)
+/// [2] globalstate      (readonly)
+/// [3] payer            (writable, signer, must hold NETWORK_ADMIN)
+/// [4] system_program
+/// [5] permission       (readonly, optional — payer's Permission PDA)
+pub fn process_delete_qos_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &QosProfileDeleteArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let qos_profile_account = next_account_info(accounts_iter)?;
+    let qos_profile_ids_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(
+        qos_profile_account,
+        program_id,
+        writable = true,
+        "QosProfile"
+    );
+    validate_program_account!(
+        qos_profile_ids_account,
+        program_id,
+        writable = true,
+        pda = &get_resource_extension_pda(program_id, ResourceType::QosProfileIds).0,
+        "QosProfileIds"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // Users of the tier simply fall back to unshaped once the profile is gone,
+    // so the id can go straight back to the pool.
+    let qos_profile = QosProfile::try_from(qos_profile_account)?;
+    deallocate_id(qos_profile_ids_account, qos_profile.profile_id);
+    try_acc_close(qos_profile_account, payer_account)?;
+
+    msg!(
+        "Deleted QoS profile {} for tier {}",
+        qos_profile.profile_id,
+        qos_profile.bandwidth_tier
+    );
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::{
+    authorize::authorize,
+    pda::get_globalstate_pda,
+    processors::validation::validate_program_account,
+    serializer::try_acc_write,
+    state::{globalstate::GlobalState, permission::permission_flags, qos_profile::QosProfile},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+#[cfg(test)]
+use solana_program::msg;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, Debug, Clone, PartialEq, Default)]
+pub struct QosProfileUpdateArgs {
+    pub rate_mbps: Option<u32>,
+    pub burst_kbytes: Option<u32>,
+    pub dscp: Option<u8>,
+}
+
+/// Accounts layout:
+/// [0] qos_profile PDA  (writable)
+/// [1] globalstate      (readonly)
+/// [2] payer            (writable, signer, must hold NETWORK_ADMIN)
+/// [3] system_program
+/// [4] permission       (readonly, optional — payer's Permission PDA)
+pub fn process_update_qos_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &QosProfileUpdateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let qos_profile_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_update_qos_profile({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    validate_program_account!(
+        qos_profile_account,
+        program_id,
+        writable = true,
+        "QosProfile"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+
+    // Authorization: NETWORK_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::NETWORK_ADMIN,
+    )?;
+
+    // bandwidth_tier and profile_id are fixed for the life of the profile.
+    let mut qos_profile = QosProfile::try_from(qos_profile_account)?;
+    if let Some(rate_mbps) = value.rate_mbps {
+        qos_profile.rate_mbps = rate_mbps;
+    }
+    if let Some(burst_kbytes) = value.burst_kbytes {
+        qos_profile.burst_kbytes = burst_kbytes;
+    }
+    if let Some(dscp) = value.dscp {
+        qos_profile.dscp = dscp;
+    }
+    try_acc_write(&qos_profile, qos_profile_account, payer_account, accounts)?;
+
+    Ok(())
+}
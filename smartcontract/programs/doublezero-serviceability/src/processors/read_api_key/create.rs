@@ -0,0 +1,158 @@
+use crate::{
+    error::DoubleZeroError,
+    pda::get_read_api_key_pda,
+    processors::{read_api_key::authorize_read_api_key, validation::validate_program_account},
+    seeds::{SEED_PREFIX, SEED_READ_API_KEY},
+    serializer::try_acc_create,
+    state::{
+        accounttype::AccountType,
+        contributor::{Contributor, ContributorStatus},
+        globalstate::GlobalState,
+        read_api_key::{ReadApiKey, ReadApiKeyStatus},
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use std::fmt;
+
+/// Maximum `label` length, matching the Feed `name` cap.
+pub const MAX_READ_API_KEY_LABEL_LEN: usize = 64;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct ReadApiKeyCreateArgs {
+    /// SHA-256 hash of the key; part of the PDA seed. The key itself never goes onchain.
+    pub key_hash: [u8; 32],
+    pub label: String,
+    /// Unix timestamp after which the key is rejected; 0 means it never expires.
+    pub expires_at: i64,
+}
+
+impl fmt::Debug for ReadApiKeyCreateArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "label: {}, expires_at: {}", self.label, self.expires_at)
+    }
+}
+
+pub fn process_create_read_api_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &ReadApiKeyCreateArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let read_api_key_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+    assert!(
+        read_api_key_account.is_writable,
+        "PDA Account is not writable"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    let contributor = Contributor::try_from(contributor_account)?;
+    authorize_read_api_key(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        &contributor,
+    )?;
+
+    if contributor.status != ContributorStatus::Activated {
+        msg!("Contributor {} is not activated", contributor_account.key);
+        return Err(DoubleZeroError::InvalidStatus.into());
+    }
+    if value.label.len() > MAX_READ_API_KEY_LABEL_LEN {
+        msg!(
+            "Read API key label too long: {} > {}",
+            value.label.len(),
+            MAX_READ_API_KEY_LABEL_LEN
+        );
+        return Err(DoubleZeroError::NameTooLong.into());
+    }
+    if value.key_hash == [0; 32] {
+        msg!("Read API key hash must not be empty");
+        return Err(DoubleZeroError::InvalidArgument.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if value.expires_at != 0 && value.expires_at <= now {
+        msg!(
+            "Read API key expiry {} is not in the future (now {})",
+            value.expires_at,
+            now
+        );
+        return Err(DoubleZeroError::InvalidArgument.into());
+    }
+
+    let (expected_pda, bump_seed) =
+        get_read_api_key_pda(program_id, contributor_account.key, &value.key_hash);
+    assert_eq!(
+        read_api_key_account.key, &expected_pda,
+        "Invalid ReadApiKey PubKey"
+    );
+
+    if !read_api_key_account.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let read_api_key = ReadApiKey {
+        account_type: AccountType::ReadApiKey,
+        owner: *payer_account.key,
+        bump_seed,
+        status: ReadApiKeyStatus::Activated,
+        contributor_pk: *contributor_account.key,
+        key_hash: value.key_hash,
+        label: value.label.clone(),
+        created_at: now,
+        expires_at: value.expires_at,
+    };
+
+    try_acc_create(
+        &read_api_key,
+        read_api_key_account,
+        payer_account,
+        system_program,
+        program_id,
+        &[
+            SEED_PREFIX,
+            SEED_READ_API_KEY,
+            contributor_account.key.as_ref(),
+            &value.key_hash,
+            &[bump_seed],
+        ],
+    )?;
+
+    msg!(
+        "Created read API key {} for contributor {}",
+        read_api_key_account.key,
+        contributor_account.key
+    );
+
+    Ok(())
+}
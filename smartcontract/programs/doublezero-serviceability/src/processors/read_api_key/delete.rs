@@ -0,0 +1,85 @@
+use crate::{
+    error::DoubleZeroError,
+    processors::{read_api_key::authorize_read_api_key, validation::validate_program_account},
+    serializer::try_acc_close,
+    state::{contributor::Contributor, globalstate::GlobalState, read_api_key::ReadApiKey},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct ReadApiKeyDeleteArgs {}
+
+/// Close a read-API key account and reclaim its rent. Only keys that are revoked or expired can
+/// be deleted, so a live key is never removed without first being revoked.
+pub fn process_delete_read_api_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &ReadApiKeyDeleteArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let read_api_key_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        read_api_key_account,
+        program_id,
+        writable = true,
+        "ReadApiKey"
+    );
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    let contributor = Contributor::try_from(contributor_account)?;
+    authorize_read_api_key(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        &contributor,
+    )?;
+
+    let read_api_key = ReadApiKey::try_from(read_api_key_account)?;
+    if read_api_key.contributor_pk != *contributor_account.key {
+        msg!(
+            "Read API key {} does not belong to contributor {}",
+            read_api_key_account.key,
+            contributor_account.key
+        );
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+    if read_api_key.is_valid_at(Clock::get()?.unix_timestamp) {
+        msg!("Read API key must be revoked or expired before it is deleted");
+        return Err(DoubleZeroError::InvalidStatus.into());
+    }
+
+    msg!("Deleted read API key {}", read_api_key_account.key);
+
+    try_acc_close(read_api_key_account, payer_account)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+pub mod create;
+pub mod delete;
+pub mod revoke;
+
+use crate::{
+    authorize::authorize,
+    error::DoubleZeroError,
+    state::{contributor::Contributor, globalstate::GlobalState, permission::permission_flags},
+};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+/// Authorize a read-API key instruction: the contributor owner, or CONTRIBUTOR_ADMIN
+/// (Permission account) / foundation (legacy).
+///
+/// Call this after all expected accounts have been consumed from `accounts_iter`.
+pub(crate) fn authorize_read_api_key<'a, 'b: 'a, I>(
+    program_id: &Pubkey,
+    accounts_iter: &mut I,
+    payer_key: &Pubkey,
+    globalstate: &GlobalState,
+    contributor: &Contributor,
+) -> ProgramResult
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    let is_privileged = authorize(
+        program_id,
+        accounts_iter,
+        payer_key,
+        globalstate,
+        permission_flags::CONTRIBUTOR_ADMIN,
+    )
+    .is_ok();
+
+    if contributor.owner != *payer_key && !is_privileged {
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::{
+    error::DoubleZeroError,
+    events::DoubleZeroEvent,
+    processors::{read_api_key::authorize_read_api_key, validation::validate_program_account},
+    serializer::try_acc_write,
+    state::{
+        contributor::Contributor,
+        globalstate::GlobalState,
+        read_api_key::{ReadApiKey, ReadApiKeyStatus},
+    },
+    state_machine::validate_transition,
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Debug, Clone, Default)]
+pub struct ReadApiKeyRevokeArgs {}
+
+/// Revoke a read-API key. Revocation is permanent: a revoked key can only be deleted, and
+/// services must reject it immediately.
+pub fn process_revoke_read_api_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _value: &ReadApiKeyRevokeArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let read_api_key_account = next_account_info(accounts_iter)?;
+    let contributor_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    assert!(payer_account.is_signer, "Payer must be a signer");
+    validate_program_account!(
+        read_api_key_account,
+        program_id,
+        writable = true,
+        "ReadApiKey"
+    );
+    validate_program_account!(
+        contributor_account,
+        program_id,
+        writable = false,
+        "Contributor"
+    );
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        "GlobalState"
+    );
+
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    let contributor = Contributor::try_from(contributor_account)?;
+    authorize_read_api_key(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        &contributor,
+    )?;
+
+    let mut read_api_key = ReadApiKey::try_from(read_api_key_account)?;
+    if read_api_key.contributor_pk != *contributor_account.key {
+        msg!(
+            "Read API key {} does not belong to contributor {}",
+            read_api_key_account.key,
+            contributor_account.key
+        );
+        return Err(DoubleZeroError::NotAllowed.into());
+    }
+    validate_transition(
+        read_api_key.status,
+        ReadApiKeyStatus::Revoked,
+        "RevokeReadApiKey",
+        true,
+    )?;
+
+    let from = read_api_key.status;
+    read_api_key.status = ReadApiKeyStatus::Revoked;
+
+    try_acc_write(&read_api_key, read_api_key_account, payer_account, accounts)?;
+
+    msg!("Revoked read API key {}", read_api_key_account.key);
+
+    DoubleZeroEvent::ReadApiKeyStatusChanged {
+        read_api_key: *read_api_key_account.key,
+        from,
+        to: read_api_key.status,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ pub mod allocate;
 pub mod closeaccount;
 pub mod create;
 pub mod deallocate;
+pub mod resize;
 
 pub fn get_resource_extension_range(
     program_id: &Pubkey,
@@ -70,6 +71,7 @@ pub fn get_resource_extension_range(
         ResourceType::SegmentRoutingIds => ResourceExtensionRange::IdRange(1, 65535),
         ResourceType::VrfIds => ResourceExtensionRange::IdRange(1, 1024),
         ResourceType::AdminGroupBits => ResourceExtensionRange::IdRange(1, 127),
+        ResourceType::QosProfileIds => ResourceExtensionRange::IdRange(1, 255),
     }
 }
 
@@ -175,6 +177,72 @@ pub fn create_resource(
     Ok(())
 }
 
+/// Resize an existing ResourceExtension account to the range currently derived from
+/// GlobalConfig (or the associated device), preserving existing allocations.
+pub fn resize_resource(
+    program_id: &Pubkey,
+    resource_account: &AccountInfo,
+    associated_account: Option<&AccountInfo>,
+    globalconfig_account: &AccountInfo,
+    payer_account: &AccountInfo,
+    accounts: &[AccountInfo],
+    resource_type: ResourceType,
+) -> ProgramResult {
+    // Check if the account is writable
+    assert!(resource_account.is_writable, "PDA Account is not writable");
+    assert_eq!(
+        resource_account.owner, program_id,
+        "Invalid Resource Account Owner"
+    );
+
+    let globalconfig = GlobalConfig::try_from(&globalconfig_account.data.borrow()[..])?;
+    let (globalconfig_pda, _globalconfig_bump_seed) = get_globalconfig_pda(program_id);
+    assert_eq!(
+        globalconfig_account.key, &globalconfig_pda,
+        "Invalid GlobalConfig PubKey"
+    );
+
+    let (expected_resource_pda, _bump_seed, _base_seed) =
+        get_resource_extension_pda(program_id, resource_type);
+    assert_eq!(
+        resource_account.key, &expected_resource_pda,
+        "Invalid Resource Account PubKey"
+    );
+
+    let resource_range =
+        get_resource_extension_range(program_id, &globalconfig, associated_account, resource_type);
+
+    let allocator = {
+        let mut buffer = resource_account.data.borrow_mut();
+        let resource = ResourceExtensionBorrowed::inplace_from(&mut buffer[..])?;
+        if let Some(associated_account) = associated_account {
+            assert_eq!(
+                &resource.associated_with, associated_account.key,
+                "Invalid Associated Account"
+            );
+        }
+        resource.resized_allocator(&resource_range)?
+    };
+
+    let old_size = resource_account.data_len();
+    let data_size = ResourceExtensionBorrowed::size(&resource_range);
+    doublezero_program_common::resize_account::resize_account_if_needed(
+        resource_account,
+        payer_account,
+        accounts,
+        data_size,
+    )?;
+
+    let mut buffer = resource_account.data.borrow_mut();
+    // Newly grown bitmap space must start out unallocated
+    if data_size > old_size {
+        buffer[old_size..].fill(0);
+    }
+    ResourceExtensionBorrowed::write_allocator(&mut buffer[..], &allocator)?;
+
+    Ok(())
+}
+
 /// Borrow a ResourceExtension account, deserialize it, and allocate `count` IPs.
 pub fn allocate_ip(account: &AccountInfo, count: usize) -> Result<NetworkV4, ProgramError> {
     let mut buffer = account.data.borrow_mut();
@@ -0,0 +1,92 @@
+use crate::{
+    authorize::authorize,
+    state::{globalstate::GlobalState, permission::permission_flags},
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+#[cfg(test)]
+use solana_program::msg;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+use std::fmt;
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct ResourceResizeArgs {
+    pub resource_type: crate::resource::ResourceType,
+}
+
+impl fmt::Debug for ResourceResizeArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ResourceResizeArgs {{ resource_type: {:?} }}",
+            self.resource_type
+        )
+    }
+}
+
+pub fn process_resize_resource(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &ResourceResizeArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let resource_account = next_account_info(accounts_iter)?;
+    let associated_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let globalconfig_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_resize_resource({:?})", value);
+
+    // Check if the payer is a signer
+    assert!(payer_account.is_signer, "Payer must be a signer");
+
+    // Check the owner of the accounts
+    assert_eq!(
+        globalstate_account.owner, program_id,
+        "Invalid GlobalState Account Owner"
+    );
+    assert_eq!(
+        globalconfig_account.owner, program_id,
+        "Invalid GlobalConfig Account Owner"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    assert!(
+        !resource_account.data_is_empty(),
+        "Resource Account must be initialized"
+    );
+
+    // Authorization: RESOURCE_ADMIN (Permission account) or foundation (legacy).
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::RESOURCE_ADMIN,
+    )?;
+
+    super::resize_resource(
+        program_id,
+        resource_account,
+        Some(associated_account),
+        globalconfig_account,
+        payer_account,
+        accounts,
+        value.resource_type,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::{
+    authorize::authorize,
+    error::{DoubleZeroError, Validate},
+    pda::{get_globalstate_pda, get_state_commitment_pda},
+    processors::validation::validate_program_account,
+    seeds::{SEED_PREFIX, SEED_STATE_COMMITMENT},
+    serializer::{try_acc_create, try_acc_write},
+    state::{
+        accounttype::AccountType, globalstate::GlobalState, permission::permission_flags,
+        state_commitment::StateCommitment,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    hash::Hash,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserializeIncremental, PartialEq, Clone, Default)]
+pub struct RecordStateCommitmentArgs {
+    pub slot: u64,
+    pub merkle_root: [u8; 32],
+    pub account_count: u32,
+}
+
+impl fmt::Debug for RecordStateCommitmentArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slot: {}, merkle_root: {}, account_count: {}",
+            self.slot,
+            Hash::new_from_array(self.merkle_root),
+            self.account_count
+        )
+    }
+}
+
+/// Accounts layout:
+/// [0] state_commitment PDA (writable, created on the first call)
+/// [1] globalstate          (readonly)
+/// [2] payer                (writable, signer, must hold HEALTH_ORACLE or FOUNDATION)
+/// [3] system_program
+/// [4] permission           (readonly, optional — payer's Permission PDA)
+pub fn process_record_state_commitment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &RecordStateCommitmentArgs,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let state_commitment_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    #[cfg(test)]
+    msg!("process_record_state_commitment({:?})", value);
+
+    assert!(payer_account.is_signer, "Payer account must be a signer");
+
+    validate_program_account!(
+        globalstate_account,
+        program_id,
+        writable = false,
+        pda = &get_globalstate_pda(program_id).0,
+        "GlobalState"
+    );
+    assert_eq!(
+        *system_program.unsigned_key(),
+        solana_system_interface::program::ID,
+        "Invalid System Program Account Owner"
+    );
+
+    // Authorization: HEALTH_ORACLE or FOUNDATION, via a Permission account or the legacy
+    // health_oracle_pk / foundation_allowlist.
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    authorize(
+        program_id,
+        accounts_iter,
+        payer_account.key,
+        &globalstate,
+        permission_flags::HEALTH_ORACLE | permission_flags::FOUNDATION,
+    )?;
+
+    let (expected_pda, bump_seed) = get_state_commitment_pda(program_id);
+    assert_eq!(
+        state_commitment_account.key, &expected_pda,
+        "Invalid StateCommitment PubKey"
+    );
+    assert!(
+        state_commitment_account.is_writable,
+        "StateCommitment Account is not writable"
+    );
+
+    // The snapshot cannot come from the future, and a light client must never see the
+    // commitment move back to older state.
+    let clock_slot = Clock::get()?.slot;
+    if value.slot > clock_slot {
+        msg!(
+            "State commitment slot {} is ahead of the clock ({})",
+            value.slot,
+            clock_slot
+        );
+        return Err(DoubleZeroError::InvalidStateCommitment.into());
+    }
+
+    let exists = !state_commitment_account.data_is_empty();
+    if exists {
+        validate_program_account!(
+            state_commitment_account,
+            program_id,
+            writable = true,
+            "StateCommitment"
+        );
+        let previous = StateCommitment::try_from(state_commitment_account)?;
+        if value.slot <= previous.slot {
+            msg!(
+                "State commitment slot {} is not after the recorded slot {}",
+                value.slot,
+                previous.slot
+            );
+            return Err(DoubleZeroError::InvalidStateCommitment.into());
+        }
+    }
+
+    let state_commitment = StateCommitment {
+        account_type: AccountType::StateCommitment,
+        bump_seed,
+        slot: value.slot,
+        merkle_root: value.merkle_root,
+        account_count: value.account_count,
+        recorded_by: *payer_account.key,
+        recorded_at_slot: clock_slot,
+    };
+    state_commitment.validate()?;
+
+    if exists {
+        try_acc_write(
+            &state_commitment,
+            state_commitment_account,
+            payer_account,
+            accounts,
+        )?;
+    } else {
+        try_acc_create(
+            &state_commitment,
+            state_commitment_account,
+            payer_account,
+            system_program,
+            program_id,
+            &[SEED_PREFIX, SEED_STATE_COMMITMENT, &[bump_seed]],
+        )?;
+    }
+
+    msg!(
+        "Recorded state commitment for slot {}: {} over {} accounts",
+        state_commitment.slot,
+        Hash::new_from_array(state_commitment.merkle_root),
+        state_commitment.account_count
+    );
+    Ok(())
+}
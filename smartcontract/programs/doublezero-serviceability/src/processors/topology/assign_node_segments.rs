@@ -165,6 +165,7 @@ pub fn process_assign_topology_node_segments(
             backfilled_count += 1;
         }
         if modified {
+            device.bump_config_generation();
             try_acc_write(&device, device_account, payer_account, accounts)?;
         }
     }
@@ -1,6 +1,7 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     pda::get_accesspass_pda,
     processors::validation::validate_program_account,
     serializer::try_acc_write,
@@ -10,6 +11,7 @@ use crate::{
         permission::permission_flags,
         user::{User, UserStatus},
     },
+    state_machine::validate_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -101,12 +103,16 @@ pub fn process_check_access_pass_user(
     let mut accesspass = AccessPass::try_from(accesspass_account)?;
     accesspass.update_status()?;
 
-    if user.status != UserStatus::Activated && user.status != UserStatus::OutOfCredits {
-        return Err(DoubleZeroError::InvalidStatus.into());
-    }
+    validate_transition(
+        user.status,
+        UserStatus::Activated,
+        "CheckUserAccessPass",
+        true,
+    )?;
 
     // Epoch expiry is deprecated and no longer demotes users. Access for all user
     // types is governed by allowlists, not by accesspass.last_access_epoch.
+    let from = user.status;
     user.status = UserStatus::Activated;
 
     try_acc_write(&user, user_account, payer_account, accounts)?;
@@ -115,5 +121,12 @@ pub fn process_check_access_pass_user(
     #[cfg(test)]
     msg!("OutOfCredits: {:?}", user);
 
+    DoubleZeroEvent::UserStatusChanged {
+        user: *user_account.key,
+        from,
+        to: user.status,
+    }
+    .emit();
+
     Ok(())
 }
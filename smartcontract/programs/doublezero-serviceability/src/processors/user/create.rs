@@ -1,5 +1,8 @@
 use crate::{
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
+    format_option,
+    helper::format_option_displayable,
     seeds::{SEED_PREFIX, SEED_USER},
     serializer::{try_acc_create, try_acc_write},
     state::user::*,
@@ -32,18 +35,21 @@ pub struct UserCreateArgs {
     /// user creation always allocates resources and activates atomically.
     #[incremental(default = 0)]
     pub dz_prefix_count: u8,
+    /// Requested bandwidth tier. `None` takes the access pass's tier.
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl fmt::Debug for UserCreateArgs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "user_type: {}, cyoa_type: {}, client_ip: {}, tunnel_endpoint: {}, dz_prefix_count: {}",
+            "user_type: {}, cyoa_type: {}, client_ip: {}, tunnel_endpoint: {}, dz_prefix_count: {}, bandwidth_tier: {}",
             self.user_type,
             self.cyoa_type,
             &self.client_ip,
             &self.tunnel_endpoint,
             self.dz_prefix_count,
+            format_option!(self.bandwidth_tier),
         )
     }
 }
@@ -119,6 +125,7 @@ pub fn process_create_user(
         // Plain CreateUser is unicast; no multicast group and no feed gate.
         None,
         None,
+        value.bandwidth_tier,
     )?;
 
     // Always allocate resources and activate atomically.
@@ -178,5 +185,7 @@ pub fn process_create_user(
         accounts,
     )?;
 
+    DoubleZeroEvent::user_activated(user_account.key, &result.user).emit();
+
     Ok(())
 }
@@ -81,6 +81,7 @@ pub fn create_user_core(
     // connects) and the referenced Feed account covering the device's exchange.
     target_mgroup: Option<&Pubkey>,
     feed_account: Option<&AccountInfo>,
+    bandwidth_tier: Option<BandwidthTier>,
 ) -> Result<CreateUserCoreResult, ProgramError> {
     // Check if the payer is a signer
     assert!(core.payer_account.is_signer, "Payer must be a signer");
@@ -245,6 +246,18 @@ pub fn create_user_core(
         }
     }
 
+    // The pass's bandwidth tier is the default and the ceiling: a user may ask for a lower tier,
+    // never a higher one, and never to be unshaped under a shaped pass.
+    let bandwidth_tier = bandwidth_tier.unwrap_or(accesspass.bandwidth_tier);
+    if !bandwidth_tier.is_within(accesspass.bandwidth_tier) {
+        msg!(
+            "Bandwidth tier {} exceeds access pass tier {}",
+            bandwidth_tier,
+            accesspass.bandwidth_tier
+        );
+        return Err(DoubleZeroError::BandwidthTierNotAllowed.into());
+    }
+
     // Read validator_pubkey from AccessPass
     let validator_pubkey = match &accesspass.accesspass_type {
         AccessPassType::SolanaValidator(pk) => *pk,
@@ -353,6 +366,7 @@ pub fn create_user_core(
             device.unicast_users_count += 1;
         }
     }
+    device.bump_config_generation();
 
     // Handle tenant reference counting and get tenant_pk
     let tenant_pk = if let Some(tenant_account) = core.tenant_account {
@@ -406,6 +420,7 @@ pub fn create_user_core(
         last_bgp_reported_at: 0,
         bgp_rtt_ns: 0,
         feed_pk,
+        bandwidth_tier,
     };
 
     Ok(CreateUserCoreResult {
@@ -1,6 +1,9 @@
 use crate::{
     authorize::split_trailing_permission,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
+    format_option,
+    helper::format_option_displayable,
     seeds::{SEED_PREFIX, SEED_USER},
     serializer::{try_acc_create, try_acc_write},
     state::user::*,
@@ -40,19 +43,22 @@ pub struct UserCreateSubscribeArgs {
     /// The access pass is looked up using this owner instead of the payer.
     #[incremental(default = Pubkey::default())]
     pub owner: Pubkey,
+    /// Requested bandwidth tier. `None` takes the access pass's tier.
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl fmt::Debug for UserCreateSubscribeArgs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "user_type: {}, cyoa_type: {}, client_ip: {}, tunnel_endpoint: {}, dz_prefix_count: {}, owner: {}",
+            "user_type: {}, cyoa_type: {}, client_ip: {}, tunnel_endpoint: {}, dz_prefix_count: {}, owner: {}, bandwidth_tier: {}",
             self.user_type,
             self.cyoa_type,
             &self.client_ip,
             &self.tunnel_endpoint,
             self.dz_prefix_count,
             self.owner,
+            format_option!(self.bandwidth_tier),
         )
     }
 }
@@ -133,6 +139,7 @@ pub fn process_create_subscribe_user(
         owner_override,
         Some(mgroup_account.key),
         feed_account,
+        value.bandwidth_tier,
     )?;
 
     // Subscribe user to multicast group
@@ -207,5 +214,7 @@ pub fn process_create_subscribe_user(
         accounts,
     )?;
 
+    DoubleZeroEvent::user_activated(user_account.key, &result.user).emit();
+
     Ok(())
 }
@@ -255,6 +255,7 @@ pub fn process_delete_user(
             device.unicast_users_count = device.unicast_users_count.saturating_sub(1);
         }
     }
+    device.bump_config_generation();
 
     try_acc_write(&device, device_account, payer_account, accounts)?;
     try_acc_close(user_account, owner_account)?;
@@ -1,9 +1,11 @@
 use crate::{
     authorize::authorize,
     error::DoubleZeroError,
+    events::DoubleZeroEvent,
     processors::validation::validate_program_account,
     serializer::try_acc_write,
     state::{globalstate::GlobalState, permission::permission_flags, user::*},
+    state_machine::find_transition,
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
@@ -134,6 +136,7 @@ pub fn process_request_ban_user(
     user.tunnel_net = NetworkV4::default();
     user.tunnel_id = 0;
     user.dz_ip = Ipv4Addr::UNSPECIFIED;
+    let from = user.status;
     user.status = UserStatus::Banned;
 
     #[cfg(test)]
@@ -141,11 +144,18 @@ pub fn process_request_ban_user(
 
     try_acc_write(&user, user_account, payer_account, accounts)?;
 
+    DoubleZeroEvent::UserStatusChanged {
+        user: *user_account.key,
+        from,
+        to: user.status,
+    }
+    .emit();
+
     Ok(())
 }
 
 fn can_request_ban(status: UserStatus) -> bool {
-    status == UserStatus::Activated || status == UserStatus::SuspendedDeprecated
+    find_transition(status, UserStatus::Banned, "RequestBanUser").is_some()
 }
 
 #[cfg(test)]
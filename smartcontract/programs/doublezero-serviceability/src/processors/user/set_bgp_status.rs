@@ -4,6 +4,7 @@ use crate::{
     serializer::try_acc_write,
     state::{
         device::Device,
+        globalstate::GlobalState,
         user::{BGPStatus, User},
     },
 };
@@ -48,6 +49,9 @@ pub fn process_set_bgp_status_user(
     let device_account = next_account_info(accounts_iter)?;
     let payer_account = next_account_info(accounts_iter)?;
     let _system_program = next_account_info(accounts_iter)?;
+    // Optional so agents built before the emergency pause keep working; without it
+    // the instruction is treated as unpaused.
+    let globalstate_account = accounts_iter.next();
 
     assert!(
         payer_account.is_signer,
@@ -56,10 +60,20 @@ pub fn process_set_bgp_status_user(
 
     validate_program_account!(user_account, program_id, writable = true, "User");
     validate_program_account!(device_account, program_id, writable = false, "Device");
+    if let Some(globalstate_account) = globalstate_account {
+        validate_program_account!(
+            globalstate_account,
+            program_id,
+            writable = false,
+            "GlobalState"
+        );
+        GlobalState::try_from(globalstate_account)?;
+    }
 
     let device = Device::try_from(device_account)?;
+    let clock = Clock::get()?;
 
-    if device.metrics_publisher_pk != *payer_account.key {
+    if !device.is_metrics_publisher(payer_account.key, clock.epoch) {
         return Err(DoubleZeroError::NotAllowed.into());
     }
 
@@ -69,7 +83,7 @@ pub fn process_set_bgp_status_user(
         return Err(DoubleZeroError::NotAllowed.into());
     }
 
-    let slot = Clock::get()?.slot;
+    let slot = clock.slot;
     user.bgp_status = value.bgp_status;
     user.last_bgp_reported_at = slot;
     user.bgp_rtt_ns = value.bgp_rtt_ns;
@@ -41,13 +41,15 @@ pub struct UserUpdateArgs {
     #[incremental(default = 0)]
     pub multicast_publisher_count: u8,
     pub tunnel_endpoint: Option<Ipv4Addr>,
+    /// New bandwidth tier. Not bounded by the access pass: the caller is a USER_ADMIN.
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl fmt::Debug for UserUpdateArgs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "user_type: {}, cyoa_type: {}, dz_ip: {}, tunnel_id: {}, tunnel_net: {}, validator_pubkey: {}, tenant_pk: {}, dz_prefix_count: {}, multicast_publisher_count: {}, tunnel_endpoint: {}",
+            "user_type: {}, cyoa_type: {}, dz_ip: {}, tunnel_id: {}, tunnel_net: {}, validator_pubkey: {}, tenant_pk: {}, dz_prefix_count: {}, multicast_publisher_count: {}, tunnel_endpoint: {}, bandwidth_tier: {}",
             format_option!(self.user_type),
             format_option!(self.cyoa_type),
             format_option!(self.dz_ip),
@@ -58,6 +60,7 @@ impl fmt::Debug for UserUpdateArgs {
             self.dz_prefix_count,
             self.multicast_publisher_count,
             format_option!(self.tunnel_endpoint),
+            format_option!(self.bandwidth_tier),
         )
     }
 }
@@ -315,6 +318,9 @@ pub fn process_update_user(
     if let Some(value) = value.tunnel_endpoint {
         user.tunnel_endpoint = value;
     }
+    if let Some(value) = value.bandwidth_tier {
+        user.bandwidth_tier = value;
+    }
     if let Some(new_tenant_pk) = value.tenant_pk {
         // If tenant accounts are provided, update reference counts
         if let (Some(old_tenant_acc), Some(new_tenant_acc)) =
@@ -16,6 +16,7 @@ pub enum ResourceType {
     SegmentRoutingIds,
     VrfIds,
     AdminGroupBits,
+    QosProfileIds,
 }
 
 impl fmt::Display for ResourceType {
@@ -31,6 +32,7 @@ impl fmt::Display for ResourceType {
             ResourceType::SegmentRoutingIds => write!(f, "SegmentRoutingIds"),
             ResourceType::VrfIds => write!(f, "VrfIds"),
             ResourceType::AdminGroupBits => write!(f, "AdminGroupBits"),
+            ResourceType::QosProfileIds => write!(f, "QosProfileIds"),
         }
     }
 }
@@ -25,3 +25,11 @@ pub const SEED_ADMIN_GROUP_BITS: &[u8] = b"admingroupbits";
 pub const SEED_INDEX: &[u8] = b"index";
 pub const SEED_TOPOLOGY: &[u8] = b"topology";
 pub const SEED_FEED: &[u8] = b"feed";
+pub const SEED_READ_API_KEY: &[u8] = b"readapikey";
+pub const SEED_QOS_PROFILE_IDS: &[u8] = b"qosprofileids";
+pub const SEED_QOS_PROFILE: &[u8] = b"qosprofile";
+pub const SEED_IP_ALLOCATION: &[u8] = b"ipallocation";
+pub const SEED_INTERFACE_OPER_STATUS: &[u8] = b"ifoperstatus";
+pub const SEED_ANYCAST_PREFIX: &[u8] = b"anycastprefix";
+pub const SEED_REFERRER: &[u8] = b"referrer";
+pub const SEED_STATE_COMMITMENT: &[u8] = b"statecommitment";
@@ -1,7 +1,10 @@
 use crate::{
     error::{DoubleZeroError, Validate},
     helper::deserialize_vec_with_capacity,
-    state::{accounttype::AccountType, user::UserType},
+    state::{
+        accounttype::AccountType,
+        user::{BandwidthTier, UserType},
+    },
 };
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -72,6 +75,10 @@ pub enum AccessPassType {
     /// has no production passes, so changing the payload (same discriminant index 4) does not
     /// orphan any deployed account; no migration is required.
     EdgeSeat(Vec<FeedSeat>),
+    /// A pass backed by a lamports deposit, held in the pass account on top of its rent. The
+    /// deposit is taken at `SetAccessPass` and burned by `SettleAccessPassLease` once the pass
+    /// expires (`last_access_epoch` has passed). A settled lease reads `Leased(0)`.
+    Leased(u64), // deposit in lamports
 }
 
 impl AccessPassType {
@@ -82,6 +89,7 @@ impl AccessPassType {
             AccessPassType::SolanaRPC(_) => "solana_rpc".to_string(),
             AccessPassType::Others(type_name, _) => type_name.clone(),
             AccessPassType::EdgeSeat(_) => "edge_seat".to_string(),
+            AccessPassType::Leased(_) => "leased".to_string(),
         }
     }
 }
@@ -96,6 +104,7 @@ impl fmt::Display for AccessPassType {
                 write!(f, "others: {} ({})", type_name, key)
             }
             AccessPassType::EdgeSeat(seats) => write!(f, "edge_seat: {} feed(s)", seats.len()),
+            AccessPassType::Leased(deposit) => write!(f, "leased: {deposit} lamports"),
         }
     }
 }
@@ -204,6 +213,15 @@ pub struct AccessPass {
     pub max_unicast_users: u16,        // 2 - max unicast users admitted (EdgeSeat only)
     pub multicast_user_count: u16,     // 2 - live count of multicast users (EdgeSeat only)
     pub max_multicast_users: u16,      // 2 - max multicast users admitted (EdgeSeat only)
+    pub bandwidth_tier: BandwidthTier, // 1 - tier given to users created under the pass, and the highest they may request
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub referrer: Pubkey, // 32 - credited when the pass was created; default when none
 }
 
 impl fmt::Display for AccessPass {
@@ -226,6 +244,11 @@ impl fmt::Display for AccessPass {
                 write!(f, "Others: {} ({})", type_name, details)
             }
             AccessPassType::EdgeSeat(seats) => write!(f, "EdgeSeat: ({} feed(s))", seats.len()),
+            AccessPassType::Leased(deposit) => write!(
+                f,
+                "Leased: ({deposit} lamports, expires epoch {})",
+                self.last_access_epoch
+            ),
         }
     }
 }
@@ -253,6 +276,10 @@ impl TryFrom<&[u8]> for AccessPass {
             max_unicast_users: BorshDeserialize::deserialize(&mut data).unwrap_or(1),
             multicast_user_count: BorshDeserialize::deserialize(&mut data).unwrap_or(0),
             max_multicast_users: BorshDeserialize::deserialize(&mut data).unwrap_or(1),
+            // Passes created before bandwidth tiers read as unshaped.
+            bandwidth_tier: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            // Passes created before referrals read as unreferred.
+            referrer: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
         };
 
         if out.account_type != AccountType::AccessPass {
@@ -337,6 +364,14 @@ impl AccessPass {
         }
     }
 
+    /// The lamports deposit still held by a leased pass (0 for other types and settled leases).
+    pub fn lease_deposit(&self) -> u64 {
+        match self.accesspass_type {
+            AccessPassType::Leased(deposit) => deposit,
+            _ => 0,
+        }
+    }
+
     /// The feed seats provisioned on this pass (empty for non-EdgeSeat passes).
     pub fn feed_seats(&self) -> &[FeedSeat] {
         match &self.accesspass_type {
@@ -414,6 +449,11 @@ mod tests {
         // Each FeedSeat adds 52 bytes (32 pubkey + 1 + 1 + 1 + 1 + 8 + 8).
         let d = AccessPassType::EdgeSeat(vec![FeedSeat::default()]);
         assert_eq!(object_length(&d).unwrap(), 1 + 4 + 52);
+
+        // Leased: discriminant byte + u64 deposit.
+        let e = AccessPassType::Leased(1_000_000);
+        assert_eq!(object_length(&e).unwrap(), 9);
+        assert_eq!(borsh::to_vec(&e).unwrap()[0], 5);
     }
 
     #[test]
@@ -436,6 +476,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let data = borsh::to_vec(&val).unwrap();
@@ -484,6 +526,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let data = borsh::to_vec(&val).unwrap();
@@ -526,6 +570,8 @@ mod tests {
         assert_eq!(val.max_unicast_users, 1);
         assert_eq!(val.multicast_user_count, 0);
         assert_eq!(val.max_multicast_users, 1);
+        assert_eq!(val.bandwidth_tier, BandwidthTier::Unshaped);
+        assert_eq!(val.referrer, Pubkey::default());
     }
 
     fn test_accesspass(accesspass_type: AccessPassType) -> AccessPass {
@@ -547,6 +593,8 @@ mod tests {
             max_unicast_users: 2,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         }
     }
 
@@ -668,6 +716,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let mut data = borsh::to_vec(&val).unwrap();
@@ -711,6 +761,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -737,6 +789,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
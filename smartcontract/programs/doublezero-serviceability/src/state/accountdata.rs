@@ -1,12 +1,14 @@
 use crate::{
     error::DoubleZeroError,
     state::{
-        accesspass::AccessPass, accounttype::AccountType, contributor::Contributor, device::Device,
-        exchange::Exchange, feed::Feed, globalconfig::GlobalConfig, globalstate::GlobalState,
-        index::Index, link::Link, location::Location, multicastgroup::MulticastGroup,
-        permission::Permission, programconfig::ProgramConfig,
-        resource_extension::ResourceExtensionOwned, tenant::Tenant, topology::TopologyInfo,
-        user::User,
+        accesspass::AccessPass, accounttype::AccountType, anycast_prefix::AnycastPrefix,
+        contributor::Contributor, device::Device, exchange::Exchange, feed::Feed,
+        globalconfig::GlobalConfig, globalstate::GlobalState, index::Index,
+        interface_oper_status::InterfaceOperStatus, ip_allocation::IpAllocation, link::Link,
+        location::Location, multicastgroup::MulticastGroup, permission::Permission,
+        programconfig::ProgramConfig, qos_profile::QosProfile, read_api_key::ReadApiKey,
+        referrer_stats::ReferrerStats, resource_extension::ResourceExtensionOwned,
+        state_commitment::StateCommitment, tenant::Tenant, topology::TopologyInfo, user::User,
     },
 };
 use solana_program::program_error::ProgramError;
@@ -33,6 +35,13 @@ pub enum AccountData {
     Index(Index),
     Topology(TopologyInfo),
     Feed(Feed),
+    ReadApiKey(ReadApiKey),
+    QosProfile(QosProfile),
+    IpAllocation(IpAllocation),
+    InterfaceOperStatus(InterfaceOperStatus),
+    AnycastPrefix(AnycastPrefix),
+    ReferrerStats(ReferrerStats),
+    StateCommitment(StateCommitment),
 }
 
 impl AccountData {
@@ -56,6 +65,13 @@ impl AccountData {
             AccountData::Index(_) => "Index",
             AccountData::Topology(_) => "Topology",
             AccountData::Feed(_) => "Feed",
+            AccountData::ReadApiKey(_) => "ReadApiKey",
+            AccountData::QosProfile(_) => "QosProfile",
+            AccountData::IpAllocation(_) => "IpAllocation",
+            AccountData::InterfaceOperStatus(_) => "InterfaceOperStatus",
+            AccountData::AnycastPrefix(_) => "AnycastPrefix",
+            AccountData::ReferrerStats(_) => "ReferrerStats",
+            AccountData::StateCommitment(_) => "StateCommitment",
         }
     }
 
@@ -79,6 +95,13 @@ impl AccountData {
             AccountData::Index(index) => index.to_string(),
             AccountData::Topology(topology) => topology.to_string(),
             AccountData::Feed(feed) => feed.to_string(),
+            AccountData::ReadApiKey(read_api_key) => read_api_key.to_string(),
+            AccountData::QosProfile(qos_profile) => qos_profile.to_string(),
+            AccountData::IpAllocation(ip_allocation) => ip_allocation.to_string(),
+            AccountData::InterfaceOperStatus(oper_status) => oper_status.to_string(),
+            AccountData::AnycastPrefix(anycast_prefix) => anycast_prefix.to_string(),
+            AccountData::ReferrerStats(referrer_stats) => referrer_stats.to_string(),
+            AccountData::StateCommitment(state_commitment) => state_commitment.to_string(),
         }
     }
 
@@ -217,6 +240,62 @@ impl AccountData {
             Err(DoubleZeroError::InvalidAccountType)
         }
     }
+
+    pub fn get_read_api_key(&self) -> Result<ReadApiKey, DoubleZeroError> {
+        if let AccountData::ReadApiKey(read_api_key) = self {
+            Ok(read_api_key.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_qos_profile(&self) -> Result<QosProfile, DoubleZeroError> {
+        if let AccountData::QosProfile(qos_profile) = self {
+            Ok(qos_profile.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_ip_allocation(&self) -> Result<IpAllocation, DoubleZeroError> {
+        if let AccountData::IpAllocation(ip_allocation) = self {
+            Ok(ip_allocation.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_interface_oper_status(&self) -> Result<InterfaceOperStatus, DoubleZeroError> {
+        if let AccountData::InterfaceOperStatus(oper_status) = self {
+            Ok(oper_status.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_anycast_prefix(&self) -> Result<AnycastPrefix, DoubleZeroError> {
+        if let AccountData::AnycastPrefix(anycast_prefix) = self {
+            Ok(anycast_prefix.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_referrer_stats(&self) -> Result<ReferrerStats, DoubleZeroError> {
+        if let AccountData::ReferrerStats(referrer_stats) = self {
+            Ok(referrer_stats.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
+
+    pub fn get_state_commitment(&self) -> Result<StateCommitment, DoubleZeroError> {
+        if let AccountData::StateCommitment(state_commitment) = self {
+            Ok(state_commitment.clone())
+        } else {
+            Err(DoubleZeroError::InvalidAccountType)
+        }
+    }
 }
 
 impl TryFrom<&[u8]> for AccountData {
@@ -263,6 +342,27 @@ impl TryFrom<&[u8]> for AccountData {
                 bytes as &[u8],
             )?)),
             AccountType::Feed => Ok(AccountData::Feed(Feed::try_from(bytes as &[u8])?)),
+            AccountType::ReadApiKey => Ok(AccountData::ReadApiKey(ReadApiKey::try_from(
+                bytes as &[u8],
+            )?)),
+            AccountType::QosProfile => Ok(AccountData::QosProfile(QosProfile::try_from(
+                bytes as &[u8],
+            )?)),
+            AccountType::IpAllocation => Ok(AccountData::IpAllocation(IpAllocation::try_from(
+                bytes as &[u8],
+            )?)),
+            AccountType::InterfaceOperStatus => Ok(AccountData::InterfaceOperStatus(
+                InterfaceOperStatus::try_from(bytes as &[u8])?,
+            )),
+            AccountType::AnycastPrefix => Ok(AccountData::AnycastPrefix(AnycastPrefix::try_from(
+                bytes as &[u8],
+            )?)),
+            AccountType::ReferrerStats => Ok(AccountData::ReferrerStats(ReferrerStats::try_from(
+                bytes as &[u8],
+            )?)),
+            AccountType::StateCommitment => Ok(AccountData::StateCommitment(
+                StateCommitment::try_from(bytes as &[u8])?,
+            )),
         }
     }
 }
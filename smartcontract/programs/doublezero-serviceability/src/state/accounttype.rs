@@ -6,6 +6,7 @@ use std::fmt;
 #[derive(BorshSerialize, BorshDeserialize, Debug, Default, Copy, Clone, PartialEq)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum AccountType {
     #[default]
     None = 0,
@@ -26,6 +27,13 @@ pub enum AccountType {
     Index = 16,
     Topology = 17,
     Feed = 18,
+    ReadApiKey = 19,
+    QosProfile = 20,
+    IpAllocation = 21,
+    InterfaceOperStatus = 22,
+    AnycastPrefix = 23,
+    ReferrerStats = 24,
+    StateCommitment = 25,
 }
 
 pub trait AccountTypeInfo {
@@ -56,6 +64,13 @@ impl From<u8> for AccountType {
             16 => AccountType::Index,
             17 => AccountType::Topology,
             18 => AccountType::Feed,
+            19 => AccountType::ReadApiKey,
+            20 => AccountType::QosProfile,
+            21 => AccountType::IpAllocation,
+            22 => AccountType::InterfaceOperStatus,
+            23 => AccountType::AnycastPrefix,
+            24 => AccountType::ReferrerStats,
+            25 => AccountType::StateCommitment,
             _ => AccountType::None,
         }
     }
@@ -82,6 +97,13 @@ impl fmt::Display for AccountType {
             AccountType::Index => write!(f, "index"),
             AccountType::Topology => write!(f, "topology"),
             AccountType::Feed => write!(f, "feed"),
+            AccountType::ReadApiKey => write!(f, "readapikey"),
+            AccountType::QosProfile => write!(f, "qosprofile"),
+            AccountType::IpAllocation => write!(f, "ipallocation"),
+            AccountType::InterfaceOperStatus => write!(f, "interfaceoperstatus"),
+            AccountType::AnycastPrefix => write!(f, "anycastprefix"),
+            AccountType::ReferrerStats => write!(f, "referrerstats"),
+            AccountType::StateCommitment => write!(f, "statecommitment"),
         }
     }
 }
@@ -0,0 +1,151 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use doublezero_program_common::types::NetworkV4;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+/// An anycast service address (DNS, NTP, internal services) advertised by every device.
+///
+/// The registry is global: device controllers render all `enabled` prefixes onto the anycast
+/// loopback of each device, so the service is reachable at the nearest device. Disabling a
+/// prefix withdraws it everywhere without losing the record. The PDA is derived from `code`,
+/// which is immutable; `prefix` must be a single host address (/32).
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnycastPrefix {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub owner: Pubkey, // 32
+    pub bump_seed: u8,             // 1
+    pub code: String,              // 4 + len (PDA seed, immutable)
+    pub prefix: NetworkV4,         // 5
+    pub enabled: bool,             // 1 - advertised by devices when set
+}
+
+impl fmt::Display for AnycastPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, owner: {}, bump_seed: {}, code: {}, prefix: {}, enabled: {}",
+            self.account_type, self.owner, self.bump_seed, self.code, self.prefix, self.enabled
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for AnycastPrefix {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            owner: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            code: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            prefix: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            enabled: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::AnycastPrefix {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for AnycastPrefix {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!(
+                "Failed to deserialize AnycastPrefix: {:?}",
+                res.as_ref().err()
+            );
+        }
+        res
+    }
+}
+
+impl Validate for AnycastPrefix {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::AnycastPrefix {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if self.code.len() > 32 {
+            msg!("Invalid code length: {}", self.code.len());
+            return Err(DoubleZeroError::CodeTooLong);
+        }
+        if self.prefix.prefix() != 32 {
+            msg!("Anycast prefix must be a /32: {}", self.prefix);
+            return Err(DoubleZeroError::InvalidAnycastPrefix);
+        }
+        if self.prefix.ip().is_unspecified() {
+            msg!("Anycast prefix must not be unspecified: {}", self.prefix);
+            return Err(DoubleZeroError::InvalidAnycastPrefix);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anycast_prefix() -> AnycastPrefix {
+        AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            owner: Pubkey::new_unique(),
+            bump_seed: 253,
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_anycast_prefix_serialization_roundtrip() {
+        let val = anycast_prefix();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = AnycastPrefix::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_anycast_prefix_validate() {
+        let mut val = anycast_prefix();
+        val.prefix = "100.64.255.0/24".parse().unwrap();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidAnycastPrefix));
+
+        let mut val = anycast_prefix();
+        val.prefix = "0.0.0.0/32".parse().unwrap();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidAnycastPrefix));
+
+        let mut val = anycast_prefix();
+        val.code = "a".repeat(33);
+        assert_eq!(val.validate(), Err(DoubleZeroError::CodeTooLong));
+    }
+
+    #[test]
+    fn test_anycast_prefix_wrong_account_type_rejected() {
+        let mut val = anycast_prefix();
+        val.account_type = AccountType::QosProfile;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(AnycastPrefix::try_from(&data[..]).is_err());
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     state::{
         accounttype::AccountType,
         interface::{Interface, InterfaceDeprecated, InterfaceV2},
+        ops_lock::OpsLock,
         user::UserType,
     },
 };
@@ -16,6 +17,7 @@ use std::{fmt, net::Ipv4Addr, str::FromStr};
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum DeviceType {
     #[default]
     Hybrid = 0,
@@ -61,6 +63,7 @@ impl FromStr for DeviceType {
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum DeviceStatus {
     PendingDeprecated = 0, // deprecated; unreachable for new accounts
     #[default]
@@ -130,6 +133,7 @@ pub enum DeviceHealth {
     ReadyForLinks = 2, // ready to connect links
     ReadyForUsers = 3, // ready to connect users
     Impaired = 4,
+    DriftDetected = 5, // running config differs from the rendered config
 }
 
 impl From<u8> for DeviceHealth {
@@ -140,6 +144,7 @@ impl From<u8> for DeviceHealth {
             2 => DeviceHealth::ReadyForLinks,
             3 => DeviceHealth::ReadyForUsers,
             4 => DeviceHealth::Impaired,
+            5 => DeviceHealth::DriftDetected,
             _ => DeviceHealth::Unknown,
         }
     }
@@ -155,6 +160,7 @@ impl FromStr for DeviceHealth {
             "ready-for-links" => Ok(DeviceHealth::ReadyForLinks),
             "ready-for-users" => Ok(DeviceHealth::ReadyForUsers),
             "impaired" => Ok(DeviceHealth::Impaired),
+            "drift-detected" => Ok(DeviceHealth::DriftDetected),
             _ => Err(format!("Invalid DeviceHealth: {s}")),
         }
     }
@@ -168,6 +174,7 @@ impl fmt::Display for DeviceHealth {
             DeviceHealth::ReadyForLinks => write!(f, "ready-for-links"),
             DeviceHealth::ReadyForUsers => write!(f, "ready-for-users"),
             DeviceHealth::Impaired => write!(f, "impaired"),
+            DeviceHealth::DriftDetected => write!(f, "drift-detected"),
         }
     }
 }
@@ -287,6 +294,47 @@ pub struct Device {
     /// `interfaces` (always as `InterfaceDeprecated::V2`) by the custom `BorshSerialize`
     /// impl, keeping older readers byte-compatible.
     pub interfaces: Vec<Interface>,
+    /// Bumped whenever an instruction changes what the controller renders for
+    /// this device: its interfaces, links, users, node segments or status.
+    pub config_generation: u64, // 8
+    /// Last `config_generation` the controller acknowledged as applied.
+    pub applied_config_generation: u64, // 8
+    /// Metrics publisher replaced by `RotateDeviceMetricsPublisher`, still accepted
+    /// as the device's publisher until `metrics_publisher_overlap_until_epoch`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub previous_metrics_publisher_pk: Pubkey, // 32
+    /// Last epoch in which `previous_metrics_publisher_pk` is accepted.
+    pub metrics_publisher_overlap_until_epoch: u64, // 8
+    /// Outstanding `IssueDeviceChallenge` nonce, all zeros when none is pending.
+    pub attestation_challenge: [u8; 32], // 32
+    /// Metrics publisher that answered the last challenge with `ProveDeviceKey`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub attested_key: Pubkey, // 32
+    /// Change freeze set with `SetDeviceOpsLock`.
+    pub ops_lock: OpsLock, // 44 + reason len
+    /// Key granted the contributor owner's update rights on this device only, e.g.
+    /// a managed-service provider operating the hardware. `Pubkey::default()` when
+    /// none is granted.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub co_owner_pk: Pubkey, // 32
 }
 
 impl Default for Device {
@@ -320,11 +368,52 @@ impl Default for Device {
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
             interfaces: Vec::new(),
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         }
     }
 }
 
 impl Device {
+    /// Records a change to the device's intended configuration.
+    pub fn bump_config_generation(&mut self) {
+        self.config_generation = self.config_generation.saturating_add(1);
+    }
+
+    /// Whether the controller has yet to apply the latest configuration.
+    pub fn is_config_lagging(&self) -> bool {
+        self.applied_config_generation < self.config_generation
+    }
+
+    /// Whether `key` may act as the device's metrics publisher in `current_epoch`:
+    /// the current publisher always, and the one it replaced during the overlap
+    /// window of the last rotation.
+    pub fn is_metrics_publisher(&self, key: &Pubkey, current_epoch: u64) -> bool {
+        if self.metrics_publisher_pk == *key {
+            return true;
+        }
+        self.previous_metrics_publisher_pk != Pubkey::default()
+            && self.previous_metrics_publisher_pk == *key
+            && current_epoch <= self.metrics_publisher_overlap_until_epoch
+    }
+
+    /// Whether the device's current metrics publisher has proven possession of its key.
+    /// Rotating the publisher invalidates the proof.
+    pub fn is_key_attested(&self) -> bool {
+        self.attested_key != Pubkey::default() && self.attested_key == self.metrics_publisher_pk
+    }
+
+    /// Whether `key` is the co-owner granted with `GrantDeviceCoOwner`.
+    pub fn is_co_owner(&self, key: &Pubkey) -> bool {
+        self.co_owner_pk != Pubkey::default() && self.co_owner_pk == *key
+    }
+
     pub fn find_interface(&self, name: &str) -> Result<(usize, &Interface), String> {
         self.interfaces
             .iter()
@@ -496,12 +585,15 @@ impl fmt::Display for Device {
             public_ip: {}, dz_prefixes: {}, status: {}, code: {}, metrics_publisher_pk: {}, mgmt_vrf: {}, interfaces: {:?}, \
             reference_count: {}, users_count: {}, max_users: {}, device_health: {}, desired_status: {}, \
             unicast_users_count: {}, multicast_subscribers_count: {}, max_unicast_users: {}, max_multicast_subscribers: {}, reserved_seats: {}, \
-            multicast_publishers_count: {}, max_multicast_publishers: {}",
+            multicast_publishers_count: {}, max_multicast_publishers: {}, config_generation: {}, applied_config_generation: {}, \
+            previous_metrics_publisher_pk: {}, metrics_publisher_overlap_until_epoch: {}, attestation_pending: {}, attested_key: {}, ops_lock: {}, co_owner_pk: {}",
             self.account_type, self.owner, self.index, self.contributor_pk, self.location_pk, self.exchange_pk, self.device_type,
             &self.public_ip, &self.dz_prefixes, self.status, self.code, self.metrics_publisher_pk, self.mgmt_vrf, self.interfaces,
             self.reference_count, self.users_count, self.max_users, self.device_health, self.desired_status,
             self.unicast_users_count, self.multicast_subscribers_count, self.max_unicast_users, self.max_multicast_subscribers, self.reserved_seats,
-            self.multicast_publishers_count, self.max_multicast_publishers
+            self.multicast_publishers_count, self.max_multicast_publishers, self.config_generation, self.applied_config_generation,
+            self.previous_metrics_publisher_pk, self.metrics_publisher_overlap_until_epoch,
+            self.attestation_challenge != [0; 32], self.attested_key, self.ops_lock, self.co_owner_pk
         )
     }
 }
@@ -551,6 +643,15 @@ impl borsh::BorshSerialize for Device {
         self.multicast_publishers_count.serialize(writer)?;
         self.max_multicast_publishers.serialize(writer)?;
         self.interfaces.serialize(writer)?;
+        self.config_generation.serialize(writer)?;
+        self.applied_config_generation.serialize(writer)?;
+        self.previous_metrics_publisher_pk.serialize(writer)?;
+        self.metrics_publisher_overlap_until_epoch
+            .serialize(writer)?;
+        self.attestation_challenge.serialize(writer)?;
+        self.attested_key.serialize(writer)?;
+        self.ops_lock.serialize(writer)?;
+        self.co_owner_pk.serialize(writer)?;
         Ok(())
     }
 }
@@ -601,6 +702,18 @@ impl TryFrom<&[u8]> for Device {
         // Trailing forward-compat vec: present on accounts written by the current
         // serializer, absent on legacy accounts.
         let trailing: Vec<Interface> = BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let config_generation: u64 = BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let applied_config_generation: u64 =
+            BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let previous_metrics_publisher_pk: Pubkey =
+            BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let metrics_publisher_overlap_until_epoch: u64 =
+            BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let attestation_challenge: [u8; 32] =
+            BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let attested_key: Pubkey = BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let ops_lock: OpsLock = BorshDeserialize::deserialize(&mut data).unwrap_or_default();
+        let co_owner_pk: Pubkey = BorshDeserialize::deserialize(&mut data).unwrap_or_default();
 
         let interfaces = if trailing.is_empty() {
             // Legacy account: rebuild from the legacy enum vec via per-variant
@@ -615,11 +728,10 @@ impl TryFrom<&[u8]> for Device {
                 })
                 .collect::<Result<Vec<_>, _>>()?
         } else {
-            assert_eq!(
-                trailing.len(),
-                deprecated_interfaces.len(),
-                "trailing interfaces length must match deprecated_interfaces length"
-            );
+            // The serializer always writes both vecs with the same length.
+            if trailing.len() != deprecated_interfaces.len() {
+                return Err(ProgramError::InvalidAccountData);
+            }
             trailing
         };
 
@@ -652,6 +764,14 @@ impl TryFrom<&[u8]> for Device {
             multicast_publishers_count,
             max_multicast_publishers,
             interfaces,
+            config_generation,
+            applied_config_generation,
+            previous_metrics_publisher_pk,
+            metrics_publisher_overlap_until_epoch,
+            attestation_challenge,
+            attested_key,
+            ops_lock,
+            co_owner_pk,
         };
 
         if out.account_type != AccountType::Device {
@@ -794,6 +914,152 @@ mod tests {
         crate::helper::base_tests::test_parsing::<Device>(&versions).unwrap();
     }
 
+    #[test]
+    fn test_state_device_config_generation_defaults_on_legacy_account() {
+        // Accounts written before the generation fields existed end right after
+        // the trailing interfaces vec (before the 40 bytes of rotation fields, the
+        // 64 bytes of attestation fields, the 44 bytes of an unset ops lock and the
+        // 32 bytes of the co-owner too); both fields read back as zero.
+        let device = Device {
+            code: "la".to_string(),
+            config_generation: 7,
+            applied_config_generation: 5,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            ..Device::default()
+        };
+        let data = borsh::to_vec(&device).unwrap();
+        assert_eq!(Device::try_from(&data[..]).unwrap(), device);
+
+        let legacy = Device::try_from(&data[..data.len() - 16 - 40 - 64 - 44 - 32]).unwrap();
+        assert_eq!(legacy.code, "la");
+        assert_eq!(legacy.config_generation, 0);
+        assert_eq!(legacy.applied_config_generation, 0);
+        assert!(!legacy.is_config_lagging());
+    }
+
+    #[test]
+    fn test_state_device_metrics_publisher_overlap() {
+        let old = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        let device = Device {
+            metrics_publisher_pk: new,
+            previous_metrics_publisher_pk: old,
+            metrics_publisher_overlap_until_epoch: 12,
+            ..Device::default()
+        };
+        let data = borsh::to_vec(&device).unwrap();
+        assert_eq!(Device::try_from(&data[..]).unwrap(), device);
+
+        assert!(device.is_metrics_publisher(&new, 100));
+        assert!(device.is_metrics_publisher(&old, 12));
+        assert!(!device.is_metrics_publisher(&old, 13));
+        assert!(!device.is_metrics_publisher(&Pubkey::new_unique(), 10));
+
+        // Accounts written before the rotation fields existed have no previous
+        // publisher.
+        let legacy = Device::try_from(&data[..data.len() - 40 - 64 - 44 - 32]).unwrap();
+        assert_eq!(legacy.previous_metrics_publisher_pk, Pubkey::default());
+        assert!(!legacy.is_metrics_publisher(&old, 0));
+    }
+
+    #[test]
+    fn test_state_device_key_attestation() {
+        let publisher = Pubkey::new_unique();
+        let device = Device {
+            metrics_publisher_pk: publisher,
+            attestation_challenge: [7; 32],
+            attested_key: publisher,
+            ..Device::default()
+        };
+        let data = borsh::to_vec(&device).unwrap();
+        assert_eq!(Device::try_from(&data[..]).unwrap(), device);
+        assert!(device.is_key_attested());
+
+        // Rotating the metrics publisher invalidates the proof.
+        let rotated = Device {
+            metrics_publisher_pk: Pubkey::new_unique(),
+            ..device.clone()
+        };
+        assert!(!rotated.is_key_attested());
+
+        // Accounts written before the attestation fields existed were never attested.
+        let legacy = Device::try_from(&data[..data.len() - 64 - 44 - 32]).unwrap();
+        assert_eq!(legacy.attestation_challenge, [0; 32]);
+        assert_eq!(legacy.attested_key, Pubkey::default());
+        assert!(!legacy.is_key_attested());
+    }
+
+    #[test]
+    fn test_state_device_ops_lock() {
+        let device = Device {
+            code: "la".to_string(),
+            ops_lock: OpsLock {
+                locked_by: Pubkey::new_unique(),
+                expires_at: 1_700_000_000,
+                reason: "optics swap".to_string(),
+            },
+            ..Device::default()
+        };
+        let data = borsh::to_vec(&device).unwrap();
+        assert_eq!(Device::try_from(&data[..]).unwrap(), device);
+
+        // Accounts written before the lock existed are unlocked.
+        let legacy = Device::try_from(&data[..data.len() - 44 - "optics swap".len() - 32]).unwrap();
+        assert_eq!(legacy.code, "la");
+        assert_eq!(legacy.ops_lock, OpsLock::default());
+    }
+
+    #[test]
+    fn test_state_device_co_owner() {
+        let co_owner = Pubkey::new_unique();
+        let device = Device {
+            code: "la".to_string(),
+            co_owner_pk: co_owner,
+            ..Device::default()
+        };
+        let data = borsh::to_vec(&device).unwrap();
+        assert_eq!(Device::try_from(&data[..]).unwrap(), device);
+        assert!(device.is_co_owner(&co_owner));
+        assert!(!device.is_co_owner(&Pubkey::new_unique()));
+
+        // Accounts written before the co-owner existed have none, and the default
+        // key never counts as one.
+        let legacy = Device::try_from(&data[..data.len() - 32]).unwrap();
+        assert_eq!(legacy.code, "la");
+        assert_eq!(legacy.co_owner_pk, Pubkey::default());
+        assert!(!legacy.is_co_owner(&Pubkey::default()));
+    }
+
+    #[test]
+    fn test_state_device_rejects_mismatched_trailing_interfaces() {
+        let mut device = Device {
+            code: "la".to_string(),
+            ..Device::default()
+        };
+        device.push_interface(Interface {
+            name: "Ethernet1".to_string(),
+            ..Interface::default()
+        });
+        let data = borsh::to_vec(&device).unwrap();
+
+        // Replace the trailing vec with one holding an extra interface.
+        let trailing = borsh::to_vec(&device.interfaces).unwrap();
+        // The config generations, metrics publisher rotation, attestation, ops lock
+        // and co-owner fields follow it.
+        let scalars = 16 + 40 + 64 + 44 + 32;
+        let mut corrupt = data[..data.len() - trailing.len() - scalars].to_vec();
+        let mut interfaces = device.interfaces.clone();
+        interfaces.push(interfaces[0].clone());
+        corrupt.extend(borsh::to_vec(&interfaces).unwrap());
+        corrupt.extend(vec![0; scalars]);
+
+        assert_eq!(
+            Device::try_from(&corrupt[..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
     #[test]
     fn test_state_device_try_from_defaults() {
         let data = [AccountType::Device as u8];
@@ -849,6 +1115,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert_eq!(err.unwrap_err(), DoubleZeroError::InvalidAccountType);
@@ -885,6 +1159,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert_eq!(err.unwrap_err(), DoubleZeroError::CodeTooLong);
@@ -921,6 +1203,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         assert!(valid.validate().is_ok());
 
@@ -984,6 +1274,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert_eq!(err.unwrap_err(), DoubleZeroError::InvalidLocation);
@@ -1020,6 +1318,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -1057,6 +1363,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert_eq!(err.unwrap_err(), DoubleZeroError::InvalidPublicIp);
@@ -1093,6 +1407,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert_eq!(err.unwrap_err(), DoubleZeroError::InvalidDzPrefix);
@@ -1129,6 +1451,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         // max_users == 0 means "locked", so validation should still succeed
         val.validate().unwrap();
@@ -1167,6 +1497,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 2,
             max_multicast_publishers: 1,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
 
         assert!(val.validate().is_ok());
@@ -1203,6 +1541,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -1388,6 +1734,14 @@ mod tests {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
 
         let oldsize = size_of_pre_dzd_metadata_device(val.code.len(), val.dz_prefixes.len());
@@ -1439,6 +1793,14 @@ mod test_device_validate {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         };
         assert!(device.validate().is_ok());
     }
@@ -1478,6 +1840,14 @@ mod test_device_validate_errors {
             reserved_seats: 0,
             multicast_publishers_count: 0,
             max_multicast_publishers: 0,
+            config_generation: 0,
+            applied_config_generation: 0,
+            previous_metrics_publisher_pk: Pubkey::default(),
+            metrics_publisher_overlap_until_epoch: 0,
+            attestation_challenge: [0; 32],
+            attested_key: Pubkey::default(),
+            ops_lock: OpsLock::default(),
+            co_owner_pk: Pubkey::default(),
         }
     }
 
@@ -1868,12 +2238,16 @@ mod test_device_interfaces_vec {
         new_trailing.extend_from_slice(&normal_second_bytes);
 
         // Compute the offset of the trailing vec in the original bytes: it equals
-        // the original byte length minus the original trailing vec size.
+        // the original byte length minus the original trailing vec size and the
+        // config generations, metrics publisher rotation, attestation, ops lock and
+        // co-owner fields that follow it.
         let original_trailing_len = 4 + normal_first_bytes.len() + normal_second_bytes.len();
-        let prefix_len = bytes.len() - original_trailing_len;
-        let mut forged_bytes = Vec::with_capacity(prefix_len + new_trailing.len());
+        let generations_start = bytes.len() - 16 - 40 - 64 - 44 - 32;
+        let prefix_len = generations_start - original_trailing_len;
+        let mut forged_bytes = Vec::with_capacity(bytes.len() + extra.len());
         forged_bytes.extend_from_slice(&bytes[..prefix_len]);
         forged_bytes.extend_from_slice(&new_trailing);
+        forged_bytes.extend_from_slice(&bytes[generations_start..]);
 
         let decoded = Device::try_from(&forged_bytes[..]).unwrap();
         assert_eq!(decoded.interfaces.len(), 2);
@@ -11,6 +11,7 @@ pub const BGP_COMMUNITY_MAX: u16 = 10999;
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ExchangeStatus {
     PendingDeprecated = 0, // deprecated; unreachable for new accounts
     #[default]
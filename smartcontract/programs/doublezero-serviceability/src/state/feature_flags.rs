@@ -9,6 +9,12 @@ pub enum FeatureFlag {
     /// When set, all instructions require a Permission account for authorization.
     /// The legacy GlobalState allowlist/authority fallback is disabled.
     RequirePermissionAccounts = 1,
+    /// When set, the `ip_net` of CYOA and DIA interfaces must fall within a verified
+    /// `IpAllocation` of the device's contributor.
+    RequireIpAllocations = 2,
+    /// When set, new devices start in `DeviceProvisioning` and a device can only move to
+    /// `Activated` once its metrics publisher has answered a challenge with `ProveDeviceKey`.
+    RequireDeviceAttestation = 3,
 }
 
 impl FeatureFlag {
@@ -16,6 +22,8 @@ impl FeatureFlag {
         &[
             FeatureFlag::OnChainAllocationDeprecated,
             FeatureFlag::RequirePermissionAccounts,
+            FeatureFlag::RequireIpAllocations,
+            FeatureFlag::RequireDeviceAttestation,
         ]
     }
 
@@ -29,6 +37,8 @@ impl fmt::Display for FeatureFlag {
         match self {
             FeatureFlag::OnChainAllocationDeprecated => write!(f, "onchain-allocation-deprecated"),
             FeatureFlag::RequirePermissionAccounts => write!(f, "require-permission-accounts"),
+            FeatureFlag::RequireIpAllocations => write!(f, "require-ip-allocations"),
+            FeatureFlag::RequireDeviceAttestation => write!(f, "require-device-attestation"),
         }
     }
 }
@@ -44,6 +54,8 @@ impl std::str::FromStr for FeatureFlag {
                 Ok(FeatureFlag::OnChainAllocationDeprecated)
             }
             "require-permission-accounts" => Ok(FeatureFlag::RequirePermissionAccounts),
+            "require-ip-allocations" => Ok(FeatureFlag::RequireIpAllocations),
+            "require-device-attestation" => Ok(FeatureFlag::RequireDeviceAttestation),
             _ => Err(format!("unknown feature flag: {s}")),
         }
     }
@@ -97,6 +109,16 @@ mod tests {
                 .unwrap(),
             FeatureFlag::OnChainAllocationDeprecated
         );
+        assert_eq!(
+            "require-ip-allocations".parse::<FeatureFlag>().unwrap(),
+            FeatureFlag::RequireIpAllocations
+        );
+        assert_eq!(FeatureFlag::RequireIpAllocations.to_mask(), 4u128);
+        assert_eq!(
+            "require-device-attestation".parse::<FeatureFlag>().unwrap(),
+            FeatureFlag::RequireDeviceAttestation
+        );
+        assert_eq!(FeatureFlag::RequireDeviceAttestation.to_mask(), 8u128);
         assert!("unknown-flag".parse::<FeatureFlag>().is_err());
     }
 }
@@ -30,6 +30,8 @@ pub struct GlobalState {
     pub qa_allowlist: Vec<Pubkey>,         // 4 + 32 * len
     pub feature_flags: u128,               // 16
     pub feed_authority_pk: Pubkey,         // 32
+    // Bitmask of paused instruction classes, see `pause_flags::PauseFlag`.
+    pub paused: u64, // 8
 }
 
 impl Default for GlobalState {
@@ -49,6 +51,7 @@ impl Default for GlobalState {
             qa_allowlist: Vec::new(),
             feature_flags: 0,
             feed_authority_pk: Pubkey::default(),
+            paused: 0,
         }
     }
 }
@@ -78,7 +81,11 @@ health_oracle_pk: {:?}",
             self.user_airdrop_lamports,
             self.health_oracle_pk,
         )?;
-        write!(f, ", feature_flags: {}", self.feature_flags)
+        write!(
+            f,
+            ", feature_flags: {}, paused: {}",
+            self.feature_flags, self.paused
+        )
     }
 }
 
@@ -102,6 +109,7 @@ impl TryFrom<&[u8]> for GlobalState {
             qa_allowlist: deserialize_vec_with_capacity(&mut data).unwrap_or_default(),
             feature_flags: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
             feed_authority_pk: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            paused: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
         };
 
         if out.account_type != AccountType::GlobalState {
@@ -176,6 +184,7 @@ mod tests {
         assert_eq!(val.user_airdrop_lamports, 0);
         assert_eq!(val.feature_flags, 0);
         assert_eq!(val.feed_authority_pk, Pubkey::default());
+        assert_eq!(val.paused, 0);
     }
 
     #[test]
@@ -195,6 +204,7 @@ mod tests {
             qa_allowlist: vec![Pubkey::new_unique(), Pubkey::new_unique()],
             feature_flags: 1,
             feed_authority_pk: Pubkey::new_unique(),
+            paused: 3,
         };
 
         let data = borsh::to_vec(&val).unwrap();
@@ -225,6 +235,7 @@ mod tests {
         assert_eq!(val.user_airdrop_lamports, val2.user_airdrop_lamports);
         assert_eq!(val.feature_flags, val2.feature_flags);
         assert_eq!(val.feed_authority_pk, val2.feed_authority_pk);
+        assert_eq!(val.paused, val2.paused);
     }
 
     #[test]
@@ -244,6 +255,7 @@ mod tests {
             qa_allowlist: vec![Pubkey::new_unique(), Pubkey::new_unique()],
             feature_flags: 0,
             feed_authority_pk: Pubkey::new_unique(),
+            paused: 0,
         };
         let err = val.validate();
         assert!(err.is_err());
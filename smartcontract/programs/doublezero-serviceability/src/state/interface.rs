@@ -12,6 +12,7 @@ pub const CYOA_DIA_INTERFACE_MTU: u16 = 1500;
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum InterfaceStatus {
     #[default]
     Invalid = 0,
@@ -71,6 +72,7 @@ impl fmt::Display for InterfaceStatus {
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone, Copy, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum InterfaceType {
     #[default]
     Invalid = 0,
@@ -103,6 +105,7 @@ impl fmt::Display for InterfaceType {
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone, Copy, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum LoopbackType {
     #[default]
     None = 0,
@@ -0,0 +1,299 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+/// Maximum number of interfaces tracked for one device.
+pub const MAX_INTERFACE_OPER_STATUS_ENTRIES: usize = 128;
+
+/// Light level value for a transceiver that does not report one (copper ports,
+/// missing optics, or DOM not supported).
+pub const LIGHT_LEVEL_UNAVAILABLE: i32 = i32::MIN;
+
+/// Operational state of an interface as seen on the device, after IF-MIB `ifOperStatus`.
+#[repr(u8)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
+#[borsh(use_discriminant = true)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InterfaceOperState {
+    #[default]
+    Unknown = 0,
+    Up = 1,
+    Down = 2,
+    LowerLayerDown = 3,
+    NotPresent = 4,
+}
+
+impl From<u8> for InterfaceOperState {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => InterfaceOperState::Up,
+            2 => InterfaceOperState::Down,
+            3 => InterfaceOperState::LowerLayerDown,
+            4 => InterfaceOperState::NotPresent,
+            _ => InterfaceOperState::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for InterfaceOperState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterfaceOperState::Up => write!(f, "up"),
+            InterfaceOperState::Down => write!(f, "down"),
+            InterfaceOperState::LowerLayerDown => write!(f, "lower-layer-down"),
+            InterfaceOperState::NotPresent => write!(f, "not-present"),
+            _ => write!(f, "unknown"),
+        }
+    }
+}
+
+impl std::str::FromStr for InterfaceOperState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "unknown" => Ok(InterfaceOperState::Unknown),
+            "up" => Ok(InterfaceOperState::Up),
+            "down" => Ok(InterfaceOperState::Down),
+            "lower-layer-down" => Ok(InterfaceOperState::LowerLayerDown),
+            "not-present" => Ok(InterfaceOperState::NotPresent),
+            _ => Err(format!("invalid interface oper status: {s}")),
+        }
+    }
+}
+
+/// What the device reports for one interface. Light levels are in thousandths of a dBm
+/// (`LIGHT_LEVEL_UNAVAILABLE` when not reported); counters are cumulative since the
+/// device last cleared them. `updated_at` is stamped by the program when the entry is written.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterfaceOperStatusEntry {
+    pub name: String,                    // 4 + len, matches a device interface
+    pub oper_status: InterfaceOperState, // 1
+    pub rx_power_mdbm: i32,              // 4
+    pub tx_power_mdbm: i32,              // 4
+    pub in_errors: u64,                  // 8
+    pub out_errors: u64,                 // 8
+    pub in_discards: u64,                // 8
+    pub out_discards: u64,               // 8
+    pub updated_at: i64,                 // 8, unix timestamp
+}
+
+impl InterfaceOperStatusEntry {
+    pub fn rx_power_dbm(&self) -> Option<f64> {
+        light_level_dbm(self.rx_power_mdbm)
+    }
+
+    pub fn tx_power_dbm(&self) -> Option<f64> {
+        light_level_dbm(self.tx_power_mdbm)
+    }
+}
+
+fn light_level_dbm(mdbm: i32) -> Option<f64> {
+    (mdbm != LIGHT_LEVEL_UNAVAILABLE).then(|| mdbm as f64 / 1000.0)
+}
+
+/// Operational status of a device's interfaces, written by the health oracle on a schedule.
+///
+/// The device account records how interfaces are configured; this sidecar records what the
+/// device actually reports, so the controller and CLI can tell "configured up" from "actually
+/// up" without logging into the DZD. The PDA is derived from the device. A device has more
+/// interfaces than fit in one transaction, so each write replaces only the entries it
+/// carries, matched by name. `device_pk` is immutable.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterfaceOperStatus {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub owner: Pubkey, // 32, last writer
+    pub bump_seed: u8,             // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub device_pk: Pubkey, // 32 (PDA seed, immutable)
+    pub updated_at: i64,           // 8, unix timestamp of the last write
+    pub interfaces: Vec<InterfaceOperStatusEntry>, // 4 + n * entry
+}
+
+impl InterfaceOperStatus {
+    pub fn find(&self, name: &str) -> Option<&InterfaceOperStatusEntry> {
+        self.interfaces
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for InterfaceOperStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, owner: {}, bump_seed: {}, device_pk: {}, updated_at: {}, interfaces: {}",
+            self.account_type,
+            self.owner,
+            self.bump_seed,
+            self.device_pk,
+            self.updated_at,
+            self.interfaces.len()
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for InterfaceOperStatus {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            owner: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            device_pk: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            updated_at: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            interfaces: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::InterfaceOperStatus {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for InterfaceOperStatus {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!(
+                "Failed to deserialize InterfaceOperStatus: {:?}",
+                res.as_ref().err()
+            );
+        }
+        res
+    }
+}
+
+impl Validate for InterfaceOperStatus {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::InterfaceOperStatus {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if self.interfaces.len() > MAX_INTERFACE_OPER_STATUS_ENTRIES {
+            msg!(
+                "Too many interfaces: {} > {}",
+                self.interfaces.len(),
+                MAX_INTERFACE_OPER_STATUS_ENTRIES
+            );
+            return Err(DoubleZeroError::InvalidInterfaceOperStatus);
+        }
+        for (i, entry) in self.interfaces.iter().enumerate() {
+            if self.interfaces[..i]
+                .iter()
+                .any(|other| other.name.eq_ignore_ascii_case(&entry.name))
+            {
+                msg!("Interface {} is reported twice", entry.name);
+                return Err(DoubleZeroError::InvalidInterfaceOperStatus);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str) -> InterfaceOperStatusEntry {
+        InterfaceOperStatusEntry {
+            name: name.to_string(),
+            oper_status: InterfaceOperState::Up,
+            rx_power_mdbm: -2_350,
+            tx_power_mdbm: LIGHT_LEVEL_UNAVAILABLE,
+            in_errors: 3,
+            out_errors: 0,
+            in_discards: 12,
+            out_discards: 1,
+            updated_at: 1_760_000_000,
+        }
+    }
+
+    fn oper_status() -> InterfaceOperStatus {
+        InterfaceOperStatus {
+            account_type: AccountType::InterfaceOperStatus,
+            owner: Pubkey::new_unique(),
+            bump_seed: 254,
+            device_pk: Pubkey::new_unique(),
+            updated_at: 1_760_000_000,
+            interfaces: vec![entry("Ethernet1/1"), entry("Loopback255")],
+        }
+    }
+
+    #[test]
+    fn test_interface_oper_status_serialization_roundtrip() {
+        let val = oper_status();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = InterfaceOperStatus::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_interface_oper_status_validate() {
+        let mut val = oper_status();
+        val.interfaces.push(entry("ethernet1/1"));
+        assert_eq!(
+            val.validate(),
+            Err(DoubleZeroError::InvalidInterfaceOperStatus)
+        );
+
+        let mut val = oper_status();
+        val.interfaces = (0..=MAX_INTERFACE_OPER_STATUS_ENTRIES)
+            .map(|i| entry(&format!("Ethernet{i}")))
+            .collect();
+        assert_eq!(
+            val.validate(),
+            Err(DoubleZeroError::InvalidInterfaceOperStatus)
+        );
+
+        let mut val = oper_status();
+        val.interfaces.clear();
+        assert_eq!(val.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_interface_oper_status_entry_light_levels() {
+        let val = oper_status();
+        let entry = val.find("ethernet1/1").unwrap();
+        assert_eq!(entry.rx_power_dbm(), Some(-2.35));
+        assert_eq!(entry.tx_power_dbm(), None);
+        assert!(val.find("Ethernet9").is_none());
+    }
+
+    #[test]
+    fn test_interface_oper_status_wrong_account_type_rejected() {
+        let mut val = oper_status();
+        val.account_type = AccountType::IpAllocation;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(InterfaceOperStatus::try_from(&data[..]).is_err());
+    }
+}
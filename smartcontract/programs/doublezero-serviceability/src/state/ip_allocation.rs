@@ -0,0 +1,282 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    helper::is_global,
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use doublezero_program_common::types::NetworkV4;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+/// Maximum `upstream` length.
+pub const MAX_IP_ALLOCATION_UPSTREAM_LEN: usize = 64;
+
+#[repr(u8)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
+#[borsh(use_discriminant = true)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum IpAllocationStatus {
+    #[default]
+    None = 0,
+    Pending = 1,
+    Verified = 2,
+    Rejected = 3,
+}
+
+impl From<u8> for IpAllocationStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => IpAllocationStatus::Pending,
+            2 => IpAllocationStatus::Verified,
+            3 => IpAllocationStatus::Rejected,
+            _ => IpAllocationStatus::None,
+        }
+    }
+}
+
+impl fmt::Display for IpAllocationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpAllocationStatus::None => write!(f, "none"),
+            IpAllocationStatus::Pending => write!(f, "pending"),
+            IpAllocationStatus::Verified => write!(f, "verified"),
+            IpAllocationStatus::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+impl std::str::FromStr for IpAllocationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pending" => Ok(IpAllocationStatus::Pending),
+            "verified" => Ok(IpAllocationStatus::Verified),
+            "rejected" => Ok(IpAllocationStatus::Rejected),
+            _ => Err(format!("invalid ip allocation status: {s}")),
+        }
+    }
+}
+
+/// A public prefix from an upstream provider's address space that a contributor has registered
+/// for its CYOA and DIA interfaces.
+///
+/// Contributors register a prefix as `Pending`; the foundation marks it `Verified` once ownership
+/// has been checked (e.g. against the upstream's letter of authorization), or `Rejected`. The PDA
+/// is derived from the prefix, so a prefix can be registered by one contributor only. `prefix`
+/// and `contributor_pk` are immutable.
+///
+/// When `FeatureFlag::RequireIpAllocations` is set, the `ip_net` of a CYOA or DIA interface must
+/// fall within a verified allocation of the device's contributor, so devices never announce space
+/// the contributor has not shown it owns.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpAllocation {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub owner: Pubkey, // 32
+    pub bump_seed: u8,             // 1
+    pub status: IpAllocationStatus, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub contributor_pk: Pubkey, // 32 (immutable)
+    pub prefix: NetworkV4,         // 5 (PDA seed, immutable)
+    pub upstream: String,          // 4 + len - provider the block was assigned by
+}
+
+impl IpAllocation {
+    /// Whether `ip_net` may be assigned to an interface of a device owned by `contributor_pk`.
+    pub fn covers(&self, contributor_pk: &Pubkey, ip_net: &NetworkV4) -> bool {
+        self.status == IpAllocationStatus::Verified
+            && self.contributor_pk == *contributor_pk
+            && ip_net.is_subnet_of(&self.prefix)
+    }
+}
+
+/// Whether `prefix` is written as its network address, e.g. `45.76.12.0/24` rather than
+/// `45.76.12.7/24`. The PDA is derived from the prefix as written, so this keeps one
+/// block from being registered under several addresses.
+pub fn is_network_address(prefix: &NetworkV4) -> bool {
+    let mask = u32::MAX
+        .checked_shl(32 - prefix.prefix() as u32)
+        .unwrap_or(0);
+    u32::from(prefix.ip()) & !mask == 0
+}
+
+impl fmt::Display for IpAllocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, owner: {}, bump_seed: {}, status: {}, contributor_pk: {}, prefix: {}, upstream: {}",
+            self.account_type,
+            self.owner,
+            self.bump_seed,
+            self.status,
+            self.contributor_pk,
+            self.prefix,
+            self.upstream
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for IpAllocation {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            owner: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            status: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            contributor_pk: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            prefix: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            upstream: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::IpAllocation {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for IpAllocation {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!(
+                "Failed to deserialize IpAllocation: {:?}",
+                res.as_ref().err()
+            );
+        }
+        res
+    }
+}
+
+impl Validate for IpAllocation {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::IpAllocation {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if !is_network_address(&self.prefix) {
+            msg!(
+                "Prefix must be written as its network address: {}",
+                self.prefix
+            );
+            return Err(DoubleZeroError::InvalidIpAllocation);
+        }
+        if !is_global(self.prefix.ip()) {
+            msg!("Prefix must be publicly routable: {}", self.prefix);
+            return Err(DoubleZeroError::InvalidIpAllocation);
+        }
+        if self.upstream.len() > MAX_IP_ALLOCATION_UPSTREAM_LEN {
+            msg!(
+                "Upstream too long: {} > {}",
+                self.upstream.len(),
+                MAX_IP_ALLOCATION_UPSTREAM_LEN
+            );
+            return Err(DoubleZeroError::NameTooLong);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip_allocation() -> IpAllocation {
+        IpAllocation {
+            account_type: AccountType::IpAllocation,
+            owner: Pubkey::new_unique(),
+            bump_seed: 253,
+            status: IpAllocationStatus::Verified,
+            contributor_pk: Pubkey::new_unique(),
+            prefix: "45.76.12.0/24".parse().unwrap(),
+            upstream: "AS64500".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ip_allocation_serialization_roundtrip() {
+        let val = ip_allocation();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = IpAllocation::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_ip_allocation_validate() {
+        let mut val = ip_allocation();
+        val.prefix = "45.76.12.7/24".parse().unwrap();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidIpAllocation));
+
+        let mut val = ip_allocation();
+        val.prefix = "10.0.0.0/8".parse().unwrap();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidIpAllocation));
+
+        let mut val = ip_allocation();
+        val.prefix = "0.0.0.0/0".parse().unwrap();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidIpAllocation));
+
+        let mut val = ip_allocation();
+        val.upstream = "x".repeat(MAX_IP_ALLOCATION_UPSTREAM_LEN + 1);
+        assert_eq!(val.validate(), Err(DoubleZeroError::NameTooLong));
+
+        let mut val = ip_allocation();
+        val.prefix = "185.199.108.9/32".parse().unwrap();
+        assert_eq!(val.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_ip_allocation_covers() {
+        let val = ip_allocation();
+        let contributor = val.contributor_pk;
+
+        assert!(val.covers(&contributor, &"45.76.12.9/30".parse().unwrap()));
+        assert!(val.covers(&contributor, &"45.76.12.0/24".parse().unwrap()));
+        assert!(!val.covers(&contributor, &"45.76.12.0/23".parse().unwrap()));
+        assert!(!val.covers(&contributor, &"185.199.108.1/30".parse().unwrap()));
+        assert!(!val.covers(&Pubkey::new_unique(), &"45.76.12.9/30".parse().unwrap()));
+
+        for status in [
+            IpAllocationStatus::Pending,
+            IpAllocationStatus::Rejected,
+            IpAllocationStatus::None,
+        ] {
+            let val = IpAllocation {
+                status,
+                ..ip_allocation()
+            };
+            assert!(!val.covers(&val.contributor_pk, &"45.76.12.9/30".parse().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_ip_allocation_wrong_account_type_rejected() {
+        let mut val = ip_allocation();
+        val.account_type = AccountType::QosProfile;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(IpAllocation::try_from(&data[..]).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     error::{DoubleZeroError, Validate},
-    state::accounttype::AccountType,
+    state::{accounttype::AccountType, ops_lock::OpsLock},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use doublezero_program_common::types::NetworkV4;
@@ -11,6 +11,7 @@ use std::{fmt, str::FromStr};
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum LinkLinkType {
     #[default]
     WAN = 1,
@@ -52,6 +53,7 @@ impl fmt::Display for LinkLinkType {
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum LinkStatus {
     PendingDeprecated = 0, // deprecated; unreachable for new accounts
     #[default]
@@ -266,18 +268,36 @@ pub struct Link {
     pub desired_status: LinkDesiredStatus, // 1
     pub link_topologies: Vec<Pubkey>, // 4 + 32 * len
     pub link_flags: u32,           // 4 — bitmask; see LINK_FLAG_* constants
+    pub flap_count: u32,           // 4 — flaps recorded over the link's lifetime
+    pub flap_penalty: u32,         // 4 — damping penalty as of `flap_epoch`
+    pub flap_epoch: u64,           // 8 — epoch `flap_penalty` was last decayed to
+    pub damped_until_epoch: u64,   // 8 — first epoch the link is no longer damped
+    pub ops_lock: OpsLock,         // 44 + reason len
 }
 
 /// Bit 0 of `link_flags`: link is administratively drained from unicast traffic.
 /// Maps to IS-IS admin-group UNICAST-DRAINED (group 0).
 pub const LINK_FLAG_UNICAST_DRAINED: u32 = 0x01;
 
+/// Bit 1 of `link_flags`: link is suppressed by flap damping. Kept in step with
+/// `damped_until_epoch` each time the health oracle reports the link's health.
+pub const LINK_FLAG_FLAP_DAMPED: u32 = 0x02;
+
+/// Penalty added each time a link flaps (drops from ready-for-service to impaired).
+pub const LINK_FLAP_PENALTY: u32 = 1000;
+/// A flap that takes the penalty above this damps the link.
+pub const LINK_FLAP_SUPPRESS_THRESHOLD: u32 = 2000;
+/// A damped link is released once its penalty has decayed to this.
+pub const LINK_FLAP_REUSE_THRESHOLD: u32 = 750;
+/// Penalty ceiling, which bounds how long a link stays damped (3 epochs).
+pub const LINK_FLAP_MAX_PENALTY: u32 = 6000;
+
 impl fmt::Display for Link {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "account_type: {}, owner: {}, index: {}, side_a_pk: {}, side_z_pk: {}, tunnel_type: {}, bandwidth: {}, mtu: {}, delay_ns: {}, jitter_ns: {}, tunnel_id: {}, tunnel_net: {}, status: {}, code: {}, contributor_pk: {}, link_health: {}, desired_status: {}, link_topologies: {:?}, link_flags: {:#010x}",
-            self.account_type, self.owner, self.index, self.side_a_pk, self.side_z_pk, self.link_type, self.bandwidth, self.mtu, self.delay_ns, self.jitter_ns, self.tunnel_id, &self.tunnel_net, self.status, self.code, self.contributor_pk, self.link_health, self.desired_status, self.link_topologies, self.link_flags
+            "account_type: {}, owner: {}, index: {}, side_a_pk: {}, side_z_pk: {}, tunnel_type: {}, bandwidth: {}, mtu: {}, delay_ns: {}, jitter_ns: {}, tunnel_id: {}, tunnel_net: {}, status: {}, code: {}, contributor_pk: {}, link_health: {}, desired_status: {}, link_topologies: {:?}, link_flags: {:#010x}, flap_count: {}, flap_penalty: {}, flap_epoch: {}, damped_until_epoch: {}, ops_lock: {}",
+            self.account_type, self.owner, self.index, self.side_a_pk, self.side_z_pk, self.link_type, self.bandwidth, self.mtu, self.delay_ns, self.jitter_ns, self.tunnel_id, &self.tunnel_net, self.status, self.code, self.contributor_pk, self.link_health, self.desired_status, self.link_topologies, self.link_flags, self.flap_count, self.flap_penalty, self.flap_epoch, self.damped_until_epoch, self.ops_lock
         )
     }
 }
@@ -308,6 +328,11 @@ impl Default for Link {
             desired_status: LinkDesiredStatus::Pending,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         }
     }
 }
@@ -340,6 +365,11 @@ impl TryFrom<&[u8]> for Link {
             desired_status: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
             link_topologies: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
             link_flags: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            flap_count: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            flap_penalty: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            flap_epoch: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            damped_until_epoch: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            ops_lock: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
         };
 
         if out.account_type != AccountType::Link {
@@ -428,6 +458,52 @@ impl Link {
         self.link_flags & LINK_FLAG_UNICAST_DRAINED != 0
     }
 
+    pub fn is_flap_damped(&self) -> bool {
+        self.link_flags & LINK_FLAG_FLAP_DAMPED != 0
+    }
+
+    /// Updates the flap damping state after a health report, in the manner of BGP
+    /// route-flap damping (RFC 2439) with a half-life of one epoch.
+    ///
+    /// The penalty is halved for every epoch since it was last updated. A drop from
+    /// `ReadyForService` to `Impaired` counts as a flap and adds [`LINK_FLAP_PENALTY`];
+    /// if that takes the penalty above [`LINK_FLAP_SUPPRESS_THRESHOLD`], or the link
+    /// is already damped, the link is damped until the epoch in which the penalty
+    /// will have decayed to [`LINK_FLAP_REUSE_THRESHOLD`]. [`LINK_FLAG_FLAP_DAMPED`]
+    /// is then set while `epoch` is before `damped_until_epoch`.
+    pub fn update_flap_damping(&mut self, previous_health: LinkHealth, epoch: u64) {
+        let elapsed = epoch.saturating_sub(self.flap_epoch);
+        self.flap_penalty = if elapsed >= u32::BITS as u64 {
+            0
+        } else {
+            self.flap_penalty >> elapsed
+        };
+        self.flap_epoch = epoch;
+
+        if previous_health == LinkHealth::ReadyForService
+            && self.link_health == LinkHealth::Impaired
+        {
+            self.flap_count = self.flap_count.saturating_add(1);
+            self.flap_penalty = (self.flap_penalty + LINK_FLAP_PENALTY).min(LINK_FLAP_MAX_PENALTY);
+
+            if self.flap_penalty > LINK_FLAP_SUPPRESS_THRESHOLD || epoch < self.damped_until_epoch {
+                let mut penalty = self.flap_penalty;
+                let mut until = epoch;
+                while penalty > LINK_FLAP_REUSE_THRESHOLD {
+                    penalty >>= 1;
+                    until += 1;
+                }
+                self.damped_until_epoch = self.damped_until_epoch.max(until);
+            }
+        }
+
+        if epoch < self.damped_until_epoch {
+            self.link_flags |= LINK_FLAG_FLAP_DAMPED;
+        } else {
+            self.link_flags &= !LINK_FLAG_FLAP_DAMPED;
+        }
+    }
+
     /// Checks and updates the `status` of the `Link` based on its current `status`, `desired_status`, and `link_health`.
     ///
     /// The transition logic is as follows:
@@ -570,6 +646,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
 
         let data = borsh::to_vec(&val).unwrap();
@@ -625,6 +706,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -657,6 +743,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -689,6 +780,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -721,6 +817,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err_low = val_low.validate();
         assert!(err_low.is_err());
@@ -761,6 +862,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err_low = val_low.validate();
         assert!(err_low.is_err());
@@ -802,6 +908,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
 
         let err = val.validate();
@@ -835,6 +946,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err_low = val_low.validate();
         assert!(err_low.is_err());
@@ -875,6 +991,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         let err_low = val_low.validate();
         assert!(err_low.is_err());
@@ -889,6 +1010,82 @@ mod tests {
         assert_eq!(err_high.unwrap_err(), DoubleZeroError::InvalidDelay);
     }
 
+    #[test]
+    fn test_link_flap_damping() {
+        let mut link = Link {
+            link_health: LinkHealth::ReadyForService,
+            ..Link::default()
+        };
+
+        let flap = |link: &mut Link, epoch: u64| {
+            link.link_health = LinkHealth::Impaired;
+            link.update_flap_damping(LinkHealth::ReadyForService, epoch);
+            link.link_health = LinkHealth::ReadyForService;
+            link.update_flap_damping(LinkHealth::Impaired, epoch);
+        };
+
+        // Two flaps in one epoch stay at the suppress threshold.
+        flap(&mut link, 100);
+        flap(&mut link, 100);
+        assert_eq!(link.flap_count, 2);
+        assert_eq!(link.flap_penalty, 2000);
+        assert!(!link.is_flap_damped());
+        assert_eq!(link.damped_until_epoch, 0);
+
+        // The third crosses it: 3000 decays to 750 after two epochs.
+        flap(&mut link, 100);
+        assert_eq!(link.flap_penalty, 3000);
+        assert!(link.is_flap_damped());
+        assert_eq!(link.damped_until_epoch, 102);
+
+        // A stable report in a later epoch decays the penalty but keeps the damping.
+        link.update_flap_damping(LinkHealth::ReadyForService, 101);
+        assert_eq!(link.flap_penalty, 1500);
+        assert!(link.is_flap_damped());
+
+        // A flap while damped extends the damping even below the threshold.
+        flap(&mut link, 101);
+        assert_eq!(link.flap_penalty, 2500);
+        assert_eq!(link.damped_until_epoch, 103);
+
+        link.update_flap_damping(LinkHealth::ReadyForService, 103);
+        assert_eq!(link.flap_penalty, 625);
+        assert!(!link.is_flap_damped());
+
+        // The penalty is capped, bounding the damping period.
+        for _ in 0..10 {
+            flap(&mut link, 200);
+        }
+        assert_eq!(link.flap_penalty, LINK_FLAP_MAX_PENALTY);
+        assert_eq!(link.damped_until_epoch, 203);
+        assert_eq!(link.flap_count, 14);
+
+        // Long-idle accounts decay to zero.
+        link.update_flap_damping(LinkHealth::ReadyForService, 10_000);
+        assert_eq!(link.flap_penalty, 0);
+        assert!(!link.is_flap_damped());
+    }
+
+    #[test]
+    fn test_state_link_ops_lock() {
+        let link = Link {
+            code: "la-ny".to_string(),
+            ops_lock: OpsLock {
+                locked_by: Pubkey::new_unique(),
+                expires_at: 1_700_000_000,
+                reason: "OTDR trace".to_string(),
+            },
+            ..Link::default()
+        };
+        let data = borsh::to_vec(&link).unwrap();
+        assert_eq!(Link::try_from(&data[..]).unwrap(), link);
+
+        // Accounts written before the lock existed are unlocked.
+        let legacy = Link::try_from(&data[..data.len() - 44 - "OTDR trace".len()]).unwrap();
+        assert_eq!(legacy.code, "la-ny");
+        assert_eq!(legacy.ops_lock, OpsLock::default());
+    }
+
     #[test]
     fn test_delete_invalid_link() {
         let bad_link = Link {
@@ -915,6 +1112,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: Vec::new(),
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: OpsLock::default(),
         };
         assert!(bad_link.validate().is_ok());
     }
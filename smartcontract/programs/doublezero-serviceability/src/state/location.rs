@@ -10,6 +10,7 @@ use std::fmt;
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum LocationStatus {
     PendingDeprecated = 0, // deprecated; unreachable for new accounts
     #[default]
@@ -1,6 +1,7 @@
 pub mod accesspass;
 pub mod accountdata;
 pub mod accounttype;
+pub mod anycast_prefix;
 pub mod contributor;
 pub mod device;
 pub mod exchange;
@@ -10,12 +11,20 @@ pub mod globalconfig;
 pub mod globalstate;
 pub mod index;
 pub mod interface;
+pub mod interface_oper_status;
+pub mod ip_allocation;
 pub mod link;
 pub mod location;
 pub mod multicastgroup;
+pub mod ops_lock;
+pub mod pause_flags;
 pub mod permission;
 pub mod programconfig;
+pub mod qos_profile;
+pub mod read_api_key;
+pub mod referrer_stats;
 pub mod resource_extension;
+pub mod state_commitment;
 pub mod tenant;
 pub mod topology;
 pub mod user;
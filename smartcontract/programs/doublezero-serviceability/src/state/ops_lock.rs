@@ -0,0 +1,200 @@
+use crate::error::DoubleZeroError;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    clock::Clock, msg, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+use std::fmt;
+
+/// Longest reason a lock may carry, in bytes.
+pub const MAX_OPS_LOCK_REASON_LEN: usize = 64;
+
+/// Longest a lock may be taken or extended for at once: seven days.
+pub const MAX_OPS_LOCK_DURATION_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Change freeze on a Device or Link, set by its contributor or the foundation
+/// so operators can troubleshoot without the entity changing under them.
+///
+/// While the lock is live, the entity's mutating instructions are rejected
+/// unless signed by `locked_by`. The lock lapses on its own at `expires_at`,
+/// at most [`MAX_OPS_LOCK_DURATION_SECS`] after it was set, so a forgotten lock
+/// cannot freeze an entity forever.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpsLock {
+    /// Signer holding the lock, `Pubkey::default()` when unlocked.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub locked_by: Pubkey, // 32
+    /// Unix timestamp at which the lock lapses.
+    pub expires_at: i64, // 8
+    pub reason: String, // 4 + len
+}
+
+impl fmt::Display for OpsLock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.locked_by == Pubkey::default() {
+            return write!(f, "unlocked");
+        }
+        write!(
+            f,
+            "locked by {} until {}: {}",
+            self.locked_by, self.expires_at, self.reason
+        )
+    }
+}
+
+impl OpsLock {
+    /// Whether the lock is held at unix time `now`.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.locked_by != Pubkey::default() && now < self.expires_at
+    }
+
+    /// Whether `signer` may change the entity at unix time `now`: the lock is
+    /// not held, or `signer` holds it.
+    pub fn allows(&self, signer: &Pubkey, now: i64) -> bool {
+        !self.is_active(now) || self.locked_by == *signer
+    }
+
+    /// Take, extend or release (`expires_at == 0`) the lock on behalf of
+    /// `signer`. A live lock held by someone else can only be replaced or
+    /// released by a privileged caller.
+    pub fn set(
+        &mut self,
+        signer: &Pubkey,
+        is_privileged: bool,
+        reason: &str,
+        expires_at: i64,
+        now: i64,
+    ) -> Result<(), DoubleZeroError> {
+        if !is_privileged && !self.allows(signer, now) {
+            return Err(DoubleZeroError::EntityLocked);
+        }
+        if expires_at == 0 {
+            *self = OpsLock::default();
+            return Ok(());
+        }
+        if expires_at <= now
+            || expires_at > now.saturating_add(MAX_OPS_LOCK_DURATION_SECS)
+            || reason.len() > MAX_OPS_LOCK_REASON_LEN
+        {
+            return Err(DoubleZeroError::InvalidOpsLock);
+        }
+        *self = OpsLock {
+            locked_by: *signer,
+            expires_at,
+            reason: reason.to_string(),
+        };
+        Ok(())
+    }
+}
+
+/// Reject a mutating instruction on a locked entity unless `signer` holds the
+/// lock.
+pub fn check_ops_lock(lock: &OpsLock, signer: &Pubkey, entity: &str) -> Result<(), ProgramError> {
+    let now = Clock::get()?.unix_timestamp;
+    if !lock.allows(signer, now) {
+        msg!(
+            "{} is locked by {} until {}: {}",
+            entity,
+            lock.locked_by,
+            lock.expires_at,
+            lock.reason
+        );
+        return Err(DoubleZeroError::EntityLocked.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops_lock_expiry_and_holder() {
+        let holder = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let lock = OpsLock {
+            locked_by: holder,
+            expires_at: 1_000,
+            reason: "fiber cut on side A".to_string(),
+        };
+
+        assert!(lock.is_active(999));
+        assert!(lock.allows(&holder, 999));
+        assert!(!lock.allows(&other, 999));
+
+        // The lock lapses at its expiry.
+        assert!(!lock.is_active(1_000));
+        assert!(lock.allows(&other, 1_000));
+
+        // An unset lock never blocks.
+        assert!(!OpsLock::default().is_active(0));
+        assert!(OpsLock::default().allows(&other, i64::MIN));
+    }
+
+    #[test]
+    fn test_ops_lock_set() {
+        let holder = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let mut lock = OpsLock::default();
+
+        assert_eq!(
+            lock.set(&holder, false, "x", 100, 100),
+            Err(DoubleZeroError::InvalidOpsLock)
+        );
+        assert_eq!(
+            lock.set(&holder, false, &"x".repeat(65), 200, 100),
+            Err(DoubleZeroError::InvalidOpsLock)
+        );
+        assert_eq!(
+            lock.set(
+                &holder,
+                false,
+                "x",
+                100 + MAX_OPS_LOCK_DURATION_SECS + 1,
+                100
+            ),
+            Err(DoubleZeroError::InvalidOpsLock)
+        );
+        assert_eq!(
+            lock.set(&holder, true, "x", i64::MAX, 100),
+            Err(DoubleZeroError::InvalidOpsLock)
+        );
+        lock.set(&holder, false, "x", 100 + MAX_OPS_LOCK_DURATION_SECS, 100)
+            .unwrap();
+        lock.set(&holder, false, "fiber cut", 200, 100).unwrap();
+        assert_eq!(lock.locked_by, holder);
+
+        // Someone else can neither take nor release a live lock...
+        assert_eq!(
+            lock.set(&other, false, "mine", 300, 150),
+            Err(DoubleZeroError::EntityLocked)
+        );
+        assert_eq!(
+            lock.set(&other, false, "", 0, 150),
+            Err(DoubleZeroError::EntityLocked)
+        );
+        // ...unless privileged, or once it has lapsed.
+        lock.set(&other, false, "mine", 300, 200).unwrap();
+        assert_eq!(lock.locked_by, other);
+        lock.set(&holder, true, "", 0, 250).unwrap();
+        assert_eq!(lock, OpsLock::default());
+    }
+
+    #[test]
+    fn test_ops_lock_roundtrip() {
+        let lock = OpsLock {
+            locked_by: Pubkey::new_unique(),
+            expires_at: 1_700_000_000,
+            reason: "maintenance".to_string(),
+        };
+        let data = borsh::to_vec(&lock).unwrap();
+        assert_eq!(data.len(), 32 + 8 + 4 + "maintenance".len());
+        assert_eq!(OpsLock::try_from_slice(&data).unwrap(), lock);
+    }
+}
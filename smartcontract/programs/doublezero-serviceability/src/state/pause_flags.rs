@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Classes of mutating instructions that can be paused through `GlobalState::paused`.
+/// Governance instructions (authorities, allowlists, permissions, feature flags and
+/// `SetPause` itself) are never paused, so the foundation can always lift the brake.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseFlag {
+    /// Instructions that create accounts or allocate resources.
+    Create = 0,
+    /// Instructions that change existing accounts: updates, activations, status changes.
+    Update = 1,
+    /// Instructions that delete or close accounts, or release resources.
+    Delete = 2,
+}
+
+impl PauseFlag {
+    pub fn all_variants() -> &'static [PauseFlag] {
+        &[PauseFlag::Create, PauseFlag::Update, PauseFlag::Delete]
+    }
+
+    pub fn to_mask(self) -> u64 {
+        1u64 << self as u8
+    }
+
+    /// Mask with every pause class set.
+    pub fn all_mask() -> u64 {
+        Self::all_variants()
+            .iter()
+            .fold(0, |mask, flag| mask | flag.to_mask())
+    }
+}
+
+impl fmt::Display for PauseFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauseFlag::Create => write!(f, "create"),
+            PauseFlag::Update => write!(f, "update"),
+            PauseFlag::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl std::str::FromStr for PauseFlag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(PauseFlag::Create),
+            "update" => Ok(PauseFlag::Update),
+            "delete" => Ok(PauseFlag::Delete),
+            _ => Err(format!("unknown pause flag: {s}")),
+        }
+    }
+}
+
+pub fn is_paused(paused: u64, flag: PauseFlag) -> bool {
+    paused & flag.to_mask() != 0
+}
+
+pub fn paused_flags(paused: u64) -> Vec<PauseFlag> {
+    PauseFlag::all_variants()
+        .iter()
+        .filter(|f| is_paused(paused, **f))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_flag_mask_and_helpers() {
+        assert_eq!(PauseFlag::Create.to_mask(), 1);
+        assert_eq!(PauseFlag::Delete.to_mask(), 4);
+        assert_eq!(PauseFlag::all_mask(), 7);
+
+        let paused = PauseFlag::Update.to_mask();
+        assert!(is_paused(paused, PauseFlag::Update));
+        assert!(!is_paused(paused, PauseFlag::Create));
+        assert_eq!(paused_flags(paused), vec![PauseFlag::Update]);
+        assert!(paused_flags(0).is_empty());
+    }
+
+    #[test]
+    fn test_pause_flag_display_and_from_str() {
+        for flag in PauseFlag::all_variants() {
+            assert_eq!(flag.to_string().parse::<PauseFlag>().unwrap(), *flag);
+        }
+        assert!("everything".parse::<PauseFlag>().is_err());
+    }
+}
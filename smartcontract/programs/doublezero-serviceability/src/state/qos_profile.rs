@@ -0,0 +1,164 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::{accounttype::AccountType, user::BandwidthTier},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+/// Highest valid DSCP codepoint (6 bits).
+pub const MAX_DSCP: u8 = 63;
+
+/// The tunnel QoS profile applied to users of a bandwidth tier.
+///
+/// There is at most one profile per shaped tier, at the PDA derived from the tier. Device
+/// controllers render `profile_id` as the device-side policy for the tier and attach it to the
+/// tunnel of every user whose `bandwidth_tier` matches; users of a tier without a profile, and
+/// `unshaped` users, are left unshaped. `profile_id` is allocated from the global
+/// `QosProfileIds` resource extension and released when the profile is deleted.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QosProfile {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub owner: Pubkey, // 32
+    pub bump_seed: u8,             // 1
+    pub bandwidth_tier: BandwidthTier, // 1 (PDA seed, immutable)
+    pub profile_id: u16,           // 2 - allocated from QosProfileIds
+    pub rate_mbps: u32,            // 4 - shaping rate per user tunnel
+    pub burst_kbytes: u32,         // 4 - shaper burst size, 0 = device default
+    pub dscp: u8,                  // 1 - DSCP marking for the tunnel's traffic
+}
+
+impl fmt::Display for QosProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, owner: {}, bump_seed: {}, bandwidth_tier: {}, profile_id: {}, rate_mbps: {}, burst_kbytes: {}, dscp: {}",
+            self.account_type,
+            self.owner,
+            self.bump_seed,
+            self.bandwidth_tier,
+            self.profile_id,
+            self.rate_mbps,
+            self.burst_kbytes,
+            self.dscp
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for QosProfile {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            owner: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bandwidth_tier: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            profile_id: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            rate_mbps: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            burst_kbytes: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            dscp: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::QosProfile {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for QosProfile {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!("Failed to deserialize QosProfile: {:?}", res.as_ref().err());
+        }
+        res
+    }
+}
+
+impl Validate for QosProfile {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::QosProfile {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if self.bandwidth_tier == BandwidthTier::Unshaped {
+            msg!("QoS profiles apply to shaped tiers only");
+            return Err(DoubleZeroError::InvalidQosProfile);
+        }
+        if self.rate_mbps == 0 {
+            msg!("Invalid rate_mbps: 0");
+            return Err(DoubleZeroError::InvalidQosProfile);
+        }
+        if self.dscp > MAX_DSCP {
+            msg!("Invalid dscp: {}", self.dscp);
+            return Err(DoubleZeroError::InvalidQosProfile);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn qos_profile() -> QosProfile {
+        QosProfile {
+            account_type: AccountType::QosProfile,
+            owner: Pubkey::new_unique(),
+            bump_seed: 254,
+            bandwidth_tier: BandwidthTier::Premium,
+            profile_id: 3,
+            rate_mbps: 10_000,
+            burst_kbytes: 512,
+            dscp: 46,
+        }
+    }
+
+    #[test]
+    fn test_qos_profile_serialization_roundtrip() {
+        let val = qos_profile();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = QosProfile::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_qos_profile_validate() {
+        let mut val = qos_profile();
+        val.bandwidth_tier = BandwidthTier::Unshaped;
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidQosProfile));
+
+        let mut val = qos_profile();
+        val.rate_mbps = 0;
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidQosProfile));
+
+        let mut val = qos_profile();
+        val.dscp = MAX_DSCP + 1;
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidQosProfile));
+    }
+
+    #[test]
+    fn test_qos_profile_wrong_account_type_rejected() {
+        let mut val = qos_profile();
+        val.account_type = AccountType::ReadApiKey;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(QosProfile::try_from(&data[..]).is_err());
+    }
+}
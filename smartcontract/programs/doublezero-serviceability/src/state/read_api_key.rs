@@ -0,0 +1,204 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+#[repr(u8)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
+#[borsh(use_discriminant = true)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ReadApiKeyStatus {
+    #[default]
+    None = 0,
+    Activated = 1,
+    Revoked = 2,
+}
+
+impl From<u8> for ReadApiKeyStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ReadApiKeyStatus::None,
+            1 => ReadApiKeyStatus::Activated,
+            2 => ReadApiKeyStatus::Revoked,
+            _ => ReadApiKeyStatus::None,
+        }
+    }
+}
+
+impl fmt::Display for ReadApiKeyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadApiKeyStatus::None => write!(f, "none"),
+            ReadApiKeyStatus::Activated => write!(f, "activated"),
+            ReadApiKeyStatus::Revoked => write!(f, "revoked"),
+        }
+    }
+}
+
+/// A read-API key registered by a contributor for the off-chain indexer and controller REST
+/// services.
+///
+/// Only the SHA-256 hash of the key is stored. A service authorizes a request by hashing the
+/// presented key, deriving the PDA from `(contributor_pk, key_hash)` and checking
+/// [`ReadApiKey::is_valid_at`]. `contributor_pk` and `key_hash` are the PDA seeds and are
+/// immutable.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadApiKey {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub owner: Pubkey, // 32
+    pub bump_seed: u8,             // 1
+    pub status: ReadApiKeyStatus,  // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub contributor_pk: Pubkey, // 32 (PDA seed, immutable)
+    pub key_hash: [u8; 32],        // 32 (PDA seed, immutable) - SHA-256 of the key
+    pub label: String,             // 4 + len
+    pub created_at: i64,           // 8 - unix timestamp
+    pub expires_at: i64,           // 8 - unix timestamp, 0 = never expires
+}
+
+impl ReadApiKey {
+    /// Whether the key is expired at unix timestamp `now`. Keys with `expires_at == 0` never
+    /// expire.
+    pub fn is_expired_at(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+
+    /// Whether a service should accept this key at unix timestamp `now`: it must be activated
+    /// and not expired.
+    pub fn is_valid_at(&self, now: i64) -> bool {
+        self.status == ReadApiKeyStatus::Activated && !self.is_expired_at(now)
+    }
+}
+
+impl fmt::Display for ReadApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, owner: {}, bump_seed: {}, status: {}, contributor_pk: {}, label: {}, created_at: {}, expires_at: {}",
+            self.account_type,
+            self.owner,
+            self.bump_seed,
+            self.status,
+            self.contributor_pk,
+            self.label,
+            self.created_at,
+            self.expires_at
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for ReadApiKey {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            owner: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            status: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            contributor_pk: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            key_hash: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            label: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            created_at: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            expires_at: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::ReadApiKey {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for ReadApiKey {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!("Failed to deserialize ReadApiKey: {:?}", res.as_ref().err());
+        }
+        res
+    }
+}
+
+impl Validate for ReadApiKey {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::ReadApiKey {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_api_key_with(status: ReadApiKeyStatus, expires_at: i64) -> ReadApiKey {
+        ReadApiKey {
+            account_type: AccountType::ReadApiKey,
+            owner: Pubkey::new_unique(),
+            bump_seed: 1,
+            status,
+            contributor_pk: Pubkey::new_unique(),
+            key_hash: [7; 32],
+            label: "indexer".to_string(),
+            created_at: 1_700_000_000,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_read_api_key_serialization_roundtrip() {
+        let val = read_api_key_with(ReadApiKeyStatus::Activated, 1_800_000_000);
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = ReadApiKey::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_read_api_key_validity() {
+        let key = read_api_key_with(ReadApiKeyStatus::Activated, 1_800_000_000);
+        assert!(key.is_valid_at(1_799_999_999));
+        assert!(!key.is_valid_at(1_800_000_000));
+
+        let never_expires = read_api_key_with(ReadApiKeyStatus::Activated, 0);
+        assert!(never_expires.is_valid_at(i64::MAX));
+
+        let revoked = read_api_key_with(ReadApiKeyStatus::Revoked, 0);
+        assert!(!revoked.is_valid_at(1_700_000_000));
+    }
+
+    #[test]
+    fn test_read_api_key_wrong_account_type_rejected() {
+        let mut val = read_api_key_with(ReadApiKeyStatus::Activated, 0);
+        val.account_type = AccountType::Feed;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(ReadApiKey::try_from(&data[..]).is_err());
+    }
+}
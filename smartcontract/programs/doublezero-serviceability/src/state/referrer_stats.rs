@@ -0,0 +1,176 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::fmt;
+
+/// Onboarding attributed to a referrer: the access passes created naming it.
+///
+/// Created by `SetAccessPass` the first time a new pass names the referrer, and bumped for every
+/// further new pass. Attribution is fixed at pass creation, so updating or closing a pass leaves
+/// the counters untouched. The PDA is derived from `referrer`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferrerStats {
+    pub account_type: AccountType, // 1
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub referrer: Pubkey, // 32 (PDA seed)
+    pub bump_seed: u8,             // 1
+    pub referral_count: u64,       // 8 - access passes created naming the referrer
+    pub first_referral_epoch: u64, // 8
+    pub last_referral_epoch: u64,  // 8
+}
+
+impl fmt::Display for ReferrerStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, referrer: {}, bump_seed: {}, referral_count: {}, first_referral_epoch: {}, last_referral_epoch: {}",
+            self.account_type,
+            self.referrer,
+            self.bump_seed,
+            self.referral_count,
+            self.first_referral_epoch,
+            self.last_referral_epoch
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for ReferrerStats {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            referrer: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            referral_count: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            first_referral_epoch: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            last_referral_epoch: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::ReferrerStats {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for ReferrerStats {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!(
+                "Failed to deserialize ReferrerStats: {:?}",
+                res.as_ref().err()
+            );
+        }
+        res
+    }
+}
+
+impl Validate for ReferrerStats {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::ReferrerStats {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if self.referrer == Pubkey::default() {
+            msg!("Referrer must not be the default pubkey");
+            return Err(DoubleZeroError::InvalidReferrer);
+        }
+        Ok(())
+    }
+}
+
+impl ReferrerStats {
+    /// Count one more access pass created in `epoch` naming the referrer.
+    pub fn record_referral(&mut self, epoch: u64) {
+        if self.referral_count == 0 {
+            self.first_referral_epoch = epoch;
+        }
+        self.referral_count = self.referral_count.saturating_add(1);
+        self.last_referral_epoch = epoch;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn referrer_stats() -> ReferrerStats {
+        ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer: Pubkey::new_unique(),
+            bump_seed: 252,
+            referral_count: 7,
+            first_referral_epoch: 100,
+            last_referral_epoch: 140,
+        }
+    }
+
+    #[test]
+    fn test_referrer_stats_serialization_roundtrip() {
+        let val = referrer_stats();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = ReferrerStats::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_referrer_stats_validate() {
+        let mut val = referrer_stats();
+        val.referrer = Pubkey::default();
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidReferrer));
+    }
+
+    #[test]
+    fn test_referrer_stats_record_referral() {
+        let mut val = ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        val.record_referral(10);
+        assert_eq!(
+            (
+                val.referral_count,
+                val.first_referral_epoch,
+                val.last_referral_epoch
+            ),
+            (1, 10, 10)
+        );
+        val.record_referral(12);
+        assert_eq!(
+            (
+                val.referral_count,
+                val.first_referral_epoch,
+                val.last_referral_epoch
+            ),
+            (2, 10, 12)
+        );
+    }
+
+    #[test]
+    fn test_referrer_stats_wrong_account_type_rejected() {
+        let mut val = referrer_stats();
+        val.account_type = AccountType::AnycastPrefix;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(ReferrerStats::try_from(&data[..]).is_err());
+    }
+}
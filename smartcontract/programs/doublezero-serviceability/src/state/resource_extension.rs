@@ -12,6 +12,7 @@ const _RESOURCE_EXTENSION_HEADER_SIZE_ID_ALLOCATOR: usize = 83;
 const RESOURCE_EXTENSION_HEADER_SIZE_IP_ALLOCATOR: usize = 84;
 const RESOURCE_EXTENSION_HEADER_SIZE: usize = RESOURCE_EXTENSION_HEADER_SIZE_IP_ALLOCATOR;
 const RESOURCE_EXTENSION_BITMAP_OFFSET: usize = RESOURCE_EXTENSION_HEADER_SIZE.div_ceil(8) * 8; // Align to 8 bytes
+const RESOURCE_EXTENSION_ALLOCATOR_OFFSET: usize = 1 + 32 + 1 + 32; // account_type + owner + bump_seed + associated_with
 
 #[repr(u8)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
@@ -58,13 +59,16 @@ impl TryFrom<&[u8]> for ResourceExtensionOwned {
     type Error = DoubleZeroError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        let (mut cursor, bitmap) = data.split_at(RESOURCE_EXTENSION_BITMAP_OFFSET);
+        let (mut cursor, bitmap) = data
+            .split_at_checked(RESOURCE_EXTENSION_BITMAP_OFFSET)
+            .ok_or(DoubleZeroError::SerializationFailure)?;
         let out = Self {
             account_type: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             owner: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             bump_seed: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             associated_with: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
-            allocator: BorshDeserialize::deserialize(&mut cursor).unwrap(),
+            allocator: BorshDeserialize::deserialize(&mut cursor)
+                .map_err(|_| DoubleZeroError::SerializationFailure)?,
             storage: bitmap.to_vec(),
         };
 
@@ -169,14 +173,17 @@ impl<'a> ResourceExtensionBorrowed<'a> {
     }
 
     pub fn inplace_from(data: &'a mut [u8]) -> Result<Self, DoubleZeroError> {
-        let (data, bitmap) = data.split_at_mut(RESOURCE_EXTENSION_BITMAP_OFFSET);
+        let (data, bitmap) = data
+            .split_at_mut_checked(RESOURCE_EXTENSION_BITMAP_OFFSET)
+            .ok_or(DoubleZeroError::SerializationFailure)?;
         let mut cursor: &[u8] = data;
         let out = Self {
             account_type: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             owner: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             bump_seed: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
             associated_with: BorshDeserialize::deserialize(&mut cursor).unwrap_or_default(),
-            allocator: BorshDeserialize::deserialize(&mut cursor).unwrap(),
+            allocator: BorshDeserialize::deserialize(&mut cursor)
+                .map_err(|_| DoubleZeroError::SerializationFailure)?,
             storage: bitmap,
         };
 
@@ -256,6 +263,74 @@ impl<'a> ResourceExtensionBorrowed<'a> {
             Allocator::Id(id_allocator) => id_allocator.iter_allocated(self.storage).count(),
         }
     }
+
+    /// Returns the allocator to store when moving this resource to `range`.
+    ///
+    /// Bitmap indices are relative to the start of the range, so the start (base IP or first
+    /// ID) must not change. Shrinking is only allowed when every dropped index is unallocated.
+    pub fn resized_allocator(
+        &self,
+        range: &ResourceExtensionRange,
+    ) -> Result<Allocator, DoubleZeroError> {
+        let (allocator, new_count) = match (&self.allocator, range) {
+            (Allocator::Ip(ip_allocator), ResourceExtensionRange::IpBlock(base_net, _)) => {
+                if base_net.ip() != ip_allocator.base_net.ip() {
+                    return Err(DoubleZeroError::InvalidArgument);
+                }
+                (
+                    Allocator::Ip(IpAllocator::new(*base_net)),
+                    base_net.size() as usize,
+                )
+            }
+            (Allocator::Id(id_allocator), ResourceExtensionRange::IdRange(start, end)) => {
+                if *start != id_allocator.range.0 {
+                    return Err(DoubleZeroError::InvalidArgument);
+                }
+                (
+                    Allocator::Id(
+                        IdAllocator::new((*start, *end))
+                            .map_err(|_| DoubleZeroError::InvalidArgument)?,
+                    ),
+                    (*end - *start) as usize,
+                )
+            }
+            _ => return Err(DoubleZeroError::InvalidArgument),
+        };
+
+        if self.has_allocations_from(new_count) {
+            return Err(DoubleZeroError::ResourceInUse);
+        }
+
+        Ok(allocator)
+    }
+
+    /// Overwrites the allocator header of a ResourceExtension account in place, leaving the
+    /// bitmap untouched.
+    pub fn write_allocator(data: &mut [u8], allocator: &Allocator) -> Result<(), DoubleZeroError> {
+        let mut cursor = Cursor::new(&mut data[RESOURCE_EXTENSION_ALLOCATOR_OFFSET..]);
+        allocator
+            .serialize(&mut cursor)
+            .map_err(|_| DoubleZeroError::SerializationFailure)?;
+
+        assert!(
+            RESOURCE_EXTENSION_ALLOCATOR_OFFSET + cursor.position() as usize
+                <= RESOURCE_EXTENSION_BITMAP_OFFSET,
+            "Cursor advanced more than RESOURCE_EXTENSION_BITMAP_OFFSET bytes"
+        );
+
+        Ok(())
+    }
+
+    fn has_allocations_from(&self, index: usize) -> bool {
+        let byte_index = index / 8;
+        let Some(first) = self.storage.get(byte_index) else {
+            return false;
+        };
+        if first >> (index % 8) != 0 {
+            return true;
+        }
+        self.storage[byte_index + 1..].iter().any(|b| *b != 0)
+    }
 }
 
 impl fmt::Display for ResourceExtensionBorrowed<'_> {
@@ -291,6 +366,26 @@ mod tests {
         (account_pk, owner_pk)
     }
 
+    #[test]
+    fn test_resource_extension_rejects_malformed_data() {
+        // Shorter than the header.
+        let data = [AccountType::ResourceExtension as u8; 8];
+        assert_eq!(
+            ResourceExtensionOwned::try_from(&data[..]),
+            Err(DoubleZeroError::SerializationFailure)
+        );
+
+        // Unknown allocator discriminant.
+        let mut data = vec![0u8; RESOURCE_EXTENSION_BITMAP_OFFSET];
+        data[0] = AccountType::ResourceExtension as u8;
+        data[RESOURCE_EXTENSION_ALLOCATOR_OFFSET] = 0xff;
+        assert_eq!(
+            ResourceExtensionOwned::try_from(&data[..]),
+            Err(DoubleZeroError::SerializationFailure)
+        );
+        assert!(ResourceExtensionBorrowed::inplace_from(&mut data).is_err());
+    }
+
     #[test]
     fn test_resource_extension_header_size_id_allocator() {
         let empty_vec: Vec<u8> = vec![];
@@ -398,6 +493,96 @@ mod tests {
         assert_eq!(allocated, vec![IdOrIp::Id(1), IdOrIp::Id(5)]);
     }
 
+    #[test]
+    fn test_resized_allocator_id_range() {
+        let mut buffer =
+            vec![0u8; ResourceExtensionBorrowed::size(&ResourceExtensionRange::IdRange(0, 64))];
+        construct_resource_extension(&mut buffer[..]);
+        let mut resext = ResourceExtensionBorrowed::inplace_from(&mut buffer[..]).unwrap();
+        resext.allocate(1).unwrap();
+        resext.allocate_specific(&IdOrIp::Id(40)).unwrap();
+
+        // Growing and shrinking down to the highest allocation are allowed
+        assert_eq!(
+            resext.resized_allocator(&ResourceExtensionRange::IdRange(0, 128)),
+            Ok(Allocator::Id(IdAllocator::new((0, 128)).unwrap()))
+        );
+        assert!(resext
+            .resized_allocator(&ResourceExtensionRange::IdRange(0, 41))
+            .is_ok());
+
+        // Dropping an allocated ID is rejected
+        assert_eq!(
+            resext.resized_allocator(&ResourceExtensionRange::IdRange(0, 40)),
+            Err(DoubleZeroError::ResourceInUse)
+        );
+        assert_eq!(
+            resext.resized_allocator(&ResourceExtensionRange::IdRange(0, 8)),
+            Err(DoubleZeroError::ResourceInUse)
+        );
+
+        // Moving the start or switching allocator kind is rejected
+        assert_eq!(
+            resext.resized_allocator(&ResourceExtensionRange::IdRange(1, 128)),
+            Err(DoubleZeroError::InvalidArgument)
+        );
+        assert_eq!(
+            resext.resized_allocator(&ResourceExtensionRange::IpBlock(
+                "10.0.0.0/24".parse().unwrap(),
+                1
+            )),
+            Err(DoubleZeroError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_write_allocator_preserves_allocations() {
+        let old_range = ResourceExtensionRange::IpBlock("10.0.0.0/24".parse().unwrap(), 1);
+        let new_range = ResourceExtensionRange::IpBlock("10.0.0.0/23".parse().unwrap(), 1);
+        let mut buffer = vec![0u8; ResourceExtensionBorrowed::size(&new_range)];
+        let account_pk = Pubkey::new_unique();
+        let owner_pk = Pubkey::new_unique();
+        ResourceExtensionBorrowed::construct_resource(
+            &AccountInfo::new(
+                &account_pk,
+                false,
+                true,
+                &mut 0,
+                &mut buffer[..],
+                &owner_pk,
+                false,
+            ),
+            &owner_pk,
+            1,
+            &Pubkey::default(),
+            &old_range,
+        )
+        .unwrap();
+
+        let allocator = {
+            let mut resext = ResourceExtensionBorrowed::inplace_from(&mut buffer[..]).unwrap();
+            resext.allocate(1).unwrap();
+            resext.allocate(2).unwrap();
+            resext.resized_allocator(&new_range).unwrap()
+        };
+        ResourceExtensionBorrowed::write_allocator(&mut buffer[..], &allocator).unwrap();
+
+        let resext = ResourceExtensionOwned::try_from(&buffer[..]).unwrap();
+        assert_eq!(resext.owner, owner_pk);
+        assert_eq!(
+            resext.allocator,
+            Allocator::Ip(IpAllocator::new("10.0.0.0/23".parse().unwrap()))
+        );
+        assert_eq!(
+            resext
+                .iter_allocated()
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            vec!["10.0.0.0/32", "10.0.0.2/32", "10.0.0.3/32"]
+        );
+    }
+
     #[test]
     fn test_resource_extension_owned_display_trait() {
         let owner_pk = Pubkey::default();
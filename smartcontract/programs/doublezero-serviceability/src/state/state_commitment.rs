@@ -0,0 +1,294 @@
+use crate::{
+    error::{DoubleZeroError, Validate},
+    state::accounttype::AccountType,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    hash::{hashv, Hash},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::fmt;
+
+/// Merkle root over every serviceability account, computed off-chain and recorded by the
+/// foundation or the health oracle so light clients can check a single account against it.
+///
+/// Singleton PDA, overwritten by each `RecordStateCommitment`; the recorded slot only moves
+/// forward. See [`merkle_root`] for how the tree is built.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateCommitment {
+    pub account_type: AccountType, // 1
+    pub bump_seed: u8,             // 1
+    pub slot: u64,                 // 8 - the snapshot reflects state as of this slot or later
+    pub merkle_root: [u8; 32],     // 32
+    pub account_count: u32,        // 4 - leaves in the tree
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub recorded_by: Pubkey, // 32
+    pub recorded_at_slot: u64,     // 8 - clock slot of the recording transaction
+}
+
+impl fmt::Display for StateCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, bump_seed: {}, slot: {}, merkle_root: {}, account_count: {}, recorded_by: {}, recorded_at_slot: {}",
+            self.account_type,
+            self.bump_seed,
+            self.slot,
+            Hash::new_from_array(self.merkle_root),
+            self.account_count,
+            self.recorded_by,
+            self.recorded_at_slot
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for StateCommitment {
+    type Error = ProgramError;
+
+    fn try_from(mut data: &[u8]) -> Result<Self, Self::Error> {
+        let out = Self {
+            account_type: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            bump_seed: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            slot: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            merkle_root: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            account_count: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            recorded_by: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            recorded_at_slot: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+        };
+
+        if out.account_type != AccountType::StateCommitment {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(out)
+    }
+}
+
+impl TryFrom<&AccountInfo<'_>> for StateCommitment {
+    type Error = ProgramError;
+
+    fn try_from(account: &AccountInfo) -> Result<Self, Self::Error> {
+        let data = account.try_borrow_data()?;
+        let res = Self::try_from(&data[..]);
+        if res.is_err() {
+            msg!(
+                "Failed to deserialize StateCommitment: {:?}",
+                res.as_ref().err()
+            );
+        }
+        res
+    }
+}
+
+impl Validate for StateCommitment {
+    fn validate(&self) -> Result<(), DoubleZeroError> {
+        if self.account_type != AccountType::StateCommitment {
+            msg!("Invalid account type: {}", self.account_type);
+            return Err(DoubleZeroError::InvalidAccountType);
+        }
+        if self.merkle_root == [0u8; 32] || self.account_count == 0 {
+            msg!("State commitment must cover at least one account");
+            return Err(DoubleZeroError::InvalidStateCommitment);
+        }
+        Ok(())
+    }
+}
+
+/// Leaf hash of one account: `sha256(0x00 || pubkey || data)`.
+pub fn leaf_hash(pubkey: &Pubkey, data: &[u8]) -> [u8; 32] {
+    hashv(&[&[0u8], pubkey.as_ref(), data]).to_bytes()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&[1u8], left, right]).to_bytes()
+}
+
+/// Root of the tree over `leaves`, which the caller sorts by account pubkey.
+///
+/// Pairs are hashed as `sha256(0x01 || left || right)`; a trailing unpaired node is carried up
+/// unchanged. The root of an empty tree is all zeroes.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Sibling hashes from the leaf at `index` up to the root; levels where the node is carried up
+/// unpaired contribute nothing.
+pub fn merkle_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = vec![];
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Check that `leaf` sits at `index` of a tree with `leaf_count` leaves and root `root`.
+pub fn verify_proof(
+    root: &[u8; 32],
+    leaf: &[u8; 32],
+    mut index: usize,
+    mut leaf_count: usize,
+    proof: &[[u8; 32]],
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+    let mut hash = *leaf;
+    let mut siblings = proof.iter();
+    while leaf_count > 1 {
+        let sibling = index ^ 1;
+        if sibling < leaf_count {
+            let Some(sibling_hash) = siblings.next() else {
+                return false;
+            };
+            hash = if index % 2 == 0 {
+                node_hash(&hash, sibling_hash)
+            } else {
+                node_hash(sibling_hash, &hash)
+            };
+        }
+        index /= 2;
+        leaf_count = leaf_count.div_ceil(2);
+    }
+    siblings.next().is_none() && hash == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_commitment() -> StateCommitment {
+        StateCommitment {
+            account_type: AccountType::StateCommitment,
+            bump_seed: 253,
+            slot: 1_000,
+            merkle_root: [7u8; 32],
+            account_count: 42,
+            recorded_by: Pubkey::new_unique(),
+            recorded_at_slot: 1_010,
+        }
+    }
+
+    fn leaves(n: usize) -> Vec<[u8; 32]> {
+        (0..n)
+            .map(|i| leaf_hash(&Pubkey::new_unique(), &[i as u8]))
+            .collect()
+    }
+
+    #[test]
+    fn test_state_commitment_serialization_roundtrip() {
+        let val = state_commitment();
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = StateCommitment::try_from(&data[..]).unwrap();
+        val.validate().unwrap();
+        val2.validate().unwrap();
+        assert_eq!(val, val2);
+        assert_eq!(data.len(), borsh::object_length(&val).unwrap());
+    }
+
+    #[test]
+    fn test_state_commitment_validate() {
+        let mut val = state_commitment();
+        val.merkle_root = [0u8; 32];
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidStateCommitment));
+
+        let mut val = state_commitment();
+        val.account_count = 0;
+        assert_eq!(val.validate(), Err(DoubleZeroError::InvalidStateCommitment));
+    }
+
+    #[test]
+    fn test_state_commitment_wrong_account_type_rejected() {
+        let mut val = state_commitment();
+        val.account_type = AccountType::ReferrerStats;
+        let data = borsh::to_vec(&val).unwrap();
+        assert!(StateCommitment::try_from(&data[..]).is_err());
+    }
+
+    #[test]
+    fn test_merkle_root_small_trees() {
+        assert_eq!(merkle_root(&[]), [0u8; 32]);
+
+        let l = leaves(3);
+        assert_eq!(merkle_root(&l[..1]), l[0]);
+        assert_eq!(merkle_root(&l[..2]), node_hash(&l[0], &l[1]));
+        // The third leaf is carried up unpaired.
+        assert_eq!(merkle_root(&l), node_hash(&node_hash(&l[0], &l[1]), &l[2]));
+    }
+
+    #[test]
+    fn test_merkle_proof_roundtrip() {
+        for n in 1..=9 {
+            let l = leaves(n);
+            let root = merkle_root(&l);
+            for (i, leaf) in l.iter().enumerate() {
+                let proof = merkle_proof(&l, i).unwrap();
+                assert!(verify_proof(&root, leaf, i, n, &proof), "n={n} i={i}");
+            }
+            assert!(merkle_proof(&l, n).is_none());
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_rejects_tampering() {
+        let l = leaves(5);
+        let root = merkle_root(&l);
+        let proof = merkle_proof(&l, 2).unwrap();
+
+        assert!(!verify_proof(&root, &l[3], 2, 5, &proof));
+        assert!(!verify_proof(&root, &l[2], 3, 5, &proof));
+        assert!(!verify_proof(&root, &l[2], 2, 5, &proof[1..]));
+        assert!(!verify_proof(&[9u8; 32], &l[2], 2, 5, &proof));
+
+        // The last of five leaves is carried up unpaired; a six-leaf shape expects a sibling.
+        let proof = merkle_proof(&l, 4).unwrap();
+        assert!(verify_proof(&root, &l[4], 4, 5, &proof));
+        assert!(!verify_proof(&root, &l[4], 4, 6, &proof));
+    }
+
+    #[test]
+    fn test_leaf_hash_binds_pubkey_and_data() {
+        let pk = Pubkey::new_unique();
+        assert_ne!(leaf_hash(&pk, b"a"), leaf_hash(&pk, b"b"));
+        assert_ne!(leaf_hash(&pk, b"a"), leaf_hash(&Pubkey::new_unique(), b"a"));
+    }
+}
@@ -18,6 +18,7 @@ use std::{fmt, net::Ipv4Addr};
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum UserType {
     #[default]
     IBRL = 0,
@@ -78,6 +79,7 @@ impl fmt::Display for UserType {
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum UserCYOA {
     #[default]
     None = 0,
@@ -118,6 +120,7 @@ impl fmt::Display for UserCYOA {
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum UserStatus {
     PendingDeprecated = 0, // deprecated; unreachable for new accounts
     #[default]
@@ -168,6 +171,7 @@ impl fmt::Display for UserStatus {
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[borsh(use_discriminant = true)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum BGPStatus {
     #[default]
     Unknown = 0,
@@ -185,6 +189,74 @@ impl fmt::Display for BGPStatus {
     }
 }
 
+/// Bandwidth class a user is shaped to. The program records the tier and
+/// bounds it by the access pass; controllers map each tier to a shaping policy
+/// on the device. `Unshaped` is the default and what accounts created before
+/// the field existed read as.
+#[repr(u8)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone, PartialEq, Default)]
+#[borsh(use_discriminant = true)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BandwidthTier {
+    #[default]
+    Unshaped = 0,
+    Basic = 1,
+    Standard = 2,
+    Premium = 3,
+}
+
+impl From<u8> for BandwidthTier {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => BandwidthTier::Basic,
+            2 => BandwidthTier::Standard,
+            3 => BandwidthTier::Premium,
+            _ => BandwidthTier::Unshaped,
+        }
+    }
+}
+
+impl fmt::Display for BandwidthTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BandwidthTier::Unshaped => write!(f, "unshaped"),
+            BandwidthTier::Basic => write!(f, "basic"),
+            BandwidthTier::Standard => write!(f, "standard"),
+            BandwidthTier::Premium => write!(f, "premium"),
+        }
+    }
+}
+
+impl std::str::FromStr for BandwidthTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "unshaped" => Ok(BandwidthTier::Unshaped),
+            "basic" => Ok(BandwidthTier::Basic),
+            "standard" => Ok(BandwidthTier::Standard),
+            "premium" => Ok(BandwidthTier::Premium),
+            _ => Err(format!(
+                "Invalid bandwidth tier: {s}, must be one of: unshaped, basic, standard, premium"
+            )),
+        }
+    }
+}
+
+impl BandwidthTier {
+    /// Whether a user may be given this tier under an access pass whose tier
+    /// is `limit`. An unshaped pass admits any tier; a shaped pass admits its
+    /// own tier or a lower one, never `Unshaped`.
+    pub fn is_within(&self, limit: BandwidthTier) -> bool {
+        match (limit, self) {
+            (BandwidthTier::Unshaped, _) => true,
+            (_, BandwidthTier::Unshaped) => false,
+            _ => (*self as u8) <= (limit as u8),
+        }
+    }
+}
+
 /// Bitflags stored in [`User::tunnel_flags`] to record durable tunnel
 /// properties that cannot be derived from mutable state at delete time.
 #[repr(u8)]
@@ -293,13 +365,16 @@ pub struct User {
         )
     )]
     pub feed_pk: Pubkey, // 32
+    /// Bandwidth tier the user is shaped to, set at creation from the access pass and
+    /// changeable with UpdateUser.
+    pub bandwidth_tier: BandwidthTier, // 1
 }
 
 impl fmt::Display for User {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "account_type: {}, owner: {}, index: {}, user_type: {}, device_pk: {}, cyoa_type: {}, client_ip: {}, dz_ip: {}, tunnel_id: {}, tunnel_net: {}, status: {}, tunnel_endpoint: {}",
+            "account_type: {}, owner: {}, index: {}, user_type: {}, device_pk: {}, cyoa_type: {}, client_ip: {}, dz_ip: {}, tunnel_id: {}, tunnel_net: {}, status: {}, tunnel_endpoint: {}, bandwidth_tier: {}",
             self.account_type,
             self.owner,
             self.index,
@@ -311,7 +386,8 @@ impl fmt::Display for User {
             self.tunnel_id,
             &self.tunnel_net,
             self.status,
-            &self.tunnel_endpoint
+            &self.tunnel_endpoint,
+            self.bandwidth_tier
         )
     }
 }
@@ -348,6 +424,8 @@ impl TryFrom<&[u8]> for User {
             // Appended after the feed metro gate landed; defaults to the zero pubkey (no feed seat)
             // for users created before this field existed.
             feed_pk: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
+            // Appended with bandwidth tiers; users created before read as unshaped.
+            bandwidth_tier: BorshDeserialize::deserialize(&mut data).unwrap_or_default(),
         };
 
         if out.account_type != AccountType::User {
@@ -565,6 +643,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let data = borsh::to_vec(&val).unwrap();
@@ -618,6 +697,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let err = val.validate();
@@ -651,6 +731,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -683,6 +764,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -715,6 +797,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -747,6 +830,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -779,6 +863,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -812,6 +897,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let err = val.validate();
         assert!(err.is_err());
@@ -826,6 +912,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
             ..val.clone()
         };
         let err = val_loopback.validate();
@@ -841,6 +928,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
             ..val.clone()
         };
         let err = val_link_local.validate();
@@ -856,6 +944,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
             ..val.clone()
         };
         assert!(val_unspecified.validate().is_ok());
@@ -869,6 +958,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
             ..val
         };
         assert!(val_global.validate().is_ok());
@@ -904,6 +994,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
@@ -1048,6 +1139,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         assert!(val.validate().is_ok());
@@ -1107,11 +1199,13 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let data = borsh::to_vec(&user).unwrap();
         // Remove tunnel_flags (1) + bgp_status (1) + last_bgp_up_at (8) + last_bgp_reported_at (8)
-        // + bgp_rtt_ns (8) + feed_pk (32) to simulate an old account that predates all of them.
-        let old_data = &data[..data.len() - 58];
+        // + bgp_rtt_ns (8) + feed_pk (32) + bandwidth_tier (1) to simulate an old account that
+        // predates all of them.
+        let old_data = &data[..data.len() - 59];
         let deserialized = User::try_from(old_data).unwrap();
         assert_eq!(
             deserialized.tunnel_flags, 0,
@@ -1139,6 +1233,29 @@ mod tests {
             deserialized.bgp_rtt_ns, 0,
             "Old accounts must default bgp_rtt_ns to 0"
         );
+        assert_eq!(
+            deserialized.bandwidth_tier,
+            BandwidthTier::Unshaped,
+            "Old accounts must default bandwidth_tier to Unshaped"
+        );
+    }
+
+    #[test]
+    fn test_bandwidth_tier_is_within() {
+        use BandwidthTier::*;
+
+        for tier in [Unshaped, Basic, Standard, Premium] {
+            assert!(tier.is_within(Unshaped), "{tier} under an unshaped pass");
+        }
+        assert!(Basic.is_within(Standard));
+        assert!(Standard.is_within(Standard));
+        assert!(!Premium.is_within(Standard));
+        assert!(!Unshaped.is_within(Basic));
+
+        assert_eq!("Premium".parse::<BandwidthTier>().unwrap(), Premium);
+        assert!("gold".parse::<BandwidthTier>().is_err());
+        assert_eq!(BandwidthTier::from(2), Standard);
+        assert_eq!(BandwidthTier::from(200), Unshaped);
     }
 
     #[test]
@@ -1167,6 +1284,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
         let data = borsh::to_vec(&user).unwrap();
         let deserialized = User::try_from(&data[..]).unwrap();
@@ -1203,6 +1321,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         }
     }
 
@@ -1225,6 +1344,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         }
     }
 
@@ -0,0 +1,412 @@
+//! Status transition tables.
+//!
+//! Each status enum lists the transitions the program accepts, the instruction
+//! that performs them and whether the account owner may request them or only a
+//! privileged caller (foundation or the relevant admin permission). Processors
+//! check status changes with [`validate_transition`], the SDK re-exports the
+//! tables so clients can reject a change before sending it, and
+//! `STATE_MACHINES.md` is rendered from them with [`state_diagram`].
+
+use crate::{
+    error::DoubleZeroError,
+    state::{
+        contributor::ContributorStatus, device::DeviceStatus, exchange::ExchangeStatus,
+        ip_allocation::IpAllocationStatus, link::LinkStatus, location::LocationStatus,
+        multicastgroup::MulticastGroupStatus, permission::PermissionStatus,
+        read_api_key::ReadApiKeyStatus, user::UserStatus,
+    },
+};
+use core::fmt::{self, Write};
+use solana_program::msg;
+
+/// One allowed status change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusTransition<S: 'static> {
+    pub from: S,
+    pub to: S,
+    /// Name of the instruction performing it, as returned by
+    /// `DoubleZeroInstruction::get_name`.
+    pub instruction: &'static str,
+    /// Whether the account owner may request it; otherwise only privileged
+    /// callers can.
+    pub owner_allowed: bool,
+}
+
+const fn owner<S>(from: S, to: S, instruction: &'static str) -> StatusTransition<S> {
+    StatusTransition {
+        from,
+        to,
+        instruction,
+        owner_allowed: true,
+    }
+}
+
+const fn admin<S>(from: S, to: S, instruction: &'static str) -> StatusTransition<S> {
+    StatusTransition {
+        from,
+        to,
+        instruction,
+        owner_allowed: false,
+    }
+}
+
+/// A status enum with an explicit transition table.
+pub trait StatusMachine: Copy + PartialEq + fmt::Debug + 'static {
+    /// Account name used in logs and diagrams.
+    const ENTITY: &'static str;
+    const TRANSITIONS: &'static [StatusTransition<Self>];
+}
+
+/// The table row for `from -> to` through `instruction`, if any.
+pub fn find_transition<S: StatusMachine>(
+    from: S,
+    to: S,
+    instruction: &str,
+) -> Option<&'static StatusTransition<S>> {
+    S::TRANSITIONS
+        .iter()
+        .find(|t| t.from == from && t.to == to && t.instruction == instruction)
+}
+
+/// Check a status change against the table.
+///
+/// Returns `InvalidStatus` when the table has no such transition for
+/// `instruction`, and `NotAllowed` when it is reserved for privileged callers
+/// and `is_privileged` is false.
+pub fn validate_transition<S: StatusMachine>(
+    from: S,
+    to: S,
+    instruction: &str,
+    is_privileged: bool,
+) -> Result<(), DoubleZeroError> {
+    match find_transition(from, to, instruction) {
+        None => {
+            msg!(
+                "{}: {:?} -> {:?} is not a valid {} transition",
+                instruction,
+                from,
+                to,
+                S::ENTITY
+            );
+            Err(DoubleZeroError::InvalidStatus)
+        }
+        Some(transition) if !transition.owner_allowed && !is_privileged => {
+            msg!(
+                "{}: {:?} -> {:?} requires a privileged caller",
+                instruction,
+                from,
+                to
+            );
+            Err(DoubleZeroError::NotAllowed)
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// Statuses `from` can move to, for callers with the given privilege.
+pub fn next_statuses<S: StatusMachine>(from: S, is_privileged: bool) -> Vec<S> {
+    let mut next: Vec<S> = vec![];
+    for t in S::TRANSITIONS {
+        if t.from == from && (t.owner_allowed || is_privileged) && !next.contains(&t.to) {
+            next.push(t.to);
+        }
+    }
+    next
+}
+
+/// Mermaid `stateDiagram-v2` of the table. Owner transitions are labelled with
+/// the instruction name, privileged-only ones with the name and `(admin)`.
+pub fn state_diagram<S: StatusMachine>() -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "```mermaid");
+    let _ = writeln!(out, "stateDiagram-v2");
+    for t in S::TRANSITIONS {
+        let suffix = if t.owner_allowed { "" } else { " (admin)" };
+        let _ = writeln!(
+            out,
+            "    {:?} --> {:?}: {}{}",
+            t.from, t.to, t.instruction, suffix
+        );
+    }
+    let _ = writeln!(out, "```");
+    out
+}
+
+impl StatusMachine for DeviceStatus {
+    const ENTITY: &'static str = "Device";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use DeviceStatus::*;
+        &[
+            // Contributors can only drain and undrain; this enforces a
+            // maintenance step before reactivation.
+            owner(Activated, Drained, "UpdateDevice"),
+            owner(Drained, Activated, "UpdateDevice"),
+            admin(Activated, DeviceProvisioning, "UpdateDevice"),
+            admin(Activated, LinkProvisioning, "UpdateDevice"),
+            admin(Drained, DeviceProvisioning, "UpdateDevice"),
+            admin(Drained, LinkProvisioning, "UpdateDevice"),
+            admin(DeviceProvisioning, Activated, "UpdateDevice"),
+            admin(DeviceProvisioning, Drained, "UpdateDevice"),
+            admin(DeviceProvisioning, LinkProvisioning, "UpdateDevice"),
+            admin(LinkProvisioning, Activated, "UpdateDevice"),
+            admin(LinkProvisioning, Drained, "UpdateDevice"),
+            admin(LinkProvisioning, DeviceProvisioning, "UpdateDevice"),
+            // Delete authorization is checked by the processor itself.
+            owner(Drained, Deleting, "DeleteDevice"),
+            owner(DeviceProvisioning, Deleting, "DeleteDevice"),
+            owner(LinkProvisioning, Deleting, "DeleteDevice"),
+            owner(PendingDeprecated, Deleting, "DeleteDevice"),
+            owner(RejectedDeprecated, Deleting, "DeleteDevice"),
+        ]
+    };
+}
+
+impl StatusMachine for LinkStatus {
+    const ENTITY: &'static str = "Link";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use LinkStatus::*;
+        &[
+            owner(Activated, SoftDrained, "UpdateLink"),
+            owner(Activated, HardDrained, "UpdateLink"),
+            owner(SoftDrained, Activated, "UpdateLink"),
+            owner(SoftDrained, HardDrained, "UpdateLink"),
+            owner(HardDrained, Activated, "UpdateLink"),
+            owner(HardDrained, SoftDrained, "UpdateLink"),
+            admin(Provisioning, Activated, "UpdateLink"),
+            admin(Provisioning, SoftDrained, "UpdateLink"),
+            admin(Provisioning, HardDrained, "UpdateLink"),
+            admin(Activated, Provisioning, "UpdateLink"),
+            admin(SoftDrained, Provisioning, "UpdateLink"),
+            admin(HardDrained, Provisioning, "UpdateLink"),
+        ]
+    };
+}
+
+impl StatusMachine for ContributorStatus {
+    const ENTITY: &'static str = "Contributor";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use ContributorStatus::*;
+        &[
+            admin(Activated, Suspended, "SuspendContributor"),
+            admin(Activated, Suspended, "EmergencySuspendContributor"),
+            admin(Suspended, Activated, "ResumeContributor"),
+        ]
+    };
+}
+
+impl StatusMachine for ExchangeStatus {
+    const ENTITY: &'static str = "Exchange";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use ExchangeStatus::*;
+        &[
+            admin(Activated, Suspended, "SuspendExchange"),
+            admin(Suspended, Activated, "ResumeExchange"),
+        ]
+    };
+}
+
+impl StatusMachine for LocationStatus {
+    const ENTITY: &'static str = "Location";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use LocationStatus::*;
+        &[
+            admin(Activated, Suspended, "SuspendLocation"),
+            admin(Suspended, Activated, "ResumeLocation"),
+        ]
+    };
+}
+
+impl StatusMachine for MulticastGroupStatus {
+    const ENTITY: &'static str = "MulticastGroup";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use MulticastGroupStatus::*;
+        &[
+            admin(Activated, Suspended, "SuspendMulticastGroup"),
+            admin(Suspended, Activated, "ReactivateMulticastGroup"),
+        ]
+    };
+}
+
+impl StatusMachine for PermissionStatus {
+    const ENTITY: &'static str = "Permission";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use PermissionStatus::*;
+        &[
+            admin(Activated, Suspended, "SuspendPermission"),
+            admin(Suspended, Activated, "ResumePermission"),
+        ]
+    };
+}
+
+impl StatusMachine for UserStatus {
+    const ENTITY: &'static str = "User";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use UserStatus::*;
+        &[
+            admin(Activated, Banned, "RequestBanUser"),
+            admin(SuspendedDeprecated, Banned, "RequestBanUser"),
+            // Epoch expiry no longer demotes users, so the check only
+            // restores users still marked OutOfCredits.
+            owner(Activated, Activated, "CheckUserAccessPass"),
+            owner(OutOfCredits, Activated, "CheckUserAccessPass"),
+        ]
+    };
+}
+
+impl StatusMachine for ReadApiKeyStatus {
+    const ENTITY: &'static str = "ReadApiKey";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use ReadApiKeyStatus::*;
+        &[owner(Activated, Revoked, "RevokeReadApiKey")]
+    };
+}
+
+impl StatusMachine for IpAllocationStatus {
+    const ENTITY: &'static str = "IpAllocation";
+    const TRANSITIONS: &'static [StatusTransition<Self>] = {
+        use IpAllocationStatus::*;
+        &[
+            admin(Pending, Verified, "SetIpAllocationStatus"),
+            admin(Pending, Rejected, "SetIpAllocationStatus"),
+            // A verified block can be withdrawn, and a rejected one reconsidered.
+            admin(Verified, Rejected, "SetIpAllocationStatus"),
+            admin(Rejected, Verified, "SetIpAllocationStatus"),
+        ]
+    };
+}
+
+/// Markdown document with a diagram per entity, checked in as
+/// `STATE_MACHINES.md`.
+pub fn state_machines_doc() -> String {
+    let mut out = String::from(
+        "# Serviceability state machines\n\n\
+         Generated from `src/state_machine.rs`; do not edit by hand. Regenerate with:\n\n\
+         \x20   UPDATE_STATE_MACHINES=1 cargo test -p doublezero-serviceability --lib state_machine\n\n\
+         Transitions marked `(admin)` require the foundation or the relevant admin permission.\n",
+    );
+    fn section<S: StatusMachine>(out: &mut String) {
+        let _ = write!(out, "\n## {}\n\n{}", S::ENTITY, state_diagram::<S>());
+    }
+    section::<ContributorStatus>(&mut out);
+    section::<DeviceStatus>(&mut out);
+    section::<ExchangeStatus>(&mut out);
+    section::<IpAllocationStatus>(&mut out);
+    section::<LinkStatus>(&mut out);
+    section::<LocationStatus>(&mut out);
+    section::<MulticastGroupStatus>(&mut out);
+    section::<PermissionStatus>(&mut out);
+    section::<ReadApiKeyStatus>(&mut out);
+    section::<UserStatus>(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_transition() {
+        assert!(validate_transition(
+            DeviceStatus::Activated,
+            DeviceStatus::Drained,
+            "UpdateDevice",
+            false
+        )
+        .is_ok());
+        assert_eq!(
+            validate_transition(
+                DeviceStatus::Activated,
+                DeviceStatus::DeviceProvisioning,
+                "UpdateDevice",
+                false
+            ),
+            Err(DoubleZeroError::NotAllowed)
+        );
+        assert!(validate_transition(
+            DeviceStatus::Activated,
+            DeviceStatus::DeviceProvisioning,
+            "UpdateDevice",
+            true
+        )
+        .is_ok());
+        assert_eq!(
+            validate_transition(
+                DeviceStatus::Activated,
+                DeviceStatus::Deleting,
+                "UpdateDevice",
+                true
+            ),
+            Err(DoubleZeroError::InvalidStatus)
+        );
+        // The instruction is part of the key.
+        assert_eq!(
+            validate_transition(
+                ContributorStatus::Activated,
+                ContributorStatus::Suspended,
+                "ResumeContributor",
+                true
+            ),
+            Err(DoubleZeroError::InvalidStatus)
+        );
+    }
+
+    #[test]
+    fn test_next_statuses() {
+        assert_eq!(
+            next_statuses(LinkStatus::Activated, false),
+            vec![LinkStatus::SoftDrained, LinkStatus::HardDrained]
+        );
+        assert_eq!(
+            next_statuses(LinkStatus::Provisioning, true),
+            vec![
+                LinkStatus::Activated,
+                LinkStatus::SoftDrained,
+                LinkStatus::HardDrained
+            ]
+        );
+        assert!(next_statuses(LinkStatus::Provisioning, false).is_empty());
+        assert!(next_statuses(ReadApiKeyStatus::Revoked, true).is_empty());
+    }
+
+    #[test]
+    fn test_tables_have_no_duplicates() {
+        fn check<S: StatusMachine>() {
+            for (i, a) in S::TRANSITIONS.iter().enumerate() {
+                for b in &S::TRANSITIONS[i + 1..] {
+                    assert!(
+                        !(a.from == b.from && a.to == b.to && a.instruction == b.instruction),
+                        "duplicate {} transition {:?}",
+                        S::ENTITY,
+                        a
+                    );
+                }
+            }
+        }
+        check::<ContributorStatus>();
+        check::<DeviceStatus>();
+        check::<ExchangeStatus>();
+        check::<IpAllocationStatus>();
+        check::<LinkStatus>();
+        check::<LocationStatus>();
+        check::<MulticastGroupStatus>();
+        check::<PermissionStatus>();
+        check::<ReadApiKeyStatus>();
+        check::<UserStatus>();
+    }
+
+    #[test]
+    fn test_state_machines_doc_is_up_to_date() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/STATE_MACHINES.md");
+        let rendered = state_machines_doc();
+        if std::env::var_os("UPDATE_STATE_MACHINES").is_some() {
+            std::fs::write(path, &rendered).unwrap();
+            return;
+        }
+        let current = std::fs::read_to_string(path).unwrap_or_default();
+        assert!(
+            current == rendered,
+            "STATE_MACHINES.md is out of date; regenerate with \
+             UPDATE_STATE_MACHINES=1 cargo test -p doublezero-serviceability --lib state_machine"
+        );
+    }
+}
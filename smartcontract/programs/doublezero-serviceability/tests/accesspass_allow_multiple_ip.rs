@@ -282,6 +282,8 @@ async fn test_accesspass_allow_multiple_ip() {
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -318,6 +320,7 @@ async fn test_accesspass_allow_multiple_ip() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -0,0 +1,327 @@
+//! A `Leased` access pass holds a lamports deposit from `SetAccessPass` until
+//! `SettleAccessPassLease` burns it after the pass expires.
+
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::accesspass::{
+        close::CloseAccessPassArgs, set::SetAccessPassArgs, settle_lease::SettleAccessPassLeaseArgs,
+    },
+    state::accesspass::AccessPassType,
+};
+use solana_program::{incinerator, rent::Rent};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair};
+use std::net::Ipv4Addr;
+
+mod test_helpers;
+use test_helpers::*;
+
+const DEPOSIT: u64 = 5_000_000;
+
+fn leased(deposit: u64, last_access_epoch: u64) -> DoubleZeroInstruction {
+    DoubleZeroInstruction::SetAccessPass(SetAccessPassArgs {
+        accesspass_type: AccessPassType::Leased(deposit),
+        client_ip: Ipv4Addr::new(100, 0, 0, 1),
+        last_access_epoch,
+        allow_multiple_ip: false,
+        max_unicast_users: 1,
+        max_multicast_users: 1,
+        bandwidth_tier: None,
+        referrer: None,
+    })
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, code: u32) {
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected Custom({code}), got: {error_string}"
+    );
+}
+
+async fn lamports(banks_client: &mut BanksClient, pubkey: Pubkey) -> u64 {
+    banks_client
+        .get_account(pubkey)
+        .await
+        .unwrap()
+        .map(|account| account.lamports)
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_accesspass_lease_deposit_and_settle() {
+    let (mut context, program_id) = init_test_with_context().await;
+    let payer: Keypair = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::InitGlobalState(),
+        vec![
+            AccountMeta::new(program_config_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let user_payer = Pubkey::new_unique();
+    let (accesspass_pubkey, _) =
+        get_accesspass_pda(&program_id, &Ipv4Addr::new(100, 0, 0, 1), &user_payer);
+    let set_accounts = vec![
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+        AccountMeta::new(user_payer, false),
+    ];
+    let accesspass_accounts = vec![
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+    let settle_accounts = vec![
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+        AccountMeta::new(incinerator::ID, false),
+    ];
+
+    // A lease needs a deposit and an expiry.
+    for instruction in [leased(0, 5), leased(DEPOSIT, u64::MAX)] {
+        let result = try_execute_transaction(
+            &mut context.banks_client,
+            recent_blockhash,
+            program_id,
+            instruction,
+            set_accounts.clone(),
+            &payer,
+        )
+        .await;
+        assert_custom_error(result, 109);
+    }
+
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        leased(DEPOSIT, 5),
+        set_accounts.clone(),
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(accesspass_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = Rent::default().minimum_balance(account.data.len());
+    assert_eq!(account.lamports, rent + DEPOSIT);
+    let accesspass = get_account_data(&mut context.banks_client, accesspass_pubkey)
+        .await
+        .unwrap()
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.accesspass_type, AccessPassType::Leased(DEPOSIT));
+
+    // Growing the deposit only tops up the difference; shrinking it is rejected.
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        leased(DEPOSIT * 2, 5),
+        set_accounts.clone(),
+        &payer,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        lamports(&mut context.banks_client, accesspass_pubkey).await,
+        rent + DEPOSIT * 2
+    );
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        leased(DEPOSIT, 5),
+        set_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 109);
+
+    // The deposit pins the pass until the lease is settled.
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CloseAccessPass(CloseAccessPassArgs {}),
+        accesspass_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 111);
+
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+        settle_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 110);
+
+    let recent_blockhash = warp_to_epoch(&mut context, 6).await;
+    assert_eq!(get_clock(&mut context.banks_client).await.epoch, 6);
+
+    // An expired lease cannot be extended before it is settled.
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        leased(DEPOSIT * 2, 10),
+        set_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 111);
+
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+        settle_accounts.clone(),
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        lamports(&mut context.banks_client, accesspass_pubkey).await,
+        rent
+    );
+    let accesspass = get_account_data(&mut context.banks_client, accesspass_pubkey)
+        .await
+        .unwrap()
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.accesspass_type, AccessPassType::Leased(0));
+
+    // A settled lease has nothing left to burn, and can now be closed.
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+        settle_accounts,
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 109);
+
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CloseAccessPass(CloseAccessPassArgs {}),
+        accesspass_accounts,
+        &payer,
+    )
+    .await
+    .unwrap();
+    assert!(
+        get_account_data(&mut context.banks_client, accesspass_pubkey)
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_accesspass_lease_settles_only_after_last_access_epoch() {
+    let (mut context, program_id) = init_test_with_context().await;
+    let payer: Keypair = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::InitGlobalState(),
+        vec![
+            AccountMeta::new(program_config_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let user_payer = Pubkey::new_unique();
+    let (accesspass_pubkey, _) =
+        get_accesspass_pda(&program_id, &Ipv4Addr::new(100, 0, 0, 1), &user_payer);
+    let settle_accounts = vec![
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+        AccountMeta::new(incinerator::ID, false),
+    ];
+
+    let last_access_epoch = get_clock(&mut context.banks_client).await.epoch + 1;
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        leased(DEPOSIT, last_access_epoch),
+        vec![
+            AccountMeta::new(accesspass_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(user_payer, false),
+        ],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    // The lease still runs during its last access epoch.
+    let (epoch, recent_blockhash) = advance_epochs(&mut context, 1).await;
+    assert_eq!(epoch, last_access_epoch);
+    let result = try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+        settle_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 110);
+
+    // Rolling over into the next epoch expires it.
+    let (_, recent_blockhash) = advance_epochs(&mut context, 1).await;
+    try_execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+        settle_accounts,
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let accesspass = get_account_data(&mut context.banks_client, accesspass_pubkey)
+        .await
+        .unwrap()
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.accesspass_type, AccessPassType::Leased(0));
+}
@@ -0,0 +1,220 @@
+//! `SetAccessPass` credits the referrer named when a pass is created, counting
+//! its referrals in a per-referrer `ReferrerStats` account.
+
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::accesspass::set::SetAccessPassArgs,
+    state::{accesspass::AccessPassType, accounttype::AccountType},
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use std::net::Ipv4Addr;
+
+mod test_helpers;
+use test_helpers::*;
+
+const CLIENT_IP: Ipv4Addr = Ipv4Addr::new(100, 0, 0, 1);
+
+fn set_pass(referrer: Option<Pubkey>, last_access_epoch: u64) -> DoubleZeroInstruction {
+    DoubleZeroInstruction::SetAccessPass(SetAccessPassArgs {
+        accesspass_type: AccessPassType::Prepaid,
+        client_ip: CLIENT_IP,
+        last_access_epoch,
+        allow_multiple_ip: false,
+        max_unicast_users: 1,
+        max_multicast_users: 1,
+        bandwidth_tier: None,
+        referrer,
+    })
+}
+
+fn set_accounts(
+    program_id: &Pubkey,
+    globalstate_pubkey: Pubkey,
+    user_payer: Pubkey,
+    referrer: Option<Pubkey>,
+) -> Vec<AccountMeta> {
+    let (accesspass_pubkey, _) = get_accesspass_pda(program_id, &CLIENT_IP, &user_payer);
+    let mut accounts = vec![
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+        AccountMeta::new(user_payer, false),
+    ];
+    if let Some(referrer) = referrer {
+        let (referrer_stats_pubkey, _) = get_referrer_stats_pda(program_id, &referrer);
+        accounts.push(AccountMeta::new(referrer_stats_pubkey, false));
+    }
+    accounts
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, code: u32) {
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected Custom({code}), got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_accesspass_referral() {
+    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
+
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::InitGlobalState(),
+        vec![
+            AccountMeta::new(program_config_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let referrer = Pubkey::new_unique();
+    let (referrer_stats_pubkey, _) = get_referrer_stats_pda(&program_id, &referrer);
+    let first_payer = Pubkey::new_unique();
+    let second_payer = Pubkey::new_unique();
+
+    println!("🟢 1. A default referrer or a self-referral is rejected...");
+    for (user_payer, bad_referrer) in [(first_payer, Pubkey::default()), (first_payer, first_payer)]
+    {
+        let result = try_execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            set_pass(Some(bad_referrer), 0),
+            set_accounts(
+                &program_id,
+                globalstate_pubkey,
+                user_payer,
+                Some(bad_referrer),
+            ),
+            &payer,
+        )
+        .await;
+        assert_custom_error(result, 117);
+    }
+
+    println!("🟢 2. The stats account must be the referrer's PDA...");
+    let mut accounts = set_accounts(&program_id, globalstate_pubkey, first_payer, None);
+    accounts.push(AccountMeta::new(Pubkey::new_unique(), false));
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(Some(referrer), 0),
+        accounts,
+        &payer,
+    )
+    .await;
+    assert!(result.is_err());
+
+    println!("🟢 3. Creating a pass with a referrer credits it...");
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(Some(referrer), 0),
+        set_accounts(&program_id, globalstate_pubkey, first_payer, Some(referrer)),
+        &payer,
+    )
+    .await;
+
+    let (accesspass_pubkey, _) = get_accesspass_pda(&program_id, &CLIENT_IP, &first_payer);
+    let accesspass = get_account_data(&mut banks_client, accesspass_pubkey)
+        .await
+        .unwrap()
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.referrer, referrer);
+
+    let stats = get_account_data(&mut banks_client, referrer_stats_pubkey)
+        .await
+        .unwrap()
+        .get_referrer_stats()
+        .unwrap();
+    assert_eq!(stats.account_type, AccountType::ReferrerStats);
+    assert_eq!(stats.referrer, referrer);
+    assert_eq!(stats.referral_count, 1);
+    assert_eq!(stats.first_referral_epoch, stats.last_referral_epoch);
+
+    println!("🟢 4. Updating the pass neither recounts nor changes the referrer...");
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(Some(referrer), u64::MAX),
+        set_accounts(&program_id, globalstate_pubkey, first_payer, Some(referrer)),
+        &payer,
+    )
+    .await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(None, 0),
+        set_accounts(&program_id, globalstate_pubkey, first_payer, None),
+        &payer,
+    )
+    .await;
+
+    let other_referrer = Pubkey::new_unique();
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(Some(other_referrer), 0),
+        set_accounts(
+            &program_id,
+            globalstate_pubkey,
+            first_payer,
+            Some(other_referrer),
+        ),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 117);
+
+    let accesspass = get_account_data(&mut banks_client, accesspass_pubkey)
+        .await
+        .unwrap()
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.referrer, referrer);
+    let stats = get_account_data(&mut banks_client, referrer_stats_pubkey)
+        .await
+        .unwrap()
+        .get_referrer_stats()
+        .unwrap();
+    assert_eq!(stats.referral_count, 1);
+
+    println!("🟢 5. Another new pass adds to the count...");
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pass(Some(referrer), 0),
+        set_accounts(
+            &program_id,
+            globalstate_pubkey,
+            second_payer,
+            Some(referrer),
+        ),
+        &payer,
+    )
+    .await;
+
+    let stats = get_account_data(&mut banks_client, referrer_stats_pubkey)
+        .await
+        .unwrap()
+        .get_referrer_stats()
+        .unwrap();
+    assert_eq!(stats.referral_count, 2);
+
+    println!("✅ Referrals recorded");
+}
@@ -78,6 +78,8 @@ async fn test_accesspass() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -112,6 +114,8 @@ async fn test_accesspass() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -170,6 +174,8 @@ async fn test_accesspass() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -205,6 +211,8 @@ async fn test_accesspass() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -396,6 +404,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_1, false),
@@ -438,6 +448,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_2, false),
@@ -480,6 +492,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_3, false),
@@ -516,6 +530,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_1, false),
@@ -553,6 +569,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_1, false),
@@ -595,6 +613,8 @@ async fn test_accesspass_with_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey_4, false),
@@ -683,6 +703,8 @@ async fn test_close_accesspass_rejects_nonzero_connection_count() {
         max_unicast_users: 1,
         multicast_user_count: 0,
         max_multicast_users: 1,
+        bandwidth_tier: Default::default(),
+        referrer: Pubkey::default(),
     };
 
     let accesspass_data = borsh::to_vec(&seeded_accesspass).unwrap();
@@ -795,6 +817,8 @@ async fn test_tx_lamports_to_pda_before_creation() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -827,6 +851,8 @@ async fn test_tx_lamports_to_pda_before_creation() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1040,6 +1066,8 @@ async fn test_user_create_with_matching_tenant_in_allowlist() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1072,6 +1100,7 @@ async fn test_user_create_with_matching_tenant_in_allowlist() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: std::net::Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1125,6 +1154,8 @@ async fn test_user_create_with_wrong_tenant_in_allowlist() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1157,6 +1188,7 @@ async fn test_user_create_with_wrong_tenant_in_allowlist() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: std::net::Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1201,6 +1233,8 @@ async fn test_user_create_with_empty_tenant_allowlist_rejects_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1232,6 +1266,7 @@ async fn test_user_create_with_empty_tenant_allowlist_rejects_tenant() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: std::net::Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1293,6 +1328,8 @@ async fn test_set_accesspass_unauthorized_payer_fails() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1378,6 +1415,8 @@ async fn test_set_accesspass_with_tenant_admin_succeeds() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1454,6 +1493,8 @@ async fn test_set_accesspass_with_tenant_non_admin_fails() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1569,6 +1610,8 @@ async fn test_set_accesspass_tenant_admin_cannot_replace_other_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1594,6 +1637,8 @@ async fn test_set_accesspass_tenant_admin_cannot_replace_other_tenant() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1669,6 +1714,8 @@ async fn test_set_accesspass_with_sentinel_authority_succeeds() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1733,6 +1780,8 @@ async fn test_set_accesspass_with_feed_authority_succeeds() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1813,6 +1862,8 @@ async fn test_set_accesspass_tenant_admin_without_tenant_accounts_fails() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1876,6 +1927,8 @@ async fn test_set_accesspass_airdrop_scales_with_allow_multiple_ip() {
             allow_multiple_ip: false,
             max_unicast_users: 3,
             max_multicast_users: 2,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(base_pass, false),
@@ -1907,6 +1960,8 @@ async fn test_set_accesspass_airdrop_scales_with_allow_multiple_ip() {
             allow_multiple_ip: true,
             max_unicast_users: 3,
             max_multicast_users: 2,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(scaled_pass, false),
@@ -1979,6 +2034,8 @@ async fn test_set_accesspass_refills_depleted_user_payer() {
         allow_multiple_ip: false,
         max_unicast_users: 1,
         max_multicast_users: 1,
+        bandwidth_tier: None,
+        referrer: None,
     };
     let set_access_pass_accounts = vec![
         AccountMeta::new(accesspass_pubkey, false),
@@ -0,0 +1,169 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::anycast_prefix::{
+        create::AnycastPrefixCreateArgs, delete::AnycastPrefixDeleteArgs,
+        update::AnycastPrefixUpdateArgs,
+    },
+    state::{accounttype::AccountType, anycast_prefix::AnycastPrefix},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+async fn get_anycast_prefix(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> Option<AnycastPrefix> {
+    get_account_data(banks_client, pubkey)
+        .await
+        .and_then(|data| data.get_anycast_prefix().ok())
+}
+
+#[tokio::test]
+async fn test_anycast_prefix_lifecycle() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, _globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+
+    let (dns_pubkey, _) = get_anycast_prefix_pda(&program_id, "dns");
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateAnycastPrefix(AnycastPrefixCreateArgs {
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+        }),
+        vec![
+            AccountMeta::new(dns_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let dns = get_anycast_prefix(&mut banks_client, dns_pubkey)
+        .await
+        .expect("anycast prefix should exist");
+    assert_eq!(dns.account_type, AccountType::AnycastPrefix);
+    assert_eq!(dns.code, "dns");
+    assert_eq!(dns.prefix.to_string(), "100.64.255.53/32");
+    assert!(dns.enabled);
+
+    // Update only touches the provided fields.
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateAnycastPrefix(AnycastPrefixUpdateArgs {
+            prefix: None,
+            enabled: Some(false),
+        }),
+        vec![
+            AccountMeta::new(dns_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let updated = get_anycast_prefix(&mut banks_client, dns_pubkey)
+        .await
+        .unwrap();
+    assert!(!updated.enabled);
+    assert_eq!(updated.prefix, dns.prefix);
+
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteAnycastPrefix(AnycastPrefixDeleteArgs {}),
+        vec![
+            AccountMeta::new(dns_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    assert!(get_anycast_prefix(&mut banks_client, dns_pubkey)
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_anycast_prefix_rejects_invalid_prefix() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, _globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+
+    for prefix in ["100.64.255.0/24", "0.0.0.0/32"] {
+        let (pubkey, _) = get_anycast_prefix_pda(&program_id, "ntp");
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        let result = execute_transaction_expect_failure(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateAnycastPrefix(AnycastPrefixCreateArgs {
+                code: "ntp".to_string(),
+                prefix: prefix.parse().unwrap(),
+                enabled: true,
+            }),
+            vec![
+                AccountMeta::new(pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+        // DoubleZeroError::InvalidAnycastPrefix = Custom(114)
+        assert_custom_error(result, 114);
+    }
+}
+
+#[tokio::test]
+async fn test_anycast_prefix_requires_network_admin() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, _globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+
+    let (pubkey, _) = get_anycast_prefix_pda(&program_id, "dns");
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateAnycastPrefix(AnycastPrefixCreateArgs {
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+        }),
+        vec![
+            AccountMeta::new(pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &outsider,
+    )
+    .await;
+    // DoubleZeroError::NotAllowed = Custom(8)
+    assert_custom_error(result, 8);
+}
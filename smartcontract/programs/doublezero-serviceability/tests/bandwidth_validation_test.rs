@@ -190,6 +190,7 @@ async fn test_interface_create_rejects_cyoa_zero_bandwidth() {
             user_tunnel_endpoint: true,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -246,6 +247,7 @@ async fn test_interface_create_rejects_dia_zero_bandwidth() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -304,6 +306,7 @@ async fn test_interface_update_rejects_setting_cyoa_without_bandwidth() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -343,6 +346,7 @@ async fn test_interface_update_rejects_setting_cyoa_without_bandwidth() {
             node_segment_idx: None,
             topology_count: 0,
             update_topologies: false,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -486,6 +490,7 @@ async fn test_link_create_rejects_insufficient_interface_bandwidth() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_a_pubkey, false),
@@ -556,6 +561,7 @@ async fn test_link_create_rejects_insufficient_interface_bandwidth() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_z_pubkey, false),
@@ -796,6 +802,7 @@ async fn setup_dzx_link_for_accept(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_a_pubkey, false),
@@ -866,6 +873,7 @@ async fn setup_dzx_link_for_accept(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_z_pubkey, false),
@@ -1022,6 +1030,7 @@ async fn test_link_accept_rejects_insufficient_side_a_bandwidth() {
             node_segment_idx: None,
             topology_count: 0,
             update_topologies: false,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(env.device_a_pubkey, false),
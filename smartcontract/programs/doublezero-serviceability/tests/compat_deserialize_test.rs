@@ -0,0 +1,1031 @@
+//! Property-based compatibility tests for the tolerant account readers.
+//!
+//! Every state struct gets a strategy spanning its fields. Generated values
+//! must round trip, every truncation of their bytes must be read or rejected
+//! without panicking, and arbitrary bytes accepted by a reader must be stable
+//! across a write and a second read. See
+//! `doublezero_program_common::compat_deserialize`.
+
+use doublezero_program_common::compat_deserialize::{
+    check_reread_stable, check_round_trip, check_truncated,
+    strategies::{
+        borsh_enum, coordinate, ipv4, network_v4, network_v4_list, pubkey, pubkeys, text,
+    },
+};
+use doublezero_serviceability::{
+    programversion::ProgramVersion,
+    state::{
+        accesspass::AccessPass,
+        accounttype::AccountType,
+        anycast_prefix::AnycastPrefix,
+        contributor::Contributor,
+        device::Device,
+        exchange::Exchange,
+        feed::Feed,
+        globalconfig::GlobalConfig,
+        globalstate::GlobalState,
+        index::Index,
+        interface::Interface,
+        interface_oper_status::{InterfaceOperStatus, InterfaceOperStatusEntry},
+        ip_allocation::IpAllocation,
+        link::Link,
+        location::Location,
+        multicastgroup::MulticastGroup,
+        ops_lock::OpsLock,
+        permission::Permission,
+        programconfig::ProgramConfig,
+        qos_profile::QosProfile,
+        read_api_key::ReadApiKey,
+        referrer_stats::ReferrerStats,
+        resource_extension::ResourceExtensionOwned,
+        state_commitment::StateCommitment,
+        tenant::Tenant,
+        topology::TopologyInfo,
+        user::User,
+    },
+};
+use proptest::{collection::vec, prelude::*};
+
+fn globalstate() -> impl Strategy<Value = GlobalState> {
+    (
+        (
+            any::<u8>(),
+            any::<u128>(),
+            pubkeys(4),
+            pubkeys(2),
+            pubkeys(2),
+        ),
+        (pubkey(), pubkey(), any::<u64>(), any::<u64>(), pubkey()),
+        (pubkeys(4), any::<u128>(), pubkey(), any::<u64>()),
+    )
+        .prop_map(
+            |(
+                (bump_seed, account_index, foundation_allowlist, device_allowlist, user_allowlist),
+                (
+                    activator_authority_pk,
+                    sentinel_authority_pk,
+                    contributor_airdrop_lamports,
+                    user_airdrop_lamports,
+                    health_oracle_pk,
+                ),
+                (qa_allowlist, feature_flags, feed_authority_pk, paused),
+            )| GlobalState {
+                account_type: AccountType::GlobalState,
+                bump_seed,
+                account_index,
+                foundation_allowlist,
+                _device_allowlist: device_allowlist,
+                _user_allowlist: user_allowlist,
+                activator_authority_pk,
+                sentinel_authority_pk,
+                contributor_airdrop_lamports,
+                user_airdrop_lamports,
+                health_oracle_pk,
+                qa_allowlist,
+                feature_flags,
+                feed_authority_pk,
+                paused,
+            },
+        )
+}
+
+fn globalconfig() -> impl Strategy<Value = GlobalConfig> {
+    (
+        (pubkey(), any::<u8>(), any::<u32>(), any::<u32>()),
+        (
+            network_v4(),
+            network_v4(),
+            network_v4(),
+            any::<u16>(),
+            network_v4(),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, bump_seed, local_asn, remote_asn),
+                (
+                    device_tunnel_block,
+                    user_tunnel_block,
+                    multicastgroup_block,
+                    next_bgp_community,
+                    multicast_publisher_block,
+                ),
+            )| GlobalConfig {
+                account_type: AccountType::GlobalConfig,
+                owner,
+                bump_seed,
+                local_asn,
+                remote_asn,
+                device_tunnel_block,
+                user_tunnel_block,
+                multicastgroup_block,
+                next_bgp_community,
+                multicast_publisher_block,
+            },
+        )
+}
+
+fn location() -> impl Strategy<Value = Location> {
+    (
+        (
+            pubkey(),
+            any::<u128>(),
+            any::<u8>(),
+            coordinate(),
+            coordinate(),
+        ),
+        (
+            any::<u32>(),
+            borsh_enum(8),
+            text(),
+            text(),
+            text(),
+            any::<u32>(),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, lat, lng),
+                (loc_id, status, code, name, country, reference_count),
+            )| Location {
+                account_type: AccountType::Location,
+                owner,
+                index,
+                bump_seed,
+                lat,
+                lng,
+                loc_id,
+                status,
+                code,
+                name,
+                country,
+                reference_count,
+            },
+        )
+}
+
+fn exchange() -> impl Strategy<Value = Exchange> {
+    (
+        (
+            pubkey(),
+            any::<u128>(),
+            any::<u8>(),
+            coordinate(),
+            coordinate(),
+        ),
+        (any::<u16>(), any::<u16>(), borsh_enum(8), text(), text()),
+        (any::<u32>(), pubkey(), pubkey()),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, lat, lng),
+                (bgp_community, unused, status, code, name),
+                (reference_count, device1_pk, device2_pk),
+            )| Exchange {
+                account_type: AccountType::Exchange,
+                owner,
+                index,
+                bump_seed,
+                lat,
+                lng,
+                bgp_community,
+                unused,
+                status,
+                code,
+                name,
+                reference_count,
+                device1_pk,
+                device2_pk,
+            },
+        )
+}
+
+fn interface() -> impl Strategy<Value = Interface> {
+    (
+        (
+            borsh_enum(8),
+            text(),
+            borsh_enum(8),
+            borsh_enum(8),
+            borsh_enum(8),
+        ),
+        (
+            borsh_enum(8),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u16>(),
+            borsh_enum(4),
+        ),
+        (any::<u16>(), network_v4(), any::<u16>(), any::<bool>()),
+    )
+        .prop_map(
+            |(
+                (status, name, interface_type, interface_cyoa, interface_dia),
+                (loopback_type, bandwidth, cir, mtu, routing_mode),
+                (vlan_id, ip_net, node_segment_idx, user_tunnel_endpoint),
+            )| {
+                let mut iface = Interface {
+                    status,
+                    name,
+                    interface_type,
+                    interface_cyoa,
+                    interface_dia,
+                    loopback_type,
+                    bandwidth,
+                    cir,
+                    mtu,
+                    routing_mode,
+                    vlan_id,
+                    ip_net,
+                    node_segment_idx,
+                    user_tunnel_endpoint,
+                    ..Interface::default()
+                };
+                iface.size = iface.compute_on_disk_size().unwrap();
+                iface
+            },
+        )
+}
+
+fn ops_lock() -> impl Strategy<Value = OpsLock> {
+    (pubkey(), any::<i64>(), text()).prop_map(|(locked_by, expires_at, reason)| OpsLock {
+        locked_by,
+        expires_at,
+        reason,
+    })
+}
+
+fn device() -> impl Strategy<Value = Device> {
+    (
+        (
+            pubkey(),
+            any::<u128>(),
+            any::<u8>(),
+            pubkey(),
+            pubkey(),
+            borsh_enum(4),
+        ),
+        (
+            ipv4(),
+            borsh_enum(16),
+            text(),
+            network_v4_list(4),
+            pubkey(),
+            pubkey(),
+        ),
+        (
+            text(),
+            any::<u32>(),
+            any::<[u16; 9]>(),
+            borsh_enum(8),
+            borsh_enum(8),
+        ),
+        (
+            vec(interface(), 0..4),
+            any::<u64>(),
+            any::<u64>(),
+            pubkey(),
+            any::<u64>(),
+            any::<[u8; 32]>(),
+            pubkey(),
+            ops_lock(),
+            pubkey(),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, location_pk, exchange_pk, device_type),
+                (public_ip, status, code, dz_prefixes, metrics_publisher_pk, contributor_pk),
+                (mgmt_vrf, reference_count, counters, device_health, desired_status),
+                (
+                    interfaces,
+                    config_generation,
+                    applied_config_generation,
+                    previous_metrics_publisher_pk,
+                    metrics_publisher_overlap_until_epoch,
+                    attestation_challenge,
+                    attested_key,
+                    ops_lock,
+                    co_owner_pk,
+                ),
+            )| {
+                let mut device = Device {
+                    account_type: AccountType::Device,
+                    owner,
+                    index,
+                    bump_seed,
+                    location_pk,
+                    exchange_pk,
+                    device_type,
+                    public_ip,
+                    status,
+                    code,
+                    dz_prefixes,
+                    metrics_publisher_pk,
+                    contributor_pk,
+                    mgmt_vrf,
+                    deprecated_interfaces: vec![],
+                    reference_count,
+                    users_count: counters[0],
+                    max_users: counters[1],
+                    device_health,
+                    desired_status,
+                    unicast_users_count: counters[2],
+                    multicast_subscribers_count: counters[3],
+                    max_unicast_users: counters[4],
+                    max_multicast_subscribers: counters[5],
+                    reserved_seats: counters[6],
+                    multicast_publishers_count: counters[7],
+                    max_multicast_publishers: counters[8],
+                    interfaces: vec![],
+                    config_generation,
+                    applied_config_generation,
+                    previous_metrics_publisher_pk,
+                    metrics_publisher_overlap_until_epoch,
+                    attestation_challenge,
+                    attested_key,
+                    ops_lock,
+                    co_owner_pk,
+                };
+                for iface in interfaces {
+                    device.push_interface(iface);
+                }
+                device
+            },
+        )
+}
+
+fn link() -> impl Strategy<Value = Link> {
+    (
+        (
+            pubkey(),
+            any::<u128>(),
+            any::<u8>(),
+            pubkey(),
+            pubkey(),
+            borsh_enum(4),
+        ),
+        (
+            any::<u64>(),
+            any::<u32>(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u16>(),
+        ),
+        (
+            network_v4(),
+            borsh_enum(16),
+            text(),
+            pubkey(),
+            text(),
+            text(),
+        ),
+        (
+            any::<u64>(),
+            borsh_enum(8),
+            borsh_enum(8),
+            pubkeys(4),
+            any::<u32>(),
+        ),
+        (
+            any::<u32>(),
+            any::<u32>(),
+            any::<u64>(),
+            any::<u64>(),
+            ops_lock(),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, side_a_pk, side_z_pk, link_type),
+                (bandwidth, mtu, delay_ns, jitter_ns, tunnel_id),
+                (tunnel_net, status, code, contributor_pk, side_a_iface_name, side_z_iface_name),
+                (delay_override_ns, link_health, desired_status, link_topologies, link_flags),
+                (flap_count, flap_penalty, flap_epoch, damped_until_epoch, ops_lock),
+            )| Link {
+                account_type: AccountType::Link,
+                owner,
+                index,
+                bump_seed,
+                side_a_pk,
+                side_z_pk,
+                link_type,
+                bandwidth,
+                mtu,
+                delay_ns,
+                jitter_ns,
+                tunnel_id,
+                tunnel_net,
+                status,
+                code,
+                contributor_pk,
+                side_a_iface_name,
+                side_z_iface_name,
+                delay_override_ns,
+                link_health,
+                desired_status,
+                link_topologies,
+                link_flags,
+                flap_count,
+                flap_penalty,
+                flap_epoch,
+                damped_until_epoch,
+                ops_lock,
+            },
+        )
+}
+
+fn user() -> impl Strategy<Value = User> {
+    (
+        (
+            pubkey(),
+            any::<u128>(),
+            any::<u8>(),
+            borsh_enum(8),
+            pubkey(),
+            pubkey(),
+        ),
+        (
+            borsh_enum(8),
+            ipv4(),
+            ipv4(),
+            any::<u16>(),
+            network_v4(),
+            borsh_enum(16),
+        ),
+        (
+            pubkeys(4),
+            pubkeys(4),
+            pubkey(),
+            ipv4(),
+            any::<u8>(),
+            borsh_enum(4),
+        ),
+        (
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            pubkey(),
+            borsh_enum(8),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, user_type, tenant_pk, device_pk),
+                (cyoa_type, client_ip, dz_ip, tunnel_id, tunnel_net, status),
+                (
+                    publishers,
+                    subscribers,
+                    validator_pubkey,
+                    tunnel_endpoint,
+                    tunnel_flags,
+                    bgp_status,
+                ),
+                (last_bgp_up_at, last_bgp_reported_at, bgp_rtt_ns, feed_pk, bandwidth_tier),
+            )| User {
+                account_type: AccountType::User,
+                owner,
+                index,
+                bump_seed,
+                user_type,
+                tenant_pk,
+                device_pk,
+                cyoa_type,
+                client_ip,
+                dz_ip,
+                tunnel_id,
+                tunnel_net,
+                status,
+                publishers,
+                subscribers,
+                validator_pubkey,
+                tunnel_endpoint,
+                tunnel_flags,
+                bgp_status,
+                last_bgp_up_at,
+                last_bgp_reported_at,
+                bgp_rtt_ns,
+                feed_pk,
+                bandwidth_tier,
+            },
+        )
+}
+
+fn multicastgroup() -> impl Strategy<Value = MulticastGroup> {
+    (
+        (pubkey(), any::<u128>(), any::<u8>(), pubkey(), ipv4()),
+        (
+            any::<u64>(),
+            borsh_enum(8),
+            text(),
+            any::<u32>(),
+            any::<u32>(),
+        ),
+    )
+        .prop_map(
+            |(
+                (owner, index, bump_seed, tenant_pk, multicast_ip),
+                (max_bandwidth, status, code, publisher_count, subscriber_count),
+            )| MulticastGroup {
+                account_type: AccountType::MulticastGroup,
+                owner,
+                index,
+                bump_seed,
+                tenant_pk,
+                multicast_ip,
+                max_bandwidth,
+                status,
+                code,
+                publisher_count,
+                subscriber_count,
+            },
+        )
+}
+
+fn programconfig() -> impl Strategy<Value = ProgramConfig> {
+    (any::<u8>(), any::<[u32; 3]>(), any::<[u32; 3]>()).prop_map(
+        |(bump_seed, [major, minor, patch], min)| ProgramConfig {
+            account_type: AccountType::ProgramConfig,
+            bump_seed,
+            version: ProgramVersion {
+                major,
+                minor,
+                patch,
+            },
+            min_compatible_version: ProgramVersion {
+                major: min[0],
+                minor: min[1],
+                patch: min[2],
+            },
+        },
+    )
+}
+
+fn contributor() -> impl Strategy<Value = Contributor> {
+    (
+        pubkey(),
+        any::<u128>(),
+        any::<u8>(),
+        borsh_enum(8),
+        text(),
+        any::<u32>(),
+        pubkey(),
+    )
+        .prop_map(
+            |(owner, index, bump_seed, status, code, reference_count, ops_manager_pk)| {
+                Contributor {
+                    account_type: AccountType::Contributor,
+                    owner,
+                    index,
+                    bump_seed,
+                    status,
+                    code,
+                    reference_count,
+                    ops_manager_pk,
+                }
+            },
+        )
+}
+
+fn accesspass() -> impl Strategy<Value = AccessPass> {
+    (
+        (
+            pubkey(),
+            any::<u8>(),
+            borsh_enum(8),
+            ipv4(),
+            pubkey(),
+            any::<u64>(),
+        ),
+        (
+            any::<u16>(),
+            borsh_enum(8),
+            pubkeys(4),
+            pubkeys(4),
+            any::<u8>(),
+        ),
+        (pubkeys(4), any::<[u16; 4]>(), borsh_enum(8), pubkey()),
+    )
+        .prop_map(
+            |(
+                (owner, bump_seed, accesspass_type, client_ip, user_payer, last_access_epoch),
+                (connection_count, status, mgroup_pub_allowlist, mgroup_sub_allowlist, flags),
+                (tenant_allowlist, counts, bandwidth_tier, referrer),
+            )| AccessPass {
+                account_type: AccountType::AccessPass,
+                owner,
+                bump_seed,
+                accesspass_type,
+                client_ip,
+                user_payer,
+                last_access_epoch,
+                connection_count,
+                status,
+                mgroup_pub_allowlist,
+                mgroup_sub_allowlist,
+                flags,
+                tenant_allowlist,
+                unicast_user_count: counts[0],
+                max_unicast_users: counts[1],
+                multicast_user_count: counts[2],
+                max_multicast_users: counts[3],
+                bandwidth_tier,
+                referrer,
+            },
+        )
+}
+
+fn tenant() -> impl Strategy<Value = Tenant> {
+    (
+        (pubkey(), any::<u8>(), text(), any::<u16>(), any::<u32>()),
+        (
+            pubkeys(4),
+            borsh_enum(4),
+            pubkey(),
+            any::<bool>(),
+            any::<bool>(),
+        ),
+        (borsh_enum(1), pubkeys(4)),
+    )
+        .prop_map(
+            |(
+                (owner, bump_seed, code, vrf_id, reference_count),
+                (administrators, payment_status, token_account, metro_routing, route_liveness),
+                (billing, include_topologies),
+            )| Tenant {
+                account_type: AccountType::Tenant,
+                owner,
+                bump_seed,
+                code,
+                vrf_id,
+                reference_count,
+                administrators,
+                payment_status,
+                token_account,
+                metro_routing,
+                route_liveness,
+                billing,
+                include_topologies,
+            },
+        )
+}
+
+fn permission() -> impl Strategy<Value = Permission> {
+    (
+        pubkey(),
+        any::<u8>(),
+        borsh_enum(4),
+        pubkey(),
+        any::<u128>(),
+    )
+        .prop_map(
+            |(owner, bump_seed, status, user_payer, permissions)| Permission {
+                account_type: AccountType::Permission,
+                owner,
+                bump_seed,
+                status,
+                user_payer,
+                permissions,
+            },
+        )
+}
+
+fn index() -> impl Strategy<Value = Index> {
+    (pubkey(), borsh_enum(21), text(), any::<u8>()).prop_map(
+        |(pk, entity_account_type, key, bump_seed)| Index {
+            account_type: AccountType::Index,
+            pk,
+            entity_account_type,
+            key,
+            bump_seed,
+        },
+    )
+}
+
+fn topology() -> impl Strategy<Value = TopologyInfo> {
+    (
+        pubkey(),
+        any::<u8>(),
+        text(),
+        0u8..128,
+        borsh_enum(2),
+        any::<u32>(),
+    )
+        .prop_map(
+            |(owner, bump_seed, name, admin_group_bit, constraint, reference_count)| TopologyInfo {
+                account_type: AccountType::Topology,
+                owner,
+                bump_seed,
+                name,
+                admin_group_bit,
+                flex_algo_number: 128 + admin_group_bit,
+                constraint,
+                reference_count,
+            },
+        )
+}
+
+fn feed() -> impl Strategy<Value = Feed> {
+    (pubkey(), any::<u8>(), text(), text(), pubkey(), pubkeys(4)).prop_map(
+        |(owner, bump_seed, code, name, exchange, groups)| Feed {
+            account_type: AccountType::Feed,
+            owner,
+            bump_seed,
+            code,
+            name,
+            exchange,
+            groups,
+        },
+    )
+}
+
+fn read_api_key() -> impl Strategy<Value = ReadApiKey> {
+    (
+        (pubkey(), any::<u8>(), borsh_enum(4), pubkey()),
+        (any::<[u8; 32]>(), text(), any::<i64>(), any::<i64>()),
+    )
+        .prop_map(
+            |(
+                (owner, bump_seed, status, contributor_pk),
+                (key_hash, label, created_at, expires_at),
+            )| ReadApiKey {
+                account_type: AccountType::ReadApiKey,
+                owner,
+                bump_seed,
+                status,
+                contributor_pk,
+                key_hash,
+                label,
+                created_at,
+                expires_at,
+            },
+        )
+}
+
+fn qos_profile() -> impl Strategy<Value = QosProfile> {
+    (
+        pubkey(),
+        any::<u8>(),
+        borsh_enum(8),
+        any::<u16>(),
+        any::<u32>(),
+        any::<u32>(),
+        any::<u8>(),
+    )
+        .prop_map(
+            |(owner, bump_seed, bandwidth_tier, profile_id, rate_mbps, burst_kbytes, dscp)| {
+                QosProfile {
+                    account_type: AccountType::QosProfile,
+                    owner,
+                    bump_seed,
+                    bandwidth_tier,
+                    profile_id,
+                    rate_mbps,
+                    burst_kbytes,
+                    dscp,
+                }
+            },
+        )
+}
+
+fn ip_allocation() -> impl Strategy<Value = IpAllocation> {
+    (
+        pubkey(),
+        any::<u8>(),
+        borsh_enum(4),
+        pubkey(),
+        network_v4(),
+        text(),
+    )
+        .prop_map(
+            |(owner, bump_seed, status, contributor_pk, prefix, upstream)| IpAllocation {
+                account_type: AccountType::IpAllocation,
+                owner,
+                bump_seed,
+                status,
+                contributor_pk,
+                prefix,
+                upstream,
+            },
+        )
+}
+
+fn interface_oper_status_entry() -> impl Strategy<Value = InterfaceOperStatusEntry> {
+    (
+        text(),
+        borsh_enum(5),
+        any::<i32>(),
+        any::<i32>(),
+        (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+        any::<i64>(),
+    )
+        .prop_map(
+            |(
+                name,
+                oper_status,
+                rx_power_mdbm,
+                tx_power_mdbm,
+                (in_errors, out_errors, in_discards, out_discards),
+                updated_at,
+            )| InterfaceOperStatusEntry {
+                name,
+                oper_status,
+                rx_power_mdbm,
+                tx_power_mdbm,
+                in_errors,
+                out_errors,
+                in_discards,
+                out_discards,
+                updated_at,
+            },
+        )
+}
+
+fn interface_oper_status() -> impl Strategy<Value = InterfaceOperStatus> {
+    (
+        pubkey(),
+        any::<u8>(),
+        pubkey(),
+        any::<i64>(),
+        vec(interface_oper_status_entry(), 0..4),
+    )
+        .prop_map(|(owner, bump_seed, device_pk, updated_at, interfaces)| {
+            InterfaceOperStatus {
+                account_type: AccountType::InterfaceOperStatus,
+                owner,
+                bump_seed,
+                device_pk,
+                updated_at,
+                interfaces,
+            }
+        })
+}
+
+fn anycast_prefix() -> impl Strategy<Value = AnycastPrefix> {
+    (pubkey(), any::<u8>(), text(), network_v4(), any::<bool>()).prop_map(
+        |(owner, bump_seed, code, prefix, enabled)| AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            owner,
+            bump_seed,
+            code,
+            prefix,
+            enabled,
+        },
+    )
+}
+
+fn referrer_stats() -> impl Strategy<Value = ReferrerStats> {
+    (pubkey(), any::<u8>(), any::<[u64; 3]>()).prop_map(|(referrer, bump_seed, counters)| {
+        ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer,
+            bump_seed,
+            referral_count: counters[0],
+            first_referral_epoch: counters[1],
+            last_referral_epoch: counters[2],
+        }
+    })
+}
+
+fn state_commitment() -> impl Strategy<Value = StateCommitment> {
+    (
+        any::<u8>(),
+        any::<[u64; 2]>(),
+        any::<[u8; 32]>(),
+        any::<u32>(),
+        pubkey(),
+    )
+        .prop_map(
+            |(bump_seed, slots, merkle_root, account_count, recorded_by)| StateCommitment {
+                account_type: AccountType::StateCommitment,
+                bump_seed,
+                slot: slots[0],
+                merkle_root,
+                account_count,
+                recorded_by,
+                recorded_at_slot: slots[1],
+            },
+        )
+}
+
+/// Checks every compatibility property of `value`.
+fn check_compat<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: borsh::BorshSerialize + for<'a> TryFrom<&'a [u8]> + PartialEq + std::fmt::Debug,
+{
+    check_round_trip(value).map_err(TestCaseError::fail)?;
+    check_truncated(value).map_err(TestCaseError::fail)?;
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_compat_globalstate(value in globalstate()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_globalconfig(value in globalconfig()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_location(value in location()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_exchange(value in exchange()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_device(value in device()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_link(value in link()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_user(value in user()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_multicastgroup(value in multicastgroup()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_programconfig(value in programconfig()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_contributor(value in contributor()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_accesspass(value in accesspass()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_tenant(value in tenant()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_permission(value in permission()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_index(value in index()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_topology(value in topology()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_feed(value in feed()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_read_api_key(value in read_api_key()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_qos_profile(value in qos_profile()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_ip_allocation(value in ip_allocation()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_interface_oper_status(value in interface_oper_status()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_anycast_prefix(value in anycast_prefix()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_referrer_stats(value in referrer_stats()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_state_commitment(value in state_commitment()) { check_compat(&value)?; }
+
+    #[test]
+    fn test_compat_arbitrary_bytes(account_type in borsh_enum::<AccountType>(26), tail in vec(any::<u8>(), 0..512)) {
+        let mut data = vec![account_type as u8];
+        data.extend(tail);
+        let check = match account_type {
+            AccountType::GlobalState => check_reread_stable::<GlobalState>(&data),
+            AccountType::GlobalConfig => check_reread_stable::<GlobalConfig>(&data),
+            AccountType::Location => check_reread_stable::<Location>(&data),
+            AccountType::Exchange => check_reread_stable::<Exchange>(&data),
+            AccountType::Device => check_reread_stable::<Device>(&data),
+            AccountType::Link => check_reread_stable::<Link>(&data),
+            AccountType::User => check_reread_stable::<User>(&data),
+            AccountType::MulticastGroup => check_reread_stable::<MulticastGroup>(&data),
+            AccountType::ProgramConfig => check_reread_stable::<ProgramConfig>(&data),
+            AccountType::Contributor => check_reread_stable::<Contributor>(&data),
+            AccountType::AccessPass => check_reread_stable::<AccessPass>(&data),
+            AccountType::Tenant => check_reread_stable::<Tenant>(&data),
+            AccountType::Permission => check_reread_stable::<Permission>(&data),
+            AccountType::Index => check_reread_stable::<Index>(&data),
+            AccountType::Topology => check_reread_stable::<TopologyInfo>(&data),
+            AccountType::Feed => check_reread_stable::<Feed>(&data),
+            AccountType::ReadApiKey => check_reread_stable::<ReadApiKey>(&data),
+            AccountType::QosProfile => check_reread_stable::<QosProfile>(&data),
+            AccountType::IpAllocation => check_reread_stable::<IpAllocation>(&data),
+            AccountType::InterfaceOperStatus => check_reread_stable::<InterfaceOperStatus>(&data),
+            AccountType::AnycastPrefix => check_reread_stable::<AnycastPrefix>(&data),
+            AccountType::ReferrerStats => check_reread_stable::<ReferrerStats>(&data),
+            AccountType::StateCommitment => check_reread_stable::<StateCommitment>(&data),
+            // The bitmap is stored raw after a fixed-size header, so the
+            // extension is only required not to panic.
+            AccountType::ResourceExtension => {
+                let _ = ResourceExtensionOwned::try_from(&data[..]);
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        check.map_err(TestCaseError::fail)?;
+    }
+}
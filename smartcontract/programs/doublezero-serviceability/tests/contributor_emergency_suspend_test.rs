@@ -0,0 +1,244 @@
+//! `EmergencySuspendContributor` suspends a contributor, which blocks it from
+//! creating new resources; `EmergencyDrainContributor` then drains its devices
+//! and links in bounded batches.
+
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        contributor::{
+            emergency_drain::{ContributorEmergencyDrainArgs, MAX_EMERGENCY_DRAIN_BATCH},
+            emergency_suspend::ContributorEmergencySuspendArgs,
+            resume::ContributorResumeArgs,
+        },
+        device::create::DeviceCreateArgs,
+    },
+    resource::ResourceType,
+    state::{contributor::ContributorStatus, device::*},
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn device_args(code: &str, dz_prefixes: &str) -> DeviceCreateArgs {
+    DeviceCreateArgs {
+        code: code.to_string(),
+        device_type: DeviceType::Hybrid,
+        public_ip: [8, 8, 8, 8].into(),
+        dz_prefixes: dz_prefixes.parse().unwrap(),
+        metrics_publisher_pk: Pubkey::default(),
+        mgmt_vrf: "mgmt".to_string(),
+        desired_status: Some(DeviceDesiredStatus::Activated),
+        resource_count: 2,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn create_device(
+    banks_client: &mut BanksClient,
+    program_id: Pubkey,
+    payer: &Keypair,
+    globalstate_pubkey: Pubkey,
+    globalconfig_pubkey: Pubkey,
+    location_pubkey: Pubkey,
+    exchange_pubkey: Pubkey,
+    contributor_pubkey: Pubkey,
+    args: DeviceCreateArgs,
+) -> (Pubkey, Result<(), BanksClientError>) {
+    let globalstate_account = get_globalstate(banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+    let recent_blockhash = wait_for_new_blockhash(banks_client).await;
+    let result = try_execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(args),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        payer,
+    )
+    .await;
+    (device_pubkey, result)
+}
+
+#[tokio::test]
+async fn test_emergency_suspend_contributor_drains_devices() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    // More devices than a single drain batch, and than one transaction could carry.
+    let mut device_pubkeys = Vec::new();
+    for i in 0..MAX_EMERGENCY_DRAIN_BATCH + 6 {
+        let (device_pubkey, result) = create_device(
+            &mut banks_client,
+            program_id,
+            &payer,
+            globalstate_pubkey,
+            globalconfig_pubkey,
+            location_pubkey,
+            exchange_pubkey,
+            contributor_pubkey,
+            device_args(&format!("d{i}"), &format!("110.{}.0.0/23", 2 * i)),
+        )
+        .await;
+        result.unwrap();
+        device_pubkeys.push(device_pubkey);
+    }
+    let generation_before = get_device(&mut banks_client, device_pubkeys[0])
+        .await
+        .unwrap()
+        .config_generation;
+
+    let drain_accounts = |batch: &[Pubkey]| {
+        let mut accounts = vec![
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ];
+        accounts.extend(batch.iter().map(|pk| AccountMeta::new(*pk, false)));
+        accounts
+    };
+    let drain =
+        || DoubleZeroInstruction::EmergencyDrainContributor(ContributorEmergencyDrainArgs {});
+
+    // Draining requires the contributor to be suspended first.
+    // DoubleZeroError::InvalidStatus = Custom(7)
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        drain(),
+        drain_accounts(&device_pubkeys[..1]),
+        &payer,
+    )
+    .await;
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(error_string.contains("Custom(7)"), "got: {error_string}");
+
+    try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::EmergencySuspendContributor(ContributorEmergencySuspendArgs {}),
+        vec![
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let contributor = get_account_data(&mut banks_client, contributor_pubkey)
+        .await
+        .expect("Unable to get Account")
+        .get_contributor()
+        .unwrap();
+    assert_eq!(contributor.status, ContributorStatus::Suspended);
+
+    // A suspended contributor cannot create new devices, even before it is drained.
+    let (_, result) = create_device(
+        &mut banks_client,
+        program_id,
+        &payer,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        location_pubkey,
+        exchange_pubkey,
+        contributor_pubkey,
+        device_args("la2", "111.0.0.0/23"),
+    )
+    .await;
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(
+        error_string.contains("Custom(108)"),
+        "Expected ContributorSuspended error (Custom(108)), got: {}",
+        error_string
+    );
+
+    // A batch above the bound is rejected. DoubleZeroError::InvalidArgument = Custom(65)
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        drain(),
+        drain_accounts(&device_pubkeys[..MAX_EMERGENCY_DRAIN_BATCH + 1]),
+        &payer,
+    )
+    .await;
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(error_string.contains("Custom(65)"), "got: {error_string}");
+
+    for batch in device_pubkeys.chunks(MAX_EMERGENCY_DRAIN_BATCH) {
+        try_execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            drain(),
+            drain_accounts(batch),
+            &payer,
+        )
+        .await
+        .unwrap();
+    }
+
+    for device_pubkey in &device_pubkeys {
+        let device = get_device(&mut banks_client, *device_pubkey).await.unwrap();
+        assert_eq!(device.desired_status, DeviceDesiredStatus::Drained);
+    }
+    let device = get_device(&mut banks_client, device_pubkeys[0])
+        .await
+        .unwrap();
+    assert!(device.config_generation > generation_before);
+
+    // Once resumed it can create devices again.
+    try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::ResumeContributor(ContributorResumeArgs {}),
+        vec![
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await
+    .unwrap();
+    let (_, result) = create_device(
+        &mut banks_client,
+        program_id,
+        &payer,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        location_pubkey,
+        exchange_pubkey,
+        contributor_pubkey,
+        device_args("la2", "111.0.0.0/23"),
+    )
+    .await;
+    result.unwrap();
+}
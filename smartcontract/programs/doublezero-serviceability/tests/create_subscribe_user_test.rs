@@ -7,6 +7,7 @@
 //! - Backward compatibility (old args without dz_prefix_count)
 //! - Invalid multicast group status rejection (graceful error, not panic)
 //! - Foundation allowlist owner override (custom owner for user creation)
+//! - Bandwidth tier bounded by the access pass tier
 
 use doublezero_serviceability::{
     entrypoint::process_instruction,
@@ -44,7 +45,7 @@ use doublezero_serviceability::{
         accesspass::AccessPassType,
         device::DeviceType,
         permission::permission_flags,
-        user::{TunnelFlags, UserCYOA, UserStatus, UserType},
+        user::{BandwidthTier, TunnelFlags, UserCYOA, UserStatus, UserType},
     },
 };
 use solana_program_test::*;
@@ -273,6 +274,8 @@ async fn setup_create_subscribe_fixture(client_ip: [u8; 4]) -> CreateSubscribeFi
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -384,6 +387,7 @@ async fn test_create_subscribe_user_atomic_publisher() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -468,6 +472,7 @@ async fn test_create_subscribe_user_atomic_subscriber() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -575,6 +580,8 @@ async fn test_create_subscribe_user_ignores_tenant_allowlist() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -640,6 +647,7 @@ async fn test_create_subscribe_user_ignores_tenant_allowlist() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -703,6 +711,8 @@ async fn test_create_subscribe_user_ignores_expired_epoch() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -729,6 +739,7 @@ async fn test_create_subscribe_user_ignores_expired_epoch() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -798,6 +809,7 @@ async fn test_check_access_pass_multicast_stays_activated() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -834,6 +846,8 @@ async fn test_check_access_pass_multicast_stays_activated() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1076,6 +1090,8 @@ async fn test_create_subscribe_user_foundation_owner_override() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1122,6 +1138,7 @@ async fn test_create_subscribe_user_foundation_owner_override() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: custom_owner,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1380,6 +1397,8 @@ async fn test_create_subscribe_user_sentinel_owner_override() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1426,6 +1445,7 @@ async fn test_create_subscribe_user_sentinel_owner_override() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: custom_owner,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1673,6 +1693,8 @@ async fn test_create_subscribe_user_user_admin_owner_override() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1718,6 +1740,7 @@ async fn test_create_subscribe_user_user_admin_owner_override() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: custom_owner,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1959,6 +1982,8 @@ async fn test_create_subscribe_user_non_foundation_owner_override_rejected() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -2006,6 +2031,7 @@ async fn test_create_subscribe_user_non_foundation_owner_override_rejected() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 0,
             owner: custom_owner,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2065,6 +2091,7 @@ async fn test_unsubscribe_pending_user_created_via_create_subscribe() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2169,6 +2196,7 @@ async fn test_subscribe_pending_user_succeeds() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2285,6 +2313,7 @@ async fn test_create_subscribe_user_inactive_mgroup_fails() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2346,6 +2375,7 @@ async fn test_publisher_multicast_publisher_persists_through_disconnect() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2458,6 +2488,7 @@ async fn test_publisher_disconnect_delete_decrements_publishers_count() {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -2543,3 +2574,118 @@ async fn test_publisher_disconnect_delete_decrements_publishers_count() {
         "subscribers_count must NOT change — user was created as publisher"
     );
 }
+
+/// A user may request a bandwidth tier up to the access pass's tier, and takes
+/// the access pass's tier when it requests none.
+#[tokio::test]
+async fn test_create_subscribe_user_bandwidth_tier_bounded_by_accesspass() {
+    let client_ip = [100, 0, 0, 98];
+    let f = setup_create_subscribe_fixture(client_ip).await;
+    let CreateSubscribeFixture {
+        mut banks_client,
+        payer,
+        program_id,
+        globalstate_pubkey,
+        device_pubkey,
+        accesspass_pubkey,
+        mgroup_pubkey,
+        user_ip,
+        user_tunnel_block,
+        multicast_publisher_block,
+        tunnel_ids,
+        dz_prefix_block,
+    } = f;
+
+    // Raise the access pass to the standard tier (the payer is on the foundation allowlist).
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetAccessPass(SetAccessPassArgs {
+            accesspass_type: AccessPassType::Prepaid,
+            client_ip: user_ip,
+            last_access_epoch: 9999,
+            allow_multiple_ip: false,
+            max_unicast_users: 1,
+            max_multicast_users: 1,
+            bandwidth_tier: Some(BandwidthTier::Standard),
+            referrer: None,
+        }),
+        vec![
+            AccountMeta::new(accesspass_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(payer.pubkey(), false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let accesspass = get_account_data(&mut banks_client, accesspass_pubkey)
+        .await
+        .expect("AccessPass should exist")
+        .get_accesspass()
+        .unwrap();
+    assert_eq!(accesspass.bandwidth_tier, BandwidthTier::Standard);
+
+    let (user_pubkey, _) = get_user_pda(&program_id, &user_ip, UserType::Multicast);
+    let accounts = vec![
+        AccountMeta::new(user_pubkey, false),
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(mgroup_pubkey, false),
+        AccountMeta::new(accesspass_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+        AccountMeta::new(user_tunnel_block, false),
+        AccountMeta::new(multicast_publisher_block, false),
+        AccountMeta::new(tunnel_ids, false),
+        AccountMeta::new(dz_prefix_block, false),
+    ];
+    let args = |bandwidth_tier| UserCreateSubscribeArgs {
+        user_type: UserType::Multicast,
+        cyoa_type: UserCYOA::GREOverDIA,
+        client_ip: user_ip,
+        publisher: false,
+        subscriber: true,
+        tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+        dz_prefix_count: 1,
+        owner: Pubkey::default(),
+        bandwidth_tier,
+    };
+
+    // Above the access pass tier: BandwidthTierNotAllowed (102).
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateSubscribeUser(args(Some(BandwidthTier::Premium))),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(
+        error_string.contains("Custom(102)"),
+        "Expected BandwidthTierNotAllowed error (Custom(102)), got: {error_string}"
+    );
+
+    // No tier requested: the user takes the access pass tier.
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateSubscribeUser(args(None)),
+        accounts,
+        &payer,
+    )
+    .await;
+
+    let user = get_account_data(&mut banks_client, user_pubkey)
+        .await
+        .expect("User should exist")
+        .get_user()
+        .unwrap();
+    assert_eq!(user.status, UserStatus::Activated);
+    assert_eq!(user.bandwidth_tier, BandwidthTier::Standard);
+}
@@ -250,6 +250,8 @@ async fn create_and_activate_user(
             allow_multiple_ip,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -285,6 +287,7 @@ async fn create_and_activate_user(
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -666,6 +669,7 @@ async fn try_create_user(
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -706,6 +710,8 @@ async fn test_edge_seat_user_caps_enforced() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
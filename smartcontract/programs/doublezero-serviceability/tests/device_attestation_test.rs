@@ -0,0 +1,306 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        device::{
+            create::*, issue_challenge::DeviceIssueChallengeArgs, prove_key::DeviceProveKeyArgs,
+            update::*,
+        },
+        globalstate::{setfeatureflags::SetFeatureFlagsArgs, setpause::SetPauseArgs},
+    },
+    resource::ResourceType,
+    state::{device::*, feature_flags::FeatureFlag, pause_flags::PauseFlag},
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_key_attestation() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetFeatureFlags(SetFeatureFlagsArgs {
+            feature_flags: FeatureFlag::RequireDeviceAttestation.to_mask(),
+        }),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    let publisher = Keypair::new();
+    transfer(&mut banks_client, &payer, &publisher.pubkey(), 100_000_000).await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: publisher.pubkey(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // With the flag set, new devices wait in provisioning.
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.status, DeviceStatus::DeviceProvisioning);
+    assert!(!device.is_key_attested());
+
+    let activate = DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+        status: Some(DeviceStatus::Activated),
+        ..DeviceUpdateArgs::default()
+    });
+    let update_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+
+    // DoubleZeroError::DeviceNotAttested = Custom(115)
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        activate.clone(),
+        update_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 115);
+
+    let issue =
+        |nonce| DoubleZeroInstruction::IssueDeviceChallenge(DeviceIssueChallengeArgs { nonce });
+    let issue_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+    let prove = |nonce| DoubleZeroInstruction::ProveDeviceKey(DeviceProveKeyArgs { nonce });
+    let prove_accounts = vec![AccountMeta::new(device_pubkey, false)];
+    let prove_extra_accounts = [AccountMeta::new_readonly(globalstate_pubkey, false)];
+    let nonce = [0x5a; 32];
+
+    // DoubleZeroError::InvalidDeviceAttestation = Custom(116): nothing to answer yet.
+    let result = try_execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove(nonce),
+        prove_accounts.clone(),
+        &publisher,
+        &prove_extra_accounts,
+    )
+    .await;
+    assert_custom_error(result, 116);
+
+    // Only the foundation issues challenges, and never an all-zero nonce.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        issue(nonce),
+        issue_accounts.clone(),
+        &publisher,
+    )
+    .await;
+    assert!(result.is_err());
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        issue([0; 32]),
+        issue_accounts.clone(),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 116);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        issue(nonce),
+        issue_accounts,
+        &payer,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.attestation_challenge, nonce);
+
+    // Proving is an update: it is rejected while updates are paused.
+    let set_pause = |paused| DoubleZeroInstruction::SetPause(SetPauseArgs { paused });
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(PauseFlag::Update.to_mask()),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+    let result = try_execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove(nonce),
+        prove_accounts.clone(),
+        &publisher,
+        &prove_extra_accounts,
+    )
+    .await;
+    // DoubleZeroError::ProgramPaused = Custom(105)
+    assert_custom_error(result, 105);
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(0),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    // The answer must come from the device's metrics publisher...
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let result = try_execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove(nonce),
+        prove_accounts.clone(),
+        &payer,
+        &prove_extra_accounts,
+    )
+    .await;
+    // DoubleZeroError::NotAllowed = Custom(8)
+    assert_custom_error(result, 8);
+
+    // ...and carry the issued nonce.
+    let result = try_execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove([0x11; 32]),
+        prove_accounts.clone(),
+        &publisher,
+        &prove_extra_accounts,
+    )
+    .await;
+    assert_custom_error(result, 116);
+
+    execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove(nonce),
+        prove_accounts.clone(),
+        &publisher,
+        &prove_extra_accounts,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.attestation_challenge, [0; 32]);
+    assert_eq!(device.attested_key, publisher.pubkey());
+    assert!(device.is_key_attested());
+
+    // A challenge is answered once.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let result = try_execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        prove(nonce),
+        prove_accounts,
+        &publisher,
+        &prove_extra_accounts,
+    )
+    .await;
+    assert_custom_error(result, 116);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        activate.clone(),
+        update_accounts.clone(),
+        &payer,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.status, DeviceStatus::Activated);
+
+    // Replacing the metrics publisher voids the proof: once drained, the device
+    // cannot be reactivated until the new key is attested.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+            metrics_publisher_pk: Some(Pubkey::new_unique()),
+            status: Some(DeviceStatus::Drained),
+            ..DeviceUpdateArgs::default()
+        }),
+        update_accounts.clone(),
+        &payer,
+    )
+    .await;
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        activate,
+        update_accounts,
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 115);
+}
@@ -0,0 +1,291 @@
+//! Verifies that a device co-owner gets the contributor owner's update rights on
+//! that device only, that only the contributor owner (or a privileged signer) can
+//! grant it, and that the co-owner loses them once revoked or once the device
+//! moves to another contributor.
+
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        contributor::create::ContributorCreateArgs,
+        device::{
+            create::*, grant_co_owner::DeviceGrantCoOwnerArgs,
+            revoke_co_owner::DeviceRevokeCoOwnerArgs, set_ops_lock::DeviceSetOpsLockArgs,
+            update::*,
+        },
+    },
+    resource::ResourceType,
+    state::{device::*, ops_lock::OpsLock},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    clock::Clock, instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_co_owner() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: payer.pubkey(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // A managed-service provider operating the device for the contributor.
+    let msp = Keypair::new();
+    transfer(&mut banks_client, &payer, &msp.pubkey(), 100_000_000).await;
+
+    let device_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+    let grant = |co_owner_pk| {
+        DoubleZeroInstruction::GrantDeviceCoOwner(DeviceGrantCoOwnerArgs { co_owner_pk })
+    };
+    let revoke = || DoubleZeroInstruction::RevokeDeviceCoOwner(DeviceRevokeCoOwnerArgs {});
+    let update = |max_users| {
+        DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+            max_users: Some(max_users),
+            ..DeviceUpdateArgs::default()
+        })
+    };
+
+    // Without co-ownership the provider cannot touch the device.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(64),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    // DoubleZeroError::NotAllowed = Custom(8)
+    assert_custom_error(result, 8);
+
+    // The co-owner must be set and differ from the contributor owner.
+    for co_owner_pk in [Pubkey::default(), payer.pubkey()] {
+        let result = try_execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            grant(co_owner_pk),
+            device_accounts.clone(),
+            &payer,
+        )
+        .await;
+        // DoubleZeroError::InvalidCoOwner = Custom(121)
+        assert_custom_error(result, 121);
+    }
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        grant(msp.pubkey()),
+        device_accounts.clone(),
+        &payer,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.co_owner_pk, msp.pubkey());
+
+    // The co-owner can now update the device...
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(64),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.max_users, 64);
+
+    // ...but cannot hand co-ownership on.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        grant(Pubkey::new_unique()),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    assert_custom_error(result, 8);
+
+    // Nor can anyone else revoke it.
+    let stranger = Keypair::new();
+    transfer(&mut banks_client, &payer, &stranger.pubkey(), 100_000_000).await;
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        revoke(),
+        device_accounts.clone(),
+        &stranger,
+    )
+    .await;
+    assert_custom_error(result, 8);
+
+    // The co-owner may step down, after which it loses its rights.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        revoke(),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.co_owner_pk, Pubkey::default());
+
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(96),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    assert_custom_error(result, 8);
+
+    // Granted again, the co-owner locks the device...
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        grant(msp.pubkey()),
+        device_accounts.clone(),
+        &payer,
+    )
+    .await;
+    let now = banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetDeviceOpsLock(DeviceSetOpsLockArgs {
+            reason: "msp maintenance".to_string(),
+            expires_at: now + 3600,
+        }),
+        device_accounts.clone(),
+        &msp,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.ops_lock.locked_by, msp.pubkey());
+
+    // ...then the foundation moves it to another contributor.
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (new_contributor_pubkey, _) =
+        get_contributor_pda(&program_id, globalstate_account.account_index + 1);
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateContributor(ContributorCreateArgs {
+            code: "other".to_string(),
+        }),
+        vec![
+            AccountMeta::new(new_contributor_pubkey, false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+            contributor_pk: Some(new_contributor_pubkey),
+            ..DeviceUpdateArgs::default()
+        }),
+        device_accounts,
+        &payer,
+    )
+    .await;
+
+    // Co-ownership and the co-owner's lock do not carry over.
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.contributor_pk, new_contributor_pubkey);
+    assert_eq!(device.co_owner_pk, Pubkey::default());
+    assert_eq!(device.ops_lock, OpsLock::default());
+
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(128),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(new_contributor_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &msp,
+    )
+    .await;
+    assert_custom_error(result, 8);
+}
@@ -0,0 +1,175 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::device::{ackconfig::DeviceAckConfigArgs, create::*, update::*},
+    resource::ResourceType,
+    state::device::*,
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_config_generation_ack() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let metrics_publisher = Keypair::new();
+    transfer(
+        &mut banks_client,
+        &payer,
+        &metrics_publisher.pubkey(),
+        100_000_000,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: metrics_publisher.pubkey(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.config_generation, 0);
+    assert!(!device.is_config_lagging());
+
+    // Every update bumps the generation; the device now lags until acked.
+    for max_users in [10, 20] {
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+                max_users: Some(max_users),
+                ..DeviceUpdateArgs::default()
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(contributor_pubkey, false),
+                AccountMeta::new(location_pubkey, false),
+                AccountMeta::new(location_pubkey, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+    }
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.config_generation, 2);
+    assert_eq!(device.applied_config_generation, 0);
+    assert!(device.is_config_lagging());
+
+    let ack =
+        |generation| DoubleZeroInstruction::AckDeviceConfig(DeviceAckConfigArgs { generation });
+    let ack_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+
+    // Acking a generation that was never issued fails.
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(3),
+        ack_accounts.clone(),
+        &metrics_publisher,
+    )
+    .await;
+    // DoubleZeroError::InvalidConfigGeneration = Custom(104)
+    assert_custom_error(result, 104);
+
+    // An unrelated signer may not ack.
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(1),
+        ack_accounts.clone(),
+        &outsider,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // The device's metrics publisher acks the latest generation.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(2),
+        ack_accounts.clone(),
+        &metrics_publisher,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.applied_config_generation, 2);
+    assert!(!device.is_config_lagging());
+
+    // A late ack for an older generation (here from the foundation) is ignored.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(1),
+        ack_accounts,
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.applied_config_generation, 2);
+    assert_eq!(device.config_generation, 2);
+}
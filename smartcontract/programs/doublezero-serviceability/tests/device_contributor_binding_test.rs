@@ -233,6 +233,7 @@ fn device_interface_args(name: &str) -> DeviceInterfaceCreateArgs {
         routing_mode: RoutingMode::Static,
         use_onchain_allocation: true,
         topology_count: 0,
+        with_ip_allocation: false,
     }
 }
 
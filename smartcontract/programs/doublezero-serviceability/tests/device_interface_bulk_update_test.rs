@@ -0,0 +1,191 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::device::{
+        create::*,
+        interface::{create::*, DeviceInterfaceBulkUpdateArgs, DeviceInterfaceFieldsUpdate},
+    },
+    resource::ResourceType,
+    state::{
+        device::*,
+        interface::{InterfaceCYOA, InterfaceDIA, LoopbackType, RoutingMode},
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_interface_bulk_update_is_atomic() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: Pubkey::default(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    for name in ["Ethernet1", "Ethernet2"] {
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateDeviceInterface(DeviceInterfaceCreateArgs {
+                name: name.to_string(),
+                interface_dia: InterfaceDIA::None,
+                loopback_type: LoopbackType::None,
+                interface_cyoa: InterfaceCYOA::None,
+                bandwidth: 0,
+                ip_net: None,
+                cir: 0,
+                mtu: 9000,
+                routing_mode: RoutingMode::Static,
+                vlan_id: 0,
+                user_tunnel_endpoint: false,
+                use_onchain_allocation: true,
+                topology_count: 0,
+                with_ip_allocation: false,
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(contributor_pubkey, false),
+                AccountMeta::new(globalstate_pubkey, false),
+                AccountMeta::new(
+                    get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
+                    false,
+                ),
+                AccountMeta::new(
+                    get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
+                    false,
+                ),
+            ],
+            &payer,
+        )
+        .await;
+    }
+
+    let generation = get_device(&mut banks_client, device_pubkey)
+        .await
+        .unwrap()
+        .config_generation;
+
+    let bulk = |updates| {
+        DoubleZeroInstruction::BulkUpdateDeviceInterfaces(DeviceInterfaceBulkUpdateArgs { updates })
+    };
+    let vlan = |name: &str, vlan_id| DeviceInterfaceFieldsUpdate {
+        name: name.to_string(),
+        vlan_id: Some(vlan_id),
+        ..Default::default()
+    };
+    let accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+
+    // One invalid update rejects the whole batch.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        bulk(vec![
+            vlan("Ethernet1", 100),
+            DeviceInterfaceFieldsUpdate {
+                mtu: Some(1500),
+                ..vlan("Ethernet2", 200)
+            },
+        ]),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+    // DoubleZeroError::InvalidMtu = Custom(46)
+    assert_custom_error(result, 46);
+
+    // An interface may appear only once, whatever its spelling.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        bulk(vec![vlan("Ethernet1", 100), vlan("ethernet1", 200)]),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+    // DoubleZeroError::InvalidArgument = Custom(65)
+    assert_custom_error(result, 65);
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert!(device.interfaces.iter().all(|iface| iface.vlan_id == 0));
+    assert_eq!(device.config_generation, generation);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        bulk(vec![vlan("Ethernet1", 100), vlan("Ethernet2", 200)]),
+        accounts,
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    let vlans: Vec<u16> = device
+        .interfaces
+        .iter()
+        .map(|iface| iface.vlan_id)
+        .collect();
+    assert_eq!(vlans, vec![100, 200]);
+    // The batch is a single configuration change.
+    assert_eq!(device.config_generation, generation + 1);
+}
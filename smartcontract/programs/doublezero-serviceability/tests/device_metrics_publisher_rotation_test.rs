@@ -0,0 +1,206 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::device::{
+        ackconfig::DeviceAckConfigArgs, create::*,
+        rotate_metrics_publisher::DeviceRotateMetricsPublisherArgs, update::*,
+    },
+    resource::ResourceType,
+    state::device::*,
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_metrics_publisher_rotation() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let old_publisher = Keypair::new();
+    transfer(
+        &mut banks_client,
+        &payer,
+        &old_publisher.pubkey(),
+        100_000_000,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: old_publisher.pubkey(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let new_publisher = Keypair::new();
+    transfer(
+        &mut banks_client,
+        &payer,
+        &new_publisher.pubkey(),
+        100_000_000,
+    )
+    .await;
+
+    let rotate = |metrics_publisher_pk, overlap_epochs| {
+        DoubleZeroInstruction::RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs {
+            metrics_publisher_pk,
+            overlap_epochs,
+        })
+    };
+    let rotate_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+
+    // The new key must be set, differ from the current one, and the overlap is bounded.
+    for (metrics_publisher_pk, overlap_epochs) in [
+        (Pubkey::default(), 1),
+        (old_publisher.pubkey(), 1),
+        (new_publisher.pubkey(), 11),
+    ] {
+        let result = try_execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            rotate(metrics_publisher_pk, overlap_epochs),
+            rotate_accounts.clone(),
+            &payer,
+        )
+        .await;
+        // DoubleZeroError::InvalidMetricsPublisherRotation = Custom(112)
+        assert_custom_error(result, 112);
+    }
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        rotate(new_publisher.pubkey(), 2),
+        rotate_accounts,
+        &payer,
+    )
+    .await;
+
+    let epoch = banks_client
+        .get_sysvar::<solana_sdk::clock::Clock>()
+        .await
+        .unwrap()
+        .epoch;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.metrics_publisher_pk, new_publisher.pubkey());
+    assert_eq!(device.previous_metrics_publisher_pk, old_publisher.pubkey());
+    assert_eq!(device.metrics_publisher_overlap_until_epoch, epoch + 2);
+    assert_eq!(device.config_generation, 1);
+
+    // Both keys are accepted during the overlap.
+    let ack =
+        |generation| DoubleZeroInstruction::AckDeviceConfig(DeviceAckConfigArgs { generation });
+    let ack_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(1),
+        ack_accounts.clone(),
+        &old_publisher,
+    )
+    .await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(1),
+        ack_accounts.clone(),
+        &new_publisher,
+    )
+    .await;
+
+    // Setting the publisher through UpdateDevice revokes the old key at once.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+            metrics_publisher_pk: Some(new_publisher.pubkey()),
+            ..DeviceUpdateArgs::default()
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.previous_metrics_publisher_pk, Pubkey::default());
+    assert_eq!(device.metrics_publisher_overlap_until_epoch, 0);
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        ack(2),
+        ack_accounts,
+        &old_publisher,
+    )
+    .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,211 @@
+//! Verifies that an ops lock on a device rejects changes from anyone but the
+//! signer holding it, and that the lock can be released early or overridden by
+//! the foundation.
+
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        device::{create::*, set_ops_lock::DeviceSetOpsLockArgs, update::*},
+        permission::create::PermissionCreateArgs,
+    },
+    resource::ResourceType,
+    state::{device::*, ops_lock::OpsLock, permission::permission_flags},
+};
+use solana_program_test::*;
+use solana_sdk::{clock::Clock, instruction::AccountMeta, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_device_ops_lock() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: payer.pubkey(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // A NOC operator holding NETWORK_ADMIN, distinct from the contributor owner.
+    let noc = Keypair::new();
+    transfer(&mut banks_client, &payer, &noc.pubkey(), 100_000_000).await;
+    let (noc_permission_pda, _) = get_permission_pda(&program_id, &noc.pubkey());
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreatePermission(PermissionCreateArgs {
+            user_payer: noc.pubkey(),
+            permissions: permission_flags::NETWORK_ADMIN,
+        }),
+        vec![
+            AccountMeta::new(noc_permission_pda, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let now = banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let set_lock = |reason: &str, expires_at| {
+        DoubleZeroInstruction::SetDeviceOpsLock(DeviceSetOpsLockArgs {
+            reason: reason.to_string(),
+            expires_at,
+        })
+    };
+    let device_accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+    let update = |max_users| {
+        DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+            max_users: Some(max_users),
+            ..DeviceUpdateArgs::default()
+        })
+    };
+
+    // The expiry must be in the future and the reason bounded.
+    for (reason, expires_at) in [("optics swap", now - 1), (&"x".repeat(65)[..], now + 3600)] {
+        let result = try_execute_transaction_with_extra_accounts(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            set_lock(reason, expires_at),
+            device_accounts.clone(),
+            &noc,
+            &[AccountMeta::new_readonly(noc_permission_pda, false)],
+        )
+        .await;
+        // DoubleZeroError::InvalidOpsLock = Custom(119)
+        assert_custom_error(result, 119);
+    }
+
+    execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_lock("optics swap", now + 3600),
+        device_accounts.clone(),
+        &noc,
+        &[AccountMeta::new_readonly(noc_permission_pda, false)],
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(
+        device.ops_lock,
+        OpsLock {
+            locked_by: noc.pubkey(),
+            expires_at: now + 3600,
+            reason: "optics swap".to_string(),
+        }
+    );
+
+    // The contributor owner can no longer change the device...
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(64),
+        device_accounts.clone(),
+        &payer,
+    )
+    .await;
+    // DoubleZeroError::EntityLocked = Custom(118)
+    assert_custom_error(result, 118);
+
+    // ...while the lock holder can.
+    execute_transaction_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(64),
+        device_accounts.clone(),
+        &noc,
+        &[AccountMeta::new_readonly(noc_permission_pda, false)],
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.max_users, 64);
+
+    // The foundation may release someone else's lock.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_lock("", 0),
+        device_accounts.clone(),
+        &payer,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.ops_lock, OpsLock::default());
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        update(96),
+        device_accounts,
+        &payer,
+    )
+    .await;
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    assert_eq!(device.max_users, 96);
+}
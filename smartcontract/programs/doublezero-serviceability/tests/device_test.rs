@@ -1222,6 +1222,8 @@ async fn test_delete_device_fails_with_reference_count_not_zero() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1247,6 +1249,7 @@ async fn test_delete_device_fails_with_reference_count_not_zero() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -1758,6 +1761,8 @@ async fn test_delete_device_atomic_close_fails_with_references() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1783,6 +1788,7 @@ async fn test_delete_device_atomic_close_fails_with_references() {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -236,6 +236,8 @@ async fn setup_feed_fixture(client_ip: [u8; 4]) -> FeedFixture {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -364,6 +366,7 @@ async fn try_subscribe_with_feed(
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
             owner: Pubkey::default(),
+            bandwidth_tier: None,
         }),
         &accounts,
         &f.payer,
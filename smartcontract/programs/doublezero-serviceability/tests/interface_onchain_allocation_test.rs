@@ -304,6 +304,7 @@ async fn setup_device_with_interface(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -357,6 +358,7 @@ async fn test_create_loopback_vpnv4_with_onchain_allocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -423,6 +425,7 @@ async fn test_create_loopback_non_vpnv4_with_onchain_allocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -493,6 +496,7 @@ async fn test_create_loopback_with_onchain_allocation_honors_supplied_ip_net() {
             user_tunnel_endpoint: true,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -565,6 +569,7 @@ async fn test_create_physical_with_onchain_allocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -631,6 +636,7 @@ async fn test_delete_loopback_with_onchain_deallocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -717,6 +723,7 @@ async fn test_delete_physical_with_onchain_deallocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -1190,6 +1197,7 @@ async fn test_update_interface_node_segment_idx_duplicate_allocation() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -1355,6 +1363,7 @@ async fn test_delete_loopback_deallocates_flex_algo_segments() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 2,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -1468,6 +1477,7 @@ async fn setup_vpnv4_loopback_with_topologies(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: initial_topologies.len() as u8,
+            with_ip_allocation: false,
         }),
         accounts,
         payer,
@@ -1909,6 +1919,7 @@ async fn test_update_topologies_rejects_non_vpnv4() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -2157,6 +2168,7 @@ async fn test_create_device_interface_rejects_duplicate_topology_accounts() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 2,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -0,0 +1,244 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        device::{create::*, interface::create::*},
+        interface_oper_status::{
+            delete::InterfaceOperStatusDeleteArgs, set::InterfaceOperStatusSetArgs,
+        },
+    },
+    resource::ResourceType,
+    state::{
+        accounttype::AccountType,
+        device::*,
+        interface::{InterfaceCYOA, InterfaceDIA, LoopbackType, RoutingMode},
+        interface_oper_status::{
+            InterfaceOperState, InterfaceOperStatusEntry, LIGHT_LEVEL_UNAVAILABLE,
+        },
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+fn entry(name: &str, oper_status: InterfaceOperState, in_errors: u64) -> InterfaceOperStatusEntry {
+    InterfaceOperStatusEntry {
+        name: name.to_string(),
+        oper_status,
+        rx_power_mdbm: -2_500,
+        tx_power_mdbm: LIGHT_LEVEL_UNAVAILABLE,
+        in_errors,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_interface_oper_status() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [100, 0, 0, 1].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: Pubkey::default(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    for name in ["Ethernet1", "Ethernet2"] {
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateDeviceInterface(DeviceInterfaceCreateArgs {
+                name: name.to_string(),
+                interface_dia: InterfaceDIA::None,
+                loopback_type: LoopbackType::None,
+                interface_cyoa: InterfaceCYOA::None,
+                bandwidth: 0,
+                ip_net: None,
+                cir: 0,
+                mtu: 9000,
+                routing_mode: RoutingMode::Static,
+                vlan_id: 0,
+                user_tunnel_endpoint: false,
+                use_onchain_allocation: true,
+                topology_count: 0,
+                with_ip_allocation: false,
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(contributor_pubkey, false),
+                AccountMeta::new(globalstate_pubkey, false),
+                AccountMeta::new(
+                    get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
+                    false,
+                ),
+                AccountMeta::new(
+                    get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
+                    false,
+                ),
+            ],
+            &payer,
+        )
+        .await;
+    }
+
+    let (oper_status_pubkey, _) = get_interface_oper_status_pda(&program_id, &device_pubkey);
+    let set = |interfaces| {
+        DoubleZeroInstruction::SetInterfaceOperStatus(InterfaceOperStatusSetArgs { interfaces })
+    };
+    let accounts = vec![
+        AccountMeta::new(oper_status_pubkey, false),
+        AccountMeta::new_readonly(device_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+
+    // Only interfaces the device has can be reported.
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set(vec![entry("Ethernet9", InterfaceOperState::Up, 0)]),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+    // DoubleZeroError::InterfaceNotFound = Custom(24)
+    assert_custom_error(result, 24);
+
+    // Only the health oracle or the foundation may write.
+    let stranger = Keypair::new();
+    transfer(&mut banks_client, &payer, &stranger.pubkey(), 100_000_000).await;
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set(vec![entry("Ethernet1", InterfaceOperState::Up, 0)]),
+        accounts.clone(),
+        &stranger,
+    )
+    .await;
+    // DoubleZeroError::NotAllowed = Custom(8)
+    assert_custom_error(result, 8);
+
+    // The first write creates the account.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set(vec![
+            entry("Ethernet1", InterfaceOperState::Up, 0),
+            entry("Ethernet2", InterfaceOperState::LowerLayerDown, 5),
+        ]),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+
+    let oper_status = get_account_data(&mut banks_client, oper_status_pubkey)
+        .await
+        .unwrap()
+        .get_interface_oper_status()
+        .unwrap();
+    assert_eq!(oper_status.account_type, AccountType::InterfaceOperStatus);
+    assert_eq!(oper_status.device_pk, device_pubkey);
+    assert_eq!(oper_status.owner, payer.pubkey());
+    assert_eq!(oper_status.interfaces.len(), 2);
+    let ethernet2 = oper_status.find("Ethernet2").unwrap();
+    assert_eq!(ethernet2.oper_status, InterfaceOperState::LowerLayerDown);
+    assert_eq!(ethernet2.in_errors, 5);
+    assert_eq!(ethernet2.updated_at, oper_status.updated_at);
+
+    // Later writes replace matching entries and keep the others.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set(vec![entry("ethernet2", InterfaceOperState::Up, 9)]),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+
+    let oper_status = get_account_data(&mut banks_client, oper_status_pubkey)
+        .await
+        .unwrap()
+        .get_interface_oper_status()
+        .unwrap();
+    assert_eq!(oper_status.interfaces.len(), 2);
+    assert_eq!(
+        oper_status.find("Ethernet1").unwrap().oper_status,
+        InterfaceOperState::Up
+    );
+    let ethernet2 = oper_status.find("Ethernet2").unwrap();
+    assert_eq!(ethernet2.oper_status, InterfaceOperState::Up);
+    assert_eq!(ethernet2.in_errors, 9);
+
+    // Closing the account returns its rent.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteInterfaceOperStatus(InterfaceOperStatusDeleteArgs {}),
+        vec![
+            AccountMeta::new(oper_status_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+    assert!(banks_client
+        .get_account(oper_status_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
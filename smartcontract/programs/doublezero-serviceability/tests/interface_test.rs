@@ -164,6 +164,7 @@ async fn test_interface_create_invalid_mtu_non_cyoa() {
             use_onchain_allocation: true,
 
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -336,6 +337,7 @@ async fn test_interface_create_invalid_mtu_cyoa() {
             use_onchain_allocation: true,
 
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
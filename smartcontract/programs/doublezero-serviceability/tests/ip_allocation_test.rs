@@ -0,0 +1,516 @@
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        device::{
+            create::DeviceCreateArgs,
+            interface::{create::DeviceInterfaceCreateArgs, update::DeviceInterfaceUpdateArgs},
+        },
+        globalstate::setfeatureflags::SetFeatureFlagsArgs,
+        ip_allocation::{
+            create::IpAllocationCreateArgs, delete::IpAllocationDeleteArgs,
+            setstatus::IpAllocationSetStatusArgs,
+        },
+    },
+    resource::ResourceType,
+    state::{
+        accounttype::AccountType,
+        device::{DeviceDesiredStatus, DeviceType},
+        feature_flags::FeatureFlag,
+        interface::{InterfaceCYOA, InterfaceDIA, LoopbackType, RoutingMode},
+        ip_allocation::{IpAllocation, IpAllocationStatus},
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+async fn get_ip_allocation(banks_client: &mut BanksClient, pubkey: Pubkey) -> Option<IpAllocation> {
+    get_account_data(banks_client, pubkey)
+        .await
+        .and_then(|data| data.get_ip_allocation().ok())
+}
+
+/// Initializes the program with a contributor and an activated device owned by the payer.
+/// Returns (banks_client, program_id, payer, globalstate, contributor, device).
+async fn setup() -> (BanksClient, Pubkey, Keypair, Pubkey, Pubkey, Pubkey) {
+    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
+    init_globalstate_and_config(&mut banks_client, program_id, &payer, recent_blockhash).await;
+
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    let (globalconfig_pubkey, _) = get_globalconfig_pda(&program_id);
+    let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (device_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
+    let (tunnel_ids_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_pubkey, 0));
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+            code: "la".to_string(),
+            device_type: DeviceType::Hybrid,
+            public_ip: [8, 8, 8, 8].into(),
+            dz_prefixes: "110.1.0.0/23".parse().unwrap(),
+            metrics_publisher_pk: Pubkey::default(),
+            mgmt_vrf: "mgmt".to_string(),
+            desired_status: Some(DeviceDesiredStatus::Activated),
+            resource_count: 2,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(exchange_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+            AccountMeta::new(tunnel_ids_pda, false),
+            AccountMeta::new(dz_prefix_pda, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    (
+        banks_client,
+        program_id,
+        payer,
+        globalstate_pubkey,
+        contributor_pubkey,
+        device_pubkey,
+    )
+}
+
+async fn create_ip_allocation(
+    banks_client: &mut BanksClient,
+    program_id: Pubkey,
+    globalstate_pubkey: Pubkey,
+    contributor_pubkey: Pubkey,
+    prefix: NetworkV4,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (pda_pubkey, _) = get_ip_allocation_pda(&program_id, &prefix);
+    let recent_blockhash = wait_for_new_blockhash(banks_client).await;
+    try_execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateIpAllocation(IpAllocationCreateArgs {
+            prefix,
+            upstream: "AS64500".to_string(),
+        }),
+        vec![
+            AccountMeta::new(pda_pubkey, false),
+            AccountMeta::new_readonly(contributor_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        payer,
+    )
+    .await
+}
+
+async fn set_ip_allocation_status(
+    banks_client: &mut BanksClient,
+    program_id: Pubkey,
+    globalstate_pubkey: Pubkey,
+    prefix: NetworkV4,
+    status: IpAllocationStatus,
+    payer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (pda_pubkey, _) = get_ip_allocation_pda(&program_id, &prefix);
+    let recent_blockhash = wait_for_new_blockhash(banks_client).await;
+    try_execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetIpAllocationStatus(IpAllocationSetStatusArgs { status }),
+        vec![
+            AccountMeta::new(pda_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        payer,
+    )
+    .await
+}
+
+fn cyoa_interface_args(
+    name: &str,
+    ip_net: &str,
+    with_ip_allocation: bool,
+) -> DeviceInterfaceCreateArgs {
+    DeviceInterfaceCreateArgs {
+        name: name.to_string(),
+        interface_dia: InterfaceDIA::None,
+        loopback_type: LoopbackType::None,
+        interface_cyoa: InterfaceCYOA::GREOverDIA,
+        bandwidth: 1_000_000_000,
+        ip_net: Some(ip_net.parse().unwrap()),
+        cir: 500_000_000,
+        mtu: 1500,
+        routing_mode: RoutingMode::Static,
+        vlan_id: 0,
+        user_tunnel_endpoint: false,
+        use_onchain_allocation: true,
+        topology_count: 0,
+        with_ip_allocation,
+    }
+}
+
+fn interface_accounts(
+    device_pubkey: Pubkey,
+    contributor_pubkey: Pubkey,
+    globalstate_pubkey: Pubkey,
+    ip_allocation: Option<Pubkey>,
+) -> Vec<AccountMeta> {
+    let mut accounts = vec![
+        AccountMeta::new(device_pubkey, false),
+        AccountMeta::new(contributor_pubkey, false),
+        AccountMeta::new(globalstate_pubkey, false),
+    ];
+    if let Some(ip_allocation) = ip_allocation {
+        accounts.push(AccountMeta::new_readonly(ip_allocation, false));
+    }
+    accounts
+}
+
+#[tokio::test]
+async fn test_ip_allocation_lifecycle() {
+    let (mut banks_client, program_id, payer, globalstate_pubkey, contributor_pubkey, _) =
+        setup().await;
+
+    let prefix: NetworkV4 = "45.76.12.0/24".parse().unwrap();
+    let (pda_pubkey, _) = get_ip_allocation_pda(&program_id, &prefix);
+
+    create_ip_allocation(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        contributor_pubkey,
+        prefix,
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let ip_allocation = get_ip_allocation(&mut banks_client, pda_pubkey)
+        .await
+        .expect("allocation should exist");
+    assert_eq!(ip_allocation.account_type, AccountType::IpAllocation);
+    assert_eq!(ip_allocation.status, IpAllocationStatus::Pending);
+    assert_eq!(ip_allocation.contributor_pk, contributor_pubkey);
+    assert_eq!(ip_allocation.prefix, prefix);
+    assert_eq!(ip_allocation.upstream, "AS64500");
+
+    // A prefix can only be registered once.
+    let res = create_ip_allocation(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        contributor_pubkey,
+        prefix,
+        &payer,
+    )
+    .await;
+    assert!(res.is_err());
+
+    // Host bits set and private space are rejected.
+    for bad_prefix in ["45.76.13.7/24", "10.0.0.0/8"] {
+        let res = create_ip_allocation(
+            &mut banks_client,
+            program_id,
+            globalstate_pubkey,
+            contributor_pubkey,
+            bad_prefix.parse().unwrap(),
+            &payer,
+        )
+        .await;
+        assert_custom_error(res, 106);
+    }
+
+    // Only the foundation may set the status.
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+    let res = set_ip_allocation_status(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        prefix,
+        IpAllocationStatus::Verified,
+        &outsider,
+    )
+    .await;
+    assert_custom_error(res, 8);
+
+    set_ip_allocation_status(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        prefix,
+        IpAllocationStatus::Verified,
+        &payer,
+    )
+    .await
+    .unwrap();
+    let ip_allocation = get_ip_allocation(&mut banks_client, pda_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ip_allocation.status, IpAllocationStatus::Verified);
+
+    // A verified allocation cannot go back to pending.
+    let res = set_ip_allocation_status(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        prefix,
+        IpAllocationStatus::Pending,
+        &payer,
+    )
+    .await;
+    assert_custom_error(res, 7);
+
+    // The contributor owner deletes the allocation.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteIpAllocation(IpAllocationDeleteArgs {}),
+        vec![
+            AccountMeta::new(pda_pubkey, false),
+            AccountMeta::new_readonly(contributor_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+    assert!(get_ip_allocation(&mut banks_client, pda_pubkey)
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_interface_requires_ip_allocation() {
+    let (
+        mut banks_client,
+        program_id,
+        payer,
+        globalstate_pubkey,
+        contributor_pubkey,
+        device_pubkey,
+    ) = setup().await;
+
+    let (device_tunnel_block, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock);
+    let (segment_routing_ids, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds);
+    let create_accounts = |ip_allocation: Option<Pubkey>| {
+        let mut accounts = interface_accounts(
+            device_pubkey,
+            contributor_pubkey,
+            globalstate_pubkey,
+            ip_allocation,
+        );
+        accounts.push(AccountMeta::new(device_tunnel_block, false));
+        accounts.push(AccountMeta::new(segment_routing_ids, false));
+        accounts
+    };
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetFeatureFlags(SetFeatureFlagsArgs {
+            feature_flags: FeatureFlag::RequireIpAllocations.to_mask(),
+        }),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    // Without an allocation the CYOA interface is rejected.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDeviceInterface(cyoa_interface_args(
+            "Ethernet1",
+            "45.76.12.9/30",
+            false,
+        )),
+        create_accounts(None),
+        &payer,
+    )
+    .await;
+    assert_custom_error(res, 107);
+
+    // A pending allocation does not cover the interface yet.
+    let prefix: NetworkV4 = "45.76.12.0/24".parse().unwrap();
+    let (allocation_pubkey, _) = get_ip_allocation_pda(&program_id, &prefix);
+    create_ip_allocation(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        contributor_pubkey,
+        prefix,
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDeviceInterface(cyoa_interface_args(
+            "Ethernet1",
+            "45.76.12.9/30",
+            true,
+        )),
+        create_accounts(Some(allocation_pubkey)),
+        &payer,
+    )
+    .await;
+    assert_custom_error(res, 107);
+
+    set_ip_allocation_status(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        prefix,
+        IpAllocationStatus::Verified,
+        &payer,
+    )
+    .await
+    .unwrap();
+
+    // An address outside the verified allocation is still rejected.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDeviceInterface(cyoa_interface_args(
+            "Ethernet1",
+            "45.76.13.9/30",
+            true,
+        )),
+        create_accounts(Some(allocation_pubkey)),
+        &payer,
+    )
+    .await;
+    assert_custom_error(res, 107);
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDeviceInterface(cyoa_interface_args(
+            "Ethernet1",
+            "45.76.12.9/30",
+            true,
+        )),
+        create_accounts(Some(allocation_pubkey)),
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    let (_, iface) = device.find_interface("Ethernet1").unwrap();
+    assert_eq!(iface.ip_net, "45.76.12.9/30".parse().unwrap());
+
+    // Updates that leave the address alone need no allocation.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
+            name: "Ethernet1".to_string(),
+            vlan_id: Some(100),
+            ..Default::default()
+        }),
+        interface_accounts(device_pubkey, contributor_pubkey, globalstate_pubkey, None),
+        &payer,
+    )
+    .await;
+
+    // Moving the address needs an allocation that covers the new one.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
+            name: "Ethernet1".to_string(),
+            ip_net: Some("45.76.13.9/30".parse().unwrap()),
+            with_ip_allocation: true,
+            ..Default::default()
+        }),
+        interface_accounts(
+            device_pubkey,
+            contributor_pubkey,
+            globalstate_pubkey,
+            Some(allocation_pubkey),
+        ),
+        &payer,
+    )
+    .await;
+    assert_custom_error(res, 107);
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
+            name: "Ethernet1".to_string(),
+            ip_net: Some("45.76.12.33/30".parse().unwrap()),
+            with_ip_allocation: true,
+            ..Default::default()
+        }),
+        interface_accounts(
+            device_pubkey,
+            contributor_pubkey,
+            globalstate_pubkey,
+            Some(allocation_pubkey),
+        ),
+        &payer,
+    )
+    .await;
+
+    let device = get_device(&mut banks_client, device_pubkey).await.unwrap();
+    let (_, iface) = device.find_interface("Ethernet1").unwrap();
+    assert_eq!(iface.ip_net, "45.76.12.33/30".parse().unwrap());
+}
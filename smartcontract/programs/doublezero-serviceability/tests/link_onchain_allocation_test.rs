@@ -171,6 +171,7 @@ async fn setup_wan_link_infra(
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -247,6 +248,7 @@ async fn setup_wan_link_infra(
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -1079,6 +1081,7 @@ async fn test_accept_link_with_onchain_allocation() {
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -1155,6 +1158,7 @@ async fn test_accept_link_with_onchain_allocation() {
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -4,7 +4,7 @@ use doublezero_serviceability::{
     processors::{
         contributor::create::ContributorCreateArgs,
         device::interface::update::DeviceInterfaceUpdateArgs,
-        link::{create::*, update::*},
+        link::{create::*, sethealth::*, update::*},
         topology::create::TopologyCreateArgs,
         *,
     },
@@ -24,289 +24,24 @@ use test_helpers::*;
 
 #[tokio::test]
 async fn test_cannot_set_cyoa_on_linked_interface() {
-    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
-
-    let (_program_config_pubkey, _) = get_program_config_pda(&program_id);
-    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
-
-    init_globalstate_and_config(&mut banks_client, program_id, &payer, recent_blockhash).await;
-
-    let (config_pubkey, _) = get_globalconfig_pda(&program_id);
-    let (_device_tunnel_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock);
-    let (_user_tunnel_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::UserTunnelBlock);
-    let (_multicastgroup_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::MulticastGroupBlock);
-    let (_link_ids_pda, _, _) = get_resource_extension_pda(&program_id, ResourceType::LinkIds);
-    let (_segment_routing_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds);
-    let (_multicast_publisher_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::MulticastPublisherBlock);
-    let (_vrf_ids_pda, _, _) = get_resource_extension_pda(&program_id, ResourceType::VrfIds);
-    let (_admin_group_bits_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::AdminGroupBits);
-
-    // Create location, exchange, contributor
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (location_pubkey, _) = get_location_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateLocation(location::create::LocationCreateArgs {
-            code: "la".to_string(),
-            name: "Los Angeles".to_string(),
-            country: "us".to_string(),
-            lat: 1.234,
-            lng: 4.567,
-            loc_id: 0,
-        }),
-        vec![
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (exchange_pubkey, _) = get_exchange_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateExchange(exchange::create::ExchangeCreateArgs {
-            code: "la".to_string(),
-            name: "Los Angeles".to_string(),
-            lat: 1.234,
-            lng: 4.567,
-            reserved: 0,
-        }),
-        vec![
-            AccountMeta::new(exchange_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (contributor_pubkey, _) =
-        get_contributor_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateContributor(ContributorCreateArgs {
-            code: "cont".to_string(),
-        }),
-        vec![
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(payer.pubkey(), false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Create device A with clean interface
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (device_a_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
-    let (device_a_tunnel_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_a_pubkey, 0));
-    let (device_a_dz_prefix_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_a_pubkey, 0));
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDevice(device::create::DeviceCreateArgs {
-            code: "A".to_string(),
-            device_type: DeviceType::Hybrid,
-            public_ip: [110, 0, 0, 1].into(),
-            dz_prefixes: "100.1.0.0/24".parse().unwrap(),
-            metrics_publisher_pk: Pubkey::default(),
-            mgmt_vrf: "mgmt".to_string(),
-            desired_status: Some(DeviceDesiredStatus::Activated),
-            resource_count: 2,
-        }),
-        vec![
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(exchange_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(device_a_tunnel_ids_pda, false),
-            AccountMeta::new(device_a_dz_prefix_pda, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDeviceInterface(
-            device::interface::create::DeviceInterfaceCreateArgs {
-                name: "Ethernet0".to_string(),
-                interface_dia: InterfaceDIA::None,
-                loopback_type: LoopbackType::None,
-                interface_cyoa: InterfaceCYOA::None,
-                bandwidth: 100_000_000_000,
-                ip_net: None,
-                cir: 0,
-                mtu: 9000,
-                routing_mode: RoutingMode::Static,
-                vlan_id: 0,
-                user_tunnel_endpoint: false,
-                use_onchain_allocation: true,
-
-                topology_count: 0,
-            },
-        ),
-        vec![
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Create device Z with clean interface
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (device_z_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
-    let (device_z_tunnel_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_z_pubkey, 0));
-    let (device_z_dz_prefix_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_z_pubkey, 0));
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDevice(device::create::DeviceCreateArgs {
-            code: "Z".to_string(),
-            device_type: DeviceType::Hybrid,
-            public_ip: [11, 0, 0, 1].into(),
-            dz_prefixes: "11.1.0.0/23".parse().unwrap(),
-            metrics_publisher_pk: Pubkey::default(),
-            mgmt_vrf: "mgmt".to_string(),
-            desired_status: Some(DeviceDesiredStatus::Activated),
-            resource_count: 2,
-        }),
-        vec![
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(exchange_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(device_z_tunnel_ids_pda, false),
-            AccountMeta::new(device_z_dz_prefix_pda, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDeviceInterface(
-            device::interface::create::DeviceInterfaceCreateArgs {
-                name: "Ethernet1".to_string(),
-                interface_dia: InterfaceDIA::None,
-                loopback_type: LoopbackType::None,
-                interface_cyoa: InterfaceCYOA::None,
-                bandwidth: 100_000_000_000,
-                ip_net: None,
-                cir: 0,
-                mtu: 9000,
-                routing_mode: RoutingMode::Static,
-                vlan_id: 0,
-                user_tunnel_endpoint: false,
-                use_onchain_allocation: true,
-
-                topology_count: 0,
-            },
-        ),
-        vec![
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Create and activate a link (both interfaces become Activated)
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (link_pubkey, _) = get_link_pda(&program_id, globalstate_account.account_index + 1);
-
-    let unicast_default_pda = create_unicast_default_topology(
-        &mut banks_client,
+    // Create device A and Z with clean interfaces and an activated link between
+    // them (both interfaces become Activated).
+    let TestNet {
+        mut banks_client,
         program_id,
+        payer,
         globalstate_pubkey,
-        config_pubkey,
-        &payer,
-    )
-    .await;
-
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateLink(LinkCreateArgs {
-            code: "test-link".to_string(),
-            link_type: LinkLinkType::WAN,
-            bandwidth: 20_000_000_000,
-            mtu: 9000,
-            delay_ns: 1_000_000,
-            jitter_ns: 100_000,
-            side_a_iface_name: "Ethernet0".to_string(),
-            side_z_iface_name: Some("Ethernet1".to_string()),
-            desired_status: Some(LinkDesiredStatus::Activated),
-            use_onchain_allocation: true,
-        }),
-        vec![
-            AccountMeta::new(link_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(unicast_default_pda, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::LinkIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
+        contributor_pubkey,
+        devices,
+        ..
+    } = TestNetBuilder::new()
+        .with_device("A")
+        .with_device("Z")
+        .with_link(0, 1)
+        .build()
+        .await;
+    let (device_a_pubkey, device_z_pubkey) = (devices[0].pubkey, devices[1].pubkey);
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
 
     // Verify interfaces are now Activated (linked)
     let device_a = get_account_data(&mut banks_client, device_a_pubkey)
@@ -317,387 +52,114 @@ async fn test_cannot_set_cyoa_on_linked_interface() {
     let iface_a = device_a.find_interface("Ethernet0").unwrap().1;
     assert_eq!(iface_a.status, InterfaceStatus::Activated);
 
-    // Attempt to set CYOA on linked interface — should fail
-    let res = try_execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
-            name: "Ethernet0".to_string(),
-            interface_cyoa: Some(InterfaceCYOA::GREOverDIA),
-            interface_dia: None,
-            loopback_type: None,
-            bandwidth: None,
-            cir: None,
-            mtu: None,
-            routing_mode: None,
-            vlan_id: None,
-            user_tunnel_endpoint: None,
-            status: None,
-            ip_net: None,
-            node_segment_idx: None,
-            topology_count: 0,
-            update_topologies: false,
-        }),
-        vec![
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    let error_string = format!("{:?}", res.unwrap_err());
-    assert!(
-        error_string.contains("Custom(83)"),
-        "Expected InterfaceHasEdgeAssignment error (Custom(83)), got: {}",
-        error_string
-    );
-
-    // Attempt to set DIA on linked side Z interface — should also fail
-    let res = try_execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
-            name: "Ethernet1".to_string(),
-            interface_cyoa: None,
-            interface_dia: Some(InterfaceDIA::DIA),
-            loopback_type: None,
-            bandwidth: None,
-            cir: None,
-            mtu: None,
-            routing_mode: None,
-            vlan_id: None,
-            user_tunnel_endpoint: None,
-            status: None,
-            ip_net: None,
-            node_segment_idx: None,
-            topology_count: 0,
-            update_topologies: false,
-        }),
-        vec![
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    let error_string = format!("{:?}", res.unwrap_err());
-    assert!(
-        error_string.contains("Custom(83)"),
-        "Expected InterfaceHasEdgeAssignment error (Custom(83)), got: {}",
-        error_string
-    );
-}
-
-/// Helper that sets up a full link environment and returns all relevant pubkeys.
-/// Creates: global state, global config, location, exchange, contributor, 2 devices
-/// with activated+unlinked interfaces, and a link in Pending status.
-async fn setup_link_env() -> (
-    BanksClient,
-    Pubkey,  // program_id
-    Keypair, // payer
-    Pubkey,  // globalstate_pubkey
-    Pubkey,  // contributor_pubkey
-    Pubkey,  // device_a_pubkey
-    Pubkey,  // device_z_pubkey
-    Pubkey,  // tunnel_pubkey (link)
-) {
-    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
-
-    let (_program_config_pubkey, _) = get_program_config_pda(&program_id);
-    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
-
-    init_globalstate_and_config(&mut banks_client, program_id, &payer, recent_blockhash).await;
-
-    let (config_pubkey, _) = get_globalconfig_pda(&program_id);
-    let (_device_tunnel_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock);
-    let (_user_tunnel_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::UserTunnelBlock);
-    let (_multicastgroup_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::MulticastGroupBlock);
-    let (_link_ids_pda, _, _) = get_resource_extension_pda(&program_id, ResourceType::LinkIds);
-    let (_segment_routing_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds);
-    let (_multicast_publisher_block_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::MulticastPublisherBlock);
-    let (_vrf_ids_pda, _, _) = get_resource_extension_pda(&program_id, ResourceType::VrfIds);
-    let (_admin_group_bits_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::AdminGroupBits);
-
-    // Location
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (location_pubkey, _) = get_location_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateLocation(location::create::LocationCreateArgs {
-            code: "la".to_string(),
-            name: "Los Angeles".to_string(),
-            country: "us".to_string(),
-            lat: 1.234,
-            lng: 4.567,
-            loc_id: 0,
-        }),
-        vec![
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Exchange
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (exchange_pubkey, _) = get_exchange_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateExchange(exchange::create::ExchangeCreateArgs {
-            code: "la".to_string(),
-            name: "Los Angeles".to_string(),
-            lat: 1.234,
-            lng: 4.567,
-            reserved: 0,
-        }),
-        vec![
-            AccountMeta::new(exchange_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Contributor
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (contributor_pubkey, _) =
-        get_contributor_pda(&program_id, globalstate_account.account_index + 1);
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateContributor(ContributorCreateArgs {
-            code: "cont".to_string(),
-        }),
-        vec![
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(payer.pubkey(), false),
-            AccountMeta::new(globalstate_pubkey, false),
-        ],
-        &payer,
-    )
-    .await;
-
-    // Device A
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (device_a_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
-    let (device_a_tunnel_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_a_pubkey, 0));
-    let (device_a_dz_prefix_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_a_pubkey, 0));
-    execute_transaction(
+    // Attempt to set CYOA on linked interface — should fail
+    let res = try_execute_transaction(
         &mut banks_client,
         recent_blockhash,
         program_id,
-        DoubleZeroInstruction::CreateDevice(device::create::DeviceCreateArgs {
-            code: "A".to_string(),
-            device_type: DeviceType::Hybrid,
-            public_ip: [110, 0, 0, 1].into(),
-            dz_prefixes: "100.1.0.0/24".parse().unwrap(),
-            metrics_publisher_pk: Pubkey::default(),
-            mgmt_vrf: "mgmt".to_string(),
-            desired_status: Some(DeviceDesiredStatus::Activated),
-            resource_count: 2,
+        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
+            name: "Ethernet0".to_string(),
+            interface_cyoa: Some(InterfaceCYOA::GREOverDIA),
+            interface_dia: None,
+            loopback_type: None,
+            bandwidth: None,
+            cir: None,
+            mtu: None,
+            routing_mode: None,
+            vlan_id: None,
+            user_tunnel_endpoint: None,
+            status: None,
+            ip_net: None,
+            node_segment_idx: None,
+            topology_count: 0,
+            update_topologies: false,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_a_pubkey, false),
             AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(exchange_pubkey, false),
             AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(device_a_tunnel_ids_pda, false),
-            AccountMeta::new(device_a_dz_prefix_pda, false),
         ],
         &payer,
     )
     .await;
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDeviceInterface(
-            device::interface::create::DeviceInterfaceCreateArgs {
-                name: "Ethernet0".to_string(),
-                interface_dia: InterfaceDIA::None,
-                loopback_type: LoopbackType::None,
-                interface_cyoa: InterfaceCYOA::None,
-                bandwidth: 100_000_000_000,
-                ip_net: None,
-                cir: 0,
-                mtu: 9000,
-                routing_mode: RoutingMode::Static,
-                vlan_id: 0,
-                user_tunnel_endpoint: false,
-                use_onchain_allocation: true,
 
-                topology_count: 0,
-            },
-        ),
-        vec![
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
+    let error_string = format!("{:?}", res.unwrap_err());
+    assert!(
+        error_string.contains("Custom(83)"),
+        "Expected InterfaceHasEdgeAssignment error (Custom(83)), got: {}",
+        error_string
+    );
 
-    // Device Z
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (device_z_pubkey, _) = get_device_pda(&program_id, globalstate_account.account_index + 1);
-    let (device_z_tunnel_ids_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::TunnelIds(device_z_pubkey, 0));
-    let (device_z_dz_prefix_pda, _, _) =
-        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_z_pubkey, 0));
-    execute_transaction(
+    // Attempt to set DIA on linked side Z interface — should also fail
+    let res = try_execute_transaction(
         &mut banks_client,
         recent_blockhash,
         program_id,
-        DoubleZeroInstruction::CreateDevice(device::create::DeviceCreateArgs {
-            code: "Z".to_string(),
-            device_type: DeviceType::Hybrid,
-            public_ip: [11, 0, 0, 1].into(),
-            dz_prefixes: "11.1.0.0/23".parse().unwrap(),
-            metrics_publisher_pk: Pubkey::default(),
-            mgmt_vrf: "mgmt".to_string(),
-            desired_status: Some(DeviceDesiredStatus::Activated),
-            resource_count: 2,
+        DoubleZeroInstruction::UpdateDeviceInterface(DeviceInterfaceUpdateArgs {
+            name: "Ethernet1".to_string(),
+            interface_cyoa: None,
+            interface_dia: Some(InterfaceDIA::DIA),
+            loopback_type: None,
+            bandwidth: None,
+            cir: None,
+            mtu: None,
+            routing_mode: None,
+            vlan_id: None,
+            user_tunnel_endpoint: None,
+            status: None,
+            ip_net: None,
+            node_segment_idx: None,
+            topology_count: 0,
+            update_topologies: false,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_z_pubkey, false),
             AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(location_pubkey, false),
-            AccountMeta::new(exchange_pubkey, false),
             AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(config_pubkey, false),
-            AccountMeta::new(device_z_tunnel_ids_pda, false),
-            AccountMeta::new(device_z_dz_prefix_pda, false),
         ],
         &payer,
     )
     .await;
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateDeviceInterface(
-            device::interface::create::DeviceInterfaceCreateArgs {
-                name: "Ethernet1".to_string(),
-                interface_dia: InterfaceDIA::None,
-                loopback_type: LoopbackType::None,
-                interface_cyoa: InterfaceCYOA::None,
-                bandwidth: 100_000_000_000,
-                ip_net: None,
-                cir: 0,
-                mtu: 9000,
-                routing_mode: RoutingMode::Static,
-                vlan_id: 0,
-                user_tunnel_endpoint: false,
-                use_onchain_allocation: true,
 
-                topology_count: 0,
-            },
-        ),
-        vec![
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
+    let error_string = format!("{:?}", res.unwrap_err());
+    assert!(
+        error_string.contains("Custom(83)"),
+        "Expected InterfaceHasEdgeAssignment error (Custom(83)), got: {}",
+        error_string
+    );
+}
 
-    // Create link (Pending status)
-    let globalstate_account = get_globalstate(&mut banks_client, globalstate_pubkey).await;
-    let (tunnel_pubkey, _) = get_link_pda(&program_id, globalstate_account.account_index + 1);
-    let unicast_default_pda = create_unicast_default_topology(
-        &mut banks_client,
-        program_id,
-        globalstate_pubkey,
-        config_pubkey,
-        &payer,
-    )
-    .await;
-    execute_transaction(
-        &mut banks_client,
-        recent_blockhash,
-        program_id,
-        DoubleZeroInstruction::CreateLink(LinkCreateArgs {
-            code: "la".to_string(),
-            link_type: LinkLinkType::WAN,
-            bandwidth: 10_000_000_000,
-            mtu: 9000,
-            delay_ns: 1_000_000,
-            jitter_ns: 100_000,
-            side_a_iface_name: "Ethernet0".to_string(),
-            side_z_iface_name: Some("Ethernet1".to_string()),
-            desired_status: Some(LinkDesiredStatus::Activated),
-            use_onchain_allocation: true,
-        }),
-        vec![
-            AccountMeta::new(tunnel_pubkey, false),
-            AccountMeta::new(contributor_pubkey, false),
-            AccountMeta::new(device_a_pubkey, false),
-            AccountMeta::new(device_z_pubkey, false),
-            AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(unicast_default_pda, false),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
-                false,
-            ),
-            AccountMeta::new(
-                get_resource_extension_pda(&program_id, ResourceType::LinkIds).0,
-                false,
-            ),
-        ],
-        &payer,
-    )
-    .await;
+/// Helper that sets up a full link environment and returns all relevant pubkeys.
+/// Creates: global state, global config, location, exchange, contributor, 2 devices
+/// with activated+unlinked interfaces, and an activated link between them.
+async fn setup_link_env() -> (
+    BanksClient,
+    Pubkey,  // program_id
+    Keypair, // payer
+    Pubkey,  // globalstate_pubkey
+    Pubkey,  // contributor_pubkey
+    Pubkey,  // device_a_pubkey
+    Pubkey,  // device_z_pubkey
+    Pubkey,  // tunnel_pubkey (link)
+) {
+    let net = TestNetBuilder::new()
+        .with_device("A")
+        .with_device("Z")
+        .with_link(0, 1)
+        .build()
+        .await;
 
     (
-        banks_client,
-        program_id,
-        payer,
-        globalstate_pubkey,
-        contributor_pubkey,
-        device_a_pubkey,
-        device_z_pubkey,
-        tunnel_pubkey,
+        net.banks_client,
+        net.program_id,
+        net.payer,
+        net.globalstate_pubkey,
+        net.contributor_pubkey,
+        net.devices[0].pubkey,
+        net.devices[1].pubkey,
+        net.links[0].pubkey,
     )
 }
 
@@ -950,6 +412,7 @@ async fn test_link_activation_succeeds_without_unicast_default() {
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -1023,6 +486,7 @@ async fn test_link_activation_succeeds_without_unicast_default() {
                 use_onchain_allocation: true,
 
                 topology_count: 0,
+                with_ip_allocation: false,
             },
         ),
         vec![
@@ -1612,3 +1076,96 @@ async fn test_wan_link_drain_transitions() {
         .unwrap();
     assert_eq!(link.status, LinkStatus::Activated);
 }
+
+#[tokio::test]
+async fn test_link_flap_damping() {
+    let (
+        mut banks_client,
+        program_id,
+        payer,
+        globalstate_pubkey,
+        _contributor_pubkey,
+        _device_a_pubkey,
+        _device_z_pubkey,
+        tunnel_pubkey,
+    ) = setup_link_env().await;
+
+    let link = get_account_data(&mut banks_client, tunnel_pubkey)
+        .await
+        .expect("Link not found")
+        .get_tunnel()
+        .unwrap();
+    assert_eq!(link.link_health, LinkHealth::ReadyForService);
+    assert!(!link.is_flap_damped());
+
+    // Three ReadyForService → Impaired flaps in one epoch damp the link.
+    for health in [
+        LinkHealth::Impaired,
+        LinkHealth::ReadyForService,
+        LinkHealth::Impaired,
+        LinkHealth::ReadyForService,
+        LinkHealth::Impaired,
+    ] {
+        let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::SetLinkHealth(LinkSetHealthArgs { health }),
+            vec![
+                AccountMeta::new(tunnel_pubkey, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+
+        let link = get_account_data(&mut banks_client, tunnel_pubkey)
+            .await
+            .unwrap()
+            .get_tunnel()
+            .unwrap();
+        assert_eq!(link.link_health, health);
+    }
+
+    let link = get_account_data(&mut banks_client, tunnel_pubkey)
+        .await
+        .unwrap()
+        .get_tunnel()
+        .unwrap();
+    assert_eq!(link.flap_count, 3);
+    assert_eq!(link.flap_penalty, 3 * LINK_FLAP_PENALTY);
+    assert!(link.is_flap_damped());
+    assert_eq!(link.damped_until_epoch, link.flap_epoch + 2);
+}
+
+#[tokio::test]
+async fn test_links_on_shared_device_get_distinct_tunnels() {
+    let TestNet {
+        mut banks_client,
+        devices,
+        links,
+        ..
+    } = TestNetBuilder::new()
+        .with_devices(3)
+        .with_link(0, 1)
+        .with_link(0, 2)
+        .build()
+        .await;
+
+    let mut tunnels = Vec::new();
+    for link in &links {
+        let link = get_account_data(&mut banks_client, link.pubkey)
+            .await
+            .expect("Link not found")
+            .get_tunnel()
+            .unwrap();
+        assert_eq!(link.status, LinkStatus::Activated);
+        tunnels.push((link.tunnel_id, link.tunnel_net));
+    }
+    assert_ne!(tunnels[0].0, tunnels[1].0);
+    assert_ne!(tunnels[0].1, tunnels[1].1);
+
+    // The shared device carries one interface per link.
+    assert_eq!(devices[0].interfaces, vec!["Ethernet0", "Ethernet2"]);
+}
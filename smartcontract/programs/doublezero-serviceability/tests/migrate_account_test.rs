@@ -0,0 +1,128 @@
+use doublezero_serviceability::{
+    entrypoint::process_instruction,
+    instructions::DoubleZeroInstruction,
+    pda::{get_globalstate_pda, get_resource_extension_pda},
+    processors::migrateaccount::MigrateAccountArgs,
+    resource::ResourceType,
+    state::{accounttype::AccountType, device::Device},
+};
+use solana_program::rent::Rent;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account as SolanaAccount, instruction::AccountMeta, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+#[tokio::test]
+async fn test_migrate_account_upgrades_legacy_layout() {
+    let program_id = Pubkey::new_unique();
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+
+    // A device written before the trailing interfaces vec and config generation
+    // fields existed.
+    let device = Device {
+        account_type: AccountType::Device,
+        code: "la2-dz01".to_string(),
+        ..Device::default()
+    };
+    let current = borsh::to_vec(&device).unwrap();
+    let legacy = current[..current.len() - 20].to_vec();
+
+    let device_pubkey = Pubkey::new_unique();
+    let mut program_test = ProgramTest::new(
+        "doublezero_serviceability",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_account(
+        device_pubkey,
+        SolanaAccount {
+            lamports: Rent::default().minimum_balance(legacy.len()),
+            data: legacy.clone(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    init_globalstate_and_config(&mut banks_client, program_id, &payer, recent_blockhash).await;
+
+    let migrate_accounts = |pubkey| {
+        vec![
+            AccountMeta::new(pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ]
+    };
+
+    // Only GLOBALSTATE_ADMIN or the foundation may migrate.
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+        migrate_accounts(device_pubkey),
+        &outsider,
+    )
+    .await;
+    assert!(result.is_err());
+    let account = banks_client
+        .get_account(device_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, legacy);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+        migrate_accounts(device_pubkey),
+        &payer,
+    )
+    .await;
+    let account = banks_client
+        .get_account(device_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, current);
+    assert!(account.lamports >= Rent::default().minimum_balance(current.len()));
+
+    // Migrating an account already at the current layout is a no-op.
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+        migrate_accounts(device_pubkey),
+        &payer,
+    )
+    .await;
+    let account = banks_client
+        .get_account(device_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, current);
+
+    // Account types without a registered migration are rejected.
+    let (device_tunnel_block_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock);
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+        migrate_accounts(device_tunnel_block_pda),
+        &payer,
+    )
+    .await;
+    assert!(result.is_err());
+}
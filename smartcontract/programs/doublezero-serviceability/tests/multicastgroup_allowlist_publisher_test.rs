@@ -75,6 +75,8 @@ async fn test_multicast_publisher_allowlist() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -396,6 +398,8 @@ async fn test_multicast_publisher_allowlist_sentinel_authority() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -563,6 +567,8 @@ async fn test_multicast_publisher_allowlist_allow_multiple_ip() {
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -699,6 +705,8 @@ async fn test_multicast_publisher_allowlist_allow_multiple_ip_real_ip_in_args()
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -833,6 +841,8 @@ async fn test_multicast_publisher_allowlist_wrong_pda_rejected() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_a, false),
@@ -856,6 +866,8 @@ async fn test_multicast_publisher_allowlist_wrong_pda_rejected() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_b, false),
@@ -76,6 +76,8 @@ async fn test_multicast_subscriber_allowlist() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -395,6 +397,8 @@ async fn test_multicast_subscriber_allowlist_sentinel_authority() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -564,6 +568,8 @@ async fn test_multicast_subscriber_allowlist_access_pass_admin_permission() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -738,6 +744,8 @@ async fn test_multicast_subscriber_allowlist_feed_authority() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -863,6 +871,8 @@ async fn test_multicast_subscriber_allowlist_feed_authority_different_user_payer
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1018,6 +1028,8 @@ async fn test_multicast_subscriber_allowlist_allow_multiple_ip() {
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1154,6 +1166,8 @@ async fn test_multicast_subscriber_allowlist_allow_multiple_ip_real_ip_in_args()
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1288,6 +1302,8 @@ async fn test_multicast_subscriber_allowlist_wrong_pda_rejected() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_a, false),
@@ -1311,6 +1327,8 @@ async fn test_multicast_subscriber_allowlist_wrong_pda_rejected() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_b, false),
@@ -1427,6 +1445,8 @@ async fn test_multicast_subscriber_allowlist_allow_multiple_ip_feed_authority_di
             allow_multiple_ip: true,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -1583,6 +1603,8 @@ async fn test_multicast_subscriber_allowlist_feed_authority_remove() {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
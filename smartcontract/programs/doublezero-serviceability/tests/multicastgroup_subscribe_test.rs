@@ -260,6 +260,8 @@ async fn setup_fixture() -> TestFixture {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -328,6 +330,7 @@ async fn setup_fixture() -> TestFixture {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -0,0 +1,181 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        globalstate::setpause::SetPauseArgs,
+        location::{create::LocationCreateArgs, delete::LocationDeleteArgs, update::*},
+    },
+    state::pause_flags::PauseFlag,
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_set_pause_blocks_paused_classes() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, _globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let set_pause = |paused| DoubleZeroInstruction::SetPause(SetPauseArgs { paused });
+    let location_accounts = |location_pubkey| {
+        vec![
+            AccountMeta::new(location_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ]
+    };
+    let create_location = |code: &str| {
+        DoubleZeroInstruction::CreateLocation(LocationCreateArgs {
+            code: code.to_string(),
+            name: "Los Angeles".to_string(),
+            country: "us".to_string(),
+            lat: 1.234,
+            lng: 4.567,
+            loc_id: 0,
+        })
+    };
+
+    // Only the foundation allowlist may pull the brake.
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(PauseFlag::all_mask()),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &outsider,
+    )
+    .await;
+    assert!(result.is_err());
+
+    let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (location_pubkey, _) = get_location_pda(&program_id, globalstate.account_index + 1);
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        create_location("la"),
+        location_accounts(location_pubkey),
+        &payer,
+    )
+    .await;
+
+    // Pause creates: new accounts are rejected, updates still go through.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(PauseFlag::Create.to_mask()),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+    let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    assert_eq!(globalstate.paused, PauseFlag::Create.to_mask());
+
+    let (next_location_pubkey, _) = get_location_pda(&program_id, globalstate.account_index + 1);
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        create_location("ny"),
+        location_accounts(next_location_pubkey),
+        &payer,
+    )
+    .await;
+    // DoubleZeroError::ProgramPaused = Custom(105)
+    assert_custom_error(result, 105);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateLocation(LocationUpdateArgs {
+            name: Some("Los Angeles - CA".to_string()),
+            ..LocationUpdateArgs::default()
+        }),
+        location_accounts(location_pubkey),
+        &payer,
+    )
+    .await;
+
+    // Pause everything: updates and deletes are rejected too.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(PauseFlag::all_mask()),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateLocation(LocationUpdateArgs {
+            name: Some("Los Angeles".to_string()),
+            ..LocationUpdateArgs::default()
+        }),
+        location_accounts(location_pubkey),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 105);
+
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteLocation(LocationDeleteArgs {}),
+        location_accounts(location_pubkey),
+        &payer,
+    )
+    .await;
+    assert_custom_error(result, 105);
+
+    // Reads are unaffected.
+    let location = get_account_data(&mut banks_client, location_pubkey)
+        .await
+        .expect("Unable to get Account")
+        .get_location()
+        .unwrap();
+    assert_eq!(location.name, "Los Angeles - CA");
+
+    // SetPause itself is never paused, so the foundation can lift the brake.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        set_pause(0),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteLocation(LocationDeleteArgs {}),
+        location_accounts(location_pubkey),
+        &payer,
+    )
+    .await;
+    assert_eq!(
+        get_account_data(&mut banks_client, location_pubkey).await,
+        None
+    );
+}
@@ -0,0 +1,263 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        qos_profile::{
+            create::QosProfileCreateArgs, delete::QosProfileDeleteArgs,
+            update::QosProfileUpdateArgs,
+        },
+        resource::create::ResourceCreateArgs,
+    },
+    resource::{IdOrIp, ResourceType},
+    state::{accounttype::AccountType, qos_profile::QosProfile, user::BandwidthTier},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+/// Creates the global QosProfileIds resource extension and returns its PDA.
+async fn create_qos_profile_ids(
+    banks_client: &mut BanksClient,
+    program_id: Pubkey,
+    globalstate_pubkey: Pubkey,
+    globalconfig_pubkey: Pubkey,
+    payer: &Keypair,
+) -> Pubkey {
+    let (ids_pubkey, _, _) = get_resource_extension_pda(&program_id, ResourceType::QosProfileIds);
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateResource(ResourceCreateArgs {
+            resource_type: ResourceType::QosProfileIds,
+        }),
+        vec![
+            AccountMeta::new(ids_pubkey, false),
+            AccountMeta::new(Pubkey::default(), false), // associated_account (not used for this type)
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+        ],
+        payer,
+    )
+    .await;
+    ids_pubkey
+}
+
+async fn get_qos_profile(banks_client: &mut BanksClient, pubkey: Pubkey) -> Option<QosProfile> {
+    get_account_data(banks_client, pubkey)
+        .await
+        .and_then(|data| data.get_qos_profile().ok())
+}
+
+#[tokio::test]
+async fn test_qos_profile_lifecycle() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let ids_pubkey = create_qos_profile_ids(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let (standard_pubkey, _) = get_qos_profile_pda(&program_id, BandwidthTier::Standard);
+    let (premium_pubkey, _) = get_qos_profile_pda(&program_id, BandwidthTier::Premium);
+
+    for (pubkey, tier, rate_mbps) in [
+        (standard_pubkey, BandwidthTier::Standard, 1_000),
+        (premium_pubkey, BandwidthTier::Premium, 10_000),
+    ] {
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateQosProfile(QosProfileCreateArgs {
+                bandwidth_tier: tier,
+                rate_mbps,
+                burst_kbytes: 0,
+                dscp: 0,
+            }),
+            vec![
+                AccountMeta::new(pubkey, false),
+                AccountMeta::new(ids_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+    }
+
+    let standard = get_qos_profile(&mut banks_client, standard_pubkey)
+        .await
+        .expect("standard profile should exist");
+    let premium = get_qos_profile(&mut banks_client, premium_pubkey)
+        .await
+        .expect("premium profile should exist");
+    assert_eq!(standard.account_type, AccountType::QosProfile);
+    assert_eq!(standard.bandwidth_tier, BandwidthTier::Standard);
+    assert_eq!(standard.rate_mbps, 1_000);
+    assert_eq!(premium.bandwidth_tier, BandwidthTier::Premium);
+    assert_ne!(standard.profile_id, premium.profile_id);
+
+    let ids = get_resource_extension_data(&mut banks_client, ids_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ids.iter_allocated().len(), 2);
+
+    // Update only touches the provided fields.
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::UpdateQosProfile(QosProfileUpdateArgs {
+            rate_mbps: Some(20_000),
+            burst_kbytes: None,
+            dscp: Some(46),
+        }),
+        vec![
+            AccountMeta::new(premium_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let updated = get_qos_profile(&mut banks_client, premium_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(updated.rate_mbps, 20_000);
+    assert_eq!(updated.dscp, 46);
+    assert_eq!(updated.burst_kbytes, 0);
+    assert_eq!(updated.profile_id, premium.profile_id);
+
+    // Delete closes the account and returns the id to the pool.
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteQosProfile(QosProfileDeleteArgs {}),
+        vec![
+            AccountMeta::new(premium_pubkey, false),
+            AccountMeta::new(ids_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    assert!(get_qos_profile(&mut banks_client, premium_pubkey)
+        .await
+        .is_none());
+    let ids = get_resource_extension_data(&mut banks_client, ids_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ids.iter_allocated(), vec![IdOrIp::Id(standard.profile_id)]);
+}
+
+#[tokio::test]
+async fn test_qos_profile_rejects_invalid_args() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let ids_pubkey = create_qos_profile_ids(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    for (tier, rate_mbps, dscp) in [
+        (BandwidthTier::Unshaped, 1_000, 0),
+        (BandwidthTier::Basic, 0, 0),
+        (BandwidthTier::Basic, 100, 64),
+    ] {
+        let (pubkey, _) = get_qos_profile_pda(&program_id, tier);
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        let result = execute_transaction_expect_failure(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateQosProfile(QosProfileCreateArgs {
+                bandwidth_tier: tier,
+                rate_mbps,
+                burst_kbytes: 0,
+                dscp,
+            }),
+            vec![
+                AccountMeta::new(pubkey, false),
+                AccountMeta::new(ids_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+        // DoubleZeroError::InvalidQosProfile = Custom(103)
+        assert_custom_error(result, 103);
+    }
+
+    let ids = get_resource_extension_data(&mut banks_client, ids_pubkey)
+        .await
+        .unwrap();
+    assert!(ids.iter_allocated().is_empty());
+}
+
+#[tokio::test]
+async fn test_qos_profile_requires_network_admin() {
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+    let ids_pubkey = create_qos_profile_ids(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        &payer,
+    )
+    .await;
+
+    let outsider = Keypair::new();
+    transfer(&mut banks_client, &payer, &outsider.pubkey(), 100_000_000).await;
+
+    let (pubkey, _) = get_qos_profile_pda(&program_id, BandwidthTier::Basic);
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let result = execute_transaction_expect_failure(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateQosProfile(QosProfileCreateArgs {
+            bandwidth_tier: BandwidthTier::Basic,
+            rate_mbps: 100,
+            burst_kbytes: 0,
+            dscp: 0,
+        }),
+        vec![
+            AccountMeta::new(pubkey, false),
+            AccountMeta::new(ids_pubkey, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        &outsider,
+    )
+    .await;
+    // DoubleZeroError::NotAllowed = Custom(8)
+    assert_custom_error(result, 8);
+}
@@ -0,0 +1,311 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::{
+        contributor::create::ContributorCreateArgs,
+        read_api_key::{
+            create::ReadApiKeyCreateArgs, delete::ReadApiKeyDeleteArgs,
+            revoke::ReadApiKeyRevokeArgs,
+        },
+    },
+    state::{accounttype::AccountType, read_api_key::ReadApiKeyStatus},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+/// Far enough in the future that the program-test clock never reaches it.
+const FUTURE_EXPIRY: i64 = 4_000_000_000;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+#[tokio::test]
+async fn test_read_api_key_lifecycle() {
+    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
+
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::InitGlobalState(),
+        vec![
+            AccountMeta::new(program_config_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // The contributor owner registers and manages its own keys.
+    let owner_keypair = Keypair::new();
+    let owner = owner_keypair.pubkey();
+    transfer(&mut banks_client, &payer, &owner, 100_000_000).await;
+
+    let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+    let (contributor_pubkey, _) = get_contributor_pda(&program_id, globalstate.account_index + 1);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateContributor(ContributorCreateArgs {
+            code: "co01".to_string(),
+        }),
+        vec![
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let key_hash = [42u8; 32];
+    let (read_api_key_pubkey, _) =
+        get_read_api_key_pda(&program_id, &contributor_pubkey, &key_hash);
+    let key_accounts = vec![
+        AccountMeta::new(read_api_key_pubkey, false),
+        AccountMeta::new_readonly(contributor_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+
+    // An expiry in the past is rejected.
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+            key_hash,
+            label: "indexer".to_string(),
+            expires_at: 1,
+        }),
+        key_accounts.clone(),
+        &owner_keypair,
+    )
+    .await;
+    assert_custom_error(res, 65); // InvalidArgument
+
+    // Someone who is neither the contributor owner nor an admin cannot register a key.
+    let outsider_keypair = Keypair::new();
+    transfer(
+        &mut banks_client,
+        &payer,
+        &outsider_keypair.pubkey(),
+        100_000_000,
+    )
+    .await;
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+            key_hash,
+            label: "indexer".to_string(),
+            expires_at: FUTURE_EXPIRY,
+        }),
+        key_accounts.clone(),
+        &outsider_keypair,
+    )
+    .await;
+    assert_custom_error(res, 8); // NotAllowed
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+            key_hash,
+            label: "indexer".to_string(),
+            expires_at: FUTURE_EXPIRY,
+        }),
+        key_accounts.clone(),
+        &owner_keypair,
+    )
+    .await;
+
+    let read_api_key = get_account_data(&mut banks_client, read_api_key_pubkey)
+        .await
+        .expect("Unable to get ReadApiKey")
+        .get_read_api_key()
+        .unwrap();
+    assert_eq!(read_api_key.account_type, AccountType::ReadApiKey);
+    assert_eq!(read_api_key.owner, owner);
+    assert_eq!(read_api_key.status, ReadApiKeyStatus::Activated);
+    assert_eq!(read_api_key.contributor_pk, contributor_pubkey);
+    assert_eq!(read_api_key.key_hash, key_hash);
+    assert_eq!(read_api_key.label, "indexer".to_string());
+    assert_eq!(read_api_key.expires_at, FUTURE_EXPIRY);
+    assert!(read_api_key.created_at > 0);
+
+    // A live key cannot be deleted.
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteReadApiKey(ReadApiKeyDeleteArgs {}),
+        key_accounts.clone(),
+        &owner_keypair,
+    )
+    .await;
+    assert_custom_error(res, 7); // InvalidStatus
+
+    // Foundation (the default payer) can revoke on the contributor's behalf.
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RevokeReadApiKey(ReadApiKeyRevokeArgs {}),
+        key_accounts.clone(),
+        &payer,
+    )
+    .await;
+
+    let read_api_key = get_account_data(&mut banks_client, read_api_key_pubkey)
+        .await
+        .expect("Unable to get ReadApiKey")
+        .get_read_api_key()
+        .unwrap();
+    assert_eq!(read_api_key.status, ReadApiKeyStatus::Revoked);
+
+    // Revocation is one-way.
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RevokeReadApiKey(ReadApiKeyRevokeArgs {}),
+        key_accounts.clone(),
+        &owner_keypair,
+    )
+    .await;
+    assert_custom_error(res, 7); // InvalidStatus
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::DeleteReadApiKey(ReadApiKeyDeleteArgs {}),
+        key_accounts.clone(),
+        &owner_keypair,
+    )
+    .await;
+
+    let account = banks_client.get_account(read_api_key_pubkey).await.unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn test_read_api_key_wrong_contributor_rejected() {
+    let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
+
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::InitGlobalState(),
+        vec![
+            AccountMeta::new(program_config_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // Two contributors owned by different keys.
+    let mut contributors = Vec::new();
+    for code in ["co01", "co02"] {
+        let owner_keypair = Keypair::new();
+        transfer(
+            &mut banks_client,
+            &payer,
+            &owner_keypair.pubkey(),
+            100_000_000,
+        )
+        .await;
+
+        let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+        let (contributor_pubkey, _) =
+            get_contributor_pda(&program_id, globalstate.account_index + 1);
+        execute_transaction(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::CreateContributor(ContributorCreateArgs {
+                code: code.to_string(),
+            }),
+            vec![
+                AccountMeta::new(contributor_pubkey, false),
+                AccountMeta::new(owner_keypair.pubkey(), false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+            &payer,
+        )
+        .await;
+        contributors.push((contributor_pubkey, owner_keypair));
+    }
+    let (contributor1, owner1) = &contributors[0];
+    let (contributor2, owner2) = &contributors[1];
+
+    let key_hash = [9u8; 32];
+    let (read_api_key_pubkey, _) = get_read_api_key_pda(&program_id, contributor1, &key_hash);
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+            key_hash,
+            label: String::new(),
+            expires_at: 0,
+        }),
+        vec![
+            AccountMeta::new(read_api_key_pubkey, false),
+            AccountMeta::new_readonly(*contributor1, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        owner1,
+    )
+    .await;
+
+    // The second contributor's owner cannot revoke the first contributor's key by passing
+    // their own contributor account.
+    let res = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RevokeReadApiKey(ReadApiKeyRevokeArgs {}),
+        vec![
+            AccountMeta::new(read_api_key_pubkey, false),
+            AccountMeta::new_readonly(*contributor2, false),
+            AccountMeta::new_readonly(globalstate_pubkey, false),
+        ],
+        owner2,
+    )
+    .await;
+    assert_custom_error(res, 8); // NotAllowed
+
+    let read_api_key = get_account_data(&mut banks_client, read_api_key_pubkey)
+        .await
+        .expect("Unable to get ReadApiKey")
+        .get_read_api_key()
+        .unwrap();
+    assert_eq!(read_api_key.status, ReadApiKeyStatus::Activated);
+    assert_eq!(read_api_key.expires_at, 0);
+    assert_ne!(read_api_key.contributor_pk, Pubkey::default());
+}
@@ -29,6 +29,7 @@ use doublezero_serviceability::{
         resource::{
             allocate::ResourceAllocateArgs, closeaccount::ResourceExtensionCloseAccountArgs,
             create::ResourceCreateArgs, deallocate::ResourceDeallocateArgs,
+            resize::ResourceResizeArgs,
         },
     },
     resource::{IdOrIp, ResourceType},
@@ -37,6 +38,7 @@ use doublezero_serviceability::{
         device::{DeviceDesiredStatus, DeviceStatus, DeviceType},
         interface::{InterfaceCYOA, InterfaceDIA, RoutingMode},
         permission::permission_flags,
+        resource_extension::Allocator,
     },
 };
 use solana_program_test::*;
@@ -1075,9 +1077,8 @@ async fn test_create_dz_prefix_block_resource() {
 }
 
 #[allow(clippy::too_many_arguments)]
-async fn update_device_dz_prefixes(
+async fn update_device_dz_prefixes_instruction(
     banks_client: &mut BanksClient,
-    payer: &solana_sdk::signature::Keypair,
     program_id: Pubkey,
     globalstate_pubkey: Pubkey,
     globalconfig_pubkey: Pubkey,
@@ -1085,9 +1086,7 @@ async fn update_device_dz_prefixes(
     location_pubkey: Pubkey,
     contributor_pubkey: Pubkey,
     dz_prefixes: &str,
-) {
-    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
-
+) -> (DoubleZeroInstruction, Vec<AccountMeta>) {
     let dz_prefixes_list: NetworkV4List = dz_prefixes.parse().unwrap();
 
     let device = get_device(banks_client, device_pubkey)
@@ -1107,10 +1106,7 @@ async fn update_device_dz_prefixes(
         resource_accounts.push(AccountMeta::new(pda, false));
     }
 
-    execute_transaction(
-        banks_client,
-        recent_blockhash,
-        program_id,
+    (
         DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
             dz_prefixes: Some(dz_prefixes_list),
             resource_count: resource_accounts.len(),
@@ -1128,6 +1124,41 @@ async fn update_device_dz_prefixes(
             resource_accounts,
         ]
         .concat(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn update_device_dz_prefixes(
+    banks_client: &mut BanksClient,
+    payer: &solana_sdk::signature::Keypair,
+    program_id: Pubkey,
+    globalstate_pubkey: Pubkey,
+    globalconfig_pubkey: Pubkey,
+    device_pubkey: Pubkey,
+    location_pubkey: Pubkey,
+    contributor_pubkey: Pubkey,
+    dz_prefixes: &str,
+) {
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+
+    let (instruction, accounts) = update_device_dz_prefixes_instruction(
+        banks_client,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        device_pubkey,
+        location_pubkey,
+        contributor_pubkey,
+        dz_prefixes,
+    )
+    .await;
+
+    execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        instruction,
+        accounts,
         payer,
     )
     .await;
@@ -1443,6 +1474,7 @@ async fn create_loopback_interface(
             cir: 0,
             mtu: 9000,
             routing_mode: RoutingMode::Static,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -1753,3 +1785,194 @@ async fn test_device_update_dz_prefixes_shrink_closes_orphaned_blocks() {
 
     println!("[PASS] test_device_update_dz_prefixes_shrink_closes_orphaned_blocks");
 }
+
+// ============================================================================
+// Resize Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_device_update_dz_prefix_grow_preserves_allocations() {
+    println!("[TEST] test_device_update_dz_prefix_grow_preserves_allocations");
+
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+
+    let (device_pubkey, location_pubkey, _, contributor_pubkey) = setup_device_for_dz_prefix_tests(
+        &mut banks_client,
+        &payer,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+    )
+    .await;
+
+    let (dz_prefix_pda, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(device_pubkey, 0));
+
+    // Allocate a user IP so the block can no longer be recreated from scratch
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::AllocateResource(ResourceAllocateArgs {
+            resource_type: ResourceType::DzPrefixBlock(device_pubkey, 0),
+            requested: None,
+        }),
+        vec![
+            AccountMeta::new(dz_prefix_pda, false),
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // Widen the prefix from /24 to /23 keeping the same base address
+    update_device_dz_prefixes(
+        &mut banks_client,
+        &payer,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        device_pubkey,
+        location_pubkey,
+        contributor_pubkey,
+        "110.1.0.0/23",
+    )
+    .await;
+
+    let resource = get_resource_extension_data(&mut banks_client, dz_prefix_pda)
+        .await
+        .expect("DzPrefixBlock resource should exist");
+    match &resource.allocator {
+        Allocator::Ip(ip_allocator) => {
+            assert_eq!(ip_allocator.base_net.to_string(), "110.1.0.0/23")
+        }
+        _ => panic!("Expected IpAllocator"),
+    }
+    let allocated = resource.iter_allocated();
+    assert_eq!(
+        allocated.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        vec!["110.1.0.0/32", "110.1.0.1/32"],
+        "Existing allocations must survive the resize"
+    );
+
+    // The grown half of the block is usable
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::AllocateResource(ResourceAllocateArgs {
+            resource_type: ResourceType::DzPrefixBlock(device_pubkey, 0),
+            requested: Some(IdOrIp::Ip("110.1.1.10/32".parse().unwrap())),
+        }),
+        vec![
+            AccountMeta::new(dz_prefix_pda, false),
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    // Shrinking back to /24 would drop 110.1.1.10 and must be rejected
+    let (instruction, accounts) = update_device_dz_prefixes_instruction(
+        &mut banks_client,
+        program_id,
+        globalstate_pubkey,
+        globalconfig_pubkey,
+        device_pubkey,
+        location_pubkey,
+        contributor_pubkey,
+        "110.1.0.0/24",
+    )
+    .await;
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    let result = try_execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        instruction,
+        accounts,
+        &payer,
+    )
+    .await;
+
+    // DoubleZeroError::ResourceInUse maps to ProgramError::Custom(101).
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(101),
+        ))) => {}
+        other => panic!("expected Custom(101) (ResourceInUse), got {other:?}"),
+    }
+
+    let resource = get_resource_extension_data(&mut banks_client, dz_prefix_pda)
+        .await
+        .expect("DzPrefixBlock resource should exist");
+    assert_eq!(resource.iter_allocated().len(), 3);
+
+    println!("[PASS] test_device_update_dz_prefix_grow_preserves_allocations");
+}
+
+#[tokio::test]
+async fn test_resize_resource_extension_preserves_allocations() {
+    println!("[TEST] test_resize_resource_extension_preserves_allocations");
+
+    let (mut banks_client, payer, program_id, globalstate_pubkey, globalconfig_pubkey) =
+        setup_program_with_globalconfig().await;
+
+    let (resource_pubkey, _, _) =
+        get_resource_extension_pda(&program_id, ResourceType::UserTunnelBlock);
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::AllocateResource(ResourceAllocateArgs {
+            resource_type: ResourceType::UserTunnelBlock,
+            requested: None,
+        }),
+        vec![
+            AccountMeta::new(resource_pubkey, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let before = get_resource_extension_data(&mut banks_client, resource_pubkey)
+        .await
+        .expect("UserTunnelBlock resource should exist");
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::ResizeResourceExtension(ResourceResizeArgs {
+            resource_type: ResourceType::UserTunnelBlock,
+        }),
+        vec![
+            AccountMeta::new(resource_pubkey, false),
+            AccountMeta::new(Pubkey::default(), false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(globalconfig_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let after = get_resource_extension_data(&mut banks_client, resource_pubkey)
+        .await
+        .expect("UserTunnelBlock resource should exist");
+    assert_eq!(after.storage.len(), before.storage.len());
+    assert_eq!(after.iter_allocated(), before.iter_allocated());
+    assert_eq!(after.iter_allocated().len(), 1);
+
+    println!("[PASS] test_resize_resource_extension_preserves_allocations");
+}
@@ -171,6 +171,8 @@ async fn create_edge_seat_pass(
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 4,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -634,6 +636,8 @@ async fn test_cannot_set_max_users_below_current_users() {
         max_unicast_users: 1,
         multicast_user_count: 3,
         max_multicast_users: 4,
+        bandwidth_tier: Default::default(),
+        referrer: Pubkey::default(),
     };
     program_test.add_account(
         accesspass_pubkey,
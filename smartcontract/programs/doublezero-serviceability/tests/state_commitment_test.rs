@@ -0,0 +1,247 @@
+use doublezero_serviceability::{
+    instructions::*,
+    pda::*,
+    processors::state_commitment::record::RecordStateCommitmentArgs,
+    state::{
+        accounttype::AccountType,
+        state_commitment::{leaf_hash, merkle_proof, merkle_root, verify_proof, StateCommitment},
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+use test_helpers::*;
+
+fn assert_custom_error<T: std::fmt::Debug>(result: Result<(), T>, code: u32) {
+    let error_string = format!("{:?}", result.expect_err("expected transaction to fail"));
+    assert!(
+        error_string.contains(&format!("Custom({code})")),
+        "Expected error to contain 'Custom({code})', but got: {error_string}"
+    );
+}
+
+async fn get_state_commitment(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> Option<StateCommitment> {
+    get_account_data(banks_client, pubkey)
+        .await
+        .and_then(|data| data.get_state_commitment().ok())
+}
+
+/// Leaves for `pubkeys`, sorted by pubkey as the off-chain snapshot does.
+async fn snapshot_leaves(
+    banks_client: &mut BanksClient,
+    pubkeys: &[Pubkey],
+) -> Vec<(Pubkey, [u8; 32])> {
+    let mut leaves = vec![];
+    for pubkey in pubkeys {
+        let account = banks_client.get_account(*pubkey).await.unwrap().unwrap();
+        leaves.push((*pubkey, leaf_hash(pubkey, &account.data)));
+    }
+    leaves.sort_by_key(|(pubkey, _)| *pubkey);
+    leaves
+}
+
+#[tokio::test]
+async fn test_state_commitment_record_and_verify() {
+    let (mut context, program_id) = init_test_with_context().await;
+    let payer: Keypair = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+    init_globalstate_and_config(
+        &mut context.banks_client,
+        program_id,
+        &payer,
+        recent_blockhash,
+    )
+    .await;
+
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    let (globalconfig_pubkey, _) = get_globalconfig_pda(&program_id);
+    let (program_config_pubkey, _) = get_program_config_pda(&program_id);
+    let (state_commitment_pubkey, _) = get_state_commitment_pda(&program_id);
+    let accounts = vec![
+        AccountMeta::new(state_commitment_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+
+    let leaves = snapshot_leaves(
+        &mut context.banks_client,
+        &[
+            globalstate_pubkey,
+            globalconfig_pubkey,
+            program_config_pubkey,
+        ],
+    )
+    .await;
+    let hashes: Vec<[u8; 32]> = leaves.iter().map(|(_, hash)| *hash).collect();
+    let root = merkle_root(&hashes);
+
+    let recent_blockhash = warp_to_slot(&mut context, 100).await;
+    execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+            slot: 90,
+            merkle_root: root,
+            account_count: hashes.len() as u32,
+        }),
+        accounts.clone(),
+        &payer,
+    )
+    .await;
+
+    let commitment = get_state_commitment(&mut context.banks_client, state_commitment_pubkey)
+        .await
+        .expect("state commitment should exist");
+    assert_eq!(commitment.account_type, AccountType::StateCommitment);
+    assert_eq!(commitment.slot, 90);
+    assert_eq!(commitment.merkle_root, root);
+    assert_eq!(commitment.account_count, 3);
+    assert_eq!(commitment.recorded_by, payer.pubkey());
+    assert!(commitment.recorded_at_slot >= 100);
+
+    // A light client holding only the globalstate account and its proof can check it
+    // against the onchain root.
+    let index = leaves
+        .iter()
+        .position(|(pubkey, _)| *pubkey == globalstate_pubkey)
+        .unwrap();
+    let proof = merkle_proof(&hashes, index).unwrap();
+    let globalstate = context
+        .banks_client
+        .get_account(globalstate_pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(verify_proof(
+        &commitment.merkle_root,
+        &leaf_hash(&globalstate_pubkey, &globalstate.data),
+        index,
+        commitment.account_count as usize,
+        &proof,
+    ));
+
+    // The same or an older slot cannot replace the recorded commitment.
+    for slot in [90, 50] {
+        let result = execute_transaction_expect_failure(
+            &mut context.banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+                slot,
+                merkle_root: [1u8; 32],
+                account_count: 3,
+            }),
+            accounts.clone(),
+            &payer,
+        )
+        .await;
+        assert_custom_error(result, 120);
+    }
+
+    // A later snapshot overwrites it.
+    execute_transaction(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+            slot: 95,
+            merkle_root: [2u8; 32],
+            account_count: 4,
+        }),
+        accounts,
+        &payer,
+    )
+    .await;
+    let commitment = get_state_commitment(&mut context.banks_client, state_commitment_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        (
+            commitment.slot,
+            commitment.merkle_root,
+            commitment.account_count
+        ),
+        (95, [2u8; 32], 4)
+    );
+}
+
+#[tokio::test]
+async fn test_state_commitment_rejects_invalid_records() {
+    let (mut context, program_id) = init_test_with_context().await;
+    let payer: Keypair = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+    init_globalstate_and_config(
+        &mut context.banks_client,
+        program_id,
+        &payer,
+        recent_blockhash,
+    )
+    .await;
+    let recent_blockhash = warp_to_slot(&mut context, 100).await;
+
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+    let (state_commitment_pubkey, _) = get_state_commitment_pda(&program_id);
+    let accounts = vec![
+        AccountMeta::new(state_commitment_pubkey, false),
+        AccountMeta::new_readonly(globalstate_pubkey, false),
+    ];
+
+    // A slot ahead of the clock, an empty root and an empty tree are all rejected.
+    for (slot, merkle_root, account_count) in [
+        (10_000, [1u8; 32], 1),
+        (50, [0u8; 32], 1),
+        (50, [1u8; 32], 0),
+    ] {
+        let result = execute_transaction_expect_failure(
+            &mut context.banks_client,
+            recent_blockhash,
+            program_id,
+            DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+                slot,
+                merkle_root,
+                account_count,
+            }),
+            accounts.clone(),
+            &payer,
+        )
+        .await;
+        assert_custom_error(result, 120);
+    }
+
+    // Only the foundation or the health oracle may record.
+    let outsider = Keypair::new();
+    transfer(
+        &mut context.banks_client,
+        &payer,
+        &outsider.pubkey(),
+        10_000_000,
+    )
+    .await;
+    let result = execute_transaction_expect_failure(
+        &mut context.banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+            slot: 50,
+            merkle_root: [1u8; 32],
+            account_count: 1,
+        }),
+        accounts,
+        &outsider,
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(
+        get_state_commitment(&mut context.banks_client, state_commitment_pubkey)
+            .await
+            .is_none()
+    );
+}
@@ -3,23 +3,34 @@ use doublezero_serviceability::{
     entrypoint::process_instruction,
     instructions::*,
     pda::{
-        get_contributor_pda, get_exchange_pda, get_globalconfig_pda, get_globalstate_pda,
-        get_location_pda, get_program_config_pda, get_resource_extension_pda, get_topology_pda,
+        get_contributor_pda, get_device_pda, get_exchange_pda, get_globalconfig_pda,
+        get_globalstate_pda, get_link_pda, get_location_pda, get_program_config_pda,
+        get_resource_extension_pda, get_topology_pda,
     },
     processors::{
-        contributor::create::ContributorCreateArgs, exchange::create::ExchangeCreateArgs,
-        globalconfig::set::SetGlobalConfigArgs, location::create::LocationCreateArgs,
+        contributor::create::ContributorCreateArgs,
+        device::{create::DeviceCreateArgs, interface::create::DeviceInterfaceCreateArgs},
+        exchange::create::ExchangeCreateArgs,
+        globalconfig::set::SetGlobalConfigArgs,
+        link::create::LinkCreateArgs,
+        location::create::LocationCreateArgs,
         topology::create::TopologyCreateArgs,
     },
     resource::ResourceType,
     state::{
-        accountdata::AccountData, accounttype::AccountType, device::Device,
-        globalstate::GlobalState, resource_extension::ResourceExtensionOwned,
+        accountdata::AccountData,
+        accounttype::AccountType,
+        device::{Device, DeviceDesiredStatus, DeviceType},
+        globalstate::GlobalState,
+        interface::{InterfaceCYOA, InterfaceDIA, LoopbackType, RoutingMode},
+        link::{LinkDesiredStatus, LinkLinkType},
+        resource_extension::ResourceExtensionOwned,
         topology::TopologyConstraint,
     },
 };
 use solana_program_test::*;
 use solana_sdk::{
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
@@ -85,6 +96,76 @@ pub async fn init_test() -> (BanksClient, Pubkey, Keypair, solana_program::hash:
     (banks_client, program_id, payer, recent_blockhash)
 }
 
+/// Like `init_test`, but keeps the `ProgramTestContext` so the test can warp
+/// the bank with `warp_to_slot`/`warp_to_epoch`/`advance_epochs`.
+/// Returns (context, program_id)
+#[allow(dead_code)]
+pub async fn init_test_with_context() -> (ProgramTestContext, Pubkey) {
+    let program_id = Pubkey::new_unique();
+
+    let mut context = ProgramTest::new(
+        "doublezero_serviceability",
+        program_id,
+        processor!(process_instruction),
+    )
+    .start_with_context()
+    .await;
+
+    let payer = context.payer.insecure_clone();
+    transfer(
+        &mut context.banks_client,
+        &payer,
+        &test_payer().pubkey(),
+        100_000_000,
+    )
+    .await;
+
+    (context, program_id)
+}
+
+/// Current `Clock` sysvar of the bank.
+#[allow(dead_code)]
+pub async fn get_clock(banks_client: &mut BanksClient) -> Clock {
+    banks_client.get_sysvar::<Clock>().await.unwrap()
+}
+
+/// Warp the bank forward to `slot`; returns a blockhash valid after the warp.
+#[allow(dead_code)]
+pub async fn warp_to_slot(
+    context: &mut ProgramTestContext,
+    slot: u64,
+) -> solana_program::hash::Hash {
+    context
+        .warp_to_slot(slot)
+        .unwrap_or_else(|e| panic!("Failed to warp to slot {slot}: {e:?}"));
+    context.get_new_latest_blockhash().await.unwrap()
+}
+
+/// Warp the bank forward to the first slot of `epoch`, so instructions see
+/// `Clock::epoch == epoch`; returns a blockhash valid after the warp.
+#[allow(dead_code)]
+pub async fn warp_to_epoch(
+    context: &mut ProgramTestContext,
+    epoch: u64,
+) -> solana_program::hash::Hash {
+    context
+        .warp_to_epoch(epoch)
+        .unwrap_or_else(|e| panic!("Failed to warp to epoch {epoch}: {e:?}"));
+    context.get_new_latest_blockhash().await.unwrap()
+}
+
+/// Roll the bank over `epochs` epoch boundaries from the current epoch.
+/// Returns (new epoch, blockhash valid after the warp)
+#[allow(dead_code)]
+pub async fn advance_epochs(
+    context: &mut ProgramTestContext,
+    epochs: u64,
+) -> (u64, solana_program::hash::Hash) {
+    let epoch = get_clock(&mut context.banks_client).await.epoch + epochs;
+    let recent_blockhash = warp_to_epoch(context, epoch).await;
+    (epoch, recent_blockhash)
+}
+
 #[allow(dead_code)]
 pub async fn get_globalstate(
     banks_client: &mut BanksClient,
@@ -798,3 +879,298 @@ pub async fn create_unicast_default_topology(
 
     unicast_default_pda
 }
+
+/// A device created by [`TestNetBuilder`], with the interfaces its links use.
+#[allow(dead_code)]
+pub struct TestDevice {
+    pub pubkey: Pubkey,
+    pub code: String,
+    pub interfaces: Vec<String>,
+}
+
+/// A WAN link created by [`TestNetBuilder`] between two of its devices.
+#[allow(dead_code)]
+pub struct TestLink {
+    pub pubkey: Pubkey,
+    pub code: String,
+    pub side_a_pk: Pubkey,
+    pub side_a_iface_name: String,
+    pub side_z_pk: Pubkey,
+    pub side_z_iface_name: String,
+}
+
+/// An initialized program with a location, exchange and contributor ("la", "la",
+/// "cont", owned by `payer`) and the devices and links requested from
+/// [`TestNetBuilder`].
+#[allow(dead_code)]
+pub struct TestNet {
+    pub banks_client: BanksClient,
+    pub program_id: Pubkey,
+    pub payer: Keypair,
+    pub globalstate_pubkey: Pubkey,
+    pub globalconfig_pubkey: Pubkey,
+    pub location_pubkey: Pubkey,
+    pub exchange_pubkey: Pubkey,
+    pub contributor_pubkey: Pubkey,
+    pub devices: Vec<TestDevice>,
+    pub links: Vec<TestLink>,
+}
+
+/// Builds the usual test network in a few lines:
+///
+/// ```ignore
+/// let net = TestNetBuilder::new().with_devices(2).with_link(0, 1).build().await;
+/// let link = &net.links[0];
+/// ```
+///
+/// Devices are Hybrid, activated and coded `dev0`, `dev1`, ... unless named
+/// with [`TestNetBuilder::with_device`]. Each link gets its own interfaces,
+/// `Ethernet{2i}` on side A and `Ethernet{2i+1}` on side Z, and is created with
+/// onchain allocation so it comes up activated and tagged with the
+/// `unicast-default` topology.
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct TestNetBuilder {
+    devices: Vec<String>,
+    links: Vec<(usize, usize)>,
+}
+
+#[allow(dead_code)]
+impl TestNetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `count` devices with generated codes.
+    pub fn with_devices(mut self, count: usize) -> Self {
+        for _ in 0..count {
+            let code = format!("dev{}", self.devices.len());
+            self.devices.push(code);
+        }
+        self
+    }
+
+    /// Adds a device with the given code.
+    pub fn with_device(mut self, code: &str) -> Self {
+        self.devices.push(code.to_string());
+        self
+    }
+
+    /// Adds a link between the devices at indexes `side_a` and `side_z`.
+    pub fn with_link(mut self, side_a: usize, side_z: usize) -> Self {
+        self.links.push((side_a, side_z));
+        self
+    }
+
+    pub async fn build(self) -> TestNet {
+        let (mut banks_client, program_id, payer, recent_blockhash) = init_test().await;
+        init_globalstate_and_config(&mut banks_client, program_id, &payer, recent_blockhash).await;
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+        let (globalconfig_pubkey, _) = get_globalconfig_pda(&program_id);
+        let (location_pubkey, exchange_pubkey, contributor_pubkey) = setup_device_prerequisites(
+            &mut banks_client,
+            recent_blockhash,
+            program_id,
+            globalstate_pubkey,
+            globalconfig_pubkey,
+            &payer,
+        )
+        .await;
+
+        let mut devices = Vec::with_capacity(self.devices.len());
+        for (idx, code) in self.devices.into_iter().enumerate() {
+            let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+            let (pubkey, _) = get_device_pda(&program_id, globalstate.account_index + 1);
+            let (tunnel_ids_pda, _, _) =
+                get_resource_extension_pda(&program_id, ResourceType::TunnelIds(pubkey, 0));
+            let (dz_prefix_pda, _, _) =
+                get_resource_extension_pda(&program_id, ResourceType::DzPrefixBlock(pubkey, 0));
+            let octet = u8::try_from(idx + 1).expect("too many test devices");
+
+            execute_transaction(
+                &mut banks_client,
+                recent_blockhash,
+                program_id,
+                DoubleZeroInstruction::CreateDevice(DeviceCreateArgs {
+                    code: code.clone(),
+                    device_type: DeviceType::Hybrid,
+                    public_ip: [110, 0, 0, octet].into(),
+                    dz_prefixes: format!("100.{octet}.0.0/24").parse().unwrap(),
+                    metrics_publisher_pk: Pubkey::default(),
+                    mgmt_vrf: "mgmt".to_string(),
+                    desired_status: Some(DeviceDesiredStatus::Activated),
+                    resource_count: 2,
+                }),
+                vec![
+                    AccountMeta::new(pubkey, false),
+                    AccountMeta::new(contributor_pubkey, false),
+                    AccountMeta::new(location_pubkey, false),
+                    AccountMeta::new(exchange_pubkey, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                    AccountMeta::new(globalconfig_pubkey, false),
+                    AccountMeta::new(tunnel_ids_pda, false),
+                    AccountMeta::new(dz_prefix_pda, false),
+                ],
+                &payer,
+            )
+            .await;
+
+            devices.push(TestDevice {
+                pubkey,
+                code,
+                interfaces: Vec::new(),
+            });
+        }
+
+        let mut links = Vec::with_capacity(self.links.len());
+        if !self.links.is_empty() {
+            let unicast_default_pda = create_unicast_default_topology(
+                &mut banks_client,
+                program_id,
+                globalstate_pubkey,
+                globalconfig_pubkey,
+                &payer,
+            )
+            .await;
+
+            for (idx, (side_a, side_z)) in self.links.into_iter().enumerate() {
+                let side_a_iface_name = format!("Ethernet{}", 2 * idx);
+                let side_z_iface_name = format!("Ethernet{}", 2 * idx + 1);
+                for (device_idx, name) in
+                    [(side_a, &side_a_iface_name), (side_z, &side_z_iface_name)]
+                {
+                    create_wan_interface(
+                        &mut banks_client,
+                        recent_blockhash,
+                        program_id,
+                        devices[device_idx].pubkey,
+                        contributor_pubkey,
+                        globalstate_pubkey,
+                        name,
+                        &payer,
+                    )
+                    .await;
+                    devices[device_idx].interfaces.push(name.clone());
+                }
+
+                let globalstate = get_globalstate(&mut banks_client, globalstate_pubkey).await;
+                let (pubkey, _) = get_link_pda(&program_id, globalstate.account_index + 1);
+                let code = format!("link{idx}");
+                let (side_a_pk, side_z_pk) = (devices[side_a].pubkey, devices[side_z].pubkey);
+
+                execute_transaction(
+                    &mut banks_client,
+                    recent_blockhash,
+                    program_id,
+                    DoubleZeroInstruction::CreateLink(LinkCreateArgs {
+                        code: code.clone(),
+                        link_type: LinkLinkType::WAN,
+                        bandwidth: 10_000_000_000,
+                        mtu: 9000,
+                        delay_ns: 1_000_000,
+                        jitter_ns: 100_000,
+                        side_a_iface_name: side_a_iface_name.clone(),
+                        side_z_iface_name: Some(side_z_iface_name.clone()),
+                        desired_status: Some(LinkDesiredStatus::Activated),
+                        use_onchain_allocation: true,
+                    }),
+                    vec![
+                        AccountMeta::new(pubkey, false),
+                        AccountMeta::new(contributor_pubkey, false),
+                        AccountMeta::new(side_a_pk, false),
+                        AccountMeta::new(side_z_pk, false),
+                        AccountMeta::new(globalstate_pubkey, false),
+                        AccountMeta::new(unicast_default_pda, false),
+                        AccountMeta::new(
+                            get_resource_extension_pda(
+                                &program_id,
+                                ResourceType::DeviceTunnelBlock,
+                            )
+                            .0,
+                            false,
+                        ),
+                        AccountMeta::new(
+                            get_resource_extension_pda(&program_id, ResourceType::LinkIds).0,
+                            false,
+                        ),
+                    ],
+                    &payer,
+                )
+                .await;
+
+                links.push(TestLink {
+                    pubkey,
+                    code,
+                    side_a_pk,
+                    side_a_iface_name,
+                    side_z_pk,
+                    side_z_iface_name,
+                });
+            }
+        }
+
+        TestNet {
+            banks_client,
+            program_id,
+            payer,
+            globalstate_pubkey,
+            globalconfig_pubkey,
+            location_pubkey,
+            exchange_pubkey,
+            contributor_pubkey,
+            devices,
+            links,
+        }
+    }
+}
+
+/// Create a plain 100G WAN interface on a device with onchain allocation.
+#[allow(dead_code, clippy::too_many_arguments)]
+pub async fn create_wan_interface(
+    banks_client: &mut BanksClient,
+    recent_blockhash: solana_program::hash::Hash,
+    program_id: Pubkey,
+    device_pubkey: Pubkey,
+    contributor_pubkey: Pubkey,
+    globalstate_pubkey: Pubkey,
+    name: &str,
+    payer: &Keypair,
+) {
+    execute_transaction(
+        banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::CreateDeviceInterface(DeviceInterfaceCreateArgs {
+            name: name.to_string(),
+            interface_dia: InterfaceDIA::None,
+            loopback_type: LoopbackType::None,
+            interface_cyoa: InterfaceCYOA::None,
+            bandwidth: 100_000_000_000,
+            ip_net: None,
+            cir: 0,
+            mtu: 9000,
+            routing_mode: RoutingMode::Static,
+            vlan_id: 0,
+            user_tunnel_endpoint: false,
+            use_onchain_allocation: true,
+            topology_count: 0,
+            with_ip_allocation: false,
+        }),
+        vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(contributor_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+            AccountMeta::new(
+                get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock).0,
+                false,
+            ),
+            AccountMeta::new(
+                get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds).0,
+                false,
+            ),
+        ],
+        payer,
+    )
+    .await;
+}
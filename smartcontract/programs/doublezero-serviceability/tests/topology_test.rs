@@ -783,6 +783,7 @@ async fn setup_wan_link(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_a_pubkey, false),
@@ -849,6 +850,7 @@ async fn setup_wan_link(
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_z_pubkey, false),
@@ -1793,6 +1795,7 @@ async fn test_topology_backfill_populates_vpnv4_loopbacks() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -2156,6 +2159,7 @@ async fn test_topology_backfill_allocates_sr_id_from_onchain_resource() {
             user_tunnel_endpoint: false,
             use_onchain_allocation: true,
             topology_count: 0,
+            with_ip_allocation: false,
         }),
         vec![
             AccountMeta::new(device_pubkey, false),
@@ -12,6 +12,7 @@ use doublezero_serviceability::{
         device::{create::DeviceCreateArgs, update::DeviceUpdateArgs},
         exchange::create::ExchangeCreateArgs,
         globalconfig::set::SetGlobalConfigArgs,
+        globalstate::setpause::SetPauseArgs,
         location::create::LocationCreateArgs,
         user::{create::UserCreateArgs, set_bgp_status::SetUserBGPStatusArgs},
     },
@@ -19,6 +20,7 @@ use doublezero_serviceability::{
     state::{
         accesspass::AccessPassType,
         device::DeviceType,
+        pause_flags::PauseFlag,
         user::{BGPStatus, UserCYOA, UserType},
     },
 };
@@ -256,6 +258,8 @@ async fn setup() -> BgpStatusTestEnv {
             allow_multiple_ip: false,
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
         }),
         vec![
             AccountMeta::new(accesspass_pubkey, false),
@@ -277,6 +281,7 @@ async fn setup() -> BgpStatusTestEnv {
             cyoa_type: UserCYOA::GREOverDIA,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             dz_prefix_count: 1,
+            bandwidth_tier: None,
         }),
         vec![
             AccountMeta::new(user_pubkey, false),
@@ -339,7 +344,7 @@ async fn test_bgp_status_first_write_up() {
     assert_eq!(user_before.bgp_rtt_ns, 0);
 
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
-    execute_transaction(
+    execute_transaction_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -352,6 +357,10 @@ async fn test_bgp_status_first_write_up() {
             AccountMeta::new(device_pubkey, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -389,7 +398,7 @@ async fn test_bgp_status_first_write_down() {
     } = setup().await;
 
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
-    execute_transaction(
+    execute_transaction_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -402,6 +411,10 @@ async fn test_bgp_status_first_write_down() {
             AccountMeta::new(device_pubkey, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -436,7 +449,7 @@ async fn test_bgp_status_multiple_writes() {
 
     // Write 1: Up
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
-    execute_transaction(
+    execute_transaction_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -449,6 +462,10 @@ async fn test_bgp_status_multiple_writes() {
             AccountMeta::new(device_pubkey, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -464,7 +481,7 @@ async fn test_bgp_status_multiple_writes() {
 
     // Write 2: Down — last_bgp_up_at must not change; last_bgp_reported_at must advance
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
-    execute_transaction(
+    execute_transaction_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -477,6 +494,10 @@ async fn test_bgp_status_multiple_writes() {
             AccountMeta::new(device_pubkey, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -498,7 +519,7 @@ async fn test_bgp_status_multiple_writes() {
 
     // Write 3: Up again — last_bgp_up_at must advance; last_bgp_reported_at must advance
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
-    execute_transaction(
+    execute_transaction_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -511,6 +532,10 @@ async fn test_bgp_status_multiple_writes() {
             AccountMeta::new(device_pubkey, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -554,7 +579,7 @@ async fn test_bgp_status_wrong_signer() {
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
 
     // wrong_signer.pubkey() != device.metrics_publisher_pk (== payer.pubkey())
-    let result = execute_transaction_expect_failure(
+    let result = execute_transaction_expect_failure_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -567,6 +592,10 @@ async fn test_bgp_status_wrong_signer() {
             AccountMeta::new(device_pubkey, false),
         ],
         &wrong_signer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
@@ -670,7 +699,7 @@ async fn test_bgp_status_user_device_mismatch() {
     let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
 
     // user_pubkey.device_pk == device_1; passing device_2 must trigger NotAllowed.
-    let result = execute_transaction_expect_failure(
+    let result = execute_transaction_expect_failure_with_extra_accounts(
         &mut banks_client,
         recent_blockhash,
         program_id,
@@ -683,8 +712,100 @@ async fn test_bgp_status_user_device_mismatch() {
             AccountMeta::new(device_pubkey_2, false),
         ],
         &payer,
+        &[AccountMeta::new_readonly(
+            get_globalstate_pda(&program_id).0,
+            false,
+        )],
     )
     .await;
 
     assert_not_allowed(result);
 }
+
+/// SetUserBGPStatus is an update: it is rejected while updates are paused.
+#[tokio::test]
+async fn test_bgp_status_rejected_when_updates_paused() {
+    let BgpStatusTestEnv {
+        mut banks_client,
+        payer,
+        program_id,
+        device_pubkey,
+        user_pubkey,
+    } = setup().await;
+    let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetPause(SetPauseArgs {
+            paused: PauseFlag::Update.to_mask(),
+        }),
+        vec![AccountMeta::new(globalstate_pubkey, false)],
+        &payer,
+    )
+    .await;
+
+    let result = execute_transaction_expect_failure_with_extra_accounts(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetUserBGPStatus(SetUserBGPStatusArgs {
+            bgp_status: BGPStatus::Up,
+            bgp_rtt_ns: 0,
+        }),
+        vec![
+            AccountMeta::new(user_pubkey, false),
+            AccountMeta::new(device_pubkey, false),
+        ],
+        &payer,
+        &[AccountMeta::new_readonly(globalstate_pubkey, false)],
+    )
+    .await;
+
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(105), // DoubleZeroError::ProgramPaused
+        ))) => {}
+        _ => panic!("expected ProgramPaused (Custom(105)), got: {:?}", result),
+    }
+}
+
+/// Agents built before the emergency pause send no GlobalState; they keep working
+/// and are treated as unpaused.
+#[tokio::test]
+async fn test_bgp_status_without_globalstate_succeeds() {
+    let BgpStatusTestEnv {
+        mut banks_client,
+        payer,
+        program_id,
+        device_pubkey,
+        user_pubkey,
+    } = setup().await;
+
+    let recent_blockhash = wait_for_new_blockhash(&mut banks_client).await;
+    execute_transaction(
+        &mut banks_client,
+        recent_blockhash,
+        program_id,
+        DoubleZeroInstruction::SetUserBGPStatus(SetUserBGPStatusArgs {
+            bgp_status: BGPStatus::Up,
+            bgp_rtt_ns: 0,
+        }),
+        vec![
+            AccountMeta::new(user_pubkey, false),
+            AccountMeta::new(device_pubkey, false),
+        ],
+        &payer,
+    )
+    .await;
+
+    let user = get_account_data(&mut banks_client, user_pubkey)
+        .await
+        .unwrap()
+        .get_user()
+        .unwrap();
+    assert_eq!(user.bgp_status, BGPStatus::Up);
+}
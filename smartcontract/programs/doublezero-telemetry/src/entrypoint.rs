@@ -1,10 +1,16 @@
 use crate::{
     instructions::TelemetryInstruction,
     processors::telemetry::{
+        close_samples_account::process_close_samples_account,
+        finalize_epoch_samples::process_finalize_epoch_samples,
         initialize_device_latency_samples::process_initialize_device_latency_samples,
         initialize_internet_latency_samples::process_initialize_internet_latency_samples,
+        initialize_path_probe_samples::process_initialize_path_probe_samples,
+        rotate_metrics_publisher::process_rotate_metrics_publisher,
+        set_provider_sampling_budget::process_set_provider_sampling_budget,
         write_device_latency_samples::process_write_device_latency_samples,
         write_internet_latency_samples::process_write_internet_latency_samples,
+        write_path_probe_samples::process_write_path_probe_samples,
     },
 };
 
@@ -37,6 +43,24 @@ pub fn process_instruction(
         TelemetryInstruction::WriteInternetLatencySamples(args) => {
             process_write_internet_latency_samples(program_id, accounts, &args)?
         }
+        TelemetryInstruction::CloseSamplesAccount(args) => {
+            process_close_samples_account(program_id, accounts, &args)?
+        }
+        TelemetryInstruction::FinalizeEpochSamples(args) => {
+            process_finalize_epoch_samples(program_id, accounts, &args)?
+        }
+        TelemetryInstruction::RotateMetricsPublisher => {
+            process_rotate_metrics_publisher(program_id, accounts)?
+        }
+        TelemetryInstruction::SetProviderSamplingBudget(args) => {
+            process_set_provider_sampling_budget(program_id, accounts, &args)?
+        }
+        TelemetryInstruction::InitializePathProbeSamples(args) => {
+            process_initialize_path_probe_samples(program_id, accounts, &args)?
+        }
+        TelemetryInstruction::WritePathProbeSamples(args) => {
+            process_write_path_probe_samples(program_id, accounts, &args)?
+        }
     };
 
     Ok(())
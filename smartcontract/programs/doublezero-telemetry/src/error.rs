@@ -37,6 +37,28 @@ pub enum TelemetryError {
     SameTargetAsOrigin = 1016,
     /// Write transaction contains no samples
     EmptyLatencySamples = 1017,
+    /// Samples account is still within its retention period
+    RetentionPeriodNotElapsed = 1018,
+    /// Signer is neither the account's agent nor in the foundation allowlist
+    UnauthorizedCloser = 1019,
+    /// Rent refund destination is not in the foundation allowlist
+    InvalidTreasury = 1020,
+    /// Samples account has been finalized and accepts no more writes
+    SamplesAccountFinalized = 1021,
+    /// Samples account's epoch has not ended yet
+    EpochNotEnded = 1022,
+    /// Rent refund destination is not the account's agent
+    InvalidRentRecipient = 1023,
+    /// Samples account already belongs to the device's metrics publisher
+    MetricsPublisherUnchanged = 1024,
+    /// Device is not the samples account's origin device
+    OriginDeviceMismatch = 1025,
+    /// Write would exceed the data provider's sampling budget
+    SamplingBudgetExceeded = 1026,
+    /// Sampling budget must allow between 1 and the account capacity of samples
+    InvalidSamplingBudget = 1027,
+    /// User is not activated
+    UserNotActivated = 1028,
 }
 
 impl From<TelemetryError> for ProgramError {
@@ -45,6 +67,44 @@ impl From<TelemetryError> for ProgramError {
     }
 }
 
+impl TryFrom<u32> for TelemetryError {
+    type Error = u32;
+
+    fn try_from(code: u32) -> Result<Self, u32> {
+        match code {
+            1001 => Ok(Self::UnauthorizedAgent),
+            1002 => Ok(Self::DeviceNotActivated),
+            1003 => Ok(Self::LinkNotActivated),
+            1004 => Ok(Self::InvalidLink),
+            1005 => Ok(Self::EpochMismatch),
+            1006 => Ok(Self::SamplesAccountFull),
+            1007 => Ok(Self::InvalidAccountType),
+            1008 => Ok(Self::InvalidAccountOwner),
+            1009 => Ok(Self::InvalidPDA),
+            1010 => Ok(Self::AccountAlreadyExists),
+            1011 => Ok(Self::AccountDoesNotExist),
+            1012 => Ok(Self::InvalidSamplingInterval),
+            1013 => Ok(Self::SamplesBatchTooLarge),
+            1014 => Ok(Self::ExchangeNotActiveOrSuspended),
+            1015 => Ok(Self::DataProviderNameTooLong),
+            1016 => Ok(Self::SameTargetAsOrigin),
+            1017 => Ok(Self::EmptyLatencySamples),
+            1018 => Ok(Self::RetentionPeriodNotElapsed),
+            1019 => Ok(Self::UnauthorizedCloser),
+            1020 => Ok(Self::InvalidTreasury),
+            1021 => Ok(Self::SamplesAccountFinalized),
+            1022 => Ok(Self::EpochNotEnded),
+            1023 => Ok(Self::InvalidRentRecipient),
+            1024 => Ok(Self::MetricsPublisherUnchanged),
+            1025 => Ok(Self::OriginDeviceMismatch),
+            1026 => Ok(Self::SamplingBudgetExceeded),
+            1027 => Ok(Self::InvalidSamplingBudget),
+            1028 => Ok(Self::UserNotActivated),
+            _ => Err(code),
+        }
+    }
+}
+
 impl fmt::Display for TelemetryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -78,6 +138,33 @@ impl fmt::Display for TelemetryError {
             Self::DataProviderNameTooLong => write!(f, "Data provider name exceeds 32 bytes"),
             Self::SameTargetAsOrigin => write!(f, "Origin and target are the same exchange"),
             Self::EmptyLatencySamples => write!(f, "Write transaction contains no samples"),
+            Self::RetentionPeriodNotElapsed => {
+                write!(f, "Samples account is still within its retention period")
+            }
+            Self::UnauthorizedCloser => write!(
+                f,
+                "Signer is neither the account's agent nor in the foundation allowlist"
+            ),
+            Self::InvalidTreasury => {
+                write!(f, "Treasury is not in the foundation allowlist")
+            }
+            Self::SamplesAccountFinalized => write!(f, "Samples account is finalized"),
+            Self::EpochNotEnded => write!(f, "Samples account epoch has not ended"),
+            Self::InvalidRentRecipient => {
+                write!(f, "Rent refund destination is not the account's agent")
+            }
+            Self::MetricsPublisherUnchanged => write!(
+                f,
+                "Samples account already belongs to the device's metrics publisher"
+            ),
+            Self::OriginDeviceMismatch => {
+                write!(f, "Device is not the samples account's origin device")
+            }
+            Self::SamplingBudgetExceeded => {
+                write!(f, "Write would exceed the data provider's sampling budget")
+            }
+            Self::InvalidSamplingBudget => write!(f, "Invalid sampling budget"),
+            Self::UserNotActivated => write!(f, "User is not activated"),
         }
     }
 }
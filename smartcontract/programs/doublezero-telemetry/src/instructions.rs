@@ -1,8 +1,13 @@
 use crate::processors::telemetry::{
+    close_samples_account::CloseSamplesAccountArgs,
+    finalize_epoch_samples::FinalizeEpochSamplesArgs,
     initialize_device_latency_samples::InitializeDeviceLatencySamplesArgs,
     initialize_internet_latency_samples::InitializeInternetLatencySamplesArgs,
+    initialize_path_probe_samples::InitializePathProbeSamplesArgs,
+    set_provider_sampling_budget::SetProviderSamplingBudgetArgs,
     write_device_latency_samples::WriteDeviceLatencySamplesArgs,
     write_internet_latency_samples::WriteInternetLatencySamplesArgs,
+    write_path_probe_samples::WritePathProbeSamplesArgs,
 };
 use borsh::BorshSerialize;
 use solana_program::program_error::ProgramError;
@@ -18,12 +23,30 @@ pub enum TelemetryInstruction {
     InitializeInternetLatencySamples(InitializeInternetLatencySamplesArgs),
     /// Write internet latency samples to chain
     WriteInternetLatencySamples(WriteInternetLatencySamplesArgs),
+    /// Close an aged latency samples account, refunding its rent to the treasury
+    CloseSamplesAccount(CloseSamplesAccountArgs),
+    /// Freeze a latency samples account whose epoch has ended, optionally compacting it
+    FinalizeEpochSamples(FinalizeEpochSamplesArgs),
+    /// Hand a device latency samples account over to its device's new metrics publisher
+    RotateMetricsPublisher,
+    /// Cap the samples an internet latency data provider may write per exchange pair and epoch
+    SetProviderSamplingBudget(SetProviderSamplingBudgetArgs),
+    /// Initialize path probe samples account for a user's tunnel and a target device
+    InitializePathProbeSamples(InitializePathProbeSamplesArgs),
+    /// Write path probe samples to chain
+    WritePathProbeSamples(WritePathProbeSamplesArgs),
 }
 
 pub const INITIALIZE_DEVICE_LATENCY_SAMPLES_INSTRUCTION_INDEX: u8 = 0;
 pub const WRITE_DEVICE_LATENCY_SAMPLES_INSTRUCTION_INDEX: u8 = 1;
 pub const INITIALIZE_INTERNET_LATENCY_SAMPLES_INSTRUCTION_INDEX: u8 = 2;
 pub const WRITE_INTERNET_LATENCY_SAMPLES_INSTRUCTION_INDEX: u8 = 3;
+pub const CLOSE_SAMPLES_ACCOUNT_INSTRUCTION_INDEX: u8 = 4;
+pub const FINALIZE_EPOCH_SAMPLES_INSTRUCTION_INDEX: u8 = 5;
+pub const ROTATE_METRICS_PUBLISHER_INSTRUCTION_INDEX: u8 = 6;
+pub const SET_PROVIDER_SAMPLING_BUDGET_INSTRUCTION_INDEX: u8 = 7;
+pub const INITIALIZE_PATH_PROBE_SAMPLES_INSTRUCTION_INDEX: u8 = 8;
+pub const WRITE_PATH_PROBE_SAMPLES_INSTRUCTION_INDEX: u8 = 9;
 
 impl TelemetryInstruction {
     pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {
@@ -63,6 +86,30 @@ impl TelemetryInstruction {
                     WriteInternetLatencySamplesArgs::try_from(rest)?,
                 )
             }
+            CLOSE_SAMPLES_ACCOUNT_INSTRUCTION_INDEX => {
+                TelemetryInstruction::CloseSamplesAccount(CloseSamplesAccountArgs::try_from(rest)?)
+            }
+            FINALIZE_EPOCH_SAMPLES_INSTRUCTION_INDEX => TelemetryInstruction::FinalizeEpochSamples(
+                FinalizeEpochSamplesArgs::try_from(rest)?,
+            ),
+            ROTATE_METRICS_PUBLISHER_INSTRUCTION_INDEX => {
+                TelemetryInstruction::RotateMetricsPublisher
+            }
+            SET_PROVIDER_SAMPLING_BUDGET_INSTRUCTION_INDEX => {
+                TelemetryInstruction::SetProviderSamplingBudget(
+                    SetProviderSamplingBudgetArgs::try_from(rest)?,
+                )
+            }
+            INITIALIZE_PATH_PROBE_SAMPLES_INSTRUCTION_INDEX => {
+                TelemetryInstruction::InitializePathProbeSamples(
+                    InitializePathProbeSamplesArgs::try_from(rest)?,
+                )
+            }
+            WRITE_PATH_PROBE_SAMPLES_INSTRUCTION_INDEX => {
+                TelemetryInstruction::WritePathProbeSamples(WritePathProbeSamplesArgs::try_from(
+                    rest,
+                )?)
+            }
             _ => return Err(ProgramError::InvalidInstructionData),
         };
 
@@ -110,5 +157,33 @@ mod tests {
                 samples: vec![],
             },
         ));
+        test_instruction(TelemetryInstruction::CloseSamplesAccount(
+            CloseSamplesAccountArgs {
+                retention_epochs: 10,
+            },
+        ));
+        test_instruction(TelemetryInstruction::FinalizeEpochSamples(
+            FinalizeEpochSamplesArgs { compact: true },
+        ));
+        test_instruction(TelemetryInstruction::RotateMetricsPublisher);
+        test_instruction(TelemetryInstruction::SetProviderSamplingBudget(
+            SetProviderSamplingBudgetArgs {
+                oracle_agent_pk: solana_program::pubkey::Pubkey::new_unique(),
+                data_provider_name: "data_provider_name".to_string(),
+                max_samples_per_epoch: 1_440,
+            },
+        ));
+        test_instruction(TelemetryInstruction::InitializePathProbeSamples(
+            InitializePathProbeSamplesArgs {
+                epoch: 100,
+                sampling_interval_microseconds: 30_000_000,
+            },
+        ));
+        test_instruction(TelemetryInstruction::WritePathProbeSamples(
+            WritePathProbeSamplesArgs {
+                start_timestamp_microseconds: 1000,
+                samples: vec![1_200, 0, 1_350],
+            },
+        ));
     }
 }
@@ -1,4 +1,7 @@
-use crate::seeds::{SEED_DEVICE_LATENCY_SAMPLES, SEED_INTERNET_LATENCY_SAMPLES, SEED_PREFIX};
+use crate::seeds::{
+    SEED_DEVICE_LATENCY_SAMPLES, SEED_INTERNET_LATENCY_SAMPLES, SEED_PATH_PROBE_SAMPLES,
+    SEED_PREFIX, SEED_PROVIDER_SAMPLING_BUDGET,
+};
 use solana_program::pubkey::Pubkey;
 
 /// Derive PDA for DZ latency samples account.
@@ -22,6 +25,41 @@ pub fn derive_device_latency_samples_pda(
     )
 }
 
+/// Derive PDA for a shard of a DZ latency samples account. Shard 0 is the
+/// account from [`derive_device_latency_samples_pda`]; writes roll over to
+/// later shards, which append the shard index to the seeds, once it is full.
+pub fn derive_device_latency_samples_shard_pda(
+    program_id: &Pubkey,
+    origin_device_pk: &Pubkey,
+    target_device_pk: &Pubkey,
+    link_pk: &Pubkey,
+    epoch: u64,
+    shard_index: u8,
+) -> (Pubkey, u8) {
+    if shard_index == 0 {
+        return derive_device_latency_samples_pda(
+            program_id,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            epoch,
+        );
+    }
+
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_DEVICE_LATENCY_SAMPLES,
+            origin_device_pk.as_ref(),
+            target_device_pk.as_ref(),
+            link_pk.as_ref(),
+            &epoch.to_le_bytes(),
+            &[shard_index],
+        ],
+        program_id,
+    )
+}
+
 /// Derive PDA for Internet latency samples account
 pub fn derive_internet_latency_samples_pda(
     program_id: &Pubkey,
@@ -44,3 +82,41 @@ pub fn derive_internet_latency_samples_pda(
         program_id,
     )
 }
+
+/// Derive PDA for the sampling budget of an internet latency data provider
+pub fn derive_provider_sampling_budget_pda(
+    program_id: &Pubkey,
+    oracle_agent_pk: &Pubkey,
+    data_provider_name: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_PROVIDER_SAMPLING_BUDGET,
+            oracle_agent_pk.as_ref(),
+            data_provider_name.as_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive PDA for the path probe samples of a user's tunnel towards a target device
+pub fn derive_path_probe_samples_pda(
+    program_id: &Pubkey,
+    prober_agent_pk: &Pubkey,
+    user_pk: &Pubkey,
+    target_device_pk: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_PATH_PROBE_SAMPLES,
+            prober_agent_pk.as_ref(),
+            user_pk.as_ref(),
+            target_device_pk.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
@@ -0,0 +1,145 @@
+use crate::{
+    error::TelemetryError,
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+        internet_latency_samples::InternetLatencySamplesHeader,
+        path_probe_samples::PathProbeSamplesHeader,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::account_io::try_acc_close;
+use doublezero_serviceability::{pda::get_globalstate_pda, state::globalstate::GlobalState};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Minimum number of epochs a samples account is kept after its own epoch.
+/// Requests for a shorter retention are raised to this floor so that samples
+/// still needed by downstream consumers can't be pruned.
+pub const MIN_SAMPLES_RETENTION_EPOCHS: u64 = 10;
+
+// Instruction arguments for closing an aged latency samples account.
+#[derive(BorshSerialize, BorshDeserializeIncremental, Clone, Debug, PartialEq)]
+pub struct CloseSamplesAccountArgs {
+    pub retention_epochs: u64,
+}
+
+/// Closes a `DeviceLatencySamples`, `InternetLatencySamples` or
+/// `PathProbeSamples` account once its epoch is older than the retention
+/// period, refunding the rent to a treasury account.
+///
+/// The signer must be the agent that owns the samples account or a member of
+/// the serviceability foundation allowlist. The treasury must also be in the
+/// foundation allowlist, so the rent always returns to the foundation.
+///
+/// Errors:
+/// - `RetentionPeriodNotElapsed`: account epoch + retention >= current epoch
+/// - `UnauthorizedCloser`: signer is neither the agent nor a foundation member
+/// - `InvalidTreasury`: treasury is not in the foundation allowlist
+/// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`, `InvalidPDA`
+pub fn process_close_samples_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CloseSamplesAccountArgs,
+) -> ProgramResult {
+    msg!("Processing CloseSamplesAccount: {:?}", args);
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [latency_samples_account, signer, treasury, serviceability_globalstate]
+    let latency_samples_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+    let treasury = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if latency_samples_account.data_is_empty() {
+        msg!("Latency samples account does not exist");
+        return Err(TelemetryError::AccountDoesNotExist.into());
+    }
+    if latency_samples_account.owner != program_id {
+        return Err(TelemetryError::InvalidAccountOwner.into());
+    }
+
+    // Read the epoch and the authorized agent from whichever header this is.
+    let (epoch, agent_pk) = {
+        let data = latency_samples_account.try_borrow_data()?;
+        match AccountType::try_from(data[0]) {
+            Ok(AccountType::DeviceLatencySamples) => {
+                let header = DeviceLatencySamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+                (header.epoch, header.origin_device_agent_pk)
+            }
+            Ok(AccountType::InternetLatencySamples) => {
+                let header = InternetLatencySamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+                (header.epoch, header.oracle_agent_pk)
+            }
+            Ok(AccountType::PathProbeSamples) => {
+                let header = PathProbeSamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+                (header.epoch, header.prober_agent_pk)
+            }
+            _ => return Err(TelemetryError::InvalidAccountType.into()),
+        }
+    };
+
+    let retention_epochs = args.retention_epochs.max(MIN_SAMPLES_RETENTION_EPOCHS);
+    let current_epoch = Clock::get()?.epoch;
+    if epoch.saturating_add(retention_epochs) >= current_epoch {
+        msg!(
+            "Samples for epoch {} are retained for {} epochs (current epoch {})",
+            epoch,
+            retention_epochs,
+            current_epoch
+        );
+        return Err(TelemetryError::RetentionPeriodNotElapsed.into());
+    }
+
+    // The foundation allowlist lives in the serviceability global state.
+    let serviceability_program_id = &serviceability_program_id();
+    if globalstate_account.owner != serviceability_program_id {
+        msg!("Global state is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (globalstate_pda, _) = get_globalstate_pda(serviceability_program_id);
+    if *globalstate_account.key != globalstate_pda {
+        msg!("Invalid PDA for serviceability global state");
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+
+    if *signer.key != agent_pk && !globalstate.foundation_allowlist.contains(signer.key) {
+        msg!("Signer {} may not close samples account", signer.key);
+        return Err(TelemetryError::UnauthorizedCloser.into());
+    }
+    if !globalstate.foundation_allowlist.contains(treasury.key) {
+        msg!(
+            "Treasury {} is not in the foundation allowlist",
+            treasury.key
+        );
+        return Err(TelemetryError::InvalidTreasury.into());
+    }
+
+    // Refund the rent to the treasury and release the account.
+    try_acc_close(latency_samples_account, treasury)?;
+
+    msg!(
+        "Closed latency samples account {} for epoch {}",
+        latency_samples_account.key,
+        epoch
+    );
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use crate::{
+    error::TelemetryError,
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        device_latency_samples::{DeviceLatencySamplesHeader, DEVICE_LATENCY_SAMPLES_HEADER_SIZE},
+        internet_latency_samples::InternetLatencySamplesHeader,
+        path_probe_samples::{PathProbeSamplesHeader, PATH_PROBE_SAMPLES_HEADER_SIZE},
+        SAMPLES_FLAG_FINALIZED,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_serviceability::{pda::get_globalstate_pda, state::globalstate::GlobalState};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+// Instruction arguments for finalizing a latency samples account.
+#[derive(BorshSerialize, BorshDeserializeIncremental, Clone, Debug, PartialEq)]
+pub struct FinalizeEpochSamplesArgs {
+    /// Shrink the account to the samples actually written and refund the
+    /// surplus rent to the agent that funded it.
+    pub compact: bool,
+}
+
+enum SamplesHeader {
+    Device(DeviceLatencySamplesHeader),
+    Internet(InternetLatencySamplesHeader),
+    PathProbe(PathProbeSamplesHeader),
+}
+
+/// Freezes a `DeviceLatencySamples`, `InternetLatencySamples` or `PathProbeSamples`
+/// account once its epoch has ended, so later writes fail with `SamplesAccountFinalized`.
+///
+/// With `compact`, the account is truncated to its header plus the samples
+/// written so far and any lamports above the rent-exempt minimum go back to
+/// the agent, which paid for the account and its growth. The account can
+/// still be closed with `CloseSamplesAccount` once its retention has elapsed.
+///
+/// The signer must be the agent that owns the samples account or a member of
+/// the serviceability foundation allowlist.
+///
+/// Errors:
+/// - `EpochNotEnded`: account epoch >= current epoch
+/// - `SamplesAccountFinalized`: the account is already finalized
+/// - `UnauthorizedCloser`: signer is neither the agent nor a foundation member
+/// - `InvalidRentRecipient`: the refund account is not the account's agent
+/// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`, `InvalidPDA`
+pub fn process_finalize_epoch_samples(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &FinalizeEpochSamplesArgs,
+) -> ProgramResult {
+    msg!("Processing FinalizeEpochSamples: {:?}", args);
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [latency_samples_account, signer, agent, serviceability_globalstate]
+    let latency_samples_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+    let agent = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if latency_samples_account.data_is_empty() {
+        msg!("Latency samples account does not exist");
+        return Err(TelemetryError::AccountDoesNotExist.into());
+    }
+    if latency_samples_account.owner != program_id {
+        return Err(TelemetryError::InvalidAccountOwner.into());
+    }
+
+    let header = {
+        let data = latency_samples_account.try_borrow_data()?;
+        match AccountType::try_from(data[0]) {
+            Ok(AccountType::DeviceLatencySamples) => SamplesHeader::Device(
+                DeviceLatencySamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+            Ok(AccountType::InternetLatencySamples) => SamplesHeader::Internet(
+                InternetLatencySamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+            Ok(AccountType::PathProbeSamples) => SamplesHeader::PathProbe(
+                PathProbeSamplesHeader::try_from(&data[..])
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+            _ => return Err(TelemetryError::InvalidAccountType.into()),
+        }
+    };
+    let (epoch, agent_pk, finalized, used_len) = match &header {
+        SamplesHeader::Device(h) => (
+            h.epoch,
+            h.origin_device_agent_pk,
+            h.is_finalized(),
+            DEVICE_LATENCY_SAMPLES_HEADER_SIZE + h.next_sample_index as usize * 4,
+        ),
+        SamplesHeader::Internet(h) => (
+            h.epoch,
+            h.oracle_agent_pk,
+            h.is_finalized(),
+            InternetLatencySamplesHeader::instance_size(h.data_provider_name.len())
+                + h.next_sample_index as usize * 4,
+        ),
+        SamplesHeader::PathProbe(h) => (
+            h.epoch,
+            h.prober_agent_pk,
+            h.is_finalized(),
+            PATH_PROBE_SAMPLES_HEADER_SIZE + h.next_sample_index as usize * 4,
+        ),
+    };
+
+    if finalized {
+        msg!("Samples account for epoch {} is already finalized", epoch);
+        return Err(TelemetryError::SamplesAccountFinalized.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    if epoch >= current_epoch {
+        msg!(
+            "Samples epoch {} has not ended (current epoch {})",
+            epoch,
+            current_epoch
+        );
+        return Err(TelemetryError::EpochNotEnded.into());
+    }
+
+    if *signer.key != agent_pk {
+        // Anyone other than the agent must be in the foundation allowlist,
+        // which lives in the serviceability global state.
+        let serviceability_program_id = &serviceability_program_id();
+        if globalstate_account.owner != serviceability_program_id {
+            msg!("Global state is not owned by serviceability program");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (globalstate_pda, _) = get_globalstate_pda(serviceability_program_id);
+        if *globalstate_account.key != globalstate_pda {
+            msg!("Invalid PDA for serviceability global state");
+            return Err(TelemetryError::InvalidPDA.into());
+        }
+        let globalstate = GlobalState::try_from(globalstate_account)?;
+        if !globalstate.foundation_allowlist.contains(signer.key) {
+            msg!("Signer {} may not finalize samples account", signer.key);
+            return Err(TelemetryError::UnauthorizedCloser.into());
+        }
+    }
+    if *agent.key != agent_pk {
+        msg!(
+            "Rent refund account {} is not the agent {}",
+            agent.key,
+            agent_pk
+        );
+        return Err(TelemetryError::InvalidRentRecipient.into());
+    }
+
+    {
+        let mut data = &mut latency_samples_account.data.borrow_mut()[..];
+        match header {
+            SamplesHeader::Device(mut h) => {
+                h.flags |= SAMPLES_FLAG_FINALIZED;
+                h.serialize(&mut data)?;
+            }
+            SamplesHeader::Internet(mut h) => {
+                h.flags |= SAMPLES_FLAG_FINALIZED;
+                h.serialize(&mut data)?;
+            }
+            SamplesHeader::PathProbe(mut h) => {
+                h.flags |= SAMPLES_FLAG_FINALIZED;
+                h.serialize(&mut data)?;
+            }
+        }
+    }
+
+    if args.compact {
+        if latency_samples_account.data_len() > used_len {
+            latency_samples_account.resize(used_len)?;
+        }
+        let rent_exempt = Rent::get()?.minimum_balance(latency_samples_account.data_len());
+        let surplus = latency_samples_account
+            .lamports()
+            .saturating_sub(rent_exempt);
+        if surplus > 0 {
+            **agent.lamports.borrow_mut() = agent
+                .lamports()
+                .checked_add(surplus)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            **latency_samples_account.lamports.borrow_mut() -= surplus;
+            msg!("Refunded {} lamports to agent {}", surplus, agent.key);
+        }
+    }
+
+    msg!(
+        "Finalized latency samples account {} for epoch {}",
+        latency_samples_account.key,
+        epoch
+    );
+
+    Ok(())
+}
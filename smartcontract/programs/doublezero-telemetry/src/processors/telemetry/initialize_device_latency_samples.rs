@@ -17,6 +17,7 @@ use doublezero_serviceability::state::{
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
@@ -49,7 +50,8 @@ pub struct InitializeDeviceLatencySamplesArgs {
 /// Errors:
 /// - `InvalidSamplingInterval`: zero interval
 /// - `DeviceNotActivated`, `LinkNotActivated`: inactive device or link
-/// - `UnauthorizedAgent`: agent not authorized for origin device
+/// - `UnauthorizedAgent`: agent is neither the origin device's metrics publisher
+///   nor, during a publisher rotation's overlap window, the one it replaced
 /// - `InvalidPDA`, `AccountAlreadyExists`
 pub fn process_initialize_device_latency_samples(
     program_id: &Pubkey,
@@ -101,7 +103,7 @@ pub fn process_initialize_device_latency_samples(
     }
 
     // Confirm the agent is authorized to publish for the origin device.
-    if origin_device.metrics_publisher_pk != *agent.key {
+    if !origin_device.is_metrics_publisher(agent.key, Clock::get()?.epoch) {
         msg!(
             "Agent {} is not authorized for origin device {}",
             agent.key,
@@ -210,7 +212,12 @@ pub fn process_initialize_device_latency_samples(
         next_sample_index: 0,
         agent_version: args.agent_version,
         agent_commit: args.agent_commit,
-        _unused: [0; 104],
+        flags: 0,
+        shard_index: 0,
+        previous_agent_pk: Pubkey::default(),
+        agent_rotation_epoch: 0,
+        previous_agent_until_epoch: 0,
+        _unused: [0; 54],
     };
 
     // Write the account data.
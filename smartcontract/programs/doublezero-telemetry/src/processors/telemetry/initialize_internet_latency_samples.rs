@@ -176,7 +176,8 @@ pub fn process_initialize_internet_latency_samples(
         sampling_interval_microseconds: args.sampling_interval_microseconds,
         start_timestamp_microseconds: 0, // will be set on first write
         next_sample_index: 0,
-        _unused: [0; 128],
+        flags: 0,
+        _unused: [0; 127],
     };
 
     // Write the account data
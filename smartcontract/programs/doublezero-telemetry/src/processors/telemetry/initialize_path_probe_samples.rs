@@ -0,0 +1,184 @@
+use crate::{
+    error::TelemetryError,
+    pda::derive_path_probe_samples_pda,
+    seeds::{SEED_PATH_PROBE_SAMPLES, SEED_PREFIX},
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        path_probe_samples::{PathProbeSamplesHeader, PATH_PROBE_SAMPLES_HEADER_SIZE},
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::create_account::try_create_account;
+use doublezero_serviceability::{
+    pda::get_globalstate_pda,
+    state::{
+        device::Device,
+        globalstate::GlobalState,
+        user::{User, UserStatus},
+    },
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+// Instruction arguments for initializing a path probe samples account.
+// Represents the probes sent through one user's tunnel to one target device during an epoch.
+#[derive(BorshSerialize, BorshDeserializeIncremental, Clone, Debug, PartialEq)]
+pub struct InitializePathProbeSamplesArgs {
+    pub epoch: u64,
+    pub sampling_interval_microseconds: u64,
+}
+
+/// Initializes a new PDA account for collecting synthetic path probe samples.
+///
+/// The account is uniquely derived using the prober agent, the user whose
+/// tunnel carries the probes, the target device, and epoch. The user's own
+/// device is cached in the header as the origin device.
+///
+/// The prober must be the device health oracle registered in the
+/// serviceability global state, the user must be activated and the target
+/// must be an activated device other than the user's.
+///
+/// Errors:
+/// - `InvalidSamplingInterval`: zero interval
+/// - `UnauthorizedAgent`: signer is not the serviceability health oracle
+/// - `UserNotActivated`, `DeviceNotActivated`: inactive user or target device
+/// - `SameTargetAsOrigin`: the target is the device terminating the user's tunnel
+/// - `InvalidPDA`, `AccountAlreadyExists`
+pub fn process_initialize_path_probe_samples(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &InitializePathProbeSamplesArgs,
+) -> ProgramResult {
+    msg!("Processing InitializePathProbeSamples: {:?}", args);
+
+    if args.sampling_interval_microseconds == 0 {
+        msg!("Sampling interval must be non-zero");
+        return Err(TelemetryError::InvalidSamplingInterval.into());
+    }
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [path_probe_samples_account, prober, user, target_device, serviceability_globalstate, system_program]
+    let path_probe_samples_account = next_account_info(accounts_iter)?;
+    let prober = next_account_info(accounts_iter)?;
+    let user_account = next_account_info(accounts_iter)?;
+    let target_device_account = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    if !prober.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure all relevant accounts are owned by the serviceability program.
+    let serviceability_program_id = &serviceability_program_id();
+    if user_account.owner != serviceability_program_id {
+        msg!("User is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if target_device_account.owner != serviceability_program_id {
+        msg!("Target device is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if globalstate_account.owner != serviceability_program_id {
+        msg!("Global state is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Only the device health oracle may probe user paths.
+    let (globalstate_pda, _) = get_globalstate_pda(serviceability_program_id);
+    if *globalstate_account.key != globalstate_pda {
+        msg!("Invalid PDA for serviceability global state");
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    if globalstate.health_oracle_pk != *prober.key {
+        msg!("Prober {} is not the health oracle", prober.key);
+        return Err(TelemetryError::UnauthorizedAgent.into());
+    }
+
+    let user = User::try_from(user_account)?;
+    if user.status != UserStatus::Activated {
+        msg!("User {} is not activated", user_account.key);
+        return Err(TelemetryError::UserNotActivated.into());
+    }
+
+    let target_device = Device::try_from(target_device_account)?;
+    if !target_device.allow_latency() {
+        msg!("Target device is not activated");
+        return Err(TelemetryError::DeviceNotActivated.into());
+    }
+
+    if user.device_pk == *target_device_account.key {
+        msg!("Target device terminates the user's tunnel");
+        return Err(TelemetryError::SameTargetAsOrigin.into());
+    }
+
+    let (path_probe_samples_pda, bump_seed) = derive_path_probe_samples_pda(
+        program_id,
+        prober.key,
+        user_account.key,
+        target_device_account.key,
+        args.epoch,
+    );
+    if *path_probe_samples_account.key != path_probe_samples_pda {
+        msg!("Invalid PDA for path probe samples account");
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+
+    if !path_probe_samples_account.data_is_empty() {
+        msg!("Path probe samples account already exists");
+        return Err(TelemetryError::AccountAlreadyExists.into());
+    }
+
+    try_create_account(
+        prober.key,
+        &path_probe_samples_pda,
+        path_probe_samples_account.lamports(),
+        PATH_PROBE_SAMPLES_HEADER_SIZE,
+        program_id,
+        accounts,
+        &[
+            SEED_PREFIX,
+            SEED_PATH_PROBE_SAMPLES,
+            prober.key.as_ref(),
+            user_account.key.as_ref(),
+            target_device_account.key.as_ref(),
+            &args.epoch.to_le_bytes(),
+            &[bump_seed],
+        ],
+    )?;
+
+    let header = PathProbeSamplesHeader {
+        account_type: AccountType::PathProbeSamples,
+        epoch: args.epoch,
+        prober_agent_pk: *prober.key,
+        user_pk: *user_account.key,
+        origin_device_pk: user.device_pk,
+        target_device_pk: *target_device_account.key,
+        sampling_interval_microseconds: args.sampling_interval_microseconds,
+        start_timestamp_microseconds: 0, // will be set on first write
+        next_sample_index: 0,
+        flags: 0,
+        _unused: [0; 64],
+    };
+
+    let mut data = &mut path_probe_samples_account.data.borrow_mut()[..];
+    header.serialize(&mut data)?;
+
+    msg!(
+        "Initialized path probe samples account {} for user {} towards {}",
+        path_probe_samples_pda,
+        user_account.key,
+        target_device_account.key
+    );
+
+    Ok(())
+}
@@ -1,4 +1,10 @@
+pub mod close_samples_account;
+pub mod finalize_epoch_samples;
 pub mod initialize_device_latency_samples;
 pub mod initialize_internet_latency_samples;
+pub mod initialize_path_probe_samples;
+pub mod rotate_metrics_publisher;
+pub mod set_provider_sampling_budget;
 pub mod write_device_latency_samples;
 pub mod write_internet_latency_samples;
+pub mod write_path_probe_samples;
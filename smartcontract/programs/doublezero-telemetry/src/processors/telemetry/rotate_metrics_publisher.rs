@@ -0,0 +1,130 @@
+use crate::{
+    error::TelemetryError,
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        device_latency_samples::{
+            DeviceLatencySamplesHeader, DEVICE_LATENCY_SAMPLES_HEADER_SIZE,
+            METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS,
+        },
+    },
+};
+use borsh::BorshSerialize;
+use doublezero_serviceability::state::device::Device;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Hands a `DeviceLatencySamples` account over to its origin device's new
+/// metrics publisher after the key was rotated in the serviceability program.
+///
+/// The signer must be the device's current `metrics_publisher_pk`. The old
+/// agent is kept as `previous_agent_pk` and may keep writing for
+/// `METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS` after the rotation epoch, or until
+/// the end of the device's overlap window when `RotateDeviceMetricsPublisher`
+/// replaced that agent with a longer one, so an agent that is still flushing
+/// samples under the old key does not leave a gap.
+///
+/// Errors:
+/// - `UnauthorizedAgent`: signer is not the device's metrics publisher
+/// - `MetricsPublisherUnchanged`: the account already belongs to the signer
+/// - `SamplesAccountFinalized`: the account was frozen by `FinalizeEpochSamples`
+/// - `OriginDeviceMismatch`: the device is not the account's origin device
+/// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`
+pub fn process_rotate_metrics_publisher(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Processing RotateMetricsPublisher");
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [latency_samples_account, agent, origin_device]
+    let latency_samples_account = next_account_info(accounts_iter)?;
+    let agent = next_account_info(accounts_iter)?;
+    let origin_device_account = next_account_info(accounts_iter)?;
+
+    if !agent.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if latency_samples_account.data_is_empty() {
+        msg!("Latency samples account does not exist");
+        return Err(TelemetryError::AccountDoesNotExist.into());
+    }
+    if latency_samples_account.owner != program_id {
+        return Err(TelemetryError::InvalidAccountOwner.into());
+    }
+    if origin_device_account.owner != &serviceability_program_id() {
+        msg!("Origin device is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut header = DeviceLatencySamplesHeader::try_from(
+        &latency_samples_account.try_borrow_data()?[..DEVICE_LATENCY_SAMPLES_HEADER_SIZE],
+    )
+    .map_err(|e| {
+        msg!("Failed to deserialize DeviceLatencySamples: {}", e);
+        ProgramError::InvalidAccountData
+    })?;
+    if header.account_type != AccountType::DeviceLatencySamples {
+        return Err(TelemetryError::InvalidAccountType.into());
+    }
+    if header.is_finalized() {
+        msg!("Samples account for epoch {} is finalized", header.epoch);
+        return Err(TelemetryError::SamplesAccountFinalized.into());
+    }
+
+    if header.origin_device_pk != *origin_device_account.key {
+        msg!(
+            "Device {} is not the origin device {} of the samples account",
+            origin_device_account.key,
+            header.origin_device_pk
+        );
+        return Err(TelemetryError::OriginDeviceMismatch.into());
+    }
+
+    let origin_device = Device::try_from(origin_device_account)?;
+    if origin_device.metrics_publisher_pk != *agent.key {
+        msg!(
+            "Agent {} is not the metrics publisher of device {}",
+            agent.key,
+            origin_device_account.key
+        );
+        return Err(TelemetryError::UnauthorizedAgent.into());
+    }
+    if header.origin_device_agent_pk == *agent.key {
+        msg!("Samples account already belongs to agent {}", agent.key);
+        return Err(TelemetryError::MetricsPublisherUnchanged.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    msg!(
+        "Rotating samples account {} from agent {} to {} in epoch {}",
+        latency_samples_account.key,
+        header.origin_device_agent_pk,
+        agent.key,
+        current_epoch
+    );
+    let mut previous_agent_until_epoch =
+        current_epoch.saturating_add(METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS);
+    if header.origin_device_agent_pk == origin_device.previous_metrics_publisher_pk {
+        previous_agent_until_epoch =
+            previous_agent_until_epoch.max(origin_device.metrics_publisher_overlap_until_epoch);
+    }
+    header.previous_agent_pk = header.origin_device_agent_pk;
+    header.origin_device_agent_pk = *agent.key;
+    header.agent_rotation_epoch = current_epoch;
+    header.previous_agent_until_epoch = previous_agent_until_epoch;
+
+    let mut data = &mut latency_samples_account.data.borrow_mut()[..];
+    header.serialize(&mut data)?;
+
+    Ok(())
+}
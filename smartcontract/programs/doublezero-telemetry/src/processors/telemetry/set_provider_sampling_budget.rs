@@ -0,0 +1,148 @@
+use crate::{
+    error::TelemetryError,
+    pda::derive_provider_sampling_budget_pda,
+    seeds::{SEED_PREFIX, SEED_PROVIDER_SAMPLING_BUDGET},
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        internet_latency_samples::{MAX_DATA_PROVIDER_NAME_BYTES, MAX_INTERNET_LATENCY_SAMPLES},
+        provider_sampling_budget::ProviderSamplingBudget,
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use doublezero_program_common::create_account::try_create_account;
+use doublezero_serviceability::{pda::get_globalstate_pda, state::globalstate::GlobalState};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+// Instruction arguments for capping the samples an internet latency provider may write.
+#[derive(BorshSerialize, BorshDeserializeIncremental, Clone, Debug, PartialEq)]
+pub struct SetProviderSamplingBudgetArgs {
+    pub oracle_agent_pk: Pubkey,
+    pub data_provider_name: String,
+    pub max_samples_per_epoch: u32,
+}
+
+/// Creates or updates the `ProviderSamplingBudget` of an oracle agent and data
+/// provider. The signer must be in the serviceability foundation allowlist and
+/// pays the rent when the account is created.
+///
+/// Errors:
+/// - `UnauthorizedAgent`: signer is not in the foundation allowlist
+/// - `InvalidSamplingBudget`: zero, or more samples than an account can hold
+/// - `DataProviderNameTooLong`, `InvalidPDA`, `InvalidAccountType`, `InvalidAccountOwner`
+pub fn process_set_provider_sampling_budget(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SetProviderSamplingBudgetArgs,
+) -> ProgramResult {
+    msg!("Processing SetProviderSamplingBudget: {:?}", args);
+
+    if args.data_provider_name.len() > MAX_DATA_PROVIDER_NAME_BYTES {
+        msg!("Data provider name is greater than 32 bytes");
+        return Err(TelemetryError::DataProviderNameTooLong.into());
+    }
+
+    if args.max_samples_per_epoch == 0
+        || args.max_samples_per_epoch as usize > MAX_INTERNET_LATENCY_SAMPLES
+    {
+        msg!(
+            "Sampling budget must be between 1 and {} samples",
+            MAX_INTERNET_LATENCY_SAMPLES
+        );
+        return Err(TelemetryError::InvalidSamplingBudget.into());
+    }
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [budget_account, signer, serviceability_globalstate, system_program]
+    let budget_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+    let globalstate_account = next_account_info(accounts_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The foundation allowlist lives in the serviceability global state.
+    let serviceability_program_id = &serviceability_program_id();
+    if globalstate_account.owner != serviceability_program_id {
+        msg!("Global state is not owned by serviceability program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (globalstate_pda, _) = get_globalstate_pda(serviceability_program_id);
+    if *globalstate_account.key != globalstate_pda {
+        msg!("Invalid PDA for serviceability global state");
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+    let globalstate = GlobalState::try_from(globalstate_account)?;
+    if !globalstate.foundation_allowlist.contains(signer.key) {
+        msg!("Signer {} is not in the foundation allowlist", signer.key);
+        return Err(TelemetryError::UnauthorizedAgent.into());
+    }
+
+    let (budget_pda, budget_bump_seed) = derive_provider_sampling_budget_pda(
+        program_id,
+        &args.oracle_agent_pk,
+        &args.data_provider_name,
+    );
+    if *budget_account.key != budget_pda {
+        msg!("Invalid PDA for provider sampling budget account");
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+
+    let budget = if budget_account.data_is_empty() {
+        try_create_account(
+            signer.key,
+            &budget_pda,
+            budget_account.lamports(),
+            ProviderSamplingBudget::instance_size(args.data_provider_name.len()),
+            program_id,
+            accounts,
+            &[
+                SEED_PREFIX,
+                SEED_PROVIDER_SAMPLING_BUDGET,
+                args.oracle_agent_pk.as_ref(),
+                args.data_provider_name.as_bytes(),
+                &[budget_bump_seed],
+            ],
+        )?;
+
+        ProviderSamplingBudget {
+            account_type: AccountType::ProviderSamplingBudget,
+            oracle_agent_pk: args.oracle_agent_pk,
+            data_provider_name: args.data_provider_name.clone(),
+            max_samples_per_epoch: args.max_samples_per_epoch,
+            _unused: [0; 32],
+        }
+    } else {
+        if budget_account.owner != program_id {
+            return Err(TelemetryError::InvalidAccountOwner.into());
+        }
+        let mut budget = ProviderSamplingBudget::try_from(&budget_account.try_borrow_data()?[..])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if budget.account_type != AccountType::ProviderSamplingBudget {
+            return Err(TelemetryError::InvalidAccountType.into());
+        }
+        budget.max_samples_per_epoch = args.max_samples_per_epoch;
+        budget
+    };
+
+    let mut data = &mut budget_account.data.borrow_mut()[..];
+    budget.serialize(&mut data)?;
+
+    msg!(
+        "Provider {} of agent {} may write {} samples per exchange pair and epoch",
+        budget.data_provider_name,
+        budget.oracle_agent_pk,
+        budget.max_samples_per_epoch
+    );
+
+    Ok(())
+}
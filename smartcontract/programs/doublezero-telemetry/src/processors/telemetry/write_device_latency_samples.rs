@@ -1,23 +1,30 @@
 use crate::{
     error::TelemetryError,
+    pda::derive_device_latency_samples_shard_pda,
+    seeds::{SEED_DEVICE_LATENCY_SAMPLES, SEED_PREFIX},
     state::{
         accounttype::AccountType,
         device_latency_samples::{
             DeviceLatencySamplesHeader, DEVICE_LATENCY_SAMPLES_HEADER_SIZE,
             MAX_DEVICE_LATENCY_SAMPLES,
         },
+        SAMPLES_FLAG_ROLLED_OVER,
     },
 };
 use borsh::BorshSerialize;
 use borsh_incremental::BorshDeserializeIncremental;
 use core::fmt;
-use doublezero_program_common::resize_account::resize_account_if_needed;
+use doublezero_program_common::{
+    create_account::try_create_account, resize_account::resize_account_if_needed,
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
     msg,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// Instruction arguments for writing RTT samples to a latency samples account.
@@ -51,9 +58,18 @@ impl fmt::Debug for WriteDeviceLatencySamplesArgs {
 /// Also handles rent top-up if additional space requires higher rent-exempt balance.
 /// If `samples` is empty, the call is treated as a no-op.
 ///
+/// When the samples do not all fit and the next shard's PDA is passed as a
+/// fourth account, the account is filled up, flagged as rolled over, and the
+/// remaining samples start the next shard, which is created and paid for by
+/// the agent. Agents then keep writing to the new shard.
+///
 /// Errors:
-/// - `UnauthorizedAgent`: signer does not match `origin_device_agent_pk`
-/// - `SamplesAccountFull`: exceeds sample or byte limit
+/// - `UnauthorizedAgent`: signer is neither `origin_device_agent_pk` nor, within
+///   the rotation grace period, `previous_agent_pk`
+/// - `SamplesAccountFull`: exceeds sample or byte limit and no next shard was given
+/// - `InvalidPDA`, `AccountAlreadyExists`: the next shard account is not the
+///   expected PDA, or already exists
+/// - `SamplesAccountFinalized`: the account was frozen by `FinalizeEpochSamples`
 /// - `EmptyLatencySamples`: a write instruction was received with no samples to record
 /// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`
 pub fn process_write_device_latency_samples(
@@ -71,9 +87,10 @@ pub fn process_write_device_latency_samples(
 
     let accounts_iter = &mut accounts.iter();
 
-    // Expected order: [latency_samples_account, agent, system_program]
+    // Expected order: [latency_samples_account, agent, system_program, next_shard_account?]
     let latency_samples_account = next_account_info(accounts_iter)?;
     let agent = next_account_info(accounts_iter)?;
+    let next_shard_account = accounts.get(3);
 
     // Only the authorized agent may sign this instruction.
     if !agent.is_signer {
@@ -107,8 +124,8 @@ pub fn process_write_device_latency_samples(
         return Err(TelemetryError::InvalidAccountType.into());
     }
 
-    // Confirm the writing agent matches the account owner.
-    if header.origin_device_agent_pk != *agent.key {
+    // Confirm the writing agent owns the account, or owned it until a recent rotation.
+    if !header.is_authorized_writer(agent.key, Clock::get()?.epoch) {
         msg!(
             "Agent mismatch: account expects {}, got {}",
             header.origin_device_agent_pk,
@@ -117,13 +134,27 @@ pub fn process_write_device_latency_samples(
         return Err(TelemetryError::UnauthorizedAgent.into());
     }
 
-    // Ensure we won't exceed sample capacity.
-    if header.next_sample_index as usize + args.samples.len() > MAX_DEVICE_LATENCY_SAMPLES {
-        msg!(
-            "Cannot add {} samples, would exceed max capacity",
-            args.samples.len()
-        );
-        return Err(TelemetryError::SamplesAccountFull.into());
+    // Finalized accounts are frozen for good.
+    if header.is_finalized() {
+        msg!("Samples account for epoch {} is finalized", header.epoch);
+        return Err(TelemetryError::SamplesAccountFinalized.into());
+    }
+
+    // Split off whatever does not fit; it rolls over to the next shard.
+    let capacity = MAX_DEVICE_LATENCY_SAMPLES.saturating_sub(header.next_sample_index as usize);
+    let (samples, overflow) = args.samples.split_at(args.samples.len().min(capacity));
+    if !overflow.is_empty() {
+        if next_shard_account.is_none() || header.is_rolled_over() {
+            msg!(
+                "Cannot add {} samples, would exceed max capacity",
+                args.samples.len()
+            );
+            return Err(TelemetryError::SamplesAccountFull.into());
+        }
+        if header.shard_index == u8::MAX {
+            msg!("No shards left after shard {}", header.shard_index);
+            return Err(TelemetryError::SamplesAccountFull.into());
+        }
     }
 
     // Set the first-write timestamp exactly once.
@@ -153,7 +184,10 @@ pub fn process_write_device_latency_samples(
 
     // Append new samples and update sample index.
     let write_index = header.next_sample_index as usize;
-    header.next_sample_index += args.samples.len() as u32;
+    header.next_sample_index += samples.len() as u32;
+    if !overflow.is_empty() {
+        header.flags |= SAMPLES_FLAG_ROLLED_OVER;
+    }
 
     // Determine whether the account needs to be resized to hold the new data.
     let new_len = DEVICE_LATENCY_SAMPLES_HEADER_SIZE + header.next_sample_index as usize * 4;
@@ -166,7 +200,7 @@ pub fn process_write_device_latency_samples(
         header.serialize(&mut data)?;
 
         // Write each u32 sample to the account's sample region at the correct offset.
-        for (i, sample) in args.samples.iter().enumerate() {
+        for (i, sample) in samples.iter().enumerate() {
             let offset = (write_index + i) * 4;
             data[offset..offset + 4].copy_from_slice(&sample.to_le_bytes());
         }
@@ -177,5 +211,91 @@ pub fn process_write_device_latency_samples(
         );
     }
 
+    if let Some(next_shard_account) = next_shard_account.filter(|_| !overflow.is_empty()) {
+        // The first rolled-over sample follows the ones that still fit.
+        let start_timestamp_microseconds = args.start_timestamp_microseconds
+            + samples.len() as u64 * header.sampling_interval_microseconds;
+        start_next_shard(
+            program_id,
+            accounts,
+            agent,
+            next_shard_account,
+            &header,
+            start_timestamp_microseconds,
+            overflow,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Create the shard after `header`'s and write `samples` to it.
+fn start_next_shard(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    agent: &AccountInfo,
+    next_shard_account: &AccountInfo,
+    header: &DeviceLatencySamplesHeader,
+    start_timestamp_microseconds: u64,
+    samples: &[u32],
+) -> ProgramResult {
+    let shard_index = header.shard_index + 1;
+    let (next_shard_pda, bump_seed) = derive_device_latency_samples_shard_pda(
+        program_id,
+        &header.origin_device_pk,
+        &header.target_device_pk,
+        &header.link_pk,
+        header.epoch,
+        shard_index,
+    );
+    if *next_shard_account.key != next_shard_pda {
+        msg!("Invalid PDA for latency samples shard {}", shard_index);
+        return Err(TelemetryError::InvalidPDA.into());
+    }
+    if !next_shard_account.data_is_empty() {
+        msg!("Latency samples shard {} already exists", shard_index);
+        return Err(TelemetryError::AccountAlreadyExists.into());
+    }
+
+    msg!(
+        "Rolling {} samples over to shard {}: {}",
+        samples.len(),
+        shard_index,
+        next_shard_pda
+    );
+
+    try_create_account(
+        agent.key,
+        &next_shard_pda,
+        next_shard_account.lamports(),
+        DEVICE_LATENCY_SAMPLES_HEADER_SIZE + samples.len() * 4,
+        program_id,
+        accounts,
+        &[
+            SEED_PREFIX,
+            SEED_DEVICE_LATENCY_SAMPLES,
+            header.origin_device_pk.as_ref(),
+            header.target_device_pk.as_ref(),
+            header.link_pk.as_ref(),
+            &header.epoch.to_le_bytes(),
+            &[shard_index],
+            &[bump_seed],
+        ],
+    )?;
+
+    let next_header = DeviceLatencySamplesHeader {
+        start_timestamp_microseconds,
+        next_sample_index: samples.len() as u32,
+        flags: 0,
+        shard_index,
+        ..header.clone()
+    };
+
+    let mut data = &mut next_shard_account.data.borrow_mut()[..];
+    next_header.serialize(&mut data)?;
+    for (i, sample) in samples.iter().enumerate() {
+        data[i * 4..i * 4 + 4].copy_from_slice(&sample.to_le_bytes());
+    }
+
     Ok(())
 }
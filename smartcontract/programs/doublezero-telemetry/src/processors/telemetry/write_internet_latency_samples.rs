@@ -1,8 +1,10 @@
 use crate::{
     error::TelemetryError,
+    pda::derive_provider_sampling_budget_pda,
     state::{
         accounttype::AccountType,
         internet_latency_samples::{InternetLatencySamplesHeader, MAX_INTERNET_LATENCY_SAMPLES},
+        provider_sampling_budget::ProviderSamplingBudget,
     },
 };
 use borsh::BorshSerialize;
@@ -45,9 +47,16 @@ impl fmt::Debug for WriteInternetLatencySamplesArgs {
 /// Also handles rent top-up if additional space requires higher rent-exempt balance.
 /// If `samples` is empty, the call is treated as a no-op.
 ///
+/// When the provider's `ProviderSamplingBudget` PDA is passed as a fourth account
+/// and has been set, the account may not grow past its `max_samples_per_epoch`.
+/// A budget PDA that was never set imposes no limit.
+///
 /// Error:
 /// - `UnauthorizedAgent`: signer does not match `oracle_agent_pk`
 /// - `SamplesAccountFull`: exceeds sample or byte limit
+/// - `SamplingBudgetExceeded`: exceeds the provider's sampling budget
+/// - `InvalidPDA`: the fourth account is not the provider's budget PDA
+/// - `SamplesAccountFinalized`: the account was frozen by `FinalizeEpochSamples`
 /// - `EmptyLatencySamples`: a write instruction was received with no samples to record
 /// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`
 pub fn process_write_internet_latency_samples(
@@ -65,9 +74,10 @@ pub fn process_write_internet_latency_samples(
 
     let accounts_iter = &mut accounts.iter();
 
-    // Expected order: [latency_samples_account, agent, system_program]
+    // Expected order: [latency_samples_account, agent, system_program, provider_sampling_budget?]
     let latency_samples_acct = next_account_info(accounts_iter)?;
     let collector_agent = next_account_info(accounts_iter)?;
+    let budget_account = accounts.get(3);
 
     // Only the authorized agent may sign the instruction
     if !collector_agent.is_signer {
@@ -110,6 +120,12 @@ pub fn process_write_internet_latency_samples(
         return Err(TelemetryError::UnauthorizedAgent.into());
     }
 
+    // Finalized accounts are frozen for good.
+    if header.is_finalized() {
+        msg!("Samples account for epoch {} is finalized", header.epoch);
+        return Err(TelemetryError::SamplesAccountFinalized.into());
+    }
+
     // Ensure we won't exceed sample capacity
     if header.next_sample_index as usize + args.samples.len() > MAX_INTERNET_LATENCY_SAMPLES {
         msg!(
@@ -119,6 +135,40 @@ pub fn process_write_internet_latency_samples(
         return Err(TelemetryError::SamplesAccountFull.into());
     }
 
+    // Enforce the provider's sampling budget, if one has been set
+    if let Some(budget_account) = budget_account {
+        let (budget_pda, _) = derive_provider_sampling_budget_pda(
+            program_id,
+            &header.oracle_agent_pk,
+            &header.data_provider_name,
+        );
+        if *budget_account.key != budget_pda {
+            msg!("Invalid PDA for provider sampling budget account");
+            return Err(TelemetryError::InvalidPDA.into());
+        }
+
+        if !budget_account.data_is_empty() {
+            if budget_account.owner != program_id {
+                return Err(TelemetryError::InvalidAccountOwner.into());
+            }
+            let budget = ProviderSamplingBudget::try_from(&budget_account.try_borrow_data()?[..])
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if budget.account_type != AccountType::ProviderSamplingBudget {
+                return Err(TelemetryError::InvalidAccountType.into());
+            }
+            if header.next_sample_index as usize + args.samples.len()
+                > budget.max_samples_per_epoch as usize
+            {
+                msg!(
+                    "Cannot add {} samples, would exceed the provider's budget of {} samples",
+                    args.samples.len(),
+                    budget.max_samples_per_epoch
+                );
+                return Err(TelemetryError::SamplingBudgetExceeded.into());
+            }
+        }
+    }
+
     // Set the first-write timestamp exactly once
     if header.start_timestamp_microseconds == 0 {
         header.start_timestamp_microseconds = args.start_timestamp_microseconds;
@@ -0,0 +1,153 @@
+use crate::{
+    error::TelemetryError,
+    state::{
+        accounttype::AccountType,
+        path_probe_samples::{
+            PathProbeSamplesHeader, MAX_PATH_PROBE_SAMPLES, PATH_PROBE_SAMPLES_HEADER_SIZE,
+        },
+    },
+};
+use borsh::BorshSerialize;
+use borsh_incremental::BorshDeserializeIncremental;
+use core::fmt;
+use doublezero_program_common::resize_account::resize_account_if_needed;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+// Instruction arguments for appending probe results to a path probe samples account.
+// A sample of zero records a lost probe.
+#[derive(BorshDeserializeIncremental, BorshSerialize, Clone, PartialEq)]
+pub struct WritePathProbeSamplesArgs {
+    pub start_timestamp_microseconds: u64,
+    pub samples: Vec<u32>,
+}
+
+impl fmt::Debug for WritePathProbeSamplesArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "start_timestamp_microseconds: {}, samples: {}",
+            self.start_timestamp_microseconds,
+            self.samples.len(),
+        )
+    }
+}
+
+/// Appends probe results to an existing `PathProbeSamples` account.
+///
+/// Validates that the signer is the prober agent recorded in the account, then
+/// resizes the account to fit the new samples, topping up rent from the prober.
+///
+/// Errors:
+/// - `UnauthorizedAgent`: signer does not match `prober_agent_pk`
+/// - `SamplesAccountFull`: exceeds the sample limit
+/// - `SamplesBatchTooLarge`: the batch exceeds the per-instruction realloc limit
+/// - `SamplesAccountFinalized`: the account was frozen by `FinalizeEpochSamples`
+/// - `EmptyLatencySamples`: a write instruction was received with no samples to record
+/// - `AccountDoesNotExist`, `InvalidAccountType`, `InvalidAccountOwner`
+pub fn process_write_path_probe_samples(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &WritePathProbeSamplesArgs,
+) -> ProgramResult {
+    msg!("Processing WritePathProbeSamples: {:?}", args);
+
+    if args.samples.is_empty() {
+        msg!("No samples provided");
+        return Err(TelemetryError::EmptyLatencySamples.into());
+    }
+
+    let accounts_iter = &mut accounts.iter();
+
+    // Expected order: [path_probe_samples_account, prober, system_program]
+    let path_probe_samples_account = next_account_info(accounts_iter)?;
+    let prober = next_account_info(accounts_iter)?;
+
+    if !prober.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if path_probe_samples_account.data_is_empty() {
+        msg!("Path probe samples account does not exist");
+        return Err(TelemetryError::AccountDoesNotExist.into());
+    }
+    if path_probe_samples_account.owner != program_id {
+        return Err(TelemetryError::InvalidAccountOwner.into());
+    }
+
+    let mut header =
+        PathProbeSamplesHeader::try_from(&path_probe_samples_account.try_borrow_data()?[..])
+            .map_err(|e| {
+                msg!("Failed to deserialize PathProbeSamples {}", e);
+                ProgramError::InvalidAccountData
+            })?;
+
+    if header.account_type != AccountType::PathProbeSamples {
+        return Err(TelemetryError::InvalidAccountType.into());
+    }
+
+    if header.prober_agent_pk != *prober.key {
+        msg!(
+            "Prober mismatch: account expects {}, got {}",
+            header.prober_agent_pk,
+            prober.key
+        );
+        return Err(TelemetryError::UnauthorizedAgent.into());
+    }
+
+    if header.is_finalized() {
+        msg!("Samples account for epoch {} is finalized", header.epoch);
+        return Err(TelemetryError::SamplesAccountFinalized.into());
+    }
+
+    if header.next_sample_index as usize + args.samples.len() > MAX_PATH_PROBE_SAMPLES {
+        msg!(
+            "Cannot add {} samples, would exceed max capacity",
+            args.samples.len(),
+        );
+        return Err(TelemetryError::SamplesAccountFull.into());
+    }
+
+    if args.samples.len() > MAX_PERMITTED_DATA_INCREASE / 4 {
+        msg!(
+            "Cannot increase by {} samples in one transaction, realloc would exceed Solana inner instruction limit {} bytes",
+            args.samples.len(),
+            MAX_PERMITTED_DATA_INCREASE
+        );
+        return Err(TelemetryError::SamplesBatchTooLarge.into());
+    }
+
+    // Set the first-write timestamp exactly once
+    if header.start_timestamp_microseconds == 0 {
+        header.start_timestamp_microseconds = args.start_timestamp_microseconds;
+    }
+
+    let write_index = header.next_sample_index as usize;
+    header.next_sample_index += args.samples.len() as u32;
+
+    let new_len = PATH_PROBE_SAMPLES_HEADER_SIZE + header.next_sample_index as usize * 4;
+    resize_account_if_needed(path_probe_samples_account, prober, accounts, new_len)?;
+
+    {
+        let mut data = &mut path_probe_samples_account.data.borrow_mut()[..];
+        header.serialize(&mut data)?;
+
+        // `data` now starts right after the header.
+        for (i, sample) in args.samples.iter().enumerate() {
+            let offset = (write_index + i) * 4;
+            data[offset..offset + 4].copy_from_slice(&sample.to_le_bytes());
+        }
+
+        msg!(
+            "Updated account; now has {} samples",
+            header.next_sample_index,
+        );
+    }
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
 pub const SEED_PREFIX: &[u8] = b"telemetry";
 pub const SEED_DEVICE_LATENCY_SAMPLES: &[u8] = b"dzlatency";
 pub const SEED_INTERNET_LATENCY_SAMPLES: &[u8] = b"inetlatency";
+pub const SEED_PROVIDER_SAMPLING_BUDGET: &[u8] = b"providerbudget";
+pub const SEED_PATH_PROBE_SAMPLES: &[u8] = b"pathprobe";
@@ -11,6 +11,8 @@ pub enum AccountType {
     InternetLatencySamplesV0 = 2,
     DeviceLatencySamples = 3,
     InternetLatencySamples = 4,
+    ProviderSamplingBudget = 5,
+    PathProbeSamples = 6,
 }
 
 impl TryFrom<u8> for AccountType {
@@ -22,6 +24,8 @@ impl TryFrom<u8> for AccountType {
             2 => Ok(Self::InternetLatencySamplesV0),
             3 => Ok(Self::DeviceLatencySamples),
             4 => Ok(Self::InternetLatencySamples),
+            5 => Ok(Self::ProviderSamplingBudget),
+            6 => Ok(Self::PathProbeSamples),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -34,6 +38,8 @@ impl fmt::Display for AccountType {
             Self::InternetLatencySamplesV0 => write!(f, "InternetLatencySamplesV0"),
             Self::DeviceLatencySamples => write!(f, "DeviceLatencySamples"),
             Self::InternetLatencySamples => write!(f, "InternetLatencySamples"),
+            Self::ProviderSamplingBudget => write!(f, "ProviderSamplingBudget"),
+            Self::PathProbeSamples => write!(f, "PathProbeSamples"),
         }
     }
 }
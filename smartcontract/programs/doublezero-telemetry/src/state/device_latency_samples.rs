@@ -1,6 +1,9 @@
 use crate::{
     seeds::SEED_DEVICE_LATENCY_SAMPLES,
-    state::accounttype::{AccountType, AccountTypeInfo},
+    state::{
+        accounttype::{AccountType, AccountTypeInfo},
+        SAMPLES_FLAG_FINALIZED, SAMPLES_FLAG_ROLLED_OVER,
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -9,6 +12,11 @@ use std::{
     io::{self, Read, Write},
 };
 
+/// Number of epochs after `RotateMetricsPublisher` during which the previous
+/// agent may still write to the account, unless the device's publisher rotation
+/// granted a longer overlap.
+pub const METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS: u64 = 1;
+
 /// Maximum number of RTT samples storable in a single account.
 /// With 5-second intervals, 35,000 samples ~= 48 hours of data.
 pub const MAX_DEVICE_LATENCY_SAMPLES: usize = 35_000;
@@ -22,7 +30,12 @@ pub const MAX_DEVICE_LATENCY_SAMPLES: usize = 35_000;
 /// - 8 bytes: `sampling_interval_microseconds`
 /// - 8 bytes: `start_timestamp_microseconds`
 /// - 4 bytes: `next_sample_index`
-/// - 128 bytes: reserved for future use
+/// - 1 byte: `flags`
+/// - 1 byte: `shard_index`
+/// - 32 bytes: `previous_agent_pk`
+/// - 8 bytes: `agent_rotation_epoch`
+/// - 8 bytes: `previous_agent_until_epoch`
+/// - 54 bytes: reserved for future use
 ///
 /// Total size: 350 bytes
 pub const DEVICE_LATENCY_SAMPLES_HEADER_SIZE: usize = {
@@ -39,7 +52,12 @@ pub const DEVICE_LATENCY_SAMPLES_HEADER_SIZE: usize = {
     + 4 // next_sample_index
     + 16 // agent_version
     + 8 // agent_commit
-    + 104 // _unused
+    + 1 // flags
+    + 1 // shard_index
+    + 32 // previous_agent_pk
+    + 8 // agent_rotation_epoch
+    + 8 // previous_agent_until_epoch
+    + 54 // _unused
 };
 
 /// Onchain data structure representing a latency samples account header between two devices
@@ -131,9 +149,65 @@ pub struct DeviceLatencySamplesHeader {
     // Truncated to 8 bytes if longer. Zero-filled means unknown.
     pub agent_commit: [u8; 8], // 8
 
+    // Bitmask of SAMPLES_FLAG_* values. Zero for accounts created before flags existed.
+    pub flags: u8, // 1
+
+    // Position of this account among the shards of its link, origin and epoch.
+    // Zero for the account derived without a shard index, and for accounts
+    // created before sharding existed.
+    pub shard_index: u8, // 1
+
+    // Agent that owned the account before `RotateMetricsPublisher` moved it to the
+    // device's new metrics publisher. Default for accounts that were never rotated.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub previous_agent_pk: Pubkey, // 32
+
+    // Epoch in which the account was last rotated to a new agent.
+    pub agent_rotation_epoch: u64, // 8
+
+    // Last epoch in which `previous_agent_pk` may write. Zero for accounts
+    // rotated before the field existed, which fall back to the grace period.
+    pub previous_agent_until_epoch: u64, // 8
+
     // Reserved for future use.
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    pub _unused: [u8; 104], // 104
+    pub _unused: [u8; 54], // 54
+}
+
+impl DeviceLatencySamplesHeader {
+    /// Whether `FinalizeEpochSamples` has frozen the account against further writes.
+    pub fn is_finalized(&self) -> bool {
+        self.flags & SAMPLES_FLAG_FINALIZED != 0
+    }
+
+    /// Whether writes have moved on to the shard after this one.
+    pub fn is_rolled_over(&self) -> bool {
+        self.flags & SAMPLES_FLAG_ROLLED_OVER != 0
+    }
+
+    /// Whether `agent` may write samples in `current_epoch`: the current agent always,
+    /// and the previous one until `previous_agent_until_epoch`, or for
+    /// `METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS` after the rotation when that is unset.
+    pub fn is_authorized_writer(&self, agent: &Pubkey, current_epoch: u64) -> bool {
+        if self.origin_device_agent_pk == *agent {
+            return true;
+        }
+        let until_epoch = if self.previous_agent_until_epoch != 0 {
+            self.previous_agent_until_epoch
+        } else {
+            self.agent_rotation_epoch
+                .saturating_add(METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS)
+        };
+        self.previous_agent_pk != Pubkey::default()
+            && self.previous_agent_pk == *agent
+            && current_epoch <= until_epoch
+    }
 }
 
 impl TryFrom<&[u8]> for DeviceLatencySamplesHeader {
@@ -246,7 +320,12 @@ mod tests {
                 next_sample_index: samples.len() as u32,
                 agent_version: *b"0.16.1\0\0\0\0\0\0\0\0\0\0",
                 agent_commit: *b"8ab7b505",
-                _unused: [0; 104],
+                flags: 0,
+                shard_index: 0,
+                previous_agent_pk: Pubkey::new_unique(),
+                agent_rotation_epoch: 19799,
+                previous_agent_until_epoch: 19801,
+                _unused: [0; 54],
             },
             samples: samples.clone(),
         };
@@ -284,6 +363,12 @@ mod tests {
         assert_eq!(header.next_sample_index, header2.next_sample_index);
         assert_eq!(header.agent_version, header2.agent_version);
         assert_eq!(header.agent_commit, header2.agent_commit);
+        assert_eq!(header.previous_agent_pk, header2.previous_agent_pk);
+        assert_eq!(header.agent_rotation_epoch, header2.agent_rotation_epoch);
+        assert_eq!(
+            header.previous_agent_until_epoch,
+            header2.previous_agent_until_epoch
+        );
         assert_eq!(val.samples, val2.samples);
         assert_eq!(
             data.len(),
@@ -291,4 +376,56 @@ mod tests {
             "Invalid Size"
         );
     }
+
+    #[test]
+    fn test_is_authorized_writer_grace_period() {
+        let current = Pubkey::new_unique();
+        let previous = Pubkey::new_unique();
+        let header = DeviceLatencySamplesHeader {
+            account_type: AccountType::DeviceLatencySamples,
+            epoch: 100,
+            origin_device_agent_pk: current,
+            origin_device_pk: Pubkey::new_unique(),
+            target_device_pk: Pubkey::new_unique(),
+            origin_device_location_pk: Pubkey::new_unique(),
+            target_device_location_pk: Pubkey::new_unique(),
+            link_pk: Pubkey::new_unique(),
+            sampling_interval_microseconds: 5_000_000,
+            start_timestamp_microseconds: 0,
+            next_sample_index: 0,
+            agent_version: [0; 16],
+            agent_commit: [0; 8],
+            flags: 0,
+            shard_index: 0,
+            previous_agent_pk: previous,
+            agent_rotation_epoch: 100,
+            previous_agent_until_epoch: 0,
+            _unused: [0; 54],
+        };
+
+        assert!(header.is_authorized_writer(&current, 100));
+        assert!(header.is_authorized_writer(&current, 500));
+        assert!(header.is_authorized_writer(&previous, 100));
+        assert!(header.is_authorized_writer(&previous, 101));
+        assert!(!header.is_authorized_writer(&previous, 102));
+        assert!(!header.is_authorized_writer(&Pubkey::new_unique(), 100));
+
+        // Accounts that were never rotated only accept their agent.
+        let never_rotated = DeviceLatencySamplesHeader {
+            previous_agent_pk: Pubkey::default(),
+            agent_rotation_epoch: 0,
+            ..header
+        };
+        assert!(!never_rotated.is_authorized_writer(&Pubkey::default(), 0));
+        assert!(never_rotated.is_authorized_writer(&current, 0));
+
+        // A rotation that recorded the device's overlap window uses it instead
+        // of the grace period.
+        let overlapping = DeviceLatencySamplesHeader {
+            previous_agent_until_epoch: 104,
+            ..header
+        };
+        assert!(overlapping.is_authorized_writer(&previous, 104));
+        assert!(!overlapping.is_authorized_writer(&previous, 105));
+    }
 }
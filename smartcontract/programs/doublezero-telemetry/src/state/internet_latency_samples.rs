@@ -1,6 +1,9 @@
 use crate::{
     seeds::SEED_INTERNET_LATENCY_SAMPLES,
-    state::accounttype::{AccountType, AccountTypeInfo},
+    state::{
+        accounttype::{AccountType, AccountTypeInfo},
+        SAMPLES_FLAG_FINALIZED,
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
@@ -24,7 +27,8 @@ pub const MAX_DATA_PROVIDER_NAME_BYTES: usize = 32;
 /// - 8 bytes: `sampling_interval_microseconds`
 /// - 8 bytes: `start_timestamp_microseconds`
 /// - 4 bytes: `next_sample_index`
-/// - 128 bytes: reserved for future use
+/// - 1 byte: `flags`
+/// - 127 bytes: reserved for future use
 ///
 /// Total size: 290 bytes
 pub const INTERNET_LATENCY_SAMPLES_MAX_HEADER_SIZE: usize =
@@ -40,7 +44,8 @@ const INTERNET_LATENCY_SAMPLES_HEADER_SIZE_MINUS_PROVIDER: usize = {
     + 8 // sampling_interval_microseconds
     + 8 // start_timestamp_microseconds
     + 4 // next_sample_index
-    + 128 // _unused
+    + 1 // flags
+    + 127 // _unused
 };
 
 /// Onchain data structure representing a latency samples account header between two
@@ -89,12 +94,19 @@ pub struct InternetLatencySamplesHeader {
     pub start_timestamp_microseconds: u64, // 8
     // Tracks how many samples have been appended
     pub next_sample_index: u32, // 4
+    // Bitmask of SAMPLES_FLAG_* values. Zero for accounts created before flags existed
+    pub flags: u8, // 1
     // Reserved for future use
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    pub _unused: [u8; 128], // 128
+    pub _unused: [u8; 127], // 127
 }
 
 impl InternetLatencySamplesHeader {
+    /// Whether `FinalizeEpochSamples` has frozen the account against further writes.
+    pub fn is_finalized(&self) -> bool {
+        self.flags & SAMPLES_FLAG_FINALIZED != 0
+    }
+
     pub fn data_provider_name_length(data: &[u8]) -> Result<usize, std::array::TryFromSliceError> {
         const DATA_PROVIDER_LOC: usize = 1 + 8;
 
@@ -236,7 +248,8 @@ mod tests {
                 sampling_interval_microseconds: 60_000_000,
                 start_timestamp_microseconds: 1_700_000_000_000_000,
                 next_sample_index: samples.len() as u32,
-                _unused: [0; 128],
+                flags: 0,
+                _unused: [0; 127],
             },
             samples,
         };
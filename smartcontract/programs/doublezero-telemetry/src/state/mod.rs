@@ -1,3 +1,12 @@
 pub mod accounttype;
 pub mod device_latency_samples;
 pub mod internet_latency_samples;
+pub mod path_probe_samples;
+pub mod provider_sampling_budget;
+
+/// Set by `FinalizeEpochSamples`; the account accepts no further writes.
+pub const SAMPLES_FLAG_FINALIZED: u8 = 1 << 0;
+
+/// Set on a device latency samples shard once writes have rolled over to the
+/// next shard; the shard is full and readers should continue with the next one.
+pub const SAMPLES_FLAG_ROLLED_OVER: u8 = 1 << 1;
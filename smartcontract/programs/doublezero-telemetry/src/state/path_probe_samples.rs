@@ -0,0 +1,241 @@
+use crate::{
+    seeds::SEED_PATH_PROBE_SAMPLES,
+    state::{
+        accounttype::{AccountType, AccountTypeInfo},
+        SAMPLES_FLAG_FINALIZED,
+    },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+/// Maximum number of probe samples storable in a single account.
+/// With one probe every 30 seconds, 6_000 samples ~= 50 hours of data.
+pub const MAX_PATH_PROBE_SAMPLES: usize = 6_000;
+
+/// Sample value recorded for a probe that got no reply within its timeout.
+pub const PATH_PROBE_SAMPLE_LOST: u32 = 0;
+
+/// Static size of the `PathProbeSamplesHeader` struct.
+/// Used to calculate initial account allocation.
+pub const PATH_PROBE_SAMPLES_HEADER_SIZE: usize = {
+    1 // account_type
+    + 8 // epoch
+    + 32 // prober_agent_pk
+    + 32 // user_pk
+    + 32 // origin_device_pk
+    + 32 // target_device_pk
+    + 8 // sampling_interval_microseconds
+    + 8 // start_timestamp_microseconds
+    + 4 // next_sample_index
+    + 1 // flags
+    + 64 // _unused
+};
+
+/// Onchain data structure representing the header of a synthetic path probe
+/// samples account for a specific epoch. Probes are sent end-to-end through a
+/// user's tunnel (client -> origin device -> target device) by the device health
+/// oracle, which is the only agent authorized to write.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathProbeSamplesHeader {
+    // Discriminator to distinguish from other accounts during deserialization
+    pub account_type: AccountType, // 1
+    // Epoch number in which samples were collected
+    pub epoch: u64, // 8
+    // Agent authorized to write probe samples (must match the signer)
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub prober_agent_pk: Pubkey, // 32
+    // User whose tunnel the probes are sent through
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub user_pk: Pubkey, // 32
+    // Cached device terminating the user's tunnel, for query/UI optimization
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub origin_device_pk: Pubkey, // 32
+    // Destination device the probes are addressed to
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub target_device_pk: Pubkey, // 32
+    // Probe interval configured by the agent (in microseconds)
+    pub sampling_interval_microseconds: u64, // 8
+    // Timestamp of the first written sample (µs since UNIX epoch)
+    // Set on the first write, remains unchanged on subsequent writes
+    pub start_timestamp_microseconds: u64, // 8
+    // Tracks how many samples have been appended
+    pub next_sample_index: u32, // 4
+    // Bitmask of SAMPLES_FLAG_* values
+    pub flags: u8, // 1
+    // Reserved for future use
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
+    pub _unused: [u8; 64], // 64
+}
+
+impl PathProbeSamplesHeader {
+    /// Whether `FinalizeEpochSamples` has frozen the account against further writes.
+    pub fn is_finalized(&self) -> bool {
+        self.flags & SAMPLES_FLAG_FINALIZED != 0
+    }
+}
+
+impl TryFrom<&[u8]> for PathProbeSamplesHeader {
+    type Error = borsh::io::Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() < PATH_PROBE_SAMPLES_HEADER_SIZE {
+            return Err(borsh::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "account data too short for header",
+            ));
+        }
+
+        Self::deserialize(&mut &data[..])
+    }
+}
+
+/// Structured representation of a path probe samples account.
+///
+/// Each sample is the round-trip time of one probe in microseconds, or
+/// `PATH_PROBE_SAMPLE_LOST` when the probe was lost.
+///
+/// This is not the onchain data structure, but a convenience wrapper for the header and samples.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PathProbeSamples {
+    pub header: PathProbeSamplesHeader,
+    pub samples: Vec<u32>,
+}
+
+impl PathProbeSamples {
+    /// Number of probes that got no reply.
+    pub fn lost_count(&self) -> usize {
+        self.samples
+            .iter()
+            .filter(|&&rtt| rtt == PATH_PROBE_SAMPLE_LOST)
+            .count()
+    }
+}
+
+impl fmt::Display for PathProbeSamples {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, epoch: {}, prober_agent: {}, user: {}, origin_device: {}, target_device: {}, samples: {}, lost: {}",
+            self.header.account_type, self.header.epoch, self.header.prober_agent_pk, self.header.user_pk, self.header.origin_device_pk, self.header.target_device_pk, self.samples.len(), self.lost_count()
+        )
+    }
+}
+
+impl BorshSerialize for PathProbeSamples {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.header.serialize(writer)?;
+        for sample in &self.samples {
+            writer.write_all(&sample.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for PathProbeSamples {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let header = PathProbeSamplesHeader::deserialize_reader(reader)?;
+
+        let num_samples = header.next_sample_index as usize;
+        let mut samples = Vec::with_capacity(num_samples);
+        let mut buf = [0u8; 4];
+
+        for _ in 0..num_samples {
+            reader.read_exact(&mut buf)?;
+            samples.push(u32::from_le_bytes(buf));
+        }
+
+        Ok(PathProbeSamples { header, samples })
+    }
+}
+
+impl TryFrom<&[u8]> for PathProbeSamples {
+    type Error = borsh::io::Error;
+
+    /// Enables deserializing from raw Solana account data.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        borsh::from_slice(data)
+    }
+}
+
+impl AccountTypeInfo for PathProbeSamples {
+    /// Returns the fixed seed associated with this account type.
+    fn seed(&self) -> &[u8] {
+        SEED_PATH_PROBE_SAMPLES
+    }
+
+    /// Computes the full serialized size of this account (for realloc).
+    fn size(&self) -> usize {
+        PATH_PROBE_SAMPLES_HEADER_SIZE + self.samples.len() * 4
+    }
+
+    /// Returns the public key of the agent who owns/writes to this account.
+    fn owner(&self) -> Pubkey {
+        self.header.prober_agent_pk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_probe_samples_serialization() {
+        let samples = vec![1_200u32, PATH_PROBE_SAMPLE_LOST, 1_350u32, 1_180u32];
+        let val = PathProbeSamples {
+            header: PathProbeSamplesHeader {
+                account_type: AccountType::PathProbeSamples,
+                epoch: 19800,
+                prober_agent_pk: Pubkey::new_unique(),
+                user_pk: Pubkey::new_unique(),
+                origin_device_pk: Pubkey::new_unique(),
+                target_device_pk: Pubkey::new_unique(),
+                sampling_interval_microseconds: 30_000_000,
+                start_timestamp_microseconds: 1_700_000_000_000_000,
+                next_sample_index: samples.len() as u32,
+                flags: 0,
+                _unused: [0; 64],
+            },
+            samples,
+        };
+
+        let data = borsh::to_vec(&val).unwrap();
+        let val2 = PathProbeSamples::try_from(&data[..]).unwrap();
+
+        assert_eq!(val, val2);
+        assert_eq!(val2.lost_count(), 1);
+        assert_eq!(data.len(), val.size(), "Invalid Size");
+        assert_eq!(
+            borsh::to_vec(&val.header).unwrap().len(),
+            PATH_PROBE_SAMPLES_HEADER_SIZE
+        );
+    }
+}
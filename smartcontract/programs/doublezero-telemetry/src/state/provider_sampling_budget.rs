@@ -0,0 +1,104 @@
+use crate::{
+    seeds::SEED_PROVIDER_SAMPLING_BUDGET,
+    state::accounttype::{AccountType, AccountTypeInfo},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use std::fmt;
+
+/// Size of a `ProviderSamplingBudget` account without the data provider name bytes.
+const PROVIDER_SAMPLING_BUDGET_SIZE_MINUS_PROVIDER: usize = {
+    1 // account_type
+    + 32 // oracle_agent_pk
+    + 4 // data_provider_name.len()
+    + 4 // max_samples_per_epoch
+    + 32 // _unused
+};
+
+/// Onchain cap on the samples an internet latency provider may write, set by the
+/// foundation for one oracle agent and data provider.
+///
+/// `WriteInternetLatencySamples` rejects a write that would take a samples
+/// account, which covers one exchange pair for one epoch, past
+/// `max_samples_per_epoch`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProviderSamplingBudget {
+    pub account_type: AccountType, // 1
+    // Agent whose writes the budget applies to
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "doublezero_program_common::serializer::serialize_pubkey_as_string",
+            deserialize_with = "doublezero_program_common::serializer::deserialize_pubkey_from_string"
+        )
+    )]
+    pub oracle_agent_pk: Pubkey, // 32
+    // Name of the third-party provider of the sampling probes
+    pub data_provider_name: String, // 4 + up to 32
+    // Most samples one exchange pair may hold in an epoch
+    pub max_samples_per_epoch: u32, // 4
+    // Reserved for future use
+    pub _unused: [u8; 32], // 32
+}
+
+impl ProviderSamplingBudget {
+    pub fn instance_size(data_provider_name_size: usize) -> usize {
+        PROVIDER_SAMPLING_BUDGET_SIZE_MINUS_PROVIDER + data_provider_name_size
+    }
+}
+
+impl TryFrom<&[u8]> for ProviderSamplingBudget {
+    type Error = borsh::io::Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::deserialize(&mut &data[..])
+    }
+}
+
+impl fmt::Display for ProviderSamplingBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account_type: {}, oracle_agent: {}, data_provider_name: {}, max_samples_per_epoch: {}",
+            self.account_type,
+            self.oracle_agent_pk,
+            self.data_provider_name,
+            self.max_samples_per_epoch,
+        )
+    }
+}
+
+impl AccountTypeInfo for ProviderSamplingBudget {
+    fn seed(&self) -> &[u8] {
+        SEED_PROVIDER_SAMPLING_BUDGET
+    }
+
+    fn size(&self) -> usize {
+        Self::instance_size(self.data_provider_name.len())
+    }
+
+    fn owner(&self) -> Pubkey {
+        self.oracle_agent_pk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_sampling_budget_serialized() {
+        let val = ProviderSamplingBudget {
+            account_type: AccountType::ProviderSamplingBudget,
+            oracle_agent_pk: Pubkey::new_unique(),
+            data_provider_name: "RIPE Atlas".to_string(),
+            max_samples_per_epoch: 1_440,
+            _unused: [0; 32],
+        };
+
+        let data = borsh::to_vec(&val).unwrap();
+        assert_eq!(data.len(), val.size());
+        assert_eq!(ProviderSamplingBudget::try_from(&data[..]).unwrap(), val);
+    }
+}
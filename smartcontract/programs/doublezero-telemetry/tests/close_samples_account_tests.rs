@@ -0,0 +1,263 @@
+use doublezero_telemetry::{
+    error::TelemetryError,
+    processors::telemetry::close_samples_account::MIN_SAMPLES_RETENTION_EPOCHS,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+#[tokio::test]
+async fn test_close_samples_account_fail_retention_not_elapsed() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    // Seed ledger with two linked devices, and a funded origin device agent.
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    // Wait for a new blockhash before moving on.
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    // The ledger is still in its first epochs, so the samples must be retained.
+    let result = ledger
+        .telemetry
+        .close_samples_account(&origin_device_agent, latency_samples_pda, payer.pubkey(), 0)
+        .await;
+    assert_telemetry_error(result, TelemetryError::RetentionPeriodNotElapsed);
+
+    // The account is untouched.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+}
+
+#[tokio::test]
+async fn test_close_samples_account_success_after_retention() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+    let rent = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist")
+        .lamports;
+    let treasury_before = ledger
+        .get_account(payer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Move past the retention window for epoch 1.
+    ledger
+        .warp_to_epoch(2 + MIN_SAMPLES_RETENTION_EPOCHS)
+        .await
+        .unwrap();
+    ledger
+        .telemetry
+        .close_samples_account(&origin_device_agent, latency_samples_pda, payer.pubkey(), 0)
+        .await
+        .unwrap();
+
+    assert!(ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .is_none());
+    let treasury_after = ledger
+        .get_account(payer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(treasury_after, treasury_before + rent);
+}
+
+#[tokio::test]
+async fn test_close_samples_account_fail_unauthorized_closer() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    // A signer that is neither the agent nor a foundation member.
+    let outsider = Keypair::new();
+    ledger
+        .fund_account(&outsider.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+
+    ledger
+        .warp_to_epoch(2 + MIN_SAMPLES_RETENTION_EPOCHS)
+        .await
+        .unwrap();
+    let result = ledger
+        .telemetry
+        .close_samples_account(&outsider, latency_samples_pda, payer.pubkey(), 0)
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedCloser);
+
+    // The account is untouched.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+}
+
+#[tokio::test]
+async fn test_close_samples_account_fail_invalid_treasury() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    ledger
+        .warp_to_epoch(2 + MIN_SAMPLES_RETENTION_EPOCHS)
+        .await
+        .unwrap();
+
+    // The rent may only be refunded to a foundation member.
+    let result = ledger
+        .telemetry
+        .close_samples_account(
+            &origin_device_agent,
+            latency_samples_pda,
+            Pubkey::new_unique(),
+            0,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::InvalidTreasury);
+
+    // The account is untouched.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+}
+
+#[tokio::test]
+async fn test_close_samples_account_fail_account_not_owned_by_program() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+
+    // The serviceability global state is not a telemetry samples account.
+    let globalstate_pk = ledger.serviceability.global_state_pubkey;
+    let result = ledger
+        .telemetry
+        .close_samples_account(&payer, globalstate_pk, payer.pubkey(), 0)
+        .await;
+    assert_banksclient_error(
+        result,
+        InstructionError::Custom(TelemetryError::InvalidAccountOwner as u32),
+    );
+}
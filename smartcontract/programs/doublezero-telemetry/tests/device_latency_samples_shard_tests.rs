@@ -0,0 +1,227 @@
+use doublezero_telemetry::{
+    error::TelemetryError,
+    pda::derive_device_latency_samples_shard_pda,
+    state::device_latency_samples::{DeviceLatencySamples, MAX_DEVICE_LATENCY_SAMPLES},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+/// Seed two linked devices and a samples account for epoch 1 holding
+/// `MAX_DEVICE_LATENCY_SAMPLES - 100` samples.
+async fn seed_nearly_full_shard(
+    ledger: &mut LedgerHelper,
+) -> (Keypair, Pubkey, Pubkey, Pubkey, Pubkey) {
+    let payer_pubkey = ledger
+        .context
+        .lock()
+        .unwrap()
+        .payer
+        .insecure_clone()
+        .pubkey();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer_pubkey)
+        .await
+        .unwrap();
+
+    let (agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    let target = MAX_DEVICE_LATENCY_SAMPLES - 100;
+    let chunk_size = MAX_PERMITTED_DATA_INCREASE / 4;
+    let mut total_written = 0;
+    while total_written < target {
+        ledger.wait_for_new_blockhash().await.unwrap();
+        let chunk = vec![1234u32; chunk_size.min(target - total_written)];
+        ledger
+            .telemetry
+            .write_device_latency_samples(
+                &agent,
+                latency_samples_pda,
+                chunk.clone(),
+                1_700_000_000_000_000,
+            )
+            .await
+            .unwrap();
+        total_written += chunk.len();
+    }
+
+    (
+        agent,
+        origin_device_pk,
+        target_device_pk,
+        link_pk,
+        latency_samples_pda,
+    )
+}
+
+#[tokio::test]
+async fn test_write_device_latency_samples_rolls_over_to_next_shard() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+    let (agent, origin_device_pk, target_device_pk, link_pk, latency_samples_pda) =
+        seed_nearly_full_shard(&mut ledger).await;
+
+    let (next_shard_pda, _) = derive_device_latency_samples_shard_pda(
+        &ledger.telemetry.program_id,
+        &origin_device_pk,
+        &target_device_pk,
+        &link_pk,
+        1,
+        1,
+    );
+    assert_ne!(next_shard_pda, latency_samples_pda);
+
+    // 100 samples fit, the other 150 start shard 1.
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let samples: Vec<u32> = (0..250).collect();
+    ledger
+        .telemetry
+        .write_device_latency_samples_with_next_shard(
+            &agent,
+            latency_samples_pda,
+            next_shard_pda,
+            samples.clone(),
+            1_800_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let shard0 = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(shard0.samples.len(), MAX_DEVICE_LATENCY_SAMPLES);
+    assert_eq!(
+        shard0.samples[MAX_DEVICE_LATENCY_SAMPLES - 100..],
+        samples[..100]
+    );
+    assert!(shard0.header.is_rolled_over());
+    assert_eq!(shard0.header.shard_index, 0);
+
+    let account = ledger.get_account(next_shard_pda).await.unwrap().unwrap();
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+    let shard1 = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(shard1.samples, samples[100..]);
+    assert_eq!(shard1.header.shard_index, 1);
+    assert!(!shard1.header.is_rolled_over());
+    assert_eq!(shard1.header.origin_device_agent_pk, agent.pubkey());
+    assert_eq!(shard1.header.link_pk, link_pk);
+    assert_eq!(shard1.header.epoch, 1);
+    assert_eq!(
+        shard1.header.start_timestamp_microseconds,
+        1_800_000_000_000_000 + 100 * 5_000_000
+    );
+
+    // The rolled-over shard takes no more samples; writes continue on shard 1.
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let (shard2_pda, _) = derive_device_latency_samples_shard_pda(
+        &ledger.telemetry.program_id,
+        &origin_device_pk,
+        &target_device_pk,
+        &link_pk,
+        1,
+        2,
+    );
+    let result = ledger
+        .telemetry
+        .write_device_latency_samples_with_next_shard(
+            &agent,
+            latency_samples_pda,
+            shard2_pda,
+            vec![1, 2, 3],
+            1_800_000_100_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::SamplesAccountFull);
+
+    ledger
+        .telemetry
+        .write_device_latency_samples(&agent, next_shard_pda, vec![1, 2, 3], 1_800_000_100_000_000)
+        .await
+        .unwrap();
+    let account = ledger.get_account(next_shard_pda).await.unwrap().unwrap();
+    let shard1 = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(shard1.samples.len(), 153);
+}
+
+#[tokio::test]
+async fn test_write_device_latency_samples_fail_full_without_next_shard() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+    let (agent, _, _, _, latency_samples_pda) = seed_nearly_full_shard(&mut ledger).await;
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let result = ledger
+        .telemetry
+        .write_device_latency_samples(
+            &agent,
+            latency_samples_pda,
+            vec![1234; 101],
+            1_800_000_000_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::SamplesAccountFull);
+
+    // Nothing was written.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let shard0 = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(shard0.samples.len(), MAX_DEVICE_LATENCY_SAMPLES - 100);
+    assert!(!shard0.header.is_rolled_over());
+}
+
+#[tokio::test]
+async fn test_write_device_latency_samples_fail_invalid_next_shard_pda() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+    let (agent, origin_device_pk, target_device_pk, link_pk, latency_samples_pda) =
+        seed_nearly_full_shard(&mut ledger).await;
+
+    // Shard 2 is not the shard after shard 0.
+    let (wrong_shard_pda, _) = derive_device_latency_samples_shard_pda(
+        &ledger.telemetry.program_id,
+        &origin_device_pk,
+        &target_device_pk,
+        &link_pk,
+        1,
+        2,
+    );
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let result = ledger
+        .telemetry
+        .write_device_latency_samples_with_next_shard(
+            &agent,
+            latency_samples_pda,
+            wrong_shard_pda,
+            vec![1234; 101],
+            1_800_000_000_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::InvalidPDA);
+}
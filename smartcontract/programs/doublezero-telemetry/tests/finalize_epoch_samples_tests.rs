@@ -0,0 +1,158 @@
+use doublezero_telemetry::{
+    error::TelemetryError, state::device_latency_samples::DeviceLatencySamples,
+};
+use solana_program_test::*;
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+#[tokio::test]
+async fn test_finalize_epoch_samples_fail_epoch_not_ended() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    // Seed ledger with two linked devices, and a funded origin device agent.
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    // Wait for a new blockhash before moving on.
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    // The ledger is still in its first epochs, so epoch 1 has not ended.
+    let result = ledger
+        .telemetry
+        .finalize_epoch_samples(
+            &origin_device_agent,
+            latency_samples_pda,
+            origin_device_agent.pubkey(),
+            true,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::EpochNotEnded);
+
+    // The account is still open for writes.
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &origin_device_agent,
+            latency_samples_pda,
+            vec![100, 200, 300],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let samples_data = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert!(!samples_data.header.is_finalized());
+    assert_eq!(samples_data.samples, vec![100, 200, 300]);
+}
+
+#[tokio::test]
+async fn test_finalize_epoch_samples_success_after_epoch_rollover() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let epoch = ledger.get_clock().await.unwrap().epoch;
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            epoch,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &origin_device_agent,
+            latency_samples_pda,
+            vec![100, 200, 300],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    // Once the ledger rolls into the next epoch, the samples can be finalized.
+    ledger.advance_epochs(1).await.unwrap();
+    ledger
+        .telemetry
+        .finalize_epoch_samples(
+            &origin_device_agent,
+            latency_samples_pda,
+            origin_device_agent.pubkey(),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let samples_data = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert!(samples_data.header.is_finalized());
+    assert_eq!(samples_data.samples, vec![100, 200, 300]);
+}
+
+#[tokio::test]
+async fn test_finalize_epoch_samples_fail_account_not_owned_by_program() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+
+    // The serviceability global state is not a telemetry samples account.
+    let globalstate_pk = ledger.serviceability.global_state_pubkey;
+    let result = ledger
+        .telemetry
+        .finalize_epoch_samples(&payer, globalstate_pk, payer.pubkey(), false)
+        .await;
+    assert_banksclient_error(
+        result,
+        InstructionError::Custom(TelemetryError::InvalidAccountOwner as u32),
+    );
+}
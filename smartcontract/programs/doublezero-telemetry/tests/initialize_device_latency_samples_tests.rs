@@ -716,6 +716,11 @@ async fn test_initialize_device_latency_samples_fail_link_wrong_owner() {
         desired_status: doublezero_serviceability::state::link::LinkDesiredStatus::Activated,
         link_topologies: Vec::new(),
         link_flags: 0,
+        flap_count: 0,
+        flap_penalty: 0,
+        flap_epoch: 0,
+        damped_until_epoch: 0,
+        ops_lock: Default::default(),
     };
 
     let mut data = Vec::new();
@@ -85,7 +85,8 @@ async fn test_initialize_internet_latency_samples_success_active_exchanges() {
             sampling_interval_microseconds: 60_000_000,
             next_sample_index: 0,
             start_timestamp_microseconds: 0,
-            _unused: [0u8; 128],
+            flags: 0,
+            _unused: [0u8; 127],
         }
     );
 }
@@ -0,0 +1,242 @@
+use doublezero_serviceability::state::{
+    accounttype::AccountType as ServiceabilityAccountType,
+    user::{BGPStatus, BandwidthTier, User, UserCYOA, UserStatus, UserType},
+};
+use doublezero_telemetry::{
+    error::TelemetryError,
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        path_probe_samples::{PathProbeSamples, PathProbeSamplesHeader, PATH_PROBE_SAMPLE_LOST},
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+// Stage a user connected to `device_pk` directly in the bank.
+fn stage_user(ledger: &mut LedgerHelper, device_pk: Pubkey, status: UserStatus) -> Pubkey {
+    let user = User {
+        account_type: ServiceabilityAccountType::User,
+        owner: Pubkey::new_unique(),
+        index: 1,
+        bump_seed: 255,
+        user_type: UserType::IBRL,
+        tenant_pk: Pubkey::default(),
+        device_pk,
+        cyoa_type: UserCYOA::GREOverDIA,
+        client_ip: [180, 0, 0, 1].into(),
+        dz_ip: [180, 0, 0, 1].into(),
+        tunnel_id: 500,
+        tunnel_net: "169.254.0.0/31".parse().unwrap(),
+        status,
+        publishers: vec![],
+        subscribers: vec![],
+        validator_pubkey: Pubkey::default(),
+        tunnel_endpoint: [0, 0, 0, 0].into(),
+        tunnel_flags: 0,
+        bgp_status: BGPStatus::default(),
+        last_bgp_up_at: 0,
+        last_bgp_reported_at: 0,
+        bgp_rtt_ns: 0,
+        feed_pk: Pubkey::default(),
+        bandwidth_tier: BandwidthTier::default(),
+    };
+    let data = borsh::to_vec(&user).unwrap();
+
+    let user_pk = Pubkey::new_unique();
+    ledger.set_account(
+        &user_pk,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: serviceability_program_id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    user_pk
+}
+
+// Returns the health oracle (the ledger payer), a user on the origin device and the target device.
+async fn setup(user_status: UserStatus) -> (LedgerHelper, Keypair, Pubkey, Pubkey, Pubkey) {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    // GlobalState is initialized with the payer as health oracle.
+    let health_oracle = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), health_oracle.pubkey())
+        .await
+        .unwrap();
+    let (_, origin_device_pk, target_device_pk, _) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    let user_pk = stage_user(&mut ledger, origin_device_pk, user_status);
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    (
+        ledger,
+        health_oracle,
+        user_pk,
+        origin_device_pk,
+        target_device_pk,
+    )
+}
+
+#[tokio::test]
+async fn test_path_probe_samples_initialize_and_write() {
+    let (mut ledger, health_oracle, user_pk, origin_device_pk, target_device_pk) =
+        setup(UserStatus::Activated).await;
+
+    let pda = ledger
+        .telemetry
+        .initialize_path_probe_samples(&health_oracle, user_pk, target_device_pk, 42, 30_000_000)
+        .await
+        .unwrap();
+
+    let account = ledger.get_account(pda).await.unwrap().unwrap();
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+    let samples = PathProbeSamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(
+        samples.header,
+        PathProbeSamplesHeader {
+            account_type: AccountType::PathProbeSamples,
+            epoch: 42,
+            prober_agent_pk: health_oracle.pubkey(),
+            user_pk,
+            origin_device_pk,
+            target_device_pk,
+            sampling_interval_microseconds: 30_000_000,
+            start_timestamp_microseconds: 0,
+            next_sample_index: 0,
+            flags: 0,
+            _unused: [0; 64],
+        }
+    );
+
+    ledger
+        .telemetry
+        .write_path_probe_samples(
+            &health_oracle,
+            pda,
+            vec![1_200, PATH_PROBE_SAMPLE_LOST],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+    ledger.wait_for_new_blockhash().await.unwrap();
+    ledger
+        .telemetry
+        .write_path_probe_samples(&health_oracle, pda, vec![1_350], 1_700_000_060_000_000)
+        .await
+        .unwrap();
+
+    let account = ledger.get_account(pda).await.unwrap().unwrap();
+    let samples = PathProbeSamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(samples.samples, vec![1_200, PATH_PROBE_SAMPLE_LOST, 1_350]);
+    assert_eq!(samples.lost_count(), 1);
+    assert_eq!(
+        samples.header.start_timestamp_microseconds,
+        1_700_000_000_000_000
+    );
+
+    // Nobody but the prober may write to the account.
+    let intruder = Keypair::new();
+    ledger
+        .fund_account(&intruder.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let result = ledger
+        .telemetry
+        .write_path_probe_samples(&intruder, pda, vec![900], 1_700_000_000_000_000)
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedAgent);
+}
+
+#[tokio::test]
+async fn test_initialize_path_probe_samples_fail_not_health_oracle() {
+    let (mut ledger, _, user_pk, _, target_device_pk) = setup(UserStatus::Activated).await;
+
+    let prober = Keypair::new();
+    ledger
+        .fund_account(&prober.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    let result = ledger
+        .telemetry
+        .initialize_path_probe_samples(&prober, user_pk, target_device_pk, 42, 30_000_000)
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedAgent);
+}
+
+#[tokio::test]
+async fn test_initialize_path_probe_samples_fail_user_not_activated() {
+    let (mut ledger, health_oracle, user_pk, _, target_device_pk) =
+        setup(UserStatus::Deleting).await;
+
+    let result = ledger
+        .telemetry
+        .initialize_path_probe_samples(&health_oracle, user_pk, target_device_pk, 42, 30_000_000)
+        .await;
+    assert_telemetry_error(result, TelemetryError::UserNotActivated);
+}
+
+#[tokio::test]
+async fn test_initialize_path_probe_samples_fail_target_is_user_device() {
+    let (mut ledger, health_oracle, user_pk, origin_device_pk, _) =
+        setup(UserStatus::Activated).await;
+
+    let result = ledger
+        .telemetry
+        .initialize_path_probe_samples(&health_oracle, user_pk, origin_device_pk, 42, 30_000_000)
+        .await;
+    assert_telemetry_error(result, TelemetryError::SameTargetAsOrigin);
+}
+
+#[tokio::test]
+async fn test_path_probe_samples_finalize() {
+    let (mut ledger, health_oracle, user_pk, _, target_device_pk) =
+        setup(UserStatus::Activated).await;
+
+    let epoch = ledger.get_clock().await.unwrap().epoch;
+    let pda = ledger
+        .telemetry
+        .initialize_path_probe_samples(&health_oracle, user_pk, target_device_pk, epoch, 30_000_000)
+        .await
+        .unwrap();
+    ledger
+        .telemetry
+        .write_path_probe_samples(&health_oracle, pda, vec![1_200], 1_700_000_000_000_000)
+        .await
+        .unwrap();
+
+    ledger.advance_epochs(1).await.unwrap();
+    ledger
+        .telemetry
+        .finalize_epoch_samples(&health_oracle, pda, health_oracle.pubkey(), true)
+        .await
+        .unwrap();
+
+    let account = ledger.get_account(pda).await.unwrap().unwrap();
+    let samples = PathProbeSamples::try_from(&account.data[..]).unwrap();
+    assert!(samples.header.is_finalized());
+    assert_eq!(samples.samples, vec![1_200]);
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let result = ledger
+        .telemetry
+        .write_path_probe_samples(&health_oracle, pda, vec![1_300], 1_700_000_000_000_000)
+        .await;
+    assert_telemetry_error(result, TelemetryError::SamplesAccountFinalized);
+}
@@ -0,0 +1,217 @@
+use doublezero_telemetry::{
+    error::TelemetryError,
+    pda::derive_provider_sampling_budget_pda,
+    state::{
+        accounttype::AccountType, internet_latency_samples::InternetLatencySamples,
+        provider_sampling_budget::ProviderSamplingBudget,
+    },
+};
+use solana_program_test::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+#[tokio::test]
+async fn test_set_provider_sampling_budget_create_and_update() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let oracle_agent_pk = Pubkey::new_unique();
+    let provider_name = "RIPE Atlas".to_string();
+
+    let budget_pda = ledger
+        .telemetry
+        .set_provider_sampling_budget(&payer, oracle_agent_pk, provider_name.clone(), 1_440)
+        .await
+        .unwrap();
+
+    let account = ledger
+        .get_account(budget_pda)
+        .await
+        .unwrap()
+        .expect("Provider sampling budget does not exist");
+    assert_eq!(account.owner, ledger.telemetry.program_id);
+    let budget = ProviderSamplingBudget::try_from(&account.data[..]).unwrap();
+    assert_eq!(
+        budget,
+        ProviderSamplingBudget {
+            account_type: AccountType::ProviderSamplingBudget,
+            oracle_agent_pk,
+            data_provider_name: provider_name.clone(),
+            max_samples_per_epoch: 1_440,
+            _unused: [0; 32],
+        }
+    );
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    ledger
+        .telemetry
+        .set_provider_sampling_budget(&payer, oracle_agent_pk, provider_name, 720)
+        .await
+        .unwrap();
+
+    let account = ledger.get_account(budget_pda).await.unwrap().unwrap();
+    let budget = ProviderSamplingBudget::try_from(&account.data[..]).unwrap();
+    assert_eq!(budget.max_samples_per_epoch, 720);
+}
+
+#[tokio::test]
+async fn test_set_provider_sampling_budget_fail_not_foundation() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let (oracle_agent, _, _) = ledger.seed_with_two_exchanges().await.unwrap();
+
+    let result = ledger
+        .telemetry
+        .set_provider_sampling_budget(
+            &oracle_agent,
+            oracle_agent.pubkey(),
+            "RIPE Atlas".to_string(),
+            1_440,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedAgent);
+}
+
+#[tokio::test]
+async fn test_set_provider_sampling_budget_fail_invalid_budget() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+
+    let result = ledger
+        .telemetry
+        .set_provider_sampling_budget(&payer, Pubkey::new_unique(), "RIPE Atlas".to_string(), 0)
+        .await;
+    assert_telemetry_error(result, TelemetryError::InvalidSamplingBudget);
+}
+
+#[tokio::test]
+async fn test_write_internet_latency_samples_enforces_budget() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let (oracle_agent, origin_exchange_pk, target_exchange_pk) =
+        ledger.seed_with_two_exchanges().await.unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let provider_name = "RIPE Atlas".to_string();
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_internet_latency_samples(
+            &oracle_agent,
+            provider_name.clone(),
+            origin_exchange_pk,
+            target_exchange_pk,
+            1u64,
+            60_000_000,
+        )
+        .await
+        .unwrap();
+
+    // A budget that was never set imposes no limit.
+    let (budget_pda, _) = derive_provider_sampling_budget_pda(
+        &ledger.telemetry.program_id,
+        &oracle_agent.pubkey(),
+        &provider_name,
+    );
+    ledger
+        .telemetry
+        .write_internet_latency_samples_with_budget(
+            &oracle_agent,
+            latency_samples_pda,
+            budget_pda,
+            vec![1000, 1100],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    ledger
+        .telemetry
+        .set_provider_sampling_budget(&payer, oracle_agent.pubkey(), provider_name, 3)
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    // Filling the budget exactly is allowed.
+    ledger
+        .telemetry
+        .write_internet_latency_samples_with_budget(
+            &oracle_agent,
+            latency_samples_pda,
+            budget_pda,
+            vec![1200],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let result = ledger
+        .telemetry
+        .write_internet_latency_samples_with_budget(
+            &oracle_agent,
+            latency_samples_pda,
+            budget_pda,
+            vec![1300],
+            1_700_000_000_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::SamplingBudgetExceeded);
+
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let samples = InternetLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(samples.samples, vec![1000, 1100, 1200]);
+}
+
+#[tokio::test]
+async fn test_write_internet_latency_samples_fail_wrong_budget_pda() {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let (oracle_agent, origin_exchange_pk, target_exchange_pk) =
+        ledger.seed_with_two_exchanges().await.unwrap();
+
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_internet_latency_samples(
+            &oracle_agent,
+            "RIPE Atlas".to_string(),
+            origin_exchange_pk,
+            target_exchange_pk,
+            1u64,
+            60_000_000,
+        )
+        .await
+        .unwrap();
+
+    // The budget of another provider does not apply.
+    let (other_budget_pda, _) = derive_provider_sampling_budget_pda(
+        &ledger.telemetry.program_id,
+        &oracle_agent.pubkey(),
+        "Other",
+    );
+    let result = ledger
+        .telemetry
+        .write_internet_latency_samples_with_budget(
+            &oracle_agent,
+            latency_samples_pda,
+            other_budget_pda,
+            vec![1000],
+            1_700_000_000_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::InvalidPDA);
+}
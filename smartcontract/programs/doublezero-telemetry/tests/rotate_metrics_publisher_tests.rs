@@ -0,0 +1,283 @@
+use doublezero_telemetry::{
+    error::TelemetryError, state::device_latency_samples::DeviceLatencySamples,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+mod test_helpers;
+
+use test_helpers::*;
+
+/// Seeds two linked devices and a samples account written by the origin
+/// device's agent, and returns the ledger with the pieces needed to rotate it.
+async fn setup_samples_account() -> (LedgerHelper, Pubkey, Keypair, Pubkey, Pubkey) {
+    let mut ledger = LedgerHelper::new().await.unwrap();
+
+    let payer = ledger.context.lock().unwrap().payer.insecure_clone();
+    let contributor_pk = ledger
+        .serviceability
+        .create_contributor("CONTRIB".to_string(), payer.pubkey())
+        .await
+        .unwrap();
+
+    // Seed ledger with two linked devices, and a funded origin device agent.
+    let (origin_device_agent, origin_device_pk, target_device_pk, link_pk) = ledger
+        .seed_with_two_linked_devices(contributor_pk)
+        .await
+        .unwrap();
+
+    // Wait for a new blockhash before moving on.
+    ledger.wait_for_new_blockhash().await.unwrap();
+
+    let latency_samples_pda = ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &origin_device_agent,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            1u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &origin_device_agent,
+            latency_samples_pda,
+            vec![100, 200],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    (
+        ledger,
+        contributor_pk,
+        origin_device_agent,
+        origin_device_pk,
+        latency_samples_pda,
+    )
+}
+
+#[tokio::test]
+async fn test_rotate_metrics_publisher_success() {
+    let (mut ledger, contributor_pk, old_agent, origin_device_pk, latency_samples_pda) =
+        setup_samples_account().await;
+
+    // Rotate the device's metrics publisher in serviceability.
+    let new_agent = Keypair::new();
+    ledger
+        .fund_account(&new_agent.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    ledger
+        .serviceability
+        .set_device_metrics_publisher(contributor_pk, origin_device_pk, new_agent.pubkey())
+        .await
+        .unwrap();
+
+    ledger
+        .telemetry
+        .rotate_metrics_publisher(&new_agent, latency_samples_pda, origin_device_pk)
+        .await
+        .unwrap();
+
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let samples_data = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(
+        samples_data.header.origin_device_agent_pk,
+        new_agent.pubkey()
+    );
+    assert_eq!(samples_data.header.previous_agent_pk, old_agent.pubkey());
+
+    // Both keys may write during the grace period.
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &new_agent,
+            latency_samples_pda,
+            vec![300],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &old_agent,
+            latency_samples_pda,
+            vec![400],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let samples_data = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(samples_data.samples, vec![100, 200, 300, 400]);
+
+    // Any other key is still rejected.
+    let outsider = Keypair::new();
+    ledger
+        .fund_account(&outsider.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let result = ledger
+        .telemetry
+        .write_device_latency_samples(
+            &outsider,
+            latency_samples_pda,
+            vec![500],
+            1_700_000_000_000_000,
+        )
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedAgent);
+
+    // Rotating to the same key again is rejected.
+    ledger.wait_for_new_blockhash().await.unwrap();
+    let result = ledger
+        .telemetry
+        .rotate_metrics_publisher(&new_agent, latency_samples_pda, origin_device_pk)
+        .await;
+    assert_telemetry_error(result, TelemetryError::MetricsPublisherUnchanged);
+}
+
+#[tokio::test]
+async fn test_rotate_metrics_publisher_fail_not_device_publisher() {
+    let (mut ledger, _contributor_pk, _old_agent, origin_device_pk, latency_samples_pda) =
+        setup_samples_account().await;
+
+    // The device still points at the old agent, so nobody else may take the account.
+    let new_agent = Keypair::new();
+    ledger
+        .fund_account(&new_agent.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let result = ledger
+        .telemetry
+        .rotate_metrics_publisher(&new_agent, latency_samples_pda, origin_device_pk)
+        .await;
+    assert_telemetry_error(result, TelemetryError::UnauthorizedAgent);
+}
+
+#[tokio::test]
+async fn test_rotate_metrics_publisher_fail_wrong_origin_device() {
+    let (mut ledger, contributor_pk, _old_agent, origin_device_pk, latency_samples_pda) =
+        setup_samples_account().await;
+
+    let new_agent = Keypair::new();
+    ledger
+        .fund_account(&new_agent.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    ledger
+        .serviceability
+        .set_device_metrics_publisher(contributor_pk, origin_device_pk, new_agent.pubkey())
+        .await
+        .unwrap();
+
+    // The target device is not the account's origin device.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let target_device_pk = DeviceLatencySamples::try_from(&account.data[..])
+        .unwrap()
+        .header
+        .target_device_pk;
+    let result = ledger
+        .telemetry
+        .rotate_metrics_publisher(&new_agent, latency_samples_pda, target_device_pk)
+        .await;
+    assert_telemetry_error(result, TelemetryError::OriginDeviceMismatch);
+}
+
+#[tokio::test]
+async fn test_rotate_metrics_publisher_honours_device_overlap() {
+    let (mut ledger, contributor_pk, old_agent, origin_device_pk, latency_samples_pda) =
+        setup_samples_account().await;
+
+    // Rotate with an overlap window, so the old key stays valid for a while.
+    let new_agent = Keypair::new();
+    ledger
+        .fund_account(&new_agent.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    ledger
+        .serviceability
+        .rotate_device_metrics_publisher(contributor_pk, origin_device_pk, new_agent.pubkey(), 5)
+        .await
+        .unwrap();
+    let device = ledger
+        .serviceability
+        .get_device(origin_device_pk)
+        .await
+        .unwrap();
+    assert_eq!(device.metrics_publisher_pk, new_agent.pubkey());
+    assert_eq!(device.previous_metrics_publisher_pk, old_agent.pubkey());
+
+    // The old agent may still open accounts for new epochs during the overlap.
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let header = DeviceLatencySamples::try_from(&account.data[..])
+        .unwrap()
+        .header;
+    ledger.wait_for_new_blockhash().await.unwrap();
+    ledger
+        .telemetry
+        .initialize_device_latency_samples(
+            &old_agent,
+            origin_device_pk,
+            header.target_device_pk,
+            header.link_pk,
+            2u64,
+            5_000_000,
+        )
+        .await
+        .unwrap();
+
+    // Handing the account over records the device's overlap window.
+    ledger
+        .telemetry
+        .rotate_metrics_publisher(&new_agent, latency_samples_pda, origin_device_pk)
+        .await
+        .unwrap();
+    let account = ledger
+        .get_account(latency_samples_pda)
+        .await
+        .unwrap()
+        .expect("Latency samples does not exist");
+    let samples_data = DeviceLatencySamples::try_from(&account.data[..]).unwrap();
+    assert_eq!(
+        samples_data.header.previous_agent_until_epoch,
+        device.metrics_publisher_overlap_until_epoch
+    );
+
+    ledger
+        .telemetry
+        .write_device_latency_samples(
+            &old_agent,
+            latency_samples_pda,
+            vec![300],
+            1_700_000_000_000_000,
+        )
+        .await
+        .unwrap();
+}
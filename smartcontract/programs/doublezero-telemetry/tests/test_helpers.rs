@@ -11,6 +11,7 @@ use doublezero_serviceability::{
         contributor::create::ContributorCreateArgs,
         device::{
             create::DeviceCreateArgs, interface::create::DeviceInterfaceCreateArgs,
+            rotate_metrics_publisher::DeviceRotateMetricsPublisherArgs,
             sethealth::DeviceSetHealthArgs, update::DeviceUpdateArgs,
         },
         exchange::{create::ExchangeCreateArgs, suspend::ExchangeSuspendArgs},
@@ -33,12 +34,20 @@ use doublezero_serviceability::{
 use doublezero_telemetry::{
     error::TelemetryError,
     instructions::{TelemetryInstruction, INITIALIZE_DEVICE_LATENCY_SAMPLES_INSTRUCTION_INDEX},
-    pda::{derive_device_latency_samples_pda, derive_internet_latency_samples_pda},
+    pda::{
+        derive_device_latency_samples_pda, derive_internet_latency_samples_pda,
+        derive_path_probe_samples_pda, derive_provider_sampling_budget_pda,
+    },
     processors::telemetry::{
+        close_samples_account::CloseSamplesAccountArgs,
+        finalize_epoch_samples::FinalizeEpochSamplesArgs,
         initialize_device_latency_samples::InitializeDeviceLatencySamplesArgs,
         initialize_internet_latency_samples::InitializeInternetLatencySamplesArgs,
+        initialize_path_probe_samples::InitializePathProbeSamplesArgs,
+        set_provider_sampling_budget::SetProviderSamplingBudgetArgs,
         write_device_latency_samples::WriteDeviceLatencySamplesArgs,
         write_internet_latency_samples::WriteInternetLatencySamplesArgs,
+        write_path_probe_samples::WritePathProbeSamplesArgs,
     },
     serviceability_program_id,
 };
@@ -49,6 +58,7 @@ use solana_commitment_config::CommitmentLevel;
 use solana_program_test::*;
 use solana_sdk::{
     account::Account,
+    clock::Clock,
     hash::Hash,
     instruction::{AccountMeta, Instruction, InstructionError},
     message::{v0::Message, VersionedMessage},
@@ -161,6 +171,8 @@ pub struct LedgerHelper {
     pub context: Arc<Mutex<LedgerContext>>,
     pub serviceability: ServiceabilityProgramHelper,
     pub telemetry: TelemetryProgramHelper,
+    /// Owns the bank so tests can warp it to a later slot or epoch.
+    program_test_context: ProgramTestContext,
 }
 
 impl LedgerHelper {
@@ -178,12 +190,12 @@ impl LedgerHelper {
             program_test.add_account(pk, account);
         }
 
-        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+        let program_test_context = program_test.start_with_context().await;
 
         let context = Arc::new(Mutex::new(LedgerContext {
-            banks_client,
-            payer,
-            recent_blockhash,
+            banks_client: program_test_context.banks_client.clone(),
+            payer: program_test_context.payer.insecure_clone(),
+            recent_blockhash: program_test_context.last_blockhash,
         }));
 
         let serviceability =
@@ -195,9 +207,47 @@ impl LedgerHelper {
             context,
             serviceability,
             telemetry,
+            program_test_context,
         })
     }
 
+    /// Current `Clock` sysvar of the bank.
+    pub async fn get_clock(&mut self) -> Result<Clock, BanksClientError> {
+        let banks_client = { self.context.lock().unwrap().banks_client.clone() };
+        banks_client.get_sysvar::<Clock>().await
+    }
+
+    /// Warp the bank forward to `slot` and pick up a blockhash from it.
+    pub async fn warp_to_slot(&mut self, slot: u64) -> Result<(), BanksClientError> {
+        self.program_test_context
+            .warp_to_slot(slot)
+            .unwrap_or_else(|e| panic!("Failed to warp to slot {slot}: {e:?}"));
+        self.wait_for_new_blockhash().await
+    }
+
+    /// Warp the bank forward to the first slot of `epoch`, so instructions see
+    /// `Clock::epoch == epoch` without waiting for real epochs to pass.
+    pub async fn warp_to_epoch(&mut self, epoch: u64) -> Result<(), BanksClientError> {
+        self.program_test_context
+            .warp_to_epoch(epoch)
+            .unwrap_or_else(|e| panic!("Failed to warp to epoch {epoch}: {e:?}"));
+        self.wait_for_new_blockhash().await
+    }
+
+    /// Roll the bank over `epochs` epoch boundaries from the current epoch.
+    pub async fn advance_epochs(&mut self, epochs: u64) -> Result<u64, BanksClientError> {
+        let epoch = self.get_clock().await?.epoch + epochs;
+        self.warp_to_epoch(epoch).await?;
+        Ok(epoch)
+    }
+
+    /// Overwrite an account directly in the bank, e.g. to stage serviceability
+    /// state that is expensive to reach through instructions.
+    pub fn set_account(&mut self, pubkey: &Pubkey, account: Account) {
+        self.program_test_context
+            .set_account(pubkey, &account.into());
+    }
+
     pub async fn get_account(
         &mut self,
         pubkey: Pubkey,
@@ -495,6 +545,32 @@ impl TelemetryProgramHelper {
         .await
     }
 
+    pub async fn write_device_latency_samples_with_next_shard(
+        &mut self,
+        agent: &Keypair,
+        latency_samples_pda: Pubkey,
+        next_shard_pda: Pubkey,
+        samples: Vec<u32>,
+        start_timestamp_microseconds: u64,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            TelemetryInstruction::WriteDeviceLatencySamples(WriteDeviceLatencySamplesArgs {
+                start_timestamp_microseconds,
+                samples,
+                agent_version: [0; 16],
+                agent_commit: [0; 8],
+            }),
+            &[agent],
+            vec![
+                AccountMeta::new(latency_samples_pda, false),
+                AccountMeta::new(agent.pubkey(), true),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+                AccountMeta::new(next_shard_pda, false),
+            ],
+        )
+        .await
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn write_device_latency_samples_with_version(
         &mut self,
@@ -694,6 +770,61 @@ impl TelemetryProgramHelper {
         .await
     }
 
+    pub async fn write_internet_latency_samples_with_budget(
+        &mut self,
+        agent: &Keypair,
+        latency_samples_pda: Pubkey,
+        budget_pda: Pubkey,
+        samples: Vec<u32>,
+        start_timestamp_microseconds: u64,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            TelemetryInstruction::WriteInternetLatencySamples(WriteInternetLatencySamplesArgs {
+                start_timestamp_microseconds,
+                samples,
+            }),
+            &[agent],
+            vec![
+                AccountMeta::new(latency_samples_pda, false),
+                AccountMeta::new(agent.pubkey(), true),
+                AccountMeta::new(solana_system_interface::program::ID, false),
+                AccountMeta::new_readonly(budget_pda, false),
+            ],
+        )
+        .await
+    }
+
+    pub async fn set_provider_sampling_budget(
+        &mut self,
+        signer: &Keypair,
+        oracle_agent_pk: Pubkey,
+        data_provider_name: String,
+        max_samples_per_epoch: u32,
+    ) -> Result<Pubkey, BanksClientError> {
+        let (budget_pda, _) = derive_provider_sampling_budget_pda(
+            &self.program_id,
+            &oracle_agent_pk,
+            &data_provider_name,
+        );
+        let (globalstate_pk, _) = get_globalstate_pda(&serviceability_program_id());
+        self.execute_transaction(
+            TelemetryInstruction::SetProviderSamplingBudget(SetProviderSamplingBudgetArgs {
+                oracle_agent_pk,
+                data_provider_name,
+                max_samples_per_epoch,
+            }),
+            &[signer],
+            vec![
+                AccountMeta::new(budget_pda, false),
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new_readonly(globalstate_pk, false),
+                AccountMeta::new(solana_system_interface::program::ID, false),
+            ],
+        )
+        .await?;
+        Ok(budget_pda)
+    }
+
     pub async fn write_internet_latency_samples_with_pda(
         &self,
         agent: &Keypair,
@@ -741,6 +872,123 @@ impl TelemetryProgramHelper {
         banks_client.process_transaction(tx).await
     }
 
+    pub async fn close_samples_account(
+        &mut self,
+        signer: &Keypair,
+        latency_samples_pda: Pubkey,
+        treasury_pk: Pubkey,
+        retention_epochs: u64,
+    ) -> Result<(), BanksClientError> {
+        let (globalstate_pk, _) = get_globalstate_pda(&serviceability_program_id());
+        self.execute_transaction(
+            TelemetryInstruction::CloseSamplesAccount(CloseSamplesAccountArgs { retention_epochs }),
+            &[signer],
+            vec![
+                AccountMeta::new(latency_samples_pda, false),
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(treasury_pk, false),
+                AccountMeta::new_readonly(globalstate_pk, false),
+            ],
+        )
+        .await
+    }
+
+    pub async fn finalize_epoch_samples(
+        &mut self,
+        signer: &Keypair,
+        latency_samples_pda: Pubkey,
+        agent_pk: Pubkey,
+        compact: bool,
+    ) -> Result<(), BanksClientError> {
+        let (globalstate_pk, _) = get_globalstate_pda(&serviceability_program_id());
+        self.execute_transaction(
+            TelemetryInstruction::FinalizeEpochSamples(FinalizeEpochSamplesArgs { compact }),
+            &[signer],
+            vec![
+                AccountMeta::new(latency_samples_pda, false),
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(agent_pk, false),
+                AccountMeta::new_readonly(globalstate_pk, false),
+            ],
+        )
+        .await
+    }
+
+    pub async fn initialize_path_probe_samples(
+        &mut self,
+        prober: &Keypair,
+        user_pk: Pubkey,
+        target_device_pk: Pubkey,
+        epoch: u64,
+        sampling_interval_microseconds: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let (pda, _) = derive_path_probe_samples_pda(
+            &self.program_id,
+            &prober.pubkey(),
+            &user_pk,
+            &target_device_pk,
+            epoch,
+        );
+        let (globalstate_pk, _) = get_globalstate_pda(&serviceability_program_id());
+        self.execute_transaction(
+            TelemetryInstruction::InitializePathProbeSamples(InitializePathProbeSamplesArgs {
+                epoch,
+                sampling_interval_microseconds,
+            }),
+            &[prober],
+            vec![
+                AccountMeta::new(pda, false),
+                AccountMeta::new(prober.pubkey(), true),
+                AccountMeta::new_readonly(user_pk, false),
+                AccountMeta::new_readonly(target_device_pk, false),
+                AccountMeta::new_readonly(globalstate_pk, false),
+                AccountMeta::new(solana_system_interface::program::ID, false),
+            ],
+        )
+        .await?;
+        Ok(pda)
+    }
+
+    pub async fn write_path_probe_samples(
+        &mut self,
+        prober: &Keypair,
+        path_probe_samples_pda: Pubkey,
+        samples: Vec<u32>,
+        start_timestamp_microseconds: u64,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            TelemetryInstruction::WritePathProbeSamples(WritePathProbeSamplesArgs {
+                start_timestamp_microseconds,
+                samples,
+            }),
+            &[prober],
+            vec![
+                AccountMeta::new(path_probe_samples_pda, false),
+                AccountMeta::new(prober.pubkey(), true),
+                AccountMeta::new(solana_system_interface::program::ID, false),
+            ],
+        )
+        .await
+    }
+
+    pub async fn rotate_metrics_publisher(
+        &mut self,
+        agent: &Keypair,
+        latency_samples_pda: Pubkey,
+        origin_device_pk: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            TelemetryInstruction::RotateMetricsPublisher,
+            &[agent],
+            vec![
+                AccountMeta::new(latency_samples_pda, false),
+                AccountMeta::new(agent.pubkey(), true),
+                AccountMeta::new_readonly(origin_device_pk, false),
+            ],
+        )
+        .await
+    }
+
     pub async fn execute_transaction(
         &mut self,
         instruction: TelemetryInstruction,
@@ -1082,6 +1330,47 @@ impl ServiceabilityProgramHelper {
         .await
     }
 
+    pub async fn set_device_metrics_publisher(
+        &mut self,
+        contributor_pk: Pubkey,
+        pubkey: Pubkey,
+        metrics_publisher_pk: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            DoubleZeroInstruction::UpdateDevice(DeviceUpdateArgs {
+                metrics_publisher_pk: Some(metrics_publisher_pk),
+                ..Default::default()
+            }),
+            vec![
+                AccountMeta::new(pubkey, false),
+                AccountMeta::new(contributor_pk, false),
+                AccountMeta::new(self.global_state_pubkey, false),
+            ],
+        )
+        .await
+    }
+
+    pub async fn rotate_device_metrics_publisher(
+        &mut self,
+        contributor_pk: Pubkey,
+        pubkey: Pubkey,
+        metrics_publisher_pk: Pubkey,
+        overlap_epochs: u64,
+    ) -> Result<(), BanksClientError> {
+        self.execute_transaction(
+            DoubleZeroInstruction::RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs {
+                metrics_publisher_pk,
+                overlap_epochs,
+            }),
+            vec![
+                AccountMeta::new(pubkey, false),
+                AccountMeta::new(contributor_pk, false),
+                AccountMeta::new(self.global_state_pubkey, false),
+            ],
+        )
+        .await
+    }
+
     pub async fn create_interface(
         &mut self,
         device_pk: Pubkey,
@@ -1107,6 +1396,7 @@ impl ServiceabilityProgramHelper {
                 user_tunnel_endpoint: false,
                 use_onchain_allocation: true,
                 topology_count: 0,
+                with_ip_allocation: false,
             }),
             vec![
                 AccountMeta::new(device_pk, false),
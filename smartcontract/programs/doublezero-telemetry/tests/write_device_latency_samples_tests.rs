@@ -514,7 +514,12 @@ async fn test_write_device_latency_samples_fail_invalid_account_owner() {
             next_sample_index: 0,
             agent_version: [0; 16],
             agent_commit: [0; 8],
-            _unused: [0; 104],
+            flags: 0,
+            shard_index: 0,
+            previous_agent_pk: Pubkey::default(),
+            agent_rotation_epoch: 0,
+            previous_agent_until_epoch: 0,
+            _unused: [0; 54],
         },
         samples: vec![],
     };
@@ -405,7 +405,8 @@ async fn test_write_internet_latency_samples_fail_with_invalid_pda() {
             sampling_interval_microseconds: 60_000_000,
             start_timestamp_microseconds: 0,
             next_sample_index: 0,
-            _unused: [0; 128],
+            flags: 0,
+            _unused: [0; 127],
         },
         samples: vec![],
     };
@@ -13,22 +13,27 @@ type Client struct {
 }
 
 type ProgramData struct {
-	GlobalState        *GlobalState
-	GlobalConfig       *GlobalConfig
-	Locations          []Location
-	Exchanges          []Exchange
-	Contributors       []Contributor
-	Tenants            []Tenant
-	Devices            []Device
-	Links              []Link
-	Users              []User
-	MulticastGroups    []MulticastGroup
-	ProgramConfig      *ProgramConfig
-	AccessPasses       []AccessPass
-	ResourceExtensions []ResourceExtension
-	Permissions        []Permission
-	Topologies         []TopologyInfo
-	Feeds              []Feed
+	GlobalState           *GlobalState
+	GlobalConfig          *GlobalConfig
+	Locations             []Location
+	Exchanges             []Exchange
+	Contributors          []Contributor
+	Tenants               []Tenant
+	Devices               []Device
+	Links                 []Link
+	Users                 []User
+	MulticastGroups       []MulticastGroup
+	ProgramConfig         *ProgramConfig
+	AccessPasses          []AccessPass
+	ResourceExtensions    []ResourceExtension
+	Permissions           []Permission
+	Topologies            []TopologyInfo
+	Feeds                 []Feed
+	QosProfiles           []QosProfile
+	InterfaceOperStatuses []InterfaceOperStatus
+	AnycastPrefixes       []AnycastPrefix
+	ReferrerStats         []ReferrerStats
+	StateCommitments      []StateCommitment
 }
 
 func New(rpc RPCClient, programID solana.PublicKey) *Client {
@@ -50,19 +55,24 @@ func (c *Client) GetProgramData(ctx context.Context) (*ProgramData, error) {
 	}
 
 	pd := &ProgramData{
-		Locations:          []Location{},
-		Exchanges:          []Exchange{},
-		Contributors:       []Contributor{},
-		Tenants:            []Tenant{},
-		Devices:            []Device{},
-		Links:              []Link{},
-		Users:              []User{},
-		MulticastGroups:    []MulticastGroup{},
-		AccessPasses:       []AccessPass{},
-		ResourceExtensions: []ResourceExtension{},
-		Permissions:        []Permission{},
-		Topologies:         []TopologyInfo{},
-		Feeds:              []Feed{},
+		Locations:             []Location{},
+		Exchanges:             []Exchange{},
+		Contributors:          []Contributor{},
+		Tenants:               []Tenant{},
+		Devices:               []Device{},
+		Links:                 []Link{},
+		Users:                 []User{},
+		MulticastGroups:       []MulticastGroup{},
+		AccessPasses:          []AccessPass{},
+		ResourceExtensions:    []ResourceExtension{},
+		Permissions:           []Permission{},
+		Topologies:            []TopologyInfo{},
+		Feeds:                 []Feed{},
+		QosProfiles:           []QosProfile{},
+		InterfaceOperStatuses: []InterfaceOperStatus{},
+		AnycastPrefixes:       []AnycastPrefix{},
+		ReferrerStats:         []ReferrerStats{},
+		StateCommitments:      []StateCommitment{},
 	}
 
 	for _, element := range out {
@@ -152,6 +162,31 @@ func (c *Client) GetProgramData(ctx context.Context) (*ProgramData, error) {
 			DeserializeFeed(reader, &feed)
 			feed.PubKey = element.Pubkey
 			pd.Feeds = append(pd.Feeds, feed)
+		case QosProfileType:
+			var q QosProfile
+			DeserializeQosProfile(reader, &q)
+			q.PubKey = element.Pubkey
+			pd.QosProfiles = append(pd.QosProfiles, q)
+		case InterfaceOperStatusType:
+			var s InterfaceOperStatus
+			DeserializeInterfaceOperStatus(reader, &s)
+			s.PubKey = element.Pubkey
+			pd.InterfaceOperStatuses = append(pd.InterfaceOperStatuses, s)
+		case AnycastPrefixType:
+			var a AnycastPrefix
+			DeserializeAnycastPrefix(reader, &a)
+			a.PubKey = element.Pubkey
+			pd.AnycastPrefixes = append(pd.AnycastPrefixes, a)
+		case ReferrerStatsType:
+			var r ReferrerStats
+			DeserializeReferrerStats(reader, &r)
+			r.PubKey = element.Pubkey
+			pd.ReferrerStats = append(pd.ReferrerStats, r)
+		case StateCommitmentType:
+			var s StateCommitment
+			DeserializeStateCommitment(reader, &s)
+			s.PubKey = element.Pubkey
+			pd.StateCommitments = append(pd.StateCommitments, s)
 		}
 	}
 
@@ -188,19 +188,24 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 					MulticastPublisherBlock: [5]byte{147, 51, 126, 0, 23},
 					PubKey:                  pubkeys[0],
 				},
-				Locations:          []Location{},
-				Devices:            []Device{},
-				Links:              []Link{},
-				Users:              []User{},
-				Exchanges:          []Exchange{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Devices:               []Device{},
+				Links:                 []Link{},
+				Users:                 []User{},
+				Exchanges:             []Exchange{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -232,18 +237,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey: pubkeys[1],
 					},
 				},
-				Locations:          []Location{},
-				Devices:            []Device{},
-				Links:              []Link{},
-				Users:              []User{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Devices:               []Device{},
+				Links:                 []Link{},
+				Users:                 []User{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -324,18 +334,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey: pubkeys[2],
 					},
 				},
-				Locations:          []Location{},
-				Exchanges:          []Exchange{},
-				Links:              []Link{},
-				Users:              []User{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Exchanges:             []Exchange{},
+				Links:                 []Link{},
+				Users:                 []User{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -360,18 +375,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey:         pubkeys[3],
 					},
 				},
-				Exchanges:          []Exchange{},
-				Devices:            []Device{},
-				Links:              []Link{},
-				Users:              []User{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Exchanges:             []Exchange{},
+				Devices:               []Device{},
+				Links:                 []Link{},
+				Users:                 []User{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -398,18 +418,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey:         pubkeys[4],
 					},
 				},
-				Locations:          []Location{},
-				Devices:            []Device{},
-				Links:              []Link{},
-				Exchanges:          []Exchange{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Devices:               []Device{},
+				Links:                 []Link{},
+				Exchanges:             []Exchange{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -443,18 +468,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey:            pubkeys[5],
 					},
 				},
-				Locations:          []Location{},
-				Devices:            []Device{},
-				Exchanges:          []Exchange{},
-				Users:              []User{},
-				Contributors:       []Contributor{},
-				Tenants:            []Tenant{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Devices:               []Device{},
+				Exchanges:             []Exchange{},
+				Users:                 []User{},
+				Contributors:          []Contributor{},
+				Tenants:               []Tenant{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -485,11 +515,16 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey:          pubkeys[6],
 					},
 				},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -518,18 +553,23 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						PubKey:        pubkeys[7],
 					},
 				},
-				Locations:          []Location{},
-				Devices:            []Device{},
-				Links:              []Link{},
-				Exchanges:          []Exchange{},
-				Users:              []User{},
-				Contributors:       []Contributor{},
-				MulticastGroups:    []MulticastGroup{},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				Locations:             []Location{},
+				Devices:               []Device{},
+				Links:                 []Link{},
+				Exchanges:             []Exchange{},
+				Users:                 []User{},
+				Contributors:          []Contributor{},
+				MulticastGroups:       []MulticastGroup{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 		{
@@ -554,11 +594,16 @@ func TestSDK_Serviceability_GetProgramData(t *testing.T) {
 						Patch: 3,
 					},
 				},
-				AccessPasses:       []AccessPass{},
-				ResourceExtensions: []ResourceExtension{},
-				Permissions:        []Permission{},
-				Topologies:         []TopologyInfo{},
-				Feeds:              []Feed{},
+				AccessPasses:          []AccessPass{},
+				ResourceExtensions:    []ResourceExtension{},
+				Permissions:           []Permission{},
+				Topologies:            []TopologyInfo{},
+				Feeds:                 []Feed{},
+				QosProfiles:           []QosProfile{},
+				InterfaceOperStatuses: []InterfaceOperStatus{},
+				AnycastPrefixes:       []AnycastPrefix{},
+				ReferrerStats:         []ReferrerStats{},
+				StateCommitments:      []StateCommitment{},
 			},
 		},
 	}
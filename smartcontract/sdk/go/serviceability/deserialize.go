@@ -20,6 +20,7 @@ func DeserializeGlobalState(reader *ByteReader, gs *GlobalState) {
 	gs.QAAllowlist = reader.ReadPubkeySlice()
 	gs.FeatureFlags = reader.ReadU128()
 	gs.FeedAuthorityPK = reader.ReadPubkey()
+	gs.Paused = reader.ReadU64()
 }
 
 func DeserializeGlobalConfig(reader *ByteReader, cfg *GlobalConfig) {
@@ -255,6 +256,19 @@ func DeserializeDevice(reader *ByteReader, dev *Device) {
 		for i := uint32(0); i < newLen; i++ {
 			DeserializeInterfaceSized(reader, &dev.Interfaces[i])
 		}
+		// Accounts written before config generations existed end here; both read as 0.
+		dev.ConfigGeneration = reader.ReadU64()
+		dev.AppliedConfigGeneration = reader.ReadU64()
+		// Likewise for the metrics publisher rotation fields, which read as zero values.
+		dev.PreviousMetricsPublisherPubKey = reader.ReadPubkey()
+		dev.MetricsPublisherOverlapUntilEpoch = reader.ReadU64()
+		// And for the key attestation fields.
+		dev.AttestationChallenge = reader.ReadPubkey()
+		dev.AttestedKey = reader.ReadPubkey()
+		// And for the ops lock, which reads as unlocked.
+		DeserializeOpsLock(reader, &dev.OpsLock)
+		// And for the co-owner, which reads as none.
+		dev.CoOwnerPubKey = reader.ReadPubkey()
 	}
 	// Note: dev.PubKey is set separately in client.go after deserialization
 }
@@ -283,6 +297,17 @@ func DeserializeLink(reader *ByteReader, link *Link) {
 	link.LinkDesiredStatus = LinkDesiredStatus(reader.ReadU8())
 	link.LinkTopologies = reader.ReadPubkeySlice()
 	link.LinkFlags = reader.ReadU32()
+	link.FlapCount = reader.ReadU32()
+	link.FlapPenalty = reader.ReadU32()
+	link.FlapEpoch = reader.ReadU64()
+	link.DampedUntilEpoch = reader.ReadU64()
+	DeserializeOpsLock(reader, &link.OpsLock)
+}
+
+func DeserializeOpsLock(reader *ByteReader, lock *OpsLock) {
+	lock.LockedBy = reader.ReadPubkey()
+	lock.ExpiresAt = reader.ReadI64()
+	lock.Reason = reader.ReadString()
 }
 
 func DeserializeUser(reader *ByteReader, user *User) {
@@ -313,6 +338,8 @@ func DeserializeUser(reader *ByteReader, user *User) {
 	// ReadPubkey returns the zero pubkey on EOF, so old accounts that predate FeedPk deserialize
 	// with it defaulted — matches the Rust append-only contract.
 	user.FeedPk = reader.ReadPubkey()
+	// ReadU8 returns 0 (unshaped) on EOF for accounts that predate BandwidthTier.
+	user.BandwidthTier = BandwidthTier(reader.ReadU8())
 	// Note: user.PubKey is set separately in client.go after deserialization
 }
 
@@ -397,6 +424,9 @@ func DeserializeAccessPass(reader *ByteReader, ap *AccessPass) {
 				}
 			}
 		}
+	case AccessPassTypeLeased:
+		// Leased carries the deposit it holds, in lamports.
+		ap.LeaseDeposit = reader.ReadU64()
 	}
 	// Prepaid carries no associated data.
 	ap.ClientIp = reader.ReadIPv4()
@@ -413,6 +443,10 @@ func DeserializeAccessPass(reader *ByteReader, ap *AccessPass) {
 	ap.MaxUnicastUsers = reader.ReadU16OrDefault(1)
 	ap.MulticastUserCount = reader.ReadU16()
 	ap.MaxMulticastUsers = reader.ReadU16OrDefault(1)
+	// ReadU8 returns 0 (unshaped) on EOF for accounts that predate BandwidthTier.
+	ap.BandwidthTier = BandwidthTier(reader.ReadU8())
+	// ReadPubkey returns zero on EOF for accounts that predate referrals.
+	ap.Referrer = reader.ReadPubkey()
 }
 
 // ResourceExtension binary layout (from Rust):
@@ -497,6 +531,76 @@ func DeserializeTopologyInfo(reader *ByteReader, t *TopologyInfo) {
 	// Note: t.PubKey is set from the account address in client.go after deserialization
 }
 
+func DeserializeQosProfile(reader *ByteReader, q *QosProfile) {
+	q.AccountType = AccountType(reader.ReadU8())
+	q.Owner = reader.ReadPubkey()
+	q.BumpSeed = reader.ReadU8()
+	q.BandwidthTier = BandwidthTier(reader.ReadU8())
+	q.ProfileID = reader.ReadU16()
+	q.RateMbps = reader.ReadU32()
+	q.BurstKbytes = reader.ReadU32()
+	q.DSCP = reader.ReadU8()
+	// Note: q.PubKey is set from the account address in client.go after deserialization
+}
+
+func DeserializeInterfaceOperStatus(reader *ByteReader, s *InterfaceOperStatus) {
+	s.AccountType = AccountType(reader.ReadU8())
+	s.Owner = reader.ReadPubkey()
+	s.BumpSeed = reader.ReadU8()
+	s.DevicePubKey = reader.ReadPubkey()
+	s.UpdatedAt = reader.ReadI64()
+	length := reader.ReadU32()
+	// Each entry is at least 53 bytes (empty name); bail on a corrupt length.
+	if length == 0 || length*53 > reader.Remaining() {
+		return
+	}
+	s.Interfaces = make([]InterfaceOperStatusEntry, length)
+	for i := range s.Interfaces {
+		e := &s.Interfaces[i]
+		e.Name = reader.ReadString()
+		e.OperStatus = InterfaceOperState(reader.ReadU8())
+		e.RxPowerMdbm = int32(reader.ReadU32())
+		e.TxPowerMdbm = int32(reader.ReadU32())
+		e.InErrors = reader.ReadU64()
+		e.OutErrors = reader.ReadU64()
+		e.InDiscards = reader.ReadU64()
+		e.OutDiscards = reader.ReadU64()
+		e.UpdatedAt = reader.ReadI64()
+	}
+	// Note: s.PubKey is set from the account address in client.go after deserialization
+}
+
+func DeserializeAnycastPrefix(reader *ByteReader, a *AnycastPrefix) {
+	a.AccountType = AccountType(reader.ReadU8())
+	a.Owner = reader.ReadPubkey()
+	a.BumpSeed = reader.ReadU8()
+	a.Code = reader.ReadString()
+	a.Prefix = reader.ReadNetworkV4()
+	a.Enabled = (reader.ReadU8() != 0)
+	// Note: a.PubKey is set from the account address in client.go after deserialization
+}
+
+func DeserializeReferrerStats(reader *ByteReader, r *ReferrerStats) {
+	r.AccountType = AccountType(reader.ReadU8())
+	r.Referrer = reader.ReadPubkey()
+	r.BumpSeed = reader.ReadU8()
+	r.ReferralCount = reader.ReadU64()
+	r.FirstReferralEpoch = reader.ReadU64()
+	r.LastReferralEpoch = reader.ReadU64()
+	// Note: r.PubKey is set from the account address in client.go after deserialization
+}
+
+func DeserializeStateCommitment(reader *ByteReader, s *StateCommitment) {
+	s.AccountType = AccountType(reader.ReadU8())
+	s.BumpSeed = reader.ReadU8()
+	s.Slot = reader.ReadU64()
+	s.MerkleRoot = reader.ReadPubkey()
+	s.AccountCount = reader.ReadU32()
+	s.RecordedBy = reader.ReadPubkey()
+	s.RecordedAtSlot = reader.ReadU64()
+	// Note: s.PubKey is set from the account address in client.go after deserialization
+}
+
 func DeserializeFeed(reader *ByteReader, feed *Feed) {
 	feed.AccountType = AccountType(reader.ReadU8())
 	feed.Owner = reader.ReadPubkey()
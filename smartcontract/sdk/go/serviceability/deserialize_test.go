@@ -230,6 +230,57 @@ func TestDeserializeDevice_TrailingLengthMismatchSetsError(t *testing.T) {
 	assert.Contains(t, dev.DeserializeError.Error(), "interfaces length 1 != deprecated_interfaces length 2")
 }
 
+func TestDeserializeDevice_ConfigGeneration(t *testing.T) {
+	var b deviceBuilder
+	b.writeDevice(1,
+		func(bb *deviceBuilder) {
+			writeLegacyInterfaceV2(bb, "Eth1")
+		},
+		func(bb *deviceBuilder) {
+			bb.writeU32(1)
+			writeNewInterfaceSized(bb, "Eth1")
+			bb.writeU64(9)              // config_generation
+			bb.writeU64(7)              // applied_config_generation
+			bb.writePubkey([32]byte{1}) // previous_metrics_publisher_pk
+			bb.writeU64(12)             // metrics_publisher_overlap_until_epoch
+			bb.writePubkey([32]byte{3}) // attestation_challenge
+			bb.writePubkey([32]byte{2}) // attested_key
+		},
+	)
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var dev serviceability.Device
+	serviceability.DeserializeDevice(r, &dev)
+	require.NoError(t, dev.DeserializeError)
+	assert.Equal(t, uint64(9), dev.ConfigGeneration)
+	assert.Equal(t, uint64(7), dev.AppliedConfigGeneration)
+	assert.True(t, dev.IsConfigLagging())
+	assert.Equal(t, [32]byte{1}, dev.PreviousMetricsPublisherPubKey)
+	assert.Equal(t, uint64(12), dev.MetricsPublisherOverlapUntilEpoch)
+	assert.Equal(t, [32]byte{3}, dev.AttestationChallenge)
+	assert.Equal(t, [32]byte{2}, dev.AttestedKey)
+	assert.False(t, dev.IsKeyAttested())
+
+	// Accounts written before the generation fields existed read as zero.
+	var legacy deviceBuilder
+	legacy.writeDevice(1,
+		func(bb *deviceBuilder) {
+			writeLegacyInterfaceV2(bb, "Eth1")
+		},
+		func(bb *deviceBuilder) {
+			bb.writeU32(1)
+			writeNewInterfaceSized(bb, "Eth1")
+		},
+	)
+
+	r = serviceability.NewByteReader(legacy.buf.Bytes())
+	dev = serviceability.Device{}
+	serviceability.DeserializeDevice(r, &dev)
+	require.NoError(t, dev.DeserializeError)
+	assert.Equal(t, uint64(0), dev.ConfigGeneration)
+	assert.False(t, dev.IsConfigLagging())
+}
+
 func TestDeserializeInterfaceSized_FutureVersionSkipsTrailingBytes(t *testing.T) {
 	// Forge an element with version=5 and 8 trailing junk bytes appended past
 	// the known body. The reader should advance past start+size and leave the
@@ -262,3 +313,137 @@ func TestDeserializeInterfaceSized_FutureVersionSkipsTrailingBytes(t *testing.T)
 	// Body fields up to known shape are still parsed.
 	assert.Equal(t, "Future1", dev.Interfaces[0].Name)
 }
+
+func TestDeserializeQosProfile(t *testing.T) {
+	owner := [32]byte{7}
+	var b deviceBuilder
+	b.writeU8(uint8(serviceability.QosProfileType))
+	b.writeBytes(owner[:])
+	b.writeU8(254)                                        // bump_seed
+	b.writeU8(uint8(serviceability.BandwidthTierPremium)) // bandwidth_tier
+	b.writeU16(3)                                         // profile_id
+	b.writeU32(10_000)                                    // rate_mbps
+	b.writeU32(512)                                       // burst_kbytes
+	b.writeU8(46)                                         // dscp
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var q serviceability.QosProfile
+	serviceability.DeserializeQosProfile(r, &q)
+	assert.Equal(t, serviceability.QosProfileType, q.AccountType)
+	assert.Equal(t, owner, q.Owner)
+	assert.Equal(t, serviceability.BandwidthTierPremium, q.BandwidthTier)
+	assert.Equal(t, uint16(3), q.ProfileID)
+	assert.Equal(t, uint32(10_000), q.RateMbps)
+	assert.Equal(t, uint32(512), q.BurstKbytes)
+	assert.Equal(t, uint8(46), q.DSCP)
+	assert.Equal(t, uint32(0), r.Remaining())
+}
+
+func TestDeserializeInterfaceOperStatus(t *testing.T) {
+	owner := [32]byte{7}
+	device := [32]byte{9}
+	rxPower := int32(-2_500)
+	txPower := serviceability.LightLevelUnavailable
+	var b deviceBuilder
+	b.writeU8(uint8(serviceability.InterfaceOperStatusType))
+	b.writeBytes(owner[:])
+	b.writeU8(254)             // bump_seed
+	b.writeBytes(device[:])    // device_pk
+	b.writeU64(1_760_000_000)  // updated_at
+	b.writeU32(1)              // interfaces len
+	b.writeString("Ethernet1") // name
+	b.writeU8(uint8(serviceability.InterfaceOperStateUp))
+	b.writeU32(uint32(rxPower)) // rx_power_mdbm
+	b.writeU32(uint32(txPower)) // tx_power_mdbm
+	b.writeU64(3)               // in_errors
+	b.writeU64(0)               // out_errors
+	b.writeU64(12)              // in_discards
+	b.writeU64(1)               // out_discards
+	b.writeU64(1_760_000_000)   // updated_at
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var s serviceability.InterfaceOperStatus
+	serviceability.DeserializeInterfaceOperStatus(r, &s)
+	assert.Equal(t, serviceability.InterfaceOperStatusType, s.AccountType)
+	assert.Equal(t, owner, s.Owner)
+	assert.Equal(t, device, s.DevicePubKey)
+	assert.Equal(t, int64(1_760_000_000), s.UpdatedAt)
+	require.Len(t, s.Interfaces, 1)
+	entry := s.Interfaces[0]
+	assert.Equal(t, "Ethernet1", entry.Name)
+	assert.Equal(t, serviceability.InterfaceOperStateUp, entry.OperStatus)
+	assert.Equal(t, rxPower, entry.RxPowerMdbm)
+	assert.Equal(t, serviceability.LightLevelUnavailable, entry.TxPowerMdbm)
+	assert.Equal(t, uint64(3), entry.InErrors)
+	assert.Equal(t, uint64(12), entry.InDiscards)
+	assert.Equal(t, uint64(1), entry.OutDiscards)
+	assert.Equal(t, uint32(0), r.Remaining())
+}
+
+func TestDeserializeAnycastPrefix(t *testing.T) {
+	owner := [32]byte{7}
+	var b deviceBuilder
+	b.writeU8(uint8(serviceability.AnycastPrefixType))
+	b.writeBytes(owner[:])
+	b.writeU8(253) // bump_seed
+	b.writeString("dns")
+	b.writeNetworkV4([5]byte{100, 64, 255, 53, 32})
+	b.writeBool(true) // enabled
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var a serviceability.AnycastPrefix
+	serviceability.DeserializeAnycastPrefix(r, &a)
+	assert.Equal(t, serviceability.AnycastPrefixType, a.AccountType)
+	assert.Equal(t, owner, a.Owner)
+	assert.Equal(t, "dns", a.Code)
+	assert.Equal(t, [5]byte{100, 64, 255, 53, 32}, a.Prefix)
+	assert.True(t, a.Enabled)
+	assert.Equal(t, uint32(0), r.Remaining())
+}
+
+func TestDeserializeStateCommitment(t *testing.T) {
+	root := [32]byte{7, 7, 7}
+	recordedBy := [32]byte{9}
+	var b deviceBuilder
+	b.writeU8(uint8(serviceability.StateCommitmentType))
+	b.writeU8(253)  // bump_seed
+	b.writeU64(900) // slot
+	b.writePubkey(root)
+	b.writeU32(42) // account_count
+	b.writePubkey(recordedBy)
+	b.writeU64(910) // recorded_at_slot
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var s serviceability.StateCommitment
+	serviceability.DeserializeStateCommitment(r, &s)
+	assert.Equal(t, serviceability.StateCommitmentType, s.AccountType)
+	assert.Equal(t, uint8(253), s.BumpSeed)
+	assert.Equal(t, uint64(900), s.Slot)
+	assert.Equal(t, root, s.MerkleRoot)
+	assert.Equal(t, uint32(42), s.AccountCount)
+	assert.Equal(t, recordedBy, s.RecordedBy)
+	assert.Equal(t, uint64(910), s.RecordedAtSlot)
+	assert.Equal(t, uint32(0), r.Remaining())
+}
+
+func TestDeserializeReferrerStats(t *testing.T) {
+	referrer := [32]byte{9}
+	var b deviceBuilder
+	b.writeU8(uint8(serviceability.ReferrerStatsType))
+	b.writeBytes(referrer[:])
+	b.writeU8(252)  // bump_seed
+	b.writeU64(7)   // referral_count
+	b.writeU64(100) // first_referral_epoch
+	b.writeU64(140) // last_referral_epoch
+
+	r := serviceability.NewByteReader(b.buf.Bytes())
+	var s serviceability.ReferrerStats
+	serviceability.DeserializeReferrerStats(r, &s)
+	assert.Equal(t, serviceability.ReferrerStatsType, s.AccountType)
+	assert.Equal(t, referrer, s.Referrer)
+	assert.Equal(t, uint8(252), s.BumpSeed)
+	assert.Equal(t, uint64(7), s.ReferralCount)
+	assert.Equal(t, uint64(100), s.FirstReferralEpoch)
+	assert.Equal(t, uint64(140), s.LastReferralEpoch)
+	assert.Equal(t, uint32(0), r.Remaining())
+}
@@ -16,11 +16,12 @@ import (
 )
 
 const (
-	instructionCreateUser       = 36
-	instructionDeleteUser       = 42
-	instructionSetDeviceHealth  = 83
-	instructionSetLinkHealth    = 84
-	instructionSetUserBGPStatus = 106
+	instructionCreateUser             = 36
+	instructionDeleteUser             = 42
+	instructionSetDeviceHealth        = 83
+	instructionSetLinkHealth          = 84
+	instructionSetUserBGPStatus       = 106
+	instructionSetInterfaceOperStatus = 133
 )
 
 var (
@@ -427,12 +428,16 @@ type UserBGPStatusUpdate struct {
 // SetUserBGPStatus submits a SetUserBGPStatus instruction for a single user.
 // The executor's signer must be the device's metrics_publisher_pk.
 func (e *Executor) SetUserBGPStatus(ctx context.Context, u UserBGPStatusUpdate) (solana.Signature, error) {
-	instr := e.buildSetUserBGPStatusInstruction(u.UserPubkey, u.DevicePubkey, u.Status, u.BgpRttNs)
+	globalStatePDA, _, err := GetGlobalStatePDA(e.programID)
+	if err != nil {
+		return solana.Signature{}, fmt.Errorf("derive globalstate PDA: %w", err)
+	}
+	instr := e.buildSetUserBGPStatusInstruction(u.UserPubkey, u.DevicePubkey, globalStatePDA, u.Status, u.BgpRttNs)
 	sig, _, err := e.executeTransaction(ctx, []solana.Instruction{instr})
 	return sig, err
 }
 
-func (e *Executor) buildSetUserBGPStatusInstruction(userPubkey, devicePubkey solana.PublicKey, status BGPStatus, bgpRttNs uint64) solana.Instruction {
+func (e *Executor) buildSetUserBGPStatusInstruction(userPubkey, devicePubkey, globalStatePubkey solana.PublicKey, status BGPStatus, bgpRttNs uint64) solana.Instruction {
 	data := make([]byte, 10)
 	data[0] = instructionSetUserBGPStatus
 	data[1] = byte(status)
@@ -444,6 +449,9 @@ func (e *Executor) buildSetUserBGPStatusInstruction(userPubkey, devicePubkey sol
 			solana.Meta(devicePubkey),
 			solana.Meta(e.signer.PublicKey()).SIGNER().WRITE(),
 			solana.Meta(solana.SystemProgramID),
+			// Optional trailing account read by the program's pause check;
+			// programs built before the pause ignore it.
+			solana.Meta(globalStatePubkey),
 		},
 		data: data,
 	}
@@ -475,6 +483,52 @@ func (e *Executor) buildSetLinkHealthInstruction(linkPubkey, globalStatePubkey s
 	}
 }
 
+// SetInterfaceOperStatus records what a device reports for some of its interfaces, creating
+// the device's InterfaceOperStatus account on first use. Entries replace any existing entry with
+// the same name; UpdatedAt is ignored and stamped onchain.
+func (e *Executor) SetInterfaceOperStatus(ctx context.Context, devicePubkey, globalStatePubkey solana.PublicKey, entries []InterfaceOperStatusEntry) (solana.Signature, error) {
+	instr, err := e.buildSetInterfaceOperStatusInstruction(devicePubkey, globalStatePubkey, entries)
+	if err != nil {
+		return solana.Signature{}, err
+	}
+	sig, _, err := e.executeTransaction(ctx, []solana.Instruction{instr})
+	return sig, err
+}
+
+func (e *Executor) buildSetInterfaceOperStatusInstruction(devicePubkey, globalStatePubkey solana.PublicKey, entries []InterfaceOperStatusEntry) (solana.Instruction, error) {
+	operStatusPDA, _, err := GetInterfaceOperStatusPDA(e.programID, devicePubkey)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive InterfaceOperStatus PDA: %w", err)
+	}
+
+	data := []byte{instructionSetInterfaceOperStatus}
+	data = binary.LittleEndian.AppendUint32(data, uint32(len(entries)))
+	for _, entry := range entries {
+		data = binary.LittleEndian.AppendUint32(data, uint32(len(entry.Name)))
+		data = append(data, entry.Name...)
+		data = append(data, byte(entry.OperStatus))
+		data = binary.LittleEndian.AppendUint32(data, uint32(entry.RxPowerMdbm))
+		data = binary.LittleEndian.AppendUint32(data, uint32(entry.TxPowerMdbm))
+		data = binary.LittleEndian.AppendUint64(data, entry.InErrors)
+		data = binary.LittleEndian.AppendUint64(data, entry.OutErrors)
+		data = binary.LittleEndian.AppendUint64(data, entry.InDiscards)
+		data = binary.LittleEndian.AppendUint64(data, entry.OutDiscards)
+		data = binary.LittleEndian.AppendUint64(data, uint64(entry.UpdatedAt))
+	}
+
+	return &genericInstruction{
+		programID: e.programID,
+		accounts: solana.AccountMetaSlice{
+			solana.Meta(operStatusPDA).WRITE(),
+			solana.Meta(devicePubkey),
+			solana.Meta(globalStatePubkey),
+			solana.Meta(e.signer.PublicKey()).SIGNER().WRITE(),
+			solana.Meta(solana.SystemProgramID),
+		},
+		data: data,
+	}, nil
+}
+
 type genericInstruction struct {
 	programID solana.PublicKey
 	accounts  solana.AccountMetaSlice
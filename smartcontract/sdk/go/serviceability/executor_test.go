@@ -270,20 +270,23 @@ func TestBuildSetUserBGPStatusInstruction(t *testing.T) {
 
 	userPubkey := solana.NewWallet().PublicKey()
 	devicePubkey := solana.NewWallet().PublicKey()
+	globalStatePubkey := solana.NewWallet().PublicKey()
 	const rttNs uint64 = 7_500_000 // 7.5 ms
 
-	instruction := executor.buildSetUserBGPStatusInstruction(userPubkey, devicePubkey, BGPStatusUp, rttNs)
+	instruction := executor.buildSetUserBGPStatusInstruction(userPubkey, devicePubkey, globalStatePubkey, BGPStatusUp, rttNs)
 
 	assert.Equal(t, executor.programID, instruction.ProgramID())
 
 	accounts := instruction.Accounts()
-	require.Len(t, accounts, 4)
+	require.Len(t, accounts, 5)
 	assert.Equal(t, userPubkey, accounts[0].PublicKey)
 	assert.True(t, accounts[0].IsWritable)
 	assert.Equal(t, devicePubkey, accounts[1].PublicKey)
 	assert.Equal(t, signer.PublicKey(), accounts[2].PublicKey)
 	assert.True(t, accounts[2].IsSigner)
 	assert.Equal(t, solana.SystemProgramID, accounts[3].PublicKey)
+	assert.Equal(t, globalStatePubkey, accounts[4].PublicKey)
+	assert.False(t, accounts[4].IsWritable)
 
 	data, err := instruction.Data()
 	require.NoError(t, err)
@@ -293,6 +296,50 @@ func TestBuildSetUserBGPStatusInstruction(t *testing.T) {
 	assert.Equal(t, rttNs, binary.LittleEndian.Uint64(data[2:]))
 }
 
+func TestBuildSetInterfaceOperStatusInstruction(t *testing.T) {
+	t.Parallel()
+
+	rpc := &mockRPCClient{}
+	executor, signer := newTestExecutor(t, rpc)
+
+	devicePubkey := solana.NewWallet().PublicKey()
+	globalStatePubkey := solana.NewWallet().PublicKey()
+	operStatusPDA, _, err := GetInterfaceOperStatusPDA(executor.programID, devicePubkey)
+	require.NoError(t, err)
+
+	instruction, err := executor.buildSetInterfaceOperStatusInstruction(devicePubkey, globalStatePubkey, []InterfaceOperStatusEntry{{
+		Name:        "Ethernet1",
+		OperStatus:  InterfaceOperStateLowerLayerDown,
+		RxPowerMdbm: -2_500,
+		TxPowerMdbm: LightLevelUnavailable,
+		InErrors:    7,
+	}})
+	require.NoError(t, err)
+
+	accounts := instruction.Accounts()
+	require.Len(t, accounts, 5)
+	assert.Equal(t, operStatusPDA, accounts[0].PublicKey)
+	assert.True(t, accounts[0].IsWritable)
+	assert.Equal(t, devicePubkey, accounts[1].PublicKey)
+	assert.False(t, accounts[1].IsWritable)
+	assert.Equal(t, globalStatePubkey, accounts[2].PublicKey)
+	assert.Equal(t, signer.PublicKey(), accounts[3].PublicKey)
+	assert.True(t, accounts[3].IsSigner)
+	assert.Equal(t, solana.SystemProgramID, accounts[4].PublicKey)
+
+	data, err := instruction.Data()
+	require.NoError(t, err)
+	require.Len(t, data, 1+4+(4+9)+1+4+4+5*8, "opcode + vec len + one entry")
+	assert.Equal(t, byte(instructionSetInterfaceOperStatus), data[0])
+	assert.Equal(t, uint32(1), binary.LittleEndian.Uint32(data[1:]))
+	assert.Equal(t, uint32(9), binary.LittleEndian.Uint32(data[5:]))
+	assert.Equal(t, "Ethernet1", string(data[9:18]))
+	assert.Equal(t, byte(InterfaceOperStateLowerLayerDown), data[18])
+	assert.Equal(t, int32(-2_500), int32(binary.LittleEndian.Uint32(data[19:])))
+	assert.Equal(t, LightLevelUnavailable, int32(binary.LittleEndian.Uint32(data[23:])))
+	assert.Equal(t, uint64(7), binary.LittleEndian.Uint64(data[27:]))
+}
+
 func TestBuildSetLinkHealthInstruction(t *testing.T) {
 	t.Parallel()
 
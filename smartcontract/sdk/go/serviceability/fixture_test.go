@@ -1,7 +1,9 @@
 package serviceability_test
 
 import (
+	"encoding/hex"
 	"encoding/json"
+	"fmt"
 	"os"
 	"path/filepath"
 	"runtime"
@@ -100,6 +102,32 @@ func TestFixtureDevice(t *testing.T) {
 	assert.True(t, ni1.UserTunnelEndpoint)
 	assert.Empty(t, ni1.FlexAlgoNodeSegments)
 	assert.Equal(t, expectedInterfaceSize(ni1), ni1.Size)
+
+	assert.Equal(t, uint64(9), dev.ConfigGeneration)
+	assert.Equal(t, uint64(7), dev.AppliedConfigGeneration)
+	assert.True(t, dev.IsConfigLagging())
+
+	previous := [32]byte{0x46}
+	assert.Equal(t, previous, dev.PreviousMetricsPublisherPubKey)
+	assert.Equal(t, uint64(42), dev.MetricsPublisherOverlapUntilEpoch)
+	assert.True(t, dev.IsMetricsPublisher(previous, 42))
+	assert.False(t, dev.IsMetricsPublisher(previous, 43))
+
+	challenge := [32]byte{}
+	for i := range challenge {
+		challenge[i] = 0x47
+	}
+	assert.Equal(t, challenge, dev.AttestationChallenge)
+	assert.Equal(t, [32]byte{0x48}, dev.AttestedKey)
+	assert.False(t, dev.IsKeyAttested())
+
+	assert.Equal(t, [32]byte{0x49}, dev.OpsLock.LockedBy)
+	assert.Equal(t, int64(1_700_000_000), dev.OpsLock.ExpiresAt)
+	assert.Equal(t, "optics swap", dev.OpsLock.Reason)
+	assert.True(t, dev.OpsLock.IsActive(1_699_999_999))
+	assert.False(t, dev.OpsLock.IsActive(1_700_000_000))
+
+	assert.Equal(t, [32]byte{0x4a}, dev.CoOwnerPubKey)
 }
 
 // Pre-#3667 on-disk format: legacy `interfaces` vec only, no trailing
@@ -133,6 +161,14 @@ func TestFixtureDeviceLegacy(t *testing.T) {
 	assert.Equal(t, serviceability.LoopbackTypeVpnv4, dev.Interfaces[0].LoopbackType)
 	assert.Equal(t, "Ethernet1", dev.Interfaces[1].Name)
 	assert.True(t, dev.Interfaces[1].UserTunnelEndpoint)
+	assert.Equal(t, uint64(0), dev.ConfigGeneration)
+	assert.Equal(t, uint64(0), dev.AppliedConfigGeneration)
+	assert.Equal(t, [32]byte{}, dev.PreviousMetricsPublisherPubKey)
+	assert.Equal(t, uint64(0), dev.MetricsPublisherOverlapUntilEpoch)
+	assert.Equal(t, [32]byte{}, dev.AttestationChallenge)
+	assert.Equal(t, [32]byte{}, dev.AttestedKey)
+	assert.Equal(t, serviceability.OpsLock{}, dev.OpsLock)
+	assert.Equal(t, [32]byte{}, dev.CoOwnerPubKey)
 }
 
 // Same on-disk shape as device.bin, but the last trailing-vec element is
@@ -160,6 +196,43 @@ func TestFixtureDeviceFutureVersion(t *testing.T) {
 	assert.Equal(t, expectedInterfaceSize(ni1)+8, ni1.Size)
 	assert.Equal(t, "Ethernet1", ni1.Name)
 	assert.True(t, ni1.UserTunnelEndpoint)
+
+	// Fields after the trailing vec are still read from the right offset.
+	assert.Equal(t, uint64(9), dev.ConfigGeneration)
+	assert.Equal(t, uint64(7), dev.AppliedConfigGeneration)
+	assert.Equal(t, uint64(42), dev.MetricsPublisherOverlapUntilEpoch)
+	assert.Equal(t, [32]byte{0x48}, dev.AttestedKey)
+	assert.Equal(t, "optics swap", dev.OpsLock.Reason)
+	assert.Equal(t, [32]byte{0x4a}, dev.CoOwnerPubKey)
+}
+
+func TestFixtureLink(t *testing.T) {
+	data, meta := loadFixture(t, "link")
+	require.Equal(t, "Link", meta.Name)
+
+	var link serviceability.Link
+	serviceability.DeserializeLink(serviceability.NewByteReader(data), &link)
+
+	assert.Equal(t, serviceability.LinkType, link.AccountType)
+	assert.Equal(t, "ams-fra", link.Code)
+	assert.Equal(t, serviceability.LinkHealthReadyForService, link.LinkHealth)
+	assert.Equal(t, uint32(4), link.FlapCount)
+	assert.Equal(t, uint32(3000), link.FlapPenalty)
+	assert.Equal(t, uint64(812), link.FlapEpoch)
+	assert.Equal(t, uint64(814), link.DampedUntilEpoch)
+	assert.True(t, link.IsFlapDamped())
+	assert.Equal(t, [32]byte{0x54}, link.OpsLock.LockedBy)
+	assert.Equal(t, int64(1_700_000_000), link.OpsLock.ExpiresAt)
+	assert.Equal(t, "OTDR trace", link.OpsLock.Reason)
+
+	// An account that predates flap damping lacks the trailing 24 damping bytes
+	// and the ops lock after them, and decodes with zero damping and lock state.
+	opsLockLen := 32 + 8 + 4 + len("OTDR trace")
+	var legacy serviceability.Link
+	serviceability.DeserializeLink(serviceability.NewByteReader(data[:len(data)-24-opsLockLen]), &legacy)
+	assert.Equal(t, uint32(0), legacy.FlapCount)
+	assert.Equal(t, uint64(0), legacy.DampedUntilEpoch)
+	assert.Equal(t, serviceability.OpsLock{}, legacy.OpsLock)
 }
 
 func TestFixtureAccessPass(t *testing.T) {
@@ -179,6 +252,8 @@ func TestFixtureAccessPass(t *testing.T) {
 	assert.Equal(t, uint16(4), ap.MaxUnicastUsers)
 	assert.Equal(t, uint16(1), ap.MulticastUserCount)
 	assert.Equal(t, uint16(3), ap.MaxMulticastUsers)
+	assert.Equal(t, serviceability.BandwidthTierPremium, ap.BandwidthTier)
+	assert.NotEqual(t, [32]byte{}, ap.Referrer)
 }
 
 func TestFixtureAccessPassValidator(t *testing.T) {
@@ -223,6 +298,23 @@ func TestFixtureAccessPassEdgeSeat(t *testing.T) {
 	assert.Equal(t, uint16(3), ap.MaxMulticastUsers)
 }
 
+func TestFixtureAccessPassLeased(t *testing.T) {
+	data, meta := loadFixture(t, "access_pass_leased")
+	require.Equal(t, "AccessPassLeased", meta.Name)
+
+	var ap serviceability.AccessPass
+	serviceability.DeserializeAccessPass(serviceability.NewByteReader(data), &ap)
+
+	// Leased is Rust discriminant 5 and carries the deposit in lamports.
+	assert.Equal(t, serviceability.AccessPassTypeLeased, ap.AccessPassTypeTag)
+	assert.Equal(t, uint64(5000000), ap.LeaseDeposit)
+	assert.Equal(t, [4]uint8{10, 0, 0, 60}, ap.ClientIp)
+	assert.Equal(t, byte(0xC1), ap.UserPayer[0])
+	assert.Equal(t, uint64(120), ap.LastAccessEpoch)
+	assert.Equal(t, uint16(1), ap.MaxUnicastUsers)
+	assert.Equal(t, uint16(1), ap.MaxMulticastUsers)
+}
+
 func TestFixtureFeed(t *testing.T) {
 	data, meta := loadFixture(t, "feed")
 	require.Equal(t, "Feed", meta.Name)
@@ -241,11 +333,11 @@ func TestFixtureFeed(t *testing.T) {
 	assert.Equal(t, byte(0xE3), feed.Groups[1][0])
 }
 
-// A pre-migration account (lacking the 8 trailing cap bytes) decodes with counts 0 and caps 1,
-// matching the Rust program's TryFrom unwrap_or defaults.
+// A pre-migration account (lacking the 8 trailing cap bytes and the bandwidth tier) decodes with
+// counts 0 and caps 1, matching the Rust program's TryFrom unwrap_or defaults.
 func TestFixtureAccessPassLegacyCapDefaults(t *testing.T) {
 	data, _ := loadFixture(t, "access_pass")
-	legacy := data[:len(data)-8] // drop the four u16 cap fields
+	legacy := data[:len(data)-41] // drop the four u16 cap fields, the bandwidth tier and the referrer
 
 	var ap serviceability.AccessPass
 	serviceability.DeserializeAccessPass(serviceability.NewByteReader(legacy), &ap)
@@ -254,4 +346,131 @@ func TestFixtureAccessPassLegacyCapDefaults(t *testing.T) {
 	assert.Equal(t, uint16(1), ap.MaxUnicastUsers)
 	assert.Equal(t, uint16(0), ap.MulticastUserCount)
 	assert.Equal(t, uint16(1), ap.MaxMulticastUsers)
+	assert.Equal(t, serviceability.BandwidthTierUnshaped, ap.BandwidthTier)
+	assert.Equal(t, [32]byte{}, ap.Referrer)
+}
+
+type negativeFixtureMeta struct {
+	Name           string         `json:"name"`
+	Account        string         `json:"account"`
+	AccountType    uint8          `json:"account_type"`
+	Corruption     string         `json:"corruption"`
+	CorruptedField *string        `json:"corrupted_field"`
+	Error          *string        `json:"error"`
+	Fields         []fixtureField `json:"fields"`
+}
+
+// Corrupted fixtures under negative/ carry the verdict of the Rust reader. Where Rust
+// rejects the buffer, the Go decoder must either report an error or yield a mismatched
+// AccountType (callers filter on it); otherwise the listed fields must decode the same.
+func TestFixtureNegative(t *testing.T) {
+	dir := filepath.Join(fixturesDir(), "negative")
+	manifestBytes, err := os.ReadFile(filepath.Join(dir, "manifest.json"))
+	require.NoError(t, err)
+	var manifest struct {
+		Fixtures []string `json:"fixtures"`
+	}
+	require.NoError(t, json.Unmarshal(manifestBytes, &manifest))
+	require.NotEmpty(t, manifest.Fixtures)
+
+	for _, name := range manifest.Fixtures {
+		t.Run(name, func(t *testing.T) {
+			data, err := os.ReadFile(filepath.Join(dir, name+".bin"))
+			require.NoError(t, err)
+			jsonBytes, err := os.ReadFile(filepath.Join(dir, name+".json"))
+			require.NoError(t, err)
+			var meta negativeFixtureMeta
+			require.NoError(t, json.Unmarshal(jsonBytes, &meta))
+
+			var accountType uint8
+			var decodeErr error
+			got := map[string]string{}
+			r := serviceability.NewByteReader(data)
+			switch meta.Account {
+			case "Location":
+				var loc serviceability.Location
+				serviceability.DeserializeLocation(r, &loc)
+				accountType = uint8(loc.AccountType)
+				got["AccountType"] = fmt.Sprint(uint8(loc.AccountType))
+				got["Status"] = fmt.Sprint(uint8(loc.Status))
+				got["Code"] = loc.Code
+			case "Device":
+				var dev serviceability.Device
+				serviceability.DeserializeDevice(r, &dev)
+				accountType = uint8(dev.AccountType)
+				decodeErr = dev.DeserializeError
+				got["AccountType"] = fmt.Sprint(uint8(dev.AccountType))
+				got["Status"] = fmt.Sprint(uint8(dev.Status))
+				got["Code"] = dev.Code
+			case "User":
+				var user serviceability.User
+				serviceability.DeserializeUser(r, &user)
+				accountType = uint8(user.AccountType)
+				got["AccountType"] = fmt.Sprint(uint8(user.AccountType))
+				got["UserType"] = fmt.Sprint(uint8(user.UserType))
+				got["Status"] = fmt.Sprint(uint8(user.Status))
+			default:
+				t.Fatalf("no decoder for account %q", meta.Account)
+			}
+
+			if meta.Error != nil {
+				assert.True(t, decodeErr != nil || accountType != meta.AccountType,
+					"Rust rejects %s with %s, Go decoder accepted it", name, *meta.Error)
+				return
+			}
+			for _, f := range meta.Fields {
+				assert.Equalf(t, f.Value, got[f.Name], "%s.%s", name, f.Name)
+			}
+		})
+	}
+}
+
+type matrixMeta struct {
+	Name    string `json:"name"`
+	Entries []struct {
+		Name   string         `json:"name"`
+		Data   string         `json:"data"`
+		Fields []fixtureField `json:"fields"`
+	} `json:"entries"`
+}
+
+// Enum-variant matrices under matrix/: one Device per combination of the declared axes,
+// with the field values the Rust reader decodes back.
+func TestFixtureMatrix(t *testing.T) {
+	for _, name := range []string{"device_matrix", "interface_version_matrix"} {
+		t.Run(name, func(t *testing.T) {
+			jsonBytes, err := os.ReadFile(filepath.Join(fixturesDir(), "matrix", name+".json"))
+			require.NoError(t, err)
+			var meta matrixMeta
+			require.NoError(t, json.Unmarshal(jsonBytes, &meta))
+			require.NotEmpty(t, meta.Entries)
+
+			for _, entry := range meta.Entries {
+				data, err := hex.DecodeString(entry.Data)
+				require.NoError(t, err)
+
+				var dev serviceability.Device
+				serviceability.DeserializeDevice(serviceability.NewByteReader(data), &dev)
+				require.NoErrorf(t, dev.DeserializeError, "%s", entry.Name)
+
+				got := map[string]string{
+					"AccountType":  fmt.Sprint(uint8(dev.AccountType)),
+					"DeviceType":   fmt.Sprint(uint8(dev.DeviceType)),
+					"Status":       fmt.Sprint(uint8(dev.Status)),
+					"DeviceHealth": fmt.Sprint(uint8(dev.DeviceHealth)),
+					"Code":         dev.Code,
+				}
+				if len(dev.Interfaces) > 0 {
+					got["InterfacesLen"] = fmt.Sprint(len(dev.Interfaces))
+					got["Interface0Version"] = fmt.Sprint(dev.Interfaces[0].Version)
+					got["Interface0Name"] = dev.Interfaces[0].Name
+					got["Interface0UserTunnelEndpoint"] = fmt.Sprint(dev.Interfaces[0].UserTunnelEndpoint)
+					got["Interface0FlexAlgoNodeSegmentsLen"] = fmt.Sprint(len(dev.Interfaces[0].FlexAlgoNodeSegments))
+				}
+				for _, f := range entry.Fields {
+					assert.Equalf(t, f.Value, got[f.Name], "%s.%s", entry.Name, f.Name)
+				}
+			}
+		})
+	}
 }
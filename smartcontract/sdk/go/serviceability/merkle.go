@@ -0,0 +1,55 @@
+package serviceability
+
+import (
+	"bytes"
+	"crypto/sha256"
+)
+
+// StateLeafHash is the leaf of one account in a StateCommitment tree:
+// sha256(0x00 || pubkey || data), matching the Rust program.
+func StateLeafHash(pubkey [32]byte, data []byte) [32]byte {
+	h := sha256.New()
+	h.Write([]byte{0})
+	h.Write(pubkey[:])
+	h.Write(data)
+	var out [32]byte
+	copy(out[:], h.Sum(nil))
+	return out
+}
+
+func stateNodeHash(left, right [32]byte) [32]byte {
+	h := sha256.New()
+	h.Write([]byte{1})
+	h.Write(left[:])
+	h.Write(right[:])
+	var out [32]byte
+	copy(out[:], h.Sum(nil))
+	return out
+}
+
+// VerifyStateProof checks that leaf sits at index of a tree with leafCount leaves and the given
+// root. Leaves are ordered by account pubkey, and a trailing unpaired node is carried up a level
+// unchanged, so such levels contribute no sibling to the proof.
+func VerifyStateProof(root, leaf [32]byte, index, leafCount uint64, proof [][32]byte) bool {
+	if index >= leafCount {
+		return false
+	}
+	hash := leaf
+	for leafCount > 1 {
+		sibling := index ^ 1
+		if sibling < leafCount {
+			if len(proof) == 0 {
+				return false
+			}
+			if index%2 == 0 {
+				hash = stateNodeHash(hash, proof[0])
+			} else {
+				hash = stateNodeHash(proof[0], hash)
+			}
+			proof = proof[1:]
+		}
+		index /= 2
+		leafCount = (leafCount + 1) / 2
+	}
+	return len(proof) == 0 && bytes.Equal(hash[:], root[:])
+}
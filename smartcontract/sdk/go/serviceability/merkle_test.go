@@ -0,0 +1,24 @@
+package serviceability
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestVerifyStateProof(t *testing.T) {
+	// Three leaves: ((l0, l1), l2), with l2 carried up unpaired.
+	l0 := StateLeafHash([32]byte{1}, []byte{5, 0})
+	l1 := StateLeafHash([32]byte{2}, []byte{5, 1})
+	l2 := StateLeafHash([32]byte{3}, []byte{6})
+	n01 := stateNodeHash(l0, l1)
+	root := stateNodeHash(n01, l2)
+
+	assert.True(t, VerifyStateProof(root, l0, 0, 3, [][32]byte{l1, l2}))
+	assert.True(t, VerifyStateProof(root, l1, 1, 3, [][32]byte{l0, l2}))
+	assert.True(t, VerifyStateProof(root, l2, 2, 3, [][32]byte{n01}))
+
+	assert.False(t, VerifyStateProof(root, l1, 0, 3, [][32]byte{l1, l2}))
+	assert.False(t, VerifyStateProof(root, l2, 2, 3, [][32]byte{n01, l0}))
+	assert.False(t, VerifyStateProof(root, l2, 3, 3, [][32]byte{n01}))
+}
@@ -24,6 +24,7 @@ const (
 	SeedAccessPass              = "accesspass"
 	SeedTunnelIds               = "tunnelids"
 	SeedDzPrefixBlock           = "dzprefixblock"
+	SeedInterfaceOperStatus     = "ifoperstatus"
 )
 
 // DeriveGlobalStatePDA derives the PDA for the GlobalState account.
@@ -132,6 +133,16 @@ func GetPermissionPDA(programID solana.PublicKey, userPayer solana.PublicKey) (s
 	return solana.FindProgramAddress(seeds, programID)
 }
 
+// GetInterfaceOperStatusPDA derives the PDA for a device's InterfaceOperStatus account.
+func GetInterfaceOperStatusPDA(programID solana.PublicKey, devicePubkey solana.PublicKey) (solana.PublicKey, uint8, error) {
+	seeds := [][]byte{
+		[]byte(SeedPrefix),
+		[]byte(SeedInterfaceOperStatus),
+		devicePubkey[:],
+	}
+	return solana.FindProgramAddress(seeds, programID)
+}
+
 // GetUserPDA derives the PDA for a User account, keyed by (client_ip, user_type).
 // Mirrors smartcontract/programs/doublezero-serviceability/src/pda.rs:get_user_pda.
 func GetUserPDA(programID solana.PublicKey, clientIP [4]byte, userType UserUserType) (solana.PublicKey, uint8, error) {
@@ -1,8 +1,10 @@
 package serviceability
 
 import (
+	"encoding/hex"
 	"encoding/json"
 	"fmt"
+	"math"
 	"net"
 
 	"github.com/mr-tron/base58"
@@ -29,6 +31,13 @@ const (
 	IndexType      AccountType = 16
 	TopologyType   AccountType = 17
 	FeedType       AccountType = 18
+	// 19 (ReadApiKey) is not decoded by this SDK
+	QosProfileType AccountType = 20
+	// 21 (IpAllocation) is not decoded by this SDK
+	InterfaceOperStatusType AccountType = 22
+	AnycastPrefixType       AccountType = 23
+	ReferrerStatsType       AccountType = 24
+	StateCommitmentType     AccountType = 25
 )
 
 type LocationStatus uint8
@@ -84,6 +93,7 @@ type GlobalState struct {
 	QAAllowlist                [][32]byte
 	FeatureFlags               Uint128
 	FeedAuthorityPK            [32]byte
+	Paused                     uint64
 	PubKey                     [32]byte
 }
 
@@ -206,6 +216,7 @@ const (
 	DeviceHealthReadyForLinks DeviceHealth = 2
 	DeviceHealthReadyForUsers DeviceHealth = 3
 	DeviceHealthImpaired      DeviceHealth = 4
+	DeviceHealthDriftDetected DeviceHealth = 5
 )
 
 func (d DeviceHealth) String() string {
@@ -220,6 +231,8 @@ func (d DeviceHealth) String() string {
 		return "ready_for_users"
 	case DeviceHealthImpaired:
 		return "impaired"
+	case DeviceHealthDriftDetected:
+		return "drift_detected"
 	default:
 		return fmt.Sprintf("DeviceHealth(%d)", d)
 	}
@@ -444,6 +457,34 @@ func (i Interface) MarshalJSON() ([]byte, error) {
 	return json.Marshal(jsonIface)
 }
 
+// OpsLock freezes a Device or Link against changes by anyone but LockedBy until
+// ExpiresAt (unix seconds). LockedBy is all zeros when the entity is unlocked.
+type OpsLock struct {
+	LockedBy  [32]uint8
+	ExpiresAt int64
+	Reason    string
+}
+
+// IsActive reports whether the lock is held at unix time now.
+func (o OpsLock) IsActive(now int64) bool {
+	return o.LockedBy != [32]byte{} && now < o.ExpiresAt
+}
+
+func (o OpsLock) MarshalJSON() ([]byte, error) {
+	type OpsLockAlias OpsLock
+
+	jsonLock := &struct {
+		OpsLockAlias
+		LockedBy string `json:"LockedBy"`
+	}{
+		OpsLockAlias: OpsLockAlias(o),
+	}
+
+	jsonLock.LockedBy = base58.Encode(o.LockedBy[:])
+
+	return json.Marshal(jsonLock)
+}
+
 // CurrentInterfaceVersion is the on-wire schema version of the size-prefixed
 // Interface format (matching Rust's CURRENT_INTERFACE_SCHEMA_VERSION).
 const CurrentInterfaceVersion = 4
@@ -480,6 +521,23 @@ type Device struct {
 	// accounts (no trailing bytes), this is rebuilt from DeprecatedInterfaces by DeserializeDevice.
 	// When populated from the wire, len(Interfaces) == len(DeprecatedInterfaces) is enforced.
 	Interfaces []Interface `influx:"-" json:",omitempty"`
+	// ConfigGeneration is bumped onchain by every change the controller renders for the
+	// device; AppliedConfigGeneration is the last generation the controller acknowledged.
+	ConfigGeneration        uint64 `influx:"field,config_generation"`
+	AppliedConfigGeneration uint64 `influx:"field,applied_config_generation"`
+	// PreviousMetricsPublisherPubKey is the publisher replaced by the last rotation; it is
+	// still accepted up to and including MetricsPublisherOverlapUntilEpoch.
+	PreviousMetricsPublisherPubKey    [32]uint8 `influx:"tag,previous_metrics_publisher_pubkey,pubkey"`
+	MetricsPublisherOverlapUntilEpoch uint64    `influx:"field,metrics_publisher_overlap_until_epoch"`
+	// AttestationChallenge is the pending nonce the metrics publisher must echo back
+	// (all zeros when none); AttestedKey is the key that last answered a challenge.
+	AttestationChallenge [32]uint8 `influx:"-"`
+	AttestedKey          [32]uint8 `influx:"tag,attested_key,pubkey"`
+	// OpsLock freezes the device against changes by other signers while it is live.
+	OpsLock OpsLock `influx:"-"`
+	// CoOwnerPubKey is granted the contributor owner's update rights on this device only,
+	// e.g. a managed-service provider operating it; all zeros when there is none.
+	CoOwnerPubKey [32]uint8 `influx:"tag,co_owner_pubkey,pubkey"`
 	// DeserializeError is set when DeserializeDevice encounters a recoverable but
 	// account-malformed condition (e.g. trailing interfaces length mismatch with
 	// the deprecated_interfaces vec). Consumers should check this before trusting the
@@ -488,22 +546,49 @@ type Device struct {
 	PubKey           [32]byte `influx:"tag,pubkey,pubkey"`
 }
 
+// IsMetricsPublisher reports whether key may publish for the device at epoch: the
+// current publisher, or the previous one until its overlap ends.
+func (d Device) IsMetricsPublisher(key [32]byte, epoch uint64) bool {
+	if key == d.MetricsPublisherPubKey {
+		return true
+	}
+	return d.PreviousMetricsPublisherPubKey != [32]byte{} &&
+		key == d.PreviousMetricsPublisherPubKey &&
+		epoch <= d.MetricsPublisherOverlapUntilEpoch
+}
+
+// IsKeyAttested reports whether the current metrics publisher key has answered an
+// attestation challenge.
+func (d Device) IsKeyAttested() bool {
+	return d.AttestedKey != [32]byte{} && d.AttestedKey == d.MetricsPublisherPubKey
+}
+
+// IsConfigLagging reports whether the controller has yet to acknowledge the device's
+// latest config generation.
+func (d Device) IsConfigLagging() bool {
+	return d.AppliedConfigGeneration < d.ConfigGeneration
+}
+
 func (d Device) MarshalJSON() ([]byte, error) {
 	type DeviceAlias Device
 
 	jsonDevice := &struct {
 		DeviceAlias
-		Owner                  string   `json:"Owner"`
-		LocationPubKey         string   `json:"LocationPubKey"`
-		ExchangePubKey         string   `json:"ExchangePubKey"`
-		PublicIp               string   `json:"PublicIp"`
-		DzPrefixes             []string `json:"DzPrefixes"`
-		MetricsPublisherPubKey string   `json:"MetricsPublisherPubKey"`
-		ContributorPubKey      string   `json:"ContributorPubKey"`
-		PubKey                 string   `json:"PubKey"`
-		Status                 string   `json:"Status"`
-		DeviceHealth           string   `json:"DeviceHealth"`
-		DeviceDesiredStatus    string   `json:"DeviceDesiredStatus"`
+		Owner                          string   `json:"Owner"`
+		LocationPubKey                 string   `json:"LocationPubKey"`
+		ExchangePubKey                 string   `json:"ExchangePubKey"`
+		PublicIp                       string   `json:"PublicIp"`
+		DzPrefixes                     []string `json:"DzPrefixes"`
+		MetricsPublisherPubKey         string   `json:"MetricsPublisherPubKey"`
+		PreviousMetricsPublisherPubKey string   `json:"PreviousMetricsPublisherPubKey"`
+		AttestationChallenge           string   `json:"AttestationChallenge"`
+		AttestedKey                    string   `json:"AttestedKey"`
+		CoOwnerPubKey                  string   `json:"CoOwnerPubKey"`
+		ContributorPubKey              string   `json:"ContributorPubKey"`
+		PubKey                         string   `json:"PubKey"`
+		Status                         string   `json:"Status"`
+		DeviceHealth                   string   `json:"DeviceHealth"`
+		DeviceDesiredStatus            string   `json:"DeviceDesiredStatus"`
 	}{
 		DeviceAlias: DeviceAlias(d),
 	}
@@ -512,6 +597,10 @@ func (d Device) MarshalJSON() ([]byte, error) {
 	jsonDevice.LocationPubKey = base58.Encode(d.LocationPubKey[:])
 	jsonDevice.ExchangePubKey = base58.Encode(d.ExchangePubKey[:])
 	jsonDevice.MetricsPublisherPubKey = base58.Encode(d.MetricsPublisherPubKey[:])
+	jsonDevice.PreviousMetricsPublisherPubKey = base58.Encode(d.PreviousMetricsPublisherPubKey[:])
+	jsonDevice.AttestationChallenge = hex.EncodeToString(d.AttestationChallenge[:])
+	jsonDevice.AttestedKey = base58.Encode(d.AttestedKey[:])
+	jsonDevice.CoOwnerPubKey = base58.Encode(d.CoOwnerPubKey[:])
 	jsonDevice.ContributorPubKey = base58.Encode(d.ContributorPubKey[:])
 	jsonDevice.PubKey = base58.Encode(d.PubKey[:])
 
@@ -649,6 +738,36 @@ func (b BGPStatus) MarshalJSON() ([]byte, error) {
 	return json.Marshal(b.String())
 }
 
+// BandwidthTier is the bandwidth class a user is shaped to. Unknown values
+// read as BandwidthTierUnshaped onchain.
+type BandwidthTier uint8
+
+const (
+	BandwidthTierUnshaped BandwidthTier = 0
+	BandwidthTierBasic    BandwidthTier = 1
+	BandwidthTierStandard BandwidthTier = 2
+	BandwidthTierPremium  BandwidthTier = 3
+)
+
+func (b BandwidthTier) String() string {
+	switch b {
+	case BandwidthTierUnshaped:
+		return "unshaped"
+	case BandwidthTierBasic:
+		return "basic"
+	case BandwidthTierStandard:
+		return "standard"
+	case BandwidthTierPremium:
+		return "premium"
+	default:
+		return fmt.Sprintf("BandwidthTier(%d)", b)
+	}
+}
+
+func (b BandwidthTier) MarshalJSON() ([]byte, error) {
+	return json.Marshal(b.String())
+}
+
 type LinkDesiredStatus uint8
 
 const (
@@ -701,12 +820,26 @@ type Link struct {
 	LinkDesiredStatus LinkDesiredStatus `influx:"tag,link_desired_status"`
 	LinkTopologies    [][32]byte        `json:",omitempty"`
 	LinkFlags         uint32            `json:",omitempty"`
+	FlapCount         uint32            `json:",omitempty"`
+	FlapPenalty       uint32            `json:",omitempty"`
+	FlapEpoch         uint64            `json:",omitempty"`
+	DampedUntilEpoch  uint64            `json:",omitempty"`
+	OpsLock           OpsLock           `influx:"-" json:",omitzero"`
 	PubKey            [32]byte          `influx:"tag,pubkey,pubkey"`
 }
 
 // LinkFlagUnicastDrained is set in LinkFlags when the link is marked as unicast-drained.
 const LinkFlagUnicastDrained uint32 = 0x01
 
+// LinkFlagFlapDamped is set in LinkFlags while the link is suppressed by flap
+// damping, i.e. until DampedUntilEpoch.
+const LinkFlagFlapDamped uint32 = 0x02
+
+// IsFlapDamped reports whether the link is suppressed by flap damping.
+func (l Link) IsFlapDamped() bool {
+	return l.LinkFlags&LinkFlagFlapDamped != 0
+}
+
 func (l Link) MarshalJSON() ([]byte, error) {
 	type LinkAlias Link
 
@@ -985,7 +1118,10 @@ type User struct {
 	// FeedPk is the EdgeSeat Feed whose per-feed seat this user consumed at connect (multicast
 	// only); the zero pubkey for non-EdgeSeat/unicast users.
 	FeedPk [32]byte
-	PubKey [32]byte
+	// BandwidthTier is the bandwidth class the user is shaped to; unshaped on
+	// accounts that predate the field.
+	BandwidthTier BandwidthTier
+	PubKey        [32]byte
 }
 
 func (u User) MarshalJSON() ([]byte, error) {
@@ -1103,6 +1239,7 @@ const (
 	AccessPassTypeSolanaRPC       AccessPassTypeTag = 2
 	AccessPassTypeOthers          AccessPassTypeTag = 3
 	AccessPassTypeEdgeSeat        AccessPassTypeTag = 4
+	AccessPassTypeLeased          AccessPassTypeTag = 5
 )
 
 type AccessPassStatus uint8
@@ -1154,6 +1291,7 @@ type AccessPass struct {
 	OthersTypeName     string     // for Others variant
 	OthersKey          string     // for Others variant
 	FeedSeats          []FeedSeat // for EdgeSeat variant
+	LeaseDeposit       uint64     // for Leased variant, in lamports
 	ClientIp           [4]uint8
 	UserPayer          [32]byte
 	LastAccessEpoch    uint64
@@ -1167,7 +1305,12 @@ type AccessPass struct {
 	MaxUnicastUsers    uint16
 	MulticastUserCount uint16
 	MaxMulticastUsers  uint16
-	PubKey             [32]byte
+	// BandwidthTier is given to users created under the pass, and is the
+	// highest tier they may request.
+	BandwidthTier BandwidthTier
+	// Referrer is credited with onboarding the pass holder; zero when none.
+	Referrer [32]byte
+	PubKey   [32]byte
 }
 
 func onChainNetToString(n [5]uint8) string {
@@ -1425,6 +1568,115 @@ type TopologyInfo struct {
 	PubKey         [32]byte
 }
 
+// QosProfile is the tunnel QoS profile for one shaped bandwidth tier. Controllers render
+// ProfileID as the device-side policy and attach it to the tunnels of users whose
+// BandwidthTier matches; users of a tier with no profile are left unshaped.
+type QosProfile struct {
+	AccountType   AccountType
+	Owner         [32]byte
+	BumpSeed      uint8
+	BandwidthTier BandwidthTier
+	ProfileID     uint16
+	RateMbps      uint32
+	BurstKbytes   uint32 // 0 = device default
+	DSCP          uint8
+	PubKey        [32]byte
+}
+
+type InterfaceOperState uint8
+
+const (
+	InterfaceOperStateUnknown        InterfaceOperState = 0
+	InterfaceOperStateUp             InterfaceOperState = 1
+	InterfaceOperStateDown           InterfaceOperState = 2
+	InterfaceOperStateLowerLayerDown InterfaceOperState = 3
+	InterfaceOperStateNotPresent     InterfaceOperState = 4
+)
+
+func (s InterfaceOperState) String() string {
+	switch s {
+	case InterfaceOperStateUp:
+		return "up"
+	case InterfaceOperStateDown:
+		return "down"
+	case InterfaceOperStateLowerLayerDown:
+		return "lower-layer-down"
+	case InterfaceOperStateNotPresent:
+		return "not-present"
+	default:
+		return "unknown"
+	}
+}
+
+func (s InterfaceOperState) MarshalJSON() ([]byte, error) {
+	return json.Marshal(s.String())
+}
+
+// LightLevelUnavailable is the light level of a transceiver that does not report one.
+const LightLevelUnavailable int32 = math.MinInt32
+
+// InterfaceOperStatusEntry is what a device reports for one of its interfaces. Light levels
+// are in thousandths of a dBm; counters are cumulative since the device last cleared them.
+type InterfaceOperStatusEntry struct {
+	Name        string
+	OperStatus  InterfaceOperState
+	RxPowerMdbm int32
+	TxPowerMdbm int32
+	InErrors    uint64
+	OutErrors   uint64
+	InDiscards  uint64
+	OutDiscards uint64
+	UpdatedAt   int64
+}
+
+// InterfaceOperStatus is the operational status of a device's interfaces, written by the
+// health oracle. One account per device, at the PDA derived from DevicePubKey.
+type InterfaceOperStatus struct {
+	AccountType  AccountType
+	Owner        [32]byte
+	BumpSeed     uint8
+	DevicePubKey [32]byte
+	UpdatedAt    int64
+	Interfaces   []InterfaceOperStatusEntry
+	PubKey       [32]byte
+}
+
+// AnycastPrefix is an anycast service address (DNS, NTP, internal services). Controllers
+// render every enabled prefix onto the anycast loopback of each device.
+type AnycastPrefix struct {
+	AccountType AccountType
+	Owner       [32]byte
+	BumpSeed    uint8
+	Code        string
+	Prefix      [5]byte // /32
+	Enabled     bool
+	PubKey      [32]byte
+}
+
+// ReferrerStats counts the access passes created naming a referrer.
+type ReferrerStats struct {
+	AccountType        AccountType
+	Referrer           [32]byte
+	BumpSeed           uint8
+	ReferralCount      uint64
+	FirstReferralEpoch uint64
+	LastReferralEpoch  uint64
+	PubKey             [32]byte
+}
+
+// StateCommitment is the merkle root over every serviceability account, recorded by the
+// foundation or the health oracle. See VerifyStateProof.
+type StateCommitment struct {
+	AccountType    AccountType
+	BumpSeed       uint8
+	Slot           uint64
+	MerkleRoot     [32]byte
+	AccountCount   uint32
+	RecordedBy     [32]byte
+	RecordedAtSlot uint64
+	PubKey         [32]byte
+}
+
 // Feed is a serviceability catalog entry: one SKU scoped to a single metro (Exchange), holding the
 // multicast groups joinable there. One feed_key is one feed in one metro.
 type Feed struct {
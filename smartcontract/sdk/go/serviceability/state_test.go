@@ -239,6 +239,14 @@ func TestCustomJSONMarshal(t *testing.T) {
                 "ReservedSeats": 0,
                 "MulticastPublishersCount": 0,
                 "MaxMulticastPublishers": 0,
+                "ConfigGeneration": 0,
+                "AppliedConfigGeneration": 0,
+                "PreviousMetricsPublisherPubKey": "11111111111111111111111111111111",
+                "MetricsPublisherOverlapUntilEpoch": 0,
+                "AttestationChallenge": "0000000000000000000000000000000000000000000000000000000000000000",
+                "AttestedKey": "11111111111111111111111111111111",
+                "OpsLock": {"LockedBy": "11111111111111111111111111111111", "ExpiresAt": 0, "Reason": ""},
+                "CoOwnerPubKey": "11111111111111111111111111111111",
                 "PubKey": "` + dummyPubKeyB58 + `"
             }`,
 			expectErr: false,
@@ -331,6 +339,14 @@ func TestCustomJSONMarshal(t *testing.T) {
                 "ReservedSeats": 0,
                 "MulticastPublishersCount": 0,
                 "MaxMulticastPublishers": 0,
+                "ConfigGeneration": 0,
+                "AppliedConfigGeneration": 0,
+                "PreviousMetricsPublisherPubKey": "11111111111111111111111111111111",
+                "MetricsPublisherOverlapUntilEpoch": 0,
+                "AttestationChallenge": "0000000000000000000000000000000000000000000000000000000000000000",
+                "AttestedKey": "11111111111111111111111111111111",
+                "OpsLock": {"LockedBy": "11111111111111111111111111111111", "ExpiresAt": 0, "Reason": ""},
+                "CoOwnerPubKey": "11111111111111111111111111111111",
                 "PubKey": "` + dummyPubKeyB58 + `"
             }`,
 			expectErr: false,
@@ -381,7 +397,8 @@ func TestCustomJSONMarshal(t *testing.T) {
 				"LastBgpReportedAt": 0,
 				"BgpRttNs": 0,
 				"PubKey": "` + dummyPubKeyB58 + `",
-				"FeedPk": "11111111111111111111111111111111"
+				"FeedPk": "11111111111111111111111111111111",
+				"BandwidthTier": "unshaped"
 			}`,
 			expectErr: false,
 		},
@@ -7,6 +7,7 @@ import (
 	"errors"
 	"fmt"
 	"log/slog"
+	"math"
 	"strconv"
 
 	bin "github.com/gagliardetto/binary"
@@ -19,6 +20,10 @@ var (
 	ErrAccountNotFound      = errors.New("account not found")
 	ErrSamplesBatchTooLarge = errors.New("samples batch too large")
 	ErrSamplesAccountFull   = errors.New("samples account is full")
+
+	// ErrSamplingBudgetExceeded wraps ErrSamplesAccountFull so that writers treat an exhausted
+	// provider budget the same way as a full account.
+	ErrSamplingBudgetExceeded = fmt.Errorf("provider sampling budget exceeded: %w", ErrSamplesAccountFull)
 )
 
 type Client struct {
@@ -49,6 +54,9 @@ func (c *Client) Signer() *solana.PrivateKey {
 	return c.executor.signer
 }
 
+// GetDeviceLatencySamples returns the samples the origin device recorded over a link in an
+// epoch, following the shards writes rolled over to. The header is the first shard's, with the
+// sample count of all of them and the flags of the last one.
 func (c *Client) GetDeviceLatencySamples(
 	ctx context.Context,
 	originDevicePK solana.PublicKey,
@@ -56,12 +64,49 @@ func (c *Client) GetDeviceLatencySamples(
 	linkPK solana.PublicKey,
 	epoch uint64,
 ) (*DeviceLatencySamples, error) {
-	pda, _, err := DeriveDeviceLatencySamplesPDA(
+	var stitched *DeviceLatencySamples
+	for shardIndex := 0; shardIndex <= math.MaxUint8; shardIndex++ {
+		shard, err := c.GetDeviceLatencySamplesShard(ctx, originDevicePK, targetDevicePK, linkPK, epoch, uint8(shardIndex))
+		if err != nil {
+			// A later shard may already have been closed; keep what was read.
+			if shardIndex > 0 && errors.Is(err, ErrAccountNotFound) {
+				break
+			}
+			return nil, err
+		}
+		rolledOver := shard.IsRolledOver()
+		if stitched == nil {
+			stitched = shard
+		} else {
+			stitched.Samples = append(stitched.Samples, shard.Samples...)
+			stitched.Flags = shard.Flags
+		}
+		if !rolledOver {
+			break
+		}
+	}
+
+	stitched.Flags &^= SamplesFlagRolledOver
+	stitched.NextSampleIndex = uint32(len(stitched.Samples))
+	return stitched, nil
+}
+
+// GetDeviceLatencySamplesShard returns a single shard of a device latency samples account.
+func (c *Client) GetDeviceLatencySamplesShard(
+	ctx context.Context,
+	originDevicePK solana.PublicKey,
+	targetDevicePK solana.PublicKey,
+	linkPK solana.PublicKey,
+	epoch uint64,
+	shardIndex uint8,
+) (*DeviceLatencySamples, error) {
+	pda, _, err := DeriveDeviceLatencySamplesShardPDA(
 		c.executor.programID,
 		originDevicePK,
 		targetDevicePK,
 		linkPK,
 		epoch,
+		shardIndex,
 	)
 	if err != nil {
 		return nil, fmt.Errorf("failed to derive PDA: %w", err)
@@ -103,6 +148,8 @@ func (c *Client) GetDeviceLatencySamples(
 	}
 }
 
+// GetDeviceLatencySamplesHeader returns the header of the first shard of a device latency
+// samples account.
 func (c *Client) GetDeviceLatencySamplesHeader(
 	ctx context.Context,
 	originDevicePK solana.PublicKey,
@@ -110,12 +157,26 @@ func (c *Client) GetDeviceLatencySamplesHeader(
 	linkPK solana.PublicKey,
 	epoch uint64,
 ) (*DeviceLatencySamplesHeader, error) {
-	pda, _, err := DeriveDeviceLatencySamplesPDA(
+	return c.GetDeviceLatencySamplesShardHeader(ctx, originDevicePK, targetDevicePK, linkPK, epoch, 0)
+}
+
+// GetDeviceLatencySamplesShardHeader returns the header of a single shard of a device latency
+// samples account, without reading its samples.
+func (c *Client) GetDeviceLatencySamplesShardHeader(
+	ctx context.Context,
+	originDevicePK solana.PublicKey,
+	targetDevicePK solana.PublicKey,
+	linkPK solana.PublicKey,
+	epoch uint64,
+	shardIndex uint8,
+) (*DeviceLatencySamplesHeader, error) {
+	pda, _, err := DeriveDeviceLatencySamplesShardPDA(
 		c.executor.programID,
 		originDevicePK,
 		targetDevicePK,
 		linkPK,
 		epoch,
+		shardIndex,
 	)
 	if err != nil {
 		return nil, fmt.Errorf("failed to derive PDA: %w", err)
@@ -294,6 +355,9 @@ func (c *Client) WriteDeviceLatencySamples(
 	if len(config.Samples) > MaxDeviceLatencySamplesPerBatch {
 		return solana.Signature{}, nil, ErrSamplesBatchTooLarge
 	}
+	if config.RollOver && len(config.Samples) > MaxDeviceLatencySamplesPerRollOverBatch {
+		return solana.Signature{}, nil, ErrSamplesBatchTooLarge
+	}
 
 	instruction, err := BuildWriteDeviceLatencySamplesInstruction(c.executor.programID, config)
 	if err != nil {
@@ -406,6 +470,111 @@ func (c *Client) WriteInternetLatencySamples(
 		return solana.Signature{}, nil, fmt.Errorf("failed to build instruction: %w", err)
 	}
 
+	sig, res, err := c.executor.ExecuteTransaction(ctx, instruction, nil)
+	if err != nil {
+		var rpcErr *jsonrpc.RPCError
+		if errors.As(err, &rpcErr) {
+			if data, ok := rpcErr.Data.(map[string]any); ok {
+				switch v := data["err"].(type) {
+				case string:
+					if v == "AccountNotFound" {
+						return solana.Signature{}, nil, ErrAccountNotFound
+					}
+				case map[string]any:
+					if ie, ok := v["InstructionError"].([]any); ok && len(ie) == 2 {
+						if custom, ok := ie[1].(map[string]any); ok {
+							if code, ok := custom["Custom"].(json.Number); ok {
+								switch code.String() {
+								case strconv.Itoa(InstructionErrorAccountDoesNotExist):
+									return solana.Signature{}, nil, ErrAccountNotFound
+								case strconv.Itoa(InstructionErrorAccountSamplesAccountFull):
+									return solana.Signature{}, nil, ErrSamplesAccountFull
+								case strconv.Itoa(InstructionErrorSamplingBudgetExceeded):
+									return solana.Signature{}, nil, ErrSamplingBudgetExceeded
+								}
+							}
+						}
+					}
+				}
+			}
+		}
+		return solana.Signature{}, nil, fmt.Errorf("failed to execute instruction: %w", err)
+	}
+
+	return sig, res, nil
+}
+
+func (c *Client) GetPathProbeSamples(
+	ctx context.Context,
+	userPK solana.PublicKey,
+	targetDevicePK solana.PublicKey,
+	proberPK solana.PublicKey,
+	epoch uint64,
+) (*PathProbeSamples, error) {
+	pda, _, err := DerivePathProbeSamplesPDA(
+		c.executor.programID,
+		proberPK,
+		userPK,
+		targetDevicePK,
+		epoch,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive PDA: %w", err)
+	}
+
+	account, err := c.rpc.GetAccountInfo(ctx, pda)
+	if err != nil {
+		if errors.Is(err, solanarpc.ErrNotFound) {
+			return nil, ErrAccountNotFound
+		}
+		return nil, fmt.Errorf("failed to get account data: %w", err)
+	}
+	if account.Value == nil {
+		return nil, ErrAccountNotFound
+	}
+
+	var pathProbeSamples PathProbeSamples
+	if err := pathProbeSamples.Deserialize(account.Value.Data.GetBinary()); err != nil {
+		return nil, fmt.Errorf("failed to deserialize PathProbeSamples: %w", err)
+	}
+
+	return &pathProbeSamples, nil
+}
+
+func (c *Client) InitializePathProbeSamples(
+	ctx context.Context,
+	config InitializePathProbeSamplesInstructionConfig,
+) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+	instruction, err := BuildInitializePathProbeSamplesInstruction(c.executor.programID, c.executor.signer.PublicKey(), config)
+	if err != nil {
+		return solana.Signature{}, nil, fmt.Errorf("failed to build instruction: %w", err)
+	}
+
+	sig, res, err := c.executor.ExecuteTransaction(ctx, instruction, &ExecuteTransactionOptions{
+		// Skip preflight/simulation on this transaction since it creates the account in the
+		// instruction itself. Otherwise the preflight will fail with AccountNotFound.
+		SkipPreflight: true,
+	})
+	if err != nil {
+		return solana.Signature{}, nil, fmt.Errorf("failed to execute instruction: %w", err)
+	}
+
+	return sig, res, nil
+}
+
+func (c *Client) WritePathProbeSamples(
+	ctx context.Context,
+	config WritePathProbeSamplesInstructionConfig,
+) (solana.Signature, *solanarpc.GetTransactionResult, error) {
+	if len(config.Samples) > MaxPathProbeSamplesPerBatch {
+		return solana.Signature{}, nil, ErrSamplesBatchTooLarge
+	}
+
+	instruction, err := BuildWritePathProbeSamplesInstruction(c.executor.programID, c.executor.signer.PublicKey(), config)
+	if err != nil {
+		return solana.Signature{}, nil, fmt.Errorf("failed to build instruction: %w", err)
+	}
+
 	sig, res, err := c.executor.ExecuteTransaction(ctx, instruction, nil)
 	if err != nil {
 		var rpcErr *jsonrpc.RPCError
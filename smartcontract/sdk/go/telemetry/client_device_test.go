@@ -67,6 +67,72 @@ func TestSDK_Telemetry_Client_GetDeviceLatencySamples_HappyPath(t *testing.T) {
 	require.Equal(t, expected, got)
 }
 
+func TestSDK_Telemetry_Client_GetDeviceLatencySamples_FollowsShards(t *testing.T) {
+	t.Parallel()
+
+	signer := solana.NewWallet().PrivateKey
+	programID := solana.NewWallet().PublicKey()
+	originPK := solana.NewWallet().PublicKey()
+	targetPK := solana.NewWallet().PublicKey()
+	linkPK := solana.NewWallet().PublicKey()
+	epoch := uint64(42)
+
+	shard := func(shardIndex uint8, flags uint8, samples []uint32) *telemetry.DeviceLatencySamples {
+		return &telemetry.DeviceLatencySamples{
+			DeviceLatencySamplesHeader: telemetry.DeviceLatencySamplesHeader{
+				AccountType:                  telemetry.AccountTypeDeviceLatencySamples,
+				Epoch:                        epoch,
+				OriginDevicePK:               originPK,
+				TargetDevicePK:               targetPK,
+				LinkPK:                       linkPK,
+				SamplingIntervalMicroseconds: 100_000,
+				StartTimestampMicroseconds:   1_600_000_000 + uint64(shardIndex),
+				NextSampleIndex:              uint32(len(samples)),
+				Flags:                        flags,
+				ShardIndex:                   shardIndex,
+			},
+			Samples: samples,
+		}
+	}
+	accounts := map[solana.PublicKey][]byte{}
+	for _, s := range []*telemetry.DeviceLatencySamples{
+		shard(0, telemetry.SamplesFlagRolledOver, []uint32{1, 2}),
+		shard(1, telemetry.SamplesFlagRolledOver, []uint32{3}),
+		shard(2, telemetry.SamplesFlagFinalized, []uint32{4, 5}),
+	} {
+		pda, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originPK, targetPK, linkPK, epoch, s.ShardIndex)
+		require.NoError(t, err)
+		buf := new(bytes.Buffer)
+		require.NoError(t, s.Serialize(buf))
+		accounts[pda] = buf.Bytes()
+	}
+
+	mockRPC := &mockRPCClient{
+		GetAccountInfoFunc: func(_ context.Context, pda solana.PublicKey) (*solanarpc.GetAccountInfoResult, error) {
+			data, ok := accounts[pda]
+			if !ok {
+				return nil, solanarpc.ErrNotFound
+			}
+			return &solanarpc.GetAccountInfoResult{
+				Value: &solanarpc.Account{
+					Data: solanarpc.DataBytesOrJSONFromBytes(data),
+				},
+			}, nil
+		},
+	}
+
+	client := telemetry.New(slog.Default(), mockRPC, &signer, programID)
+
+	got, err := client.GetDeviceLatencySamples(context.Background(), originPK, targetPK, linkPK, epoch)
+	require.NoError(t, err)
+	require.Equal(t, []uint32{1, 2, 3, 4, 5}, got.Samples)
+	require.Equal(t, uint32(5), got.NextSampleIndex)
+	require.Equal(t, uint8(0), got.ShardIndex)
+	require.Equal(t, uint64(1_600_000_000), got.StartTimestampMicroseconds)
+	require.False(t, got.IsRolledOver())
+	require.True(t, got.IsFinalized())
+}
+
 func TestSDK_Telemetry_Client_GetDeviceLatencySamples_AccountNotFound(t *testing.T) {
 	t.Parallel()
 
@@ -736,6 +802,33 @@ func TestSDK_Telemetry_Client_WriteDeviceLatencySamples_SamplesBatchTooLarge(t *
 	require.Equal(t, solana.Signature{}, sig)
 	require.Nil(t, tx)
 }
+
+func TestSDK_Telemetry_Client_WriteDeviceLatencySamples_RollOverBatchTooLarge(t *testing.T) {
+	t.Parallel()
+
+	signer := solana.NewWallet().PrivateKey
+	programID := solana.NewWallet().PublicKey()
+
+	client := telemetry.New(slog.Default(), &mockRPCClient{}, &signer, programID)
+
+	epoch := uint64(42)
+	config := telemetry.WriteDeviceLatencySamplesInstructionConfig{
+		AgentPK:                    signer.PublicKey(),
+		OriginDevicePK:             solana.NewWallet().PublicKey(),
+		TargetDevicePK:             solana.NewWallet().PublicKey(),
+		LinkPK:                     solana.NewWallet().PublicKey(),
+		Epoch:                      &epoch,
+		StartTimestampMicroseconds: 1_600_000_000,
+		Samples:                    make([]uint32, telemetry.MaxDeviceLatencySamplesPerRollOverBatch+1),
+		RollOver:                   true,
+	}
+
+	sig, tx, err := client.WriteDeviceLatencySamples(context.Background(), config)
+
+	require.ErrorIs(t, err, telemetry.ErrSamplesBatchTooLarge)
+	require.Equal(t, solana.Signature{}, sig)
+	require.Nil(t, tx)
+}
 func TestSDK_Telemetry_Client_WriteDeviceLatencySamples_PreflightAccountNotFound(t *testing.T) {
 	t.Parallel()
 
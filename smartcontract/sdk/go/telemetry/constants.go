@@ -12,6 +12,12 @@ const (
 	InitializeInternetLatencySamplesInstructionIndex TelemetryInstructionType = 2
 	// Represents the write internet latency samples instruction
 	WriteInternetLatencySamplesInstructionIndex TelemetryInstructionType = 3
+	// Represents the close samples account instruction
+	CloseSamplesAccountInstructionIndex TelemetryInstructionType = 4
+	// Represents the initialize path probe samples instruction
+	InitializePathProbeSamplesInstructionIndex TelemetryInstructionType = 8
+	// Represents the write path probe samples instruction
+	WritePathProbeSamplesInstructionIndex TelemetryInstructionType = 9
 
 	// InstructionErrorAccountSamplesAccountFull is the error code that the telemetry program returns
 	// when the given PDA has reached maximum capacity for samples.
@@ -21,6 +27,10 @@ const (
 	// when the given PDA does not exist.
 	InstructionErrorAccountDoesNotExist = 1011
 
+	// InstructionErrorSamplingBudgetExceeded is the error code that the telemetry program returns
+	// when a write would exceed the data provider's sampling budget.
+	InstructionErrorSamplingBudgetExceeded = 1026
+
 	// Messages transmitted to Solana validators must not exceed the IPv6 MTU size to ensure fast
 	// and reliable network transmission of cluster info over UDP. Solana's networking stack uses a
 	// conservative MTU size of 1280 bytes which, after accounting for headers, leaves 1232 bytes
@@ -31,6 +41,11 @@ const (
 	// agent_commit (8 bytes) fields in WriteDeviceLatencySamples.
 	MaxDeviceLatencySamplesPerBatch   = 239 // 956 bytes of samples
 	MaxInternetLatencySamplesPerBatch = 245 // 980 bytes of samples
+	MaxPathProbeSamplesPerBatch       = 245 // 980 bytes of samples
+
+	// MaxDeviceLatencySamplesPerRollOverBatch leaves room for the next shard's account, which
+	// WriteDeviceLatencySamples passes when the samples may not fit in the current shard.
+	MaxDeviceLatencySamplesPerRollOverBatch = 230 // 920 bytes of samples
 
 	// MaxDeviceLatencySamplesPerAccount is the maximum number of samples that can be written to a single device latency samples account.
 	// This provides space for just over 12 samples per minute, or 1 sample every 5 seconds.
@@ -40,6 +55,10 @@ const (
 	// This provides space for just over 1 sample per minute.
 	MaxInternetLatencySamplesPerAccount = 3000
 
+	// MaxPathProbeSamplesPerAccount is the maximum number of samples that can be written to a path probe samples account.
+	// This provides space for one probe every 30 seconds.
+	MaxPathProbeSamplesPerAccount = 6000
+
 	// MaxInternetLatencyDataProviderNameLength is the maximum length of a data provider name.
 	MaxInternetLatencyDataProviderNameLength = 32
 )
@@ -60,4 +79,8 @@ const (
 	DeviceLatencySamplesSeed = "dzlatency"
 	// Seed for internet latency samples PDAs
 	InternetLatencySamplesSeed = "inetlatency"
+	// Seed for provider sampling budget PDAs
+	ProviderSamplingBudgetSeed = "providerbudget"
+	// Seed for path probe samples PDAs
+	PathProbeSamplesSeed = "pathprobe"
 )
@@ -0,0 +1,87 @@
+package telemetry
+
+import (
+	"fmt"
+
+	"github.com/gagliardetto/solana-go"
+	"github.com/near/borsh-go"
+)
+
+type InitializePathProbeSamplesInstructionConfig struct {
+	UserPK                       solana.PublicKey
+	TargetDevicePK               solana.PublicKey
+	ServiceabilityGlobalStatePK  solana.PublicKey
+	Epoch                        uint64
+	SamplingIntervalMicroseconds uint64
+}
+
+func (c *InitializePathProbeSamplesInstructionConfig) Validate() error {
+	if c.UserPK.IsZero() {
+		return fmt.Errorf("user public key is required")
+	}
+	if c.TargetDevicePK.IsZero() {
+		return fmt.Errorf("target device public key is required")
+	}
+	if c.ServiceabilityGlobalStatePK.IsZero() {
+		return fmt.Errorf("serviceability global state public key is required")
+	}
+	if c.Epoch == 0 {
+		return fmt.Errorf("epoch is required")
+	}
+	if c.SamplingIntervalMicroseconds == 0 {
+		return fmt.Errorf("sampling interval microseconds is required")
+	}
+	return nil
+}
+
+func BuildInitializePathProbeSamplesInstruction(
+	programID solana.PublicKey,
+	signerPK solana.PublicKey,
+	config InitializePathProbeSamplesInstructionConfig,
+) (solana.Instruction, error) {
+	if err := config.Validate(); err != nil {
+		return nil, fmt.Errorf("failed to validate config: %w", err)
+	}
+
+	// Serialize the instruction data
+	data, err := borsh.Serialize(struct {
+		Discriminator                uint8
+		Epoch                        uint64
+		SamplingIntervalMicroseconds uint64
+	}{
+		Discriminator:                uint8(InitializePathProbeSamplesInstructionIndex),
+		Epoch:                        config.Epoch,
+		SamplingIntervalMicroseconds: config.SamplingIntervalMicroseconds,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to serialize args: %w", err)
+	}
+
+	// Derive the PDA.
+	pda, _, err := DerivePathProbeSamplesPDA(
+		programID,
+		signerPK,
+		config.UserPK,
+		config.TargetDevicePK,
+		config.Epoch,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive PDA: %w", err)
+	}
+
+	// Build accounts
+	accounts := []*solana.AccountMeta{
+		{PublicKey: pda, IsSigner: false, IsWritable: true},
+		{PublicKey: signerPK, IsSigner: true, IsWritable: true},
+		{PublicKey: config.UserPK, IsSigner: false, IsWritable: false},
+		{PublicKey: config.TargetDevicePK, IsSigner: false, IsWritable: false},
+		{PublicKey: config.ServiceabilityGlobalStatePK, IsSigner: false, IsWritable: false},
+		{PublicKey: solana.SystemProgramID, IsSigner: false, IsWritable: false},
+	}
+
+	return &solana.GenericInstruction{
+		ProgID:        programID,
+		AccountValues: accounts,
+		DataBytes:     data,
+	}, nil
+}
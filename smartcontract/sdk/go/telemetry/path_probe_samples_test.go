@@ -0,0 +1,224 @@
+package telemetry_test
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"log/slog"
+	"testing"
+
+	"github.com/gagliardetto/solana-go"
+	solanarpc "github.com/gagliardetto/solana-go/rpc"
+	"github.com/malbeclabs/doublezero/smartcontract/sdk/go/telemetry"
+	"github.com/near/borsh-go"
+	"github.com/stretchr/testify/require"
+)
+
+func TestSDK_Telemetry_InitializePathProbeSamples_HappyPath(t *testing.T) {
+	t.Parallel()
+
+	programID := solana.NewWallet().PublicKey()
+	proberPK := solana.NewWallet().PublicKey()
+	config := telemetry.InitializePathProbeSamplesInstructionConfig{
+		UserPK:                       solana.NewWallet().PublicKey(),
+		TargetDevicePK:               solana.NewWallet().PublicKey(),
+		ServiceabilityGlobalStatePK:  solana.NewWallet().PublicKey(),
+		Epoch:                        42,
+		SamplingIntervalMicroseconds: 30_000_000,
+	}
+
+	ix, err := telemetry.BuildInitializePathProbeSamplesInstruction(programID, proberPK, config)
+	require.NoError(t, err)
+
+	pda, _, err := telemetry.DerivePathProbeSamplesPDA(programID, proberPK, config.UserPK, config.TargetDevicePK, config.Epoch)
+	require.NoError(t, err)
+
+	accounts := ix.Accounts()
+	require.Len(t, accounts, 6)
+	require.Equal(t, pda, accounts[0].PublicKey)
+	require.True(t, accounts[0].IsWritable)
+	require.Equal(t, proberPK, accounts[1].PublicKey)
+	require.True(t, accounts[1].IsSigner)
+	require.Equal(t, config.UserPK, accounts[2].PublicKey)
+	require.Equal(t, config.TargetDevicePK, accounts[3].PublicKey)
+	require.Equal(t, config.ServiceabilityGlobalStatePK, accounts[4].PublicKey)
+	require.Equal(t, solana.SystemProgramID, accounts[5].PublicKey)
+
+	data, err := ix.Data()
+	require.NoError(t, err)
+	expected, err := borsh.Serialize(struct {
+		Discriminator                uint8
+		Epoch                        uint64
+		SamplingIntervalMicroseconds uint64
+	}{
+		Discriminator:                uint8(telemetry.InitializePathProbeSamplesInstructionIndex),
+		Epoch:                        42,
+		SamplingIntervalMicroseconds: 30_000_000,
+	})
+	require.NoError(t, err)
+	require.Equal(t, expected, data)
+}
+
+func TestSDK_Telemetry_InitializePathProbeSamples_MissingFields(t *testing.T) {
+	t.Parallel()
+
+	base := telemetry.InitializePathProbeSamplesInstructionConfig{
+		UserPK:                       solana.NewWallet().PublicKey(),
+		TargetDevicePK:               solana.NewWallet().PublicKey(),
+		ServiceabilityGlobalStatePK:  solana.NewWallet().PublicKey(),
+		Epoch:                        42,
+		SamplingIntervalMicroseconds: 30_000_000,
+	}
+
+	tests := []struct {
+		name        string
+		mutate      func(c *telemetry.InitializePathProbeSamplesInstructionConfig)
+		expectError string
+	}{
+		{
+			name:        "missing_user_pk",
+			mutate:      func(c *telemetry.InitializePathProbeSamplesInstructionConfig) { c.UserPK = solana.PublicKey{} },
+			expectError: "user public key is required",
+		},
+		{
+			name:        "missing_target_device_pk",
+			mutate:      func(c *telemetry.InitializePathProbeSamplesInstructionConfig) { c.TargetDevicePK = solana.PublicKey{} },
+			expectError: "target device public key is required",
+		},
+		{
+			name:        "missing_globalstate_pk",
+			mutate:      func(c *telemetry.InitializePathProbeSamplesInstructionConfig) { c.ServiceabilityGlobalStatePK = solana.PublicKey{} },
+			expectError: "serviceability global state public key is required",
+		},
+		{
+			name:        "missing_sampling_interval",
+			mutate:      func(c *telemetry.InitializePathProbeSamplesInstructionConfig) { c.SamplingIntervalMicroseconds = 0 },
+			expectError: "sampling interval microseconds is required",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			config := base
+			tt.mutate(&config)
+			_, err := telemetry.BuildInitializePathProbeSamplesInstruction(solana.NewWallet().PublicKey(), solana.NewWallet().PublicKey(), config)
+			require.ErrorContains(t, err, tt.expectError)
+		})
+	}
+}
+
+func TestSDK_Telemetry_WritePathProbeSamples_HappyPath(t *testing.T) {
+	t.Parallel()
+
+	programID := solana.NewWallet().PublicKey()
+	proberPK := solana.NewWallet().PublicKey()
+	config := telemetry.WritePathProbeSamplesInstructionConfig{
+		UserPK:                     solana.NewWallet().PublicKey(),
+		TargetDevicePK:             solana.NewWallet().PublicKey(),
+		Epoch:                      42,
+		StartTimestampMicroseconds: 1_700_000_000_000_000,
+		Samples:                    []uint32{1_200, telemetry.PathProbeSampleLost, 1_350},
+	}
+
+	ix, err := telemetry.BuildWritePathProbeSamplesInstruction(programID, proberPK, config)
+	require.NoError(t, err)
+
+	pda, _, err := telemetry.DerivePathProbeSamplesPDA(programID, proberPK, config.UserPK, config.TargetDevicePK, config.Epoch)
+	require.NoError(t, err)
+
+	accounts := ix.Accounts()
+	require.Len(t, accounts, 3)
+	require.Equal(t, pda, accounts[0].PublicKey)
+	require.Equal(t, proberPK, accounts[1].PublicKey)
+	require.True(t, accounts[1].IsSigner)
+	require.Equal(t, solana.SystemProgramID, accounts[2].PublicKey)
+
+	data, err := ix.Data()
+	require.NoError(t, err)
+	require.Equal(t, uint8(telemetry.WritePathProbeSamplesInstructionIndex), data[0])
+}
+
+func TestSDK_Telemetry_DerivePathProbeSamplesPDA(t *testing.T) {
+	t.Parallel()
+
+	programID := solana.NewWallet().PublicKey()
+	proberPK := solana.NewWallet().PublicKey()
+	userPK := solana.NewWallet().PublicKey()
+	targetA := solana.NewWallet().PublicKey()
+	targetB := solana.NewWallet().PublicKey()
+
+	pdaA, _, err := telemetry.DerivePathProbeSamplesPDA(programID, proberPK, userPK, targetA, 100)
+	require.NoError(t, err)
+	pdaB, _, err := telemetry.DerivePathProbeSamplesPDA(programID, proberPK, userPK, targetB, 100)
+	require.NoError(t, err)
+	pdaNextEpoch, _, err := telemetry.DerivePathProbeSamplesPDA(programID, proberPK, userPK, targetA, 101)
+	require.NoError(t, err)
+
+	require.NotEqual(t, pdaA, pdaB, "PDA should differ per target device")
+	require.NotEqual(t, pdaA, pdaNextEpoch, "PDA should differ per epoch")
+}
+
+func TestSDK_Telemetry_Client_GetPathProbeSamples(t *testing.T) {
+	t.Parallel()
+
+	signer := solana.NewWallet().PrivateKey
+	programID := solana.NewWallet().PublicKey()
+
+	expected := &telemetry.PathProbeSamples{
+		PathProbeSamplesHeader: telemetry.PathProbeSamplesHeader{
+			AccountType:                  telemetry.AccountTypePathProbeSamples,
+			Epoch:                        42,
+			ProberAgentPK:                signer.PublicKey(),
+			UserPK:                       solana.NewWallet().PublicKey(),
+			OriginDevicePK:               solana.NewWallet().PublicKey(),
+			TargetDevicePK:               solana.NewWallet().PublicKey(),
+			SamplingIntervalMicroseconds: 30_000_000,
+			StartTimestampMicroseconds:   1_700_000_000_000_000,
+			NextSampleIndex:              3,
+		},
+		Samples: []uint32{1_200, telemetry.PathProbeSampleLost, 1_350},
+	}
+
+	mockRPC := &mockRPCClient{
+		GetAccountInfoFunc: func(_ context.Context, _ solana.PublicKey) (*solanarpc.GetAccountInfoResult, error) {
+			buf := new(bytes.Buffer)
+			if err := expected.Serialize(buf); err != nil {
+				return nil, fmt.Errorf("mock serialize: %w", err)
+			}
+			return &solanarpc.GetAccountInfoResult{
+				Value: &solanarpc.Account{
+					Data: solanarpc.DataBytesOrJSONFromBytes(buf.Bytes()),
+				},
+			}, nil
+		},
+	}
+
+	client := telemetry.New(slog.Default(), mockRPC, &signer, programID)
+
+	got, err := client.GetPathProbeSamples(context.Background(), expected.UserPK, expected.TargetDevicePK, signer.PublicKey(), expected.Epoch)
+	require.NoError(t, err)
+	require.Equal(t, expected, got)
+
+	mockRPC.GetAccountInfoFunc = func(_ context.Context, _ solana.PublicKey) (*solanarpc.GetAccountInfoResult, error) {
+		return nil, solanarpc.ErrNotFound
+	}
+	_, err = client.GetPathProbeSamples(context.Background(), expected.UserPK, expected.TargetDevicePK, signer.PublicKey(), expected.Epoch)
+	require.ErrorIs(t, err, telemetry.ErrAccountNotFound)
+}
+
+func TestSDK_Telemetry_Client_WritePathProbeSamples_SamplesBatchTooLarge(t *testing.T) {
+	t.Parallel()
+
+	signer := solana.NewWallet().PrivateKey
+	client := telemetry.New(slog.Default(), &mockRPCClient{}, &signer, solana.NewWallet().PublicKey())
+
+	_, _, err := client.WritePathProbeSamples(context.Background(), telemetry.WritePathProbeSamplesInstructionConfig{
+		UserPK:         solana.NewWallet().PublicKey(),
+		TargetDevicePK: solana.NewWallet().PublicKey(),
+		Epoch:          42,
+		Samples:        make([]uint32, telemetry.MaxPathProbeSamplesPerBatch+1),
+	})
+	require.ErrorIs(t, err, telemetry.ErrSamplesBatchTooLarge)
+}
@@ -32,6 +32,37 @@ func DeriveDeviceLatencySamplesPDA(
 	return solana.FindProgramAddress(seeds, programID)
 }
 
+// Derives the PDA for a shard of a device latency samples account. Shard 0 is the
+// account from DeriveDeviceLatencySamplesPDA; writes roll over to later shards,
+// which append the shard index to the seeds, once it is full.
+func DeriveDeviceLatencySamplesShardPDA(
+	programID solana.PublicKey,
+	originDevicePK solana.PublicKey,
+	targetDevicePK solana.PublicKey,
+	linkPK solana.PublicKey,
+	epoch uint64,
+	shardIndex uint8,
+) (solana.PublicKey, uint8, error) {
+	if shardIndex == 0 {
+		return DeriveDeviceLatencySamplesPDA(programID, originDevicePK, targetDevicePK, linkPK, epoch)
+	}
+
+	epochBytes := make([]byte, 8)
+	binary.LittleEndian.PutUint64(epochBytes, epoch)
+
+	seeds := [][]byte{
+		[]byte(TelemetrySeedPrefix),
+		[]byte(DeviceLatencySamplesSeed),
+		originDevicePK[:],
+		targetDevicePK[:],
+		linkPK[:],
+		epochBytes,
+		{shardIndex},
+	}
+
+	return solana.FindProgramAddress(seeds, programID)
+}
+
 // Derives the PDA for internet latency samples account
 func DeriveInternetLatencySamplesPDA(
 	programID solana.PublicKey,
@@ -59,3 +90,42 @@ func DeriveInternetLatencySamplesPDA(
 	// Find program address
 	return solana.FindProgramAddress(seeds, programID)
 }
+
+// Derives the PDA for a data provider's sampling budget account
+func DeriveProviderSamplingBudgetPDA(
+	programID solana.PublicKey,
+	collectorOraclePK solana.PublicKey,
+	dataProviderName string,
+) (solana.PublicKey, uint8, error) {
+	seeds := [][]byte{
+		[]byte(TelemetrySeedPrefix),
+		[]byte(ProviderSamplingBudgetSeed),
+		collectorOraclePK[:],
+		[]byte(dataProviderName),
+	}
+
+	return solana.FindProgramAddress(seeds, programID)
+}
+
+// Derives the PDA for the path probe samples of a user's tunnel towards a target device
+func DerivePathProbeSamplesPDA(
+	programID solana.PublicKey,
+	proberAgentPK solana.PublicKey,
+	userPK solana.PublicKey,
+	targetDevicePK solana.PublicKey,
+	epoch uint64,
+) (solana.PublicKey, uint8, error) {
+	epochBytes := make([]byte, 8)
+	binary.LittleEndian.PutUint64(epochBytes, epoch)
+
+	seeds := [][]byte{
+		[]byte(TelemetrySeedPrefix),
+		[]byte(PathProbeSamplesSeed),
+		proberAgentPK[:],
+		userPK[:],
+		targetDevicePK[:],
+		epochBytes,
+	}
+
+	return solana.FindProgramAddress(seeds, programID)
+}
@@ -34,6 +34,30 @@ func TestSDK_Telemetry_DeriveDeviceLatencySamplesPDA(t *testing.T) {
 	require.NotEqual(t, pda1, pda2, "PDA should be different if device key order changes")
 }
 
+func TestSDK_Telemetry_DeriveDeviceLatencySamplesShardPDA(t *testing.T) {
+	t.Parallel()
+
+	programID := solana.NewWallet().PublicKey()
+	originDevicePK := solana.NewWallet().PublicKey()
+	targetDevicePK := solana.NewWallet().PublicKey()
+	linkPK := solana.NewWallet().PublicKey()
+
+	// Shard 0 is the unsharded account.
+	base, _, err := telemetry.DeriveDeviceLatencySamplesPDA(programID, originDevicePK, targetDevicePK, linkPK, 100)
+	require.NoError(t, err)
+	shard0, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originDevicePK, targetDevicePK, linkPK, 100, 0)
+	require.NoError(t, err)
+	require.Equal(t, base, shard0)
+
+	// Later shards get their own addresses.
+	shard1, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originDevicePK, targetDevicePK, linkPK, 100, 1)
+	require.NoError(t, err)
+	shard2, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originDevicePK, targetDevicePK, linkPK, 100, 2)
+	require.NoError(t, err)
+	require.NotEqual(t, shard0, shard1)
+	require.NotEqual(t, shard1, shard2)
+}
+
 func TestSDK_Telemetry_DeriveDeviceLatencySamplesPDADifferentEpochs(t *testing.T) {
 	t.Parallel()
 
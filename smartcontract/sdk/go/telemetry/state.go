@@ -15,11 +15,22 @@ const (
 	AccountTypeInternetLatencySamplesV0
 	AccountTypeDeviceLatencySamples
 	AccountTypeInternetLatencySamples
+	AccountTypeProviderSamplingBudget
+	AccountTypePathProbeSamples
 )
 
 // Covers both V0 (350 bytes) and V1 (349 bytes) header layouts.
 const DeviceLatencySamplesHeaderSize = 350
 
+const (
+	// SamplesFlagFinalized marks an account frozen by FinalizeEpochSamples.
+	SamplesFlagFinalized uint8 = 1 << 0
+
+	// SamplesFlagRolledOver marks a device latency samples account whose writes
+	// moved on to the next shard once it was full.
+	SamplesFlagRolledOver uint8 = 1 << 1
+)
+
 type DeviceLatencySamplesHeaderOnlyAccountType struct {
 	AccountType AccountType // 1
 }
@@ -82,8 +93,36 @@ type DeviceLatencySamplesHeader struct {
 	// Short git commit hash of the telemetry agent binary.
 	AgentCommit [8]uint8 // 8
 
+	// Bitmask of SamplesFlag* values. Zero for accounts created before flags existed.
+	Flags uint8 // 1
+
+	// Position of this account among the shards of its link, origin and epoch.
+	// Zero for the account derived without a shard index.
+	ShardIndex uint8 // 1
+
+	// Agent that owned the account before it was rotated to the device's new
+	// metrics publisher. Zero for accounts that were never rotated.
+	PreviousAgentPK solana.PublicKey // 32
+
+	// Epoch in which the account was last rotated to a new agent.
+	AgentRotationEpoch uint64 // 8
+
+	// Last epoch in which PreviousAgentPK may write. Zero when the rotation
+	// grace period applies instead.
+	PreviousAgentUntilEpoch uint64 // 8
+
 	// Reserved for future use.
-	Unused [104]uint8 // 104
+	Unused [54]uint8 // 54
+}
+
+// IsFinalized reports whether the account was frozen against further writes.
+func (h *DeviceLatencySamplesHeader) IsFinalized() bool {
+	return h.Flags&SamplesFlagFinalized != 0
+}
+
+// IsRolledOver reports whether writes have moved on to the shard after this one.
+func (h *DeviceLatencySamplesHeader) IsRolledOver() bool {
+	return h.Flags&SamplesFlagRolledOver != 0
 }
 
 type DeviceLatencySamples struct {
@@ -192,3 +231,79 @@ func (d *InternetLatencySamples) Deserialize(data []byte) error {
 	}
 	return nil
 }
+
+// PathProbeSampleLost is the sample value recorded for a probe that got no reply.
+const PathProbeSampleLost uint32 = 0
+
+type PathProbeSamplesHeader struct {
+	// AccountType is used to distinguish this account type during deserialization.
+	AccountType AccountType // 1
+
+	// Epoch is the epoch number in which samples were collected.
+	Epoch uint64 // 8
+
+	// ProberAgentPK is authorized to write probe samples (must match signer).
+	ProberAgentPK solana.PublicKey // 32
+
+	// UserPK is the user whose tunnel the probes are sent through.
+	UserPK solana.PublicKey // 32
+
+	// OriginDevicePK is the device terminating the user's tunnel.
+	OriginDevicePK solana.PublicKey // 32
+
+	// TargetDevicePK is the device the probes are addressed to.
+	TargetDevicePK solana.PublicKey // 32
+
+	// SamplingIntervalMicroseconds is the interval between probes (in microseconds).
+	SamplingIntervalMicroseconds uint64 // 8
+
+	// StartTimestampMicroseconds is the timestamp of the first written sample (µs since UNIX epoch).
+	// Set on the first write, remains unchanged after.
+	StartTimestampMicroseconds uint64 // 8
+
+	// NextSampleIndex tracks how many samples have been appended.
+	NextSampleIndex uint32 // 4
+
+	// Flags is a bitmask of samples flags, e.g. finalized.
+	Flags uint8 // 1
+
+	// Unused is reserved for future use.
+	Unused [64]uint8 // 64
+}
+
+type PathProbeSamples struct {
+	PathProbeSamplesHeader
+	Samples []uint32 // n*4 (RTT values in microseconds, PathProbeSampleLost for lost probes)
+}
+
+func (d *PathProbeSamples) Serialize(w io.Writer) error {
+	enc := bin.NewBorshEncoder(w)
+	if err := enc.Encode(d.PathProbeSamplesHeader); err != nil {
+		return err
+	}
+	for _, sample := range d.Samples {
+		if err := enc.Encode(sample); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (d *PathProbeSamples) Deserialize(data []byte) error {
+	dec := bin.NewBorshDecoder(data)
+	if err := dec.Decode(&d.PathProbeSamplesHeader); err != nil {
+		return err
+	}
+
+	if d.PathProbeSamplesHeader.NextSampleIndex > MaxPathProbeSamplesPerAccount {
+		return fmt.Errorf("next sample index %d exceeds max allowed samples %d", d.PathProbeSamplesHeader.NextSampleIndex, MaxPathProbeSamplesPerAccount)
+	}
+
+	d.Samples = make([]uint32, d.PathProbeSamplesHeader.NextSampleIndex)
+	for i := 0; i < int(d.PathProbeSamplesHeader.NextSampleIndex); i++ {
+		if err := dec.Decode(&d.Samples[i]); err != nil {
+			return err
+		}
+	}
+	return nil
+}
@@ -33,7 +33,12 @@ func TestSDK_Telemetry_State_DeviceLatencySamples(t *testing.T) {
 				NextSampleIndex:              5,
 				AgentVersion:                 [16]uint8{},
 				AgentCommit:                  [8]uint8{},
-				Unused:                       [104]byte{88},
+				Flags:                        SamplesFlagRolledOver,
+				ShardIndex:                   2,
+				PreviousAgentPK:              target,
+				AgentRotationEpoch:           40,
+				PreviousAgentUntilEpoch:      41,
+				Unused:                       [54]byte{88},
 			},
 			Samples: []uint32{100, 200, 300, 400, 500},
 		}
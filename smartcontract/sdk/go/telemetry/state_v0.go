@@ -1,6 +1,7 @@
 package telemetry
 
 import (
+	"encoding/binary"
 	"fmt"
 	"io"
 
@@ -90,10 +91,12 @@ func (d *DeviceLatencySamplesV0) Deserialize(data []byte) error {
 func (d *DeviceLatencySamplesHeaderV0) ToV1Header() DeviceLatencySamplesHeader {
 	var agentVersion [16]uint8
 	var agentCommit [8]uint8
-	var unused [104]uint8
+	var previousAgentPK solana.PublicKey
+	var unused [54]uint8
 	copy(agentVersion[:], d.Unused[0:16])
 	copy(agentCommit[:], d.Unused[16:24])
-	copy(unused[:], d.Unused[24:128])
+	copy(previousAgentPK[:], d.Unused[26:58])
+	copy(unused[:], d.Unused[74:128])
 
 	return DeviceLatencySamplesHeader{
 		AccountType:                  AccountTypeDeviceLatencySamples,
@@ -109,6 +112,11 @@ func (d *DeviceLatencySamplesHeaderV0) ToV1Header() DeviceLatencySamplesHeader {
 		NextSampleIndex:              d.NextSampleIndex,
 		AgentVersion:                 agentVersion,
 		AgentCommit:                  agentCommit,
+		Flags:                        d.Unused[24],
+		ShardIndex:                   d.Unused[25],
+		PreviousAgentPK:              previousAgentPK,
+		AgentRotationEpoch:           binary.LittleEndian.Uint64(d.Unused[58:66]),
+		PreviousAgentUntilEpoch:      binary.LittleEndian.Uint64(d.Unused[66:74]),
 		Unused:                       unused,
 	}
 }
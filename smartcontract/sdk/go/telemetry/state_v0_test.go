@@ -129,7 +129,7 @@ func TestSDK_Telemetry_State_DeviceLatencySamplesV0(t *testing.T) {
 				SamplingIntervalMicroseconds: 1_000_000,
 				StartTimestampMicroseconds:   2_000_000,
 				NextSampleIndex:              3,
-				Unused:                       [128]byte{0x42},
+				Unused:                       [128]byte{0x42, 24: 0x02, 25: 3, 26: 0x09, 58: 5, 66: 6},
 			},
 			Samples: []uint32{111, 222, 333},
 		}
@@ -147,15 +147,24 @@ func TestSDK_Telemetry_State_DeviceLatencySamplesV0(t *testing.T) {
 		require.Equal(t, v0.SamplingIntervalMicroseconds, v1.SamplingIntervalMicroseconds)
 		require.Equal(t, v0.StartTimestampMicroseconds, v1.StartTimestampMicroseconds)
 		require.Equal(t, v0.NextSampleIndex, v1.NextSampleIndex)
-		// V0 Unused[0:16] maps to V1 AgentVersion, [16:24] to AgentCommit, [24:128] to Unused.
+		// V0 Unused[0:16] maps to V1 AgentVersion, [16:24] to AgentCommit, [24] to Flags,
+		// [25] to ShardIndex, [26:58] to PreviousAgentPK, [58:66] to AgentRotationEpoch,
+		// [66:74] to PreviousAgentUntilEpoch and [74:128] to Unused.
 		var expectedVersion [16]uint8
 		copy(expectedVersion[:], v0.Unused[0:16])
 		require.Equal(t, expectedVersion, v1.AgentVersion)
 		var expectedCommit [8]uint8
 		copy(expectedCommit[:], v0.Unused[16:24])
 		require.Equal(t, expectedCommit, v1.AgentCommit)
-		var expectedUnused [104]uint8
-		copy(expectedUnused[:], v0.Unused[24:128])
+		require.Equal(t, v0.Unused[24], v1.Flags)
+		require.Equal(t, v0.Unused[25], v1.ShardIndex)
+		var expectedPreviousAgentPK solana.PublicKey
+		copy(expectedPreviousAgentPK[:], v0.Unused[26:58])
+		require.Equal(t, expectedPreviousAgentPK, v1.PreviousAgentPK)
+		require.Equal(t, binary.LittleEndian.Uint64(v0.Unused[58:66]), v1.AgentRotationEpoch)
+		require.Equal(t, binary.LittleEndian.Uint64(v0.Unused[66:74]), v1.PreviousAgentUntilEpoch)
+		var expectedUnused [54]uint8
+		copy(expectedUnused[:], v0.Unused[74:128])
 		require.Equal(t, expectedUnused, v1.Unused)
 		require.Equal(t, v0.Samples, v1.Samples)
 	})
@@ -2,6 +2,7 @@ package telemetry
 
 import (
 	"fmt"
+	"math"
 
 	"github.com/gagliardetto/solana-go"
 	"github.com/near/borsh-go"
@@ -17,6 +18,13 @@ type WriteDeviceLatencySamplesInstructionConfig struct {
 	Samples                    []uint32
 	AgentVersion               string
 	AgentCommit                string
+
+	// ShardIndex is the shard of the account the samples are written to.
+	ShardIndex uint8
+
+	// RollOver passes the next shard's account, so that samples that do not fit
+	// in this shard start the next one instead of failing the write.
+	RollOver bool
 }
 
 func (c *WriteDeviceLatencySamplesInstructionConfig) Validate() error {
@@ -35,6 +43,9 @@ func (c *WriteDeviceLatencySamplesInstructionConfig) Validate() error {
 	if c.Epoch == nil {
 		return fmt.Errorf("epoch is required")
 	}
+	if c.RollOver && c.ShardIndex == math.MaxUint8 {
+		return fmt.Errorf("no shard after shard %d", c.ShardIndex)
+	}
 	return nil
 }
 
@@ -72,12 +83,13 @@ func BuildWriteDeviceLatencySamplesInstruction(
 	}
 
 	// Derive the PDA.
-	pda, _, err := DeriveDeviceLatencySamplesPDA(
+	pda, _, err := DeriveDeviceLatencySamplesShardPDA(
 		programID,
 		config.OriginDevicePK,
 		config.TargetDevicePK,
 		config.LinkPK,
 		epoch,
+		config.ShardIndex,
 	)
 	if err != nil {
 		return nil, fmt.Errorf("failed to derive PDA: %w", err)
@@ -89,6 +101,20 @@ func BuildWriteDeviceLatencySamplesInstruction(
 		{PublicKey: config.AgentPK, IsSigner: true, IsWritable: false},
 		{PublicKey: solana.SystemProgramID, IsSigner: false, IsWritable: false},
 	}
+	if config.RollOver {
+		nextShardPDA, _, err := DeriveDeviceLatencySamplesShardPDA(
+			programID,
+			config.OriginDevicePK,
+			config.TargetDevicePK,
+			config.LinkPK,
+			epoch,
+			config.ShardIndex+1,
+		)
+		if err != nil {
+			return nil, fmt.Errorf("failed to derive next shard PDA: %w", err)
+		}
+		accounts = append(accounts, &solana.AccountMeta{PublicKey: nextShardPDA, IsSigner: false, IsWritable: true})
+	}
 
 	return &solana.GenericInstruction{
 		ProgID:        programID,
@@ -53,6 +53,49 @@ func TestSDK_Telemetry_WriteDeviceLatencySamples_HappyPath(t *testing.T) {
 	require.Equal(t, uint8(telemetry.WriteDeviceLatencySamplesInstructionIndex), data[0])
 }
 
+func TestSDK_Telemetry_WriteDeviceLatencySamples_RollOver(t *testing.T) {
+	t.Parallel()
+
+	programID := solana.NewWallet().PublicKey()
+	originPK := solana.NewWallet().PublicKey()
+	targetPK := solana.NewWallet().PublicKey()
+	linkPK := solana.NewWallet().PublicKey()
+	epoch := uint64(123)
+
+	config := telemetry.WriteDeviceLatencySamplesInstructionConfig{
+		AgentPK:                    solana.NewWallet().PublicKey(),
+		OriginDevicePK:             originPK,
+		TargetDevicePK:             targetPK,
+		LinkPK:                     linkPK,
+		Epoch:                      &epoch,
+		StartTimestampMicroseconds: 1_600_000_000,
+		Samples:                    []uint32{1, 2, 3},
+		ShardIndex:                 1,
+		RollOver:                   true,
+	}
+
+	ix, err := telemetry.BuildWriteDeviceLatencySamplesInstruction(programID, config)
+	require.NoError(t, err)
+
+	shard1, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originPK, targetPK, linkPK, epoch, 1)
+	require.NoError(t, err)
+	shard2, _, err := telemetry.DeriveDeviceLatencySamplesShardPDA(programID, originPK, targetPK, linkPK, epoch, 2)
+	require.NoError(t, err)
+
+	accounts := ix.Accounts()
+	require.Len(t, accounts, 4)
+	require.Equal(t, shard1, accounts[0].PublicKey)
+	require.True(t, accounts[0].IsWritable)
+	require.Equal(t, shard2, accounts[3].PublicKey)
+	require.False(t, accounts[3].IsSigner)
+	require.True(t, accounts[3].IsWritable)
+
+	// There is no shard after the last one.
+	config.ShardIndex = 255
+	_, err = telemetry.BuildWriteDeviceLatencySamplesInstruction(programID, config)
+	require.ErrorContains(t, err, "no shard after shard 255")
+}
+
 func TestSDK_Telemetry_WriteDeviceLatencySamples_MissingFields(t *testing.T) {
 	t.Parallel()
 
@@ -69,11 +69,18 @@ func BuildWriteInternetLatencySamplesInstruction(
 		return nil, fmt.Errorf("failed to derive PDA: %w", err)
 	}
 
+	// Derive the provider's sampling budget PDA, which the program enforces if it has been set.
+	budgetPDA, _, err := DeriveProviderSamplingBudgetPDA(programID, signerPK, config.DataProviderName)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive sampling budget PDA: %w", err)
+	}
+
 	// Build accounts.
 	accounts := []*solana.AccountMeta{
 		{PublicKey: pda, IsSigner: false, IsWritable: true},
 		{PublicKey: signerPK, IsSigner: true, IsWritable: false},
 		{PublicKey: solana.SystemProgramID, IsSigner: false, IsWritable: false},
+		{PublicKey: budgetPDA, IsSigner: false, IsWritable: false},
 	}
 
 	return &solana.GenericInstruction{
@@ -36,7 +36,7 @@ func TestSDK_Telemetry_WriteInternetLatencySamples_HappyPath(t *testing.T) {
 
 	require.Equal(t, programID, ix.ProgramID())
 	accounts := ix.Accounts()
-	require.Len(t, accounts, 3)
+	require.Len(t, accounts, 4)
 
 	require.Equal(t, oracleAgentPK, accounts[1].PublicKey)
 	require.True(t, accounts[1].IsSigner)
@@ -46,6 +46,12 @@ func TestSDK_Telemetry_WriteInternetLatencySamples_HappyPath(t *testing.T) {
 	require.False(t, accounts[2].IsSigner)
 	require.False(t, accounts[2].IsWritable)
 
+	budgetPDA, _, err := telemetry.DeriveProviderSamplingBudgetPDA(programID, oracleAgentPK, config.DataProviderName)
+	require.NoError(t, err)
+	require.Equal(t, budgetPDA, accounts[3].PublicKey)
+	require.False(t, accounts[3].IsSigner)
+	require.False(t, accounts[3].IsWritable)
+
 	data, err := ix.Data()
 	require.NoError(t, err)
 	require.Greater(t, len(data), 0)
@@ -0,0 +1,79 @@
+package telemetry
+
+import (
+	"fmt"
+
+	"github.com/gagliardetto/solana-go"
+	"github.com/near/borsh-go"
+)
+
+type WritePathProbeSamplesInstructionConfig struct {
+	UserPK                     solana.PublicKey
+	TargetDevicePK             solana.PublicKey
+	Epoch                      uint64
+	StartTimestampMicroseconds uint64
+	Samples                    []uint32
+}
+
+func (c *WritePathProbeSamplesInstructionConfig) Validate() error {
+	if c.UserPK.IsZero() {
+		return fmt.Errorf("user public key is required")
+	}
+	if c.TargetDevicePK.IsZero() {
+		return fmt.Errorf("target device public key is required")
+	}
+	if c.Epoch == 0 {
+		return fmt.Errorf("epoch is required")
+	}
+	return nil
+}
+
+// Builds the instruction for writing path probe samples.
+func BuildWritePathProbeSamplesInstruction(
+	programID solana.PublicKey,
+	signerPK solana.PublicKey,
+	config WritePathProbeSamplesInstructionConfig,
+) (solana.Instruction, error) {
+	if err := config.Validate(); err != nil {
+		return nil, fmt.Errorf("failed to validate config: %w", err)
+	}
+
+	// Serialize the instruction data.
+	data, err := borsh.Serialize(struct {
+		Discriminator              uint8
+		StartTimestampMicroseconds uint64
+		Samples                    []uint32
+	}{
+		Discriminator:              uint8(WritePathProbeSamplesInstructionIndex),
+		StartTimestampMicroseconds: config.StartTimestampMicroseconds,
+		Samples:                    config.Samples,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to serialize args: %w", err)
+	}
+
+	// Derive the PDA.
+	pda, _, err := DerivePathProbeSamplesPDA(
+		programID,
+		signerPK,
+		config.UserPK,
+		config.TargetDevicePK,
+		config.Epoch,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive PDA: %w", err)
+	}
+
+	// Build accounts.
+	accounts := []*solana.AccountMeta{
+		{PublicKey: pda, IsSigner: false, IsWritable: true},
+		{PublicKey: signerPK, IsSigner: true, IsWritable: false},
+		{PublicKey: solana.SystemProgramID, IsSigner: false, IsWritable: false},
+	}
+
+	return &solana.GenericInstruction{
+		ProgID:        programID,
+		AccountValues: accounts,
+		DataBytes:     data,
+	}, nil
+}
@@ -0,0 +1,467 @@
+//! Optional in-process cache of decoded serviceability accounts.
+//!
+//! [`DZClient::with_account_cache`](crate::DZClient::with_account_cache)
+//! enables it. Every cached account remembers the slot it was read at, and a
+//! `programSubscribe` websocket subscription replaces or evicts it as newer
+//! versions are confirmed, so long-running callers can re-read accounts that
+//! rarely change (locations, exchanges, global state) without hitting the RPC
+//! every cycle.
+//!
+//! The cache only answers while the subscription is up. When it drops,
+//! everything is discarded and reads go to the RPC until it is re-established,
+//! since updates may have been missed in between. `max_age` bounds how long an
+//! entry is trusted without a notification, which covers closed accounts: a
+//! closed account leaves the program, and the subscription does not always
+//! report it.
+
+use crate::AccountData;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use log::{debug, warn};
+use solana_account_decoder::{UiAccountData, UiAccountEncoding};
+use solana_client::{
+    pubsub_client::PubsubClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How long the subscription thread waits before resubscribing, and how
+/// often it checks whether the client was dropped.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone)]
+struct CachedAccount {
+    slot: u64,
+    fetched_at: Instant,
+    account_type: u8,
+    data: AccountData,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// Whether the invalidation subscription is up. Nothing is served or
+    /// stored while it is not.
+    live: bool,
+    accounts: HashMap<Pubkey, CachedAccount>,
+    /// Account types whose complete listing is cached, and when it was read.
+    /// Accounts of a listed type are kept in `accounts`.
+    listings: HashMap<u8, Instant>,
+}
+
+/// Hit and miss counts of an [`AccountCache`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccountCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Decoded accounts keyed by pubkey, each tagged with the slot it was read
+/// at. See the [module documentation](self).
+#[derive(Debug)]
+pub struct AccountCache {
+    max_age: Duration,
+    state: Mutex<CacheState>,
+    stats: Mutex<AccountCacheStats>,
+}
+
+impl AccountCache {
+    pub fn new(max_age: Duration) -> Self {
+        AccountCache {
+            max_age,
+            state: Mutex::new(CacheState::default()),
+            stats: Mutex::new(AccountCacheStats::default()),
+        }
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.state.lock().unwrap().live
+    }
+
+    pub fn stats(&self) -> AccountCacheStats {
+        *self.stats.lock().unwrap()
+    }
+
+    /// Mark the invalidation subscription as up or down. Either way the
+    /// cached accounts are dropped: on the way down they stop being kept
+    /// current, and on the way up they may predate the subscription.
+    pub fn set_live(&self, live: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.live = live;
+        state.accounts.clear();
+        state.listings.clear();
+    }
+
+    fn record(&self, hit: bool) {
+        let mut stats = self.stats.lock().unwrap();
+        if hit {
+            stats.hits += 1;
+        } else {
+            stats.misses += 1;
+        }
+    }
+
+    /// The cached account at `pubkey`, if the cache is live and the entry is
+    /// younger than `max_age`.
+    pub fn get(&self, pubkey: &Pubkey) -> Option<AccountData> {
+        let state = self.state.lock().unwrap();
+        let found = state
+            .accounts
+            .get(pubkey)
+            .filter(|cached| state.live && cached.fetched_at.elapsed() < self.max_age)
+            .map(|cached| cached.data.clone());
+        drop(state);
+        self.record(found.is_some());
+        found
+    }
+
+    /// Every cached account of `account_type`, if a complete listing of the
+    /// type was stored with [`Self::insert_listing`] less than `max_age` ago.
+    pub fn get_listing(&self, account_type: u8) -> Option<HashMap<Pubkey, AccountData>> {
+        let state = self.state.lock().unwrap();
+        let found = state
+            .listings
+            .get(&account_type)
+            .filter(|fetched_at| state.live && fetched_at.elapsed() < self.max_age)
+            .map(|_| {
+                state
+                    .accounts
+                    .iter()
+                    .filter(|(_, cached)| cached.account_type == account_type)
+                    .map(|(pubkey, cached)| (*pubkey, cached.data.clone()))
+                    .collect()
+            });
+        drop(state);
+        self.record(found.is_some());
+        found
+    }
+
+    /// Store an account read from the RPC at `slot`. A version already cached
+    /// from the same or a later slot is kept.
+    pub fn insert(&self, pubkey: Pubkey, slot: u64, account_type: u8, data: AccountData) {
+        let mut state = self.state.lock().unwrap();
+        if state.live {
+            Self::upsert(&mut state, pubkey, slot, account_type, data, false);
+        }
+    }
+
+    /// Store the complete listing of `account_type` read from the RPC at
+    /// `slot`, replacing any accounts of the type missing from it.
+    pub fn insert_listing(
+        &self,
+        account_type: u8,
+        slot: u64,
+        accounts: impl IntoIterator<Item = (Pubkey, AccountData)>,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        if !state.live {
+            return;
+        }
+        let accounts: HashMap<Pubkey, AccountData> = accounts.into_iter().collect();
+        state.accounts.retain(|pubkey, cached| {
+            cached.account_type != account_type
+                || cached.slot > slot
+                || accounts.contains_key(pubkey)
+        });
+        for (pubkey, data) in accounts {
+            Self::upsert(&mut state, pubkey, slot, account_type, data, false);
+        }
+        state.listings.insert(account_type, Instant::now());
+    }
+
+    /// Apply a confirmed change seen by the subscription. `None` means the
+    /// account no longer holds a readable serviceability account.
+    pub fn apply_notification(
+        &self,
+        pubkey: Pubkey,
+        slot: u64,
+        account: Option<(u8, AccountData)>,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        if !state.live {
+            return;
+        }
+        match account {
+            Some((account_type, data)) => {
+                Self::upsert(&mut state, pubkey, slot, account_type, data, true)
+            }
+            None => {
+                if state
+                    .accounts
+                    .get(&pubkey)
+                    .is_none_or(|cached| cached.slot <= slot)
+                {
+                    Self::evict(&mut state, &pubkey);
+                }
+            }
+        }
+    }
+
+    /// Drop `pubkeys` and every cached listing, e.g. after this client sent a
+    /// transaction that may have created, changed or closed them.
+    pub fn invalidate(&self, pubkeys: &[Pubkey]) {
+        let mut state = self.state.lock().unwrap();
+        for pubkey in pubkeys {
+            state.accounts.remove(pubkey);
+        }
+        state.listings.clear();
+    }
+
+    fn upsert(
+        state: &mut CacheState,
+        pubkey: Pubkey,
+        slot: u64,
+        account_type: u8,
+        data: AccountData,
+        from_notification: bool,
+    ) {
+        // A read from the RPC at the slot of a notification may predate it;
+        // the notification is authoritative for its slot.
+        let newer = |cached: &CachedAccount| {
+            if from_notification {
+                cached.slot <= slot
+            } else {
+                cached.slot < slot
+            }
+        };
+        if state
+            .accounts
+            .get(&pubkey)
+            .is_some_and(|cached| !newer(cached))
+        {
+            return;
+        }
+        if let Some(previous) = state.accounts.get(&pubkey) {
+            if previous.account_type != account_type {
+                state.listings.remove(&previous.account_type);
+            }
+        }
+        state.accounts.insert(
+            pubkey,
+            CachedAccount {
+                slot,
+                fetched_at: Instant::now(),
+                account_type,
+                data,
+            },
+        );
+    }
+
+    fn evict(state: &mut CacheState, pubkey: &Pubkey) {
+        if let Some(previous) = state.accounts.remove(pubkey) {
+            // The listing no longer knows whether the account still exists.
+            state.listings.remove(&previous.account_type);
+        }
+    }
+}
+
+/// An [`AccountCache`] kept current by a background `programSubscribe`
+/// subscription, which stops when this is dropped.
+pub(crate) struct SubscribedAccountCache {
+    pub(crate) cache: Arc<AccountCache>,
+    stop: Arc<AtomicBool>,
+}
+
+impl SubscribedAccountCache {
+    pub(crate) fn start(ws_url: String, program_id: Pubkey, max_age: Duration) -> Self {
+        let cache = Arc::new(AccountCache::new(max_age));
+        let stop = Arc::new(AtomicBool::new(false));
+        {
+            let cache = cache.clone();
+            let stop = stop.clone();
+            thread::spawn(move || run_subscription(&cache, &ws_url, &program_id, &stop));
+        }
+        SubscribedAccountCache { cache, stop }
+    }
+}
+
+impl Drop for SubscribedAccountCache {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn run_subscription(cache: &AccountCache, ws_url: &str, program_id: &Pubkey, stop: &AtomicBool) {
+    while !stop.load(Ordering::Relaxed) {
+        let options = RpcProgramAccountsConfig {
+            filters: None,
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: None,
+            },
+            with_context: None,
+            sort_results: None,
+        };
+        match PubsubClient::program_subscribe(ws_url, program_id, Some(options)) {
+            Ok((_subscription, receiver)) => {
+                cache.set_live(true);
+                debug!("Account cache subscribed to {program_id}");
+                while !stop.load(Ordering::Relaxed) {
+                    match receiver.recv_timeout(RESUBSCRIBE_DELAY) {
+                        Ok(response) => match decode_notification(&response.value) {
+                            Some((pubkey, account)) => {
+                                cache.apply_notification(pubkey, response.context.slot, account)
+                            }
+                            // Without the pubkey there is no telling what changed.
+                            None => cache.set_live(true),
+                        },
+                        Err(e) if e.is_disconnected() => break,
+                        Err(_) => {}
+                    }
+                }
+                cache.set_live(false);
+            }
+            Err(e) => warn!("Account cache unable to program_subscribe: {e}"),
+        }
+        thread::sleep(RESUBSCRIBE_DELAY);
+    }
+}
+
+/// The pubkey of a notification and, if it still holds a readable
+/// serviceability account, its account type and decoded data.
+fn decode_notification(
+    keyed_account: &solana_rpc_client_api::response::RpcKeyedAccount,
+) -> Option<(Pubkey, Option<(u8, AccountData)>)> {
+    let pubkey = Pubkey::from_str(&keyed_account.pubkey).ok()?;
+    let account = match &keyed_account.account.data {
+        UiAccountData::Binary(data, UiAccountEncoding::Base64) => BASE64_STANDARD
+            .decode(data)
+            .ok()
+            .filter(|bytes| !bytes.is_empty() && keyed_account.account.lamports > 0)
+            .and_then(|bytes| {
+                AccountData::try_from(&bytes[..])
+                    .ok()
+                    .map(|data| (bytes[0], data))
+            }),
+        _ => None,
+    };
+    Some((pubkey, account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        location::{Location, LocationStatus},
+    };
+
+    fn location(code: &str) -> AccountData {
+        AccountData::Location(Location {
+            account_type: AccountType::Location,
+            owner: Pubkey::default(),
+            index: 0,
+            bump_seed: 0,
+            lat: 0.0,
+            lng: 0.0,
+            loc_id: 0,
+            status: LocationStatus::Activated,
+            code: code.to_string(),
+            name: code.to_string(),
+            country: "nl".to_string(),
+            reference_count: 0,
+        })
+    }
+
+    fn live_cache() -> AccountCache {
+        let cache = AccountCache::new(Duration::from_secs(60));
+        cache.set_live(true);
+        cache
+    }
+
+    const LOCATION: u8 = AccountType::Location as u8;
+
+    #[test]
+    fn serves_nothing_until_live() {
+        let cache = AccountCache::new(Duration::from_secs(60));
+        let pubkey = Pubkey::new_unique();
+        cache.insert(pubkey, 10, LOCATION, location("ams"));
+        assert_eq!(cache.get(&pubkey), None);
+
+        cache.set_live(true);
+        cache.insert(pubkey, 10, LOCATION, location("ams"));
+        assert_eq!(cache.get(&pubkey), Some(location("ams")));
+
+        // Losing the subscription drops everything.
+        cache.set_live(false);
+        cache.set_live(true);
+        assert_eq!(cache.get(&pubkey), None);
+        assert_eq!(cache.stats(), AccountCacheStats { hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn keeps_the_newest_slot() {
+        let cache = live_cache();
+        let pubkey = Pubkey::new_unique();
+        cache.apply_notification(pubkey, 20, Some((LOCATION, location("new"))));
+        // A read that started before the notification does not overwrite it.
+        cache.insert(pubkey, 15, LOCATION, location("old"));
+        cache.insert(pubkey, 20, LOCATION, location("old"));
+        assert_eq!(cache.get(&pubkey), Some(location("new")));
+
+        cache.insert(pubkey, 21, LOCATION, location("newer"));
+        assert_eq!(cache.get(&pubkey), Some(location("newer")));
+        cache.apply_notification(pubkey, 19, None);
+        assert_eq!(cache.get(&pubkey), Some(location("newer")));
+        cache.apply_notification(pubkey, 22, None);
+        assert_eq!(cache.get(&pubkey), None);
+    }
+
+    #[test]
+    fn listings_follow_notifications() {
+        let cache = live_cache();
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        assert_eq!(cache.get_listing(LOCATION), None);
+
+        cache.insert_listing(LOCATION, 10, [(a, location("a")), (b, location("b"))]);
+        assert_eq!(cache.get_listing(LOCATION).unwrap().len(), 2);
+
+        // New accounts join the listing; changes replace their entry.
+        cache.apply_notification(c, 11, Some((LOCATION, location("c"))));
+        cache.apply_notification(a, 11, Some((LOCATION, location("a2"))));
+        let listing = cache.get_listing(LOCATION).unwrap();
+        assert_eq!(listing.len(), 3);
+        assert_eq!(listing[&a], location("a2"));
+
+        // A closed account leaves the listing unknown.
+        cache.apply_notification(b, 12, None);
+        assert_eq!(cache.get_listing(LOCATION), None);
+        assert_eq!(cache.get(&a), Some(location("a2")));
+    }
+
+    #[test]
+    fn invalidate_drops_accounts_and_listings() {
+        let cache = live_cache();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        cache.insert_listing(LOCATION, 10, [(a, location("a")), (b, location("b"))]);
+        cache.invalidate(&[a]);
+        assert_eq!(cache.get(&a), None);
+        assert_eq!(cache.get(&b), Some(location("b")));
+        assert_eq!(cache.get_listing(LOCATION), None);
+    }
+
+    #[test]
+    fn entries_expire_after_max_age() {
+        let cache = AccountCache::new(Duration::ZERO);
+        cache.set_live(true);
+        let pubkey = Pubkey::new_unique();
+        cache.insert_listing(LOCATION, 10, [(pubkey, location("a"))]);
+        assert_eq!(cache.get(&pubkey), None);
+        assert_eq!(cache.get_listing(LOCATION), None);
+    }
+}
@@ -0,0 +1,675 @@
+//! Preview of what blocks deleting a serviceability account.
+//!
+//! The program rejects deleting an account that is still referenced
+//! (`ReferenceCountNotZero`, `MulticastGroupNotEmpty`, `DeviceHasInterfaces`)
+//! and a link that is still activated. [`CascadeSnapshot::preview`] walks the
+//! references of an account and lists what blocks its deletion, followed by
+//! every drain, detach and delete step in the order it must be applied, so
+//! operators do not discover the blockers one failed transaction at a time.
+//! Nothing is sent.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use doublezero_serviceability::state::{
+    accesspass::AccessPass,
+    contributor::Contributor,
+    device::Device,
+    exchange::Exchange,
+    link::{Link, LinkStatus},
+    location::Location,
+    multicastgroup::MulticastGroup,
+    tenant::Tenant,
+    user::User,
+};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    commands::{
+        accesspass::list::ListAccessPassCommand, contributor::list::ListContributorCommand,
+        device::list::ListDeviceCommand, exchange::list::ListExchangeCommand,
+        link::list::ListLinkCommand, location::list::ListLocationCommand,
+        multicastgroup::list::ListMulticastGroupCommand, tenant::list::ListTenantCommand,
+        user::list::ListUserCommand,
+    },
+    DoubleZeroClient,
+};
+
+/// An account that can be deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteTarget {
+    Contributor(Pubkey),
+    Location(Pubkey),
+    Exchange(Pubkey),
+    Device(Pubkey),
+    Link(Pubkey),
+    User(Pubkey),
+    MulticastGroup(Pubkey),
+    Tenant(Pubkey),
+}
+
+impl DeleteTarget {
+    pub fn pubkey(&self) -> Pubkey {
+        match self {
+            DeleteTarget::Contributor(pk)
+            | DeleteTarget::Location(pk)
+            | DeleteTarget::Exchange(pk)
+            | DeleteTarget::Device(pk)
+            | DeleteTarget::Link(pk)
+            | DeleteTarget::User(pk)
+            | DeleteTarget::MulticastGroup(pk)
+            | DeleteTarget::Tenant(pk) => *pk,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeleteTarget::Contributor(_) => "contributor",
+            DeleteTarget::Location(_) => "location",
+            DeleteTarget::Exchange(_) => "exchange",
+            DeleteTarget::Device(_) => "device",
+            DeleteTarget::Link(_) => "link",
+            DeleteTarget::User(_) => "user",
+            DeleteTarget::MulticastGroup(_) => "multicast group",
+            DeleteTarget::Tenant(_) => "tenant",
+        }
+    }
+}
+
+/// One change to apply before, or as, the deletion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CascadeAction {
+    /// Delete the account.
+    Delete(DeleteTarget),
+    /// Move an activated link to soft-drained or hard-drained; the program
+    /// only deletes drained links.
+    DrainLink(Pubkey),
+    /// Delete a device interface.
+    DeleteInterface { device_pk: Pubkey, name: String },
+    /// Clear the exchange's device1/device2 slot that holds the device.
+    UnsetExchangeDevice {
+        exchange_pk: Pubkey,
+        device_pk: Pubkey,
+    },
+    /// Remove the user from a multicast group it publishes or subscribes to.
+    LeaveMulticastGroup {
+        user_pk: Pubkey,
+        group_pk: Pubkey,
+        publisher: bool,
+    },
+    /// Remove the tenant from the tenant allowlist of an access pass.
+    RemoveTenantFromAccessPass {
+        accesspass_pk: Pubkey,
+        tenant_pk: Pubkey,
+    },
+}
+
+/// A [`CascadeAction`] with a human-readable description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadeStep {
+    pub action: CascadeAction,
+    pub description: String,
+}
+
+impl fmt::Display for CascadeStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+/// What blocks deleting an account and how to unblock it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadePreview {
+    pub target: DeleteTarget,
+    /// Kind, code and pubkey of the target, e.g. `device dz1 (<pubkey>)`.
+    pub target_label: String,
+    /// Direct references that make the program reject the deletion today.
+    pub blockers: Vec<CascadeStep>,
+    /// Every step in the order it must be applied, dependencies first. The
+    /// last step deletes the target.
+    pub steps: Vec<CascadeStep>,
+    /// Part of the target's reference count not explained by the accounts
+    /// in the snapshot, e.g. references from account types the preview does
+    /// not track. Deletion stays blocked until they are released.
+    pub unexplained_references: u32,
+}
+
+impl CascadePreview {
+    /// Whether the program would reject deleting the target right now.
+    pub fn is_blocked(&self) -> bool {
+        !self.blockers.is_empty() || self.unexplained_references > 0
+    }
+}
+
+/// The accounts whose references can block a deletion.
+#[derive(Debug, Clone, Default)]
+pub struct CascadeSnapshot {
+    pub contributors: HashMap<Pubkey, Contributor>,
+    pub locations: HashMap<Pubkey, Location>,
+    pub exchanges: HashMap<Pubkey, Exchange>,
+    pub devices: HashMap<Pubkey, Device>,
+    pub links: HashMap<Pubkey, Link>,
+    pub users: HashMap<Pubkey, User>,
+    pub multicast_groups: HashMap<Pubkey, MulticastGroup>,
+    pub tenants: HashMap<Pubkey, Tenant>,
+    pub access_passes: HashMap<Pubkey, AccessPass>,
+}
+
+impl CascadeSnapshot {
+    /// Fetch every account type that can hold a reference.
+    pub fn load(client: &dyn DoubleZeroClient) -> eyre::Result<Self> {
+        Ok(Self {
+            contributors: ListContributorCommand {}.execute(client)?,
+            locations: ListLocationCommand.execute(client)?,
+            exchanges: ListExchangeCommand.execute(client)?,
+            devices: ListDeviceCommand.execute(client)?,
+            links: ListLinkCommand.execute(client)?,
+            users: ListUserCommand.execute(client)?,
+            multicast_groups: ListMulticastGroupCommand.execute(client)?,
+            tenants: ListTenantCommand {}.execute(client)?,
+            access_passes: ListAccessPassCommand.execute(client)?,
+        })
+    }
+
+    /// Preview the deletion of `target`. Fails if the target is not in the
+    /// snapshot.
+    pub fn preview(&self, target: DeleteTarget) -> eyre::Result<CascadePreview> {
+        let reference_count = self
+            .reference_count(&target)
+            .ok_or_else(|| eyre::eyre!("{} {} not found", target.kind(), target.pubkey()))?;
+
+        let blockers = self.prerequisites(&CascadeAction::Delete(target));
+        let explained = blockers
+            .iter()
+            .filter(|action| self.counts_as_reference(action))
+            .count() as u32;
+
+        let mut steps = vec![];
+        let mut seen = HashSet::new();
+        self.plan(CascadeAction::Delete(target), &mut steps, &mut seen);
+
+        Ok(CascadePreview {
+            target,
+            target_label: format!("{} {}", target.kind(), self.label(&target)),
+            blockers: blockers.into_iter().map(|a| self.step(a)).collect(),
+            steps: steps.into_iter().map(|a| self.step(a)).collect(),
+            unexplained_references: reference_count.saturating_sub(explained),
+        })
+    }
+
+    /// The reference count the program checks before deleting the target,
+    /// or `None` if the target is not in the snapshot.
+    fn reference_count(&self, target: &DeleteTarget) -> Option<u32> {
+        let count = match target {
+            DeleteTarget::Contributor(pk) => self.contributors.get(pk)?.reference_count,
+            DeleteTarget::Location(pk) => self.locations.get(pk)?.reference_count,
+            DeleteTarget::Exchange(pk) => self.exchanges.get(pk)?.reference_count,
+            DeleteTarget::Device(pk) => self.devices.get(pk)?.reference_count,
+            DeleteTarget::Tenant(pk) => self.tenants.get(pk)?.reference_count,
+            DeleteTarget::MulticastGroup(pk) => {
+                let group = self.multicast_groups.get(pk)?;
+                group.publisher_count + group.subscriber_count
+            }
+            DeleteTarget::Link(pk) => {
+                self.links.get(pk)?;
+                0
+            }
+            DeleteTarget::User(pk) => {
+                self.users.get(pk)?;
+                0
+            }
+        };
+        Some(count)
+    }
+
+    /// Whether the program counts the blocker in the target's reference
+    /// count. Interfaces and link status block deletion on their own.
+    fn counts_as_reference(&self, action: &CascadeAction) -> bool {
+        !matches!(
+            action,
+            CascadeAction::DrainLink(_) | CascadeAction::DeleteInterface { .. }
+        )
+    }
+
+    /// Append the steps needed to apply `action` to `steps`, dependencies
+    /// first, skipping the ones already planned.
+    fn plan(
+        &self,
+        action: CascadeAction,
+        steps: &mut Vec<CascadeAction>,
+        seen: &mut HashSet<CascadeAction>,
+    ) {
+        if seen.contains(&action) {
+            return;
+        }
+        for prerequisite in self.prerequisites(&action) {
+            self.plan(prerequisite, steps, seen);
+        }
+        seen.insert(action.clone());
+        steps.push(action);
+    }
+
+    /// The actions that must be applied directly before `action`, in order.
+    fn prerequisites(&self, action: &CascadeAction) -> Vec<CascadeAction> {
+        let CascadeAction::Delete(target) = action else {
+            return vec![];
+        };
+        match *target {
+            DeleteTarget::Contributor(pk) => {
+                let links = sorted(&self.links, |l| l.contributor_pk == pk, |l| &l.code);
+                let devices = sorted(&self.devices, |d| d.contributor_pk == pk, |d| &d.code);
+                links
+                    .into_iter()
+                    .map(DeleteTarget::Link)
+                    .chain(devices.into_iter().map(DeleteTarget::Device))
+                    .map(CascadeAction::Delete)
+                    .collect()
+            }
+            DeleteTarget::Location(pk) => {
+                sorted(&self.devices, |d| d.location_pk == pk, |d| &d.code)
+                    .into_iter()
+                    .map(|d| CascadeAction::Delete(DeleteTarget::Device(d)))
+                    .collect()
+            }
+            DeleteTarget::Exchange(pk) => {
+                sorted(&self.devices, |d| d.exchange_pk == pk, |d| &d.code)
+                    .into_iter()
+                    .map(|d| CascadeAction::Delete(DeleteTarget::Device(d)))
+                    .collect()
+            }
+            DeleteTarget::Device(pk) => {
+                let mut actions: Vec<CascadeAction> =
+                    sorted(&self.users, |u| u.device_pk == pk, |u| &u.client_ip)
+                        .into_iter()
+                        .map(|u| CascadeAction::Delete(DeleteTarget::User(u)))
+                        .collect();
+                actions.extend(
+                    sorted(
+                        &self.links,
+                        |l| l.side_a_pk == pk || l.side_z_pk == pk,
+                        |l| &l.code,
+                    )
+                    .into_iter()
+                    .map(|l| CascadeAction::Delete(DeleteTarget::Link(l))),
+                );
+                for exchange_pk in sorted(
+                    &self.exchanges,
+                    |x| x.device1_pk == pk || x.device2_pk == pk,
+                    |x| &x.code,
+                ) {
+                    // Each slot holding the device is one reference.
+                    let exchange = &self.exchanges[&exchange_pk];
+                    let slots = [exchange.device1_pk, exchange.device2_pk]
+                        .iter()
+                        .filter(|slot| **slot == pk)
+                        .count();
+                    for _ in 0..slots {
+                        actions.push(CascadeAction::UnsetExchangeDevice {
+                            exchange_pk,
+                            device_pk: pk,
+                        });
+                    }
+                }
+                if let Some(device) = self.devices.get(&pk) {
+                    actions.extend(device.interfaces.iter().map(|iface| {
+                        CascadeAction::DeleteInterface {
+                            device_pk: pk,
+                            name: iface.name.clone(),
+                        }
+                    }));
+                }
+                actions
+            }
+            DeleteTarget::Link(pk) => match self.links.get(&pk) {
+                Some(link) if link.status == LinkStatus::Activated => {
+                    vec![CascadeAction::DrainLink(pk)]
+                }
+                _ => vec![],
+            },
+            DeleteTarget::User(pk) => match self.users.get(&pk) {
+                Some(user) => {
+                    let leave = |groups: &[Pubkey], publisher| {
+                        groups
+                            .iter()
+                            .map(move |group_pk| CascadeAction::LeaveMulticastGroup {
+                                user_pk: pk,
+                                group_pk: *group_pk,
+                                publisher,
+                            })
+                            .collect::<Vec<_>>()
+                    };
+                    let mut actions = leave(&user.publishers, true);
+                    actions.extend(leave(&user.subscribers, false));
+                    actions
+                }
+                None => vec![],
+            },
+            DeleteTarget::MulticastGroup(pk) => {
+                let mut actions = vec![];
+                for user_pk in sorted(
+                    &self.users,
+                    |u| u.publishers.contains(&pk) || u.subscribers.contains(&pk),
+                    |u| &u.client_ip,
+                ) {
+                    let user = &self.users[&user_pk];
+                    for (publisher, groups) in
+                        [(true, &user.publishers), (false, &user.subscribers)]
+                    {
+                        if groups.contains(&pk) {
+                            actions.push(CascadeAction::LeaveMulticastGroup {
+                                user_pk,
+                                group_pk: pk,
+                                publisher,
+                            });
+                        }
+                    }
+                }
+                actions
+            }
+            DeleteTarget::Tenant(pk) => {
+                let mut actions: Vec<CascadeAction> =
+                    sorted(&self.users, |u| u.tenant_pk == pk, |u| &u.client_ip)
+                        .into_iter()
+                        .map(|u| CascadeAction::Delete(DeleteTarget::User(u)))
+                        .collect();
+                actions.extend(
+                    sorted(
+                        &self.access_passes,
+                        |ap| ap.tenant_allowlist.contains(&pk),
+                        |ap| &ap.client_ip,
+                    )
+                    .into_iter()
+                    .map(|accesspass_pk| {
+                        CascadeAction::RemoveTenantFromAccessPass {
+                            accesspass_pk,
+                            tenant_pk: pk,
+                        }
+                    }),
+                );
+                actions
+            }
+        }
+    }
+
+    fn step(&self, action: CascadeAction) -> CascadeStep {
+        let description = match &action {
+            CascadeAction::Delete(target) => {
+                format!("delete {} {}", target.kind(), self.label(target))
+            }
+            CascadeAction::DrainLink(pk) => format!(
+                "drain link {} (activated; set it to soft-drained or hard-drained)",
+                self.label(&DeleteTarget::Link(*pk))
+            ),
+            CascadeAction::DeleteInterface { device_pk, name } => format!(
+                "delete interface {name} of device {}",
+                self.label(&DeleteTarget::Device(*device_pk))
+            ),
+            CascadeAction::UnsetExchangeDevice {
+                exchange_pk,
+                device_pk,
+            } => format!(
+                "remove device {} from exchange {}",
+                self.label(&DeleteTarget::Device(*device_pk)),
+                self.label(&DeleteTarget::Exchange(*exchange_pk))
+            ),
+            CascadeAction::LeaveMulticastGroup {
+                user_pk,
+                group_pk,
+                publisher,
+            } => format!(
+                "remove user {} as {} of multicast group {}",
+                self.label(&DeleteTarget::User(*user_pk)),
+                if *publisher {
+                    "publisher"
+                } else {
+                    "subscriber"
+                },
+                self.label(&DeleteTarget::MulticastGroup(*group_pk))
+            ),
+            CascadeAction::RemoveTenantFromAccessPass {
+                accesspass_pk,
+                tenant_pk,
+            } => {
+                let client_ip = self
+                    .access_passes
+                    .get(accesspass_pk)
+                    .map(|ap| format!(" ({})", ap.client_ip))
+                    .unwrap_or_default();
+                format!(
+                    "remove tenant {} from access pass {accesspass_pk}{client_ip}",
+                    self.label(&DeleteTarget::Tenant(*tenant_pk))
+                )
+            }
+        };
+        CascadeStep {
+            action,
+            description,
+        }
+    }
+
+    /// Code and pubkey of an account, or the pubkey alone when it has no
+    /// code or is not in the snapshot.
+    fn label(&self, target: &DeleteTarget) -> String {
+        let pk = target.pubkey();
+        let code = match target {
+            DeleteTarget::Contributor(pk) => self.contributors.get(pk).map(|c| c.code.clone()),
+            DeleteTarget::Location(pk) => self.locations.get(pk).map(|l| l.code.clone()),
+            DeleteTarget::Exchange(pk) => self.exchanges.get(pk).map(|x| x.code.clone()),
+            DeleteTarget::Device(pk) => self.devices.get(pk).map(|d| d.code.clone()),
+            DeleteTarget::Link(pk) => self.links.get(pk).map(|l| l.code.clone()),
+            DeleteTarget::MulticastGroup(pk) => {
+                self.multicast_groups.get(pk).map(|g| g.code.clone())
+            }
+            DeleteTarget::Tenant(pk) => self.tenants.get(pk).map(|t| t.code.clone()),
+            DeleteTarget::User(pk) => {
+                return match self.users.get(pk) {
+                    Some(user) => format!("{pk} ({})", user.client_ip),
+                    None => pk.to_string(),
+                };
+            }
+        };
+        match code {
+            Some(code) if !code.is_empty() => format!("{code} ({pk})"),
+            _ => pk.to_string(),
+        }
+    }
+}
+
+/// Keys of the accounts matching `filter`, sorted by `key` then pubkey so
+/// previews are stable.
+fn sorted<T, K: Ord>(
+    accounts: &HashMap<Pubkey, T>,
+    filter: impl Fn(&T) -> bool,
+    key: impl Fn(&T) -> &K,
+) -> Vec<Pubkey> {
+    let mut matching: Vec<(&Pubkey, &T)> = accounts.iter().filter(|(_, a)| filter(a)).collect();
+    matching.sort_by(|(pk_a, a), (pk_b, b)| key(a).cmp(key(b)).then(pk_a.cmp(pk_b)));
+    matching.into_iter().map(|(pk, _)| *pk).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_program_common::types::NetworkV4;
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        contributor::ContributorStatus,
+        device::DeviceStatus,
+        interface::Interface,
+        user::{UserCYOA, UserStatus, UserType},
+    };
+    use std::net::Ipv4Addr;
+
+    fn user(device_pk: Pubkey, last_octet: u8) -> User {
+        User {
+            account_type: AccountType::User,
+            owner: Pubkey::default(),
+            index: 0,
+            bump_seed: 0,
+            user_type: UserType::Multicast,
+            tenant_pk: Pubkey::default(),
+            device_pk,
+            cyoa_type: UserCYOA::GREOverDIA,
+            client_ip: Ipv4Addr::new(10, 0, 0, last_octet),
+            dz_ip: Ipv4Addr::UNSPECIFIED,
+            tunnel_id: 0,
+            tunnel_net: NetworkV4::default(),
+            status: UserStatus::Activated,
+            publishers: vec![],
+            subscribers: vec![],
+            validator_pubkey: Pubkey::default(),
+            tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+            tunnel_flags: 0,
+            bgp_status: Default::default(),
+            last_bgp_up_at: 0,
+            last_bgp_reported_at: 0,
+            bgp_rtt_ns: 0,
+            feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
+        }
+    }
+
+    fn device(code: &str, contributor_pk: Pubkey, reference_count: u32) -> Device {
+        Device {
+            account_type: AccountType::Device,
+            code: code.to_string(),
+            contributor_pk,
+            reference_count,
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        }
+    }
+
+    fn link(code: &str, side_a_pk: Pubkey, side_z_pk: Pubkey, status: LinkStatus) -> Link {
+        Link {
+            account_type: AccountType::Link,
+            code: code.to_string(),
+            side_a_pk,
+            side_z_pk,
+            status,
+            ..Default::default()
+        }
+    }
+
+    fn descriptions(steps: &[CascadeStep]) -> Vec<String> {
+        steps.iter().map(|s| s.description.clone()).collect()
+    }
+
+    #[test]
+    fn test_preview_device_orders_dependencies_first() {
+        let [d1, d2, l1, u1, mg] = std::array::from_fn(|_| Pubkey::new_unique());
+        let contributor_pk = Pubkey::new_unique();
+
+        let mut device1 = device("dz1", contributor_pk, 3);
+        device1.interfaces = vec![Interface {
+            name: "Ethernet1".to_string(),
+            ..Default::default()
+        }];
+
+        let mut subscriber = user(d1, 1);
+        subscriber.subscribers = vec![mg];
+
+        let snapshot = CascadeSnapshot {
+            devices: HashMap::from([(d1, device1), (d2, device("dz2", contributor_pk, 1))]),
+            links: HashMap::from([(l1, link("dz1:dz2", d1, d2, LinkStatus::Activated))]),
+            users: HashMap::from([(u1, subscriber)]),
+            multicast_groups: HashMap::from([(
+                mg,
+                MulticastGroup {
+                    code: "mg1".to_string(),
+                    subscriber_count: 1,
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let preview = snapshot.preview(DeleteTarget::Device(d1)).unwrap();
+        assert!(preview.is_blocked());
+        // The user, the link and the interface.
+        assert_eq!(preview.blockers.len(), 3);
+        // The reference count holds one more reference than the user and link.
+        assert_eq!(preview.unexplained_references, 1);
+        assert_eq!(
+            descriptions(&preview.steps),
+            vec![
+                format!("remove user {u1} (10.0.0.1) as subscriber of multicast group mg1 ({mg})"),
+                format!("delete user {u1} (10.0.0.1)"),
+                format!(
+                    "drain link dz1:dz2 ({l1}) (activated; set it to soft-drained or hard-drained)"
+                ),
+                format!("delete link dz1:dz2 ({l1})"),
+                format!("delete interface Ethernet1 of device dz1 ({d1})"),
+                format!("delete device dz1 ({d1})"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_contributor_deduplicates_shared_links() {
+        let [d1, d2, l1] = std::array::from_fn(|_| Pubkey::new_unique());
+        let contributor_pk = Pubkey::new_unique();
+
+        let mut drained = link("dz1:dz2", d1, d2, LinkStatus::SoftDrained);
+        drained.contributor_pk = contributor_pk;
+
+        let snapshot = CascadeSnapshot {
+            contributors: HashMap::from([(
+                contributor_pk,
+                Contributor {
+                    account_type: AccountType::Contributor,
+                    owner: Pubkey::default(),
+                    index: 1,
+                    bump_seed: 0,
+                    status: ContributorStatus::Activated,
+                    code: "co01".to_string(),
+                    reference_count: 3,
+                    ops_manager_pk: Pubkey::default(),
+                },
+            )]),
+            devices: HashMap::from([
+                (d1, device("dz1", contributor_pk, 1)),
+                (d2, device("dz2", contributor_pk, 1)),
+            ]),
+            links: HashMap::from([(l1, drained)]),
+            ..Default::default()
+        };
+
+        let preview = snapshot
+            .preview(DeleteTarget::Contributor(contributor_pk))
+            .unwrap();
+        assert_eq!(preview.blockers.len(), 3);
+        assert_eq!(preview.unexplained_references, 0);
+        assert_eq!(
+            descriptions(&preview.steps),
+            vec![
+                format!("delete link dz1:dz2 ({l1})"),
+                format!("delete device dz1 ({d1})"),
+                format!("delete device dz2 ({d2})"),
+                format!("delete contributor co01 ({contributor_pk})"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_unblocked_and_missing() {
+        let d1 = Pubkey::new_unique();
+        let snapshot = CascadeSnapshot {
+            devices: HashMap::from([(d1, device("dz1", Pubkey::new_unique(), 0))]),
+            ..Default::default()
+        };
+
+        let preview = snapshot.preview(DeleteTarget::Device(d1)).unwrap();
+        assert!(!preview.is_blocked());
+        assert_eq!(
+            descriptions(&preview.steps),
+            vec![format!("delete device dz1 ({d1})")]
+        );
+
+        assert!(snapshot
+            .preview(DeleteTarget::Tenant(Pubkey::new_unique()))
+            .is_err());
+    }
+}
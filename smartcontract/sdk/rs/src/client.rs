@@ -1,14 +1,14 @@
-use backon::{BlockingRetryable, ExponentialBuilder};
+use backon::BlockingRetryable;
 use base64::{engine::general_purpose, Engine};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use doublezero_config::Environment;
-use std::time::Duration;
 
 use crate::config::default_program_id;
 use doublezero_serviceability::{
     error::DoubleZeroError, instructions::*, pda::get_permission_pda,
     state::accounttype::AccountType,
 };
+use doublezero_telemetry::instructions::TelemetryInstruction;
 use eyre::{bail, eyre, OptionExt};
 use log::debug;
 use solana_account_decoder::UiAccountEncoding;
@@ -17,15 +17,18 @@ use solana_client::{
     rpc_client::RpcClient,
     rpc_config::{
         RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig,
-        RpcTransactionConfig,
+        RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
     },
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_commitment_config::CommitmentConfig;
 use solana_compute_budget_interface::ComputeBudgetInstruction;
-use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind as ClientErrorKind};
+use solana_rpc_client_api::client_error::{
+    Error as ClientError, ErrorKind as ClientErrorKind, Result as ClientResult,
+};
 use solana_sdk::{
     account::Account,
+    hash::Hash,
     instruction::{AccountMeta, Instruction, InstructionError},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -45,14 +48,21 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc, Mutex,
     },
+    time::Duration,
 };
 
 use crate::{
+    cache::{AccountCache, SubscribedAccountCache},
     config::*,
+    confirm,
     doublezeroclient::DoubleZeroClient,
+    dryrun::DryRunTransaction,
     dztransaction::DZTransaction,
-    errors::{SimulationError, SimulationTransactionError},
+    endpoints::{RpcEndpointConfig, RpcEndpoints, RpcReadMode},
+    errors::{with_decoded_error, ErrorProgram, SimulationError, SimulationTransactionError},
+    health::NetworkHealth,
     keypair::load_keypair,
+    policy::ClientPolicy,
     rpckeyedaccount_decode::rpckeyedaccount_decode,
     AccountData,
 };
@@ -63,11 +73,14 @@ use crate::{
 // which are not re-exported through `solana-sdk`.
 const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
 const MAX_HEAP_FRAME_BYTES: u32 = 256 * 1024;
+// Largest serialized transaction the cluster accepts; mirrors `solana-packet`'s
+// `PACKET_DATA_SIZE`, which is likewise not re-exported through `solana-sdk`.
+const PACKET_DATA_SIZE: usize = 1232;
 
 pub struct DZClient {
-    rpc_url: String,
-    client: RpcClient,
-    rpc_ws_url: String,
+    /// The ledger endpoint in use and any fallbacks. See
+    /// [`DZClient::with_fallback_endpoints`].
+    endpoints: RpcEndpoints,
     payer: Option<Keypair>,
     pub(crate) program_id: Pubkey,
     /// Memoizes the payer's Permission PDA lookup so authorized transactions
@@ -75,6 +88,16 @@ pub struct DZClient {
     /// lifetime). `None` = not yet resolved; `Some(None)` = resolved, no
     /// on-chain Permission account; `Some(Some(meta))` = resolved and present.
     permission_account_cache: Mutex<Option<Option<AccountMeta>>>,
+    /// When set, transactions are built and recorded here instead of being
+    /// sent. See [`DZClient::with_dry_run`].
+    dry_run: Option<Mutex<Vec<DryRunTransaction>>>,
+    /// Timeouts and retry behaviour applied to every RPC call and
+    /// transaction send. See [`DZClient::with_policy`].
+    policy: ClientPolicy,
+    /// When set, `get` and `gets` are answered from decoded accounts kept
+    /// current over a websocket subscription. See
+    /// [`DZClient::with_account_cache`].
+    account_cache: Option<SubscribedAccountCache>,
 }
 
 impl DZClient {
@@ -91,7 +114,12 @@ impl DZClient {
         let rpc_ws_url =
             convert_ws_moniker(websocket_url.unwrap_or(config.websocket_url.unwrap_or(ws_url)));
 
-        let client = RpcClient::new_with_commitment(rpc_url.clone(), CommitmentConfig::confirmed());
+        let policy = ClientPolicy::resolve(config.client_policy.as_ref())?;
+        let endpoints = RpcEndpoints::single(rpc_url, rpc_ws_url, &policy).with_fallbacks(
+            &config.fallback_endpoints,
+            config.rpc_read_mode,
+            &policy,
+        )?;
         let payer = load_keypair(keypair, None, config.keypair_path)
             .ok()
             .map(|r| r.keypair);
@@ -110,12 +138,13 @@ impl DZClient {
         };
 
         Ok(DZClient {
-            rpc_url,
-            client,
-            rpc_ws_url,
+            endpoints,
             payer,
             program_id,
             permission_account_cache: Mutex::new(None),
+            dry_run: None,
+            policy,
+            account_cache: None,
         })
     }
 
@@ -133,6 +162,12 @@ impl DZClient {
     /// preserved. The context's `keypair_path` is used only as the lower-
     /// precedence config/default path; passing it as the CLI source would mask
     /// the env var.
+    ///
+    /// The timeout and retry policy honours the `DOUBLEZERO_RPC_*` /
+    /// `DOUBLEZERO_CONFIRM_*` environment variables only; callers layering a
+    /// persisted or per-command policy apply it with [`DZClient::with_policy`],
+    /// and persisted fallback endpoints with
+    /// [`DZClient::with_fallback_endpoints`].
     #[cfg(feature = "cli-context")]
     pub fn from_context(
         ctx: &doublezero_cli_core::CliContext,
@@ -141,7 +176,8 @@ impl DZClient {
         let rpc_url = ctx.ledger_rpc_url.clone();
         let rpc_ws_url = ctx.ledger_ws_rpc_url.clone();
 
-        let client = RpcClient::new_with_commitment(rpc_url.clone(), CommitmentConfig::confirmed());
+        let policy = ClientPolicy::resolve(None)?;
+        let endpoints = RpcEndpoints::single(rpc_url, rpc_ws_url, &policy);
 
         let default_path = ctx
             .keypair_path
@@ -152,21 +188,108 @@ impl DZClient {
             .map(|r| r.keypair);
 
         Ok(DZClient {
-            rpc_url,
-            client,
-            rpc_ws_url,
+            endpoints,
             payer,
             program_id: ctx.serviceability_program_id,
             permission_account_cache: Mutex::new(None),
+            dry_run: None,
+            policy,
+            account_cache: None,
         })
     }
 
+    /// Switch the client to dry-run mode: every transaction is fully built
+    /// (recent blockhash included) but recorded unsigned instead of being
+    /// sent, and the default signature is returned in place of the real one.
+    /// Recorded transactions are drained with
+    /// [`DZClient::take_dry_run_transactions`].
+    pub fn with_dry_run(mut self) -> Self {
+        self.dry_run = Some(Mutex::new(Vec::new()));
+        self
+    }
+
+    /// Replace the client's timeout and retry policy, e.g. for a command that
+    /// needs a longer confirmation window than the configured default. The
+    /// underlying RPC client is rebuilt so the new timeouts take effect.
+    pub fn with_policy(mut self, policy: ClientPolicy) -> Self {
+        self.endpoints = self.endpoints.with_policy(&policy);
+        self.policy = policy;
+        self
+    }
+
+    /// Add ledger endpoints to fall back to, in order, when a request to the
+    /// active one fails with a transient network error. A fallback is only
+    /// switched to if it answers a health probe, unless none does. With
+    /// [`RpcReadMode::Fastest`] every endpoint is probed now and the client
+    /// starts on the quickest.
+    pub fn with_fallback_endpoints(
+        mut self,
+        fallbacks: &[RpcEndpointConfig],
+        read_mode: RpcReadMode,
+    ) -> eyre::Result<Self> {
+        self.endpoints = self
+            .endpoints
+            .with_fallbacks(fallbacks, read_mode, &self.policy)?;
+        Ok(self)
+    }
+
+    /// Cache the accounts read with `get` and `gets` in process. A background
+    /// `programSubscribe` subscription on the active websocket endpoint
+    /// replaces or evicts cached accounts as they change, and the cache is
+    /// bypassed while it is down. Entries are re-read at the latest after
+    /// `max_age`, and the accounts written by this client's own transactions
+    /// are dropped as they are sent.
+    pub fn with_account_cache(mut self, max_age: Duration) -> Self {
+        self.account_cache = Some(SubscribedAccountCache::start(
+            self.get_ws().clone(),
+            self.program_id,
+            max_age,
+        ));
+        self
+    }
+
+    pub fn account_cache(&self) -> Option<&AccountCache> {
+        self.account_cache.as_ref().map(|cached| &*cached.cache)
+    }
+
+    pub fn policy(&self) -> &ClientPolicy {
+        &self.policy
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    pub fn take_dry_run_transactions(&self) -> Vec<DryRunTransaction> {
+        self.dry_run
+            .as_ref()
+            .map(|captured| std::mem::take(&mut *captured.lock().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// Record `transaction` when in dry-run mode. Returns the placeholder
+    /// signature to hand back to the caller, or `None` if the transaction
+    /// should be sent.
+    fn capture_dry_run(
+        &self,
+        transaction: &Transaction,
+        program_id: &Pubkey,
+        summaries: &[(String, String)],
+    ) -> eyre::Result<Option<Signature>> {
+        let Some(captured) = self.dry_run.as_ref() else {
+            return Ok(None);
+        };
+        let dry_run = DryRunTransaction::new(transaction, program_id, summaries)?;
+        captured.lock().unwrap().push(dry_run);
+        Ok(Some(Signature::default()))
+    }
+
     pub fn get_rpc(&self) -> &String {
-        &self.rpc_url
+        &self.endpoints.active().rpc_url
     }
 
     pub fn rpc_client(&self) -> &RpcClient {
-        &self.client
+        &self.endpoints.active().client
     }
 
     pub fn payer_keypair(&self) -> Option<&Keypair> {
@@ -174,7 +297,7 @@ impl DZClient {
     }
 
     pub fn get_ws(&self) -> &String {
-        &self.rpc_ws_url
+        &self.endpoints.active().ws_url
     }
 
     pub fn get_program_id(&self) -> &Pubkey {
@@ -185,44 +308,67 @@ impl DZClient {
         Environment::from_program_id(&self.program_id.to_string()).unwrap_or_default()
     }
 
-    fn rpc_retry_builder() -> ExponentialBuilder {
-        ExponentialBuilder::new()
-            .with_max_times(3)
-            .with_min_delay(Duration::from_millis(500))
-            .with_max_delay(Duration::from_secs(5))
-    }
-
     /// Assemble the full instruction list for a serviceability transaction.
     ///
     /// Every transaction is prefixed with the protocol-max compute-unit and
     /// heap-frame requests (serviceability runs on a dedicated private cluster
-    /// where this is always required — see the module-level constants). The main
-    /// instruction's trailing accounts are always `[payer, system]`, optionally
-    /// followed by the payer's Permission PDA. The Permission account MUST stay
-    /// last because `authorize()` reads it as the final account after the
-    /// expected ones have been consumed.
+    /// where this is always required — see the module-level constants), followed
+    /// by one program instruction per `batch` entry. Each program instruction's
+    /// trailing accounts are always `[payer, system]`, optionally followed by the
+    /// payer's Permission PDA. The Permission account MUST stay last because
+    /// `authorize()` reads it as the final account after the expected ones have
+    /// been consumed.
     fn assemble_instructions(
         program_id: &Pubkey,
         payer: &Pubkey,
-        instruction: &DoubleZeroInstruction,
-        accounts: Vec<AccountMeta>,
+        batch: &[(DoubleZeroInstruction, Vec<AccountMeta>)],
+        optional_accounts: &[AccountMeta],
         permission: Option<AccountMeta>,
     ) -> Vec<Instruction> {
-        let data = instruction.pack();
-
         let mut trailing = vec![
             AccountMeta::new(*payer, true),
             AccountMeta::new(program::id(), false),
         ];
+        trailing.extend_from_slice(optional_accounts);
         if let Some(permission) = permission {
             trailing.push(permission);
         }
 
-        vec![
+        let mut instructions = vec![
             ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNIT_LIMIT),
             ComputeBudgetInstruction::request_heap_frame(MAX_HEAP_FRAME_BYTES),
-            Instruction::new_with_bytes(*program_id, &data, [accounts, trailing].concat()),
-        ]
+        ];
+        instructions.extend(batch.iter().map(|(instruction, accounts)| {
+            Instruction::new_with_bytes(
+                *program_id,
+                &instruction.pack(),
+                [accounts.clone(), trailing.clone()].concat(),
+            )
+        }));
+        instructions
+    }
+
+    /// Whether `batch` fits in a single authorized transaction signed by `payer`.
+    ///
+    /// The size is measured with a Permission account appended, whether or not the
+    /// payer has one, so a batch that fits here still fits once the account is
+    /// resolved at send time.
+    pub fn batch_fits_in_transaction(
+        program_id: &Pubkey,
+        payer: &Pubkey,
+        batch: &[(DoubleZeroInstruction, Vec<AccountMeta>)],
+    ) -> bool {
+        let (permission_pda, _) = get_permission_pda(program_id, payer);
+        let instructions = Self::assemble_instructions(
+            program_id,
+            payer,
+            batch,
+            &[],
+            Some(AccountMeta::new_readonly(permission_pda, false)),
+        );
+        let transaction = Transaction::new_with_payer(&instructions, Some(payer));
+        bincode::serde::encode_to_vec(&transaction, bincode::config::legacy())
+            .is_ok_and(|bytes| bytes.len() <= PACKET_DATA_SIZE)
     }
 
     /// Whether landing `instruction` can change whether the payer's Permission PDA
@@ -293,13 +439,8 @@ impl DZClient {
         // cached as "no account": doing so would latch the legacy allowlist path until
         // the next create/delete invalidation or a restart (see issue #4011). Leave the
         // cache empty so the next authorized transaction re-resolves.
-        match (|| {
-            self.client
-                .get_account_with_commitment(&permission_pda, self.client.commitment())
-        })
-        .retry(Self::rpc_retry_builder())
-        .when(Self::is_retryable_rpc_error)
-        .call()
+        match self
+            .with_rpc(|rpc| rpc.get_account_with_commitment(&permission_pda, rpc.commitment()))
         {
             Ok(response) => {
                 let meta = response
@@ -314,8 +455,8 @@ impl DZClient {
 
     fn execute_transaction_inner(
         &self,
-        instruction: DoubleZeroInstruction,
-        accounts: Vec<AccountMeta>,
+        batch: Vec<(DoubleZeroInstruction, Vec<AccountMeta>)>,
+        optional_accounts: &[AccountMeta],
         quiet: bool,
         with_permission: bool,
     ) -> eyre::Result<Signature> {
@@ -345,14 +486,24 @@ impl DZClient {
             let instructions = Self::assemble_instructions(
                 &self.program_id,
                 &payer.pubkey(),
-                &instruction,
-                accounts.clone(),
+                &batch,
+                optional_accounts,
                 permission.clone(),
             );
 
             let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
 
-            let blockhash = self.client.get_latest_blockhash().map_err(|e| eyre!(e))?;
+            let blockhash = self.get_latest_blockhash()?;
+            transaction.message.recent_blockhash = blockhash;
+            let summaries: Vec<(String, String)> = batch
+                .iter()
+                .map(|(instruction, _)| (instruction.get_name(), instruction.get_args()))
+                .collect();
+            if let Some(signature) =
+                self.capture_dry_run(&transaction, &self.program_id, &summaries)?
+            {
+                return Ok(signature);
+            }
             transaction.sign(&[&payer], blockhash);
 
             debug!("Sending transaction: {transaction:?}");
@@ -363,17 +514,34 @@ impl DZClient {
                 ..RpcSendTransactionConfig::default()
             };
 
-            let send_result = self
-                .client
-                .send_and_confirm_transaction_with_spinner_and_config(
+            // Resending the same signed transaction on a transient error is safe: a
+            // copy that already landed is deduplicated by the cluster on its signature.
+            let send_result = self.with_rpc(|rpc| {
+                confirm::send_and_confirm_transaction(
+                    rpc,
+                    self.get_ws(),
                     &transaction,
-                    self.client.commitment(),
                     send_config,
-                );
+                    self.policy.confirm_timeout,
+                )
+            });
 
             // Maintain the permission cache before inspecting the send result — the tx
             // may have landed on-chain even on an Err (see `note_transaction_sent`).
-            self.note_transaction_sent(&instruction);
+            for (instruction, _) in &batch {
+                self.note_transaction_sent(instruction);
+            }
+            // Same reasoning for cached accounts: don't serve what this
+            // transaction may have just written.
+            if let Some(cache) = self.account_cache() {
+                let written: Vec<Pubkey> = batch
+                    .iter()
+                    .flat_map(|(_, accounts)| accounts.iter())
+                    .filter(|meta| meta.is_writable)
+                    .map(|meta| meta.pubkey)
+                    .collect();
+                cache.invalidate(&written);
+            }
 
             let client_err = match send_result {
                 Ok(sig) => return Ok(sig),
@@ -398,13 +566,13 @@ impl DZClient {
 
             // The tx may have landed onchain (skip_preflight=true means failing
             // txs still land). Fetch logs from the confirmed tx if available.
-            let program_logs = self
-                .client
+            let rpc = self.rpc_client();
+            let program_logs = rpc
                 .get_transaction_with_config(
                     &signature,
                     RpcTransactionConfig {
                         encoding: Some(UiTransactionEncoding::Base64),
-                        commitment: Some(self.client.commitment()),
+                        commitment: Some(rpc.commitment()),
                         max_supported_transaction_version: Some(0),
                     },
                 )
@@ -482,6 +650,20 @@ impl DZClient {
         }
     }
 
+    /// Run `request` against the active ledger endpoint, retrying transient
+    /// network errors per the client policy. Each retry first fails over to the
+    /// next healthy fallback endpoint, if any are configured.
+    fn with_rpc<T>(
+        &self,
+        mut request: impl FnMut(&RpcClient) -> ClientResult<T>,
+    ) -> ClientResult<T> {
+        (|| request(self.rpc_client()))
+            .retry(self.policy.retry_builder())
+            .when(Self::is_retryable_rpc_error)
+            .notify(|_, _| self.endpoints.fail_over())
+            .call()
+    }
+
     /// Returns true for transient network errors that are worth retrying.
     /// Returns false for permanent errors like AccountNotFound or RPC response errors.
     fn is_retryable_rpc_error(err: &ClientError) -> bool {
@@ -491,6 +673,25 @@ impl DZClient {
         )
     }
 
+    fn get_latest_blockhash(&self) -> eyre::Result<Hash> {
+        self.with_rpc(|rpc| rpc.get_latest_blockhash())
+            .map_err(|e| eyre!(e))
+    }
+
+    /// Send a signed transaction and wait for its confirmation over the
+    /// websocket (see [`confirm::send_and_confirm_transaction`]).
+    fn send_and_confirm_transaction(&self, transaction: &Transaction) -> ClientResult<Signature> {
+        self.with_rpc(|rpc| {
+            confirm::send_and_confirm_transaction(
+                rpc,
+                self.get_ws(),
+                transaction,
+                RpcSendTransactionConfig::default(),
+                self.policy.confirm_timeout,
+            )
+        })
+    }
+
     pub fn get_balance(&self) -> eyre::Result<u64> {
         let payer = self
             .payer
@@ -498,35 +699,32 @@ impl DZClient {
             .ok_or_else(|| eyre!("No payer configured for client!"))?;
 
         let pubkey = payer.pubkey();
-        (|| self.client.get_balance(&pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_balance(&pubkey))
             .map_err(|e| eyre!(e))
     }
 
     pub fn get_epoch(&self) -> eyre::Result<u64> {
-        (|| self.client.get_epoch_info())
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_epoch_info())
             .map_err(|e| eyre!(e))
             .map(|info| info.epoch)
     }
 
+    pub fn get_slot(&self) -> eyre::Result<u64> {
+        self.with_rpc(|rpc| rpc.get_slot()).map_err(|e| eyre!(e))
+    }
+
+    /// Summarize the health of the whole network from one account snapshot.
+    pub fn network_health(&self) -> eyre::Result<NetworkHealth> {
+        NetworkHealth::load(self)
+    }
+
     pub fn get_account(&self, pubkey: Pubkey) -> eyre::Result<Account> {
-        (|| self.client.get_account(&pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_account(&pubkey))
             .map_err(|e| eyre!(e))
     }
 
     pub fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> eyre::Result<u64> {
-        (|| self.client.get_minimum_balance_for_rent_exemption(data_len))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_minimum_balance_for_rent_exemption(data_len))
             .map_err(|e| eyre!(e))
     }
 
@@ -538,20 +736,80 @@ impl DZClient {
         let ix = solana_system_interface::instruction::transfer(&payer.pubkey(), &to, lamports);
         let mut transaction =
             solana_sdk::transaction::Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
-        let blockhash = self.client.get_latest_blockhash().map_err(|e| eyre!(e))?;
+        let blockhash = self.get_latest_blockhash()?;
+        transaction.message.recent_blockhash = blockhash;
+        if let Some(signature) = self.capture_dry_run(
+            &transaction,
+            &program::ID,
+            &[(
+                "Transfer".to_string(),
+                format!("to: {to}, lamports: {lamports}"),
+            )],
+        )? {
+            return Ok(signature);
+        }
         transaction.sign(&[payer], blockhash);
-        self.client
-            .send_and_confirm_transaction(&transaction)
+        self.send_and_confirm_transaction(&transaction)
             .map_err(|e| eyre!(e))
     }
 
+    /// Ask the ledger's faucet for `lamports` to the payer and wait until the
+    /// airdrop lands. Only clusters with a faucet (devnet, testnet, local)
+    /// answer this.
+    pub fn request_airdrop(&self, lamports: u64) -> eyre::Result<Signature> {
+        let payer = self
+            .payer
+            .as_ref()
+            .ok_or_eyre("No default signer found, run \"doublezero keygen\" to create a new one")?;
+        if self.is_dry_run() {
+            eyre::bail!("Airdrops cannot be recorded in dry-run mode");
+        }
+        let pubkey = payer.pubkey();
+        let signature = self
+            .with_rpc(|rpc| rpc.request_airdrop(&pubkey, lamports))
+            .map_err(|e| eyre!(e))?;
+        self.with_rpc(|rpc| rpc.poll_for_signature(&signature))
+            .map_err(|e| eyre!(e))?;
+        Ok(signature)
+    }
+
+    pub fn execute_telemetry_transaction(
+        &self,
+        telemetry_program_id: &Pubkey,
+        instruction: TelemetryInstruction,
+        accounts: Vec<AccountMeta>,
+    ) -> eyre::Result<Signature> {
+        let payer = self
+            .payer
+            .as_ref()
+            .ok_or_eyre("No default signer found, run \"doublezero keygen\" to create a new one")?;
+        let data = instruction
+            .pack()
+            .map_err(|e| eyre!("failed to serialize instruction: {e}"))?;
+        let ix = Instruction::new_with_bytes(*telemetry_program_id, &data, accounts);
+        let mut transaction = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        let blockhash = self.get_latest_blockhash()?;
+        transaction.message.recent_blockhash = blockhash;
+        if let Some(signature) = self.capture_dry_run(
+            &transaction,
+            telemetry_program_id,
+            &[("Telemetry".to_string(), format!("{instruction:?}"))],
+        )? {
+            return Ok(signature);
+        }
+        transaction.sign(&[payer], blockhash);
+        self.send_and_confirm_transaction(&transaction)
+            .map_err(|e| {
+                let tx_err = Self::parse_transaction_error(&e);
+                with_decoded_error(ErrorProgram::Telemetry, tx_err.as_ref(), eyre!(e))
+            })
+    }
+
     pub fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> eyre::Result<Vec<Option<Account>>> {
         let mut results = Vec::with_capacity(pubkeys.len());
         for chunk in pubkeys.chunks(100) {
-            let accounts = (|| self.client.get_multiple_accounts(chunk))
-                .retry(Self::rpc_retry_builder())
-                .when(Self::is_retryable_rpc_error)
-                .call()
+            let accounts = self
+                .with_rpc(|rpc| rpc.get_multiple_accounts(chunk))
                 .map_err(|e| eyre!(e))?;
             results.extend(accounts);
         }
@@ -606,8 +864,12 @@ impl DZClient {
                 sort_results: None,
             };
             let (mut _client, receiver) =
-                PubsubClient::program_subscribe(&self.rpc_ws_url, &self.program_id, Some(options))
-                    .map_err(|_| eyre!("Unable to program_subscribe"))?;
+                PubsubClient::program_subscribe(self.get_ws(), &self.program_id, Some(options))
+                    .map_err(|_| {
+                        // Let the caller's next attempt subscribe through a fallback.
+                        self.endpoints.fail_over();
+                        eyre!("Unable to program_subscribe")
+                    })?;
 
             for response in receiver {
                 let event = response.value;
@@ -623,21 +885,13 @@ impl DZClient {
     pub fn get_logs(&self, pubkey: &Pubkey) -> eyre::Result<Vec<String>> {
         let mut errors: Vec<String> = Vec::new();
 
-        let signatures = (|| self.client.get_signatures_for_address(pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()?;
+        let signatures = self.with_rpc(|rpc| rpc.get_signatures_for_address(pubkey))?;
 
         for signature_info in signatures {
             let signature = Signature::from_str(&signature_info.signature)?;
 
-            if let Ok(trans) = (|| {
-                self.client
-                    .get_transaction(&signature, UiTransactionEncoding::Base64)
-            })
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+            if let Ok(trans) =
+                self.with_rpc(|rpc| rpc.get_transaction(&signature, UiTransactionEncoding::Base64))
             {
                 if let EncodedTransaction::Binary(_, base) = trans.transaction.transaction {
                     if base == TransactionBinaryEncoding::Base64 {
@@ -655,6 +909,39 @@ impl DZClient {
 
         Ok(errors)
     }
+
+    /// Stream the logs of confirmed transactions that mention `pubkey`,
+    /// calling `action` with each signature and its log lines until it
+    /// returns `false`. Failed transactions are skipped.
+    pub fn follow_logs<F>(&self, pubkey: &Pubkey, mut action: F) -> eyre::Result<()>
+    where
+        F: FnMut(String, Vec<String>) -> bool,
+    {
+        loop {
+            let (mut _client, receiver) = PubsubClient::logs_subscribe(
+                self.get_ws(),
+                RpcTransactionLogsFilter::Mentions(vec![pubkey.to_string()]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )
+            .map_err(|_| {
+                // Let the caller's next attempt subscribe through a fallback.
+                self.endpoints.fail_over();
+                eyre!("Unable to logs_subscribe")
+            })?;
+
+            for response in receiver {
+                let logs = response.value;
+                if logs.err.is_some() {
+                    continue;
+                }
+                if !action(logs.signature, logs.logs) {
+                    return Ok(());
+                }
+            }
+        }
+    }
 }
 
 impl DoubleZeroClient for DZClient {
@@ -671,24 +958,18 @@ impl DoubleZeroClient for DZClient {
 
     fn get_balance(&self) -> eyre::Result<u64> {
         let payer = self.get_payer();
-        (|| self.client.get_balance(&payer))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_balance(&payer))
             .map_err(|e| eyre!(e))
     }
 
     fn get_epoch(&self) -> eyre::Result<u64> {
-        (|| self.client.get_epoch_info())
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_epoch_info())
             .map_err(|e| eyre!(e))
             .map(|info| info.epoch)
     }
 
     fn get_block_time(&self, slot: u64) -> eyre::Result<Option<i64>> {
-        match self.client.get_block_time(slot) {
+        match self.rpc_client().get_block_time(slot) {
             Ok(ts) => Ok(Some(ts)),
             Err(e) => {
                 let msg = e.to_string();
@@ -716,13 +997,9 @@ impl DoubleZeroClient for DZClient {
 
         let mut list: HashMap<Box<Pubkey>, Box<AccountData>> = HashMap::new();
 
-        let accounts = (|| {
-            self.client
-                .get_program_accounts_with_config(&self.program_id, options.clone())
-        })
-        .retry(Self::rpc_retry_builder())
-        .when(Self::is_retryable_rpc_error)
-        .call()?;
+        let accounts = self.with_rpc(|rpc| {
+            rpc.get_program_accounts_with_config(&self.program_id, options.clone())
+        })?;
 
         for (pubkey, account) in accounts {
             let account = match AccountData::try_from(&account.data[..]) {
@@ -745,7 +1022,7 @@ impl DoubleZeroClient for DZClient {
         instruction: DoubleZeroInstruction,
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature> {
-        self.execute_transaction_inner(instruction, accounts, false, false)
+        self.execute_transaction_inner(vec![(instruction, accounts)], &[], false, false)
     }
 
     fn execute_transaction_quiet(
@@ -753,7 +1030,21 @@ impl DoubleZeroClient for DZClient {
         instruction: DoubleZeroInstruction,
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature> {
-        self.execute_transaction_inner(instruction, accounts, true, false)
+        self.execute_transaction_inner(vec![(instruction, accounts)], &[], true, false)
+    }
+
+    fn execute_transaction_with_optional_accounts(
+        &self,
+        instruction: DoubleZeroInstruction,
+        accounts: Vec<AccountMeta>,
+        optional_accounts: Vec<AccountMeta>,
+    ) -> eyre::Result<Signature> {
+        self.execute_transaction_inner(
+            vec![(instruction, accounts)],
+            &optional_accounts,
+            false,
+            false,
+        )
     }
 
     fn execute_authorized_transaction(
@@ -761,7 +1052,7 @@ impl DoubleZeroClient for DZClient {
         instruction: DoubleZeroInstruction,
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature> {
-        self.execute_transaction_inner(instruction, accounts, false, true)
+        self.execute_transaction_inner(vec![(instruction, accounts)], &[], false, true)
     }
 
     fn execute_authorized_transaction_quiet(
@@ -769,7 +1060,17 @@ impl DoubleZeroClient for DZClient {
         instruction: DoubleZeroInstruction,
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature> {
-        self.execute_transaction_inner(instruction, accounts, true, true)
+        self.execute_transaction_inner(vec![(instruction, accounts)], &[], true, true)
+    }
+
+    fn execute_authorized_transactions(
+        &self,
+        batch: Vec<(DoubleZeroInstruction, Vec<AccountMeta>)>,
+    ) -> eyre::Result<Signature> {
+        if batch.is_empty() {
+            bail!("No instructions to send");
+        }
+        self.execute_transaction_inner(batch, &[], false, true)
     }
 
     fn gets(&self, account_type: AccountType) -> eyre::Result<HashMap<Pubkey, AccountData>> {
@@ -790,44 +1091,63 @@ impl DoubleZeroClient for DZClient {
             sort_results: None,
         };
 
+        if let Some(list) = self
+            .account_cache()
+            .and_then(|cache| cache.get_listing(account_type))
+        {
+            return Ok(list);
+        }
+        // Read the slot first: the listing is at least as recent.
+        let slot = self
+            .account_cache()
+            .map(|_| self.with_rpc(|rpc| rpc.get_slot()))
+            .transpose()?;
+
         let mut list: HashMap<Pubkey, AccountData> = HashMap::new();
         let program_id = self.get_program_id();
-        let accounts = (|| {
-            self.client
-                .get_program_accounts_with_config(program_id, options.clone())
-        })
-        .retry(Self::rpc_retry_builder())
-        .when(Self::is_retryable_rpc_error)
-        .call()?;
+        let accounts =
+            self.with_rpc(|rpc| rpc.get_program_accounts_with_config(program_id, options.clone()))?;
 
         for (pubkey, account) in accounts {
             assert!(account.data[0] == account_type, "Invalid account type");
             list.insert(pubkey, AccountData::try_from(&account.data[..])?);
         }
 
+        if let (Some(cache), Some(slot)) = (self.account_cache(), slot) {
+            cache.insert_listing(account_type, slot, list.clone());
+        }
+
         Ok(list)
     }
 
     fn get(&self, pubkey: Pubkey) -> eyre::Result<AccountData> {
-        let account = (|| self.client.get_account(&pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        if let Some(data) = self.account_cache().and_then(|cache| cache.get(&pubkey)) {
+            return Ok(data);
+        }
+        // Read the slot first: the account is at least as recent.
+        let slot = self
+            .account_cache()
+            .map(|_| self.with_rpc(|rpc| rpc.get_slot()))
+            .transpose()?;
+
+        let account = self
+            .with_rpc(|rpc| rpc.get_account(&pubkey))
             .map_err(|e| eyre!(e))?;
 
         if account.owner == self.program_id {
             let data = account.data;
-            Ok(AccountData::try_from(&data[..])?)
+            let decoded = AccountData::try_from(&data[..])?;
+            if let (Some(cache), Some(slot)) = (self.account_cache(), slot) {
+                cache.insert(pubkey, slot, data[0], decoded.clone());
+            }
+            Ok(decoded)
         } else {
             Ok(AccountData::None)
         }
     }
 
     fn get_account(&self, pubkey: Pubkey) -> eyre::Result<Account> {
-        (|| self.client.get_account(&pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()
+        self.with_rpc(|rpc| rpc.get_account(&pubkey))
             .map_err(|e| eyre!(e))
     }
 
@@ -843,39 +1163,34 @@ impl DoubleZeroClient for DZClient {
         self.transfer_sol(to, lamports)
     }
 
+    fn execute_telemetry_transaction(
+        &self,
+        telemetry_program_id: &Pubkey,
+        instruction: TelemetryInstruction,
+        accounts: Vec<AccountMeta>,
+    ) -> eyre::Result<Signature> {
+        self.execute_telemetry_transaction(telemetry_program_id, instruction, accounts)
+    }
+
     fn get_program_accounts(
         &self,
         program_id: &Pubkey,
         config: RpcProgramAccountsConfig,
     ) -> eyre::Result<Vec<(Pubkey, Account)>> {
-        (|| {
-            self.client
-                .get_program_accounts_with_config(program_id, config.clone())
-        })
-        .retry(Self::rpc_retry_builder())
-        .when(Self::is_retryable_rpc_error)
-        .call()
-        .map_err(|e| eyre!(e))
+        self.with_rpc(|rpc| rpc.get_program_accounts_with_config(program_id, config.clone()))
+            .map_err(|e| eyre!(e))
     }
 
     #[allow(deprecated)]
     fn get_transactions(&self, pubkey: Pubkey) -> eyre::Result<Vec<DZTransaction>> {
         let mut transactions: Vec<DZTransaction> = Vec::new();
 
-        let signatures = (|| self.client.get_signatures_for_address(&pubkey))
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()?;
+        let signatures = self.with_rpc(|rpc| rpc.get_signatures_for_address(&pubkey))?;
 
         for signature_info in signatures.into_iter() {
             let signature = Signature::from_str(&signature_info.signature)?;
-            let enc_transaction = (|| {
-                self.client
-                    .get_transaction(&signature, UiTransactionEncoding::Base64)
-            })
-            .retry(Self::rpc_retry_builder())
-            .when(Self::is_retryable_rpc_error)
-            .call()?;
+            let enc_transaction = self
+                .with_rpc(|rpc| rpc.get_transaction(&signature, UiTransactionEncoding::Base64))?;
 
             let time = enc_transaction.block_time.unwrap_or_default();
 
@@ -961,8 +1276,8 @@ mod assemble_instructions_tests {
         let ixs = DZClient::assemble_instructions(
             &program_id,
             &payer,
-            &DoubleZeroInstruction::InitGlobalState(),
-            base_accounts(),
+            &[(DoubleZeroInstruction::InitGlobalState(), base_accounts())],
+            &[],
             None,
         );
 
@@ -985,8 +1300,8 @@ mod assemble_instructions_tests {
         let ixs = DZClient::assemble_instructions(
             &program_id,
             &payer,
-            &DoubleZeroInstruction::InitGlobalState(),
-            base.clone(),
+            &[(DoubleZeroInstruction::InitGlobalState(), base.clone())],
+            &[],
             None,
         );
 
@@ -1000,6 +1315,31 @@ mod assemble_instructions_tests {
         assert_eq!(metas[base.len() + 1].pubkey, program::id());
     }
 
+    /// Optional accounts follow payer + system, ahead of any Permission account.
+    #[test]
+    fn optional_accounts_follow_payer_and_system() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let (permission_pda, _) = get_permission_pda(&program_id, &payer);
+        let optional = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+        let base = base_accounts();
+
+        let ixs = DZClient::assemble_instructions(
+            &program_id,
+            &payer,
+            &[(DoubleZeroInstruction::InitGlobalState(), base.clone())],
+            std::slice::from_ref(&optional),
+            Some(AccountMeta::new_readonly(permission_pda, false)),
+        );
+
+        let metas = &ixs[2].accounts;
+        assert_eq!(metas.len(), base.len() + 4);
+        assert_eq!(metas[base.len()].pubkey, payer);
+        assert_eq!(metas[base.len() + 1].pubkey, program::id());
+        assert_eq!(metas[base.len() + 2], optional);
+        assert_eq!(metas[base.len() + 3].pubkey, permission_pda);
+    }
+
     /// H2 regression: when present, the Permission account MUST be the trailing
     /// account — after payer + system — because `authorize()` reads it last.
     #[test]
@@ -1012,8 +1352,8 @@ mod assemble_instructions_tests {
         let ixs = DZClient::assemble_instructions(
             &program_id,
             &payer,
-            &DoubleZeroInstruction::InitGlobalState(),
-            base.clone(),
+            &[(DoubleZeroInstruction::InitGlobalState(), base.clone())],
+            &[],
             Some(AccountMeta::new_readonly(permission_pda, false)),
         );
 
@@ -1027,6 +1367,50 @@ mod assemble_instructions_tests {
         assert!(!perm.is_signer && !perm.is_writable);
     }
 
+    #[test]
+    fn batches_share_one_compute_budget_prefix() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let ixs = DZClient::assemble_instructions(
+            &program_id,
+            &payer,
+            &[
+                (DoubleZeroInstruction::InitGlobalState(), base_accounts()),
+                (DoubleZeroInstruction::InitGlobalState(), base_accounts()),
+            ],
+            &[],
+            None,
+        );
+
+        assert_eq!(ixs.len(), 4);
+        assert_ne!(ixs[1].program_id, program_id);
+        assert_eq!(ixs[2].program_id, program_id);
+        assert_eq!(ixs[3].program_id, program_id);
+        assert_eq!(ixs[3].accounts[2].pubkey, payer);
+    }
+
+    #[test]
+    fn batch_fits_in_transaction_stops_at_packet_size() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let batch: Vec<_> = (0..40)
+            .map(|_| (DoubleZeroInstruction::InitGlobalState(), base_accounts()))
+            .collect();
+
+        assert!(DZClient::batch_fits_in_transaction(
+            &program_id,
+            &payer,
+            &batch[..1]
+        ));
+        assert!(!DZClient::batch_fits_in_transaction(
+            &program_id,
+            &payer,
+            &batch
+        ));
+    }
+
     /// The permission-account cache must be dropped exactly when an instruction can
     /// change whether the payer's Permission PDA exists — otherwise a client that
     /// bootstraps its own Permission account keeps serving the stale "does not exist"
@@ -1066,13 +1450,14 @@ mod assemble_instructions_tests {
         };
 
         let client = DZClient {
-            rpc_url: String::new(),
-            client: RpcClient::new_mock(String::new()),
-            rpc_ws_url: String::new(),
+            endpoints: RpcEndpoints::mock(""),
             payer: None,
             program_id: Pubkey::new_unique(),
             // Seed the resolved-but-absent state that the bug served stale forever.
             permission_account_cache: Mutex::new(Some(None)),
+            dry_run: None,
+            policy: ClientPolicy::default(),
+            account_cache: None,
         };
 
         // Update and unrelated instructions leave the memo intact.
@@ -1151,12 +1536,13 @@ mod assemble_instructions_tests {
 
     fn client_with_mock_rpc(url: &str) -> DZClient {
         DZClient {
-            rpc_url: String::new(),
-            client: RpcClient::new_mock(url.to_string()),
-            rpc_ws_url: String::new(),
+            endpoints: RpcEndpoints::mock(url),
             payer: None,
             program_id: Pubkey::new_unique(),
             permission_account_cache: Mutex::new(None),
+            dry_run: None,
+            policy: ClientPolicy::default(),
+            account_cache: None,
         }
     }
 
@@ -1195,6 +1581,39 @@ mod assemble_instructions_tests {
         // transaction re-resolves rather than serving a stale "no account".
         assert_eq!(*client.permission_account_cache.lock().unwrap(), None);
     }
+
+    /// In dry-run mode the fully built transaction is recorded unsigned and
+    /// nothing is sent; the caller gets the default signature back.
+    #[test]
+    fn dry_run_records_transaction_instead_of_sending() {
+        let payer = Keypair::new();
+        let client = DZClient {
+            payer: Some(payer.insecure_clone()),
+            ..client_with_mock_rpc("succeeds")
+        }
+        .with_dry_run();
+
+        let signature = client
+            .execute_transaction(
+                DoubleZeroInstruction::InitGlobalState(),
+                vec![AccountMeta::new(Pubkey::new_unique(), false)],
+            )
+            .unwrap();
+        assert_eq!(signature, Signature::default());
+
+        let captured = client.take_dry_run_transactions();
+        assert_eq!(captured.len(), 1);
+        assert_eq!(captured[0].fee_payer, payer.pubkey().to_string());
+        let main = captured[0]
+            .instructions
+            .iter()
+            .find(|ix| ix.program_id == client.program_id.to_string())
+            .unwrap();
+        assert_eq!(main.name, "InitGlobalState");
+
+        // Drained on take.
+        assert!(client.take_dry_run_transactions().is_empty());
+    }
 }
 
 #[cfg(all(test, feature = "cli-context"))]
@@ -3,4 +3,6 @@ pub mod close;
 pub mod get;
 pub mod list;
 pub mod set;
+pub mod set_batch;
 pub mod set_feeds;
+pub mod settle_lease;
@@ -1,8 +1,10 @@
 use std::net::Ipv4Addr;
 
 use doublezero_serviceability::{
-    instructions::DoubleZeroInstruction, pda::get_accesspass_pda,
-    processors::accesspass::set::SetAccessPassArgs, state::accesspass::AccessPassType,
+    instructions::DoubleZeroInstruction,
+    pda::{get_accesspass_pda, get_referrer_stats_pda},
+    processors::accesspass::set::SetAccessPassArgs,
+    state::{accesspass::AccessPassType, user::BandwidthTier},
 };
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
 
@@ -21,6 +23,10 @@ pub struct SetAccessPassCommand {
     pub tenant: Pubkey,
     pub max_unicast_users: u16,
     pub max_multicast_users: u16,
+    /// Bandwidth tier for the pass's users; `None` keeps the current tier.
+    pub bandwidth_tier: Option<BandwidthTier>,
+    /// Referrer credited when this creates the pass; an existing pass keeps its referrer.
+    pub referrer: Option<Pubkey>,
 }
 
 impl SetAccessPassCommand {
@@ -29,6 +35,16 @@ impl SetAccessPassCommand {
             .execute(client)
             .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
 
+        let (instruction, accounts) = self.instruction(client, &globalstate_pubkey)?;
+        client.execute_authorized_transaction(instruction, accounts)
+    }
+
+    /// Build the `SetAccessPass` instruction and its accounts without sending it.
+    pub(crate) fn instruction(
+        &self,
+        client: &dyn DoubleZeroClient,
+        globalstate_pubkey: &Pubkey,
+    ) -> eyre::Result<(DoubleZeroInstruction, Vec<AccountMeta>)> {
         if self.last_access_epoch > 0 && self.last_access_epoch != u64::MAX {
             let epoch = client.get_epoch()?;
             if self.last_access_epoch < epoch {
@@ -51,10 +67,16 @@ impl SetAccessPassCommand {
 
         let mut accounts = vec![
             AccountMeta::new(pda_pubkey, false),
-            AccountMeta::new_readonly(globalstate_pubkey, false),
+            AccountMeta::new_readonly(*globalstate_pubkey, false),
             AccountMeta::new(self.user_payer, false),
         ];
 
+        if let Some(referrer) = self.referrer {
+            let (referrer_stats_pubkey, _) =
+                get_referrer_stats_pda(&client.get_program_id(), &referrer);
+            accounts.push(AccountMeta::new(referrer_stats_pubkey, false));
+        }
+
         // Get the current tenant from the existing access pass (if any)
         let current_tenant = accesspass
             .as_ref()
@@ -67,7 +89,7 @@ impl SetAccessPassCommand {
             accounts.push(AccountMeta::new(self.tenant, false));
         }
 
-        client.execute_authorized_transaction(
+        Ok((
             DoubleZeroInstruction::SetAccessPass(SetAccessPassArgs {
                 accesspass_type: self.accesspass_type.clone(),
                 client_ip: self.client_ip,
@@ -75,9 +97,11 @@ impl SetAccessPassCommand {
                 allow_multiple_ip: self.allow_multiple_ip,
                 max_unicast_users: self.max_unicast_users,
                 max_multicast_users: self.max_multicast_users,
+                bandwidth_tier: self.bandwidth_tier,
+                referrer: self.referrer,
             }),
             accounts,
-        )
+        ))
     }
 }
 
@@ -89,7 +113,7 @@ mod tests {
     };
     use doublezero_serviceability::{
         instructions::DoubleZeroInstruction,
-        pda::{get_accesspass_pda, get_globalstate_pda},
+        pda::{get_accesspass_pda, get_globalstate_pda, get_referrer_stats_pda},
         processors::accesspass::set::SetAccessPassArgs,
         state::{
             accesspass::{AccessPass, AccessPassStatus, AccessPassType},
@@ -129,6 +153,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         client
             .expect_get()
@@ -154,11 +180,69 @@ mod tests {
                     allow_multiple_ip: false,
                     max_unicast_users: 1,
                     max_multicast_users: 1,
+                    bandwidth_tier: None,
+                    referrer: None,
+                })),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                    AccountMeta::new(payer, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetAccessPassCommand {
+            accesspass_type: AccessPassType::Prepaid,
+            client_ip,
+            user_payer: payer,
+            last_access_epoch: 0,
+            allow_multiple_ip: false,
+            tenant: Pubkey::default(),
+            max_unicast_users: 1,
+            max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_commands_set_accesspass_command_with_referrer() {
+        let mut client = create_test_client();
+
+        let client_ip = [10, 0, 0, 2].into();
+        let payer = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_accesspass_pda(&client.get_program_id(), &client_ip, &payer);
+        let (referrer_stats_pubkey, _) =
+            get_referrer_stats_pda(&client.get_program_id(), &referrer);
+
+        // A new pass: neither the exact-IP nor the dynamic PDA exists yet.
+        client
+            .expect_get()
+            .returning(|_| Err(eyre::eyre!("account not found")));
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetAccessPass(SetAccessPassArgs {
+                    accesspass_type: AccessPassType::Prepaid,
+                    client_ip,
+                    last_access_epoch: 0,
+                    allow_multiple_ip: false,
+                    max_unicast_users: 1,
+                    max_multicast_users: 1,
+                    bandwidth_tier: None,
+                    referrer: Some(referrer),
                 })),
                 predicate::eq(vec![
                     AccountMeta::new(pda_pubkey, false),
                     AccountMeta::new_readonly(globalstate_pubkey, false),
                     AccountMeta::new(payer, false),
+                    AccountMeta::new(referrer_stats_pubkey, false),
                 ]),
             )
             .returning(|_, _| Ok(Signature::new_unique()));
@@ -172,6 +256,8 @@ mod tests {
             tenant: Pubkey::default(),
             max_unicast_users: 1,
             max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: Some(referrer),
         }
         .execute(&client);
         assert!(res.is_ok());
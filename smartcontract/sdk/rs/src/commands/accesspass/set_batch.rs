@@ -0,0 +1,195 @@
+use doublezero_serviceability::instructions::DoubleZeroInstruction;
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+use crate::{
+    commands::{accesspass::set::SetAccessPassCommand, globalstate::get::GetGlobalStateCommand},
+    DZClient, DoubleZeroClient,
+};
+
+/// Instructions sent together in one transaction, with their accounts.
+type Batch = Vec<(DoubleZeroInstruction, Vec<AccountMeta>)>;
+
+/// Sets many access passes, packing their instructions into as few
+/// transactions as fit.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetAccessPassBatchCommand {
+    pub passes: Vec<SetAccessPassCommand>,
+}
+
+impl SetAccessPassBatchCommand {
+    /// Returns one result per pass, in input order. Passes that land in the same
+    /// transaction share its signature.
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<Vec<eyre::Result<Signature>>> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let program_id = client.get_program_id();
+        let payer = client.get_payer();
+
+        let mut results: Vec<Option<eyre::Result<Signature>>> =
+            self.passes.iter().map(|_| None).collect();
+
+        // Each batch is paired with the indexes of the passes it sets.
+        let mut batches: Vec<(Vec<usize>, Batch)> = vec![];
+        let mut batch: Batch = vec![];
+        let mut indexes: Vec<usize> = vec![];
+        for (index, pass) in self.passes.iter().enumerate() {
+            let instruction = match pass.instruction(client, &globalstate_pubkey) {
+                Ok(instruction) => instruction,
+                Err(err) => {
+                    results[index] = Some(Err(err));
+                    continue;
+                }
+            };
+
+            batch.push(instruction);
+            indexes.push(index);
+            if batch.len() > 1 && !DZClient::batch_fits_in_transaction(&program_id, &payer, &batch)
+            {
+                let overflow = batch.pop().expect("batch is not empty");
+                let overflow_index = indexes.pop().expect("indexes is not empty");
+                batches.push((std::mem::take(&mut indexes), std::mem::take(&mut batch)));
+                batch.push(overflow);
+                indexes.push(overflow_index);
+            }
+        }
+        if !batch.is_empty() {
+            batches.push((indexes, batch));
+        }
+
+        for (indexes, instructions) in batches {
+            match client.execute_authorized_transactions(instructions.clone()) {
+                Ok(signature) => {
+                    for index in indexes {
+                        results[index] = Some(Ok(signature));
+                    }
+                }
+                Err(err) if indexes.len() == 1 => results[indexes[0]] = Some(Err(err)),
+                // The transaction is atomic, so none of its passes were set. Resend them
+                // one at a time to find out which one failed; setting a pass is an upsert,
+                // so a resend is harmless even if the batch did land after all.
+                Err(_) => {
+                    for (index, (instruction, accounts)) in indexes.into_iter().zip(instructions) {
+                        results[index] =
+                            Some(client.execute_authorized_transaction(instruction, accounts));
+                    }
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.expect("every pass has a result"))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::accesspass::{set::SetAccessPassCommand, set_batch::SetAccessPassBatchCommand},
+        tests::utils::create_test_client,
+        DZClient, DoubleZeroClient, MockDoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction, pda::get_globalstate_pda,
+        state::accesspass::AccessPassType,
+    };
+    use mockall::predicate;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+    use std::net::Ipv4Addr;
+
+    fn pass(index: u8) -> SetAccessPassCommand {
+        SetAccessPassCommand {
+            accesspass_type: AccessPassType::Prepaid,
+            client_ip: Ipv4Addr::new(100, 0, 0, index),
+            user_payer: Pubkey::new_unique(),
+            last_access_epoch: u64::MAX,
+            allow_multiple_ip: false,
+            tenant: Pubkey::default(),
+            max_unicast_users: 1,
+            max_multicast_users: 1,
+            bandwidth_tier: None,
+            referrer: None,
+        }
+    }
+
+    fn mock_no_accesspasses(client: &mut MockDoubleZeroClient) {
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        client
+            .expect_get()
+            .with(predicate::function(move |pk: &Pubkey| {
+                *pk != globalstate_pubkey
+            }))
+            .returning(|_| Err(eyre::eyre!("account not found")));
+    }
+
+    #[test]
+    fn test_commands_set_accesspass_batch_packs_transactions() {
+        let mut client = create_test_client();
+        mock_no_accesspasses(&mut client);
+
+        let program_id = client.get_program_id();
+        let payer = client.get_payer();
+        client
+            .expect_execute_authorized_transactions()
+            .withf(move |batch| {
+                !batch.is_empty() && DZClient::batch_fits_in_transaction(&program_id, &payer, batch)
+            })
+            .times(2..)
+            .returning(|_| Ok(Signature::new_unique()));
+
+        let passes: Vec<_> = (1..=30).map(pass).collect();
+        let results = SetAccessPassBatchCommand { passes }
+            .execute(&client)
+            .unwrap();
+
+        assert_eq!(results.len(), 30);
+        assert!(results.iter().all(|result| result.is_ok()));
+        // Consecutive passes share a transaction.
+        assert_eq!(results[0].as_ref().unwrap(), results[1].as_ref().unwrap());
+    }
+
+    #[test]
+    fn test_commands_set_accesspass_batch_attributes_failures() {
+        let mut client = create_test_client();
+        mock_no_accesspasses(&mut client);
+
+        let failing_ip = Ipv4Addr::new(100, 0, 0, 2);
+        client
+            .expect_execute_authorized_transactions()
+            .times(1)
+            .returning(|_| Err(eyre::eyre!("batch failed")));
+        client
+            .expect_execute_authorized_transaction()
+            .times(2)
+            .returning(move |instruction, _| match instruction {
+                DoubleZeroInstruction::SetAccessPass(args) if args.client_ip == failing_ip => {
+                    Err(eyre::eyre!("pass failed"))
+                }
+                _ => Ok(Signature::new_unique()),
+            });
+
+        let mut expired = pass(3);
+        expired.last_access_epoch = 1;
+        client.expect_get_epoch().returning(|| Ok(10));
+
+        let results = SetAccessPassBatchCommand {
+            passes: vec![pass(1), pass(2), expired],
+        }
+        .execute(&client)
+        .unwrap();
+
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].as_ref().unwrap_err().to_string(), "pass failed");
+        assert!(results[2]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("cannot be in the past"));
+    }
+}
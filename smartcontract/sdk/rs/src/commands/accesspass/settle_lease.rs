@@ -0,0 +1,72 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::accesspass::settle_lease::SettleAccessPassLeaseArgs,
+};
+use solana_sdk::{incinerator, instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SettleAccessPassLeaseCommand {
+    pub pubkey: Pubkey,
+}
+
+impl SettleAccessPassLeaseCommand {
+    /// Burns the deposit of an expired leased access pass. Anyone may settle a lease.
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        client.execute_transaction(
+            DoubleZeroInstruction::SettleAccessPassLease(SettleAccessPassLeaseArgs {}),
+            vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+                AccountMeta::new(incinerator::ID, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::accesspass::settle_lease::SettleAccessPassLeaseCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_accesspass_pda, get_globalstate_pda},
+        processors::accesspass::settle_lease::SettleAccessPassLeaseArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{incinerator, instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_settle_accesspass_lease_command() {
+        let mut client = create_test_client();
+
+        let client_ip = [10, 0, 0, 1].into();
+        let payer = Pubkey::new_unique();
+
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_accesspass_pda(&client.get_program_id(), &client_ip, &payer);
+
+        client
+            .expect_execute_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SettleAccessPassLease(
+                    SettleAccessPassLeaseArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                    AccountMeta::new(incinerator::ID, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SettleAccessPassLeaseCommand { pubkey: pda_pubkey }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_program_common::{types::NetworkV4, validate_account_code};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_anycast_prefix_pda,
+    processors::anycast_prefix::create::AnycastPrefixCreateArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateAnycastPrefixCommand {
+    pub code: String,
+    pub prefix: NetworkV4,
+    pub enabled: bool,
+}
+
+impl CreateAnycastPrefixCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let code =
+            validate_account_code(&self.code).map_err(|err| eyre::eyre!("invalid code: {err}"))?;
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), &code);
+
+        client
+            .execute_authorized_transaction(
+                DoubleZeroInstruction::CreateAnycastPrefix(AnycastPrefixCreateArgs {
+                    code,
+                    prefix: self.prefix,
+                    enabled: self.enabled,
+                }),
+                vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )
+            .map(|sig| (sig, pda_pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::anycast_prefix::create::CreateAnycastPrefixCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_anycast_prefix_pda, get_globalstate_pda},
+        processors::anycast_prefix::create::AnycastPrefixCreateArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_anycast_prefix_create_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), "dns");
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::CreateAnycastPrefix(
+                    AnycastPrefixCreateArgs {
+                        code: "dns".to_string(),
+                        prefix: "100.64.255.53/32".parse().unwrap(),
+                        enabled: true,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = CreateAnycastPrefixCommand {
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+        }
+        .execute(&client);
+
+        let (_, pubkey) = res.unwrap();
+        assert_eq!(pubkey, pda_pubkey);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_anycast_prefix_pda,
+    processors::anycast_prefix::delete::AnycastPrefixDeleteArgs,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteAnycastPrefixCommand {
+    pub code: String,
+}
+
+impl DeleteAnycastPrefixCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), &self.code);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::DeleteAnycastPrefix(AnycastPrefixDeleteArgs {}),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::anycast_prefix::delete::DeleteAnycastPrefixCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_anycast_prefix_pda, get_globalstate_pda},
+        processors::anycast_prefix::delete::AnycastPrefixDeleteArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_anycast_prefix_delete_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), "dns");
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::DeleteAnycastPrefix(
+                    AnycastPrefixDeleteArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = DeleteAnycastPrefixCommand {
+            code: "dns".to_string(),
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    pda::get_anycast_prefix_pda,
+    state::{accountdata::AccountData, anycast_prefix::AnycastPrefix},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetAnycastPrefixCommand {
+    pub code: String,
+}
+
+impl GetAnycastPrefixCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Pubkey, AnycastPrefix)> {
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), &self.code);
+        match client.get(pda_pubkey)? {
+            AccountData::AnycastPrefix(anycast_prefix) => Ok((pda_pubkey, anycast_prefix)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::anycast_prefix::get::GetAnycastPrefixCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_anycast_prefix_pda,
+        state::{
+            accountdata::AccountData, accounttype::AccountType, anycast_prefix::AnycastPrefix,
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_anycast_prefix_get_command() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _) = get_anycast_prefix_pda(&client.get_program_id(), "dns");
+        let anycast_prefix = AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            code: "dns".to_string(),
+            prefix: "100.64.255.53/32".parse().unwrap(),
+            enabled: true,
+            ..Default::default()
+        };
+        let expected = anycast_prefix.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::AnycastPrefix(anycast_prefix.clone())));
+
+        let res = GetAnycastPrefixCommand {
+            code: "dns".to_string(),
+        }
+        .execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{accountdata::AccountData, accounttype::AccountType, anycast_prefix::AnycastPrefix},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListAnycastPrefixCommand;
+
+impl ListAnycastPrefixCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, AnycastPrefix>> {
+        client
+            .gets(AccountType::AnycastPrefix)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::AnycastPrefix(anycast_prefix) = v {
+                    Ok((k, anycast_prefix))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::anycast_prefix::list::ListAnycastPrefixCommand, tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType, anycast_prefix::AnycastPrefix,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_anycast_prefix_list_command() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let anycast_prefix = AnycastPrefix {
+            account_type: AccountType::AnycastPrefix,
+            code: "ntp".to_string(),
+            prefix: "100.64.255.123/32".parse().unwrap(),
+            enabled: true,
+            ..Default::default()
+        };
+        let expected = anycast_prefix.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::AnycastPrefix))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    pubkey,
+                    AccountData::AnycastPrefix(anycast_prefix.clone()),
+                )]))
+            });
+
+        let list = ListAnycastPrefixCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(pubkey, expected)]));
+    }
+}
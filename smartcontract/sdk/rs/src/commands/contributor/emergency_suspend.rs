@@ -0,0 +1,218 @@
+use crate::{
+    commands::{
+        contributor::get::GetContributorCommand, device::list::ListDeviceCommand,
+        globalstate::get::GetGlobalStateCommand, link::list::ListLinkCommand,
+    },
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::contributor::{
+        emergency_drain::{ContributorEmergencyDrainArgs, MAX_EMERGENCY_DRAIN_BATCH},
+        emergency_suspend::ContributorEmergencySuspendArgs,
+    },
+    state::contributor::ContributorStatus,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EmergencySuspendContributorCommand {
+    pub pubkey: Pubkey,
+}
+
+impl EmergencySuspendContributorCommand {
+    /// Suspends the contributor, then drains all of its devices and links in
+    /// batches of up to `MAX_EMERGENCY_DRAIN_BATCH`, one transaction each.
+    /// Returns the signatures in the order sent. A contributor that is already
+    /// suspended is only drained, so a command interrupted midway can be rerun.
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Vec<Signature>> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand {}
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+        let (_, contributor) = GetContributorCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        let mut resources: Vec<Pubkey> = ListDeviceCommand
+            .execute(client)?
+            .into_iter()
+            .filter(|(_, device)| device.contributor_pk == self.pubkey)
+            .map(|(pk, _)| pk)
+            .chain(
+                ListLinkCommand
+                    .execute(client)?
+                    .into_iter()
+                    .filter(|(_, link)| link.contributor_pk == self.pubkey)
+                    .map(|(pk, _)| pk),
+            )
+            .collect();
+        resources.sort();
+
+        if resources.len() != contributor.reference_count as usize {
+            return Err(eyre::eyre!(
+                "Found {} devices and links for contributor {} but it references {}",
+                resources.len(),
+                self.pubkey,
+                contributor.reference_count
+            ));
+        }
+
+        let mut signatures = Vec::new();
+        if contributor.status != ContributorStatus::Suspended {
+            signatures.push(client.execute_authorized_transaction(
+                DoubleZeroInstruction::EmergencySuspendContributor(
+                    ContributorEmergencySuspendArgs {},
+                ),
+                vec![
+                    AccountMeta::new(self.pubkey, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ],
+            )?);
+        }
+
+        for batch in resources.chunks(MAX_EMERGENCY_DRAIN_BATCH) {
+            let mut accounts = vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ];
+            accounts.extend(batch.iter().map(|pk| AccountMeta::new(*pk, false)));
+            signatures.push(client.execute_authorized_transaction(
+                DoubleZeroInstruction::EmergencyDrainContributor(ContributorEmergencyDrainArgs {}),
+                accounts,
+            )?);
+        }
+
+        Ok(signatures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::contributor::emergency_suspend::EmergencySuspendContributorCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_contributor_pda, get_globalstate_pda},
+        processors::contributor::{
+            emergency_drain::{ContributorEmergencyDrainArgs, MAX_EMERGENCY_DRAIN_BATCH},
+            emergency_suspend::ContributorEmergencySuspendArgs,
+        },
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            contributor::{Contributor, ContributorStatus},
+            device::Device,
+            link::Link,
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_commands_contributor_emergency_suspend_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_contributor_pda(&client.get_program_id(), 1);
+        let other_contributor = Pubkey::new_unique();
+
+        // One more resource than fits in a drain batch, so two batches are sent.
+        let device_pks: Vec<Pubkey> = (0..MAX_EMERGENCY_DRAIN_BATCH)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let link_pk = Pubkey::new_unique();
+
+        let contributor = Contributor {
+            account_type: AccountType::Contributor,
+            index: 1,
+            bump_seed: 255,
+            reference_count: MAX_EMERGENCY_DRAIN_BATCH as u32 + 1,
+            code: "co01".to_string(),
+            status: ContributorStatus::Activated,
+            owner: Pubkey::new_unique(),
+            ops_manager_pk: Pubkey::new_unique(),
+        };
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::Contributor(contributor.clone())));
+
+        let devices: HashMap<Pubkey, Pubkey> = device_pks
+            .iter()
+            .map(|pk| (*pk, pda_pubkey))
+            .chain([(Pubkey::new_unique(), other_contributor)])
+            .collect();
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::Device))
+            .returning(move |_| {
+                Ok(devices
+                    .iter()
+                    .map(|(pk, contributor_pk)| {
+                        (
+                            *pk,
+                            AccountData::Device(Device {
+                                account_type: AccountType::Device,
+                                contributor_pk: *contributor_pk,
+                                ..Device::default()
+                            }),
+                        )
+                    })
+                    .collect())
+            });
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::Link))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    link_pk,
+                    AccountData::Link(Link {
+                        account_type: AccountType::Link,
+                        contributor_pk: pda_pubkey,
+                        ..Link::default()
+                    }),
+                )]))
+            });
+
+        let base_accounts = vec![
+            AccountMeta::new(pda_pubkey, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ];
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::EmergencySuspendContributor(
+                    ContributorEmergencySuspendArgs {},
+                )),
+                predicate::eq(base_accounts.clone()),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let mut resources = device_pks.clone();
+        resources.push(link_pk);
+        resources.sort();
+        for batch in resources.chunks(MAX_EMERGENCY_DRAIN_BATCH) {
+            let mut accounts = base_accounts.clone();
+            accounts.extend(batch.iter().map(|pk| AccountMeta::new(*pk, false)));
+            client
+                .expect_execute_authorized_transaction()
+                .with(
+                    predicate::eq(DoubleZeroInstruction::EmergencyDrainContributor(
+                        ContributorEmergencyDrainArgs {},
+                    )),
+                    predicate::eq(accounts),
+                )
+                .times(1)
+                .returning(|_, _| Ok(Signature::new_unique()));
+        }
+
+        let res = EmergencySuspendContributorCommand { pubkey: pda_pubkey }.execute(&client);
+
+        assert_eq!(res.unwrap().len(), 3);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod create;
 pub mod delete;
+pub mod emergency_suspend;
 pub mod get;
 pub mod list;
 pub mod resume;
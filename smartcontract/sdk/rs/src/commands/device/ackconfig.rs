@@ -0,0 +1,70 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::device::ackconfig::DeviceAckConfigArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AckDeviceConfigCommand {
+    pub pubkey: Pubkey,
+    pub generation: u64,
+}
+
+impl AckDeviceConfigCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::AckDeviceConfig(DeviceAckConfigArgs {
+                generation: self.generation,
+            }),
+            vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::pda::get_globalstate_pda;
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_ack_config_command() {
+        let mut client = create_test_client();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::AckDeviceConfig(
+                    DeviceAckConfigArgs { generation: 7 },
+                )),
+                // Instruction accounts: [device, globalstate].
+                predicate::function(move |accounts: &Vec<AccountMeta>| {
+                    accounts.len() == 2
+                        && accounts[0].pubkey == device_pubkey
+                        && accounts[0].is_writable
+                        && accounts[1].pubkey == globalstate_pubkey
+                        && !accounts[1].is_writable
+                }),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let command = AckDeviceConfigCommand {
+            pubkey: device_pubkey,
+            generation: 7,
+        };
+
+        let res = command.execute(&client);
+        assert!(res.is_ok());
+    }
+}
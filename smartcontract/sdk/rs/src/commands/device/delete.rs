@@ -217,6 +217,7 @@ mod tests {
             qa_allowlist: vec![],
             feature_flags: 0,
             feed_authority_pk: Pubkey::default(),
+            paused: 0,
         };
         client
             .expect_get()
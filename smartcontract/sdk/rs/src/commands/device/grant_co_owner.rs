@@ -0,0 +1,112 @@
+use crate::{
+    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::device::grant_co_owner::DeviceGrantCoOwnerArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Grant `co_owner_pk` the contributor owner's update rights on a device, replacing
+/// any previous co-owner.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GrantDeviceCoOwnerCommand {
+    pub pubkey: Pubkey,
+    pub co_owner_pk: Pubkey,
+}
+
+impl GrantDeviceCoOwnerCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.co_owner_pk == Pubkey::default() {
+            eyre::bail!("Co-owner must not be the default pubkey");
+        }
+
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (device_pubkey, device) = GetDeviceCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::GrantDeviceCoOwner(DeviceGrantCoOwnerArgs {
+                co_owner_pk: self.co_owner_pk,
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(device.contributor_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            device::{Device, DeviceStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_grant_co_owner_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestDevice".to_string(),
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        };
+        let contributor_pk = device.contributor_pk;
+        let co_owner_pk = Pubkey::new_unique();
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::GrantDeviceCoOwner(
+                    DeviceGrantCoOwnerArgs { co_owner_pk },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = GrantDeviceCoOwnerCommand {
+            pubkey: device_pubkey,
+            co_owner_pk,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        // The default pubkey is rejected before sending.
+        let res = GrantDeviceCoOwnerCommand {
+            pubkey: device_pubkey,
+            co_owner_pk: Pubkey::default(),
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    commands::{
+        device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand,
+        ip_allocation::resolve_interface_ip_allocation,
+    },
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::device::interface::{
+        DeviceInterfaceBulkUpdateArgs, DeviceInterfaceFieldsUpdate, MAX_BULK_INTERFACE_UPDATES,
+    },
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Update several interfaces of one device in a single transaction. Either all
+/// updates apply or none does.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BulkUpdateDeviceInterfacesCommand {
+    pub pubkey: Pubkey,
+    pub updates: Vec<DeviceInterfaceFieldsUpdate>,
+}
+
+impl BulkUpdateDeviceInterfacesCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.updates.is_empty() || self.updates.len() > MAX_BULK_INTERFACE_UPDATES {
+            eyre::bail!(
+                "A bulk update takes 1 to {MAX_BULK_INTERFACE_UPDATES} interfaces, got {}",
+                self.updates.len()
+            );
+        }
+
+        let (globalstate_pubkey, globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (device_pubkey, device) = GetDeviceCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        let mut accounts = vec![
+            AccountMeta::new(device_pubkey, false),
+            AccountMeta::new(device.contributor_pk, false),
+            AccountMeta::new(globalstate_pubkey, false),
+        ];
+
+        // One allocation per distinct block touched, as for UpdateDeviceInterface.
+        // Unknown interfaces are left for the program to reject.
+        let mut ip_allocations: Vec<Pubkey> = vec![];
+        for update in self.updates.iter().filter(|update| update.touches_ip()) {
+            let Ok((_, iface)) = device.find_interface(&update.name) else {
+                continue;
+            };
+            if let Some(ip_allocation) = resolve_interface_ip_allocation(
+                client,
+                &globalstate,
+                &device.contributor_pk,
+                update.interface_cyoa.unwrap_or(iface.interface_cyoa),
+                update.interface_dia.unwrap_or(iface.interface_dia),
+                update.ip_net.or(Some(iface.ip_net)),
+            )? {
+                if !ip_allocations.contains(&ip_allocation) {
+                    ip_allocations.push(ip_allocation);
+                }
+            }
+        }
+        accounts.extend(
+            ip_allocations
+                .iter()
+                .map(|ip_allocation| AccountMeta::new_readonly(*ip_allocation, false)),
+        );
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::BulkUpdateDeviceInterfaces(DeviceInterfaceBulkUpdateArgs {
+                updates: self.updates.clone(),
+            }),
+            accounts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            device::{Device, DeviceStatus},
+            interface::InterfaceStatus,
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_interface_bulk_update_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestDevice".to_string(),
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        };
+        let contributor_pk = device.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        let updates = vec![
+            DeviceInterfaceFieldsUpdate {
+                name: "Ethernet1".to_string(),
+                status: Some(InterfaceStatus::Activated),
+                ..Default::default()
+            },
+            DeviceInterfaceFieldsUpdate {
+                name: "Ethernet2".to_string(),
+                mtu: Some(9000),
+                ..Default::default()
+            },
+        ];
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::BulkUpdateDeviceInterfaces(
+                    DeviceInterfaceBulkUpdateArgs {
+                        updates: updates.clone(),
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = BulkUpdateDeviceInterfacesCommand {
+            pubkey: device_pubkey,
+            updates,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        let res = BulkUpdateDeviceInterfacesCommand {
+            pubkey: device_pubkey,
+            updates: vec![],
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
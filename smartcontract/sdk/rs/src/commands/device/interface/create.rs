@@ -1,5 +1,8 @@
 use crate::{
-    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    commands::{
+        device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand,
+        ip_allocation::resolve_interface_ip_allocation,
+    },
     DoubleZeroClient,
 };
 use doublezero_program_common::types::network_v4::NetworkV4;
@@ -33,7 +36,7 @@ pub struct CreateDeviceInterfaceCommand {
 
 impl CreateDeviceInterfaceCommand {
     pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
-        let (globalstate_pubkey, _) = GetGlobalStateCommand
+        let (globalstate_pubkey, globalstate) = GetGlobalStateCommand
             .execute(client)
             .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
 
@@ -42,6 +45,15 @@ impl CreateDeviceInterfaceCommand {
         }
         .execute(client)?;
 
+        let ip_allocation = resolve_interface_ip_allocation(
+            client,
+            &globalstate,
+            &device.contributor_pk,
+            self.interface_cyoa,
+            self.interface_dia,
+            self.ip_net,
+        )?;
+
         let (device_tunnel_block_ext, _, _) =
             get_resource_extension_pda(&client.get_program_id(), ResourceType::DeviceTunnelBlock);
         let (segment_routing_ids_ext, _, _) =
@@ -50,9 +62,12 @@ impl CreateDeviceInterfaceCommand {
             AccountMeta::new(device_pubkey, false),
             AccountMeta::new(device.contributor_pk, false),
             AccountMeta::new(globalstate_pubkey, false),
-            AccountMeta::new(device_tunnel_block_ext, false),
-            AccountMeta::new(segment_routing_ids_ext, false),
         ];
+        if let Some(ip_allocation) = ip_allocation {
+            accounts.push(AccountMeta::new_readonly(ip_allocation, false));
+        }
+        accounts.push(AccountMeta::new(device_tunnel_block_ext, false));
+        accounts.push(AccountMeta::new(segment_routing_ids_ext, false));
 
         // For Vpnv4 loopbacks, append topology PDAs so the onchain program
         // can allocate FlexAlgoNodeSegment entries atomically.
@@ -87,6 +102,7 @@ impl CreateDeviceInterfaceCommand {
                     user_tunnel_endpoint: self.user_tunnel_endpoint,
                     use_onchain_allocation: true,
                     topology_count,
+                    with_ip_allocation: ip_allocation.is_some(),
                 }),
                 accounts,
             )
@@ -97,16 +113,20 @@ impl CreateDeviceInterfaceCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::utils::create_test_client;
+    use crate::{tests::utils::create_test_client, MockDoubleZeroClient};
     use doublezero_serviceability::{
         pda::get_globalstate_pda,
         state::{
             accountdata::AccountData,
             accounttype::AccountType,
             device::{Device, DeviceDesiredStatus, DeviceHealth, DeviceStatus, DeviceType},
+            feature_flags::FeatureFlag,
+            globalstate::GlobalState,
+            ip_allocation::{IpAllocation, IpAllocationStatus},
         },
     };
     use mockall::predicate;
+    use std::collections::HashMap;
 
     fn make_test_device() -> Device {
         Device {
@@ -179,6 +199,7 @@ mod tests {
                         user_tunnel_endpoint: false,
                         use_onchain_allocation: true,
                         topology_count: 0,
+                        with_ip_allocation: false,
                     },
                 )),
                 predicate::eq(vec![
@@ -210,4 +231,163 @@ mod tests {
 
         assert!(res.is_ok());
     }
+
+    fn create_test_client_requiring_ip_allocations() -> MockDoubleZeroClient {
+        let mut client = MockDoubleZeroClient::new();
+
+        let payer = Pubkey::new_unique();
+        client.expect_get_payer().returning(move || payer);
+        let program_id = Pubkey::new_unique();
+        client.expect_get_program_id().returning(move || program_id);
+
+        let (globalstate_pubkey, bump_seed) = get_globalstate_pda(&program_id);
+        let globalstate = GlobalState {
+            account_type: AccountType::GlobalState,
+            bump_seed,
+            account_index: 0,
+            foundation_allowlist: vec![],
+            _device_allowlist: vec![],
+            _user_allowlist: vec![],
+            activator_authority_pk: Pubkey::new_unique(),
+            sentinel_authority_pk: Pubkey::new_unique(),
+            contributor_airdrop_lamports: 1_000_000_000,
+            user_airdrop_lamports: 40_000,
+            health_oracle_pk: Pubkey::new_unique(),
+            qa_allowlist: vec![],
+            feature_flags: FeatureFlag::RequireIpAllocations.to_mask(),
+            feed_authority_pk: Pubkey::default(),
+            paused: 0,
+        };
+        client
+            .expect_get()
+            .with(predicate::eq(globalstate_pubkey))
+            .returning(move |_| Ok(AccountData::GlobalState(globalstate.clone())));
+        client
+    }
+
+    fn cyoa_command(device_pubkey: Pubkey) -> CreateDeviceInterfaceCommand {
+        CreateDeviceInterfaceCommand {
+            pubkey: device_pubkey,
+            name: "Ethernet1".to_string(),
+            loopback_type: LoopbackType::None,
+            interface_cyoa: InterfaceCYOA::GREOverDIA,
+            interface_dia: InterfaceDIA::None,
+            ip_net: Some("45.76.12.9/30".parse().unwrap()),
+            bandwidth: 0,
+            cir: 0,
+            mtu: 1500,
+            routing_mode: RoutingMode::Static,
+            vlan_id: 0,
+            user_tunnel_endpoint: false,
+            topology_names: vec![],
+        }
+    }
+
+    #[test]
+    fn test_commands_device_create_interface_with_ip_allocation() {
+        let mut client = create_test_client_requiring_ip_allocations();
+
+        let program_id = client.get_program_id();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&program_id);
+        let (device_tunnel_block_ext, _, _) =
+            get_resource_extension_pda(&program_id, ResourceType::DeviceTunnelBlock);
+        let (segment_routing_ids_ext, _, _) =
+            get_resource_extension_pda(&program_id, ResourceType::SegmentRoutingIds);
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = make_test_device();
+        let contributor_pk = device.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        let allocation_pubkey = Pubkey::new_unique();
+        let pending_pubkey = Pubkey::new_unique();
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::IpAllocation))
+            .returning(move |_| {
+                Ok(HashMap::from([
+                    (
+                        pending_pubkey,
+                        AccountData::IpAllocation(IpAllocation {
+                            account_type: AccountType::IpAllocation,
+                            status: IpAllocationStatus::Pending,
+                            contributor_pk,
+                            prefix: "45.76.12.0/25".parse().unwrap(),
+                            ..Default::default()
+                        }),
+                    ),
+                    (
+                        allocation_pubkey,
+                        AccountData::IpAllocation(IpAllocation {
+                            account_type: AccountType::IpAllocation,
+                            status: IpAllocationStatus::Verified,
+                            contributor_pk,
+                            prefix: "45.76.12.0/24".parse().unwrap(),
+                            ..Default::default()
+                        }),
+                    ),
+                ]))
+            });
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::CreateDeviceInterface(
+                    DeviceInterfaceCreateArgs {
+                        name: "Ethernet1".to_string(),
+                        loopback_type: LoopbackType::None,
+                        interface_cyoa: InterfaceCYOA::GREOverDIA,
+                        interface_dia: InterfaceDIA::None,
+                        bandwidth: 0,
+                        cir: 0,
+                        ip_net: Some("45.76.12.9/30".parse().unwrap()),
+                        mtu: 1500,
+                        routing_mode: RoutingMode::Static,
+                        vlan_id: 0,
+                        user_tunnel_endpoint: false,
+                        use_onchain_allocation: true,
+                        topology_count: 0,
+                        with_ip_allocation: true,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                    AccountMeta::new_readonly(allocation_pubkey, false),
+                    AccountMeta::new(device_tunnel_block_ext, false),
+                    AccountMeta::new(segment_routing_ids_ext, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = cyoa_command(device_pubkey).execute(&client);
+
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_commands_device_create_interface_without_ip_allocation_fails() {
+        let mut client = create_test_client_requiring_ip_allocations();
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = make_test_device();
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::IpAllocation))
+            .returning(|_| Ok(HashMap::new()));
+
+        let err = cyoa_command(device_pubkey).execute(&client).unwrap_err();
+
+        assert!(err.to_string().contains("No verified IP allocation"));
+    }
 }
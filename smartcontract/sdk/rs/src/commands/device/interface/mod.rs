@@ -1,3 +1,4 @@
+pub mod bulk_update;
 pub mod create;
 pub mod delete;
 pub mod update;
@@ -1,5 +1,8 @@
 use crate::{
-    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    commands::{
+        device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand,
+        ip_allocation::resolve_interface_ip_allocation,
+    },
     DoubleZeroClient,
 };
 use doublezero_program_common::types::NetworkV4;
@@ -36,7 +39,7 @@ pub struct UpdateDeviceInterfaceCommand {
 
 impl UpdateDeviceInterfaceCommand {
     pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
-        let (globalstate_pubkey, _) = GetGlobalStateCommand
+        let (globalstate_pubkey, globalstate) = GetGlobalStateCommand
             .execute(client)
             .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
 
@@ -51,6 +54,26 @@ impl UpdateDeviceInterfaceCommand {
             AccountMeta::new(globalstate_pubkey, false),
         ];
 
+        // The program only re-checks the allocation when the address or the CYOA/DIA
+        // role changes, so only look one up then.
+        // An unknown interface is left for the program to reject.
+        let mut ip_allocation = None;
+        let touches_ip =
+            self.ip_net.is_some() || self.interface_cyoa.is_some() || self.interface_dia.is_some();
+        if let (true, Ok((_, iface))) = (touches_ip, device.find_interface(&self.name)) {
+            ip_allocation = resolve_interface_ip_allocation(
+                client,
+                &globalstate,
+                &device.contributor_pk,
+                self.interface_cyoa.unwrap_or(iface.interface_cyoa),
+                self.interface_dia.unwrap_or(iface.interface_dia),
+                self.ip_net.or(Some(iface.ip_net)),
+            )?;
+        }
+        if let Some(ip_allocation) = ip_allocation {
+            accounts.push(AccountMeta::new_readonly(ip_allocation, false));
+        }
+
         let update_topologies = self.topology_names.is_some();
         let needs_seg_ext = self.node_segment_idx.is_some() || update_topologies;
 
@@ -94,6 +117,7 @@ impl UpdateDeviceInterfaceCommand {
                 node_segment_idx: self.node_segment_idx,
                 topology_count,
                 update_topologies,
+                with_ip_allocation: ip_allocation.is_some(),
             }),
             accounts,
         )
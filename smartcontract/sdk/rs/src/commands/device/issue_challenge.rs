@@ -0,0 +1,80 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::device::issue_challenge::DeviceIssueChallengeArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Ask a device's metrics publisher to prove it holds its key by echoing `nonce`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IssueDeviceChallengeCommand {
+    pub pubkey: Pubkey,
+    pub nonce: [u8; 32],
+}
+
+impl IssueDeviceChallengeCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.nonce == [0; 32] {
+            eyre::bail!("Challenge nonce must not be all zeros");
+        }
+
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::IssueDeviceChallenge(DeviceIssueChallengeArgs {
+                nonce: self.nonce,
+            }),
+            vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::pda::get_globalstate_pda;
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_issue_challenge_command() {
+        let mut client = create_test_client();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::IssueDeviceChallenge(
+                    DeviceIssueChallengeArgs { nonce: [9; 32] },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = IssueDeviceChallengeCommand {
+            pubkey: device_pubkey,
+            nonce: [9; 32],
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        // The program treats an all-zero nonce as "no challenge"; reject it up front.
+        let res = IssueDeviceChallengeCommand {
+            pubkey: device_pubkey,
+            nonce: [0; 32],
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
@@ -1,7 +1,14 @@
+pub mod ackconfig;
 pub mod create;
 pub mod delete;
 pub mod get;
+pub mod grant_co_owner;
 pub mod interface;
+pub mod issue_challenge;
 pub mod list;
+pub mod prove_key;
+pub mod revoke_co_owner;
+pub mod rotate_metrics_publisher;
+pub mod set_ops_lock;
 pub mod sethealth;
 pub mod update;
@@ -0,0 +1,62 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_globalstate_pda,
+    processors::device::prove_key::DeviceProveKeyArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Answer a device's pending attestation challenge. The client's payer must be the
+/// device's metrics publisher key.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProveDeviceKeyCommand {
+    pub pubkey: Pubkey,
+    pub nonce: [u8; 32],
+}
+
+impl ProveDeviceKeyCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        // GlobalState is optional and trailing, so the pause applies without
+        // breaking against a program built before it.
+        client.execute_transaction_with_optional_accounts(
+            DoubleZeroInstruction::ProveDeviceKey(DeviceProveKeyArgs { nonce: self.nonce }),
+            vec![AccountMeta::new(self.pubkey, false)],
+            vec![AccountMeta::new_readonly(globalstate_pubkey, false)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_prove_key_command() {
+        let mut client = create_test_client();
+
+        let device_pubkey = Pubkey::new_unique();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        client
+            .expect_execute_transaction_with_optional_accounts()
+            .with(
+                predicate::eq(DoubleZeroInstruction::ProveDeviceKey(DeviceProveKeyArgs {
+                    nonce: [9; 32],
+                })),
+                predicate::eq(vec![AccountMeta::new(device_pubkey, false)]),
+                predicate::eq(vec![AccountMeta::new_readonly(globalstate_pubkey, false)]),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(Signature::new_unique()));
+
+        let res = ProveDeviceKeyCommand {
+            pubkey: device_pubkey,
+            nonce: [9; 32],
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::device::revoke_co_owner::DeviceRevokeCoOwnerArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Remove a device's co-owner. Signed by the contributor owner, a NETWORK_ADMIN or
+/// the co-owner itself.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RevokeDeviceCoOwnerCommand {
+    pub pubkey: Pubkey,
+}
+
+impl RevokeDeviceCoOwnerCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (device_pubkey, device) = GetDeviceCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::RevokeDeviceCoOwner(DeviceRevokeCoOwnerArgs {}),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(device.contributor_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            device::{Device, DeviceStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_revoke_co_owner_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestDevice".to_string(),
+            status: DeviceStatus::Activated,
+            co_owner_pk: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let contributor_pk = device.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::RevokeDeviceCoOwner(
+                    DeviceRevokeCoOwnerArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = RevokeDeviceCoOwnerCommand {
+            pubkey: device_pubkey,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    processors::device::rotate_metrics_publisher::{
+        DeviceRotateMetricsPublisherArgs, MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS,
+    },
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Hand a device's metrics publisher over to a new key, keeping the current one
+/// valid for `overlap_epochs` more epochs.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RotateDeviceMetricsPublisherCommand {
+    pub pubkey: Pubkey,
+    pub metrics_publisher_pk: Pubkey,
+    pub overlap_epochs: u64,
+}
+
+impl RotateDeviceMetricsPublisherCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.overlap_epochs > MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS {
+            eyre::bail!(
+                "Overlap may be at most {MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS} epochs, got {}",
+                self.overlap_epochs
+            );
+        }
+
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (device_pubkey, device) = GetDeviceCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::RotateDeviceMetricsPublisher(DeviceRotateMetricsPublisherArgs {
+                metrics_publisher_pk: self.metrics_publisher_pk,
+                overlap_epochs: self.overlap_epochs,
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(device.contributor_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            device::{Device, DeviceStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_rotate_metrics_publisher_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestDevice".to_string(),
+            status: DeviceStatus::Activated,
+            metrics_publisher_pk: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let contributor_pk = device.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        let metrics_publisher_pk = Pubkey::new_unique();
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::RotateDeviceMetricsPublisher(
+                    DeviceRotateMetricsPublisherArgs {
+                        metrics_publisher_pk,
+                        overlap_epochs: 2,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = RotateDeviceMetricsPublisherCommand {
+            pubkey: device_pubkey,
+            metrics_publisher_pk,
+            overlap_epochs: 2,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        // An overlap above the program's limit is rejected before sending.
+        let res = RotateDeviceMetricsPublisherCommand {
+            pubkey: device_pubkey,
+            metrics_publisher_pk,
+            overlap_epochs: MAX_METRICS_PUBLISHER_OVERLAP_EPOCHS + 1,
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    commands::{device::get::GetDeviceCommand, globalstate::get::GetGlobalStateCommand},
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::device::set_ops_lock::DeviceSetOpsLockArgs,
+    state::ops_lock::MAX_OPS_LOCK_REASON_LEN,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Freeze a device against changes by anyone but the caller until `expires_at`
+/// (unix seconds), or release the freeze with `expires_at` 0.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetDeviceOpsLockCommand {
+    pub pubkey: Pubkey,
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl SetDeviceOpsLockCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.reason.len() > MAX_OPS_LOCK_REASON_LEN {
+            eyre::bail!(
+                "Lock reason may be at most {MAX_OPS_LOCK_REASON_LEN} bytes, got {}",
+                self.reason.len()
+            );
+        }
+
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (device_pubkey, device) = GetDeviceCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::SetDeviceOpsLock(DeviceSetOpsLockArgs {
+                reason: self.reason.clone(),
+                expires_at: self.expires_at,
+            }),
+            vec![
+                AccountMeta::new(device_pubkey, false),
+                AccountMeta::new(device.contributor_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            device::{Device, DeviceStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_device_set_ops_lock_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let device_pubkey = Pubkey::new_unique();
+        let device = Device {
+            account_type: AccountType::Device,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestDevice".to_string(),
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        };
+        let contributor_pk = device.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(device_pubkey))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetDeviceOpsLock(
+                    DeviceSetOpsLockArgs {
+                        reason: "optics swap".to_string(),
+                        expires_at: 1_700_000_000,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(device_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetDeviceOpsLockCommand {
+            pubkey: device_pubkey,
+            reason: "optics swap".to_string(),
+            expires_at: 1_700_000_000,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        // A reason above the program's limit is rejected before sending.
+        let res = SetDeviceOpsLockCommand {
+            pubkey: device_pubkey,
+            reason: "x".repeat(MAX_OPS_LOCK_REASON_LEN + 1),
+            expires_at: 1_700_000_000,
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
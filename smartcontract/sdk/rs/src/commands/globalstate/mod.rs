@@ -3,4 +3,5 @@ pub mod init;
 pub mod setairdrop;
 pub mod setauthority;
 pub mod setfeatureflags;
+pub mod setpause;
 pub mod setversion;
@@ -0,0 +1,60 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::globalstate::setpause::SetPauseArgs,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPauseCommand {
+    /// Bitmask of paused instruction classes, see `pause_flags::PauseFlag`.
+    pub paused: u64,
+}
+
+impl SetPauseCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("GlobalState not initialized"))?;
+
+        // SetPause is authorized by the foundation allowlist only, so no Permission
+        // account is appended.
+        client.execute_transaction(
+            DoubleZeroInstruction::SetPause(SetPauseArgs {
+                paused: self.paused,
+            }),
+            vec![AccountMeta::new(globalstate_pubkey, false)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::globalstate::setpause::SetPauseCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction, pda::get_globalstate_pda,
+        processors::globalstate::setpause::SetPauseArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_setpause_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+
+        client
+            .expect_execute_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetPause(SetPauseArgs { paused: 3 })),
+                predicate::eq(vec![AccountMeta::new(globalstate_pubkey, false)]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetPauseCommand { paused: 3 }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_interface_oper_status_pda,
+    processors::interface_oper_status::delete::InterfaceOperStatusDeleteArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteInterfaceOperStatusCommand {
+    pub device_pk: Pubkey,
+}
+
+impl DeleteInterfaceOperStatusCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) =
+            get_interface_oper_status_pda(&client.get_program_id(), &self.device_pk);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::DeleteInterfaceOperStatus(InterfaceOperStatusDeleteArgs {}),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::interface_oper_status::delete::DeleteInterfaceOperStatusCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_interface_oper_status_pda},
+        processors::interface_oper_status::delete::InterfaceOperStatusDeleteArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_interface_oper_status_delete_command() {
+        let mut client = create_test_client();
+
+        let device_pk = Pubkey::new_unique();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_interface_oper_status_pda(&client.get_program_id(), &device_pk);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::DeleteInterfaceOperStatus(
+                    InterfaceOperStatusDeleteArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = DeleteInterfaceOperStatusCommand { device_pk }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
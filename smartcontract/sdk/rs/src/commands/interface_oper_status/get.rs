@@ -0,0 +1,72 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    pda::get_interface_oper_status_pda,
+    state::{accountdata::AccountData, interface_oper_status::InterfaceOperStatus},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetInterfaceOperStatusCommand {
+    pub device_pk: Pubkey,
+}
+
+impl GetInterfaceOperStatusCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<(Pubkey, InterfaceOperStatus)> {
+        let (pda_pubkey, _) =
+            get_interface_oper_status_pda(&client.get_program_id(), &self.device_pk);
+        match client.get(pda_pubkey)? {
+            AccountData::InterfaceOperStatus(oper_status) => Ok((pda_pubkey, oper_status)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::interface_oper_status::get::GetInterfaceOperStatusCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_interface_oper_status_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            interface_oper_status::{
+                InterfaceOperState, InterfaceOperStatus, InterfaceOperStatusEntry,
+            },
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_interface_oper_status_get_command() {
+        let mut client = create_test_client();
+
+        let device_pk = Pubkey::new_unique();
+        let (pda_pubkey, _) = get_interface_oper_status_pda(&client.get_program_id(), &device_pk);
+        let oper_status = InterfaceOperStatus {
+            account_type: AccountType::InterfaceOperStatus,
+            device_pk,
+            interfaces: vec![InterfaceOperStatusEntry {
+                name: "Ethernet1".to_string(),
+                oper_status: InterfaceOperState::Up,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let expected = oper_status.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::InterfaceOperStatus(oper_status.clone())));
+
+        let res = GetInterfaceOperStatusCommand { device_pk }.execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
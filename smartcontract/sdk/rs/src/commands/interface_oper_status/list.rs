@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{
+        accountdata::AccountData, accounttype::AccountType,
+        interface_oper_status::InterfaceOperStatus,
+    },
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListInterfaceOperStatusCommand;
+
+impl ListInterfaceOperStatusCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, InterfaceOperStatus>> {
+        client
+            .gets(AccountType::InterfaceOperStatus)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::InterfaceOperStatus(oper_status) = v {
+                    Ok((k, oper_status))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::interface_oper_status::list::ListInterfaceOperStatusCommand,
+        tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType,
+        interface_oper_status::InterfaceOperStatus,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_interface_oper_status_list_command() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let oper_status = InterfaceOperStatus {
+            account_type: AccountType::InterfaceOperStatus,
+            device_pk: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let expected = oper_status.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::InterfaceOperStatus))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    pubkey,
+                    AccountData::InterfaceOperStatus(oper_status.clone()),
+                )]))
+            });
+
+        let list = ListInterfaceOperStatusCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(pubkey, expected)]));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_interface_oper_status_pda,
+    processors::interface_oper_status::set::InterfaceOperStatusSetArgs,
+    state::interface_oper_status::InterfaceOperStatusEntry,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetInterfaceOperStatusCommand {
+    pub device_pk: Pubkey,
+    pub interfaces: Vec<InterfaceOperStatusEntry>,
+}
+
+impl SetInterfaceOperStatusCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) =
+            get_interface_oper_status_pda(&client.get_program_id(), &self.device_pk);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::SetInterfaceOperStatus(InterfaceOperStatusSetArgs {
+                interfaces: self.interfaces.clone(),
+            }),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(self.device_pk, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::interface_oper_status::set::SetInterfaceOperStatusCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_interface_oper_status_pda},
+        processors::interface_oper_status::set::InterfaceOperStatusSetArgs,
+        state::interface_oper_status::{InterfaceOperState, InterfaceOperStatusEntry},
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_interface_oper_status_set_command() {
+        let mut client = create_test_client();
+
+        let device_pk = Pubkey::new_unique();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_interface_oper_status_pda(&client.get_program_id(), &device_pk);
+        let interfaces = vec![InterfaceOperStatusEntry {
+            name: "Ethernet1".to_string(),
+            oper_status: InterfaceOperState::Down,
+            in_errors: 4,
+            ..Default::default()
+        }];
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetInterfaceOperStatus(
+                    InterfaceOperStatusSetArgs {
+                        interfaces: interfaces.clone(),
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(device_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetInterfaceOperStatusCommand {
+            device_pk,
+            interfaces,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_ip_allocation_pda,
+    processors::ip_allocation::create::IpAllocationCreateArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateIpAllocationCommand {
+    pub contributor_pk: Pubkey,
+    pub prefix: NetworkV4,
+    pub upstream: String,
+}
+
+impl CreateIpAllocationCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &self.prefix);
+
+        client
+            .execute_authorized_transaction(
+                DoubleZeroInstruction::CreateIpAllocation(IpAllocationCreateArgs {
+                    prefix: self.prefix,
+                    upstream: self.upstream.clone(),
+                }),
+                vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(self.contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )
+            .map(|sig| (sig, pda_pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::ip_allocation::create::CreateIpAllocationCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_ip_allocation_pda},
+        processors::ip_allocation::create::IpAllocationCreateArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_ip_allocation_create_command() {
+        let mut client = create_test_client();
+
+        let contributor_pk = Pubkey::new_unique();
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &prefix);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::CreateIpAllocation(
+                    IpAllocationCreateArgs {
+                        prefix,
+                        upstream: "AS64500".to_string(),
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = CreateIpAllocationCommand {
+            contributor_pk,
+            prefix,
+            upstream: "AS64500".to_string(),
+        }
+        .execute(&client);
+
+        let (_, pubkey) = res.unwrap();
+        assert_eq!(pubkey, pda_pubkey);
+    }
+}
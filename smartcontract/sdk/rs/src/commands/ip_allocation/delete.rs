@@ -0,0 +1,92 @@
+use crate::{
+    commands::{
+        globalstate::get::GetGlobalStateCommand, ip_allocation::get::GetIpAllocationCommand,
+    },
+    DoubleZeroClient,
+};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::ip_allocation::delete::IpAllocationDeleteArgs,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteIpAllocationCommand {
+    pub prefix: NetworkV4,
+}
+
+impl DeleteIpAllocationCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, ip_allocation) = GetIpAllocationCommand {
+            prefix: self.prefix,
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::DeleteIpAllocation(IpAllocationDeleteArgs {}),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(ip_allocation.contributor_pk, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::ip_allocation::delete::DeleteIpAllocationCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_ip_allocation_pda},
+        processors::ip_allocation::delete::IpAllocationDeleteArgs,
+        state::{accountdata::AccountData, accounttype::AccountType, ip_allocation::IpAllocation},
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_ip_allocation_delete_command() {
+        let mut client = create_test_client();
+
+        let contributor_pk = Pubkey::new_unique();
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &prefix);
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| {
+                Ok(AccountData::IpAllocation(IpAllocation {
+                    account_type: AccountType::IpAllocation,
+                    contributor_pk,
+                    prefix,
+                    ..Default::default()
+                }))
+            });
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::DeleteIpAllocation(
+                    IpAllocationDeleteArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = DeleteIpAllocationCommand { prefix }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::DoubleZeroClient;
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::{
+    pda::get_ip_allocation_pda,
+    state::{accountdata::AccountData, ip_allocation::IpAllocation},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetIpAllocationCommand {
+    pub prefix: NetworkV4,
+}
+
+impl GetIpAllocationCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Pubkey, IpAllocation)> {
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &self.prefix);
+        match client.get(pda_pubkey)? {
+            AccountData::IpAllocation(ip_allocation) => Ok((pda_pubkey, ip_allocation)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::ip_allocation::get::GetIpAllocationCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_ip_allocation_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            ip_allocation::{IpAllocation, IpAllocationStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_ip_allocation_get_command() {
+        let mut client = create_test_client();
+
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &prefix);
+        let ip_allocation = IpAllocation {
+            account_type: AccountType::IpAllocation,
+            status: IpAllocationStatus::Pending,
+            prefix,
+            upstream: "AS64500".to_string(),
+            ..Default::default()
+        };
+        let expected = ip_allocation.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::IpAllocation(ip_allocation.clone())));
+
+        let res = GetIpAllocationCommand { prefix }.execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
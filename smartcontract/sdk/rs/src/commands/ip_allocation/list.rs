@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{accountdata::AccountData, accounttype::AccountType, ip_allocation::IpAllocation},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListIpAllocationCommand;
+
+impl ListIpAllocationCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, IpAllocation>> {
+        client
+            .gets(AccountType::IpAllocation)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::IpAllocation(ip_allocation) = v {
+                    Ok((k, ip_allocation))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::ip_allocation::list::ListIpAllocationCommand, tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData,
+        accounttype::AccountType,
+        ip_allocation::{IpAllocation, IpAllocationStatus},
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_ip_allocation_list_command() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let ip_allocation = IpAllocation {
+            account_type: AccountType::IpAllocation,
+            status: IpAllocationStatus::Verified,
+            prefix: "45.76.12.0/24".parse().unwrap(),
+            ..Default::default()
+        };
+        let expected = ip_allocation.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::IpAllocation))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    pubkey,
+                    AccountData::IpAllocation(ip_allocation.clone()),
+                )]))
+            });
+
+        let list = ListIpAllocationCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(pubkey, expected)]));
+    }
+}
@@ -0,0 +1,51 @@
+pub mod create;
+pub mod delete;
+pub mod get;
+pub mod list;
+pub mod setstatus;
+
+use crate::{commands::ip_allocation::list::ListIpAllocationCommand, DoubleZeroClient};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::state::{
+    feature_flags::{is_feature_enabled, FeatureFlag},
+    globalstate::GlobalState,
+    interface::{InterfaceCYOA, InterfaceDIA},
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// The IP allocation to pass with a device interface instruction that leaves a CYOA or DIA
+/// interface addressed with `ip_net`.
+///
+/// Returns `None` while the program does not require allocations, or when the interface is not
+/// a CYOA/DIA interface. Otherwise returns the verified allocation of `contributor_pk` that
+/// covers `ip_net`, or an error naming the missing registration.
+pub(crate) fn resolve_interface_ip_allocation(
+    client: &dyn DoubleZeroClient,
+    globalstate: &GlobalState,
+    contributor_pk: &Pubkey,
+    interface_cyoa: InterfaceCYOA,
+    interface_dia: InterfaceDIA,
+    ip_net: Option<NetworkV4>,
+) -> eyre::Result<Option<Pubkey>> {
+    if !is_feature_enabled(globalstate.feature_flags, FeatureFlag::RequireIpAllocations)
+        || (interface_cyoa == InterfaceCYOA::None && interface_dia == InterfaceDIA::None)
+    {
+        return Ok(None);
+    }
+    let Some(ip_net) = ip_net.filter(|ip_net| *ip_net != NetworkV4::default()) else {
+        return Ok(None);
+    };
+
+    ListIpAllocationCommand
+        .execute(client)?
+        .into_iter()
+        .find(|(_, ip_allocation)| ip_allocation.covers(contributor_pk, &ip_net))
+        .map(|(pubkey, _)| Some(pubkey))
+        .ok_or_else(|| {
+            eyre::eyre!(
+                "No verified IP allocation of contributor {contributor_pk} covers {ip_net}. \
+                Register the block with 'doublezero contributor ip-allocation create' \
+                and have it verified first."
+            )
+        })
+}
@@ -0,0 +1,81 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_program_common::types::NetworkV4;
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_ip_allocation_pda,
+    processors::ip_allocation::setstatus::IpAllocationSetStatusArgs,
+    state::ip_allocation::IpAllocationStatus,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetIpAllocationStatusCommand {
+    pub prefix: NetworkV4,
+    pub status: IpAllocationStatus,
+}
+
+impl SetIpAllocationStatusCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &self.prefix);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::SetIpAllocationStatus(IpAllocationSetStatusArgs {
+                status: self.status,
+            }),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::ip_allocation::setstatus::SetIpAllocationStatusCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_ip_allocation_pda},
+        processors::ip_allocation::setstatus::IpAllocationSetStatusArgs,
+        state::ip_allocation::IpAllocationStatus,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_ip_allocation_set_status_command() {
+        let mut client = create_test_client();
+
+        let prefix = "45.76.12.0/24".parse().unwrap();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_ip_allocation_pda(&client.get_program_id(), &prefix);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetIpAllocationStatus(
+                    IpAllocationSetStatusArgs {
+                        status: IpAllocationStatus::Verified,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetIpAllocationStatusCommand {
+            prefix,
+            status: IpAllocationStatus::Verified,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
@@ -122,6 +122,11 @@ mod tests {
             desired_status: LinkDesiredStatus::Activated,
             link_topologies: vec![],
             link_flags: 0,
+            flap_count: 0,
+            flap_penalty: 0,
+            flap_epoch: 0,
+            damped_until_epoch: 0,
+            ops_lock: Default::default(),
         };
 
         let device_z = doublezero_serviceability::state::device::Device {
@@ -0,0 +1,267 @@
+use crate::{
+    commands::link::get::GetLinkCommand, telemetry::get_device_latency_samples, units::Nanoseconds,
+    DoubleZeroClient, LinkStatus,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Limits a link must stay within in both directions over its burn-in window.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct BurnInThresholds {
+    /// Highest p99 round-trip time
+    pub max_rtt_p99: Nanoseconds,
+    /// Highest jitter, the mean change in round-trip time between consecutive
+    /// answered probes
+    pub max_jitter: Nanoseconds,
+    /// Highest share of lost probes, in parts per million
+    pub max_loss_ppm: u32,
+    /// Fewest probes each direction must have sent for the verdict to count
+    pub min_samples: usize,
+}
+
+/// Evaluates the telemetry of an activated link against burn-in thresholds.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BurnInLinkCommand {
+    pub pubkey_or_code: String,
+    /// Epoch whose samples are evaluated, the current one if `None`
+    pub epoch: Option<u64>,
+    /// Only evaluate the probes sent in the last `window_us` of the samples,
+    /// all of them if `None`
+    pub window_us: Option<u64>,
+    pub thresholds: BurnInThresholds,
+    pub telemetry_program_id: Pubkey,
+}
+
+/// Probes one side of a link sent to the other over the burn-in window.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BurnInDirection {
+    pub origin_device_pk: Pubkey,
+    pub target_device_pk: Pubkey,
+    pub sample_count: usize,
+    pub lost: usize,
+    pub rtt_p99: Nanoseconds,
+    pub jitter: Nanoseconds,
+}
+
+impl BurnInDirection {
+    /// Evaluates the last samples of a direction; a zero sample is a lost
+    /// probe. RTTs are in microseconds, as written by the telemetry agents.
+    pub fn from_samples(
+        origin_device_pk: Pubkey,
+        target_device_pk: Pubkey,
+        samples: &[u32],
+    ) -> Self {
+        let mut rtts: Vec<u32> = samples.iter().copied().filter(|rtt| *rtt != 0).collect();
+
+        let jitter = if rtts.len() > 1 {
+            let total: u64 = rtts
+                .windows(2)
+                .map(|pair| pair[0].abs_diff(pair[1]) as u64)
+                .sum();
+            Nanoseconds::from_micros(total / (rtts.len() as u64 - 1))
+        } else {
+            Nanoseconds::default()
+        };
+
+        rtts.sort_unstable();
+        let rtt_p99 = match rtts.len() {
+            0 => Nanoseconds::default(),
+            n => Nanoseconds::from_micros(rtts[(n * 99).div_ceil(100) - 1] as u64),
+        };
+
+        Self {
+            origin_device_pk,
+            target_device_pk,
+            sample_count: samples.len(),
+            lost: samples.len() - rtts.len(),
+            rtt_p99,
+            jitter,
+        }
+    }
+
+    pub fn loss_ppm(&self) -> u32 {
+        match self.sample_count {
+            0 => 0,
+            n => (self.lost as u64 * 1_000_000 / n as u64) as u32,
+        }
+    }
+
+    /// Thresholds this direction exceeds, described for the report.
+    pub fn failures(&self, thresholds: &BurnInThresholds) -> Vec<String> {
+        if self.sample_count == 0 {
+            return vec!["no telemetry samples".to_string()];
+        }
+
+        let mut failures = vec![];
+        if self.sample_count < thresholds.min_samples {
+            failures.push(format!(
+                "{} samples, fewer than {}",
+                self.sample_count, thresholds.min_samples
+            ));
+        }
+        if self.lost == self.sample_count {
+            failures.push("every probe was lost".to_string());
+        } else if self.rtt_p99 > thresholds.max_rtt_p99 {
+            failures.push(format!(
+                "p99 RTT {} above {}",
+                self.rtt_p99, thresholds.max_rtt_p99
+            ));
+        }
+        if self.jitter > thresholds.max_jitter {
+            failures.push(format!(
+                "jitter {} above {}",
+                self.jitter, thresholds.max_jitter
+            ));
+        }
+        if self.loss_ppm() > thresholds.max_loss_ppm {
+            failures.push(format!(
+                "loss {:.3}% above {:.3}%",
+                self.loss_ppm() as f64 / 10_000.0,
+                thresholds.max_loss_ppm as f64 / 10_000.0
+            ));
+        }
+        failures
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BurnInReport {
+    pub link_pk: Pubkey,
+    pub link_code: String,
+    pub epoch: u64,
+    pub thresholds: BurnInThresholds,
+    /// Side A to side Z, then side Z to side A
+    pub directions: Vec<BurnInDirection>,
+}
+
+impl BurnInReport {
+    pub fn passed(&self) -> bool {
+        self.directions
+            .iter()
+            .all(|direction| direction.failures(&self.thresholds).is_empty())
+    }
+}
+
+impl BurnInLinkCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<BurnInReport> {
+        let (link_pk, link) = GetLinkCommand {
+            pubkey_or_code: self.pubkey_or_code.clone(),
+        }
+        .execute(client)?;
+        if link.status != LinkStatus::Activated {
+            eyre::bail!(
+                "Link {} is {}; only activated links can be burned in",
+                link.code,
+                link.status
+            );
+        }
+
+        let epoch = match self.epoch {
+            Some(epoch) => epoch,
+            None => client.get_epoch()?,
+        };
+
+        let directions = [
+            (link.side_a_pk, link.side_z_pk),
+            (link.side_z_pk, link.side_a_pk),
+        ]
+        .into_iter()
+        .map(|(origin, target)| {
+            // A side that has not reported yet has no samples account.
+            let samples = get_device_latency_samples(
+                client,
+                &self.telemetry_program_id,
+                &origin,
+                &target,
+                &link_pk,
+                epoch,
+            )
+            .map(|data| {
+                let interval_us = data.header.sampling_interval_microseconds;
+                let kept = match self.window_us {
+                    Some(window_us) if interval_us > 0 => window_us.div_ceil(interval_us) as usize,
+                    _ => data.samples.len(),
+                };
+                let skipped = data.samples.len().saturating_sub(kept);
+                data.samples[skipped..].to_vec()
+            })
+            .unwrap_or_default();
+            BurnInDirection::from_samples(origin, target, &samples)
+        })
+        .collect();
+
+        Ok(BurnInReport {
+            link_pk,
+            link_code: link.code,
+            epoch,
+            thresholds: self.thresholds,
+            directions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholds() -> BurnInThresholds {
+        BurnInThresholds {
+            max_rtt_p99: Nanoseconds::from_millis(10),
+            max_jitter: Nanoseconds::from_millis(2),
+            max_loss_ppm: 1_000,
+            min_samples: 4,
+        }
+    }
+
+    #[test]
+    fn test_burn_in_direction_stats() {
+        let (a, z) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let direction = BurnInDirection::from_samples(a, z, &[1_000, 0, 3_000, 2_000, 0]);
+        assert_eq!(direction.sample_count, 5);
+        assert_eq!(direction.lost, 2);
+        assert_eq!(direction.loss_ppm(), 400_000);
+        assert_eq!(direction.rtt_p99, Nanoseconds::from_millis(3));
+        // Lost probes are skipped: |1000-3000| and |3000-2000| average 1.5ms.
+        assert_eq!(direction.jitter, Nanoseconds::from_micros(1_500));
+
+        let empty = BurnInDirection::from_samples(a, z, &[]);
+        assert_eq!(empty.loss_ppm(), 0);
+        assert_eq!(empty.failures(&thresholds()), vec!["no telemetry samples"]);
+    }
+
+    #[test]
+    fn test_burn_in_direction_failures() {
+        let (a, z) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let healthy = BurnInDirection::from_samples(a, z, &[5_000, 5_100, 4_900, 5_000]);
+        assert!(healthy.failures(&thresholds()).is_empty());
+
+        let slow = BurnInDirection::from_samples(a, z, &[5_000, 15_000, 5_000]);
+        assert_eq!(
+            slow.failures(&thresholds()),
+            vec![
+                "3 samples, fewer than 4",
+                "p99 RTT 15.00ms above 10.00ms",
+                "jitter 10.00ms above 2.00ms",
+            ]
+        );
+
+        let lossy = BurnInDirection::from_samples(a, z, &[0, 0, 0, 0]);
+        assert_eq!(
+            lossy.failures(&thresholds()),
+            vec!["every probe was lost", "loss 100.000% above 0.100%"]
+        );
+
+        let report = BurnInReport {
+            link_pk: Pubkey::new_unique(),
+            link_code: "la-ny".to_string(),
+            epoch: 10,
+            thresholds: thresholds(),
+            directions: vec![healthy.clone(), healthy.clone()],
+        };
+        assert!(report.passed());
+        assert!(!BurnInReport {
+            directions: vec![healthy, slow],
+            ..report
+        }
+        .passed());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use crate::{
+    commands::globalstate::get::GetGlobalStateCommand,
+    units::{Bps, Nanoseconds},
+    DoubleZeroClient,
+};
 use doublezero_program_common::validate_account_code;
 use doublezero_serviceability::{
     instructions::DoubleZeroInstruction,
@@ -17,10 +21,10 @@ pub struct CreateLinkCommand {
     pub side_a_pk: Pubkey,
     pub side_z_pk: Pubkey,
     pub link_type: LinkLinkType,
-    pub bandwidth: u64,
+    pub bandwidth: Bps,
     pub mtu: u32,
-    pub delay_ns: u64,
-    pub jitter_ns: u64,
+    pub delay_ns: Nanoseconds,
+    pub jitter_ns: Nanoseconds,
     pub side_a_iface_name: String,
     pub side_z_iface_name: Option<String>,
 }
@@ -62,10 +66,10 @@ impl CreateLinkCommand {
                     code,
                     link_type: self.link_type,
                     desired_status: self.desired_status,
-                    bandwidth: self.bandwidth,
+                    bandwidth: self.bandwidth.into(),
                     mtu: self.mtu,
-                    delay_ns: self.delay_ns,
-                    jitter_ns: self.jitter_ns,
+                    delay_ns: self.delay_ns.into(),
+                    jitter_ns: self.jitter_ns.into(),
                     side_a_iface_name: self.side_a_iface_name.clone(),
                     side_z_iface_name: self.side_z_iface_name.clone(),
                     use_onchain_allocation: true,
@@ -79,7 +83,9 @@ impl CreateLinkCommand {
 #[cfg(test)]
 mod tests {
     use crate::{
-        commands::link::create::CreateLinkCommand, tests::utils::create_test_client,
+        commands::link::create::CreateLinkCommand,
+        tests::utils::create_test_client,
+        units::{Bps, Nanoseconds},
         DoubleZeroClient,
     };
     use doublezero_serviceability::{
@@ -141,10 +147,10 @@ mod tests {
             side_a_pk,
             side_z_pk,
             link_type: doublezero_serviceability::state::link::LinkLinkType::DZX,
-            bandwidth: 10_000_000_000,
+            bandwidth: Bps::from_gbps(10),
             mtu: 9000,
-            delay_ns: 1_000_000,
-            jitter_ns: 100_000,
+            delay_ns: Nanoseconds::from_millis(1),
+            jitter_ns: Nanoseconds::from_micros(100),
             side_a_iface_name: "Ethernet0".to_string(),
             side_z_iface_name: Some("Ethernet1".to_string()),
         }
@@ -1,11 +1,11 @@
 use crate::{
     commands::link::{get::GetLinkCommand, list::ListLinkCommand},
-    telemetry::{calculate_stats, get_all_device_latency_samples, LinkLatencyStats},
+    telemetry::{
+        calculate_stats, get_all_device_latency_samples, get_device_latency_samples,
+        LinkLatencyStats,
+    },
     DoubleZeroClient,
 };
-use doublezero_telemetry::{
-    pda::derive_device_latency_samples_pda, state::device_latency_samples::DeviceLatencySamples,
-};
 use solana_sdk::pubkey::Pubkey;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -30,18 +30,15 @@ impl LatencyLinkCommand {
                 }
                 .execute(client)?;
 
-                let (pda, _bump) = derive_device_latency_samples_pda(
+                // Get latency data
+                let latency_data = get_device_latency_samples(
+                    client,
                     &self.telemetry_program_id,
                     &link.side_a_pk,
                     &link.side_z_pk,
                     &link_pk,
                     epoch,
-                );
-
-                let account = client.get_account(pda)?;
-
-                // Get latency data
-                let latency_data = DeviceLatencySamples::try_from(&account.data[..])?;
+                )?;
 
                 let stats = calculate_stats(
                     epoch,
@@ -1,8 +1,10 @@
 pub mod accept;
+pub mod burnin;
 pub mod create;
 pub mod delete;
 pub mod get;
 pub mod latency;
 pub mod list;
+pub mod set_ops_lock;
 pub mod sethealth;
 pub mod update;
@@ -0,0 +1,120 @@
+use crate::{
+    commands::{globalstate::get::GetGlobalStateCommand, link::get::GetLinkCommand},
+    DoubleZeroClient,
+};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::link::set_ops_lock::LinkSetOpsLockArgs,
+    state::ops_lock::MAX_OPS_LOCK_REASON_LEN,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Freeze a link against changes by anyone but the caller until `expires_at`
+/// (unix seconds), or release the freeze with `expires_at` 0.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetLinkOpsLockCommand {
+    pub pubkey: Pubkey,
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl SetLinkOpsLockCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        if self.reason.len() > MAX_OPS_LOCK_REASON_LEN {
+            eyre::bail!(
+                "Lock reason may be at most {MAX_OPS_LOCK_REASON_LEN} bytes, got {}",
+                self.reason.len()
+            );
+        }
+
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (link_pubkey, link) = GetLinkCommand {
+            pubkey_or_code: self.pubkey.to_string(),
+        }
+        .execute(client)?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::SetLinkOpsLock(LinkSetOpsLockArgs {
+                reason: self.reason.clone(),
+                expires_at: self.expires_at,
+            }),
+            vec![
+                AccountMeta::new(link_pubkey, false),
+                AccountMeta::new(link.contributor_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{
+        pda::get_globalstate_pda,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            link::{Link, LinkStatus},
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_link_set_ops_lock_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+
+        let link_pubkey = Pubkey::new_unique();
+        let link = Link {
+            account_type: AccountType::Link,
+            contributor_pk: Pubkey::new_unique(),
+            code: "TestLink".to_string(),
+            status: LinkStatus::Activated,
+            ..Default::default()
+        };
+        let contributor_pk = link.contributor_pk;
+
+        client
+            .expect_get()
+            .with(predicate::eq(link_pubkey))
+            .returning(move |_| Ok(AccountData::Link(link.clone())));
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::SetLinkOpsLock(LinkSetOpsLockArgs {
+                    reason: "OTDR trace".to_string(),
+                    expires_at: 1_700_000_000,
+                })),
+                predicate::eq(vec![
+                    AccountMeta::new(link_pubkey, false),
+                    AccountMeta::new(contributor_pk, false),
+                    AccountMeta::new(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = SetLinkOpsLockCommand {
+            pubkey: link_pubkey,
+            reason: "OTDR trace".to_string(),
+            expires_at: 1_700_000_000,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+
+        // A reason above the program's limit is rejected before sending.
+        let res = SetLinkOpsLockCommand {
+            pubkey: link_pubkey,
+            reason: "x".repeat(MAX_OPS_LOCK_REASON_LEN + 1),
+            expires_at: 1_700_000_000,
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
         contributor::get::GetContributorCommand, device::get::GetDeviceCommand,
         link::get::GetLinkCommand,
     },
+    units::{Bps, Nanoseconds},
     DoubleZeroClient, GetGlobalStateCommand,
 };
 use doublezero_program_common::{types::NetworkV4, validate_account_code};
@@ -21,11 +22,11 @@ pub struct UpdateLinkCommand {
     pub code: Option<String>,
     pub contributor_pk: Option<Pubkey>,
     pub tunnel_type: Option<LinkLinkType>,
-    pub bandwidth: Option<u64>,
+    pub bandwidth: Option<Bps>,
     pub mtu: Option<u32>,
-    pub delay_ns: Option<u64>,
-    pub jitter_ns: Option<u64>,
-    pub delay_override_ns: Option<u64>,
+    pub delay_ns: Option<Nanoseconds>,
+    pub jitter_ns: Option<Nanoseconds>,
+    pub delay_override_ns: Option<Nanoseconds>,
     pub status: Option<LinkStatus>,
     pub desired_status: Option<LinkDesiredStatus>,
     pub tunnel_id: Option<u16>,
@@ -128,11 +129,11 @@ impl UpdateLinkCommand {
                 code,
                 contributor_pk: self.contributor_pk,
                 tunnel_type: self.tunnel_type,
-                bandwidth: self.bandwidth,
+                bandwidth: self.bandwidth.map(Into::into),
                 mtu: self.mtu,
-                delay_ns: self.delay_ns,
-                jitter_ns: self.jitter_ns,
-                delay_override_ns: self.delay_override_ns,
+                delay_ns: self.delay_ns.map(Into::into),
+                jitter_ns: self.jitter_ns.map(Into::into),
+                delay_override_ns: self.delay_override_ns.map(Into::into),
                 status: self.status,
                 desired_status: self.desired_status,
                 tunnel_id: self.tunnel_id,
@@ -0,0 +1,154 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    migration::{find_migration, migrate_account_data},
+    processors::migrateaccount::MigrateAccountArgs,
+    state::accounttype::AccountType,
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Lists the accounts of `account_type` that are not at the current layout and
+/// would be rewritten by [`MigrateAccountCommand`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListPendingMigrationsCommand {
+    pub account_type: AccountType,
+}
+
+impl ListPendingMigrationsCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Vec<Pubkey>> {
+        if find_migration(self.account_type).is_none() {
+            eyre::bail!("No migration registered for {}", self.account_type);
+        }
+
+        let filters = vec![RpcFilterType::Memcmp(Memcmp::new(
+            0, // account_type is the first byte
+            MemcmpEncodedBytes::Bytes(vec![self.account_type as u8]),
+        ))];
+        let accounts = client.get_program_accounts(
+            &client.get_program_id(),
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )?;
+
+        let mut pending = Vec::new();
+        for (pubkey, account) in accounts {
+            let upgraded = migrate_account_data(&account.data)
+                .map_err(|err| eyre::eyre!("Unable to read account {pubkey}: {err}"))?;
+            if upgraded.is_some() {
+                pending.push(pubkey);
+            }
+        }
+        pending.sort();
+
+        Ok(pending)
+    }
+}
+
+/// Rewrites one account at the current layout.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MigrateAccountCommand {
+    pub pubkey: Pubkey,
+}
+
+impl MigrateAccountCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {}),
+            vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_serviceability::{pda::get_globalstate_pda, state::globalstate::GlobalState};
+    use mockall::predicate;
+    use solana_sdk::account::Account;
+
+    #[test]
+    fn test_commands_list_pending_migrations() {
+        let mut client = create_test_client();
+
+        let globalstate = GlobalState {
+            account_type: AccountType::GlobalState,
+            ..GlobalState::default()
+        };
+        let current = borsh::to_vec(&globalstate).unwrap();
+        let legacy = current[..current.len() - 8].to_vec();
+        let (current_pk, legacy_pk) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        client.expect_get_program_accounts().returning(move |_, _| {
+            Ok(vec![
+                (
+                    current_pk,
+                    Account {
+                        data: current.clone(),
+                        ..Account::default()
+                    },
+                ),
+                (
+                    legacy_pk,
+                    Account {
+                        data: legacy.clone(),
+                        ..Account::default()
+                    },
+                ),
+            ])
+        });
+
+        let pending = ListPendingMigrationsCommand {
+            account_type: AccountType::GlobalState,
+        }
+        .execute(&client)
+        .unwrap();
+        assert_eq!(pending, vec![legacy_pk]);
+
+        let res = ListPendingMigrationsCommand {
+            account_type: AccountType::ResourceExtension,
+        }
+        .execute(&client);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_commands_migrate_account() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let pubkey = Pubkey::new_unique();
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::MigrateAccount(MigrateAccountArgs {})),
+                predicate::eq(vec![
+                    AccountMeta::new(pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = MigrateAccountCommand { pubkey }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
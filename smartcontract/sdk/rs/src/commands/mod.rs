@@ -1,5 +1,6 @@
 pub mod accesspass;
 pub mod allowlist;
+pub mod anycast_prefix;
 pub mod contributor;
 pub mod device;
 pub mod exchange;
@@ -7,13 +8,22 @@ pub mod feed;
 pub mod globalconfig;
 pub mod globalstate;
 pub mod index;
+pub mod interface_oper_status;
+pub mod ip_allocation;
 pub mod link;
 pub mod location;
 pub mod migrate;
+pub mod migrateaccount;
 pub mod multicastgroup;
 pub mod permission;
 pub mod programconfig;
+pub mod qos_profile;
+pub mod read_api_key;
+pub mod referrer_stats;
 pub mod resource;
+pub mod scheduler;
+pub mod state_commitment;
+pub mod telemetry;
 pub mod tenant;
 pub mod topology;
 pub mod user;
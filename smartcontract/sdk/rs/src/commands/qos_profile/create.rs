@@ -0,0 +1,115 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    pda::{get_qos_profile_pda, get_resource_extension_pda},
+    processors::qos_profile::create::QosProfileCreateArgs,
+    resource::ResourceType,
+    state::user::BandwidthTier,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateQosProfileCommand {
+    pub bandwidth_tier: BandwidthTier,
+    pub rate_mbps: u32,
+    pub burst_kbytes: u32,
+    pub dscp: u8,
+}
+
+impl CreateQosProfileCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), self.bandwidth_tier);
+        let (qos_profile_ids_pda, _, _) =
+            get_resource_extension_pda(&client.get_program_id(), ResourceType::QosProfileIds);
+
+        // Pre-flight: the id pool is created separately, once per network.
+        client.get_account(qos_profile_ids_pda).map_err(|_| {
+            eyre::eyre!(
+                "qos-profile-ids resource account not found ({}). \
+                Run 'doublezero resource create --resource-type qos-profile-ids' first.",
+                qos_profile_ids_pda
+            )
+        })?;
+
+        client
+            .execute_authorized_transaction(
+                DoubleZeroInstruction::CreateQosProfile(QosProfileCreateArgs {
+                    bandwidth_tier: self.bandwidth_tier,
+                    rate_mbps: self.rate_mbps,
+                    burst_kbytes: self.burst_kbytes,
+                    dscp: self.dscp,
+                }),
+                vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new(qos_profile_ids_pda, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )
+            .map(|sig| (sig, pda_pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::qos_profile::create::CreateQosProfileCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_qos_profile_pda, get_resource_extension_pda},
+        processors::qos_profile::create::QosProfileCreateArgs,
+        resource::ResourceType,
+        state::user::BandwidthTier,
+    };
+    use mockall::predicate;
+    use solana_sdk::{account::Account, instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_qos_profile_create_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), BandwidthTier::Premium);
+        let (qos_profile_ids_pda, _, _) =
+            get_resource_extension_pda(&client.get_program_id(), ResourceType::QosProfileIds);
+
+        client
+            .expect_get_account()
+            .with(predicate::eq(qos_profile_ids_pda))
+            .returning(|_| Ok(Account::default()));
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::CreateQosProfile(
+                    QosProfileCreateArgs {
+                        bandwidth_tier: BandwidthTier::Premium,
+                        rate_mbps: 10_000,
+                        burst_kbytes: 512,
+                        dscp: 46,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new(qos_profile_ids_pda, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = CreateQosProfileCommand {
+            bandwidth_tier: BandwidthTier::Premium,
+            rate_mbps: 10_000,
+            burst_kbytes: 512,
+            dscp: 46,
+        }
+        .execute(&client);
+
+        let (_, pubkey) = res.unwrap();
+        assert_eq!(pubkey, pda_pubkey);
+    }
+}
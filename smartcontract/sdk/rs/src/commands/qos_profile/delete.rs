@@ -0,0 +1,82 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    pda::{get_qos_profile_pda, get_resource_extension_pda},
+    processors::qos_profile::delete::QosProfileDeleteArgs,
+    resource::ResourceType,
+    state::user::BandwidthTier,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteQosProfileCommand {
+    pub bandwidth_tier: BandwidthTier,
+}
+
+impl DeleteQosProfileCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), self.bandwidth_tier);
+        let (qos_profile_ids_pda, _, _) =
+            get_resource_extension_pda(&client.get_program_id(), ResourceType::QosProfileIds);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::DeleteQosProfile(QosProfileDeleteArgs {}),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new(qos_profile_ids_pda, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::qos_profile::delete::DeleteQosProfileCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_qos_profile_pda, get_resource_extension_pda},
+        processors::qos_profile::delete::QosProfileDeleteArgs,
+        resource::ResourceType,
+        state::user::BandwidthTier,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_qos_profile_delete_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), BandwidthTier::Basic);
+        let (qos_profile_ids_pda, _, _) =
+            get_resource_extension_pda(&client.get_program_id(), ResourceType::QosProfileIds);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::DeleteQosProfile(
+                    QosProfileDeleteArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new(qos_profile_ids_pda, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = DeleteQosProfileCommand {
+            bandwidth_tier: BandwidthTier::Basic,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
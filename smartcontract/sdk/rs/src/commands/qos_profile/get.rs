@@ -0,0 +1,63 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    pda::get_qos_profile_pda,
+    state::{accountdata::AccountData, qos_profile::QosProfile, user::BandwidthTier},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetQosProfileCommand {
+    pub bandwidth_tier: BandwidthTier,
+}
+
+impl GetQosProfileCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Pubkey, QosProfile)> {
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), self.bandwidth_tier);
+        match client.get(pda_pubkey)? {
+            AccountData::QosProfile(qos_profile) => Ok((pda_pubkey, qos_profile)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::qos_profile::get::GetQosProfileCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_qos_profile_pda,
+        state::{
+            accountdata::AccountData, accounttype::AccountType, qos_profile::QosProfile,
+            user::BandwidthTier,
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_qos_profile_get_command() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), BandwidthTier::Premium);
+        let qos_profile = QosProfile {
+            account_type: AccountType::QosProfile,
+            bandwidth_tier: BandwidthTier::Premium,
+            profile_id: 1,
+            rate_mbps: 10_000,
+            ..Default::default()
+        };
+        let expected = qos_profile.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::QosProfile(qos_profile.clone())));
+
+        let res = GetQosProfileCommand {
+            bandwidth_tier: BandwidthTier::Premium,
+        }
+        .execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{accountdata::AccountData, accounttype::AccountType, qos_profile::QosProfile},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListQosProfileCommand;
+
+impl ListQosProfileCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, QosProfile>> {
+        client
+            .gets(AccountType::QosProfile)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::QosProfile(qos_profile) = v {
+                    Ok((k, qos_profile))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::qos_profile::list::ListQosProfileCommand, tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType, qos_profile::QosProfile,
+        user::BandwidthTier,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_qos_profile_list_command() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let qos_profile = QosProfile {
+            account_type: AccountType::QosProfile,
+            bandwidth_tier: BandwidthTier::Standard,
+            profile_id: 2,
+            rate_mbps: 1_000,
+            ..Default::default()
+        };
+        let expected = qos_profile.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::QosProfile))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    pubkey,
+                    AccountData::QosProfile(qos_profile.clone()),
+                )]))
+            });
+
+        let list = ListQosProfileCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(pubkey, expected)]));
+    }
+}
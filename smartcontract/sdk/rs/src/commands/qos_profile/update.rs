@@ -0,0 +1,87 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_qos_profile_pda,
+    processors::qos_profile::update::QosProfileUpdateArgs, state::user::BandwidthTier,
+};
+use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateQosProfileCommand {
+    pub bandwidth_tier: BandwidthTier,
+    pub rate_mbps: Option<u32>,
+    pub burst_kbytes: Option<u32>,
+    pub dscp: Option<u8>,
+}
+
+impl UpdateQosProfileCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_qos_profile_pda(&client.get_program_id(), self.bandwidth_tier);
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::UpdateQosProfile(QosProfileUpdateArgs {
+                rate_mbps: self.rate_mbps,
+                burst_kbytes: self.burst_kbytes,
+                dscp: self.dscp,
+            }),
+            vec![
+                AccountMeta::new(pda_pubkey, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::qos_profile::update::UpdateQosProfileCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_qos_profile_pda},
+        processors::qos_profile::update::QosProfileUpdateArgs,
+        state::user::BandwidthTier,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_qos_profile_update_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) =
+            get_qos_profile_pda(&client.get_program_id(), BandwidthTier::Standard);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::UpdateQosProfile(
+                    QosProfileUpdateArgs {
+                        rate_mbps: Some(2_000),
+                        burst_kbytes: None,
+                        dscp: None,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = UpdateQosProfileCommand {
+            bandwidth_tier: BandwidthTier::Standard,
+            rate_mbps: Some(2_000),
+            burst_kbytes: None,
+            dscp: None,
+        }
+        .execute(&client);
+        assert!(res.is_ok());
+    }
+}
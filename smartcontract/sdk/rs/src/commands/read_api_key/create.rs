@@ -0,0 +1,101 @@
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_read_api_key_pda,
+    processors::read_api_key::create::ReadApiKeyCreateArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CreateReadApiKeyCommand {
+    pub contributor_pk: Pubkey,
+    /// SHA-256 hash of the key; the key itself is never sent onchain.
+    pub key_hash: [u8; 32],
+    pub label: String,
+    /// Unix timestamp after which the key is rejected; 0 means it never expires.
+    pub expires_at: i64,
+}
+
+impl CreateReadApiKeyCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_read_api_key_pda(
+            &client.get_program_id(),
+            &self.contributor_pk,
+            &self.key_hash,
+        );
+
+        // Accounts: [read_api_key, contributor, globalstate, (payer, system appended by client)].
+        client
+            .execute_authorized_transaction(
+                DoubleZeroInstruction::CreateReadApiKey(ReadApiKeyCreateArgs {
+                    key_hash: self.key_hash,
+                    label: self.label.clone(),
+                    expires_at: self.expires_at,
+                }),
+                vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(self.contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )
+            .map(|sig| (sig, pda_pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::read_api_key::create::CreateReadApiKeyCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_read_api_key_pda},
+        processors::read_api_key::create::ReadApiKeyCreateArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_read_api_key_create_command() {
+        let mut client = create_test_client();
+
+        let contributor_pk = Pubkey::new_unique();
+        let key_hash = [3u8; 32];
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) =
+            get_read_api_key_pda(&client.get_program_id(), &contributor_pk, &key_hash);
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::CreateReadApiKey(
+                    ReadApiKeyCreateArgs {
+                        key_hash,
+                        label: "indexer".to_string(),
+                        expires_at: 1_900_000_000,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let (_, pubkey) = CreateReadApiKeyCommand {
+            contributor_pk,
+            key_hash,
+            label: "indexer".to_string(),
+            expires_at: 1_900_000_000,
+        }
+        .execute(&client)
+        .unwrap();
+        assert_eq!(pubkey, pda_pubkey);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, processors::read_api_key::delete::ReadApiKeyDeleteArgs,
+    state::accountdata::AccountData,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeleteReadApiKeyCommand {
+    pub pubkey: Pubkey,
+}
+
+impl DeleteReadApiKeyCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let read_api_key = match client.get(self.pubkey)? {
+            AccountData::ReadApiKey(read_api_key) => read_api_key,
+            _ => return Err(eyre::eyre!("Invalid Account Type")),
+        };
+
+        // Accounts: [read_api_key, contributor, globalstate, (payer, system appended by client)].
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::DeleteReadApiKey(ReadApiKeyDeleteArgs {}),
+            vec![
+                AccountMeta::new(self.pubkey, false),
+                AccountMeta::new_readonly(read_api_key.contributor_pk, false),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::read_api_key::delete::DeleteReadApiKeyCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::get_globalstate_pda,
+        processors::read_api_key::delete::ReadApiKeyDeleteArgs,
+        state::{
+            accountdata::AccountData,
+            accounttype::AccountType,
+            read_api_key::{ReadApiKey, ReadApiKeyStatus},
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_read_api_key_delete_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _globalstate) = get_globalstate_pda(&client.get_program_id());
+        let pubkey = Pubkey::new_unique();
+        let contributor_pk = Pubkey::new_unique();
+        let read_api_key = ReadApiKey {
+            account_type: AccountType::ReadApiKey,
+            status: ReadApiKeyStatus::Activated,
+            contributor_pk,
+            ..Default::default()
+        };
+
+        client
+            .expect_get()
+            .with(predicate::eq(pubkey))
+            .returning(move |_| Ok(AccountData::ReadApiKey(read_api_key.clone())));
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::DeleteReadApiKey(
+                    ReadApiKeyDeleteArgs {},
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pubkey, false),
+                    AccountMeta::new_readonly(contributor_pk, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = DeleteReadApiKeyCommand { pubkey }.execute(&client);
+        assert!(res.is_ok());
+    }
+}
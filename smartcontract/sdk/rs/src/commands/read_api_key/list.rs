@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{accountdata::AccountData, accounttype::AccountType, read_api_key::ReadApiKey},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListReadApiKeyCommand;
+
+impl ListReadApiKeyCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, ReadApiKey>> {
+        client
+            .gets(AccountType::ReadApiKey)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::ReadApiKey(read_api_key) = v {
+                    Ok((k, read_api_key))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::read_api_key::list::ListReadApiKeyCommand, tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType, read_api_key::ReadApiKey,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_read_api_key_list_command() {
+        let mut client = create_test_client();
+
+        let key_pubkey = Pubkey::new_unique();
+        let read_api_key = ReadApiKey {
+            account_type: AccountType::ReadApiKey,
+            contributor_pk: Pubkey::new_unique(),
+            label: "indexer".to_string(),
+            ..Default::default()
+        };
+        let expected = read_api_key.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::ReadApiKey))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    key_pubkey,
+                    AccountData::ReadApiKey(read_api_key.clone()),
+                )]))
+            });
+
+        let list = ListReadApiKeyCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(key_pubkey, expected)]));
+    }
+}
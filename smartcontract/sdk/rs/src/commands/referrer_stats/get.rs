@@ -0,0 +1,62 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    pda::get_referrer_stats_pda,
+    state::{accountdata::AccountData, referrer_stats::ReferrerStats},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetReferrerStatsCommand {
+    pub referrer: Pubkey,
+}
+
+impl GetReferrerStatsCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Pubkey, ReferrerStats)> {
+        let (pda_pubkey, _) = get_referrer_stats_pda(&client.get_program_id(), &self.referrer);
+        match client.get(pda_pubkey)? {
+            AccountData::ReferrerStats(referrer_stats) => Ok((pda_pubkey, referrer_stats)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::referrer_stats::get::GetReferrerStatsCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_referrer_stats_pda,
+        state::{
+            accountdata::AccountData, accounttype::AccountType, referrer_stats::ReferrerStats,
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_referrer_stats_get_command() {
+        let mut client = create_test_client();
+
+        let referrer = Pubkey::new_unique();
+        let (pda_pubkey, _) = get_referrer_stats_pda(&client.get_program_id(), &referrer);
+        let referrer_stats = ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer,
+            referral_count: 3,
+            first_referral_epoch: 10,
+            last_referral_epoch: 12,
+            ..Default::default()
+        };
+        let expected = referrer_stats.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::ReferrerStats(referrer_stats.clone())));
+
+        let res = GetReferrerStatsCommand { referrer }.execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
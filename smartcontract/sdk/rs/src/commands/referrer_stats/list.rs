@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    error::DoubleZeroError,
+    state::{accountdata::AccountData, accounttype::AccountType, referrer_stats::ReferrerStats},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListReferrerStatsCommand;
+
+impl ListReferrerStatsCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<HashMap<Pubkey, ReferrerStats>> {
+        client
+            .gets(AccountType::ReferrerStats)?
+            .into_iter()
+            .map(|(k, v)| {
+                if let AccountData::ReferrerStats(referrer_stats) = v {
+                    Ok((k, referrer_stats))
+                } else {
+                    Err(DoubleZeroError::InvalidAccountType.into())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        commands::referrer_stats::list::ListReferrerStatsCommand, tests::utils::create_test_client,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType, referrer_stats::ReferrerStats,
+    };
+    use mockall::predicate;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_commands_referrer_stats_list_command() {
+        let mut client = create_test_client();
+
+        let pubkey = Pubkey::new_unique();
+        let referrer_stats = ReferrerStats {
+            account_type: AccountType::ReferrerStats,
+            referrer: Pubkey::new_unique(),
+            referral_count: 1,
+            first_referral_epoch: 5,
+            last_referral_epoch: 5,
+            ..Default::default()
+        };
+        let expected = referrer_stats.clone();
+
+        client
+            .expect_gets()
+            .with(predicate::eq(AccountType::ReferrerStats))
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    pubkey,
+                    AccountData::ReferrerStats(referrer_stats.clone()),
+                )]))
+            });
+
+        let list = ListReferrerStatsCommand.execute(&client).unwrap();
+        assert_eq!(list, HashMap::from([(pubkey, expected)]));
+    }
+}
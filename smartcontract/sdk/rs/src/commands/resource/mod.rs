@@ -3,3 +3,4 @@ pub mod closeaccount;
 pub mod create;
 pub mod deallocate;
 pub mod get;
+pub mod resize;
@@ -0,0 +1,45 @@
+use crate::{DoubleZeroClient, GetGlobalStateCommand};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction,
+    pda::{get_globalconfig_pda, get_resource_extension_pda},
+    processors::resource::resize::ResourceResizeArgs,
+    resource::ResourceType,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ResizeResourceCommand {
+    pub resource_type: ResourceType,
+}
+
+impl ResizeResourceCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Signature> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (globalconfig_pubkey, _) = get_globalconfig_pda(&client.get_program_id());
+
+        let (resource_pubkey, _, _) =
+            get_resource_extension_pda(&client.get_program_id(), self.resource_type);
+
+        let resource_resize_args = ResourceResizeArgs {
+            resource_type: self.resource_type,
+        };
+
+        let associated_account_pk = match self.resource_type {
+            ResourceType::DzPrefixBlock(pk, _) | ResourceType::TunnelIds(pk, _) => pk,
+            _ => Pubkey::default(),
+        };
+
+        client.execute_authorized_transaction(
+            DoubleZeroInstruction::ResizeResourceExtension(resource_resize_args),
+            vec![
+                AccountMeta::new(resource_pubkey, false),
+                AccountMeta::new(associated_account_pk, false),
+                AccountMeta::new(globalstate_pubkey, false),
+                AccountMeta::new(globalconfig_pubkey, false),
+            ],
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    record::receipts::{read_execution_records, receipt_log_key, ExecutionRecord},
+    DoubleZeroClient,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Execution receipts of a scheduled job, oldest first, from the receipt log
+/// its scheduler keeps in the record program.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetExecutionHistoryCommand {
+    pub authority: Pubkey,
+    pub job_id: String,
+}
+
+impl GetExecutionHistoryCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<(Pubkey, Vec<ExecutionRecord>)> {
+        let log_pubkey = receipt_log_key(&self.authority, self.job_id.as_bytes());
+        let account = client.get_account(log_pubkey).map_err(|_| {
+            eyre::eyre!(
+                "No receipt log for job {} kept by {}",
+                self.job_id,
+                self.authority
+            )
+        })?;
+        if account.owner != crate::record::ID {
+            return Err(eyre::eyre!("Receipt log {log_pubkey} is not a record"));
+        }
+        let records = read_execution_records(&account.data)
+            .ok_or_else(|| eyre::eyre!("Receipt log {log_pubkey} is not a record"))?;
+        Ok((log_pubkey, records))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::scheduler::history::GetExecutionHistoryCommand,
+        record::receipts::{receipt_log_key, ExecutionRecord},
+        tests::utils::create_test_client,
+    };
+    use doublezero_record::state::RecordData;
+    use mockall::predicate;
+    use solana_sdk::{account::Account, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_scheduler_history_command() {
+        let mut client = create_test_client();
+
+        let authority = Pubkey::new_unique();
+        let log_pubkey = receipt_log_key(&authority, b"rewards-snapshot");
+        let first =
+            ExecutionRecord::new(1, b"rewards-snapshot", 7, &Signature::new_unique(), [1; 32])
+                .unwrap();
+        let second =
+            ExecutionRecord::new(2, b"rewards-snapshot", 8, &Signature::new_unique(), [2; 32])
+                .unwrap();
+
+        let header = RecordData {
+            version: RecordData::CURRENT_VERSION,
+            authority,
+        };
+        let mut data = bytemuck::bytes_of(&header).to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&second));
+        data.extend_from_slice(bytemuck::bytes_of(&first));
+        data.extend_from_slice(&[0u8; ExecutionRecord::LEN]);
+        let account = Account {
+            data,
+            owner: crate::record::ID,
+            ..Account::default()
+        };
+
+        client
+            .expect_get_account()
+            .with(predicate::eq(log_pubkey))
+            .returning(move |_| Ok(account.clone()));
+
+        let res = GetExecutionHistoryCommand {
+            authority,
+            job_id: "rewards-snapshot".to_string(),
+        }
+        .execute(&client);
+        assert_eq!(res.unwrap(), (log_pubkey, vec![first, second]));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::{
+    pda::get_state_commitment_pda,
+    state::{accountdata::AccountData, state_commitment::StateCommitment},
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetStateCommitmentCommand;
+
+impl GetStateCommitmentCommand {
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<(Pubkey, StateCommitment)> {
+        let (pda_pubkey, _) = get_state_commitment_pda(&client.get_program_id());
+        match client.get(pda_pubkey)? {
+            AccountData::StateCommitment(state_commitment) => Ok((pda_pubkey, state_commitment)),
+            _ => Err(eyre::eyre!("Invalid Account Type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::state_commitment::get::GetStateCommitmentCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        pda::get_state_commitment_pda,
+        state::{
+            accountdata::AccountData, accounttype::AccountType, state_commitment::StateCommitment,
+        },
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_commands_state_commitment_get_command() {
+        let mut client = create_test_client();
+
+        let (pda_pubkey, _) = get_state_commitment_pda(&client.get_program_id());
+        let state_commitment = StateCommitment {
+            account_type: AccountType::StateCommitment,
+            slot: 1_000,
+            merkle_root: [7u8; 32],
+            account_count: 42,
+            ..Default::default()
+        };
+        let expected = state_commitment.clone();
+
+        client
+            .expect_get()
+            .with(predicate::eq(pda_pubkey))
+            .returning(move |_| Ok(AccountData::StateCommitment(state_commitment.clone())));
+
+        let res = GetStateCommitmentCommand.execute(&client);
+        assert_eq!(res.unwrap(), (pda_pubkey, expected));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{commands::globalstate::get::GetGlobalStateCommand, DoubleZeroClient};
+use doublezero_serviceability::{
+    instructions::DoubleZeroInstruction, pda::get_state_commitment_pda,
+    processors::state_commitment::record::RecordStateCommitmentArgs,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecordStateCommitmentCommand {
+    pub slot: u64,
+    pub merkle_root: [u8; 32],
+    pub account_count: u32,
+}
+
+impl RecordStateCommitmentCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let (globalstate_pubkey, _globalstate) = GetGlobalStateCommand
+            .execute(client)
+            .map_err(|_err| eyre::eyre!("Globalstate not initialized"))?;
+
+        let (pda_pubkey, _) = get_state_commitment_pda(&client.get_program_id());
+
+        client
+            .execute_authorized_transaction(
+                DoubleZeroInstruction::RecordStateCommitment(RecordStateCommitmentArgs {
+                    slot: self.slot,
+                    merkle_root: self.merkle_root,
+                    account_count: self.account_count,
+                }),
+                vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )
+            .map(|sig| (sig, pda_pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::state_commitment::record::RecordStateCommitmentCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::{
+        instructions::DoubleZeroInstruction,
+        pda::{get_globalstate_pda, get_state_commitment_pda},
+        processors::state_commitment::record::RecordStateCommitmentArgs,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, signature::Signature};
+
+    #[test]
+    fn test_commands_state_commitment_record_command() {
+        let mut client = create_test_client();
+
+        let (globalstate_pubkey, _) = get_globalstate_pda(&client.get_program_id());
+        let (pda_pubkey, _) = get_state_commitment_pda(&client.get_program_id());
+
+        client
+            .expect_execute_authorized_transaction()
+            .with(
+                predicate::eq(DoubleZeroInstruction::RecordStateCommitment(
+                    RecordStateCommitmentArgs {
+                        slot: 1_000,
+                        merkle_root: [7u8; 32],
+                        account_count: 42,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(pda_pubkey, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .returning(|_, _| Ok(Signature::new_unique()));
+
+        let res = RecordStateCommitmentCommand {
+            slot: 1_000,
+            merkle_root: [7u8; 32],
+            account_count: 42,
+        }
+        .execute(&client);
+
+        let (_, pubkey) = res.unwrap();
+        assert_eq!(pubkey, pda_pubkey);
+    }
+}
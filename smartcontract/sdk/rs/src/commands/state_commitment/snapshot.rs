@@ -0,0 +1,142 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::state::{
+    accounttype::AccountType,
+    state_commitment::{leaf_hash, merkle_proof, merkle_root},
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+
+/// Leaf hashes of every serviceability account, read at or after `slot`.
+///
+/// The state commitment account itself is left out, since recording a root rewrites it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SnapshotStateCommand {
+    pub slot: u64,
+}
+
+/// Accounts sorted by pubkey with their leaf hashes, ready to be committed or proven.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct StateSnapshot {
+    pub slot: u64,
+    pub leaves: Vec<(Pubkey, [u8; 32])>,
+}
+
+impl StateSnapshot {
+    pub fn merkle_root(&self) -> [u8; 32] {
+        merkle_root(&self.hashes())
+    }
+
+    /// Index of `pubkey` in the tree and the proof of its leaf, if the snapshot holds it.
+    pub fn proof(&self, pubkey: &Pubkey) -> Option<(usize, Vec<[u8; 32]>)> {
+        let index = self
+            .leaves
+            .binary_search_by_key(pubkey, |(pk, _)| *pk)
+            .ok()?;
+        merkle_proof(&self.hashes(), index).map(|proof| (index, proof))
+    }
+
+    fn hashes(&self) -> Vec<[u8; 32]> {
+        self.leaves.iter().map(|(_, hash)| *hash).collect()
+    }
+}
+
+impl SnapshotStateCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<StateSnapshot> {
+        let options = RpcProgramAccountsConfig {
+            filters: None,
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: Some(self.slot),
+            },
+            with_context: None,
+            sort_results: None,
+        };
+
+        let mut leaves: Vec<(Pubkey, [u8; 32])> = client
+            .get_program_accounts(&client.get_program_id(), options)?
+            .into_iter()
+            .filter(|(_, account)| {
+                account
+                    .data
+                    .first()
+                    .is_some_and(|t| AccountType::from(*t) != AccountType::StateCommitment)
+            })
+            .map(|(pubkey, account)| (pubkey, leaf_hash(&pubkey, &account.data)))
+            .collect();
+        leaves.sort_by_key(|(pubkey, _)| *pubkey);
+
+        Ok(StateSnapshot {
+            slot: self.slot,
+            leaves,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::state_commitment::snapshot::SnapshotStateCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        state_commitment::{leaf_hash, verify_proof},
+    };
+    use mockall::predicate;
+    use solana_sdk::{account::Account, pubkey::Pubkey};
+
+    fn account(account_type: AccountType, payload: u8) -> Account {
+        Account {
+            data: vec![account_type as u8, payload],
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn test_commands_state_commitment_snapshot_command() {
+        let mut client = create_test_client();
+
+        let program_id = client.get_program_id();
+        let device = Pubkey::new_unique();
+        let link = Pubkey::new_unique();
+        let commitment = Pubkey::new_unique();
+        let empty = Pubkey::new_unique();
+
+        client
+            .expect_get_program_accounts()
+            .with(predicate::eq(program_id), predicate::always())
+            .returning(move |_, config| {
+                assert_eq!(config.account_config.min_context_slot, Some(500));
+                Ok(vec![
+                    (link, account(AccountType::Link, 2)),
+                    (commitment, account(AccountType::StateCommitment, 0)),
+                    (device, account(AccountType::Device, 1)),
+                    (empty, Account::default()),
+                ])
+            });
+
+        let snapshot = SnapshotStateCommand { slot: 500 }.execute(&client).unwrap();
+
+        let mut expected = vec![
+            (device, leaf_hash(&device, &[AccountType::Device as u8, 1])),
+            (link, leaf_hash(&link, &[AccountType::Link as u8, 2])),
+        ];
+        expected.sort_by_key(|(pubkey, _)| *pubkey);
+        assert_eq!(snapshot.leaves, expected);
+
+        let root = snapshot.merkle_root();
+        let (index, proof) = snapshot.proof(&link).unwrap();
+        assert!(verify_proof(
+            &root,
+            &leaf_hash(&link, &[AccountType::Link as u8, 2]),
+            index,
+            snapshot.leaves.len(),
+            &proof,
+        ));
+        assert!(snapshot.proof(&commitment).is_none());
+    }
+}
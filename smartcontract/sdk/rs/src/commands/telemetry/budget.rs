@@ -0,0 +1,108 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::pda::get_globalstate_pda;
+use doublezero_telemetry::{
+    instructions::TelemetryInstruction, pda::derive_provider_sampling_budget_pda,
+    processors::telemetry::set_provider_sampling_budget::SetProviderSamplingBudgetArgs,
+    serviceability_program_id,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetProviderSamplingBudgetCommand {
+    pub telemetry_program_id: Pubkey,
+    pub oracle_agent_pk: Pubkey,
+    pub data_provider_name: String,
+    pub max_samples_per_epoch: u32,
+}
+
+impl SetProviderSamplingBudgetCommand {
+    /// Caps the samples the oracle agent may write per epoch and exchange pair
+    /// for the data provider. The payer must be in the foundation allowlist.
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<(Signature, Pubkey)> {
+        let (budget_pda, _) = derive_provider_sampling_budget_pda(
+            &self.telemetry_program_id,
+            &self.oracle_agent_pk,
+            &self.data_provider_name,
+        );
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+
+        let signature = client.execute_telemetry_transaction(
+            &self.telemetry_program_id,
+            TelemetryInstruction::SetProviderSamplingBudget(SetProviderSamplingBudgetArgs {
+                oracle_agent_pk: self.oracle_agent_pk,
+                data_provider_name: self.data_provider_name.clone(),
+                max_samples_per_epoch: self.max_samples_per_epoch,
+            }),
+            vec![
+                AccountMeta::new(budget_pda, false),
+                AccountMeta::new(client.get_payer(), true),
+                AccountMeta::new_readonly(globalstate_pubkey, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )?;
+
+        Ok((signature, budget_pda))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::telemetry::budget::SetProviderSamplingBudgetCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::pda::get_globalstate_pda;
+    use doublezero_telemetry::{
+        instructions::TelemetryInstruction, pda::derive_provider_sampling_budget_pda,
+        processors::telemetry::set_provider_sampling_budget::SetProviderSamplingBudgetArgs,
+        serviceability_program_id,
+    };
+    use mockall::predicate;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+    #[test]
+    fn test_commands_telemetry_set_provider_sampling_budget_command() {
+        let mut client = create_test_client();
+
+        let telemetry_program_id = Pubkey::new_unique();
+        let oracle_agent_pk = Pubkey::new_unique();
+        let payer = client.get_payer();
+        let (budget_pda, _) = derive_provider_sampling_budget_pda(
+            &telemetry_program_id,
+            &oracle_agent_pk,
+            "ripeatlas",
+        );
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+
+        client
+            .expect_execute_telemetry_transaction()
+            .with(
+                predicate::eq(telemetry_program_id),
+                predicate::eq(TelemetryInstruction::SetProviderSamplingBudget(
+                    SetProviderSamplingBudgetArgs {
+                        oracle_agent_pk,
+                        data_provider_name: "ripeatlas".to_string(),
+                        max_samples_per_epoch: 1_440,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(budget_pda, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                    AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(Signature::new_unique()));
+
+        let (_, pubkey) = SetProviderSamplingBudgetCommand {
+            telemetry_program_id,
+            oracle_agent_pk,
+            data_provider_name: "ripeatlas".to_string(),
+            max_samples_per_epoch: 1_440,
+        }
+        .execute(&client)
+        .unwrap();
+        assert_eq!(pubkey, budget_pda);
+    }
+}
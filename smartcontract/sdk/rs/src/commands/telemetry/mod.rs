@@ -0,0 +1,4 @@
+pub mod budget;
+pub mod prune;
+pub mod reclaim;
+pub mod rotate;
@@ -0,0 +1,206 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::pda::get_globalstate_pda;
+use doublezero_telemetry::{
+    instructions::TelemetryInstruction,
+    processors::telemetry::close_samples_account::CloseSamplesAccountArgs,
+    serviceability_program_id, state::accounttype::AccountType,
+};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+// account_type (1 byte) followed by epoch (8 bytes) in all samples headers.
+const SAMPLES_HEADER_PREFIX_LEN: usize = 9;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PruneTelemetrySamplesCommand {
+    pub telemetry_program_id: Pubkey,
+    /// Close samples accounts whose epoch is strictly lower than this one.
+    pub epoch_before: u64,
+    pub retention_epochs: u64,
+    /// Account receiving the refunded rent; defaults to the payer.
+    pub treasury: Option<Pubkey>,
+}
+
+impl PruneTelemetrySamplesCommand {
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Vec<(Pubkey, Signature)>> {
+        let payer = client.get_payer();
+        let treasury = self.treasury.unwrap_or(payer);
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+
+        let mut closed = Vec::new();
+        for (pubkey, epoch) in self.list_samples_accounts(client)? {
+            if epoch >= self.epoch_before {
+                continue;
+            }
+
+            let signature = client.execute_telemetry_transaction(
+                &self.telemetry_program_id,
+                TelemetryInstruction::CloseSamplesAccount(CloseSamplesAccountArgs {
+                    retention_epochs: self.retention_epochs,
+                }),
+                vec![
+                    AccountMeta::new(pubkey, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new(treasury, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ],
+            )?;
+            closed.push((pubkey, signature));
+        }
+
+        Ok(closed)
+    }
+
+    // Fetch the (pubkey, epoch) of every device latency, internet latency and path probe samples account,
+    // reading only the header prefix.
+    fn list_samples_accounts(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<Vec<(Pubkey, u64)>> {
+        let mut result = Vec::new();
+
+        for account_type in [
+            AccountType::DeviceLatencySamples,
+            AccountType::InternetLatencySamples,
+            AccountType::PathProbeSamples,
+        ] {
+            let options = RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new(
+                    0, // account_type is the first byte
+                    MemcmpEncodedBytes::Bytes(vec![account_type as u8]),
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(UiDataSliceConfig {
+                        offset: 0,
+                        length: SAMPLES_HEADER_PREFIX_LEN,
+                    }),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    min_context_slot: None,
+                },
+                with_context: None,
+                sort_results: None,
+            };
+
+            for (pubkey, account) in
+                client.get_program_accounts(&self.telemetry_program_id, options)?
+            {
+                let Some(epoch_bytes) = account.data.get(1..SAMPLES_HEADER_PREFIX_LEN) else {
+                    // Skip accounts too short to hold a header
+                    continue;
+                };
+                let epoch = u64::from_le_bytes(epoch_bytes.try_into()?);
+                result.push((pubkey, epoch));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::telemetry::prune::PruneTelemetrySamplesCommand, tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::pda::get_globalstate_pda;
+    use doublezero_telemetry::{
+        instructions::TelemetryInstruction,
+        processors::telemetry::close_samples_account::CloseSamplesAccountArgs,
+        serviceability_program_id, state::accounttype::AccountType,
+    };
+    use mockall::predicate;
+    use solana_sdk::{
+        account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Signature,
+    };
+
+    fn samples_prefix(account_type: AccountType, epoch: u64) -> Account {
+        let mut data = vec![account_type as u8];
+        data.extend_from_slice(&epoch.to_le_bytes());
+        Account {
+            data,
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn test_commands_telemetry_prune_command() {
+        let mut client = create_test_client();
+
+        let telemetry_program_id = Pubkey::new_unique();
+        let payer = client.get_payer();
+        let treasury = Pubkey::new_unique();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+
+        let old_device_samples = Pubkey::new_unique();
+        let new_device_samples = Pubkey::new_unique();
+        let old_internet_samples = Pubkey::new_unique();
+
+        let mut calls = 0;
+        client
+            .expect_get_program_accounts()
+            .with(predicate::eq(telemetry_program_id), predicate::always())
+            .times(3)
+            .returning(move |_, _| {
+                calls += 1;
+                Ok(if calls == 1 {
+                    vec![
+                        (
+                            old_device_samples,
+                            samples_prefix(AccountType::DeviceLatencySamples, 5),
+                        ),
+                        (
+                            new_device_samples,
+                            samples_prefix(AccountType::DeviceLatencySamples, 20),
+                        ),
+                    ]
+                } else if calls == 2 {
+                    vec![(
+                        old_internet_samples,
+                        samples_prefix(AccountType::InternetLatencySamples, 9),
+                    )]
+                } else {
+                    vec![]
+                })
+            });
+
+        for samples in [old_device_samples, old_internet_samples] {
+            client
+                .expect_execute_telemetry_transaction()
+                .with(
+                    predicate::eq(telemetry_program_id),
+                    predicate::eq(TelemetryInstruction::CloseSamplesAccount(
+                        CloseSamplesAccountArgs {
+                            retention_epochs: 10,
+                        },
+                    )),
+                    predicate::eq(vec![
+                        AccountMeta::new(samples, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new(treasury, false),
+                        AccountMeta::new_readonly(globalstate_pubkey, false),
+                    ]),
+                )
+                .times(1)
+                .returning(|_, _, _| Ok(Signature::new_unique()));
+        }
+
+        let closed = PruneTelemetrySamplesCommand {
+            telemetry_program_id,
+            epoch_before: 10,
+            retention_epochs: 10,
+            treasury: Some(treasury),
+        }
+        .execute(&client)
+        .unwrap();
+
+        let closed: Vec<Pubkey> = closed.into_iter().map(|(pk, _)| pk).collect();
+        assert_eq!(closed, vec![old_device_samples, old_internet_samples]);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::DoubleZeroClient;
+use doublezero_serviceability::pda::get_globalstate_pda;
+use doublezero_telemetry::{
+    instructions::TelemetryInstruction,
+    processors::telemetry::{
+        close_samples_account::{CloseSamplesAccountArgs, MIN_SAMPLES_RETENTION_EPOCHS},
+        finalize_epoch_samples::FinalizeEpochSamplesArgs,
+    },
+    serviceability_program_id,
+    state::{
+        accounttype::AccountType,
+        device_latency_samples::{DeviceLatencySamplesHeader, DEVICE_LATENCY_SAMPLES_HEADER_SIZE},
+        internet_latency_samples::{
+            InternetLatencySamplesHeader, INTERNET_LATENCY_SAMPLES_MAX_HEADER_SIZE,
+        },
+        path_probe_samples::{PathProbeSamplesHeader, PATH_PROBE_SAMPLES_HEADER_SIZE},
+    },
+};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReclaimTelemetrySamplesCommand {
+    pub telemetry_program_id: Pubkey,
+    /// Only consider samples accounts whose epoch is strictly lower than this one.
+    pub before_epoch: u64,
+    pub retention_epochs: u64,
+    /// Account receiving the rent of closed accounts; defaults to the payer.
+    pub treasury: Option<Pubkey>,
+    /// Compact accounts when finalizing them, refunding surplus rent to their agent.
+    pub compact: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReclaimAction {
+    /// The account's epoch has ended; it was frozen with `FinalizeEpochSamples`.
+    Finalized,
+    /// The account outlived its retention; it was closed with `CloseSamplesAccount`.
+    Closed,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct SamplesAccountInfo {
+    pubkey: Pubkey,
+    epoch: u64,
+    agent_pk: Pubkey,
+    finalized: bool,
+}
+
+impl ReclaimTelemetrySamplesCommand {
+    /// Crank for ended epochs: closes accounts past their retention and
+    /// finalizes the remaining ones from epochs that have ended.
+    pub fn execute(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<Vec<(Pubkey, ReclaimAction, Signature)>> {
+        let payer = client.get_payer();
+        let treasury = self.treasury.unwrap_or(payer);
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+        let current_epoch = client.get_epoch()?;
+        let retention_epochs = self.retention_epochs.max(MIN_SAMPLES_RETENTION_EPOCHS);
+
+        let mut accounts = self.list_samples_accounts(client)?;
+        accounts.sort_by_key(|a| (a.epoch, a.pubkey));
+
+        let mut reclaimed = Vec::new();
+        for account in accounts {
+            if account.epoch >= self.before_epoch || account.epoch >= current_epoch {
+                continue;
+            }
+
+            if account.epoch.saturating_add(retention_epochs) < current_epoch {
+                let signature = client.execute_telemetry_transaction(
+                    &self.telemetry_program_id,
+                    TelemetryInstruction::CloseSamplesAccount(CloseSamplesAccountArgs {
+                        retention_epochs,
+                    }),
+                    vec![
+                        AccountMeta::new(account.pubkey, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new(treasury, false),
+                        AccountMeta::new_readonly(globalstate_pubkey, false),
+                    ],
+                )?;
+                reclaimed.push((account.pubkey, ReclaimAction::Closed, signature));
+            } else if !account.finalized {
+                let signature = client.execute_telemetry_transaction(
+                    &self.telemetry_program_id,
+                    TelemetryInstruction::FinalizeEpochSamples(FinalizeEpochSamplesArgs {
+                        compact: self.compact,
+                    }),
+                    vec![
+                        AccountMeta::new(account.pubkey, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new(account.agent_pk, false),
+                        AccountMeta::new_readonly(globalstate_pubkey, false),
+                    ],
+                )?;
+                reclaimed.push((account.pubkey, ReclaimAction::Finalized, signature));
+            }
+        }
+
+        Ok(reclaimed)
+    }
+
+    // Fetch the header of every device latency, internet latency and path probe samples account.
+    fn list_samples_accounts(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<Vec<SamplesAccountInfo>> {
+        let mut result = Vec::new();
+
+        for (account_type, header_len) in [
+            (
+                AccountType::DeviceLatencySamples,
+                DEVICE_LATENCY_SAMPLES_HEADER_SIZE,
+            ),
+            (
+                AccountType::InternetLatencySamples,
+                INTERNET_LATENCY_SAMPLES_MAX_HEADER_SIZE,
+            ),
+            (
+                AccountType::PathProbeSamples,
+                PATH_PROBE_SAMPLES_HEADER_SIZE,
+            ),
+        ] {
+            let options = RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new(
+                    0, // account_type is the first byte
+                    MemcmpEncodedBytes::Bytes(vec![account_type as u8]),
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(UiDataSliceConfig {
+                        offset: 0,
+                        length: header_len,
+                    }),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    min_context_slot: None,
+                },
+                with_context: None,
+                sort_results: None,
+            };
+
+            for (pubkey, account) in
+                client.get_program_accounts(&self.telemetry_program_id, options)?
+            {
+                // Skip accounts too short to hold a header
+                let info = match account_type {
+                    AccountType::DeviceLatencySamples => {
+                        DeviceLatencySamplesHeader::try_from(&account.data[..])
+                            .ok()
+                            .map(|h| (h.epoch, h.origin_device_agent_pk, h.is_finalized()))
+                    }
+                    AccountType::PathProbeSamples => {
+                        PathProbeSamplesHeader::try_from(&account.data[..])
+                            .ok()
+                            .map(|h| (h.epoch, h.prober_agent_pk, h.is_finalized()))
+                    }
+                    _ => InternetLatencySamplesHeader::try_from(&account.data[..])
+                        .ok()
+                        .map(|h| (h.epoch, h.oracle_agent_pk, h.is_finalized())),
+                };
+                if let Some((epoch, agent_pk, finalized)) = info {
+                    result.push(SamplesAccountInfo {
+                        pubkey,
+                        epoch,
+                        agent_pk,
+                        finalized,
+                    });
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::telemetry::reclaim::{ReclaimAction, ReclaimTelemetrySamplesCommand},
+        tests::utils::create_test_client,
+        DoubleZeroClient,
+    };
+    use doublezero_serviceability::pda::get_globalstate_pda;
+    use doublezero_telemetry::{
+        instructions::TelemetryInstruction,
+        processors::telemetry::{
+            close_samples_account::CloseSamplesAccountArgs,
+            finalize_epoch_samples::FinalizeEpochSamplesArgs,
+        },
+        serviceability_program_id,
+        state::{
+            accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+            SAMPLES_FLAG_FINALIZED,
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::{
+        account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Signature,
+    };
+
+    fn device_samples(epoch: u64, agent_pk: Pubkey, flags: u8) -> Account {
+        let header = DeviceLatencySamplesHeader {
+            account_type: AccountType::DeviceLatencySamples,
+            epoch,
+            origin_device_agent_pk: agent_pk,
+            origin_device_pk: Pubkey::new_unique(),
+            target_device_pk: Pubkey::new_unique(),
+            origin_device_location_pk: Pubkey::new_unique(),
+            target_device_location_pk: Pubkey::new_unique(),
+            link_pk: Pubkey::new_unique(),
+            sampling_interval_microseconds: 5_000_000,
+            start_timestamp_microseconds: 0,
+            next_sample_index: 0,
+            agent_version: [0; 16],
+            agent_commit: [0; 8],
+            flags,
+            shard_index: 0,
+            previous_agent_pk: Pubkey::default(),
+            agent_rotation_epoch: 0,
+            previous_agent_until_epoch: 0,
+            _unused: [0; 54],
+        };
+        Account {
+            data: borsh::to_vec(&header).unwrap(),
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn test_commands_telemetry_reclaim_command() {
+        let mut client = create_test_client();
+
+        let telemetry_program_id = Pubkey::new_unique();
+        let payer = client.get_payer();
+        let agent = Pubkey::new_unique();
+        let (globalstate_pubkey, _) = get_globalstate_pda(&serviceability_program_id());
+
+        // Current epoch 30 with the minimum retention of 10: epoch 5 is closed,
+        // epoch 25 is finalized, epoch 26 is already finalized and epoch 30
+        // is still running.
+        let expired = Pubkey::new_unique();
+        let ended = Pubkey::new_unique();
+        let already_finalized = Pubkey::new_unique();
+        let current = Pubkey::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(30));
+        let mut calls = 0;
+        client
+            .expect_get_program_accounts()
+            .with(predicate::eq(telemetry_program_id), predicate::always())
+            .times(3)
+            .returning(move |_, _| {
+                calls += 1;
+                Ok(if calls == 1 {
+                    vec![
+                        (current, device_samples(30, agent, 0)),
+                        (ended, device_samples(25, agent, 0)),
+                        (expired, device_samples(5, agent, SAMPLES_FLAG_FINALIZED)),
+                        (
+                            already_finalized,
+                            device_samples(26, agent, SAMPLES_FLAG_FINALIZED),
+                        ),
+                    ]
+                } else {
+                    vec![]
+                })
+            });
+
+        client
+            .expect_execute_telemetry_transaction()
+            .with(
+                predicate::eq(telemetry_program_id),
+                predicate::eq(TelemetryInstruction::CloseSamplesAccount(
+                    CloseSamplesAccountArgs {
+                        retention_epochs: 10,
+                    },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(expired, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new(payer, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(Signature::new_unique()));
+        client
+            .expect_execute_telemetry_transaction()
+            .with(
+                predicate::eq(telemetry_program_id),
+                predicate::eq(TelemetryInstruction::FinalizeEpochSamples(
+                    FinalizeEpochSamplesArgs { compact: true },
+                )),
+                predicate::eq(vec![
+                    AccountMeta::new(ended, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new(agent, false),
+                    AccountMeta::new_readonly(globalstate_pubkey, false),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(Signature::new_unique()));
+
+        let reclaimed = ReclaimTelemetrySamplesCommand {
+            telemetry_program_id,
+            before_epoch: 100,
+            retention_epochs: 0,
+            treasury: None,
+            compact: true,
+        }
+        .execute(&client)
+        .unwrap();
+
+        let reclaimed: Vec<(Pubkey, ReclaimAction)> = reclaimed
+            .into_iter()
+            .map(|(pk, action, _)| (pk, action))
+            .collect();
+        assert_eq!(
+            reclaimed,
+            vec![
+                (expired, ReclaimAction::Closed),
+                (ended, ReclaimAction::Finalized)
+            ]
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{commands::device::get::GetDeviceCommand, DoubleZeroClient};
+use doublezero_telemetry::{
+    instructions::TelemetryInstruction,
+    state::{
+        accounttype::AccountType,
+        device_latency_samples::{
+            DeviceLatencySamplesHeader, DEVICE_LATENCY_SAMPLES_HEADER_SIZE,
+            METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS,
+        },
+    },
+};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
+
+/// Offset of `origin_device_pk` in a device latency samples header:
+/// account_type (1) + epoch (8) + origin_device_agent_pk (32).
+const ORIGIN_DEVICE_PK_OFFSET: usize = 1 + 8 + 32;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RotateMetricsPublisherCommand {
+    pub telemetry_program_id: Pubkey,
+    pub device_pk: Pubkey,
+}
+
+impl RotateMetricsPublisherCommand {
+    /// Hands the device's open latency samples accounts over to the payer, which
+    /// must already be the device's `metrics_publisher_pk`. Accounts from epochs
+    /// outside the rotation grace period, finalized accounts and accounts the
+    /// payer already owns are skipped.
+    pub fn execute(&self, client: &dyn DoubleZeroClient) -> eyre::Result<Vec<(Pubkey, Signature)>> {
+        let payer = client.get_payer();
+        let (_, device) = GetDeviceCommand {
+            pubkey_or_code: self.device_pk.to_string(),
+        }
+        .execute(client)?;
+        if device.metrics_publisher_pk != payer {
+            return Err(eyre::eyre!(
+                "{} is not the metrics publisher of device {} ({})",
+                payer,
+                self.device_pk,
+                device.metrics_publisher_pk
+            ));
+        }
+
+        let min_epoch = client
+            .get_epoch()?
+            .saturating_sub(METRICS_PUBLISHER_ROTATION_GRACE_EPOCHS);
+
+        let mut accounts: Vec<(Pubkey, DeviceLatencySamplesHeader)> = self
+            .list_samples_accounts(client)?
+            .into_iter()
+            .filter(|(_, header)| {
+                header.epoch >= min_epoch
+                    && !header.is_finalized()
+                    && header.origin_device_agent_pk != payer
+            })
+            .collect();
+        accounts.sort_by_key(|(pubkey, header)| (header.epoch, header.shard_index, *pubkey));
+
+        let mut rotated = Vec::new();
+        for (pubkey, _) in accounts {
+            let signature = client.execute_telemetry_transaction(
+                &self.telemetry_program_id,
+                TelemetryInstruction::RotateMetricsPublisher,
+                vec![
+                    AccountMeta::new(pubkey, false),
+                    AccountMeta::new(payer, true),
+                    AccountMeta::new_readonly(self.device_pk, false),
+                ],
+            )?;
+            rotated.push((pubkey, signature));
+        }
+
+        Ok(rotated)
+    }
+
+    // Fetch the header of every device latency samples account originating at the device.
+    fn list_samples_accounts(
+        &self,
+        client: &dyn DoubleZeroClient,
+    ) -> eyre::Result<Vec<(Pubkey, DeviceLatencySamplesHeader)>> {
+        let options = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new(
+                    0, // account_type is the first byte
+                    MemcmpEncodedBytes::Bytes(vec![AccountType::DeviceLatencySamples as u8]),
+                )),
+                RpcFilterType::Memcmp(Memcmp::new(
+                    ORIGIN_DEVICE_PK_OFFSET,
+                    MemcmpEncodedBytes::Bytes(self.device_pk.to_bytes().to_vec()),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: DEVICE_LATENCY_SAMPLES_HEADER_SIZE,
+                }),
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: None,
+            },
+            with_context: None,
+            sort_results: None,
+        };
+
+        Ok(client
+            .get_program_accounts(&self.telemetry_program_id, options)?
+            .into_iter()
+            .filter_map(|(pubkey, account)| {
+                DeviceLatencySamplesHeader::try_from(&account.data[..])
+                    .ok()
+                    .filter(|h| h.origin_device_pk == self.device_pk)
+                    .map(|h| (pubkey, h))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        commands::telemetry::rotate::RotateMetricsPublisherCommand,
+        tests::utils::create_test_client, DoubleZeroClient,
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType as ServiceabilityAccountType,
+        device::Device,
+    };
+    use doublezero_telemetry::{
+        instructions::TelemetryInstruction,
+        state::{
+            accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+            SAMPLES_FLAG_FINALIZED,
+        },
+    };
+    use mockall::predicate;
+    use solana_sdk::{
+        account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Signature,
+    };
+
+    fn device_samples(device_pk: Pubkey, epoch: u64, agent_pk: Pubkey, flags: u8) -> Account {
+        let header = DeviceLatencySamplesHeader {
+            account_type: AccountType::DeviceLatencySamples,
+            epoch,
+            origin_device_agent_pk: agent_pk,
+            origin_device_pk: device_pk,
+            target_device_pk: Pubkey::new_unique(),
+            origin_device_location_pk: Pubkey::new_unique(),
+            target_device_location_pk: Pubkey::new_unique(),
+            link_pk: Pubkey::new_unique(),
+            sampling_interval_microseconds: 5_000_000,
+            start_timestamp_microseconds: 0,
+            next_sample_index: 0,
+            agent_version: [0; 16],
+            agent_commit: [0; 8],
+            flags,
+            shard_index: 0,
+            previous_agent_pk: Pubkey::default(),
+            agent_rotation_epoch: 0,
+            previous_agent_until_epoch: 0,
+            _unused: [0; 54],
+        };
+        Account {
+            data: borsh::to_vec(&header).unwrap(),
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn test_commands_telemetry_rotate_metrics_publisher_command() {
+        let mut client = create_test_client();
+
+        let telemetry_program_id = Pubkey::new_unique();
+        let payer = client.get_payer();
+        let old_agent = Pubkey::new_unique();
+        let device_pk = Pubkey::new_unique();
+
+        let device = Device {
+            account_type: ServiceabilityAccountType::Device,
+            metrics_publisher_pk: payer,
+            ..Device::default()
+        };
+        client
+            .expect_get()
+            .with(predicate::eq(device_pk))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        // Current epoch 30: epochs 29 and 30 are within the grace period.
+        let previous = Pubkey::new_unique();
+        let current = Pubkey::new_unique();
+        let stale = Pubkey::new_unique();
+        let finalized = Pubkey::new_unique();
+        let already_rotated = Pubkey::new_unique();
+
+        client.expect_get_epoch().returning(|| Ok(30));
+        client
+            .expect_get_program_accounts()
+            .with(predicate::eq(telemetry_program_id), predicate::always())
+            .times(1)
+            .returning(move |_, _| {
+                Ok(vec![
+                    (current, device_samples(device_pk, 30, old_agent, 0)),
+                    (previous, device_samples(device_pk, 29, old_agent, 0)),
+                    (stale, device_samples(device_pk, 28, old_agent, 0)),
+                    (
+                        finalized,
+                        device_samples(device_pk, 29, old_agent, SAMPLES_FLAG_FINALIZED),
+                    ),
+                    (already_rotated, device_samples(device_pk, 30, payer, 0)),
+                ])
+            });
+
+        for pubkey in [previous, current] {
+            client
+                .expect_execute_telemetry_transaction()
+                .with(
+                    predicate::eq(telemetry_program_id),
+                    predicate::eq(TelemetryInstruction::RotateMetricsPublisher),
+                    predicate::eq(vec![
+                        AccountMeta::new(pubkey, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new_readonly(device_pk, false),
+                    ]),
+                )
+                .times(1)
+                .returning(|_, _, _| Ok(Signature::new_unique()));
+        }
+
+        let rotated: Vec<Pubkey> = RotateMetricsPublisherCommand {
+            telemetry_program_id,
+            device_pk,
+        }
+        .execute(&client)
+        .unwrap()
+        .into_iter()
+        .map(|(pk, _)| pk)
+        .collect();
+        assert_eq!(rotated, vec![previous, current]);
+    }
+
+    #[test]
+    fn test_commands_telemetry_rotate_metrics_publisher_requires_device_publisher() {
+        let mut client = create_test_client();
+
+        let device_pk = Pubkey::new_unique();
+        let device = Device {
+            account_type: ServiceabilityAccountType::Device,
+            metrics_publisher_pk: Pubkey::new_unique(),
+            ..Device::default()
+        };
+        client
+            .expect_get()
+            .with(predicate::eq(device_pk))
+            .returning(move |_| Ok(AccountData::Device(device.clone())));
+
+        let err = RotateMetricsPublisherCommand {
+            telemetry_program_id: Pubkey::new_unique(),
+            device_pk,
+        }
+        .execute(&client)
+        .unwrap_err();
+        assert!(err.to_string().contains("is not the metrics publisher"));
+    }
+}
@@ -55,6 +55,8 @@ impl DeleteTenantCommand {
                     tenant: Pubkey::default(),
                     max_unicast_users: ap.max_unicast_users,
                     max_multicast_users: ap.max_multicast_users,
+                    bandwidth_tier: None,
+                    referrer: None,
                 }
                 .execute(client)?;
             }
@@ -100,6 +102,8 @@ impl DeleteTenantCommand {
                     tenant: Pubkey::default(),
                     max_unicast_users: ap.max_unicast_users,
                     max_multicast_users: ap.max_multicast_users,
+                    bandwidth_tier: None,
+                    referrer: None,
                 }
                 .execute(client)?;
             }
@@ -222,6 +226,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         let (accesspass_pubkey, _) = get_accesspass_pda(
@@ -247,6 +252,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let tenant_after = Tenant {
@@ -357,6 +364,8 @@ mod tests {
                     allow_multiple_ip: false,
                     max_unicast_users: 1,
                     max_multicast_users: 1,
+                    bandwidth_tier: None,
+                    referrer: None,
                 })),
                 predicate::always(),
             )
@@ -427,6 +436,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
 
         let mut seq = Sequence::new();
@@ -477,6 +488,8 @@ mod tests {
                     allow_multiple_ip: false,
                     max_unicast_users: 1,
                     max_multicast_users: 1,
+                    bandwidth_tier: None,
+                    referrer: None,
                 })),
                 predicate::always(),
             )
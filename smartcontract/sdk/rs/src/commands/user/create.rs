@@ -10,7 +10,7 @@ use doublezero_serviceability::{
     pda::{get_resource_extension_pda, get_user_pda},
     processors::user::create::UserCreateArgs,
     resource::ResourceType,
-    state::user::{UserCYOA, UserType},
+    state::user::{BandwidthTier, UserCYOA, UserType},
 };
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
 use std::net::Ipv4Addr;
@@ -23,6 +23,8 @@ pub struct CreateUserCommand {
     pub client_ip: Ipv4Addr,
     pub tunnel_endpoint: Ipv4Addr,
     pub tenant_pk: Option<Pubkey>,
+    /// Requested bandwidth tier; `None` takes the access pass's tier.
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl CreateUserCommand {
@@ -106,6 +108,7 @@ impl CreateUserCommand {
                     client_ip: self.client_ip,
                     tunnel_endpoint: self.tunnel_endpoint,
                     dz_prefix_count: dz_prefix_count_u8,
+                    bandwidth_tier: self.bandwidth_tier,
                 }),
                 accounts,
             )
@@ -167,6 +170,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         client
             .expect_get()
@@ -210,6 +215,7 @@ mod tests {
                     client_ip,
                     tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
                     dz_prefix_count: 1,
+                    bandwidth_tier: None,
                 })),
                 predicate::eq(vec![
                     AccountMeta::new(pda_pubkey, false),
@@ -231,6 +237,7 @@ mod tests {
             client_ip,
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             tenant_pk: None,
+            bandwidth_tier: None,
         }
         .execute(&client);
 
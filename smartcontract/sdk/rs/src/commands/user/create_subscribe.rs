@@ -5,7 +5,7 @@ use doublezero_serviceability::{
     resource::ResourceType,
     state::{
         multicastgroup::MulticastGroupStatus,
-        user::{UserCYOA, UserType},
+        user::{BandwidthTier, UserCYOA, UserType},
     },
 };
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
@@ -36,6 +36,8 @@ pub struct CreateSubscribeUserCommand {
     /// by the pass) covering the device's exchange and listing the target multicast group.
     /// Appended to the account list only when provided.
     pub feed_pk: Option<Pubkey>,
+    /// Requested bandwidth tier; `None` takes the access pass's tier.
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl CreateSubscribeUserCommand {
@@ -135,6 +137,7 @@ impl CreateSubscribeUserCommand {
                     tunnel_endpoint: self.tunnel_endpoint,
                     dz_prefix_count: dz_prefix_count_u8,
                     owner: self.owner.unwrap_or_default(),
+                    bandwidth_tier: self.bandwidth_tier,
                 }),
                 accounts,
             )
@@ -207,6 +210,8 @@ mod tests {
             max_unicast_users: 1,
             multicast_user_count: 0,
             max_multicast_users: 1,
+            bandwidth_tier: Default::default(),
+            referrer: Pubkey::default(),
         };
         client
             .expect_get()
@@ -254,6 +259,7 @@ mod tests {
                         tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
                         dz_prefix_count: 1,
                         owner: Pubkey::default(),
+                        bandwidth_tier: None,
                     },
                 )),
                 predicate::eq(vec![
@@ -281,6 +287,7 @@ mod tests {
             tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
             owner: None,
             feed_pk: None,
+            bandwidth_tier: None,
         }
         .execute(&client);
 
@@ -11,7 +11,7 @@ use doublezero_serviceability::{
     pda::get_resource_extension_pda,
     processors::user::update::UserUpdateArgs,
     resource::ResourceType,
-    state::user::{UserCYOA, UserType},
+    state::user::{BandwidthTier, UserCYOA, UserType},
 };
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signature};
 use std::net::Ipv4Addr;
@@ -27,6 +27,7 @@ pub struct UpdateUserCommand {
     pub validator_pubkey: Option<Pubkey>,
     pub tenant_pk: Option<Pubkey>,
     pub tunnel_endpoint: Option<Ipv4Addr>,
+    pub bandwidth_tier: Option<BandwidthTier>,
 }
 
 impl UpdateUserCommand {
@@ -121,6 +122,7 @@ impl UpdateUserCommand {
                 dz_prefix_count,
                 multicast_publisher_count,
                 tunnel_endpoint: self.tunnel_endpoint,
+                bandwidth_tier: self.bandwidth_tier,
             }),
             accounts,
         )
@@ -185,6 +187,7 @@ mod tests {
             last_bgp_reported_at: 0,
             bgp_rtt_ns: 0,
             feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
         };
 
         client
@@ -225,6 +228,7 @@ mod tests {
                     dz_prefix_count: 1,
                     multicast_publisher_count: 1,
                     tunnel_endpoint: None,
+                    bandwidth_tier: None,
                 })),
                 predicate::eq(vec![
                     AccountMeta::new(user_pubkey, false),
@@ -247,6 +251,7 @@ mod tests {
             validator_pubkey: None,
             tenant_pk: None,
             tunnel_endpoint: None,
+            bandwidth_tier: None,
         }
         .execute(&client);
 
@@ -1,3 +1,7 @@
+use crate::{
+    endpoints::{RpcEndpointConfig, RpcReadMode},
+    policy::ClientPolicyConfig,
+};
 use doublezero_config::Environment;
 use serde::{Deserialize, Serialize};
 use solana_client::client_error::reqwest::Url;
@@ -68,6 +72,14 @@ pub struct ClientConfig {
     pub address_labels: HashMap<String, String>,
     #[serde(default)]
     pub geo_program_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_policy: Option<ClientPolicyConfig>,
+    /// Ledger endpoints tried after `json_rpc_url`/`websocket_url` when it
+    /// fails with a network error.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_endpoints: Vec<RpcEndpointConfig>,
+    #[serde(default, skip_serializing_if = "RpcReadMode::is_failover")]
+    pub rpc_read_mode: RpcReadMode,
 }
 
 pub(crate) fn default_keypair_path() -> PathBuf {
@@ -86,6 +98,9 @@ impl Default for ClientConfig {
             tenant: None,
             address_labels: HashMap::new(),
             geo_program_id: None,
+            client_policy: None,
+            fallback_endpoints: Vec::new(),
+            rpc_read_mode: RpcReadMode::Failover,
         }
     }
 }
@@ -287,6 +302,9 @@ mod tests {
             tenant: None,
             address_labels: Default::default(),
             geo_program_id: None,
+            client_policy: None,
+            fallback_endpoints: Default::default(),
+            rpc_read_mode: Default::default(),
         };
 
         write_doublezero_config(&cfg).unwrap();
@@ -318,6 +336,9 @@ mod tests {
             tenant: None,
             address_labels: Default::default(),
             geo_program_id: None,
+            client_policy: None,
+            fallback_endpoints: Default::default(),
+            rpc_read_mode: Default::default(),
         };
 
         write_doublezero_config(&cfg).unwrap();
@@ -345,6 +366,9 @@ mod tests {
             tenant: None,
             address_labels: Default::default(),
             geo_program_id: None,
+            client_policy: None,
+            fallback_endpoints: Default::default(),
+            rpc_read_mode: Default::default(),
         };
 
         write_doublezero_config(&cfg).unwrap();
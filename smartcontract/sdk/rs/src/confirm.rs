@@ -0,0 +1,241 @@
+//! Transaction confirmation driven by a websocket `signatureSubscribe`.
+//!
+//! [`send_and_confirm_transaction`] subscribes to the signature before sending,
+//! so the cluster pushes the confirmation instead of the client polling
+//! `getSignatureStatuses`. The RPC is only queried to resend a transaction the
+//! cluster has not seen yet, to check the blockhash has not expired, and once
+//! to verify a confirmation. When the websocket is unavailable it falls back
+//! to polling the signature status.
+
+use log::debug;
+use solana_client::{
+    pubsub_client::{PubsubClient, SignatureSubscription},
+    rpc_client::RpcClient,
+    rpc_config::{RpcSendTransactionConfig, RpcSignatureSubscribeConfig},
+    rpc_response::RpcSignatureResult,
+};
+use solana_rpc_client_api::{
+    client_error::{Error as ClientError, ErrorKind as ClientErrorKind, Result as ClientResult},
+    request::RpcError,
+};
+use solana_sdk::{clock::Slot, signature::Signature, transaction::Transaction};
+use solana_transaction_status::TransactionStatus;
+use std::time::{Duration, Instant};
+
+/// How long to wait for a websocket notification before checking on the
+/// transaction over RPC.
+const SUBSCRIPTION_TICK: Duration = Duration::from_secs(2);
+/// Interval between signature status polls when no websocket is available.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Minimum interval between blockhash expiry checks.
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Send `transaction` and wait until it reaches the client's commitment.
+///
+/// Gives up when the cluster has not seen the transaction within
+/// `confirm_timeout`, or when its blockhash expires before it is confirmed. A
+/// transaction that was confirmed on a fork that is later abandoned is detected
+/// by checking its status at the notified slot, and is reported as dropped if
+/// it does not land again before the blockhash expires. A transaction that
+/// landed but failed is returned as a `TransactionError`.
+pub(crate) fn send_and_confirm_transaction(
+    rpc: &RpcClient,
+    ws_url: &str,
+    transaction: &Transaction,
+    config: RpcSendTransactionConfig,
+    confirm_timeout: Duration,
+) -> ClientResult<Signature> {
+    let signature = transaction.signatures[0];
+    let commitment = rpc.commitment();
+
+    // Subscribe before sending so a fast confirmation is not missed.
+    let mut subscription = subscribe(ws_url, &signature, rpc);
+    rpc.send_transaction_with_config(transaction, config)?;
+
+    let sent_at = Instant::now();
+    let mut last_expiry_check = sent_at;
+    let mut seen = false;
+    let mut dropped_at: Option<Slot> = None;
+
+    loop {
+        match &subscription {
+            Some((_, receiver)) => match receiver.recv_timeout(SUBSCRIPTION_TICK) {
+                Ok(response) => match response.value {
+                    RpcSignatureResult::ReceivedSignature(_) => {
+                        seen = true;
+                        continue;
+                    }
+                    RpcSignatureResult::ProcessedSignature(_) => {
+                        // The node closes the subscription after this notification.
+                        subscription = None;
+                        seen = true;
+                        match get_status(rpc, &signature)? {
+                            Some(status) => {
+                                if status.slot != response.context.slot {
+                                    debug!(
+                                        "{signature} confirmed in slot {} and found in slot {}",
+                                        response.context.slot, status.slot
+                                    );
+                                }
+                                return into_result(signature, status);
+                            }
+                            None => {
+                                debug!(
+                                    "{signature} confirmed in slot {} but no longer found",
+                                    response.context.slot
+                                );
+                                dropped_at = Some(response.context.slot);
+                            }
+                        }
+                    }
+                },
+                Err(err) if err.is_disconnected() => subscription = None,
+                Err(_) => {}
+            },
+            None => {
+                std::thread::sleep(POLL_INTERVAL);
+                if let Some(status) = get_status(rpc, &signature)? {
+                    seen = true;
+                    if status.satisfies_commitment(commitment) {
+                        return into_result(signature, status);
+                    }
+                }
+            }
+        }
+
+        if !seen && sent_at.elapsed() >= confirm_timeout {
+            // Not every node sends received notifications, so look once before
+            // giving up on a transaction the cluster may have seen after all.
+            match get_status(rpc, &signature)? {
+                Some(_) => seen = true,
+                None => {
+                    return Err(for_user(format!(
+                        "unable to confirm transaction {signature}: not seen by the cluster within {}s. \
+                         This can happen in situations such as transaction expiration and insufficient fee-payer funds",
+                        confirm_timeout.as_secs_f32()
+                    )))
+                }
+            }
+        }
+
+        if last_expiry_check.elapsed() >= EXPIRY_CHECK_INTERVAL {
+            last_expiry_check = Instant::now();
+            if !rpc.is_blockhash_valid(&transaction.message.recent_blockhash, commitment)? {
+                // Look once more: it may have landed just before the blockhash expired.
+                if let Some(status) = get_status(rpc, &signature)? {
+                    return into_result(signature, status);
+                }
+                return Err(for_user(match dropped_at {
+                    Some(slot) => format!(
+                        "transaction {signature} was confirmed in slot {slot} but dropped from the ledger \
+                         and its blockhash has expired"
+                    ),
+                    None => format!(
+                        "transaction {signature} expired: its blockhash is no longer valid"
+                    ),
+                }));
+            }
+        }
+
+        if !seen {
+            // Resending is safe: the cluster deduplicates on the signature.
+            if let Err(err) = rpc.send_transaction_with_config(transaction, config) {
+                debug!("Resending {signature} failed: {err}");
+            }
+        }
+    }
+}
+
+/// Subscribe to the signature's confirmation at the client's commitment, or
+/// `None` when the websocket is unavailable.
+fn subscribe(
+    ws_url: &str,
+    signature: &Signature,
+    rpc: &RpcClient,
+) -> Option<SignatureSubscription> {
+    PubsubClient::signature_subscribe(
+        ws_url,
+        signature,
+        Some(RpcSignatureSubscribeConfig {
+            commitment: Some(rpc.commitment()),
+            enable_received_notification: Some(true),
+        }),
+    )
+    .map_err(|err| debug!("signatureSubscribe on {ws_url} failed, polling instead: {err}"))
+    .ok()
+}
+
+fn get_status(rpc: &RpcClient, signature: &Signature) -> ClientResult<Option<TransactionStatus>> {
+    Ok(rpc
+        .get_signature_statuses(&[*signature])?
+        .value
+        .pop()
+        .flatten())
+}
+
+fn into_result(signature: Signature, status: TransactionStatus) -> ClientResult<Signature> {
+    match status.err {
+        None => Ok(signature),
+        Some(err) => Err(ClientErrorKind::TransactionError(err).into()),
+    }
+}
+
+fn for_user(message: String) -> ClientError {
+    RpcError::ForUser(message).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+        transaction::TransactionError,
+    };
+    use solana_system_interface::instruction::transfer;
+
+    fn signed_transaction() -> Transaction {
+        let payer = Keypair::new();
+        let ix = transfer(&payer.pubkey(), &Keypair::new().pubkey(), 1);
+        Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], Hash::default())
+    }
+
+    fn send(mock: &str, transaction: &Transaction) -> ClientResult<Signature> {
+        // The mock client has no websocket, so confirmation falls back to polling.
+        send_and_confirm_transaction(
+            &RpcClient::new_mock(mock.to_string()),
+            "",
+            transaction,
+            RpcSendTransactionConfig::default(),
+            Duration::from_millis(50),
+        )
+    }
+
+    #[test]
+    fn test_confirms_landed_transaction() {
+        let transaction = signed_transaction();
+        assert_eq!(
+            send("succeeds", &transaction).unwrap(),
+            transaction.signatures[0]
+        );
+    }
+
+    #[test]
+    fn test_returns_transaction_error_of_failed_transaction() {
+        let err = send("instruction_error", &signed_transaction()).unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ClientErrorKind::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::UninitializedAccount
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_gives_up_on_unseen_transaction() {
+        let err = send("sig_not_found", &signed_transaction()).unwrap_err();
+        assert!(err.to_string().contains("not seen by the cluster"));
+    }
+}
@@ -3,6 +3,7 @@ use doublezero_serviceability::{
     instructions::DoubleZeroInstruction,
     state::{accountdata::AccountData, accounttype::AccountType},
 };
+use doublezero_telemetry::instructions::TelemetryInstruction;
 use futures::{future::BoxFuture, stream::BoxStream, Future};
 use solana_client::{
     nonblocking::pubsub_client::PubsubClientResult, rpc_config::RpcProgramAccountsConfig,
@@ -31,6 +32,13 @@ pub trait DoubleZeroClient {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> eyre::Result<u64>;
     fn get_multiple_accounts(&self, pubkeys: Vec<Pubkey>) -> eyre::Result<Vec<Option<Account>>>;
     fn transfer_sol(&self, to: Pubkey, lamports: u64) -> eyre::Result<Signature>;
+    /// Sends a single telemetry program instruction signed by the payer.
+    fn execute_telemetry_transaction(
+        &self,
+        telemetry_program_id: &Pubkey,
+        instruction: TelemetryInstruction,
+        accounts: Vec<AccountMeta>,
+    ) -> eyre::Result<Signature>;
     fn get_program_accounts(
         &self,
         program_id: &Pubkey,
@@ -51,6 +59,16 @@ pub trait DoubleZeroClient {
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature>;
 
+    /// Like `execute_transaction`, but appends `optional_accounts` after the payer
+    /// and system program. Use this for optional trailing accounts that a program
+    /// built before they existed ignores.
+    fn execute_transaction_with_optional_accounts(
+        &self,
+        instruction: DoubleZeroInstruction,
+        accounts: Vec<AccountMeta>,
+        optional_accounts: Vec<AccountMeta>,
+    ) -> eyre::Result<Signature>;
+
     /// Like `execute_transaction` but appends the payer's Permission PDA
     /// (read-only) when it exists on-chain, so `authorize()` can find it.
     /// Use this for instructions whose processor calls `authorize()`.
@@ -68,6 +86,14 @@ pub trait DoubleZeroClient {
         accounts: Vec<AccountMeta>,
     ) -> eyre::Result<Signature>;
 
+    /// Sends several authorized instructions atomically in one transaction, in
+    /// order. Callers should check the batch with
+    /// [`crate::DZClient::batch_fits_in_transaction`] first.
+    fn execute_authorized_transactions(
+        &self,
+        batch: Vec<(DoubleZeroInstruction, Vec<AccountMeta>)>,
+    ) -> eyre::Result<Signature>;
+
     fn get_transactions(&self, pubkey: Pubkey) -> eyre::Result<Vec<DZTransaction>>;
 }
 
@@ -0,0 +1,168 @@
+use base64::{engine::general_purpose, Engine};
+use serde::Serialize;
+use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
+
+/// An account referenced by a dry-run instruction.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DryRunAccount {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Human-readable summary of one instruction in a dry-run transaction.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DryRunInstruction {
+    pub program_id: String,
+    pub name: String,
+    pub args: String,
+    pub accounts: Vec<DryRunAccount>,
+}
+
+/// A fully built, unsigned transaction captured instead of being sent.
+///
+/// `transaction` is the bincode-serialized transaction, base64-encoded, with
+/// the recent blockhash set and empty signatures, ready to be signed offline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DryRunTransaction {
+    pub fee_payer: String,
+    pub recent_blockhash: String,
+    pub transaction: String,
+    pub instructions: Vec<DryRunInstruction>,
+}
+
+impl DryRunTransaction {
+    /// Build a dry-run record for `transaction`. Instructions targeting
+    /// `program_id` are summarized, in order, with the `(name, args)` pairs of
+    /// `summaries`; the compute-budget and system instructions are labelled by
+    /// program.
+    pub fn new(
+        transaction: &Transaction,
+        program_id: &Pubkey,
+        summaries: &[(String, String)],
+    ) -> eyre::Result<Self> {
+        let message = &transaction.message;
+        let mut summaries = summaries.iter();
+
+        let instructions = message
+            .instructions
+            .iter()
+            .map(|ix| {
+                let ix_program_id = message.account_keys[ix.program_id_index as usize];
+                let (name, args) = if ix_program_id == *program_id {
+                    summaries.next().cloned().unwrap_or_default()
+                } else if ix_program_id == solana_compute_budget_interface::id() {
+                    ("ComputeBudget".to_string(), String::new())
+                } else if ix_program_id == solana_system_interface::program::ID {
+                    ("System".to_string(), String::new())
+                } else {
+                    ("Unknown".to_string(), String::new())
+                };
+
+                DryRunInstruction {
+                    program_id: ix_program_id.to_string(),
+                    name,
+                    args,
+                    accounts: ix
+                        .accounts
+                        .iter()
+                        .map(|&index| {
+                            let index = index as usize;
+                            DryRunAccount {
+                                pubkey: message.account_keys[index].to_string(),
+                                is_signer: message.is_signer(index),
+                                is_writable: message.is_maybe_writable(index, None),
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            fee_payer: message
+                .account_keys
+                .first()
+                .map(|pk| pk.to_string())
+                .unwrap_or_default(),
+            recent_blockhash: message.recent_blockhash.to_string(),
+            transaction: general_purpose::STANDARD.encode(bincode::serde::encode_to_vec(
+                transaction,
+                bincode::config::legacy(),
+            )?),
+            instructions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+    };
+
+    #[test]
+    fn test_dry_run_transaction_summary() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+
+        let instructions = vec![
+            solana_compute_budget_interface::ComputeBudgetInstruction::set_compute_unit_limit(
+                1_000,
+            ),
+            Instruction::new_with_bytes(
+                program_id,
+                &[1, 2, 3],
+                vec![
+                    AccountMeta::new_readonly(account, false),
+                    AccountMeta::new(payer, true),
+                ],
+            ),
+        ];
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer));
+        transaction.message.recent_blockhash = Hash::new_unique();
+
+        let dry_run = DryRunTransaction::new(
+            &transaction,
+            &program_id,
+            &[("Test".to_string(), "a: 1".to_string())],
+        )
+        .unwrap();
+
+        assert_eq!(dry_run.fee_payer, payer.to_string());
+        assert_eq!(
+            dry_run.recent_blockhash,
+            transaction.message.recent_blockhash.to_string()
+        );
+        assert_eq!(dry_run.instructions.len(), 2);
+        assert_eq!(dry_run.instructions[0].name, "ComputeBudget");
+        assert_eq!(dry_run.instructions[1].name, "Test");
+        assert_eq!(dry_run.instructions[1].args, "a: 1");
+        assert_eq!(
+            dry_run.instructions[1].accounts,
+            vec![
+                DryRunAccount {
+                    pubkey: account.to_string(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                DryRunAccount {
+                    pubkey: payer.to_string(),
+                    is_signer: true,
+                    is_writable: true,
+                },
+            ]
+        );
+
+        // The encoded transaction round-trips.
+        let bytes = general_purpose::STANDARD
+            .decode(&dry_run.transaction)
+            .unwrap();
+        let (decoded, _): (Transaction, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+        assert_eq!(decoded, transaction);
+    }
+}
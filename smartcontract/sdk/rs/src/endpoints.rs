@@ -0,0 +1,321 @@
+use crate::{
+    config::{convert_url_moniker, convert_url_to_ws, convert_ws_moniker},
+    policy::ClientPolicy,
+};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use std::{
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A fallback ledger endpoint from the `fallback_endpoints` list of
+/// `config.yml`. The websocket URL is derived from the RPC URL when unset.
+/// Both accept the same monikers as `doublezero config set --url/--ws`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcEndpointConfig {
+    pub json_rpc_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websocket_url: Option<String>,
+}
+
+/// How a [`DZClient`](crate::DZClient) with fallback endpoints picks the one
+/// it talks to.
+///
+/// `Failover` starts on the primary endpoint and only moves on when a request
+/// fails with a transient network error. `Fastest` probes every endpoint when
+/// the client is built and starts on the one that answered first, then fails
+/// over the same way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RpcReadMode {
+    #[default]
+    Failover,
+    Fastest,
+}
+
+impl RpcReadMode {
+    pub fn is_failover(&self) -> bool {
+        *self == RpcReadMode::Failover
+    }
+}
+
+impl fmt::Display for RpcReadMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcReadMode::Failover => write!(f, "failover"),
+            RpcReadMode::Fastest => write!(f, "fastest"),
+        }
+    }
+}
+
+impl FromStr for RpcReadMode {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "failover" => Ok(RpcReadMode::Failover),
+            "fastest" => Ok(RpcReadMode::Fastest),
+            _ => Err(eyre::eyre!(
+                "invalid RPC read mode '{s}': expected 'failover' or 'fastest'"
+            )),
+        }
+    }
+}
+
+pub(crate) struct RpcEndpoint {
+    pub(crate) rpc_url: String,
+    pub(crate) ws_url: String,
+    pub(crate) client: RpcClient,
+}
+
+/// The primary ledger endpoint followed by the configured fallbacks, and the
+/// index of the one currently in use.
+pub(crate) struct RpcEndpoints {
+    endpoints: Vec<RpcEndpoint>,
+    active: AtomicUsize,
+}
+
+impl RpcEndpoints {
+    pub(crate) fn single(rpc_url: String, ws_url: String, policy: &ClientPolicy) -> Self {
+        let client = policy.rpc_client(rpc_url.clone());
+        Self::from_endpoints(vec![RpcEndpoint {
+            rpc_url,
+            ws_url,
+            client,
+        }])
+    }
+
+    /// A single endpoint backed by `RpcClient::new_mock(url)`.
+    #[cfg(test)]
+    pub(crate) fn mock(url: &str) -> Self {
+        Self::from_endpoints(vec![RpcEndpoint {
+            rpc_url: String::new(),
+            ws_url: String::new(),
+            client: RpcClient::new_mock(url.to_string()),
+        }])
+    }
+
+    fn from_endpoints(endpoints: Vec<RpcEndpoint>) -> Self {
+        Self {
+            endpoints,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Append `fallbacks` after the current endpoints and, in `Fastest` mode,
+    /// start on whichever endpoint answers a probe first.
+    pub(crate) fn with_fallbacks(
+        mut self,
+        fallbacks: &[RpcEndpointConfig],
+        read_mode: RpcReadMode,
+        policy: &ClientPolicy,
+    ) -> eyre::Result<Self> {
+        for fallback in fallbacks {
+            let rpc_url = convert_url_moniker(fallback.json_rpc_url.clone());
+            let ws_url = match &fallback.websocket_url {
+                Some(ws_url) => convert_ws_moniker(ws_url.clone()),
+                None => convert_url_to_ws(&rpc_url)?,
+            };
+            let client = policy.rpc_client(rpc_url.clone());
+            self.endpoints.push(RpcEndpoint {
+                rpc_url,
+                ws_url,
+                client,
+            });
+        }
+        if read_mode == RpcReadMode::Fastest && self.endpoints.len() > 1 {
+            self.select_fastest();
+        }
+        Ok(self)
+    }
+
+    /// Rebuild every RPC client with `policy`'s timeouts, keeping the active
+    /// endpoint.
+    pub(crate) fn with_policy(self, policy: &ClientPolicy) -> Self {
+        let active = self.active.into_inner();
+        let endpoints = self
+            .endpoints
+            .into_iter()
+            .map(|endpoint| RpcEndpoint {
+                client: policy.rpc_client(endpoint.rpc_url.clone()),
+                ..endpoint
+            })
+            .collect();
+        Self {
+            endpoints,
+            active: AtomicUsize::new(active),
+        }
+    }
+
+    pub(crate) fn active(&self) -> &RpcEndpoint {
+        &self.endpoints[self.active.load(Ordering::Relaxed)]
+    }
+
+    /// Move to the next endpoint that answers a probe, starting after the
+    /// active one. When none does, move to the next one anyway so the
+    /// following retry does not hammer the endpoint that just failed. A
+    /// single endpoint is never probed.
+    pub(crate) fn fail_over(&self) {
+        let count = self.endpoints.len();
+        if count < 2 {
+            return;
+        }
+        let current = self.active.load(Ordering::Relaxed);
+        let next = (1..count)
+            .map(|offset| (current + offset) % count)
+            .find(|&index| Self::probe(&self.endpoints[index]).is_some())
+            .unwrap_or((current + 1) % count);
+        warn!(
+            "RPC endpoint {} failed, switching to {}",
+            self.endpoints[current].rpc_url, self.endpoints[next].rpc_url
+        );
+        self.active.store(next, Ordering::Relaxed);
+    }
+
+    /// Probe every endpoint concurrently and make the quickest responder
+    /// active. Keeps the current endpoint if none responds.
+    fn select_fastest(&mut self) {
+        let latencies: Vec<Option<Duration>> = thread::scope(|scope| {
+            let probes: Vec<_> = self
+                .endpoints
+                .iter()
+                .map(|endpoint| scope.spawn(move || Self::probe(endpoint)))
+                .collect();
+            probes
+                .into_iter()
+                .map(|probe| probe.join().ok().flatten())
+                .collect()
+        });
+        if let Some((index, latency)) = latencies
+            .iter()
+            .enumerate()
+            .filter_map(|(index, latency)| latency.map(|latency| (index, latency)))
+            .min_by_key(|(_, latency)| *latency)
+        {
+            debug!(
+                "Fastest RPC endpoint: {} ({latency:?})",
+                self.endpoints[index].rpc_url
+            );
+            *self.active.get_mut() = index;
+        }
+    }
+
+    /// Health check: the time `getSlot` took, or `None` if it failed.
+    fn probe(endpoint: &RpcEndpoint) -> Option<Duration> {
+        let start = Instant::now();
+        endpoint.client.get_slot().ok().map(|_| start.elapsed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An endpoint nothing listens on, so every request fails immediately.
+    fn unreachable(port: u16) -> RpcEndpoint {
+        let rpc_url = format!("http://127.0.0.1:{port}");
+        RpcEndpoint {
+            ws_url: format!("ws://127.0.0.1:{port}"),
+            client: RpcClient::new_with_timeout(rpc_url.clone(), Duration::from_millis(200)),
+            rpc_url,
+        }
+    }
+
+    fn healthy(rpc_url: &str) -> RpcEndpoint {
+        RpcEndpoint {
+            rpc_url: rpc_url.to_string(),
+            ws_url: String::new(),
+            client: RpcClient::new_mock("succeeds".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_fail_over_skips_unhealthy_endpoints() {
+        let endpoints =
+            RpcEndpoints::from_endpoints(vec![unreachable(1), unreachable(2), healthy("third")]);
+
+        endpoints.fail_over();
+        assert_eq!(endpoints.active().rpc_url, "third");
+
+        // The failed endpoint is not probed again; with no other healthy one,
+        // the next in line is used.
+        endpoints.fail_over();
+        assert_eq!(endpoints.active().rpc_url, "http://127.0.0.1:1");
+    }
+
+    #[test]
+    fn test_fail_over_moves_on_when_nothing_is_healthy() {
+        let endpoints = RpcEndpoints::from_endpoints(vec![unreachable(1), unreachable(2)]);
+
+        endpoints.fail_over();
+        assert_eq!(endpoints.active().rpc_url, "http://127.0.0.1:2");
+    }
+
+    #[test]
+    fn test_single_endpoint_never_fails_over() {
+        let endpoints = RpcEndpoints::from_endpoints(vec![unreachable(1)]);
+        endpoints.fail_over();
+        assert_eq!(endpoints.active().rpc_url, "http://127.0.0.1:1");
+    }
+
+    #[test]
+    fn test_fastest_selects_a_responding_endpoint() {
+        let mut endpoints = RpcEndpoints::from_endpoints(vec![unreachable(1), healthy("second")]);
+        endpoints.select_fastest();
+        assert_eq!(endpoints.active().rpc_url, "second");
+    }
+
+    #[test]
+    fn test_fallbacks_resolve_monikers_and_ws_urls() {
+        let endpoints = RpcEndpoints::single(
+            "http://primary".to_string(),
+            "ws://primary".to_string(),
+            &ClientPolicy::default(),
+        )
+        .with_fallbacks(
+            &[
+                RpcEndpointConfig {
+                    json_rpc_url: "https://backup.example.com".to_string(),
+                    websocket_url: None,
+                },
+                RpcEndpointConfig {
+                    json_rpc_url: "localhost".to_string(),
+                    websocket_url: Some("localhost".to_string()),
+                },
+            ],
+            RpcReadMode::Failover,
+            &ClientPolicy::default(),
+        )
+        .unwrap();
+
+        let urls: Vec<(&str, &str)> = endpoints
+            .endpoints
+            .iter()
+            .map(|e| (e.rpc_url.as_str(), e.ws_url.as_str()))
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                ("http://primary", "ws://primary"),
+                ("https://backup.example.com", "wss://backup.example.com/"),
+                (crate::consts::LOCALHOST_URL, crate::consts::LOCALHOST_WS),
+            ]
+        );
+        assert_eq!(endpoints.active().rpc_url, "http://primary");
+    }
+
+    #[test]
+    fn test_read_mode_parse() {
+        assert_eq!(
+            "fastest".parse::<RpcReadMode>().unwrap(),
+            RpcReadMode::Fastest
+        );
+        assert!("random".parse::<RpcReadMode>().is_err());
+        assert_eq!(RpcReadMode::Failover.to_string(), "failover");
+    }
+}
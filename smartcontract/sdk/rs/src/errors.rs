@@ -1,5 +1,10 @@
+use doublezero_config::Environment;
+use doublezero_geolocation::error::GeolocationError;
+use doublezero_serviceability::error::DoubleZeroError;
+use doublezero_telemetry::error::TelemetryError;
 use solana_rpc_client_api::{request, response};
 use solana_sdk::{
+    instruction::InstructionError, program_error::ProgramError, pubkey::Pubkey,
     signature::SignerError, transaction::TransactionError, transport::TransportError,
 };
 use std::io;
@@ -104,7 +109,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[error("{source}")]
 pub struct SimulationError {
     #[source]
-    pub source: doublezero_serviceability::error::DoubleZeroError,
+    pub source: DoubleZeroError,
     pub program_logs: Vec<String>,
 }
 
@@ -116,3 +121,295 @@ pub struct SimulationTransactionError {
     pub source: TransactionError,
     pub program_logs: Vec<String>,
 }
+
+/// The onchain program a custom error code belongs to. Each program numbers
+/// its errors independently, so a bare `Custom(n)` is only meaningful once
+/// the failing program is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorProgram {
+    Serviceability,
+    Telemetry,
+    Geolocation,
+}
+
+impl ErrorProgram {
+    /// The program deployed at `program_id` in any of the built-in
+    /// environments.
+    pub fn from_program_id(program_id: &Pubkey) -> Option<Self> {
+        [
+            Environment::MainnetBeta,
+            Environment::Testnet,
+            Environment::Devnet,
+            Environment::Local,
+        ]
+        .iter()
+        .filter_map(|env| env.config().ok())
+        .find_map(|config| {
+            if config.serviceability_program_id == *program_id {
+                Some(Self::Serviceability)
+            } else if config.telemetry_program_id == *program_id {
+                Some(Self::Telemetry)
+            } else if config.geolocation_program_id == *program_id {
+                Some(Self::Geolocation)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Decode `code`, or `None` if this program defines no such error.
+    pub fn decode(self, code: u32) -> Option<DecodedError> {
+        let (name, message) = match self {
+            Self::Serviceability => match DoubleZeroError::from(code) {
+                DoubleZeroError::Custom(_) => return None,
+                err => (format!("{err:?}"), err.to_string()),
+            },
+            Self::Telemetry => {
+                let err = TelemetryError::try_from(code).ok()?;
+                (format!("{err:?}"), err.to_string())
+            }
+            Self::Geolocation => {
+                let err = GeolocationError::try_from(code).ok()?;
+                (format!("{err:?}"), err.to_string())
+            }
+        };
+        let hint = hint(self, &name);
+        Some(DecodedError {
+            program: self,
+            code,
+            name,
+            message,
+            hint,
+        })
+    }
+}
+
+/// A program's custom error code resolved to its enum variant.
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("{name}: {message}")]
+pub struct DecodedError {
+    pub program: ErrorProgram,
+    pub code: u32,
+    /// The error's enum variant, e.g. `InterfaceHasEdgeAssignment`.
+    pub name: String,
+    /// The variant's description.
+    pub message: String,
+    /// What the user can do about it, where there is something to do.
+    pub hint: Option<&'static str>,
+}
+
+/// Decode custom error `code` returned by the program at `program_id`.
+///
+/// `None` if `program_id` is not a serviceability, telemetry or geolocation
+/// program of a built-in environment, or the program defines no such code.
+pub fn decode(program_id: &Pubkey, code: u32) -> Option<DecodedError> {
+    ErrorProgram::from_program_id(program_id)?.decode(code)
+}
+
+/// The first decodable program error in `err`'s chain.
+///
+/// Serviceability transactions surface [`DoubleZeroError`] (or a
+/// [`SimulationError`] wrapping one); telemetry and geolocation transactions
+/// attach a [`DecodedError`] via [`with_decoded_error`].
+pub fn decode_report(err: &eyre::Report) -> Option<DecodedError> {
+    if let Some(decoded) = err.downcast_ref::<DecodedError>() {
+        return Some(decoded.clone());
+    }
+    err.chain().find_map(|cause| {
+        let err = match cause.downcast_ref::<SimulationError>() {
+            Some(simulation) => &simulation.source,
+            None => cause.downcast_ref::<DoubleZeroError>()?,
+        };
+        let ProgramError::Custom(code) = err.clone().into() else {
+            return None;
+        };
+        ErrorProgram::Serviceability.decode(code)
+    })
+}
+
+/// Attach the decoded custom error, if any, to a failed transaction of
+/// `program`, keeping `tx_err` as its cause.
+pub fn with_decoded_error(
+    program: ErrorProgram,
+    tx_err: Option<&TransactionError>,
+    err: eyre::Report,
+) -> eyre::Report {
+    match tx_err {
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) => {
+            match program.decode(*code) {
+                Some(decoded) => err.wrap_err(decoded),
+                None => err,
+            }
+        }
+        _ => err,
+    }
+}
+
+/// Remediation hints for the errors a CLI user can act on.
+fn hint(program: ErrorProgram, name: &str) -> Option<&'static str> {
+    let hint = match (program, name) {
+        (ErrorProgram::Serviceability, "NotAllowed" | "Unauthorized") => {
+            "the signing keypair lacks the required role; check `doublezero address` and the \
+             foundation or contributor allowlists"
+        }
+        (ErrorProgram::Serviceability, "InvalidOwnerPubkey") => {
+            "sign with the keypair that owns the account"
+        }
+        (ErrorProgram::Serviceability, "AccessPassNotFound" | "AccessPassUnauthorized") => {
+            "request an access pass for this client IP and payer; `doublezero access-pass list` \
+             shows the existing ones"
+        }
+        (ErrorProgram::Serviceability, "InvalidClientIp") => {
+            "the access pass is for a different client IP; connect from the IP on the pass"
+        }
+        (ErrorProgram::Serviceability, "InterfaceHasEdgeAssignment") => {
+            "remove the interface's CYOA or DIA assignment before linking it, or pick another \
+             interface"
+        }
+        (ErrorProgram::Serviceability, "EntityLocked") => {
+            "the device or link is frozen for troubleshooting; `doublezero device get` or \
+             `doublezero link get` shows who holds the lock and until when"
+        }
+        (ErrorProgram::Serviceability, "InvalidCoOwner") => {
+            "grant co-ownership to a key other than the device's contributor owner"
+        }
+        (ErrorProgram::Serviceability, "ReferenceCountNotZero") => {
+            "delete the accounts that still reference this one first"
+        }
+        (ErrorProgram::Serviceability, "DeviceHasInterfaces") => {
+            "delete the device's interfaces first; `doublezero device interface list` shows them"
+        }
+        (ErrorProgram::Serviceability, "MulticastGroupNotEmpty") => {
+            "remove the group's publishers and subscribers first"
+        }
+        (ErrorProgram::Serviceability, "AccessPassInUse") => {
+            "disconnect the users on this access pass first"
+        }
+        (
+            ErrorProgram::Serviceability,
+            "MaxUsersExceeded"
+            | "MaxUnicastUsersExceeded"
+            | "MaxMulticastSubscribersExceeded"
+            | "MaxMulticastPublishersExceeded",
+        ) => "the device is full; connect to another device with `--device`",
+        (
+            ErrorProgram::Serviceability,
+            "AccessPassMaxUnicastUsersExceeded" | "AccessPassMaxMulticastUsersExceeded",
+        ) => "disconnect another user on this access pass or ask for a higher limit",
+        (ErrorProgram::Serviceability, "FeedSeatFull") => {
+            "the feed has no free seats; retry later or ask for a higher max_users"
+        }
+        (ErrorProgram::Serviceability, "CodeTooLong" | "NameTooLong" | "VrfIdTooLong") => {
+            "shorten the value and retry"
+        }
+        (ErrorProgram::Serviceability, "ProgramPaused") => {
+            "the program is paused for maintenance; retry once it resumes"
+        }
+        (ErrorProgram::Serviceability, "FeatureNotEnabled") => {
+            "this feature is not enabled in the current environment"
+        }
+        (ErrorProgram::Serviceability, "ContributorSuspended") => {
+            "the contributor is suspended; contact the foundation"
+        }
+        (ErrorProgram::Serviceability, "DeviceNotAttested") => {
+            "complete the key challenge with `doublezero device challenge` and \
+             `doublezero device prove-key` first"
+        }
+        (ErrorProgram::Serviceability, "LeaseNotExpired") => {
+            "wait for the access pass lease to expire"
+        }
+        (ErrorProgram::Telemetry, "UnauthorizedAgent") => {
+            "sign with the device's metrics publisher keypair"
+        }
+        (ErrorProgram::Telemetry, "EpochMismatch" | "SamplesAccountFinalized") => {
+            "the epoch has rolled over; write to the current epoch's samples account"
+        }
+        (ErrorProgram::Geolocation, "NotAllowed" | "Unauthorized") => {
+            "sign with the account owner or a foundation allowlist keypair"
+        }
+        (ErrorProgram::Geolocation, "TargetsNotEmpty") => "remove the user's targets first",
+        (ErrorProgram::Geolocation, "ParentDeviceNotFound" | "TargetNotFound") => {
+            "list the probe or user to see its current entries"
+        }
+        _ => return None,
+    };
+    Some(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_serviceability() {
+        let program_id = Environment::Testnet
+            .config()
+            .unwrap()
+            .serviceability_program_id;
+        let decoded = decode(&program_id, 83).unwrap();
+        assert_eq!(decoded.program, ErrorProgram::Serviceability);
+        assert_eq!(decoded.name, "InterfaceHasEdgeAssignment");
+        assert_eq!(
+            decoded.to_string(),
+            "InterfaceHasEdgeAssignment: Interface cannot have both a link and a CYOA or DIA \
+             assignment"
+        );
+        assert!(decoded.hint.is_some());
+        assert!(decode(&program_id, 100_000).is_none());
+    }
+
+    #[test]
+    fn test_decode_telemetry_and_geolocation() {
+        let config = Environment::MainnetBeta.config().unwrap();
+        let telemetry = decode(&config.telemetry_program_id, 1001).unwrap();
+        assert_eq!(telemetry.program, ErrorProgram::Telemetry);
+        assert_eq!(telemetry.name, "UnauthorizedAgent");
+
+        let geolocation = decode(&config.geolocation_program_id, 20).unwrap();
+        assert_eq!(geolocation.program, ErrorProgram::Geolocation);
+        assert_eq!(geolocation.name, "TargetsNotEmpty");
+        assert!(decode(&config.geolocation_program_id, 3).is_none());
+    }
+
+    #[test]
+    fn test_decode_unknown_program() {
+        assert!(decode(&Pubkey::new_unique(), 8).is_none());
+    }
+
+    #[test]
+    fn test_decode_report() {
+        let err = eyre::eyre!(DoubleZeroError::NotAllowed).wrap_err("creating device");
+        let decoded = decode_report(&err).unwrap();
+        assert_eq!(decoded.name, "NotAllowed");
+        assert_eq!(decoded.code, 8);
+
+        let err = eyre::eyre!(SimulationError {
+            source: DoubleZeroError::FeedSeatFull,
+            program_logs: vec![],
+        });
+        assert_eq!(decode_report(&err).unwrap().name, "FeedSeatFull");
+
+        assert!(decode_report(&eyre::eyre!(DoubleZeroError::Custom(5000))).is_none());
+        assert!(decode_report(&eyre::eyre!("connection refused")).is_none());
+    }
+
+    #[test]
+    fn test_with_decoded_error() {
+        let tx_err = TransactionError::InstructionError(1, InstructionError::Custom(1001));
+        let err = with_decoded_error(
+            ErrorProgram::Telemetry,
+            Some(&tx_err),
+            eyre::eyre!(tx_err.clone()),
+        );
+        assert_eq!(decode_report(&err).unwrap().name, "UnauthorizedAgent");
+        assert!(err.chain().nth(1).is_some());
+
+        let other = TransactionError::InstructionError(0, InstructionError::InvalidArgument);
+        let err = with_decoded_error(
+            ErrorProgram::Telemetry,
+            Some(&other),
+            eyre::eyre!(other.clone()),
+        );
+        assert!(decode_report(&err).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Decoding of the structured events the serviceability program logs on
+//! state transitions.
+//!
+//! Events reach the transaction logs as `Program data: <base64> <base64>`
+//! lines. Only data lines logged while the serviceability program itself is
+//! the innermost executing program are decoded, so data logged by other
+//! programs in the same transaction is ignored even if it happens to parse,
+//! and events from a failed invocation are dropped since its state changes
+//! were rolled back.
+
+use base64::{engine::general_purpose, Engine};
+pub use doublezero_serviceability::events::{DoubleZeroEvent, EVENT_TAG};
+use solana_sdk::pubkey::Pubkey;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Render `event` the way the runtime logs it, as a `Program data:` line.
+pub fn event_log_line(event: &DoubleZeroEvent) -> String {
+    let data = borsh::to_vec(event).unwrap_or_default();
+    format!(
+        "{PROGRAM_DATA_PREFIX}{} {}",
+        general_purpose::STANDARD.encode(EVENT_TAG),
+        general_purpose::STANDARD.encode(data)
+    )
+}
+
+/// Decode one `Program data:` log line into an event, regardless of which
+/// program logged it. `None` for any other line.
+pub fn decode_event_line(line: &str) -> Option<DoubleZeroEvent> {
+    let data = line.strip_prefix(PROGRAM_DATA_PREFIX)?;
+    let fields = data
+        .split_whitespace()
+        .map(|field| general_purpose::STANDARD.decode(field).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let fields: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+    DoubleZeroEvent::decode(&fields)
+}
+
+/// Decode the events `program_id` logged in a transaction, in log order.
+pub fn decode_events(program_id: &Pubkey, logs: &[String]) -> Vec<DoubleZeroEvent> {
+    let program_id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut pending = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            let id = words.next().unwrap_or_default();
+            match words.next() {
+                Some("invoke") => {
+                    stack.push(id);
+                    continue;
+                }
+                Some(outcome @ ("success" | "failed:")) => {
+                    stack.pop();
+                    if stack.is_empty() {
+                        // Top-level invocation finished: keep its events only
+                        // if it committed.
+                        if outcome == "success" {
+                            events.append(&mut pending);
+                        }
+                        pending.clear();
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if stack.last() == Some(&program_id.as_str()) {
+            if let Some(event) = decode_event_line(line) {
+                pending.push(event);
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_serviceability::state::{link::LinkStatus, user::UserStatus};
+
+    #[test]
+    fn test_decode_events_from_logs() {
+        let program_id = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+        let link_event = DoubleZeroEvent::LinkStatusChanged {
+            link: Pubkey::new_unique(),
+            from: LinkStatus::Activated,
+            to: LinkStatus::SoftDrained,
+        };
+        let user_event = DoubleZeroEvent::UserStatusChanged {
+            user: Pubkey::new_unique(),
+            from: UserStatus::Activated,
+            to: UserStatus::Banned,
+        };
+
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            "Program log: process_suspend_link".to_string(),
+            event_log_line(&link_event),
+            format!("Program {other_program} invoke [2]"),
+            // Same encoding, but logged by another program: ignored.
+            event_log_line(&user_event),
+            format!("Program {other_program} success"),
+            "Program data: bm90IGFuIGV2ZW50".to_string(),
+            format!("Program {program_id} consumed 1234 of 200000 compute units"),
+            format!("Program {program_id} success"),
+            format!("Program {other_program} invoke [1]"),
+            event_log_line(&user_event),
+            format!("Program {other_program} success"),
+            format!("Program {program_id} invoke [1]"),
+            // Rolled back with the failed invocation: dropped.
+            event_log_line(&user_event),
+            format!("Program {program_id} failed: custom program error: 0x1"),
+        ];
+
+        assert_eq!(decode_events(&program_id, &logs), vec![link_event.clone()]);
+        assert_eq!(
+            decode_events(&other_program, &logs),
+            vec![user_event.clone(), user_event]
+        );
+        assert_eq!(
+            decode_event_line(&event_log_line(&link_event)),
+            Some(link_event)
+        );
+        assert_eq!(decode_event_line("Program log: hello"), None);
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
         convert_geo_program_moniker, convert_url_moniker, default_geolocation_program_id,
         read_doublezero_config,
     },
+    errors::{with_decoded_error, ErrorProgram},
     keypair::load_keypair,
 };
 use doublezero_geolocation::instructions::GeolocationInstruction;
@@ -18,7 +19,7 @@ use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signature, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 use solana_system_interface::program;
 use std::{path::PathBuf, str::FromStr};
@@ -223,12 +224,20 @@ impl GeolocationClient for GeoClient {
         }
 
         if let Some(err) = result.value.err {
-            return Err(eyre!(err));
+            let err = TransactionError::from(err);
+            return Err(with_decoded_error(
+                ErrorProgram::Geolocation,
+                Some(&err),
+                eyre!(err.clone()),
+            ));
         }
 
         self.client
             .send_and_confirm_transaction(&transaction)
-            .map_err(|e| eyre!(e))
+            .map_err(|e| {
+                let tx_err = e.get_transaction_error();
+                with_decoded_error(ErrorProgram::Geolocation, tx_err.as_ref(), eyre!(e))
+            })
     }
 }
 
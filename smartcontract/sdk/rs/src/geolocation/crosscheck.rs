@@ -0,0 +1,322 @@
+//! Cross-check of a device's claimed location against measured latency.
+//!
+//! A device claims a site through its `location_pk`. Telemetry measures the
+//! round-trip time of every link between the device and its neighbours. Light
+//! in fiber cannot cover the great-circle distance between two sites faster
+//! than [`CrossCheckConfig::fiber_km_per_ms`], so a measured RTT below the
+//! physical minimum means at least one of the two claimed locations is wrong.
+
+use std::collections::HashMap;
+
+use doublezero_telemetry::state::device_latency_samples::DeviceLatencySamples;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::topology::TopologySnapshot;
+
+/// Mean Earth radius used for great-circle distances.
+pub const EARTH_RADIUS_KM: f64 = 6_371.0;
+
+/// Thresholds for [`cross_check_device_location`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossCheckConfig {
+    /// Propagation speed in fiber, roughly two thirds of the speed of light.
+    pub fiber_km_per_ms: f64,
+    /// Slack subtracted from the physical minimum RTT before a measurement is
+    /// considered impossible, to absorb timestamping jitter.
+    pub tolerance_us: f64,
+}
+
+impl Default for CrossCheckConfig {
+    fn default() -> Self {
+        Self {
+            fiber_km_per_ms: 200.0,
+            tolerance_us: 100.0,
+        }
+    }
+}
+
+impl CrossCheckConfig {
+    /// Lowest RTT in microseconds that light in fiber allows over `distance_km`.
+    pub fn min_rtt_us(&self, distance_km: f64) -> f64 {
+        2.0 * distance_km / self.fiber_km_per_ms * 1_000.0
+    }
+}
+
+/// Result of checking one link of the device.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkPlacementCheck {
+    pub link_pk: Pubkey,
+    pub peer_device_pk: Pubkey,
+    /// Great-circle distance between the two claimed locations.
+    pub distance_km: f64,
+    /// Physical minimum RTT over `distance_km`.
+    pub min_possible_rtt_us: f64,
+    /// Lowest non-lost sample measured over the link, in either direction.
+    pub measured_rtt_us: f64,
+    /// The measured RTT is faster than light in fiber allows.
+    pub impossible: bool,
+}
+
+/// Outcome of [`cross_check_device_location`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationCrossCheck {
+    pub device_pk: Pubkey,
+    pub location_pk: Pubkey,
+    /// One entry per link with a located peer and at least one measured sample.
+    pub checks: Vec<LinkPlacementCheck>,
+}
+
+impl LocationCrossCheck {
+    /// Links whose measured RTT contradicts the claimed locations.
+    pub fn impossible(&self) -> impl Iterator<Item = &LinkPlacementCheck> {
+        self.checks.iter().filter(|check| check.impossible)
+    }
+
+    /// Share of checked links consistent with the claimed location, from 0.0
+    /// to 1.0, for the attestation confidence score. `None` when no link of
+    /// the device could be checked.
+    pub fn confidence(&self) -> Option<f64> {
+        if self.checks.is_empty() {
+            return None;
+        }
+        let consistent = self.checks.iter().filter(|check| !check.impossible).count();
+        Some(consistent as f64 / self.checks.len() as f64)
+    }
+}
+
+/// Great-circle distance in kilometres between two points, by the haversine
+/// formula.
+pub fn great_circle_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let dphi = (lat2 - lat1).to_radians();
+    let dlambda = (lng2 - lng1).to_radians();
+
+    let a = (dphi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (dlambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Check the location claimed by `device_pk` against the latency measured on
+/// each of its links.
+///
+/// `samples` is the output of
+/// [`get_all_device_latency_samples`](crate::telemetry::get_all_device_latency_samples)
+/// for the epoch to check. Links whose peer has no location, or with no
+/// sample other than losses, are skipped.
+pub fn cross_check_device_location(
+    snapshot: &TopologySnapshot,
+    samples: &HashMap<Pubkey, DeviceLatencySamples>,
+    device_pk: &Pubkey,
+    config: &CrossCheckConfig,
+) -> eyre::Result<LocationCrossCheck> {
+    let device = snapshot
+        .devices
+        .get(device_pk)
+        .ok_or_else(|| eyre::eyre!("Device {device_pk} not found"))?;
+    let location = snapshot
+        .location_of(device_pk)
+        .ok_or_else(|| eyre::eyre!("Location {} not found", device.location_pk))?;
+
+    // Lowest RTT per link, over both measurement directions. A zero sample is
+    // a lost probe.
+    let mut measured: HashMap<Pubkey, u32> = HashMap::new();
+    for latency in samples.values() {
+        let header = &latency.header;
+        if header.origin_device_pk != *device_pk && header.target_device_pk != *device_pk {
+            continue;
+        }
+        if let Some(min) = latency.samples.iter().copied().filter(|&s| s > 0).min() {
+            measured
+                .entry(header.link_pk)
+                .and_modify(|current| *current = (*current).min(min))
+                .or_insert(min);
+        }
+    }
+
+    let mut checks: Vec<LinkPlacementCheck> = measured
+        .into_iter()
+        .filter_map(|(link_pk, rtt_us)| {
+            let link = snapshot.links.get(&link_pk)?;
+            let peer_device_pk = if link.side_a_pk == *device_pk {
+                link.side_z_pk
+            } else {
+                link.side_a_pk
+            };
+            let peer_location = snapshot.location_of(&peer_device_pk)?;
+
+            let distance_km = great_circle_km(
+                location.lat,
+                location.lng,
+                peer_location.lat,
+                peer_location.lng,
+            );
+            let min_possible_rtt_us = config.min_rtt_us(distance_km);
+            let measured_rtt_us = rtt_us as f64;
+            Some(LinkPlacementCheck {
+                link_pk,
+                peer_device_pk,
+                distance_km,
+                min_possible_rtt_us,
+                measured_rtt_us,
+                impossible: measured_rtt_us + config.tolerance_us < min_possible_rtt_us,
+            })
+        })
+        .collect();
+    checks.sort_by_key(|check| check.link_pk);
+
+    Ok(LocationCrossCheck {
+        device_pk: *device_pk,
+        location_pk: device.location_pk,
+        checks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::tests::{device, link};
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        device::Device,
+        location::{Location, LocationStatus},
+    };
+    use doublezero_telemetry::state::{
+        accounttype::AccountType as TelemetryAccountType,
+        device_latency_samples::DeviceLatencySamplesHeader,
+    };
+
+    fn location(lat: f64, lng: f64) -> Location {
+        Location {
+            account_type: AccountType::Location,
+            owner: Pubkey::new_unique(),
+            index: 1,
+            bump_seed: 0,
+            lat,
+            lng,
+            loc_id: 1,
+            status: LocationStatus::Activated,
+            code: String::new(),
+            name: String::new(),
+            country: String::new(),
+            reference_count: 1,
+        }
+    }
+
+    fn latency(
+        origin: Pubkey,
+        target: Pubkey,
+        link_pk: Pubkey,
+        samples: Vec<u32>,
+    ) -> DeviceLatencySamples {
+        DeviceLatencySamples {
+            header: DeviceLatencySamplesHeader {
+                account_type: TelemetryAccountType::DeviceLatencySamples,
+                epoch: 7,
+                origin_device_agent_pk: Pubkey::new_unique(),
+                origin_device_pk: origin,
+                target_device_pk: target,
+                origin_device_location_pk: Pubkey::new_unique(),
+                target_device_location_pk: Pubkey::new_unique(),
+                link_pk,
+                sampling_interval_microseconds: 5_000_000,
+                start_timestamp_microseconds: 1_700_000_000_000_000,
+                next_sample_index: samples.len() as u32,
+                agent_version: [0; 16],
+                agent_commit: [0; 8],
+                flags: 0,
+                shard_index: 0,
+                previous_agent_pk: Pubkey::default(),
+                agent_rotation_epoch: 0,
+                previous_agent_until_epoch: 0,
+                _unused: [0; 54],
+            },
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_great_circle_km() {
+        assert_eq!(great_circle_km(52.37, 4.90, 52.37, 4.90), 0.0);
+        // Amsterdam - New York is about 5,860 km.
+        let distance = great_circle_km(52.37, 4.90, 40.71, -74.01);
+        assert!((5_800.0..5_900.0).contains(&distance), "{distance}");
+        assert!((CrossCheckConfig::default().min_rtt_us(1_000.0) - 10_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cross_check_device_location() {
+        let (ams, nyc, fra) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let [dev_ams, dev_nyc, dev_fra] = std::array::from_fn(|_| Pubkey::new_unique());
+        let [ams_nyc, ams_fra] = std::array::from_fn(|_| Pubkey::new_unique());
+
+        let mut snapshot = TopologySnapshot::default();
+        snapshot.locations.insert(ams, location(52.37, 4.90));
+        snapshot.locations.insert(nyc, location(40.71, -74.01));
+        snapshot.locations.insert(fra, location(50.11, 8.68));
+        for (device_pk, location_pk) in [(dev_ams, ams), (dev_nyc, nyc), (dev_fra, fra)] {
+            snapshot.devices.insert(
+                device_pk,
+                Device {
+                    location_pk,
+                    ..device()
+                },
+            );
+        }
+        snapshot
+            .links
+            .insert(ams_nyc, link(dev_ams, dev_nyc, 70_000_000));
+        snapshot
+            .links
+            .insert(ams_fra, link(dev_fra, dev_ams, 7_000_000));
+
+        let config = CrossCheckConfig::default();
+
+        // Transatlantic in 2ms is faster than light; Amsterdam - Frankfurt in
+        // 7ms is plausible. Lost probes (0) are ignored.
+        let samples = HashMap::from([
+            (
+                Pubkey::new_unique(),
+                latency(dev_ams, dev_nyc, ams_nyc, vec![0, 2_500, 2_000]),
+            ),
+            (
+                Pubkey::new_unique(),
+                latency(dev_nyc, dev_ams, ams_nyc, vec![3_000]),
+            ),
+            (
+                Pubkey::new_unique(),
+                latency(dev_fra, dev_ams, ams_fra, vec![7_000, 0]),
+            ),
+        ]);
+        let result = cross_check_device_location(&snapshot, &samples, &dev_ams, &config).unwrap();
+        assert_eq!(result.location_pk, ams);
+        assert_eq!(result.checks.len(), 2);
+        let impossible: Vec<_> = result.impossible().collect();
+        assert_eq!(impossible.len(), 1);
+        assert_eq!(impossible[0].link_pk, ams_nyc);
+        assert_eq!(impossible[0].peer_device_pk, dev_nyc);
+        assert_eq!(impossible[0].measured_rtt_us, 2_000.0);
+        assert_eq!(result.confidence(), Some(0.5));
+
+        // A realistic transatlantic RTT is consistent with both claims.
+        let samples = HashMap::from([(
+            Pubkey::new_unique(),
+            latency(dev_ams, dev_nyc, ams_nyc, vec![72_000]),
+        )]);
+        let result = cross_check_device_location(&snapshot, &samples, &dev_nyc, &config).unwrap();
+        assert_eq!(result.impossible().count(), 0);
+        assert_eq!(result.confidence(), Some(1.0));
+
+        // Nothing measured: no score.
+        let result =
+            cross_check_device_location(&snapshot, &HashMap::new(), &dev_fra, &config).unwrap();
+        assert_eq!(result.confidence(), None);
+
+        assert!(
+            cross_check_device_location(&snapshot, &samples, &Pubkey::new_unique(), &config)
+                .is_err()
+        );
+    }
+}
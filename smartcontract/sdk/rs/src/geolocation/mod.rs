@@ -1,4 +1,5 @@
 pub mod client;
+pub mod crosscheck;
 pub mod geo_probe;
 pub mod geolocation_user;
 pub mod programconfig;
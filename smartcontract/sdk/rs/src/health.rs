@@ -0,0 +1,391 @@
+//! Network-wide health summary.
+//!
+//! [`NetworkHealth`] condenses one snapshot of the serviceability accounts
+//! into the figures operators check first: devices and links by health,
+//! users still waiting for their BGP session, allocator occupancy and how
+//! recently the health oracle reported.
+
+use std::collections::{BTreeMap, HashMap};
+
+use doublezero_program_common::serializer;
+use doublezero_serviceability::{
+    pda::get_resource_extension_pda,
+    resource::ResourceType,
+    state::{
+        accountdata::AccountData,
+        resource_extension::{Allocator, ResourceExtensionOwned},
+        user::UserStatus,
+    },
+};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::DoubleZeroClient;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct NetworkHealth {
+    pub epoch: u64,
+    /// Device count by `device_health`.
+    pub devices: BTreeMap<String, u64>,
+    /// Link count by `link_health`.
+    pub links: BTreeMap<String, u64>,
+    /// Activated users whose BGP session has never come up.
+    pub pending_users: u64,
+    pub allocators: Vec<AllocatorOccupancy>,
+    pub oracle: OracleFreshness,
+}
+
+/// How full one resource extension account is.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AllocatorOccupancy {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub pubkey: Pubkey,
+    pub resource: String,
+    /// Code of the device a per-device block belongs to.
+    pub device: Option<String>,
+    pub allocated: u64,
+    pub capacity: u64,
+}
+
+impl AllocatorOccupancy {
+    pub fn ratio(&self) -> f64 {
+        if self.capacity == 0 {
+            return 0.0;
+        }
+        self.allocated as f64 / self.capacity as f64
+    }
+}
+
+/// When the health oracle last reported a link's health. Each report stamps
+/// the link's `flap_epoch`, so the latest one across links is the oracle's
+/// most recent report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct OracleFreshness {
+    #[serde(serialize_with = "serializer::serialize_pubkey_as_string")]
+    pub health_oracle_pk: Pubkey,
+    pub last_report_epoch: Option<u64>,
+    pub epochs_since_report: Option<u64>,
+}
+
+impl NetworkHealth {
+    /// Read every serviceability account and summarize it.
+    pub fn load(client: &dyn DoubleZeroClient) -> eyre::Result<Self> {
+        let epoch = client.get_epoch()?;
+        let accounts: Vec<(Pubkey, AccountData)> = client
+            .get_all()?
+            .into_iter()
+            .map(|(pubkey, account)| (*pubkey, *account))
+            .collect();
+        Ok(Self::from_accounts(
+            &client.get_program_id(),
+            epoch,
+            &accounts,
+        ))
+    }
+
+    /// Summarize a snapshot of the serviceability accounts taken at `epoch`.
+    pub fn from_accounts(
+        program_id: &Pubkey,
+        epoch: u64,
+        accounts: &[(Pubkey, AccountData)],
+    ) -> Self {
+        let device_codes: HashMap<Pubkey, &str> = accounts
+            .iter()
+            .filter_map(|(pubkey, account)| match account {
+                AccountData::Device(device) => Some((*pubkey, device.code.as_str())),
+                _ => None,
+            })
+            .collect();
+        let global_resources = global_resources(program_id);
+
+        let mut health = Self {
+            epoch,
+            ..Default::default()
+        };
+        for (pubkey, account) in accounts {
+            match account {
+                AccountData::Device(device) => {
+                    *health
+                        .devices
+                        .entry(device.device_health.to_string())
+                        .or_default() += 1;
+                }
+                AccountData::Link(link) => {
+                    *health
+                        .links
+                        .entry(link.link_health.to_string())
+                        .or_default() += 1;
+                    if link.flap_epoch != 0 {
+                        health.oracle.last_report_epoch =
+                            health.oracle.last_report_epoch.max(Some(link.flap_epoch));
+                    }
+                }
+                AccountData::User(user) => {
+                    if user.status == UserStatus::Activated && user.last_bgp_up_at == 0 {
+                        health.pending_users += 1;
+                    }
+                }
+                AccountData::ResourceExtension(resource) => {
+                    let (name, device_pk) = resource_labels(&global_resources, pubkey, resource);
+                    health.allocators.push(AllocatorOccupancy {
+                        pubkey: *pubkey,
+                        resource: name,
+                        device: device_pk.map(|pk| {
+                            device_codes
+                                .get(&pk)
+                                .map(|code| code.to_string())
+                                .unwrap_or_else(|| pk.to_string())
+                        }),
+                        allocated: resource.iter_allocated().len() as u64,
+                        capacity: resource_capacity(resource),
+                    });
+                }
+                AccountData::GlobalState(globalstate) => {
+                    health.oracle.health_oracle_pk = globalstate.health_oracle_pk;
+                }
+                _ => {}
+            }
+        }
+
+        health.allocators.sort_by(|a, b| {
+            (&a.resource, &a.device, a.pubkey).cmp(&(&b.resource, &b.device, b.pubkey))
+        });
+        health.oracle.epochs_since_report = health
+            .oracle
+            .last_report_epoch
+            .map(|last| epoch.saturating_sub(last));
+        health
+    }
+}
+
+/// The resources the program allocates from network-wide, by PDA.
+fn global_resources(program_id: &Pubkey) -> HashMap<Pubkey, ResourceType> {
+    [
+        ResourceType::DeviceTunnelBlock,
+        ResourceType::UserTunnelBlock,
+        ResourceType::MulticastGroupBlock,
+        ResourceType::MulticastPublisherBlock,
+        ResourceType::LinkIds,
+        ResourceType::SegmentRoutingIds,
+        ResourceType::VrfIds,
+        ResourceType::AdminGroupBits,
+        ResourceType::QosProfileIds,
+    ]
+    .into_iter()
+    .map(|resource_type| {
+        (
+            get_resource_extension_pda(program_id, resource_type).0,
+            resource_type,
+        )
+    })
+    .collect()
+}
+
+/// Name the resource and the device it belongs to, if any. Per-device blocks
+/// are told apart by their allocator kind.
+fn resource_labels(
+    global_resources: &HashMap<Pubkey, ResourceType>,
+    pubkey: &Pubkey,
+    resource: &ResourceExtensionOwned,
+) -> (String, Option<Pubkey>) {
+    if let Some(resource_type) = global_resources.get(pubkey) {
+        return (resource_type.to_string(), None);
+    }
+
+    let name = match resource.allocator {
+        Allocator::Ip(_) => "DzPrefixBlock",
+        Allocator::Id(_) => "TunnelIds",
+    };
+    (name.to_string(), Some(resource.associated_with))
+}
+
+fn resource_capacity(resource: &ResourceExtensionOwned) -> u64 {
+    match &resource.allocator {
+        Allocator::Ip(allocator) => allocator.base_net.size() as u64,
+        Allocator::Id(allocator) => allocator.range.1.saturating_sub(allocator.range.0) as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::utils::create_test_client;
+    use doublezero_program_common::types::NetworkV4;
+    use doublezero_serviceability::{
+        id_allocator::IdAllocator,
+        state::{
+            accounttype::AccountType,
+            device::{Device, DeviceHealth},
+            globalstate::GlobalState,
+            link::{Link, LinkHealth},
+            user::{User, UserCYOA, UserType},
+        },
+    };
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_network_health_from_accounts() {
+        let program_id = Pubkey::new_unique();
+        let device_pk = Pubkey::new_unique();
+        let health_oracle_pk = Pubkey::new_unique();
+        let link_ids_pk = get_resource_extension_pda(&program_id, ResourceType::LinkIds).0;
+        let tunnel_ids_pk = Pubkey::new_unique();
+
+        let device = |device_health| Device {
+            account_type: AccountType::Device,
+            code: "ams-dz1".to_string(),
+            device_health,
+            ..Default::default()
+        };
+        let link = |link_health, flap_epoch| Link {
+            account_type: AccountType::Link,
+            link_health,
+            flap_epoch,
+            ..Default::default()
+        };
+        let user = |status, last_bgp_up_at| User {
+            account_type: AccountType::User,
+            owner: Pubkey::new_unique(),
+            bump_seed: 0,
+            index: 1,
+            tenant_pk: Pubkey::default(),
+            user_type: UserType::IBRL,
+            device_pk,
+            cyoa_type: UserCYOA::GREOverDIA,
+            client_ip: Ipv4Addr::new(1, 2, 3, 4),
+            dz_ip: Ipv4Addr::UNSPECIFIED,
+            tunnel_id: 0,
+            tunnel_net: NetworkV4::default(),
+            status,
+            publishers: vec![],
+            subscribers: vec![],
+            validator_pubkey: Pubkey::default(),
+            tunnel_endpoint: Ipv4Addr::UNSPECIFIED,
+            tunnel_flags: 0,
+            bgp_status: Default::default(),
+            last_bgp_up_at,
+            last_bgp_reported_at: 0,
+            bgp_rtt_ns: 0,
+            feed_pk: Pubkey::default(),
+            bandwidth_tier: Default::default(),
+        };
+        let ids = |associated_with, range| ResourceExtensionOwned {
+            account_type: AccountType::ResourceExtension,
+            owner: program_id,
+            bump_seed: 255,
+            associated_with,
+            allocator: Allocator::Id(IdAllocator::new(range).unwrap()),
+            storage: vec![0b1011, 0, 0, 0, 0, 0, 0, 0],
+        };
+
+        let accounts = vec![
+            (
+                device_pk,
+                AccountData::Device(device(DeviceHealth::ReadyForUsers)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::Device(device(DeviceHealth::ReadyForUsers)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::Device(device(DeviceHealth::Impaired)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::Link(link(LinkHealth::ReadyForService, 97)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::Link(link(LinkHealth::Pending, 0)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::User(user(UserStatus::Activated, 0)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::User(user(UserStatus::Activated, 1_000)),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::User(user(UserStatus::Deleting, 0)),
+            ),
+            (
+                link_ids_pk,
+                AccountData::ResourceExtension(ids(Pubkey::default(), (0, 64))),
+            ),
+            (
+                tunnel_ids_pk,
+                AccountData::ResourceExtension(ids(device_pk, (500, 564))),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountData::GlobalState(GlobalState {
+                    health_oracle_pk,
+                    ..Default::default()
+                }),
+            ),
+        ];
+
+        let health = NetworkHealth::from_accounts(&program_id, 100, &accounts);
+
+        assert_eq!(health.epoch, 100);
+        assert_eq!(
+            health.devices,
+            BTreeMap::from([
+                ("impaired".to_string(), 1),
+                ("ready-for-users".to_string(), 2)
+            ])
+        );
+        assert_eq!(
+            health.links,
+            BTreeMap::from([
+                ("pending".to_string(), 1),
+                ("ready-for-service".to_string(), 1)
+            ])
+        );
+        assert_eq!(health.pending_users, 1);
+        assert_eq!(
+            health.allocators,
+            vec![
+                AllocatorOccupancy {
+                    pubkey: link_ids_pk,
+                    resource: "LinkIds".to_string(),
+                    device: None,
+                    allocated: 3,
+                    capacity: 64,
+                },
+                AllocatorOccupancy {
+                    pubkey: tunnel_ids_pk,
+                    resource: "TunnelIds".to_string(),
+                    device: Some("ams-dz1".to_string()),
+                    allocated: 3,
+                    capacity: 64,
+                },
+            ]
+        );
+        assert_eq!(
+            health.oracle,
+            OracleFreshness {
+                health_oracle_pk,
+                last_report_epoch: Some(97),
+                epochs_since_report: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_network_health_without_oracle_reports() {
+        let mut client = create_test_client();
+        client.expect_get_epoch().returning(|| Ok(10));
+        client.expect_get_all().returning(|| Ok(HashMap::new()));
+
+        let health = NetworkHealth::load(&client).unwrap();
+
+        assert_eq!(health.epoch, 10);
+        assert!(health.devices.is_empty());
+        assert_eq!(health.oracle.last_report_epoch, None);
+        assert_eq!(health.oracle.epochs_since_report, None);
+    }
+}
@@ -0,0 +1,222 @@
+//! Human-readable labels for pubkeys.
+//!
+//! An [`AddressBook`] combines two sources: petnames the operator assigned
+//! locally (the `address_labels` map of the CLI config file) and labels
+//! derived from onchain accounts (the code of devices, links, locations,
+//! and the like, and the client IP of users). Petnames always win, so an
+//! operator can rename an account without touching the chain.
+//! [`AddressBook::display`] renders a labeled pubkey as `ams01 (8fj3…)` and
+//! falls back to the full pubkey when nothing is known about it.
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use doublezero_serviceability::state::accountdata::AccountData;
+use serde::{Serialize, Serializer};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::ClientConfig;
+
+/// Maximum length of a petname.
+pub const MAX_LABEL_LEN: usize = 32;
+
+/// Number of leading pubkey characters kept next to a label.
+const LABELED_PREFIX_LEN: usize = 4;
+
+/// Checks that `label` can be used as a petname: non-empty, at most
+/// [`MAX_LABEL_LEN`] characters and without whitespace, so it stays a single
+/// column in table output.
+pub fn validate_label(label: &str) -> eyre::Result<()> {
+    if label.is_empty() {
+        eyre::bail!("label must not be empty");
+    }
+    if label.chars().count() > MAX_LABEL_LEN {
+        eyre::bail!("label must be at most {MAX_LABEL_LEN} characters");
+    }
+    if label.chars().any(char::is_whitespace) {
+        eyre::bail!("label must not contain whitespace");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AddressBook {
+    petnames: HashMap<Pubkey, String>,
+    codes: HashMap<Pubkey, String>,
+}
+
+impl AddressBook {
+    /// Builds an address book from petnames keyed by base58 pubkey. Entries
+    /// whose key is not a valid pubkey are ignored.
+    pub fn from_petnames(petnames: &HashMap<String, String>) -> Self {
+        Self {
+            petnames: petnames
+                .iter()
+                .filter_map(|(pubkey, label)| {
+                    Pubkey::from_str(pubkey)
+                        .ok()
+                        .map(|pubkey| (pubkey, label.clone()))
+                })
+                .collect(),
+            codes: HashMap::new(),
+        }
+    }
+
+    /// Builds an address book from the petnames of the CLI config.
+    pub fn from_config(config: &ClientConfig) -> Self {
+        Self::from_petnames(&config.address_labels)
+    }
+
+    /// Records an onchain label for `pubkey`. A petname for the same pubkey
+    /// still takes precedence.
+    pub fn insert_code(&mut self, pubkey: Pubkey, code: impl Into<String>) {
+        self.codes.insert(pubkey, code.into());
+    }
+
+    /// Records the onchain labels of every account that carries one: the
+    /// code of devices, links, contributors, exchanges, locations, multicast
+    /// groups and tenants, and the client IP of users.
+    pub fn observe_accounts<'a>(
+        &mut self,
+        accounts: impl IntoIterator<Item = (&'a Pubkey, &'a AccountData)>,
+    ) {
+        for (pubkey, account) in accounts {
+            let code = match account {
+                AccountData::User(user) => user.client_ip.to_string(),
+                AccountData::Device(device) => device.code.clone(),
+                AccountData::Link(link) => link.code.clone(),
+                AccountData::Contributor(contributor) => contributor.code.clone(),
+                AccountData::Exchange(exchange) => exchange.code.clone(),
+                AccountData::Location(location) => location.code.clone(),
+                AccountData::MulticastGroup(group) => group.code.clone(),
+                AccountData::Tenant(tenant) => tenant.code.clone(),
+                _ => continue,
+            };
+            self.insert_code(*pubkey, code);
+        }
+    }
+
+    /// Whether a petname or onchain label is known for `pubkey`.
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.petnames.contains_key(pubkey) || self.codes.contains_key(pubkey)
+    }
+
+    /// The label of `pubkey`, preferring the petname over the onchain code.
+    pub fn label(&self, pubkey: &Pubkey) -> Option<&str> {
+        self.petnames
+            .get(pubkey)
+            .or_else(|| self.codes.get(pubkey))
+            .map(String::as_str)
+    }
+
+    /// Renders `pubkey` as `label (8fj3…)`, or as the full pubkey when it has
+    /// no label.
+    pub fn display(&self, pubkey: &Pubkey) -> String {
+        self.labeled(pubkey).to_string()
+    }
+
+    /// Pairs `pubkey` with its label, for display structs that render it
+    /// labeled in tables while serializing the bare pubkey.
+    pub fn labeled(&self, pubkey: &Pubkey) -> LabeledPubkey {
+        LabeledPubkey {
+            pubkey: *pubkey,
+            label: self.label(pubkey).map(str::to_string),
+        }
+    }
+}
+
+/// A pubkey and its label, if any. Displays as `label (8fj3…)` (or the full
+/// pubkey when unlabeled) and serializes as the base58 pubkey, so JSON output
+/// is unaffected by local petnames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabeledPubkey {
+    pub pubkey: Pubkey,
+    pub label: Option<String>,
+}
+
+impl From<Pubkey> for LabeledPubkey {
+    fn from(pubkey: Pubkey) -> Self {
+        Self {
+            pubkey,
+            label: None,
+        }
+    }
+}
+
+impl fmt::Display for LabeledPubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let base58 = self.pubkey.to_string();
+        match &self.label {
+            Some(label) => {
+                let prefix: String = base58.chars().take(LABELED_PREFIX_LEN).collect();
+                write!(f, "{label} ({prefix}…)")
+            }
+            None => f.write_str(&base58),
+        }
+    }
+}
+
+impl Serialize for LabeledPubkey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.pubkey.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doublezero_serviceability::state::{accounttype::AccountType, device::Device};
+
+    #[test]
+    fn test_petname_takes_precedence_over_code() {
+        let pubkey = Pubkey::new_unique();
+        let mut book =
+            AddressBook::from_petnames(&HashMap::from([(pubkey.to_string(), "mine".into())]));
+        book.insert_code(pubkey, "ams01");
+
+        assert_eq!(book.label(&pubkey), Some("mine"));
+        let prefix = &pubkey.to_string()[..4];
+        assert_eq!(book.display(&pubkey), format!("mine ({prefix}…)"));
+        assert_eq!(
+            serde_json::to_string(&book.labeled(&pubkey)).unwrap(),
+            format!("\"{pubkey}\"")
+        );
+    }
+
+    #[test]
+    fn test_observe_accounts_labels_codes() {
+        let device_pk = Pubkey::new_unique();
+        let unlabeled_pk = Pubkey::new_unique();
+        let accounts = [(
+            device_pk,
+            AccountData::Device(Device {
+                account_type: AccountType::Device,
+                code: "ams01".to_string(),
+                ..Default::default()
+            }),
+        )];
+
+        let mut book = AddressBook::default();
+        book.observe_accounts(accounts.iter().map(|(pk, account)| (pk, account)));
+
+        assert_eq!(book.label(&device_pk), Some("ams01"));
+        assert!(!book.contains(&unlabeled_pk));
+        assert_eq!(book.display(&unlabeled_pk), unlabeled_pk.to_string());
+    }
+
+    #[test]
+    fn test_invalid_petname_keys_are_ignored() {
+        let book = AddressBook::from_petnames(&HashMap::from([(
+            "not-a-pubkey".to_string(),
+            "x".to_string(),
+        )]));
+        assert_eq!(book, AddressBook::default());
+    }
+
+    #[test]
+    fn test_validate_label() {
+        assert!(validate_label("ams01-ops").is_ok());
+        assert!(validate_label("").is_err());
+        assert!(validate_label("has space").is_err());
+        assert!(validate_label(&"x".repeat(MAX_LABEL_LEN + 1)).is_err());
+    }
+}
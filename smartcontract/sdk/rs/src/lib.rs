@@ -6,15 +6,19 @@ pub use crate::config::{
 pub use doublezero_serviceability::{
     addresses::*,
     pda::{
-        get_contributor_pda, get_device_pda, get_exchange_pda, get_feed_pda, get_globalconfig_pda,
+        get_anycast_prefix_pda, get_contributor_pda, get_device_pda, get_exchange_pda,
+        get_feed_pda, get_globalconfig_pda, get_interface_oper_status_pda, get_ip_allocation_pda,
         get_link_pda, get_location_pda, get_multicastgroup_pda, get_permission_pda,
-        get_resource_extension_pda, get_tenant_pda, get_topology_pda, get_user_old_pda,
+        get_qos_profile_pda, get_read_api_key_pda, get_referrer_stats_pda,
+        get_resource_extension_pda, get_state_commitment_pda, get_tenant_pda, get_topology_pda,
+        get_user_old_pda,
     },
     programversion::ProgramVersion,
     resource::{IdOrIp, ResourceType},
     state::{
         accountdata::AccountData,
         accounttype::AccountType,
+        anycast_prefix::AnycastPrefix,
         contributor::{Contributor, ContributorStatus},
         device::{Device, DeviceStatus, DeviceType},
         exchange::{Exchange, ExchangeStatus, BGP_COMMUNITY_MAX, BGP_COMMUNITY_MIN},
@@ -22,33 +26,55 @@ pub use doublezero_serviceability::{
         globalconfig::GlobalConfig,
         globalstate::GlobalState,
         interface::{Interface, InterfaceDeprecated, InterfaceStatus, InterfaceType, LoopbackType},
+        interface_oper_status::{
+            InterfaceOperState, InterfaceOperStatus, InterfaceOperStatusEntry,
+        },
+        ip_allocation::{IpAllocation, IpAllocationStatus},
         link::{Link, LinkLinkType, LinkStatus},
         location::{Location, LocationStatus},
         multicastgroup::{MulticastGroup, MulticastGroupStatus},
         permission::{Permission, PermissionStatus},
         programconfig::ProgramConfig,
+        qos_profile::QosProfile,
+        read_api_key::{ReadApiKey, ReadApiKeyStatus},
+        referrer_stats::ReferrerStats,
         resource_extension::ResourceExtensionOwned,
+        state_commitment::StateCommitment,
         tenant::Tenant,
         topology::{TopologyConstraint, TopologyInfo},
-        user::{BGPStatus, User, UserCYOA, UserStatus, UserType},
+        user::{BGPStatus, BandwidthTier, User, UserCYOA, UserStatus, UserType},
+    },
+    state_machine::{
+        find_transition, next_statuses, validate_transition, StatusMachine, StatusTransition,
     },
 };
 
 mod asyncclient;
 mod client;
 mod config;
+mod confirm;
 mod consts;
 mod dztransaction;
-mod errors;
+mod endpoints;
+mod policy;
 
+pub mod cache;
+pub mod cascade;
 pub mod commands;
 pub mod doublezeroclient;
+pub mod dryrun;
+pub mod errors;
+pub mod events;
 pub mod geolocation;
+pub mod health;
 pub mod keypair;
+pub mod labels;
 pub mod record;
 pub mod rpckeyedaccount_decode;
 pub mod telemetry;
 pub mod tests;
+pub mod topology;
+pub mod units;
 pub mod utils;
 
 pub use crate::{
@@ -59,8 +85,10 @@ pub use crate::{
 pub use crate::{
     config::{convert_program_moniker, convert_url_moniker, convert_url_to_ws, convert_ws_moniker},
     doublezeroclient::{DoubleZeroClient, MockDoubleZeroClient},
-    errors::*,
+    endpoints::{RpcEndpointConfig, RpcReadMode},
+    errors::{Error, ErrorKind, Result, SimulationError, SimulationTransactionError},
     geolocation::client::{GeolocationClient, MockGeolocationClient},
+    policy::{ClientPolicy, ClientPolicyConfig},
 };
 
 pub use crate::commands::{
@@ -0,0 +1,179 @@
+use backon::ExponentialBuilder;
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use std::{env, time::Duration};
+
+pub const ENV_RPC_TIMEOUT_SECS: &str = "DOUBLEZERO_RPC_TIMEOUT_SECS";
+pub const ENV_CONFIRM_TIMEOUT_SECS: &str = "DOUBLEZERO_CONFIRM_TIMEOUT_SECS";
+pub const ENV_RPC_MAX_RETRIES: &str = "DOUBLEZERO_RPC_MAX_RETRIES";
+pub const ENV_RPC_BACKOFF_MS: &str = "DOUBLEZERO_RPC_BACKOFF_MS";
+
+/// Upper bound on a single backoff delay, as a multiple of the initial one.
+const MAX_BACKOFF_FACTOR: u32 = 10;
+
+/// Timeout and retry behaviour of a [`DZClient`](crate::DZClient).
+///
+/// `rpc_timeout` bounds every individual RPC request and `confirm_timeout`
+/// bounds how long a sent transaction may stay unseen by the cluster before
+/// confirmation gives up. Transient network failures (I/O, HTTP and middleware
+/// errors) are retried up to `max_retries` times with exponential backoff
+/// starting at `backoff` and capped at ten times that value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientPolicy {
+    pub rpc_timeout: Duration,
+    pub confirm_timeout: Duration,
+    pub max_retries: usize,
+    pub backoff: Duration,
+}
+
+impl Default for ClientPolicy {
+    fn default() -> Self {
+        ClientPolicy {
+            rpc_timeout: Duration::from_secs(30),
+            confirm_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// The `client_policy` section of `config.yml`. Every field is optional;
+/// unset fields keep the [`ClientPolicy`] default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientPolicyConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backoff_ms: Option<u64>,
+}
+
+impl ClientPolicy {
+    /// Resolve the policy from the defaults, then the persisted `config.yml`
+    /// section, then the `DOUBLEZERO_RPC_*` / `DOUBLEZERO_CONFIRM_*`
+    /// environment variables (highest wins). A set but unparsable variable is
+    /// an error rather than being silently ignored.
+    pub fn resolve(config: Option<&ClientPolicyConfig>) -> eyre::Result<Self> {
+        let policy = config.map_or_else(Self::default, |c| Self::default().with_config(c));
+        policy.with_overrides(|name| env::var(name).ok())
+    }
+
+    /// Layer the fields set in `config` over `self`.
+    pub fn with_config(mut self, config: &ClientPolicyConfig) -> Self {
+        if let Some(secs) = config.rpc_timeout_secs {
+            self.rpc_timeout = Duration::from_secs(secs);
+        }
+        if let Some(secs) = config.confirm_timeout_secs {
+            self.confirm_timeout = Duration::from_secs(secs);
+        }
+        if let Some(retries) = config.max_retries {
+            self.max_retries = retries;
+        }
+        if let Some(ms) = config.backoff_ms {
+            self.backoff = Duration::from_millis(ms);
+        }
+        self
+    }
+
+    fn with_overrides(self, lookup: impl Fn(&str) -> Option<String>) -> eyre::Result<Self> {
+        let parse = |name: &str| -> eyre::Result<Option<u64>> {
+            lookup(name)
+                .map(|value| {
+                    value.trim().parse::<u64>().map_err(|_| {
+                        eyre::eyre!("invalid {name} '{value}': expected a non-negative integer")
+                    })
+                })
+                .transpose()
+        };
+
+        let config = ClientPolicyConfig {
+            rpc_timeout_secs: parse(ENV_RPC_TIMEOUT_SECS)?,
+            confirm_timeout_secs: parse(ENV_CONFIRM_TIMEOUT_SECS)?,
+            max_retries: parse(ENV_RPC_MAX_RETRIES)?.map(|n| n as usize),
+            backoff_ms: parse(ENV_RPC_BACKOFF_MS)?,
+        };
+        Ok(self.with_config(&config))
+    }
+
+    pub(crate) fn retry_builder(&self) -> ExponentialBuilder {
+        ExponentialBuilder::new()
+            .with_max_times(self.max_retries)
+            .with_min_delay(self.backoff)
+            .with_max_delay(self.backoff * MAX_BACKOFF_FACTOR)
+    }
+
+    pub(crate) fn rpc_client(&self, rpc_url: String) -> RpcClient {
+        RpcClient::new_with_timeouts_and_commitment(
+            rpc_url,
+            self.rpc_timeout,
+            CommitmentConfig::confirmed(),
+            self.confirm_timeout,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn overrides(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_policy_layers_config_then_env() {
+        let config = ClientPolicyConfig {
+            rpc_timeout_secs: Some(10),
+            max_retries: Some(5),
+            ..Default::default()
+        };
+        let policy = ClientPolicy::default()
+            .with_config(&config)
+            .with_overrides(overrides(&[
+                (ENV_RPC_MAX_RETRIES, "1"),
+                (ENV_RPC_BACKOFF_MS, "250"),
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            policy,
+            ClientPolicy {
+                rpc_timeout: Duration::from_secs(10),
+                confirm_timeout: Duration::from_secs(5),
+                max_retries: 1,
+                backoff: Duration::from_millis(250),
+            }
+        );
+    }
+
+    #[test]
+    fn test_policy_rejects_invalid_env_value() {
+        let err = ClientPolicy::default()
+            .with_overrides(overrides(&[(ENV_RPC_TIMEOUT_SECS, "soon")]))
+            .unwrap_err();
+        assert!(err.to_string().contains(ENV_RPC_TIMEOUT_SECS));
+    }
+
+    #[test]
+    fn test_policy_config_roundtrip_omits_unset_fields() {
+        let config = ClientPolicyConfig {
+            confirm_timeout_secs: Some(60),
+            ..Default::default()
+        };
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert_eq!(yaml.trim(), "confirm_timeout_secs: 60");
+        assert_eq!(
+            serde_yaml::from_str::<ClientPolicyConfig>(&yaml).unwrap(),
+            config
+        );
+    }
+}
@@ -7,12 +7,153 @@ use solana_sdk::{
     hash::Hash,
     instruction::Instruction,
     message::{v0::Message, VersionedMessage},
+    pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
     transaction::VersionedTransaction,
 };
 
-use crate::record::instruction::{InitializeRecordInstructions, RecordWriteChunk};
+use crate::record::{
+    instruction::{
+        acquire_lease, register_namespace, release_lease, InitializeRecordInstructions,
+        RecordWriteChunk,
+    },
+    receipts::{
+        read_execution_records, receipt_log_key, receipt_log_seeds, receipt_log_space,
+        write_execution_record, ExecutionRecord,
+    },
+    registry::{decode_record, typed_record_key, RecordType},
+    state::{
+        find_lease_address, find_namespace_address, read_lease_data, read_namespace_data,
+        split_record_data, LeaseData, NamespaceData, SectionGrant,
+    },
+};
+
+/// Fetch the namespace registry entry for `prefix`, if it is registered.
+pub async fn get_namespace(
+    rpc_client: &RpcClient,
+    prefix: &[u8],
+) -> Result<Option<NamespaceData>, ClientError> {
+    let (namespace_key, _) = find_namespace_address(prefix);
+    let account = rpc_client
+        .get_account_with_commitment(&namespace_key, rpc_client.commitment())
+        .await?
+        .value;
+    Ok(account
+        .filter(|account| account.owner == crate::record::ID)
+        .and_then(|account| read_namespace_data(&account.data).copied()))
+}
+
+/// Fetch the single-writer lease `name`, if it was ever acquired.
+pub async fn get_lease(
+    rpc_client: &RpcClient,
+    name: &[u8],
+) -> Result<Option<LeaseData>, ClientError> {
+    let (lease_key, _) = find_lease_address(name);
+    let account = rpc_client
+        .get_account_with_commitment(&lease_key, rpc_client.commitment())
+        .await?
+        .value;
+    Ok(account
+        .filter(|account| account.owner == crate::record::ID)
+        .and_then(|account| read_lease_data(&account.data).copied()))
+}
+
+/// Fetch and decode the `T` record created by `authority` with `seeds`, or
+/// `None` if it does not exist. Fails if the first seed is not `T`'s
+/// namespace or the record does not hold a `T`.
+pub async fn read_record<T: RecordType>(
+    rpc_client: &RpcClient,
+    authority: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<Option<T>, ClientError> {
+    let record_key = typed_record_key::<T>(authority, seeds)
+        .map_err(|e| ClientErrorKind::Custom(e.to_string()))?;
+    let account = rpc_client
+        .get_account_with_commitment(&record_key, rpc_client.commitment())
+        .await?
+        .value;
+    account
+        .filter(|account| account.owner == crate::record::ID)
+        .map(|account| {
+            decode_record::<T>(&account.data)
+                .map_err(|e| ClientErrorKind::Custom(e.to_string()).into())
+        })
+        .transpose()
+}
+
+/// Acquire or renew the single-writer lease `name` for `duration_slots`,
+/// creating the lease account on first use. `nonce` identifies this instance
+/// of the writer and should be picked once, at random, on startup. Fails with
+/// [`RecordError::LeaseHeld`](doublezero_record::error::RecordError::LeaseHeld)
+/// while another holder's lease, or another instance's, is unexpired. A
+/// writer should acquire the lease before each batch of writes and stop
+/// writing once it cannot, and renew well before expiry to absorb
+/// confirmation delays. Writes built with
+/// [`write_fenced`](crate::record::instruction::write_fenced) and the lease
+/// generation fail once the lease is lost.
+pub async fn try_acquire_lease(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    holder_signer: &Keypair,
+    name: &[u8],
+    nonce: u64,
+    duration_slots: u64,
+) -> Result<Signature, ClientError> {
+    let instruction = acquire_lease(&holder_signer.pubkey(), name, nonce, duration_slots);
+    let transaction = new_transaction(recent_blockhash, &[instruction], &[holder_signer])?;
+    rpc_client.send_and_confirm_transaction(&transaction).await
+}
+
+/// Give up the single-writer lease `name` so that another writer can take it
+/// over without waiting for it to expire.
+pub async fn try_release_lease(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    holder_signer: &Keypair,
+    name: &[u8],
+    nonce: u64,
+) -> Result<Signature, ClientError> {
+    let instruction = release_lease(&holder_signer.pubkey(), name, nonce);
+    let transaction = new_transaction(recent_blockhash, &[instruction], &[holder_signer])?;
+    rpc_client.send_and_confirm_transaction(&transaction).await
+}
+
+/// Check the namespace of a record's seeds, which is its first seed. Returns
+/// the instruction registering the namespace to `authority` if it is not
+/// registered yet, or `None` if `authority` already holds it. Fails if the
+/// namespace belongs to another authority, so that subsystems cannot
+/// overwrite each other's records, or if the first seed is missing or longer
+/// than [`NamespaceData::MAX_PREFIX_LEN`].
+pub async fn claim_namespace_instruction(
+    rpc_client: &RpcClient,
+    authority: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<Option<Instruction>, ClientError> {
+    let prefix = match seeds.first() {
+        Some(prefix) if !prefix.is_empty() && prefix.len() <= NamespaceData::MAX_PREFIX_LEN => {
+            *prefix
+        }
+        _ => {
+            return Err(ClientErrorKind::Custom(format!(
+                "Record namespace (first seed) must be 1 to {} bytes",
+                NamespaceData::MAX_PREFIX_LEN
+            ))
+            .into())
+        }
+    };
+
+    match get_namespace(rpc_client, prefix).await? {
+        None => Ok(Some(register_namespace(authority, prefix))),
+        Some(namespace) if namespace.authority == *authority => Ok(None),
+        Some(namespace) => Err(ClientErrorKind::Custom(format!(
+            "Record namespace {:?} is registered to {}",
+            String::from_utf8_lossy(prefix),
+            namespace.authority
+        ))
+        .into()),
+    }
+}
 
 /// Try to create an account by performing the following instructions:
 /// - allocate-with-seed (System program)
@@ -20,6 +161,10 @@ use crate::record::instruction::{InitializeRecordInstructions, RecordWriteChunk}
 /// - initialize (Record program)
 /// - transfer (System program)
 ///
+/// The first seed is the record's namespace. It is registered to the payer in
+/// the same transaction if nobody holds it yet, and creation fails if another
+/// authority does (see [`claim_namespace_instruction`]).
+///
 /// If the record account is already initialized, this method will return an
 /// error.
 pub async fn try_create_record(
@@ -28,15 +173,147 @@ pub async fn try_create_record(
     payer_signer: &Keypair,
     seeds: &[&[u8]],
     space: usize,
+) -> Result<Signature, ClientError> {
+    let instructions = InitializeRecordInstructions::new(&payer_signer.pubkey(), seeds, space);
+    send_create_record(
+        rpc_client,
+        recent_blockhash,
+        payer_signer,
+        seeds,
+        instructions,
+    )
+    .await
+}
+
+/// Like [`try_create_record`], but creates a sectioned record: the payer
+/// remains the authority over the whole record, and each grant's writer may
+/// only write inside its own section (see
+/// [`write_section_chunks`](crate::record::instruction::write_section_chunks)).
+pub async fn try_create_sectioned_record(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    payer_signer: &Keypair,
+    seeds: &[&[u8]],
+    grants: &[SectionGrant],
+    space: usize,
+) -> Result<Signature, ClientError> {
+    let instructions =
+        InitializeRecordInstructions::new_sectioned(&payer_signer.pubkey(), seeds, grants, space);
+    send_create_record(
+        rpc_client,
+        recent_blockhash,
+        payer_signer,
+        seeds,
+        instructions,
+    )
+    .await
+}
+
+/// Create the receipt log of `job_id`, holding the last `capacity`
+/// execution receipts, with the signer as its authority.
+pub async fn try_create_receipt_log(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    authority_signer: &Keypair,
+    job_id: &[u8],
+    capacity: usize,
+) -> Result<Signature, ClientError> {
+    try_create_record(
+        rpc_client,
+        recent_blockhash,
+        authority_signer,
+        &receipt_log_seeds(job_id),
+        receipt_log_space(capacity),
+    )
+    .await
+}
+
+/// Fetch the receipts of `job_id` kept by `authority`, oldest first, or
+/// `None` if its receipt log does not exist.
+pub async fn get_execution_records(
+    rpc_client: &RpcClient,
+    authority: &Pubkey,
+    job_id: &[u8],
+) -> Result<Option<Vec<ExecutionRecord>>, ClientError> {
+    let account = rpc_client
+        .get_account_with_commitment(&receipt_log_key(authority, job_id), rpc_client.commitment())
+        .await?
+        .value;
+    Ok(account
+        .filter(|account| account.owner == crate::record::ID)
+        .and_then(|account| read_execution_records(&account.data)))
+}
+
+/// Append the receipt of one execution of `job_id` to the signer's receipt
+/// log, overwriting the oldest receipt once the log is full. The log must
+/// have been created with [`try_create_receipt_log`]. Appends from two
+/// writers can race for the same slot; hold the job's lease while writing.
+pub async fn try_append_execution_record(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    authority_signer: &Keypair,
+    job_id: &[u8],
+    tick: u64,
+    execution_signature: &Signature,
+    result_hash: [u8; 32],
+) -> Result<Signature, ClientError> {
+    let authority = authority_signer.pubkey();
+    let account = rpc_client
+        .get_account_with_commitment(
+            &receipt_log_key(&authority, job_id),
+            rpc_client.commitment(),
+        )
+        .await?
+        .value
+        .filter(|account| account.owner == crate::record::ID)
+        .ok_or_else(|| {
+            ClientErrorKind::Custom(format!(
+                "Receipt log of job {:?} does not exist",
+                String::from_utf8_lossy(job_id)
+            ))
+        })?;
+
+    let capacity = split_record_data(&account.data)
+        .map(|(_, _, body)| body.len() / ExecutionRecord::LEN)
+        .unwrap_or_default();
+    if capacity == 0 {
+        return Err(
+            ClientErrorKind::Custom("Receipt log holds no receipt slots".to_string()).into(),
+        );
+    }
+    let last_sequence = read_execution_records(&account.data)
+        .and_then(|records| records.last().map(ExecutionRecord::sequence))
+        .unwrap_or_default();
+
+    let record = ExecutionRecord::new(
+        last_sequence + 1,
+        job_id,
+        tick,
+        execution_signature,
+        result_hash,
+    )
+    .map_err(|e| ClientErrorKind::Custom(e.to_string()))?;
+    let instruction = write_execution_record(&authority, job_id, capacity, &record);
+    let transaction = new_transaction(recent_blockhash, &[instruction], &[authority_signer])?;
+    rpc_client.send_and_confirm_transaction(&transaction).await
+}
+
+async fn send_create_record(
+    rpc_client: &RpcClient,
+    recent_blockhash: Hash,
+    payer_signer: &Keypair,
+    seeds: &[&[u8]],
+    instructions: InitializeRecordInstructions,
 ) -> Result<Signature, ClientError> {
     let payer_key = payer_signer.pubkey();
+    let claim_namespace_ix = claim_namespace_instruction(rpc_client, &payer_key, seeds).await?;
 
     let InitializeRecordInstructions {
         allocate: allocate_ix,
         assign: assign_ix,
         initialize: initialize_ix,
         total_space,
-    } = InitializeRecordInstructions::new(&payer_key, seeds, space);
+    } = instructions;
 
     let record_key = &initialize_ix.accounts[0].pubkey;
 
@@ -54,11 +331,11 @@ pub async fn try_create_record(
         rent_exemption_lamports,
     );
 
-    let transaction = new_transaction(
-        recent_blockhash,
-        &[allocate_ix, assign_ix, transfer_ix, initialize_ix],
-        &[payer_signer],
-    )?;
+    let instructions: Vec<Instruction> = claim_namespace_ix
+        .into_iter()
+        .chain([allocate_ix, assign_ix, transfer_ix, initialize_ix])
+        .collect();
+    let transaction = new_transaction(recent_blockhash, &instructions, &[payer_signer])?;
 
     // We want to confirm this transaction because we want to ensure that the
     // account is created before we write to it.
@@ -1,6 +1,10 @@
 pub use doublezero_record::instruction::*;
 
-use doublezero_record::{instruction as record_instruction, state::RecordData, ID};
+use doublezero_record::{
+    instruction as record_instruction,
+    state::{RecordData, SectionGrant},
+    ID,
+};
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 
 use crate::record::pubkey::{create_record_key, create_record_seed_string};
@@ -19,7 +23,36 @@ impl InitializeRecordInstructions {
     pub fn new(payer_key: &Pubkey, seeds: &[&[u8]], space: usize) -> InitializeRecordInstructions {
         // We need to incorporate the header of the record account.
         let total_space = size_of::<RecordData>().saturating_add(space);
+        let record_key = create_record_key(payer_key, seeds);
+        let initialize_ix = record_instruction::initialize(&record_key, payer_key);
+
+        Self::with_initialize(payer_key, seeds, total_space, initialize_ix)
+    }
+
+    /// Like [`InitializeRecordInstructions::new`], but creates a sectioned
+    /// record whose writers may each only write their granted section.
+    /// `space` covers the writable data only; the grant table is added on top.
+    pub fn new_sectioned(
+        payer_key: &Pubkey,
+        seeds: &[&[u8]],
+        grants: &[SectionGrant],
+        space: usize,
+    ) -> InitializeRecordInstructions {
+        let total_space =
+            RecordData::sectioned_writable_start_index(grants.len()).saturating_add(space);
+        let record_key = create_record_key(payer_key, seeds);
+        let initialize_ix =
+            record_instruction::initialize_sectioned(&record_key, payer_key, grants);
+
+        Self::with_initialize(payer_key, seeds, total_space, initialize_ix)
+    }
 
+    fn with_initialize(
+        payer_key: &Pubkey,
+        seeds: &[&[u8]],
+        total_space: usize,
+        initialize_ix: Instruction,
+    ) -> InitializeRecordInstructions {
         let seed_str = create_record_seed_string(seeds);
         let record_key = Pubkey::create_with_seed(payer_key, &seed_str, &ID).unwrap();
 
@@ -49,8 +82,6 @@ impl InitializeRecordInstructions {
             &ID,
         );
 
-        let initialize_ix = record_instruction::initialize(&record_key, payer_key);
-
         InitializeRecordInstructions {
             allocate: allocate_ix,
             assign: assign_ix,
@@ -96,6 +127,34 @@ pub fn write_record_chunks<'a>(
     })
 }
 
+/// Create a series of instructions for a section writer to fill its section
+/// of a sectioned record owned by another authority, starting at
+/// `section_offset`. Chunks are at most 1,013 bytes.
+pub fn write_section_chunks<'a>(
+    record_key: &'a Pubkey,
+    writer_key: &'a Pubkey,
+    section_offset: u64,
+    data: &'a [u8],
+) -> impl Iterator<Item = RecordWriteChunk> + 'a {
+    let mut peekable_iter = data.chunks(CHUNK_SIZE).enumerate().peekable();
+
+    std::iter::from_fn(move || {
+        peekable_iter.next().map(|(i, chunk)| {
+            let offset = section_offset as usize + i * CHUNK_SIZE;
+            let instruction =
+                record_instruction::write(record_key, writer_key, offset as u64, chunk);
+            let is_last_chunk = peekable_iter.peek().is_none();
+
+            RecordWriteChunk {
+                instruction,
+                offset,
+                chunk_size: chunk.len(),
+                is_last_chunk,
+            }
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,4 +178,45 @@ mod tests {
         assert_eq!(assign_ix.accounts[0].pubkey, expected_record_key);
         assert_eq!(initialize_ix.accounts[0].pubkey, expected_record_key);
     }
+
+    #[test]
+    fn test_initialize_sectioned_record_instructions() {
+        let payer_key = Pubkey::new_unique();
+        let seeds: [&[u8]; 1] = [b"test_initialize_sectioned_record_instructions"];
+        let grants = [
+            SectionGrant::new(Pubkey::new_unique(), 0, 64),
+            SectionGrant::new(Pubkey::new_unique(), 64, 64),
+        ];
+        let InitializeRecordInstructions {
+            initialize: initialize_ix,
+            total_space,
+            ..
+        } = InitializeRecordInstructions::new_sectioned(&payer_key, &seeds, &grants, 128);
+        assert_eq!(
+            total_space,
+            128 + size_of::<RecordData>() + 1 + 2 * size_of::<SectionGrant>()
+        );
+        assert_eq!(
+            initialize_ix,
+            record_instruction::initialize_sectioned(
+                &crate::record::pubkey::create_record_key(&payer_key, &seeds),
+                &payer_key,
+                &grants,
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_section_chunks() {
+        let record_key = Pubkey::new_unique();
+        let writer_key = Pubkey::new_unique();
+        let data = vec![7u8; CHUNK_SIZE + 10];
+        let chunks: Vec<_> = write_section_chunks(&record_key, &writer_key, 500, &data).collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].offset, 500);
+        assert_eq!(chunks[1].offset, 500 + CHUNK_SIZE);
+        assert_eq!(chunks[1].chunk_size, 10);
+        assert!(chunks[1].is_last_chunk);
+        assert_eq!(chunks[1].instruction.accounts[1].pubkey, writer_key);
+    }
 }
@@ -1,6 +1,8 @@
 pub mod client;
 pub mod instruction;
 pub mod pubkey;
+pub mod receipts;
+pub mod registry;
 pub mod state;
 
 pub use doublezero_record::ID;
@@ -0,0 +1,216 @@
+use bytemuck::{Pod, Zeroable};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
+
+use crate::record::{
+    pubkey::create_record_key,
+    registry::{RecordType, SchedulerState},
+    state::split_record_data,
+};
+
+/// Second seed of a job's receipt log, after the scheduler namespace.
+pub const RECEIPTS_SEED: &[u8] = b"receipts";
+
+/// Longest job id a receipt can hold.
+pub const MAX_JOB_ID_LEN: usize = 32;
+
+/// Receipt of one scheduled job execution: which tick of the job ran, the
+/// transaction it landed and a hash of what it produced.
+///
+/// A job's receipts live in a fixed-size ring in its receipt log record, one
+/// slot per receipt, so that appending one is a single write that fits in a
+/// transaction. Once the ring is full the oldest receipt is overwritten.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ExecutionRecord {
+    /// Position of the receipt in the job's history, starting at 1, as
+    /// little-endian `u64`. An unused slot reads 0.
+    pub sequence: [u8; 8],
+
+    /// Job id, zero-padded
+    pub job_id: [u8; MAX_JOB_ID_LEN],
+
+    /// Schedule tick the execution ran for, as little-endian `u64`
+    pub tick: [u8; 8],
+
+    /// Signature of the transaction the execution landed
+    pub signature: [u8; 64],
+
+    /// Hash of the execution's result
+    pub result_hash: [u8; 32],
+}
+
+impl ExecutionRecord {
+    pub const LEN: usize = size_of::<ExecutionRecord>();
+
+    /// Fails if `job_id` is empty or longer than [`MAX_JOB_ID_LEN`].
+    pub fn new(
+        sequence: u64,
+        job_id: &[u8],
+        tick: u64,
+        signature: &Signature,
+        result_hash: [u8; 32],
+    ) -> eyre::Result<Self> {
+        Ok(Self {
+            sequence: sequence.to_le_bytes(),
+            job_id: pad_job_id(job_id)?,
+            tick: tick.to_le_bytes(),
+            signature: signature.as_ref().try_into()?,
+            result_hash,
+        })
+    }
+
+    pub fn sequence(&self) -> u64 {
+        u64::from_le_bytes(self.sequence)
+    }
+
+    pub fn tick(&self) -> u64 {
+        u64::from_le_bytes(self.tick)
+    }
+
+    /// Job id without its padding
+    pub fn job_id(&self) -> &[u8] {
+        let len = self
+            .job_id
+            .iter()
+            .rposition(|b| *b != 0)
+            .map_or(0, |i| i + 1);
+        &self.job_id[..len]
+    }
+
+    pub fn signature(&self) -> Signature {
+        Signature::from(self.signature)
+    }
+}
+
+fn pad_job_id(job_id: &[u8]) -> eyre::Result<[u8; MAX_JOB_ID_LEN]> {
+    if job_id.is_empty() || job_id.len() > MAX_JOB_ID_LEN {
+        eyre::bail!("Job id must be 1 to {MAX_JOB_ID_LEN} bytes");
+    }
+    let mut padded = [0u8; MAX_JOB_ID_LEN];
+    padded[..job_id.len()].copy_from_slice(job_id);
+    Ok(padded)
+}
+
+/// Seeds of the receipt log of `job_id`.
+pub fn receipt_log_seeds(job_id: &[u8]) -> [&[u8]; 3] {
+    [SchedulerState::NAMESPACE, RECEIPTS_SEED, job_id]
+}
+
+/// Key of the receipt log of `job_id` kept by `authority`.
+pub fn receipt_log_key(authority: &Pubkey, job_id: &[u8]) -> Pubkey {
+    create_record_key(authority, &receipt_log_seeds(job_id))
+}
+
+/// Writable space of a receipt log holding the last `capacity` receipts.
+pub fn receipt_log_space(capacity: usize) -> usize {
+    capacity * ExecutionRecord::LEN
+}
+
+/// Receipts in a receipt log account, oldest first, or `None` if the data
+/// is not a record.
+pub fn read_execution_records(data: &[u8]) -> Option<Vec<ExecutionRecord>> {
+    let (_header, _grants, body) = split_record_data(data).ok()?;
+    let mut records: Vec<ExecutionRecord> = body
+        .chunks_exact(ExecutionRecord::LEN)
+        .map(bytemuck::pod_read_unaligned::<ExecutionRecord>)
+        .filter(|record| record.sequence() != 0)
+        .collect();
+    records.sort_by_key(ExecutionRecord::sequence);
+    Some(records)
+}
+
+/// Instruction writing `record` into its slot of the receipt log of
+/// `job_id`, a ring of `capacity` receipts.
+pub fn write_execution_record(
+    authority: &Pubkey,
+    job_id: &[u8],
+    capacity: usize,
+    record: &ExecutionRecord,
+) -> Instruction {
+    let slot = (record.sequence().saturating_sub(1)) % capacity.max(1) as u64;
+    doublezero_record::instruction::write(
+        &receipt_log_key(authority, job_id),
+        authority,
+        slot * ExecutionRecord::LEN as u64,
+        bytemuck::bytes_of(record),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use doublezero_record::state::RecordData;
+
+    use super::*;
+
+    fn receipt(sequence: u64) -> ExecutionRecord {
+        ExecutionRecord::new(
+            sequence,
+            b"rewards-snapshot",
+            sequence * 10,
+            &Signature::new_unique(),
+            [sequence as u8; 32],
+        )
+        .unwrap()
+    }
+
+    fn receipt_log(slots: &[Option<ExecutionRecord>]) -> Vec<u8> {
+        let header = RecordData {
+            version: RecordData::CURRENT_VERSION,
+            authority: Pubkey::new_unique(),
+        };
+        let mut data = bytemuck::bytes_of(&header).to_vec();
+        for slot in slots {
+            match slot {
+                Some(record) => data.extend_from_slice(bytemuck::bytes_of(record)),
+                None => data.extend_from_slice(&[0u8; ExecutionRecord::LEN]),
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_execution_record_fields() {
+        let signature = Signature::new_unique();
+        let record = ExecutionRecord::new(3, b"job", 42, &signature, [9u8; 32]).unwrap();
+        assert_eq!(ExecutionRecord::LEN, 144);
+        assert_eq!(record.sequence(), 3);
+        assert_eq!(record.tick(), 42);
+        assert_eq!(record.job_id(), b"job");
+        assert_eq!(record.signature(), signature);
+
+        assert!(ExecutionRecord::new(1, b"", 0, &signature, [0u8; 32]).is_err());
+        assert!(ExecutionRecord::new(1, &[b'x'; 33], 0, &signature, [0u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_read_execution_records_orders_ring() {
+        let [r1, r2, r3, r4] = [1, 2, 3, 4].map(receipt);
+
+        // A ring of three that wrapped around: receipt 4 replaced receipt 1.
+        let data = receipt_log(&[Some(r4), Some(r2), Some(r3)]);
+        assert_eq!(read_execution_records(&data), Some(vec![r2, r3, r4]));
+
+        let data = receipt_log(&[Some(r1), None, None]);
+        assert_eq!(read_execution_records(&data), Some(vec![r1]));
+
+        assert_eq!(read_execution_records(&[0u8; 8]), None);
+    }
+
+    #[test]
+    fn test_write_execution_record_wraps() {
+        let authority = Pubkey::new_unique();
+        for (sequence, slot) in [(1, 0), (3, 2), (4, 0), (8, 1)] {
+            let record = receipt(sequence);
+            let instruction = write_execution_record(&authority, b"rewards-snapshot", 3, &record);
+            assert_eq!(
+                instruction,
+                doublezero_record::instruction::write(
+                    &receipt_log_key(&authority, b"rewards-snapshot"),
+                    &authority,
+                    slot * ExecutionRecord::LEN as u64,
+                    bytemuck::bytes_of(&record),
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+use thiserror::Error as ThisError;
+
+use crate::record::{
+    pubkey::create_record_key,
+    state::{split_record_data, RecordData},
+};
+
+/// A Borsh type stored in record accounts under its own namespace (first
+/// seed). Implementing it lets consumers read and derive records by type
+/// instead of deriving the key and decoding the bytes by hand.
+pub trait RecordType: BorshSerialize + BorshDeserialize {
+    /// Namespace every record of this type is created under.
+    const NAMESPACE: &'static [u8];
+
+    /// Human-readable name, used in errors and by [`record_type_name`].
+    const NAME: &'static str;
+}
+
+/// Known record types, by namespace.
+pub const RECORD_TYPES: &[(&[u8], &str)] = &[
+    (TelemetryAggregate::NAMESPACE, TelemetryAggregate::NAME),
+    (SchedulerState::NAMESPACE, SchedulerState::NAME),
+    (RewardSnapshot::NAMESPACE, RewardSnapshot::NAME),
+];
+
+/// Name of the record type registered for `namespace`, if any.
+pub fn record_type_name(namespace: &[u8]) -> Option<&'static str> {
+    RECORD_TYPES
+        .iter()
+        .find(|(prefix, _)| *prefix == namespace)
+        .map(|(_, name)| *name)
+}
+
+#[derive(ThisError, Debug, PartialEq)]
+pub enum RecordTypeError {
+    #[error("{expected} records live under namespace {namespace:?}, not {found:?}")]
+    WrongNamespace {
+        expected: &'static str,
+        namespace: String,
+        found: String,
+    },
+    #[error("Account data is not a record")]
+    NotARecord,
+    #[error("Record does not hold a {0}: {1}")]
+    Decode(&'static str, String),
+}
+
+fn check_namespace<T: RecordType>(seeds: &[&[u8]]) -> Result<(), RecordTypeError> {
+    match seeds.first() {
+        Some(prefix) if *prefix == T::NAMESPACE => Ok(()),
+        found => Err(RecordTypeError::WrongNamespace {
+            expected: T::NAME,
+            namespace: String::from_utf8_lossy(T::NAMESPACE).into_owned(),
+            found: String::from_utf8_lossy(found.copied().unwrap_or_default()).into_owned(),
+        }),
+    }
+}
+
+/// Key of the `T` record created by `authority` with `seeds`, whose first
+/// seed must be `T`'s namespace.
+pub fn typed_record_key<T: RecordType>(
+    authority: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<Pubkey, RecordTypeError> {
+    check_namespace::<T>(seeds)?;
+    Ok(create_record_key(authority, seeds))
+}
+
+/// Decode the body of a `T` record from its account data. Trailing bytes
+/// left over from a record allocated larger than the value are ignored.
+pub fn decode_record<T: RecordType>(data: &[u8]) -> Result<T, RecordTypeError> {
+    let (header, _grants, mut body) =
+        split_record_data(data).map_err(|_| RecordTypeError::NotARecord)?;
+    if header.version != RecordData::CURRENT_VERSION && !header.is_sectioned() {
+        return Err(RecordTypeError::NotARecord);
+    }
+    T::deserialize(&mut body).map_err(|e| RecordTypeError::Decode(T::NAME, e.to_string()))
+}
+
+/// Latency and loss of one link over one epoch, as aggregated from its
+/// telemetry samples.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+pub struct TelemetryAggregate {
+    pub epoch: u64,
+    pub link_pk: Pubkey,
+    pub sample_count: u32,
+    pub rtt_mean_us: u32,
+    pub rtt_p99_us: u32,
+    pub loss_ppm: u32,
+}
+
+impl RecordType for TelemetryAggregate {
+    const NAMESPACE: &'static [u8] = b"telemetry";
+    const NAME: &'static str = "TelemetryAggregate";
+}
+
+/// Progress of a periodic job, so that a new writer resumes where the last
+/// one stopped.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+pub struct SchedulerState {
+    pub last_run_slot: u64,
+    pub last_epoch: u64,
+    pub cursor: u64,
+}
+
+impl RecordType for SchedulerState {
+    const NAMESPACE: &'static [u8] = b"scheduler";
+    const NAME: &'static str = "SchedulerState";
+}
+
+/// Rewards owed to each contributor for one epoch.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Clone, Default)]
+pub struct RewardSnapshot {
+    pub epoch: u64,
+    pub total: u64,
+    pub rewards: Vec<(Pubkey, u64)>,
+}
+
+impl RecordType for RewardSnapshot {
+    const NAMESPACE: &'static [u8] = b"rewards";
+    const NAME: &'static str = "RewardSnapshot";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_account(body: &[u8]) -> Vec<u8> {
+        let header = RecordData {
+            version: RecordData::CURRENT_VERSION,
+            authority: Pubkey::new_unique(),
+        };
+        let mut data = bytemuck::bytes_of(&header).to_vec();
+        data.extend_from_slice(body);
+        data
+    }
+
+    #[test]
+    fn test_record_type_names() {
+        assert_eq!(record_type_name(b"rewards"), Some("RewardSnapshot"));
+        assert_eq!(record_type_name(b"scheduler"), Some("SchedulerState"));
+        assert_eq!(record_type_name(b"telemetry"), Some("TelemetryAggregate"));
+        assert_eq!(record_type_name(b"unknown"), None);
+    }
+
+    #[test]
+    fn test_typed_record_key_checks_namespace() {
+        let authority = Pubkey::new_unique();
+        let epoch = 42u64.to_le_bytes();
+        let seeds: &[&[u8]] = &[b"rewards", &epoch];
+        assert_eq!(
+            typed_record_key::<RewardSnapshot>(&authority, seeds),
+            Ok(create_record_key(&authority, seeds))
+        );
+
+        let err = typed_record_key::<SchedulerState>(&authority, seeds).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "SchedulerState records live under namespace \"scheduler\", not \"rewards\""
+        );
+    }
+
+    #[test]
+    fn test_decode_record() {
+        let snapshot = RewardSnapshot {
+            epoch: 42,
+            total: 300,
+            rewards: vec![(Pubkey::new_unique(), 100), (Pubkey::new_unique(), 200)],
+        };
+        let mut body = borsh::to_vec(&snapshot).unwrap();
+        // Records are usually allocated with room to grow.
+        body.resize(body.len() + 16, 0);
+        assert_eq!(
+            decode_record::<RewardSnapshot>(&record_account(&body)),
+            Ok(snapshot)
+        );
+
+        assert!(matches!(
+            decode_record::<RewardSnapshot>(&record_account(&[1, 2])),
+            Err(RecordTypeError::Decode("RewardSnapshot", _))
+        ));
+        assert_eq!(
+            decode_record::<SchedulerState>(&[0u8; 8]),
+            Err(RecordTypeError::NotARecord)
+        );
+        assert_eq!(
+            decode_record::<SchedulerState>(&[0u8; 64]),
+            Err(RecordTypeError::NotARecord)
+        );
+    }
+}
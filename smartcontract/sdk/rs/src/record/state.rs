@@ -1,11 +1,65 @@
-pub use doublezero_record::state::RecordData;
+pub use doublezero_record::state::{
+    find_lease_address, find_namespace_address, split_record_data, LeaseData, NamespaceData,
+    RecordData, SectionGrant,
+};
 
+/// Split record account data into its header and its writable data. For a
+/// sectioned record the section grant table is skipped; use
+/// [`split_record_data`] to read it.
 pub fn read_record_data(data: &[u8]) -> Option<(&RecordData, &[u8])> {
-    if data.len() < RecordData::WRITABLE_START_INDEX {
-        return None;
+    let (record_header, _grants, body_data) = split_record_data(data).ok()?;
+    Some((record_header, body_data))
+}
+
+/// Read a namespace registry entry, or `None` if the data does not hold one.
+pub fn read_namespace_data(data: &[u8]) -> Option<&NamespaceData> {
+    bytemuck::try_from_bytes::<NamespaceData>(data)
+        .ok()
+        .filter(|namespace| namespace.is_registered())
+}
+
+/// Read a single-writer lease, or `None` if the data does not hold one.
+pub fn read_lease_data(data: &[u8]) -> Option<&LeaseData> {
+    bytemuck::try_from_bytes::<LeaseData>(data)
+        .ok()
+        .filter(|lease| lease.is_lease())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn test_read_namespace_data() {
+        let authority = Pubkey::new_unique();
+        let namespace = NamespaceData::new(authority, b"telemetry").unwrap();
+        let data = bytemuck::bytes_of(&namespace);
+        assert_eq!(read_namespace_data(data), Some(&namespace));
+
+        // Records and truncated data are not namespaces.
+        let record = RecordData {
+            version: RecordData::CURRENT_VERSION,
+            authority,
+        };
+        let mut record_data = bytemuck::bytes_of(&record).to_vec();
+        record_data.resize(data.len(), 0);
+        assert_eq!(read_namespace_data(&record_data), None);
+        assert_eq!(read_namespace_data(&data[..40]), None);
     }
 
-    let (header_data, body_data) = data.split_at(RecordData::WRITABLE_START_INDEX);
-    let record_header = bytemuck::from_bytes::<RecordData>(header_data);
-    Some((record_header, body_data))
+    #[test]
+    fn test_read_lease_data() {
+        let lease = LeaseData::new(Pubkey::new_unique(), 7, b"scheduler", 100, 1).unwrap();
+        let data = bytemuck::bytes_of(&lease);
+        assert_eq!(read_lease_data(data), Some(&lease));
+
+        // Namespaces and truncated data are not leases.
+        let namespace = NamespaceData::new(lease.holder, b"telemetry").unwrap();
+        let mut namespace_data = bytemuck::bytes_of(&namespace).to_vec();
+        namespace_data.resize(data.len(), 0);
+        assert_eq!(read_lease_data(&namespace_data), None);
+        assert_eq!(read_lease_data(&data[..40]), None);
+    }
 }
@@ -1,5 +1,8 @@
 use crate::DoubleZeroClient;
-use doublezero_telemetry::state::device_latency_samples::DeviceLatencySamples;
+use doublezero_telemetry::{
+    pda::derive_device_latency_samples_shard_pda,
+    state::{device_latency_samples::DeviceLatencySamples, SAMPLES_FLAG_ROLLED_OVER},
+};
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::{
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
@@ -9,7 +12,9 @@ use solana_commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 
-// Fetch all device latency samples for a specific epoch in a single RPC call
+// Fetch all device latency samples for a specific epoch in a single RPC call.
+// Shards of the same link, origin and epoch are stitched into one entry keyed
+// by the first shard's address.
 pub fn get_all_device_latency_samples(
     client: &dyn DoubleZeroClient,
     telemetry_program_id: &Pubkey,
@@ -43,12 +48,21 @@ pub fn get_all_device_latency_samples(
 
     let accounts = client.get_program_accounts(telemetry_program_id, options)?;
 
-    let mut result = HashMap::new();
+    let mut shards: HashMap<(Pubkey, Pubkey, Pubkey), Vec<(Pubkey, DeviceLatencySamples)>> =
+        HashMap::new();
 
     for (pubkey, account) in accounts {
         match DeviceLatencySamples::try_from(&account.data[..]) {
             Ok(latency_data) => {
-                result.insert(pubkey, latency_data);
+                let header = &latency_data.header;
+                shards
+                    .entry((
+                        header.origin_device_pk,
+                        header.target_device_pk,
+                        header.link_pk,
+                    ))
+                    .or_default()
+                    .push((pubkey, latency_data));
             }
             Err(_) => {
                 // Skip accounts that fail to deserialize
@@ -57,5 +71,214 @@ pub fn get_all_device_latency_samples(
         }
     }
 
-    Ok(result)
+    Ok(shards
+        .into_values()
+        .filter_map(|mut shards| {
+            shards.sort_by_key(|(_, shard)| shard.header.shard_index);
+            let pubkey = shards.first()?.0;
+            let stitched = stitch_shards(shards.into_iter().map(|(_, shard)| shard).collect())?;
+            Some((pubkey, stitched))
+        })
+        .collect())
+}
+
+// Fetch the samples the origin device recorded over a link in an epoch,
+// following the shards writes rolled over to.
+pub fn get_device_latency_samples(
+    client: &dyn DoubleZeroClient,
+    telemetry_program_id: &Pubkey,
+    origin_device_pk: &Pubkey,
+    target_device_pk: &Pubkey,
+    link_pk: &Pubkey,
+    epoch: u64,
+) -> eyre::Result<DeviceLatencySamples> {
+    let mut shards = Vec::new();
+
+    for shard_index in 0..=u8::MAX {
+        let (pda, _) = derive_device_latency_samples_shard_pda(
+            telemetry_program_id,
+            origin_device_pk,
+            target_device_pk,
+            link_pk,
+            epoch,
+            shard_index,
+        );
+        let account = match client.get_account(pda) {
+            Ok(account) => account,
+            // A later shard may already have been closed; keep what was read.
+            Err(_) if shard_index > 0 => break,
+            Err(e) => return Err(e),
+        };
+        let shard = DeviceLatencySamples::try_from(&account.data[..])?;
+        let rolled_over = shard.header.is_rolled_over();
+        shards.push(shard);
+        if !rolled_over {
+            break;
+        }
+    }
+
+    stitch_shards(shards).ok_or_else(|| eyre::eyre!("No latency samples found"))
+}
+
+// Concatenate shards, ordered by shard index, into one set of samples. The
+// header is the first shard's, with the sample count of all of them and the
+// flags of the last one.
+pub(super) fn stitch_shards(shards: Vec<DeviceLatencySamples>) -> Option<DeviceLatencySamples> {
+    let mut shards = shards.into_iter();
+    let mut stitched = shards.next()?;
+
+    for shard in shards {
+        stitched.samples.extend(shard.samples);
+        stitched.header.flags = shard.header.flags;
+    }
+    stitched.header.flags &= !SAMPLES_FLAG_ROLLED_OVER;
+    stitched.header.next_sample_index = stitched.samples.len() as u32;
+
+    Some(stitched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockDoubleZeroClient;
+    use doublezero_telemetry::state::{
+        accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+    };
+    use mockall::predicate;
+    use solana_sdk::account::Account;
+
+    fn shard(
+        origin_device_pk: Pubkey,
+        target_device_pk: Pubkey,
+        link_pk: Pubkey,
+        shard_index: u8,
+        flags: u8,
+        samples: Vec<u32>,
+    ) -> DeviceLatencySamples {
+        DeviceLatencySamples {
+            header: DeviceLatencySamplesHeader {
+                account_type: AccountType::DeviceLatencySamples,
+                epoch: 7,
+                origin_device_agent_pk: Pubkey::new_unique(),
+                origin_device_pk,
+                target_device_pk,
+                origin_device_location_pk: Pubkey::new_unique(),
+                target_device_location_pk: Pubkey::new_unique(),
+                link_pk,
+                sampling_interval_microseconds: 5_000_000,
+                start_timestamp_microseconds: 1_700_000_000_000_000,
+                next_sample_index: samples.len() as u32,
+                agent_version: [0; 16],
+                agent_commit: [0; 8],
+                flags,
+                shard_index,
+                previous_agent_pk: Pubkey::default(),
+                agent_rotation_epoch: 0,
+                previous_agent_until_epoch: 0,
+                _unused: [0; 54],
+            },
+            samples,
+        }
+    }
+
+    fn account(samples: &DeviceLatencySamples) -> Account {
+        Account {
+            data: borsh::to_vec(samples).unwrap(),
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn test_get_device_latency_samples_follows_shards() {
+        let mut client = MockDoubleZeroClient::new();
+        let telemetry_program_id = Pubkey::new_unique();
+        let (origin, target, link) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let shards = [
+            shard(
+                origin,
+                target,
+                link,
+                0,
+                SAMPLES_FLAG_ROLLED_OVER,
+                vec![1, 2],
+            ),
+            shard(origin, target, link, 1, SAMPLES_FLAG_ROLLED_OVER, vec![3]),
+            shard(origin, target, link, 2, 0, vec![4, 5]),
+        ];
+        for shard in shards {
+            let (pda, _) = derive_device_latency_samples_shard_pda(
+                &telemetry_program_id,
+                &origin,
+                &target,
+                &link,
+                7,
+                shard.header.shard_index,
+            );
+            client
+                .expect_get_account()
+                .with(predicate::eq(pda))
+                .returning(move |_| Ok(account(&shard)));
+        }
+
+        let samples =
+            get_device_latency_samples(&client, &telemetry_program_id, &origin, &target, &link, 7)
+                .unwrap();
+        assert_eq!(samples.samples, vec![1, 2, 3, 4, 5]);
+        assert_eq!(samples.header.next_sample_index, 5);
+        assert_eq!(samples.header.shard_index, 0);
+        assert!(!samples.header.is_rolled_over());
+    }
+
+    #[test]
+    fn test_get_all_device_latency_samples_stitches_shards() {
+        let mut client = MockDoubleZeroClient::new();
+        let telemetry_program_id = Pubkey::new_unique();
+        let (origin, target, link) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let other_link = Pubkey::new_unique();
+        let (shard0_pk, shard1_pk, other_pk) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let accounts = vec![
+            (
+                shard1_pk,
+                account(&shard(origin, target, link, 1, 0, vec![3, 4])),
+            ),
+            (
+                shard0_pk,
+                account(&shard(
+                    origin,
+                    target,
+                    link,
+                    0,
+                    SAMPLES_FLAG_ROLLED_OVER,
+                    vec![1, 2],
+                )),
+            ),
+            (
+                other_pk,
+                account(&shard(origin, target, other_link, 0, 0, vec![9])),
+            ),
+        ];
+        client
+            .expect_get_program_accounts()
+            .returning(move |_, _| Ok(accounts.clone()));
+
+        let samples = get_all_device_latency_samples(&client, &telemetry_program_id, 7).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[&shard0_pk].samples, vec![1, 2, 3, 4]);
+        assert_eq!(samples[&shard0_pk].header.next_sample_index, 4);
+        assert_eq!(samples[&other_pk].samples, vec![9]);
+    }
 }
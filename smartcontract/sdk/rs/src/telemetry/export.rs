@@ -0,0 +1,404 @@
+//! Export latency samples and link stats to standard observability stacks.
+//!
+//! Samples and stats are first flattened into [`MetricPoint`]s, which can then be
+//! rendered as InfluxDB line protocol ([`to_line_protocol`]) or as a snappy-compressed
+//! Prometheus remote-write request body ([`to_remote_write`]).
+
+use crate::telemetry::stats::LinkLatencyStats;
+use doublezero_telemetry::state::{
+    device_latency_samples::DeviceLatencySamples, internet_latency_samples::InternetLatencySamples,
+};
+use std::{collections::BTreeMap, fmt::Write};
+
+pub const DEVICE_RTT_METRIC: &str = "doublezero_device_latency_rtt_microseconds";
+pub const DEVICE_LOSS_METRIC: &str = "doublezero_device_latency_loss";
+pub const INTERNET_RTT_METRIC: &str = "doublezero_internet_latency_rtt_microseconds";
+pub const INTERNET_LOSS_METRIC: &str = "doublezero_internet_latency_loss";
+pub const LINK_STATS_METRIC_PREFIX: &str = "doublezero_link_latency";
+
+/// One value of a time series, shared by both export formats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricPoint {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    pub timestamp_us: u64,
+}
+
+/// Points for every written sample of a device latency samples account. A zero
+/// sample is a lost probe: it is exported as a loss of 1 rather than an RTT.
+pub fn device_samples_points(
+    samples: &DeviceLatencySamples,
+    link_code: Option<&str>,
+) -> Vec<MetricPoint> {
+    let header = &samples.header;
+    let mut labels = BTreeMap::from([
+        ("epoch".to_string(), header.epoch.to_string()),
+        ("link".to_string(), header.link_pk.to_string()),
+        (
+            "origin_device".to_string(),
+            header.origin_device_pk.to_string(),
+        ),
+        (
+            "target_device".to_string(),
+            header.target_device_pk.to_string(),
+        ),
+    ]);
+    if let Some(code) = link_code {
+        labels.insert("link_code".to_string(), code.to_string());
+    }
+
+    sample_points(
+        &samples.samples,
+        header.start_timestamp_microseconds,
+        header.sampling_interval_microseconds,
+        (DEVICE_RTT_METRIC, DEVICE_LOSS_METRIC),
+        &labels,
+    )
+}
+
+/// Points for every written sample of an internet latency samples account. A zero
+/// sample is a lost probe: it is exported as a loss of 1 rather than an RTT.
+pub fn internet_samples_points(samples: &InternetLatencySamples) -> Vec<MetricPoint> {
+    let header = &samples.header;
+    let labels = BTreeMap::from([
+        (
+            "data_provider".to_string(),
+            header.data_provider_name.clone(),
+        ),
+        ("epoch".to_string(), header.epoch.to_string()),
+        (
+            "origin_exchange".to_string(),
+            header.origin_exchange_pk.to_string(),
+        ),
+        (
+            "target_exchange".to_string(),
+            header.target_exchange_pk.to_string(),
+        ),
+    ]);
+
+    sample_points(
+        &samples.samples,
+        header.start_timestamp_microseconds,
+        header.sampling_interval_microseconds,
+        (INTERNET_RTT_METRIC, INTERNET_LOSS_METRIC),
+        &labels,
+    )
+}
+
+fn sample_points(
+    samples: &[u32],
+    start_timestamp_us: u64,
+    interval_us: u64,
+    (rtt_metric, loss_metric): (&str, &str),
+    labels: &BTreeMap<String, String>,
+) -> Vec<MetricPoint> {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &rtt)| {
+            let (name, value) = if rtt == 0 {
+                (loss_metric, 1.0)
+            } else {
+                (rtt_metric, rtt as f64)
+            };
+            MetricPoint {
+                name: name.to_string(),
+                labels: labels.clone(),
+                value,
+                timestamp_us: start_timestamp_us + i as u64 * interval_us,
+            }
+        })
+        .collect()
+}
+
+/// Points for the derived stats of one link, stamped with `timestamp_us`. Latencies
+/// are in milliseconds, as computed by [`calculate_stats`](crate::telemetry::calculate_stats).
+pub fn link_stats_points(stats: &LinkLatencyStats, timestamp_us: u64) -> Vec<MetricPoint> {
+    let mut labels = BTreeMap::from([
+        ("epoch".to_string(), stats.epoch.to_string()),
+        ("link".to_string(), stats.link_pk.to_string()),
+        (
+            "origin_device".to_string(),
+            stats.origin_device_pk.to_string(),
+        ),
+        (
+            "target_device".to_string(),
+            stats.target_device_pk.to_string(),
+        ),
+    ]);
+    if let Some(code) = &stats.link_code {
+        labels.insert("link_code".to_string(), code.clone());
+    }
+
+    [
+        ("sample_count", stats.sample_count as f64),
+        ("p50_milliseconds", stats.p50),
+        ("p90_milliseconds", stats.p90),
+        ("p95_milliseconds", stats.p95),
+        ("p99_milliseconds", stats.p99),
+        ("mean_milliseconds", stats.mean),
+        ("min_milliseconds", stats.min),
+        ("max_milliseconds", stats.max),
+        ("stddev_milliseconds", stats.stddev),
+    ]
+    .into_iter()
+    .map(|(suffix, value)| MetricPoint {
+        name: format!("{LINK_STATS_METRIC_PREFIX}_{suffix}"),
+        labels: labels.clone(),
+        value,
+        timestamp_us,
+    })
+    .collect()
+}
+
+/// Render points as InfluxDB line protocol, one line per point with a single `value`
+/// field and a nanosecond timestamp.
+pub fn to_line_protocol(points: &[MetricPoint]) -> String {
+    let mut out = String::new();
+    for point in points {
+        out.push_str(&escape_influx(&point.name, false));
+        for (key, value) in &point.labels {
+            if value.is_empty() {
+                continue;
+            }
+            let _ = write!(
+                out,
+                ",{}={}",
+                escape_influx(key, true),
+                escape_influx(value, true)
+            );
+        }
+        let _ = writeln!(
+            out,
+            " value={} {}",
+            point.value,
+            point.timestamp_us.saturating_mul(1_000)
+        );
+    }
+    out
+}
+
+// Measurements escape commas and spaces; tag keys and values also escape `=`.
+fn escape_influx(s: &str, is_tag: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == ',' || c == ' ' || (is_tag && c == '=') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Render points as the body of a Prometheus remote-write request: a snappy-compressed
+/// protobuf `WriteRequest`. Send it with `Content-Encoding: snappy`,
+/// `Content-Type: application/x-protobuf` and `X-Prometheus-Remote-Write-Version: 0.1.0`.
+pub fn to_remote_write(points: &[MetricPoint]) -> eyre::Result<Vec<u8>> {
+    snap::raw::Encoder::new()
+        .compress_vec(&encode_write_request(points))
+        .map_err(|err| eyre::eyre!("snappy compression failed: {err}"))
+}
+
+/// Encode points as an uncompressed protobuf `WriteRequest`. Points with the same name
+/// and labels form one time series; labels are sorted with `__name__` among them and
+/// samples are ordered by timestamp, as remote-write receivers expect.
+pub fn encode_write_request(points: &[MetricPoint]) -> Vec<u8> {
+    // Sorted labels -> (value, timestamp in ms) samples.
+    type Labels<'a> = Vec<(&'a str, &'a str)>;
+    let mut series: BTreeMap<Labels, Vec<(f64, i64)>> = BTreeMap::new();
+    for point in points {
+        let mut labels: Vec<(&str, &str)> = point
+            .labels
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        labels.push(("__name__", point.name.as_str()));
+        labels.sort_unstable();
+        series
+            .entry(labels)
+            .or_default()
+            .push((point.value, (point.timestamp_us / 1_000) as i64));
+    }
+
+    let mut request = Vec::new();
+    for (labels, mut samples) in series {
+        samples.sort_by_key(|&(_, timestamp_ms)| timestamp_ms);
+
+        let mut timeseries = Vec::new();
+        for (name, value) in labels {
+            let mut label = Vec::new();
+            put_bytes(&mut label, 1, name.as_bytes());
+            put_bytes(&mut label, 2, value.as_bytes());
+            put_bytes(&mut timeseries, 1, &label);
+        }
+        for (value, timestamp_ms) in samples {
+            let mut sample = Vec::new();
+            put_key(&mut sample, 1, WIRE_FIXED64);
+            sample.extend_from_slice(&value.to_le_bytes());
+            put_key(&mut sample, 2, WIRE_VARINT);
+            put_varint(&mut sample, timestamp_ms as u64);
+            put_bytes(&mut timeseries, 2, &sample);
+        }
+        put_bytes(&mut request, 1, &timeseries);
+    }
+    request
+}
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn put_key(buf: &mut Vec<u8>, field: u32, wire_type: u8) {
+    put_varint(buf, ((field as u64) << 3) | wire_type as u64);
+}
+
+fn put_bytes(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    put_key(buf, field, WIRE_LEN);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::calculate_stats;
+    use doublezero_telemetry::state::{
+        accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    fn device_samples() -> DeviceLatencySamples {
+        DeviceLatencySamples {
+            header: DeviceLatencySamplesHeader {
+                account_type: AccountType::DeviceLatencySamples,
+                epoch: 42,
+                origin_device_agent_pk: Pubkey::new_unique(),
+                origin_device_pk: Pubkey::new_unique(),
+                target_device_pk: Pubkey::new_unique(),
+                origin_device_location_pk: Pubkey::new_unique(),
+                target_device_location_pk: Pubkey::new_unique(),
+                link_pk: Pubkey::new_unique(),
+                sampling_interval_microseconds: 5_000_000,
+                start_timestamp_microseconds: 1_700_000_000_000_000,
+                next_sample_index: 3,
+                agent_version: [0; 16],
+                agent_commit: [0; 8],
+                flags: 0,
+                shard_index: 0,
+                previous_agent_pk: Pubkey::default(),
+                agent_rotation_epoch: 0,
+                previous_agent_until_epoch: 0,
+                _unused: [0; 54],
+            },
+            samples: vec![1_250, 0, 1_300],
+        }
+    }
+
+    #[test]
+    fn test_device_samples_points() {
+        let samples = device_samples();
+        let points = device_samples_points(&samples, Some("ams-fra"));
+
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].name, DEVICE_RTT_METRIC);
+        assert_eq!(points[0].value, 1_250.0);
+        assert_eq!(points[0].timestamp_us, 1_700_000_000_000_000);
+        assert_eq!(points[0].labels["link_code"], "ams-fra");
+        assert_eq!(points[0].labels["epoch"], "42");
+        // The lost probe in the middle is a loss, spaced by the sampling interval.
+        assert_eq!(points[1].name, DEVICE_LOSS_METRIC);
+        assert_eq!(points[1].value, 1.0);
+        assert_eq!(points[1].timestamp_us, 1_700_000_005_000_000);
+        assert_eq!(points[2].timestamp_us, 1_700_000_010_000_000);
+    }
+
+    #[test]
+    fn test_link_stats_points() {
+        let samples = device_samples();
+        let header = &samples.header;
+        let stats = calculate_stats(
+            header.epoch,
+            header.link_pk,
+            None,
+            header.origin_device_pk,
+            header.target_device_pk,
+            &samples.samples,
+        )
+        .unwrap();
+
+        let points = link_stats_points(&stats, 7);
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[0].name, "doublezero_link_latency_sample_count");
+        assert_eq!(points[0].value, 3.0);
+        assert!(points.iter().all(|p| p.timestamp_us == 7));
+        assert!(!points[0].labels.contains_key("link_code"));
+    }
+
+    #[test]
+    fn test_to_line_protocol() {
+        let point = MetricPoint {
+            name: "rtt us".to_string(),
+            labels: BTreeMap::from([
+                ("link_code".to_string(), "a=b,c d".to_string()),
+                ("empty".to_string(), String::new()),
+            ]),
+            value: 1.5,
+            timestamp_us: 2,
+        };
+        assert_eq!(
+            to_line_protocol(&[point]),
+            "rtt\\ us,link_code=a\\=b\\,c\\ d value=1.5 2000\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_write_request() {
+        let point = |value, timestamp_us| MetricPoint {
+            name: "m".to_string(),
+            labels: BTreeMap::from([("a".to_string(), "b".to_string())]),
+            value,
+            timestamp_us,
+        };
+        // Out of order samples of one series are merged and sorted.
+        let encoded = encode_write_request(&[point(2.0, 3_000), point(1.0, 1_000)]);
+
+        let label = |name: &[u8], value: &[u8]| {
+            let mut out = vec![0x0a, 2 + name.len() as u8 + 2 + value.len() as u8];
+            out.extend_from_slice(&[0x0a, name.len() as u8]);
+            out.extend_from_slice(name);
+            out.extend_from_slice(&[0x12, value.len() as u8]);
+            out.extend_from_slice(value);
+            out
+        };
+        let sample = |value: f64, timestamp_ms: u8| {
+            let mut out = vec![0x12, 11, 0x09];
+            out.extend_from_slice(&value.to_le_bytes());
+            out.extend_from_slice(&[0x10, timestamp_ms]);
+            out
+        };
+        let mut timeseries = label(b"__name__", b"m");
+        timeseries.extend(label(b"a", b"b"));
+        timeseries.extend(sample(1.0, 1));
+        timeseries.extend(sample(2.0, 3));
+        let mut expected = vec![0x0a, timeseries.len() as u8];
+        expected.extend(timeseries);
+
+        assert_eq!(encoded, expected);
+
+        let compressed = to_remote_write(&[point(2.0, 3_000), point(1.0, 1_000)]).unwrap();
+        let decompressed = snap::raw::Decoder::new()
+            .decompress_vec(&compressed)
+            .unwrap();
+        assert_eq!(decompressed, expected);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod client;
+pub mod export;
+pub mod series;
 pub mod stats;
 
-pub use client::get_all_device_latency_samples;
+pub use client::{get_all_device_latency_samples, get_device_latency_samples};
+pub use series::{get_link_latency_series, LatencyPoint, LinkLatencySeries};
 pub use stats::{calculate_stats, LinkLatencyStats};
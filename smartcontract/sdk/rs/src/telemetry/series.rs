@@ -0,0 +1,401 @@
+use crate::{telemetry::client::stitch_shards, DoubleZeroClient};
+use doublezero_telemetry::state::device_latency_samples::DeviceLatencySamples;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::BTreeMap;
+
+const DEVICE_LATENCY_SAMPLES_ACCOUNT_TYPE: u8 = 3;
+// account_type (1) + epoch (8) + five pubkeys (agent, origin, target and
+// their locations) precede the link pubkey in the header.
+const LINK_PK_OFFSET: usize = 1 + 8 + 5 * 32;
+
+/// One entry of a latency series, in time order.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LatencyPoint {
+    /// A probe sent at `timestamp_us`; `rtt_us` is `None` if it was lost.
+    Sample {
+        timestamp_us: u64,
+        rtt_us: Option<u32>,
+    },
+    /// No samples were recorded from `start_us` until `end_us`, e.g. an epoch
+    /// whose account is missing or an agent that stopped writing.
+    Gap { start_us: u64, end_us: u64 },
+}
+
+/// Samples one device recorded over a link, stitched across epochs.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LinkLatencySeries {
+    pub link_pk: Pubkey,
+    pub origin_device_pk: Pubkey,
+    pub target_device_pk: Pubkey,
+    /// Epochs whose samples accounts contributed to the series
+    pub epochs: Vec<u64>,
+    pub points: Vec<LatencyPoint>,
+}
+
+impl LinkLatencySeries {
+    pub fn samples(&self) -> impl Iterator<Item = (u64, Option<u32>)> + '_ {
+        self.points.iter().filter_map(|point| match point {
+            LatencyPoint::Sample {
+                timestamp_us,
+                rtt_us,
+            } => Some((*timestamp_us, *rtt_us)),
+            LatencyPoint::Gap { .. } => None,
+        })
+    }
+
+    pub fn gaps(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.points.iter().filter_map(|point| match point {
+            LatencyPoint::Gap { start_us, end_us } => Some((*start_us, *end_us)),
+            LatencyPoint::Sample { .. } => None,
+        })
+    }
+}
+
+// Fetch every latency samples account of a link in a single RPC call and build
+// one continuous series per direction covering `[from_ts_us, to_ts_us)`.
+// Sample timestamps are rebuilt from each epoch's start timestamp and sampling
+// interval; wherever an expected sample is missing, including at either end of
+// the range, a gap is marked.
+pub fn get_link_latency_series(
+    client: &dyn DoubleZeroClient,
+    telemetry_program_id: &Pubkey,
+    link_pk: &Pubkey,
+    from_ts_us: u64,
+    to_ts_us: u64,
+) -> eyre::Result<Vec<LinkLatencySeries>> {
+    if from_ts_us >= to_ts_us {
+        eyre::bail!("Empty time range: {from_ts_us} is not before {to_ts_us}");
+    }
+
+    let filters = vec![
+        RpcFilterType::Memcmp(Memcmp::new(
+            0, // account_type is the first byte
+            MemcmpEncodedBytes::Bytes(vec![DEVICE_LATENCY_SAMPLES_ACCOUNT_TYPE]),
+        )),
+        RpcFilterType::Memcmp(Memcmp::new(
+            LINK_PK_OFFSET,
+            MemcmpEncodedBytes::Bytes(link_pk.to_bytes().to_vec()),
+        )),
+    ];
+
+    let options = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: None,
+            commitment: Some(CommitmentConfig::confirmed()),
+            min_context_slot: None,
+        },
+        with_context: None,
+        sort_results: None,
+    };
+
+    let accounts = client.get_program_accounts(telemetry_program_id, options)?;
+
+    // Shards of one direction and epoch, then epochs of one direction.
+    let mut shards: BTreeMap<(Pubkey, Pubkey, u64), Vec<DeviceLatencySamples>> = BTreeMap::new();
+    for (_, account) in accounts {
+        let Ok(samples) = DeviceLatencySamples::try_from(&account.data[..]) else {
+            continue;
+        };
+        let header = &samples.header;
+        if header.link_pk != *link_pk {
+            continue;
+        }
+        shards
+            .entry((
+                header.origin_device_pk,
+                header.target_device_pk,
+                header.epoch,
+            ))
+            .or_default()
+            .push(samples);
+    }
+
+    let mut epochs: BTreeMap<(Pubkey, Pubkey), Vec<DeviceLatencySamples>> = BTreeMap::new();
+    for ((origin, target, _), mut epoch_shards) in shards {
+        epoch_shards.sort_by_key(|shard| shard.header.shard_index);
+        if let Some(stitched) = stitch_shards(epoch_shards) {
+            epochs.entry((origin, target)).or_default().push(stitched);
+        }
+    }
+
+    Ok(epochs
+        .into_iter()
+        .filter_map(|((origin_device_pk, target_device_pk), segments)| {
+            stitch_epochs(
+                *link_pk,
+                origin_device_pk,
+                target_device_pk,
+                segments,
+                from_ts_us,
+                to_ts_us,
+            )
+        })
+        .collect())
+}
+
+// Lay the epochs of one direction end to end by their start timestamps. An
+// epoch without a sampling interval carries no timeline and is skipped, as are
+// samples overlapping ones already placed.
+fn stitch_epochs(
+    link_pk: Pubkey,
+    origin_device_pk: Pubkey,
+    target_device_pk: Pubkey,
+    mut segments: Vec<DeviceLatencySamples>,
+    from_ts_us: u64,
+    to_ts_us: u64,
+) -> Option<LinkLatencySeries> {
+    segments.retain(|segment| segment.header.sampling_interval_microseconds > 0);
+    segments.sort_by_key(|segment| segment.header.start_timestamp_microseconds);
+
+    let mut series = LinkLatencySeries {
+        link_pk,
+        origin_device_pk,
+        target_device_pk,
+        epochs: vec![],
+        points: vec![],
+    };
+    // Time the next sample is expected at, and the interval it is expected in.
+    let mut expected: Option<(u64, u64)> = None;
+
+    for segment in &segments {
+        let start = segment.header.start_timestamp_microseconds;
+        let interval = segment.header.sampling_interval_microseconds;
+        let mut contributed = false;
+
+        for (i, &rtt) in segment.samples.iter().enumerate() {
+            let timestamp_us = start.saturating_add(i as u64 * interval);
+            if timestamp_us < from_ts_us {
+                continue;
+            }
+            if timestamp_us >= to_ts_us {
+                break;
+            }
+
+            let gap_start = match expected {
+                Some((next, _)) if timestamp_us < next.saturating_sub(interval / 2) => continue,
+                Some((next, previous_interval)) => {
+                    (timestamp_us > next + previous_interval / 2).then_some(next)
+                }
+                None => (timestamp_us >= from_ts_us + interval).then_some(from_ts_us),
+            };
+            if let Some(start_us) = gap_start {
+                series.points.push(LatencyPoint::Gap {
+                    start_us,
+                    end_us: timestamp_us,
+                });
+            }
+
+            series.points.push(LatencyPoint::Sample {
+                timestamp_us,
+                rtt_us: (rtt != 0).then_some(rtt),
+            });
+            expected = Some((timestamp_us + interval, interval));
+            contributed = true;
+        }
+
+        if contributed {
+            series.epochs.push(segment.header.epoch);
+        }
+    }
+
+    let (next, interval) = expected?;
+    if to_ts_us > next + interval / 2 {
+        series.points.push(LatencyPoint::Gap {
+            start_us: next,
+            end_us: to_ts_us,
+        });
+    }
+    Some(series)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockDoubleZeroClient;
+    use doublezero_telemetry::state::{
+        accounttype::AccountType, device_latency_samples::DeviceLatencySamplesHeader,
+        SAMPLES_FLAG_ROLLED_OVER,
+    };
+    use solana_sdk::account::Account;
+
+    const INTERVAL: u64 = 5_000_000;
+    const T0: u64 = 1_700_000_000_000_000;
+
+    fn segment(
+        origin_device_pk: Pubkey,
+        link_pk: Pubkey,
+        epoch: u64,
+        start_timestamp_microseconds: u64,
+        shard_index: u8,
+        flags: u8,
+        samples: Vec<u32>,
+    ) -> Account {
+        let samples = DeviceLatencySamples {
+            header: DeviceLatencySamplesHeader {
+                account_type: AccountType::DeviceLatencySamples,
+                epoch,
+                origin_device_agent_pk: Pubkey::new_unique(),
+                origin_device_pk,
+                target_device_pk: Pubkey::new_from_array([9; 32]),
+                origin_device_location_pk: Pubkey::new_unique(),
+                target_device_location_pk: Pubkey::new_unique(),
+                link_pk,
+                sampling_interval_microseconds: INTERVAL,
+                start_timestamp_microseconds,
+                next_sample_index: samples.len() as u32,
+                agent_version: [0; 16],
+                agent_commit: [0; 8],
+                flags,
+                shard_index,
+                previous_agent_pk: Pubkey::default(),
+                agent_rotation_epoch: 0,
+                previous_agent_until_epoch: 0,
+                _unused: [0; 54],
+            },
+            samples,
+        };
+        Account {
+            data: borsh::to_vec(&samples).unwrap(),
+            ..Account::default()
+        }
+    }
+
+    fn series_for(
+        accounts: Vec<Account>,
+        link: Pubkey,
+        from: u64,
+        to: u64,
+    ) -> Vec<LinkLatencySeries> {
+        let mut client = MockDoubleZeroClient::new();
+        let accounts: Vec<(Pubkey, Account)> = accounts
+            .into_iter()
+            .map(|account| (Pubkey::new_unique(), account))
+            .collect();
+        client
+            .expect_get_program_accounts()
+            .returning(move |_, _| Ok(accounts.clone()));
+        get_link_latency_series(&client, &Pubkey::new_unique(), &link, from, to).unwrap()
+    }
+
+    #[test]
+    fn test_link_pk_offset_matches_header() {
+        let link = Pubkey::new_unique();
+        let account = segment(Pubkey::new_unique(), link, 1, T0, 0, 0, vec![]);
+        assert_eq!(
+            &account.data[LINK_PK_OFFSET..LINK_PK_OFFSET + 32],
+            link.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_series_stitches_epochs_and_shards() {
+        let (origin, link) = (Pubkey::new_unique(), Pubkey::new_unique());
+        // Epoch 10 holds three samples over two shards, epoch 11 picks up
+        // right after them.
+        let accounts = vec![
+            segment(origin, link, 11, T0 + 3 * INTERVAL, 0, 0, vec![40, 0]),
+            segment(origin, link, 10, T0, 1, 0, vec![30]),
+            segment(
+                origin,
+                link,
+                10,
+                T0,
+                0,
+                SAMPLES_FLAG_ROLLED_OVER,
+                vec![10, 20],
+            ),
+        ];
+
+        let series = series_for(accounts, link, T0, T0 + 5 * INTERVAL);
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].origin_device_pk, origin);
+        assert_eq!(series[0].epochs, vec![10, 11]);
+        assert_eq!(
+            series[0].samples().collect::<Vec<_>>(),
+            vec![
+                (T0, Some(10)),
+                (T0 + INTERVAL, Some(20)),
+                (T0 + 2 * INTERVAL, Some(30)),
+                (T0 + 3 * INTERVAL, Some(40)),
+                (T0 + 4 * INTERVAL, None),
+            ]
+        );
+        assert_eq!(series[0].gaps().count(), 0);
+    }
+
+    #[test]
+    fn test_series_marks_missing_epochs_and_range_ends() {
+        let (origin, link) = (Pubkey::new_unique(), Pubkey::new_unique());
+        // Epoch 11 is missing: epoch 12 starts ten intervals after epoch 10.
+        let accounts = vec![
+            segment(origin, link, 10, T0, 0, 0, vec![10, 20]),
+            segment(origin, link, 12, T0 + 12 * INTERVAL, 0, 0, vec![30, 40]),
+            segment(Pubkey::new_unique(), link, 10, T0, 0, 0, vec![50]),
+        ];
+
+        let series = series_for(accounts, link, T0 - 3 * INTERVAL, T0 + 20 * INTERVAL);
+        let series = series
+            .iter()
+            .find(|series| series.origin_device_pk == origin)
+            .unwrap();
+        assert_eq!(series.epochs, vec![10, 12]);
+        assert_eq!(
+            series.points,
+            vec![
+                LatencyPoint::Gap {
+                    start_us: T0 - 3 * INTERVAL,
+                    end_us: T0
+                },
+                LatencyPoint::Sample {
+                    timestamp_us: T0,
+                    rtt_us: Some(10)
+                },
+                LatencyPoint::Sample {
+                    timestamp_us: T0 + INTERVAL,
+                    rtt_us: Some(20)
+                },
+                LatencyPoint::Gap {
+                    start_us: T0 + 2 * INTERVAL,
+                    end_us: T0 + 12 * INTERVAL
+                },
+                LatencyPoint::Sample {
+                    timestamp_us: T0 + 12 * INTERVAL,
+                    rtt_us: Some(30)
+                },
+                LatencyPoint::Sample {
+                    timestamp_us: T0 + 13 * INTERVAL,
+                    rtt_us: Some(40)
+                },
+                LatencyPoint::Gap {
+                    start_us: T0 + 14 * INTERVAL,
+                    end_us: T0 + 20 * INTERVAL
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_series_trims_to_range() {
+        let (origin, link) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = vec![segment(origin, link, 10, T0, 0, 0, vec![10, 20, 30, 40])];
+
+        let series = series_for(accounts, link, T0 + INTERVAL, T0 + 3 * INTERVAL);
+        assert_eq!(
+            series[0].samples().collect::<Vec<_>>(),
+            vec![(T0 + INTERVAL, Some(20)), (T0 + 2 * INTERVAL, Some(30))]
+        );
+        assert_eq!(series[0].gaps().count(), 0);
+
+        // A range with no samples yields no series at all.
+        let accounts = vec![segment(origin, link, 10, T0, 0, 0, vec![10])];
+        assert!(series_for(accounts, link, T0 + INTERVAL, T0 + 2 * INTERVAL).is_empty());
+    }
+}
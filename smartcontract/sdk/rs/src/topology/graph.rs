@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use doublezero_serviceability::state::{
+    device::{Device, DeviceStatus},
+    link::{Link, LinkHealth, LinkStatus},
+};
+use petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+use solana_sdk::pubkey::Pubkey;
+
+/// Weight given to soft-drained and flap-damped links, matching the ISIS metric
+/// the controller pushes for them (1s, in microseconds).
+pub const SOFT_DRAINED_WEIGHT_US: u64 = 1_000_000;
+
+/// Delay overrides outside this range (10us - 1s) are ignored, as they are by
+/// the controller.
+const DELAY_OVERRIDE_RANGE_NS: std::ops::RangeInclusive<u64> = 10_000..=1_000_000_000;
+
+/// Controls which links enter the graph and how they are weighted.
+///
+/// With the defaults a link's weight is its ISIS metric as computed by the
+/// controller: the delay (or valid delay override) in microseconds, rounded up,
+/// or [`SOFT_DRAINED_WEIGHT_US`] for soft-drained and flap-damped links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightConfig {
+    /// Added to the weight of links whose health is `Impaired`.
+    pub impaired_penalty_us: u64,
+    /// When non-zero, adds `reference_bandwidth_bps / bandwidth` microseconds to
+    /// each link, so lower-capacity links cost more.
+    pub reference_bandwidth_bps: u64,
+    /// Keep links flagged as unicast-drained.
+    pub include_unicast_drained: bool,
+}
+
+impl Default for WeightConfig {
+    fn default() -> Self {
+        Self {
+            impaired_penalty_us: 0,
+            reference_bandwidth_bps: 0,
+            include_unicast_drained: true,
+        }
+    }
+}
+
+impl WeightConfig {
+    /// Weight of `link` in microseconds, or `None` if the link does not carry
+    /// traffic and is left out of the graph.
+    pub fn link_weight(&self, link: &Link) -> Option<u64> {
+        match link.status {
+            LinkStatus::Activated | LinkStatus::SoftDrained => {}
+            _ => return None,
+        }
+        if link.delay_ns == 0 || (!self.include_unicast_drained && link.is_unicast_drained()) {
+            return None;
+        }
+
+        let delay_ns = if DELAY_OVERRIDE_RANGE_NS.contains(&link.delay_override_ns) {
+            link.delay_override_ns
+        } else {
+            link.delay_ns
+        };
+        let mut weight = if link.status == LinkStatus::SoftDrained || link.is_flap_damped() {
+            SOFT_DRAINED_WEIGHT_US
+        } else {
+            delay_ns.div_ceil(1_000)
+        };
+
+        if link.link_health == LinkHealth::Impaired {
+            weight = weight.saturating_add(self.impaired_penalty_us);
+        }
+        if self.reference_bandwidth_bps != 0 && link.bandwidth != 0 {
+            weight = weight.saturating_add(self.reference_bandwidth_bps / link.bandwidth);
+        }
+        Some(weight.max(1))
+    }
+}
+
+/// A link as an edge of the [`TopologyGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkEdge {
+    pub link_pk: Pubkey,
+    /// Weight in microseconds, see [`WeightConfig::link_weight`].
+    pub weight: u64,
+    pub bandwidth: u64,
+}
+
+/// Undirected graph of activated devices (nodes) and the links between them
+/// (edges) that carry traffic.
+///
+/// Devices and links are inserted in pubkey order, so path computations are
+/// deterministic for a given snapshot.
+#[derive(Debug, Clone, Default)]
+pub struct TopologyGraph {
+    pub(crate) graph: UnGraph<Pubkey, LinkEdge>,
+    pub(crate) nodes: HashMap<Pubkey, NodeIndex>,
+    pub(crate) edges: HashMap<Pubkey, EdgeIndex>,
+}
+
+impl TopologyGraph {
+    /// Build the graph from device and link accounts, as returned by
+    /// `ListDeviceCommand` and `ListLinkCommand`.
+    pub fn from_accounts(
+        devices: &HashMap<Pubkey, Device>,
+        links: &HashMap<Pubkey, Link>,
+        config: &WeightConfig,
+    ) -> Self {
+        let mut topology = Self::default();
+
+        let mut device_pks: Vec<&Pubkey> = devices
+            .iter()
+            .filter(|(_, device)| device.status == DeviceStatus::Activated)
+            .map(|(pk, _)| pk)
+            .collect();
+        device_pks.sort();
+        for pk in device_pks {
+            let index = topology.graph.add_node(*pk);
+            topology.nodes.insert(*pk, index);
+        }
+
+        let mut links: Vec<(&Pubkey, &Link)> = links.iter().collect();
+        links.sort_by_key(|(pk, _)| **pk);
+        for (pk, link) in links {
+            let (Some(&side_a), Some(&side_z)) = (
+                topology.nodes.get(&link.side_a_pk),
+                topology.nodes.get(&link.side_z_pk),
+            ) else {
+                continue;
+            };
+            if side_a == side_z {
+                continue;
+            }
+            let Some(weight) = config.link_weight(link) else {
+                continue;
+            };
+            let index = topology.graph.add_edge(
+                side_a,
+                side_z,
+                LinkEdge {
+                    link_pk: *pk,
+                    weight,
+                    bandwidth: link.bandwidth,
+                },
+            );
+            topology.edges.insert(*pk, index);
+        }
+
+        topology
+    }
+
+    /// Number of devices in the graph.
+    pub fn device_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Number of links in the graph.
+    pub fn link_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    pub fn contains_device(&self, device_pk: &Pubkey) -> bool {
+        self.nodes.contains_key(device_pk)
+    }
+
+    /// The edge for `link_pk`, if the link is part of the graph.
+    pub fn link(&self, link_pk: &Pubkey) -> Option<&LinkEdge> {
+        self.edges
+            .get(link_pk)
+            .and_then(|index| self.graph.edge_weight(*index))
+    }
+
+    /// The underlying petgraph graph, for algorithms not wrapped here.
+    pub fn graph(&self) -> &UnGraph<Pubkey, LinkEdge> {
+        &self.graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::tests::{device, link};
+    use doublezero_serviceability::state::link::{
+        LINK_FLAG_FLAP_DAMPED, LINK_FLAG_UNICAST_DRAINED,
+    };
+
+    #[test]
+    fn test_link_weight() {
+        let config = WeightConfig::default();
+        let a = Pubkey::new_unique();
+        let z = Pubkey::new_unique();
+
+        let mut l = link(a, z, 1_500);
+        assert_eq!(config.link_weight(&l), Some(2));
+
+        // A valid override replaces the measured delay; an out-of-range one is ignored.
+        l.delay_override_ns = 20_000;
+        assert_eq!(config.link_weight(&l), Some(20));
+        l.delay_override_ns = 5_000;
+        assert_eq!(config.link_weight(&l), Some(2));
+
+        l.link_flags = LINK_FLAG_FLAP_DAMPED;
+        assert_eq!(config.link_weight(&l), Some(SOFT_DRAINED_WEIGHT_US));
+        l.link_flags = 0;
+
+        l.status = LinkStatus::SoftDrained;
+        assert_eq!(config.link_weight(&l), Some(SOFT_DRAINED_WEIGHT_US));
+        l.status = LinkStatus::HardDrained;
+        assert_eq!(config.link_weight(&l), None);
+
+        let mut l = link(a, z, 0);
+        assert_eq!(config.link_weight(&l), None);
+
+        l.delay_ns = 10_000;
+        l.link_health = LinkHealth::Impaired;
+        l.link_flags = LINK_FLAG_UNICAST_DRAINED;
+        let config = WeightConfig {
+            impaired_penalty_us: 500,
+            reference_bandwidth_bps: 100_000_000_000,
+            include_unicast_drained: true,
+        };
+        // 10us delay + 500us impaired penalty + 100Gbps / 10Gbps.
+        assert_eq!(config.link_weight(&l), Some(520));
+        let config = WeightConfig {
+            include_unicast_drained: false,
+            ..config
+        };
+        assert_eq!(config.link_weight(&l), None);
+    }
+
+    #[test]
+    fn test_from_accounts_skips_inactive_devices_and_links() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+        let mut drained = device();
+        drained.status = DeviceStatus::Drained;
+        let devices = HashMap::from([(a, device()), (b, device()), (c, drained)]);
+
+        let ab = Pubkey::new_unique();
+        let bc = Pubkey::new_unique();
+        let mut requested = link(a, b, 1_000);
+        requested.status = LinkStatus::Requested;
+        let links = HashMap::from([
+            (ab, link(a, b, 1_000)),
+            (bc, link(b, c, 1_000)),
+            (Pubkey::new_unique(), requested),
+        ]);
+
+        let topology = TopologyGraph::from_accounts(&devices, &links, &WeightConfig::default());
+        assert_eq!(topology.device_count(), 2);
+        assert!(!topology.contains_device(&c));
+        assert_eq!(topology.link_count(), 1);
+        assert_eq!(topology.link(&ab).unwrap().weight, 1);
+        assert!(topology.link(&bc).is_none());
+    }
+}
@@ -0,0 +1,47 @@
+//! Graph model of the DoubleZero topology.
+//!
+//! [`TopologyGraph`] turns a snapshot of device and link accounts into a
+//! weighted graph and provides the path computations shared by device
+//! selection, multicast tree estimation, planning tools and QA validations:
+//! shortest path, k-shortest paths, reachability, partitions and single
+//! points of failure. [`TopologySnapshot`] loads the accounts the graph is
+//! built from and derives per-contributor graphs.
+
+mod graph;
+mod paths;
+mod snapshot;
+
+pub use graph::{LinkEdge, TopologyGraph, WeightConfig, SOFT_DRAINED_WEIGHT_US};
+pub use paths::{MulticastTree, TopologyPath};
+pub use snapshot::TopologySnapshot;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use doublezero_serviceability::state::{
+        accounttype::AccountType,
+        device::{Device, DeviceStatus},
+        link::{Link, LinkHealth, LinkStatus},
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    pub fn device() -> Device {
+        Device {
+            account_type: AccountType::Device,
+            status: DeviceStatus::Activated,
+            ..Default::default()
+        }
+    }
+
+    pub fn link(side_a_pk: Pubkey, side_z_pk: Pubkey, delay_ns: u64) -> Link {
+        Link {
+            account_type: AccountType::Link,
+            side_a_pk,
+            side_z_pk,
+            bandwidth: 10_000_000_000,
+            delay_ns,
+            status: LinkStatus::Activated,
+            link_health: LinkHealth::ReadyForService,
+            ..Default::default()
+        }
+    }
+}
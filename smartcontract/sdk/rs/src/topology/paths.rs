@@ -0,0 +1,357 @@
+use std::collections::{BTreeSet, HashSet};
+
+use petgraph::{
+    algo::{articulation_points::articulation_points, astar, bridges},
+    graph::{EdgeIndex, NodeIndex},
+    visit::{Bfs, EdgeFiltered, EdgeRef},
+};
+use solana_sdk::pubkey::Pubkey;
+
+use super::graph::TopologyGraph;
+
+/// A loop-free path through the topology.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyPath {
+    /// Devices from source to destination, both included.
+    pub devices: Vec<Pubkey>,
+    /// Links traversed, one fewer than `devices`.
+    pub links: Vec<Pubkey>,
+    /// Sum of the link weights, in microseconds.
+    pub cost: u64,
+}
+
+/// Links joining a source to a set of subscribers along their shortest paths,
+/// as an estimate of the tree a multicast group would use.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MulticastTree {
+    pub links: BTreeSet<Pubkey>,
+    /// Sum of the weights of `links`, counting each link once.
+    pub cost: u64,
+    /// Subscribers with no path from the source.
+    pub unreachable: Vec<Pubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct IndexPath {
+    cost: u64,
+    edges: Vec<EdgeIndex>,
+    nodes: Vec<NodeIndex>,
+}
+
+impl TopologyGraph {
+    /// Lowest-cost path between two devices, or `None` if either is not in the
+    /// graph or they are not connected.
+    pub fn shortest_path(&self, from: &Pubkey, to: &Pubkey) -> Option<TopologyPath> {
+        let (&from, &to) = (self.nodes.get(from)?, self.nodes.get(to)?);
+        self.path_avoiding(from, to, &HashSet::new(), &HashSet::new())
+            .map(|path| self.to_topology_path(path))
+    }
+
+    /// Up to `k` loop-free paths between two devices in increasing cost order
+    /// (Yen's algorithm). Parallel links between the same devices yield
+    /// distinct paths.
+    pub fn k_shortest_paths(&self, from: &Pubkey, to: &Pubkey, k: usize) -> Vec<TopologyPath> {
+        let (Some(&from), Some(&to)) = (self.nodes.get(from), self.nodes.get(to)) else {
+            return vec![];
+        };
+        if k == 0 {
+            return vec![];
+        }
+        let Some(first) = self.path_avoiding(from, to, &HashSet::new(), &HashSet::new()) else {
+            return vec![];
+        };
+
+        let mut accepted = vec![first];
+        let mut candidates: BTreeSet<IndexPath> = BTreeSet::new();
+        while accepted.len() < k {
+            let previous = accepted.last().expect("at least one accepted path");
+            for i in 0..previous.edges.len() {
+                let spur_node = previous.nodes[i];
+                let root_nodes = &previous.nodes[..=i];
+                let root_edges = &previous.edges[..i];
+
+                let excluded_edges: HashSet<EdgeIndex> = accepted
+                    .iter()
+                    .filter(|path| path.nodes.starts_with(root_nodes))
+                    .filter(|path| path.edges.starts_with(root_edges))
+                    .filter_map(|path| path.edges.get(i).copied())
+                    .collect();
+                let excluded_nodes: HashSet<NodeIndex> = root_nodes[..i].iter().copied().collect();
+
+                let Some(spur) =
+                    self.path_avoiding(spur_node, to, &excluded_nodes, &excluded_edges)
+                else {
+                    continue;
+                };
+                let root_cost: u64 = root_edges.iter().map(|e| self.graph[*e].weight).sum();
+                let mut nodes = root_nodes[..i].to_vec();
+                nodes.extend(spur.nodes);
+                let mut edges = root_edges.to_vec();
+                edges.extend(spur.edges);
+                let candidate = IndexPath {
+                    cost: root_cost + spur.cost,
+                    edges,
+                    nodes,
+                };
+                if !accepted.contains(&candidate) {
+                    candidates.insert(candidate);
+                }
+            }
+            match candidates.pop_first() {
+                Some(next) => accepted.push(next),
+                None => break,
+            }
+        }
+
+        accepted
+            .into_iter()
+            .map(|path| self.to_topology_path(path))
+            .collect()
+    }
+
+    /// Devices reachable from `from`, including itself. Empty if `from` is not
+    /// in the graph.
+    pub fn reachable_from(&self, from: &Pubkey) -> HashSet<Pubkey> {
+        let Some(&start) = self.nodes.get(from) else {
+            return HashSet::new();
+        };
+        let mut reachable = HashSet::new();
+        let mut bfs = Bfs::new(&self.graph, start);
+        while let Some(node) = bfs.next(&self.graph) {
+            reachable.insert(self.graph[node]);
+        }
+        reachable
+    }
+
+    /// Whether a path exists between two devices.
+    pub fn is_reachable(&self, from: &Pubkey, to: &Pubkey) -> bool {
+        self.nodes.contains_key(to) && self.reachable_from(from).contains(to)
+    }
+
+    /// Connected components of the graph, each sorted by pubkey, largest first.
+    /// A fully connected topology has exactly one partition.
+    pub fn partitions(&self) -> Vec<Vec<Pubkey>> {
+        let mut seen: HashSet<Pubkey> = HashSet::new();
+        let mut partitions = vec![];
+        for node in self.graph.node_indices() {
+            let device_pk = self.graph[node];
+            if seen.contains(&device_pk) {
+                continue;
+            }
+            let mut partition: Vec<Pubkey> = self.reachable_from(&device_pk).into_iter().collect();
+            partition.sort();
+            seen.extend(partition.iter().copied());
+            partitions.push(partition);
+        }
+        partitions.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        partitions
+    }
+
+    /// Devices whose loss would split their partition, sorted by pubkey.
+    pub fn articulation_points(&self) -> Vec<Pubkey> {
+        // The algorithm needs ordered edge weights, which links do not need to
+        // be for connectivity.
+        let graph = self.graph.map(|_, device_pk| *device_pk, |_, _| ());
+        let mut devices: Vec<Pubkey> = articulation_points(&graph)
+            .into_iter()
+            .map(|node| graph[node])
+            .collect();
+        devices.sort();
+        devices
+    }
+
+    /// Links whose loss would split their partition, sorted by pubkey. A link
+    /// backed by a parallel link between the same devices is not one.
+    pub fn single_link_failures(&self) -> Vec<Pubkey> {
+        let mut links: Vec<Pubkey> = bridges(&self.graph)
+            .filter(|edge| {
+                self.graph
+                    .edges_connecting(edge.source(), edge.target())
+                    .count()
+                    == 1
+            })
+            .map(|edge| edge.weight().link_pk)
+            .collect();
+        links.sort();
+        links
+    }
+
+    /// Union of the shortest paths from `source` to each subscriber.
+    pub fn multicast_tree(&self, source: &Pubkey, subscribers: &[Pubkey]) -> MulticastTree {
+        let mut tree = MulticastTree::default();
+        for subscriber in subscribers {
+            match self.shortest_path(source, subscriber) {
+                Some(path) => tree.links.extend(path.links),
+                None => tree.unreachable.push(*subscriber),
+            }
+        }
+        tree.cost = tree
+            .links
+            .iter()
+            .filter_map(|pk| self.link(pk))
+            .map(|edge| edge.weight)
+            .sum();
+        tree
+    }
+
+    fn path_avoiding(
+        &self,
+        from: NodeIndex,
+        to: NodeIndex,
+        excluded_nodes: &HashSet<NodeIndex>,
+        excluded_edges: &HashSet<EdgeIndex>,
+    ) -> Option<IndexPath> {
+        let allowed = |edge: petgraph::graph::EdgeReference<'_, _>| {
+            !excluded_edges.contains(&edge.id())
+                && !excluded_nodes.contains(&edge.source())
+                && !excluded_nodes.contains(&edge.target())
+        };
+        let filtered = EdgeFiltered::from_fn(&self.graph, allowed);
+        let (cost, nodes) = astar(
+            &filtered,
+            from,
+            |node| node == to,
+            |edge| edge.weight().weight,
+            |_| 0,
+        )?;
+
+        // astar only reports nodes; with parallel links pick the cheapest
+        // allowed one between each hop, which is the one astar relaxed.
+        let edges = nodes
+            .windows(2)
+            .map(|hop| {
+                self.graph
+                    .edges_connecting(hop[0], hop[1])
+                    .filter(|edge| allowed(*edge))
+                    .min_by_key(|edge| (edge.weight().weight, edge.id()))
+                    .map(|edge| edge.id())
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(IndexPath { cost, edges, nodes })
+    }
+
+    fn to_topology_path(&self, path: IndexPath) -> TopologyPath {
+        TopologyPath {
+            devices: path.nodes.iter().map(|n| self.graph[*n]).collect(),
+            links: path.edges.iter().map(|e| self.graph[*e].link_pk).collect(),
+            cost: path.cost,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::topology::{
+        tests::{device, link},
+        WeightConfig,
+    };
+
+    /// a --1-- b --1-- d
+    ///  \             /
+    ///   3-----c----3
+    /// plus a second, slower a-b link (2) and an isolated device e.
+    fn sample() -> (TopologyGraph, [Pubkey; 5], [Pubkey; 5]) {
+        let devices: [Pubkey; 5] = std::array::from_fn(|_| Pubkey::new_unique());
+        let [a, b, c, d, _e] = devices;
+        let links: [Pubkey; 5] = std::array::from_fn(|_| Pubkey::new_unique());
+        let [ab, ab2, bd, ac, cd] = links;
+
+        let device_map = devices.iter().map(|pk| (*pk, device())).collect();
+        let link_map = HashMap::from([
+            (ab, link(a, b, 1_000)),
+            (ab2, link(a, b, 2_000)),
+            (bd, link(b, d, 1_000)),
+            (ac, link(a, c, 3_000)),
+            (cd, link(c, d, 3_000)),
+        ]);
+        (
+            TopologyGraph::from_accounts(&device_map, &link_map, &WeightConfig::default()),
+            devices,
+            links,
+        )
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let (topology, [a, b, _, d, e], [ab, _, bd, _, _]) = sample();
+
+        let path = topology.shortest_path(&a, &d).unwrap();
+        assert_eq!(path.devices, vec![a, b, d]);
+        assert_eq!(path.links, vec![ab, bd]);
+        assert_eq!(path.cost, 2);
+
+        assert!(topology.shortest_path(&a, &e).is_none());
+        assert!(topology.shortest_path(&a, &Pubkey::new_unique()).is_none());
+
+        let path = topology.shortest_path(&a, &a).unwrap();
+        assert_eq!(path.devices, vec![a]);
+        assert!(path.links.is_empty());
+    }
+
+    #[test]
+    fn test_k_shortest_paths() {
+        let (topology, [a, b, c, d, _], [ab, ab2, bd, ac, cd]) = sample();
+
+        let paths = topology.k_shortest_paths(&a, &d, 5);
+        let summary: Vec<(Vec<Pubkey>, u64)> =
+            paths.iter().map(|p| (p.links.clone(), p.cost)).collect();
+        assert_eq!(
+            summary,
+            vec![(vec![ab, bd], 2), (vec![ab2, bd], 3), (vec![ac, cd], 6),]
+        );
+        assert_eq!(paths[2].devices, vec![a, c, d]);
+        assert!(paths.iter().all(|p| p.devices[1] == b || p.devices[1] == c));
+
+        assert_eq!(topology.k_shortest_paths(&a, &d, 1).len(), 1);
+        assert!(topology.k_shortest_paths(&a, &d, 0).is_empty());
+    }
+
+    #[test]
+    fn test_reachability_and_partitions() {
+        let (topology, [a, b, c, d, e], _) = sample();
+
+        assert_eq!(topology.reachable_from(&a), HashSet::from([a, b, c, d]));
+        assert!(topology.is_reachable(&d, &c));
+        assert!(!topology.is_reachable(&a, &e));
+
+        let partitions = topology.partitions();
+        assert_eq!(partitions.len(), 2);
+        assert_eq!(partitions[0].len(), 4);
+        assert_eq!(partitions[1], vec![e]);
+    }
+
+    #[test]
+    fn test_single_points_of_failure() {
+        let (topology, [a, b, _, d, _], [_, _, bd, _, _]) = sample();
+
+        // The a-b-d-c ring has no cut device; the parallel a-b links back each other.
+        assert!(topology.articulation_points().is_empty());
+        assert!(topology.single_link_failures().is_empty());
+
+        // Dropping the c side leaves a chain a = b - d.
+        let devices = [a, b, d].iter().map(|pk| (*pk, device())).collect();
+        let (ab, ab2) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let links = HashMap::from([
+            (ab, link(a, b, 1_000)),
+            (ab2, link(a, b, 2_000)),
+            (bd, link(b, d, 1_000)),
+        ]);
+        let chain = TopologyGraph::from_accounts(&devices, &links, &WeightConfig::default());
+        assert_eq!(chain.articulation_points(), vec![b]);
+        assert_eq!(chain.single_link_failures(), vec![bd]);
+    }
+
+    #[test]
+    fn test_multicast_tree() {
+        let (topology, [a, b, c, d, e], [ab, _, bd, ac, _]) = sample();
+
+        let tree = topology.multicast_tree(&a, &[b, c, d, e]);
+        assert_eq!(tree.links, BTreeSet::from([ab, bd, ac]));
+        assert_eq!(tree.cost, 1 + 1 + 3);
+        assert_eq!(tree.unreachable, vec![e]);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use doublezero_serviceability::state::{
+    device::Device, exchange::Exchange, link::Link, location::Location,
+};
+use solana_sdk::pubkey::Pubkey;
+
+use super::graph::{TopologyGraph, WeightConfig};
+use crate::{
+    commands::{
+        device::list::ListDeviceCommand, exchange::list::ListExchangeCommand,
+        link::list::ListLinkCommand, location::list::ListLocationCommand,
+    },
+    DoubleZeroClient,
+};
+
+/// The device, link, exchange and location accounts a [`TopologyGraph`] is
+/// built from, kept so graph results can be mapped back to metros and sites.
+#[derive(Debug, Clone, Default)]
+pub struct TopologySnapshot {
+    pub devices: HashMap<Pubkey, Device>,
+    pub links: HashMap<Pubkey, Link>,
+    pub exchanges: HashMap<Pubkey, Exchange>,
+    pub locations: HashMap<Pubkey, Location>,
+}
+
+impl TopologySnapshot {
+    /// Fetch every device, link, exchange and location account.
+    pub fn load(client: &dyn DoubleZeroClient) -> eyre::Result<Self> {
+        Ok(Self {
+            devices: ListDeviceCommand.execute(client)?,
+            links: ListLinkCommand.execute(client)?,
+            exchanges: ListExchangeCommand.execute(client)?,
+            locations: ListLocationCommand.execute(client)?,
+        })
+    }
+
+    /// Graph of the whole topology.
+    pub fn graph(&self, config: &WeightConfig) -> TopologyGraph {
+        TopologyGraph::from_accounts(&self.devices, &self.links, config)
+    }
+
+    /// Graph of the devices operated by `contributor_pk` and the links it
+    /// operates between them.
+    pub fn contributor_graph(
+        &self,
+        contributor_pk: &Pubkey,
+        config: &WeightConfig,
+    ) -> TopologyGraph {
+        let devices: HashMap<Pubkey, Device> = self
+            .devices
+            .iter()
+            .filter(|(_, device)| device.contributor_pk == *contributor_pk)
+            .map(|(pk, device)| (*pk, device.clone()))
+            .collect();
+        let links: HashMap<Pubkey, Link> = self
+            .links
+            .iter()
+            .filter(|(_, link)| link.contributor_pk == *contributor_pk)
+            .map(|(pk, link)| (*pk, link.clone()))
+            .collect();
+        TopologyGraph::from_accounts(&devices, &links, config)
+    }
+
+    /// Exchange (metro) the device belongs to.
+    pub fn exchange_of(&self, device_pk: &Pubkey) -> Option<&Exchange> {
+        self.devices
+            .get(device_pk)
+            .and_then(|device| self.exchanges.get(&device.exchange_pk))
+    }
+
+    /// Location (site) the device is installed at.
+    pub fn location_of(&self, device_pk: &Pubkey) -> Option<&Location> {
+        self.devices
+            .get(device_pk)
+            .and_then(|device| self.locations.get(&device.location_pk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::utils::create_test_client,
+        topology::tests::{device, link},
+    };
+    use doublezero_serviceability::state::{
+        accountdata::AccountData, accounttype::AccountType, exchange::ExchangeStatus,
+        location::LocationStatus,
+    };
+    use mockall::predicate;
+
+    #[test]
+    fn test_load_and_contributor_graph() {
+        let mut client = create_test_client();
+
+        let (contributor1, contributor2) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (exchange_pk, location_pk) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let [a, b, c] = std::array::from_fn(|_| Pubkey::new_unique());
+        let [ab, bc] = std::array::from_fn(|_| Pubkey::new_unique());
+
+        let owned_device = |contributor_pk| Device {
+            contributor_pk,
+            exchange_pk,
+            location_pk,
+            ..device()
+        };
+        let owned_link = |side_a_pk, side_z_pk, contributor_pk| Link {
+            contributor_pk,
+            ..link(side_a_pk, side_z_pk, 1_000)
+        };
+        let exchange = Exchange {
+            account_type: AccountType::Exchange,
+            index: 1,
+            bump_seed: 0,
+            reference_count: 3,
+            code: "xams".to_string(),
+            name: "Amsterdam".to_string(),
+            device1_pk: Pubkey::default(),
+            device2_pk: Pubkey::default(),
+            lat: 52.37,
+            lng: 4.9,
+            bgp_community: 10_000,
+            unused: 0,
+            status: ExchangeStatus::Activated,
+            owner: Pubkey::new_unique(),
+        };
+        let location = Location {
+            account_type: AccountType::Location,
+            index: 2,
+            bump_seed: 0,
+            reference_count: 3,
+            code: "ams1".to_string(),
+            name: "Amsterdam 1".to_string(),
+            country: "NL".to_string(),
+            lat: 52.37,
+            lng: 4.9,
+            loc_id: 1,
+            status: LocationStatus::Activated,
+            owner: Pubkey::new_unique(),
+        };
+
+        let accounts = [
+            (
+                AccountType::Device,
+                vec![
+                    (a, AccountData::Device(owned_device(contributor1))),
+                    (b, AccountData::Device(owned_device(contributor1))),
+                    (c, AccountData::Device(owned_device(contributor2))),
+                ],
+            ),
+            (
+                AccountType::Link,
+                vec![
+                    (ab, AccountData::Link(owned_link(a, b, contributor1))),
+                    (bc, AccountData::Link(owned_link(b, c, contributor2))),
+                ],
+            ),
+            (
+                AccountType::Exchange,
+                vec![(exchange_pk, AccountData::Exchange(exchange))],
+            ),
+            (
+                AccountType::Location,
+                vec![(location_pk, AccountData::Location(location))],
+            ),
+        ];
+        for (account_type, data) in accounts {
+            client
+                .expect_gets()
+                .with(predicate::eq(account_type))
+                .returning(move |_| Ok(data.clone().into_iter().collect()));
+        }
+
+        let snapshot = TopologySnapshot::load(&client).unwrap();
+        assert_eq!(snapshot.exchange_of(&a).unwrap().code, "xams");
+        assert_eq!(snapshot.location_of(&c).unwrap().code, "ams1");
+        assert!(snapshot.exchange_of(&Pubkey::new_unique()).is_none());
+
+        let config = WeightConfig::default();
+        let full = snapshot.graph(&config);
+        assert_eq!((full.device_count(), full.link_count()), (3, 2));
+
+        let owned = snapshot.contributor_graph(&contributor1, &config);
+        assert_eq!((owned.device_count(), owned.link_count()), (2, 1));
+        assert!(owned.link(&ab).is_some());
+        assert!(!owned.contains_device(&c));
+
+        // contributor2's link ends on a device it does not operate.
+        let owned = snapshot.contributor_graph(&contributor2, &config);
+        assert_eq!((owned.device_count(), owned.link_count()), (1, 0));
+    }
+}
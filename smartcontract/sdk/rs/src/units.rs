@@ -0,0 +1,241 @@
+//! Newtypes for the bandwidth, latency and epoch values carried as raw `u64`s
+//! onchain, so that a bits-per-second value cannot be passed where megabits
+//! are expected or a millisecond delay where nanoseconds are.
+//!
+//! Each wraps a single `u64` and serializes exactly like it, in Borsh and
+//! serde alike, so they can stand in for the raw fields without changing any
+//! account or instruction layout.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use doublezero_program_common::types::parse_utils::{bandwidth_parse, bandwidth_to_string};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+macro_rules! u64_newtype {
+    ($name:ident) => {
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+/// Bandwidth in bits per second, as stored in links, interfaces and
+/// multicast groups.
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[serde(transparent)]
+pub struct Bps(pub u64);
+
+u64_newtype!(Bps);
+
+impl Bps {
+    pub const fn from_kbps(kbps: u64) -> Self {
+        Self(kbps * 1_000)
+    }
+
+    pub const fn from_mbps(mbps: u64) -> Self {
+        Self(mbps * 1_000_000)
+    }
+
+    pub const fn from_gbps(gbps: u64) -> Self {
+        Self(gbps * 1_000_000_000)
+    }
+
+    pub fn as_mbps(self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+
+    pub fn as_gbps(self) -> f64 {
+        self.0 as f64 / 1_000_000_000.0
+    }
+}
+
+/// Formats with the largest fitting unit, e.g. `10Gbps` or `1.50Mbps`.
+impl fmt::Display for Bps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&bandwidth_to_string(&self.0))
+    }
+}
+
+/// Parses a value with a unit, e.g. `100Kbps`, `1Mbps` or `10 Gbps`.
+impl FromStr for Bps {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bandwidth_parse(s).map(Self)
+    }
+}
+
+/// A duration in nanoseconds, as stored for link delay and jitter.
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[serde(transparent)]
+pub struct Nanoseconds(pub u64);
+
+u64_newtype!(Nanoseconds);
+
+impl Nanoseconds {
+    pub const fn from_micros(us: u64) -> Self {
+        Self(us * 1_000)
+    }
+
+    pub const fn from_millis(ms: u64) -> Self {
+        Self(ms * 1_000_000)
+    }
+
+    /// Converts fractional milliseconds, as taken on the command line,
+    /// truncating below a nanosecond. Negative values saturate to zero.
+    pub fn from_millis_f64(ms: f64) -> Self {
+        Self((ms * 1_000_000.0) as u64)
+    }
+
+    pub fn as_micros(self) -> u64 {
+        self.0 / 1_000
+    }
+
+    pub fn as_millis_f64(self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+}
+
+/// Formats as milliseconds with two decimals, e.g. `1.25ms`.
+impl fmt::Display for Nanoseconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}ms", self.as_millis_f64())
+    }
+}
+
+/// A Solana epoch number.
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[serde(transparent)]
+pub struct EpochNumber(pub u64);
+
+u64_newtype!(EpochNumber);
+
+impl EpochNumber {
+    pub fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+
+    pub fn saturating_add(self, epochs: u64) -> Self {
+        Self(self.0.saturating_add(epochs))
+    }
+
+    /// Epochs elapsed from `earlier` to `self`, or `None` if `earlier` is
+    /// later.
+    pub fn epochs_since(self, earlier: EpochNumber) -> Option<u64> {
+        self.0.checked_sub(earlier.0)
+    }
+}
+
+impl fmt::Display for EpochNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_keep_u64_layout() {
+        for value in [0, 1, 1_234_567_890, u64::MAX] {
+            let raw = borsh::to_vec(&value).unwrap();
+            assert_eq!(borsh::to_vec(&Bps(value)).unwrap(), raw);
+            assert_eq!(borsh::to_vec(&Nanoseconds(value)).unwrap(), raw);
+            assert_eq!(borsh::to_vec(&EpochNumber(value)).unwrap(), raw);
+            assert_eq!(borsh::from_slice::<Bps>(&raw).unwrap(), Bps(value));
+
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::to_string(&Nanoseconds(value)).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<EpochNumber>(&json).unwrap(),
+                EpochNumber(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_bps_conversions() {
+        assert_eq!(Bps::from_gbps(10), Bps(10_000_000_000));
+        assert_eq!(Bps::from_mbps(1), Bps::from_kbps(1_000));
+        assert_eq!(Bps::from_mbps(1_500).as_gbps(), 1.5);
+        assert_eq!(Bps(2_500_000).as_mbps(), 2.5);
+
+        assert_eq!(Bps::from_gbps(10).to_string(), "10Gbps");
+        assert_eq!(Bps(1_500_000).to_string(), "1.50Mbps");
+        assert_eq!("10Gbps".parse::<Bps>(), Ok(Bps::from_gbps(10)));
+        assert_eq!("100 kbps".parse::<Bps>(), Ok(Bps::from_kbps(100)));
+        assert!("100".parse::<Bps>().is_err());
+    }
+
+    #[test]
+    fn test_nanoseconds_conversions() {
+        assert_eq!(Nanoseconds::from_millis(3), Nanoseconds(3_000_000));
+        assert_eq!(Nanoseconds::from_micros(250), Nanoseconds(250_000));
+        assert_eq!(Nanoseconds::from_millis_f64(0.25), Nanoseconds(250_000));
+        assert_eq!(Nanoseconds::from_millis_f64(-1.0), Nanoseconds(0));
+        assert_eq!(Nanoseconds(1_250_000).as_micros(), 1_250);
+        assert_eq!(Nanoseconds(1_250_000).to_string(), "1.25ms");
+    }
+
+    #[test]
+    fn test_epoch_number() {
+        let epoch = EpochNumber(100);
+        assert_eq!(epoch.next(), EpochNumber(101));
+        assert_eq!(EpochNumber(u64::MAX).next(), EpochNumber(u64::MAX));
+        assert_eq!(epoch.saturating_add(5), EpochNumber(105));
+        assert_eq!(EpochNumber(105).epochs_since(epoch), Some(5));
+        assert_eq!(epoch.epochs_since(EpochNumber(105)), None);
+        assert_eq!(epoch.to_string(), "100");
+    }
+}
@@ -0,0 +1,92 @@
+//! Snapshot of the public state enums re-exported by the SDK.
+//!
+//! These enums are `#[non_exhaustive]`, so adding a variant is not a breaking
+//! change for downstream crates. Renaming, removing or renumbering one is, as is
+//! dropping a re-export. This test pins every variant's name and onchain
+//! discriminant so such changes show up in review.
+//!
+//! After an intentional change, regenerate the snapshot with:
+//!
+//!     UPDATE_PUBLIC_API=1 cargo test -p doublezero_sdk --test public_api
+
+use std::{fmt::Debug, fs, path::Path};
+
+use borsh::BorshDeserialize;
+use doublezero_sdk::{
+    AccountType, BGPStatus, BandwidthTier, ContributorStatus, DeviceStatus, DeviceType,
+    ExchangeStatus, InterfaceOperState, InterfaceStatus, InterfaceType, IpAllocationStatus,
+    LinkLinkType, LinkStatus, LocationStatus, LoopbackType, MulticastGroupStatus, PermissionStatus,
+    ReadApiKeyStatus, UserCYOA, UserStatus, UserType,
+};
+
+const SNAPSHOT: &str = "tests/snapshots/public_api.txt";
+
+/// Every `name = discriminant` pair of a borsh `use_discriminant` enum, found by
+/// decoding each possible tag byte.
+fn variants<T: BorshDeserialize + Debug>() -> Vec<String> {
+    (0..=u8::MAX)
+        .filter_map(|b| {
+            borsh::from_slice::<T>(&[b])
+                .ok()
+                .map(|v| format!("{v:?} = {b}"))
+        })
+        .collect()
+}
+
+macro_rules! enum_section {
+    ($out:ident, $($t:ident),+ $(,)?) => {
+        $(
+            $out.push_str(&format!("enum {}\n", stringify!($t)));
+            for variant in variants::<$t>() {
+                $out.push_str(&format!("    {variant}\n"));
+            }
+        )+
+    };
+}
+
+fn render() -> String {
+    let mut out = String::new();
+    enum_section!(
+        out,
+        AccountType,
+        BGPStatus,
+        BandwidthTier,
+        ContributorStatus,
+        DeviceStatus,
+        DeviceType,
+        ExchangeStatus,
+        InterfaceOperState,
+        InterfaceStatus,
+        InterfaceType,
+        IpAllocationStatus,
+        LinkLinkType,
+        LinkStatus,
+        LocationStatus,
+        LoopbackType,
+        MulticastGroupStatus,
+        PermissionStatus,
+        ReadApiKeyStatus,
+        UserCYOA,
+        UserStatus,
+        UserType,
+    );
+    out
+}
+
+#[test]
+fn public_api_enums_match_snapshot() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SNAPSHOT);
+    let actual = render();
+
+    if std::env::var_os("UPDATE_PUBLIC_API").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        actual == expected,
+        "public API enums changed; if intentional, rerun with UPDATE_PUBLIC_API=1 \
+         and commit {SNAPSHOT}\n--- expected\n{expected}\n--- actual\n{actual}"
+    );
+}
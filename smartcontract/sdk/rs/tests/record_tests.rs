@@ -65,6 +65,26 @@ async fn test_record_client() {
 
     let payer_key = payer_signer.pubkey();
 
+    // Creating the record claimed its namespace for the payer.
+    let namespace = record::client::get_namespace(&rpc_client, b"test_record_client")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(namespace.authority, payer_key);
+    assert!(
+        record::client::claim_namespace_instruction(&rpc_client, &payer_key, seeds)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(record::client::claim_namespace_instruction(
+        &rpc_client,
+        &Keypair::new().pubkey(),
+        seeds
+    )
+    .await
+    .is_err());
+
     let mut count = 0;
 
     for record_chunk in record::instruction::write_record_chunks(&payer_key, seeds, record_data) {